@@ -0,0 +1,286 @@
+//! management of REST API tokens
+
+use app_core::{ApiScope, CrTopic, DEFAULT_API_TOKEN_RATE_LIMIT_PER_MINUTE};
+use app_utils::{
+    error::{ComponentError, strategy::handle_read_error},
+    hooks::use_on_cancel::use_on_cancel,
+    server_fn::api_token::{CreateApiToken, RevokeApiToken, list_api_tokens},
+    state::{
+        activity_tracker::ActivityTracker, error_state::PageErrorContext, toast_state::ToastContext,
+    },
+};
+use cr_leptos_axum_socket::use_client_registry_socket;
+use leptos::prelude::*;
+use uuid::Uuid;
+
+#[component]
+pub fn ApiTokens() -> impl IntoView {
+    // --- global context and state ---
+    let page_err_ctx = expect_context::<PageErrorContext>();
+    let toast_ctx = expect_context::<ToastContext>();
+    let activity_tracker = expect_context::<ActivityTracker>();
+    let component_id = StoredValue::new(Uuid::new_v4());
+
+    on_cleanup(move || {
+        page_err_ctx.clear_all_for_component(component_id.get_value());
+        activity_tracker.remove_component(component_id.get_value());
+    });
+
+    // --- local state ---
+    let name = RwSignal::new(String::new());
+    let scopes = RwSignal::new(vec![ApiScope::ReadPublic]);
+    let rate_limit = RwSignal::new(DEFAULT_API_TOKEN_RATE_LIMIT_PER_MINUTE);
+    let include_revoked = RwSignal::new(false);
+    // secret of the last created token; shown only once
+    let new_secret = RwSignal::new(None::<String>);
+
+    let tokens = Resource::new(
+        move || include_revoked.get(),
+        move |include_revoked| async move {
+            activity_tracker
+                .track_activity_wrapper(component_id.get_value(), list_api_tokens(include_revoked))
+                .await
+                .map_err(|app_error| ComponentError::new(component_id.get_value(), app_error))
+        },
+    );
+
+    let refetch = Callback::new(move |()| tokens.refetch());
+    page_err_ctx.register_retry_handler(component_id.get_value(), refetch);
+
+    // Subscribe to changes of api tokens
+    use_client_registry_socket(
+        Signal::derive(|| Some(CrTopic::ApiTokens)),
+        None.into(),
+        refetch,
+    );
+
+    let create_token = ServerAction::<CreateApiToken>::new();
+    activity_tracker.track_pending_memo(component_id.get_value(), create_token.pending());
+    Effect::new(move || match create_token.value().get() {
+        Some(Ok(created)) => {
+            new_secret.set(Some(created.secret));
+            name.set(String::new());
+            tokens.refetch();
+        }
+        Some(Err(err)) => {
+            toast_ctx.error(format!("Could not create token: {err}"), None);
+        }
+        None => {}
+    });
+
+    let revoke_token = ServerAction::<RevokeApiToken>::new();
+    activity_tracker.track_pending_memo(component_id.get_value(), revoke_token.pending());
+    Effect::new(move || match revoke_token.value().get() {
+        Some(Ok(revoked)) => {
+            toast_ctx.success(format!("Token '{}' revoked.", revoked.get_name()), None);
+            tokens.refetch();
+        }
+        Some(Err(err)) => {
+            toast_ctx.error(format!("Could not revoke token: {err}"), None);
+        }
+        None => {}
+    });
+
+    let on_submit = move || {
+        if name.get_untracked().trim().is_empty() || scopes.get_untracked().is_empty() {
+            toast_ctx.warning("Name and at least one scope are required.", None);
+            return;
+        }
+        new_secret.set(None);
+        create_token.dispatch(CreateApiToken {
+            name: name.get_untracked(),
+            scopes: scopes.get_untracked(),
+            rate_limit_per_minute: rate_limit.get_untracked(),
+        });
+    };
+
+    let on_cancel = use_on_cancel();
+
+    view! {
+        <div class="card w-full bg-base-100 shadow-xl" data-testid="api-tokens-root">
+            <div class="card-body">
+                <h2 class="card-title">"API Tokens"</h2>
+                <form
+                    class="flex flex-col gap-2"
+                    on:submit=move |ev| {
+                        ev.prevent_default();
+                        on_submit();
+                    }
+                >
+                    <div class="flex flex-wrap items-end gap-4">
+                        <label class="form-control">
+                            <span class="label-text">"Name"</span>
+                            <input
+                                type="text"
+                                class="input input-bordered w-full md:w-64"
+                                data-testid="input-api-token-name"
+                                prop:value=name
+                                on:input=move |ev| name.set(event_target_value(&ev))
+                            />
+                        </label>
+                        <label class="form-control">
+                            <span class="label-text">"Requests per minute"</span>
+                            <input
+                                type="number"
+                                min="1"
+                                class="input input-bordered w-32"
+                                data-testid="input-api-token-rate-limit"
+                                prop:value=move || rate_limit.get().to_string()
+                                on:input=move |ev| {
+                                    if let Ok(value) = event_target_value(&ev).parse() {
+                                        rate_limit.set(value);
+                                    }
+                                }
+                            />
+                        </label>
+                    </div>
+                    <div class="flex flex-wrap gap-4">
+                        {ApiScope::ALL
+                            .into_iter()
+                            .map(|scope| {
+                                view! {
+                                    <label class="label cursor-pointer gap-2">
+                                        <input
+                                            type="checkbox"
+                                            class="checkbox checkbox-sm"
+                                            data-testid=format!("input-api-token-scope-{scope}")
+                                            prop:checked=move || scopes.get().contains(&scope)
+                                            on:change=move |ev| {
+                                                let checked = event_target_checked(&ev);
+                                                scopes
+                                                    .update(|s| {
+                                                        s.retain(|x| *x != scope);
+                                                        if checked {
+                                                            s.push(scope);
+                                                        }
+                                                    });
+                                            }
+                                        />
+                                        <span class="label-text">{scope.as_str()}</span>
+                                    </label>
+                                }
+                            })
+                            .collect_view()}
+                    </div>
+                    <div>
+                        <button
+                            type="submit"
+                            class="btn btn-primary btn-sm"
+                            data-testid="action-btn-create-api-token"
+                            disabled=move || create_token.pending().get()
+                        >
+                            "Create Token"
+                        </button>
+                    </div>
+                </form>
+                {move || {
+                    new_secret
+                        .get()
+                        .map(|secret| {
+                            view! {
+                                <div role="alert" class="alert alert-success flex-col items-start">
+                                    <span>
+                                        "Copy the token now, it will not be shown again:"
+                                    </span>
+                                    <code class="break-all" data-testid="api-token-secret">
+                                        {secret}
+                                    </code>
+                                </div>
+                            }
+                        })
+                }}
+                <label class="label cursor-pointer gap-2 justify-start">
+                    <input
+                        type="checkbox"
+                        class="checkbox checkbox-sm"
+                        data-testid="input-api-token-include-revoked"
+                        prop:checked=include_revoked
+                        on:change=move |ev| include_revoked.set(event_target_checked(&ev))
+                    />
+                    <span class="label-text">"Show revoked tokens"</span>
+                </label>
+                <Transition fallback=move || {
+                    view! { <span class="loading loading-spinner loading-md"></span> }
+                }>
+                    <ErrorBoundary fallback=move |errors| {
+                        for (_err_id, err) in errors.get().into_iter() {
+                            if let Some(comp_err) = err.into_inner().downcast_ref::<ComponentError>() {
+                                handle_read_error(&page_err_ctx, comp_err, on_cancel);
+                            }
+                        }
+                    }>
+                        {move || {
+                            tokens
+                                .and_then(|list| {
+                                    let list = list.clone();
+                                    view! {
+                                        <table class="table table-sm" data-testid="api-tokens-table">
+                                            <thead>
+                                                <tr>
+                                                    <th>"Name"</th>
+                                                    <th>"Token"</th>
+                                                    <th>"Scopes"</th>
+                                                    <th>"Limit"</th>
+                                                    <th>"Last used"</th>
+                                                    <th></th>
+                                                </tr>
+                                            </thead>
+                                            <tbody>
+                                                <For
+                                                    each=move || list.clone()
+                                                    key=|t| (t.get_id(), t.get_version())
+                                                    children=move |token| {
+                                                        let id = token.get_id();
+                                                        let version = token.get_version().unwrap_or_default();
+                                                        let revoked = token.is_revoked();
+                                                        let scopes = token
+                                                            .get_scopes()
+                                                            .iter()
+                                                            .map(|s| s.as_str())
+                                                            .collect::<Vec<_>>()
+                                                            .join(", ");
+                                                        let last_used = token
+                                                            .get_last_used_at()
+                                                            .map(|t| t.format("%Y-%m-%d %H:%M").to_string())
+                                                            .unwrap_or_else(|| "never".to_string());
+                                                        view! {
+                                                            <tr data-testid="api-tokens-row" class:opacity-50=revoked>
+                                                                <td>{token.get_name().to_string()}</td>
+                                                                <td>
+                                                                    <code>{format!("{}…", token.get_secret_prefix())}</code>
+                                                                </td>
+                                                                <td>{scopes}</td>
+                                                                <td>{format!("{}/min", token.get_rate_limit_per_minute())}</td>
+                                                                <td>{last_used}</td>
+                                                                <td>
+                                                                    <Show
+                                                                        when=move || !revoked
+                                                                        fallback=|| view! { <span class="badge">"revoked"</span> }
+                                                                    >
+                                                                        <button
+                                                                            class="btn btn-error btn-xs"
+                                                                            data-testid="action-btn-revoke-api-token"
+                                                                            disabled=move || revoke_token.pending().get()
+                                                                            on:click=move |_| {
+                                                                                revoke_token.dispatch(RevokeApiToken { id, version });
+                                                                            }
+                                                                        >
+                                                                            "Revoke"
+                                                                        </button>
+                                                                    </Show>
+                                                                </td>
+                                                            </tr>
+                                                        }
+                                                    }
+                                                />
+                                            </tbody>
+                                        </table>
+                                    }
+                                })
+                        }}
+                    </ErrorBoundary>
+                </Transition>
+            </div>
+        </div>
+    }
+}
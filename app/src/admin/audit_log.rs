@@ -0,0 +1,243 @@
+//! history of all changes of a tournament
+
+use app_core::merge_display_value;
+use app_utils::{
+    error::{ComponentError, strategy::handle_read_error},
+    hooks::{
+        use_on_cancel::use_on_cancel,
+        use_url_navigation::{UseQueryNavigationReturn, use_query_navigation},
+    },
+    params::{ParamQuery, TournamentBaseIdQuery},
+    server_fn::audit_log::{AUDIT_LOG_LIST_LIMIT, list_audit_records},
+    state::{activity_tracker::ActivityTracker, error_state::PageErrorContext},
+};
+use leptos::prelude::*;
+use leptos_router::{NavigateOptions, hooks::use_navigate};
+use uuid::Uuid;
+
+#[component]
+pub fn AuditLog() -> impl IntoView {
+    // --- global context and state ---
+    let page_err_ctx = expect_context::<PageErrorContext>();
+    let activity_tracker = expect_context::<ActivityTracker>();
+    let component_id = StoredValue::new(Uuid::new_v4());
+
+    on_cleanup(move || {
+        page_err_ctx.clear_all_for_component(component_id.get_value());
+        activity_tracker.remove_component(component_id.get_value());
+    });
+
+    // --- url parameters and local state ---
+    let tournament_id = TournamentBaseIdQuery::use_param_query();
+    let UseQueryNavigationReturn {
+        url_update_query, ..
+    } = use_query_navigation();
+    let navigate = use_navigate();
+    let tournament_input = RwSignal::new(String::new());
+    Effect::new(move || {
+        if let Some(id) = tournament_id.get() {
+            tournament_input.set(id.to_string());
+        }
+    });
+    let input_is_valid =
+        move || tournament_input.with(|input| Uuid::parse_str(input.trim()).is_ok());
+
+    let records = Resource::new(
+        move || tournament_id.get(),
+        move |t_id| async move {
+            let Some(t_id) = t_id else {
+                return Ok(Vec::new());
+            };
+            activity_tracker
+                .track_activity_wrapper(component_id.get_value(), list_audit_records(t_id))
+                .await
+                .map_err(|app_error| ComponentError::new(component_id.get_value(), app_error))
+        },
+    );
+
+    // audit records are not published by the client registry; refresh manually
+    let refetch = Callback::new(move |()| records.refetch());
+    page_err_ctx.register_retry_handler(component_id.get_value(), refetch);
+
+    let on_cancel = use_on_cancel();
+
+    let show_history = move || {
+        let input = tournament_input.get_untracked();
+        if let Ok(id) = Uuid::parse_str(input.trim()) {
+            navigate(
+                &url_update_query(TournamentBaseIdQuery::KEY, &id.to_string(), None),
+                NavigateOptions::default(),
+            );
+        }
+    };
+
+    view! {
+        <div class="card w-full bg-base-100 shadow-xl" data-testid="audit-log-root">
+            <div class="card-body">
+                <div class="flex items-center justify-between">
+                    <h2 class="card-title">"Audit Log"</h2>
+                    <button
+                        class="btn btn-sm btn-outline"
+                        data-testid="action-btn-refresh-audit-log"
+                        disabled=move || tournament_id.get().is_none()
+                        on:click=move |_| refetch.run(())
+                    >
+                        "Refresh"
+                    </button>
+                </div>
+                <p class="text-sm text-base-content/70">
+                    {format!(
+                        "All changes of a tournament and its objects, newest first. At most {AUDIT_LOG_LIST_LIMIT} changes are shown.",
+                    )}
+                </p>
+                <form
+                    class="join w-full max-w-xl"
+                    on:submit=move |ev| {
+                        ev.prevent_default();
+                        show_history();
+                    }
+                >
+                    <input
+                        type="text"
+                        class="input input-bordered input-sm join-item w-full font-mono"
+                        placeholder="Tournament ID"
+                        aria-label="Tournament ID"
+                        data-testid="input-audit-log-tournament-id"
+                        prop:value=move || tournament_input.get()
+                        on:input=move |ev| tournament_input.set(event_target_value(&ev))
+                    />
+                    <button
+                        type="submit"
+                        class="btn btn-sm btn-primary join-item"
+                        data-testid="action-btn-show-audit-log"
+                        disabled=move || !input_is_valid()
+                    >
+                        "Show history"
+                    </button>
+                </form>
+                <Transition fallback=move || {
+                    view! { <span class="loading loading-spinner loading-md"></span> }
+                }>
+                    <ErrorBoundary fallback=move |errors| {
+                        for (_err_id, err) in errors.get().into_iter() {
+                            if let Some(comp_err) = err.into_inner().downcast_ref::<ComponentError>() {
+                                handle_read_error(&page_err_ctx, comp_err, on_cancel);
+                            }
+                        }
+                    }>
+                        {move || {
+                            records
+                                .and_then(|list| {
+                                    let list = list.clone();
+                                    let is_empty = list.is_empty();
+                                    view! {
+                                        <Show
+                                            when=move || !is_empty
+                                            fallback=move || {
+                                                view! {
+                                                    <p data-testid="audit-log-empty">
+                                                        {move || {
+                                                            if tournament_id.get().is_some() {
+                                                                "No changes recorded."
+                                                            } else {
+                                                                "Enter the ID of a tournament to show its history."
+                                                            }
+                                                        }}
+                                                    </p>
+                                                }
+                                            }
+                                        >
+                                            <table class="table table-sm" data-testid="audit-log-table">
+                                                <thead>
+                                                    <tr>
+                                                        <th>"Changed"</th>
+                                                        <th>"Actor"</th>
+                                                        <th>"Action"</th>
+                                                        <th>"Object"</th>
+                                                        <th>"Version"</th>
+                                                        <th>"Changes"</th>
+                                                    </tr>
+                                                </thead>
+                                                <tbody>
+                                                    <For
+                                                        each={
+                                                            let list = list.clone();
+                                                            move || list.clone()
+                                                        }
+                                                        key=|r| r.get_id()
+                                                        children=move |record| {
+                                                            let changed = record
+                                                                .get_created_at()
+                                                                .map(|t| t.format("%Y-%m-%d %H:%M:%S").to_string())
+                                                                .unwrap_or_default();
+                                                            let versions = match (
+                                                                record.get_old_version(),
+                                                                record.get_new_version(),
+                                                            ) {
+                                                                (Some(old), Some(new)) => format!("{old} → {new}"),
+                                                                (None, Some(new)) => new.to_string(),
+                                                                (Some(old), None) => format!("{old} → –"),
+                                                                (None, None) => String::new(),
+                                                            };
+                                                            let changes = record
+                                                                .get_diff()
+                                                                .as_object()
+                                                                .map(|fields| {
+                                                                    fields
+                                                                        .iter()
+                                                                        .map(|(field, change)| {
+                                                                            (
+                                                                                field.clone(),
+                                                                                merge_display_value(&change["old"]),
+                                                                                merge_display_value(&change["new"]),
+                                                                            )
+                                                                        })
+                                                                        .collect::<Vec<_>>()
+                                                                })
+                                                                .unwrap_or_default();
+                                                            view! {
+                                                                <tr data-testid="audit-log-row">
+                                                                    <td class="whitespace-nowrap">{changed}</td>
+                                                                    <td>{record.get_actor().to_string()}</td>
+                                                                    <td>{record.get_action().to_string()}</td>
+                                                                    <td>
+                                                                        <div>{record.get_object_type().to_string()}</div>
+                                                                        <code class="text-xs">
+                                                                            {record.get_object_id().to_string()}
+                                                                        </code>
+                                                                    </td>
+                                                                    <td class="whitespace-nowrap">{versions}</td>
+                                                                    <td>
+                                                                        <ul class="text-xs">
+                                                                            {changes
+                                                                                .into_iter()
+                                                                                .map(|(field, old, new)| {
+                                                                                    view! {
+                                                                                        <li class="break-all">
+                                                                                            <span class="font-mono">{field}</span>
+                                                                                            ": "
+                                                                                            <span class="line-through opacity-60">{old}</span>
+                                                                                            " → "
+                                                                                            <span>{new}</span>
+                                                                                        </li>
+                                                                                    }
+                                                                                })
+                                                                                .collect_view()}
+                                                                        </ul>
+                                                                    </td>
+                                                                </tr>
+                                                            }
+                                                        }
+                                                    />
+                                                </tbody>
+                                            </table>
+                                        </Show>
+                                    }
+                                })
+                        }}
+                    </ErrorBoundary>
+                </Transition>
+            </div>
+        </div>
+    }
+}
@@ -0,0 +1,138 @@
+//! uncaught errors reported by clients
+
+use app_utils::{
+    error::{ComponentError, strategy::handle_read_error},
+    hooks::use_on_cancel::use_on_cancel,
+    server_fn::client_error::{CLIENT_ERROR_LIST_LIMIT, list_client_errors},
+    state::{activity_tracker::ActivityTracker, error_state::PageErrorContext},
+};
+use leptos::prelude::*;
+use uuid::Uuid;
+
+#[component]
+pub fn ClientErrors() -> impl IntoView {
+    // --- global context and state ---
+    let page_err_ctx = expect_context::<PageErrorContext>();
+    let activity_tracker = expect_context::<ActivityTracker>();
+    let component_id = StoredValue::new(Uuid::new_v4());
+
+    on_cleanup(move || {
+        page_err_ctx.clear_all_for_component(component_id.get_value());
+        activity_tracker.remove_component(component_id.get_value());
+    });
+
+    let reports = Resource::new(
+        || (),
+        move |()| async move {
+            activity_tracker
+                .track_activity_wrapper(component_id.get_value(), list_client_errors())
+                .await
+                .map_err(|app_error| ComponentError::new(component_id.get_value(), app_error))
+        },
+    );
+
+    // reports are not published by the client registry; refresh manually
+    let refetch = Callback::new(move |()| reports.refetch());
+    page_err_ctx.register_retry_handler(component_id.get_value(), refetch);
+
+    let on_cancel = use_on_cancel();
+
+    view! {
+        <div class="card w-full bg-base-100 shadow-xl" data-testid="client-errors-root">
+            <div class="card-body">
+                <div class="flex items-center justify-between">
+                    <h2 class="card-title">"Client Errors"</h2>
+                    <button
+                        class="btn btn-sm btn-outline"
+                        data-testid="action-btn-refresh-client-errors"
+                        on:click=move |_| refetch.run(())
+                    >
+                        "Refresh"
+                    </button>
+                </div>
+                <p class="text-sm text-base-content/70">
+                    {format!(
+                        "Uncaught errors of clients, newest first. At most {CLIENT_ERROR_LIST_LIMIT} errors are shown.",
+                    )}
+                </p>
+                <Transition fallback=move || {
+                    view! { <span class="loading loading-spinner loading-md"></span> }
+                }>
+                    <ErrorBoundary fallback=move |errors| {
+                        for (_err_id, err) in errors.get().into_iter() {
+                            if let Some(comp_err) = err.into_inner().downcast_ref::<ComponentError>() {
+                                handle_read_error(&page_err_ctx, comp_err, on_cancel);
+                            }
+                        }
+                    }>
+                        {move || {
+                            reports
+                                .and_then(|list| {
+                                    let list = list.clone();
+                                    let is_empty = list.is_empty();
+                                    view! {
+                                        <Show
+                                            when=move || !is_empty
+                                            fallback=|| {
+                                                view! {
+                                                    <p data-testid="client-errors-empty">
+                                                        "No errors reported."
+                                                    </p>
+                                                }
+                                            }
+                                        >
+                                            <table class="table table-sm" data-testid="client-errors-table">
+                                                <thead>
+                                                    <tr>
+                                                        <th>"Reported"</th>
+                                                        <th>"Route"</th>
+                                                        <th>"Component"</th>
+                                                        <th>"Version"</th>
+                                                        <th>"Count"</th>
+                                                        <th>"Error"</th>
+                                                    </tr>
+                                                </thead>
+                                                <tbody>
+                                                    <For
+                                                        each={
+                                                            let list = list.clone();
+                                                            move || list.clone()
+                                                        }
+                                                        key=|r| r.get_id()
+                                                        children=move |report| {
+                                                            let reported = report
+                                                                .get_created_at()
+                                                                .map(|t| t.format("%Y-%m-%d %H:%M:%S").to_string())
+                                                                .unwrap_or_default();
+                                                            let component = report
+                                                                .get_component_id()
+                                                                .map(|id| id.to_string())
+                                                                .unwrap_or_default();
+                                                            view! {
+                                                                <tr data-testid="client-errors-row">
+                                                                    <td class="whitespace-nowrap">{reported}</td>
+                                                                    <td>
+                                                                        <code>{report.get_route().to_string()}</code>
+                                                                    </td>
+                                                                    <td>
+                                                                        <code class="text-xs">{component}</code>
+                                                                    </td>
+                                                                    <td>{report.get_app_version().to_string()}</td>
+                                                                    <td>{report.get_occurrences()}</td>
+                                                                    <td class="break-all">{report.get_message().to_string()}</td>
+                                                                </tr>
+                                                            }
+                                                        }
+                                                    />
+                                                </tbody>
+                                            </table>
+                                        </Show>
+                                    }
+                                })
+                        }}
+                    </ErrorBoundary>
+                </Transition>
+            </div>
+        </div>
+    }
+}
@@ -0,0 +1,11 @@
+//! administration pages
+
+mod api_tokens;
+mod audit_log;
+mod client_errors;
+mod webhooks;
+
+pub use api_tokens::*;
+pub use audit_log::*;
+pub use client_errors::*;
+pub use webhooks::*;
@@ -0,0 +1,442 @@
+//! management of webhook endpoints and their delivery history
+
+use app_core::{CrTopic, WebhookEventType, WebhookFormat};
+use app_utils::{
+    error::{ComponentError, strategy::handle_read_error},
+    hooks::use_on_cancel::use_on_cancel,
+    server_fn::webhook::{
+        SaveWebhookEndpoint, SendTestWebhook, list_webhook_deliveries, list_webhook_endpoints,
+    },
+    state::{
+        activity_tracker::ActivityTracker, error_state::PageErrorContext, toast_state::ToastContext,
+    },
+};
+use cr_leptos_axum_socket::use_client_registry_socket;
+use leptos::prelude::*;
+use std::str::FromStr;
+use uuid::Uuid;
+
+#[component]
+pub fn Webhooks() -> impl IntoView {
+    // --- global context and state ---
+    let page_err_ctx = expect_context::<PageErrorContext>();
+    let toast_ctx = expect_context::<ToastContext>();
+    let activity_tracker = expect_context::<ActivityTracker>();
+    let component_id = StoredValue::new(Uuid::new_v4());
+
+    on_cleanup(move || {
+        page_err_ctx.clear_all_for_component(component_id.get_value());
+        activity_tracker.remove_component(component_id.get_value());
+    });
+
+    // --- local state ---
+    let name = RwSignal::new(String::new());
+    let url = RwSignal::new(String::new());
+    let event_types = RwSignal::new(WebhookEventType::SUBSCRIBABLE.to_vec());
+    let format = RwSignal::new(WebhookFormat::default());
+    // endpoint, of which the delivery history is shown
+    let selected = RwSignal::new(None::<Uuid>);
+
+    let endpoints = Resource::new(
+        || (),
+        move |_| async move {
+            activity_tracker
+                .track_activity_wrapper(component_id.get_value(), list_webhook_endpoints())
+                .await
+                .map_err(|app_error| ComponentError::new(component_id.get_value(), app_error))
+        },
+    );
+
+    let refetch = Callback::new(move |()| endpoints.refetch());
+    page_err_ctx.register_retry_handler(component_id.get_value(), refetch);
+
+    // Subscribe to changes of webhook endpoints
+    use_client_registry_socket(
+        Signal::derive(|| Some(CrTopic::WebhookEndpoints)),
+        None.into(),
+        refetch,
+    );
+
+    let save_endpoint = ServerAction::<SaveWebhookEndpoint>::new();
+    activity_tracker.track_pending_memo(component_id.get_value(), save_endpoint.pending());
+    Effect::new(move || match save_endpoint.value().get() {
+        Some(Ok(saved)) => {
+            toast_ctx.success(format!("Webhook '{}' saved.", saved.get_name()), None);
+            name.set(String::new());
+            url.set(String::new());
+            endpoints.refetch();
+        }
+        Some(Err(err)) => {
+            toast_ctx.error(format!("Could not save webhook: {err}"), None);
+        }
+        None => {}
+    });
+
+    let send_test = ServerAction::<SendTestWebhook>::new();
+    activity_tracker.track_pending_memo(component_id.get_value(), send_test.pending());
+    Effect::new(move || match send_test.value().get() {
+        Some(Ok(delivery)) => {
+            selected.set(Some(delivery.endpoint_id));
+            match delivery.status_code {
+                Some(code) if delivery.is_success() => {
+                    toast_ctx.success(format!("Test delivered with status {code}."), None)
+                }
+                Some(code) => {
+                    toast_ctx.warning(format!("Receiver answered with status {code}."), None)
+                }
+                None => toast_ctx.error(
+                    format!(
+                        "Test delivery failed: {}",
+                        delivery.error.unwrap_or_default()
+                    ),
+                    None,
+                ),
+            }
+        }
+        Some(Err(err)) => {
+            toast_ctx.error(format!("Could not send test: {err}"), None);
+        }
+        None => {}
+    });
+
+    let on_submit = move || {
+        if name.get_untracked().trim().is_empty()
+            || url.get_untracked().trim().is_empty()
+            || event_types.get_untracked().is_empty()
+        {
+            toast_ctx.warning("Name, url and at least one event are required.", None);
+            return;
+        }
+        save_endpoint.dispatch(SaveWebhookEndpoint {
+            id: None,
+            version: 0,
+            name: name.get_untracked(),
+            url: url.get_untracked(),
+            event_types: event_types.get_untracked(),
+            format: format.get_untracked(),
+            active: true,
+        });
+    };
+
+    let on_cancel = use_on_cancel();
+
+    view! {
+        <div class="card w-full bg-base-100 shadow-xl" data-testid="webhooks-root">
+            <div class="card-body">
+                <h2 class="card-title">"Webhooks"</h2>
+                <p class="text-sm opacity-70">
+                    "Deliveries are signed with the secret of the endpoint in header X-Fkt-Signature."
+                </p>
+                <form
+                    class="flex flex-col gap-2"
+                    on:submit=move |ev| {
+                        ev.prevent_default();
+                        on_submit();
+                    }
+                >
+                    <div class="flex flex-wrap items-end gap-4">
+                        <label class="form-control">
+                            <span class="label-text">"Name"</span>
+                            <input
+                                type="text"
+                                class="input input-bordered w-full md:w-64"
+                                data-testid="input-webhook-name"
+                                prop:value=name
+                                on:input=move |ev| name.set(event_target_value(&ev))
+                            />
+                        </label>
+                        <label class="form-control">
+                            <span class="label-text">"Url"</span>
+                            <input
+                                type="url"
+                                class="input input-bordered w-full md:w-96"
+                                placeholder="https://"
+                                data-testid="input-webhook-url"
+                                prop:value=url
+                                on:input=move |ev| url.set(event_target_value(&ev))
+                            />
+                        </label>
+                        <label class="form-control">
+                            <span class="label-text">"Format"</span>
+                            <select
+                                class="select select-bordered"
+                                data-testid="select-webhook-format"
+                                prop:value=move || format.get().as_str()
+                                on:change=move |ev| {
+                                    if let Ok(value) = WebhookFormat::from_str(&event_target_value(&ev)) {
+                                        format.set(value);
+                                    }
+                                }
+                            >
+                                <option value="json">"JSON (signed payload)"</option>
+                                <option value="discord">"Discord"</option>
+                                <option value="slack">"Slack"</option>
+                            </select>
+                        </label>
+                    </div>
+                    <div class="flex flex-wrap gap-4">
+                        {WebhookEventType::SUBSCRIBABLE
+                            .into_iter()
+                            .map(|event_type| {
+                                view! {
+                                    <label class="label cursor-pointer gap-2">
+                                        <input
+                                            type="checkbox"
+                                            class="checkbox checkbox-sm"
+                                            data-testid=format!("input-webhook-event-{event_type}")
+                                            prop:checked=move || {
+                                                event_types.get().contains(&event_type)
+                                            }
+                                            on:change=move |ev| {
+                                                let checked = event_target_checked(&ev);
+                                                event_types
+                                                    .update(|e| {
+                                                        e.retain(|x| *x != event_type);
+                                                        if checked {
+                                                            e.push(event_type);
+                                                        }
+                                                    });
+                                            }
+                                        />
+                                        <span class="label-text">{event_type.as_str()}</span>
+                                    </label>
+                                }
+                            })
+                            .collect_view()}
+                    </div>
+                    <div>
+                        <button
+                            type="submit"
+                            class="btn btn-primary btn-sm"
+                            data-testid="action-btn-create-webhook"
+                            disabled=move || save_endpoint.pending().get()
+                        >
+                            "Add Webhook"
+                        </button>
+                    </div>
+                </form>
+                <Transition fallback=move || {
+                    view! { <span class="loading loading-spinner loading-md"></span> }
+                }>
+                    <ErrorBoundary fallback=move |errors| {
+                        for (_err_id, err) in errors.get().into_iter() {
+                            if let Some(comp_err) = err.into_inner().downcast_ref::<ComponentError>() {
+                                handle_read_error(&page_err_ctx, comp_err, on_cancel);
+                            }
+                        }
+                    }>
+                        {move || {
+                            endpoints
+                                .and_then(|list| {
+                                    let list = list.clone();
+                                    view! {
+                                        <table class="table table-sm" data-testid="webhooks-table">
+                                            <thead>
+                                                <tr>
+                                                    <th>"Name"</th>
+                                                    <th>"Url"</th>
+                                                    <th>"Events"</th>
+                                                    <th>"Format"</th>
+                                                    <th>"Secret"</th>
+                                                    <th></th>
+                                                </tr>
+                                            </thead>
+                                            <tbody>
+                                                <For
+                                                    each=move || list.clone()
+                                                    key=|e| (e.get_id(), e.get_version())
+                                                    children=move |endpoint| {
+                                                        let id = endpoint.get_id();
+                                                        let active = endpoint.is_active();
+                                                        let events = endpoint
+                                                            .get_event_types()
+                                                            .iter()
+                                                            .map(|e| e.as_str())
+                                                            .collect::<Vec<_>>()
+                                                            .join(", ");
+                                                        let toggle = SaveWebhookEndpoint {
+                                                            id: Some(id),
+                                                            version: endpoint.get_version().unwrap_or_default(),
+                                                            name: endpoint.get_name().to_string(),
+                                                            url: endpoint.get_url().to_string(),
+                                                            event_types: endpoint.get_event_types().to_vec(),
+                                                            format: endpoint.get_format(),
+                                                            active: !active,
+                                                        };
+                                                        view! {
+                                                            <tr
+                                                                data-testid="webhooks-row"
+                                                                class:opacity-50=!active
+                                                                class:bg-base-200=move || selected.get() == Some(id)
+                                                            >
+                                                                <td>{endpoint.get_name().to_string()}</td>
+                                                                <td class="break-all">{endpoint.get_url().to_string()}</td>
+                                                                <td>{events}</td>
+                                                                <td>{endpoint.get_format().as_str()}</td>
+                                                                <td>
+                                                                    <code class="break-all" data-testid="webhook-secret">
+                                                                        {endpoint.get_secret().to_string()}
+                                                                    </code>
+                                                                </td>
+                                                                <td class="flex gap-1">
+                                                                    <button
+                                                                        class="btn btn-xs"
+                                                                        data-testid="action-btn-toggle-webhook"
+                                                                        disabled=move || save_endpoint.pending().get()
+                                                                        on:click=move |_| {
+                                                                            save_endpoint.dispatch(toggle.clone());
+                                                                        }
+                                                                    >
+                                                                        {if active { "Deactivate" } else { "Activate" }}
+                                                                    </button>
+                                                                    <button
+                                                                        class="btn btn-secondary btn-xs"
+                                                                        data-testid="action-btn-test-webhook"
+                                                                        disabled=move || send_test.pending().get()
+                                                                        on:click=move |_| {
+                                                                            send_test.dispatch(SendTestWebhook { id });
+                                                                        }
+                                                                    >
+                                                                        "Send test"
+                                                                    </button>
+                                                                    <button
+                                                                        class="btn btn-ghost btn-xs"
+                                                                        data-testid="action-btn-show-webhook-deliveries"
+                                                                        on:click=move |_| selected.set(Some(id))
+                                                                    >
+                                                                        "Deliveries"
+                                                                    </button>
+                                                                </td>
+                                                            </tr>
+                                                        }
+                                                    }
+                                                />
+                                            </tbody>
+                                        </table>
+                                    }
+                                })
+                        }}
+                    </ErrorBoundary>
+                </Transition>
+                {move || {
+                    selected
+                        .get()
+                        .map(|endpoint_id| view! { <WebhookDeliveries endpoint_id=endpoint_id /> })
+                }}
+            </div>
+        </div>
+    }
+}
+
+/// delivery history of a webhook endpoint, newest first
+#[component]
+fn WebhookDeliveries(endpoint_id: Uuid) -> impl IntoView {
+    // --- global context and state ---
+    let page_err_ctx = expect_context::<PageErrorContext>();
+    let activity_tracker = expect_context::<ActivityTracker>();
+    let component_id = StoredValue::new(Uuid::new_v4());
+
+    on_cleanup(move || {
+        page_err_ctx.clear_all_for_component(component_id.get_value());
+        activity_tracker.remove_component(component_id.get_value());
+    });
+
+    let deliveries = Resource::new(
+        || (),
+        move |_| async move {
+            activity_tracker
+                .track_activity_wrapper(
+                    component_id.get_value(),
+                    list_webhook_deliveries(endpoint_id),
+                )
+                .await
+                .map_err(|app_error| ComponentError::new(component_id.get_value(), app_error))
+        },
+    );
+
+    let refetch = Callback::new(move |()| deliveries.refetch());
+    page_err_ctx.register_retry_handler(component_id.get_value(), refetch);
+
+    // Subscribe to new deliveries of endpoint
+    use_client_registry_socket(
+        Signal::derive(move || Some(CrTopic::WebhookDeliveries { endpoint_id })),
+        None.into(),
+        refetch,
+    );
+
+    let on_cancel = use_on_cancel();
+
+    view! {
+        <div class="flex flex-col gap-2" data-testid="webhook-deliveries">
+            <h3 class="font-bold">"Delivery history"</h3>
+            <Transition fallback=move || {
+                view! { <span class="loading loading-spinner loading-md"></span> }
+            }>
+                <ErrorBoundary fallback=move |errors| {
+                    for (_err_id, err) in errors.get().into_iter() {
+                        if let Some(comp_err) = err.into_inner().downcast_ref::<ComponentError>() {
+                            handle_read_error(&page_err_ctx, comp_err, on_cancel);
+                        }
+                    }
+                }>
+                    {move || {
+                        deliveries
+                            .and_then(|list| {
+                                let list = list.clone();
+                                view! {
+                                    <table class="table table-xs" data-testid="webhook-deliveries-table">
+                                        <thead>
+                                            <tr>
+                                                <th>"Time"</th>
+                                                <th>"Event"</th>
+                                                <th>"Status"</th>
+                                                <th>"Duration"</th>
+                                                <th>"Error"</th>
+                                            </tr>
+                                        </thead>
+                                        <tbody>
+                                            <For
+                                                each=move || list.clone()
+                                                key=|d| d.id
+                                                children=move |delivery| {
+                                                    let success = delivery.is_success();
+                                                    let status = delivery
+                                                        .status_code
+                                                        .map(|c| c.to_string())
+                                                        .unwrap_or_else(|| "-".to_string());
+                                                    view! {
+                                                        <tr data-testid="webhook-deliveries-row">
+                                                            <td>
+                                                                {delivery
+                                                                    .attempted_at
+                                                                    .format("%Y-%m-%d %H:%M:%S")
+                                                                    .to_string()}
+                                                            </td>
+                                                            <td>{delivery.event_type.as_str()}</td>
+                                                            <td>
+                                                                <span
+                                                                    class="badge badge-sm"
+                                                                    class:badge-success=success
+                                                                    class:badge-error=!success
+                                                                >
+                                                                    {status}
+                                                                </span>
+                                                            </td>
+                                                            <td>{format!("{} ms", delivery.duration_ms)}</td>
+                                                            <td class="break-all">
+                                                                {delivery.error.clone().unwrap_or_default()}
+                                                            </td>
+                                                        </tr>
+                                                    }
+                                                }
+                                            />
+                                        </tbody>
+                                    </table>
+                                }
+                            })
+                    }}
+                </ErrorBoundary>
+            </Transition>
+        </div>
+    }
+}
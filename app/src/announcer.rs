@@ -0,0 +1,195 @@
+//! announcer screen of court calls, e.g. on a big screen at the venue
+
+use crate::public::{PublicLayout, PublicText};
+use app_core::{CourtCall, CourtCallQueue, CourtCallState, CrTopic, Language};
+use app_utils::{
+    error::{ComponentError, strategy::handle_read_error},
+    hooks::{use_on_cancel::use_on_cancel, use_ui_language::use_ui_language},
+    params::{ParamQuery, PublicTournamentIdParams},
+    server_fn::court_call::load_court_calls,
+    state::{activity_tracker::ActivityTracker, error_state::PageErrorContext},
+};
+use cr_leptos_axum_socket::use_client_registry_socket;
+use leptos::prelude::*;
+#[allow(unused_imports)]
+use leptos_router::MatchNestedRoutes;
+use leptos_router::{
+    ParamSegment, StaticSegment,
+    any_nested_route::IntoAnyNestedRoute,
+    components::{ParentRoute, Route},
+};
+use uuid::Uuid;
+
+#[component(transparent)]
+pub fn AnnouncerRoutes() -> impl MatchNestedRoutes + Clone {
+    view! {
+        // announcer screens run unattended, therefore they use the layout without navigation
+        <ParentRoute path=StaticSegment("announcer") view=PublicLayout>
+            <Route path=ParamSegment(PublicTournamentIdParams::KEY) view=AnnouncerPage />
+        </ParentRoute>
+    }
+    .into_inner()
+    .into_any_nested_route()
+}
+
+#[component]
+pub fn AnnouncerPage() -> impl IntoView {
+    // --- global context and state ---
+    let page_err_ctx = expect_context::<PageErrorContext>();
+    let activity_tracker = expect_context::<ActivityTracker>();
+    let component_id = StoredValue::new(Uuid::new_v4());
+
+    on_cleanup(move || {
+        page_err_ctx.clear_all_for_component(component_id.get_value());
+        activity_tracker.remove_component(component_id.get_value());
+    });
+
+    let tournament_id = PublicTournamentIdParams::use_param_query();
+    let language = use_ui_language();
+
+    let queue = Resource::new(
+        move || tournament_id.get(),
+        move |t_id| async move {
+            match t_id {
+                Some(t_id) => activity_tracker
+                    .track_activity_wrapper(component_id.get_value(), load_court_calls(t_id))
+                    .await
+                    .map_err(|app_error| ComponentError::new(component_id.get_value(), app_error)),
+                None => Ok(None),
+            }
+        },
+    );
+
+    let refetch = Callback::new(move |()| queue.refetch());
+    page_err_ctx.register_retry_handler(component_id.get_value(), refetch);
+
+    // new calls are announced explicitly; entered results free stations as well
+    let calls_topic = Signal::derive(move || {
+        tournament_id
+            .get()
+            .map(|tournament_id| CrTopic::CourtCalls { tournament_id })
+    });
+    use_client_registry_socket(calls_topic, None.into(), refetch);
+    let scores_topic = Signal::derive(move || {
+        tournament_id
+            .get()
+            .map(|tournament_id| CrTopic::LiveScores { tournament_id })
+    });
+    use_client_registry_socket(scores_topic, None.into(), refetch);
+
+    let on_cancel = use_on_cancel();
+
+    view! {
+        <div class="flex flex-col gap-6 w-full max-w-6xl mx-auto" data-testid="announcer-root">
+            <Transition fallback=move || {
+                view! { <span class="loading loading-spinner loading-md"></span> }
+            }>
+                <ErrorBoundary fallback=move |errors| {
+                    for (_err_id, err) in errors.get().into_iter() {
+                        if let Some(comp_err) = err.into_inner().downcast_ref::<ComponentError>() {
+                            handle_read_error(&page_err_ctx, comp_err, on_cancel);
+                        }
+                    }
+                }>
+                    {move || {
+                        queue
+                            .and_then(|queue| match queue.clone() {
+                                Some(queue) => {
+                                    view! { <AnnouncerView queue=queue language=language /> }
+                                        .into_any()
+                                }
+                                None => {
+                                    view! {
+                                        <div class="alert" data-testid="announcer-not-found">
+                                            {move || {
+                                                PublicText::TournamentNotFound.get(language.get())
+                                            }}
+                                        </div>
+                                    }
+                                        .into_any()
+                                }
+                            })
+                    }}
+                </ErrorBoundary>
+            </Transition>
+        </div>
+    }
+}
+
+#[component]
+fn AnnouncerView(queue: CourtCallQueue, language: Signal<Language>) -> impl IntoView {
+    let (called, warm_up): (Vec<CourtCall>, Vec<CourtCall>) = queue
+        .calls
+        .into_iter()
+        .partition(|c| c.state == CourtCallState::Called);
+    let has_calls = !called.is_empty() || !warm_up.is_empty();
+    let has_warm_up = !warm_up.is_empty();
+
+    view! {
+        <h1 class="text-4xl font-bold text-center" data-testid="announcer-tournament-name">
+            {queue.tournament_name}
+        </h1>
+        <Show
+            when=move || has_calls
+            fallback=move || {
+                view! {
+                    <p class="text-2xl text-center opacity-70" data-testid="announcer-no-calls">
+                        {move || PublicText::NoCalls.get(language.get())}
+                    </p>
+                }
+            }
+        >
+            <h2 class="text-3xl font-semibold">
+                {move || PublicText::CalledMatches.get(language.get())}
+            </h2>
+            <div class="grid grid-cols-1 lg:grid-cols-2 gap-4" data-testid="announcer-called">
+                {called
+                    .iter()
+                    .map(|call| view! { <CourtCallCard call=call.clone() language=language /> })
+                    .collect_view()}
+            </div>
+            <Show when=move || has_warm_up>
+                <h2 class="text-2xl font-semibold">
+                    {move || PublicText::WarmUp.get(language.get())}
+                </h2>
+                <div class="grid grid-cols-1 lg:grid-cols-3 gap-4" data-testid="announcer-warm-up">
+                    {warm_up
+                        .iter()
+                        .map(|call| view! { <CourtCallCard call=call.clone() language=language /> })
+                        .collect_view()}
+                </div>
+            </Show>
+        </Show>
+    }
+}
+
+#[component]
+fn CourtCallCard(call: CourtCall, language: Signal<Language>) -> impl IntoView {
+    let is_called = call.state == CourtCallState::Called;
+    let entrants = call.entrants.clone();
+
+    view! {
+        <div
+            class="card bg-base-100 shadow-xl"
+            class:border-4=is_called
+            class:border-primary=is_called
+            data-testid="announcer-call"
+        >
+            <div class="card-body">
+                <h3 class="card-title" class:text-3xl=is_called class:text-xl=!is_called>
+                    {call.station_name.clone()}
+                </h3>
+                <p class:text-2xl=is_called>
+                    {move || {
+                        if entrants.is_empty() {
+                            PublicText::ToBeDecided.get(language.get()).to_string()
+                        } else {
+                            entrants.join(" – ")
+                        }
+                    }}
+                </p>
+                <p class="opacity-70">{call.start_at.format("%H:%M").to_string()}</p>
+            </div>
+        </div>
+    }
+}
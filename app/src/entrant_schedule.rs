@@ -0,0 +1,305 @@
+//! public schedule and results of a single entrant
+//!
+//! Entrants and referees open their schedule on their phones, therefore matches are listed
+//! as cards on small screens.
+
+use crate::public::{PublicLayout, PublicText};
+use app_core::{CrTopic, EntrantMatch, EntrantMatchResult, EntrantSchedule, Language};
+use app_utils::{
+    error::{ComponentError, strategy::handle_read_error},
+    hooks::{use_on_cancel::use_on_cancel, use_ui_language::use_ui_language},
+    params::{EntrantIdParams, ParamQuery},
+    server_fn::public_tournament::load_entrant_schedule,
+    state::{activity_tracker::ActivityTracker, error_state::PageErrorContext},
+};
+use cr_leptos_axum_socket::use_client_registry_socket;
+use leptos::prelude::*;
+#[allow(unused_imports)]
+use leptos_router::MatchNestedRoutes;
+use leptos_router::{
+    ParamSegment, StaticSegment,
+    any_nested_route::IntoAnyNestedRoute,
+    components::{A, ParentRoute, Route},
+};
+use uuid::Uuid;
+
+#[component(transparent)]
+pub fn EntrantScheduleRoutes() -> impl MatchNestedRoutes + Clone {
+    view! {
+        // schedules are shared with entrants, therefore they use the public layout
+        <ParentRoute path=StaticSegment("entrant") view=PublicLayout>
+            <Route
+                path=(ParamSegment(EntrantIdParams::KEY), StaticSegment("schedule"))
+                view=EntrantSchedulePage
+            />
+        </ParentRoute>
+    }
+    .into_inner()
+    .into_any_nested_route()
+}
+
+#[component]
+pub fn EntrantSchedulePage() -> impl IntoView {
+    // --- global context and state ---
+    let page_err_ctx = expect_context::<PageErrorContext>();
+    let activity_tracker = expect_context::<ActivityTracker>();
+    let component_id = StoredValue::new(Uuid::new_v4());
+
+    on_cleanup(move || {
+        page_err_ctx.clear_all_for_component(component_id.get_value());
+        activity_tracker.remove_component(component_id.get_value());
+    });
+
+    let entrant_id = EntrantIdParams::use_param_query();
+    let language = use_ui_language();
+
+    let schedule = Resource::new(
+        move || entrant_id.get(),
+        move |e_id| async move {
+            match e_id {
+                Some(e_id) => activity_tracker
+                    .track_activity_wrapper(component_id.get_value(), load_entrant_schedule(e_id))
+                    .await
+                    .map_err(|app_error| ComponentError::new(component_id.get_value(), app_error)),
+                None => Ok(None),
+            }
+        },
+    );
+
+    let refetch = Callback::new(move |()| schedule.refetch());
+    page_err_ctx.register_retry_handler(component_id.get_value(), refetch);
+
+    // live updates of entrants of the tournament, e.g. changed names of opponents
+    let topic = Signal::derive(move || {
+        schedule
+            .get()
+            .and_then(|res| res.ok().flatten())
+            .map(|s| CrTopic::Entrants {
+                tournament_id: s.tournament_id,
+            })
+    });
+    use_client_registry_socket(topic, None.into(), refetch);
+
+    let on_cancel = use_on_cancel();
+
+    view! {
+        <div class="flex flex-col gap-3 sm:gap-4 w-full max-w-3xl mx-auto" data-testid="entrant-schedule-root">
+            <Transition fallback=move || {
+                view! { <span class="loading loading-spinner loading-md"></span> }
+            }>
+                <ErrorBoundary fallback=move |errors| {
+                    for (_err_id, err) in errors.get().into_iter() {
+                        if let Some(comp_err) = err.into_inner().downcast_ref::<ComponentError>() {
+                            handle_read_error(&page_err_ctx, comp_err, on_cancel);
+                        }
+                    }
+                }>
+                    {move || {
+                        schedule
+                            .and_then(|schedule| match schedule.clone() {
+                                Some(schedule) => {
+                                    view! {
+                                        <EntrantScheduleView schedule=schedule language=language />
+                                    }
+                                        .into_any()
+                                }
+                                None => {
+                                    view! {
+                                        <div class="alert" data-testid="entrant-schedule-not-found">
+                                            {move || PublicText::EntrantNotFound.get(language.get())}
+                                        </div>
+                                    }
+                                        .into_any()
+                                }
+                            })
+                    }}
+                </ErrorBoundary>
+            </Transition>
+        </div>
+    }
+}
+
+#[component]
+fn EntrantScheduleView(schedule: EntrantSchedule, language: Signal<Language>) -> impl IntoView {
+    let entrant_id = schedule.entrant.get_id();
+    // absolute link is only known in the browser
+    let share_link = RwSignal::new(String::new());
+    Effect::new(move || {
+        let origin = window().location().origin().unwrap_or_default();
+        share_link.set(format!("{origin}/entrant/{entrant_id}/schedule"));
+    });
+    let has_upcoming = !schedule.upcoming.is_empty();
+    let has_results = !schedule.results.is_empty();
+
+    view! {
+        <div class="card w-full bg-base-100 shadow-xl">
+            <div class="card-body p-4 sm:p-8">
+                <h1 class="card-title text-xl sm:text-2xl" data-testid="entrant-schedule-name">
+                    {schedule.entrant.get_name().to_string()}
+                </h1>
+                <p>{schedule.entrant.get_club().unwrap_or_default().to_string()}</p>
+                <A
+                    href=format!("/public/tournament/{}", schedule.tournament_id)
+                    attr:class="link"
+                    attr:data-testid="entrant-schedule-tournament-link"
+                >
+                    {schedule.tournament_name.clone()}
+                </A>
+                <div class="flex flex-col gap-1">
+                    <span class="text-sm opacity-70">
+                        {move || PublicText::ShareLink.get(language.get())}
+                    </span>
+                    <code class="break-all" data-testid="entrant-schedule-share-link">
+                        {share_link}
+                    </code>
+                </div>
+            </div>
+        </div>
+        <div class="card w-full bg-base-100 shadow-xl" data-testid="entrant-schedule-upcoming">
+            <div class="card-body p-4 sm:p-8">
+                <h2 class="card-title">
+                    {move || PublicText::UpcomingMatches.get(language.get())}
+                </h2>
+                <Show
+                    when=move || has_upcoming
+                    fallback=move || {
+                        view! {
+                            <p class="opacity-70">
+                                {move || PublicText::NoMatchesScheduled.get(language.get())}
+                            </p>
+                        }
+                    }
+                >
+                    <EntrantMatchTable
+                        matches=schedule.upcoming.clone()
+                        language=language
+                        with_result=false
+                    />
+                </Show>
+            </div>
+        </div>
+        <div class="card w-full bg-base-100 shadow-xl" data-testid="entrant-schedule-results">
+            <div class="card-body p-4 sm:p-8">
+                <h2 class="card-title">{move || PublicText::Results.get(language.get())}</h2>
+                <Show
+                    when=move || has_results
+                    fallback=move || {
+                        view! {
+                            <p class="opacity-70">
+                                {move || PublicText::NoResultsYet.get(language.get())}
+                            </p>
+                        }
+                    }
+                >
+                    <EntrantMatchTable
+                        matches=schedule.results.clone()
+                        language=language
+                        with_result=true
+                    />
+                </Show>
+            </div>
+        </div>
+    }
+}
+
+/// Matches of the entrant: a list of cards on phones, a table on larger screens.
+#[component]
+fn EntrantMatchTable(
+    matches: Vec<EntrantMatch>,
+    language: Signal<Language>,
+    with_result: bool,
+) -> impl IntoView {
+    let opponent_text = move |opponent: &Option<String>| {
+        opponent
+            .clone()
+            .unwrap_or_else(|| PublicText::ToBeDecided.get(language.get()).to_string())
+    };
+    view! {
+        <ul class="flex flex-col gap-2 sm:hidden">
+            {matches
+                .iter()
+                .map(|m| {
+                    let opponent = m.opponent.clone();
+                    let result = m.result.clone();
+                    view! {
+                        <li class="rounded-box bg-base-200 p-3" data-testid="entrant-schedule-item">
+                            <div class="flex justify-between gap-2 text-sm opacity-70">
+                                <span>{m.start_at.format("%Y-%m-%d %H:%M").to_string()}</span>
+                                <span>{m.station_name.clone()}</span>
+                            </div>
+                            <div class="flex justify-between gap-2">
+                                <span class="font-semibold">
+                                    {move || opponent_text(&opponent)}
+                                </span>
+                                <Show when=move || with_result>
+                                    <span>
+                                        {
+                                            let result = result.clone();
+                                            move || result_text(result.as_ref(), language.get())
+                                        }
+                                    </span>
+                                </Show>
+                            </div>
+                        </li>
+                    }
+                })
+                .collect_view()}
+        </ul>
+        <table class="table table-sm hidden sm:table">
+            <thead>
+                <tr>
+                    <th>{move || PublicText::Time.get(language.get())}</th>
+                    <th>{move || PublicText::Station.get(language.get())}</th>
+                    <th>{move || PublicText::Opponent.get(language.get())}</th>
+                    <Show when=move || with_result>
+                        <th>{move || PublicText::Result.get(language.get())}</th>
+                    </Show>
+                </tr>
+            </thead>
+            <tbody>
+                {matches
+                    .into_iter()
+                    .map(|m| {
+                        let opponent = m.opponent.clone();
+                        let result = m.result.clone();
+                        view! {
+                            <tr data-testid="entrant-schedule-row">
+                                <td>{m.start_at.format("%Y-%m-%d %H:%M").to_string()}</td>
+                                <td>{m.station_name.clone()}</td>
+                                <td>{move || opponent_text(&opponent)}</td>
+                                <Show when=move || with_result>
+                                    <td>
+                                        {
+                                            let result = result.clone();
+                                            move || result_text(result.as_ref(), language.get())
+                                        }
+                                    </td>
+                                </Show>
+                            </tr>
+                        }
+                    })
+                    .collect_view()}
+            </tbody>
+        </table>
+    }
+}
+
+/// Result of a match from the perspective of the entrant, e.g. `11:9, 5:11`.
+fn result_text(result: Option<&EntrantMatchResult>, language: Language) -> String {
+    match result {
+        Some(EntrantMatchResult::Played { own, opponent }) => own
+            .iter()
+            .zip(opponent.iter())
+            .map(|(own, opponent)| format!("{own}:{opponent}"))
+            .collect::<Vec<_>>()
+            .join(", "),
+        Some(EntrantMatchResult::WalkoverWon) => PublicText::WalkoverWon.get(language).to_string(),
+        Some(EntrantMatchResult::WalkoverLost) => {
+            PublicText::WalkoverLost.get(language).to_string()
+        }
+        Some(EntrantMatchResult::DoubleForfeit) => {
+            PublicText::DoubleForfeit.get(language).to_string()
+        }
+        None => String::new(),
+    }
+}
@@ -0,0 +1,216 @@
+//! search box of the navbar
+//!
+//! Typing searches tournaments, entrants, sport configurations and postal addresses with
+//! [`search_all`] after a short pause. Hits are listed by relevance and tagged with their kind.
+//! Arrow keys select a hit, Enter opens it and Escape closes the list.
+
+use app_core::{SEARCH_MIN_TERM_LENGTH, SearchHit, SearchHitKind};
+use app_utils::{
+    hooks::use_ui_language::use_ui_language,
+    i18n::UiText,
+    params::{AddressIdQuery, ParamQuery, SportConfigIdQuery, SportIdQuery, TournamentBaseIdQuery},
+    server_fn::search::search_all,
+};
+use leptos::prelude::*;
+use leptos_router::{NavigateOptions, hooks::use_navigate};
+use std::time::Duration;
+
+/// pause after the last key stroke before searching
+const SEARCH_DEBOUNCE: Duration = Duration::from_millis(250);
+
+/// text of the badge, which tags a hit with its kind
+fn kind_text(kind: SearchHitKind) -> UiText {
+    match kind {
+        SearchHitKind::Tournament => UiText::SearchTournament,
+        SearchHitKind::Entrant => UiText::SearchEntrant,
+        SearchHitKind::SportConfig => UiText::SearchSportConfig,
+        SearchHitKind::PostalAddress => UiText::SearchPostalAddress,
+    }
+}
+
+/// URL of the page, which edits the object of `hit`. Entrants are edited in their tournament.
+fn hit_url(hit: &SearchHit) -> String {
+    let sport = hit
+        .sport_id
+        .map(|sport_id| format!("{}={sport_id}&", SportIdQuery::KEY))
+        .unwrap_or_default();
+    match hit.kind {
+        SearchHitKind::Tournament => format!(
+            "/tournaments/edit?{sport}{}={}",
+            TournamentBaseIdQuery::KEY,
+            hit.id
+        ),
+        SearchHitKind::Entrant => format!(
+            "/tournaments/edit?{sport}{}={}",
+            TournamentBaseIdQuery::KEY,
+            hit.tournament_id.unwrap_or(hit.id)
+        ),
+        SearchHitKind::SportConfig => format!(
+            "/sport-configurations/edit?{sport}{}={}",
+            SportConfigIdQuery::KEY,
+            hit.id
+        ),
+        SearchHitKind::PostalAddress => {
+            format!("/postal-address/edit?{}={}", AddressIdQuery::KEY, hit.id)
+        }
+    }
+}
+
+#[component]
+pub fn GlobalSearch() -> impl IntoView {
+    let ui_language = use_ui_language();
+    let text = move |text: UiText| move || text.get(ui_language.get());
+
+    let input = RwSignal::new(String::new());
+    let term = RwSignal::new(String::new());
+    let is_open = RwSignal::new(false);
+    let selected = RwSignal::new(None::<usize>);
+    // only the last key stroke within the debounce time starts a search
+    let generation = StoredValue::new(0_u64);
+
+    let hits = Resource::new(
+        move || term.get(),
+        move |term| async move {
+            if term.trim().chars().count() < SEARCH_MIN_TERM_LENGTH {
+                return Vec::new();
+            }
+            // search is a shortcut; failed searches show no hits
+            search_all(term).await.unwrap_or_default()
+        },
+    );
+    let current_hits = move || hits.get().unwrap_or_default();
+
+    let on_input = move |ev| {
+        input.set(event_target_value(&ev));
+        is_open.set(true);
+        selected.set(None);
+        generation.update_value(|g| *g += 1);
+        let current = generation.get_value();
+        set_timeout(
+            move || {
+                if generation.get_value() == current {
+                    term.set(input.get_untracked());
+                }
+            },
+            SEARCH_DEBOUNCE,
+        );
+    };
+
+    let open_hit = move |hit: SearchHit| {
+        is_open.set(false);
+        selected.set(None);
+        input.set(String::new());
+        term.set(String::new());
+        let navigate = use_navigate();
+        navigate(&hit_url(&hit), NavigateOptions::default());
+    };
+
+    let on_keydown = move |ev: leptos::ev::KeyboardEvent| {
+        let num_hits = hits.with_untracked(|h| h.as_ref().map_or(0, Vec::len));
+        match ev.key().as_str() {
+            "ArrowDown" if num_hits > 0 => {
+                ev.prevent_default();
+                is_open.set(true);
+                selected.update(|s| *s = Some(s.map_or(0, |i| (i + 1) % num_hits)));
+            }
+            "ArrowUp" if num_hits > 0 => {
+                ev.prevent_default();
+                selected.update(|s| {
+                    *s = Some(s.map_or(num_hits - 1, |i| (i + num_hits - 1) % num_hits))
+                });
+            }
+            "Enter" => {
+                ev.prevent_default();
+                let hit = hits.with_untracked(|h| {
+                    h.as_ref()
+                        .and_then(|h| h.get(selected.get_untracked().unwrap_or(0)).cloned())
+                });
+                if let Some(hit) = hit {
+                    open_hit(hit);
+                }
+            }
+            "Escape" => {
+                is_open.set(false);
+                selected.set(None);
+            }
+            _ => {}
+        }
+    };
+
+    view! {
+        <div class="relative" data-testid="global-search">
+            <input
+                type="search"
+                class="input input-bordered input-sm w-40 md:w-64"
+                placeholder=text(UiText::SearchPlaceholder)
+                aria-label=text(UiText::SearchPlaceholder)
+                autocomplete="off"
+                data-testid="input-global-search"
+                prop:value=move || input.get()
+                on:input=on_input
+                on:keydown=on_keydown
+                on:focus=move |_| is_open.set(true)
+                on:blur=move |_| is_open.set(false)
+            />
+            <Show when=move || {
+                is_open.get() && term.with(|t| t.trim().chars().count() >= SEARCH_MIN_TERM_LENGTH)
+            }>
+                <ul
+                    class="menu absolute right-0 mt-2 w-80 max-h-96 overflow-y-auto flex-nowrap bg-base-100 rounded-box shadow border border-base-content/10 z-[1]"
+                    data-testid="global-search-hits"
+                >
+                    <Transition fallback=move || {
+                        view! { <span class="loading loading-spinner loading-sm"></span> }
+                    }>
+                        {move || {
+                            let hits = current_hits();
+                            if hits.is_empty() {
+                                return view! {
+                                    <li class="disabled" data-testid="global-search-no-hits">
+                                        <span>{text(UiText::SearchNoHits)}</span>
+                                    </li>
+                                }
+                                    .into_any();
+                            }
+                            hits.into_iter()
+                                .enumerate()
+                                .map(|(index, hit)| {
+                                    let kind = hit.kind;
+                                    let label = hit.label.clone();
+                                    let detail = hit.detail.clone();
+                                    view! {
+                                        <li>
+                                            // mousedown instead of click: the input must not
+                                            // lose focus and close the list before
+                                            <a
+                                                class:active=move || selected.get() == Some(index)
+                                                data-testid=format!("global-search-hit-{index}")
+                                                data-kind=kind.as_str()
+                                                on:mousedown=move |ev| {
+                                                    ev.prevent_default();
+                                                    open_hit(hit.clone());
+                                                }
+                                            >
+                                                <span class="badge badge-sm badge-outline">
+                                                    {text(kind_text(kind))}
+                                                </span>
+                                                <span class="font-medium">{label}</span>
+                                                {detail
+                                                    .map(|detail| {
+                                                        view! {
+                                                            <span class="text-xs opacity-70">{detail}</span>
+                                                        }
+                                                    })}
+                                            </a>
+                                        </li>
+                                    }
+                                })
+                                .collect_view()
+                                .into_any()
+                        }}
+                    </Transition>
+                </ul>
+            </Show>
+        </div>
+    }
+}
@@ -1,13 +1,18 @@
 //! header component
 
 use crate::{
-    home::select_sport::STORAGE_KEY_SPORT_ID, tournament_tree_navigation::TournamentTreeNavigation,
+    global_search::GlobalSearch, home::select_sport::STORAGE_KEY_SPORT_ID,
+    tournament_tree_navigation::TournamentTreeNavigation,
 };
 use app_utils::{
+    components::theme_switcher::ThemeSwitcher,
     hooks::{
         blur_active_element::blur_active_element,
+        use_presence::{get_presence_name, set_presence_name},
+        use_ui_language::{set_ui_language, use_ui_language},
         use_url_navigation::{UseQueryNavigationReturn, use_query_navigation},
     },
+    i18n::{UI_LANGUAGES, UiText},
     params::{ParamQuery, TournamentBaseIdQuery},
     state::{
         activity_tracker::ActivityTracker, object_table::ObjectEditorMapContext,
@@ -45,19 +50,34 @@ pub fn Header() -> impl IntoView {
     provide_context(tournament_editor_map);
 
     let tournament_base_id = TournamentBaseIdQuery::use_param_query();
+    let ui_language = use_ui_language();
+    let text = move |text: UiText| move || text.get(ui_language.get());
+
+    // name shown to other editors; local storage is read after hydration
+    let presence_name = RwSignal::new(String::new());
+    Effect::new(move || presence_name.set(get_presence_name()));
 
     view! {
         <header class="navbar bg-base-300 sticky top-0 z-50">
             <div class="flex-1">
                 <A href=move || url_update_path("/") attr:class="btn btn-ghost normal-case text-xl">
-                    "Tournament Planner"
+                    {text(UiText::AppTitle)}
                 </A>
             </div>
             // Group loading indicator and menu button together on the right
             <div class="flex-none flex items-center gap-3 px-2">
+                <GlobalSearch />
                 <Show when=move || activity_tracker.is_active.get()>
                     <span class="loading loading-bars loading-sm"></span>
                 </Show>
+                <Show when=move || activity_tracker.is_slow.get()>
+                    <span
+                        class="text-sm text-base-content/70 hidden sm:inline"
+                        data-testid="activity-still-working"
+                    >
+                        {text(UiText::StillWorking)}
+                    </span>
+                </Show>
                 <div class="dropdown dropdown-end" class:dropdown-open=move || menu_open.get()>
                     // Use a button instead of label/input to avoid event conflicts in Leptos.
                     // The 'swap-active' class controls which icon is visible based on the signal.
@@ -95,7 +115,62 @@ pub fn Header() -> impl IntoView {
                                     blur_active_element();
                                 }
                             >
-                                "Postal Addresses"
+                                {text(UiText::PostalAddresses)}
+                            </A>
+                        </li>
+                        <li>
+                            <A
+                                href="/venues"
+                                on:click=move |_| {
+                                    set_menu_open.set(false);
+                                    blur_active_element();
+                                }
+                            >
+                                {text(UiText::Venues)}
+                            </A>
+                        </li>
+                        <li>
+                            <A
+                                href="/admin/api-tokens"
+                                on:click=move |_| {
+                                    set_menu_open.set(false);
+                                    blur_active_element();
+                                }
+                            >
+                                {text(UiText::ApiTokens)}
+                            </A>
+                        </li>
+                        <li>
+                            <A
+                                href="/admin/webhooks"
+                                on:click=move |_| {
+                                    set_menu_open.set(false);
+                                    blur_active_element();
+                                }
+                            >
+                                {text(UiText::Webhooks)}
+                            </A>
+                        </li>
+                        <li>
+                            <A
+                                href="/admin/client-errors"
+                                on:click=move |_| {
+                                    set_menu_open.set(false);
+                                    blur_active_element();
+                                }
+                            >
+                                {text(UiText::ClientErrors)}
+                            </A>
+                        </li>
+                        <li>
+                            <A
+                                href="/admin/audit-log"
+                                on:click=move |_| {
+                                    set_menu_open.set(false);
+                                    blur_active_element();
+                                }
+                            >
+                                {text(UiText::AuditLog)}
                             </A>
                         </li>
                         <li>
@@ -109,9 +184,49 @@ pub fn Header() -> impl IntoView {
                                     blur_active_element();
                                 }
                             >
-                                "Sport Selection"
+                                {text(UiText::SportSelection)}
                             </A>
                         </li>
+                        <li class="menu-title">{text(UiText::LanguageLabel)}</li>
+                        <li>
+                            <div class="join" data-testid="ui-language-select">
+                                {UI_LANGUAGES
+                                    .into_iter()
+                                    .map(|language| {
+                                        view! {
+                                            <button
+                                                type="button"
+                                                class="btn btn-xs join-item"
+                                                class:btn-active=move || ui_language.get() == language
+                                                data-testid=format!("action-btn-ui-language-{}", language.code())
+                                                on:click=move |_| set_ui_language(language)
+                                            >
+                                                {language.to_string()}
+                                            </button>
+                                        }
+                                    })
+                                    .collect_view()}
+                            </div>
+                        </li>
+                        <li class="menu-title">{text(UiText::ThemeLabel)}</li>
+                        <li>
+                            <ThemeSwitcher />
+                        </li>
+                        <li class="menu-title">{text(UiText::PresenceNameLabel)}</li>
+                        <li>
+                            <input
+                                type="text"
+                                class="input input-bordered input-sm"
+                                aria-label=text(UiText::PresenceNameLabel)
+                                data-testid="input-presence-name"
+                                prop:value=presence_name
+                                on:change=move |ev| {
+                                    let name = event_target_value(&ev);
+                                    set_presence_name(&name);
+                                    presence_name.set(get_presence_name());
+                                }
+                            />
+                        </li>
                         <Show when=move || tournament_base_id.get().is_some()>
                             <li class="menu-title border-t border-base-content/10 my-1 py-0 h-px"></li>
                             <li>
@@ -126,6 +126,18 @@ pub fn SportDashboard() -> impl IntoView {
                             "Plan New Tournament"
                         </button>
 
+                        <A
+                            href=url_matched_route(
+                                MatchedRouteHandler::Extend("new-tournament-wizard"),
+                            )
+                            attr:class="btn btn-secondary btn-outline h-auto min-h-[4rem] text-lg shadow-md"
+                            attr:data-testid="link-nav-wizard"
+                            scroll=false
+                        >
+                            <span class="icon-[heroicons--sparkles] w-6 h-6 mr-2"></span>
+                            "New Tournament Wizard"
+                        </A>
+
                         <A
                             href=url_matched_route(MatchedRouteHandler::Extend("adhoc-tournament"))
                             attr:class="btn btn-accent h-auto min-h-[4rem] text-lg shadow-md"
@@ -1,7 +1,11 @@
 //! Component for selecting a sport plugin in the sport configuration flow.
 
-use app_core::{SportPluginManagerPort, utils::traits::ObjectIdVersion};
-use app_utils::state::global_state::{GlobalState, GlobalStateStoreFields};
+use app_core::{CrTopic, SportPluginManagerPort, utils::traits::ObjectIdVersion};
+use app_utils::{
+    server_fn::sport_plugin::list_sport_plugin_ids,
+    state::global_state::{GlobalState, GlobalStateStoreFields},
+};
+use cr_leptos_axum_socket::use_client_registry_socket;
 use leptos::{leptos_dom::helpers::window, prelude::*};
 use leptos_router::components::A;
 use leptos_router::hooks::use_navigate;
@@ -30,8 +34,21 @@ pub fn SelectSportPlugin() -> impl IntoView {
         }
     });
 
+    // plugins may be deregistered at the server after start; until the ids of the server
+    // are loaded, all plugins of the client are shown
+    let server_sport_ids = LocalResource::new(list_sport_plugin_ids);
+    let refetch = Callback::new(move |()| server_sport_ids.refetch());
+    use_client_registry_socket(
+        Signal::derive(|| Some(CrTopic::SportPlugins)),
+        None.into(),
+        refetch,
+    );
+
     let sport_list = Signal::derive(move || {
         let mut list = sport_plugin_manager.get().list();
+        if let Some(Ok(server_ids)) = server_sport_ids.get() {
+            list.retain(|plugin| server_ids.contains(&plugin.get_id_version().get_id()));
+        }
         // Sort plugins alphabetically by name to ensure stable order
         list.sort_by_key(|plugin| plugin.name());
         list
@@ -1,15 +1,91 @@
 //! displaying information about a sport plugin
 
+use app_core::SportCapabilities;
+use app_utils::{
+    params::{ParamQuery, SportIdQuery},
+    state::global_state::{GlobalState, GlobalStateStoreFields},
+};
 use leptos::prelude::*;
+use reactive_stores::Store;
 
 #[component]
 pub fn AboutSport() -> impl IntoView {
+    // get global state and sport plugin manager
+    let state = expect_context::<Store<GlobalState>>();
+    let sport_plugin_manager = state.sport_plugin_manager();
+    let sport_id = SportIdQuery::use_param_query();
+
+    let plugin_info = move || {
+        sport_id.get().and_then(|sport_id| {
+            sport_plugin_manager
+                .get()
+                .get_web_ui(&sport_id)
+                .map(|plugin| (plugin.name().to_string(), plugin.capabilities()))
+        })
+    };
+
     view! {
         <div class="flex flex-col items-center w-full max-w-4xl mx-auto py-8 space-y-6">
             <h2 class="text-3xl font-bold">"About This Sport"</h2>
-            <p class="text-base-content/70 text-center">
-                "ToDo: Add information about the selected sport plugin here, such as its features, rules, and how to use it within the FK Tournament Planner."
-            </p>
+            {move || {
+                match plugin_info() {
+                    Some((name, capabilities)) => {
+                        view! {
+                            <h3 class="text-xl font-semibold" data-testid="about-sport-name">
+                                {name}
+                            </h3>
+                            <SportCapabilitiesList capabilities=capabilities />
+                        }
+                            .into_any()
+                    }
+                    None => {
+                        view! {
+                            <p class="text-base-content/70 text-center">
+                                "No information available for this sport."
+                            </p>
+                        }
+                            .into_any()
+                    }
+                }
+            }}
         </div>
     }
 }
+
+/// list of capabilities of a sport plugin
+#[component]
+fn SportCapabilitiesList(capabilities: SportCapabilities) -> impl IntoView {
+    let entries = [
+        ("Draws", capabilities.supports_draws),
+        ("Scoring per set", capabilities.per_set_scoring),
+        ("Handicaps", capabilities.supports_handicap),
+        ("Time caps", capabilities.supports_time_cap),
+    ];
+    view! {
+        <ul class="list w-full max-w-md bg-base-100 rounded-box shadow-md" data-testid="about-sport-capabilities">
+            {entries
+                .into_iter()
+                .map(|(label, supported)| {
+                    view! {
+                        <li class="list-row flex justify-between">
+                            <span>{label}</span>
+                            <span
+                                class="badge"
+                                class:badge-success=supported
+                                class:badge-ghost=!supported
+                            >
+                                {if supported { "supported" } else { "not supported" }}
+                            </span>
+                        </li>
+                    }
+                })
+                .collect_view()}
+            <li class="list-row flex justify-between">
+                <span>"Matches are played on"</span>
+                <span class="badge badge-outline" data-testid="about-sport-station-type">
+                    {capabilities.station_label()}
+                </span>
+            </li>
+        </ul>
+    }
+}
@@ -0,0 +1,380 @@
+//! check-in of entrants on tournament day with deadline, waitlist and no-show handling
+
+use super::{format_datetime_local, parse_datetime_local};
+use app_core::{CheckInOverview, CrTopic, Entrant, NoShowPolicy};
+use app_utils::{
+    error::{ComponentError, strategy::handle_read_error},
+    hooks::use_on_cancel::use_on_cancel,
+    server_fn::{
+        check_in::{
+            ResolveNoShows, SaveCheckInSettings, SetEntrantWaitlistPosition, load_check_in_overview,
+        },
+        entrant::SetEntrantCheckIn,
+    },
+    state::{
+        activity_tracker::ActivityTracker, error_state::PageErrorContext, toast_state::ToastContext,
+    },
+};
+use chrono::Utc;
+use cr_leptos_axum_socket::use_client_registry_socket;
+use leptos::prelude::*;
+use uuid::Uuid;
+
+#[component]
+pub fn CheckInPanel(#[prop(into)] tournament_id: Signal<Option<Uuid>>) -> impl IntoView {
+    // --- global context and state ---
+    let page_err_ctx = expect_context::<PageErrorContext>();
+    let toast_ctx = expect_context::<ToastContext>();
+    let activity_tracker = expect_context::<ActivityTracker>();
+    let component_id = StoredValue::new(Uuid::new_v4());
+
+    on_cleanup(move || {
+        page_err_ctx.clear_all_for_component(component_id.get_value());
+        activity_tracker.remove_component(component_id.get_value());
+    });
+
+    let overview = Resource::new(
+        move || tournament_id.get(),
+        move |t_id| async move {
+            match t_id {
+                Some(t_id) => activity_tracker
+                    .track_activity_wrapper(component_id.get_value(), load_check_in_overview(t_id))
+                    .await
+                    .map_err(|app_error| ComponentError::new(component_id.get_value(), app_error)),
+                None => Ok(None),
+            }
+        },
+    );
+
+    let refetch = Callback::new(move |()| overview.refetch());
+    page_err_ctx.register_retry_handler(component_id.get_value(), refetch);
+
+    // Subscribe to entrant changes of this tournament
+    let topic = Signal::derive(move || {
+        tournament_id
+            .get()
+            .map(|tournament_id| CrTopic::Entrants { tournament_id })
+    });
+    use_client_registry_socket(topic, None.into(), refetch);
+
+    let set_check_in = ServerAction::<SetEntrantCheckIn>::new();
+    activity_tracker.track_pending_memo(component_id.get_value(), set_check_in.pending());
+    Effect::new(move || match set_check_in.value().get() {
+        Some(Ok(_)) => overview.refetch(),
+        Some(Err(err)) => {
+            toast_ctx.error(format!("Could not change check-in: {err}"), None);
+            overview.refetch();
+        }
+        None => {}
+    });
+
+    let set_waitlist = ServerAction::<SetEntrantWaitlistPosition>::new();
+    activity_tracker.track_pending_memo(component_id.get_value(), set_waitlist.pending());
+    Effect::new(move || match set_waitlist.value().get() {
+        Some(Ok(_)) => overview.refetch(),
+        Some(Err(err)) => {
+            toast_ctx.error(format!("Could not change waitlist: {err}"), None);
+            overview.refetch();
+        }
+        None => {}
+    });
+
+    let save_settings = ServerAction::<SaveCheckInSettings>::new();
+    activity_tracker.track_pending_memo(component_id.get_value(), save_settings.pending());
+    Effect::new(move || match save_settings.value().get() {
+        Some(Ok(())) => {
+            toast_ctx.success("Check-in settings saved.", None);
+            overview.refetch();
+        }
+        Some(Err(err)) => toast_ctx.error(format!("Could not save check-in settings: {err}"), None),
+        None => {}
+    });
+
+    let resolve = ServerAction::<ResolveNoShows>::new();
+    activity_tracker.track_pending_memo(component_id.get_value(), resolve.pending());
+    Effect::new(move || match resolve.value().get() {
+        Some(Ok(changed)) => {
+            let num_no_shows = changed.iter().filter(|e| e.is_no_show()).count();
+            toast_ctx.success(format!("Resolved {num_no_shows} no-shows."), None);
+            overview.refetch();
+        }
+        Some(Err(err)) => toast_ctx.error(format!("Could not resolve no-shows: {err}"), None),
+        None => {}
+    });
+
+    // --- settings form ---
+    let deadline = RwSignal::new(String::new());
+    let policy = RwSignal::new(NoShowPolicy::default());
+    Effect::new(move || {
+        if let Some(Ok(Some(o))) = overview.get() {
+            deadline.set(format_datetime_local(o.deadline));
+            policy.set(o.policy);
+        }
+    });
+    let on_save_settings = move |_| {
+        if let Some(tournament_id) = tournament_id.get_untracked() {
+            save_settings.dispatch(SaveCheckInSettings {
+                tournament_id,
+                deadline: parse_datetime_local(&deadline.get_untracked()),
+                policy: policy.get_untracked(),
+            });
+        }
+    };
+
+    let on_check_in = Callback::new(move |(entrant_id, checked_in): (Uuid, bool)| {
+        if let Some(tournament_id) = tournament_id.get_untracked() {
+            set_check_in.dispatch(SetEntrantCheckIn {
+                tournament_id,
+                entrant_id,
+                checked_in,
+            });
+        }
+    });
+    let on_waitlist = Callback::new(move |(entrant_id, position): (Uuid, Option<u32>)| {
+        if let Some(tournament_id) = tournament_id.get_untracked() {
+            set_waitlist.dispatch(SetEntrantWaitlistPosition {
+                tournament_id,
+                entrant_id,
+                position,
+            });
+        }
+    });
+    let is_pending = Signal::derive(move || {
+        set_check_in.pending().get() || set_waitlist.pending().get() || resolve.pending().get()
+    });
+
+    let on_cancel = use_on_cancel();
+
+    view! {
+        <div id="check-in" class="card w-full bg-base-100 shadow-xl" data-testid="check-in-root">
+            <div class="card-body">
+                <h2 class="card-title">"Check-in"</h2>
+                <div class="flex flex-wrap items-end gap-4">
+                    <label class="form-control">
+                        <span class="label-text">"Deadline"</span>
+                        <input
+                            type="datetime-local"
+                            class="input input-bordered input-sm"
+                            data-testid="input-check-in-deadline"
+                            prop:value=deadline
+                            on:change=move |ev| deadline.set(event_target_value(&ev))
+                        />
+                    </label>
+                    <label class="form-control">
+                        <span class="label-text">"No-shows"</span>
+                        <select
+                            class="select select-bordered select-sm"
+                            data-testid="select-no-show-policy"
+                            prop:value=move || format!("{:?}", policy.get())
+                            on:change=move |ev| {
+                                policy
+                                    .set(
+                                        match event_target_value(&ev).as_str() {
+                                            "Bye" => NoShowPolicy::Bye,
+                                            _ => NoShowPolicy::ReplaceFromWaitlist,
+                                        },
+                                    );
+                            }
+                        >
+                            <option value="ReplaceFromWaitlist">
+                                {NoShowPolicy::ReplaceFromWaitlist.to_string()}
+                            </option>
+                            <option value="Bye">{NoShowPolicy::Bye.to_string()}</option>
+                        </select>
+                    </label>
+                    <button
+                        class="btn btn-sm btn-primary"
+                        data-testid="action-btn-save-check-in-settings"
+                        disabled=move || save_settings.pending().get()
+                        on:click=on_save_settings
+                    >
+                        "Save"
+                    </button>
+                </div>
+                <Transition fallback=move || {
+                    view! { <span class="loading loading-spinner loading-md"></span> }
+                }>
+                    <ErrorBoundary fallback=move |errors| {
+                        for (_err_id, err) in errors.get().into_iter() {
+                            if let Some(comp_err) = err.into_inner().downcast_ref::<ComponentError>() {
+                                handle_read_error(&page_err_ctx, comp_err, on_cancel);
+                            }
+                        }
+                    }>
+                        {move || {
+                            overview
+                                .and_then(|o| {
+                                    o.clone()
+                                        .map(|o| {
+                                            view! {
+                                                <CheckInLists
+                                                    overview=o
+                                                    is_pending=is_pending
+                                                    on_check_in=on_check_in
+                                                    on_waitlist=on_waitlist
+                                                    on_resolve=Callback::new(move |()| {
+                                                        if let Some(tournament_id) = tournament_id
+                                                            .get_untracked()
+                                                        {
+                                                            resolve.dispatch(ResolveNoShows { tournament_id });
+                                                        }
+                                                    })
+                                                />
+                                            }
+                                        })
+                                })
+                        }}
+                    </ErrorBoundary>
+                </Transition>
+            </div>
+        </div>
+    }
+}
+
+/// field, waitlist and no-shows of the tournament
+#[component]
+fn CheckInLists(
+    overview: CheckInOverview,
+    #[prop(into)] is_pending: Signal<bool>,
+    on_check_in: Callback<(Uuid, bool)>,
+    on_waitlist: Callback<(Uuid, Option<u32>)>,
+    on_resolve: Callback<()>,
+) -> impl IntoView {
+    let deadline_passed = overview.is_deadline_passed(Utc::now());
+    let summary = format!(
+        "{} of {} checked in",
+        overview.num_checked_in(),
+        overview.field.len()
+    );
+    let next_position = overview
+        .waitlist
+        .iter()
+        .filter_map(Entrant::get_waitlist_position)
+        .max()
+        .unwrap_or_default()
+        + 1;
+    let check_in_box = move |entrant: &Entrant| {
+        let entrant_id = entrant.get_id();
+        view! {
+            <input
+                type="checkbox"
+                class="checkbox checkbox-sm"
+                aria-label="Present"
+                data-testid="action-checkbox-check-in-present"
+                prop:checked=entrant.is_checked_in()
+                disabled=move || is_pending.get()
+                on:change=move |ev| on_check_in.run((entrant_id, event_target_checked(&ev)))
+            />
+        }
+    };
+    let field = overview.field.clone();
+    let waitlist = overview.waitlist.clone();
+    let no_shows = overview.no_shows.clone();
+    let has_no_shows = !no_shows.is_empty();
+
+    view! {
+        <div class="flex flex-wrap items-center gap-4">
+            <span data-testid="check-in-summary">{summary}</span>
+            <Show when=move || deadline_passed>
+                <button
+                    class="btn btn-sm btn-warning"
+                    data-testid="action-btn-resolve-no-shows"
+                    disabled=move || is_pending.get()
+                    on:click=move |_| on_resolve.run(())
+                >
+                    "Resolve no-shows"
+                </button>
+            </Show>
+        </div>
+        <h3 class="font-bold">"Field"</h3>
+        <table class="table table-sm" data-testid="check-in-field">
+            <thead>
+                <tr>
+                    <th>"Seed"</th>
+                    <th>"Name"</th>
+                    <th>"Present"</th>
+                    <th></th>
+                </tr>
+            </thead>
+            <tbody>
+                <For
+                    each=move || field.clone()
+                    key=|e| (e.get_id(), e.get_version())
+                    children=move |entrant| {
+                        let entrant_id = entrant.get_id();
+                        view! {
+                            <tr data-testid="check-in-field-row">
+                                <td>{entrant.get_seed().map(|s| s.to_string()).unwrap_or_default()}</td>
+                                <td>{entrant.get_name().to_string()}</td>
+                                <td>{check_in_box(&entrant)}</td>
+                                <td>
+                                    <button
+                                        class="btn btn-xs btn-ghost"
+                                        data-testid="action-btn-move-to-waitlist"
+                                        disabled=move || is_pending.get()
+                                        on:click=move |_| {
+                                            on_waitlist.run((entrant_id, Some(next_position)))
+                                        }
+                                    >
+                                        "To waitlist"
+                                    </button>
+                                </td>
+                            </tr>
+                        }
+                    }
+                />
+            </tbody>
+        </table>
+        <h3 class="font-bold">"Waitlist"</h3>
+        <table class="table table-sm" data-testid="check-in-waitlist">
+            <thead>
+                <tr>
+                    <th>"Position"</th>
+                    <th>"Name"</th>
+                    <th>"Present"</th>
+                    <th></th>
+                </tr>
+            </thead>
+            <tbody>
+                <For
+                    each=move || waitlist.clone()
+                    key=|e| (e.get_id(), e.get_version())
+                    children=move |entrant| {
+                        let entrant_id = entrant.get_id();
+                        view! {
+                            <tr data-testid="check-in-waitlist-row">
+                                <td>
+                                    {entrant
+                                        .get_waitlist_position()
+                                        .map(|p| p.to_string())
+                                        .unwrap_or_default()}
+                                </td>
+                                <td>{entrant.get_name().to_string()}</td>
+                                <td>{check_in_box(&entrant)}</td>
+                                <td>
+                                    <button
+                                        class="btn btn-xs btn-ghost"
+                                        data-testid="action-btn-move-to-field"
+                                        disabled=move || is_pending.get()
+                                        on:click=move |_| on_waitlist.run((entrant_id, None))
+                                    >
+                                        "To field"
+                                    </button>
+                                </td>
+                            </tr>
+                        }
+                    }
+                />
+            </tbody>
+        </table>
+        <Show when=move || has_no_shows>
+            <h3 class="font-bold">"No-shows"</h3>
+            <ul class="list-disc list-inside" data-testid="check-in-no-shows">
+                {no_shows
+                    .iter()
+                    .map(|e| view! { <li>{e.get_name().to_string()}</li> })
+                    .collect_view()}
+            </ul>
+        </Show>
+    }
+}
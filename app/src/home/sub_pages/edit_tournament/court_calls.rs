@@ -0,0 +1,269 @@
+//! court calls of matches: call-ahead policy, free stations and link to the announcer screen
+
+use app_core::{CourtCallPolicy, CourtCallQueue, CourtCallState, CrTopic, MAX_CALL_AHEAD};
+use app_utils::{
+    error::{AppError, ComponentError, strategy::handle_read_error},
+    hooks::use_on_cancel::use_on_cancel,
+    server_fn::{
+        court_call::{AnnounceFreeStation, SaveCourtCallPolicy, load_court_calls},
+        tournament_base::load_tournament_base,
+    },
+    state::{
+        activity_tracker::ActivityTracker, error_state::PageErrorContext, toast_state::ToastContext,
+    },
+};
+use cr_leptos_axum_socket::use_client_registry_socket;
+use leptos::prelude::*;
+use leptos_router::components::A;
+use uuid::Uuid;
+
+#[component]
+pub fn CourtCallsPanel(#[prop(into)] tournament_id: Signal<Option<Uuid>>) -> impl IntoView {
+    // --- global context and state ---
+    let page_err_ctx = expect_context::<PageErrorContext>();
+    let toast_ctx = expect_context::<ToastContext>();
+    let activity_tracker = expect_context::<ActivityTracker>();
+    let component_id = StoredValue::new(Uuid::new_v4());
+
+    on_cleanup(move || {
+        page_err_ctx.clear_all_for_component(component_id.get_value());
+        activity_tracker.remove_component(component_id.get_value());
+    });
+
+    // tournament and its court calls
+    let queue = Resource::new(
+        move || tournament_id.get(),
+        move |t_id| async move {
+            let Some(t_id) = t_id else {
+                return Ok(None);
+            };
+            activity_tracker
+                .track_activity_wrapper(component_id.get_value(), async move {
+                    let Some(tournament) = load_tournament_base(t_id).await? else {
+                        return Ok(None);
+                    };
+                    let Some(queue) = load_court_calls(t_id).await? else {
+                        return Ok(None);
+                    };
+                    Ok::<_, AppError>(Some((tournament, queue)))
+                })
+                .await
+                .map_err(|app_error| ComponentError::new(component_id.get_value(), app_error))
+        },
+    );
+
+    let refetch = Callback::new(move |()| queue.refetch());
+    page_err_ctx.register_retry_handler(component_id.get_value(), refetch);
+
+    let calls_topic = Signal::derive(move || {
+        tournament_id
+            .get()
+            .map(|tournament_id| CrTopic::CourtCalls { tournament_id })
+    });
+    use_client_registry_socket(calls_topic, None.into(), refetch);
+    let scores_topic = Signal::derive(move || {
+        tournament_id
+            .get()
+            .map(|tournament_id| CrTopic::LiveScores { tournament_id })
+    });
+    use_client_registry_socket(scores_topic, None.into(), refetch);
+
+    let save_policy = ServerAction::<SaveCourtCallPolicy>::new();
+    activity_tracker.track_pending_memo(component_id.get_value(), save_policy.pending());
+    Effect::new(move || match save_policy.value().get() {
+        Some(Ok(_)) => {
+            toast_ctx.success("Court call policy saved.", None);
+            queue.refetch();
+        }
+        Some(Err(err)) => toast_ctx.error(format!("Could not save court call policy: {err}"), None),
+        None => {}
+    });
+
+    let announce = ServerAction::<AnnounceFreeStation>::new();
+    activity_tracker.track_pending_memo(component_id.get_value(), announce.pending());
+    Effect::new(move || match announce.value().get() {
+        Some(Ok(Some(call))) => {
+            toast_ctx.success(format!("Match called to {}.", call.station_name), None);
+            queue.refetch();
+        }
+        Some(Ok(None)) => toast_ctx.info("No pending match at this station.", None),
+        Some(Err(err)) => toast_ctx.error(format!("Could not call next match: {err}"), None),
+        None => {}
+    });
+
+    // --- policy form ---
+    let call_ahead = RwSignal::new(0_u32);
+    let notify_entrants = RwSignal::new(false);
+    let num_stations = RwSignal::new(0_u32);
+    Effect::new(move || {
+        if let Some(Ok(Some((tournament, _)))) = queue.get() {
+            let policy = tournament.get_court_call_policy();
+            call_ahead.set(policy.call_ahead);
+            notify_entrants.set(policy.notify_entrants);
+            num_stations.set(tournament.get_num_stations());
+        }
+    });
+    let on_save_policy = move |_| {
+        if let Some(tournament_id) = tournament_id.get_untracked() {
+            save_policy.dispatch(SaveCourtCallPolicy {
+                tournament_id,
+                policy: CourtCallPolicy {
+                    call_ahead: call_ahead.get_untracked(),
+                    notify_entrants: notify_entrants.get_untracked(),
+                },
+            });
+        }
+    };
+    let on_station_free = Callback::new(move |station: u32| {
+        if let Some(tournament_id) = tournament_id.get_untracked() {
+            announce.dispatch(AnnounceFreeStation {
+                tournament_id,
+                station,
+            });
+        }
+    });
+
+    let on_cancel = use_on_cancel();
+
+    view! {
+        <div id="court-calls" class="card w-full bg-base-100 shadow-xl" data-testid="court-calls-root">
+            <div class="card-body">
+                <div class="flex items-center justify-between">
+                    <h2 class="card-title">"Court Calls"</h2>
+                    {move || {
+                        tournament_id
+                            .get()
+                            .map(|tournament_id| {
+                                view! {
+                                    <A
+                                        href=format!("/announcer/{tournament_id}")
+                                        attr:class="btn btn-sm btn-outline"
+                                        attr:target="_blank"
+                                        attr:data-testid="link-announcer-screen"
+                                    >
+                                        "Announcer screen"
+                                    </A>
+                                }
+                            })
+                    }}
+                </div>
+                <div class="flex flex-wrap items-end gap-4">
+                    <label class="form-control">
+                        <span class="label-text">"Call ahead"</span>
+                        <input
+                            type="number"
+                            min="0"
+                            max=MAX_CALL_AHEAD
+                            class="input input-bordered input-sm w-24"
+                            data-testid="input-court-call-ahead"
+                            prop:value=move || call_ahead.get().to_string()
+                            on:change=move |ev| {
+                                call_ahead
+                                    .set(
+                                        event_target_value(&ev)
+                                            .parse::<u32>()
+                                            .unwrap_or_default()
+                                            .min(MAX_CALL_AHEAD),
+                                    );
+                            }
+                        />
+                    </label>
+                    <label class="label cursor-pointer gap-2">
+                        <input
+                            type="checkbox"
+                            class="checkbox checkbox-sm"
+                            data-testid="input-court-call-notify"
+                            prop:checked=notify_entrants
+                            on:change=move |ev| notify_entrants.set(event_target_checked(&ev))
+                        />
+                        <span class="label-text">"Notify entrants by email"</span>
+                    </label>
+                    <button
+                        class="btn btn-sm btn-primary"
+                        data-testid="action-btn-save-court-call-policy"
+                        disabled=move || save_policy.pending().get()
+                        on:click=on_save_policy
+                    >
+                        "Save"
+                    </button>
+                </div>
+                <div class="flex flex-wrap gap-2" data-testid="court-calls-stations">
+                    {move || {
+                        (1..=num_stations.get())
+                            .map(|station| {
+                                view! {
+                                    <button
+                                        class="btn btn-sm"
+                                        data-testid="action-btn-station-free"
+                                        disabled=move || announce.pending().get()
+                                        on:click=move |_| on_station_free.run(station)
+                                    >
+                                        {format!("Station {station} free")}
+                                    </button>
+                                }
+                            })
+                            .collect_view()
+                    }}
+                </div>
+                <Transition fallback=move || {
+                    view! { <span class="loading loading-spinner loading-md"></span> }
+                }>
+                    <ErrorBoundary fallback=move |errors| {
+                        for (_err_id, err) in errors.get().into_iter() {
+                            if let Some(comp_err) = err.into_inner().downcast_ref::<ComponentError>() {
+                                handle_read_error(&page_err_ctx, comp_err, on_cancel);
+                            }
+                        }
+                    }>
+                        {move || {
+                            queue
+                                .and_then(|loaded| {
+                                    loaded.clone().map(|(_, queue)| view! { <CourtCallTable queue=queue /> })
+                                })
+                        }}
+                    </ErrorBoundary>
+                </Transition>
+            </div>
+        </div>
+    }
+}
+
+#[component]
+fn CourtCallTable(queue: CourtCallQueue) -> impl IntoView {
+    let has_calls = !queue.calls.is_empty();
+
+    view! {
+        <Show
+            when=move || has_calls
+            fallback=|| view! { <p class="opacity-70">"No matches are called right now."</p> }
+        >
+            <table class="table table-sm" data-testid="court-calls-table">
+                <thead>
+                    <tr>
+                        <th>"Station"</th>
+                        <th>"Start"</th>
+                        <th>"Entrants"</th>
+                        <th>"State"</th>
+                    </tr>
+                </thead>
+                <tbody>
+                    {queue
+                        .calls
+                        .iter()
+                        .map(|call| {
+                            let is_called = call.state == CourtCallState::Called;
+                            view! {
+                                <tr class:font-semibold=is_called data-testid="court-call-row">
+                                    <td>{call.station_name.clone()}</td>
+                                    <td>{call.start_at.format("%H:%M").to_string()}</td>
+                                    <td>{call.entrants.join(" – ")}</td>
+                                    <td>{call.state.to_string()}</td>
+                                </tr>
+                            }
+                        })
+                        .collect_view()}
+                </tbody>
+            </table>
+        </Show>
+    }
+}
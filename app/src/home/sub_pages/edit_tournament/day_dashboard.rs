@@ -0,0 +1,252 @@
+//! operational dashboard of a tournament day: result entry per station, check-in of
+//! entrants and consistency of group standings
+
+use app_core::{CrTopic, RESULT_ENTRY_LAG_MINUTES};
+use app_utils::{
+    components::standings_warning::StandingsWarning,
+    error::{ComponentError, strategy::handle_read_error},
+    hooks::use_on_cancel::use_on_cancel,
+    server_fn::tournament_base::{RefreshDisplayBoards, load_day_dashboard},
+    state::{
+        activity_tracker::ActivityTracker, error_state::PageErrorContext, toast_state::ToastContext,
+    },
+};
+use cr_leptos_axum_socket::use_client_registry_socket;
+use leptos::prelude::*;
+use leptos_router::{NavigateOptions, components::A, hooks::use_navigate};
+use uuid::Uuid;
+
+/// format latency in seconds as minutes and seconds, e.g. `12:05`
+fn format_latency(seconds: Option<i64>) -> String {
+    seconds
+        .map(|s| format!("{}:{:02}", s / 60, s % 60))
+        .unwrap_or_else(|| String::from("–"))
+}
+
+#[component]
+pub fn DayDashboardPanel(#[prop(into)] tournament_id: Signal<Option<Uuid>>) -> impl IntoView {
+    // --- global context and state ---
+    let page_err_ctx = expect_context::<PageErrorContext>();
+    let toast_ctx = expect_context::<ToastContext>();
+    let activity_tracker = expect_context::<ActivityTracker>();
+    let component_id = StoredValue::new(Uuid::new_v4());
+
+    on_cleanup(move || {
+        page_err_ctx.clear_all_for_component(component_id.get_value());
+        activity_tracker.remove_component(component_id.get_value());
+    });
+
+    let dashboard = Resource::new(
+        move || tournament_id.get(),
+        move |t_id| async move {
+            match t_id {
+                Some(t_id) => activity_tracker
+                    .track_activity_wrapper(component_id.get_value(), load_day_dashboard(t_id))
+                    .await
+                    .map(Some)
+                    .map_err(|app_error| ComponentError::new(component_id.get_value(), app_error)),
+                None => Ok(None),
+            }
+        },
+    );
+
+    let refetch = Callback::new(move |()| dashboard.refetch());
+    page_err_ctx.register_retry_handler(component_id.get_value(), refetch);
+
+    // re-evaluate on check-in of entrants; latencies grow with time, refresh manually
+    let entrants_topic = Signal::derive(move || {
+        tournament_id
+            .get()
+            .map(|tournament_id| CrTopic::Entrants { tournament_id })
+    });
+    use_client_registry_socket(entrants_topic, None.into(), refetch);
+
+    // display boards at the venue reload on changes; corrections may require a manual reload
+    let refresh_displays = ServerAction::<RefreshDisplayBoards>::new();
+    activity_tracker.track_pending_memo(component_id.get_value(), refresh_displays.pending());
+    Effect::new(move || match refresh_displays.value().get() {
+        Some(Ok(())) => toast_ctx.success("Display boards reloaded.", None),
+        Some(Err(err)) => toast_ctx.error(format!("Could not reload display boards: {err}"), None),
+        None => {}
+    });
+    let on_refresh_displays = move |_| {
+        if let Some(id) = tournament_id.get_untracked() {
+            refresh_displays.dispatch(RefreshDisplayBoards { id });
+        }
+    };
+
+    // inconsistent standings point to data-entry errors; drill down to the score sheet
+    let standings_checks = Signal::derive(move || {
+        dashboard
+            .get()
+            .and_then(Result::ok)
+            .flatten()
+            .map(|dashboard| dashboard.standings_checks)
+            .unwrap_or_default()
+    });
+    let navigate = use_navigate();
+    let on_select_match = Callback::new(move |match_id: Uuid| {
+        navigate(
+            &format!("/match/{match_id}/score-sheet"),
+            NavigateOptions::default(),
+        );
+    });
+
+    let on_cancel = use_on_cancel();
+
+    view! {
+        <div class="card w-full bg-base-100 shadow-xl" data-testid="day-dashboard-root">
+            <div class="card-body">
+                <div class="flex items-center justify-between">
+                    <h2 class="card-title">"Tournament Day"</h2>
+                    <div class="flex gap-2">
+                        {move || {
+                            tournament_id
+                                .get()
+                                .map(|tournament_id| {
+                                    view! {
+                                        <A
+                                            href=format!("/display/{tournament_id}")
+                                            attr:class="btn btn-sm btn-outline"
+                                            attr:target="_blank"
+                                            attr:data-testid="link-display-board"
+                                        >
+                                            "Display board"
+                                        </A>
+                                    }
+                                })
+                        }}
+                        <button
+                            class="btn btn-sm btn-outline"
+                            data-testid="action-btn-refresh-display-boards"
+                            disabled=move || {
+                                tournament_id.get().is_none() || refresh_displays.pending().get()
+                            }
+                            on:click=on_refresh_displays
+                        >
+                            "Reload displays"
+                        </button>
+                        <button
+                            class="btn btn-sm btn-outline"
+                            data-testid="action-btn-refresh-day-dashboard"
+                            disabled=move || tournament_id.get().is_none()
+                            on:click=move |_| refetch.run(())
+                        >
+                            "Refresh"
+                        </button>
+                    </div>
+                </div>
+                <StandingsWarning reports=standings_checks on_select_match=on_select_match />
+                <Transition fallback=move || {
+                    view! { <span class="loading loading-spinner loading-md"></span> }
+                }>
+                    <ErrorBoundary fallback=move |errors| {
+                        for (_err_id, err) in errors.get().into_iter() {
+                            if let Some(comp_err) = err.into_inner().downcast_ref::<ComponentError>() {
+                                handle_read_error(&page_err_ctx, comp_err, on_cancel);
+                            }
+                        }
+                    }>
+                        {move || {
+                            dashboard
+                                .and_then(|loaded| {
+                                    loaded
+                                        .clone()
+                                        .map(|dashboard| {
+                                            let check_in = dashboard.check_in.clone();
+                                            let num_unconfirmed = dashboard.num_unconfirmed();
+                                            let num_outstanding = dashboard.num_outstanding();
+                                            let generated_at = dashboard
+                                                .generated_at
+                                                .format("%H:%M:%S")
+                                                .to_string();
+                                            view! {
+                                                <div class="stats stats-vertical lg:stats-horizontal shadow">
+                                                    <div class="stat" data-testid="day-dashboard-check-in">
+                                                        <div class="stat-title">"Checked in"</div>
+                                                        <div class="stat-value">
+                                                            {format!("{}%", check_in.percent())}
+                                                        </div>
+                                                        <div class="stat-desc">
+                                                            {format!(
+                                                                "{} of {} entrants",
+                                                                check_in.num_checked_in,
+                                                                check_in.num_entrants,
+                                                            )}
+                                                        </div>
+                                                    </div>
+                                                    <div class="stat" data-testid="day-dashboard-outstanding">
+                                                        <div class="stat-title">"Outstanding results"</div>
+                                                        <div class="stat-value">{num_outstanding}</div>
+                                                        <div class="stat-desc">"projected end passed"</div>
+                                                    </div>
+                                                    <div class="stat" data-testid="day-dashboard-unconfirmed">
+                                                        <div class="stat-title">"Unconfirmed results"</div>
+                                                        <div class="stat-value">{num_unconfirmed}</div>
+                                                    </div>
+                                                </div>
+                                                <Show when=move || !check_in.is_complete()>
+                                                    <p class="text-sm" data-testid="day-dashboard-missing-check-ins">
+                                                        <span class="font-semibold">"Not checked in: "</span>
+                                                        {dashboard.check_in.missing.join(", ")}
+                                                    </p>
+                                                </Show>
+                                                <table class="table table-sm" data-testid="day-dashboard-stations">
+                                                    <thead>
+                                                        <tr>
+                                                            <th>"Station"</th>
+                                                            <th>"Entered"</th>
+                                                            <th>"Outstanding"</th>
+                                                            <th>"Unconfirmed"</th>
+                                                            <th>"Mean latency"</th>
+                                                            <th>"Max latency"</th>
+                                                        </tr>
+                                                    </thead>
+                                                    <tbody>
+                                                        {dashboard
+                                                            .stations
+                                                            .into_iter()
+                                                            .map(|stats| {
+                                                                let is_lagging = stats.is_lagging();
+                                                                view! {
+                                                                    <tr
+                                                                        class:bg-warning=is_lagging
+                                                                        data-testid="day-dashboard-station-row"
+                                                                    >
+                                                                        <td>
+                                                                            {stats.name.clone()}
+                                                                            {is_lagging
+                                                                                .then(|| {
+                                                                                    view! {
+                                                                                        <span class="badge badge-error badge-sm ml-2">
+                                                                                            "lagging"
+                                                                                        </span>
+                                                                                    }
+                                                                                })}
+                                                                        </td>
+                                                                        <td>{stats.num_entered}</td>
+                                                                        <td>{stats.num_outstanding}</td>
+                                                                        <td>{stats.num_unconfirmed}</td>
+                                                                        <td>{format_latency(stats.mean_latency_seconds)}</td>
+                                                                        <td>{format_latency(stats.max_latency_seconds)}</td>
+                                                                    </tr>
+                                                                }
+                                                            })
+                                                            .collect_view()}
+                                                    </tbody>
+                                                </table>
+                                                <p class="text-xs text-base-content/70">
+                                                    {format!(
+                                                        "Latency is the time between projected end of a match and entry of its result. Stations lagging by {RESULT_ENTRY_LAG_MINUTES} minutes or more are highlighted. Evaluated at {generated_at}.",
+                                                    )}
+                                                </p>
+                                            }
+                                        })
+                                })
+                        }}
+                    </ErrorBoundary>
+                </Transition>
+            </div>
+        </div>
+    }
+}
@@ -0,0 +1,159 @@
+//! estimated duration of the stages of a tournament, which directors check for feasibility
+//! before scheduling
+
+use app_core::{CrTopic, TournamentDurationEstimate};
+use app_utils::{
+    error::{ComponentError, strategy::handle_read_error},
+    hooks::use_on_cancel::use_on_cancel,
+    server_fn::tournament_base::load_tournament_duration_estimate,
+    state::{activity_tracker::ActivityTracker, error_state::PageErrorContext},
+};
+use cr_leptos_axum_socket::use_client_registry_socket;
+use leptos::prelude::*;
+use std::time::Duration;
+use uuid::Uuid;
+
+/// format duration as hours and minutes, e.g. `6:40 h`
+fn format_hours(duration: Duration) -> String {
+    let minutes = duration.as_secs() / 60;
+    format!("{}:{:02} h", minutes / 60, minutes % 60)
+}
+
+#[component]
+pub fn DurationEstimatePanel(#[prop(into)] tournament_id: Signal<Option<Uuid>>) -> impl IntoView {
+    // --- global context and state ---
+    let page_err_ctx = expect_context::<PageErrorContext>();
+    let activity_tracker = expect_context::<ActivityTracker>();
+    let component_id = StoredValue::new(Uuid::new_v4());
+
+    on_cleanup(move || {
+        page_err_ctx.clear_all_for_component(component_id.get_value());
+        activity_tracker.remove_component(component_id.get_value());
+    });
+
+    let estimate = Resource::new(
+        move || tournament_id.get(),
+        move |t_id| async move {
+            match t_id {
+                Some(t_id) => activity_tracker
+                    .track_activity_wrapper(
+                        component_id.get_value(),
+                        load_tournament_duration_estimate(t_id),
+                    )
+                    .await
+                    .map_err(|app_error| ComponentError::new(component_id.get_value(), app_error)),
+                None => Ok(None),
+            }
+        },
+    );
+
+    let refetch = Callback::new(move |()| estimate.refetch());
+    page_err_ctx.register_retry_handler(component_id.get_value(), refetch);
+
+    // re-estimate on changes of mode, entrants or stations of tournament
+    let tournament_topic = Signal::derive(move || {
+        tournament_id
+            .get()
+            .map(|tournament_base_id| CrTopic::TournamentBase { tournament_base_id })
+    });
+    use_client_registry_socket(tournament_topic, None.into(), refetch);
+
+    let on_cancel = use_on_cancel();
+
+    view! {
+        <div class="card w-full bg-base-100 shadow-xl" data-testid="duration-estimate-root">
+            <div class="card-body">
+                <h2 class="card-title">"Estimated Duration"</h2>
+                <Transition fallback=move || {
+                    view! { <span class="loading loading-spinner loading-md"></span> }
+                }>
+                    <ErrorBoundary fallback=move |errors| {
+                        for (_err_id, err) in errors.get().into_iter() {
+                            if let Some(comp_err) = err.into_inner().downcast_ref::<ComponentError>() {
+                                handle_read_error(&page_err_ctx, comp_err, on_cancel);
+                            }
+                        }
+                    }>
+                        {move || {
+                            estimate
+                                .and_then(|loaded| match loaded.clone() {
+                                    Some(estimate) => {
+                                        view! { <DurationEstimateView estimate=estimate /> }.into_any()
+                                    }
+                                    None => {
+                                        view! {
+                                            <p
+                                                class="text-base-content/70"
+                                                data-testid="duration-estimate-unavailable"
+                                            >
+                                                "Create a valid configuration for the sport of the tournament to estimate its duration."
+                                            </p>
+                                        }
+                                            .into_any()
+                                    }
+                                })
+                        }}
+                    </ErrorBoundary>
+                </Transition>
+            </div>
+        </div>
+    }
+}
+
+#[component]
+fn DurationEstimateView(estimate: TournamentDurationEstimate) -> impl IntoView {
+    let num_matches = estimate.num_matches();
+    let timing = estimate.timing;
+
+    view! {
+        <div class="stats stats-vertical lg:stats-horizontal shadow">
+            <div class="stat" data-testid="duration-estimate-total">
+                <div class="stat-title">"Tournament"</div>
+                <div class="stat-value">{format_hours(estimate.duration)}</div>
+                <div class="stat-desc">{format!("{num_matches} matches")}</div>
+            </div>
+            <div class="stat" data-testid="duration-estimate-match">
+                <div class="stat-title">"Match"</div>
+                <div class="stat-value">
+                    {format!("{} min", timing.match_duration.as_secs() / 60)}
+                </div>
+                <div class="stat-desc">
+                    {format!("+ {} min changeover", timing.changeover.as_secs() / 60)}
+                </div>
+            </div>
+        </div>
+        <table class="table table-sm" data-testid="duration-estimate-stages">
+            <thead>
+                <tr>
+                    <th>"Stage"</th>
+                    <th>"Matches"</th>
+                    <th>"Rounds"</th>
+                    <th>"Stations"</th>
+                    <th>"Matches per station"</th>
+                    <th>"Duration"</th>
+                </tr>
+            </thead>
+            <tbody>
+                {estimate
+                    .stages
+                    .into_iter()
+                    .map(|stage| {
+                        view! {
+                            <tr data-testid="duration-estimate-stage-row">
+                                <td>{stage.stage_name}</td>
+                                <td>{stage.num_matches}</td>
+                                <td>{stage.num_rounds}</td>
+                                <td>{stage.num_stations}</td>
+                                <td>{stage.num_slots}</td>
+                                <td>{format_hours(stage.duration)}</td>
+                            </tr>
+                        }
+                    })
+                    .collect_view()}
+            </tbody>
+        </table>
+        <p class="text-xs text-base-content/70">
+            "Stages are played one after another. Each round needs at least one match per station; the estimate uses the sport configuration of the tournament and ignores breaks and delays."
+        </p>
+    }
+}
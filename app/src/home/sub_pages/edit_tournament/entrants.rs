@@ -0,0 +1,206 @@
+//! entrants of tournament
+
+use app_core::{CoreError, CrTopic, utils::validation::FieldError};
+use app_utils::{
+    components::file_drop::TextFileDropZone,
+    error::{AppError, ComponentError, strategy::handle_read_error},
+    hooks::{use_on_cancel::use_on_cancel, use_ui_language::use_ui_language},
+    server_fn::entrant::{ImportEntrantsCsv, SetEntrantCheckIn, list_entrants},
+    state::{
+        activity_tracker::ActivityTracker, error_state::PageErrorContext, toast_state::ToastContext,
+    },
+};
+use cr_leptos_axum_socket::use_client_registry_socket;
+use leptos::prelude::*;
+use uuid::Uuid;
+
+#[component]
+pub fn EntrantsPanel(#[prop(into)] tournament_id: Signal<Option<Uuid>>) -> impl IntoView {
+    // --- global context and state ---
+    let page_err_ctx = expect_context::<PageErrorContext>();
+    let toast_ctx = expect_context::<ToastContext>();
+    let activity_tracker = expect_context::<ActivityTracker>();
+    let component_id = StoredValue::new(Uuid::new_v4());
+
+    on_cleanup(move || {
+        page_err_ctx.clear_all_for_component(component_id.get_value());
+        activity_tracker.remove_component(component_id.get_value());
+    });
+
+    // --- local state ---
+    let import_errors = RwSignal::new(Vec::<FieldError>::new());
+    let ui_language = use_ui_language();
+
+    let entrants = Resource::new(
+        move || tournament_id.get(),
+        move |t_id| async move {
+            match t_id {
+                Some(t_id) => activity_tracker
+                    .track_activity_wrapper(component_id.get_value(), list_entrants(t_id))
+                    .await
+                    .map_err(|app_error| ComponentError::new(component_id.get_value(), app_error)),
+                None => Ok(vec![]),
+            }
+        },
+    );
+
+    let refetch = Callback::new(move |()| entrants.refetch());
+    page_err_ctx.register_retry_handler(component_id.get_value(), refetch);
+
+    // Subscribe to entrant changes of this tournament
+    let topic = Signal::derive(move || {
+        tournament_id
+            .get()
+            .map(|tournament_id| CrTopic::Entrants { tournament_id })
+    });
+    use_client_registry_socket(topic, None.into(), refetch);
+
+    let import_csv = ServerAction::<ImportEntrantsCsv>::new();
+    activity_tracker.track_pending_memo(component_id.get_value(), import_csv.pending());
+    Effect::new(move || match import_csv.value().get() {
+        Some(Ok(imported)) => {
+            import_errors.set(vec![]);
+            toast_ctx.success(format!("Imported {} entrants.", imported.len()), None);
+            entrants.refetch();
+        }
+        Some(Err(AppError::Core(CoreError::Validation(errs)))) => {
+            toast_ctx.warning("CSV contains invalid rows, nothing was imported.", None);
+            import_errors.set(errs.errors);
+        }
+        Some(Err(err)) => {
+            import_errors.set(vec![]);
+            toast_ctx.error(format!("Could not import entrants: {err}"), None);
+        }
+        None => {}
+    });
+
+    let set_check_in = ServerAction::<SetEntrantCheckIn>::new();
+    activity_tracker.track_pending_memo(component_id.get_value(), set_check_in.pending());
+    Effect::new(move || match set_check_in.value().get() {
+        Some(Ok(_)) => entrants.refetch(),
+        Some(Err(err)) => {
+            toast_ctx.error(format!("Could not change check-in: {err}"), None);
+            entrants.refetch();
+        }
+        None => {}
+    });
+
+    let on_load = Callback::new(move |csv: String| {
+        if let Some(tournament_id) = tournament_id.get_untracked() {
+            import_csv.dispatch(ImportEntrantsCsv { tournament_id, csv });
+        }
+    });
+
+    let on_cancel = use_on_cancel();
+
+    view! {
+        <div id="entrants" class="card w-full bg-base-100 shadow-xl" data-testid="entrants-root">
+            <div class="card-body">
+                <h2 class="card-title">"Entrants"</h2>
+                <TextFileDropZone
+                    label="Drop CSV file (name, club, seed, email) here or click to select"
+                    accept=".csv,text/csv"
+                    data_testid="action-input-import-entrants"
+                    on_load=on_load
+                    disabled=import_csv.pending()
+                />
+                <Show when=move || !import_errors.get().is_empty()>
+                    <div role="alert" class="alert alert-warning" data-testid="entrants-import-errors">
+                        <ul class="list-disc list-inside">
+                            <For
+                                each=move || import_errors.get()
+                                key=|e| (e.get_object_id(), e.get_field().to_string(), e.get_code().to_string())
+                                children=move |e| view! { <li>{move || e.render(ui_language.get())}</li> }
+                            />
+                        </ul>
+                    </div>
+                </Show>
+                <Transition fallback=move || {
+                    view! { <span class="loading loading-spinner loading-md"></span> }
+                }>
+                    <ErrorBoundary fallback=move |errors| {
+                        for (_err_id, err) in errors.get().into_iter() {
+                            if let Some(comp_err) = err.into_inner().downcast_ref::<ComponentError>() {
+                                handle_read_error(&page_err_ctx, comp_err, on_cancel);
+                            }
+                        }
+                    }>
+                        {move || {
+                            entrants
+                                .and_then(|list| {
+                                    let list = list.clone();
+                                    view! {
+                                        <table class="table table-sm" data-testid="entrants-table">
+                                            <thead>
+                                                <tr>
+                                                    <th>"Seed"</th>
+                                                    <th>"Name"</th>
+                                                    <th>"Club"</th>
+                                                    <th>"Email"</th>
+                                                    <th>"Checked in"</th>
+                                                </tr>
+                                            </thead>
+                                            <tbody>
+                                                <For
+                                                    each=move || list.clone()
+                                                    key=|e| (e.get_id(), e.get_version())
+                                                    children=move |entrant| {
+                                                        let entrant_id = entrant.get_id();
+                                                        let checked_in = entrant.is_checked_in();
+                                                        let checked_in_at = entrant
+                                                            .get_checked_in_at()
+                                                            .map(|t| t.format("%H:%M").to_string())
+                                                            .unwrap_or_default();
+                                                        view! {
+                                                            <tr data-testid="entrants-row">
+                                                                <td>
+                                                                    {entrant
+                                                                        .get_seed()
+                                                                        .map(|s| s.to_string())
+                                                                        .unwrap_or_default()}
+                                                                </td>
+                                                                <td>{entrant.get_name().to_string()}</td>
+                                                                <td>
+                                                                    {entrant.get_club().unwrap_or_default().to_string()}
+                                                                </td>
+                                                                <td>
+                                                                    {entrant.get_email().unwrap_or_default().to_string()}
+                                                                </td>
+                                                                <td class="whitespace-nowrap">
+                                                                    <input
+                                                                        type="checkbox"
+                                                                        class="checkbox checkbox-sm"
+                                                                        aria-label="Checked in"
+                                                                        data-testid="action-checkbox-entrant-check-in"
+                                                                        prop:checked=checked_in
+                                                                        disabled=move || set_check_in.pending().get()
+                                                                        on:change=move |ev| {
+                                                                            if let Some(tournament_id) = tournament_id
+                                                                                .get_untracked()
+                                                                            {
+                                                                                set_check_in
+                                                                                    .dispatch(SetEntrantCheckIn {
+                                                                                        tournament_id,
+                                                                                        entrant_id,
+                                                                                        checked_in: event_target_checked(&ev),
+                                                                                    });
+                                                                            }
+                                                                        }
+                                                                    />
+                                                                    <span class="ml-2 text-xs">{checked_in_at}</span>
+                                                                </td>
+                                                            </tr>
+                                                        }
+                                                    }
+                                                />
+                                            </tbody>
+                                        </table>
+                                    }
+                                })
+                        }}
+                    </ErrorBoundary>
+                </Transition>
+            </div>
+        </div>
+    }
+}
@@ -0,0 +1,168 @@
+//! notes and tags of matches, searchable by directors
+
+use app_core::CrTopic;
+use app_utils::{
+    error::{AppError, ComponentError, strategy::handle_read_error},
+    hooks::use_on_cancel::use_on_cancel,
+    server_fn::match_note::{list_match_note_tags, list_match_notes},
+    state::{activity_tracker::ActivityTracker, error_state::PageErrorContext},
+};
+use cr_leptos_axum_socket::use_client_registry_socket;
+use leptos::prelude::*;
+use uuid::Uuid;
+
+/// Notes are added and edited in the match view, see [`crate::score_sheet`].
+#[component]
+pub fn MatchNotesPanel(#[prop(into)] tournament_id: Signal<Option<Uuid>>) -> impl IntoView {
+    // --- global context and state ---
+    let page_err_ctx = expect_context::<PageErrorContext>();
+    let activity_tracker = expect_context::<ActivityTracker>();
+    let component_id = StoredValue::new(Uuid::new_v4());
+
+    on_cleanup(move || {
+        page_err_ctx.clear_all_for_component(component_id.get_value());
+        activity_tracker.remove_component(component_id.get_value());
+    });
+
+    // --- local state ---
+    let tag_filter = RwSignal::new(None::<String>);
+    let query = RwSignal::new(String::new());
+
+    // tags with counts and filtered notes
+    let notes = Resource::new(
+        move || (tournament_id.get(), tag_filter.get(), query.get()),
+        move |(t_id, tag, query)| async move {
+            let Some(t_id) = t_id else {
+                return Ok((vec![], vec![]));
+            };
+            activity_tracker
+                .track_activity_wrapper(component_id.get_value(), async move {
+                    let tags = list_match_note_tags(t_id).await?;
+                    let query = (!query.trim().is_empty()).then_some(query);
+                    let notes = list_match_notes(t_id, tag, query).await?;
+                    Ok::<_, AppError>((tags, notes))
+                })
+                .await
+                .map_err(|app_error| ComponentError::new(component_id.get_value(), app_error))
+        },
+    );
+
+    let refetch = Callback::new(move |()| notes.refetch());
+    page_err_ctx.register_retry_handler(component_id.get_value(), refetch);
+
+    // Subscribe to note changes of this tournament
+    let topic = Signal::derive(move || {
+        tournament_id
+            .get()
+            .map(|tournament_id| CrTopic::MatchNotes { tournament_id })
+    });
+    use_client_registry_socket(topic, None.into(), refetch);
+
+    let on_cancel = use_on_cancel();
+
+    view! {
+        <div class="card w-full bg-base-100 shadow-xl" data-testid="match-notes-root">
+            <div class="card-body">
+                <h2 class="card-title">"Match Notes"</h2>
+                <input
+                    type="search"
+                    class="input input-bordered w-full md:w-64"
+                    placeholder="Search notes and tags"
+                    data-testid="input-match-notes-query"
+                    prop:value=query
+                    on:input=move |ev| query.set(event_target_value(&ev))
+                />
+                <Transition fallback=move || {
+                    view! { <span class="loading loading-spinner loading-md"></span> }
+                }>
+                    <ErrorBoundary fallback=move |errors| {
+                        for (_err_id, err) in errors.get().into_iter() {
+                            if let Some(comp_err) = err.into_inner().downcast_ref::<ComponentError>() {
+                                handle_read_error(&page_err_ctx, comp_err, on_cancel);
+                            }
+                        }
+                    }>
+                        {move || {
+                            notes
+                                .and_then(|(tags, list)| {
+                                    let tags = tags.clone();
+                                    let list = list.clone();
+                                    let is_empty = list.is_empty();
+                                    view! {
+                                        <div class="flex flex-wrap gap-2" data-testid="match-notes-tags">
+                                            {tags
+                                                .into_iter()
+                                                .map(|(tag, count)| {
+                                                    let tag_for_click = tag.clone();
+                                                    let tag_for_class = tag.clone();
+                                                    view! {
+                                                        <button
+                                                            class="badge badge-outline cursor-pointer"
+                                                            class:badge-primary=move || {
+                                                                tag_filter.get().as_deref()
+                                                                    == Some(tag_for_class.as_str())
+                                                            }
+                                                            data-testid="action-btn-match-note-tag"
+                                                            on:click=move |_| {
+                                                                let tag = tag_for_click.clone();
+                                                                tag_filter
+                                                                    .update(|filter| {
+                                                                        *filter = if filter.as_deref() == Some(tag.as_str()) {
+                                                                            None
+                                                                        } else {
+                                                                            Some(tag)
+                                                                        };
+                                                                    });
+                                                            }
+                                                        >
+                                                            {format!("{tag} ({count})")}
+                                                        </button>
+                                                    }
+                                                })
+                                                .collect_view()}
+                                        </div>
+                                        <Show when=move || is_empty>
+                                            <p class="text-base-content/70" data-testid="match-notes-empty">
+                                                "No match notes found."
+                                            </p>
+                                        </Show>
+                                        <ul class="flex flex-col gap-2" data-testid="match-notes-list">
+                                            <For
+                                                each=move || list.clone()
+                                                key=|n| (n.get_id(), n.get_version())
+                                                children=move |note| {
+                                                    let updated = note
+                                                        .get_updated_at()
+                                                        .map(|t| t.format("%Y-%m-%d %H:%M").to_string())
+                                                        .unwrap_or_default();
+                                                    view! {
+                                                        <li class="p-3 rounded-lg bg-base-200" data-testid="match-note">
+                                                            <div class="text-sm opacity-70">
+                                                                {format!("{updated} · {}", note.get_author())}
+                                                            </div>
+                                                            <div class="flex flex-wrap gap-1">
+                                                                {note
+                                                                    .get_tags()
+                                                                    .iter()
+                                                                    .map(|tag| {
+                                                                        view! {
+                                                                            <span class="badge badge-sm badge-ghost">{tag.clone()}</span>
+                                                                        }
+                                                                    })
+                                                                    .collect_view()}
+                                                            </div>
+                                                            <p class="whitespace-pre-wrap">{note.get_text().to_string()}</p>
+                                                        </li>
+                                                    }
+                                                }
+                                            />
+                                        </ul>
+                                    }
+                                })
+                        }}
+                    </ErrorBoundary>
+                </Transition>
+            </div>
+        </div>
+    }
+}
@@ -1,14 +1,45 @@
 //! Edit tournament components
 
+pub mod check_in;
+pub mod court_calls;
+pub mod day_dashboard;
+pub mod duration_estimate;
+pub mod entrants;
+pub mod match_notes;
+pub mod officials;
+pub mod planning;
+pub mod public_languages;
+pub mod readiness;
+pub mod scorekeepers;
+pub mod seeding;
+pub mod shift_log;
+pub mod stage_timing;
+pub mod stations;
 pub mod tournament_base;
 pub mod tournament_group;
 pub mod tournament_stage;
 
+pub use check_in::*;
+pub use court_calls::*;
+pub use day_dashboard::*;
+pub use duration_estimate::*;
+pub use entrants::*;
+pub use match_notes::*;
+pub use officials::*;
+pub use planning::*;
+pub use public_languages::*;
+pub use readiness::*;
+pub use scorekeepers::*;
+pub use seeding::*;
+pub use shift_log::*;
+pub use stage_timing::*;
+pub use stations::*;
 pub use tournament_base::*;
 pub use tournament_group::*;
 pub use tournament_stage::*;
 
 use app_utils::params::{GroupNumberParams, ParamQuery, StageNumberParams};
+use chrono::{DateTime, Local, NaiveDateTime, Utc};
 use leptos::prelude::*;
 #[allow(unused_imports)]
 use leptos_router::MatchNestedRoutes;
@@ -19,6 +50,23 @@ use leptos_router::{
     path,
 };
 
+/// format of `<input type="datetime-local">`
+const DATETIME_LOCAL: &str = "%Y-%m-%dT%H:%M";
+
+/// Parse the value of a `datetime-local` input in local time; `None`, if empty or invalid.
+pub(crate) fn parse_datetime_local(value: &str) -> Option<DateTime<Utc>> {
+    NaiveDateTime::parse_from_str(value, DATETIME_LOCAL)
+        .ok()
+        .and_then(|t| t.and_local_timezone(Local).earliest())
+        .map(|t| t.with_timezone(&Utc))
+}
+
+/// Format `time` as value of a `datetime-local` input in local time; empty for `None`.
+pub(crate) fn format_datetime_local(time: Option<DateTime<Utc>>) -> String {
+    time.map(|t| t.with_timezone(&Local).format(DATETIME_LOCAL).to_string())
+        .unwrap_or_default()
+}
+
 #[component(transparent)]
 pub fn EditSubRoutes() -> impl MatchNestedRoutes + Clone {
     view! {
@@ -0,0 +1,396 @@
+//! officials (e.g. referees) of tournament and their workload
+
+use super::{format_datetime_local, parse_datetime_local};
+use app_core::{AvailabilityWindow, CrTopic, Official, OfficialAssignmentPlan};
+use app_utils::{
+    error::{AppError, ComponentError, strategy::handle_read_error},
+    hooks::use_on_cancel::use_on_cancel,
+    server_fn::official::{SaveOfficial, list_officials, plan_official_assignments},
+    state::{
+        activity_tracker::ActivityTracker, error_state::PageErrorContext, toast_state::ToastContext,
+    },
+};
+use cr_leptos_axum_socket::use_client_registry_socket;
+use leptos::prelude::*;
+use uuid::Uuid;
+
+#[component]
+pub fn OfficialsPanel(#[prop(into)] tournament_id: Signal<Option<Uuid>>) -> impl IntoView {
+    // --- global context and state ---
+    let page_err_ctx = expect_context::<PageErrorContext>();
+    let toast_ctx = expect_context::<ToastContext>();
+    let activity_tracker = expect_context::<ActivityTracker>();
+    let component_id = StoredValue::new(Uuid::new_v4());
+
+    on_cleanup(move || {
+        page_err_ctx.clear_all_for_component(component_id.get_value());
+        activity_tracker.remove_component(component_id.get_value());
+    });
+
+    // --- local state ---
+    // id and version of edited official; None creates a new official
+    let editing = RwSignal::new(None::<(Uuid, u32)>);
+    let name = RwSignal::new(String::new());
+    let club = RwSignal::new(String::new());
+    // comma separated qualifications, e.g. `referee, line judge`
+    let qualifications = RwSignal::new(String::new());
+    let availability = RwSignal::new(Vec::<AvailabilityWindow>::new());
+    let window_start = RwSignal::new(String::new());
+    let window_end = RwSignal::new(String::new());
+    // qualification required for the assignment to matches; empty for any official
+    let required_qualification = RwSignal::new(String::new());
+
+    let reset_form = move || {
+        editing.set(None);
+        name.set(String::new());
+        club.set(String::new());
+        qualifications.set(String::new());
+        availability.set(Vec::new());
+        window_start.set(String::new());
+        window_end.set(String::new());
+    };
+    let edit_official = move |official: &Official| {
+        editing.set(Some((
+            official.get_id(),
+            official.get_version().unwrap_or_default(),
+        )));
+        name.set(official.get_name().to_string());
+        club.set(official.get_club().unwrap_or_default().to_string());
+        qualifications.set(official.get_qualifications().join(", "));
+        availability.set(official.get_availability().to_vec());
+    };
+
+    let officials = Resource::new(
+        move || (tournament_id.get(), required_qualification.get()),
+        move |(t_id, qualification)| async move {
+            match t_id {
+                Some(t_id) => {
+                    let load = async move {
+                        let list = list_officials(t_id).await?;
+                        let plan = plan_official_assignments(t_id, Some(qualification)).await?;
+                        Ok::<_, AppError>((list, plan))
+                    };
+                    activity_tracker
+                        .track_activity_wrapper(component_id.get_value(), load)
+                        .await
+                        .map_err(|app_error| {
+                            ComponentError::new(component_id.get_value(), app_error)
+                        })
+                }
+                None => Ok((vec![], OfficialAssignmentPlan::default())),
+            }
+        },
+    );
+
+    let refetch = Callback::new(move |()| officials.refetch());
+    page_err_ctx.register_retry_handler(component_id.get_value(), refetch);
+
+    // Subscribe to official changes of this tournament
+    let topic = Signal::derive(move || {
+        tournament_id
+            .get()
+            .map(|tournament_id| CrTopic::Officials { tournament_id })
+    });
+    use_client_registry_socket(topic, None.into(), refetch);
+
+    let save_official = ServerAction::<SaveOfficial>::new();
+    activity_tracker.track_pending_memo(component_id.get_value(), save_official.pending());
+    Effect::new(move || match save_official.value().get() {
+        Some(Ok(saved)) => {
+            toast_ctx.success(format!("Official '{}' saved.", saved.get_name()), None);
+            reset_form();
+            officials.refetch();
+        }
+        Some(Err(err)) => {
+            toast_ctx.error(format!("Could not save official: {err}"), None);
+        }
+        None => {}
+    });
+
+    let on_submit = move || {
+        let Some(tournament_id) = tournament_id.get_untracked() else {
+            return;
+        };
+        if name.get_untracked().trim().is_empty() {
+            toast_ctx.warning("Name of official is required.", None);
+            return;
+        }
+        let (id, version) = match editing.get_untracked() {
+            Some((id, version)) => (Some(id), version),
+            None => (None, 0),
+        };
+        save_official.dispatch(SaveOfficial {
+            tournament_id,
+            id,
+            version,
+            name: name.get_untracked(),
+            club: Some(club.get_untracked()),
+            qualifications: qualifications
+                .get_untracked()
+                .split(',')
+                .map(|q| q.trim().to_string())
+                .collect(),
+            availability: availability.get_untracked(),
+        });
+    };
+
+    let add_window = move |_| match (
+        parse_datetime_local(&window_start.get_untracked()),
+        parse_datetime_local(&window_end.get_untracked()),
+    ) {
+        (Some(start), Some(end)) if start < end => {
+            availability.update(|a| {
+                a.push(AvailabilityWindow::new(start, end));
+                a.sort_by_key(|w| w.start);
+            });
+            window_start.set(String::new());
+            window_end.set(String::new());
+        }
+        _ => toast_ctx.warning("End of availability must be after its start.", None),
+    };
+
+    let on_cancel = use_on_cancel();
+
+    view! {
+        <div id="officials" class="card w-full bg-base-100 shadow-xl" data-testid="officials-root">
+            <div class="card-body">
+                <h2 class="card-title">"Officials"</h2>
+                <p class="text-sm opacity-70">
+                    "Officials are assigned to matches, which do not involve their own club. Officials without availability are always available."
+                </p>
+                <form
+                    class="flex flex-col gap-2"
+                    on:submit=move |ev| {
+                        ev.prevent_default();
+                        on_submit();
+                    }
+                >
+                    <div class="flex flex-wrap items-end gap-4">
+                        <label class="form-control">
+                            <span class="label-text">"Name"</span>
+                            <input
+                                type="text"
+                                class="input input-bordered w-full md:w-64"
+                                data-testid="input-official-name"
+                                prop:value=name
+                                on:input=move |ev| name.set(event_target_value(&ev))
+                            />
+                        </label>
+                        <label class="form-control">
+                            <span class="label-text">"Club"</span>
+                            <input
+                                type="text"
+                                class="input input-bordered w-full md:w-64"
+                                data-testid="input-official-club"
+                                prop:value=club
+                                on:input=move |ev| club.set(event_target_value(&ev))
+                            />
+                        </label>
+                        <label class="form-control">
+                            <span class="label-text">"Qualifications (comma separated)"</span>
+                            <input
+                                type="text"
+                                class="input input-bordered w-full md:w-64"
+                                data-testid="input-official-qualifications"
+                                prop:value=qualifications
+                                on:input=move |ev| qualifications.set(event_target_value(&ev))
+                            />
+                        </label>
+                    </div>
+                    <div class="flex flex-wrap items-end gap-4">
+                        <label class="form-control">
+                            <span class="label-text">"Available from"</span>
+                            <input
+                                type="datetime-local"
+                                class="input input-bordered"
+                                data-testid="input-official-available-from"
+                                prop:value=window_start
+                                on:change=move |ev| window_start.set(event_target_value(&ev))
+                            />
+                        </label>
+                        <label class="form-control">
+                            <span class="label-text">"Available until"</span>
+                            <input
+                                type="datetime-local"
+                                class="input input-bordered"
+                                data-testid="input-official-available-until"
+                                prop:value=window_end
+                                on:change=move |ev| window_end.set(event_target_value(&ev))
+                            />
+                        </label>
+                        <button
+                            type="button"
+                            class="btn btn-sm"
+                            data-testid="action-btn-add-availability"
+                            on:click=add_window
+                        >
+                            "Add Availability"
+                        </button>
+                    </div>
+                    <ul class="list-disc list-inside text-sm" data-testid="official-availability-list">
+                        {move || {
+                            availability
+                                .get()
+                                .into_iter()
+                                .enumerate()
+                                .map(|(index, window)| {
+                                    view! {
+                                        <li>
+                                            {format!(
+                                                "{} - {}",
+                                                format_datetime_local(Some(window.start)),
+                                                format_datetime_local(Some(window.end)),
+                                            )}
+                                            <button
+                                                type="button"
+                                                class="btn btn-ghost btn-xs"
+                                                data-testid=format!("action-btn-remove-availability-{}", index + 1)
+                                                on:click=move |_| {
+                                                    availability
+                                                        .update(|a| {
+                                                            if index < a.len() {
+                                                                a.remove(index);
+                                                            }
+                                                        })
+                                                }
+                                            >
+                                                "Remove"
+                                            </button>
+                                        </li>
+                                    }
+                                })
+                                .collect_view()
+                        }}
+                    </ul>
+                    <div class="flex gap-2">
+                        <button
+                            type="submit"
+                            class="btn btn-primary btn-sm"
+                            data-testid="action-btn-save-official"
+                            disabled=move || {
+                                save_official.pending().get() || tournament_id.get().is_none()
+                            }
+                        >
+                            {move || if editing.get().is_some() { "Save Official" } else { "Add Official" }}
+                        </button>
+                        <Show when=move || editing.get().is_some()>
+                            <button
+                                type="button"
+                                class="btn btn-ghost btn-sm"
+                                data-testid="action-btn-cancel-official"
+                                on:click=move |_| reset_form()
+                            >
+                                "Cancel"
+                            </button>
+                        </Show>
+                    </div>
+                </form>
+                <label class="form-control w-full md:w-64">
+                    <span class="label-text">"Required qualification"</span>
+                    <input
+                        type="text"
+                        class="input input-bordered input-sm"
+                        data-testid="input-official-required-qualification"
+                        prop:value=required_qualification
+                        on:change=move |ev| required_qualification.set(event_target_value(&ev))
+                    />
+                </label>
+                <Transition fallback=move || {
+                    view! { <span class="loading loading-spinner loading-md"></span> }
+                }>
+                    <ErrorBoundary fallback=move |errors| {
+                        for (_err_id, err) in errors.get().into_iter() {
+                            if let Some(comp_err) = err.into_inner().downcast_ref::<ComponentError>() {
+                                handle_read_error(&page_err_ctx, comp_err, on_cancel);
+                            }
+                        }
+                    }>
+                        {move || {
+                            officials
+                                .and_then(|(list, plan)| {
+                                    let list = list.clone();
+                                    let plan = StoredValue::new(plan.clone());
+                                    let num_unassigned = plan.with_value(|p| p.unassigned.len());
+                                    view! {
+                                        <Show when=move || { num_unassigned > 0 }>
+                                            <div
+                                                role="alert"
+                                                class="alert alert-warning"
+                                                data-testid="officials-unassigned"
+                                            >
+                                                {format!("{num_unassigned} matches without eligible official.")}
+                                            </div>
+                                        </Show>
+                                        <table class="table table-sm" data-testid="officials-table">
+                                            <thead>
+                                                <tr>
+                                                    <th>"Name"</th>
+                                                    <th>"Club"</th>
+                                                    <th>"Qualifications"</th>
+                                                    <th>"Availability"</th>
+                                                    <th>"Matches"</th>
+                                                    <th></th>
+                                                </tr>
+                                            </thead>
+                                            <tbody>
+                                                <For
+                                                    each=move || list.clone()
+                                                    key=|o| (o.get_id(), o.get_version())
+                                                    children=move |official| {
+                                                        let id = official.get_id();
+                                                        let num_matches = plan
+                                                            .with_value(|p| {
+                                                                p.workload
+                                                                    .iter()
+                                                                    .find(|w| w.official_id == id)
+                                                                    .map_or(0, |w| w.num_matches)
+                                                            });
+                                                        let num_windows = official.get_availability().len();
+                                                        let official = StoredValue::new(official);
+                                                        view! {
+                                                            <tr
+                                                                data-testid="officials-row"
+                                                                class:bg-base-200=move || {
+                                                                    editing.get().map(|(e_id, _)| e_id) == Some(id)
+                                                                }
+                                                            >
+                                                                <td>{official.with_value(|o| o.get_name().to_string())}</td>
+                                                                <td>
+                                                                    {official
+                                                                        .with_value(|o| o.get_club().unwrap_or_default().to_string())}
+                                                                </td>
+                                                                <td>
+                                                                    {official.with_value(|o| o.get_qualifications().join(", "))}
+                                                                </td>
+                                                                <td>
+                                                                    {if num_windows == 0 {
+                                                                        "always".to_string()
+                                                                    } else {
+                                                                        format!("{num_windows} windows")
+                                                                    }}
+                                                                </td>
+                                                                <td>{num_matches}</td>
+                                                                <td>
+                                                                    <button
+                                                                        class="btn btn-xs"
+                                                                        data-testid="action-btn-edit-official"
+                                                                        on:click=move |_| official.with_value(edit_official)
+                                                                    >
+                                                                        "Edit"
+                                                                    </button>
+                                                                </td>
+                                                            </tr>
+                                                        }
+                                                    }
+                                                />
+                                            </tbody>
+                                        </table>
+                                    }
+                                })
+                        }}
+                    </ErrorBoundary>
+                </Transition>
+            </div>
+        </div>
+    }
+}
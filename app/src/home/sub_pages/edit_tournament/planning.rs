@@ -0,0 +1,188 @@
+//! suggestions of tournament modes and group sizes for a new tournament
+
+use app_core::{
+    PlanSuggestion, PlanningRequest, SportConfig, TournamentMode, suggest_plans,
+    utils::id_version::IdVersion,
+};
+use app_utils::{
+    params::{ParamQuery, SportIdQuery},
+    state::{
+        global_state::{GlobalState, GlobalStateStoreFields},
+        tournament::TournamentEditorContext,
+    },
+};
+use chrono::Duration;
+use leptos::prelude::*;
+use reactive_stores::Store;
+
+/// Estimated match duration in minutes of the default configuration of the sport of the
+/// current route.
+pub(crate) fn use_default_match_minutes() -> i64 {
+    let state = expect_context::<Store<GlobalState>>();
+    SportIdQuery::use_param_query()
+        .get_untracked()
+        .and_then(|sport_id| {
+            let plugin = state
+                .sport_plugin_manager()
+                .get_untracked()
+                .get_web_ui(&sport_id)?;
+            let mut config = SportConfig::new(IdVersion::default());
+            config
+                .set_sport_id(sport_id)
+                .set_config(plugin.get_default_config());
+            plugin.estimate_match_duration(&config).ok()
+        })
+        .map_or(20, |duration| (duration.as_secs() / 60).max(1) as i64)
+}
+
+/// Group sizes of each stage, matches and estimated duration of `suggestion`, e.g.
+/// `groups 6/6/6/6 → 4/4/4/4/4/4, ≥ 6 matches per entrant, 78 matches, 6:40 h`
+pub(crate) fn plan_summary(suggestion: &PlanSuggestion) -> String {
+    let groups = suggestion
+        .group_sizes
+        .iter()
+        .map(|sizes| {
+            sizes
+                .iter()
+                .map(u32::to_string)
+                .collect::<Vec<_>>()
+                .join("/")
+        })
+        .collect::<Vec<_>>()
+        .join(" → ");
+    let minutes = suggestion.estimated_duration.num_minutes();
+    format!(
+        "groups {groups}, ≥ {} matches per entrant, {} matches, {}:{:02} h",
+        suggestion.min_matches_per_entrant,
+        suggestion.num_matches,
+        minutes / 60,
+        minutes % 60,
+    )
+}
+
+#[component]
+pub fn PlanningSuggestions(
+    /// callback to save the tournament base after a change
+    on_submit: Callback<()>,
+) -> impl IntoView {
+    let base_editor = expect_context::<TournamentEditorContext>().base_editor;
+
+    let min_matches = RwSignal::new(3_u32);
+    let available_hours = RwSignal::new(6_i64);
+    let match_minutes = RwSignal::new(use_default_match_minutes());
+
+    let suggestions = Signal::derive(move || {
+        suggest_plans(&PlanningRequest {
+            num_entrants: base_editor.num_entrants.get().unwrap_or_default(),
+            num_stations: base_editor.num_stations.get().unwrap_or_default(),
+            available_time: Duration::hours(available_hours.get()),
+            match_duration: Duration::minutes(match_minutes.get()),
+            min_matches: min_matches.get(),
+        })
+    });
+
+    let on_apply = move |mode: TournamentMode| {
+        base_editor.set_mode.run(Some(mode));
+        on_submit.run(());
+    };
+
+    view! {
+        <div class="md:col-span-2 flex flex-col gap-4" data-testid="planning-suggestions">
+            <span class="label-text font-semibold">"Planning Suggestions"</span>
+            <div class="flex flex-wrap items-end gap-4">
+                <label class="form-control">
+                    <span class="label-text">"Minimum matches per entrant"</span>
+                    <input
+                        type="number"
+                        min="1"
+                        class="input input-bordered w-24"
+                        data-testid="input-planning-min-matches"
+                        prop:value=move || min_matches.get().to_string()
+                        on:input=move |ev| {
+                            min_matches.set(event_target_value(&ev).parse().unwrap_or_default());
+                        }
+                    />
+                </label>
+                <label class="form-control">
+                    <span class="label-text">"Available time (hours)"</span>
+                    <input
+                        type="number"
+                        min="1"
+                        class="input input-bordered w-24"
+                        data-testid="input-planning-available-hours"
+                        prop:value=move || available_hours.get().to_string()
+                        on:input=move |ev| {
+                            available_hours
+                                .set(event_target_value(&ev).parse().unwrap_or_default());
+                        }
+                    />
+                </label>
+                <label class="form-control">
+                    <span class="label-text">"Match duration (minutes)"</span>
+                    <input
+                        type="number"
+                        min="1"
+                        class="input input-bordered w-24"
+                        data-testid="input-planning-match-minutes"
+                        prop:value=move || match_minutes.get().to_string()
+                        on:input=move |ev| {
+                            match_minutes.set(event_target_value(&ev).parse().unwrap_or_default());
+                        }
+                    />
+                </label>
+            </div>
+            <Show
+                when=move || suggestions.with(|s| !s.is_empty())
+                fallback=|| {
+                    view! {
+                        <p class="text-sm opacity-70" data-testid="planning-no-suggestions">
+                            "No mode meets the minimum number of matches with these entrants."
+                        </p>
+                    }
+                }
+            >
+                <ul class="flex flex-col gap-2" data-testid="planning-suggestion-list">
+                    {move || {
+                        suggestions
+                            .get()
+                            .into_iter()
+                            .enumerate()
+                            .map(|(index, suggestion)| {
+                                let number = index + 1;
+                                let mode = suggestion.mode;
+                                view! {
+                                    <li
+                                        class="flex flex-wrap items-center gap-2"
+                                        data-testid=format!("planning-suggestion-{number}")
+                                    >
+                                        <span class="font-medium">{mode.to_string()}</span>
+                                        <span class="text-sm">{plan_summary(&suggestion)}</span>
+                                        <span
+                                            class="badge badge-sm"
+                                            class:badge-success=suggestion.fits_available_time
+                                            class:badge-warning=!suggestion.fits_available_time
+                                        >
+                                            {if suggestion.fits_available_time {
+                                                "fits"
+                                            } else {
+                                                "too long"
+                                            }}
+                                        </span>
+                                        <button
+                                            type="button"
+                                            class="btn btn-xs btn-ghost"
+                                            data-testid=format!("action-btn-apply-suggestion-{number}")
+                                            on:click=move |_| on_apply(mode)
+                                        >
+                                            "Apply"
+                                        </button>
+                                    </li>
+                                }
+                            })
+                            .collect_view()
+                    }}
+                </ul>
+            </Show>
+        </div>
+    }
+}
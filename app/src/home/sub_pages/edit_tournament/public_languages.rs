@@ -0,0 +1,171 @@
+//! display languages and description of public pages of a tournament
+
+use app_core::Language;
+use app_utils::state::tournament::TournamentEditorContext;
+use leptos::prelude::*;
+
+#[component]
+pub fn PublicLanguagesFields(
+    /// callback to save the tournament base after a change
+    on_submit: Callback<()>,
+) -> impl IntoView {
+    let tournament_editor = expect_context::<TournamentEditorContext>();
+    let base_editor = tournament_editor.base_editor;
+
+    let languages = Signal::derive(move || base_editor.languages.get().unwrap_or_default());
+    // without declared languages public pages are displayed in the default language only
+    let display_languages = Signal::derive(move || {
+        let languages = languages.get();
+        if languages.is_empty() {
+            vec![Language::default()]
+        } else {
+            languages
+        }
+    });
+
+    let toggle_language = move |language: Language, checked: bool| {
+        let mut languages = display_languages.get_untracked();
+        if checked {
+            languages.push(language);
+        } else {
+            languages.retain(|l| *l != language);
+        }
+        base_editor.set_languages.run(languages);
+        on_submit.run(());
+    };
+    let set_default_language = move |language: Language| {
+        let mut languages = display_languages.get_untracked();
+        let former_default = languages.first().copied().unwrap_or_default();
+        // translation of the new default language becomes the text of the description
+        let mut description = base_editor.description.get_untracked().unwrap_or_default();
+        if let Some(translation) = description.get_translation(language).map(str::to_string) {
+            let text = description.get_text().to_string();
+            description
+                .set_text(translation)
+                .set_translation(former_default, text);
+            base_editor.set_description.run(description);
+        }
+        languages.retain(|l| *l != language);
+        languages.insert(0, language);
+        base_editor.set_languages.run(languages);
+        on_submit.run(());
+    };
+    let set_description_text = move |language: Option<Language>, text: String| {
+        let mut description = base_editor.description.get_untracked().unwrap_or_default();
+        match language {
+            None => description.set_text(text),
+            Some(language) => description.set_translation(language, text),
+        };
+        base_editor.set_description.run(description);
+        on_submit.run(());
+    };
+
+    view! {
+        <div class="md:col-span-2 flex flex-col gap-4" data-testid="public-languages-fields">
+            <div class="form-control w-full">
+                <label class="label">
+                    <span class="label-text">"Languages of public pages"</span>
+                </label>
+                <div class="flex flex-wrap gap-4">
+                    {Language::ALL
+                        .into_iter()
+                        .map(|language| {
+                            view! {
+                                <label class="label cursor-pointer gap-2">
+                                    <input
+                                        type="checkbox"
+                                        class="checkbox checkbox-sm"
+                                        data-testid=format!("input-tournament-language-{}", language.code())
+                                        prop:checked=move || display_languages.get().contains(&language)
+                                        prop:disabled=move || {
+                                            display_languages.get() == vec![language]
+                                        }
+                                        on:change=move |ev| {
+                                            toggle_language(language, event_target_checked(&ev))
+                                        }
+                                    />
+                                    <span class="label-text">{language.to_string()}</span>
+                                </label>
+                            }
+                        })
+                        .collect_view()}
+                </div>
+            </div>
+            <div class="form-control w-full">
+                <label class="label">
+                    <span class="label-text">"Default language"</span>
+                </label>
+                <select
+                    class="select select-bordered w-full"
+                    data-testid="select-tournament-default-language"
+                    prop:value=move || {
+                        display_languages.get().first().copied().unwrap_or_default().code()
+                    }
+                    on:change=move |ev| {
+                        if let Ok(language) = event_target_value(&ev).parse::<Language>() {
+                            set_default_language(language);
+                        }
+                    }
+                >
+                    {move || {
+                        display_languages
+                            .get()
+                            .into_iter()
+                            .enumerate()
+                            .map(|(index, language)| {
+                                view! {
+                                    <option value=language.code() selected=index == 0>
+                                        {language.to_string()}
+                                    </option>
+                                }
+                            })
+                            .collect_view()
+                    }}
+                </select>
+            </div>
+            <For
+                each=move || display_languages.get().into_iter().enumerate()
+                key=|(index, language)| (*index == 0, *language)
+                children=move |(index, language)| {
+                    // description of the default language is the text, all others are translations
+                    let translation = (index > 0).then_some(language);
+                    let value = move || {
+                        base_editor
+                            .description
+                            .get()
+                            .map(|d| match translation {
+                                None => d.get_text().to_string(),
+                                Some(language) => {
+                                    d.get_translation(language).unwrap_or_default().to_string()
+                                }
+                            })
+                            .unwrap_or_default()
+                    };
+                    let label = match translation {
+                        None => format!("Description ({language})"),
+                        Some(_) => format!("Description ({language}, optional)"),
+                    };
+                    view! {
+                        <div class="form-control w-full">
+                            <label class="label">
+                                <span class="label-text">{label}</span>
+                            </label>
+                            <textarea
+                                class="textarea textarea-bordered w-full"
+                                rows="3"
+                                data-testid=format!(
+                                    "input-tournament-description-{}",
+                                    language.code(),
+                                )
+                                prop:value=value
+                                on:change=move |ev| {
+                                    set_description_text(translation, event_target_value(&ev))
+                                }
+                            ></textarea>
+                        </div>
+                    }
+                }
+            />
+        </div>
+    }
+}
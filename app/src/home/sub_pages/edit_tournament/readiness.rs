@@ -0,0 +1,257 @@
+//! readiness checklist, which gates publishing and starting a tournament, and links to the
+//! final report of finished tournaments
+
+use app_core::{
+    CoreError, CrTopic, ReadinessCheck, ReadinessItem, ReadinessStatus, TournamentState,
+};
+use app_utils::{
+    error::{AppError, ComponentError, strategy::handle_read_error},
+    hooks::{
+        use_on_cancel::use_on_cancel,
+        use_url_navigation::{UseQueryNavigationReturn, use_query_navigation},
+    },
+    server_fn::tournament_base::{
+        ChangeTournamentState, load_tournament_base, load_tournament_readiness,
+    },
+    state::{
+        activity_tracker::ActivityTracker, error_state::PageErrorContext, toast_state::ToastContext,
+    },
+};
+use cr_leptos_axum_socket::use_client_registry_socket;
+use leptos::prelude::*;
+use leptos_router::components::A;
+use uuid::Uuid;
+
+#[component]
+pub fn ReadinessPanel(#[prop(into)] tournament_id: Signal<Option<Uuid>>) -> impl IntoView {
+    // --- global context and state ---
+    let page_err_ctx = expect_context::<PageErrorContext>();
+    let toast_ctx = expect_context::<ToastContext>();
+    let activity_tracker = expect_context::<ActivityTracker>();
+    let component_id = StoredValue::new(Uuid::new_v4());
+
+    on_cleanup(move || {
+        page_err_ctx.clear_all_for_component(component_id.get_value());
+        activity_tracker.remove_component(component_id.get_value());
+    });
+
+    let UseQueryNavigationReturn {
+        url_update_path, ..
+    } = use_query_navigation();
+
+    // tournament and its checklist
+    let readiness = Resource::new(
+        move || tournament_id.get(),
+        move |t_id| async move {
+            let Some(t_id) = t_id else {
+                return Ok(None);
+            };
+            activity_tracker
+                .track_activity_wrapper(component_id.get_value(), async move {
+                    let Some(tournament) = load_tournament_base(t_id).await? else {
+                        return Ok(None);
+                    };
+                    let checklist = load_tournament_readiness(t_id).await?;
+                    Ok::<_, AppError>(Some((tournament, checklist)))
+                })
+                .await
+                .map_err(|app_error| ComponentError::new(component_id.get_value(), app_error))
+        },
+    );
+
+    let refetch = Callback::new(move |()| readiness.refetch());
+    page_err_ctx.register_retry_handler(component_id.get_value(), refetch);
+
+    // re-evaluate on changes of tournament and entrants
+    let tournament_topic = Signal::derive(move || {
+        tournament_id
+            .get()
+            .map(|tournament_base_id| CrTopic::TournamentBase { tournament_base_id })
+    });
+    use_client_registry_socket(tournament_topic, None.into(), refetch);
+    let entrants_topic = Signal::derive(move || {
+        tournament_id
+            .get()
+            .map(|tournament_id| CrTopic::Entrants { tournament_id })
+    });
+    use_client_registry_socket(entrants_topic, None.into(), refetch);
+
+    let change_state = ServerAction::<ChangeTournamentState>::new();
+    activity_tracker.track_pending_memo(component_id.get_value(), change_state.pending());
+    Effect::new(move || match change_state.value().get() {
+        Some(Ok(tournament)) => {
+            toast_ctx.success(
+                format!(
+                    "{} is now {}.",
+                    tournament.get_name(),
+                    tournament.get_tournament_state()
+                ),
+                None,
+            );
+            readiness.refetch();
+        }
+        Some(Err(AppError::Core(CoreError::Validation(_)))) => {
+            toast_ctx.warning("Tournament is not ready. Fix the failed items first.", None);
+            readiness.refetch();
+        }
+        Some(Err(err)) => {
+            toast_ctx.error(format!("Could not change tournament state: {err}"), None);
+        }
+        None => {}
+    });
+
+    // link to the page, where a failed item is fixed
+    let fix_link = move |item: &ReadinessItem| match item.check {
+        ReadinessCheck::SportConfig => Some(url_update_path("/sport-configurations")),
+        ReadinessCheck::Stages => Some(url_update_path(&format!(
+            "/tournaments/edit/{}",
+            item.stage_number.unwrap_or_default()
+        ))),
+        ReadinessCheck::Entrants => Some(String::from("#entrants")),
+        ReadinessCheck::Schedule | ReadinessCheck::Stations => None,
+    };
+
+    let on_cancel = use_on_cancel();
+
+    view! {
+        <div class="card w-full bg-base-100 shadow-xl" data-testid="readiness-root">
+            <div class="card-body">
+                <h2 class="card-title">"Readiness"</h2>
+                <Transition fallback=move || {
+                    view! { <span class="loading loading-spinner loading-md"></span> }
+                }>
+                    <ErrorBoundary fallback=move |errors| {
+                        for (_err_id, err) in errors.get().into_iter() {
+                            if let Some(comp_err) = err.into_inner().downcast_ref::<ComponentError>() {
+                                handle_read_error(&page_err_ctx, comp_err, on_cancel);
+                            }
+                        }
+                    }>
+                        {move || {
+                            readiness
+                                .and_then(|loaded| {
+                                    loaded
+                                        .clone()
+                                        .map(|(tournament, checklist)| {
+                                            // sandbox tournaments are not gated by the checklist
+                                            let is_ready = checklist.is_ready() || tournament.is_sandbox();
+                                            let state = tournament.get_tournament_state();
+                                            let id = tournament.get_id();
+                                            let version = tournament.get_version().unwrap_or_default();
+                                            view! {
+                                                <ul class="flex flex-col gap-2" data-testid="readiness-checklist">
+                                                    {checklist
+                                                        .items
+                                                        .into_iter()
+                                                        .map(|item| {
+                                                            let (icon, badge) = match item.status {
+                                                                ReadinessStatus::Passed => {
+                                                                    ("icon-[heroicons--check-circle] text-success", "")
+                                                                }
+                                                                ReadinessStatus::Failed => {
+                                                                    ("icon-[heroicons--x-circle] text-error", "")
+                                                                }
+                                                                ReadinessStatus::Unavailable => {
+                                                                    ("icon-[heroicons--minus-circle] text-base-content/50", "not available yet")
+                                                                }
+                                                            };
+                                                            let link = item.is_failed().then(|| fix_link(&item)).flatten();
+                                                            view! {
+                                                                <li
+                                                                    class="flex items-center gap-2"
+                                                                    data-testid=format!("readiness-item-{:?}", item.check)
+                                                                >
+                                                                    <span class=format!("{icon} w-5 h-5")></span>
+                                                                    <span class="font-semibold">{item.check.to_string()}</span>
+                                                                    <span class="text-base-content/70">{item.message.clone()}</span>
+                                                                    {(!badge.is_empty())
+                                                                        .then(|| {
+                                                                            view! { <span class="badge badge-ghost">{badge}</span> }
+                                                                        })}
+                                                                    {link
+                                                                        .map(|href| {
+                                                                            view! {
+                                                                                <A
+                                                                                    href=href
+                                                                                    attr:class="btn btn-xs btn-outline"
+                                                                                    attr:data-testid="readiness-fix-link"
+                                                                                    scroll=false
+                                                                                >
+                                                                                    "Fix"
+                                                                                </A>
+                                                                            }
+                                                                        })}
+                                                                </li>
+                                                            }
+                                                        })
+                                                        .collect_view()}
+                                                </ul>
+                                                <div class="card-actions justify-end">
+                                                    <Show when=move || state == TournamentState::Draft>
+                                                        <button
+                                                            class="btn btn-primary btn-sm"
+                                                            data-testid="action-btn-publish-tournament"
+                                                            disabled=move || !is_ready || change_state.pending().get()
+                                                            on:click=move |_| {
+                                                                change_state
+                                                                    .dispatch(ChangeTournamentState {
+                                                                        id,
+                                                                        version,
+                                                                        state: TournamentState::Published,
+                                                                    });
+                                                            }
+                                                        >
+                                                            "Publish"
+                                                        </button>
+                                                    </Show>
+                                                    <Show when=move || {
+                                                        matches!(state, TournamentState::Draft | TournamentState::Published)
+                                                    }>
+                                                        <button
+                                                            class="btn btn-secondary btn-sm"
+                                                            data-testid="action-btn-start-tournament"
+                                                            disabled=move || !is_ready || change_state.pending().get()
+                                                            on:click=move |_| {
+                                                                change_state
+                                                                    .dispatch(ChangeTournamentState {
+                                                                        id,
+                                                                        version,
+                                                                        state: TournamentState::ActiveStage(0),
+                                                                    });
+                                                            }
+                                                        >
+                                                            "Start"
+                                                        </button>
+                                                    </Show>
+                                                    // final report is served by the server; external links bypass the router
+                                                    <Show when=move || state == TournamentState::Finished>
+                                                        <a
+                                                            class="btn btn-sm btn-outline"
+                                                            href=format!("/api/tournament/{id}/final_report.pdf")
+                                                            target="_blank"
+                                                            rel="external"
+                                                            data-testid="action-btn-final-report-pdf"
+                                                        >
+                                                            "Final Report (PDF)"
+                                                        </a>
+                                                        <a
+                                                            class="btn btn-sm btn-outline"
+                                                            href=format!("/api/tournament/{id}/final_report.html")
+                                                            target="_blank"
+                                                            rel="external"
+                                                            data-testid="action-btn-final-report-html"
+                                                        >
+                                                            "Final Report (HTML)"
+                                                        </a>
+                                                    </Show>
+                                                </div>
+                                            }
+                                        })
+                                })
+                        }}
+                    </ErrorBoundary>
+                </Transition>
+            </div>
+        </div>
+    }
+}
@@ -0,0 +1,399 @@
+//! access links of scorekeepers for result entry without accounts
+
+use app_core::{CrTopic, ScorekeeperGrant};
+use app_utils::{
+    error::{ComponentError, strategy::handle_read_error},
+    hooks::use_on_cancel::use_on_cancel,
+    server_fn::scorekeeper::{
+        CreateScorekeeperToken, RevokeScorekeeperToken, list_scorekeeper_tokens,
+    },
+    state::{
+        activity_tracker::ActivityTracker, error_state::PageErrorContext, toast_state::ToastContext,
+    },
+};
+use cr_leptos_axum_socket::use_client_registry_socket;
+use leptos::prelude::*;
+use uuid::Uuid;
+
+/// default validity of new access links; a tournament day
+const DEFAULT_VALID_FOR_HOURS: u32 = 24;
+
+#[component]
+pub fn ScorekeepersPanel(#[prop(into)] tournament_id: Signal<Option<Uuid>>) -> impl IntoView {
+    // --- global context and state ---
+    let page_err_ctx = expect_context::<PageErrorContext>();
+    let toast_ctx = expect_context::<ToastContext>();
+    let activity_tracker = expect_context::<ActivityTracker>();
+    let component_id = StoredValue::new(Uuid::new_v4());
+
+    on_cleanup(move || {
+        page_err_ctx.clear_all_for_component(component_id.get_value());
+        activity_tracker.remove_component(component_id.get_value());
+    });
+
+    // --- local state ---
+    let label = RwSignal::new(String::new());
+    let grants = RwSignal::new(Vec::<ScorekeeperGrant>::new());
+    // stage and station inputs start with 1, group input is the group letter
+    let stage_input = RwSignal::new(1_u32);
+    let group_input = RwSignal::new(String::from("A"));
+    let station_input = RwSignal::new(1_u32);
+    let valid_for_hours = RwSignal::new(Some(DEFAULT_VALID_FOR_HOURS));
+    let include_revoked = RwSignal::new(false);
+    // access link of the last created token; shown only once
+    let new_link = RwSignal::new(None::<String>);
+
+    let tokens = Resource::new(
+        move || (tournament_id.get(), include_revoked.get()),
+        move |(t_id, include_revoked)| async move {
+            match t_id {
+                Some(t_id) => activity_tracker
+                    .track_activity_wrapper(
+                        component_id.get_value(),
+                        list_scorekeeper_tokens(t_id, include_revoked),
+                    )
+                    .await
+                    .map_err(|app_error| ComponentError::new(component_id.get_value(), app_error)),
+                None => Ok(vec![]),
+            }
+        },
+    );
+
+    let refetch = Callback::new(move |()| tokens.refetch());
+    page_err_ctx.register_retry_handler(component_id.get_value(), refetch);
+
+    // Subscribe to scorekeeper token changes of this tournament
+    let topic = Signal::derive(move || {
+        tournament_id
+            .get()
+            .map(|tournament_id| CrTopic::ScorekeeperTokens { tournament_id })
+    });
+    use_client_registry_socket(topic, None.into(), refetch);
+
+    let create_token = ServerAction::<CreateScorekeeperToken>::new();
+    activity_tracker.track_pending_memo(component_id.get_value(), create_token.pending());
+    Effect::new(move || match create_token.value().get() {
+        Some(Ok(created)) => {
+            let origin = window().location().origin().unwrap_or_default();
+            new_link.set(Some(format!("{origin}/score/{}", created.secret)));
+            label.set(String::new());
+            grants.set(vec![]);
+            tokens.refetch();
+        }
+        Some(Err(err)) => {
+            toast_ctx.error(format!("Could not create scorekeeper link: {err}"), None);
+        }
+        None => {}
+    });
+
+    let revoke_token = ServerAction::<RevokeScorekeeperToken>::new();
+    activity_tracker.track_pending_memo(component_id.get_value(), revoke_token.pending());
+    Effect::new(move || match revoke_token.value().get() {
+        Some(Ok(revoked)) => {
+            toast_ctx.success(format!("Link '{}' revoked.", revoked.get_label()), None);
+            tokens.refetch();
+        }
+        Some(Err(err)) => {
+            toast_ctx.error(format!("Could not revoke scorekeeper link: {err}"), None);
+        }
+        None => {}
+    });
+
+    let add_grant = move |grant: ScorekeeperGrant| {
+        grants.update(|g| {
+            if !g.contains(&grant) {
+                g.push(grant);
+            }
+        });
+    };
+    let on_add_group = move |_| {
+        let group = group_input.get_untracked().trim().to_uppercase();
+        match group.as_bytes() {
+            [letter @ b'A'..=b'Z'] if stage_input.get_untracked() > 0 => {
+                add_grant(ScorekeeperGrant::Group {
+                    stage_number: stage_input.get_untracked() - 1,
+                    group_index: (letter - b'A') as u32,
+                })
+            }
+            _ => toast_ctx.warning("Enter a stage number and a group letter.", None),
+        }
+    };
+    let on_add_station = move |_| {
+        if station_input.get_untracked() > 0 {
+            add_grant(ScorekeeperGrant::Station {
+                station: station_input.get_untracked(),
+            });
+        }
+    };
+
+    let on_submit = move || {
+        let Some(tournament_id) = tournament_id.get_untracked() else {
+            return;
+        };
+        if label.get_untracked().trim().is_empty() || grants.get_untracked().is_empty() {
+            toast_ctx.warning(
+                "Label and at least one group or station are required.",
+                None,
+            );
+            return;
+        }
+        new_link.set(None);
+        create_token.dispatch(CreateScorekeeperToken {
+            tournament_id,
+            label: label.get_untracked(),
+            grants: grants.get_untracked(),
+            valid_for_hours: valid_for_hours.get_untracked(),
+        });
+    };
+
+    let on_cancel = use_on_cancel();
+
+    view! {
+        <div class="card w-full bg-base-100 shadow-xl" data-testid="scorekeepers-root">
+            <div class="card-body">
+                <h2 class="card-title">"Scorekeeper Links"</h2>
+                <form
+                    class="flex flex-col gap-2"
+                    on:submit=move |ev| {
+                        ev.prevent_default();
+                        on_submit();
+                    }
+                >
+                    <div class="flex flex-wrap items-end gap-4">
+                        <label class="form-control">
+                            <span class="label-text">"Label"</span>
+                            <input
+                                type="text"
+                                class="input input-bordered w-full md:w-64"
+                                data-testid="input-scorekeeper-label"
+                                prop:value=label
+                                on:input=move |ev| label.set(event_target_value(&ev))
+                            />
+                        </label>
+                        <label class="form-control">
+                            <span class="label-text">"Valid for hours (empty: until revoked)"</span>
+                            <input
+                                type="number"
+                                min="1"
+                                class="input input-bordered w-32"
+                                data-testid="input-scorekeeper-valid-for-hours"
+                                prop:value=move || {
+                                    valid_for_hours.get().map(|h| h.to_string()).unwrap_or_default()
+                                }
+                                on:input=move |ev| {
+                                    valid_for_hours.set(event_target_value(&ev).parse().ok());
+                                }
+                            />
+                        </label>
+                    </div>
+                    <div class="flex flex-wrap items-end gap-4">
+                        <label class="form-control">
+                            <span class="label-text">"Stage"</span>
+                            <input
+                                type="number"
+                                min="1"
+                                class="input input-bordered input-sm w-20"
+                                data-testid="input-scorekeeper-stage"
+                                prop:value=move || stage_input.get().to_string()
+                                on:input=move |ev| {
+                                    if let Ok(value) = event_target_value(&ev).parse() {
+                                        stage_input.set(value);
+                                    }
+                                }
+                            />
+                        </label>
+                        <label class="form-control">
+                            <span class="label-text">"Group"</span>
+                            <input
+                                type="text"
+                                maxlength="1"
+                                class="input input-bordered input-sm w-16"
+                                data-testid="input-scorekeeper-group"
+                                prop:value=group_input
+                                on:input=move |ev| group_input.set(event_target_value(&ev))
+                            />
+                        </label>
+                        <button
+                            type="button"
+                            class="btn btn-sm"
+                            data-testid="action-btn-scorekeeper-add-group"
+                            on:click=on_add_group
+                        >
+                            "Add Group"
+                        </button>
+                        <label class="form-control">
+                            <span class="label-text">"Station"</span>
+                            <input
+                                type="number"
+                                min="1"
+                                class="input input-bordered input-sm w-20"
+                                data-testid="input-scorekeeper-station"
+                                prop:value=move || station_input.get().to_string()
+                                on:input=move |ev| {
+                                    if let Ok(value) = event_target_value(&ev).parse() {
+                                        station_input.set(value);
+                                    }
+                                }
+                            />
+                        </label>
+                        <button
+                            type="button"
+                            class="btn btn-sm"
+                            data-testid="action-btn-scorekeeper-add-station"
+                            on:click=on_add_station
+                        >
+                            "Add Station"
+                        </button>
+                    </div>
+                    <div class="flex flex-wrap gap-2" data-testid="scorekeeper-grants">
+                        <For
+                            each=move || grants.get()
+                            key=|grant| *grant
+                            children=move |grant| {
+                                view! {
+                                    <span class="badge badge-outline gap-1">
+                                        {grant.to_string()}
+                                        <button
+                                            type="button"
+                                            class="btn btn-ghost btn-xs"
+                                            on:click=move |_| grants.update(|g| g.retain(|x| *x != grant))
+                                        >
+                                            "✕"
+                                        </button>
+                                    </span>
+                                }
+                            }
+                        />
+                    </div>
+                    <div>
+                        <button
+                            type="submit"
+                            class="btn btn-primary btn-sm"
+                            data-testid="action-btn-create-scorekeeper-link"
+                            disabled=move || create_token.pending().get()
+                        >
+                            "Create Link"
+                        </button>
+                    </div>
+                </form>
+                {move || {
+                    new_link
+                        .get()
+                        .map(|link| {
+                            view! {
+                                <div role="alert" class="alert alert-success flex-col items-start">
+                                    <span>
+                                        "Hand out this link now, it will not be shown again:"
+                                    </span>
+                                    <code class="break-all" data-testid="scorekeeper-link">
+                                        {link}
+                                    </code>
+                                </div>
+                            }
+                        })
+                }}
+                <label class="label cursor-pointer gap-2 justify-start">
+                    <input
+                        type="checkbox"
+                        class="checkbox checkbox-sm"
+                        data-testid="input-scorekeeper-include-revoked"
+                        prop:checked=include_revoked
+                        on:change=move |ev| include_revoked.set(event_target_checked(&ev))
+                    />
+                    <span class="label-text">"Show revoked links"</span>
+                </label>
+                <Transition fallback=move || {
+                    view! { <span class="loading loading-spinner loading-md"></span> }
+                }>
+                    <ErrorBoundary fallback=move |errors| {
+                        for (_err_id, err) in errors.get().into_iter() {
+                            if let Some(comp_err) = err.into_inner().downcast_ref::<ComponentError>() {
+                                handle_read_error(&page_err_ctx, comp_err, on_cancel);
+                            }
+                        }
+                    }>
+                        {move || {
+                            tokens
+                                .and_then(|list| {
+                                    let list = list.clone();
+                                    view! {
+                                        <table class="table table-sm" data-testid="scorekeepers-table">
+                                            <thead>
+                                                <tr>
+                                                    <th>"Label"</th>
+                                                    <th>"Link"</th>
+                                                    <th>"Groups / Stations"</th>
+                                                    <th>"Valid until"</th>
+                                                    <th>"Last used"</th>
+                                                    <th></th>
+                                                </tr>
+                                            </thead>
+                                            <tbody>
+                                                <For
+                                                    each=move || list.clone()
+                                                    key=|t| (t.get_id(), t.get_version())
+                                                    children=move |token| {
+                                                        let tournament_id = token.get_tournament_id();
+                                                        let id = token.get_id();
+                                                        let version = token.get_version().unwrap_or_default();
+                                                        let revoked = token.is_revoked();
+                                                        let grants = token
+                                                            .get_grants()
+                                                            .iter()
+                                                            .map(|g| g.to_string())
+                                                            .collect::<Vec<_>>()
+                                                            .join(", ");
+                                                        let expires = token
+                                                            .get_expires_at()
+                                                            .map(|t| t.format("%Y-%m-%d %H:%M").to_string())
+                                                            .unwrap_or_else(|| "until revoked".to_string());
+                                                        let last_used = token
+                                                            .get_last_used_at()
+                                                            .map(|t| t.format("%Y-%m-%d %H:%M").to_string())
+                                                            .unwrap_or_else(|| "never".to_string());
+                                                        view! {
+                                                            <tr data-testid="scorekeepers-row" class:opacity-50=revoked>
+                                                                <td>{token.get_label().to_string()}</td>
+                                                                <td>
+                                                                    <code>{format!("{}…", token.get_secret_prefix())}</code>
+                                                                </td>
+                                                                <td>{grants}</td>
+                                                                <td>{expires}</td>
+                                                                <td>{last_used}</td>
+                                                                <td>
+                                                                    <Show
+                                                                        when=move || !revoked
+                                                                        fallback=|| view! { <span class="badge">"revoked"</span> }
+                                                                    >
+                                                                        <button
+                                                                            class="btn btn-error btn-xs"
+                                                                            data-testid="action-btn-revoke-scorekeeper-link"
+                                                                            disabled=move || revoke_token.pending().get()
+                                                                            on:click=move |_| {
+                                                                                revoke_token
+                                                                                    .dispatch(RevokeScorekeeperToken {
+                                                                                        tournament_id,
+                                                                                        id,
+                                                                                        version,
+                                                                                    });
+                                                                            }
+                                                                        >
+                                                                            "Revoke"
+                                                                        </button>
+                                                                    </Show>
+                                                                </td>
+                                                            </tr>
+                                                        }
+                                                    }
+                                                />
+                                            </tbody>
+                                        </table>
+                                    }
+                                })
+                        }}
+                    </ErrorBoundary>
+                </Transition>
+            </div>
+        </div>
+    }
+}
@@ -0,0 +1,328 @@
+//! group seeding of the first stage with drag-and-drop of entrants between groups
+
+use app_core::{GroupSeeding, SeedingStrategy};
+use app_utils::{
+    components::{
+        bulk_select::BulkConfirmDialog,
+        inputs::{EnumSelect, InputCommitAction},
+    },
+    error::{ComponentError, strategy::handle_read_error},
+    hooks::use_on_cancel::use_on_cancel,
+    server_fn::seeding::{AssignEntrantsToGroup, SaveGroupSeeding, load_group_seeding},
+    state::{
+        activity_tracker::ActivityTracker, error_state::PageErrorContext, toast_state::ToastContext,
+    },
+};
+use leptos::{ev::DragEvent, prelude::*};
+use std::collections::HashSet;
+use uuid::Uuid;
+
+#[component]
+pub fn SeedingPanel(
+    #[prop(into)] tournament_id: Signal<Option<Uuid>>,
+    /// seeding cannot be changed, if stage 0 is locked by a running or finished tournament
+    #[prop(into)]
+    is_locked: Signal<bool>,
+) -> impl IntoView {
+    // --- global context and state ---
+    let page_err_ctx = expect_context::<PageErrorContext>();
+    let toast_ctx = expect_context::<ToastContext>();
+    let activity_tracker = expect_context::<ActivityTracker>();
+    let component_id = StoredValue::new(Uuid::new_v4());
+
+    on_cleanup(move || {
+        page_err_ctx.clear_all_for_component(component_id.get_value());
+        activity_tracker.remove_component(component_id.get_value());
+    });
+
+    // without chosen strategy the saved seeding is loaded
+    let strategy = RwSignal::new(None::<SeedingStrategy>);
+    let seeding_res = Resource::new(
+        move || (tournament_id.get(), strategy.get()),
+        move |(t_id, strategy)| async move {
+            match t_id {
+                Some(t_id) => activity_tracker
+                    .track_activity_wrapper(
+                        component_id.get_value(),
+                        load_group_seeding(t_id, strategy),
+                    )
+                    .await
+                    .map_err(|app_error| ComponentError::new(component_id.get_value(), app_error)),
+                None => Ok(None),
+            }
+        },
+    );
+
+    let refetch = Callback::new(move |()| seeding_res.refetch());
+    page_err_ctx.register_retry_handler(component_id.get_value(), refetch);
+
+    // local copy of seeding, which is changed by dragging entrants between groups
+    let seeding = RwSignal::new(None::<GroupSeeding>);
+    Effect::new(move || {
+        if let Some(Ok(loaded)) = seeding_res.get() {
+            seeding.set(loaded);
+        }
+    });
+
+    let save_seeding = ServerAction::<SaveGroupSeeding>::new();
+    activity_tracker.track_pending_memo(component_id.get_value(), save_seeding.pending());
+    Effect::new(move || match save_seeding.value().get() {
+        Some(Ok(_)) => toast_ctx.success("Group seeding saved.", None),
+        Some(Err(err)) => toast_ctx.error(format!("Could not save group seeding: {err}"), None),
+        None => {}
+    });
+
+    // entrant, which is dragged at the moment
+    let dragged = RwSignal::new(None::<Uuid>);
+    let on_drop = Callback::new(move |(group, position): (usize, usize)| {
+        if let Some(entrant_id) = dragged.get_untracked() {
+            seeding.update(|s| {
+                if let Some(s) = s {
+                    s.move_entrant(entrant_id, group, position);
+                }
+            });
+        }
+        dragged.set(None);
+    });
+
+    let on_save = move |_| {
+        if let Some(seeding) = seeding.get_untracked() {
+            save_seeding.dispatch(SaveGroupSeeding { seeding });
+        }
+    };
+
+    // move checked entrants at once to the target group; the seeding is saved on success
+    let checked = RwSignal::new(HashSet::<Uuid>::new());
+    let target_group = RwSignal::new(0_usize);
+    let is_bulk_move_open = RwSignal::new(false);
+    let assign_entrants = ServerAction::<AssignEntrantsToGroup>::new();
+    activity_tracker.track_pending_memo(component_id.get_value(), assign_entrants.pending());
+    Effect::new(move || match assign_entrants.value().get() {
+        Some(Ok(saved)) => {
+            toast_ctx.success("Entrants moved to group.", None);
+            checked.set(HashSet::new());
+            seeding.set(saved);
+        }
+        Some(Err(err)) => toast_ctx.error(format!("Could not move entrants: {err}"), None),
+        None => {}
+    });
+    let on_confirm_bulk_move = Callback::new(move |()| {
+        is_bulk_move_open.set(false);
+        if let Some(seeding) = seeding.get_untracked() {
+            assign_entrants.dispatch(AssignEntrantsToGroup {
+                seeding,
+                entrant_ids: checked.get_untracked().into_iter().collect(),
+                group: target_group.get_untracked(),
+            });
+        }
+    });
+    let num_groups = move || seeding.with(|s| s.as_ref().map_or(0, |s| s.groups.len()));
+
+    let on_cancel = use_on_cancel();
+
+    view! {
+        <div id="seeding" class="card w-full bg-base-100 shadow-xl" data-testid="seeding-root">
+            <div class="card-body">
+                <h2 class="card-title">"Group Seeding"</h2>
+                <div class="flex flex-wrap items-end gap-4">
+                    <EnumSelect
+                        label="Strategy"
+                        name="seeding-strategy"
+                        data_testid="select-seeding-strategy"
+                        value=Signal::derive(move || {
+                            strategy
+                                .get()
+                                .or_else(|| seeding.with(|s| s.as_ref().map(|s| s.strategy)))
+                        })
+                        action=InputCommitAction::WriteTo(
+                            Callback::new(move |s: Option<SeedingStrategy>| {
+                                strategy.set(Some(s.unwrap_or_default()))
+                            }),
+                        )
+                    />
+                    <button
+                        class="btn btn-sm btn-primary"
+                        data-testid="action-btn-save-seeding"
+                        disabled=move || {
+                            is_locked.get() || seeding.get().is_none()
+                                || save_seeding.pending().get()
+                        }
+                        on:click=on_save
+                    >
+                        "Save"
+                    </button>
+                    <label class="form-control">
+                        <span class="label-text">"Target group"</span>
+                        <select
+                            class="select select-bordered select-sm"
+                            data-testid="select-bulk-target-group"
+                            on:change=move |ev| {
+                                if let Ok(group) = event_target_value(&ev).parse() {
+                                    target_group.set(group);
+                                }
+                            }
+                        >
+                            {move || {
+                                (0..num_groups())
+                                    .map(|g| {
+                                        view! {
+                                            <option
+                                                value=g.to_string()
+                                                selected=move || target_group.get() == g
+                                            >
+                                                {format!("Group {}", g + 1)}
+                                            </option>
+                                        }
+                                    })
+                                    .collect_view()
+                            }}
+                        </select>
+                    </label>
+                    <button
+                        class="btn btn-sm btn-secondary"
+                        data-testid="action-btn-bulk-move"
+                        disabled=move || {
+                            is_locked.get() || checked.with(HashSet::is_empty)
+                                || assign_entrants.pending().get()
+                        }
+                        on:click=move |_| is_bulk_move_open.set(true)
+                    >
+                        {move || format!("Move {} checked to group", checked.with(HashSet::len))}
+                    </button>
+                </div>
+                <BulkConfirmDialog
+                    open=is_bulk_move_open
+                    title=Signal::derive(move || {
+                        format!("Move entrants to group {}", target_group.get() + 1)
+                    })
+                    count=Signal::derive(move || checked.with(HashSet::len))
+                    on_confirm=on_confirm_bulk_move
+                    on_cancel=Callback::new(move |()| is_bulk_move_open.set(false))
+                />
+                <Show when=move || is_locked.get()>
+                    <p class="text-sm" data-testid="seeding-locked">
+                        "The first stage has started; the seeding cannot be changed anymore."
+                    </p>
+                </Show>
+                <Transition fallback=move || {
+                    view! { <span class="loading loading-spinner loading-md"></span> }
+                }>
+                    <ErrorBoundary fallback=move |errors| {
+                        for (_err_id, err) in errors.get().into_iter() {
+                            if let Some(comp_err) = err.into_inner().downcast_ref::<ComponentError>() {
+                                handle_read_error(&page_err_ctx, comp_err, on_cancel);
+                            }
+                        }
+                    }>
+                        {move || {
+                            seeding_res
+                                .and_then(|_| {
+                                    view! {
+                                        <SeedingGroups
+                                            seeding=seeding
+                                            checked=checked
+                                            dragged=dragged
+                                            is_locked=is_locked
+                                            on_drop=on_drop
+                                        />
+                                    }
+                                })
+                        }}
+                    </ErrorBoundary>
+                </Transition>
+            </div>
+        </div>
+    }
+}
+
+/// groups of the seeding; entrants can be dragged onto other entrants or to the end of a group
+#[component]
+fn SeedingGroups(
+    seeding: RwSignal<Option<GroupSeeding>>,
+    /// entrants, which are checked for moving them at once
+    checked: RwSignal<HashSet<Uuid>>,
+    dragged: RwSignal<Option<Uuid>>,
+    #[prop(into)] is_locked: Signal<bool>,
+    on_drop: Callback<(usize, usize)>,
+) -> impl IntoView {
+    let groups = move || seeding.get().map(|s| s.groups).unwrap_or_default();
+
+    view! {
+        <div
+            class="grid grid-cols-1 md:grid-cols-2 lg:grid-cols-4 gap-4"
+            data-testid="seeding-groups"
+        >
+            <For
+                each=move || groups().into_iter().enumerate()
+                key=|(g, entrants)| {
+                    (*g, entrants.iter().map(|e| e.get_id()).collect::<Vec<_>>())
+                }
+                children=move |(g, entrants)| {
+                    let num_entrants = entrants.len();
+                    view! {
+                        <div
+                            class="card card-compact bg-base-200"
+                            data-testid=format!("seeding-group-{g}")
+                            on:dragover=move |ev: DragEvent| ev.prevent_default()
+                            on:drop=move |ev: DragEvent| {
+                                ev.prevent_default();
+                                on_drop.run((g, num_entrants));
+                            }
+                        >
+                            <div class="card-body">
+                                <h3 class="font-bold">{format!("Group {}", g + 1)}</h3>
+                                <ol class="list-decimal list-inside">
+                                    {entrants
+                                        .into_iter()
+                                        .enumerate()
+                                        .map(|(position, entrant)| {
+                                            let entrant_id = entrant.get_id();
+                                            view! {
+                                                <li
+                                                    class="cursor-move"
+                                                    data-testid="seeding-entrant"
+                                                    draggable=move || {
+                                                        if is_locked.get() { "false" } else { "true" }
+                                                    }
+                                                    on:dragstart=move |_| dragged.set(Some(entrant_id))
+                                                    on:dragend=move |_| dragged.set(None)
+                                                    on:drop=move |ev: DragEvent| {
+                                                        ev.prevent_default();
+                                                        ev.stop_propagation();
+                                                        on_drop.run((g, position));
+                                                    }
+                                                >
+                                                    <input
+                                                        type="checkbox"
+                                                        class="checkbox checkbox-xs mr-2"
+                                                        data-testid=format!("checkbox-bulk-{entrant_id}")
+                                                        disabled=move || is_locked.get()
+                                                        prop:checked=move || {
+                                                            checked.with(|c| c.contains(&entrant_id))
+                                                        }
+                                                        on:change=move |ev| {
+                                                            let is_checked = event_target_checked(&ev);
+                                                            checked
+                                                                .update(|c| {
+                                                                    if is_checked {
+                                                                        c.insert(entrant_id);
+                                                                    } else {
+                                                                        c.remove(&entrant_id);
+                                                                    }
+                                                                });
+                                                        }
+                                                    />
+                                                    {entrant.get_name().to_string()}
+                                                </li>
+                                            }
+                                        })
+                                        .collect_view()}
+                                </ol>
+                            </div>
+                        </div>
+                    }
+                }
+            />
+        </div>
+    }
+}
@@ -0,0 +1,208 @@
+//! shift log of the tournament desk
+
+use app_core::{CrTopic, ShiftLogEntry};
+use app_utils::{
+    error::{ComponentError, strategy::handle_read_error},
+    hooks::use_on_cancel::use_on_cancel,
+    server_fn::shift_log::{SaveShiftLogEntry, list_shift_log_entries},
+    state::{
+        activity_tracker::ActivityTracker, error_state::PageErrorContext, toast_state::ToastContext,
+    },
+};
+use cr_leptos_axum_socket::use_client_registry_socket;
+use leptos::prelude::*;
+use uuid::Uuid;
+
+/// maximum number of entries shown in the shift log panel
+const SHIFT_LOG_PANEL_LIMIT: usize = 50;
+
+#[component]
+pub fn ShiftLogPanel(#[prop(into)] tournament_id: Signal<Option<Uuid>>) -> impl IntoView {
+    // --- global context and state ---
+    let page_err_ctx = expect_context::<PageErrorContext>();
+    let toast_ctx = expect_context::<ToastContext>();
+    let activity_tracker = expect_context::<ActivityTracker>();
+    let component_id = StoredValue::new(Uuid::new_v4());
+
+    on_cleanup(move || {
+        page_err_ctx.clear_all_for_component(component_id.get_value());
+        activity_tracker.remove_component(component_id.get_value());
+    });
+
+    // --- local state ---
+    let author = RwSignal::new(String::new());
+    let text = RwSignal::new(String::new());
+    let pin_new = RwSignal::new(false);
+
+    let entries = Resource::new(
+        move || tournament_id.get(),
+        move |t_id| async move {
+            match t_id {
+                Some(t_id) => activity_tracker
+                    .track_activity_wrapper(
+                        component_id.get_value(),
+                        list_shift_log_entries(t_id, false, Some(SHIFT_LOG_PANEL_LIMIT)),
+                    )
+                    .await
+                    .map_err(|app_error| ComponentError::new(component_id.get_value(), app_error)),
+                None => Ok(vec![]),
+            }
+        },
+    );
+
+    let refetch = Callback::new(move |()| entries.refetch());
+    page_err_ctx.register_retry_handler(component_id.get_value(), refetch);
+
+    // Subscribe to shift log changes of this tournament
+    let topic = Signal::derive(move || {
+        tournament_id
+            .get()
+            .map(|tournament_id| CrTopic::ShiftLog { tournament_id })
+    });
+    use_client_registry_socket(topic, None.into(), refetch);
+
+    let save_entry = ServerAction::<SaveShiftLogEntry>::new();
+    activity_tracker.track_pending_memo(component_id.get_value(), save_entry.pending());
+    Effect::new(move || match save_entry.value().get() {
+        Some(Ok(_)) => {
+            text.set(String::new());
+            pin_new.set(false);
+            entries.refetch();
+        }
+        Some(Err(err)) => {
+            toast_ctx.error(format!("Could not save note: {err}"), None);
+        }
+        None => {}
+    });
+
+    let on_submit = move || {
+        if let Some(t_id) = tournament_id.get_untracked() {
+            let mut entry = ShiftLogEntry::default();
+            entry
+                .set_tournament_id(t_id)
+                .set_author(author.get_untracked())
+                .set_text(text.get_untracked())
+                .set_pinned(pin_new.get_untracked());
+            if entry.validate().is_ok() {
+                save_entry.dispatch(SaveShiftLogEntry { entry });
+            } else {
+                toast_ctx.warning("Author and note are required.", None);
+            }
+        }
+    };
+
+    let toggle_pin = move |mut entry: ShiftLogEntry| {
+        let pinned = entry.is_pinned();
+        entry.set_pinned(!pinned);
+        save_entry.dispatch(SaveShiftLogEntry { entry });
+    };
+
+    let on_cancel = use_on_cancel();
+
+    view! {
+        <div class="card w-full bg-base-100 shadow-xl" data-testid="shift-log-root">
+            <div class="card-body">
+                <h2 class="card-title">"Shift Log"</h2>
+                <form
+                    class="flex flex-col gap-2"
+                    on:submit=move |ev| {
+                        ev.prevent_default();
+                        on_submit();
+                    }
+                >
+                    <input
+                        type="text"
+                        class="input input-bordered w-full md:w-64"
+                        placeholder="Your name"
+                        data-testid="input-shift-log-author"
+                        prop:value=author
+                        on:input=move |ev| author.set(event_target_value(&ev))
+                    />
+                    <textarea
+                        class="textarea textarea-bordered w-full"
+                        placeholder="Note for the tournament desk"
+                        data-testid="input-shift-log-text"
+                        prop:value=text
+                        on:input=move |ev| text.set(event_target_value(&ev))
+                    ></textarea>
+                    <div class="flex items-center gap-4">
+                        <label class="label cursor-pointer gap-2">
+                            <input
+                                type="checkbox"
+                                class="checkbox checkbox-sm"
+                                data-testid="input-shift-log-pinned"
+                                prop:checked=pin_new
+                                on:change=move |ev| pin_new.set(event_target_checked(&ev))
+                            />
+                            <span class="label-text">"Handover note"</span>
+                        </label>
+                        <button
+                            type="submit"
+                            class="btn btn-primary btn-sm"
+                            data-testid="action-btn-add-shift-log"
+                            disabled=move || save_entry.pending().get()
+                        >
+                            "Add Note"
+                        </button>
+                    </div>
+                </form>
+                <Transition fallback=move || {
+                    view! { <span class="loading loading-spinner loading-md"></span> }
+                }>
+                    <ErrorBoundary fallback=move |errors| {
+                        for (_err_id, err) in errors.get().into_iter() {
+                            if let Some(comp_err) = err.into_inner().downcast_ref::<ComponentError>() {
+                                handle_read_error(&page_err_ctx, comp_err, on_cancel);
+                            }
+                        }
+                    }>
+                        {move || {
+                            entries
+                                .and_then(|list| {
+                                    let list = list.clone();
+                                    view! {
+                                        <ul class="flex flex-col gap-2" data-testid="shift-log-list">
+                                            <For
+                                                each=move || list.clone()
+                                                key=|e| (e.get_id(), e.get_version())
+                                                children=move |entry| {
+                                                    let pinned = entry.is_pinned();
+                                                    let created = entry
+                                                        .get_created_at()
+                                                        .map(|t| t.format("%Y-%m-%d %H:%M").to_string())
+                                                        .unwrap_or_default();
+                                                    let entry_for_pin = entry.clone();
+                                                    view! {
+                                                        <li
+                                                            class="p-3 rounded-lg bg-base-200"
+                                                            class:border-l-4=pinned
+                                                            class:border-warning=pinned
+                                                            data-testid="shift-log-entry"
+                                                        >
+                                                            <div class="flex justify-between items-center text-sm opacity-70">
+                                                                <span>{format!("{created} · {}", entry.get_author())}</span>
+                                                                <button
+                                                                    class="btn btn-ghost btn-xs"
+                                                                    data-testid="action-btn-toggle-pin"
+                                                                    on:click=move |_| toggle_pin(entry_for_pin.clone())
+                                                                >
+                                                                    {if pinned { "Unpin" } else { "Pin" }}
+                                                                </button>
+                                                            </div>
+                                                            <p class="whitespace-pre-wrap">
+                                                                {entry.get_text().to_string()}
+                                                            </p>
+                                                        </li>
+                                                    }
+                                                }
+                                            />
+                                        </ul>
+                                    }
+                                })
+                        }}
+                    </ErrorBoundary>
+                </Transition>
+            </div>
+        </div>
+    }
+}
@@ -0,0 +1,240 @@
+//! timing dashboard of a stage: planned vs actual times per station, delay and projected
+//! finish of the stage
+
+use app_core::{CrTopic, StageTimingDashboard};
+use app_utils::{
+    error::{ComponentError, strategy::handle_read_error},
+    hooks::use_on_cancel::use_on_cancel,
+    server_fn::stage::{CompressStageSchedule, load_stage_timing_dashboard},
+    state::{
+        activity_tracker::ActivityTracker, error_state::PageErrorContext, toast_state::ToastContext,
+    },
+};
+use chrono::{DateTime, Utc};
+use cr_leptos_axum_socket::use_client_registry_socket;
+use leptos::prelude::*;
+use uuid::Uuid;
+
+/// format delay in seconds as signed minutes, e.g. `+12 min`
+fn format_delay(seconds: i64) -> String {
+    format!("{:+} min", seconds / 60)
+}
+
+/// format optional time as hours and minutes, e.g. `14:05`
+fn format_time(time: Option<DateTime<Utc>>) -> String {
+    time.map(|t| t.format("%H:%M").to_string())
+        .unwrap_or_else(|| String::from("–"))
+}
+
+#[component]
+pub fn StageTimingPanel(
+    #[prop(into)] tournament_id: Signal<Option<Uuid>>,
+    #[prop(into)] stage_id: Signal<Option<Uuid>>,
+) -> impl IntoView {
+    // --- global context and state ---
+    let page_err_ctx = expect_context::<PageErrorContext>();
+    let toast_ctx = expect_context::<ToastContext>();
+    let activity_tracker = expect_context::<ActivityTracker>();
+    let component_id = StoredValue::new(Uuid::new_v4());
+
+    on_cleanup(move || {
+        page_err_ctx.clear_all_for_component(component_id.get_value());
+        activity_tracker.remove_component(component_id.get_value());
+    });
+
+    let dashboard = Resource::new(
+        move || stage_id.get(),
+        move |s_id| async move {
+            match s_id {
+                Some(s_id) => activity_tracker
+                    .track_activity_wrapper(
+                        component_id.get_value(),
+                        load_stage_timing_dashboard(s_id),
+                    )
+                    .await
+                    .map(Some)
+                    .map_err(|app_error| ComponentError::new(component_id.get_value(), app_error)),
+                None => Ok(None),
+            }
+        },
+    );
+
+    let refetch = Callback::new(move |()| dashboard.refetch());
+    page_err_ctx.register_retry_handler(component_id.get_value(), refetch);
+
+    // re-evaluate, when results are entered or the stage changes
+    let scores_topic = Signal::derive(move || {
+        tournament_id
+            .get()
+            .map(|tournament_id| CrTopic::LiveScores { tournament_id })
+    });
+    use_client_registry_socket(scores_topic, None.into(), refetch);
+    let stage_topic =
+        Signal::derive(move || stage_id.get().map(|stage_id| CrTopic::Stage { stage_id }));
+    use_client_registry_socket(stage_topic, None.into(), refetch);
+
+    let compress = ServerAction::<CompressStageSchedule>::new();
+    activity_tracker.track_pending_memo(component_id.get_value(), compress.pending());
+    Effect::new(move || match compress.value().get() {
+        Some(Ok((rescheduled, compressed))) => {
+            toast_ctx.success(
+                format!(
+                    "Schedule compressed: {} pending matches rescheduled, projected finish {}.",
+                    rescheduled.len(),
+                    format_time(compressed.projected_finish),
+                ),
+                None,
+            );
+            dashboard.refetch();
+        }
+        Some(Err(err)) => {
+            toast_ctx.error(format!("Could not compress schedule: {err}"), None);
+        }
+        None => {}
+    });
+
+    let on_compress = move |_| {
+        if let Some(stage_id) = stage_id.get_untracked() {
+            compress.dispatch(CompressStageSchedule { stage_id });
+        }
+    };
+
+    let on_cancel = use_on_cancel();
+
+    view! {
+        <div class="card w-full bg-base-100 shadow-xl" data-testid="stage-timing-root">
+            <div class="card-body">
+                <div class="flex items-center justify-between">
+                    <h2 class="card-title">"Stage Timing"</h2>
+                    <div class="flex gap-2">
+                        <button
+                            class="btn btn-sm btn-outline"
+                            data-testid="action-btn-refresh-stage-timing"
+                            disabled=move || stage_id.get().is_none()
+                            on:click=move |_| refetch.run(())
+                        >
+                            "Refresh"
+                        </button>
+                        <button
+                            class="btn btn-sm btn-primary"
+                            data-testid="action-btn-compress-schedule"
+                            disabled=move || stage_id.get().is_none() || compress.pending().get()
+                            on:click=on_compress
+                        >
+                            "Compress schedule"
+                        </button>
+                    </div>
+                </div>
+                <Transition fallback=move || {
+                    view! { <span class="loading loading-spinner loading-md"></span> }
+                }>
+                    <ErrorBoundary fallback=move |errors| {
+                        for (_err_id, err) in errors.get().into_iter() {
+                            if let Some(comp_err) = err.into_inner().downcast_ref::<ComponentError>() {
+                                handle_read_error(&page_err_ctx, comp_err, on_cancel);
+                            }
+                        }
+                    }>
+                        {move || {
+                            dashboard
+                                .and_then(|loaded| {
+                                    loaded.clone().map(|dashboard| view! { <StageTimingView dashboard=dashboard /> })
+                                })
+                        }}
+                    </ErrorBoundary>
+                </Transition>
+            </div>
+        </div>
+    }
+}
+
+#[component]
+fn StageTimingView(dashboard: StageTimingDashboard) -> impl IntoView {
+    let delay = dashboard.delay_seconds();
+    let is_late = delay > 0;
+    let generated_at = dashboard.generated_at.format("%H:%M:%S").to_string();
+
+    view! {
+        <div class="stats stats-vertical lg:stats-horizontal shadow">
+            <div class="stat" data-testid="stage-timing-planned-finish">
+                <div class="stat-title">"Planned finish"</div>
+                <div class="stat-value">{format_time(dashboard.planned_finish)}</div>
+            </div>
+            <div class="stat" data-testid="stage-timing-projected-finish">
+                <div class="stat-title">"Projected finish"</div>
+                <div class="stat-value">{format_time(dashboard.projected_finish)}</div>
+                <div class="stat-desc" class:text-error=is_late>
+                    {format_delay(delay)}
+                </div>
+            </div>
+        </div>
+        <table class="table table-sm" data-testid="stage-timing-stations">
+            <thead>
+                <tr>
+                    <th>"Station"</th>
+                    <th>"Match"</th>
+                    <th>"Planned"</th>
+                    <th>"Actual / projected"</th>
+                    <th>"Delay"</th>
+                </tr>
+            </thead>
+            <tbody>
+                {dashboard
+                    .stations
+                    .into_iter()
+                    .map(|station| {
+                        let name = station.name.clone();
+                        let station_delay = station.delay_seconds;
+                        let is_station_late = station_delay > 0;
+                        let rows = station
+                            .matches
+                            .into_iter()
+                            .enumerate()
+                            .map(|(index, m)| {
+                                let projected = m.times.actual_end.is_none();
+                                view! {
+                                    <tr data-testid="stage-timing-match-row">
+                                        <td></td>
+                                        <td>{index + 1}</td>
+                                        <td>
+                                            {format!(
+                                                "{} – {}",
+                                                m.times.planned_start.format("%H:%M"),
+                                                m.times.planned_end.format("%H:%M"),
+                                            )}
+                                        </td>
+                                        <td class:italic=projected>
+                                            {format!(
+                                                "{} – {}",
+                                                m.projected_start.format("%H:%M"),
+                                                m.projected_end.format("%H:%M"),
+                                            )}
+                                        </td>
+                                        <td>{format_delay(m.delay_seconds())}</td>
+                                    </tr>
+                                }
+                            })
+                            .collect_view();
+                        view! {
+                            <tr class="font-semibold" data-testid="stage-timing-station-row">
+                                <td>{name}</td>
+                                <td></td>
+                                <td></td>
+                                <td>{format_time(station.projected_end)}</td>
+                                <td class:text-error=is_station_late>
+                                    {format_delay(station_delay)}
+                                </td>
+                            </tr>
+                            {rows}
+                        }
+                    })
+                    .collect_view()}
+            </tbody>
+        </table>
+        <p class="text-xs text-base-content/70">
+            {format!(
+                "Delays are cumulative per station: a match starts after the previous match at its station ended. Projected times are shown in italics. Evaluated at {generated_at}.",
+            )}
+        </p>
+    }
+}
@@ -0,0 +1,439 @@
+//! named stations of a tournament: venue, bulk setup, renaming, reordering and availability
+//! over the tournament
+
+use super::{format_datetime_local, parse_datetime_local};
+use app_core::{StationSetup, StationWindow, TournamentBase};
+use app_utils::{
+    server_fn::{
+        tournament_base::{ApplyVenue, CreateStations},
+        venue::list_venues,
+    },
+    state::{
+        EditorContext,
+        activity_tracker::ActivityTracker,
+        toast_state::ToastContext,
+        tournament::{TournamentEditorContext, base::BaseEditorContext},
+    },
+};
+use chrono::{Duration, Utc};
+use leptos::prelude::*;
+use uuid::Uuid;
+
+#[component]
+pub fn StationsFields(
+    /// type of station of the sport, e.g. "Table"
+    station_label: String,
+    /// callback to save the tournament base after a change
+    on_submit: Callback<()>,
+) -> impl IntoView {
+    let tournament_editor = expect_context::<TournamentEditorContext>();
+    let base_editor = tournament_editor.base_editor;
+    let toast_ctx = expect_context::<ToastContext>();
+    let activity_tracker = expect_context::<ActivityTracker>();
+    let component_id = StoredValue::new(Uuid::new_v4());
+
+    on_cleanup(move || {
+        activity_tracker.remove_component(component_id.get_value());
+    });
+
+    // --- bulk setup, e.g. "Court 1..8 at Main Hall" ---
+    let name_prefix = RwSignal::new(station_label.clone());
+    let count = RwSignal::new(base_editor.num_stations.get_untracked().unwrap_or(1));
+    let venue = RwSignal::new(String::new());
+    let setup = Signal::derive(move || StationSetup {
+        name_prefix: name_prefix.get(),
+        count: count.get(),
+        first_number: 1,
+        venue: venue.get(),
+    });
+    let is_valid_setup =
+        Signal::derive(move || setup.with(|setup| setup.validate(Default::default()).is_ok()));
+
+    let create_stations = ServerAction::<CreateStations>::new();
+    activity_tracker.track_pending_memo(component_id.get_value(), create_stations.pending());
+    Effect::new(move || match create_stations.value().get() {
+        Some(Ok(base)) => {
+            create_stations.clear();
+            toast_ctx.success(
+                format!("Created {} stations.", base.get_stations().len()),
+                None,
+            );
+            base_editor.set_object(base);
+        }
+        Some(Err(err)) => {
+            create_stations.clear();
+            toast_ctx.error(format!("Could not create stations: {err}"), None);
+        }
+        None => {}
+    });
+
+    let on_create = move |_| {
+        let setup = setup.get_untracked();
+        match (
+            base_editor.id.get_untracked(),
+            base_editor.version.get_untracked(),
+        ) {
+            (Some(id), Some(version)) => {
+                create_stations.dispatch(CreateStations { id, version, setup });
+            }
+            // new tournaments are saved together with their stations
+            _ => {
+                base_editor.set_stations.run(setup.stations());
+                base_editor.set_num_stations.run(Some(setup.count));
+                on_submit.run(());
+            }
+        }
+    };
+
+    // --- venue: available courts become the stations ---
+    let venues = Resource::new(
+        || (),
+        |_| async move { list_venues(String::new(), None).await.unwrap_or_default() },
+    );
+    let selected_venue = RwSignal::new(base_editor.venue_id.get_untracked());
+    let venue_station_label = StoredValue::new(station_label.clone());
+
+    let apply_venue = ServerAction::<ApplyVenue>::new();
+    activity_tracker.track_pending_memo(component_id.get_value(), apply_venue.pending());
+    Effect::new(move || match apply_venue.value().get() {
+        Some(Ok(base)) => {
+            apply_venue.clear();
+            toast_ctx.success(
+                format!("Took {} stations from venue.", base.get_stations().len()),
+                None,
+            );
+            base_editor.set_object(base);
+        }
+        Some(Err(err)) => {
+            apply_venue.clear();
+            toast_ctx.error(format!("Could not apply venue: {err}"), None);
+        }
+        None => {}
+    });
+
+    let on_apply_venue = move |_| {
+        let Some(venue_id) = selected_venue.get_untracked() else {
+            return;
+        };
+        match (
+            base_editor.id.get_untracked(),
+            base_editor.version.get_untracked(),
+        ) {
+            (Some(id), Some(version)) => {
+                apply_venue.dispatch(ApplyVenue {
+                    id,
+                    version,
+                    venue_id,
+                });
+            }
+            // new tournaments are saved together with the stations of the venue
+            _ => {
+                let venue = venues
+                    .get_untracked()
+                    .unwrap_or_default()
+                    .into_iter()
+                    .find(|v| v.get_id() == venue_id);
+                if let Some(venue) = venue {
+                    base_editor.apply_venue.run(venue);
+                    on_submit.run(());
+                }
+            }
+        }
+    };
+
+    let num_named = Signal::derive(move || {
+        base_editor
+            .stations
+            .with(|stations| stations.as_ref().map_or(0, Vec::len) as u32)
+    });
+
+    view! {
+        <div class="md:col-span-2 flex flex-col gap-4" data-testid="stations-fields">
+            <div class="flex flex-wrap items-end gap-4">
+                <label class="form-control">
+                    <span class="label-text">"Venue"</span>
+                    <select
+                        class="select select-bordered w-full md:w-64"
+                        data-testid="select-venue"
+                        prop:value=move || {
+                            selected_venue.get().map(|id| id.to_string()).unwrap_or_default()
+                        }
+                        on:change=move |ev| {
+                            selected_venue.set(Uuid::parse_str(&event_target_value(&ev)).ok());
+                        }
+                    >
+                        <option value="">"-- Select venue --"</option>
+                        <Transition>
+                            {move || {
+                                venues
+                                    .get()
+                                    .unwrap_or_default()
+                                    .into_iter()
+                                    .map(|v| {
+                                        let id = v.get_id();
+                                        view! {
+                                            <option
+                                                value=id.to_string()
+                                                selected=move || selected_venue.get() == Some(id)
+                                            >
+                                                {format!(
+                                                    "{} ({} {}s)",
+                                                    v.get_name(),
+                                                    v.stations().len(),
+                                                    venue_station_label.get_value(),
+                                                )}
+                                            </option>
+                                        }
+                                    })
+                                    .collect_view()
+                            }}
+                        </Transition>
+                    </select>
+                </label>
+                <button
+                    type="button"
+                    class="btn btn-sm"
+                    data-testid="action-btn-apply-venue"
+                    disabled=move || {
+                        selected_venue.get().is_none() || apply_venue.pending().get()
+                            || base_editor.is_disabled_base_editing.get()
+                    }
+                    on:click=on_apply_venue
+                >
+                    "Apply venue"
+                </button>
+            </div>
+            <div class="flex flex-wrap items-end gap-4">
+                <label class="form-control">
+                    <span class="label-text">{format!("{station_label} name")}</span>
+                    <input
+                        type="text"
+                        class="input input-bordered w-full md:w-48"
+                        data-testid="input-station-prefix"
+                        prop:value=name_prefix
+                        on:input=move |ev| name_prefix.set(event_target_value(&ev))
+                    />
+                </label>
+                <label class="form-control">
+                    <span class="label-text">"Count"</span>
+                    <input
+                        type="number"
+                        min="1"
+                        class="input input-bordered w-24"
+                        data-testid="input-station-count"
+                        prop:value=move || count.get().to_string()
+                        on:input=move |ev| {
+                            count.set(event_target_value(&ev).parse().unwrap_or_default());
+                        }
+                    />
+                </label>
+                <label class="form-control">
+                    <span class="label-text">"Venue (optional)"</span>
+                    <input
+                        type="text"
+                        class="input input-bordered w-full md:w-64"
+                        data-testid="input-station-venue"
+                        prop:value=venue
+                        on:input=move |ev| venue.set(event_target_value(&ev))
+                    />
+                </label>
+                <button
+                    type="button"
+                    class="btn btn-sm btn-primary"
+                    data-testid="action-btn-create-stations"
+                    disabled=move || !is_valid_setup.get() || create_stations.pending().get()
+                    on:click=on_create
+                >
+                    {format!("Create {station_label}s")}
+                </button>
+            </div>
+            <Show when=move || { num_named.get() > 0 }>
+                <ul class="flex flex-col gap-2" data-testid="station-list">
+                    {move || {
+                        base_editor
+                            .stations
+                            .get()
+                            .unwrap_or_default()
+                            .into_iter()
+                            .enumerate()
+                            .map(|(index, station)| {
+                                let number = index as u32 + 1;
+                                let is_empty = station.get_name().is_empty();
+                                view! {
+                                    <li class="flex items-center gap-2">
+                                        <span class="w-8 text-right opacity-70">{number}</span>
+                                        <input
+                                            type="text"
+                                            class="input input-bordered input-sm w-full md:w-64"
+                                            class:input-error=is_empty
+                                            data-testid=format!("input-station-name-{number}")
+                                            prop:value=station.get_name().to_string()
+                                            on:change=move |ev| {
+                                                let name = event_target_value(&ev);
+                                                edit_stations(
+                                                    base_editor,
+                                                    on_submit,
+                                                    |base| base.rename_station(number, name),
+                                                );
+                                            }
+                                        />
+                                        <span class="text-sm opacity-70">
+                                            {station.get_venue().to_string()}
+                                        </span>
+                                        <button
+                                            type="button"
+                                            class="btn btn-ghost btn-xs"
+                                            aria-label="Move up"
+                                            data-testid=format!("action-btn-station-up-{number}")
+                                            disabled=number == 1
+                                            on:click=move |_| {
+                                                edit_stations(
+                                                    base_editor,
+                                                    on_submit,
+                                                    |base| base.move_station(number, number - 1),
+                                                );
+                                            }
+                                        >
+                                            <span class="icon-[heroicons--chevron-up] w-4 h-4"></span>
+                                        </button>
+                                        <button
+                                            type="button"
+                                            class="btn btn-ghost btn-xs"
+                                            aria-label="Move down"
+                                            data-testid=format!("action-btn-station-down-{number}")
+                                            disabled=move || number == num_named.get()
+                                            on:click=move |_| {
+                                                edit_stations(
+                                                    base_editor,
+                                                    on_submit,
+                                                    |base| base.move_station(number, number + 1),
+                                                );
+                                            }
+                                        >
+                                            <span class="icon-[heroicons--chevron-down] w-4 h-4"></span>
+                                        </button>
+                                    </li>
+                                }
+                            })
+                            .collect_view()
+                    }}
+                </ul>
+            </Show>
+            <StationWindowsFields station_label=station_label on_submit=on_submit />
+        </div>
+    }
+}
+
+/// availability of stations over the tournament, e.g. 4 courts in the morning and 6 in the
+/// afternoon
+#[component]
+fn StationWindowsFields(station_label: String, on_submit: Callback<()>) -> impl IntoView {
+    let base_editor = expect_context::<TournamentEditorContext>().base_editor;
+    let windows = Signal::derive(move || base_editor.station_windows.get().unwrap_or_default());
+    let num_stations = Signal::derive(move || base_editor.num_stations.get().unwrap_or(1));
+
+    let edit_windows = move |edit: &dyn Fn(&mut Vec<StationWindow>)| {
+        let mut windows = windows.get_untracked();
+        edit(&mut windows);
+        base_editor.set_station_windows.run(windows);
+        on_submit.run(());
+    };
+    let on_add = move |_| {
+        edit_windows(&|windows| {
+            // new window starts one hour after the last window
+            let start = windows
+                .last()
+                .map_or_else(Utc::now, |w| w.start + Duration::hours(1));
+            windows.push(StationWindow::new(start, num_stations.get_untracked()));
+        });
+    };
+
+    view! {
+        <div class="flex flex-col gap-2" data-testid="station-windows">
+            <span class="label-text">
+                {format!(
+                    "Available {station_label}s over the day (all {station_label}s before the first time window)",
+                )}
+            </span>
+            <ul class="flex flex-col gap-2">
+                {move || {
+                    windows
+                        .get()
+                        .into_iter()
+                        .enumerate()
+                        .map(|(index, window)| {
+                            let number = index + 1;
+                            view! {
+                                <li class="flex items-center gap-2">
+                                    <input
+                                        type="datetime-local"
+                                        class="input input-bordered input-sm"
+                                        data-testid=format!("input-station-window-start-{number}")
+                                        prop:value=format_datetime_local(Some(window.start))
+                                        on:change=move |ev| {
+                                            if let Some(start) = parse_datetime_local(
+                                                &event_target_value(&ev),
+                                            ) {
+                                                edit_windows(&|windows| windows[index].start = start);
+                                            }
+                                        }
+                                    />
+                                    <input
+                                        type="number"
+                                        min="0"
+                                        max=move || num_stations.get().to_string()
+                                        class="input input-bordered input-sm w-24"
+                                        data-testid=format!("input-station-window-count-{number}")
+                                        prop:value=window.num_stations.to_string()
+                                        on:change=move |ev| {
+                                            if let Ok(count) = event_target_value(&ev).parse() {
+                                                edit_windows(
+                                                    &|windows| windows[index].num_stations = count,
+                                                );
+                                            }
+                                        }
+                                    />
+                                    <button
+                                        type="button"
+                                        class="btn btn-ghost btn-xs"
+                                        aria-label="Remove time window"
+                                        data-testid=format!("action-btn-remove-station-window-{number}")
+                                        on:click=move |_| {
+                                            edit_windows(&|windows| {
+                                                windows.remove(index);
+                                            });
+                                        }
+                                    >
+                                        <span class="icon-[heroicons--x-mark] w-4 h-4"></span>
+                                    </button>
+                                </li>
+                            }
+                        })
+                        .collect_view()
+                }}
+            </ul>
+            <button
+                type="button"
+                class="btn btn-sm btn-ghost self-start"
+                data-testid="action-btn-add-station-window"
+                on:click=on_add
+            >
+                "Add time window"
+            </button>
+        </div>
+    }
+}
+
+/// apply `edit` to the stations of the edited tournament and save the change
+fn edit_stations(
+    base_editor: BaseEditorContext,
+    on_submit: Callback<()>,
+    edit: impl FnOnce(&mut TournamentBase) -> bool,
+) {
+    if let Some(mut base) = base_editor.local.get_untracked()
+        && edit(&mut base)
+    {
+        base_editor.set_stations.run(base.get_stations().to_vec());
+        on_submit.run(());
+    }
+}
@@ -1,10 +1,22 @@
 //! create or edit a tournament
 
+use super::{
+    CheckInPanel, CourtCallsPanel, DayDashboardPanel, DurationEstimatePanel, EntrantsPanel,
+    MatchNotesPanel, OfficialsPanel, PlanningSuggestions, PublicLanguagesFields, ReadinessPanel,
+    ScorekeepersPanel, SeedingPanel, ShiftLogPanel, StationsFields,
+};
 use app_core::{TournamentBase, TournamentMode};
 #[cfg(feature = "test-mock")]
 use app_utils::server_fn::tournament_base::save_tournament_base_inner;
 use app_utils::{
-    components::inputs::{EnumSelect, InputCommitAction, NumberInput, TextInput},
+    components::{
+        address_select::AddressSelect,
+        inputs::{EnumSelect, InputCommitAction, NumberInput, TextInput},
+        json_file::{JsonFileUpload, download_json},
+        presence_banner::PresenceBanner,
+        sport_config_select::SportConfigSelect,
+        version_conflict::VersionConflictDialog,
+    },
     enum_utils::EditAction,
     hooks::{
         use_on_cancel::use_on_cancel,
@@ -14,15 +26,19 @@ use app_utils::{
             use_matched_route_navigation, use_query_navigation,
         },
     },
-    params::{EditActionParams, FilterNameQuery, ParamQuery, TournamentBaseIdQuery},
-    server_fn::tournament_base::SaveTournamentBase,
+    params::{EditActionParams, FilterNameQuery, ParamQuery, SportIdQuery, TournamentBaseIdQuery},
+    server_fn::tournament_base::{ExportTournament, ImportTournament, SaveTournamentBase},
     state::{
-        EditorContextWithResource, object_table::ObjectEditorMapContext,
+        EditorContextWithResource,
+        global_state::{GlobalState, GlobalStateStoreFields},
+        object_table::ObjectEditorMapContext,
+        toast_state::ToastContext,
         tournament::TournamentEditorContext,
     },
 };
 use leptos::{html::H2, prelude::*};
 use leptos_router::{NavigateOptions, hooks::use_navigate, nested_router::Outlet};
+use reactive_stores::Store;
 use uuid::Uuid;
 
 #[component]
@@ -32,9 +48,13 @@ pub fn EditTournamentBase() -> impl IntoView {
         url_is_matched_route,
         ..
     } = use_matched_route_navigation();
+    let UseQueryNavigationReturn {
+        url_update_queries, ..
+    } = use_query_navigation();
 
     let edit_action = EditActionParams::use_param_query();
     let tournament_base_id = TournamentBaseIdQuery::use_param_query();
+    let toast_ctx = expect_context::<ToastContext>();
 
     // --- local state ---
     let tournament_editor_map =
@@ -72,6 +92,52 @@ pub fn EditTournamentBase() -> impl IntoView {
         }
     });
 
+    // export tournament as JSON document
+    let export_tournament = ServerAction::<ExportTournament>::new();
+    Effect::new(move || match export_tournament.value().get() {
+        Some(Ok(document)) => {
+            let id = tournament_base_id.get_untracked().unwrap_or_default();
+            download_json(&format!("tournament_{id}.json"), &document);
+        }
+        Some(Err(err)) => {
+            toast_ctx.error(format!("Could not export Tournament: {err}"), None);
+        }
+        None => {}
+    });
+
+    // import tournament from JSON document and continue editing the imported tournament
+    let import_tournament = ServerAction::<ImportTournament>::new();
+    Effect::new(move || match import_tournament.value().get() {
+        Some(Ok(imported)) => {
+            toast_ctx.success(format!("Imported {}", imported.get_name()), None);
+            let tb_id = imported.get_id().to_string();
+            let key_value = vec![
+                (TournamentBaseIdQuery::KEY, tb_id.as_str()),
+                (FilterNameQuery::KEY, imported.get_name()),
+            ];
+            let nav_url = url_update_queries(key_value, Some("/tournaments/edit"));
+            let navigate = use_navigate();
+            navigate(
+                &nav_url,
+                NavigateOptions {
+                    scroll: false,
+                    ..Default::default()
+                },
+            );
+        }
+        Some(Err(err)) => {
+            toast_ctx.error(format!("Could not import Tournament: {err}"), None);
+        }
+        None => {}
+    });
+
+    // only saved tournaments have other editors
+    let presence_id = Signal::derive(move || {
+        matches!(edit_action.get(), Some(EditAction::Edit))
+            .then(|| tournament_base_id.get())
+            .flatten()
+    });
+
     // cancel function for close button
     let on_cancel = use_on_cancel();
 
@@ -96,15 +162,78 @@ pub fn EditTournamentBase() -> impl IntoView {
                                 None => "",
                             }}
                         </h2>
-                        <button
-                            class="btn btn-square btn-ghost btn-sm"
-                            on:click=move |_| on_cancel.run(())
-                            aria-label="Close"
-                            data-testid="action-btn-close-edit-base"
-                        >
-                            <span class="icon-[heroicons--x-mark] w-6 h-6"></span>
-                        </button>
+                        <div class="flex items-center gap-2">
+                            <Show when=move || matches!(edit_action.get(), Some(EditAction::Edit))>
+                                <button
+                                    class="btn btn-sm btn-outline"
+                                    data-testid="action-btn-export-tournament"
+                                    disabled=move || {
+                                        export_tournament.pending().get()
+                                            || tournament_base_id.get().is_none()
+                                    }
+                                    on:click=move |_| {
+                                        if let Some(id) = tournament_base_id.get() {
+                                            export_tournament.dispatch(ExportTournament { id });
+                                        }
+                                    }
+                                >
+                                    "Export"
+                                </button>
+                                // PDF is rendered by the server; external link bypasses the router
+                                <a
+                                    class="btn btn-sm btn-outline"
+                                    href=move || {
+                                        tournament_base_id
+                                            .get()
+                                            .map(|id| format!("/api/tournament/{id}/schedule.pdf"))
+                                            .unwrap_or_default()
+                                    }
+                                    target="_blank"
+                                    rel="external"
+                                    data-testid="action-btn-print-schedule"
+                                >
+                                    "Print Schedule"
+                                </a>
+                                // spectator view opens in new tab, so that editing continues
+                                <a
+                                    class="btn btn-sm btn-outline"
+                                    href=move || {
+                                        tournament_base_id
+                                            .get()
+                                            .map(|id| format!("/public/tournament/{id}"))
+                                            .unwrap_or_default()
+                                    }
+                                    target="_blank"
+                                    data-testid="action-btn-public-view"
+                                >
+                                    "Public View"
+                                </a>
+                            </Show>
+                            <Show when=move || matches!(edit_action.get(), Some(EditAction::New))>
+                                <JsonFileUpload
+                                    label="Import"
+                                    data_testid="action-input-import-tournament"
+                                    disabled=import_tournament.pending()
+                                    on_load=Callback::new(move |document: String| {
+                                        import_tournament
+                                            .dispatch(ImportTournament {
+                                                document,
+                                                new_name: None,
+                                            });
+                                    })
+                                />
+                            </Show>
+                            <button
+                                class="btn btn-square btn-ghost btn-sm"
+                                on:click=move |_| on_cancel.run(())
+                                aria-label="Close"
+                                data-testid="action-btn-close-edit-base"
+                            >
+                                <span class="icon-[heroicons--x-mark] w-6 h-6"></span>
+                            </button>
+                        </div>
                     </div>
+                    <PresenceBanner object_id=presence_id />
                     {move || {
                         editor
                             .try_get()
@@ -139,6 +268,38 @@ pub fn EditTournamentBase() -> impl IntoView {
             </div>
             <div class="my-4"></div>
             <Outlet />
+            <Show when=move || matches!(edit_action.get(), Some(EditAction::Edit))>
+                <div class="my-4"></div>
+                <ReadinessPanel tournament_id=tournament_base_id />
+                <div class="my-4"></div>
+                <DurationEstimatePanel tournament_id=tournament_base_id />
+                <div class="my-4"></div>
+                <DayDashboardPanel tournament_id=tournament_base_id />
+                <div class="my-4"></div>
+                <CheckInPanel tournament_id=tournament_base_id />
+                <div class="my-4"></div>
+                <CourtCallsPanel tournament_id=tournament_base_id />
+                <div class="my-4"></div>
+                <SeedingPanel
+                    tournament_id=tournament_base_id
+                    is_locked=Signal::derive(move || {
+                        editor
+                            .get()
+                            .and_then(|e| e.base_editor.local.get())
+                            .is_some_and(|t| t.is_locked_by_state())
+                    })
+                />
+                <div class="my-4"></div>
+                <EntrantsPanel tournament_id=tournament_base_id />
+                <div class="my-4"></div>
+                <ShiftLogPanel tournament_id=tournament_base_id />
+                <div class="my-4"></div>
+                <MatchNotesPanel tournament_id=tournament_base_id />
+                <div class="my-4"></div>
+                <ScorekeepersPanel tournament_id=tournament_base_id />
+                <div class="my-4"></div>
+                <OfficialsPanel tournament_id=tournament_base_id />
+            </Show>
         </Show>
     }
 }
@@ -152,6 +313,19 @@ fn TournamentBaseForm(tournament_editor: TournamentEditorContext) -> impl IntoVi
     let UseMatchedRouteNavigationReturn {
         url_matched_route, ..
     } = use_matched_route_navigation();
+    let state = expect_context::<Store<GlobalState>>();
+
+    // stations are labeled by the type of station of the sport, e.g. "Number of Tables"
+    let stations_label = SportIdQuery::use_param_query()
+        .get_untracked()
+        .and_then(|sport_id| {
+            state
+                .sport_plugin_manager()
+                .get_untracked()
+                .get_web_ui(&sport_id)
+        })
+        .map(|plugin| plugin.capabilities().station_label())
+        .unwrap_or_else(|| "Station".to_string());
     let navigate = use_navigate();
 
     let edit_action = EditActionParams::use_param_query();
@@ -184,6 +358,56 @@ fn TournamentBaseForm(tournament_editor: TournamentEditorContext) -> impl IntoVi
         .post_save_callback
         .set_value(Some(post_save_callback));
 
+    // structural edits are recorded for undo/redo
+    let set_mode = Callback::new(move |mode: Option<TournamentMode>| {
+        if mode.is_some() && mode != tournament_editor.base_editor.mode.get_untracked() {
+            tournament_editor.record_structural_edit();
+            tournament_editor.base_editor.set_mode.run(mode);
+        }
+    });
+    let is_undo_disabled = move || {
+        !tournament_editor.can_undo.get()
+            || tournament_editor.base_editor.is_disabled_base_editing.get()
+    };
+    let is_redo_disabled = move || {
+        !tournament_editor.can_redo.get()
+            || tournament_editor.base_editor.is_disabled_base_editing.get()
+    };
+    // Ctrl+Z undo, Ctrl+Shift+Z or Ctrl+Y redo; text inputs keep their native undo
+    let undo_redo_listener = window_event_listener(leptos::ev::keydown, move |ev| {
+        if !(ev.ctrl_key() || ev.meta_key()) {
+            return;
+        }
+        let in_text_input = document()
+            .active_element()
+            .is_some_and(|el| matches!(el.tag_name().as_str(), "INPUT" | "TEXTAREA"));
+        if in_text_input {
+            return;
+        }
+        match ev.key().to_lowercase().as_str() {
+            "z" if ev.shift_key() => {
+                ev.prevent_default();
+                if !is_redo_disabled() {
+                    tournament_editor.redo();
+                }
+            }
+            "z" => {
+                ev.prevent_default();
+                if !is_undo_disabled() {
+                    tournament_editor.undo();
+                }
+            }
+            "y" => {
+                ev.prevent_default();
+                if !is_redo_disabled() {
+                    tournament_editor.redo();
+                }
+            }
+            _ => {}
+        }
+    });
+    on_cleanup(move || undo_redo_listener.remove());
+
     let on_submit = move || {
         if let Some(base) = tournament_editor.base_editor.local.get()
             && base.validate().is_ok()
@@ -215,6 +439,36 @@ fn TournamentBaseForm(tournament_editor: TournamentEditorContext) -> impl IntoVi
     };
 
     view! {
+        <VersionConflictDialog
+            editor=tournament_editor.base_editor
+            on_submit=Callback::new(move |()| on_submit())
+        />
+        <div class="flex justify-end gap-2 mb-2" data-testid="tournament-editor-history">
+            <button
+                type="button"
+                class="btn btn-sm btn-ghost"
+                title="Undo structural change (Ctrl+Z)"
+                aria-label="Undo"
+                data-testid="action-btn-undo-tournament-edit"
+                disabled=is_undo_disabled
+                on:click=move |_| tournament_editor.undo()
+            >
+                <span class="icon-[heroicons--arrow-uturn-left] w-5 h-5"></span>
+                "Undo"
+            </button>
+            <button
+                type="button"
+                class="btn btn-sm btn-ghost"
+                title="Redo structural change (Ctrl+Shift+Z)"
+                aria-label="Redo"
+                data-testid="action-btn-redo-tournament-edit"
+                disabled=is_redo_disabled
+                on:click=move |_| tournament_editor.redo()
+            >
+                <span class="icon-[heroicons--arrow-uturn-right] w-5 h-5"></span>
+                "Redo"
+            </button>
+        </div>
         // --- Tournament Base Form ---
         <div data-testid="tournament-editor-form">
             <form on:submit:capture=move |ev| {
@@ -280,13 +534,45 @@ fn TournamentBaseForm(tournament_editor: TournamentEditorContext) -> impl IntoVi
                             min="2".to_string()
                         />
 
+                        <NumberInput
+                            label=format!("Number of {stations_label}s")
+                            data_testid="input-tournament-stations"
+                            value=tournament_editor.base_editor.num_stations
+                            action=InputCommitAction::WriteAndSubmit(
+                                tournament_editor.base_editor.set_num_stations,
+                            )
+                            validation_result=tournament_editor.base_editor.validation_result
+                            object_id=tournament_editor.base_editor.id
+                            field="num_stations"
+                            min="1".to_string()
+                        />
+
+                        <SportConfigSelect
+                            label="Sport Configuration"
+                            data_testid="select-tournament-sport-config"
+                            sport_id=tournament_editor.base_editor.sport_id
+                            value=tournament_editor.base_editor.sport_config_id
+                            action=InputCommitAction::WriteAndSubmit(
+                                tournament_editor.base_editor.set_sport_config_id,
+                            )
+                            clear_label="No sport configuration"
+                        />
+
+                        <AddressSelect
+                            label="Location"
+                            data_testid="select-tournament-address"
+                            value=tournament_editor.base_editor.address_id
+                            action=InputCommitAction::WriteAndSubmit(
+                                tournament_editor.base_editor.set_address_id,
+                            )
+                            clear_label="No location"
+                        />
+
                         <EnumSelect
                             label="Mode"
                             data_testid="select-tournament-mode"
                             value=tournament_editor.base_editor.mode
-                            action=InputCommitAction::WriteAndSubmit(
-                                tournament_editor.base_editor.set_mode,
-                            )
+                            action=InputCommitAction::WriteAndSubmit(set_mode)
                         />
 
                         <Show when=move || {
@@ -309,6 +595,59 @@ fn TournamentBaseForm(tournament_editor: TournamentEditorContext) -> impl IntoVi
                             />
                         </Show>
 
+                        <Show when=move || {
+                            matches!(
+                                tournament_editor.base_editor.mode.get(),
+                                Some(TournamentMode::RingSystem { .. })
+                            )
+                        }>
+                            <NumberInput
+                                label="Neighbors on each side (Ring System)"
+                                data_testid="input-tournament-ring-num_neighbors"
+                                value=tournament_editor.base_editor.num_neighbors_ring_system
+                                action=InputCommitAction::WriteAndSubmit(
+                                    tournament_editor.base_editor.set_num_neighbors_ring_system,
+                                )
+                                validation_result=tournament_editor.base_editor.validation_result
+                                object_id=tournament_editor.base_editor.id
+                                field="mode.num_neighbors"
+                                min="1".to_string()
+                            />
+                        </Show>
+
+                        <label class="label cursor-pointer gap-2 justify-start">
+                            <input
+                                type="checkbox"
+                                class="toggle"
+                                name="tournament-sandbox"
+                                data-testid="input-tournament-sandbox"
+                                prop:checked=move || {
+                                    tournament_editor.base_editor.sandbox.get().unwrap_or_default()
+                                }
+                                on:change=move |ev| {
+                                    tournament_editor
+                                        .base_editor
+                                        .set_sandbox
+                                        .run(event_target_checked(&ev));
+                                    on_submit();
+                                }
+                            />
+                            <span class="label-text">
+                                "Sandbox: practice tournament, hidden from the public and purgeable"
+                            </span>
+                        </label>
+
+                        <StationsFields
+                            station_label=stations_label.clone()
+                            on_submit=Callback::new(move |()| on_submit())
+                        />
+
+                        <PublicLanguagesFields on_submit=Callback::new(move |()| on_submit()) />
+
+                        <Show when=move || matches!(edit_action.get(), Some(EditAction::New))>
+                            <PlanningSuggestions on_submit=Callback::new(move |()| on_submit()) />
+                        </Show>
+
                     </div>
                 </fieldset>
                 <Show when=move || show_stage_navigation.get()>
@@ -1,9 +1,11 @@
 //! Edit tournament stage component
 
+use super::{StageTimingPanel, format_datetime_local, parse_datetime_local};
+use app_core::{ScoringOverride, TieBreakerPreset};
 #[cfg(feature = "test-mock")]
 use app_utils::server_fn::stage::save_stage_inner;
 use app_utils::{
-    components::inputs::{InputCommitAction, NumberInput},
+    components::inputs::{EnumSelect, InputCommitAction, NumberInput},
     hooks::{
         use_on_cancel::use_on_cancel,
         use_scroll_into_view::use_scroll_h2_into_view,
@@ -53,6 +55,8 @@ pub fn EditTournamentStage() -> impl IntoView {
                     view! {
                         <TournamentStageForm tournament_editor=editor stage_editor=stage_editor />
                         <div class="my-4"></div>
+                        <StageTimingPanel tournament_id=tournament_base_id stage_id=stage_editor.id />
+                        <div class="my-4"></div>
                         <Outlet />
                     }
                 })
@@ -123,6 +127,35 @@ fn TournamentStageForm(
         }
     };
 
+    // change of number of groups is recorded for undo/redo
+    let set_num_groups = Callback::new(move |num_groups: Option<u32>| {
+        if num_groups != stage_editor.num_groups.get_untracked() {
+            tournament_editor.record_structural_edit();
+            stage_editor.set_num_groups.run(num_groups);
+        }
+    });
+
+    // scoring policy override of stage; unset values fall back to the sport configuration
+    let scoring_override =
+        Signal::derive(move || stage_editor.scoring_override.get().unwrap_or_default());
+    let update_scoring_override = move |update: fn(&mut ScoringOverride, Option<f32>)| {
+        Callback::new(move |value: Option<f32>| {
+            let mut so = scoring_override.get_untracked();
+            update(&mut so, value);
+            stage_editor.set_scoring_override.run(so);
+        })
+    };
+    let set_victory_points_win = update_scoring_override(|so, value| so.victory_points_win = value);
+    let set_victory_points_draw =
+        update_scoring_override(|so, value| so.victory_points_draw = value);
+    let set_victory_points_loss =
+        update_scoring_override(|so, value| so.victory_points_loss = value);
+    let set_tie_breakers = Callback::new(move |preset: Option<TieBreakerPreset>| {
+        let mut so = scoring_override.get_untracked();
+        so.tie_breakers = preset;
+        stage_editor.set_scoring_override.run(so);
+    });
+
     // For single stage or swiss system tournaments, ensure that stage 0 always has 1 group
     Effect::new(move || {
         if tournament_editor.base_editor.skip_stage_editor.get()
@@ -181,19 +214,164 @@ fn TournamentStageForm(
                                     }
                                 />
                                 <div class="w-full max-w-md grid grid-cols-1 gap-6">
+                                    // started stages keep their groups; only their schedule may change
+                                    <fieldset
+                                        disabled=move || {
+                                            stage_editor.is_disabled_structure_editing.get()
+                                        }
+                                        class="contents"
+                                        data-testid="stage-structure-fields"
+                                    >
+                                        <NumberInput
+                                            label="Number of Groups"
+                                            name="stage-num-groups"
+                                            data_testid="input-stage-num-groups"
+                                            value=stage_editor.num_groups
+                                            action=InputCommitAction::WriteAndSubmit(set_num_groups)
+                                            validation_result=stage_editor.validation_result
+                                            min="1".to_string()
+                                            object_id=stage_editor.id
+                                            field="num_groups"
+                                        />
+                                    </fieldset>
                                     <NumberInput
-                                        label="Number of Groups"
-                                        name="stage-num-groups"
-                                        data_testid="input-stage-num-groups"
-                                        value=stage_editor.num_groups
+                                        label="Station Limit"
+                                        name="stage-max-stations"
+                                        data_testid="input-stage-max-stations"
+                                        value=stage_editor.max_stations
                                         action=InputCommitAction::WriteAndSubmit(
-                                            stage_editor.set_num_groups,
+                                            stage_editor.set_max_stations,
                                         )
                                         validation_result=stage_editor.validation_result
                                         min="1".to_string()
                                         object_id=stage_editor.id
-                                        field="num_groups"
+                                        field="max_stations"
+                                        optional=true
+                                        placeholder="all stations"
                                     />
+                                    <label class="form-control">
+                                        <span class="label-text">"Latest End (time cap)"</span>
+                                        <input
+                                            type="datetime-local"
+                                            class="input input-bordered w-full"
+                                            name="stage-latest-end"
+                                            data-testid="input-stage-latest-end"
+                                            prop:value=move || {
+                                                format_datetime_local(stage_editor.latest_end.get())
+                                            }
+                                            on:change=move |ev| {
+                                                stage_editor
+                                                    .set_latest_end
+                                                    .run(parse_datetime_local(&event_target_value(&ev)));
+                                                on_submit();
+                                            }
+                                        />
+                                    </label>
+                                    <Show when=move || {
+                                        active_stage_number
+                                            .get()
+                                            .zip(tournament_editor.base_editor.mode.get())
+                                            .is_some_and(|(sn, mode)| {
+                                                mode.has_result_dependent_rounds(sn)
+                                            })
+                                    }>
+                                        <label class="label cursor-pointer gap-2 justify-start">
+                                            <input
+                                                type="checkbox"
+                                                class="toggle"
+                                                name="stage-auto-advance"
+                                                data-testid="input-stage-auto-advance"
+                                                prop:checked=move || {
+                                                    stage_editor.auto_advance.get().unwrap_or(true)
+                                                }
+                                                on:change=move |ev| {
+                                                    stage_editor
+                                                        .set_auto_advance
+                                                        .run(event_target_checked(&ev));
+                                                    on_submit();
+                                                }
+                                            />
+                                            <span class="label-text">
+                                                "Generate next round automatically, when all results of a round are confirmed"
+                                            </span>
+                                        </label>
+                                    </Show>
+                                    <fieldset
+                                        disabled=move || {
+                                            stage_editor.is_disabled_structure_editing.get()
+                                        }
+                                        class="contents"
+                                        data-testid="stage-scoring-fields"
+                                    >
+                                        <EnumSelect
+                                            label="Tie Breakers"
+                                            name="stage-tie-breakers"
+                                            data_testid="select-stage-tie-breakers"
+                                            value=Signal::derive(move || {
+                                                scoring_override.get().tie_breakers
+                                            })
+                                            action=InputCommitAction::WriteAndSubmit(set_tie_breakers)
+                                            validation_result=stage_editor.validation_result
+                                            object_id=stage_editor.id
+                                            field="tie_breakers"
+                                            optional=true
+                                            clear_label="Sport default"
+                                        />
+                                        <NumberInput
+                                            label="Victory Points for Win"
+                                            name="stage-victory-points-win"
+                                            data_testid="input-stage-victory-points-win"
+                                            value=Signal::derive(move || {
+                                                scoring_override.get().victory_points_win
+                                            })
+                                            action=InputCommitAction::WriteAndSubmit(
+                                                set_victory_points_win,
+                                            )
+                                            validation_result=stage_editor.validation_result
+                                            step="0.5".to_string()
+                                            min="0".to_string()
+                                            object_id=stage_editor.id
+                                            field="victory_points_win"
+                                            optional=true
+                                            placeholder="sport default"
+                                        />
+                                        <NumberInput
+                                            label="Victory Points for Draw"
+                                            name="stage-victory-points-draw"
+                                            data_testid="input-stage-victory-points-draw"
+                                            value=Signal::derive(move || {
+                                                scoring_override.get().victory_points_draw
+                                            })
+                                            action=InputCommitAction::WriteAndSubmit(
+                                                set_victory_points_draw,
+                                            )
+                                            validation_result=stage_editor.validation_result
+                                            step="0.5".to_string()
+                                            min="0".to_string()
+                                            object_id=stage_editor.id
+                                            field="victory_points_draw"
+                                            optional=true
+                                            placeholder="sport default"
+                                        />
+                                        <NumberInput
+                                            label="Victory Points for Loss"
+                                            name="stage-victory-points-loss"
+                                            data_testid="input-stage-victory-points-loss"
+                                            value=Signal::derive(move || {
+                                                scoring_override.get().victory_points_loss
+                                            })
+                                            action=InputCommitAction::WriteAndSubmit(
+                                                set_victory_points_loss,
+                                            )
+                                            validation_result=stage_editor.validation_result
+                                            step="0.5".to_string()
+                                            min="0".to_string()
+                                            object_id=stage_editor.id
+                                            field="victory_points_loss"
+                                            optional=true
+                                            placeholder="sport default"
+                                        />
+                                    </fieldset>
                                 </div>
                             // group editor links
                             </fieldset>
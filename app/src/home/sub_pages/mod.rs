@@ -3,11 +3,13 @@
 mod about_sport;
 mod adhoc_tournament;
 mod edit_tournament;
+mod new_tournament_wizard;
 mod sport_configurations;
 mod tournaments;
 
 pub use about_sport::*;
 pub use adhoc_tournament::*;
 pub use edit_tournament::*;
+pub use new_tournament_wizard::*;
 pub use sport_configurations::*;
 pub use tournaments::*;
@@ -0,0 +1,352 @@
+//! step by step setup of a new tournament with recommended mode
+//!
+//! Directors enter the number of entrants, the available time and stations and the minimum
+//! number of matches per entrant. The wizard recommends modes and group sizes with
+//! [`suggest_plans`] and pre-fills the editor of a new tournament with the chosen plan.
+
+use super::edit_tournament::planning::{plan_summary, use_default_match_minutes};
+use app_core::{PlanSuggestion, PlanningRequest, suggest_plans};
+use app_utils::{
+    hooks::use_url_navigation::{UseQueryNavigationReturn, use_query_navigation},
+    params::{ParamQuery, TournamentBaseIdQuery},
+    state::{
+        SimpleEditorOptions, object_table::ObjectEditorMapContext, toast_state::ToastContext,
+        tournament::TournamentEditorContext,
+    },
+};
+use chrono::Duration;
+use leptos::prelude::*;
+use leptos_router::{NavigateOptions, hooks::use_navigate};
+
+/// steps of the wizard
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum WizardStep {
+    Entrants,
+    TimeAndStations,
+    Style,
+}
+
+impl WizardStep {
+    fn number(self) -> u32 {
+        match self {
+            WizardStep::Entrants => 1,
+            WizardStep::TimeAndStations => 2,
+            WizardStep::Style => 3,
+        }
+    }
+
+    fn previous(self) -> Option<Self> {
+        match self {
+            WizardStep::Entrants => None,
+            WizardStep::TimeAndStations => Some(WizardStep::Entrants),
+            WizardStep::Style => Some(WizardStep::TimeAndStations),
+        }
+    }
+
+    fn next(self) -> Option<Self> {
+        match self {
+            WizardStep::Entrants => Some(WizardStep::TimeAndStations),
+            WizardStep::TimeAndStations => Some(WizardStep::Style),
+            WizardStep::Style => None,
+        }
+    }
+}
+
+#[component]
+pub fn NewTournamentWizard() -> impl IntoView {
+    let UseQueryNavigationReturn {
+        url_update_queries, ..
+    } = use_query_navigation();
+    let toast_ctx = expect_context::<ToastContext>();
+    let tournament_editor_map =
+        expect_context::<ObjectEditorMapContext<TournamentEditorContext, TournamentBaseIdQuery>>();
+
+    let step = RwSignal::new(WizardStep::Entrants);
+    let name = RwSignal::new(String::new());
+    let num_entrants = RwSignal::new(16_u32);
+    let num_stations = RwSignal::new(4_u32);
+    let available_hours = RwSignal::new(6_i64);
+    let match_minutes = RwSignal::new(use_default_match_minutes());
+    let min_matches = RwSignal::new(3_u32);
+
+    let suggestions = Signal::derive(move || {
+        suggest_plans(&PlanningRequest {
+            num_entrants: num_entrants.get(),
+            num_stations: num_stations.get(),
+            available_time: Duration::hours(available_hours.get()),
+            match_duration: Duration::minutes(match_minutes.get()),
+            min_matches: min_matches.get(),
+        })
+    });
+    // first suggestion is the recommendation
+    let selected = RwSignal::new(0_usize);
+    let selected_plan =
+        Signal::derive(move || suggestions.with(|s| s.get(selected.get()).cloned()));
+
+    let is_valid_step = Signal::derive(move || match step.get() {
+        WizardStep::Entrants => !name.with(|n| n.trim().is_empty()) && num_entrants.get() >= 2,
+        WizardStep::TimeAndStations => {
+            num_stations.get() > 0 && available_hours.get() > 0 && match_minutes.get() > 0
+        }
+        WizardStep::Style => selected_plan.with(Option::is_some),
+    });
+
+    // pre-fill a new tournament with the selected plan and open it in the editor
+    let on_finish = move |_| {
+        let Some(plan) = selected_plan.get_untracked() else {
+            return;
+        };
+        let Some(editor) =
+            tournament_editor_map.spawn_editor_for_new_object(SimpleEditorOptions::no_id())
+        else {
+            toast_ctx.warning("Failed to create a new tournament", None);
+            return;
+        };
+        apply_plan(
+            editor,
+            &plan,
+            name.get_untracked(),
+            num_stations.get_untracked(),
+        );
+        if let Some(new_id) = editor.base_editor.id.get_untracked() {
+            let new_id = new_id.to_string();
+            let nav_url = url_update_queries(
+                vec![(TournamentBaseIdQuery::KEY, new_id.as_str())],
+                Some("/tournaments/new"),
+            );
+            let navigate = use_navigate();
+            navigate(
+                &nav_url,
+                NavigateOptions {
+                    scroll: false,
+                    ..Default::default()
+                },
+            );
+        }
+    };
+
+    view! {
+        <div
+            class="card w-full max-w-3xl mx-auto bg-base-100 shadow-xl"
+            data-testid="new-tournament-wizard"
+        >
+            <div class="card-body flex flex-col gap-6">
+                <h2 class="card-title">"New Tournament Wizard"</h2>
+                <ul class="steps w-full">
+                    <li class="step" class:step-primary=move || step.get().number() >= 1>
+                        "Entrants"
+                    </li>
+                    <li class="step" class:step-primary=move || step.get().number() >= 2>
+                        "Time & Stations"
+                    </li>
+                    <li class="step" class:step-primary=move || step.get().number() >= 3>
+                        "Style"
+                    </li>
+                </ul>
+
+                <Show when=move || step.get() == WizardStep::Entrants>
+                    <div class="grid grid-cols-1 md:grid-cols-2 gap-4">
+                        <label class="form-control">
+                            <span class="label-text">"Tournament Name"</span>
+                            <input
+                                type="text"
+                                class="input input-bordered w-full"
+                                data-testid="input-wizard-name"
+                                prop:value=name
+                                on:input=move |ev| name.set(event_target_value(&ev))
+                            />
+                        </label>
+                        <NumberField
+                            label="Number of Entrants"
+                            data_testid="input-wizard-entrants"
+                            min=2
+                            value=num_entrants
+                        />
+                    </div>
+                </Show>
+
+                <Show when=move || step.get() == WizardStep::TimeAndStations>
+                    <div class="grid grid-cols-1 md:grid-cols-3 gap-4">
+                        <NumberField
+                            label="Number of Stations"
+                            data_testid="input-wizard-stations"
+                            min=1
+                            value=num_stations
+                        />
+                        <NumberField
+                            label="Available time (hours)"
+                            data_testid="input-wizard-available-hours"
+                            min=1
+                            value=available_hours
+                        />
+                        <NumberField
+                            label="Match duration (minutes)"
+                            data_testid="input-wizard-match-minutes"
+                            min=1
+                            value=match_minutes
+                        />
+                    </div>
+                </Show>
+
+                <Show when=move || step.get() == WizardStep::Style>
+                    <div class="flex flex-col gap-4">
+                        <NumberField
+                            label="Minimum matches per entrant"
+                            data_testid="input-wizard-min-matches"
+                            min=1
+                            value=min_matches
+                        />
+                        <Show
+                            when=move || suggestions.with(|s| !s.is_empty())
+                            fallback=|| {
+                                view! {
+                                    <p class="text-sm opacity-70" data-testid="wizard-no-suggestions">
+                                        "No mode meets the minimum number of matches with these entrants."
+                                    </p>
+                                }
+                            }
+                        >
+                            <ul class="flex flex-col gap-2" data-testid="wizard-suggestion-list">
+                                {move || {
+                                    suggestions
+                                        .get()
+                                        .into_iter()
+                                        .enumerate()
+                                        .map(|(index, suggestion)| {
+                                            let number = index + 1;
+                                            let summary = plan_summary(&suggestion);
+                                            let fits = suggestion.fits_available_time;
+                                            view! {
+                                                <li>
+                                                    <label class="label cursor-pointer justify-start gap-3">
+                                                        <input
+                                                            type="radio"
+                                                            name="wizard-plan"
+                                                            class="radio radio-primary"
+                                                            data-testid=format!("input-wizard-plan-{number}")
+                                                            prop:checked=move || selected.get() == index
+                                                            on:change=move |_| selected.set(index)
+                                                        />
+                                                        <span class="font-medium">
+                                                            {suggestion.mode.to_string()}
+                                                        </span>
+                                                        <Show when=move || index == 0>
+                                                            <span class="badge badge-primary badge-sm">
+                                                                "Recommended"
+                                                            </span>
+                                                        </Show>
+                                                        <span class="text-sm">
+                                                            {summary}
+                                                        </span>
+                                                        <Show when=move || !fits>
+                                                            <span class="badge badge-warning badge-sm">
+                                                                "too long"
+                                                            </span>
+                                                        </Show>
+                                                    </label>
+                                                </li>
+                                            }
+                                        })
+                                        .collect_view()
+                                }}
+                            </ul>
+                        </Show>
+                    </div>
+                </Show>
+
+                <div class="card-actions justify-between">
+                    <button
+                        type="button"
+                        class="btn btn-ghost"
+                        data-testid="action-btn-wizard-back"
+                        disabled=move || step.get().previous().is_none()
+                        on:click=move |_| {
+                            if let Some(previous) = step.get_untracked().previous() {
+                                step.set(previous);
+                            }
+                        }
+                    >
+                        "Back"
+                    </button>
+                    <button
+                        type="button"
+                        class="btn btn-primary"
+                        class:hidden=move || step.get().next().is_none()
+                        data-testid="action-btn-wizard-next"
+                        disabled=move || !is_valid_step.get()
+                        on:click=move |_| {
+                            if let Some(next) = step.get_untracked().next() {
+                                selected.set(0);
+                                step.set(next);
+                            }
+                        }
+                    >
+                        "Next"
+                    </button>
+                    <button
+                        type="button"
+                        class="btn btn-primary"
+                        class:hidden=move || step.get().next().is_some()
+                        data-testid="action-btn-wizard-finish"
+                        disabled=move || !is_valid_step.get()
+                        on:click=on_finish
+                    >
+                        "Open in Editor"
+                    </button>
+                </div>
+            </div>
+        </div>
+    }
+}
+
+/// number input of the wizard, which writes to `value` on input
+#[component]
+fn NumberField<T>(
+    label: &'static str,
+    data_testid: &'static str,
+    min: T,
+    value: RwSignal<T>,
+) -> impl IntoView
+where
+    T: Copy + ToString + std::str::FromStr + Default + Send + Sync + 'static,
+{
+    view! {
+        <label class="form-control">
+            <span class="label-text">{label}</span>
+            <input
+                type="number"
+                min=min.to_string()
+                class="input input-bordered w-full md:w-32"
+                data-testid=data_testid
+                prop:value=move || value.get().to_string()
+                on:input=move |ev| value.set(event_target_value(&ev).parse().unwrap_or_default())
+            />
+        </label>
+    }
+}
+
+/// Write base fields of `plan` to the new tournament of `editor` and prepare its stages with
+/// the number of groups of the plan.
+fn apply_plan(
+    editor: TournamentEditorContext,
+    plan: &PlanSuggestion,
+    name: String,
+    num_stations: u32,
+) {
+    let base_editor = editor.base_editor;
+    base_editor.set_name.run(Some(name.trim().to_string()));
+    base_editor
+        .set_num_entrants
+        .run(plan.group_sizes.first().map(|sizes| sizes.iter().sum()));
+    base_editor.set_num_stations.run(Some(num_stations));
+    base_editor.set_mode.run(Some(plan.mode));
+    if plan.mode.get_num_of_stages() > 1 {
+        for (stage_number, sizes) in plan.group_sizes.iter().enumerate() {
+            let stage_number = stage_number as u32;
+            editor.prepare_stage(stage_number);
+            if let Some(stage_editor) = editor.get_stage_editor(stage_number) {
+                stage_editor.set_num_groups.run(Some(sizes.len() as u32));
+            }
+        }
+    }
+}
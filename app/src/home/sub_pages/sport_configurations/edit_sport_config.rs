@@ -4,7 +4,12 @@ use app_core::SportConfig;
 #[cfg(feature = "test-mock")]
 use app_utils::server_fn::sport_config::save_sport_config_inner;
 use app_utils::{
-    components::inputs::{InputCommitAction, TextInput},
+    components::{
+        config_schema_form::ConfigSchemaForm,
+        inputs::{InputCommitAction, TextInput},
+        presence_banner::PresenceBanner,
+        version_conflict::VersionConflictDialog,
+    },
     enum_utils::EditAction,
     hooks::{
         use_on_cancel::use_on_cancel,
@@ -25,6 +30,8 @@ use app_utils::{
 use leptos::{html::H2, prelude::*};
 use leptos_router::{NavigateOptions, hooks::use_navigate};
 use reactive_stores::Store;
+use shared::SportPortWebUi;
+use std::sync::Arc;
 use uuid::Uuid;
 
 #[component]
@@ -92,6 +99,13 @@ pub fn EditSportConfiguration() -> impl IntoView {
         }
     });
 
+    // only saved sport configurations have other editors
+    let presence_id = Signal::derive(move || {
+        matches!(edit_action.get(), Some(EditAction::Edit))
+            .then(|| sport_config_id.get())
+            .flatten()
+    });
+
     // cancel function for cancel button and error handling
     let on_cancel = use_on_cancel();
 
@@ -130,6 +144,7 @@ pub fn EditSportConfiguration() -> impl IntoView {
                             <span class="icon-[heroicons--x-mark] w-6 h-6"></span>
                         </button>
                     </div>
+                    <PresenceBanner object_id=presence_id />
                     {move || {
                         editor
                             .try_get()
@@ -252,6 +267,10 @@ fn SportConfigForm(sport_config_editor: SportConfigEditorContext) -> impl IntoVi
     };
 
     view! {
+        <VersionConflictDialog
+            editor=sport_config_editor
+            on_submit=Callback::new(move |()| on_submit())
+        />
         // --- Sport Config Form ---
         <div data-testid="form-sport-config">
             <form on:submit:capture=move |ev| {
@@ -283,9 +302,60 @@ fn SportConfigForm(sport_config_editor: SportConfigEditorContext) -> impl IntoVi
                         field="name"
                     />
                     // Sport specific configuration UI
-                    {move || { sport_plugin().map(|plugin| plugin.render_configuration()) }}
+                    {move || {
+                        sport_plugin()
+                            .map(|plugin| {
+                                let schema = plugin.config_schema();
+                                if schema.is_empty() {
+                                    plugin.render_configuration()
+                                } else {
+                                    view! {
+                                        <ConfigSchemaForm schema=schema />
+                                        <EstimatedMatchDuration plugin=plugin />
+                                    }
+                                        .into_any()
+                                }
+                            })
+                    }}
+                    // facts derived from the edited configuration, e.g. sets and duration
+                    {move || {
+                        sport_plugin()
+                            .and_then(|plugin| {
+                                sport_config_editor
+                                    .local_read_only
+                                    .with(|sc| sc.as_ref().map(|sc| plugin.render_summary(sc)))
+                            })
+                    }}
                 </fieldset>
             </form>
         </div>
     }
 }
+
+/// read only display of the estimated match duration of the edited sport configuration
+#[component]
+fn EstimatedMatchDuration(plugin: Arc<dyn SportPortWebUi>) -> impl IntoView {
+    let sport_config_editor = expect_context::<SportConfigEditorContext>();
+    let minutes = move || {
+        sport_config_editor.local_read_only.with(|sc| {
+            sc.as_ref()
+                .and_then(|sc| plugin.estimate_match_duration(sc).ok())
+                .map(|duration| duration.as_secs() / 60)
+                .unwrap_or_default()
+        })
+    };
+
+    view! {
+        <div class="form-control w-full">
+            <label class="label">
+                <span class="label-text">"Estimated Match Duration"</span>
+            </label>
+            <div
+                class="input input-bordered flex items-center bg-base-200 text-base-content/70 cursor-not-allowed"
+                data-testid="estimated-match-duration"
+            >
+                {move || format!("{} minutes", minutes())}
+            </div>
+        </div>
+    }
+}
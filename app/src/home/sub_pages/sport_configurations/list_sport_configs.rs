@@ -1,14 +1,22 @@
 //! listing, creating and modifying sport configurations
 
-use app_core::CrTopic;
+use app_core::{
+    CrTopic, SportConfig, SportConfigSortColumn,
+    utils::{id_version::IdVersion, list_order::SortDirection},
+};
 use app_utils::{
-    components::inputs::{EnumSelect, InputCommitAction, InputUpdateStrategy, TextInput},
+    components::{
+        bulk_select::{BulkCheckAll, BulkCheckCell, BulkConfirmDialog},
+        inputs::{EnumSelect, InputCommitAction, InputUpdateStrategy, TextInput},
+        list_pager::ListPager,
+    },
     enum_utils::{EditAction, FilterLimit},
     error::{
         ComponentError,
         strategy::{handle_read_error, handle_unexpected_ui_error},
     },
     hooks::{
+        use_list_order::use_list_order,
         use_on_cancel::use_on_cancel,
         use_scroll_into_view::use_scroll_h2_into_view,
         use_url_navigation::{
@@ -16,10 +24,10 @@ use app_utils::{
         },
     },
     params::{
-        EditActionParams, FilterLimitQuery, FilterNameQuery, ParamQuery, SportConfigIdQuery,
-        SportIdQuery,
+        EditActionParams, FilterLimitQuery, FilterNameQuery, IncludeArchivedQuery, ParamQuery,
+        SortByQuery, SortDirectionQuery, SportConfigIdQuery, SportIdQuery,
     },
-    server_fn::sport_config::list_sport_config_ids,
+    server_fn::sport_config::{ArchiveSportConfig, ArchiveSportConfigs, list_sport_config_ids},
     state::{
         LabeledAction, SimpleEditorOptions,
         activity_tracker::ActivityTracker,
@@ -72,7 +80,9 @@ pub fn ListSportConfigurations() -> impl IntoView {
     let sport_id = SportIdQuery::use_param_query();
     let sport_config_id = SportConfigIdQuery::use_param_query();
     let search_term = FilterNameQuery::use_param_query();
+    let include_archived = IncludeArchivedQuery::use_param_query();
     let limit = FilterLimitQuery::use_param_query();
+    let order = use_list_order::<SportConfig>();
 
     // Resource that fetches data when filters change
     let sport_config_ids = Resource::new(
@@ -80,20 +90,24 @@ pub fn ListSportConfigurations() -> impl IntoView {
             (
                 sport_id.get(),
                 search_term.get(),
+                include_archived.get(),
                 limit.get(),
+                order.get(),
                 sport_config_editor_map.track_fetch_trigger.get(),
             )
         },
-        move |(maybe_sport_id, term, lim, _)| async move {
+        move |(maybe_sport_id, term, include_archived, lim, order, _)| async move {
             if let Some(s_id) = maybe_sport_id {
                 activity_tracker
-                    .track_activity_wrapper(
+                    .track_request(
                         component_id.get_value(),
                         list_sport_config_ids(
                             s_id,
                             term.unwrap_or_default(),
+                            include_archived.unwrap_or(false),
                             lim.or_else(|| Some(FilterLimit::default()))
                                 .map(|l| l as usize),
+                            order,
                         ),
                     )
                     .await
@@ -135,6 +149,65 @@ pub fn ListSportConfigurations() -> impl IntoView {
         }
     });
 
+    // archive selected sport config
+    let archive_sport_config = ServerAction::<ArchiveSportConfig>::new();
+    activity_tracker.track_pending_memo(component_id.get_value(), archive_sport_config.pending());
+    Effect::new(move || match archive_sport_config.value().get() {
+        Some(Ok(archived)) => {
+            toast_ctx.success(format!("Archived {}", archived.get_name()), None);
+            sport_config_editor_map.set_selected_id.run(None);
+            sport_config_editor_map.remove_editor(archived.get_id());
+            sport_config_ids.refetch();
+        }
+        Some(Err(err)) => {
+            toast_ctx.error(
+                format!("Could not archive Sport Configuration: {err}"),
+                None,
+            );
+        }
+        None => {}
+    });
+
+    // archive checked sport configs at once
+    let archive_sport_configs = ServerAction::<ArchiveSportConfigs>::new();
+    activity_tracker.track_pending_memo(component_id.get_value(), archive_sport_configs.pending());
+    let is_bulk_archive_open = RwSignal::new(false);
+    Effect::new(move || match archive_sport_configs.value().get() {
+        Some(Ok(archived)) => {
+            toast_ctx.success(
+                format!("Archived {} Sport Configurations", archived.len()),
+                None,
+            );
+            sport_config_editor_map.set_all_checked(false);
+            sport_config_editor_map.set_selected_id.run(None);
+            for sc in archived.iter() {
+                sport_config_editor_map.remove_editor(sc.get_id());
+            }
+            sport_config_ids.refetch();
+        }
+        Some(Err(err)) => {
+            toast_ctx.error(
+                format!("Could not archive Sport Configurations: {err}"),
+                None,
+            );
+        }
+        None => {}
+    });
+    let on_confirm_bulk_archive = Callback::new(move |()| {
+        is_bulk_archive_open.set(false);
+        let configs = sport_config_editor_map
+            .checked_ids()
+            .into_iter()
+            .filter_map(|id| {
+                sport_config_editor_map
+                    .get_editor_untracked(id)
+                    .and_then(|editor| editor.version.get_untracked())
+                    .map(|version| IdVersion::new(id, Some(version)))
+            })
+            .collect();
+        archive_sport_configs.dispatch(ArchiveSportConfigs { configs });
+    });
+
     // on_cancel handler
     let on_cancel = use_on_cancel();
 
@@ -242,6 +315,45 @@ pub fn ListSportConfigurations() -> impl IntoView {
                                                         action=InputCommitAction::SubmitForm
                                                     />
                                                 </div>
+                                                // Sort Selectors
+                                                <div class="w-full max-w-xs">
+                                                    <EnumSelect<
+                                                    SportConfigSortColumn,
+                                                >
+                                                        name=SortByQuery::KEY
+                                                        label="Sort by"
+                                                        value=Signal::derive(move || Some(order.get().get_sort_by()))
+                                                        data_testid="filter-sort-by-select"
+                                                        action=InputCommitAction::SubmitForm
+                                                    />
+                                                </div>
+                                                <div class="w-full max-w-xs">
+                                                    <EnumSelect<
+                                                    SortDirection,
+                                                >
+                                                        name=SortDirectionQuery::KEY
+                                                        label="Direction"
+                                                        value=Signal::derive(move || Some(order.get().get_direction()))
+                                                        data_testid="filter-sort-direction-select"
+                                                        action=InputCommitAction::SubmitForm
+                                                    />
+                                                </div>
+
+                                                // Archived Toggle
+                                                <div class="form-control w-full max-w-xs flex flex-col">
+                                                    <label class="label">
+                                                        <span class="label-text">"Include Archived"</span>
+                                                    </label>
+                                                    <input
+                                                        type="checkbox"
+                                                        class="toggle"
+                                                        name=IncludeArchivedQuery::KEY
+                                                        data-testid="filter-include-archived-toggle"
+                                                        value="true"
+                                                        prop:checked=move || include_archived.get().unwrap_or(false)
+                                                        oninput="this.form.requestSubmit()"
+                                                    />
+                                                </div>
                                             </div>
                                         </Form>
 
@@ -269,6 +381,7 @@ pub fn ListSportConfigurations() -> impl IntoView {
                                                 <table class="table w-full" data-testid="table-list">
                                                     <thead data-testid="table-list-header">
                                                         <tr>
+                                                            <BulkCheckAll map=sport_config_editor_map />
                                                             <th>"Name"</th>
                                                             <th>"Preview"</th>
                                                         </tr>
@@ -287,6 +400,15 @@ pub fn ListSportConfigurations() -> impl IntoView {
                                                 </table>
                                             </Show>
                                         </div>
+                                        // --- Pager ---
+                                        <ListPager
+                                            num_hits=Signal::derive(move || {
+                                                sport_config_editor_map.visible_ids_list.with(|ids| ids.len())
+                                            })
+                                            page_size=Signal::derive(move || {
+                                                limit.get().unwrap_or_default() as usize
+                                            })
+                                        />
                                         // --- Action Bar ---
                                         <div class="flex flex-col md:flex-row justify-end gap-4">
                                             <div class:hidden=move || {
@@ -340,6 +462,51 @@ pub fn ListSportConfigurations() -> impl IntoView {
                                             >
                                                 "Copy selected Sport Configuration"
                                             </button>
+                                            <button
+                                                class="btn btn-sm btn-warning"
+                                                class:hidden=move || {
+                                                    sport_config_editor_map
+                                                        .selected_id
+                                                        .get()
+                                                        .and_then(|id| sport_config_editor_map.get_editor(id))
+                                                        .and_then(|editor| editor.local_read_only.get())
+                                                        .is_none_or(|sc| sc.is_archived())
+                                                }
+                                                data-testid="action-btn-archive"
+                                                disabled=move || archive_sport_config.pending().get()
+                                                on:click=move |_| {
+                                                    if let Some(editor) = sport_config_editor_map
+                                                        .selected_id
+                                                        .get_untracked()
+                                                        .and_then(|id| sport_config_editor_map.get_editor_untracked(id))
+                                                        && let (Some(id), Some(version)) = (
+                                                            editor.id.get_untracked(),
+                                                            editor.version.get_untracked(),
+                                                        )
+                                                    {
+                                                        archive_sport_config
+                                                            .dispatch(ArchiveSportConfig { id, version });
+                                                    }
+                                                }
+                                            >
+                                                "Archive selected Sport Configuration"
+                                            </button>
+                                            <button
+                                                class="btn btn-sm btn-warning"
+                                                class:hidden=move || {
+                                                    sport_config_editor_map.checked_ids().is_empty()
+                                                }
+                                                data-testid="action-btn-bulk-archive"
+                                                disabled=move || archive_sport_configs.pending().get()
+                                                on:click=move |_| is_bulk_archive_open.set(true)
+                                            >
+                                                {move || {
+                                                    format!(
+                                                        "Archive {} checked Sport Configurations",
+                                                        sport_config_editor_map.checked_ids().len(),
+                                                    )
+                                                }}
+                                            </button>
                                             <button
                                                 class="btn btn-sm btn-primary"
                                                 data-testid="action-btn-new"
@@ -375,6 +542,15 @@ pub fn ListSportConfigurations() -> impl IntoView {
                                         </div>
                                     </div>
                                 </div>
+                                <BulkConfirmDialog
+                                    open=is_bulk_archive_open
+                                    title="Archive Sport Configurations"
+                                    count=Signal::derive(move || {
+                                        sport_config_editor_map.checked_ids().len()
+                                    })
+                                    on_confirm=on_confirm_bulk_archive
+                                    on_cancel=Callback::new(move |()| is_bulk_archive_open.set(false))
+                                />
                                 <div class="my-4"></div>
                                 <Outlet />
                             }
@@ -435,11 +611,24 @@ fn SportConfigTableRow(id: Uuid) -> impl IntoView {
                                                 }
                                             }
                                         >
+                                            <BulkCheckCell map=sport_config_editor_map id=id />
                                             <td
                                                 class="font-bold"
                                                 data-testid=format!("table-entry-name-{}", id)
                                             >
                                                 {move || sport_config_editor.name.get()}
+                                                <Show when=move || {
+                                                    sport_config_editor
+                                                        .local_read_only
+                                                        .with(|sc| sc.as_ref().is_some_and(|sc| sc.is_archived()))
+                                                }>
+                                                    <span
+                                                        class="badge badge-ghost ml-2"
+                                                        data-testid=format!("table-entry-archived-{}", id)
+                                                    >
+                                                        "Archived"
+                                                    </span>
+                                                </Show>
                                             </td>
                                             <td data-testid=format!(
                                                 "table-entry-preview-{}",
@@ -458,7 +647,7 @@ fn SportConfigTableRow(id: Uuid) -> impl IntoView {
                                         </tr>
                                         <Show when=move || sport_config_editor_map.is_selected(id)>
                                             <tr>
-                                                <td colspan="2" class="p-0">
+                                                <td colspan="3" class="p-0">
                                                     {move || {
                                                         sport_config_editor
                                                             .local_read_only
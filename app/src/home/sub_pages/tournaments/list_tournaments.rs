@@ -1,8 +1,16 @@
 //! list tournaments
 
-use app_core::{CrTopic, TournamentState};
+use app_core::{
+    CrTopic, TournamentBase, TournamentBaseSortColumn, TournamentState,
+    utils::{id_version::IdVersion, list_order::SortDirection},
+};
 use app_utils::{
-    components::inputs::{EnumSelect, InputCommitAction, InputUpdateStrategy, TextInput},
+    components::{
+        address_select::AddressSelect,
+        bulk_select::{BulkCheckAll, BulkCheckCell, BulkConfirmDialog},
+        inputs::{EnumSelect, InputCommitAction, InputUpdateStrategy, TextInput},
+        list_pager::ListPager,
+    },
     enum_utils::EditAction,
     enum_utils::FilterLimit,
     error::{
@@ -10,6 +18,7 @@ use app_utils::{
         strategy::{handle_read_error, handle_unexpected_ui_error},
     },
     hooks::{
+        use_list_order::use_list_order,
         use_on_cancel::use_on_cancel,
         use_scroll_into_view::use_scroll_h2_into_view,
         use_url_navigation::{
@@ -17,10 +26,14 @@ use app_utils::{
         },
     },
     params::{
-        EditActionParams, FilterLimitQuery, FilterNameQuery, IncludeAdhocQuery, ParamQuery,
-        SportIdQuery, TournamentBaseIdQuery, TournamentStateQuery,
+        DistanceFromQuery, EditActionParams, FilterLimitQuery, FilterNameQuery, IncludeAdhocQuery,
+        IncludeArchivedQuery, ParamQuery, SortByQuery, SortDirectionQuery, SportIdQuery,
+        TournamentBaseIdQuery, TournamentStateQuery,
+    },
+    server_fn::tournament_base::{
+        ArchiveTournament, CloneTournament, DeleteDraftTournaments, PurgeSandboxTournament,
+        list_tournament_base_ids, load_tournament_distance_km,
     },
-    server_fn::tournament_base::list_tournament_base_ids,
     state::{
         LabeledAction, SimpleEditorOptions, activity_tracker::ActivityTracker,
         error_state::PageErrorContext, object_table::ObjectEditorMapContext,
@@ -69,7 +82,9 @@ pub fn ListTournaments() -> impl IntoView {
     let search_term = FilterNameQuery::use_param_query();
     let tournament_state = TournamentStateQuery::use_param_query();
     let include_adhoc = IncludeAdhocQuery::use_param_query();
+    let include_archived = IncludeArchivedQuery::use_param_query();
     let limit = FilterLimitQuery::use_param_query();
+    let order = use_list_order::<TournamentBase>();
 
     // Resource that fetches data when filters change
     let tournament_ids = Resource::new(
@@ -79,21 +94,25 @@ pub fn ListTournaments() -> impl IntoView {
                 search_term.get(),
                 tournament_state.get(),
                 include_adhoc.get(),
+                include_archived.get(),
                 limit.get(),
+                order.get(),
             )
         },
-        move |(maybe_sport_id, term, status, include_adhoc, lim)| async move {
+        move |(maybe_sport_id, term, status, adhoc, archived, lim, order)| async move {
             if let Some(s_id) = maybe_sport_id {
                 activity_tracker
-                    .track_activity_wrapper(
+                    .track_request(
                         component_id.get_value(),
                         list_tournament_base_ids(
                             s_id,
                             term.unwrap_or_default(),
                             status,
-                            include_adhoc.unwrap_or(false),
+                            adhoc.unwrap_or(false),
+                            archived.unwrap_or(false),
                             lim.or_else(|| Some(FilterLimit::default()))
                                 .map(|l| l as usize),
+                            order,
                         ),
                     )
                     .await
@@ -135,6 +154,99 @@ pub fn ListTournaments() -> impl IntoView {
         }
     });
 
+    // archive selected tournament
+    let archive_tournament = ServerAction::<ArchiveTournament>::new();
+    activity_tracker.track_pending_memo(component_id.get_value(), archive_tournament.pending());
+    Effect::new(move || match archive_tournament.value().get() {
+        Some(Ok(archived)) => {
+            toast_ctx.success(format!("Archived {}", archived.get_name()), None);
+            tournament_editor_map.set_selected_id.run(None);
+            tournament_editor_map.remove_editor(archived.get_id());
+            tournament_ids.refetch();
+        }
+        Some(Err(err)) => {
+            toast_ctx.error(format!("Could not archive Tournament: {err}"), None);
+        }
+        None => {}
+    });
+
+    // purge selected sandbox tournament
+    let purge_sandbox = ServerAction::<PurgeSandboxTournament>::new();
+    activity_tracker.track_pending_memo(component_id.get_value(), purge_sandbox.pending());
+    Effect::new(move || match purge_sandbox.value().get() {
+        Some(Ok(purged)) => {
+            toast_ctx.success(format!("Purged {}", purged.get_name()), None);
+            tournament_editor_map.set_selected_id.run(None);
+            tournament_editor_map.remove_editor(purged.get_id());
+            tournament_ids.refetch();
+        }
+        Some(Err(err)) => {
+            toast_ctx.error(format!("Could not purge Tournament: {err}"), None);
+        }
+        None => {}
+    });
+
+    // use selected tournament as template for a new tournament
+    let clone_tournament = ServerAction::<CloneTournament>::new();
+    activity_tracker.track_pending_memo(component_id.get_value(), clone_tournament.pending());
+    Effect::new(move || match clone_tournament.value().get() {
+        Some(Ok(copy)) => {
+            toast_ctx.success(format!("Created {} from template", copy.get_name()), None);
+            tournament_ids.refetch();
+            let navigate = use_navigate();
+            let nav_url = url_matched_route_update_query(
+                TournamentBaseIdQuery::KEY,
+                &copy.get_id().to_string(),
+                MatchedRouteHandler::Extend("edit"),
+            );
+            navigate(
+                &nav_url,
+                NavigateOptions {
+                    scroll: false,
+                    ..Default::default()
+                },
+            );
+        }
+        Some(Err(err)) => {
+            toast_ctx.error(format!("Could not use Tournament as template: {err}"), None);
+        }
+        None => {}
+    });
+
+    // delete checked draft tournaments at once
+    let delete_drafts = ServerAction::<DeleteDraftTournaments>::new();
+    activity_tracker.track_pending_memo(component_id.get_value(), delete_drafts.pending());
+    let is_bulk_delete_open = RwSignal::new(false);
+    Effect::new(move || match delete_drafts.value().get() {
+        Some(Ok(deleted)) => {
+            toast_ctx.success(format!("Deleted {} draft Tournaments", deleted.len()), None);
+            tournament_editor_map.set_all_checked(false);
+            tournament_editor_map.set_selected_id.run(None);
+            for id in deleted {
+                tournament_editor_map.remove_editor(id);
+            }
+            tournament_ids.refetch();
+        }
+        Some(Err(err)) => {
+            toast_ctx.error(format!("Could not delete draft Tournaments: {err}"), None);
+        }
+        None => {}
+    });
+    let on_confirm_bulk_delete = Callback::new(move |()| {
+        is_bulk_delete_open.set(false);
+        let tournaments = tournament_editor_map
+            .checked_ids()
+            .into_iter()
+            .filter_map(|id| {
+                tournament_editor_map
+                    .get_editor_untracked(id)
+                    .and_then(|editor| editor.base_editor.version.get_untracked())
+                    .map(|version| IdVersion::new(id, Some(version)))
+            })
+            .collect();
+        delete_drafts.dispatch(DeleteDraftTournaments { tournaments });
+    });
+
     // on_cancel handler
     let on_cancel = use_on_cancel();
 
@@ -257,6 +369,40 @@ pub fn ListTournaments() -> impl IntoView {
                                                         action=InputCommitAction::SubmitForm
                                                     />
                                                 </div>
+                                                // Sort Selectors
+                                                <div class="w-full max-w-xs">
+                                                    <EnumSelect<
+                                                    TournamentBaseSortColumn,
+                                                >
+                                                        name=SortByQuery::KEY
+                                                        label="Sort by"
+                                                        value=Signal::derive(move || Some(order.get().get_sort_by()))
+                                                        data_testid="filter-sort-by-select"
+                                                        action=InputCommitAction::SubmitForm
+                                                    />
+                                                </div>
+                                                <div class="w-full max-w-xs">
+                                                    <EnumSelect<
+                                                    SortDirection,
+                                                >
+                                                        name=SortDirectionQuery::KEY
+                                                        label="Direction"
+                                                        value=Signal::derive(move || Some(order.get().get_direction()))
+                                                        data_testid="filter-sort-direction-select"
+                                                        action=InputCommitAction::SubmitForm
+                                                    />
+                                                </div>
+                                                // Distance Selector
+                                                <div class="w-full max-w-xs">
+                                                    <AddressSelect
+                                                        name=DistanceFromQuery::KEY
+                                                        label="Distance from"
+                                                        value=DistanceFromQuery::use_param_query()
+                                                        data_testid="filter-distance-from-select"
+                                                        action=InputCommitAction::SubmitForm
+                                                        clear_label="No distance"
+                                                    />
+                                                </div>
 
                                                 // Adhoc Toggle
                                                 <div class="form-control w-full max-w-xs flex flex-col">
@@ -273,6 +419,22 @@ pub fn ListTournaments() -> impl IntoView {
                                                         oninput="this.form.requestSubmit()"
                                                     />
                                                 </div>
+
+                                                // Archived Toggle
+                                                <div class="form-control w-full max-w-xs flex flex-col">
+                                                    <label class="label">
+                                                        <span class="label-text">"Include Archived"</span>
+                                                    </label>
+                                                    <input
+                                                        type="checkbox"
+                                                        class="toggle"
+                                                        name=IncludeArchivedQuery::KEY
+                                                        data-testid="filter-include-archived-toggle"
+                                                        value="true"
+                                                        prop:checked=move || include_archived.get().unwrap_or(false)
+                                                        oninput="this.form.requestSubmit()"
+                                                    />
+                                                </div>
                                             </div>
                                         </Form>
                                         // --- Table Area ---
@@ -299,8 +461,10 @@ pub fn ListTournaments() -> impl IntoView {
                                                 <table class="table w-full" data-testid="tournaments-table">
                                                     <thead data-testid="tournaments-table-header">
                                                         <tr>
+                                                            <BulkCheckAll map=tournament_editor_map />
                                                             <th>"Name"</th>
                                                             <th>"Preview"</th>
+                                                            <th>"Distance"</th>
                                                         </tr>
                                                     </thead>
                                                     <tbody>
@@ -317,6 +481,15 @@ pub fn ListTournaments() -> impl IntoView {
                                                 </table>
                                             </Show>
                                         </div>
+                                        // --- Pager ---
+                                        <ListPager
+                                            num_hits=Signal::derive(move || {
+                                                tournament_editor_map.visible_ids_list.with(|ids| ids.len())
+                                            })
+                                            page_size=Signal::derive(move || {
+                                                limit.get().unwrap_or_default() as usize
+                                            })
+                                        />
                                         // --- Action Bar ---
                                         <div class="flex flex-col md:flex-row justify-end gap-4">
                                             <div class:hidden=move || {
@@ -345,6 +518,103 @@ pub fn ListTournaments() -> impl IntoView {
                                             >
                                                 "Copy selected Tournament"
                                             </button>
+                                            <button
+                                                class="btn btn-sm btn-secondary-content"
+                                                class:hidden=move || {
+                                                    tournament_editor_map.selected_id.get().is_none()
+                                                }
+                                                data-testid="action-btn-use-as-template"
+                                                disabled=move || clone_tournament.pending().get()
+                                                on:click=move |_| {
+                                                    if let Some(editor) = tournament_editor_map
+                                                        .selected_id
+                                                        .get_untracked()
+                                                        .and_then(|id| tournament_editor_map.get_editor_untracked(id))
+                                                        && let (Some(source_id), Some(name)) = (
+                                                            editor.base_editor.id.get_untracked(),
+                                                            editor.base_editor.name.get_untracked(),
+                                                        )
+                                                    {
+                                                        clone_tournament
+                                                            .dispatch(CloneTournament {
+                                                                source_id,
+                                                                new_name: format!("{name} (Copy)"),
+                                                            });
+                                                    }
+                                                }
+                                            >
+                                                "Use selected Tournament as template"
+                                            </button>
+                                            <button
+                                                class="btn btn-sm btn-warning"
+                                                class:hidden=move || {
+                                                    tournament_editor_map
+                                                        .selected_id
+                                                        .get()
+                                                        .and_then(|id| tournament_editor_map.get_editor(id))
+                                                        .and_then(|editor| editor.base_editor.local.get())
+                                                        .is_none_or(|tb| tb.is_archived())
+                                                }
+                                                data-testid="action-btn-archive"
+                                                disabled=move || archive_tournament.pending().get()
+                                                on:click=move |_| {
+                                                    if let Some(editor) = tournament_editor_map
+                                                        .selected_id
+                                                        .get_untracked()
+                                                        .and_then(|id| tournament_editor_map.get_editor_untracked(id))
+                                                        && let (Some(id), Some(version)) = (
+                                                            editor.base_editor.id.get_untracked(),
+                                                            editor.base_editor.version.get_untracked(),
+                                                        )
+                                                    {
+                                                        archive_tournament
+                                                            .dispatch(ArchiveTournament { id, version });
+                                                    }
+                                                }
+                                            >
+                                                "Archive selected Tournament"
+                                            </button>
+                                            <button
+                                                class="btn btn-sm btn-error"
+                                                class:hidden=move || {
+                                                    tournament_editor_map
+                                                        .selected_id
+                                                        .get()
+                                                        .and_then(|id| tournament_editor_map.get_editor(id))
+                                                        .and_then(|editor| editor.base_editor.local.get())
+                                                        .is_none_or(|tb| !tb.is_sandbox())
+                                                }
+                                                data-testid="action-btn-purge-sandbox"
+                                                disabled=move || purge_sandbox.pending().get()
+                                                on:click=move |_| {
+                                                    if let Some(id) = tournament_editor_map
+                                                        .selected_id
+                                                        .get_untracked()
+                                                        .and_then(|id| tournament_editor_map.get_editor_untracked(id))
+                                                        .and_then(|editor| editor.base_editor.id.get_untracked())
+                                                    {
+                                                        purge_sandbox.dispatch(PurgeSandboxTournament { id });
+                                                    }
+                                                }
+                                            >
+                                                "Purge selected Sandbox"
+                                            </button>
+                                            <button
+                                                class="btn btn-sm btn-error"
+                                                class:hidden=move || {
+                                                    tournament_editor_map.checked_ids().is_empty()
+                                                }
+                                                data-testid="action-btn-bulk-delete"
+                                                disabled=move || delete_drafts.pending().get()
+                                                on:click=move |_| is_bulk_delete_open.set(true)
+                                            >
+                                                {move || {
+                                                    format!(
+                                                        "Delete {} checked draft Tournaments",
+                                                        tournament_editor_map.checked_ids().len(),
+                                                    )
+                                                }}
+                                            </button>
                                             <button
                                                 class="btn btn-sm btn-primary"
                                                 data-testid="action-btn-new"
@@ -377,6 +647,15 @@ pub fn ListTournaments() -> impl IntoView {
                                         </div>
                                     </div>
                                 </div>
+                                <BulkConfirmDialog
+                                    open=is_bulk_delete_open
+                                    title="Delete draft Tournaments"
+                                    count=Signal::derive(move || {
+                                        tournament_editor_map.checked_ids().len()
+                                    })
+                                    on_confirm=on_confirm_bulk_delete
+                                    on_cancel=Callback::new(move |()| is_bulk_delete_open.set(false))
+                                />
                                 <div class="my-4"></div>
                                 <Outlet />
                             }
@@ -397,6 +676,17 @@ fn TournamentTableRow(id: Uuid) -> impl IntoView {
         .spawn_editor_for_edit_object(SimpleEditorOptions::with_id(id))
         .unwrap();
     let tournament_id = TournamentBaseIdQuery::use_param_query();
+    let distance_from = DistanceFromQuery::use_param_query();
+    // distance of the tournament location from the address selected in the filter bar
+    let distance_km = Resource::new(
+        move || distance_from.get(),
+        move |maybe_from| async move {
+            match maybe_from {
+                Some(from) => load_tournament_distance_km(id, from).await.ok().flatten(),
+                None => None,
+            }
+        },
+    );
 
     view! {
         {move || {
@@ -421,11 +711,38 @@ fn TournamentTableRow(id: Uuid) -> impl IntoView {
                                         }
                                     }
                                 >
+                                    <BulkCheckCell map=tournament_editor_map id=id />
                                     <td
                                         class="font-bold"
                                         data-testid=format!("table-entry-name-{}", id)
                                     >
                                         {move || tournament_editor.base_editor.name.get()}
+                                        <Show when=move || {
+                                            tournament_editor
+                                                .base_editor
+                                                .local
+                                                .with(|tb| tb.as_ref().is_some_and(|tb| tb.is_archived()))
+                                        }>
+                                            <span
+                                                class="badge badge-ghost ml-2"
+                                                data-testid=format!("table-entry-archived-{}", id)
+                                            >
+                                                "Archived"
+                                            </span>
+                                        </Show>
+                                        <Show when=move || {
+                                            tournament_editor
+                                                .base_editor
+                                                .local
+                                                .with(|tb| tb.as_ref().is_some_and(|tb| tb.is_sandbox()))
+                                        }>
+                                            <span
+                                                class="badge badge-info ml-2"
+                                                data-testid=format!("table-entry-sandbox-{}", id)
+                                            >
+                                                "Sandbox"
+                                            </span>
+                                        </Show>
                                     </td>
                                     <td data-testid=format!("table-entry-preview-{}", id)>
                                         <p>
@@ -453,10 +770,21 @@ fn TournamentTableRow(id: Uuid) -> impl IntoView {
                                             }}
                                         </p>
                                     </td>
+                                    <td data-testid=format!("table-entry-distance-{}", id)>
+                                        <Transition>
+                                            {move || {
+                                                distance_km
+                                                    .get()
+                                                    .flatten()
+                                                    .map(|km| format!("{km:.1} km"))
+                                                    .unwrap_or_else(|| "–".to_string())
+                                            }}
+                                        </Transition>
+                                    </td>
                                 </tr>
                                 <Show when=move || tournament_editor_map.is_selected(id)>
                                     <tr>
-                                        <td colspan="2" class="p-0">
+                                        <td colspan="4" class="p-0">
                                             <div
                                                 class="p-4 bg-base-100 border border-base-300 rounded-lg"
                                                 data-testid="table-entry-detailed-preview"
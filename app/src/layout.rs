@@ -2,7 +2,11 @@
 
 use crate::header::Header;
 use app_utils::{
-    components::{global_error_banner::GlobalErrorBanner, toast::ToastContainer},
+    components::{
+        feedback::FeedbackWidget, global_error_banner::GlobalErrorBanner, toast::ToastContainer,
+    },
+    hooks::use_ui_language::use_ui_language,
+    i18n::UiText,
     state::error_state::PageErrorContext,
 };
 use leptos::prelude::*;
@@ -12,6 +16,7 @@ use leptos_router::nested_router::Outlet;
 pub fn Layout() -> impl IntoView {
     // Get context needed for UI logic
     let page_err_ctx = expect_context::<PageErrorContext>();
+    let ui_language = use_ui_language();
 
     view! {
         <div class="flex flex-col min-h-screen">
@@ -19,6 +24,8 @@ pub fn Layout() -> impl IntoView {
 
             <ToastContainer />
 
+            <FeedbackWidget />
+
             <div class="sticky z-40 top-16 bg-base-200">
                 <GlobalErrorBanner />
             </div>
@@ -34,7 +41,10 @@ pub fn Layout() -> impl IntoView {
 
             <footer class="footer footer-center p-4 bg-base-300 text-base-content">
                 <div>
-                    <p>"© 2025 FK-Tournament-Planer - Alle Rechte vorbehalten"</p>
+                    <p>
+                        "© 2025 FK-Tournament-Planer - "
+                        {move || UiText::AllRightsReserved.get(ui_language.get())}
+                    </p>
                 </div>
             </footer>
         </div>
@@ -1,18 +1,37 @@
 #![recursion_limit = "512"]
 // web app ui
 
+pub mod admin;
+pub mod announcer;
+pub mod entrant_schedule;
+pub mod global_search;
 pub mod header;
 pub mod home;
 pub mod layout;
 pub mod postal_addresses;
+pub mod public;
+pub mod score_sheet;
+pub mod scorekeeper;
+pub mod sport_plugins;
 pub mod tournament_tree_navigation;
+pub mod venues;
 
-use app_utils::state::{
-    activity_tracker::ActivityTracker, error_state::PageErrorContext, global_state::GlobalState,
-    toast_state::ToastContext,
+use admin::*;
+use announcer::*;
+use app_utils::{
+    error::reporter::ClientErrorReporter,
+    hooks::{
+        use_service_worker::use_register_service_worker, use_ui_language::use_init_ui_language,
+    },
+    offline::OfflineSync,
+    state::{
+        activity_tracker::ActivityTracker, client_errors::ClientErrorLog,
+        error_state::PageErrorContext, global_state::GlobalState, theme::initial_theme_preference,
+        toast_state::ToastContext,
+    },
 };
-use ddc_plugin::DdcSportPlugin;
-use generic_sport_plugin::GenericSportPlugin;
+use cr_leptos_axum_socket::provide_socket_catch_up;
+use entrant_schedule::*;
 use home::*;
 use layout::*;
 use leptos::prelude::*;
@@ -23,15 +42,24 @@ use leptos_router::{
     path,
 };
 use postal_addresses::*;
+use public::*;
 use reactive_stores::Store;
-use std::sync::Arc;
+use score_sheet::*;
+use scorekeeper::*;
+use sport_plugins::{SportPluginKind, register_sport_plugins, spawn_sport_plugin_parity_check};
+use venues::*;
 
 pub fn provide_global_context() {
     // Provides context that manages stylesheets, titles, meta tags, etc.
     provide_meta_context();
     // Provides the WebSocket socket context for client registry communication
     provide_socket_context();
-    // set context for error reporting
+    // catch up on client registry messages missed while the websocket was disconnected
+    provide_socket_catch_up();
+    // set context for error reporting; recent errors are attached to feedback of users
+    provide_context(ClientErrorLog::new());
+    // uncaught errors of components are reported to the server
+    provide_context(ClientErrorReporter::new());
     let page_error_context = PageErrorContext::new();
     provide_context(page_error_context);
     let toast_context = ToastContext::new();
@@ -39,26 +67,35 @@ pub fn provide_global_context() {
     // set context for global activity tracker
     let activity_tracker = ActivityTracker::new();
     provide_context(activity_tracker);
+    // queue edits while the network is down; uses toast context to report failed replays
+    provide_context(OfflineSync::new());
 
-    let mut global_state = GlobalState::new();
-    global_state
-        .sport_plugin_manager
-        .register(Arc::new(GenericSportPlugin::new()))
-        .unwrap();
-    global_state
-        .sport_plugin_manager
-        .register(Arc::new(DdcSportPlugin::new()))
-        .unwrap();
+    let global_state = GlobalState::new();
+    // clients know all plugins; the server registers the configured ones
+    register_sport_plugins(&global_state.sport_plugin_manager, &SportPluginKind::ALL)
+        .expect("sport plugins have unique ids and names");
+    // effects only run on the client: check once after hydration for drift to the server
+    let sport_plugin_manager = global_state.sport_plugin_manager.clone();
+    Effect::new(move |_| spawn_sport_plugin_parity_check(&sport_plugin_manager));
     provide_context(Store::new(global_state));
 }
 
 pub fn shell(options: LeptosOptions) -> impl IntoView {
+    // render selected theme on the server to avoid a flash of the default theme
+    let data_theme = initial_theme_preference().data_theme();
     view! {
         <!DOCTYPE html>
-        <html lang="en" data-theme="fantasy">
+        <html lang="en" data-theme=data_theme>
             <head>
                 <meta charset="utf-8" />
-                <meta name="viewport" content="width=device-width, initial-scale=1" />
+                <meta name="viewport" content="width=device-width, initial-scale=1, viewport-fit=cover" />
+                // installable as PWA, e.g. for score entry on phones courtside
+                <link rel="manifest" href="/manifest.webmanifest" />
+                <link rel="icon" href="/icon.svg" type="image/svg+xml" />
+                <link rel="apple-touch-icon" href="/icon.svg" />
+                <meta name="theme-color" content="#1e293b" />
+                <meta name="mobile-web-app-capable" content="yes" />
+                <meta name="apple-mobile-web-app-capable" content="yes" />
                 <AutoReload options=options.clone() />
                 <HydrationScripts options />
                 <MetaTags />
@@ -74,6 +111,10 @@ pub fn shell(options: LeptosOptions) -> impl IntoView {
 pub fn App() -> impl IntoView {
     // provide global context elements
     provide_global_context();
+    // load display language selected by user
+    use_init_ui_language();
+    // cache app shell for courtside use on flaky networks
+    use_register_service_worker();
 
     // Get the activity tracker context to reactively toggle the inert state
     let activity_tracker = expect_context::<ActivityTracker>();
@@ -106,11 +147,29 @@ pub fn App() -> impl IntoView {
                         />
                         <TournamentsRoutes />
                         <Route path=path!("adhoc-tournament") view=AdhocTournament />
+                        <Route path=path!("new-tournament-wizard") view=NewTournamentWizard />
                         <SportConfigRoutes />
                         <Route path=path!("about-sport") view=AboutSport />
                     </ParentRoute>
                     <PostalAddressRoutes />
+                    <Route path=path!("/venues") view=Venues />
+                    <Route path=path!("/admin/api-tokens") view=ApiTokens />
+                    <Route path=path!("/admin/webhooks") view=Webhooks />
+                    <Route path=path!("/admin/client-errors") view=ClientErrors />
+                    <Route path=path!("/admin/audit-log") view=AuditLog />
                 </ParentRoute>
+                // spectator pages without editing controls
+                <PublicRoutes />
+                // result entry of scorekeepers with access link
+                <ScorekeeperRoutes />
+                // printable score sheets for referees
+                <ScoreSheetRoutes />
+                // schedules and results of entrants, which entrants may share
+                <EntrantScheduleRoutes />
+                // court calls for a big screen at the venue
+                <AnnouncerRoutes />
+                // full screen display boards for TVs at the venue
+                <DisplayRoutes />
             </Routes>
         </Router>
     }
@@ -4,7 +4,12 @@ use app_core::PostalAddress;
 #[cfg(feature = "test-mock")]
 use app_utils::server_fn::postal_address::save_postal_address_inner;
 use app_utils::{
-    components::inputs::{EnumSelect, InputCommitAction, TextInput},
+    components::{
+        inputs::{EnumSelect, InputCommitAction, TextInput},
+        map_preview::MapPreview,
+        presence_banner::PresenceBanner,
+        version_conflict::VersionConflictDialog,
+    },
     enum_utils::EditAction,
     hooks::{
         use_on_cancel::use_on_cancel,
@@ -69,6 +74,13 @@ pub fn EditPostalAddress() -> impl IntoView {
         }
     });
 
+    // only saved postal addresses have other editors
+    let presence_id = Signal::derive(move || {
+        matches!(edit_action.get(), Some(EditAction::Edit))
+            .then(|| address_id.get())
+            .flatten()
+    });
+
     // cancel function for close / cancel button
     let on_cancel = use_on_cancel();
 
@@ -98,6 +110,7 @@ pub fn EditPostalAddress() -> impl IntoView {
                             <span class="icon-[heroicons--x-mark] w-6 h-6"></span>
                         </button>
                     </div>
+                    <PresenceBanner object_id=presence_id />
                     {move || {
                         editor
                             .try_get()
@@ -201,6 +214,10 @@ fn PostalAddressForm(postal_address_editor: PostalAddressEditorContext) -> impl
     };
 
     view! {
+        <VersionConflictDialog
+            editor=postal_address_editor
+            on_submit=Callback::new(move |()| on_submit())
+        />
         // --- Address Form ---
         <div data-testid="form-address">
             <form on:submit:capture=move |ev| {
@@ -283,6 +300,7 @@ fn PostalAddressForm(postal_address_editor: PostalAddressEditorContext) -> impl
                         field="country"
                     />
                 </fieldset>
+                <MapPreview location=postal_address_editor.location />
             </form>
         </div>
     }
@@ -1,21 +1,28 @@
 //! Postal Address Search Component
 
-use app_core::CrTopic;
+use app_core::{CrTopic, PostalAddress, PostalAddressSortColumn, utils::list_order::SortDirection};
 use app_utils::{
-    components::inputs::{EnumSelect, InputCommitAction, InputUpdateStrategy, TextInput},
+    components::{
+        inputs::{EnumSelect, InputCommitAction, InputUpdateStrategy, TextInput},
+        list_pager::ListPager,
+    },
     enum_utils::{EditAction, FilterLimit},
     error::{
         ComponentError,
         strategy::{handle_read_error, handle_unexpected_ui_error},
     },
     hooks::{
+        use_list_order::use_list_order,
         use_on_cancel::use_on_cancel,
         use_scroll_into_view::use_scroll_h2_into_view,
         use_url_navigation::{
             MatchedRouteHandler, UseMatchedRouteNavigationReturn, use_matched_route_navigation,
         },
     },
-    params::{AddressIdQuery, EditActionParams, FilterLimitQuery, FilterNameQuery, ParamQuery},
+    params::{
+        AddressIdQuery, EditActionParams, FilterLimitQuery, FilterNameQuery, ParamQuery,
+        SortByQuery, SortDirectionQuery,
+    },
     server_fn::postal_address::list_postal_address_ids,
     state::{
         LabeledAction, SimpleEditorOptions, activity_tracker::ActivityTracker,
@@ -71,6 +78,7 @@ pub fn ListPostalAddresses() -> impl IntoView {
     let address_id = AddressIdQuery::use_param_query();
     let search_term = FilterNameQuery::use_param_query();
     let limit = FilterLimitQuery::use_param_query();
+    let order = use_list_order::<PostalAddress>();
 
     // Resource that fetches data when filters change
     let postal_address_ids = Resource::new(
@@ -78,17 +86,19 @@ pub fn ListPostalAddresses() -> impl IntoView {
             (
                 search_term.get(),
                 limit.get(),
+                order.get(),
                 postal_address_editor_map.track_fetch_trigger.get(),
             )
         },
-        move |(term, lim, _)| async move {
+        move |(term, lim, order, _)| async move {
             activity_tracker
-                .track_activity_wrapper(
+                .track_request(
                     component_id.get_value(),
                     list_postal_address_ids(
                         term.unwrap_or_default(),
                         lim.or_else(|| Some(FilterLimit::default()))
                             .map(|l| l as usize),
+                        order,
                     ),
                 )
                 .await
@@ -225,6 +235,29 @@ pub fn ListPostalAddresses() -> impl IntoView {
                                                         action=InputCommitAction::SubmitForm
                                                     />
                                                 </div>
+                                                // Sort Selectors
+                                                <div class="w-full max-w-xs">
+                                                    <EnumSelect<
+                                                    PostalAddressSortColumn,
+                                                >
+                                                        name=SortByQuery::KEY
+                                                        label="Sort by"
+                                                        value=Signal::derive(move || Some(order.get().get_sort_by()))
+                                                        data_testid="filter-sort-by-select"
+                                                        action=InputCommitAction::SubmitForm
+                                                    />
+                                                </div>
+                                                <div class="w-full max-w-xs">
+                                                    <EnumSelect<
+                                                    SortDirection,
+                                                >
+                                                        name=SortDirectionQuery::KEY
+                                                        label="Direction"
+                                                        value=Signal::derive(move || Some(order.get().get_direction()))
+                                                        data_testid="filter-sort-direction-select"
+                                                        action=InputCommitAction::SubmitForm
+                                                    />
+                                                </div>
                                             </div>
                                         </Form>
                                         // --- Table Area ---
@@ -269,6 +302,15 @@ pub fn ListPostalAddresses() -> impl IntoView {
                                                 </table>
                                             </Show>
                                         </div>
+                                        // --- Pager ---
+                                        <ListPager
+                                            num_hits=Signal::derive(move || {
+                                                postal_address_editor_map.visible_ids_list.with(|ids| ids.len())
+                                            })
+                                            page_size=Signal::derive(move || {
+                                                limit.get().unwrap_or_default() as usize
+                                            })
+                                        />
                                         // --- Action Bar ---
                                         <div class="flex flex-col md:flex-row justify-end gap-4">
                                             <div class:hidden=move || {
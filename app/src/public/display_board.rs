@@ -0,0 +1,341 @@
+//! display board for TVs at the venue
+//!
+//! The display board rotates between its pages without any interactive controls. It reloads
+//! on notices of the client registry, e.g. entered results or an explicit reload by the
+//! director.
+
+use super::{PublicBracket, PublicText};
+use app_core::{
+    CrTopic, DISPLAY_PAGE_SECONDS, DisplayBoard, DisplayMatch, DisplayPage, DisplayStandingRow,
+    DisplayStation, Language,
+};
+use app_utils::{
+    components::global_error_banner::GlobalErrorBanner,
+    error::{ComponentError, strategy::handle_read_error},
+    hooks::{use_on_cancel::use_on_cancel, use_ui_language::use_ui_language},
+    params::{ParamQuery, PublicTournamentIdParams},
+    server_fn::public_tournament::load_display_board,
+    state::{activity_tracker::ActivityTracker, error_state::PageErrorContext},
+};
+use cr_leptos_axum_socket::use_client_registry_socket;
+use leptos::prelude::*;
+#[allow(unused_imports)]
+use leptos_router::MatchNestedRoutes;
+use leptos_router::{
+    ParamSegment, StaticSegment,
+    any_nested_route::IntoAnyNestedRoute,
+    components::{ParentRoute, Route},
+    nested_router::Outlet,
+};
+use std::time::Duration;
+use uuid::Uuid;
+
+#[component(transparent)]
+pub fn DisplayRoutes() -> impl MatchNestedRoutes + Clone {
+    view! {
+        <ParentRoute path=StaticSegment("display") view=DisplayLayout>
+            <Route path=ParamSegment(PublicTournamentIdParams::KEY) view=DisplayBoardPage />
+        </ParentRoute>
+    }
+    .into_inner()
+    .into_any_nested_route()
+}
+
+/// full screen layout of display boards without navigation and footer
+#[component]
+pub fn DisplayLayout() -> impl IntoView {
+    view! {
+        <div class="flex flex-col min-h-screen bg-base-200 cursor-none">
+            <div class="sticky z-40 top-0">
+                <GlobalErrorBanner />
+            </div>
+            <main class="flex-grow p-8">
+                <Outlet />
+            </main>
+        </div>
+    }
+}
+
+#[component]
+pub fn DisplayBoardPage() -> impl IntoView {
+    // --- global context and state ---
+    let page_err_ctx = expect_context::<PageErrorContext>();
+    let activity_tracker = expect_context::<ActivityTracker>();
+    let component_id = StoredValue::new(Uuid::new_v4());
+
+    on_cleanup(move || {
+        page_err_ctx.clear_all_for_component(component_id.get_value());
+        activity_tracker.remove_component(component_id.get_value());
+    });
+
+    let tournament_id = PublicTournamentIdParams::use_param_query();
+    let language = use_ui_language();
+
+    let board = Resource::new(
+        move || tournament_id.get(),
+        move |t_id| async move {
+            match t_id {
+                Some(t_id) => activity_tracker
+                    .track_activity_wrapper(component_id.get_value(), load_display_board(t_id))
+                    .await
+                    .map_err(|app_error| ComponentError::new(component_id.get_value(), app_error)),
+                None => Ok(None),
+            }
+        },
+    );
+
+    let refetch = Callback::new(move |()| board.refetch());
+    page_err_ctx.register_retry_handler(component_id.get_value(), refetch);
+
+    // server driven reload: changes of tournament, stages, entrants, results and calls, and
+    // explicit reloads by the director
+    let tournament_topic = Signal::derive(move || {
+        tournament_id
+            .get()
+            .map(|tournament_base_id| CrTopic::TournamentBase { tournament_base_id })
+    });
+    use_client_registry_socket(tournament_topic, None.into(), refetch);
+    let new_stage_topic = Signal::derive(move || {
+        tournament_id
+            .get()
+            .map(|tournament_base_id| CrTopic::NewStage { tournament_base_id })
+    });
+    use_client_registry_socket(new_stage_topic, None.into(), refetch);
+    let entrants_topic = Signal::derive(move || {
+        tournament_id
+            .get()
+            .map(|tournament_id| CrTopic::Entrants { tournament_id })
+    });
+    use_client_registry_socket(entrants_topic, None.into(), refetch);
+    let scores_topic = Signal::derive(move || {
+        tournament_id
+            .get()
+            .map(|tournament_id| CrTopic::LiveScores { tournament_id })
+    });
+    use_client_registry_socket(scores_topic, None.into(), refetch);
+    let calls_topic = Signal::derive(move || {
+        tournament_id
+            .get()
+            .map(|tournament_id| CrTopic::CourtCalls { tournament_id })
+    });
+    use_client_registry_socket(calls_topic, None.into(), refetch);
+    let display_topic = Signal::derive(move || {
+        tournament_id
+            .get()
+            .map(|tournament_id| CrTopic::DisplayBoard { tournament_id })
+    });
+    use_client_registry_socket(display_topic, None.into(), refetch);
+
+    // rotation of pages; effects run only in the browser
+    let page_index = RwSignal::new(0_usize);
+    let page_seconds = Memo::new(move |_| {
+        board
+            .get()
+            .and_then(|res| res.ok().flatten())
+            .map(|b| b.page_seconds)
+            .unwrap_or(DISPLAY_PAGE_SECONDS)
+    });
+    Effect::new(move || {
+        let seconds = page_seconds.get().max(1);
+        if let Ok(handle) = set_interval_with_handle(
+            move || page_index.update(|i| *i = i.wrapping_add(1)),
+            Duration::from_secs(seconds as u64),
+        ) {
+            on_cleanup(move || handle.clear());
+        }
+    });
+
+    let on_cancel = use_on_cancel();
+
+    view! {
+        <div class="flex flex-col gap-8 w-full h-full" data-testid="display-board-root">
+            <Transition fallback=move || {
+                view! { <span class="loading loading-spinner loading-lg"></span> }
+            }>
+                <ErrorBoundary fallback=move |errors| {
+                    for (_err_id, err) in errors.get().into_iter() {
+                        if let Some(comp_err) = err.into_inner().downcast_ref::<ComponentError>() {
+                            handle_read_error(&page_err_ctx, comp_err, on_cancel);
+                        }
+                    }
+                }>
+                    {move || {
+                        board
+                            .and_then(|board| match board.clone() {
+                                Some(board) => {
+                                    view! {
+                                        <DisplayBoardView
+                                            board=board
+                                            page_index=page_index.into()
+                                            language=language
+                                        />
+                                    }
+                                        .into_any()
+                                }
+                                None => {
+                                    view! {
+                                        <div class="alert text-2xl" data-testid="display-board-not-public">
+                                            {move || PublicText::NotPublic.get(language.get())}
+                                        </div>
+                                    }
+                                        .into_any()
+                                }
+                            })
+                    }}
+                </ErrorBoundary>
+            </Transition>
+        </div>
+    }
+}
+
+#[component]
+fn DisplayBoardView(
+    board: DisplayBoard,
+    page_index: Signal<usize>,
+    language: Signal<Language>,
+) -> impl IntoView {
+    let pages = StoredValue::new(board.pages);
+    let current_page = move || {
+        pages.with_value(|pages| {
+            (!pages.is_empty()).then(|| pages[page_index.get() % pages.len()].clone())
+        })
+    };
+
+    view! {
+        <h1 class="text-5xl font-bold text-center" data-testid="display-board-tournament-name">
+            {board.tournament_name}
+        </h1>
+        {move || match current_page() {
+            Some(DisplayPage::Stations(stations)) => {
+                view! { <DisplayStations stations=stations language=language /> }.into_any()
+            }
+            Some(DisplayPage::Standings { stage_name, group_label, rows }) => {
+                view! {
+                    <DisplayStandings
+                        title=format!("{stage_name} – {group_label}")
+                        rows=rows
+                        language=language
+                    />
+                }
+                    .into_any()
+            }
+            Some(DisplayPage::Bracket { stage_name, group_label, rounds }) => {
+                view! {
+                    <div class="flex flex-col gap-4 text-2xl" data-testid="display-board-bracket">
+                        <h2 class="text-4xl font-semibold">
+                            {format!("{stage_name} – {group_label}")}
+                        </h2>
+                        <PublicBracket label=group_label bracket=rounds />
+                    </div>
+                }
+                    .into_any()
+            }
+            None => {
+                view! {
+                    <p class="text-3xl text-center opacity-70" data-testid="display-board-empty">
+                        {move || PublicText::NothingToDisplay.get(language.get())}
+                    </p>
+                }
+                    .into_any()
+            }
+        }}
+    }
+}
+
+#[component]
+fn DisplayStations(stations: Vec<DisplayStation>, language: Signal<Language>) -> impl IntoView {
+    view! {
+        <div class="grid grid-cols-1 lg:grid-cols-2 2xl:grid-cols-3 gap-6" data-testid="display-board-stations">
+            {stations
+                .into_iter()
+                .map(|station| {
+                    view! {
+                        <div class="card bg-base-100 shadow-xl" data-testid="display-board-station">
+                            <div class="card-body gap-4">
+                                <h2 class="card-title text-3xl">{station.name}</h2>
+                                <DisplayMatchLine
+                                    label=PublicText::NowPlaying
+                                    display_match=station.current
+                                    language=language
+                                />
+                                <DisplayMatchLine
+                                    label=PublicText::NextMatch
+                                    display_match=station.next
+                                    language=language
+                                />
+                            </div>
+                        </div>
+                    }
+                })
+                .collect_view()}
+        </div>
+    }
+}
+
+#[component]
+fn DisplayMatchLine(
+    label: PublicText,
+    display_match: Option<DisplayMatch>,
+    language: Signal<Language>,
+) -> impl IntoView {
+    display_match.map(|m| {
+        let start_at = m.start_at.format("%H:%M").to_string();
+        let entrants = m.entrants;
+        view! {
+            <div class="flex flex-col">
+                <span class="text-lg opacity-70">
+                    {move || format!("{} · {start_at}", label.get(language.get()))}
+                </span>
+                <span class="text-2xl">
+                    {move || {
+                        if entrants.is_empty() {
+                            PublicText::ToBeDecided.get(language.get()).to_string()
+                        } else {
+                            entrants.join(" – ")
+                        }
+                    }}
+                </span>
+            </div>
+        }
+    })
+}
+
+#[component]
+fn DisplayStandings(
+    title: String,
+    rows: Vec<DisplayStandingRow>,
+    language: Signal<Language>,
+) -> impl IntoView {
+    view! {
+        <div class="flex flex-col gap-4" data-testid="display-board-standings">
+            <h2 class="text-4xl font-semibold">{title}</h2>
+            <table class="table text-2xl">
+                <thead class="text-xl">
+                    <tr>
+                        <th>{move || PublicText::Rank.get(language.get())}</th>
+                        <th>{move || PublicText::Name.get(language.get())}</th>
+                        <th>{move || PublicText::Points.get(language.get())}</th>
+                        <th>{move || PublicText::Difference.get(language.get())}</th>
+                        <th>{move || PublicText::Byes.get(language.get())}</th>
+                    </tr>
+                </thead>
+                <tbody>
+                    {rows
+                        .into_iter()
+                        .map(|row| {
+                            view! {
+                                <tr>
+                                    <td>{row.rank}</td>
+                                    <td>{row.name}</td>
+                                    <td>{row.victory_points}</td>
+                                    <td>{format!("{:+}", row.relative_score)}</td>
+                                    <td>{row.byes}</td>
+                                </tr>
+                            }
+                        })
+                        .collect_view()}
+                </tbody>
+            </table>
+        </div>
+    }
+}
@@ -0,0 +1,70 @@
+//! public read-only pages for spectators
+//!
+//! Pages of this route tree are not authenticated. They must not contain editing controls
+//! and must only use server functions of `app_utils::server_fn::public_tournament`.
+
+mod display_board;
+mod texts;
+mod tournament;
+
+pub use display_board::*;
+pub use texts::*;
+pub use tournament::*;
+
+use app_utils::{
+    components::{global_error_banner::GlobalErrorBanner, toast::ToastContainer},
+    hooks::use_ui_language::use_ui_language,
+    params::{ParamQuery, PublicTournamentIdParams},
+};
+use leptos::prelude::*;
+#[allow(unused_imports)]
+use leptos_router::MatchNestedRoutes;
+use leptos_router::{
+    ParamSegment, StaticSegment,
+    any_nested_route::IntoAnyNestedRoute,
+    components::{ParentRoute, Route},
+    nested_router::Outlet,
+};
+
+#[component(transparent)]
+pub fn PublicRoutes() -> impl MatchNestedRoutes + Clone {
+    view! {
+        <ParentRoute path=StaticSegment("public") view=PublicLayout>
+            <Route
+                path=(StaticSegment("tournament"), ParamSegment(PublicTournamentIdParams::KEY))
+                view=PublicTournament
+            />
+        </ParentRoute>
+    }
+    .into_inner()
+    .into_any_nested_route()
+}
+
+/// layout of public pages without navigation to editing pages
+#[component]
+pub fn PublicLayout() -> impl IntoView {
+    let language = use_ui_language();
+
+    view! {
+        <div class="flex flex-col min-h-screen">
+            <ToastContainer />
+
+            <div class="sticky z-40 top-0 bg-base-200">
+                <GlobalErrorBanner />
+            </div>
+
+            <main class="flex-grow p-2 sm:p-4 bg-base-200">
+                <Outlet />
+            </main>
+
+            <footer class="footer footer-center p-4 bg-base-300 text-base-content">
+                <div>
+                    <p>
+                        "© 2025 FK-Tournament-Planer - "
+                        {move || PublicText::AllRightsReserved.get(language.get())}
+                    </p>
+                </div>
+            </footer>
+        </div>
+    }
+}
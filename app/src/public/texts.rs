@@ -0,0 +1,213 @@
+//! texts of public pages in all display languages
+
+use app_core::Language;
+
+/// fixed text of public pages
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum PublicText {
+    NotPublic,
+    LanguageLabel,
+    Mode,
+    State,
+    UpcomingMatches,
+    NoMatchesScheduled,
+    ShareImage,
+    Group,
+    Entrants,
+    Rank,
+    Slot,
+    Matches,
+    Score,
+    Seed,
+    Name,
+    Club,
+    EntrantNotFound,
+    Results,
+    NoResultsYet,
+    Time,
+    Station,
+    Opponent,
+    Result,
+    ToBeDecided,
+    WalkoverWon,
+    WalkoverLost,
+    DoubleForfeit,
+    ShareLink,
+    TournamentNotFound,
+    CalledMatches,
+    WarmUp,
+    NoCalls,
+    NowPlaying,
+    NextMatch,
+    Points,
+    Difference,
+    Byes,
+    NothingToDisplay,
+    AllRightsReserved,
+}
+
+impl PublicText {
+    /// Get the text in `language`.
+    pub fn get(self, language: Language) -> &'static str {
+        use Language::*;
+        use PublicText::*;
+        match (self, language) {
+            (NotPublic, En) => "This tournament is not public.",
+            (NotPublic, De) => "Dieses Turnier ist nicht öffentlich.",
+            (NotPublic, Fr) => "Ce tournoi n'est pas public.",
+            (NotPublic, Es) => "Este torneo no es público.",
+            (LanguageLabel, En) => "Language",
+            (LanguageLabel, De) => "Sprache",
+            (LanguageLabel, Fr) => "Langue",
+            (LanguageLabel, Es) => "Idioma",
+            (Mode, En) => "Mode",
+            (Mode, De) => "Modus",
+            (Mode, Fr) => "Mode",
+            (Mode, Es) => "Modalidad",
+            (State, En) => "State",
+            (State, De) => "Status",
+            (State, Fr) => "État",
+            (State, Es) => "Estado",
+            (UpcomingMatches, En) => "Upcoming Matches",
+            (UpcomingMatches, De) => "Nächste Spiele",
+            (UpcomingMatches, Fr) => "Prochains matchs",
+            (UpcomingMatches, Es) => "Próximos partidos",
+            (NoMatchesScheduled, En) => "No matches scheduled yet.",
+            (NoMatchesScheduled, De) => "Noch keine Spiele angesetzt.",
+            (NoMatchesScheduled, Fr) => "Aucun match programmé pour l'instant.",
+            (NoMatchesScheduled, Es) => "Todavía no hay partidos programados.",
+            (ShareImage, En) => "Share image",
+            (ShareImage, De) => "Bild teilen",
+            (ShareImage, Fr) => "Partager l'image",
+            (ShareImage, Es) => "Compartir imagen",
+            (Group, En) => "Group",
+            (Group, De) => "Gruppe",
+            (Group, Fr) => "Groupe",
+            (Group, Es) => "Grupo",
+            (Entrants, En) => "Entrants",
+            (Entrants, De) => "Teilnehmer",
+            (Entrants, Fr) => "Participants",
+            (Entrants, Es) => "Participantes",
+            (Rank, En) => "Rank",
+            (Rank, De) => "Platz",
+            (Rank, Fr) => "Rang",
+            (Rank, Es) => "Puesto",
+            (Slot, En) => "Slot",
+            (Slot, De) => "Setzplatz",
+            (Slot, Fr) => "Position",
+            (Slot, Es) => "Posición",
+            (Matches, En) => "Matches",
+            (Matches, De) => "Spiele",
+            (Matches, Fr) => "Matchs",
+            (Matches, Es) => "Partidos",
+            (Score, En) => "Score",
+            (Score, De) => "Punkte",
+            (Score, Fr) => "Points",
+            (Score, Es) => "Puntos",
+            (Seed, En) => "Seed",
+            (Seed, De) => "Setzung",
+            (Seed, Fr) => "Tête de série",
+            (Seed, Es) => "Cabeza de serie",
+            (Name, En) => "Name",
+            (Name, De) => "Name",
+            (Name, Fr) => "Nom",
+            (Name, Es) => "Nombre",
+            (Club, En) => "Club",
+            (Club, De) => "Verein",
+            (Club, Fr) => "Club",
+            (Club, Es) => "Club",
+            (EntrantNotFound, En) => "This entrant is not public.",
+            (EntrantNotFound, De) => "Dieser Teilnehmer ist nicht öffentlich.",
+            (EntrantNotFound, Fr) => "Ce participant n'est pas public.",
+            (EntrantNotFound, Es) => "Este participante no es público.",
+            (Results, En) => "Results",
+            (Results, De) => "Ergebnisse",
+            (Results, Fr) => "Résultats",
+            (Results, Es) => "Resultados",
+            (NoResultsYet, En) => "No results yet.",
+            (NoResultsYet, De) => "Noch keine Ergebnisse.",
+            (NoResultsYet, Fr) => "Pas encore de résultats.",
+            (NoResultsYet, Es) => "Todavía no hay resultados.",
+            (Time, En) => "Time",
+            (Time, De) => "Zeit",
+            (Time, Fr) => "Heure",
+            (Time, Es) => "Hora",
+            (Station, En) => "Station",
+            (Station, De) => "Station",
+            (Station, Fr) => "Terrain",
+            (Station, Es) => "Pista",
+            (Opponent, En) => "Opponent",
+            (Opponent, De) => "Gegner",
+            (Opponent, Fr) => "Adversaire",
+            (Opponent, Es) => "Rival",
+            (Result, En) => "Result",
+            (Result, De) => "Ergebnis",
+            (Result, Fr) => "Résultat",
+            (Result, Es) => "Resultado",
+            (ToBeDecided, En) => "to be decided",
+            (ToBeDecided, De) => "steht noch nicht fest",
+            (ToBeDecided, Fr) => "à déterminer",
+            (ToBeDecided, Es) => "por determinar",
+            (WalkoverWon, En) => "won by walkover",
+            (WalkoverWon, De) => "kampflos gewonnen",
+            (WalkoverWon, Fr) => "gagné par forfait",
+            (WalkoverWon, Es) => "ganado por incomparecencia",
+            (WalkoverLost, En) => "lost by walkover",
+            (WalkoverLost, De) => "kampflos verloren",
+            (WalkoverLost, Fr) => "perdu par forfait",
+            (WalkoverLost, Es) => "perdido por incomparecencia",
+            (DoubleForfeit, En) => "double forfeit",
+            (DoubleForfeit, De) => "beidseitig nicht angetreten",
+            (DoubleForfeit, Fr) => "double forfait",
+            (DoubleForfeit, Es) => "doble incomparecencia",
+            (ShareLink, En) => "Link to share this schedule",
+            (ShareLink, De) => "Link zum Teilen dieses Spielplans",
+            (ShareLink, Fr) => "Lien pour partager ce programme",
+            (ShareLink, Es) => "Enlace para compartir este calendario",
+            (TournamentNotFound, En) => "This tournament does not exist.",
+            (TournamentNotFound, De) => "Dieses Turnier existiert nicht.",
+            (TournamentNotFound, Fr) => "Ce tournoi n'existe pas.",
+            (TournamentNotFound, Es) => "Este torneo no existe.",
+            (CalledMatches, En) => "Please come to your station",
+            (CalledMatches, De) => "Bitte kommt zu eurer Station",
+            (CalledMatches, Fr) => "Veuillez rejoindre votre terrain",
+            (CalledMatches, Es) => "Por favor, acudid a vuestra pista",
+            (WarmUp, En) => "Get ready to warm up",
+            (WarmUp, De) => "Bitte zum Einspielen bereithalten",
+            (WarmUp, Fr) => "Préparez-vous pour l'échauffement",
+            (WarmUp, Es) => "Preparaos para calentar",
+            (NoCalls, En) => "No matches are called right now.",
+            (NoCalls, De) => "Zurzeit werden keine Spiele aufgerufen.",
+            (NoCalls, Fr) => "Aucun match n'est appelé pour le moment.",
+            (NoCalls, Es) => "No se llama a ningún partido en este momento.",
+            (NowPlaying, En) => "Now playing",
+            (NowPlaying, De) => "Läuft gerade",
+            (NowPlaying, Fr) => "En cours",
+            (NowPlaying, Es) => "En juego",
+            (NextMatch, En) => "Next",
+            (NextMatch, De) => "Als Nächstes",
+            (NextMatch, Fr) => "Ensuite",
+            (NextMatch, Es) => "A continuación",
+            (Points, En) => "Points",
+            (Points, De) => "Punkte",
+            (Points, Fr) => "Points",
+            (Points, Es) => "Puntos",
+            (Difference, En) => "Difference",
+            (Difference, De) => "Differenz",
+            (Difference, Fr) => "Différence",
+            (Difference, Es) => "Diferencia",
+            (Byes, En) => "Byes",
+            (Byes, De) => "Freilose",
+            (Byes, Fr) => "Exempts",
+            (Byes, Es) => "Descansos",
+            (NothingToDisplay, En) => "The tournament starts soon.",
+            (NothingToDisplay, De) => "Das Turnier beginnt in Kürze.",
+            (NothingToDisplay, Fr) => "Le tournoi commence bientôt.",
+            (NothingToDisplay, Es) => "El torneo comienza pronto.",
+            (AllRightsReserved, En) => "All rights reserved",
+            (AllRightsReserved, De) => "Alle Rechte vorbehalten",
+            (AllRightsReserved, Fr) => "Tous droits réservés",
+            (AllRightsReserved, Es) => "Todos los derechos reservados",
+        }
+    }
+}
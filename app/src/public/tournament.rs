@@ -0,0 +1,415 @@
+//! public read-only view of a tournament
+
+use super::PublicText;
+use app_core::{
+    CrTopic, Entrant, Language, PublicGroup, PublicStage, PublicTournamentView,
+    slots::{KoRound, KoSide},
+};
+use app_utils::{
+    error::{ComponentError, strategy::handle_read_error},
+    hooks::use_url_navigation::{UseQueryNavigationReturn, use_query_navigation},
+    params::{LanguageQuery, ParamQuery, PublicTournamentIdParams},
+    server_fn::public_tournament::load_public_tournament,
+    state::{activity_tracker::ActivityTracker, error_state::PageErrorContext},
+};
+use cr_leptos_axum_socket::use_client_registry_socket;
+use leptos::prelude::*;
+use leptos_router::{components::A, hooks::use_navigate};
+use uuid::Uuid;
+
+#[component]
+pub fn PublicTournament() -> impl IntoView {
+    // --- global context and state ---
+    let page_err_ctx = expect_context::<PageErrorContext>();
+    let activity_tracker = expect_context::<ActivityTracker>();
+    let component_id = StoredValue::new(Uuid::new_v4());
+
+    on_cleanup(move || {
+        page_err_ctx.clear_all_for_component(component_id.get_value());
+        activity_tracker.remove_component(component_id.get_value());
+    });
+
+    let tournament_id = PublicTournamentIdParams::use_param_query();
+    let lang_query = LanguageQuery::use_param_query();
+
+    let public_view = Resource::new(
+        move || tournament_id.get(),
+        move |t_id| async move {
+            match t_id {
+                Some(t_id) => activity_tracker
+                    .track_activity_wrapper(component_id.get_value(), load_public_tournament(t_id))
+                    .await
+                    .map_err(|app_error| ComponentError::new(component_id.get_value(), app_error)),
+                None => Ok(None),
+            }
+        },
+    );
+
+    let refetch = Callback::new(move |()| public_view.refetch());
+    page_err_ctx.register_retry_handler(component_id.get_value(), refetch);
+
+    // live updates of tournament, its stages and its entrants
+    let tournament_topic = Signal::derive(move || {
+        tournament_id
+            .get()
+            .map(|tournament_base_id| CrTopic::TournamentBase { tournament_base_id })
+    });
+    use_client_registry_socket(tournament_topic, None.into(), refetch);
+    let new_stage_topic = Signal::derive(move || {
+        tournament_id
+            .get()
+            .map(|tournament_base_id| CrTopic::NewStage { tournament_base_id })
+    });
+    use_client_registry_socket(new_stage_topic, None.into(), refetch);
+    let entrants_topic = Signal::derive(move || {
+        tournament_id
+            .get()
+            .map(|tournament_id| CrTopic::Entrants { tournament_id })
+    });
+    use_client_registry_socket(entrants_topic, None.into(), refetch);
+
+    // public pages have no parent page; go to start page
+    let navigate = use_navigate();
+    let on_back = Callback::new(move |()| navigate("/", Default::default()));
+
+    view! {
+        <div class="flex flex-col gap-4 w-full max-w-5xl mx-auto" data-testid="public-tournament-root">
+            <Transition fallback=move || {
+                view! { <span class="loading loading-spinner loading-md"></span> }
+            }>
+                <ErrorBoundary fallback=move |errors| {
+                    for (_err_id, err) in errors.get().into_iter() {
+                        if let Some(comp_err) = err.into_inner().downcast_ref::<ComponentError>() {
+                            handle_read_error(&page_err_ctx, comp_err, on_back);
+                        }
+                    }
+                }>
+                    {move || {
+                        public_view
+                            .and_then(|view| match view.clone() {
+                                Some(view) => {
+                                    view! {
+                                        <PublicTournamentContent
+                                            view=view
+                                            lang_query=lang_query
+                                            refetch=refetch
+                                        />
+                                    }
+                                        .into_any()
+                                }
+                                None => {
+                                    view! {
+                                        <div class="alert" data-testid="public-tournament-not-found">
+                                            {move || {
+                                                PublicText::NotPublic
+                                                    .get(lang_query.get().unwrap_or_default())
+                                            }}
+                                        </div>
+                                    }
+                                        .into_any()
+                                }
+                            })
+                    }}
+                </ErrorBoundary>
+            </Transition>
+        </div>
+    }
+}
+
+#[component]
+fn PublicTournamentContent(
+    view: PublicTournamentView,
+    lang_query: Memo<Option<Language>>,
+    refetch: Callback<()>,
+) -> impl IntoView {
+    let tournament = view.tournament;
+    let tournament_id = tournament.get_id();
+    let entrants = view.entrants;
+    let languages = tournament.get_languages().to_vec();
+    let description = tournament.get_description().clone();
+    // requested language, if the tournament is displayed in it; default language otherwise
+    let default_language = tournament.get_default_language();
+    let language = Signal::derive({
+        let tournament = tournament.clone();
+        move || {
+            lang_query
+                .get()
+                .filter(|l| tournament.has_language(*l))
+                .unwrap_or(default_language)
+        }
+    });
+    let mode = tournament.get_tournament_mode();
+    let state = tournament.get_tournament_state();
+    view! {
+        <div class="card w-full bg-base-100 shadow-xl">
+            <div class="card-body">
+                <div class="flex justify-between items-start gap-2">
+                    <h1 class="card-title text-2xl" data-testid="public-tournament-name">
+                        {tournament.get_name().to_string()}
+                    </h1>
+                    <Show when={
+                        let num_languages = languages.len();
+                        move || num_languages > 1
+                    }>
+                        <LanguageToggle languages=languages.clone() language=language />
+                    </Show>
+                </div>
+                <Show when={
+                    let is_empty = description.is_empty();
+                    move || !is_empty
+                }>
+                    <p class="whitespace-pre-line" data-testid="public-tournament-description">
+                        {
+                            let description = description.clone();
+                            move || description.get(language.get()).to_string()
+                        }
+                    </p>
+                </Show>
+                <p>{move || format!("{}: {mode}", PublicText::Mode.get(language.get()))}</p>
+                <p data-testid="public-tournament-state">
+                    {move || format!("{}: {state}", PublicText::State.get(language.get()))}
+                </p>
+            </div>
+        </div>
+        <div class="card w-full bg-base-100 shadow-xl" data-testid="public-upcoming-matches">
+            <div class="card-body">
+                <h2 class="card-title">
+                    {move || PublicText::UpcomingMatches.get(language.get())}
+                </h2>
+                <p class="opacity-70">
+                    {move || PublicText::NoMatchesScheduled.get(language.get())}
+                </p>
+            </div>
+        </div>
+        <For
+            each=move || view.stages.clone()
+            key=|stage| (stage.id, stage.groups.len())
+            children=move |stage| {
+                view! {
+                    <PublicStageCard
+                        tournament_id=tournament_id
+                        stage=stage
+                        language=language
+                        refetch=refetch
+                    />
+                }
+            }
+        />
+        <PublicEntrants entrants=entrants language=language />
+    }
+}
+
+/// links to display the public page in each language of the tournament
+#[component]
+fn LanguageToggle(languages: Vec<Language>, language: Signal<Language>) -> impl IntoView {
+    let UseQueryNavigationReturn {
+        url_update_query, ..
+    } = use_query_navigation();
+    view! {
+        <div
+            class="join"
+            role="group"
+            aria-label=move || PublicText::LanguageLabel.get(language.get())
+            data-testid="public-language-toggle"
+        >
+            {languages
+                .into_iter()
+                .map(|l| {
+                    view! {
+                        <A
+                            href=move || url_update_query(LanguageQuery::KEY, l.code(), None)
+                            attr:class=move || {
+                                if language.get() == l {
+                                    "join-item btn btn-sm btn-primary"
+                                } else {
+                                    "join-item btn btn-sm"
+                                }
+                            }
+                            attr:data-testid=format!("public-language-{}", l.code())
+                            attr:title=l.to_string()
+                            scroll=false
+                        >
+                            {l.code().to_uppercase()}
+                        </A>
+                    }
+                })
+                .collect_view()}
+        </div>
+    }
+}
+
+#[component]
+fn PublicStageCard(
+    tournament_id: Uuid,
+    stage: PublicStage,
+    language: Signal<Language>,
+    refetch: Callback<()>,
+) -> impl IntoView {
+    // subscribe to changes of the stage, e.g. number of groups
+    let stage_id = stage.id;
+    use_client_registry_socket(
+        Signal::derive(move || Some(CrTopic::Stage { stage_id })),
+        None.into(),
+        refetch,
+    );
+    // image of stage for sharing, e.g. in chats or on a beamer
+    let image_url = format!(
+        "/api/tournament/{tournament_id}/stage.png?stage={}",
+        stage.number
+    );
+    view! {
+        <div class="card w-full bg-base-100 shadow-xl" data-testid="public-stage">
+            <div class="card-body">
+                <div class="flex justify-between items-center">
+                    <h2 class="card-title">{stage.name}</h2>
+                    <a
+                        class="btn btn-sm btn-ghost"
+                        href=image_url
+                        target="_blank"
+                        rel="noopener"
+                        data-testid="public-stage-image-link"
+                    >
+                        {move || PublicText::ShareImage.get(language.get())}
+                    </a>
+                </div>
+                <div class="grid grid-cols-1 md:grid-cols-2 gap-4">
+                    {stage
+                        .groups
+                        .into_iter()
+                        .map(|group| view! { <PublicGroupView group=group language=language /> })
+                        .collect_view()}
+                </div>
+            </div>
+        </div>
+    }
+}
+
+#[component]
+fn PublicGroupView(group: PublicGroup, language: Signal<Language>) -> impl IntoView {
+    let label = group.label.clone();
+    let group_label = group.label.clone();
+    let num_entrants = group.num_entrants;
+    let is_bracket = !group.bracket.is_empty();
+    view! {
+        <div class="flex flex-col gap-2" data-testid="public-group">
+            <h3 class="font-bold">
+                {move || {
+                    let language = language.get();
+                    format!(
+                        "{} {group_label} ({num_entrants} {})",
+                        PublicText::Group.get(language),
+                        PublicText::Entrants.get(language),
+                    )
+                }}
+            </h3>
+            <table class="table table-sm" data-testid="public-group-standings">
+                <thead>
+                    <tr>
+                        <th>{move || PublicText::Rank.get(language.get())}</th>
+                        <th>{move || PublicText::Slot.get(language.get())}</th>
+                        <th>{move || PublicText::Matches.get(language.get())}</th>
+                        <th>{move || PublicText::Score.get(language.get())}</th>
+                    </tr>
+                </thead>
+                <tbody>
+                    {(1..=group.num_entrants)
+                        .map(|slot| {
+                            view! {
+                                <tr>
+                                    <td>"-"</td>
+                                    <td>{format!("{label}{slot}")}</td>
+                                    <td>"0"</td>
+                                    <td>"0"</td>
+                                </tr>
+                            }
+                        })
+                        .collect_view()}
+                </tbody>
+            </table>
+            <Show when=move || is_bracket>
+                <PublicBracket label=group.label.clone() bracket=group.bracket.clone() />
+            </Show>
+        </div>
+    }
+}
+
+/// KO bracket of a group, rounds from left to right
+#[component]
+pub fn PublicBracket(label: String, bracket: Vec<KoRound>) -> impl IntoView {
+    let side = move |side: KoSide| match side {
+        KoSide::Slot(slot) => format!("{label}{slot}"),
+        KoSide::WinnerOf(number) => format!("Winner M{number}"),
+        KoSide::LoserOf(number) => format!("Loser M{number}"),
+    };
+    view! {
+        <div class="flex flex-row gap-4 overflow-x-auto" data-testid="public-bracket">
+            {bracket
+                .into_iter()
+                .map(|round| {
+                    view! {
+                        <div class="flex flex-col justify-around gap-2">
+                            <h4 class="font-semibold">{round.name}</h4>
+                            {round
+                                .matches
+                                .into_iter()
+                                .map(|m| {
+                                    view! {
+                                        <div class="border rounded p-2 text-sm">
+                                            <div class="opacity-70">{format!("M{}", m.number)}</div>
+                                            <div>{side(m.side_a)}</div>
+                                            <div>{side(m.side_b)}</div>
+                                        </div>
+                                    }
+                                })
+                                .collect_view()}
+                        </div>
+                    }
+                })
+                .collect_view()}
+        </div>
+    }
+}
+
+#[component]
+fn PublicEntrants(entrants: Vec<Entrant>, language: Signal<Language>) -> impl IntoView {
+    view! {
+        <div class="card w-full bg-base-100 shadow-xl" data-testid="public-entrants">
+            <div class="card-body">
+                <h2 class="card-title">{move || PublicText::Entrants.get(language.get())}</h2>
+                <table class="table table-sm">
+                    <thead>
+                        <tr>
+                            <th>{move || PublicText::Seed.get(language.get())}</th>
+                            <th>{move || PublicText::Name.get(language.get())}</th>
+                            <th>{move || PublicText::Club.get(language.get())}</th>
+                        </tr>
+                    </thead>
+                    <tbody>
+                        {entrants
+                            .into_iter()
+                            .map(|entrant| {
+                                view! {
+                                    <tr>
+                                        <td>
+                                            {entrant.get_seed().map(|s| s.to_string()).unwrap_or_default()}
+                                        </td>
+                                        <td>
+                                            <A
+                                                href=format!("/entrant/{}/schedule", entrant.get_id())
+                                                attr:class="link"
+                                                attr:data-testid="public-entrant-schedule-link"
+                                            >
+                                                {entrant.get_name().to_string()}
+                                            </A>
+                                        </td>
+                                        <td>{entrant.get_club().unwrap_or_default().to_string()}</td>
+                                    </tr>
+                                }
+                            })
+                            .collect_view()}
+                    </tbody>
+                </table>
+            </div>
+        </div>
+    }
+}
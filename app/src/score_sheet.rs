@@ -0,0 +1,346 @@
+//! printable score sheet of a match for referees and note of the match for directors
+
+use crate::public::PublicLayout;
+use app_core::{CrTopic, MatchNote, ScoreSheet};
+use app_utils::{
+    error::{ComponentError, strategy::handle_read_error},
+    hooks::use_on_cancel::use_on_cancel,
+    params::{MatchIdParams, ParamQuery},
+    server_fn::{
+        match_note::{SaveMatchNote, load_match_note},
+        score_sheet::load_score_sheet,
+    },
+    state::{
+        activity_tracker::ActivityTracker, error_state::PageErrorContext, toast_state::ToastContext,
+    },
+};
+use cr_leptos_axum_socket::use_client_registry_socket;
+use leptos::prelude::*;
+#[allow(unused_imports)]
+use leptos_router::MatchNestedRoutes;
+use leptos_router::{
+    ParamSegment, StaticSegment,
+    any_nested_route::IntoAnyNestedRoute,
+    components::{ParentRoute, Route},
+};
+use uuid::Uuid;
+
+#[component(transparent)]
+pub fn ScoreSheetRoutes() -> impl MatchNestedRoutes + Clone {
+    view! {
+        // score sheets are printed, therefore they use the layout without navigation
+        <ParentRoute path=StaticSegment("match") view=PublicLayout>
+            <Route
+                path=(ParamSegment(MatchIdParams::KEY), StaticSegment("score-sheet"))
+                view=ScoreSheetPage
+            />
+        </ParentRoute>
+    }
+    .into_inner()
+    .into_any_nested_route()
+}
+
+#[component]
+pub fn ScoreSheetPage() -> impl IntoView {
+    // --- global context and state ---
+    let page_err_ctx = expect_context::<PageErrorContext>();
+    let activity_tracker = expect_context::<ActivityTracker>();
+    let component_id = StoredValue::new(Uuid::new_v4());
+
+    on_cleanup(move || {
+        page_err_ctx.clear_all_for_component(component_id.get_value());
+        activity_tracker.remove_component(component_id.get_value());
+    });
+
+    let match_id = MatchIdParams::use_param_query();
+
+    let sheet = Resource::new(
+        move || match_id.get(),
+        move |m_id| async move {
+            match m_id {
+                Some(m_id) => activity_tracker
+                    .track_activity_wrapper(component_id.get_value(), load_score_sheet(m_id))
+                    .await
+                    .map_err(|app_error| ComponentError::new(component_id.get_value(), app_error)),
+                None => Ok(None),
+            }
+        },
+    );
+
+    let refetch = Callback::new(move |()| sheet.refetch());
+    page_err_ctx.register_retry_handler(component_id.get_value(), refetch);
+
+    let on_cancel = use_on_cancel();
+
+    view! {
+        <div class="flex flex-col gap-4 w-full max-w-3xl mx-auto" data-testid="score-sheet-root">
+            <Transition fallback=move || {
+                view! { <span class="loading loading-spinner loading-md"></span> }
+            }>
+                <ErrorBoundary fallback=move |errors| {
+                    for (_err_id, err) in errors.get().into_iter() {
+                        if let Some(comp_err) = err.into_inner().downcast_ref::<ComponentError>() {
+                            handle_read_error(&page_err_ctx, comp_err, on_cancel);
+                        }
+                    }
+                }>
+                    {move || {
+                        sheet
+                            .and_then(|sheet| match sheet.clone() {
+                                Some(sheet) => {
+                                    let tournament_id = sheet.tournament_id;
+                                    let match_id = sheet.match_id;
+                                    view! {
+                                        <ScoreSheetView sheet=sheet />
+                                        <MatchNoteEditor tournament_id=tournament_id match_id=match_id />
+                                    }
+                                        .into_any()
+                                }
+                                None => {
+                                    view! {
+                                        <div class="alert" data-testid="score-sheet-not-found">
+                                            "Match not found."
+                                        </div>
+                                    }
+                                        .into_any()
+                                }
+                            })
+                    }}
+                </ErrorBoundary>
+            </Transition>
+        </div>
+    }
+}
+
+/// score sheet with one blank box per set and side
+#[component]
+fn ScoreSheetView(sheet: ScoreSheet) -> impl IntoView {
+    let pdf_url = format!("/api/match/{}/scoresheet.pdf", sheet.match_id);
+    let sets: Vec<u16> = (1..=sheet.num_sets.max(1)).collect();
+    let side_row = move |name: String, testid: &'static str| {
+        let boxes = sets
+            .iter()
+            .map(|_| view! { <td class="border border-base-content h-12 w-16"></td> })
+            .collect_view();
+        view! {
+            <tr data-testid=testid>
+                <th class="text-left pr-4">{name}</th>
+                {boxes}
+            </tr>
+        }
+    };
+
+    view! {
+        <div class="card w-full bg-base-100 shadow-xl print:shadow-none" data-testid="score-sheet">
+            <div class="card-body">
+                <div class="flex justify-between items-start gap-2">
+                    <div>
+                        <h1 class="card-title text-2xl">{sheet.tournament_name.clone()}</h1>
+                        <p>{format!("Score Sheet - {}", sheet.sport_name)}</p>
+                    </div>
+                    <div class="flex gap-2 print:hidden">
+                        <button
+                            class="btn btn-sm btn-primary"
+                            data-testid="action-btn-print-score-sheet"
+                            on:click=move |_| {
+                                let _ = window().print();
+                            }
+                        >
+                            "Print"
+                        </button>
+                        // PDF is rendered by the server; external link bypasses the router
+                        <a
+                            class="btn btn-sm btn-outline"
+                            href=pdf_url
+                            target="_blank"
+                            rel="external"
+                            data-testid="action-btn-score-sheet-pdf"
+                        >
+                            "PDF"
+                        </a>
+                    </div>
+                </div>
+                <h2 class="text-xl font-bold" data-testid="score-sheet-match">
+                    {format!("Match {}", sheet.match_number)}
+                </h2>
+                <p data-testid="score-sheet-station">{format!("Station: {}", sheet.station)}</p>
+                <p data-testid="score-sheet-start">
+                    {format!("Start: {}", sheet.start_at.format("%Y-%m-%d %H:%M"))}
+                </p>
+                <table class="table-auto border-collapse my-4" data-testid="score-sheet-sets">
+                    <thead>
+                        <tr>
+                            <th></th>
+                            {(1..=sheet.num_sets.max(1))
+                                .map(|set| view! { <th class="px-2">{format!("Set {set}")}</th> })
+                                .collect_view()}
+                        </tr>
+                    </thead>
+                    <tbody>
+                        {side_row(sheet.side_a.clone(), "score-sheet-side-a")}
+                        {side_row(sheet.side_b.clone(), "score-sheet-side-b")}
+                    </tbody>
+                </table>
+                <div class="grid grid-cols-2 gap-6 mt-4">
+                    <p>"Winner: ____________________"</p>
+                    <p>"Referee: ____________________"</p>
+                    <p>{format!("Signature {}: ____________", sheet.side_a)}</p>
+                    <p>{format!("Signature {}: ____________", sheet.side_b)}</p>
+                </div>
+            </div>
+        </div>
+    }
+}
+
+/// tags and note of the match; hidden, when the score sheet is printed
+#[component]
+fn MatchNoteEditor(tournament_id: Uuid, match_id: Uuid) -> impl IntoView {
+    // --- global context and state ---
+    let page_err_ctx = expect_context::<PageErrorContext>();
+    let toast_ctx = expect_context::<ToastContext>();
+    let activity_tracker = expect_context::<ActivityTracker>();
+    let component_id = StoredValue::new(Uuid::new_v4());
+
+    on_cleanup(move || {
+        page_err_ctx.clear_all_for_component(component_id.get_value());
+        activity_tracker.remove_component(component_id.get_value());
+    });
+
+    // --- local state ---
+    // stored note keeps id and version for updates
+    let note = RwSignal::new(None::<MatchNote>);
+    let author = RwSignal::new(String::new());
+    let tags = RwSignal::new(String::new());
+    let text = RwSignal::new(String::new());
+
+    let loaded = Resource::new(
+        move || (),
+        move |()| async move {
+            activity_tracker
+                .track_activity_wrapper(
+                    component_id.get_value(),
+                    load_match_note(tournament_id, match_id),
+                )
+                .await
+                .map_err(|app_error| ComponentError::new(component_id.get_value(), app_error))
+        },
+    );
+
+    let refetch = Callback::new(move |()| loaded.refetch());
+    page_err_ctx.register_retry_handler(component_id.get_value(), refetch);
+
+    // notes may be changed by other directors
+    let topic = Signal::derive(move || Some(CrTopic::MatchNotes { tournament_id }));
+    use_client_registry_socket(topic, None.into(), refetch);
+
+    Effect::new(move || {
+        if let Some(Ok(Some(stored))) = loaded.get() {
+            author.set(stored.get_author().to_string());
+            tags.set(stored.get_tags().join(", "));
+            text.set(stored.get_text().to_string());
+            note.set(Some(stored));
+        }
+    });
+
+    let save_note = ServerAction::<SaveMatchNote>::new();
+    activity_tracker.track_pending_memo(component_id.get_value(), save_note.pending());
+    Effect::new(move || match save_note.value().get() {
+        Some(Ok(saved)) => {
+            note.set(Some(saved));
+            toast_ctx.success("Match note saved.", None);
+        }
+        Some(Err(err)) => {
+            toast_ctx.error(format!("Could not save match note: {err}"), None);
+        }
+        None => {}
+    });
+
+    let on_submit = Callback::new(move |()| {
+        let mut edited = note.get_untracked().unwrap_or_else(|| {
+            let mut new_note = MatchNote::default();
+            new_note
+                .set_tournament_id(tournament_id)
+                .set_match_id(match_id);
+            new_note
+        });
+        edited
+            .set_author(author.get_untracked())
+            .set_tags(tags.get_untracked().split(','))
+            .set_text(text.get_untracked());
+        if edited.validate().is_ok() {
+            save_note.dispatch(SaveMatchNote { note: edited });
+        } else {
+            toast_ctx.warning("Author and a tag or note are required.", None);
+        }
+    });
+
+    let on_cancel = use_on_cancel();
+
+    view! {
+        <div class="card w-full bg-base-100 shadow-xl print:hidden" data-testid="match-note-editor">
+            <div class="card-body">
+                <h2 class="card-title">"Match Note"</h2>
+                <Transition fallback=move || {
+                    view! { <span class="loading loading-spinner loading-md"></span> }
+                }>
+                    <ErrorBoundary fallback=move |errors| {
+                        for (_err_id, err) in errors.get().into_iter() {
+                            if let Some(comp_err) = err.into_inner().downcast_ref::<ComponentError>() {
+                                handle_read_error(&page_err_ctx, comp_err, on_cancel);
+                            }
+                        }
+                    }>
+                        {move || {
+                            loaded
+                                .and_then(|_| {
+                                    view! {
+                                        <form
+                                            class="flex flex-col gap-2"
+                                            on:submit=move |ev| {
+                                                ev.prevent_default();
+                                                on_submit.run(());
+                                            }
+                                        >
+                                            <input
+                                                type="text"
+                                                class="input input-bordered w-full md:w-64"
+                                                placeholder="Your name"
+                                                data-testid="input-match-note-author"
+                                                prop:value=author
+                                                on:input=move |ev| author.set(event_target_value(&ev))
+                                            />
+                                            <input
+                                                type="text"
+                                                class="input input-bordered w-full"
+                                                placeholder="Tags, separated by commas"
+                                                data-testid="input-match-note-tags"
+                                                prop:value=tags
+                                                on:input=move |ev| tags.set(event_target_value(&ev))
+                                            />
+                                            <textarea
+                                                class="textarea textarea-bordered w-full"
+                                                placeholder="Note of the match"
+                                                data-testid="input-match-note-text"
+                                                prop:value=text
+                                                on:input=move |ev| text.set(event_target_value(&ev))
+                                            ></textarea>
+                                            <div>
+                                                <button
+                                                    type="submit"
+                                                    class="btn btn-primary btn-sm"
+                                                    data-testid="action-btn-save-match-note"
+                                                    disabled=move || save_note.pending().get()
+                                                >
+                                                    "Save Note"
+                                                </button>
+                                            </div>
+                                        </form>
+                                    }
+                                })
+                        }}
+                    </ErrorBoundary>
+                </Transition>
+            </div>
+        </div>
+    }
+}
@@ -0,0 +1,33 @@
+//! reduced result entry pages for scorekeepers
+//!
+//! Scorekeepers have no accounts; they open an access link containing the secret of a
+//! scorekeeper token. Pages of this route tree must only show groups and stations granted
+//! by this token and must only use server functions of `app_utils::server_fn::scorekeeper`,
+//! which authenticate the secret.
+
+mod score_entry;
+
+pub use score_entry::*;
+
+use crate::public::PublicLayout;
+use app_utils::params::{ParamQuery, ScorekeeperSecretParams};
+use leptos::prelude::*;
+#[allow(unused_imports)]
+use leptos_router::MatchNestedRoutes;
+use leptos_router::{
+    ParamSegment, StaticSegment,
+    any_nested_route::IntoAnyNestedRoute,
+    components::{ParentRoute, Route},
+};
+
+#[component(transparent)]
+pub fn ScorekeeperRoutes() -> impl MatchNestedRoutes + Clone {
+    view! {
+        // scorekeeper pages share the layout of public pages without navigation
+        <ParentRoute path=StaticSegment("score") view=PublicLayout>
+            <Route path=ParamSegment(ScorekeeperSecretParams::KEY) view=ScoreEntry />
+        </ParentRoute>
+    }
+    .into_inner()
+    .into_any_nested_route()
+}
@@ -0,0 +1,301 @@
+//! result entry of a scorekeeper for the granted groups and stations
+//!
+//! Referees use this page on their phones courtside, therefore its layout is mobile first.
+//! Connectivity and edits waiting for replay are shown by [`OfflineStatus`]. Results are
+//! submitted via [`OfflineSync`], which queues them, while the phone has no connection.
+
+use app_core::{CrTopic, ScorekeeperAccess, ScorekeeperGrant, ScorekeeperMatch};
+use app_utils::{
+    components::offline_status::OfflineStatus,
+    error::{ComponentError, strategy::handle_read_error},
+    offline::{OfflineSync, PendingEdit},
+    params::{ParamQuery, ScorekeeperSecretParams},
+    server_fn::scorekeeper::{list_scorekeeper_matches, load_scorekeeper_access},
+    state::{
+        activity_tracker::ActivityTracker, error_state::PageErrorContext, toast_state::ToastContext,
+    },
+};
+use cr_leptos_axum_socket::use_client_registry_socket;
+use leptos::prelude::*;
+use leptos_router::hooks::use_navigate;
+use uuid::Uuid;
+
+#[component]
+pub fn ScoreEntry() -> impl IntoView {
+    // --- global context and state ---
+    let page_err_ctx = expect_context::<PageErrorContext>();
+    let activity_tracker = expect_context::<ActivityTracker>();
+    let component_id = StoredValue::new(Uuid::new_v4());
+
+    on_cleanup(move || {
+        page_err_ctx.clear_all_for_component(component_id.get_value());
+        activity_tracker.remove_component(component_id.get_value());
+    });
+
+    let secret = ScorekeeperSecretParams::use_param_query();
+
+    let access = Resource::new(
+        move || secret.get(),
+        move |secret| async move {
+            match secret {
+                Some(secret) => activity_tracker
+                    .track_activity_wrapper(
+                        component_id.get_value(),
+                        load_scorekeeper_access(secret),
+                    )
+                    .await
+                    .map_err(|app_error| ComponentError::new(component_id.get_value(), app_error)),
+                None => Ok(None),
+            }
+        },
+    );
+
+    let refetch = Callback::new(move |()| access.refetch());
+    page_err_ctx.register_retry_handler(component_id.get_value(), refetch);
+
+    // revoked or changed tokens take effect without reload
+    let tokens_topic = Signal::derive(move || {
+        access
+            .get()
+            .and_then(|res| res.ok().flatten())
+            .map(|access| CrTopic::ScorekeeperTokens {
+                tournament_id: access.tournament_id,
+            })
+    });
+    use_client_registry_socket(tokens_topic, None.into(), refetch);
+
+    // scorekeeper pages have no parent page; go to start page
+    let navigate = use_navigate();
+    let on_back = Callback::new(move |()| navigate("/", Default::default()));
+
+    view! {
+        <div class="flex flex-col gap-3 sm:gap-4 w-full max-w-xl mx-auto" data-testid="score-entry-root">
+            <Transition fallback=move || {
+                view! { <span class="loading loading-spinner loading-md"></span> }
+            }>
+                <ErrorBoundary fallback=move |errors| {
+                    for (_err_id, err) in errors.get().into_iter() {
+                        if let Some(comp_err) = err.into_inner().downcast_ref::<ComponentError>() {
+                            handle_read_error(&page_err_ctx, comp_err, on_back);
+                        }
+                    }
+                }>
+                    {move || {
+                        access
+                            .and_then(|access| match access.clone() {
+                                Some(access) => {
+                                    let secret = secret.get_untracked().unwrap_or_default();
+                                    view! { <ScoreEntryContent access=access secret=secret /> }
+                                        .into_any()
+                                }
+                                None => {
+                                    view! {
+                                        <div class="alert alert-warning" data-testid="score-entry-invalid">
+                                            "This scorekeeper link is invalid, expired or revoked."
+                                        </div>
+                                    }
+                                        .into_any()
+                                }
+                            })
+                    }}
+                </ErrorBoundary>
+            </Transition>
+        </div>
+    }
+}
+
+#[component]
+fn ScoreEntryContent(access: ScorekeeperAccess, secret: String) -> impl IntoView {
+    let expires = access
+        .expires_at
+        .map(|e| format!("Link valid until {}", e.format("%Y-%m-%d %H:%M UTC")));
+    view! {
+        <div class="card w-full bg-base-100 shadow-xl">
+            <div class="card-body p-4 sm:p-8">
+                <h1 class="card-title text-xl sm:text-2xl" data-testid="score-entry-tournament">
+                    {access.tournament_name}
+                </h1>
+                <p data-testid="score-entry-label">{format!("Scorekeeper: {}", access.label)}</p>
+                {expires.map(|e| view! { <p class="text-sm opacity-70">{e}</p> })}
+                <OfflineStatus />
+            </div>
+        </div>
+        {access
+            .grants
+            .into_iter()
+            .map(|grant| view! { <GrantCard grant=grant secret=secret.clone() /> })
+            .collect_view()}
+    }
+}
+
+/// matches of one granted group or station
+#[component]
+fn GrantCard(grant: ScorekeeperGrant, secret: String) -> impl IntoView {
+    let page_err_ctx = expect_context::<PageErrorContext>();
+    let activity_tracker = expect_context::<ActivityTracker>();
+    let component_id = StoredValue::new(Uuid::new_v4());
+    let secret = StoredValue::new(secret);
+
+    on_cleanup(move || {
+        page_err_ctx.clear_all_for_component(component_id.get_value());
+        activity_tracker.remove_component(component_id.get_value());
+    });
+
+    let matches = Resource::new(
+        || (),
+        move |()| async move {
+            activity_tracker
+                .track_activity_wrapper(
+                    component_id.get_value(),
+                    list_scorekeeper_matches(secret.get_value(), grant),
+                )
+                .await
+                .map(Option::unwrap_or_default)
+                .map_err(|app_error| ComponentError::new(component_id.get_value(), app_error))
+        },
+    );
+    let refetch = Callback::new(move |()| matches.refetch());
+    page_err_ctx.register_retry_handler(component_id.get_value(), refetch);
+
+    view! {
+        <div class="card w-full bg-base-100 shadow-xl" data-testid="score-entry-grant">
+            <div class="card-body p-4 sm:p-8">
+                <h2 class="card-title">{grant.to_string()}</h2>
+                <Transition fallback=move || {
+                    view! { <span class="loading loading-spinner loading-md"></span> }
+                }>
+                    {move || {
+                        matches
+                            .and_then(|list| {
+                                if list.is_empty() {
+                                    return view! {
+                                        <p class="opacity-70">"No matches to score yet."</p>
+                                    }
+                                        .into_any();
+                                }
+                                list.iter()
+                                    .cloned()
+                                    .map(|m| {
+                                        view! {
+                                            <ScoreEntryForm
+                                                scorekeeper_match=m
+                                                secret=secret.get_value()
+                                                on_saved=refetch
+                                            />
+                                        }
+                                    })
+                                    .collect_view()
+                                    .into_any()
+                            })
+                    }}
+                </Transition>
+            </div>
+        </div>
+    }
+}
+
+/// Parse the scores of all sets of one side, e.g. `21, 18`.
+fn parse_set_scores(input: &str) -> Option<Vec<u16>> {
+    input
+        .split([',', ' '])
+        .filter(|set| !set.is_empty())
+        .map(|set| set.parse().ok())
+        .collect()
+}
+
+/// score inputs of one match, sized for touch
+#[component]
+fn ScoreEntryForm(
+    scorekeeper_match: ScorekeeperMatch,
+    secret: String,
+    on_saved: Callback<()>,
+) -> impl IntoView {
+    let toast_ctx = expect_context::<ToastContext>();
+    let sync = expect_context::<OfflineSync>();
+    let score_a = RwSignal::new(String::new());
+    let score_b = RwSignal::new(String::new());
+    let pending = RwSignal::new(false);
+    let match_id = scorekeeper_match.match_id;
+
+    let on_submit = move || {
+        let (Some(a), Some(b)) = (
+            parse_set_scores(&score_a.get_untracked()),
+            parse_set_scores(&score_b.get_untracked()),
+        ) else {
+            toast_ctx.warning("Enter the points of each set, separated by commas.", None);
+            return;
+        };
+        let edit = PendingEdit::ScoreEntry {
+            secret: secret.clone(),
+            match_id,
+            score_a: a,
+            score_b: b,
+        };
+        pending.set(true);
+        leptos::task::spawn_local(async move {
+            match sync.submit(edit).await {
+                Ok(Some(_)) => {
+                    toast_ctx.success("Result saved.", None);
+                    on_saved.run(());
+                }
+                Ok(None) => {
+                    toast_ctx.info("No connection: result is sent, when online again.", None);
+                }
+                Err(err) => toast_ctx.error(format!("Could not save result: {err}"), None),
+            }
+            pending.set(false);
+        });
+    };
+
+    let title = match scorekeeper_match.station {
+        0 => format!("Match {}", scorekeeper_match.number),
+        station => format!("Match {} · Station {station}", scorekeeper_match.number),
+    };
+    view! {
+        <form
+            class="flex flex-col gap-3 p-3 rounded-lg bg-base-200"
+            data-testid="score-entry-match"
+            on:submit=move |ev| {
+                ev.prevent_default();
+                on_submit();
+            }
+        >
+            <div class="flex justify-between text-sm opacity-70">
+                <span>{title}</span>
+                <span>{scorekeeper_match.start_at.format("%H:%M UTC").to_string()}</span>
+            </div>
+            <label class="flex flex-col gap-1">
+                <span class="font-semibold">{scorekeeper_match.entrant_a.clone()}</span>
+                <input
+                    type="text"
+                    inputmode="numeric"
+                    class="input input-bordered input-lg w-full"
+                    placeholder="e.g. 21, 18"
+                    data-testid="input-score-a"
+                    prop:value=score_a
+                    on:input=move |ev| score_a.set(event_target_value(&ev))
+                />
+            </label>
+            <label class="flex flex-col gap-1">
+                <span class="font-semibold">{scorekeeper_match.entrant_b.clone()}</span>
+                <input
+                    type="text"
+                    inputmode="numeric"
+                    class="input input-bordered input-lg w-full"
+                    placeholder="e.g. 15, 21"
+                    data-testid="input-score-b"
+                    prop:value=score_b
+                    on:input=move |ev| score_b.set(event_target_value(&ev))
+                />
+            </label>
+            <button
+                type="submit"
+                class="btn btn-primary btn-lg w-full"
+                data-testid="action-btn-submit-score"
+                disabled=move || pending.get()
+            >
+                "Submit Result"
+            </button>
+        </form>
+    }
+}
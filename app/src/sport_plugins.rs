@@ -0,0 +1,94 @@
+//! registration of sport plugins shared by server and client
+//!
+//! Server and client register their plugins in separate sport plugin managers. Both use
+//! [`register_sport_plugins`], so that they register the same plugins in the same versions.
+//! Remaining drift, e.g. a client of an older deployment, is detected with
+//! [`check_sport_plugin_parity`] after start of the client.
+
+use anyhow::Result;
+use app_core::{SportPluginManagerPort, SportPort, utils::traits::ObjectIdVersion};
+use app_utils::server_fn::sport_plugin::check_sport_plugin_parity;
+use ddc_plugin::DdcSportPlugin;
+use generic_sport_plugin::GenericSportPlugin;
+use shared::SportPortWebUi;
+use sport_plugin_manager::SportPluginManagerMap;
+use std::{str::FromStr, sync::Arc};
+use table_tennis_plugin::TtSportPlugin;
+use ultimate_plugin::UltimateSportPlugin;
+
+/// sport plugins, which may be registered
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum SportPluginKind {
+    Generic,
+    Ddc,
+    TableTennis,
+    Ultimate,
+}
+
+impl SportPluginKind {
+    pub const ALL: [SportPluginKind; 4] = [
+        SportPluginKind::Generic,
+        SportPluginKind::Ddc,
+        SportPluginKind::TableTennis,
+        SportPluginKind::Ultimate,
+    ];
+
+    /// new instance of the plugin
+    pub fn plugin(self) -> Arc<dyn SportPortWebUi> {
+        match self {
+            SportPluginKind::Generic => Arc::new(GenericSportPlugin::new()),
+            SportPluginKind::Ddc => Arc::new(DdcSportPlugin::new()),
+            SportPluginKind::TableTennis => Arc::new(TtSportPlugin::new()),
+            SportPluginKind::Ultimate => Arc::new(UltimateSportPlugin::new()),
+        }
+    }
+}
+
+impl FromStr for SportPluginKind {
+    type Err = String;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s.trim() {
+            "generic" => Ok(SportPluginKind::Generic),
+            "ddc" => Ok(SportPluginKind::Ddc),
+            "table_tennis" => Ok(SportPluginKind::TableTennis),
+            "ultimate" => Ok(SportPluginKind::Ultimate),
+            other => Err(format!(
+                "unknown sport plugin `{other}`; expected generic, ddc, table_tennis or ultimate"
+            )),
+        }
+    }
+}
+
+/// Register plugins `kinds` in order at `manager`.
+///
+/// The server registers the configured plugins, clients register all plugins.
+pub fn register_sport_plugins(
+    manager: &SportPluginManagerMap,
+    kinds: &[SportPluginKind],
+) -> Result<()> {
+    for kind in kinds.iter() {
+        manager.register(kind.plugin())?;
+    }
+    Ok(())
+}
+
+/// Compare plugins of `manager` of the client with the plugins of the server and log drift
+/// to the console. The server logs drift as well.
+pub fn spawn_sport_plugin_parity_check(manager: &SportPluginManagerMap) {
+    let client_plugins: Vec<_> = manager
+        .list()
+        .iter()
+        .map(|plugin| (plugin.get_id_version().get_id(), plugin.plugin_version()))
+        .collect();
+    leptos::task::spawn_local(async move {
+        match check_sport_plugin_parity(client_plugins).await {
+            Ok(parity) if !parity.is_ok() => {
+                leptos::logging::error!("sport plugins of client and server differ: {parity}")
+            }
+            Ok(_) => {}
+            // parity is checked again with the next start of the client
+            Err(err) => leptos::logging::warn!("could not check sport plugins: {err}"),
+        }
+    });
+}
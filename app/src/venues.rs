@@ -0,0 +1,365 @@
+//! management of venues and their courts
+
+use app_core::{Court, CourtSurface, CrTopic, Venue};
+use app_utils::{
+    components::{address_select::AddressSelect, inputs::InputCommitAction},
+    error::{ComponentError, strategy::handle_read_error},
+    hooks::use_on_cancel::use_on_cancel,
+    server_fn::venue::{SaveVenue, list_venues},
+    state::{
+        activity_tracker::ActivityTracker, error_state::PageErrorContext, toast_state::ToastContext,
+    },
+};
+use cr_leptos_axum_socket::use_client_registry_socket;
+use leptos::prelude::*;
+use std::str::FromStr;
+use uuid::Uuid;
+
+#[component]
+pub fn Venues() -> impl IntoView {
+    // --- global context and state ---
+    let page_err_ctx = expect_context::<PageErrorContext>();
+    let toast_ctx = expect_context::<ToastContext>();
+    let activity_tracker = expect_context::<ActivityTracker>();
+    let component_id = StoredValue::new(Uuid::new_v4());
+
+    on_cleanup(move || {
+        page_err_ctx.clear_all_for_component(component_id.get_value());
+        activity_tracker.remove_component(component_id.get_value());
+    });
+
+    // --- local state ---
+    // id and version of edited venue; None creates a new venue
+    let editing = RwSignal::new(None::<(Uuid, u32)>);
+    let name = RwSignal::new(String::new());
+    let address_id = RwSignal::new(None::<Uuid>);
+    let courts = RwSignal::new(Vec::<Court>::new());
+
+    let reset_form = move || {
+        editing.set(None);
+        name.set(String::new());
+        address_id.set(None);
+        courts.set(Vec::new());
+    };
+    let edit_venue = move |venue: &Venue| {
+        editing.set(Some((
+            venue.get_id(),
+            venue.get_version().unwrap_or_default(),
+        )));
+        name.set(venue.get_name().to_string());
+        address_id.set(venue.get_address_id());
+        courts.set(venue.get_courts().to_vec());
+    };
+
+    let venues = Resource::new(
+        || (),
+        move |_| async move {
+            activity_tracker
+                .track_activity_wrapper(component_id.get_value(), list_venues(String::new(), None))
+                .await
+                .map_err(|app_error| ComponentError::new(component_id.get_value(), app_error))
+        },
+    );
+
+    let refetch = Callback::new(move |()| venues.refetch());
+    page_err_ctx.register_retry_handler(component_id.get_value(), refetch);
+
+    // Subscribe to changes of venues
+    use_client_registry_socket(
+        Signal::derive(|| Some(CrTopic::Venues)),
+        None.into(),
+        refetch,
+    );
+
+    let save_venue = ServerAction::<SaveVenue>::new();
+    activity_tracker.track_pending_memo(component_id.get_value(), save_venue.pending());
+    Effect::new(move || match save_venue.value().get() {
+        Some(Ok(saved)) => {
+            toast_ctx.success(format!("Venue '{}' saved.", saved.get_name()), None);
+            reset_form();
+            venues.refetch();
+        }
+        Some(Err(err)) => {
+            toast_ctx.error(format!("Could not save venue: {err}"), None);
+        }
+        None => {}
+    });
+
+    let on_submit = move || {
+        if name.get_untracked().trim().is_empty() {
+            toast_ctx.warning("Name of venue is required.", None);
+            return;
+        }
+        let (id, version) = match editing.get_untracked() {
+            Some((id, version)) => (Some(id), version),
+            None => (None, 0),
+        };
+        save_venue.dispatch(SaveVenue {
+            id,
+            version,
+            name: name.get_untracked(),
+            address_id: address_id.get_untracked(),
+            courts: courts.get_untracked(),
+        });
+    };
+
+    let add_court = move |_| {
+        courts.update(|c| {
+            let court = Court::new(format!("Court {}", c.len() + 1), CourtSurface::default());
+            c.push(court);
+        })
+    };
+
+    let on_cancel = use_on_cancel();
+
+    view! {
+        <div class="card w-full bg-base-100 shadow-xl" data-testid="venues-root">
+            <div class="card-body">
+                <h2 class="card-title">"Venues"</h2>
+                <p class="text-sm opacity-70">
+                    "Available courts of a venue become the stations of tournaments at the venue."
+                </p>
+                <form
+                    class="flex flex-col gap-2"
+                    on:submit=move |ev| {
+                        ev.prevent_default();
+                        on_submit();
+                    }
+                >
+                    <div class="flex flex-wrap items-end gap-4">
+                        <label class="form-control">
+                            <span class="label-text">"Name"</span>
+                            <input
+                                type="text"
+                                class="input input-bordered w-full md:w-64"
+                                data-testid="input-venue-name"
+                                prop:value=name
+                                on:input=move |ev| name.set(event_target_value(&ev))
+                            />
+                        </label>
+                        <div class="w-full md:w-96">
+                            <AddressSelect
+                                label="Postal Address"
+                                data_testid="select-venue-address"
+                                value=address_id
+                                action=InputCommitAction::WriteTo(
+                                    Callback::new(move |id| address_id.set(id)),
+                                )
+                                clear_label="-- No address --"
+                            />
+                        </div>
+                    </div>
+                    <table class="table table-sm" data-testid="venue-courts-table">
+                        <thead>
+                            <tr>
+                                <th>"Court"</th>
+                                <th>"Surface"</th>
+                                <th>"Available"</th>
+                                <th></th>
+                            </tr>
+                        </thead>
+                        <tbody>
+                            {move || {
+                                (0..courts.with(|c| c.len()))
+                                    .map(|index| {
+                                        view! {
+                                            <tr data-testid="venue-court-row">
+                                                <td>
+                                                    <input
+                                                        type="text"
+                                                        class="input input-bordered input-sm"
+                                                        data-testid=format!("input-court-name-{}", index + 1)
+                                                        prop:value=move || {
+                                                            courts
+                                                                .with(|c| {
+                                                                    c.get(index)
+                                                                        .map(|court| court.get_name().to_string())
+                                                                        .unwrap_or_default()
+                                                                })
+                                                        }
+                                                        on:change=move |ev| {
+                                                            let value = event_target_value(&ev);
+                                                            courts
+                                                                .update(|c| {
+                                                                    if let Some(court) = c.get_mut(index) {
+                                                                        court.set_name(value);
+                                                                    }
+                                                                });
+                                                        }
+                                                    />
+                                                </td>
+                                                <td>
+                                                    <select
+                                                        class="select select-bordered select-sm"
+                                                        data-testid=format!("select-court-surface-{}", index + 1)
+                                                        prop:value=move || {
+                                                            courts
+                                                                .with(|c| {
+                                                                    c.get(index)
+                                                                        .map(|court| court.get_surface().as_str())
+                                                                        .unwrap_or_default()
+                                                                })
+                                                        }
+                                                        on:change=move |ev| {
+                                                            if let Ok(surface) = CourtSurface::from_str(
+                                                                &event_target_value(&ev),
+                                                            ) {
+                                                                courts
+                                                                    .update(|c| {
+                                                                        if let Some(court) = c.get_mut(index) {
+                                                                            court.set_surface(surface);
+                                                                        }
+                                                                    });
+                                                            }
+                                                        }
+                                                    >
+                                                        {CourtSurface::ALL
+                                                            .into_iter()
+                                                            .map(|surface| {
+                                                                view! {
+                                                                    <option value=surface.as_str()>{surface.as_str()}</option>
+                                                                }
+                                                            })
+                                                            .collect_view()}
+                                                    </select>
+                                                </td>
+                                                <td>
+                                                    <input
+                                                        type="checkbox"
+                                                        class="checkbox checkbox-sm"
+                                                        data-testid=format!("input-court-available-{}", index + 1)
+                                                        prop:checked=move || {
+                                                            courts
+                                                                .with(|c| c.get(index).is_some_and(Court::is_available))
+                                                        }
+                                                        on:change=move |ev| {
+                                                            let checked = event_target_checked(&ev);
+                                                            courts
+                                                                .update(|c| {
+                                                                    if let Some(court) = c.get_mut(index) {
+                                                                        court.set_available(checked);
+                                                                    }
+                                                                });
+                                                        }
+                                                    />
+                                                </td>
+                                                <td>
+                                                    <button
+                                                        type="button"
+                                                        class="btn btn-ghost btn-xs"
+                                                        data-testid=format!("action-btn-remove-court-{}", index + 1)
+                                                        on:click=move |_| {
+                                                            courts
+                                                                .update(|c| {
+                                                                    if index < c.len() {
+                                                                        c.remove(index);
+                                                                    }
+                                                                })
+                                                        }
+                                                    >
+                                                        "Remove"
+                                                    </button>
+                                                </td>
+                                            </tr>
+                                        }
+                                    })
+                                    .collect_view()
+                            }}
+                        </tbody>
+                    </table>
+                    <div class="flex gap-2">
+                        <button
+                            type="button"
+                            class="btn btn-sm"
+                            data-testid="action-btn-add-court"
+                            on:click=add_court
+                        >
+                            "Add Court"
+                        </button>
+                        <button
+                            type="submit"
+                            class="btn btn-primary btn-sm"
+                            data-testid="action-btn-save-venue"
+                            disabled=move || save_venue.pending().get()
+                        >
+                            {move || if editing.get().is_some() { "Save Venue" } else { "Add Venue" }}
+                        </button>
+                        <Show when=move || editing.get().is_some()>
+                            <button
+                                type="button"
+                                class="btn btn-ghost btn-sm"
+                                data-testid="action-btn-cancel-venue"
+                                on:click=move |_| reset_form()
+                            >
+                                "Cancel"
+                            </button>
+                        </Show>
+                    </div>
+                </form>
+                <Transition fallback=move || {
+                    view! { <span class="loading loading-spinner loading-md"></span> }
+                }>
+                    <ErrorBoundary fallback=move |errors| {
+                        for (_err_id, err) in errors.get().into_iter() {
+                            if let Some(comp_err) = err.into_inner().downcast_ref::<ComponentError>() {
+                                handle_read_error(&page_err_ctx, comp_err, on_cancel);
+                            }
+                        }
+                    }>
+                        {move || {
+                            venues
+                                .and_then(|list| {
+                                    let list = list.clone();
+                                    view! {
+                                        <table class="table table-sm" data-testid="venues-table">
+                                            <thead>
+                                                <tr>
+                                                    <th>"Name"</th>
+                                                    <th>"Courts"</th>
+                                                    <th>"Available"</th>
+                                                    <th></th>
+                                                </tr>
+                                            </thead>
+                                            <tbody>
+                                                <For
+                                                    each=move || list.clone()
+                                                    key=|v| (v.get_id(), v.get_version())
+                                                    children=move |venue| {
+                                                        let id = venue.get_id();
+                                                        let num_courts = venue.get_courts().len();
+                                                        let num_available = venue.stations().len();
+                                                        let venue = StoredValue::new(venue);
+                                                        view! {
+                                                            <tr
+                                                                data-testid="venues-row"
+                                                                class:bg-base-200=move || {
+                                                                    editing.get().map(|(e_id, _)| e_id) == Some(id)
+                                                                }
+                                                            >
+                                                                <td>{venue.with_value(|v| v.get_name().to_string())}</td>
+                                                                <td>{num_courts}</td>
+                                                                <td>{num_available}</td>
+                                                                <td>
+                                                                    <button
+                                                                        class="btn btn-xs"
+                                                                        data-testid="action-btn-edit-venue"
+                                                                        on:click=move |_| venue.with_value(edit_venue)
+                                                                    >
+                                                                        "Edit"
+                                                                    </button>
+                                                                </td>
+                                                            </tr>
+                                                        }
+                                                    }
+                                                />
+                                            </tbody>
+                                        </table>
+                                    }
+                                })
+                        }}
+                    </ErrorBoundary>
+                </Transition>
+            </div>
+        </div>
+    }
+}
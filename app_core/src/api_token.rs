@@ -0,0 +1,468 @@
+//! tokens for the REST API of third-party clients
+
+use crate::{
+    AuditAction, AuditObjectType, Core, CoreError, CoreResult, CrMsg, CrTopic, DbError,
+    utils::{id_version::IdVersion, normalize::*, traits::ObjectIdVersion, validation::*},
+};
+use chrono::{DateTime, Duration, Utc};
+use serde::{Deserialize, Serialize};
+use sha2::{Digest, Sha256};
+use std::fmt::{self, Display};
+use tracing::warn;
+use uuid::Uuid;
+
+/// default number of requests per minute a token may send
+pub const DEFAULT_API_TOKEN_RATE_LIMIT_PER_MINUTE: u32 = 60;
+
+/// prefix of every token secret, makes leaked secrets easy to identify
+pub const API_TOKEN_SECRET_PREFIX: &str = "fkt_";
+
+/// number of characters of the secret, which are stored in clear text to identify a token
+const API_TOKEN_DISPLAY_PREFIX_LEN: usize = API_TOKEN_SECRET_PREFIX.len() + 6;
+
+/// `last_used_at` is only updated, if it is older than this, to avoid a write per request
+const LAST_USED_RESOLUTION_SECONDS: i64 = 60;
+
+/// Scopes, which may be granted to an API token.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash, Serialize, Deserialize)]
+#[serde(rename_all = "kebab-case")]
+pub enum ApiScope {
+    /// read data, which is visible on public pages
+    ReadPublic,
+    /// read all data of the organization; includes `ReadPublic`
+    ReadOrg,
+    /// enter and change match results
+    WriteScores,
+    /// change data of the organization, e.g. import entrants of tournaments
+    WriteOrg,
+}
+
+impl ApiScope {
+    pub const ALL: [ApiScope; 4] = [
+        ApiScope::ReadPublic,
+        ApiScope::ReadOrg,
+        ApiScope::WriteScores,
+        ApiScope::WriteOrg,
+    ];
+
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            ApiScope::ReadPublic => "read-public",
+            ApiScope::ReadOrg => "read-org",
+            ApiScope::WriteScores => "write-scores",
+            ApiScope::WriteOrg => "write-org",
+        }
+    }
+}
+
+impl Display for ApiScope {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{}", self.as_str())
+    }
+}
+
+/// Hash of a token secret as stored in the database (hex encoded SHA-256).
+///
+/// Secrets are random with 244 bits of entropy, therefore a fast hash without salt is
+/// sufficient and allows lookup of tokens by hash.
+pub fn hash_api_token_secret(secret: &str) -> String {
+    Sha256::digest(secret.as_bytes())
+        .iter()
+        .map(|b| format!("{b:02x}"))
+        .collect()
+}
+
+/// Token for the REST API. Only the hash of the secret is stored; the secret itself is
+/// shown once at creation.
+#[derive(Clone, Debug, PartialEq, Eq, Serialize, Deserialize, Default, ObjectIdVersion)]
+pub struct ApiToken {
+    /// id and optimistic locking version of token
+    id_version: IdVersion,
+    /// name of token, e.g. the client using it
+    name: String,
+    /// granted scopes
+    scopes: Vec<ApiScope>,
+    /// first characters of the secret to identify the token in lists
+    secret_prefix: String,
+    /// hash of the secret, see [`hash_api_token_secret`]
+    secret_hash: String,
+    /// maximum number of requests per minute
+    rate_limit_per_minute: u32,
+    /// timestamp of creation; set by database
+    created_at: Option<DateTime<Utc>>,
+    /// timestamp of last authenticated request
+    last_used_at: Option<DateTime<Utc>>,
+    /// timestamp of revocation; revoked tokens are rejected
+    revoked_at: Option<DateTime<Utc>>,
+}
+
+impl ApiToken {
+    /// Create a new `ApiToken` with the given `IdVersion`.
+    pub fn new(id_version: IdVersion) -> Self {
+        ApiToken {
+            id_version,
+            rate_limit_per_minute: DEFAULT_API_TOKEN_RATE_LIMIT_PER_MINUTE,
+            ..Default::default()
+        }
+    }
+
+    /// Get the unique identifier of the token.
+    pub fn get_id(&self) -> Uuid {
+        self.id_version.get_id()
+    }
+
+    /// Get the version number of the token.
+    pub fn get_version(&self) -> Option<u32> {
+        self.id_version.get_version()
+    }
+
+    /// Get the name of the token.
+    pub fn get_name(&self) -> &str {
+        &self.name
+    }
+
+    /// Get the granted scopes.
+    pub fn get_scopes(&self) -> &[ApiScope] {
+        &self.scopes
+    }
+
+    /// Returns true, if `scope` is granted. `ReadOrg` includes `ReadPublic`.
+    pub fn has_scope(&self, scope: ApiScope) -> bool {
+        self.scopes.contains(&scope)
+            || (scope == ApiScope::ReadPublic && self.scopes.contains(&ApiScope::ReadOrg))
+    }
+
+    /// Get the first characters of the secret.
+    pub fn get_secret_prefix(&self) -> &str {
+        &self.secret_prefix
+    }
+
+    /// Get the hash of the secret.
+    pub fn get_secret_hash(&self) -> &str {
+        &self.secret_hash
+    }
+
+    /// Get the maximum number of requests per minute.
+    pub fn get_rate_limit_per_minute(&self) -> u32 {
+        self.rate_limit_per_minute
+    }
+
+    /// Get the timestamp of creation, if token is persisted.
+    pub fn get_created_at(&self) -> Option<DateTime<Utc>> {
+        self.created_at
+    }
+
+    /// Get the timestamp of the last authenticated request.
+    pub fn get_last_used_at(&self) -> Option<DateTime<Utc>> {
+        self.last_used_at
+    }
+
+    /// Get the timestamp of revocation.
+    pub fn get_revoked_at(&self) -> Option<DateTime<Utc>> {
+        self.revoked_at
+    }
+
+    /// Check if the token is revoked.
+    pub fn is_revoked(&self) -> bool {
+        self.revoked_at.is_some()
+    }
+
+    /// Set the `IdVersion` of the token.
+    pub fn set_id_version(&mut self, id_version: IdVersion) -> &mut Self {
+        self.id_version = id_version;
+        self
+    }
+
+    /// Set the name of the token with whitespace normalization.
+    pub fn set_name(&mut self, name: impl Into<String>) -> &mut Self {
+        self.name = normalize_ws(name);
+        self
+    }
+
+    /// Set the granted scopes. Duplicates are removed, scopes are kept in order of `ApiScope::ALL`.
+    pub fn set_scopes(&mut self, scopes: impl IntoIterator<Item = ApiScope>) -> &mut Self {
+        let requested: Vec<ApiScope> = scopes.into_iter().collect();
+        self.scopes = ApiScope::ALL
+            .into_iter()
+            .filter(|s| requested.contains(s))
+            .collect();
+        self
+    }
+
+    /// Set the maximum number of requests per minute.
+    pub fn set_rate_limit_per_minute(&mut self, rate_limit_per_minute: u32) -> &mut Self {
+        self.rate_limit_per_minute = rate_limit_per_minute;
+        self
+    }
+
+    /// Set prefix and hash of the secret. Only used at creation and by database adapters.
+    pub fn set_secret_prefix_and_hash(
+        &mut self,
+        secret_prefix: impl Into<String>,
+        secret_hash: impl Into<String>,
+    ) -> &mut Self {
+        self.secret_prefix = secret_prefix.into();
+        self.secret_hash = secret_hash.into();
+        self
+    }
+
+    /// Set the timestamp of creation. Only used by database adapters.
+    pub fn set_created_at(&mut self, created_at: Option<DateTime<Utc>>) -> &mut Self {
+        self.created_at = created_at;
+        self
+    }
+
+    /// Set the timestamp of the last authenticated request. Only used by database adapters.
+    pub fn set_last_used_at(&mut self, last_used_at: Option<DateTime<Utc>>) -> &mut Self {
+        self.last_used_at = last_used_at;
+        self
+    }
+
+    /// Set the timestamp of revocation.
+    pub fn set_revoked_at(&mut self, revoked_at: Option<DateTime<Utc>>) -> &mut Self {
+        self.revoked_at = revoked_at;
+        self
+    }
+
+    /// Validate the token.
+    pub fn validate(&self) -> ValidationResult<()> {
+        let mut errs = ValidationErrors::new();
+        let object_id = self.get_id();
+
+        if self.name.is_empty() {
+            errs.add(
+                FieldError::builder()
+                    .set_field(String::from("name"))
+                    .add_required()
+                    .set_object_id(object_id)
+                    .build(),
+            );
+        }
+        if self.scopes.is_empty() {
+            errs.add(
+                FieldError::builder()
+                    .set_field(String::from("scopes"))
+                    .add_required()
+                    .add_message("At least one scope is required")
+                    .set_object_id(object_id)
+                    .build(),
+            );
+        }
+        if self.rate_limit_per_minute == 0 {
+            errs.add(
+                FieldError::builder()
+                    .set_field(String::from("rate_limit_per_minute"))
+                    .add_user_defined_code("out_of_range")
+                    .add_message("Rate limit must be at least 1 request per minute")
+                    .set_object_id(object_id)
+                    .build(),
+            );
+        }
+        if self.secret_hash.is_empty() {
+            errs.add(
+                FieldError::builder()
+                    .set_field(String::from("secret_hash"))
+                    .add_required()
+                    .set_object_id(object_id)
+                    .build(),
+            );
+        }
+
+        if errs.is_empty() { Ok(()) } else { Err(errs) }
+    }
+}
+
+/// State for API token operations
+pub struct ApiTokenState {
+    token: ApiToken,
+}
+
+// switch state to api token state
+impl<S> Core<S> {
+    pub fn as_api_token_state(&self) -> Core<ApiTokenState> {
+        self.switch_state(ApiTokenState {
+            token: ApiToken::new(IdVersion::NewWithId(Uuid::new_v4())),
+        })
+    }
+
+    /// Authenticate a request with token `secret`.
+    ///
+    /// Returns `None`, if no token with this secret exists or if it is revoked. Updates
+    /// `last_used_at` of the token with a resolution of one minute. The update is best
+    /// effort: if it fails, e.g. because the database is read only, the token is still
+    /// authenticated.
+    pub async fn authenticate_api_token(&self, secret: &str) -> CoreResult<Option<ApiToken>> {
+        if !secret.starts_with(API_TOKEN_SECRET_PREFIX) {
+            return Ok(None);
+        }
+        let hash = hash_api_token_secret(secret);
+        let Some(mut token) = self.database.get_api_token_by_hash(&hash).await? else {
+            return Ok(None);
+        };
+        if token.is_revoked() {
+            return Ok(None);
+        }
+        let now = Utc::now();
+        if token
+            .get_last_used_at()
+            .is_none_or(|t| now - t >= Duration::seconds(LAST_USED_RESOLUTION_SECONDS))
+        {
+            match self
+                .database
+                .touch_api_token_last_used(token.get_id(), now)
+                .await
+            {
+                Ok(()) => {
+                    token.set_last_used_at(Some(now));
+                }
+                Err(e) => {
+                    warn!(error = %e, token_id = %token.get_id(), "api_token_touch_failed");
+                }
+            }
+        }
+        Ok(Some(token))
+    }
+}
+
+impl Core<ApiTokenState> {
+    pub fn get(&self) -> &ApiToken {
+        &self.state.token
+    }
+    pub fn get_mut(&mut self) -> &mut ApiToken {
+        &mut self.state.token
+    }
+    pub async fn load(&mut self, id: Uuid) -> CoreResult<Option<&ApiToken>> {
+        if let Some(token) = self.database.get_api_token(id).await? {
+            self.state.token = token;
+            Ok(Some(self.get()))
+        } else {
+            Ok(None)
+        }
+    }
+    /// Create the token prepared with `get_mut()` with a new random secret.
+    ///
+    /// Returns the saved token and its secret. The secret is not stored and cannot be
+    /// retrieved later.
+    pub async fn create(&mut self) -> CoreResult<(&ApiToken, String)> {
+        if !self.state.token.get_id_version().is_new() {
+            return Err(CoreError::from(DbError::UniqueViolation(Some(
+                "api_tokens_pkey".into(),
+            ))));
+        }
+        // uuid v4 uses the random number generator of the OS
+        let secret = format!(
+            "{API_TOKEN_SECRET_PREFIX}{}{}",
+            Uuid::new_v4().simple(),
+            Uuid::new_v4().simple()
+        );
+        self.state.token.set_secret_prefix_and_hash(
+            &secret[..API_TOKEN_DISPLAY_PREFIX_LEN],
+            hash_api_token_secret(&secret),
+        );
+        self.save().await?;
+        Ok((self.get(), secret))
+    }
+    /// Save changes of name, scopes or rate limit of the loaded token.
+    pub async fn save(&mut self) -> CoreResult<&ApiToken> {
+        self.state.token.validate()?;
+        // stored copy before the save for the audit log
+        let old = match self.state.token.get_version() {
+            Some(_) => {
+                let id = self.state.token.get_id();
+                self.database.get_api_token(id).await?
+            }
+            None => None,
+        };
+        self.state.token = self.database.save_api_token(&self.state.token).await?;
+
+        // publish change of api token to client registry
+        let id = self.state.token.get_id();
+        let version = self
+            .state
+            .token
+            .get_version()
+            .expect("expecting save_api_token to return always an existing id and version");
+        let msg = CrMsg::ApiTokenUpdated { id, version };
+        self.client_registry
+            .publish(CrTopic::ApiTokens, msg)
+            .await?;
+        let action = match &old {
+            None => AuditAction::Create,
+            Some(old) if !old.is_revoked() && self.state.token.is_revoked() => AuditAction::Revoke,
+            Some(_) => AuditAction::Update,
+        };
+        self.audit(
+            action,
+            AuditObjectType::ApiToken,
+            None,
+            old.as_ref(),
+            Some(&self.state.token),
+        )
+        .await;
+        Ok(self.get())
+    }
+    /// Revoke the loaded token. Revoked tokens are kept for auditing.
+    pub async fn revoke(&mut self) -> CoreResult<&ApiToken> {
+        if self.state.token.get_id_version().is_new() {
+            return Err(CoreError::from(DbError::NotFound));
+        }
+        self.state.token.set_revoked_at(Some(Utc::now()));
+        self.save().await
+    }
+    pub async fn list_tokens(&self, include_revoked: bool) -> CoreResult<Vec<ApiToken>> {
+        let list = self.database.list_api_tokens(include_revoked).await?;
+        Ok(list)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn read_org_includes_read_public() {
+        let mut token = ApiToken::default();
+        token.set_scopes([ApiScope::ReadOrg]);
+        assert!(token.has_scope(ApiScope::ReadPublic));
+        assert!(token.has_scope(ApiScope::ReadOrg));
+        assert!(!token.has_scope(ApiScope::WriteScores));
+        assert!(!token.has_scope(ApiScope::WriteOrg));
+    }
+
+    #[test]
+    fn scopes_are_deduplicated_and_ordered() {
+        let mut token = ApiToken::default();
+        token.set_scopes([
+            ApiScope::WriteScores,
+            ApiScope::ReadPublic,
+            ApiScope::WriteScores,
+        ]);
+        assert_eq!(
+            token.get_scopes(),
+            &[ApiScope::ReadPublic, ApiScope::WriteScores]
+        );
+    }
+
+    #[test]
+    fn scopes_serialize_kebab_case() {
+        let json = serde_json::to_string(&ApiScope::WriteScores).unwrap();
+        assert_eq!(json, "\"write-scores\"");
+        assert_eq!(ApiScope::ReadOrg.to_string(), "read-org");
+    }
+
+    #[test]
+    fn hash_is_hex_sha256() {
+        assert_eq!(
+            hash_api_token_secret("abc"),
+            "ba7816bf8f01cfea414140de5dae2223b00361a396177a9cb410ff61f20015ad"
+        );
+    }
+
+    #[test]
+    fn given_new_token_without_secret_when_validate_then_errors() {
+        let token = ApiToken::new(IdVersion::NewWithId(Uuid::new_v4()));
+        let errs = token.validate().unwrap_err();
+        let fields: Vec<_> = errs.errors.iter().map(|e| e.get_field()).collect();
+        assert_eq!(fields, vec!["name", "scopes", "secret_hash"]);
+    }
+}
@@ -0,0 +1,518 @@
+//! audit log of all mutating operations
+//!
+//! Every save, archive, revoke or delete through a Core state writes an audit record with
+//! the actor, the changed object, its versions before and after the change and a diff of
+//! all changed fields. Audit records are append only.
+
+use crate::{
+    Core, CoreError, CoreResult,
+    utils::{id_version::IdVersion, normalize::*, traits::ObjectIdVersion},
+};
+use chrono::{DateTime, Utc};
+use serde::{Deserialize, Serialize};
+use serde_json::{Map, Value, json};
+use std::{
+    collections::BTreeSet,
+    fmt::{self, Display},
+    str::FromStr,
+};
+use tracing::warn;
+use uuid::Uuid;
+
+/// actor of all changes, which are not made by an authenticated client of the REST API
+pub const AUDIT_ACTOR_WEB: &str = "web";
+
+/// fields, which are not part of the diff, because they are maintained by the database
+const AUDIT_IGNORED_FIELDS: [&str; 4] = ["id_version", "created_at", "updated_at", "last_used_at"];
+
+/// fields, whose values are never written to the audit log
+const AUDIT_REDACTED_FIELDS: [&str; 2] = ["secret", "secret_hash"];
+
+/// value of redacted fields in diffs
+const AUDIT_REDACTED_VALUE: &str = "[redacted]";
+
+/// kind of a mutating operation
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash, Serialize, Deserialize)]
+#[serde(rename_all = "kebab-case")]
+pub enum AuditAction {
+    Create,
+    Update,
+    Archive,
+    Revoke,
+    Delete,
+}
+
+impl AuditAction {
+    pub const ALL: [AuditAction; 5] = [
+        AuditAction::Create,
+        AuditAction::Update,
+        AuditAction::Archive,
+        AuditAction::Revoke,
+        AuditAction::Delete,
+    ];
+
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            AuditAction::Create => "create",
+            AuditAction::Update => "update",
+            AuditAction::Archive => "archive",
+            AuditAction::Revoke => "revoke",
+            AuditAction::Delete => "delete",
+        }
+    }
+}
+
+impl Display for AuditAction {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{}", self.as_str())
+    }
+}
+
+impl FromStr for AuditAction {
+    type Err = CoreError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        AuditAction::ALL
+            .into_iter()
+            .find(|a| a.as_str() == s)
+            .ok_or_else(|| CoreError::ParsingError(format!("unknown audit action: {s}")))
+    }
+}
+
+/// type of an audited object
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash, Serialize, Deserialize)]
+#[serde(rename_all = "kebab-case")]
+pub enum AuditObjectType {
+    PostalAddress,
+    SportConfig,
+    TournamentBase,
+    Stage,
+    Group,
+    Entrant,
+    MatchNote,
+    ShiftLogEntry,
+    PairingOverride,
+    WebhookEndpoint,
+    ApiToken,
+    ScorekeeperToken,
+    Venue,
+    Official,
+}
+
+impl AuditObjectType {
+    pub const ALL: [AuditObjectType; 14] = [
+        AuditObjectType::PostalAddress,
+        AuditObjectType::SportConfig,
+        AuditObjectType::TournamentBase,
+        AuditObjectType::Stage,
+        AuditObjectType::Group,
+        AuditObjectType::Entrant,
+        AuditObjectType::MatchNote,
+        AuditObjectType::ShiftLogEntry,
+        AuditObjectType::PairingOverride,
+        AuditObjectType::WebhookEndpoint,
+        AuditObjectType::ApiToken,
+        AuditObjectType::ScorekeeperToken,
+        AuditObjectType::Venue,
+        AuditObjectType::Official,
+    ];
+
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            AuditObjectType::PostalAddress => "postal-address",
+            AuditObjectType::SportConfig => "sport-config",
+            AuditObjectType::TournamentBase => "tournament-base",
+            AuditObjectType::Stage => "stage",
+            AuditObjectType::Group => "group",
+            AuditObjectType::Entrant => "entrant",
+            AuditObjectType::MatchNote => "match-note",
+            AuditObjectType::ShiftLogEntry => "shift-log-entry",
+            AuditObjectType::PairingOverride => "pairing-override",
+            AuditObjectType::WebhookEndpoint => "webhook-endpoint",
+            AuditObjectType::ApiToken => "api-token",
+            AuditObjectType::ScorekeeperToken => "scorekeeper-token",
+            AuditObjectType::Venue => "venue",
+            AuditObjectType::Official => "official",
+        }
+    }
+}
+
+impl Display for AuditObjectType {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{}", self.as_str())
+    }
+}
+
+impl FromStr for AuditObjectType {
+    type Err = CoreError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        AuditObjectType::ALL
+            .into_iter()
+            .find(|t| t.as_str() == s)
+            .ok_or_else(|| CoreError::ParsingError(format!("unknown audit object type: {s}")))
+    }
+}
+
+/// Diff of two copies of an object as JSON object of all changed top level fields:
+/// `{"name": {"old": "Spring Cup", "new": "Summer Cup"}}`.
+///
+/// `old` is `None` for created objects, `new` is `None` for deleted objects. Fields maintained
+/// by the database are ignored; values of secrets are redacted.
+pub fn audit_diff<T: Serialize>(old: Option<&T>, new: Option<&T>) -> Value {
+    let old = audit_fields(old);
+    let new = audit_fields(new);
+    let keys: BTreeSet<&String> = old.keys().chain(new.keys()).collect();
+
+    let mut diff = Map::new();
+    for key in keys {
+        if AUDIT_IGNORED_FIELDS.contains(&key.as_str()) {
+            continue;
+        }
+        let old_value = old.get(key).unwrap_or(&Value::Null);
+        let new_value = new.get(key).unwrap_or(&Value::Null);
+        if old_value == new_value {
+            continue;
+        }
+        let change = if AUDIT_REDACTED_FIELDS.contains(&key.as_str()) {
+            json!({"old": AUDIT_REDACTED_VALUE, "new": AUDIT_REDACTED_VALUE})
+        } else {
+            json!({"old": old_value, "new": new_value})
+        };
+        diff.insert(key.clone(), change);
+    }
+    Value::Object(diff)
+}
+
+fn audit_fields<T: Serialize>(object: Option<&T>) -> Map<String, Value> {
+    match object.and_then(|o| serde_json::to_value(o).ok()) {
+        Some(Value::Object(fields)) => fields,
+        Some(value) => Map::from_iter([(String::from("value"), value)]),
+        None => Map::new(),
+    }
+}
+
+/// Record of one mutating operation. Records are append only.
+#[derive(Clone, Debug, PartialEq, Eq, Serialize, Deserialize, ObjectIdVersion)]
+pub struct AuditRecord {
+    /// id and optimistic locking version of record
+    id_version: IdVersion,
+    /// who made the change, e.g. [`AUDIT_ACTOR_WEB`] or `api-token:<name>`
+    actor: String,
+    /// kind of operation
+    action: AuditAction,
+    /// type of changed object
+    object_type: AuditObjectType,
+    /// id of changed object
+    object_id: Uuid,
+    /// id of tournament, the changed object belongs to; `None` for global objects
+    tournament_id: Option<Uuid>,
+    /// version of object before the change; `None` for created objects
+    old_version: Option<u32>,
+    /// version of object after the change; `None` for deleted objects
+    new_version: Option<u32>,
+    /// changed fields, see [`audit_diff`]
+    diff: Value,
+    /// timestamp of creation; set by database
+    created_at: Option<DateTime<Utc>>,
+}
+
+impl Default for AuditRecord {
+    fn default() -> Self {
+        AuditRecord {
+            id_version: IdVersion::default(),
+            actor: String::from(AUDIT_ACTOR_WEB),
+            action: AuditAction::Update,
+            object_type: AuditObjectType::TournamentBase,
+            object_id: Uuid::nil(),
+            tournament_id: None,
+            old_version: None,
+            new_version: None,
+            diff: Value::Object(Map::new()),
+            created_at: None,
+        }
+    }
+}
+
+impl AuditRecord {
+    /// Create a new `AuditRecord` with the given `IdVersion`.
+    pub fn new(id_version: IdVersion) -> Self {
+        AuditRecord {
+            id_version,
+            ..Default::default()
+        }
+    }
+
+    /// Get the unique identifier of the record.
+    pub fn get_id(&self) -> Uuid {
+        self.id_version.get_id()
+    }
+
+    /// Get the version number of the record.
+    pub fn get_version(&self) -> Option<u32> {
+        self.id_version.get_version()
+    }
+
+    /// Get the actor, who made the change.
+    pub fn get_actor(&self) -> &str {
+        &self.actor
+    }
+
+    /// Get the kind of operation.
+    pub fn get_action(&self) -> AuditAction {
+        self.action
+    }
+
+    /// Get the type of the changed object.
+    pub fn get_object_type(&self) -> AuditObjectType {
+        self.object_type
+    }
+
+    /// Get the id of the changed object.
+    pub fn get_object_id(&self) -> Uuid {
+        self.object_id
+    }
+
+    /// Get the id of the tournament, the changed object belongs to.
+    pub fn get_tournament_id(&self) -> Option<Uuid> {
+        self.tournament_id
+    }
+
+    /// Get the version of the object before the change.
+    pub fn get_old_version(&self) -> Option<u32> {
+        self.old_version
+    }
+
+    /// Get the version of the object after the change.
+    pub fn get_new_version(&self) -> Option<u32> {
+        self.new_version
+    }
+
+    /// Get the diff of all changed fields.
+    pub fn get_diff(&self) -> &Value {
+        &self.diff
+    }
+
+    /// Get the names of all changed fields.
+    pub fn get_changed_fields(&self) -> Vec<&str> {
+        self.diff
+            .as_object()
+            .map(|fields| fields.keys().map(String::as_str).collect())
+            .unwrap_or_default()
+    }
+
+    /// Get the timestamp of creation, if the record is persisted.
+    pub fn get_created_at(&self) -> Option<DateTime<Utc>> {
+        self.created_at
+    }
+
+    /// Set the `IdVersion` of the record.
+    pub fn set_id_version(&mut self, id_version: IdVersion) -> &mut Self {
+        self.id_version = id_version;
+        self
+    }
+
+    /// Set the actor with whitespace normalization.
+    pub fn set_actor(&mut self, actor: impl Into<String>) -> &mut Self {
+        self.actor = normalize_ws(actor);
+        self
+    }
+
+    /// Set the kind of operation.
+    pub fn set_action(&mut self, action: AuditAction) -> &mut Self {
+        self.action = action;
+        self
+    }
+
+    /// Set the type of the changed object.
+    pub fn set_object_type(&mut self, object_type: AuditObjectType) -> &mut Self {
+        self.object_type = object_type;
+        self
+    }
+
+    /// Set the id of the changed object.
+    pub fn set_object_id(&mut self, object_id: Uuid) -> &mut Self {
+        self.object_id = object_id;
+        self
+    }
+
+    /// Set the id of the tournament, the changed object belongs to.
+    pub fn set_tournament_id(&mut self, tournament_id: Option<Uuid>) -> &mut Self {
+        self.tournament_id = tournament_id;
+        self
+    }
+
+    /// Set the version of the object before the change.
+    pub fn set_old_version(&mut self, old_version: Option<u32>) -> &mut Self {
+        self.old_version = old_version;
+        self
+    }
+
+    /// Set the version of the object after the change.
+    pub fn set_new_version(&mut self, new_version: Option<u32>) -> &mut Self {
+        self.new_version = new_version;
+        self
+    }
+
+    /// Set the diff of all changed fields.
+    pub fn set_diff(&mut self, diff: Value) -> &mut Self {
+        self.diff = diff;
+        self
+    }
+
+    /// Set the timestamp of creation. Only used by database adapters.
+    pub fn set_created_at(&mut self, created_at: Option<DateTime<Utc>>) -> &mut Self {
+        self.created_at = created_at;
+        self
+    }
+}
+
+// recording of mutations in all states
+impl<S> Core<S> {
+    /// Record a create or update of `new` in the audit log; `old` is the stored copy before
+    /// the save, if any.
+    pub(crate) async fn audit_save<T: ObjectIdVersion + Serialize>(
+        &self,
+        object_type: AuditObjectType,
+        tournament_id: Option<Uuid>,
+        old: Option<&T>,
+        new: &T,
+    ) {
+        let action = if old.is_some() {
+            AuditAction::Update
+        } else {
+            AuditAction::Create
+        };
+        self.audit(action, object_type, tournament_id, old, Some(new))
+            .await;
+    }
+
+    /// Record a mutation in the audit log. `old` is `None` for created objects, `new` is `None`
+    /// for deleted objects.
+    ///
+    /// The mutation is already persisted, therefore a failure to record it is logged and does
+    /// not fail the operation.
+    pub(crate) async fn audit<T: ObjectIdVersion + Serialize>(
+        &self,
+        action: AuditAction,
+        object_type: AuditObjectType,
+        tournament_id: Option<Uuid>,
+        old: Option<&T>,
+        new: Option<&T>,
+    ) {
+        let Some(object_id) = new.or(old).map(|o| o.get_id_version().get_id()) else {
+            return;
+        };
+        let mut record = AuditRecord::new(IdVersion::NewWithId(Uuid::new_v4()));
+        record
+            .set_actor(self.get_actor())
+            .set_action(action)
+            .set_object_type(object_type)
+            .set_object_id(object_id)
+            .set_tournament_id(tournament_id)
+            .set_old_version(old.and_then(|o| o.get_id_version().get_version()))
+            .set_new_version(new.and_then(|n| n.get_id_version().get_version()))
+            .set_diff(audit_diff(old, new));
+        if let Err(e) = self.database.save_audit_record(&record).await {
+            warn!(error = %e, object_type = %object_type, %object_id, "audit_record_not_saved");
+        }
+    }
+}
+
+/// State for browsing the audit log of one tournament
+pub struct AuditLogState {
+    tournament_id: Uuid,
+}
+
+// switch state to audit log state
+impl<S> Core<S> {
+    pub fn as_audit_log_state(&self, tournament_id: Uuid) -> Core<AuditLogState> {
+        self.switch_state(AuditLogState { tournament_id })
+    }
+}
+
+impl Core<AuditLogState> {
+    /// List audit records of all objects of the tournament, newest first.
+    pub async fn list_audit_records(&self, limit: Option<usize>) -> CoreResult<Vec<AuditRecord>> {
+        let mut list = self
+            .database
+            .list_audit_records_of_tournament(self.state.tournament_id, limit)
+            .await?;
+        list.sort_by_key(|r| std::cmp::Reverse(r.get_created_at()));
+        Ok(list)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[derive(Serialize)]
+    struct Object {
+        id_version: IdVersion,
+        name: String,
+        secret: String,
+        count: u32,
+    }
+
+    #[test]
+    fn given_changed_fields_when_diff_then_only_changes_are_recorded() {
+        let old = Object {
+            id_version: IdVersion::new(Uuid::new_v4(), Some(0)),
+            name: String::from("Spring Cup"),
+            secret: String::from("abc"),
+            count: 4,
+        };
+        let new = Object {
+            id_version: IdVersion::new(old.id_version.get_id(), Some(1)),
+            name: String::from("Summer Cup"),
+            secret: String::from("xyz"),
+            count: 4,
+        };
+
+        let diff = audit_diff(Some(&old), Some(&new));
+
+        assert_eq!(
+            diff,
+            json!({
+                "name": {"old": "Spring Cup", "new": "Summer Cup"},
+                "secret": {"old": AUDIT_REDACTED_VALUE, "new": AUDIT_REDACTED_VALUE},
+            })
+        );
+    }
+
+    #[test]
+    fn given_created_object_when_diff_then_all_fields_are_new() {
+        let new = Object {
+            id_version: IdVersion::new(Uuid::new_v4(), Some(0)),
+            name: String::from("Spring Cup"),
+            secret: String::new(),
+            count: 4,
+        };
+
+        let diff = audit_diff(None, Some(&new));
+
+        assert_eq!(
+            diff,
+            json!({
+                "count": {"old": null, "new": 4},
+                "name": {"old": null, "new": "Spring Cup"},
+                "secret": {"old": AUDIT_REDACTED_VALUE, "new": AUDIT_REDACTED_VALUE},
+            })
+        );
+    }
+
+    #[test]
+    fn audit_enums_round_trip_as_str() {
+        for action in AuditAction::ALL {
+            assert_eq!(AuditAction::from_str(action.as_str()).unwrap(), action);
+        }
+        for object_type in AuditObjectType::ALL {
+            assert_eq!(
+                AuditObjectType::from_str(object_type.as_str()).unwrap(),
+                object_type
+            );
+        }
+        assert!(AuditObjectType::from_str("user").is_err());
+    }
+}
@@ -0,0 +1,758 @@
+//! read-model cache in front of the database port
+//!
+//! During live tournaments many clients poll the same tournament, stages and entrants.
+//! [`CachedDatabasePort`] keeps hot lookups in memory for a limited time. Entries are
+//! invalidated on writes through the cache and on messages published to the client registry,
+//! which covers writes of transactions and of other cores sharing the cache.
+//!
+//! Messages of other server instances do not pass the client registry of the core. A client
+//! registry, which forwards them (e.g. the redis client registry), must forward them into a
+//! [`CacheInvalidatingClientRegistry`]; otherwise writes of other instances are only visible
+//! after the time to live of the cached copies.
+
+use crate::{
+    ApiToken, AuditRecord, CachedGroupStandings, ClientErrorReport, ClientRegistryPort, CrMsg,
+    CrResult, CrSequence, CrTopic, DatabasePort, DbBatchResult, DbResult, DbTransaction,
+    DbpApiToken, DbpAuditLog, DbpClientError, DbpEntrant, DbpFeedback, DbpGroup, DbpGroupSeeding,
+    DbpGroupStandings, DbpMatch, DbpMatchNote, DbpOfficial, DbpPairingOverride, DbpPostalAddress,
+    DbpScorekeeperToken, DbpSearch, DbpShiftLog, DbpSportConfig, DbpStage, DbpStageSnapshot,
+    DbpTournamentBase, DbpVenue, DbpWebhook, Entrant, Feedback, Group, Match, MatchNote, Official,
+    PairingOverride, PostalAddress, PresenceEditor, SavedGroupSeeding, ScorekeeperToken, SearchHit,
+    ShiftLogEntry, SportConfig, Stage, StageSnapshot, TournamentBase, Venue, WebhookDelivery,
+    WebhookEndpoint,
+    utils::{filter::Filter, list_order::ListOrder},
+};
+use async_trait::async_trait;
+use chrono::{DateTime, Utc};
+use std::{
+    collections::HashMap,
+    hash::Hash,
+    sync::{Arc, Mutex},
+    time::{Duration, Instant},
+};
+use uuid::Uuid;
+
+/// time to live of cached objects per entity; `Duration::ZERO` disables caching of an entity
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct CacheConfig {
+    pub postal_address: Duration,
+    pub sport_config: Duration,
+    pub tournament_base: Duration,
+    pub stage: Duration,
+    /// entrants by id and entrant lists of tournaments
+    pub entrant: Duration,
+}
+
+impl Default for CacheConfig {
+    fn default() -> Self {
+        CacheConfig {
+            postal_address: Duration::from_secs(300),
+            sport_config: Duration::from_secs(300),
+            tournament_base: Duration::from_secs(30),
+            stage: Duration::from_secs(30),
+            entrant: Duration::from_secs(10),
+        }
+    }
+}
+
+/// map of cached values, which expire after `ttl`
+struct TtlCache<K, V> {
+    ttl: Duration,
+    entries: Mutex<HashMap<K, (Instant, V)>>,
+}
+
+impl<K: Eq + Hash, V: Clone> TtlCache<K, V> {
+    fn new(ttl: Duration) -> Self {
+        TtlCache {
+            ttl,
+            entries: Mutex::new(HashMap::new()),
+        }
+    }
+
+    fn get(&self, key: &K) -> Option<V> {
+        let mut entries = self.entries.lock().unwrap();
+        match entries.get(key) {
+            Some((cached_at, value)) if cached_at.elapsed() < self.ttl => Some(value.clone()),
+            Some(_) => {
+                entries.remove(key);
+                None
+            }
+            None => None,
+        }
+    }
+
+    fn insert(&self, key: K, value: V) {
+        if self.ttl.is_zero() {
+            return;
+        }
+        self.entries
+            .lock()
+            .unwrap()
+            .insert(key, (Instant::now(), value));
+    }
+
+    fn remove(&self, key: &K) {
+        self.entries.lock().unwrap().remove(key);
+    }
+
+    fn retain(&self, mut keep: impl FnMut(&V) -> bool) {
+        self.entries
+            .lock()
+            .unwrap()
+            .retain(|_, (_, value)| keep(value));
+    }
+
+    fn clear(&self) {
+        self.entries.lock().unwrap().clear();
+    }
+}
+
+/// Caching decorator of a database port.
+///
+/// Lookups by id of postal addresses, sport configs, tournament bases, stages and entrants and
+/// the entrant lists of tournaments are cached. All other operations are passed to the
+/// wrapped port. Transactions use the wrapped port and do not read from the cache.
+pub struct CachedDatabasePort {
+    inner: Arc<dyn DatabasePort>,
+    postal_addresses: TtlCache<Uuid, PostalAddress>,
+    sport_configs: TtlCache<Uuid, SportConfig>,
+    tournament_bases: TtlCache<Uuid, TournamentBase>,
+    stages: TtlCache<Uuid, Stage>,
+    entrants: TtlCache<Uuid, Entrant>,
+    /// entrants of tournament by tournament id
+    entrant_lists: TtlCache<Uuid, Vec<Entrant>>,
+}
+
+impl CachedDatabasePort {
+    pub fn new(inner: Arc<dyn DatabasePort>, config: CacheConfig) -> Self {
+        CachedDatabasePort {
+            inner,
+            postal_addresses: TtlCache::new(config.postal_address),
+            sport_configs: TtlCache::new(config.sport_config),
+            tournament_bases: TtlCache::new(config.tournament_base),
+            stages: TtlCache::new(config.stage),
+            entrants: TtlCache::new(config.entrant),
+            entrant_lists: TtlCache::new(config.entrant),
+        }
+    }
+
+    /// Remove cached copies of the object changed according to `msg`.
+    pub fn invalidate(&self, msg: &CrMsg) {
+        match msg {
+            CrMsg::AddressUpdated { id, .. } => self.postal_addresses.remove(id),
+            CrMsg::SportConfigUpdated { id, .. } => self.sport_configs.remove(id),
+            CrMsg::TournamentBaseUpdated { id, .. } => self.tournament_bases.remove(id),
+            CrMsg::StageUpdated { id, .. } => self.stages.remove(id),
+            CrMsg::EntrantUpdated { id, .. } => {
+                self.entrants.remove(id);
+                // message does not name the tournament; new entrants are in no cached list
+                self.entrant_lists.clear();
+            }
+            CrMsg::ShiftLogUpdated { .. }
+            | CrMsg::MatchNoteUpdated { .. }
+            | CrMsg::ApiTokenUpdated { .. }
+            | CrMsg::ScorekeeperTokenUpdated { .. }
+            | CrMsg::WebhookEndpointUpdated { .. }
+            | CrMsg::VenueUpdated { .. }
+            | CrMsg::OfficialUpdated { .. }
+            | CrMsg::WebhookDelivered { .. }
+            | CrMsg::MatchUpdated { .. }
+            | CrMsg::LiveScoreUpdated { .. }
+            | CrMsg::CourtCalled { .. }
+            | CrMsg::GroupStandingsUpdated { .. }
+            | CrMsg::DisplayBoardRefreshed { .. }
+            | CrMsg::SportPluginsUpdated { .. }
+            | CrMsg::Ping { .. }
+            | CrMsg::PresenceChanged { .. } => {}
+        }
+    }
+
+    /// Remove all cached objects.
+    pub fn clear(&self) {
+        self.postal_addresses.clear();
+        self.sport_configs.clear();
+        self.tournament_bases.clear();
+        self.stages.clear();
+        self.entrants.clear();
+        self.entrant_lists.clear();
+    }
+}
+
+/// Client registry, which invalidates the cache with every published message before passing
+/// it to the wrapped client registry. The core wraps its client registry with it; client
+/// registries forwarding messages of other server instances wrap their local registry.
+pub struct CacheInvalidatingClientRegistry {
+    inner: Arc<dyn ClientRegistryPort>,
+    cache: Arc<CachedDatabasePort>,
+}
+
+impl CacheInvalidatingClientRegistry {
+    pub fn new(inner: Arc<dyn ClientRegistryPort>, cache: Arc<CachedDatabasePort>) -> Self {
+        CacheInvalidatingClientRegistry { inner, cache }
+    }
+}
+
+#[async_trait]
+impl ClientRegistryPort for CacheInvalidatingClientRegistry {
+    async fn publish(&self, topic: CrTopic, msg: CrMsg) -> CrResult<()> {
+        self.cache.invalidate(&msg);
+        self.inner.publish(topic, msg).await
+    }
+
+    async fn publish_sequenced(
+        &self,
+        topic: CrTopic,
+        msg: CrMsg,
+        sequence: CrSequence,
+    ) -> CrResult<()> {
+        self.cache.invalidate(&msg);
+        self.inner.publish_sequenced(topic, msg, sequence).await
+    }
+
+    async fn topic_sequences(&self, topics: &[CrTopic]) -> CrResult<Option<(Uuid, Vec<u64>)>> {
+        self.inner.topic_sequences(topics).await
+    }
+
+    async fn join_presence(&self, topic: CrTopic, editor: PresenceEditor) -> CrResult<bool> {
+        self.inner.join_presence(topic, editor).await
+    }
+
+    async fn leave_presence(&self, topic: CrTopic, client_id: Uuid) -> CrResult<bool> {
+        self.inner.leave_presence(topic, client_id).await
+    }
+
+    async fn list_presence(&self, topic: CrTopic) -> CrResult<Vec<PresenceEditor>> {
+        self.inner.list_presence(topic).await
+    }
+}
+
+#[async_trait]
+impl DatabasePort for CachedDatabasePort {
+    async fn ping_db(&self) -> DbResult<()> {
+        self.inner.ping_db().await
+    }
+    async fn begin_transaction(&self) -> DbResult<Arc<dyn DbTransaction>> {
+        self.inner.begin_transaction().await
+    }
+}
+
+#[async_trait]
+impl DbpPostalAddress for CachedDatabasePort {
+    async fn get_postal_address(&self, id: Uuid) -> DbResult<Option<PostalAddress>> {
+        if let Some(address) = self.postal_addresses.get(&id) {
+            return Ok(Some(address));
+        }
+        let address = self.inner.get_postal_address(id).await?;
+        if let Some(address) = address.as_ref() {
+            self.postal_addresses.insert(id, address.clone());
+        }
+        Ok(address)
+    }
+    async fn save_postal_address(&self, address: &PostalAddress) -> DbResult<PostalAddress> {
+        let result = self.inner.save_postal_address(address).await;
+        // invalidate after the write, concurrent reads may have cached the old copy
+        self.postal_addresses.remove(&address.get_id());
+        result
+    }
+    async fn list_postal_address_ids(
+        &self,
+        name_filter: Option<&str>,
+        limit: Option<usize>,
+        order: &ListOrder<PostalAddress>,
+    ) -> DbResult<Vec<Uuid>> {
+        self.inner
+            .list_postal_address_ids(name_filter, limit, order)
+            .await
+    }
+}
+
+#[async_trait]
+impl DbpSportConfig for CachedDatabasePort {
+    async fn get_sport_config(&self, config_id: Uuid) -> DbResult<Option<SportConfig>> {
+        if let Some(config) = self.sport_configs.get(&config_id) {
+            return Ok(Some(config));
+        }
+        let config = self.inner.get_sport_config(config_id).await?;
+        if let Some(config) = config.as_ref() {
+            self.sport_configs.insert(config_id, config.clone());
+        }
+        Ok(config)
+    }
+    async fn save_sport_config(&self, sport_config: &SportConfig) -> DbResult<SportConfig> {
+        let result = self.inner.save_sport_config(sport_config).await;
+        self.sport_configs.remove(&sport_config.get_id());
+        result
+    }
+    async fn archive_sport_config(&self, config_id: Uuid, version: u32) -> DbResult<SportConfig> {
+        let result = self.inner.archive_sport_config(config_id, version).await;
+        self.sport_configs.remove(&config_id);
+        result
+    }
+    async fn list_sport_config_ids(
+        &self,
+        sport_id: Uuid,
+        name_filter: Option<&str>,
+        include_archived: bool,
+        limit: Option<usize>,
+        order: &ListOrder<SportConfig>,
+    ) -> DbResult<Vec<Uuid>> {
+        self.inner
+            .list_sport_config_ids(sport_id, name_filter, include_archived, limit, order)
+            .await
+    }
+}
+
+#[async_trait]
+impl DbpTournamentBase for CachedDatabasePort {
+    async fn get_tournament_base(&self, base_id: Uuid) -> DbResult<Option<TournamentBase>> {
+        if let Some(tournament) = self.tournament_bases.get(&base_id) {
+            return Ok(Some(tournament));
+        }
+        let tournament = self.inner.get_tournament_base(base_id).await?;
+        if let Some(tournament) = tournament.as_ref() {
+            self.tournament_bases.insert(base_id, tournament.clone());
+        }
+        Ok(tournament)
+    }
+    async fn save_tournament_base(
+        &self,
+        tournament_base: &TournamentBase,
+    ) -> DbResult<TournamentBase> {
+        let result = self.inner.save_tournament_base(tournament_base).await;
+        self.tournament_bases.remove(&tournament_base.get_id());
+        result
+    }
+    async fn archive_tournament_base(
+        &self,
+        base_id: Uuid,
+        version: u32,
+    ) -> DbResult<TournamentBase> {
+        let result = self.inner.archive_tournament_base(base_id, version).await;
+        self.tournament_bases.remove(&base_id);
+        result
+    }
+    async fn purge_sandbox_tournament_base(&self, base_id: Uuid) -> DbResult<()> {
+        let result = self.inner.purge_sandbox_tournament_base(base_id).await;
+        self.tournament_bases.remove(&base_id);
+        self.stages.retain(|s| s.get_tournament_id() != base_id);
+        self.entrants.retain(|e| e.get_tournament_id() != base_id);
+        self.entrant_lists.remove(&base_id);
+        result
+    }
+    async fn delete_tournament_base(&self, base_id: Uuid, version: u32) -> DbResult<()> {
+        let result = self.inner.delete_tournament_base(base_id, version).await;
+        self.tournament_bases.remove(&base_id);
+        self.stages.retain(|s| s.get_tournament_id() != base_id);
+        self.entrants.retain(|e| e.get_tournament_id() != base_id);
+        self.entrant_lists.remove(&base_id);
+        result
+    }
+    async fn save_tournament_diff(
+        &self,
+        tournament_base: Option<&TournamentBase>,
+        stages: &[Stage],
+        groups: &[Group],
+    ) -> DbBatchResult<(Option<TournamentBase>, Vec<Stage>, Vec<Group>)> {
+        let result = self
+            .inner
+            .save_tournament_diff(tournament_base, stages, groups)
+            .await;
+        if let Some(tournament_base) = tournament_base {
+            self.tournament_bases.remove(&tournament_base.get_id());
+        }
+        for stage in stages {
+            self.stages.remove(&stage.get_id());
+        }
+        result
+    }
+    async fn list_tournament_base_ids(
+        &self,
+        filter: &Filter<TournamentBase>,
+        order: &ListOrder<TournamentBase>,
+    ) -> DbResult<Vec<Uuid>> {
+        self.inner.list_tournament_base_ids(filter, order).await
+    }
+}
+
+#[async_trait]
+impl DbpStage for CachedDatabasePort {
+    async fn get_stage_by_id(&self, stage_id: Uuid) -> DbResult<Option<Stage>> {
+        if let Some(stage) = self.stages.get(&stage_id) {
+            return Ok(Some(stage));
+        }
+        let stage = self.inner.get_stage_by_id(stage_id).await?;
+        if let Some(stage) = stage.as_ref() {
+            self.stages.insert(stage_id, *stage);
+        }
+        Ok(stage)
+    }
+    async fn get_stage_by_number(
+        &self,
+        tournament_base_id: Uuid,
+        number: u32,
+    ) -> DbResult<Option<Stage>> {
+        self.inner
+            .get_stage_by_number(tournament_base_id, number)
+            .await
+    }
+    async fn save_stage(&self, stage: &Stage) -> DbResult<Stage> {
+        let result = self.inner.save_stage(stage).await;
+        self.stages.remove(&stage.get_id());
+        result
+    }
+    async fn list_stage_ids_of_tournament(
+        &self,
+        tournament_id: Uuid,
+        number_of_stages: u32,
+    ) -> DbResult<Vec<(Uuid, u32)>> {
+        self.inner
+            .list_stage_ids_of_tournament(tournament_id, number_of_stages)
+            .await
+    }
+}
+
+#[async_trait]
+impl DbpShiftLog for CachedDatabasePort {
+    async fn get_shift_log_entry(&self, entry_id: Uuid) -> DbResult<Option<ShiftLogEntry>> {
+        self.inner.get_shift_log_entry(entry_id).await
+    }
+    async fn save_shift_log_entry(&self, entry: &ShiftLogEntry) -> DbResult<ShiftLogEntry> {
+        self.inner.save_shift_log_entry(entry).await
+    }
+    async fn list_shift_log_entries(
+        &self,
+        tournament_id: Uuid,
+        pinned_only: bool,
+        limit: Option<usize>,
+    ) -> DbResult<Vec<ShiftLogEntry>> {
+        self.inner
+            .list_shift_log_entries(tournament_id, pinned_only, limit)
+            .await
+    }
+}
+
+#[async_trait]
+impl DbpMatchNote for CachedDatabasePort {
+    async fn get_match_note_of_match(&self, match_id: Uuid) -> DbResult<Option<MatchNote>> {
+        self.inner.get_match_note_of_match(match_id).await
+    }
+    async fn save_match_note(&self, note: &MatchNote) -> DbResult<MatchNote> {
+        self.inner.save_match_note(note).await
+    }
+    async fn list_match_notes(
+        &self,
+        tournament_id: Uuid,
+        tag: Option<&str>,
+    ) -> DbResult<Vec<MatchNote>> {
+        self.inner.list_match_notes(tournament_id, tag).await
+    }
+}
+
+#[async_trait]
+impl DbpPairingOverride for CachedDatabasePort {
+    async fn save_pairing_override(
+        &self,
+        pairing_override: &PairingOverride,
+    ) -> DbResult<PairingOverride> {
+        self.inner.save_pairing_override(pairing_override).await
+    }
+    async fn list_pairing_overrides(
+        &self,
+        stage_id: Uuid,
+        round_number: u32,
+    ) -> DbResult<Vec<PairingOverride>> {
+        self.inner
+            .list_pairing_overrides(stage_id, round_number)
+            .await
+    }
+}
+
+#[async_trait]
+impl DbpEntrant for CachedDatabasePort {
+    async fn get_entrant(&self, entrant_id: Uuid) -> DbResult<Option<Entrant>> {
+        if let Some(entrant) = self.entrants.get(&entrant_id) {
+            return Ok(Some(entrant));
+        }
+        let entrant = self.inner.get_entrant(entrant_id).await?;
+        if let Some(entrant) = entrant.as_ref() {
+            self.entrants.insert(entrant_id, entrant.clone());
+        }
+        Ok(entrant)
+    }
+    async fn save_entrant(&self, entrant: &Entrant) -> DbResult<Entrant> {
+        let result = self.inner.save_entrant(entrant).await;
+        self.entrants.remove(&entrant.get_id());
+        self.entrant_lists.remove(&entrant.get_tournament_id());
+        result
+    }
+    async fn list_entrants_of_tournament(&self, tournament_id: Uuid) -> DbResult<Vec<Entrant>> {
+        if let Some(entrants) = self.entrant_lists.get(&tournament_id) {
+            return Ok(entrants);
+        }
+        let entrants = self
+            .inner
+            .list_entrants_of_tournament(tournament_id)
+            .await?;
+        self.entrant_lists.insert(tournament_id, entrants.clone());
+        Ok(entrants)
+    }
+}
+
+#[async_trait]
+impl DbpApiToken for CachedDatabasePort {
+    async fn get_api_token(&self, token_id: Uuid) -> DbResult<Option<ApiToken>> {
+        self.inner.get_api_token(token_id).await
+    }
+    async fn get_api_token_by_hash(&self, secret_hash: &str) -> DbResult<Option<ApiToken>> {
+        self.inner.get_api_token_by_hash(secret_hash).await
+    }
+    async fn save_api_token(&self, token: &ApiToken) -> DbResult<ApiToken> {
+        self.inner.save_api_token(token).await
+    }
+    async fn touch_api_token_last_used(&self, token_id: Uuid, at: DateTime<Utc>) -> DbResult<()> {
+        self.inner.touch_api_token_last_used(token_id, at).await
+    }
+    async fn list_api_tokens(&self, include_revoked: bool) -> DbResult<Vec<ApiToken>> {
+        self.inner.list_api_tokens(include_revoked).await
+    }
+}
+
+#[async_trait]
+impl DbpScorekeeperToken for CachedDatabasePort {
+    async fn get_scorekeeper_token(&self, token_id: Uuid) -> DbResult<Option<ScorekeeperToken>> {
+        self.inner.get_scorekeeper_token(token_id).await
+    }
+    async fn get_scorekeeper_token_by_hash(
+        &self,
+        secret_hash: &str,
+    ) -> DbResult<Option<ScorekeeperToken>> {
+        self.inner.get_scorekeeper_token_by_hash(secret_hash).await
+    }
+    async fn save_scorekeeper_token(&self, token: &ScorekeeperToken) -> DbResult<ScorekeeperToken> {
+        self.inner.save_scorekeeper_token(token).await
+    }
+    async fn touch_scorekeeper_token_last_used(
+        &self,
+        token_id: Uuid,
+        at: DateTime<Utc>,
+    ) -> DbResult<()> {
+        self.inner
+            .touch_scorekeeper_token_last_used(token_id, at)
+            .await
+    }
+    async fn list_scorekeeper_tokens(
+        &self,
+        tournament_id: Uuid,
+        include_revoked: bool,
+    ) -> DbResult<Vec<ScorekeeperToken>> {
+        self.inner
+            .list_scorekeeper_tokens(tournament_id, include_revoked)
+            .await
+    }
+}
+
+#[async_trait]
+impl DbpWebhook for CachedDatabasePort {
+    async fn get_webhook_endpoint(&self, endpoint_id: Uuid) -> DbResult<Option<WebhookEndpoint>> {
+        self.inner.get_webhook_endpoint(endpoint_id).await
+    }
+    async fn save_webhook_endpoint(&self, endpoint: &WebhookEndpoint) -> DbResult<WebhookEndpoint> {
+        self.inner.save_webhook_endpoint(endpoint).await
+    }
+    async fn list_webhook_endpoints(&self) -> DbResult<Vec<WebhookEndpoint>> {
+        self.inner.list_webhook_endpoints().await
+    }
+    async fn save_webhook_delivery(&self, delivery: &WebhookDelivery) -> DbResult<()> {
+        self.inner.save_webhook_delivery(delivery).await
+    }
+    async fn list_webhook_deliveries(
+        &self,
+        endpoint_id: Uuid,
+        limit: usize,
+    ) -> DbResult<Vec<WebhookDelivery>> {
+        self.inner.list_webhook_deliveries(endpoint_id, limit).await
+    }
+}
+
+#[async_trait]
+impl DbpOfficial for CachedDatabasePort {
+    async fn get_official(&self, official_id: Uuid) -> DbResult<Option<Official>> {
+        self.inner.get_official(official_id).await
+    }
+    async fn save_official(&self, official: &Official) -> DbResult<Official> {
+        self.inner.save_official(official).await
+    }
+    async fn list_officials_of_tournament(&self, tournament_id: Uuid) -> DbResult<Vec<Official>> {
+        self.inner.list_officials_of_tournament(tournament_id).await
+    }
+}
+
+#[async_trait]
+impl DbpGroupStandings for CachedDatabasePort {
+    async fn get_group_standings(&self, group_id: Uuid) -> DbResult<Option<CachedGroupStandings>> {
+        self.inner.get_group_standings(group_id).await
+    }
+    async fn save_group_standings(&self, standings: &CachedGroupStandings) -> DbResult<()> {
+        self.inner.save_group_standings(standings).await
+    }
+    async fn delete_group_standings(&self, group_id: Uuid) -> DbResult<()> {
+        self.inner.delete_group_standings(group_id).await
+    }
+    async fn list_group_standings_of_tournament(
+        &self,
+        tournament_id: Uuid,
+    ) -> DbResult<Vec<CachedGroupStandings>> {
+        self.inner
+            .list_group_standings_of_tournament(tournament_id)
+            .await
+    }
+}
+
+#[async_trait]
+impl DbpGroup for CachedDatabasePort {
+    async fn get_group(&self, group_id: Uuid) -> DbResult<Option<Group>> {
+        self.inner.get_group(group_id).await
+    }
+    async fn list_groups_of_stage(&self, stage_id: Uuid) -> DbResult<Vec<Group>> {
+        self.inner.list_groups_of_stage(stage_id).await
+    }
+}
+
+#[async_trait]
+impl DbpGroupSeeding for CachedDatabasePort {
+    async fn get_group_seeding(&self, stage_id: Uuid) -> DbResult<Option<SavedGroupSeeding>> {
+        self.inner.get_group_seeding(stage_id).await
+    }
+    async fn save_group_seeding(&self, seeding: &SavedGroupSeeding) -> DbResult<()> {
+        self.inner.save_group_seeding(seeding).await
+    }
+}
+
+#[async_trait]
+impl DbpStageSnapshot for CachedDatabasePort {
+    async fn get_stage_snapshot(&self, snapshot_id: Uuid) -> DbResult<Option<StageSnapshot>> {
+        self.inner.get_stage_snapshot(snapshot_id).await
+    }
+    async fn save_stage_snapshot(&self, snapshot: &StageSnapshot) -> DbResult<()> {
+        self.inner.save_stage_snapshot(snapshot).await
+    }
+    async fn delete_stage_snapshot(&self, snapshot_id: Uuid) -> DbResult<()> {
+        self.inner.delete_stage_snapshot(snapshot_id).await
+    }
+    async fn list_stage_snapshots_of_tournament(
+        &self,
+        tournament_id: Uuid,
+    ) -> DbResult<Vec<StageSnapshot>> {
+        self.inner
+            .list_stage_snapshots_of_tournament(tournament_id)
+            .await
+    }
+}
+
+#[async_trait]
+impl DbpMatch for CachedDatabasePort {
+    async fn get_match(&self, match_id: Uuid) -> DbResult<Option<Match>> {
+        self.inner.get_match(match_id).await
+    }
+    async fn save_matches(&self, matches: &[Match]) -> DbResult<Vec<Match>> {
+        self.inner.save_matches(matches).await
+    }
+    async fn delete_matches_of_stage(&self, stage_id: Uuid) -> DbResult<()> {
+        self.inner.delete_matches_of_stage(stage_id).await
+    }
+    async fn list_matches_of_stage(&self, stage_id: Uuid) -> DbResult<Vec<Match>> {
+        self.inner.list_matches_of_stage(stage_id).await
+    }
+    async fn list_matches_of_tournament(&self, tournament_id: Uuid) -> DbResult<Vec<Match>> {
+        self.inner.list_matches_of_tournament(tournament_id).await
+    }
+    async fn list_matches_of_stage_for_update(&self, stage_id: Uuid) -> DbResult<Vec<Match>> {
+        self.inner.list_matches_of_stage_for_update(stage_id).await
+    }
+    async fn list_matches_of_tournament_for_update(
+        &self,
+        tournament_id: Uuid,
+    ) -> DbResult<Vec<Match>> {
+        self.inner
+            .list_matches_of_tournament_for_update(tournament_id)
+            .await
+    }
+}
+
+#[async_trait]
+impl DbpVenue for CachedDatabasePort {
+    async fn get_venue(&self, venue_id: Uuid) -> DbResult<Option<Venue>> {
+        self.inner.get_venue(venue_id).await
+    }
+    async fn save_venue(&self, venue: &Venue) -> DbResult<Venue> {
+        self.inner.save_venue(venue).await
+    }
+    async fn list_venues(
+        &self,
+        name_filter: Option<&str>,
+        limit: Option<usize>,
+    ) -> DbResult<Vec<Venue>> {
+        self.inner.list_venues(name_filter, limit).await
+    }
+}
+
+#[async_trait]
+impl DbpFeedback for CachedDatabasePort {
+    async fn save_feedback(&self, feedback: &Feedback) -> DbResult<Feedback> {
+        self.inner.save_feedback(feedback).await
+    }
+    async fn list_feedback(&self, limit: Option<usize>) -> DbResult<Vec<Feedback>> {
+        self.inner.list_feedback(limit).await
+    }
+}
+
+#[async_trait]
+impl DbpClientError for CachedDatabasePort {
+    async fn save_client_error(&self, report: &ClientErrorReport) -> DbResult<ClientErrorReport> {
+        self.inner.save_client_error(report).await
+    }
+    async fn list_client_errors(&self, limit: Option<usize>) -> DbResult<Vec<ClientErrorReport>> {
+        self.inner.list_client_errors(limit).await
+    }
+}
+
+#[async_trait]
+impl DbpAuditLog for CachedDatabasePort {
+    async fn save_audit_record(&self, record: &AuditRecord) -> DbResult<AuditRecord> {
+        self.inner.save_audit_record(record).await
+    }
+    async fn list_audit_records_of_tournament(
+        &self,
+        tournament_id: Uuid,
+        limit: Option<usize>,
+    ) -> DbResult<Vec<AuditRecord>> {
+        self.inner
+            .list_audit_records_of_tournament(tournament_id, limit)
+            .await
+    }
+}
+
+#[async_trait]
+impl DbpSearch for CachedDatabasePort {
+    async fn search_all(&self, term: &str, limit_per_kind: usize) -> DbResult<Vec<SearchHit>> {
+        self.inner.search_all(term, limit_per_kind).await
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn ttl_cache_expires_and_disables_with_zero_ttl() {
+        let cache = TtlCache::new(Duration::from_millis(20));
+        cache.insert(1, "one");
+        assert_eq!(cache.get(&1), Some("one"));
+        std::thread::sleep(Duration::from_millis(30));
+        assert_eq!(cache.get(&1), None);
+
+        let disabled = TtlCache::new(Duration::ZERO);
+        disabled.insert(1, "one");
+        assert_eq!(disabled.get(&1), None);
+    }
+}
@@ -0,0 +1,394 @@
+//! check-in of entrants on tournament day
+
+use crate::{
+    AuditObjectType, Core, CoreError, CoreResult, DbError, Entrant, SavedGroupSeeding,
+    TournamentBaseCondition, TournamentState,
+    utils::{filter::Filter, list_order::ListOrder, validation::FieldError},
+};
+use chrono::{DateTime, Utc};
+use displaydoc::Display;
+use serde::{Deserialize, Serialize};
+use uuid::Uuid;
+
+/// handling of entrants, who are not checked in by the check-in deadline
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default, Serialize, Deserialize, Display)]
+pub enum NoShowPolicy {
+    /// Replace from Waitlist
+    #[default]
+    ReplaceFromWaitlist,
+    /// Convert to Bye
+    Bye,
+}
+
+/// Check-in status of all entrants of a tournament.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct CheckInOverview {
+    pub tournament_id: Uuid,
+    pub deadline: Option<DateTime<Utc>>,
+    pub policy: NoShowPolicy,
+    /// entrants playing in the tournament, sorted by seed and name
+    pub field: Vec<Entrant>,
+    /// waitlisted entrants sorted by waitlist position
+    pub waitlist: Vec<Entrant>,
+    /// entrants, who were removed from the field, since they did not check in by the deadline
+    pub no_shows: Vec<Entrant>,
+}
+
+impl CheckInOverview {
+    /// Check if the deadline has passed at `now`; false, if there is no deadline.
+    pub fn is_deadline_passed(&self, now: DateTime<Utc>) -> bool {
+        self.deadline.is_some_and(|deadline| deadline <= now)
+    }
+
+    /// Number of checked in entrants of the field.
+    pub fn num_checked_in(&self) -> usize {
+        self.field.iter().filter(|e| e.is_checked_in()).count()
+    }
+}
+
+/// Plan the handling of no-shows, i.e. entrants of the field, who are not checked in.
+///
+/// With [`NoShowPolicy::ReplaceFromWaitlist`] checked in entrants of the waitlist replace
+/// no-shows in order of their waitlist position and take over their seed, so that the group
+/// assignment by seed is kept. No-shows, who cannot be replaced, leave a bye. Returns the
+/// changed entrants in order of saving; `entrants` are all entrants of one tournament.
+pub fn plan_no_show_resolution(entrants: &[Entrant], policy: NoShowPolicy) -> Vec<Entrant> {
+    let mut no_shows: Vec<&Entrant> = entrants
+        .iter()
+        .filter(|e| e.is_in_field() && !e.is_checked_in())
+        .collect();
+    // replace no-shows with the best seed first
+    no_shows.sort_by_key(|e| (e.get_seed().is_none(), e.get_seed()));
+    let mut replacements: Vec<&Entrant> = match policy {
+        NoShowPolicy::ReplaceFromWaitlist => entrants
+            .iter()
+            .filter(|e| e.is_waitlisted() && !e.is_no_show() && e.is_checked_in())
+            .collect(),
+        NoShowPolicy::Bye => Vec::new(),
+    };
+    replacements.sort_by_key(|e| e.get_waitlist_position());
+    let mut replacements = replacements.into_iter();
+
+    let mut changed = Vec::new();
+    for no_show in no_shows {
+        // seeds are unique per tournament: free the seed before it is taken over
+        let mut removed = no_show.clone();
+        removed.set_no_show(true).set_seed(None);
+        changed.push(removed);
+        if let Some(replacement) = replacements.next() {
+            let mut replacement = replacement.clone();
+            replacement
+                .set_waitlist_position(None)
+                .set_seed(no_show.get_seed());
+            changed.push(replacement);
+        }
+    }
+    changed
+}
+
+/// Reassign the saved groups of stage 0 after no-shows were resolved: replacements take over
+/// group and position of the no-show they replace, no-shows without replacement leave their
+/// group as a bye. `changed` are the entrants of [`plan_no_show_resolution`] in its order.
+pub fn reassign_no_show_groups(seeding: &mut SavedGroupSeeding, changed: &[Entrant]) {
+    let mut changed = changed.iter().peekable();
+    while let Some(no_show) = changed.next() {
+        if !no_show.is_no_show() {
+            continue;
+        }
+        let replacement = changed.next_if(|e| !e.is_no_show()).map(|e| e.get_id());
+        for group in seeding.groups.iter_mut() {
+            if let Some(position) = group.iter().position(|id| *id == no_show.get_id()) {
+                match replacement {
+                    Some(replacement) => group[position] = replacement,
+                    None => {
+                        group.remove(position);
+                    }
+                }
+            }
+        }
+    }
+}
+
+/// State for check-in of the entrants of one tournament
+pub struct CheckInState {
+    tournament_id: Uuid,
+}
+
+// switch state to check-in state
+impl<S> Core<S> {
+    pub fn as_check_in_state(&self, tournament_id: Uuid) -> Core<CheckInState> {
+        self.switch_state(CheckInState { tournament_id })
+    }
+
+    /// Resolve no-shows of all tournaments, whose check-in deadline has passed and which are
+    /// not started yet. Returns the number of changed entrants.
+    pub async fn resolve_due_check_ins(&self) -> CoreResult<usize> {
+        let now = Utc::now();
+        let filter = Filter::new()
+            .with(TournamentBaseCondition::StateIn(vec![
+                TournamentState::Draft,
+                TournamentState::Published,
+            ]))
+            .with(TournamentBaseCondition::NotArchived);
+        let mut num_changed = 0;
+        for tournament_id in self
+            .database
+            .list_tournament_base_ids(&filter, &ListOrder::default())
+            .await?
+        {
+            let Some(tournament) = self.database.get_tournament_base(tournament_id).await? else {
+                continue;
+            };
+            if tournament.is_check_in_deadline_passed(now) {
+                num_changed += self
+                    .as_check_in_state(tournament_id)
+                    .resolve_no_shows(now)
+                    .await?
+                    .len();
+            }
+        }
+        Ok(num_changed)
+    }
+}
+
+impl Core<CheckInState> {
+    /// Load the check-in status of the tournament. Returns `None`, if the tournament does
+    /// not exist.
+    pub async fn load_overview(&self) -> CoreResult<Option<CheckInOverview>> {
+        let Some(tournament) = self
+            .database
+            .get_tournament_base(self.state.tournament_id)
+            .await?
+        else {
+            return Ok(None);
+        };
+        let entrants = self
+            .as_entrant_state(self.state.tournament_id)
+            .list_entrants()
+            .await?;
+        let (no_shows, entrants): (Vec<_>, Vec<_>) =
+            entrants.into_iter().partition(|e| e.is_no_show());
+        let (mut waitlist, field): (Vec<_>, Vec<_>) =
+            entrants.into_iter().partition(|e| e.is_waitlisted());
+        waitlist.sort_by_key(|e| e.get_waitlist_position());
+        Ok(Some(CheckInOverview {
+            tournament_id: self.state.tournament_id,
+            deadline: tournament.get_check_in_deadline(),
+            policy: tournament.get_no_show_policy(),
+            field,
+            waitlist,
+            no_shows,
+        }))
+    }
+
+    /// Mark entrant `id` as present or absent.
+    pub async fn set_present(&self, id: Uuid, present: bool) -> CoreResult<Entrant> {
+        let mut entrant_core = self.as_entrant_state(self.state.tournament_id);
+        let entrant = entrant_core.set_check_in(id, present).await?;
+        Ok(entrant.clone())
+    }
+
+    /// Put entrant `id` on the waitlist at `position` or move it into the field with `None`.
+    pub async fn set_waitlist_position(
+        &self,
+        id: Uuid,
+        position: Option<u32>,
+    ) -> CoreResult<Entrant> {
+        let mut entrant_core = self.as_entrant_state(self.state.tournament_id);
+        if entrant_core
+            .load(id)
+            .await?
+            .is_none_or(|e| e.get_tournament_id() != self.state.tournament_id)
+        {
+            return Err(CoreError::from(DbError::NotFound));
+        }
+        entrant_core.get_mut().set_waitlist_position(position);
+        let entrant = entrant_core.save().await?;
+        Ok(entrant.clone())
+    }
+
+    /// Set check-in deadline and no-show policy of the tournament.
+    pub async fn set_settings(
+        &self,
+        deadline: Option<DateTime<Utc>>,
+        policy: NoShowPolicy,
+    ) -> CoreResult<()> {
+        let mut base_core = self.as_tournament_base_state();
+        if base_core.load(self.state.tournament_id).await?.is_none() {
+            return Err(CoreError::from(DbError::NotFound));
+        }
+        base_core
+            .get_mut()
+            .set_check_in_deadline(deadline)
+            .set_no_show_policy(policy);
+        base_core.save().await?;
+        Ok(())
+    }
+
+    /// Replace or remove entrants, who are not checked in by the deadline, see
+    /// [`plan_no_show_resolution`]. Nothing is changed, if the deadline has not passed at
+    /// `now`. No-shows can only be resolved before stage 0 starts. The saved groups of stage
+    /// 0 are reassigned, see [`reassign_no_show_groups`]. All changed entrants and the groups
+    /// are saved in one transaction; the changed entrants are returned.
+    pub async fn resolve_no_shows(&self, now: DateTime<Utc>) -> CoreResult<Vec<Entrant>> {
+        let tournament_id = self.state.tournament_id;
+        let tournament = self
+            .database
+            .get_tournament_base(tournament_id)
+            .await?
+            .ok_or(CoreError::from(DbError::NotFound))?;
+        if !tournament.is_check_in_deadline_passed(now) {
+            return Ok(Vec::new());
+        }
+        if matches!(
+            tournament.get_tournament_state(),
+            TournamentState::ActiveStage(_) | TournamentState::Finished
+        ) {
+            return Err(CoreError::from(
+                FieldError::builder()
+                    .set_field(String::from("check_in_deadline"))
+                    .add_message("no-shows can only be resolved before stage 0 starts")
+                    .set_object_id(tournament_id)
+                    .build(),
+            ));
+        }
+        let entrants = self
+            .database
+            .list_entrants_of_tournament(tournament_id)
+            .await?;
+        let changed = plan_no_show_resolution(&entrants, tournament.get_no_show_policy());
+        if changed.is_empty() {
+            return Ok(changed);
+        }
+
+        self.with_transaction(|core| async move {
+            let entrant_core = core.as_entrant_state(tournament_id);
+            let mut saved = Vec::with_capacity(changed.len());
+            for entrant in changed {
+                let old = entrants.iter().find(|e| e.get_id() == entrant.get_id());
+                let entrant = entrant_core.database.save_entrant(&entrant).await?;
+                entrant_core.publish_entrant_update(&entrant).await?;
+                entrant_core
+                    .audit_save(AuditObjectType::Entrant, Some(tournament_id), old, &entrant)
+                    .await;
+                saved.push(entrant);
+            }
+            if let Some(stage) = core.database.get_stage_by_number(tournament_id, 0).await?
+                && let Some(mut seeding) = core.database.get_group_seeding(stage.get_id()).await?
+            {
+                reassign_no_show_groups(&mut seeding, &saved);
+                core.database.save_group_seeding(&seeding).await?;
+            }
+            entrant_core.dispatch_entrants_updated(saved.len()).await?;
+            Ok(saved)
+        })
+        .await
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::utils::id_version::IdVersion;
+
+    fn entrant(name: &str, seed: Option<u32>, checked_in: bool) -> Entrant {
+        let mut e = Entrant::new(IdVersion::new(Uuid::new_v4(), Some(0)));
+        e.set_name(name)
+            .set_seed(seed)
+            .set_checked_in_at(checked_in.then(Utc::now));
+        e
+    }
+
+    fn waitlisted(name: &str, position: u32, checked_in: bool) -> Entrant {
+        let mut e = entrant(name, None, checked_in);
+        e.set_waitlist_position(Some(position));
+        e
+    }
+
+    fn find<'a>(changed: &'a [Entrant], name: &str) -> &'a Entrant {
+        changed.iter().find(|e| e.get_name() == name).unwrap()
+    }
+
+    #[test]
+    fn given_all_checked_in_when_plan_then_nothing_changes() {
+        let entrants = vec![
+            entrant("A", Some(1), true),
+            entrant("B", Some(2), true),
+            waitlisted("W", 1, false),
+        ];
+        assert!(plan_no_show_resolution(&entrants, NoShowPolicy::ReplaceFromWaitlist).is_empty());
+    }
+
+    #[test]
+    fn given_waitlist_when_plan_then_present_waitlisted_replace_no_shows_in_order() {
+        let entrants = vec![
+            entrant("A", Some(1), true),
+            entrant("B", Some(2), false),
+            entrant("C", Some(3), false),
+            entrant("D", Some(4), false),
+            waitlisted("W2", 2, true),
+            waitlisted("W1", 1, true),
+            waitlisted("W3", 3, false),
+        ];
+        let changed = plan_no_show_resolution(&entrants, NoShowPolicy::ReplaceFromWaitlist);
+        assert_eq!(changed.len(), 5);
+
+        let w1 = find(&changed, "W1");
+        assert!(w1.is_in_field());
+        assert_eq!(w1.get_seed(), Some(2));
+        let w2 = find(&changed, "W2");
+        assert!(w2.is_in_field());
+        assert_eq!(w2.get_seed(), Some(3));
+        for name in ["B", "C", "D"] {
+            let no_show = find(&changed, name);
+            assert!(no_show.is_no_show());
+            assert!(!no_show.is_in_field());
+            assert_eq!(no_show.get_seed(), None);
+        }
+        // absent waitlisted entrant stays on the waitlist
+        assert!(changed.iter().all(|e| e.get_name() != "W3"));
+    }
+
+    #[test]
+    fn given_resolved_no_shows_when_reassign_groups_then_replacements_take_their_place() {
+        let entrants = vec![
+            entrant("A", Some(1), true),
+            entrant("B", Some(2), false),
+            entrant("C", Some(3), false),
+            entrant("D", Some(4), true),
+            waitlisted("W1", 1, true),
+        ];
+        let id = |name: &str| {
+            entrants
+                .iter()
+                .find(|e| e.get_name() == name)
+                .unwrap()
+                .get_id()
+        };
+        let mut seeding = SavedGroupSeeding {
+            tournament_id: Uuid::new_v4(),
+            stage_id: Uuid::new_v4(),
+            groups: vec![vec![id("A"), id("D")], vec![id("B"), id("C")]],
+            ranking_system: None,
+            strategy: Default::default(),
+        };
+
+        let changed = plan_no_show_resolution(&entrants, NoShowPolicy::ReplaceFromWaitlist);
+        reassign_no_show_groups(&mut seeding, &changed);
+
+        assert_eq!(seeding.groups, vec![vec![id("A"), id("D")], vec![id("W1")]]);
+    }
+
+    #[test]
+    fn given_bye_policy_when_plan_then_no_shows_are_removed_without_replacement() {
+        let entrants = vec![
+            entrant("A", Some(1), false),
+            entrant("B", None, true),
+            waitlisted("W1", 1, true),
+        ];
+        let changed = plan_no_show_resolution(&entrants, NoShowPolicy::Bye);
+        assert_eq!(changed.len(), 1);
+        assert_eq!(changed[0].get_name(), "A");
+        assert!(changed[0].is_no_show());
+    }
+}
@@ -0,0 +1,244 @@
+//! errors of clients reported to the server
+
+use crate::{
+    Core, CoreResult,
+    utils::{id_version::IdVersion, normalize::*, traits::ObjectIdVersion, validation::*},
+};
+use chrono::{DateTime, Utc};
+use serde::{Deserialize, Serialize};
+use uuid::Uuid;
+
+/// maximum length of a reported client error in characters; longer errors are truncated
+pub const CLIENT_ERROR_MESSAGE_MAX_LEN: usize = 2000;
+
+/// Uncaught error of a client, e.g. a failed load of a component.
+///
+/// Clients deduplicate errors before reporting: `occurrences` counts, how often the error
+/// occurred since it was reported last. Reports are append only.
+#[derive(Clone, Debug, PartialEq, Eq, Serialize, Deserialize, ObjectIdVersion)]
+pub struct ClientErrorReport {
+    /// id and optimistic locking version of report
+    id_version: IdVersion,
+    /// id of the component, which caught the error
+    component_id: Option<Uuid>,
+    /// route of the client, e.g. `/tournaments/edit`
+    route: String,
+    /// version of the app of the client
+    app_version: String,
+    /// error message
+    message: String,
+    /// number of occurrences of the error since the last report
+    occurrences: u32,
+    /// timestamp of creation; set by database
+    created_at: Option<DateTime<Utc>>,
+}
+
+impl Default for ClientErrorReport {
+    fn default() -> Self {
+        ClientErrorReport {
+            id_version: IdVersion::default(),
+            component_id: None,
+            route: String::new(),
+            app_version: String::new(),
+            message: String::new(),
+            occurrences: 1,
+            created_at: None,
+        }
+    }
+}
+
+impl ClientErrorReport {
+    /// Create a new `ClientErrorReport` with the given `IdVersion`.
+    pub fn new(id_version: IdVersion) -> Self {
+        ClientErrorReport {
+            id_version,
+            ..Default::default()
+        }
+    }
+
+    /// Get the unique identifier of the report.
+    pub fn get_id(&self) -> Uuid {
+        self.id_version.get_id()
+    }
+
+    /// Get the version number of the report.
+    pub fn get_version(&self) -> Option<u32> {
+        self.id_version.get_version()
+    }
+
+    /// Get the id of the component, which caught the error.
+    pub fn get_component_id(&self) -> Option<Uuid> {
+        self.component_id
+    }
+
+    /// Get the route of the client.
+    pub fn get_route(&self) -> &str {
+        &self.route
+    }
+
+    /// Get the version of the app of the client.
+    pub fn get_app_version(&self) -> &str {
+        &self.app_version
+    }
+
+    /// Get the error message.
+    pub fn get_message(&self) -> &str {
+        &self.message
+    }
+
+    /// Get the number of occurrences of the error since the last report.
+    pub fn get_occurrences(&self) -> u32 {
+        self.occurrences
+    }
+
+    /// Get the timestamp of creation, if the report is persisted.
+    pub fn get_created_at(&self) -> Option<DateTime<Utc>> {
+        self.created_at
+    }
+
+    /// Set the `IdVersion` of the report.
+    pub fn set_id_version(&mut self, id_version: IdVersion) -> &mut Self {
+        self.id_version = id_version;
+        self
+    }
+
+    /// Set the id of the component, which caught the error.
+    pub fn set_component_id(&mut self, component_id: Option<Uuid>) -> &mut Self {
+        self.component_id = component_id;
+        self
+    }
+
+    /// Set the route of the client with whitespace normalization.
+    pub fn set_route(&mut self, route: impl Into<String>) -> &mut Self {
+        self.route = normalize_ws(route);
+        self
+    }
+
+    /// Set the version of the app of the client with whitespace normalization.
+    pub fn set_app_version(&mut self, app_version: impl Into<String>) -> &mut Self {
+        self.app_version = normalize_ws(app_version);
+        self
+    }
+
+    /// Set the error message; it is truncated to [`CLIENT_ERROR_MESSAGE_MAX_LEN`] characters.
+    pub fn set_message(&mut self, message: impl Into<String>) -> &mut Self {
+        self.message = message
+            .into()
+            .trim()
+            .chars()
+            .take(CLIENT_ERROR_MESSAGE_MAX_LEN)
+            .collect();
+        self
+    }
+
+    /// Set the number of occurrences of the error since the last report.
+    pub fn set_occurrences(&mut self, occurrences: u32) -> &mut Self {
+        self.occurrences = occurrences;
+        self
+    }
+
+    /// Set the timestamp of creation. Only used by database adapters.
+    pub fn set_created_at(&mut self, created_at: Option<DateTime<Utc>>) -> &mut Self {
+        self.created_at = created_at;
+        self
+    }
+
+    /// Validate the report.
+    pub fn validate(&self) -> ValidationResult<()> {
+        let mut errs = ValidationErrors::new();
+        let object_id = self.get_id();
+
+        if self.message.is_empty() {
+            errs.add(
+                FieldError::builder()
+                    .set_field(String::from("message"))
+                    .add_required()
+                    .set_object_id(object_id)
+                    .build(),
+            );
+        }
+        if self.route.is_empty() {
+            errs.add(
+                FieldError::builder()
+                    .set_field(String::from("route"))
+                    .add_required()
+                    .set_object_id(object_id)
+                    .build(),
+            );
+        }
+        if self.occurrences == 0 {
+            errs.add(
+                FieldError::builder()
+                    .set_field(String::from("occurrences"))
+                    .add_user_defined_code("invalid_value")
+                    .add_message("An error must occur at least once")
+                    .set_object_id(object_id)
+                    .build(),
+            );
+        }
+
+        if errs.is_empty() { Ok(()) } else { Err(errs) }
+    }
+}
+
+/// State for reporting and listing client errors
+pub struct ClientErrorState {
+    report: ClientErrorReport,
+}
+
+// switch state to client error state
+impl<S> Core<S> {
+    pub fn as_client_error_state(&self) -> Core<ClientErrorState> {
+        self.switch_state(ClientErrorState {
+            report: ClientErrorReport::default(),
+        })
+    }
+}
+
+impl Core<ClientErrorState> {
+    pub fn get(&self) -> &ClientErrorReport {
+        &self.state.report
+    }
+    pub fn get_mut(&mut self) -> &mut ClientErrorReport {
+        &mut self.state.report
+    }
+    /// Save a new report. Reports are append only.
+    pub async fn save(&mut self) -> CoreResult<&ClientErrorReport> {
+        self.state.report.validate()?;
+        self.state.report = self.database.save_client_error(&self.state.report).await?;
+        Ok(self.get())
+    }
+    /// List reported client errors, newest first.
+    pub async fn list_client_errors(
+        &self,
+        limit: Option<usize>,
+    ) -> CoreResult<Vec<ClientErrorReport>> {
+        let mut list = self.database.list_client_errors(limit).await?;
+        list.sort_by_key(|r| std::cmp::Reverse(r.get_created_at()));
+        Ok(list)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn given_empty_report_when_validate_then_all_errors_are_collected() {
+        let mut r = ClientErrorReport::default();
+        r.set_occurrences(0);
+        let errs = r.validate().unwrap_err();
+        let fields: Vec<_> = errs.errors.iter().map(|e| e.get_field()).collect();
+        assert_eq!(fields, vec!["message", "route", "occurrences"]);
+    }
+
+    #[test]
+    fn given_long_message_when_set_then_truncated() {
+        let mut r = ClientErrorReport::default();
+        r.set_message("x".repeat(CLIENT_ERROR_MESSAGE_MAX_LEN + 10));
+        assert_eq!(
+            r.get_message().chars().count(),
+            CLIENT_ERROR_MESSAGE_MAX_LEN
+        );
+    }
+}
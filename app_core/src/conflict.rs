@@ -0,0 +1,180 @@
+//! Resolution of optimistic lock conflicts
+//!
+//! If an object was saved by someone else since it was loaded, saving it fails with a
+//! version conflict. Core returns the current server copy of the object with the conflict,
+//! so that the client can decide to keep its own copy, take the server copy or merge both
+//! copies field by field.
+
+use crate::{PostalAddress, SportConfig, TournamentBase};
+use serde::{Deserialize, Serialize};
+
+/// current server copy of an object, whose save failed due to a version conflict
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub enum ServerCopy {
+    PostalAddress(PostalAddress),
+    SportConfig(SportConfig),
+    TournamentBase(TournamentBase),
+}
+
+impl ServerCopy {
+    /// Get the version of the server copy.
+    pub fn get_version(&self) -> Option<u32> {
+        match self {
+            ServerCopy::PostalAddress(pa) => pa.get_version(),
+            ServerCopy::SportConfig(sc) => sc.get_version(),
+            ServerCopy::TournamentBase(tb) => tb.get_version(),
+        }
+    }
+
+    /// Get the server copy, if it is a postal address.
+    pub fn as_postal_address(&self) -> Option<&PostalAddress> {
+        match self {
+            ServerCopy::PostalAddress(pa) => Some(pa),
+            _ => None,
+        }
+    }
+
+    /// Get the server copy, if it is a sport config.
+    pub fn as_sport_config(&self) -> Option<&SportConfig> {
+        match self {
+            ServerCopy::SportConfig(sc) => Some(sc),
+            _ => None,
+        }
+    }
+
+    /// Get the server copy, if it is a tournament base.
+    pub fn as_tournament_base(&self) -> Option<&TournamentBase> {
+        match self {
+            ServerCopy::TournamentBase(tb) => Some(tb),
+            _ => None,
+        }
+    }
+}
+
+/// field, whose value differs between the local copy and the server copy of an object
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct ConflictField {
+    /// name of field, e.g. `name` or `config.num_sets`
+    pub field: String,
+    /// display value of the local copy
+    pub mine: String,
+    /// display value of the server copy
+    pub theirs: String,
+}
+
+/// objects, whose copies can be merged field by field
+pub trait MergeFields: Clone {
+    /// Names of all fields, which may be taken from the local copy during a merge.
+    /// `theirs` is the server copy, e.g. to include keys of dynamic configurations.
+    fn merge_field_names(&self, theirs: &Self) -> Vec<String>;
+    /// Display value of a field; `None`, if the field is unknown.
+    fn merge_field_value(&self, field: &str) -> Option<String>;
+    /// Take the value of a field from `other`. Unknown fields are ignored.
+    fn take_merge_field(&mut self, other: &Self, field: &str);
+
+    /// Get all fields, whose values differ between `self` (mine) and `theirs`.
+    fn conflicting_fields(&self, theirs: &Self) -> Vec<ConflictField> {
+        self.merge_field_names(theirs)
+            .into_iter()
+            .filter_map(|field| {
+                let mine = self.merge_field_value(&field).unwrap_or_default();
+                let theirs = theirs.merge_field_value(&field).unwrap_or_default();
+                (mine != theirs).then_some(ConflictField {
+                    field,
+                    mine,
+                    theirs,
+                })
+            })
+            .collect()
+    }
+}
+
+/// decision of the user how to resolve a version conflict
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub enum ConflictResolution {
+    /// overwrite the server copy with the local copy
+    KeepMine,
+    /// discard the local copy
+    TakeTheirs,
+    /// take the listed fields from the local copy and all other fields from the server copy
+    Merge(Vec<String>),
+}
+
+impl ConflictResolution {
+    /// Resolve the conflict of `mine` and `theirs`. The resolved object always has the
+    /// version of `theirs`, so that it can be saved with optimistic locking.
+    pub fn resolve<T: MergeFields>(&self, mine: &T, theirs: &T) -> T {
+        let mut resolved = theirs.clone();
+        let fields = match self {
+            ConflictResolution::KeepMine => mine.merge_field_names(theirs),
+            ConflictResolution::TakeTheirs => Vec::new(),
+            ConflictResolution::Merge(fields) => fields.clone(),
+        };
+        for field in fields {
+            resolved.take_merge_field(mine, &field);
+        }
+        resolved
+    }
+}
+
+/// Display value of a field: strings are shown as they are, all other values as JSON.
+pub fn merge_display_value(value: impl Serialize) -> String {
+    match serde_json::to_value(value) {
+        Ok(serde_json::Value::String(s)) => s,
+        Ok(serde_json::Value::Null) => String::new(),
+        Ok(value) => value.to_string(),
+        Err(_) => String::new(),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use serde_json::json;
+
+    #[test]
+    fn sport_config_is_merged_by_config_keys() {
+        let mut mine = SportConfig::default();
+        mine.set_name("Mine")
+            .set_config(json!({"sets": 3, "points": 11}));
+        let mut theirs = mine.clone();
+        theirs
+            .set_name("Theirs")
+            .set_config(json!({"sets": 5, "points": 11, "tie_break": true}));
+
+        let fields = mine
+            .conflicting_fields(&theirs)
+            .into_iter()
+            .map(|f| f.field)
+            .collect::<Vec<_>>();
+        assert_eq!(fields, vec!["name", "config.sets", "config.tie_break"]);
+
+        let merged =
+            ConflictResolution::Merge(vec![String::from("config.sets")]).resolve(&mine, &theirs);
+        assert_eq!(merged.get_name(), "Theirs");
+        assert_eq!(
+            merged.get_config(),
+            &json!({"sets": 3, "points": 11, "tie_break": true})
+        );
+    }
+
+    #[test]
+    fn tournament_base_keeps_stations_consistent_on_merge() {
+        let mut mine = TournamentBase::default();
+        mine.set_num_stations(2);
+        let mut theirs = mine.clone();
+        theirs.set_stations(
+            crate::StationSetup {
+                count: 4,
+                ..Default::default()
+            }
+            .stations(),
+        );
+
+        let merged =
+            ConflictResolution::Merge(vec![String::from("num_stations")]).resolve(&mine, &theirs);
+
+        assert_eq!(merged.get_num_stations(), 2);
+        assert_eq!(merged.get_stations().len(), 2);
+    }
+}
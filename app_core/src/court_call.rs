@@ -0,0 +1,329 @@
+//! court calls of matches
+//!
+//! When a station becomes free, the next match of the station is called, i.e. its entrants
+//! are asked to come to the station. With a call-ahead (see [`CourtCallPolicy`]) the
+//! following matches of each station are called to warm up. Calls are shown on the announcer
+//! screen and, if configured, sent to the entrants by email.
+
+use crate::{Core, CoreError, CoreResult, CrMsg, CrTopic, DbError, Entrant, Match, TournamentBase};
+use chrono::{DateTime, Utc};
+use displaydoc::Display;
+use serde::{Deserialize, Serialize};
+use std::collections::{BTreeMap, HashMap};
+use tracing::info;
+use uuid::Uuid;
+
+/// maximum number of matches per station, which may be called ahead
+pub const MAX_CALL_AHEAD: u32 = 3;
+
+/// configuration of court calls of a tournament
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default, Serialize, Deserialize)]
+pub struct CourtCallPolicy {
+    /// number of matches per station, which are called ahead to warm up, e.g. 1 calls the
+    /// next-but-one match
+    #[serde(default)]
+    pub call_ahead: u32,
+    /// send calls of matches to their entrants by email
+    #[serde(default)]
+    pub notify_entrants: bool,
+}
+
+/// state of a called match
+#[derive(
+    Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash, Serialize, Deserialize, Display,
+)]
+pub enum CourtCallState {
+    /// Called
+    Called,
+    /// Warm up
+    WarmUp,
+}
+
+/// scheduled match, which may be called
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct CourtCallCandidate {
+    pub match_id: Uuid,
+    /// number of station (starting with 1), at which the match is played
+    pub station: u32,
+    pub start_at: DateTime<Utc>,
+    /// match is played right now
+    pub in_progress: bool,
+    /// result of match is known
+    pub decided: bool,
+    /// entrants of both sides, as far as they are decided
+    pub entrant_ids: Vec<Uuid>,
+}
+
+impl From<&Match> for CourtCallCandidate {
+    fn from(m: &Match) -> Self {
+        CourtCallCandidate {
+            match_id: *m.get_id(),
+            station: m.get_station() as u32,
+            start_at: m.get_start_at().with_timezone(&Utc),
+            in_progress: m.is_in_progress(),
+            decided: m.is_decided(),
+            entrant_ids: m
+                .get_entrants()
+                .map(|(a, b)| vec![*a, *b])
+                .unwrap_or_default(),
+        }
+    }
+}
+
+/// call of a match to its station
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct CourtCall {
+    pub match_id: Uuid,
+    /// number of station, starting with 1
+    pub station: u32,
+    /// name of station; `Station <number>`, if the station is not named
+    pub station_name: String,
+    pub start_at: DateTime<Utc>,
+    pub entrant_ids: Vec<Uuid>,
+    /// names of entrants; empty, if the entrants are not decided yet
+    pub entrants: Vec<String>,
+    pub state: CourtCallState,
+}
+
+/// queue of court calls of a tournament, see [`Core::load_court_calls`]
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct CourtCallQueue {
+    pub tournament_id: Uuid,
+    pub tournament_name: String,
+    pub generated_at: DateTime<Utc>,
+    /// called matches in order of their station, followed by matches to warm up in order
+    /// of station and start
+    pub calls: Vec<CourtCall>,
+}
+
+impl CourtCallQueue {
+    /// Build the queue of `tournament` from `candidates`. A station is free, if none of its
+    /// matches is in progress; the next pending match of a free station is called. The
+    /// following pending matches of each station are called to warm up according to the
+    /// court call policy of the tournament.
+    pub fn build(
+        tournament: &TournamentBase,
+        entrants: &[Entrant],
+        candidates: &[CourtCallCandidate],
+        now: DateTime<Utc>,
+    ) -> Self {
+        let names: HashMap<Uuid, &str> = entrants
+            .iter()
+            .map(|e| (e.get_id(), e.get_name()))
+            .collect();
+        let call_ahead = tournament.get_court_call_policy().call_ahead as usize;
+        let mut per_station: BTreeMap<u32, Vec<&CourtCallCandidate>> = BTreeMap::new();
+        for candidate in candidates.iter().filter(|c| !c.decided) {
+            per_station
+                .entry(candidate.station)
+                .or_default()
+                .push(candidate);
+        }
+
+        let mut calls = Vec::new();
+        for (station, mut matches) in per_station {
+            matches.sort_by_key(|m| (m.start_at, m.match_id));
+            let is_free = !matches.iter().any(|m| m.in_progress);
+            let states = is_free
+                .then_some(CourtCallState::Called)
+                .into_iter()
+                .chain(std::iter::repeat_n(CourtCallState::WarmUp, call_ahead));
+            let pending = matches.into_iter().filter(|m| !m.in_progress);
+            calls.extend(pending.zip(states).map(|(m, state)| {
+                CourtCall {
+                    match_id: m.match_id,
+                    station,
+                    station_name: tournament
+                        .get_station_name(station)
+                        .map(str::to_string)
+                        .unwrap_or_else(|| format!("Station {station}")),
+                    start_at: m.start_at,
+                    entrant_ids: m.entrant_ids.clone(),
+                    entrants: m
+                        .entrant_ids
+                        .iter()
+                        .filter_map(|id| names.get(id).map(|name| name.to_string()))
+                        .collect(),
+                    state,
+                }
+            }));
+        }
+        // stable sort keeps order of stations and start
+        calls.sort_by_key(|c| c.state);
+
+        CourtCallQueue {
+            tournament_id: tournament.get_id(),
+            tournament_name: tournament.get_name().to_string(),
+            generated_at: now,
+            calls,
+        }
+    }
+
+    /// Called match of `station`, if any.
+    pub fn called_at(&self, station: u32) -> Option<&CourtCall> {
+        self.calls
+            .iter()
+            .find(|c| c.station == station && c.state == CourtCallState::Called)
+    }
+}
+
+impl<S> Core<S> {
+    /// Load the court call queue of the tournament with id `tournament_id`.
+    pub async fn load_court_calls(
+        &self,
+        tournament_id: Uuid,
+    ) -> CoreResult<Option<CourtCallQueue>> {
+        let Some(tournament) = self.database.get_tournament_base(tournament_id).await? else {
+            return Ok(None);
+        };
+        let entrants = self
+            .database
+            .list_entrants_of_tournament(tournament_id)
+            .await?;
+        let candidates: Vec<CourtCallCandidate> = self
+            .database
+            .list_matches_of_tournament(tournament_id)
+            .await?
+            .iter()
+            // matches without station are not placed yet
+            .filter(|m| m.get_station() > 0)
+            .map(CourtCallCandidate::from)
+            .collect();
+        Ok(Some(CourtCallQueue::build(
+            &tournament,
+            &entrants,
+            &candidates,
+            Utc::now(),
+        )))
+    }
+
+    /// Announce, that `station` of the tournament became free, e.g. after entry of a result.
+    /// The next match of the station is called: announcer screens are notified and, if
+    /// configured, its entrants are sent an email. Returns the call, if the station has a
+    /// pending match.
+    pub async fn announce_free_station(
+        &self,
+        tournament_id: Uuid,
+        station: u32,
+    ) -> CoreResult<Option<CourtCall>> {
+        let Some(queue) = self.load_court_calls(tournament_id).await? else {
+            return Err(CoreError::from(DbError::NotFound));
+        };
+        let Some(call) = queue.called_at(station).cloned() else {
+            info!(%tournament_id, station, "no_match_to_call");
+            return Ok(None);
+        };
+        let notice = CrTopic::CourtCalls { tournament_id };
+        let msg = CrMsg::CourtCalled {
+            id: call.match_id,
+            version: station,
+        };
+        self.client_registry.publish(notice, msg).await?;
+
+        if let Some(tournament) = self.database.get_tournament_base(tournament_id).await?
+            && tournament.get_court_call_policy().notify_entrants
+            // sandbox tournaments must not leak to entrants
+            && !tournament.is_sandbox()
+        {
+            self.notify_court_call(&tournament, &call).await;
+        }
+        Ok(Some(call))
+    }
+
+    /// Set the court call policy of the tournament with id `tournament_id`.
+    pub async fn set_court_call_policy(
+        &self,
+        tournament_id: Uuid,
+        policy: CourtCallPolicy,
+    ) -> CoreResult<TournamentBase> {
+        let mut base_core = self.as_tournament_base_state();
+        if base_core.load(tournament_id).await?.is_none() {
+            return Err(CoreError::from(DbError::NotFound));
+        }
+        base_core.get_mut().set_court_call_policy(policy);
+        let saved = base_core.save().await?;
+        Ok(saved.clone())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use chrono::TimeZone;
+
+    fn at(hour: u32) -> DateTime<Utc> {
+        Utc.with_ymd_and_hms(2026, 7, 11, hour, 0, 0).unwrap()
+    }
+
+    fn candidate(station: u32, hour: u32, in_progress: bool, decided: bool) -> CourtCallCandidate {
+        CourtCallCandidate {
+            match_id: Uuid::new_v4(),
+            station,
+            start_at: at(hour),
+            in_progress,
+            decided,
+            entrant_ids: Vec::new(),
+        }
+    }
+
+    #[test]
+    fn given_free_and_busy_stations_when_build_then_next_matches_are_called() {
+        let mut tournament = TournamentBase::default();
+        tournament
+            .set_num_stations(2)
+            .set_court_call_policy(CourtCallPolicy {
+                call_ahead: 1,
+                notify_entrants: false,
+            });
+        let candidates = vec![
+            // station 1 is free after its first match
+            candidate(1, 9, false, true),
+            candidate(1, 11, false, false),
+            candidate(1, 10, false, false),
+            candidate(1, 12, false, false),
+            // station 2 is busy
+            candidate(2, 9, true, false),
+            candidate(2, 10, false, false),
+            candidate(2, 11, false, false),
+        ];
+
+        let queue = CourtCallQueue::build(&tournament, &[], &candidates, at(10));
+
+        let calls: Vec<(u32, DateTime<Utc>, CourtCallState)> = queue
+            .calls
+            .iter()
+            .map(|c| (c.station, c.start_at, c.state))
+            .collect();
+        assert_eq!(
+            calls,
+            vec![
+                (1, at(10), CourtCallState::Called),
+                (1, at(11), CourtCallState::WarmUp),
+                (2, at(10), CourtCallState::WarmUp),
+            ]
+        );
+        assert_eq!(queue.called_at(1).unwrap().match_id, candidates[2].match_id);
+        assert!(queue.called_at(2).is_none());
+        assert_eq!(queue.calls[0].station_name, "Station 1");
+    }
+
+    #[test]
+    fn given_too_many_calls_ahead_when_validate_then_error() {
+        let mut tournament = TournamentBase::default();
+        tournament
+            .set_name("Cup")
+            .set_num_entrants(8)
+            .set_court_call_policy(CourtCallPolicy {
+                call_ahead: MAX_CALL_AHEAD + 1,
+                notify_entrants: true,
+            });
+
+        let errs = tournament.validate().unwrap_err();
+
+        assert!(
+            errs.errors
+                .iter()
+                .any(|e| e.get_field() == "court_call_policy.call_ahead")
+        );
+    }
+}
@@ -0,0 +1,255 @@
+//! domain events for integrations
+//!
+//! Core emits a [`DomainEvent`] alongside the messages to the client registry. Messages of
+//! the client registry only name the changed object, whereas events carry the data external
+//! software needs, e.g. a scoreboard following the event stream of the server. Events are
+//! emitted through the [`DomainEventPort`]; events of a transaction are emitted after commit.
+//! Like webhooks, sandbox tournaments emit no events.
+
+use crate::{Core, TournamentBase, TournamentState};
+use chrono::{DateTime, Utc};
+use serde::{Deserialize, Serialize};
+use tracing::warn;
+use uuid::Uuid;
+
+/// Events of tournaments. Serialized with the event type as field `type`, e.g.
+/// `{"type": "stage_completed", "tournament_id": "<uuid>", ...}`.
+#[derive(Clone, Debug, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(tag = "type", rename_all = "snake_case")]
+pub enum DomainEvent {
+    TournamentCreated {
+        tournament_id: Uuid,
+        sport_id: Uuid,
+        name: String,
+    },
+    TournamentUpdated {
+        tournament_id: Uuid,
+        name: String,
+        /// state as displayed, e.g. `Running (Stage 1)`
+        state: String,
+    },
+    EntrantsUpdated {
+        tournament_id: Uuid,
+        /// number of entrants, which were added or changed
+        num_changed: usize,
+    },
+    MatchResultEntered {
+        tournament_id: Uuid,
+        stage_number: u32,
+        match_number: u32,
+        /// result as displayed by the sport plugin, e.g. `2:1 (11:9, 8:11, 11:5)`
+        result: String,
+    },
+    /// scheduled sides of a match were resolved to entrants, e.g. to the winner of a previous
+    /// match of a KO bracket; unresolved sides are `None`
+    MatchEntrantsResolved {
+        tournament_id: Uuid,
+        match_id: Uuid,
+        entrant_a: Option<Uuid>,
+        entrant_b: Option<Uuid>,
+    },
+    /// final score of a match was corrected
+    MatchScoreCorrected {
+        tournament_id: Uuid,
+        match_id: Uuid,
+        winner_changed: bool,
+        /// number of dependent matches, whose entrants or results are affected
+        num_affected_matches: usize,
+    },
+    StageCompleted {
+        tournament_id: Uuid,
+        stage_number: u32,
+        stage_name: String,
+    },
+    TournamentFinished {
+        tournament_id: Uuid,
+    },
+    /// completed stage is active again after a rollback to its snapshot
+    StageRolledBack {
+        tournament_id: Uuid,
+        stage_number: u32,
+    },
+}
+
+impl DomainEvent {
+    /// type of event as serialized in field `type`
+    pub fn event_type(&self) -> &'static str {
+        match self {
+            DomainEvent::TournamentCreated { .. } => "tournament_created",
+            DomainEvent::TournamentUpdated { .. } => "tournament_updated",
+            DomainEvent::EntrantsUpdated { .. } => "entrants_updated",
+            DomainEvent::MatchResultEntered { .. } => "match_result_entered",
+            DomainEvent::MatchEntrantsResolved { .. } => "match_entrants_resolved",
+            DomainEvent::MatchScoreCorrected { .. } => "match_score_corrected",
+            DomainEvent::StageCompleted { .. } => "stage_completed",
+            DomainEvent::TournamentFinished { .. } => "tournament_finished",
+            DomainEvent::StageRolledBack { .. } => "stage_rolled_back",
+        }
+    }
+
+    /// id of the tournament, the event belongs to
+    pub fn tournament_id(&self) -> Uuid {
+        match self {
+            DomainEvent::TournamentCreated { tournament_id, .. }
+            | DomainEvent::TournamentUpdated { tournament_id, .. }
+            | DomainEvent::EntrantsUpdated { tournament_id, .. }
+            | DomainEvent::MatchResultEntered { tournament_id, .. }
+            | DomainEvent::MatchEntrantsResolved { tournament_id, .. }
+            | DomainEvent::MatchScoreCorrected { tournament_id, .. }
+            | DomainEvent::StageCompleted { tournament_id, .. }
+            | DomainEvent::StageRolledBack { tournament_id, .. }
+            | DomainEvent::TournamentFinished { tournament_id } => *tournament_id,
+        }
+    }
+
+    /// Events of saving `tournament`, whose stored copy before the save is `old`; `old` is
+    /// `None` for new tournaments.
+    pub fn tournament_saved(
+        tournament: &TournamentBase,
+        old: Option<&TournamentBase>,
+    ) -> Vec<Self> {
+        let tournament_id = tournament.get_id();
+        let mut events = Vec::new();
+        let Some(old) = old else {
+            events.push(DomainEvent::TournamentCreated {
+                tournament_id,
+                sport_id: tournament.get_sport_id(),
+                name: tournament.get_name().to_string(),
+            });
+            return events;
+        };
+        events.push(DomainEvent::TournamentUpdated {
+            tournament_id,
+            name: tournament.get_name().to_string(),
+            state: tournament.get_tournament_state().to_string(),
+        });
+        let previous = Some(old.get_tournament_state());
+        let next = tournament.get_tournament_state();
+        if let Some(stage_number) = completed_stage(previous, next) {
+            events.push(DomainEvent::StageCompleted {
+                tournament_id,
+                stage_number,
+                stage_name: tournament
+                    .get_tournament_mode()
+                    .get_stage_name(stage_number)
+                    .unwrap_or_else(|| format!("Stage {}", stage_number + 1)),
+            });
+        }
+        if is_finishing(previous, next) {
+            events.push(DomainEvent::TournamentFinished { tournament_id });
+        }
+        events
+    }
+}
+
+/// Stage completed by changing the state of a tournament from `previous` to `next`.
+pub(crate) fn completed_stage(
+    previous: Option<TournamentState>,
+    next: TournamentState,
+) -> Option<u32> {
+    let Some(TournamentState::ActiveStage(completed)) = previous else {
+        return None;
+    };
+    let is_completed = match next {
+        TournamentState::ActiveStage(stage) => stage > completed,
+        TournamentState::Finished => true,
+        TournamentState::Draft | TournamentState::Published => false,
+    };
+    is_completed.then_some(completed)
+}
+
+/// Returns true, if changing the state of a tournament from `previous` to `next` finishes it.
+pub(crate) fn is_finishing(previous: Option<TournamentState>, next: TournamentState) -> bool {
+    next == TournamentState::Finished && previous != Some(TournamentState::Finished)
+}
+
+/// Emitted event with id and time of occurrence.
+#[derive(Clone, Debug, PartialEq, Eq, Serialize, Deserialize)]
+pub struct DomainEventEnvelope {
+    /// id of event; subscribers may use it to detect duplicates
+    pub event_id: Uuid,
+    pub occurred_at: DateTime<Utc>,
+    pub event: DomainEvent,
+}
+
+impl DomainEventEnvelope {
+    pub fn new(event: DomainEvent) -> Self {
+        DomainEventEnvelope {
+            event_id: Uuid::new_v4(),
+            occurred_at: Utc::now(),
+            event,
+        }
+    }
+}
+
+impl<S> Core<S> {
+    /// Emit `event` to subscribers of domain events.
+    ///
+    /// Failures are logged and do not fail the operation, which caused the event.
+    pub async fn emit_domain_event(&self, event: DomainEvent) {
+        // domain events of a transaction are emitted after commit
+        if let Some(events) = self.transaction_events.as_ref() {
+            events.defer_domain_event(event);
+            return;
+        }
+        let envelope = DomainEventEnvelope::new(event);
+        if let Err(e) = self.domain_events.emit(&envelope).await {
+            warn!(error = %e, event_type = envelope.event.event_type(), "domain_event_not_emitted");
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::utils::id_version::IdVersion;
+
+    fn tournament(state: TournamentState) -> TournamentBase {
+        let mut tournament = TournamentBase::new(IdVersion::new(Uuid::new_v4(), Some(1)));
+        tournament
+            .set_name("Spring Cup")
+            .set_tournament_state(state);
+        tournament
+    }
+
+    #[test]
+    fn given_new_tournament_when_saved_then_created() {
+        let tournament = tournament(TournamentState::Draft);
+        let events = DomainEvent::tournament_saved(&tournament, None);
+        assert_eq!(events.len(), 1);
+        assert_eq!(events[0].event_type(), "tournament_created");
+        assert_eq!(events[0].tournament_id(), tournament.get_id());
+    }
+
+    #[test]
+    fn given_last_stage_when_finished_then_stage_completed_and_finished() {
+        let old = tournament(TournamentState::ActiveStage(0));
+        let mut tournament = old.clone();
+        tournament.set_tournament_state(TournamentState::Finished);
+        let types: Vec<_> = DomainEvent::tournament_saved(&tournament, Some(&old))
+            .iter()
+            .map(DomainEvent::event_type)
+            .collect();
+        assert_eq!(
+            types,
+            vec![
+                "tournament_updated",
+                "stage_completed",
+                "tournament_finished"
+            ]
+        );
+    }
+
+    #[test]
+    fn event_type_matches_serialized_type() {
+        let event = DomainEvent::EntrantsUpdated {
+            tournament_id: Uuid::new_v4(),
+            num_changed: 3,
+        };
+        let json = serde_json::to_value(&event).unwrap();
+        assert_eq!(json["type"], event.event_type());
+        assert_eq!(json["num_changed"], 3);
+        let restored: DomainEvent = serde_json::from_value(json).unwrap();
+        assert_eq!(restored, event);
+    }
+}
@@ -1,22 +1,430 @@
-// entrants of tournament
+//! entrants of tournament
 
+use crate::{
+    AuditObjectType, Core, CoreError, CoreResult, CrMsg, CrTopic, DbError, DomainEvent,
+    WebhookEventData,
+    utils::{id_version::IdVersion, normalize::*, traits::ObjectIdVersion, validation::*},
+};
+use chrono::{DateTime, Utc};
+use serde::{Deserialize, Serialize};
+use std::collections::HashMap;
 use uuid::Uuid;
 
-/// entrant of tournament; either team or individual athlete
-// ToDo: remove allow(dead_code) flag
-#[allow(dead_code)]
-#[derive(Debug, Clone)]
+/// Entrant of tournament; either team or individual athlete.
+#[derive(Clone, Debug, PartialEq, Eq, Serialize, Deserialize, Default, ObjectIdVersion)]
 pub struct Entrant {
-    /// id of entrant in tournament
-    pub id: Uuid,
+    /// id and optimistic locking version of entrant
+    id_version: IdVersion,
     /// id of tournament
     tournament_id: Uuid,
-    /// optional global id of entrant, if entrant stats are kept in database
-    global_id: Option<Uuid>,
-    /// name of entrant
+    /// name of entrant, unique per tournament
     name: String,
-    /// members of entrant
-    members: Vec<Member>,
+    /// optional club of entrant
+    club: Option<String>,
+    /// optional seed of entrant; 1 is the strongest entrant
+    seed: Option<u32>,
+    /// optional contact email for notifications, e.g. registration confirmation
+    email: Option<String>,
+    /// time of check-in of entrant on tournament day; `None`, if not checked in
+    #[serde(default)]
+    checked_in_at: Option<DateTime<Utc>>,
+    /// position on the waitlist starting at 1; `None`, if the entrant is in the field of the
+    /// tournament. Waitlisted entrants move up, if entrants of the field do not check in.
+    #[serde(default)]
+    waitlist_position: Option<u32>,
+    /// entrant did not check in by the deadline and was removed from the field
+    #[serde(default)]
+    no_show: bool,
+}
+
+impl Entrant {
+    /// Create a new `Entrant` with the given `IdVersion`.
+    pub fn new(id_version: IdVersion) -> Self {
+        Entrant {
+            id_version,
+            ..Default::default()
+        }
+    }
+
+    /// Get the unique identifier of the entrant.
+    pub fn get_id(&self) -> Uuid {
+        self.id_version.get_id()
+    }
+
+    /// Get the version number of the entrant.
+    pub fn get_version(&self) -> Option<u32> {
+        self.id_version.get_version()
+    }
+
+    /// Get the tournament ID.
+    pub fn get_tournament_id(&self) -> Uuid {
+        self.tournament_id
+    }
+
+    /// Get the name of the entrant.
+    pub fn get_name(&self) -> &str {
+        &self.name
+    }
+
+    /// Get the club of the entrant.
+    pub fn get_club(&self) -> Option<&str> {
+        self.club.as_deref()
+    }
+
+    /// Get the seed of the entrant.
+    pub fn get_seed(&self) -> Option<u32> {
+        self.seed
+    }
+
+    /// Get the contact email of the entrant.
+    pub fn get_email(&self) -> Option<&str> {
+        self.email.as_deref()
+    }
+
+    /// Get the time of check-in of the entrant.
+    pub fn get_checked_in_at(&self) -> Option<DateTime<Utc>> {
+        self.checked_in_at
+    }
+
+    /// Check if the entrant is checked in.
+    pub fn is_checked_in(&self) -> bool {
+        self.checked_in_at.is_some()
+    }
+
+    /// Get the position of the entrant on the waitlist.
+    pub fn get_waitlist_position(&self) -> Option<u32> {
+        self.waitlist_position
+    }
+
+    /// Check if the entrant is on the waitlist.
+    pub fn is_waitlisted(&self) -> bool {
+        self.waitlist_position.is_some()
+    }
+
+    /// Check if the entrant did not check in by the deadline.
+    pub fn is_no_show(&self) -> bool {
+        self.no_show
+    }
+
+    /// Check if the entrant plays in the tournament, i.e. is neither waitlisted nor a no-show.
+    pub fn is_in_field(&self) -> bool {
+        !self.is_waitlisted() && !self.no_show
+    }
+
+    /// Set the `IdVersion` of the entrant.
+    pub fn set_id_version(&mut self, id_version: IdVersion) -> &mut Self {
+        self.id_version = id_version;
+        self
+    }
+
+    /// Set the tournament ID.
+    pub fn set_tournament_id(&mut self, tournament_id: Uuid) -> &mut Self {
+        self.tournament_id = tournament_id;
+        self
+    }
+
+    /// Set the name of the entrant with whitespace normalization.
+    pub fn set_name(&mut self, name: impl Into<String>) -> &mut Self {
+        self.name = normalize_ws(name);
+        self
+    }
+
+    /// Set the club of the entrant with whitespace normalization. Empty clubs are stored as `None`.
+    pub fn set_club(&mut self, club: Option<impl Into<String>>) -> &mut Self {
+        self.club = club.map(normalize_ws).filter(|c| !c.is_empty());
+        self
+    }
+
+    /// Set the seed of the entrant.
+    pub fn set_seed(&mut self, seed: Option<u32>) -> &mut Self {
+        self.seed = seed;
+        self
+    }
+
+    /// Set the contact email of the entrant. Whitespace is removed, empty emails are stored
+    /// as `None`.
+    pub fn set_email(&mut self, email: Option<impl Into<String>>) -> &mut Self {
+        self.email = email
+            .map(|e| e.into().split_whitespace().collect::<String>())
+            .filter(|e| !e.is_empty());
+        self
+    }
+
+    /// Set the time of check-in of the entrant; `None` to check the entrant out.
+    pub fn set_checked_in_at(&mut self, checked_in_at: Option<DateTime<Utc>>) -> &mut Self {
+        self.checked_in_at = checked_in_at;
+        self
+    }
+
+    /// Set the position of the entrant on the waitlist; `None` to move the entrant into the
+    /// field.
+    pub fn set_waitlist_position(&mut self, waitlist_position: Option<u32>) -> &mut Self {
+        self.waitlist_position = waitlist_position;
+        self
+    }
+
+    /// Mark the entrant as no-show.
+    pub fn set_no_show(&mut self, no_show: bool) -> &mut Self {
+        self.no_show = no_show;
+        self
+    }
+
+    /// Validate the entrant.
+    pub fn validate(&self) -> ValidationResult<()> {
+        let mut errs = ValidationErrors::new();
+        let object_id = self.get_id();
+
+        if self.tournament_id.is_nil() {
+            errs.add(
+                FieldError::builder()
+                    .set_field(String::from("tournament_id"))
+                    .add_required()
+                    .set_object_id(object_id)
+                    .build(),
+            );
+        }
+        if self.name.is_empty() {
+            errs.add(
+                FieldError::builder()
+                    .set_field(String::from("name"))
+                    .add_required()
+                    .set_object_id(object_id)
+                    .build(),
+            );
+        }
+        if self.seed == Some(0) {
+            errs.add(
+                FieldError::builder()
+                    .set_field(String::from("seed"))
+                    .add_user_defined_code("out_of_range")
+                    .add_message("Seed must be at least 1")
+                    .set_object_id(object_id)
+                    .build(),
+            );
+        }
+        if self.waitlist_position == Some(0) {
+            errs.add(
+                FieldError::builder()
+                    .set_field(String::from("waitlist_position"))
+                    .add_user_defined_code("out_of_range")
+                    .add_message("Waitlist position must be at least 1")
+                    .set_object_id(object_id)
+                    .build(),
+            );
+        }
+        if let Some(email) = self.email.as_deref()
+            && !is_plausible_email(email)
+        {
+            errs.add(
+                FieldError::builder()
+                    .set_field(String::from("email"))
+                    .add_invalid_format()
+                    .add_message(format!("Email '{email}' is not valid"))
+                    .set_object_id(object_id)
+                    .build(),
+            );
+        }
+
+        if errs.is_empty() { Ok(()) } else { Err(errs) }
+    }
+}
+
+/// Parse entrants of tournament `tournament_id` from CSV with columns `name, club, seed, email`.
+///
+/// `club`, `seed` and `email` are optional. A header row starting with `name` is skipped. Fields
+/// are separated by `,` or, if the first row contains more `;` than `,` (e.g. spreadsheet
+/// exports with German locale), by `;`. Fields may be quoted with `"`; quoted fields must
+/// not span multiple lines.
+///
+/// All rows are checked; every error carries the line number of the CSV as param `row`.
+pub fn parse_entrants_csv(tournament_id: Uuid, csv: &str) -> ValidationResult<Vec<Entrant>> {
+    let rows = parse_entrant_rows(tournament_id, csv)?;
+    Ok(rows.into_iter().map(|(_, e)| e).collect())
+}
+
+/// parse CSV to entrants with their line number
+fn parse_entrant_rows(tournament_id: Uuid, csv: &str) -> ValidationResult<Vec<(usize, Entrant)>> {
+    let mut errs = ValidationErrors::new();
+    let mut entrants: Vec<(usize, Entrant)> = Vec::new();
+
+    let mut lines = csv
+        .lines()
+        .enumerate()
+        .map(|(index, line)| (index + 1, line))
+        .filter(|(_, line)| !line.trim().is_empty())
+        .peekable();
+    let delimiter = match lines.peek() {
+        Some((_, first)) if first.matches(';').count() > first.matches(',').count() => ';',
+        _ => ',',
+    };
+
+    for (index, (row, line)) in lines.enumerate() {
+        let fields = split_csv_line(line, delimiter);
+        if index == 0 && fields[0].trim().eq_ignore_ascii_case("name") {
+            // header row
+            continue;
+        }
+        let mut entrant = Entrant::new(IdVersion::NewWithId(Uuid::new_v4()));
+        entrant
+            .set_tournament_id(tournament_id)
+            .set_name(fields[0].as_str())
+            .set_club(fields.get(1))
+            .set_email(fields.get(3));
+
+        if fields.len() > 4 {
+            errs.add(row_error(
+                row,
+                FieldError::builder()
+                    .set_field(String::from("row"))
+                    .add_invalid_format()
+                    .add_message(format!(
+                        "expected at most 4 columns, found {}",
+                        fields.len()
+                    ))
+                    .set_object_id(entrant.get_id())
+                    .build(),
+            ));
+        }
+        match fields.get(2).map(|s| s.trim()).filter(|s| !s.is_empty()) {
+            None => {}
+            Some(seed) => match seed.parse::<u32>() {
+                Ok(seed) => {
+                    entrant.set_seed(Some(seed));
+                }
+                Err(_) => errs.add(row_error(
+                    row,
+                    FieldError::builder()
+                        .set_field(String::from("seed"))
+                        .add_invalid_format()
+                        .add_message(format!("Seed '{seed}' is not a positive number"))
+                        .set_object_id(entrant.get_id())
+                        .build(),
+                )),
+            },
+        }
+        if let Err(validation) = entrant.validate() {
+            for err in validation.errors {
+                errs.add(row_error(row, err));
+            }
+        }
+        entrants.push((row, entrant));
+    }
+
+    let rows: Vec<(usize, &Entrant)> = entrants.iter().map(|(row, e)| (*row, e)).collect();
+    errs.append(check_duplicates(&rows, &[]));
+
+    if errs.is_empty() {
+        Ok(entrants)
+    } else {
+        Err(errs)
+    }
+}
+
+/// Minimal check of email syntax: one `@` with a non-empty local part and a domain with a
+/// dot. Deliverability is only known when sending.
+fn is_plausible_email(email: &str) -> bool {
+    match email.split_once('@') {
+        Some((local, domain)) => {
+            !local.is_empty()
+                && !domain.contains('@')
+                && domain
+                    .split_once('.')
+                    .is_some_and(|(host, tld)| !host.is_empty() && !tld.is_empty())
+        }
+        None => false,
+    }
+}
+
+/// Check `rows` for names and seeds, which are used twice in `rows` or are already used by
+/// `existing` entrants. Names are compared case-insensitively, as in the database.
+fn check_duplicates(rows: &[(usize, &Entrant)], existing: &[Entrant]) -> ValidationErrors {
+    let mut errs = ValidationErrors::new();
+    let mut names: HashMap<String, Option<usize>> = existing
+        .iter()
+        .map(|e| (e.get_name().to_lowercase(), None))
+        .collect();
+    let mut seeds: HashMap<u32, Option<usize>> = existing
+        .iter()
+        .filter_map(|e| e.get_seed().map(|s| (s, None)))
+        .collect();
+
+    for (row, entrant) in rows {
+        if !entrant.get_name().is_empty()
+            && let Some(first) = names.insert(entrant.get_name().to_lowercase(), Some(*row))
+        {
+            let message = match first {
+                Some(first) => format!(
+                    "Name '{}' is already used in row {first}",
+                    entrant.get_name()
+                ),
+                None => format!("Entrant '{}' already exists", entrant.get_name()),
+            };
+            errs.add(row_error(
+                *row,
+                FieldError::builder()
+                    .set_field(String::from("name"))
+                    .add_user_defined_code("duplicate")
+                    .add_message(message)
+                    .set_object_id(entrant.get_id())
+                    .build(),
+            ));
+        }
+        if let Some(seed) = entrant.get_seed()
+            && let Some(first) = seeds.insert(seed, Some(*row))
+        {
+            let message = match first {
+                Some(first) => format!("Seed {seed} is already used in row {first}"),
+                None => format!("Seed {seed} is already assigned"),
+            };
+            errs.add(row_error(
+                *row,
+                FieldError::builder()
+                    .set_field(String::from("seed"))
+                    .add_user_defined_code("duplicate")
+                    .add_message(message)
+                    .set_object_id(entrant.get_id())
+                    .build(),
+            ));
+        }
+    }
+    errs
+}
+
+/// prefix message of `err` with CSV line number and add it as param `row`
+fn row_error(row: usize, err: FieldError) -> FieldError {
+    let message = if err.get_message().is_empty() {
+        format!("Row {row}: {}: {}", err.get_field(), err.get_code())
+    } else {
+        format!("Row {row}: {}", err.get_message())
+    };
+    FieldError::builder()
+        .set_field(err.get_field())
+        .add_user_defined_code(err.get_code())
+        .add_message(message)
+        .add_params(String::from("row"), row.to_string())
+        .set_object_id(err.get_object_id())
+        .build()
+}
+
+/// split one CSV line into fields; quotes are removed and `""` inside quotes is unescaped
+fn split_csv_line(line: &str, delimiter: char) -> Vec<String> {
+    let mut fields = Vec::new();
+    let mut field = String::new();
+    let mut in_quotes = false;
+    let mut chars = line.chars().peekable();
+    while let Some(c) = chars.next() {
+        match c {
+            '"' if in_quotes && chars.peek() == Some(&'"') => {
+                field.push('"');
+                chars.next();
+            }
+            '"' => in_quotes = !in_quotes,
+            c if c == delimiter && !in_quotes => fields.push(std::mem::take(&mut field)),
+            c => field.push(c),
+        }
+    }
+    fields.push(field);
+    fields
 }
 
 // ToDo: move this into generic people mod?
@@ -32,3 +440,271 @@ pub struct Member {
     /// name of member
     name: String,
 }
+
+/// State for entrant operations of one tournament
+pub struct EntrantState {
+    tournament_id: Uuid,
+    entrant: Entrant,
+}
+
+// switch state to entrant state
+impl<S> Core<S> {
+    pub fn as_entrant_state(&self, tournament_id: Uuid) -> Core<EntrantState> {
+        let mut entrant = Entrant::default();
+        entrant.set_tournament_id(tournament_id);
+        self.switch_state(EntrantState {
+            tournament_id,
+            entrant,
+        })
+    }
+}
+
+impl Core<EntrantState> {
+    pub fn get(&self) -> &Entrant {
+        &self.state.entrant
+    }
+    pub fn get_mut(&mut self) -> &mut Entrant {
+        &mut self.state.entrant
+    }
+    pub async fn load(&mut self, id: Uuid) -> CoreResult<Option<&Entrant>> {
+        if let Some(entrant) = self.database.get_entrant(id).await? {
+            self.state.entrant = entrant;
+            Ok(Some(self.get()))
+        } else {
+            Ok(None)
+        }
+    }
+    pub async fn save(&mut self) -> CoreResult<&Entrant> {
+        self.state.entrant.validate()?;
+        let is_new = self.state.entrant.get_version().is_none();
+        // stored copy before the save for the audit log
+        let old = if is_new {
+            None
+        } else {
+            self.database
+                .get_entrant(self.state.entrant.get_id())
+                .await?
+        };
+        self.state.entrant = self.database.save_entrant(&self.state.entrant).await?;
+        self.publish_entrant_update(&self.state.entrant).await?;
+        self.audit_save(
+            AuditObjectType::Entrant,
+            Some(self.state.tournament_id),
+            old.as_ref(),
+            &self.state.entrant,
+        )
+        .await;
+        // sandbox tournaments must not leak to integrations or entrants
+        if self.is_sandbox_tournament().await? {
+            return Ok(self.get());
+        }
+        self.dispatch_webhook_event(WebhookEventData::EntrantsUpdated {
+            tournament_id: self.state.tournament_id,
+            num_changed: 1,
+        })
+        .await;
+        self.emit_domain_event(DomainEvent::EntrantsUpdated {
+            tournament_id: self.state.tournament_id,
+            num_changed: 1,
+        })
+        .await;
+        if is_new {
+            self.notify_registration_confirmed(&self.state.entrant)
+                .await;
+        }
+        Ok(self.get())
+    }
+    /// Check entrant `id` in or out. Checking in an entrant, who is already checked in, keeps
+    /// the time of the first check-in.
+    pub async fn set_check_in(&mut self, id: Uuid, checked_in: bool) -> CoreResult<&Entrant> {
+        let entrant = self
+            .database
+            .get_entrant(id)
+            .await?
+            .filter(|e| e.get_tournament_id() == self.state.tournament_id)
+            .ok_or(CoreError::from(DbError::NotFound))?;
+        if entrant.is_checked_in() == checked_in {
+            self.state.entrant = entrant;
+            return Ok(self.get());
+        }
+        self.state.entrant = entrant;
+        self.state
+            .entrant
+            .set_checked_in_at(checked_in.then(Utc::now));
+        self.save().await
+    }
+    /// List entrants of tournament, sorted by seed and name. Entrants without seed are last.
+    pub async fn list_entrants(&self) -> CoreResult<Vec<Entrant>> {
+        let mut list = self
+            .database
+            .list_entrants_of_tournament(self.state.tournament_id)
+            .await?;
+        list.sort_by(|a, b| {
+            (
+                a.get_seed().is_none(),
+                a.get_seed(),
+                a.get_name().to_lowercase(),
+            )
+                .cmp(&(
+                    b.get_seed().is_none(),
+                    b.get_seed(),
+                    b.get_name().to_lowercase(),
+                ))
+        });
+        Ok(list)
+    }
+    /// Import entrants from CSV, see [`parse_entrants_csv`].
+    ///
+    /// Names and seeds must neither be used twice in the CSV nor by existing entrants of the
+    /// tournament. If any row is invalid, no entrant is saved and the errors of all rows are
+    /// returned. All entrants are saved in one transaction.
+    pub async fn import_entrants_csv(&mut self, csv: &str) -> CoreResult<Vec<Entrant>> {
+        let entrants = parse_entrant_rows(self.state.tournament_id, csv)?;
+        let existing = self
+            .database
+            .list_entrants_of_tournament(self.state.tournament_id)
+            .await?;
+        let rows: Vec<(usize, &Entrant)> = entrants.iter().map(|(row, e)| (*row, e)).collect();
+        let errs = check_duplicates(&rows, &existing);
+        if !errs.is_empty() {
+            return Err(errs.into());
+        }
+
+        let tournament_id = self.state.tournament_id;
+        self.with_transaction(|core| async move {
+            let entrant_core = core.as_entrant_state(tournament_id);
+            let mut saved = Vec::with_capacity(entrants.len());
+            for (_, entrant) in entrants {
+                let entrant = entrant_core.database.save_entrant(&entrant).await?;
+                entrant_core.publish_entrant_update(&entrant).await?;
+                entrant_core
+                    .audit_save(
+                        AuditObjectType::Entrant,
+                        Some(tournament_id),
+                        None,
+                        &entrant,
+                    )
+                    .await;
+                saved.push(entrant);
+            }
+            entrant_core.dispatch_entrants_updated(saved.len()).await?;
+            Ok(saved)
+        })
+        .await
+    }
+    /// Notify integrations of `num_changed` saved entrants, unless the tournament is a
+    /// sandbox.
+    pub(crate) async fn dispatch_entrants_updated(&self, num_changed: usize) -> CoreResult<()> {
+        // sandbox tournaments must not leak to integrations
+        if self.is_sandbox_tournament().await? {
+            return Ok(());
+        }
+        let tournament_id = self.state.tournament_id;
+        self.dispatch_webhook_event(WebhookEventData::EntrantsUpdated {
+            tournament_id,
+            num_changed,
+        })
+        .await;
+        self.emit_domain_event(DomainEvent::EntrantsUpdated {
+            tournament_id,
+            num_changed,
+        })
+        .await;
+        Ok(())
+    }
+    async fn is_sandbox_tournament(&self) -> CoreResult<bool> {
+        Ok(self
+            .database
+            .get_tournament_base(self.state.tournament_id)
+            .await?
+            .is_some_and(|t| t.is_sandbox()))
+    }
+    pub(crate) async fn publish_entrant_update(&self, entrant: &Entrant) -> CoreResult<()> {
+        // publish change of entrant to client registry
+        let id = entrant.get_id();
+        let version = entrant
+            .get_version()
+            .expect("expecting save_entrant to return always an existing id and version");
+        let notice = CrTopic::Entrants {
+            tournament_id: self.state.tournament_id,
+        };
+        let msg = CrMsg::EntrantUpdated { id, version };
+        self.client_registry.publish(notice, msg).await?;
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn rows_of(errs: &ValidationErrors) -> Vec<(&str, &str)> {
+        errs.errors
+            .iter()
+            .map(|e| (e.get_params()["row"].as_str(), e.get_field()))
+            .collect()
+    }
+
+    #[test]
+    fn given_csv_with_header_when_parse_then_entrants() {
+        let t_id = Uuid::new_v4();
+        let csv = "name,club,seed\n  Team  A ,TV Nord,1\n\n\"Smith, J.\",,\nTeam C,\"SV \"\"Süd\"\"\",3\n";
+        let entrants = parse_entrants_csv(t_id, csv).unwrap();
+        assert_eq!(entrants.len(), 3);
+        assert_eq!(entrants[0].get_name(), "Team A");
+        assert_eq!(entrants[0].get_club(), Some("TV Nord"));
+        assert_eq!(entrants[0].get_seed(), Some(1));
+        assert_eq!(entrants[1].get_name(), "Smith, J.");
+        assert_eq!(entrants[1].get_club(), None);
+        assert_eq!(entrants[1].get_seed(), None);
+        assert_eq!(entrants[2].get_club(), Some("SV \"Süd\""));
+        assert!(entrants.iter().all(|e| e.get_tournament_id() == t_id));
+    }
+
+    #[test]
+    fn given_semicolon_csv_without_header_when_parse_then_entrants() {
+        let entrants = parse_entrants_csv(Uuid::new_v4(), "Team A;Club;2\nTeam B").unwrap();
+        assert_eq!(entrants.len(), 2);
+        assert_eq!(entrants[0].get_seed(), Some(2));
+        assert_eq!(entrants[1].get_name(), "Team B");
+    }
+
+    #[test]
+    fn given_csv_with_email_when_parse_then_email_is_checked() {
+        let csv = "Team A,,, captain@example.org \nTeam B,,,no-mail\n";
+        let errs = parse_entrants_csv(Uuid::new_v4(), csv).unwrap_err();
+        assert_eq!(rows_of(&errs), vec![("2", "email")]);
+
+        let entrants = parse_entrants_csv(Uuid::new_v4(), "Team A,,,captain@example.org").unwrap();
+        assert_eq!(entrants[0].get_email(), Some("captain@example.org"));
+    }
+
+    #[test]
+    fn given_invalid_rows_when_parse_then_errors_of_all_rows() {
+        let csv = "name,club,seed\nTeam A,,1\n,Club,\nTeam C,,x\nteam a,,0\nTeam E,,1,e@example.org,extra\n";
+        let errs = parse_entrants_csv(Uuid::new_v4(), csv).unwrap_err();
+        assert_eq!(
+            rows_of(&errs),
+            vec![
+                ("3", "name"),
+                ("4", "seed"),
+                ("5", "seed"),
+                ("6", "row"),
+                ("5", "name"),
+                ("6", "seed"),
+            ]
+        );
+        assert!(errs.errors[0].get_message().starts_with("Row 3:"));
+        assert_eq!(errs.errors[4].get_code(), "duplicate");
+    }
+
+    #[test]
+    fn given_existing_entrants_when_check_duplicates_then_errors() {
+        let t_id = Uuid::new_v4();
+        let existing = parse_entrants_csv(t_id, "Team A,,1").unwrap();
+        let new = parse_entrants_csv(t_id, "TEAM A,,\nTeam B,,1\nTeam C,,2").unwrap();
+        let rows: Vec<_> = new.iter().enumerate().map(|(i, e)| (i + 1, e)).collect();
+        let errs = check_duplicates(&rows, &existing);
+        assert_eq!(rows_of(&errs), vec![("1", "name"), ("2", "seed")]);
+    }
+}
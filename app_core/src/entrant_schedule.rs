@@ -0,0 +1,280 @@
+//! schedule and results of a single entrant
+//!
+//! The schedule lists the upcoming matches of an entrant with start time, station and
+//! opponent and the results of its decided matches. Like the public view of the tournament
+//! it contains only data visible to spectators anyway, so entrants may share its link.
+
+use crate::{Core, CoreResult, Entrant, Match, MatchOutcome, ScheduledEntrant, TournamentBase};
+use chrono::{DateTime, Utc};
+use serde::{Deserialize, Serialize};
+use std::collections::HashMap;
+use uuid::Uuid;
+
+/// result of a decided match from the perspective of the entrant
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub enum EntrantMatchResult {
+    /// scores of each set of the entrant and its opponent
+    Played { own: Vec<u16>, opponent: Vec<u16> },
+    /// won by forfeit of the opponent or by bye
+    WalkoverWon,
+    /// lost by forfeit of the entrant
+    WalkoverLost,
+    /// both sides forfeited
+    DoubleForfeit,
+}
+
+/// match of the entrant
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct EntrantMatch {
+    pub match_id: Uuid,
+    pub start_at: DateTime<Utc>,
+    /// number of station, starting with 1
+    pub station: u32,
+    /// name of station; `Station <number>`, if the station is not named
+    pub station_name: String,
+    /// name of opponent; `None`, if the opponent is not decided yet
+    pub opponent: Option<String>,
+    /// result of match; `None` for upcoming matches
+    pub result: Option<EntrantMatchResult>,
+}
+
+/// schedule and results of an entrant, see [`Core::load_entrant_schedule`]
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct EntrantSchedule {
+    pub tournament_id: Uuid,
+    pub tournament_name: String,
+    /// entrant without email address
+    pub entrant: Entrant,
+    /// matches without result in order of their start
+    pub upcoming: Vec<EntrantMatch>,
+    /// decided matches, latest first
+    pub results: Vec<EntrantMatch>,
+}
+
+impl EntrantSchedule {
+    /// Build the schedule of `entrant` from `matches` of `tournament`. Matches of other
+    /// entrants are skipped; `entrants` provide the names of opponents.
+    pub fn build(
+        tournament: &TournamentBase,
+        entrant: &Entrant,
+        entrants: &[Entrant],
+        matches: &[Match],
+    ) -> Self {
+        let names: HashMap<Uuid, &str> = entrants
+            .iter()
+            .map(|e| (e.get_id(), e.get_name()))
+            .collect();
+        let entrant_id = entrant.get_id();
+        let mut upcoming = Vec::new();
+        let mut results = Vec::new();
+
+        for m in matches {
+            let (side_a, side_b) = m.get_sides();
+            let (is_side_a, opponent) = match (side_a, side_b) {
+                (ScheduledEntrant::Entrant(id), other) if *id == entrant_id => (true, other),
+                (other, ScheduledEntrant::Entrant(id)) if *id == entrant_id => (false, other),
+                _ => continue,
+            };
+            let opponent = match opponent {
+                ScheduledEntrant::Entrant(id) => names.get(id).map(|name| name.to_string()),
+                _ => None,
+            };
+            let station = m.get_station() as u32;
+            let result = m.is_decided().then(|| {
+                let (score_a, score_b) = m.get_scores();
+                match (m.get_outcome(), is_side_a) {
+                    (MatchOutcome::Played, true) => EntrantMatchResult::Played {
+                        own: score_a.clone(),
+                        opponent: score_b.clone(),
+                    },
+                    (MatchOutcome::Played, false) => EntrantMatchResult::Played {
+                        own: score_b.clone(),
+                        opponent: score_a.clone(),
+                    },
+                    (MatchOutcome::DoubleForfeit, _) => EntrantMatchResult::DoubleForfeit,
+                    // a bye is won by side a
+                    (MatchOutcome::ForfeitB | MatchOutcome::Bye, true)
+                    | (MatchOutcome::ForfeitA, false) => EntrantMatchResult::WalkoverWon,
+                    (MatchOutcome::ForfeitA, true)
+                    | (MatchOutcome::ForfeitB | MatchOutcome::Bye, false) => {
+                        EntrantMatchResult::WalkoverLost
+                    }
+                }
+            });
+            let entry = EntrantMatch {
+                match_id: *m.get_id(),
+                start_at: m.get_start_at().with_timezone(&Utc),
+                station,
+                station_name: tournament
+                    .get_station_name(station)
+                    .map(str::to_string)
+                    .unwrap_or_else(|| format!("Station {station}")),
+                opponent,
+                result,
+            };
+            if entry.result.is_some() {
+                results.push(entry);
+            } else {
+                upcoming.push(entry);
+            }
+        }
+        upcoming.sort_by_key(|e| (e.start_at, e.station));
+        results.sort_by_key(|e| std::cmp::Reverse((e.start_at, e.station)));
+
+        let mut entrant = entrant.clone();
+        entrant.set_email(None::<String>);
+        EntrantSchedule {
+            tournament_id: tournament.get_id(),
+            tournament_name: tournament.get_name().to_string(),
+            entrant,
+            upcoming,
+            results,
+        }
+    }
+
+    /// Next match of the entrant, if any.
+    pub fn next_match(&self) -> Option<&EntrantMatch> {
+        self.upcoming.first()
+    }
+}
+
+impl<S> Core<S> {
+    /// Load the schedule of the entrant with id `entrant_id`. Returns `None`, if the entrant
+    /// does not exist or if its tournament is not public, i.e. a draft or a sandbox.
+    pub async fn load_entrant_schedule(
+        &self,
+        entrant_id: Uuid,
+    ) -> CoreResult<Option<EntrantSchedule>> {
+        let Some(entrant) = self.database.get_entrant(entrant_id).await? else {
+            return Ok(None);
+        };
+        let tournament_id = entrant.get_tournament_id();
+        let Some(tournament) = self.database.get_tournament_base(tournament_id).await? else {
+            return Ok(None);
+        };
+        if !tournament.is_public() {
+            return Ok(None);
+        }
+        let entrants = self
+            .database
+            .list_entrants_of_tournament(tournament_id)
+            .await?;
+        let matches = self
+            .database
+            .list_matches_of_tournament(tournament_id)
+            .await?;
+        Ok(Some(EntrantSchedule::build(
+            &tournament,
+            &entrant,
+            &entrants,
+            &matches,
+        )))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::utils::id_version::IdVersion;
+
+    fn entrant(name: &str) -> Entrant {
+        let mut e = Entrant::new(IdVersion::new(Uuid::new_v4(), Some(0)));
+        e.set_name(name).set_email(Some("team@example.com"));
+        e
+    }
+
+    #[test]
+    fn given_matches_when_build_then_split_in_upcoming_and_results_of_entrant() {
+        let mut tournament = TournamentBase::default();
+        tournament.set_name("Cup").set_num_stations(2);
+        let (team, rival, other) = (entrant("Team"), entrant("Rival"), entrant("Other"));
+        let entrants = vec![team.clone(), rival.clone(), other.clone()];
+        let sport_id = Uuid::new_v4();
+        let played = Match::new_played(
+            Uuid::new_v4(),
+            rival.get_id(),
+            team.get_id(),
+            sport_id,
+            vec![11, 5],
+            vec![9, 11],
+        );
+        let forfeit = Match::new_walkover(
+            Uuid::new_v4(),
+            rival.get_id(),
+            team.get_id(),
+            sport_id,
+            MatchOutcome::ForfeitA,
+        );
+        let upcoming = Match::new_played(
+            Uuid::new_v4(),
+            team.get_id(),
+            other.get_id(),
+            sport_id,
+            vec![],
+            vec![],
+        );
+        let foreign = Match::new_played(
+            Uuid::new_v4(),
+            rival.get_id(),
+            other.get_id(),
+            sport_id,
+            vec![],
+            vec![],
+        );
+
+        let schedule = EntrantSchedule::build(
+            &tournament,
+            &team,
+            &entrants,
+            &[played.clone(), forfeit.clone(), upcoming.clone(), foreign],
+        );
+
+        assert_eq!(schedule.tournament_name, "Cup");
+        assert_eq!(schedule.entrant.get_email(), None);
+        assert_eq!(schedule.upcoming.len(), 1);
+        let next = schedule.next_match().unwrap();
+        assert_eq!(next.match_id, *upcoming.get_id());
+        assert_eq!(next.opponent.as_deref(), Some("Other"));
+        assert_eq!(next.station_name, "Station 0");
+        assert_eq!(next.result, None);
+        assert_eq!(schedule.results.len(), 2);
+        let played_result = schedule
+            .results
+            .iter()
+            .find(|r| r.match_id == *played.get_id())
+            .unwrap();
+        assert_eq!(
+            played_result.result,
+            Some(EntrantMatchResult::Played {
+                own: vec![9, 11],
+                opponent: vec![11, 5]
+            })
+        );
+        let forfeit_result = schedule
+            .results
+            .iter()
+            .find(|r| r.match_id == *forfeit.get_id())
+            .unwrap();
+        assert_eq!(forfeit_result.result, Some(EntrantMatchResult::WalkoverWon));
+    }
+
+    #[test]
+    fn given_undecided_opponent_when_build_then_opponent_is_none() {
+        let tournament = TournamentBase::default();
+        let team = entrant("Team");
+        let m = Match::new_played(
+            Uuid::new_v4(),
+            team.get_id(),
+            Uuid::new_v4(),
+            Uuid::new_v4(),
+            vec![],
+            vec![],
+        );
+
+        let schedule =
+            EntrantSchedule::build(&tournament, &team, std::slice::from_ref(&team), &[m]);
+
+        assert_eq!(schedule.upcoming.len(), 1);
+        assert_eq!(schedule.upcoming[0].opponent, None);
+    }
+}
@@ -1,7 +1,7 @@
 //! Definitions for error types used throughout core.
 
 use crate::{
-    CrError, DbError, SportError,
+    BlobError, CrError, DbError, ServerCopy, SportError,
     utils::validation::{FieldError, ValidationErrors},
 };
 use serde::{Deserialize, Serialize};
@@ -21,6 +21,10 @@ pub enum CoreError {
     #[error("sport error: {0}")]
     Sport(#[from] SportError),
 
+    /// blob storage error
+    #[error("blob storage error: {0}")]
+    Blob(#[from] BlobError),
+
     /// Generic validation error of one field of an entity
     /// Returns the first error only
     #[error("field validation error: {0}")]
@@ -31,6 +35,10 @@ pub enum CoreError {
     #[error("validation error: {0:?}")]
     Validation(#[from] ValidationErrors),
 
+    /// object was saved by someone else since it was loaded; holds the current server copy
+    #[error("version conflict: object was changed by someone else (server version {})", .0.get_version().unwrap_or_default())]
+    VersionConflict(Box<ServerCopy>),
+
     /// Parsing error for enums of core
     #[error("parsing error: {0}")]
     ParsingError(String),
@@ -39,8 +47,30 @@ pub enum CoreError {
 pub type CoreResult<T> = Result<T, CoreError>;
 
 impl CoreError {
+    pub fn version_conflict(server_copy: ServerCopy) -> Self {
+        CoreError::VersionConflict(Box::new(server_copy))
+    }
     pub fn is_optimistic_lock_conflict(&self) -> bool {
-        matches!(self, CoreError::Db(DbError::OptimisticLockConflict))
+        matches!(
+            self,
+            CoreError::Db(DbError::OptimisticLockConflict | DbError::VersionConflict { .. })
+                | CoreError::VersionConflict(_)
+        )
+    }
+    /// Get the current server copy of an object, whose save failed due to a version conflict.
+    pub fn get_server_copy(&self) -> Option<&ServerCopy> {
+        if let CoreError::VersionConflict(server_copy) = self {
+            Some(server_copy)
+        } else {
+            None
+        }
+    }
+    /// Result was rejected, because the match has already a final result.
+    pub fn is_match_decided(&self) -> bool {
+        matches!(self, CoreError::Sport(SportError::MatchDecided))
+    }
+    pub fn is_timeout(&self) -> bool {
+        matches!(self, CoreError::Db(DbError::Timeout))
     }
     pub fn is_unique_violation(&self) -> bool {
         matches!(self, CoreError::Db(DbError::UniqueViolation(_)))
@@ -0,0 +1,246 @@
+//! feedback of users with context of the app
+
+use crate::{
+    Core, CoreResult,
+    utils::{id_version::IdVersion, normalize::*, traits::ObjectIdVersion, validation::*},
+};
+use chrono::{DateTime, Utc};
+use serde::{Deserialize, Serialize};
+use uuid::Uuid;
+
+/// maximum length of a feedback message in characters
+pub const FEEDBACK_MESSAGE_MAX_LEN: usize = 4000;
+/// maximum number of recent client errors attached to a feedback
+pub const FEEDBACK_MAX_RECENT_ERRORS: usize = 20;
+/// maximum length of a recent client error in characters; longer errors are truncated
+pub const FEEDBACK_ERROR_MAX_LEN: usize = 500;
+
+/// Feedback or bug report of a user.
+///
+/// Besides the message, feedback captures the context of the client at the time of
+/// submission: current route, tournament, version of the app and recent client errors.
+/// Feedback is append only.
+#[derive(Clone, Debug, PartialEq, Eq, Serialize, Deserialize, Default, ObjectIdVersion)]
+pub struct Feedback {
+    /// id and optimistic locking version of feedback
+    id_version: IdVersion,
+    /// message of user
+    message: String,
+    /// route of the client, e.g. `/tournaments/edit`
+    route: String,
+    /// id of tournament, which was open in the client
+    tournament_id: Option<Uuid>,
+    /// version of the app of the client
+    app_version: String,
+    /// recent errors of the client, oldest first
+    recent_errors: Vec<String>,
+    /// timestamp of creation; set by database
+    created_at: Option<DateTime<Utc>>,
+}
+
+impl Feedback {
+    /// Create a new `Feedback` with the given `IdVersion`.
+    pub fn new(id_version: IdVersion) -> Self {
+        Feedback {
+            id_version,
+            ..Default::default()
+        }
+    }
+
+    /// Get the unique identifier of the feedback.
+    pub fn get_id(&self) -> Uuid {
+        self.id_version.get_id()
+    }
+
+    /// Get the version number of the feedback.
+    pub fn get_version(&self) -> Option<u32> {
+        self.id_version.get_version()
+    }
+
+    /// Get the message of the user.
+    pub fn get_message(&self) -> &str {
+        &self.message
+    }
+
+    /// Get the route of the client.
+    pub fn get_route(&self) -> &str {
+        &self.route
+    }
+
+    /// Get the id of the tournament, which was open in the client.
+    pub fn get_tournament_id(&self) -> Option<Uuid> {
+        self.tournament_id
+    }
+
+    /// Get the version of the app of the client.
+    pub fn get_app_version(&self) -> &str {
+        &self.app_version
+    }
+
+    /// Get the recent errors of the client, oldest first.
+    pub fn get_recent_errors(&self) -> &[String] {
+        &self.recent_errors
+    }
+
+    /// Get the timestamp of creation, if feedback is persisted.
+    pub fn get_created_at(&self) -> Option<DateTime<Utc>> {
+        self.created_at
+    }
+
+    /// Set the `IdVersion` of the feedback.
+    pub fn set_id_version(&mut self, id_version: IdVersion) -> &mut Self {
+        self.id_version = id_version;
+        self
+    }
+
+    /// Set the message of the user.
+    ///
+    /// Line breaks are kept, only leading and trailing whitespace is removed.
+    pub fn set_message(&mut self, message: impl Into<String>) -> &mut Self {
+        self.message = message.into().trim().to_string();
+        self
+    }
+
+    /// Set the route of the client with whitespace normalization.
+    pub fn set_route(&mut self, route: impl Into<String>) -> &mut Self {
+        self.route = normalize_ws(route);
+        self
+    }
+
+    /// Set the id of the tournament, which was open in the client.
+    pub fn set_tournament_id(&mut self, tournament_id: Option<Uuid>) -> &mut Self {
+        self.tournament_id = tournament_id;
+        self
+    }
+
+    /// Set the version of the app of the client with whitespace normalization.
+    pub fn set_app_version(&mut self, app_version: impl Into<String>) -> &mut Self {
+        self.app_version = normalize_ws(app_version);
+        self
+    }
+
+    /// Set the recent errors of the client, oldest first.
+    ///
+    /// Only the newest [`FEEDBACK_MAX_RECENT_ERRORS`] errors are kept; each error is
+    /// truncated to [`FEEDBACK_ERROR_MAX_LEN`] characters.
+    pub fn set_recent_errors(&mut self, recent_errors: Vec<String>) -> &mut Self {
+        let skip = recent_errors
+            .len()
+            .saturating_sub(FEEDBACK_MAX_RECENT_ERRORS);
+        self.recent_errors = recent_errors
+            .into_iter()
+            .skip(skip)
+            .map(|e| e.trim().chars().take(FEEDBACK_ERROR_MAX_LEN).collect())
+            .collect();
+        self
+    }
+
+    /// Set the timestamp of creation. Only used by database adapters.
+    pub fn set_created_at(&mut self, created_at: Option<DateTime<Utc>>) -> &mut Self {
+        self.created_at = created_at;
+        self
+    }
+
+    /// Validate the feedback.
+    pub fn validate(&self) -> ValidationResult<()> {
+        let mut errs = ValidationErrors::new();
+        let object_id = self.get_id();
+
+        if self.message.is_empty() {
+            errs.add(
+                FieldError::builder()
+                    .set_field(String::from("message"))
+                    .add_required()
+                    .set_object_id(object_id)
+                    .build(),
+            );
+        } else if self.message.chars().count() > FEEDBACK_MESSAGE_MAX_LEN {
+            errs.add(
+                FieldError::builder()
+                    .set_field(String::from("message"))
+                    .add_user_defined_code("too_long")
+                    .add_message(format!(
+                        "Feedback must not exceed {FEEDBACK_MESSAGE_MAX_LEN} characters"
+                    ))
+                    .set_object_id(object_id)
+                    .build(),
+            );
+        }
+        if self.route.is_empty() {
+            errs.add(
+                FieldError::builder()
+                    .set_field(String::from("route"))
+                    .add_required()
+                    .set_object_id(object_id)
+                    .build(),
+            );
+        }
+
+        if errs.is_empty() { Ok(()) } else { Err(errs) }
+    }
+}
+
+/// State for submitting and listing feedback
+pub struct FeedbackState {
+    feedback: Feedback,
+}
+
+// switch state to feedback state
+impl<S> Core<S> {
+    pub fn as_feedback_state(&self) -> Core<FeedbackState> {
+        self.switch_state(FeedbackState {
+            feedback: Feedback::default(),
+        })
+    }
+}
+
+impl Core<FeedbackState> {
+    pub fn get(&self) -> &Feedback {
+        &self.state.feedback
+    }
+    pub fn get_mut(&mut self) -> &mut Feedback {
+        &mut self.state.feedback
+    }
+    /// Save new feedback. Feedback is append only.
+    pub async fn save(&mut self) -> CoreResult<&Feedback> {
+        self.state.feedback.validate()?;
+        self.state.feedback = self.database.save_feedback(&self.state.feedback).await?;
+        Ok(self.get())
+    }
+    /// List feedback, newest first.
+    pub async fn list_feedback(&self, limit: Option<usize>) -> CoreResult<Vec<Feedback>> {
+        let mut list = self.database.list_feedback(limit).await?;
+        list.sort_by_key(|f| std::cmp::Reverse(f.get_created_at()));
+        Ok(list)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn given_empty_fields_when_validate_then_all_errors_are_collected() {
+        let f = Feedback::default();
+        let errs = f.validate().unwrap_err();
+        let fields: Vec<_> = errs.errors.iter().map(|e| e.get_field()).collect();
+        assert_eq!(fields, vec!["message", "route"]);
+    }
+
+    #[test]
+    fn given_many_recent_errors_when_set_then_newest_are_kept_and_truncated() {
+        let mut f = Feedback::default();
+        let errors: Vec<String> = (0..FEEDBACK_MAX_RECENT_ERRORS + 5)
+            .map(|n| format!("error {n}"))
+            .collect();
+        f.set_recent_errors(errors);
+        assert_eq!(f.get_recent_errors().len(), FEEDBACK_MAX_RECENT_ERRORS);
+        assert_eq!(f.get_recent_errors()[0], "error 5");
+
+        f.set_recent_errors(vec!["x".repeat(FEEDBACK_ERROR_MAX_LEN + 10)]);
+        assert_eq!(
+            f.get_recent_errors()[0].chars().count(),
+            FEEDBACK_ERROR_MAX_LEN
+        );
+    }
+}
@@ -10,7 +10,7 @@ use uuid::Uuid;
 /// group of a stage
 // ToDo: remove allow(dead_code) flag
 #[allow(dead_code)]
-#[derive(Debug, Clone, PartialEq, Eq, Deserialize, Serialize)]
+#[derive(Debug, Clone, PartialEq, Eq, Deserialize, Serialize, ObjectIdVersion, ObjectNumber)]
 pub struct Group {
     /// id of group in tournament
     id_version: IdVersion,
@@ -48,22 +48,50 @@ pub struct Group {
     rounds: Vec<Uuid>,
 }
 
-impl ObjectIdVersion for Group {
-    fn get_id_version(&self) -> IdVersion {
-        self.id_version
+impl Group {
+    pub fn new(
+        id_version: IdVersion,
+        stage_id: Uuid,
+        number: u32,
+        mode: Mode,
+        scoring_policy: Uuid,
+        timing: Uuid,
+    ) -> Self {
+        Group {
+            id_version,
+            stage_id,
+            number,
+            mode,
+            scoring_policy,
+            timing,
+            scheduled_entrants: Vec::new(),
+            entrant_scores: Vec::new(),
+            rounds: Vec::new(),
+        }
     }
-}
-
-impl ObjectNumber for Group {
-    fn get_object_number(&self) -> u32 {
-        self.number
+    pub fn get_id(&self) -> Uuid {
+        self.id_version.get_id()
+    }
+    pub fn get_version(&self) -> Option<u32> {
+        self.id_version.get_version()
+    }
+    pub fn get_stage_id(&self) -> Uuid {
+        self.stage_id
     }
-}
-
-impl Group {
     pub fn get_number(&self) -> u32 {
         self.number
     }
+    pub fn get_mode(&self) -> &Mode {
+        &self.mode
+    }
+    pub fn set_id_version(&mut self, id_version: IdVersion) -> &mut Self {
+        self.id_version = id_version;
+        self
+    }
+    pub fn set_number(&mut self, number: u32) -> &mut Self {
+        self.number = number;
+        self
+    }
 }
 
 #[derive(Debug, Clone, PartialEq, Eq, Deserialize, Serialize)]
@@ -84,8 +112,14 @@ pub enum ScheduledEntrant {
     /// Uuid of stage, usize: index of entrant in entrant list sorted by stage rank
     StageRank(Uuid, usize),
     /// rank of entrant in group after concluded stage
-    /// Uuid of stage, usize: index of entrant in entrant list of group sorted by group rank
+    /// Uuid of group, usize: index of entrant in entrant list of group sorted by group rank
     GroupRank(Uuid, usize),
+    /// winner of a previous match, e.g. in KO brackets
+    /// Uuid: id of match
+    WinnerOf(Uuid),
+    /// loser of a previous match, e.g. in matches for third place
+    /// Uuid: id of match
+    LoserOf(Uuid),
     /// In Swiss system entrants are allocated to matches during tournament depending on
     /// their achieved results and the results of their opponents.
     // ToDo: In pure Swiss system, index of ranks may be precalculated. But it may be
@@ -93,4 +127,30 @@ pub enum ScheduledEntrant {
     // best teams from meeting to early in tournament. If this option is not used,
     // we could add here precalculated index of rank.
     Swiss,
+    /// No opponent: the entrant of the other side pauses and wins by bye, e.g. in groups
+    /// with an odd number of entrants.
+    Bye,
+}
+
+impl ScheduledEntrant {
+    /// Replace the referenced entrant, stage, group or match id with `new_id(id)`, e.g. to
+    /// import a match with fresh ids.
+    pub fn map_id<F>(&self, new_id: &mut F) -> Self
+    where
+        F: FnMut(Uuid) -> Uuid,
+    {
+        match self {
+            ScheduledEntrant::Entrant(id) => ScheduledEntrant::Entrant(new_id(*id)),
+            ScheduledEntrant::StageRank(id, index) => {
+                ScheduledEntrant::StageRank(new_id(*id), *index)
+            }
+            ScheduledEntrant::GroupRank(id, index) => {
+                ScheduledEntrant::GroupRank(new_id(*id), *index)
+            }
+            ScheduledEntrant::WinnerOf(id) => ScheduledEntrant::WinnerOf(new_id(*id)),
+            ScheduledEntrant::LoserOf(id) => ScheduledEntrant::LoserOf(new_id(*id)),
+            ScheduledEntrant::Swiss => ScheduledEntrant::Swiss,
+            ScheduledEntrant::Bye => ScheduledEntrant::Bye,
+        }
+    }
 }
@@ -0,0 +1,160 @@
+//! display languages and free texts, which are entered per language
+
+use crate::CoreError;
+use serde::{Deserialize, Serialize};
+use std::{collections::BTreeMap, fmt::Display, str::FromStr};
+
+/// language, in which public pages may be displayed
+#[derive(
+    Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash, Default, Serialize, Deserialize,
+)]
+pub enum Language {
+    #[default]
+    En,
+    De,
+    Fr,
+    Es,
+}
+
+impl Language {
+    /// all supported languages
+    pub const ALL: [Language; 4] = [Language::En, Language::De, Language::Fr, Language::Es];
+
+    /// ISO 639-1 code of language, e.g. `de`
+    pub fn code(&self) -> &'static str {
+        match self {
+            Language::En => "en",
+            Language::De => "de",
+            Language::Fr => "fr",
+            Language::Es => "es",
+        }
+    }
+}
+
+/// name of language in the language itself, e.g. `Deutsch`
+impl Display for Language {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Language::En => write!(f, "English"),
+            Language::De => write!(f, "Deutsch"),
+            Language::Fr => write!(f, "Français"),
+            Language::Es => write!(f, "Español"),
+        }
+    }
+}
+
+impl FromStr for Language {
+    type Err = CoreError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        Language::ALL
+            .into_iter()
+            .find(|l| l.code().eq_ignore_ascii_case(s))
+            .ok_or_else(|| CoreError::ParsingError(format!("Unknown language: {s}")))
+    }
+}
+
+/// Free text, which may optionally be translated into further languages.
+///
+/// Languages without translation fall back to the text.
+#[derive(Debug, Clone, Default, PartialEq, Eq, Serialize, Deserialize)]
+pub struct LocalizedText {
+    /// text in the default language
+    text: String,
+    /// translations of text
+    #[serde(default)]
+    translations: BTreeMap<Language, String>,
+}
+
+impl LocalizedText {
+    /// Create a new `LocalizedText` without translations.
+    pub fn new(text: impl Into<String>) -> Self {
+        LocalizedText {
+            text: text.into(),
+            translations: BTreeMap::new(),
+        }
+    }
+
+    /// Get the text in the default language.
+    pub fn get_text(&self) -> &str {
+        &self.text
+    }
+
+    /// Get the translation into `language`, if any.
+    pub fn get_translation(&self, language: Language) -> Option<&str> {
+        self.translations.get(&language).map(String::as_str)
+    }
+
+    /// Get the text in `language`; falls back to the text in the default language.
+    pub fn get(&self, language: Language) -> &str {
+        self.get_translation(language).unwrap_or(&self.text)
+    }
+
+    /// Check if neither text nor translations are given.
+    pub fn is_empty(&self) -> bool {
+        self.text.is_empty() && self.translations.is_empty()
+    }
+
+    /// Set the text in the default language.
+    pub fn set_text(&mut self, text: impl Into<String>) -> &mut Self {
+        self.text = text.into().trim().to_string();
+        self
+    }
+
+    /// Set the translation into `language`. An empty translation removes the translation.
+    pub fn set_translation(&mut self, language: Language, text: impl Into<String>) -> &mut Self {
+        let text = text.into().trim().to_string();
+        if text.is_empty() {
+            self.translations.remove(&language);
+        } else {
+            self.translations.insert(language, text);
+        }
+        self
+    }
+
+    /// Remove translations into languages, which are not in `languages`.
+    pub fn retain_languages(&mut self, languages: &[Language]) -> &mut Self {
+        self.translations.retain(|l, _| languages.contains(l));
+        self
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn languages_are_parsed_from_code() {
+        assert_eq!("de".parse::<Language>().unwrap(), Language::De);
+        assert_eq!("FR".parse::<Language>().unwrap(), Language::Fr);
+        assert!("xx".parse::<Language>().is_err());
+    }
+
+    #[test]
+    fn missing_translation_falls_back_to_text() {
+        let mut description = LocalizedText::new("Welcome");
+        description
+            .set_translation(Language::De, " Willkommen ")
+            .set_translation(Language::Fr, "");
+
+        assert_eq!(description.get(Language::De), "Willkommen");
+        assert_eq!(description.get(Language::Fr), "Welcome");
+        assert_eq!(description.get(Language::En), "Welcome");
+
+        description.set_translation(Language::De, "");
+        assert_eq!(description.get_translation(Language::De), None);
+    }
+
+    #[test]
+    fn serialized_translations_are_keyed_by_language() {
+        let mut description = LocalizedText::new("Welcome");
+        description.set_translation(Language::De, "Willkommen");
+
+        let json = serde_json::to_value(&description).unwrap();
+        assert_eq!(json["translations"]["De"], "Willkommen");
+        assert_eq!(
+            serde_json::from_value::<LocalizedText>(json).unwrap(),
+            description
+        );
+    }
+}
@@ -1,31 +1,89 @@
 // contains core functionality
 
+// derive macros of app_core_derive refer to `::app_core`, also within this crate
+extern crate self as app_core;
+
+mod api_token;
+mod audit;
+mod cached_database;
+mod check_in;
+mod client_error;
+mod conflict;
+mod court_call;
+mod domain_event;
 mod entrant;
+mod entrant_schedule;
 mod errors;
+mod feedback;
 mod group;
+mod language;
+mod live_score;
 mod match_;
+mod match_dependency;
+mod match_note;
+mod match_result;
+mod notification;
+mod official;
+mod pairing;
 mod ports;
 mod postal_address;
+mod presence;
 mod round;
+mod score_correction;
+mod score_sheet;
+mod scorekeeper;
 mod scoring;
+mod search;
+mod shift_log;
 mod sport_config;
+mod sport_config_schema;
 mod sport_plugin;
 mod timing;
 mod tournament;
+mod transaction;
 pub mod utils;
+mod venue;
+mod webhook;
 
+pub use api_token::*;
+pub use audit::*;
+pub use cached_database::*;
+pub use check_in::*;
+pub use client_error::*;
+pub use conflict::*;
+pub use court_call::*;
+pub use domain_event::*;
 pub use entrant::*;
+pub use entrant_schedule::*;
 pub use errors::*;
+pub use feedback::*;
 pub use group::*;
+pub use language::*;
+pub use live_score::*;
 pub use match_::*;
+pub use match_dependency::*;
+pub use match_note::*;
+pub use match_result::*;
+pub use notification::*;
+pub use official::*;
+pub use pairing::*;
 pub use ports::*;
 pub use postal_address::*;
+pub use presence::*;
 pub use round::*;
+pub use score_correction::*;
+pub use score_sheet::*;
+pub use scorekeeper::*;
 pub use scoring::*;
+pub use search::*;
+pub use shift_log::*;
 pub use sport_config::*;
+pub use sport_config_schema::*;
 pub use sport_plugin::*;
 pub use timing::*;
 pub use tournament::*;
+pub use venue::*;
+pub use webhook::*;
 
 use std::sync::Arc;
 
@@ -37,7 +95,7 @@ use std::sync::Arc;
 /// - Administration API
 ///
 /// Core holds connections to all required ports (e.g. data base, sending email,
-///   connectors to sport specific ranking systems).
+///   connectors to sport specific ranking systems, which are provided by sport plugins).
 ///
 /// Core does provide on client context:
 /// - input validators
@@ -46,17 +104,49 @@ pub struct Core<S> {
     pub database: Arc<dyn DatabasePort>,
     pub client_registry: Arc<dyn ClientRegistryPort>,
     pub sport_plugins: Arc<dyn SportPluginManagerPort>,
+    pub webhooks: Arc<dyn WebhookTransportPort>,
+    pub email: Arc<dyn EmailPort>,
+    pub blobs: Arc<dyn BlobStoragePort>,
+    pub domain_events: Arc<dyn DomainEventPort>,
+    /// optional geocoding of postal addresses; without it addresses have no location
+    pub geocoding: Option<Arc<dyn GeocodingPort>>,
+    /// optional background delivery of webhooks; without it webhooks are delivered by the
+    /// operation, which triggered the event
+    pub webhook_queue: Option<Arc<dyn WebhookQueuePort>>,
+    /// actor of all changes made with this core, see [`AuditRecord`]
+    actor: String,
+    /// events of the transaction, this core is part of, see [`Core::with_transaction`]
+    transaction_events: Option<Arc<transaction::TransactionEvents>>,
 }
 
 impl<S> Core<S> {
     fn switch_state<N>(&self, new_state: N) -> Core<N> {
         Core {
             state: new_state,
+            actor: self.actor.clone(),
             database: self.database.clone(),
             client_registry: self.client_registry.clone(),
             sport_plugins: self.sport_plugins.clone(),
+            webhooks: self.webhooks.clone(),
+            email: self.email.clone(),
+            blobs: self.blobs.clone(),
+            domain_events: self.domain_events.clone(),
+            geocoding: self.geocoding.clone(),
+            webhook_queue: self.webhook_queue.clone(),
+            transaction_events: self.transaction_events.clone(),
         }
     }
+
+    /// Set the actor of all changes made with this core, e.g. the name of an api token.
+    pub fn with_actor(mut self, actor: impl Into<String>) -> Self {
+        self.actor = actor.into();
+        self
+    }
+
+    /// Get the actor of all changes made with this core.
+    pub fn get_actor(&self) -> &str {
+        &self.actor
+    }
 }
 
 // ToDo: we probably need some kind of configuration to provide init values for port creation. Or we do everything via .env.
@@ -66,72 +156,233 @@ pub type CoreState = Arc<Core<InitState>>;
 pub struct NoDB {}
 pub struct NoCR {}
 pub struct NoSPM {}
+pub struct NoWH {}
+pub struct NoEM {}
+pub struct NoBS {}
+pub struct NoEV {}
 
-pub struct DynDB(Arc<dyn DatabasePort>);
+/// database port and its cache, if the database port is cached
+pub struct DynDB(Arc<dyn DatabasePort>, Option<Arc<CachedDatabasePort>>);
 pub struct DynCR(Arc<dyn ClientRegistryPort>);
 pub struct DynSPM(Arc<dyn SportPluginManagerPort>);
+pub struct DynWH(Arc<dyn WebhookTransportPort>);
+pub struct DynEM(Arc<dyn EmailPort>);
+pub struct DynBS(Arc<dyn BlobStoragePort>);
+pub struct DynEV(Arc<dyn DomainEventPort>);
 
-pub struct CoreBuilder<DB, CR, SPM> {
+pub struct CoreBuilder<DB, CR, SPM, WH, EM, BS, EV> {
     state_db: DB,
     state_cr: CR,
     state_spm: SPM,
+    state_wh: WH,
+    state_em: EM,
+    state_bs: BS,
+    state_ev: EV,
+    geocoding: Option<Arc<dyn GeocodingPort>>,
+    webhook_queue: Option<Arc<dyn WebhookQueuePort>>,
 }
 
-impl CoreBuilder<NoDB, NoCR, NoSPM> {
+impl CoreBuilder<NoDB, NoCR, NoSPM, NoWH, NoEM, NoBS, NoEV> {
     pub fn new() -> Self {
         CoreBuilder {
             state_db: NoDB {},
             state_cr: NoCR {},
             state_spm: NoSPM {},
+            state_wh: NoWH {},
+            state_em: NoEM {},
+            state_bs: NoBS {},
+            state_ev: NoEV {},
+            geocoding: None,
+            webhook_queue: None,
         }
     }
 }
 
-impl Default for CoreBuilder<NoDB, NoCR, NoSPM> {
+impl Default for CoreBuilder<NoDB, NoCR, NoSPM, NoWH, NoEM, NoBS, NoEV> {
     fn default() -> Self {
         Self::new()
     }
 }
 
-impl<DB, CR, SPM> CoreBuilder<DB, CR, SPM> {
-    pub fn set_db(self, database: Arc<dyn DatabasePort>) -> CoreBuilder<DynDB, CR, SPM> {
+impl<DB, CR, SPM, WH, EM, BS, EV> CoreBuilder<DB, CR, SPM, WH, EM, BS, EV> {
+    pub fn set_db(
+        self,
+        database: Arc<dyn DatabasePort>,
+    ) -> CoreBuilder<DynDB, CR, SPM, WH, EM, BS, EV> {
+        CoreBuilder {
+            state_db: DynDB(database, None),
+            state_cr: self.state_cr,
+            state_spm: self.state_spm,
+            state_wh: self.state_wh,
+            state_em: self.state_em,
+            state_bs: self.state_bs,
+            state_ev: self.state_ev,
+            geocoding: self.geocoding,
+            webhook_queue: self.webhook_queue,
+        }
+    }
+
+    /// Set `database` wrapped in a [`CachedDatabasePort`] with `config`. Messages published
+    /// to the client registry invalidate the cache.
+    pub fn set_cached_db(
+        self,
+        database: Arc<dyn DatabasePort>,
+        config: CacheConfig,
+    ) -> CoreBuilder<DynDB, CR, SPM, WH, EM, BS, EV> {
+        let cache = Arc::new(CachedDatabasePort::new(database, config));
         CoreBuilder {
-            state_db: DynDB(database),
+            state_db: DynDB(cache.clone(), Some(cache)),
             state_cr: self.state_cr,
             state_spm: self.state_spm,
+            state_wh: self.state_wh,
+            state_em: self.state_em,
+            state_bs: self.state_bs,
+            state_ev: self.state_ev,
+            geocoding: self.geocoding,
+            webhook_queue: self.webhook_queue,
         }
     }
 
     pub fn set_cr(
         self,
         client_registry: Arc<dyn ClientRegistryPort>,
-    ) -> CoreBuilder<DB, DynCR, SPM> {
+    ) -> CoreBuilder<DB, DynCR, SPM, WH, EM, BS, EV> {
         CoreBuilder {
             state_db: self.state_db,
             state_cr: DynCR(client_registry),
             state_spm: self.state_spm,
+            state_wh: self.state_wh,
+            state_em: self.state_em,
+            state_bs: self.state_bs,
+            state_ev: self.state_ev,
+            geocoding: self.geocoding,
+            webhook_queue: self.webhook_queue,
         }
     }
 
     pub fn set_spm(
         self,
         sport_plugin_manager: Arc<dyn SportPluginManagerPort>,
-    ) -> CoreBuilder<DB, CR, DynSPM> {
+    ) -> CoreBuilder<DB, CR, DynSPM, WH, EM, BS, EV> {
         CoreBuilder {
             state_db: self.state_db,
             state_cr: self.state_cr,
             state_spm: DynSPM(sport_plugin_manager),
+            state_wh: self.state_wh,
+            state_em: self.state_em,
+            state_bs: self.state_bs,
+            state_ev: self.state_ev,
+            geocoding: self.geocoding,
+            webhook_queue: self.webhook_queue,
         }
     }
+
+    pub fn set_wh(
+        self,
+        webhooks: Arc<dyn WebhookTransportPort>,
+    ) -> CoreBuilder<DB, CR, SPM, DynWH, EM, BS, EV> {
+        CoreBuilder {
+            state_db: self.state_db,
+            state_cr: self.state_cr,
+            state_spm: self.state_spm,
+            state_wh: DynWH(webhooks),
+            state_em: self.state_em,
+            state_bs: self.state_bs,
+            state_ev: self.state_ev,
+            geocoding: self.geocoding,
+            webhook_queue: self.webhook_queue,
+        }
+    }
+
+    pub fn set_em(self, email: Arc<dyn EmailPort>) -> CoreBuilder<DB, CR, SPM, WH, DynEM, BS, EV> {
+        CoreBuilder {
+            state_db: self.state_db,
+            state_cr: self.state_cr,
+            state_spm: self.state_spm,
+            state_wh: self.state_wh,
+            state_em: DynEM(email),
+            state_bs: self.state_bs,
+            state_ev: self.state_ev,
+            geocoding: self.geocoding,
+            webhook_queue: self.webhook_queue,
+        }
+    }
+
+    pub fn set_bs(
+        self,
+        blobs: Arc<dyn BlobStoragePort>,
+    ) -> CoreBuilder<DB, CR, SPM, WH, EM, DynBS, EV> {
+        CoreBuilder {
+            state_db: self.state_db,
+            state_cr: self.state_cr,
+            state_spm: self.state_spm,
+            state_wh: self.state_wh,
+            state_em: self.state_em,
+            state_bs: DynBS(blobs),
+            state_ev: self.state_ev,
+            geocoding: self.geocoding,
+            webhook_queue: self.webhook_queue,
+        }
+    }
+
+    pub fn set_ev(
+        self,
+        domain_events: Arc<dyn DomainEventPort>,
+    ) -> CoreBuilder<DB, CR, SPM, WH, EM, BS, DynEV> {
+        CoreBuilder {
+            state_db: self.state_db,
+            state_cr: self.state_cr,
+            state_spm: self.state_spm,
+            state_wh: self.state_wh,
+            state_em: self.state_em,
+            state_bs: self.state_bs,
+            state_ev: DynEV(domain_events),
+            geocoding: self.geocoding,
+            webhook_queue: self.webhook_queue,
+        }
+    }
+
+    /// Set optional `geocoding` of postal addresses.
+    pub fn set_geo(mut self, geocoding: Arc<dyn GeocodingPort>) -> Self {
+        self.geocoding = Some(geocoding);
+        self
+    }
+
+    /// Set optional `webhook_queue` for background delivery of webhooks.
+    pub fn set_webhook_queue(mut self, webhook_queue: Arc<dyn WebhookQueuePort>) -> Self {
+        self.webhook_queue = Some(webhook_queue);
+        self
+    }
+}
+
+impl<CR, SPM, WH, EM, BS, EV> CoreBuilder<DynDB, CR, SPM, WH, EM, BS, EV> {
+    /// Get the cache of the database port, if it is cached. Client registries, which forward
+    /// messages of other server instances, must invalidate this cache, see
+    /// [`CacheInvalidatingClientRegistry`].
+    pub fn get_cache(&self) -> Option<Arc<CachedDatabasePort>> {
+        self.state_db.1.clone()
+    }
 }
 
-impl CoreBuilder<DynDB, DynCR, DynSPM> {
+impl CoreBuilder<DynDB, DynCR, DynSPM, DynWH, DynEM, DynBS, DynEV> {
     pub fn build(self) -> Core<InitState> {
+        let client_registry: Arc<dyn ClientRegistryPort> = match self.state_db.1 {
+            Some(cache) => Arc::new(CacheInvalidatingClientRegistry::new(self.state_cr.0, cache)),
+            None => self.state_cr.0,
+        };
         Core {
             state: InitState {},
             database: self.state_db.0,
-            client_registry: self.state_cr.0,
+            client_registry,
             sport_plugins: self.state_spm.0,
+            webhooks: self.state_wh.0,
+            email: self.state_em.0,
+            blobs: self.state_bs.0,
+            domain_events: self.state_ev.0,
+            geocoding: self.geocoding,
+            webhook_queue: self.webhook_queue,
+            actor: String::from(AUDIT_ACTOR_WEB),
+            transaction_events: None,
         }
     }
 }
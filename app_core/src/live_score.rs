@@ -0,0 +1,144 @@
+// running scores of matches in progress
+
+use crate::{Core, CoreResult, CrMsg, CrTopic, LiveScore, Match, SportError, SportResult};
+use chrono::Utc;
+use uuid::Uuid;
+
+/// Check if `score_a` and `score_b` may be recorded as running score of `m`. Running scores
+/// are not validated against the rules of the sport, since sets are not yet finished. The
+/// match must have concrete entrants, must not be decided and both sides must have the same
+/// number of sets.
+pub fn check_live_score(m: &Match, score_a: &[u16], score_b: &[u16]) -> SportResult<()> {
+    if m.get_entrants().is_none() {
+        return Err(SportError::InvalidScore(
+            "Both sides of the match must have concrete entrant IDs".to_string(),
+        ));
+    }
+    if m.is_decided() {
+        return Err(SportError::MatchDecided);
+    }
+    if score_a.len() != score_b.len() {
+        return Err(SportError::InvalidScore(
+            "Both sides must have the same number of sets".to_string(),
+        ));
+    }
+    Ok(())
+}
+
+// live scores are available in every core state
+impl<S> Core<S> {
+    /// Record the running score of match `m` and publish a live score tick to spectators.
+    /// The final score is validated with
+    /// [`SportPort::validate_final_score`](crate::SportPort::validate_final_score) at the end
+    /// of the match. The match is saved before the tick is published.
+    pub async fn record_live_score(
+        &self,
+        m: &mut Match,
+        score_a: Vec<u16>,
+        score_b: Vec<u16>,
+    ) -> CoreResult<LiveScore> {
+        check_live_score(m, &score_a, &score_b)?;
+        m.set_live_score(score_a, score_b, Utc::now());
+        let live_score = m
+            .get_live_score()
+            .cloned()
+            .expect("expecting live score to be set");
+        if let Some(saved) = self
+            .database
+            .save_matches(std::slice::from_ref(m))
+            .await?
+            .pop()
+        {
+            *m = saved;
+        }
+
+        let notice = CrTopic::LiveScores {
+            tournament_id: *m.get_tournament_id(),
+        };
+        let msg = CrMsg::LiveScoreUpdated {
+            id: *m.get_id(),
+            version: live_score.tick,
+        };
+        self.client_registry.publish(notice, msg).await?;
+        Ok(live_score)
+    }
+
+    /// Update the running score of the match with id `match_id`. Returns `None`, if the
+    /// match does not exist.
+    pub async fn update_live_score(
+        &self,
+        match_id: Uuid,
+        score_a: Vec<u16>,
+        score_b: Vec<u16>,
+    ) -> CoreResult<Option<LiveScore>> {
+        let Some(mut m) = self.database.get_match(match_id).await? else {
+            return Ok(None);
+        };
+        let live_score = self.record_live_score(&mut m, score_a, score_b).await?;
+        Ok(Some(live_score))
+    }
+
+    /// List running scores of all matches in progress of tournament `tournament_id` by
+    /// match id.
+    pub async fn list_live_scores(
+        &self,
+        tournament_id: Uuid,
+    ) -> CoreResult<Vec<(Uuid, LiveScore)>> {
+        let matches = self
+            .database
+            .list_matches_of_tournament(tournament_id)
+            .await?;
+        Ok(matches
+            .into_iter()
+            .filter(|m| m.is_in_progress())
+            .filter_map(|m| m.get_live_score().cloned().map(|l| (*m.get_id(), l)))
+            .collect())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::MatchOutcome;
+
+    fn running_match() -> Match {
+        Match::new_played(
+            Uuid::new_v4(),
+            Uuid::new_v4(),
+            Uuid::new_v4(),
+            Uuid::nil(),
+            vec![],
+            vec![],
+        )
+    }
+
+    #[test]
+    fn given_running_match_when_set_live_score_then_tick_increments() {
+        let mut m = running_match();
+        assert!(check_live_score(&m, &[3], &[5]).is_ok());
+        m.set_live_score(vec![3], vec![5], Utc::now());
+        m.set_live_score(vec![11, 2], vec![9, 0], Utc::now());
+        assert!(m.is_in_progress());
+        let live = m.get_live_score().unwrap();
+        assert_eq!(live.tick, 2);
+        assert_eq!(live.score_a, vec![11, 2]);
+    }
+
+    #[test]
+    fn given_decided_or_malformed_match_when_check_live_score_then_errors() {
+        let m = running_match();
+        assert!(check_live_score(&m, &[11, 3], &[9]).is_err());
+
+        let forfeit = Match::new_walkover(
+            Uuid::new_v4(),
+            Uuid::new_v4(),
+            Uuid::new_v4(),
+            Uuid::nil(),
+            MatchOutcome::ForfeitA,
+        );
+        assert!(matches!(
+            check_live_score(&forfeit, &[1], &[0]),
+            Err(SportError::MatchDecided)
+        ));
+    }
+}
@@ -1,16 +1,58 @@
 // match of tournament
 
-use crate::ScheduledEntrant;
-use chrono::{DateTime, Local};
+use crate::{ScheduledEntrant, utils::id_version::IdVersion};
+use chrono::{DateTime, Local, Utc};
+use displaydoc::Display;
+use serde::{Deserialize, Serialize};
 use uuid::Uuid;
 
+/// outcome of a match
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default, Serialize, Deserialize, Display)]
+pub enum MatchOutcome {
+    /// Played
+    #[default]
+    Played,
+    /// Forfeit by A
+    ForfeitA,
+    /// Forfeit by B
+    ForfeitB,
+    /// Double Forfeit
+    DoubleForfeit,
+    /// Bye
+    Bye,
+}
+
+impl MatchOutcome {
+    /// Check if the match was decided without playing, i.e. by forfeit or bye.
+    pub fn is_walkover(&self) -> bool {
+        *self != MatchOutcome::Played
+    }
+}
+
+/// running score of a match in progress; it is not validated against the rules of the sport
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct LiveScore {
+    /// score of a; each Vec entry represents one set, the last one is the running set
+    pub score_a: Vec<u16>,
+    /// score of b; each Vec entry represents one set, the last one is the running set
+    pub score_b: Vec<u16>,
+    /// number of updates of the live score; used as version of live score ticks
+    pub tick: u32,
+    /// time of last update
+    pub updated_at: DateTime<Utc>,
+}
+
 /// match of tournament
 // ToDo: remove allow(dead_code) flag
 #[allow(dead_code)]
-#[derive(Debug, Clone)]
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
 pub struct Match {
     /// id of match in tournament
     id: Uuid,
+    /// version of the stored match for optimistic locking; it is kept in the database row
+    /// and not in the stored match itself. New matches have no version.
+    #[serde(skip)]
+    version: Option<u32>,
     /// tournament id
     tournament_id: Uuid,
     /// id of sport
@@ -19,6 +61,10 @@ pub struct Match {
     stage_id: Uuid,
     /// id of group
     group_id: Uuid,
+    /// number of group in its stage, starting with 0; matches stored before groups were
+    /// numbered have 0, see [`crate::stage_group_ids`]
+    #[serde(default)]
+    group_number: u32,
     /// id of round
     round_id: Uuid,
     /// number of match in round
@@ -27,6 +73,12 @@ pub struct Match {
     side_a: ScheduledEntrant,
     /// entrant b, either scheduled or concrete id
     side_b: ScheduledEntrant,
+    /// scheduled entrant a, before side a was resolved to a concrete id
+    #[serde(default)]
+    origin_a: Option<ScheduledEntrant>,
+    /// scheduled entrant b, before side b was resolved to a concrete id
+    #[serde(default)]
+    origin_b: Option<ScheduledEntrant>,
     /// station of match
     station: u16,
     /// date and start time of match
@@ -37,6 +89,18 @@ pub struct Match {
     score_a: Vec<u16>,
     /// score of b; each Vec entry represents one set
     score_b: Vec<u16>,
+    /// handicap offset of a; points credited to a at start of each set
+    handicap_a: u16,
+    /// handicap offset of b; points credited to b at start of each set
+    handicap_b: u16,
+    /// outcome of match; matches, which are not played, have no scores. A bye is won by side a.
+    outcome: MatchOutcome,
+    /// running score, while the match is in progress; final scores are kept in score_a and
+    /// score_b
+    live_score: Option<LiveScore>,
+    /// time of entry of the final result; `None`, if no result is entered yet
+    #[serde(default)]
+    result_at: Option<DateTime<Utc>>,
 }
 
 impl Match {
@@ -44,6 +108,19 @@ impl Match {
     pub fn get_id(&self) -> &Uuid {
         &self.id
     }
+    /// Returns the version of the stored match, if any.
+    pub fn get_version(&self) -> Option<u32> {
+        self.version
+    }
+    /// Returns id and version of the match.
+    pub fn get_id_version(&self) -> IdVersion {
+        IdVersion::new(self.id, self.version)
+    }
+    /// Sets the version of the stored match, e.g. after loading it from the database.
+    pub fn set_version(&mut self, version: Option<u32>) -> &mut Self {
+        self.version = version;
+        self
+    }
     /// Returns the tournament ID.
     pub fn get_tournament_id(&self) -> &Uuid {
         &self.tournament_id
@@ -60,10 +137,125 @@ impl Match {
     pub fn get_group_id(&self) -> &Uuid {
         &self.group_id
     }
+    /// Returns the number of the group in its stage, starting with 0.
+    pub fn get_group_number(&self) -> u32 {
+        self.group_number
+    }
+    /// Sets the number of the group in its stage, starting with 0.
+    pub fn set_group_number(&mut self, group_number: u32) -> &mut Self {
+        self.group_number = group_number;
+        self
+    }
     /// Returns the round ID.
     pub fn get_round_id(&self) -> &Uuid {
         &self.round_id
     }
+    /// Returns the number of the match in its round.
+    pub fn get_number(&self) -> u32 {
+        self.number
+    }
+    /// Returns the scheduled entrants of both sides.
+    pub fn get_sides(&self) -> (&ScheduledEntrant, &ScheduledEntrant) {
+        (&self.side_a, &self.side_b)
+    }
+    /// Sets the scheduled entrants of both sides.
+    pub fn set_sides(&mut self, side_a: ScheduledEntrant, side_b: ScheduledEntrant) -> &mut Self {
+        self.side_a = side_a;
+        self.side_b = side_b;
+        self.origin_a = None;
+        self.origin_b = None;
+        self
+    }
+    /// Returns the scheduled entrants of both sides as scheduled, i.e. before they were
+    /// resolved to entrants.
+    pub fn get_scheduled_sides(&self) -> (&ScheduledEntrant, &ScheduledEntrant) {
+        (
+            self.origin_a.as_ref().unwrap_or(&self.side_a),
+            self.origin_b.as_ref().unwrap_or(&self.side_b),
+        )
+    }
+    /// Replaces scheduled sides by the entrants, which `resolve` returns for them.
+    /// Returns if at least one side was resolved.
+    pub fn resolve_sides<F>(&mut self, resolve: F) -> bool
+    where
+        F: Fn(&ScheduledEntrant) -> Option<Uuid>,
+    {
+        let mut resolved = false;
+        for (side, origin) in [
+            (&mut self.side_a, &mut self.origin_a),
+            (&mut self.side_b, &mut self.origin_b),
+        ] {
+            if matches!(side, ScheduledEntrant::Entrant(_)) {
+                continue;
+            }
+            if let Some(id) = resolve(side) {
+                *origin = Some(std::mem::replace(side, ScheduledEntrant::Entrant(id)));
+                resolved = true;
+            }
+        }
+        resolved
+    }
+    /// Resets resolved sides to their scheduled entrants, if `affected` returns true for
+    /// them, e.g. after a correction of the match, on which they depend.
+    /// Returns if at least one side was reset.
+    pub fn unresolve_sides<F>(&mut self, affected: F) -> bool
+    where
+        F: Fn(&ScheduledEntrant) -> bool,
+    {
+        let mut reset = false;
+        for (side, origin) in [
+            (&mut self.side_a, &mut self.origin_a),
+            (&mut self.side_b, &mut self.origin_b),
+        ] {
+            if origin.as_ref().is_some_and(&affected) {
+                *side = origin.take().expect("expecting origin to be set");
+                reset = true;
+            }
+        }
+        reset
+    }
+    /// Replaces the ids of the match, its tournament, stage, group and round as well as the
+    /// ids referenced by its sides with `new_id(id)`, e.g. to import a match with fresh ids.
+    /// The sport id is kept; the match is a new match without version.
+    pub fn map_ids<F>(&mut self, mut new_id: F) -> &mut Self
+    where
+        F: FnMut(Uuid) -> Uuid,
+    {
+        for id in [
+            &mut self.id,
+            &mut self.tournament_id,
+            &mut self.stage_id,
+            &mut self.group_id,
+            &mut self.round_id,
+        ] {
+            *id = new_id(*id);
+        }
+        for side in [&mut self.side_a, &mut self.side_b] {
+            *side = side.map_id(&mut new_id);
+        }
+        for origin in [&mut self.origin_a, &mut self.origin_b]
+            .into_iter()
+            .flatten()
+        {
+            *origin = origin.map_id(&mut new_id);
+        }
+        self.version = None;
+        self
+    }
+    /// Returns the station of the match.
+    pub fn get_station(&self) -> u16 {
+        self.station
+    }
+    /// Returns date and start time of the match.
+    pub fn get_start_at(&self) -> DateTime<Local> {
+        self.start_at
+    }
+    /// Sets station and start time of the match.
+    pub fn set_slot(&mut self, station: u16, start_at: DateTime<Local>) -> &mut Self {
+        self.station = station;
+        self.start_at = start_at;
+        self
+    }
     /// Returns the entrant IDs of both sides if they are concrete entrants.
     pub fn get_entrants(&self) -> Option<(&Uuid, &Uuid)> {
         match (&self.side_a, &self.side_b) {
@@ -73,14 +265,109 @@ impl Match {
             _ => None,
         }
     }
+    /// Drops the result and the running score of the match, e.g. when its stage is rolled
+    /// back. Byes stay decided.
+    pub fn reset_result(&mut self) -> &mut Self {
+        self.score_a.clear();
+        self.score_b.clear();
+        self.live_score = None;
+        self.result_at = None;
+        if self.outcome != MatchOutcome::Bye {
+            self.outcome = MatchOutcome::Played;
+        }
+        self
+    }
+    /// Returns the entrant, which wins the match by bye, i.e. side a of a match with outcome
+    /// [`MatchOutcome::Bye`].
+    pub fn get_bye_entrant(&self) -> Option<&Uuid> {
+        match (&self.outcome, &self.side_a) {
+            (MatchOutcome::Bye, ScheduledEntrant::Entrant(id)) => Some(id),
+            _ => None,
+        }
+    }
     /// Returns if match has been played, i.e., if scores are available.
     pub fn is_played(&self) -> bool {
         !self.score_a.is_empty() && !self.score_b.is_empty()
     }
+    /// Returns if the result of the match is known, i.e. if it has been played or was decided
+    /// by forfeit or bye.
+    pub fn is_decided(&self) -> bool {
+        self.outcome.is_walkover() || self.is_played()
+    }
+    /// Returns if the match has started, but has no final result yet.
+    pub fn is_in_progress(&self) -> bool {
+        self.live_score.is_some() && !self.is_decided()
+    }
+    /// Returns the time of entry of the final result, if it is entered.
+    pub fn get_result_at(&self) -> Option<DateTime<Utc>> {
+        self.result_at
+    }
+    /// Sets the time of entry of the final result.
+    pub fn set_result_at(&mut self, result_at: Option<DateTime<Utc>>) -> &mut Self {
+        self.result_at = result_at;
+        self
+    }
+    /// Returns the running score of the match, if it is or was in progress.
+    pub fn get_live_score(&self) -> Option<&LiveScore> {
+        self.live_score.as_ref()
+    }
+    /// Sets the running score of the match and increments its tick.
+    pub fn set_live_score(
+        &mut self,
+        score_a: Vec<u16>,
+        score_b: Vec<u16>,
+        updated_at: DateTime<Utc>,
+    ) -> &mut Self {
+        let tick = self.live_score.as_ref().map_or(1, |l| l.tick + 1);
+        self.live_score = Some(LiveScore {
+            score_a,
+            score_b,
+            tick,
+            updated_at,
+        });
+        self
+    }
+    /// Returns the outcome of the match.
+    pub fn get_outcome(&self) -> MatchOutcome {
+        self.outcome
+    }
+    /// Sets the outcome of the match.
+    pub fn set_outcome(&mut self, outcome: MatchOutcome) -> &mut Self {
+        self.outcome = outcome;
+        self
+    }
+    /// Returns the handicap offsets of both sides, which are credited at start of each set.
+    pub fn get_handicaps(&self) -> (u16, u16) {
+        (self.handicap_a, self.handicap_b)
+    }
+    /// Sets the handicap offsets of both sides.
+    pub fn set_handicaps(&mut self, handicap_a: u16, handicap_b: u16) -> &mut Self {
+        self.handicap_a = handicap_a;
+        self.handicap_b = handicap_b;
+        self
+    }
     /// Returns the scores of both entrants as references to their respective vectors.
     pub fn get_scores(&self) -> (&Vec<u16>, &Vec<u16>) {
         (&self.score_a, &self.score_b)
     }
+    /// Sets the final scores of both entrants.
+    pub fn set_scores(&mut self, score_a: Vec<u16>, score_b: Vec<u16>) -> &mut Self {
+        self.score_a = score_a;
+        self.score_b = score_b;
+        self
+    }
+    /// Returns the scores of both sides, by which the match is scored. The winner of a
+    /// forfeit or bye scores `free_ticket` (one entry per set) and the loser zero in each
+    /// set; double forfeits score zero for both sides. Played matches return their scores.
+    pub fn get_result_scores(&self, free_ticket: &[u16]) -> (Vec<u16>, Vec<u16>) {
+        let zeros = vec![0; free_ticket.len()];
+        match self.outcome {
+            MatchOutcome::Played => (self.score_a.clone(), self.score_b.clone()),
+            MatchOutcome::ForfeitA => (zeros, free_ticket.to_vec()),
+            MatchOutcome::ForfeitB | MatchOutcome::Bye => (free_ticket.to_vec(), zeros),
+            MatchOutcome::DoubleForfeit => (zeros.clone(), zeros),
+        }
+    }
     /// Creates a new match with scores (played match).
     /// Useful for testing and initializing played matches.
     // ToDo: try later to find a better way to create played matches for testing
@@ -94,18 +381,74 @@ impl Match {
     ) -> Self {
         Self {
             id,
+            version: None,
             tournament_id: Uuid::nil(),
             sport_id,
             stage_id: Uuid::nil(),
             group_id: Uuid::nil(),
+            group_number: 0,
             round_id: Uuid::nil(),
             number: 0,
             side_a: ScheduledEntrant::Entrant(entrant_a),
             side_b: ScheduledEntrant::Entrant(entrant_b),
+            origin_a: None,
+            origin_b: None,
             station: 0,
             start_at: Local::now(),
             score_a,
             score_b,
+            handicap_a: 0,
+            handicap_b: 0,
+            outcome: MatchOutcome::Played,
+            live_score: None,
+            result_at: None,
         }
     }
+    /// Creates a new match of both entrants, which was decided by `outcome` without scores.
+    pub fn new_walkover(
+        id: Uuid,
+        entrant_a: Uuid,
+        entrant_b: Uuid,
+        sport_id: Uuid,
+        outcome: MatchOutcome,
+    ) -> Self {
+        let mut m = Self::new_played(id, entrant_a, entrant_b, sport_id, vec![], vec![]);
+        m.set_outcome(outcome);
+        m
+    }
+    /// Creates a new match with `number` in round `round_id` of group `group_id`, whose
+    /// entrants are scheduled by `side_a` and `side_b`, e.g. by results of previous matches.
+    pub fn new_scheduled(
+        id: Uuid,
+        group_id: Uuid,
+        round_id: Uuid,
+        number: u32,
+        side_a: ScheduledEntrant,
+        side_b: ScheduledEntrant,
+    ) -> Self {
+        let mut m = Self::new_played(id, Uuid::nil(), Uuid::nil(), Uuid::nil(), vec![], vec![]);
+        m.group_id = group_id;
+        m.round_id = round_id;
+        m.number = number;
+        m.set_sides(side_a, side_b);
+        m
+    }
+    /// Sets tournament, sport and stage of the match.
+    pub fn set_tournament(
+        &mut self,
+        tournament_id: Uuid,
+        sport_id: Uuid,
+        stage_id: Uuid,
+    ) -> &mut Self {
+        self.tournament_id = tournament_id;
+        self.sport_id = sport_id;
+        self.stage_id = stage_id;
+        self
+    }
+    /// Creates a new match, in which `entrant` pauses and wins by bye without opponent.
+    pub fn new_bye(id: Uuid, entrant: Uuid, sport_id: Uuid) -> Self {
+        let mut m = Self::new_walkover(id, entrant, Uuid::nil(), sport_id, MatchOutcome::Bye);
+        m.side_b = ScheduledEntrant::Bye;
+        m
+    }
 }
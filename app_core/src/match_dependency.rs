@@ -0,0 +1,318 @@
+//! dependencies of matches on results of other matches and groups
+//!
+//! Entrants of KO matches are usually unknown, when the matches are scheduled: a side is the
+//! winner or loser of another match or the entrant of a given rank in a group (see
+//! [`ScheduledEntrant`]). [`MatchDependencyGraph`] links these matches to the matches and
+//! groups, they depend on. When a result is entered or the standings of a group are final,
+//! Core resolves the dependent sides to the actual entrants and notifies clients, so
+//! brackets update automatically.
+
+use crate::{
+    Core, CoreResult, DomainEvent, GroupStanding, Match, ScheduledEntrant, SportConfig, SportError,
+    SportPort, SportResult,
+};
+use petgraph::{algo::is_cyclic_directed, graphmap::DiGraphMap};
+use serde::{Deserialize, Serialize};
+use std::slice;
+use uuid::Uuid;
+
+/// Directed graph from matches and groups to the matches, whose sides depend on them.
+/// Nodes are ids of matches and groups; ids are unique across both.
+#[derive(Debug, Clone, Default)]
+pub struct MatchDependencyGraph {
+    graph: DiGraphMap<Uuid, ()>,
+}
+
+impl MatchDependencyGraph {
+    /// Build the dependency graph of `matches`.
+    pub fn new(matches: &[Match]) -> Self {
+        let mut graph = DiGraphMap::new();
+        for m in matches {
+            // resolved sides still depend on their scheduled source, e.g. for corrections
+            let (side_a, side_b) = m.get_scheduled_sides();
+            graph.add_node(*m.get_id());
+            for source in [side_a, side_b].into_iter().filter_map(dependency_source) {
+                graph.add_edge(source, *m.get_id(), ());
+            }
+        }
+        MatchDependencyGraph { graph }
+    }
+
+    /// Ids of matches, which depend directly on match or group `source`.
+    pub fn dependents(&self, source: Uuid) -> Vec<Uuid> {
+        if !self.graph.contains_node(source) {
+            return Vec::new();
+        }
+        self.graph.neighbors(source).collect()
+    }
+
+    /// Check if no match depends on its own result, directly or via other matches.
+    pub fn is_acyclic(&self) -> bool {
+        !is_cyclic_directed(&self.graph)
+    }
+}
+
+/// match or group, on which `side` depends; `None`, if the side does not depend on a
+/// result of the stage
+fn dependency_source(side: &ScheduledEntrant) -> Option<Uuid> {
+    match side {
+        ScheduledEntrant::WinnerOf(match_id) | ScheduledEntrant::LoserOf(match_id) => {
+            Some(*match_id)
+        }
+        ScheduledEntrant::GroupRank(group_id, _) => Some(*group_id),
+        _ => None,
+    }
+}
+
+/// winner and loser of a decided match
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+pub struct MatchWinner {
+    pub match_id: Uuid,
+    pub winner: Uuid,
+    /// loser of match; `None` for a bye without opponent
+    pub loser: Option<Uuid>,
+}
+
+impl MatchWinner {
+    /// Determine winner and loser of `m` by the rules of the sport of `config`.
+    /// Returns `None`, if `m` is not decided, ended in a draw or was lost by both sides.
+    pub fn of_match(
+        plugin: &dyn SportPort,
+        config: &SportConfig,
+        m: &Match,
+    ) -> SportResult<Option<Self>> {
+        if !m.is_decided() {
+            return Ok(None);
+        }
+        if let Some(&winner) = m.get_bye_entrant()
+            && m.get_entrants().is_none()
+        {
+            return Ok(Some(MatchWinner {
+                match_id: *m.get_id(),
+                winner,
+                loser: None,
+            }));
+        }
+        let Some((&a, &b)) = m.get_entrants() else {
+            return Ok(None);
+        };
+        let score_a =
+            plugin.get_entrant_group_score(config, *m.get_group_id(), a, slice::from_ref(m))?;
+        let score_b =
+            plugin.get_entrant_group_score(config, *m.get_group_id(), b, slice::from_ref(m))?;
+        let (winner, loser) = match (score_a.wins, score_b.wins) {
+            (1, 0) => (a, b),
+            (0, 1) => (b, a),
+            _ => return Ok(None),
+        };
+        Ok(Some(MatchWinner {
+            match_id: *m.get_id(),
+            winner,
+            loser: Some(loser),
+        }))
+    }
+
+    fn resolve(&self, side: &ScheduledEntrant) -> Option<Uuid> {
+        match side {
+            ScheduledEntrant::WinnerOf(id) if *id == self.match_id => Some(self.winner),
+            ScheduledEntrant::LoserOf(id) if *id == self.match_id => self.loser,
+            _ => None,
+        }
+    }
+}
+
+/// entrant of the rank of group `group_id`, which `side` refers to; `None`, if `standings`
+/// have no entrant at this index
+fn resolve_group_rank(
+    group_id: Uuid,
+    standings: &[GroupStanding],
+    side: &ScheduledEntrant,
+) -> Option<Uuid> {
+    match side {
+        ScheduledEntrant::GroupRank(id, index) if *id == group_id => {
+            standings.get(*index).map(GroupStanding::get_entrant_id)
+        }
+        _ => None,
+    }
+}
+
+// dependencies may be resolved in every core state
+impl<S> Core<S> {
+    /// Resolve the sides of `matches`, which depend on the result of the decided match
+    /// `decided`, to its winner and loser. Subscribers of domain events are notified of each
+    /// resolved match; clients are notified by the caller, when the matches are saved.
+    /// Returns the ids of the resolved matches. Matches, whose result is a draw or a double
+    /// forfeit, resolve no sides.
+    pub async fn resolve_match_dependencies(
+        &self,
+        config: &SportConfig,
+        decided: &Match,
+        matches: &mut [Match],
+    ) -> CoreResult<Vec<Uuid>> {
+        let sport_id = config.get_sport_id();
+        let plugin = self
+            .sport_plugins
+            .get(&sport_id)
+            .ok_or(SportError::UnknownSportId(sport_id))?;
+        let Some(result) = MatchWinner::of_match(plugin.as_ref(), config, decided)? else {
+            return Ok(Vec::new());
+        };
+        self.resolve_dependents(*decided.get_id(), matches, |side| result.resolve(side))
+            .await
+    }
+
+    /// Resolve the sides of `matches`, which depend on a rank of group `group_id`, with the
+    /// final `standings` of the group, sorted by rank. Subscribers of domain events are
+    /// notified of each resolved match; clients are notified by the caller, when the matches
+    /// are saved. Returns the ids of the resolved matches.
+    pub async fn resolve_group_dependencies(
+        &self,
+        group_id: Uuid,
+        standings: &[GroupStanding],
+        matches: &mut [Match],
+    ) -> CoreResult<Vec<Uuid>> {
+        self.resolve_dependents(group_id, matches, |side| {
+            resolve_group_rank(group_id, standings, side)
+        })
+        .await
+    }
+
+    async fn resolve_dependents<F>(
+        &self,
+        source: Uuid,
+        matches: &mut [Match],
+        resolve: F,
+    ) -> CoreResult<Vec<Uuid>>
+    where
+        F: Fn(&ScheduledEntrant) -> Option<Uuid>,
+    {
+        let dependents = MatchDependencyGraph::new(matches).dependents(source);
+        let mut resolved = Vec::new();
+        for m in matches
+            .iter_mut()
+            .filter(|m| dependents.contains(m.get_id()))
+        {
+            if !m.resolve_sides(&resolve) {
+                continue;
+            }
+            let (side_a, side_b) = m.get_sides();
+            self.emit_domain_event(DomainEvent::MatchEntrantsResolved {
+                tournament_id: *m.get_tournament_id(),
+                match_id: *m.get_id(),
+                entrant_a: scheduled_entrant_id(side_a),
+                entrant_b: scheduled_entrant_id(side_b),
+            })
+            .await;
+            resolved.push(*m.get_id());
+        }
+        Ok(resolved)
+    }
+}
+
+fn scheduled_entrant_id(side: &ScheduledEntrant) -> Option<Uuid> {
+    match side {
+        ScheduledEntrant::Entrant(id) => Some(*id),
+        _ => None,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn dependent(id: Uuid, side_a: ScheduledEntrant, side_b: ScheduledEntrant) -> Match {
+        let mut m = Match::new_played(id, Uuid::nil(), Uuid::nil(), Uuid::nil(), vec![], vec![]);
+        m.set_sides(side_a, side_b);
+        m
+    }
+
+    #[test]
+    fn given_ko_bracket_when_build_graph_then_final_depends_on_semi_finals() {
+        let (semi_1, semi_2, last, third) = (
+            Uuid::new_v4(),
+            Uuid::new_v4(),
+            Uuid::new_v4(),
+            Uuid::new_v4(),
+        );
+        let group_id = Uuid::new_v4();
+        let matches = vec![
+            dependent(
+                semi_1,
+                ScheduledEntrant::GroupRank(group_id, 0),
+                ScheduledEntrant::GroupRank(group_id, 3),
+            ),
+            dependent(
+                semi_2,
+                ScheduledEntrant::GroupRank(group_id, 1),
+                ScheduledEntrant::GroupRank(group_id, 2),
+            ),
+            dependent(
+                last,
+                ScheduledEntrant::WinnerOf(semi_1),
+                ScheduledEntrant::WinnerOf(semi_2),
+            ),
+            dependent(
+                third,
+                ScheduledEntrant::LoserOf(semi_1),
+                ScheduledEntrant::LoserOf(semi_2),
+            ),
+        ];
+
+        let graph = MatchDependencyGraph::new(&matches);
+
+        assert!(graph.is_acyclic());
+        let mut semi_finals = graph.dependents(group_id);
+        semi_finals.sort();
+        let mut expected = vec![semi_1, semi_2];
+        expected.sort();
+        assert_eq!(semi_finals, expected);
+        let mut after_semi_1 = graph.dependents(semi_1);
+        after_semi_1.sort();
+        let mut expected = vec![last, third];
+        expected.sort();
+        assert_eq!(after_semi_1, expected);
+        assert!(graph.dependents(last).is_empty());
+        assert!(graph.dependents(Uuid::new_v4()).is_empty());
+    }
+
+    #[test]
+    fn given_match_depending_on_itself_when_build_graph_then_cyclic() {
+        let (a, b) = (Uuid::new_v4(), Uuid::new_v4());
+        let matches = vec![
+            dependent(
+                a,
+                ScheduledEntrant::WinnerOf(b),
+                ScheduledEntrant::Entrant(Uuid::new_v4()),
+            ),
+            dependent(
+                b,
+                ScheduledEntrant::LoserOf(a),
+                ScheduledEntrant::Entrant(Uuid::new_v4()),
+            ),
+        ];
+
+        assert!(!MatchDependencyGraph::new(&matches).is_acyclic());
+    }
+
+    #[test]
+    fn given_result_when_resolve_then_winner_and_loser_fill_sides() {
+        let (decided, winner, loser) = (Uuid::new_v4(), Uuid::new_v4(), Uuid::new_v4());
+        let result = MatchWinner {
+            match_id: decided,
+            winner,
+            loser: Some(loser),
+        };
+        let mut m = dependent(
+            Uuid::new_v4(),
+            ScheduledEntrant::LoserOf(decided),
+            ScheduledEntrant::WinnerOf(Uuid::new_v4()),
+        );
+
+        assert!(m.resolve_sides(|side| result.resolve(side)));
+
+        let (side_a, side_b) = m.get_sides();
+        assert_eq!(side_a, &ScheduledEntrant::Entrant(loser));
+        assert!(matches!(side_b, ScheduledEntrant::WinnerOf(_)));
+        assert!(!m.resolve_sides(|side| result.resolve(side)));
+    }
+}
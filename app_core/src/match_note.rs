@@ -0,0 +1,405 @@
+//! notes and tags of matches
+//!
+//! Directors annotate matches with free-form tags (e.g. "protest pending", "injury delay")
+//! and a note. Each match has at most one [`MatchNote`]. Notes are searchable by tag and text
+//! and are included in the final report of the tournament.
+
+use crate::{
+    AuditObjectType, Core, CoreResult, CrMsg, CrTopic,
+    utils::{id_version::IdVersion, normalize::*, traits::ObjectIdVersion, validation::*},
+};
+use chrono::{DateTime, Utc};
+use serde::{Deserialize, Serialize};
+use uuid::Uuid;
+
+/// maximum length of the text of a match note in characters
+pub const MATCH_NOTE_TEXT_MAX_LEN: usize = 2000;
+
+/// maximum length of a tag in characters
+pub const MATCH_NOTE_TAG_MAX_LEN: usize = 32;
+
+/// maximum number of tags of a match
+pub const MATCH_NOTE_MAX_TAGS: usize = 10;
+
+/// Normalize a tag: whitespace is normalized and letters are lower case.
+pub fn normalize_tag(tag: impl Into<String>) -> String {
+    normalize_ws(tag).to_lowercase()
+}
+
+/// Tags and note of a match.
+#[derive(Clone, Debug, PartialEq, Eq, Serialize, Deserialize, Default, ObjectIdVersion)]
+pub struct MatchNote {
+    /// id and optimistic locking version of note
+    id_version: IdVersion,
+    /// id of tournament of match
+    tournament_id: Uuid,
+    /// id of annotated match
+    match_id: Uuid,
+    /// normalized tags without duplicates
+    tags: Vec<String>,
+    /// free text of note; may be empty, if note has tags
+    text: String,
+    /// name of director, who changed the note last
+    author: String,
+    /// timestamp of last change; set by database
+    updated_at: Option<DateTime<Utc>>,
+}
+
+impl MatchNote {
+    /// Create a new `MatchNote` with the given `IdVersion`.
+    pub fn new(id_version: IdVersion) -> Self {
+        MatchNote {
+            id_version,
+            ..Default::default()
+        }
+    }
+
+    /// Get the unique identifier of the note.
+    pub fn get_id(&self) -> Uuid {
+        self.id_version.get_id()
+    }
+
+    /// Get the version number of the note.
+    pub fn get_version(&self) -> Option<u32> {
+        self.id_version.get_version()
+    }
+
+    /// Get the tournament ID.
+    pub fn get_tournament_id(&self) -> Uuid {
+        self.tournament_id
+    }
+
+    /// Get the ID of the annotated match.
+    pub fn get_match_id(&self) -> Uuid {
+        self.match_id
+    }
+
+    /// Get the normalized tags.
+    pub fn get_tags(&self) -> &[String] {
+        &self.tags
+    }
+
+    /// Get the free text of the note.
+    pub fn get_text(&self) -> &str {
+        &self.text
+    }
+
+    /// Get the name of the author.
+    pub fn get_author(&self) -> &str {
+        &self.author
+    }
+
+    /// Get the timestamp of the last change, if note is persisted.
+    pub fn get_updated_at(&self) -> Option<DateTime<Utc>> {
+        self.updated_at
+    }
+
+    /// Returns true, if note has `tag`. `tag` is normalized before comparison.
+    pub fn has_tag(&self, tag: &str) -> bool {
+        let tag = normalize_tag(tag);
+        self.tags.contains(&tag)
+    }
+
+    /// Returns true, if a tag or the text contains `query`, ignoring case.
+    pub fn matches_query(&self, query: &str) -> bool {
+        let query = query.trim().to_lowercase();
+        self.tags.iter().any(|t| t.contains(&query)) || self.text.to_lowercase().contains(&query)
+    }
+
+    /// Set the `IdVersion` of the note.
+    pub fn set_id_version(&mut self, id_version: IdVersion) -> &mut Self {
+        self.id_version = id_version;
+        self
+    }
+
+    /// Set the tournament ID.
+    pub fn set_tournament_id(&mut self, tournament_id: Uuid) -> &mut Self {
+        self.tournament_id = tournament_id;
+        self
+    }
+
+    /// Set the ID of the annotated match.
+    pub fn set_match_id(&mut self, match_id: Uuid) -> &mut Self {
+        self.match_id = match_id;
+        self
+    }
+
+    /// Set the tags. Tags are normalized, empty tags and duplicates are removed.
+    pub fn set_tags<I, T>(&mut self, tags: I) -> &mut Self
+    where
+        I: IntoIterator<Item = T>,
+        T: Into<String>,
+    {
+        self.tags.clear();
+        for tag in tags {
+            self.add_tag(tag);
+        }
+        self
+    }
+
+    /// Add a normalized tag, if it is not empty and not yet part of the tags.
+    pub fn add_tag(&mut self, tag: impl Into<String>) -> &mut Self {
+        let tag = normalize_tag(tag);
+        if !tag.is_empty() && !self.tags.contains(&tag) {
+            self.tags.push(tag);
+        }
+        self
+    }
+
+    /// Remove `tag`. `tag` is normalized before comparison.
+    pub fn remove_tag(&mut self, tag: &str) -> &mut Self {
+        let tag = normalize_tag(tag);
+        self.tags.retain(|t| *t != tag);
+        self
+    }
+
+    /// Set the free text of the note.
+    ///
+    /// Line breaks are kept, only leading and trailing whitespace is removed.
+    pub fn set_text(&mut self, text: impl Into<String>) -> &mut Self {
+        self.text = text.into().trim().to_string();
+        self
+    }
+
+    /// Set the name of the author with whitespace normalization.
+    pub fn set_author(&mut self, author: impl Into<String>) -> &mut Self {
+        self.author = normalize_ws(author);
+        self
+    }
+
+    /// Set the timestamp of the last change. Only used by database adapters.
+    pub fn set_updated_at(&mut self, updated_at: Option<DateTime<Utc>>) -> &mut Self {
+        self.updated_at = updated_at;
+        self
+    }
+
+    /// Validate the note.
+    pub fn validate(&self) -> ValidationResult<()> {
+        let mut errs = ValidationErrors::new();
+        let object_id = self.get_id();
+
+        if self.tournament_id.is_nil() {
+            errs.add(
+                FieldError::builder()
+                    .set_field(String::from("tournament_id"))
+                    .add_required()
+                    .set_object_id(object_id)
+                    .build(),
+            );
+        }
+        if self.match_id.is_nil() {
+            errs.add(
+                FieldError::builder()
+                    .set_field(String::from("match_id"))
+                    .add_required()
+                    .set_object_id(object_id)
+                    .build(),
+            );
+        }
+        if self.author.is_empty() {
+            errs.add(
+                FieldError::builder()
+                    .set_field(String::from("author"))
+                    .add_required()
+                    .set_object_id(object_id)
+                    .build(),
+            );
+        }
+        if self.tags.is_empty() && self.text.is_empty() {
+            errs.add(
+                FieldError::builder()
+                    .set_field(String::from("text"))
+                    .add_user_defined_code("empty_note")
+                    .add_message("Add a tag or a note")
+                    .set_object_id(object_id)
+                    .build(),
+            );
+        }
+        if self.text.chars().count() > MATCH_NOTE_TEXT_MAX_LEN {
+            errs.add(
+                FieldError::builder()
+                    .set_field(String::from("text"))
+                    .add_user_defined_code("too_long")
+                    .add_message(format!(
+                        "Note must not exceed {MATCH_NOTE_TEXT_MAX_LEN} characters"
+                    ))
+                    .set_object_id(object_id)
+                    .build(),
+            );
+        }
+        if self.tags.len() > MATCH_NOTE_MAX_TAGS {
+            errs.add(
+                FieldError::builder()
+                    .set_field(String::from("tags"))
+                    .add_user_defined_code("too_many")
+                    .add_message(format!(
+                        "A match must not have more than {MATCH_NOTE_MAX_TAGS} tags"
+                    ))
+                    .set_object_id(object_id)
+                    .build(),
+            );
+        }
+        if self
+            .tags
+            .iter()
+            .any(|t| t.chars().count() > MATCH_NOTE_TAG_MAX_LEN)
+        {
+            errs.add(
+                FieldError::builder()
+                    .set_field(String::from("tags"))
+                    .add_user_defined_code("too_long")
+                    .add_message(format!(
+                        "Tags must not exceed {MATCH_NOTE_TAG_MAX_LEN} characters"
+                    ))
+                    .set_object_id(object_id)
+                    .build(),
+            );
+        }
+
+        if errs.is_empty() { Ok(()) } else { Err(errs) }
+    }
+}
+
+/// State for match note operations of one tournament
+pub struct MatchNoteState {
+    tournament_id: Uuid,
+    note: MatchNote,
+}
+
+// switch state to match note state
+impl<S> Core<S> {
+    pub fn as_match_note_state(&self, tournament_id: Uuid) -> Core<MatchNoteState> {
+        let mut note = MatchNote::default();
+        note.set_tournament_id(tournament_id);
+        self.switch_state(MatchNoteState {
+            tournament_id,
+            note,
+        })
+    }
+}
+
+impl Core<MatchNoteState> {
+    pub fn get(&self) -> &MatchNote {
+        &self.state.note
+    }
+    pub fn get_mut(&mut self) -> &mut MatchNote {
+        &mut self.state.note
+    }
+    /// Load the note of match with id `match_id`.
+    pub async fn load_of_match(&mut self, match_id: Uuid) -> CoreResult<Option<&MatchNote>> {
+        if let Some(note) = self.database.get_match_note_of_match(match_id).await? {
+            self.state.note = note;
+            Ok(Some(self.get()))
+        } else {
+            Ok(None)
+        }
+    }
+    pub async fn save(&mut self) -> CoreResult<&MatchNote> {
+        self.state.note.validate()?;
+        // stored copy before the save for the audit log
+        let old = match self.state.note.get_version() {
+            Some(_) => {
+                let match_id = self.state.note.get_match_id();
+                self.database.get_match_note_of_match(match_id).await?
+            }
+            None => None,
+        };
+        self.state.note = self.database.save_match_note(&self.state.note).await?;
+
+        // publish change of match notes to client registry
+        let id = self.state.note.get_id();
+        let version = self
+            .state
+            .note
+            .get_version()
+            .expect("expecting save_match_note to return always an existing id and version");
+        let notice = CrTopic::MatchNotes {
+            tournament_id: self.state.tournament_id,
+        };
+        let msg = CrMsg::MatchNoteUpdated { id, version };
+        self.client_registry.publish(notice, msg).await?;
+        self.audit_save(
+            AuditObjectType::MatchNote,
+            Some(self.state.tournament_id),
+            old.as_ref(),
+            &self.state.note,
+        )
+        .await;
+        Ok(self.get())
+    }
+    /// List notes of tournament, most recently changed first. Notes are filtered by `tag` and
+    /// by `query`, which must be contained in a tag or in the text of the note.
+    pub async fn list_notes(
+        &self,
+        tag: Option<&str>,
+        query: Option<&str>,
+    ) -> CoreResult<Vec<MatchNote>> {
+        let tag = tag.map(normalize_tag).filter(|t| !t.is_empty());
+        let mut list = self
+            .database
+            .list_match_notes(self.state.tournament_id, tag.as_deref())
+            .await?;
+        if let Some(query) = query.filter(|q| !q.trim().is_empty()) {
+            list.retain(|n| n.matches_query(query));
+        }
+        list.sort_by_key(|n| std::cmp::Reverse(n.get_updated_at()));
+        Ok(list)
+    }
+
+    /// All tags used in the tournament with their number of matches, most used first.
+    pub async fn tag_counts(&self) -> CoreResult<Vec<(String, usize)>> {
+        let notes = self
+            .database
+            .list_match_notes(self.state.tournament_id, None)
+            .await?;
+        let mut counts: Vec<(String, usize)> = Vec::new();
+        for tag in notes.iter().flat_map(|n| n.get_tags()) {
+            match counts.iter_mut().find(|(t, _)| t == tag) {
+                Some((_, count)) => *count += 1,
+                None => counts.push((tag.clone(), 1)),
+            }
+        }
+        counts.sort_by(|a, b| b.1.cmp(&a.1).then(a.0.cmp(&b.0)));
+        Ok(counts)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn valid_note() -> MatchNote {
+        let mut n = MatchNote::default();
+        n.set_tournament_id(Uuid::new_v4())
+            .set_match_id(Uuid::new_v4())
+            .set_author("  Jane   Doe ")
+            .set_tags(["Protest  Pending", "protest pending", " ", "Injury Delay"])
+            .set_text("  Team B protests the score of set 2.  ");
+        n
+    }
+
+    #[test]
+    fn given_tags_when_set_then_normalized_without_duplicates() {
+        let n = valid_note();
+        assert_eq!(n.get_author(), "Jane Doe");
+        assert_eq!(n.get_tags(), ["protest pending", "injury delay"]);
+        assert!(n.has_tag("Injury delay"));
+        assert!(n.matches_query("PROTEST"));
+        assert!(n.matches_query("score of set"));
+        assert!(!n.matches_query("forfeit"));
+        assert!(n.validate().is_ok());
+    }
+
+    #[test]
+    fn given_note_without_tags_and_text_when_validate_then_err() {
+        let mut n = valid_note();
+        n.set_tags(Vec::<String>::new()).set_text("");
+        let errs = n.validate().unwrap_err();
+        assert_eq!(errs.errors.len(), 1);
+        assert_eq!(errs.errors[0].get_code(), "empty_note");
+
+        n.add_tag("x".repeat(MATCH_NOTE_TAG_MAX_LEN + 1));
+        let errs = n.validate().unwrap_err();
+        assert_eq!(errs.errors[0].get_code(), "too_long");
+    }
+}
@@ -0,0 +1,240 @@
+// final results of matches
+
+use crate::{
+    Core, CoreResult, CrMsg, CrTopic, DbError, DomainEvent, Match, MatchOutcome, ScheduledEntrant,
+    SportError, SportResult, WebhookEventData, WebhookMatchResult,
+};
+use chrono::Utc;
+use uuid::Uuid;
+
+/// Check if `score_a` and `score_b` may be entered as final result of `m`. The match must
+/// have concrete entrants and both sides must have the same number of sets, at least one.
+/// Scores are validated against the rules of the sport with
+/// [`SportPort::validate_final_score`](crate::SportPort::validate_final_score).
+pub fn check_match_result(m: &Match, score_a: &[u16], score_b: &[u16]) -> SportResult<()> {
+    if m.get_entrants().is_none() {
+        return Err(SportError::InvalidScore(
+            "Both sides of the match must have concrete entrant IDs".to_string(),
+        ));
+    }
+    if score_a.is_empty() || score_a.len() != score_b.len() {
+        return Err(SportError::InvalidScore(
+            "Both sides must have the same number of sets, at least one".to_string(),
+        ));
+    }
+    Ok(())
+}
+
+/// Result of `m` as displayed in notifications, e.g. `11:9, 8:11, 11:5`. Matches decided
+/// without playing show their outcome.
+pub fn format_match_result(m: &Match) -> String {
+    if m.get_outcome().is_walkover() {
+        return m.get_outcome().to_string();
+    }
+    let (score_a, score_b) = m.get_scores();
+    score_a
+        .iter()
+        .zip(score_b)
+        .map(|(a, b)| format!("{a}:{b}"))
+        .collect::<Vec<_>>()
+        .join(", ")
+}
+
+// results may be entered in every core state, e.g. by api clients
+impl<S> Core<S> {
+    /// Enter the final result of the match with id `match_id`. Returns `None`, if the match
+    /// does not exist.
+    ///
+    /// Results are entered for matches of the active stage, which have no final result yet;
+    /// final results are changed with [`Core::apply_score_correction`]. The scores are
+    /// validated against the rules of the sport. Sides of matches, which depend on the
+    /// result, are resolved and saved with the match. The last result of a round generates
+    /// the next Swiss round of auto advancing stages.
+    pub async fn enter_match_result(
+        &self,
+        match_id: Uuid,
+        score_a: Vec<u16>,
+        score_b: Vec<u16>,
+    ) -> CoreResult<Option<Match>> {
+        let Some(mut m) = self.database.get_match(match_id).await? else {
+            return Ok(None);
+        };
+        check_match_result(&m, &score_a, &score_b)?;
+        if m.is_decided() {
+            return Err(SportError::MatchDecided.into());
+        }
+        let tournament = self
+            .database
+            .get_tournament_base(*m.get_tournament_id())
+            .await?
+            .ok_or(DbError::NotFound)?;
+        let stage = self
+            .database
+            .get_stage_by_id(*m.get_stage_id())
+            .await?
+            .ok_or(DbError::NotFound)?;
+        if !tournament.is_stage_active(stage.get_number()) {
+            return Err(SportError::InvalidScore(
+                "Results can only be entered for matches of the active stage".to_string(),
+            )
+            .into());
+        }
+        let sport_id = tournament.get_sport_id();
+        let plugin = self
+            .sport_plugins
+            .get(&sport_id)
+            .ok_or(SportError::UnknownSportId(sport_id))?;
+        let config = self
+            .load_tournament_sport_config(&tournament)
+            .await?
+            .ok_or_else(|| {
+                SportError::Other(format!("No valid configuration of sport {sport_id}"))
+            })?;
+
+        m.set_scores(score_a, score_b)
+            .set_outcome(MatchOutcome::Played)
+            .set_result_at(Some(Utc::now()));
+        plugin.validate_final_score(&config, &m)?;
+
+        let mut matches = self
+            .database
+            .list_matches_of_stage_for_update(stage.get_id())
+            .await?;
+        if let Some(stored) = matches.iter_mut().find(|s| s.get_id() == m.get_id()) {
+            *stored = m.clone();
+        }
+        let resolved = self
+            .resolve_match_dependencies(&config, &m, &mut matches)
+            .await?;
+        let mut changed: Vec<Match> = matches
+            .iter()
+            .filter(|s| resolved.contains(s.get_id()))
+            .cloned()
+            .collect();
+        changed.push(m.clone());
+        let saved = self.database.save_matches(&changed).await?;
+        self.publish_saved_matches(&saved).await?;
+        for saved in saved {
+            if saved.get_id() == m.get_id() {
+                m = saved.clone();
+            }
+            if let Some(stored) = matches.iter_mut().find(|s| s.get_id() == saved.get_id()) {
+                *stored = saved;
+            }
+        }
+
+        // sandbox tournaments must not leak to integrations
+        if !tournament.is_sandbox() {
+            let result = self.webhook_match_result(&m).await?;
+            self.emit_domain_event(DomainEvent::MatchResultEntered {
+                tournament_id: tournament.get_id(),
+                stage_number: stage.get_number(),
+                match_number: m.get_number(),
+                result: result.result.clone(),
+            })
+            .await;
+            self.dispatch_webhook_event(WebhookEventData::MatchFinished {
+                tournament_id: tournament.get_id(),
+                tournament_name: tournament.get_name().to_string(),
+                stage_number: stage.get_number(),
+                result,
+            })
+            .await;
+        }
+        let next_round = self
+            .advance_round(&tournament, &stage, &config, &m, &matches)
+            .await?;
+        tracing::info!(
+            tournament_id = %tournament.get_id(),
+            %match_id,
+            num_resolved = resolved.len(),
+            num_next_round = next_round.len(),
+            "match_result_entered"
+        );
+        Ok(Some(m))
+    }
+
+    /// Publish updates of the matches `saved` with their new versions to the clients of
+    /// their groups.
+    pub(crate) async fn publish_saved_matches(&self, saved: &[Match]) -> CoreResult<()> {
+        for m in saved {
+            let notice = CrTopic::GroupMatches {
+                group_id: *m.get_group_id(),
+            };
+            let msg = CrMsg::MatchUpdated {
+                id: *m.get_id(),
+                version: m.get_version().unwrap_or_default(),
+            };
+            self.client_registry.publish(notice, msg).await?;
+        }
+        Ok(())
+    }
+
+    /// Result of the decided match `m` with the names of its entrants for notifications.
+    pub(crate) async fn webhook_match_result(&self, m: &Match) -> CoreResult<WebhookMatchResult> {
+        let (side_a, side_b) = m.get_sides();
+        Ok(WebhookMatchResult {
+            number: m.get_number(),
+            side_a: self.side_display_name(side_a).await?,
+            side_b: self.side_display_name(side_b).await?,
+            result: format_match_result(m),
+        })
+    }
+
+    /// Name of the entrant of `side`; sides, which are not yet resolved to an entrant, are
+    /// `TBD`.
+    async fn side_display_name(&self, side: &ScheduledEntrant) -> CoreResult<String> {
+        let name = match side {
+            ScheduledEntrant::Entrant(entrant_id) => self
+                .database
+                .get_entrant(*entrant_id)
+                .await?
+                .map(|e| e.get_name().to_string()),
+            ScheduledEntrant::Bye => Some("Bye".to_string()),
+            _ => None,
+        };
+        Ok(name.unwrap_or_else(|| "TBD".to_string()))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn given_malformed_result_when_check_match_result_then_errors() {
+        let m = Match::new_played(
+            Uuid::new_v4(),
+            Uuid::new_v4(),
+            Uuid::new_v4(),
+            Uuid::nil(),
+            vec![],
+            vec![],
+        );
+        assert!(check_match_result(&m, &[11, 11], &[5, 7]).is_ok());
+        assert!(check_match_result(&m, &[], &[]).is_err());
+        assert!(check_match_result(&m, &[11, 11], &[5]).is_err());
+    }
+
+    #[test]
+    fn given_played_and_walkover_when_format_match_result_then_sets_or_outcome() {
+        let played = Match::new_played(
+            Uuid::new_v4(),
+            Uuid::new_v4(),
+            Uuid::new_v4(),
+            Uuid::nil(),
+            vec![11, 8, 11],
+            vec![9, 11, 5],
+        );
+        assert_eq!(format_match_result(&played), "11:9, 8:11, 11:5");
+
+        let forfeit = Match::new_walkover(
+            Uuid::new_v4(),
+            Uuid::new_v4(),
+            Uuid::new_v4(),
+            Uuid::nil(),
+            MatchOutcome::ForfeitA,
+        );
+        assert_eq!(format_match_result(&forfeit), "Forfeit by A");
+    }
+}
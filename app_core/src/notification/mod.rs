@@ -0,0 +1,202 @@
+//! email notifications of entrants
+//!
+//! Entrants with an email address are notified about their registration, the publication of
+//! the schedule, changed start times of their matches and calls of their matches to the
+//! stations. The content of each email is rendered from the [`EmailTemplate`] of its
+//! [`NotificationKind`].
+//!
+//! Notifications are best effort: failures of the email port are logged and never fail the
+//! operation, which triggered the notification.
+
+mod templates;
+
+pub use templates::*;
+
+use crate::{Core, CourtCall, EmailMessage, Entrant, TournamentBase};
+use chrono::{DateTime, Local};
+use tracing::{info, warn};
+use uuid::Uuid;
+
+/// Changed start time of a match, which is notified to the entrants of the match.
+#[derive(Debug, Clone, PartialEq)]
+pub struct MatchTimeChange {
+    pub tournament_id: Uuid,
+    /// number of match as shown in schedule
+    pub match_number: u32,
+    /// entrants playing the match
+    pub entrant_ids: Vec<Uuid>,
+    pub old_start: DateTime<Local>,
+    pub new_start: DateTime<Local>,
+    /// station of the match, if already assigned
+    pub station: Option<u32>,
+}
+
+const START_TIME_FORMAT: &str = "%Y-%m-%d %H:%M";
+
+impl<S> Core<S> {
+    /// Send the registration confirmation to `entrant`, if it has an email address.
+    /// Returns the number of sent emails.
+    pub async fn notify_registration_confirmed(&self, entrant: &Entrant) -> usize {
+        let Some(tournament) = self
+            .notification_tournament(entrant.get_tournament_id())
+            .await
+        else {
+            return 0;
+        };
+        let values = [
+            ("tournament", tournament.get_name()),
+            ("entrant", entrant.get_name()),
+        ];
+        self.send_notification(NotificationKind::RegistrationConfirmed, entrant, &values)
+            .await as usize
+    }
+
+    /// Notify all entrants of `tournament` with an email address about the publication of
+    /// the schedule. Returns the number of sent emails.
+    pub async fn notify_schedule_published(&self, tournament: &TournamentBase) -> usize {
+        let entrants = match self
+            .database
+            .list_entrants_of_tournament(tournament.get_id())
+            .await
+        {
+            Ok(entrants) => entrants,
+            Err(e) => {
+                warn!(error = %e, tournament_id = %tournament.get_id(), "notification_entrants_not_loaded");
+                return 0;
+            }
+        };
+        let mut sent = 0;
+        for entrant in entrants.iter() {
+            let values = [
+                ("tournament", tournament.get_name()),
+                ("entrant", entrant.get_name()),
+            ];
+            if self
+                .send_notification(NotificationKind::SchedulePublished, entrant, &values)
+                .await
+            {
+                sent += 1;
+            }
+        }
+        sent
+    }
+
+    /// Notify the entrants of a match about its changed start time. Returns the number of
+    /// sent emails.
+    pub async fn notify_match_time_changed(&self, change: &MatchTimeChange) -> usize {
+        let Some(tournament) = self.notification_tournament(change.tournament_id).await else {
+            return 0;
+        };
+        let match_number = change.match_number.to_string();
+        let old_start = change.old_start.format(START_TIME_FORMAT).to_string();
+        let new_start = change.new_start.format(START_TIME_FORMAT).to_string();
+        let station = change
+            .station
+            .map(|s| format!(" at station {s}"))
+            .unwrap_or_default();
+        let mut sent = 0;
+        for entrant_id in change.entrant_ids.iter() {
+            let entrant = match self.database.get_entrant(*entrant_id).await {
+                Ok(Some(entrant)) if entrant.get_tournament_id() == change.tournament_id => entrant,
+                Ok(_) => {
+                    warn!(entrant_id = %entrant_id, "notification_entrant_not_found");
+                    continue;
+                }
+                Err(e) => {
+                    warn!(error = %e, entrant_id = %entrant_id, "notification_entrant_not_loaded");
+                    continue;
+                }
+            };
+            let values = [
+                ("tournament", tournament.get_name()),
+                ("entrant", entrant.get_name()),
+                ("match", match_number.as_str()),
+                ("old_start", old_start.as_str()),
+                ("new_start", new_start.as_str()),
+                ("station", station.as_str()),
+            ];
+            if self
+                .send_notification(NotificationKind::MatchTimeChanged, &entrant, &values)
+                .await
+            {
+                sent += 1;
+            }
+        }
+        sent
+    }
+
+    /// Notify the entrants of a called match to come to its station. Returns the number of
+    /// sent emails.
+    pub async fn notify_court_call(&self, tournament: &TournamentBase, call: &CourtCall) -> usize {
+        let mut sent = 0;
+        for entrant_id in call.entrant_ids.iter() {
+            let entrant = match self.database.get_entrant(*entrant_id).await {
+                Ok(Some(entrant)) if entrant.get_tournament_id() == tournament.get_id() => entrant,
+                Ok(_) => {
+                    warn!(entrant_id = %entrant_id, "notification_entrant_not_found");
+                    continue;
+                }
+                Err(e) => {
+                    warn!(error = %e, entrant_id = %entrant_id, "notification_entrant_not_loaded");
+                    continue;
+                }
+            };
+            let values = [
+                ("tournament", tournament.get_name()),
+                ("entrant", entrant.get_name()),
+                ("station", call.station_name.as_str()),
+            ];
+            if self
+                .send_notification(NotificationKind::CourtCall, &entrant, &values)
+                .await
+            {
+                sent += 1;
+            }
+        }
+        sent
+    }
+
+    async fn notification_tournament(&self, tournament_id: Uuid) -> Option<TournamentBase> {
+        match self.database.get_tournament_base(tournament_id).await {
+            Ok(Some(tournament)) => Some(tournament),
+            Ok(None) => {
+                warn!(tournament_id = %tournament_id, "notification_tournament_not_found");
+                None
+            }
+            Err(e) => {
+                warn!(error = %e, tournament_id = %tournament_id, "notification_tournament_not_loaded");
+                None
+            }
+        }
+    }
+
+    /// Render the template of `kind` and send it to `entrant`. Entrants without email address
+    /// are skipped. Returns true, if the email was sent.
+    async fn send_notification(
+        &self,
+        kind: NotificationKind,
+        entrant: &Entrant,
+        values: &[(&str, &str)],
+    ) -> bool {
+        let Some(to) = entrant.get_email() else {
+            return false;
+        };
+        let (subject, body) = kind.template().render(values);
+        let message = EmailMessage {
+            to: to.to_string(),
+            to_name: Some(entrant.get_name().to_string()),
+            subject,
+            body,
+        };
+        match self.email.send_email(&message).await {
+            Ok(()) => {
+                info!(kind = %kind, entrant_id = %entrant.get_id(), "notification_sent");
+                true
+            }
+            Err(e) => {
+                warn!(error = %e, kind = %kind, entrant_id = %entrant.get_id(), "notification_not_sent");
+                false
+            }
+        }
+    }
+}
@@ -0,0 +1,121 @@
+//! templated content of notification emails
+
+use std::fmt::{self, Display};
+
+/// Kinds of notification emails sent to entrants.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub enum NotificationKind {
+    /// entrant was registered at a tournament
+    RegistrationConfirmed,
+    /// tournament and its schedule were published
+    SchedulePublished,
+    /// start time of a match of the entrant changed
+    MatchTimeChanged,
+    /// match of the entrant was called to its station
+    CourtCall,
+}
+
+impl NotificationKind {
+    /// Get the template of this kind.
+    pub fn template(&self) -> &'static EmailTemplate {
+        match self {
+            NotificationKind::RegistrationConfirmed => &REGISTRATION_CONFIRMED_TEMPLATE,
+            NotificationKind::SchedulePublished => &SCHEDULE_PUBLISHED_TEMPLATE,
+            NotificationKind::MatchTimeChanged => &MATCH_TIME_CHANGED_TEMPLATE,
+            NotificationKind::CourtCall => &COURT_CALL_TEMPLATE,
+        }
+    }
+}
+
+impl Display for NotificationKind {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let name = match self {
+            NotificationKind::RegistrationConfirmed => "registration_confirmed",
+            NotificationKind::SchedulePublished => "schedule_published",
+            NotificationKind::MatchTimeChanged => "match_time_changed",
+            NotificationKind::CourtCall => "court_call",
+        };
+        write!(f, "{name}")
+    }
+}
+
+/// Subject and plain text body of an email with placeholders `{name}`.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct EmailTemplate {
+    pub subject: &'static str,
+    pub body: &'static str,
+}
+
+impl EmailTemplate {
+    /// Render subject and body by replacing placeholders with `values`. Placeholders
+    /// without value are kept, so missing values are visible in the email.
+    pub fn render(&self, values: &[(&str, &str)]) -> (String, String) {
+        (
+            render_placeholders(self.subject, values),
+            render_placeholders(self.body, values),
+        )
+    }
+}
+
+fn render_placeholders(template: &str, values: &[(&str, &str)]) -> String {
+    let mut out = template.to_string();
+    for (name, value) in values {
+        out = out.replace(&format!("{{{name}}}"), value);
+    }
+    out
+}
+
+pub const REGISTRATION_CONFIRMED_TEMPLATE: EmailTemplate = EmailTemplate {
+    subject: "Registration confirmed: {tournament}",
+    body: "Hello {entrant},\n\
+           \n\
+           you are registered at the tournament {tournament}.\n\
+           We will send you another email, when the schedule is published.\n",
+};
+
+pub const SCHEDULE_PUBLISHED_TEMPLATE: EmailTemplate = EmailTemplate {
+    subject: "Schedule published: {tournament}",
+    body: "Hello {entrant},\n\
+           \n\
+           the schedule of the tournament {tournament} is published.\n\
+           You can follow the tournament on its public page.\n",
+};
+
+pub const MATCH_TIME_CHANGED_TEMPLATE: EmailTemplate = EmailTemplate {
+    subject: "Match time changed: {tournament}",
+    body: "Hello {entrant},\n\
+           \n\
+           match {match} of the tournament {tournament} was moved\n\
+           from {old_start} to {new_start}{station}.\n",
+};
+
+pub const COURT_CALL_TEMPLATE: EmailTemplate = EmailTemplate {
+    subject: "Your match is called: {tournament}",
+    body: "Hello {entrant},\n\
+           \n\
+           your match of the tournament {tournament} is called.\n\
+           Please come to {station} now.\n",
+};
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn render_replaces_all_placeholders() {
+        let (subject, body) = NotificationKind::RegistrationConfirmed
+            .template()
+            .render(&[("tournament", "Summer Cup"), ("entrant", "Team A")]);
+        assert_eq!(subject, "Registration confirmed: Summer Cup");
+        assert!(
+            body.starts_with("Hello Team A,\n\nyou are registered at the tournament Summer Cup.\n")
+        );
+    }
+
+    #[test]
+    fn render_keeps_unknown_placeholders() {
+        let (_, body) = SCHEDULE_PUBLISHED_TEMPLATE.render(&[("entrant", "Team A")]);
+        assert!(body.contains("{tournament}"));
+        assert!(body.starts_with("Hello Team A,"));
+    }
+}
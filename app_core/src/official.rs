@@ -0,0 +1,541 @@
+//! officials of a tournament, e.g. referees, and their assignment to matches
+//!
+//! [`assign_officials`] assigns one official to each match. An official is only assigned to a
+//! match, if
+//! - the official has the required qualification, if any,
+//! - the official is available for the whole match,
+//! - the official does not officiate another match at the same time and
+//! - the official is not of the same club as one of the entrants of the match (conflict of
+//!   interest).
+//!
+//! Of all eligible officials the official with the fewest assigned matches is chosen, which
+//! balances the workload. Matches are assigned in order of their start.
+
+use crate::{
+    AuditObjectType, Core, CoreResult, CrMsg, CrTopic, Entrant, Match,
+    utils::{id_version::IdVersion, normalize::*, traits::ObjectIdVersion, validation::*},
+};
+use chrono::{DateTime, Duration, Utc};
+use serde::{Deserialize, Serialize};
+use std::collections::{HashMap, HashSet};
+use uuid::Uuid;
+
+/// time span, in which an official is available
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Serialize, Deserialize)]
+pub struct AvailabilityWindow {
+    /// start of window
+    pub start: DateTime<Utc>,
+    /// end of window
+    pub end: DateTime<Utc>,
+}
+
+impl AvailabilityWindow {
+    /// Create a new availability window.
+    pub fn new(start: DateTime<Utc>, end: DateTime<Utc>) -> Self {
+        AvailabilityWindow { start, end }
+    }
+
+    /// Check if the window covers the time from `start` to `end`.
+    pub fn covers(&self, start: DateTime<Utc>, end: DateTime<Utc>) -> bool {
+        self.start <= start && end <= self.end
+    }
+}
+
+/// Official of a tournament, e.g. a referee or umpire.
+#[derive(Clone, Debug, PartialEq, Eq, Serialize, Deserialize, Default, ObjectIdVersion)]
+pub struct Official {
+    /// id and optimistic locking version of official
+    id_version: IdVersion,
+    /// id of tournament
+    tournament_id: Uuid,
+    /// name of official, unique per tournament
+    name: String,
+    /// optional club of official; officials do not officiate matches of their club
+    club: Option<String>,
+    /// qualifications of official, e.g. `national referee`
+    #[serde(default)]
+    qualifications: Vec<String>,
+    /// time spans, in which the official is available; always available, if empty
+    #[serde(default)]
+    availability: Vec<AvailabilityWindow>,
+}
+
+impl Official {
+    /// Create a new `Official` with the given `IdVersion`.
+    pub fn new(id_version: IdVersion) -> Self {
+        Official {
+            id_version,
+            ..Default::default()
+        }
+    }
+
+    /// Get the unique identifier of the official.
+    pub fn get_id(&self) -> Uuid {
+        self.id_version.get_id()
+    }
+
+    /// Get the version number of the official.
+    pub fn get_version(&self) -> Option<u32> {
+        self.id_version.get_version()
+    }
+
+    /// Get the tournament ID.
+    pub fn get_tournament_id(&self) -> Uuid {
+        self.tournament_id
+    }
+
+    /// Get the name of the official.
+    pub fn get_name(&self) -> &str {
+        &self.name
+    }
+
+    /// Get the club of the official.
+    pub fn get_club(&self) -> Option<&str> {
+        self.club.as_deref()
+    }
+
+    /// Get the qualifications of the official.
+    pub fn get_qualifications(&self) -> &[String] {
+        &self.qualifications
+    }
+
+    /// Get the time spans, in which the official is available.
+    pub fn get_availability(&self) -> &[AvailabilityWindow] {
+        &self.availability
+    }
+
+    /// Check if the official has qualification `qualification`; compared case insensitive.
+    pub fn has_qualification(&self, qualification: &str) -> bool {
+        let qualification = normalize_ws(qualification).to_lowercase();
+        self.qualifications
+            .iter()
+            .any(|q| q.to_lowercase() == qualification)
+    }
+
+    /// Check if the official is available for the whole time from `start` to `end`.
+    pub fn is_available(&self, start: DateTime<Utc>, end: DateTime<Utc>) -> bool {
+        self.availability.is_empty() || self.availability.iter().any(|w| w.covers(start, end))
+    }
+
+    /// Check if the official is of one of `clubs`; compared case insensitive.
+    pub fn is_of_club(&self, clubs: &[String]) -> bool {
+        self.club.as_ref().is_some_and(|club| {
+            clubs
+                .iter()
+                .any(|c| c.to_lowercase() == club.to_lowercase())
+        })
+    }
+
+    /// Set the `IdVersion` of the official.
+    pub fn set_id_version(&mut self, id_version: IdVersion) -> &mut Self {
+        self.id_version = id_version;
+        self
+    }
+
+    /// Set the tournament ID.
+    pub fn set_tournament_id(&mut self, tournament_id: Uuid) -> &mut Self {
+        self.tournament_id = tournament_id;
+        self
+    }
+
+    /// Set the name of the official with whitespace normalization.
+    pub fn set_name(&mut self, name: impl Into<String>) -> &mut Self {
+        self.name = normalize_ws(name);
+        self
+    }
+
+    /// Set the club of the official with whitespace normalization. Empty clubs are stored as
+    /// `None`.
+    pub fn set_club(&mut self, club: Option<impl Into<String>>) -> &mut Self {
+        self.club = club.map(normalize_ws).filter(|c| !c.is_empty());
+        self
+    }
+
+    /// Set the qualifications of the official with whitespace normalization. Empty and
+    /// repeated qualifications are dropped.
+    pub fn set_qualifications(
+        &mut self,
+        qualifications: impl IntoIterator<Item = impl Into<String>>,
+    ) -> &mut Self {
+        let mut seen = HashSet::new();
+        self.qualifications = qualifications
+            .into_iter()
+            .map(normalize_ws)
+            .filter(|q| !q.is_empty() && seen.insert(q.to_lowercase()))
+            .collect();
+        self
+    }
+
+    /// Set the time spans, in which the official is available, sorted by start.
+    pub fn set_availability(&mut self, mut availability: Vec<AvailabilityWindow>) -> &mut Self {
+        availability.sort_by_key(|w| w.start);
+        self.availability = availability;
+        self
+    }
+
+    /// Validate the official.
+    pub fn validate(&self) -> ValidationResult<()> {
+        let mut errs = ValidationErrors::new();
+        let object_id = self.get_id();
+
+        if self.name.is_empty() {
+            errs.add(
+                FieldError::builder()
+                    .set_field(String::from("name"))
+                    .add_required()
+                    .set_object_id(object_id)
+                    .build(),
+            );
+        }
+
+        for (index, window) in self.availability.iter().enumerate() {
+            if window.end <= window.start {
+                errs.add(
+                    FieldError::builder()
+                        .set_field(format!("availability.{}", index + 1))
+                        .add_message("end of availability must be after its start")
+                        .set_object_id(object_id)
+                        .build(),
+                );
+            }
+        }
+
+        if errs.is_empty() { Ok(()) } else { Err(errs) }
+    }
+}
+
+/// match, which needs an official
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct OfficiatedMatch {
+    pub match_id: Uuid,
+    pub start_at: DateTime<Utc>,
+    pub end_at: DateTime<Utc>,
+    /// clubs of both entrants of the match; officials of these clubs are not assigned
+    pub clubs: Vec<String>,
+}
+
+impl OfficiatedMatch {
+    /// Match `m` of estimated duration `duration`. Clubs are taken from `entrants`; sides,
+    /// which are not yet resolved to an entrant, have no club.
+    pub fn from_match(m: &Match, duration: Duration, entrants: &[Entrant]) -> Self {
+        let start_at = m.get_start_at().with_timezone(&Utc);
+        let clubs = m
+            .get_entrants()
+            .map(|(a, b)| {
+                entrants
+                    .iter()
+                    .filter(|e| e.get_id() == *a || e.get_id() == *b)
+                    .filter_map(|e| e.get_club().map(str::to_string))
+                    .collect()
+            })
+            .unwrap_or_default();
+        OfficiatedMatch {
+            match_id: *m.get_id(),
+            start_at,
+            end_at: start_at + duration,
+            clubs,
+        }
+    }
+
+    fn overlaps(&self, other: &OfficiatedMatch) -> bool {
+        self.start_at < other.end_at && other.start_at < self.end_at
+    }
+}
+
+/// official assigned to a match
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+pub struct OfficialAssignment {
+    pub match_id: Uuid,
+    pub official_id: Uuid,
+}
+
+/// number of matches assigned to an official
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct OfficialWorkload {
+    pub official_id: Uuid,
+    pub name: String,
+    pub num_matches: u32,
+}
+
+/// result of [`assign_officials`]
+#[derive(Debug, Clone, Default, PartialEq, Eq, Serialize, Deserialize)]
+pub struct OfficialAssignmentPlan {
+    /// assignments in order of the start of their matches
+    pub assignments: Vec<OfficialAssignment>,
+    /// matches, for which no eligible official was found
+    pub unassigned: Vec<Uuid>,
+    /// workload of all officials in order of their names
+    pub workload: Vec<OfficialWorkload>,
+}
+
+impl OfficialAssignmentPlan {
+    /// Get the official assigned to match `match_id`, if any.
+    pub fn official_of_match(&self, match_id: Uuid) -> Option<Uuid> {
+        self.assignments
+            .iter()
+            .find(|a| a.match_id == match_id)
+            .map(|a| a.official_id)
+    }
+}
+
+/// Assign one of `officials` to each of `matches`, see [module documentation](self).
+/// `required_qualification` restricts the assignment to officials with this qualification.
+pub fn assign_officials(
+    matches: &[OfficiatedMatch],
+    officials: &[Official],
+    required_qualification: Option<&str>,
+) -> OfficialAssignmentPlan {
+    let mut officials: Vec<&Official> = officials.iter().collect();
+    officials.sort_by_key(|o| (o.get_name().to_lowercase(), o.get_id()));
+    let mut matches: Vec<&OfficiatedMatch> = matches.iter().collect();
+    matches.sort_by_key(|m| (m.start_at, m.match_id));
+
+    let mut plan = OfficialAssignmentPlan::default();
+    let mut assigned: HashMap<Uuid, Vec<&OfficiatedMatch>> = HashMap::new();
+    for m in matches {
+        let chosen = officials
+            .iter()
+            .filter(|o| required_qualification.is_none_or(|q| o.has_qualification(q)))
+            .filter(|o| o.is_available(m.start_at, m.end_at))
+            .filter(|o| !o.is_of_club(&m.clubs))
+            .filter(|o| {
+                assigned
+                    .get(&o.get_id())
+                    .is_none_or(|busy| busy.iter().all(|b| !b.overlaps(m)))
+            })
+            // officials are sorted by name, therefore min_by_key keeps the first of equal load
+            .min_by_key(|o| assigned.get(&o.get_id()).map_or(0, Vec::len));
+        match chosen {
+            Some(official) => {
+                assigned.entry(official.get_id()).or_default().push(m);
+                plan.assignments.push(OfficialAssignment {
+                    match_id: m.match_id,
+                    official_id: official.get_id(),
+                });
+            }
+            None => plan.unassigned.push(m.match_id),
+        }
+    }
+    plan.workload = officials
+        .iter()
+        .map(|o| OfficialWorkload {
+            official_id: o.get_id(),
+            name: o.get_name().to_string(),
+            num_matches: assigned.get(&o.get_id()).map_or(0, Vec::len) as u32,
+        })
+        .collect();
+    plan
+}
+
+/// State for official operations of one tournament
+pub struct OfficialState {
+    tournament_id: Uuid,
+    official: Official,
+}
+
+// switch state to official state
+impl<S> Core<S> {
+    pub fn as_official_state(&self, tournament_id: Uuid) -> Core<OfficialState> {
+        let mut official = Official::new(IdVersion::NewWithId(Uuid::new_v4()));
+        official.set_tournament_id(tournament_id);
+        self.switch_state(OfficialState {
+            tournament_id,
+            official,
+        })
+    }
+}
+
+impl Core<OfficialState> {
+    pub fn get(&self) -> &Official {
+        &self.state.official
+    }
+    pub fn get_mut(&mut self) -> &mut Official {
+        &mut self.state.official
+    }
+    /// Load official `id` of the tournament. Officials of other tournaments are not loaded.
+    pub async fn load(&mut self, id: Uuid) -> CoreResult<Option<&Official>> {
+        match self
+            .database
+            .get_official(id)
+            .await?
+            .filter(|o| o.get_tournament_id() == self.state.tournament_id)
+        {
+            Some(official) => {
+                self.state.official = official;
+                Ok(Some(self.get()))
+            }
+            None => Ok(None),
+        }
+    }
+    pub async fn save(&mut self) -> CoreResult<&Official> {
+        self.state
+            .official
+            .set_tournament_id(self.state.tournament_id);
+        self.state.official.validate()?;
+        // stored copy before the save for the audit log
+        let old = match self.state.official.get_version() {
+            Some(_) => {
+                self.database
+                    .get_official(self.state.official.get_id())
+                    .await?
+            }
+            None => None,
+        };
+        self.state.official = self.database.save_official(&self.state.official).await?;
+
+        // publish change of official to client registry
+        let id = self.state.official.get_id();
+        let version = self
+            .state
+            .official
+            .get_version()
+            .expect("expecting save_official to return always an existing id and version");
+        let notice = CrTopic::Officials {
+            tournament_id: self.state.tournament_id,
+        };
+        let msg = CrMsg::OfficialUpdated { id, version };
+        self.client_registry.publish(notice, msg).await?;
+        self.audit_save(
+            AuditObjectType::Official,
+            Some(self.state.tournament_id),
+            old.as_ref(),
+            &self.state.official,
+        )
+        .await;
+        Ok(self.get())
+    }
+    /// List officials of tournament, sorted by name.
+    pub async fn list_officials(&self) -> CoreResult<Vec<Official>> {
+        let mut list = self
+            .database
+            .list_officials_of_tournament(self.state.tournament_id)
+            .await?;
+        list.sort_by_key(|o| o.get_name().to_lowercase());
+        Ok(list)
+    }
+    /// Assign the officials of the tournament to its scheduled matches, which are not decided
+    /// yet, see [`assign_officials`].
+    pub async fn plan_assignments(
+        &self,
+        required_qualification: Option<&str>,
+    ) -> CoreResult<OfficialAssignmentPlan> {
+        let officials = self.list_officials().await?;
+        let tournament_id = self.state.tournament_id;
+        let matches = match self.database.get_tournament_base(tournament_id).await? {
+            Some(tournament) => {
+                let timing = self.estimate_match_timing_or_fallback(&tournament).await?;
+                let duration =
+                    Duration::from_std(timing.match_duration).unwrap_or_else(|_| Duration::zero());
+                let entrants = self
+                    .database
+                    .list_entrants_of_tournament(tournament_id)
+                    .await?;
+                self.database
+                    .list_matches_of_tournament(tournament_id)
+                    .await?
+                    .iter()
+                    // decided matches, including byes, need no official anymore
+                    .filter(|m| !m.is_decided())
+                    .map(|m| OfficiatedMatch::from_match(m, duration, &entrants))
+                    .collect()
+            }
+            None => Vec::new(),
+        };
+        Ok(assign_officials(
+            &matches,
+            &officials,
+            required_qualification,
+        ))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use chrono::TimeZone;
+
+    fn at(hour: u32) -> DateTime<Utc> {
+        Utc.with_ymd_and_hms(2026, 5, 1, hour, 0, 0).unwrap()
+    }
+
+    fn official(name: &str, club: Option<&str>) -> Official {
+        let mut official = Official::new(IdVersion::new(Uuid::new_v4(), Some(0)));
+        official.set_name(name).set_club(club);
+        official
+    }
+
+    fn officiated(start_hour: u32, clubs: &[&str]) -> OfficiatedMatch {
+        OfficiatedMatch {
+            match_id: Uuid::new_v4(),
+            start_at: at(start_hour),
+            end_at: at(start_hour + 1),
+            clubs: clubs.iter().map(|c| c.to_string()).collect(),
+        }
+    }
+
+    #[test]
+    fn given_parallel_matches_when_assign_then_workload_is_balanced() {
+        let officials = [official("Anna", None), official("Ben", None)];
+        let matches = [
+            officiated(9, &[]),
+            officiated(9, &[]),
+            officiated(10, &[]),
+            officiated(11, &[]),
+        ];
+
+        let plan = assign_officials(&matches, &officials, None);
+
+        assert!(plan.unassigned.is_empty());
+        // parallel matches need different officials
+        assert_ne!(
+            plan.official_of_match(matches[0].match_id),
+            plan.official_of_match(matches[1].match_id)
+        );
+        let loads: Vec<_> = plan.workload.iter().map(|w| w.num_matches).collect();
+        assert_eq!(loads, vec![2, 2]);
+    }
+
+    #[test]
+    fn given_conflict_of_interest_when_assign_then_official_of_other_club_is_chosen() {
+        let officials = [
+            official("Anna", Some("TV Nord")),
+            official("Ben", Some("SV Süd")),
+        ];
+        let matches = [officiated(9, &["tv nord", "TSV Ost"])];
+
+        let plan = assign_officials(&matches, &officials, None);
+
+        assert_eq!(
+            plan.official_of_match(matches[0].match_id),
+            Some(officials[1].get_id())
+        );
+    }
+
+    #[test]
+    fn given_unavailable_or_unqualified_officials_when_assign_then_match_is_unassigned() {
+        let mut anna = official("Anna", None);
+        anna.set_availability(vec![AvailabilityWindow::new(at(8), at(10))])
+            .set_qualifications(["National Referee"]);
+        let ben = official("Ben", None);
+        let matches = [officiated(9, &[]), officiated(10, &[])];
+
+        let plan = assign_officials(&matches, &[anna.clone(), ben], Some("national  referee"));
+
+        assert_eq!(
+            plan.official_of_match(matches[0].match_id),
+            Some(anna.get_id())
+        );
+        assert_eq!(plan.unassigned, vec![matches[1].match_id]);
+    }
+
+    #[test]
+    fn given_empty_name_and_inverted_window_when_validate_then_all_errors_are_collected() {
+        let mut official = official(" ", None);
+        official.set_availability(vec![AvailabilityWindow::new(at(12), at(10))]);
+
+        let errs = official.validate().unwrap_err();
+
+        let fields: Vec<_> = errs.errors.iter().map(|e| e.get_field()).collect();
+        assert_eq!(fields, vec!["name", "availability.1"]);
+    }
+}
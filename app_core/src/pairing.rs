@@ -0,0 +1,820 @@
+//! manual override of generated pairings
+//!
+//! Directors may adjust the generated pairings of a round before it starts. Each adjustment
+//! swaps the places of two entrants in the pairings, which swaps opponents or moves a bye to
+//! another entrant. Adjusted pairings are re-validated against the constraints of the pairing
+//! engine (no rematches, bye limit). Violations are reported as [`PairingWarning`]s and do not
+//! block the override.
+//!
+//! Overrides are persisted append only with an audit note. The pairing engine re-applies all
+//! overrides of a round in order of creation on top of its generated pairings, see
+//! [`Core::apply_pairing_overrides`].
+
+use crate::{
+    AuditObjectType, Core, CoreError, CoreResult, Match, TournamentState, round_ids,
+    slots::ring_rounds,
+    utils::{id_version::IdVersion, normalize::*, traits::ObjectIdVersion, validation::*},
+};
+use chrono::{DateTime, Utc};
+use serde::{Deserialize, Serialize};
+use std::{
+    collections::{HashMap, HashSet},
+    fmt::{self, Display},
+};
+use uuid::Uuid;
+
+/// maximum number of byes (free wins) of an entrant in a stage
+pub const PAIRING_BYE_LIMIT: u32 = 1;
+
+/// maximum length of the audit note of a pairing override in characters
+pub const PAIRING_NOTE_MAX_LEN: usize = 1000;
+
+/// pairing of a round; an entrant without opponent has a bye
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+pub struct Pairing {
+    pub entrant_a: Uuid,
+    pub entrant_b: Option<Uuid>,
+}
+
+impl Pairing {
+    pub fn contains(&self, entrant_id: Uuid) -> bool {
+        self.entrant_a == entrant_id || self.entrant_b == Some(entrant_id)
+    }
+
+    /// Replace `entrant_id` with `other`. Returns false, if `entrant_id` is not part of pairing.
+    fn replace(&mut self, entrant_id: Uuid, other: Uuid) -> bool {
+        if self.entrant_a == entrant_id {
+            self.entrant_a = other;
+        } else if self.entrant_b == Some(entrant_id) {
+            self.entrant_b = Some(other);
+        } else {
+            return false;
+        }
+        true
+    }
+}
+
+/// manual adjustment of pairings: `first` and `second` swap their places
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+pub struct PairingAdjustment {
+    pub first: Uuid,
+    pub second: Uuid,
+}
+
+/// constraint of the pairing engine, which is violated by adjusted pairings
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+pub enum PairingWarning {
+    /// entrants already played each other in this stage
+    Rematch { entrant_a: Uuid, entrant_b: Uuid },
+    /// entrant gets more byes than [`PAIRING_BYE_LIMIT`]
+    ByeLimitExceeded { entrant_id: Uuid, byes: u32 },
+}
+
+impl Display for PairingWarning {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            PairingWarning::Rematch {
+                entrant_a,
+                entrant_b,
+            } => write!(f, "{entrant_a} and {entrant_b} already played each other"),
+            PairingWarning::ByeLimitExceeded { entrant_id, byes } => write!(
+                f,
+                "{entrant_id} gets bye number {byes}, only {PAIRING_BYE_LIMIT} allowed"
+            ),
+        }
+    }
+}
+
+/// played matches and byes of a stage, which constrain pairings of next round
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct PairingHistory {
+    /// pairs of opponents with smaller id first
+    opponents: HashSet<(Uuid, Uuid)>,
+    byes: HashMap<Uuid, u32>,
+}
+
+impl PairingHistory {
+    /// Create history from matches of the stage. Byes must be added with [`Self::add_bye`].
+    pub fn from_matches(matches: &[Match]) -> Self {
+        let mut history = PairingHistory::default();
+        for (a, b) in matches.iter().filter_map(|m| m.get_entrants()) {
+            history.add_match(*a, *b);
+        }
+        history
+    }
+
+    pub fn add_match(&mut self, entrant_a: Uuid, entrant_b: Uuid) -> &mut Self {
+        self.opponents.insert(ordered(entrant_a, entrant_b));
+        self
+    }
+
+    pub fn add_bye(&mut self, entrant_id: Uuid) -> &mut Self {
+        *self.byes.entry(entrant_id).or_default() += 1;
+        self
+    }
+
+    pub fn have_played(&self, entrant_a: Uuid, entrant_b: Uuid) -> bool {
+        self.opponents.contains(&ordered(entrant_a, entrant_b))
+    }
+
+    pub fn get_byes(&self, entrant_id: Uuid) -> u32 {
+        self.byes.get(&entrant_id).copied().unwrap_or_default()
+    }
+}
+
+fn ordered(a: Uuid, b: Uuid) -> (Uuid, Uuid) {
+    if a <= b { (a, b) } else { (b, a) }
+}
+
+/// pairings of one round
+#[derive(Debug, Clone, Default, PartialEq, Eq, Serialize, Deserialize)]
+pub struct RoundPairings {
+    pub pairings: Vec<Pairing>,
+}
+
+impl RoundPairings {
+    pub fn new(pairings: Vec<Pairing>) -> Self {
+        RoundPairings { pairings }
+    }
+
+    pub fn contains(&self, entrant_id: Uuid) -> bool {
+        self.pairings.iter().any(|p| p.contains(entrant_id))
+    }
+
+    /// Apply `adjustment`. Returns false and keeps pairings unchanged, if one of the entrants
+    /// is not part of the pairings.
+    pub fn apply(&mut self, adjustment: &PairingAdjustment) -> bool {
+        let PairingAdjustment { first, second } = *adjustment;
+        if !self.contains(first) || !self.contains(second) {
+            return false;
+        }
+        if first == second {
+            return true;
+        }
+        // swap with placeholder, because both entrants may be part of the same pairing
+        let placeholder = Uuid::nil();
+        for (from, to) in [(first, placeholder), (second, first), (placeholder, second)] {
+            for pairing in self.pairings.iter_mut() {
+                if pairing.replace(from, to) {
+                    break;
+                }
+            }
+        }
+        true
+    }
+
+    /// Check pairings against constraints of the pairing engine.
+    pub fn check(&self, history: &PairingHistory) -> Vec<PairingWarning> {
+        self.pairings
+            .iter()
+            .filter_map(|p| match p.entrant_b {
+                Some(entrant_b) if history.have_played(p.entrant_a, entrant_b) => {
+                    Some(PairingWarning::Rematch {
+                        entrant_a: p.entrant_a,
+                        entrant_b,
+                    })
+                }
+                Some(_) => None,
+                None => {
+                    let byes = history.get_byes(p.entrant_a) + 1;
+                    (byes > PAIRING_BYE_LIMIT).then_some(PairingWarning::ByeLimitExceeded {
+                        entrant_id: p.entrant_a,
+                        byes,
+                    })
+                }
+            })
+            .collect()
+    }
+}
+
+/// Pairings of all rounds of a ring system stage. `entrants` are placed in the ring in the
+/// given order, usually by seeding, and play their `num_neighbors` next neighbors on each side.
+/// Entrants without pairing in a round pause; pauses are no byes.
+pub fn ring_pairings(entrants: &[Uuid], num_neighbors: u32) -> Vec<RoundPairings> {
+    ring_rounds(entrants.len() as u32, num_neighbors)
+        .into_iter()
+        .map(|round| {
+            RoundPairings::new(
+                round
+                    .into_iter()
+                    .map(|(a, b)| Pairing {
+                        entrant_a: entrants[a as usize - 1],
+                        entrant_b: Some(entrants[b as usize - 1]),
+                    })
+                    .collect(),
+            )
+        })
+        .collect()
+}
+
+/// maximum number of tried pairings, before Swiss pairing falls back to pairing neighbors
+/// in the ranking without avoiding rematches
+pub const SWISS_PAIRING_STEP_LIMIT: u32 = 10_000;
+
+/// Swiss pairings of the next round. `ranking` holds the entrants sorted by current rank.
+/// With an odd number of entrants the lowest ranked entrant with fewest byes gets a bye. Each
+/// entrant from the top of the ranking plays the next best entrant, which it has not played
+/// yet. If no pairings without rematches are found within [`SWISS_PAIRING_STEP_LIMIT`]
+/// steps, neighbors in the ranking are paired.
+pub fn swiss_pairings(ranking: &[Uuid], history: &PairingHistory) -> RoundPairings {
+    let mut remaining = ranking.to_vec();
+    let bye = if remaining.len() % 2 == 1 {
+        let fewest_byes = remaining
+            .iter()
+            .map(|e| history.get_byes(*e))
+            .min()
+            .unwrap_or_default();
+        let index = remaining
+            .iter()
+            .rposition(|e| history.get_byes(*e) == fewest_byes)
+            .unwrap_or_default();
+        Some(remaining.remove(index))
+    } else {
+        None
+    };
+
+    let mut steps = SWISS_PAIRING_STEP_LIMIT;
+    let pairs = pair_without_rematches(&remaining, history, &mut steps)
+        .unwrap_or_else(|| remaining.chunks(2).map(|pair| (pair[0], pair[1])).collect());
+    let mut pairings: Vec<Pairing> = pairs
+        .into_iter()
+        .map(|(entrant_a, entrant_b)| Pairing {
+            entrant_a,
+            entrant_b: Some(entrant_b),
+        })
+        .collect();
+    if let Some(entrant_a) = bye {
+        pairings.push(Pairing {
+            entrant_a,
+            entrant_b: None,
+        });
+    }
+    RoundPairings::new(pairings)
+}
+
+/// Pair the best ranked of `entrants` with the next best entrant, which it has not played
+/// yet, and the remaining entrants recursively. Backtracks, if the remaining entrants cannot
+/// be paired.
+fn pair_without_rematches(
+    entrants: &[Uuid],
+    history: &PairingHistory,
+    steps: &mut u32,
+) -> Option<Vec<(Uuid, Uuid)>> {
+    let Some((first, others)) = entrants.split_first() else {
+        return Some(vec![]);
+    };
+    for (index, opponent) in others.iter().enumerate() {
+        if history.have_played(*first, *opponent) {
+            continue;
+        }
+        if *steps == 0 {
+            return None;
+        }
+        *steps -= 1;
+        let mut rest = others.to_vec();
+        rest.remove(index);
+        if let Some(mut pairs) = pair_without_rematches(&rest, history, steps) {
+            pairs.insert(0, (*first, *opponent));
+            return Some(pairs);
+        }
+    }
+    None
+}
+
+/// Persisted manual override of the pairings of a round.
+#[derive(Clone, Debug, PartialEq, Eq, Serialize, Deserialize, Default, ObjectIdVersion)]
+pub struct PairingOverride {
+    /// id and version of override; overrides are never updated
+    id_version: IdVersion,
+    tournament_id: Uuid,
+    stage_id: Uuid,
+    /// round number in stage
+    round_number: u32,
+    /// adjustments in order of application
+    adjustments: Vec<PairingAdjustment>,
+    /// audit note: why pairings were adjusted
+    note: String,
+    /// name of director, who adjusted pairings
+    author: String,
+    /// timestamp of creation; set by database
+    created_at: Option<DateTime<Utc>>,
+}
+
+impl PairingOverride {
+    pub fn new(id_version: IdVersion) -> Self {
+        PairingOverride {
+            id_version,
+            ..Default::default()
+        }
+    }
+
+    pub fn get_id(&self) -> Uuid {
+        self.id_version.get_id()
+    }
+
+    pub fn get_version(&self) -> Option<u32> {
+        self.id_version.get_version()
+    }
+
+    pub fn get_tournament_id(&self) -> Uuid {
+        self.tournament_id
+    }
+
+    pub fn get_stage_id(&self) -> Uuid {
+        self.stage_id
+    }
+
+    pub fn get_round_number(&self) -> u32 {
+        self.round_number
+    }
+
+    pub fn get_adjustments(&self) -> &[PairingAdjustment] {
+        &self.adjustments
+    }
+
+    pub fn get_note(&self) -> &str {
+        &self.note
+    }
+
+    pub fn get_author(&self) -> &str {
+        &self.author
+    }
+
+    pub fn get_created_at(&self) -> Option<DateTime<Utc>> {
+        self.created_at
+    }
+
+    pub fn set_id_version(&mut self, id_version: IdVersion) -> &mut Self {
+        self.id_version = id_version;
+        self
+    }
+
+    pub fn set_tournament_id(&mut self, tournament_id: Uuid) -> &mut Self {
+        self.tournament_id = tournament_id;
+        self
+    }
+
+    pub fn set_stage_id(&mut self, stage_id: Uuid) -> &mut Self {
+        self.stage_id = stage_id;
+        self
+    }
+
+    pub fn set_round_number(&mut self, round_number: u32) -> &mut Self {
+        self.round_number = round_number;
+        self
+    }
+
+    pub fn set_adjustments(&mut self, adjustments: Vec<PairingAdjustment>) -> &mut Self {
+        self.adjustments = adjustments;
+        self
+    }
+
+    pub fn add_adjustment(&mut self, first: Uuid, second: Uuid) -> &mut Self {
+        self.adjustments.push(PairingAdjustment { first, second });
+        self
+    }
+
+    /// Set the audit note. Line breaks are kept, only leading and trailing whitespace is removed.
+    pub fn set_note(&mut self, note: impl Into<String>) -> &mut Self {
+        self.note = note.into().trim().to_string();
+        self
+    }
+
+    /// Set the name of the author with whitespace normalization.
+    pub fn set_author(&mut self, author: impl Into<String>) -> &mut Self {
+        self.author = normalize_ws(author);
+        self
+    }
+
+    /// Set the timestamp of creation. Only used by database adapters.
+    pub fn set_created_at(&mut self, created_at: Option<DateTime<Utc>>) -> &mut Self {
+        self.created_at = created_at;
+        self
+    }
+
+    pub fn validate(&self) -> ValidationResult<()> {
+        let mut errs = ValidationErrors::new();
+        let object_id = self.get_id();
+
+        for (field, id) in [
+            ("tournament_id", self.tournament_id),
+            ("stage_id", self.stage_id),
+        ] {
+            if id.is_nil() {
+                errs.add(
+                    FieldError::builder()
+                        .set_field(String::from(field))
+                        .add_required()
+                        .set_object_id(object_id)
+                        .build(),
+                );
+            }
+        }
+        if self.adjustments.is_empty() {
+            errs.add(
+                FieldError::builder()
+                    .set_field(String::from("adjustments"))
+                    .add_required()
+                    .set_object_id(object_id)
+                    .build(),
+            );
+        }
+        if self.author.is_empty() {
+            errs.add(
+                FieldError::builder()
+                    .set_field(String::from("author"))
+                    .add_required()
+                    .set_object_id(object_id)
+                    .build(),
+            );
+        }
+        if self.note.is_empty() {
+            errs.add(
+                FieldError::builder()
+                    .set_field(String::from("note"))
+                    .add_required()
+                    .set_object_id(object_id)
+                    .build(),
+            );
+        } else if self.note.chars().count() > PAIRING_NOTE_MAX_LEN {
+            errs.add(
+                FieldError::builder()
+                    .set_field(String::from("note"))
+                    .add_user_defined_code("too_long")
+                    .add_message(format!(
+                        "Note must not exceed {PAIRING_NOTE_MAX_LEN} characters"
+                    ))
+                    .set_object_id(object_id)
+                    .build(),
+            );
+        }
+
+        if errs.is_empty() { Ok(()) } else { Err(errs) }
+    }
+}
+
+/// pairings after a manual override
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct AdjustedPairings {
+    pub pairings: RoundPairings,
+    /// violated constraints; warnings do not block the override
+    pub warnings: Vec<PairingWarning>,
+    /// persisted override
+    pub pairing_override: PairingOverride,
+}
+
+impl<S> Core<S> {
+    /// Apply `pairing_override` to `pairings`, check the adjusted pairings against `history`
+    /// and persist the override. Overrides of started rounds are rejected, see
+    /// [`is_round_started`].
+    pub async fn override_pairings(
+        &self,
+        pairings: &RoundPairings,
+        history: &PairingHistory,
+        pairing_override: &PairingOverride,
+    ) -> CoreResult<AdjustedPairings> {
+        pairing_override.validate()?;
+        self.ensure_pairings_adjustable(pairing_override).await?;
+
+        let mut adjusted = pairings.clone();
+        for adjustment in pairing_override.get_adjustments() {
+            if !adjusted.apply(adjustment) {
+                let unknown = if adjusted.contains(adjustment.first) {
+                    adjustment.second
+                } else {
+                    adjustment.first
+                };
+                return Err(pairing_error(
+                    pairing_override,
+                    "adjustments",
+                    format!("Entrant {unknown} is not part of the pairings of this round"),
+                ));
+            }
+        }
+        let warnings = adjusted.check(history);
+
+        let pairing_override = self
+            .database
+            .save_pairing_override(pairing_override)
+            .await?;
+        // overrides are append only
+        self.audit_save(
+            AuditObjectType::PairingOverride,
+            Some(pairing_override.get_tournament_id()),
+            None,
+            &pairing_override,
+        )
+        .await;
+        Ok(AdjustedPairings {
+            pairings: adjusted,
+            warnings,
+            pairing_override,
+        })
+    }
+
+    /// Apply all persisted overrides of a round to the generated `pairings`. Adjustments of
+    /// entrants, which are not part of the pairings anymore, are skipped.
+    pub async fn apply_pairing_overrides(
+        &self,
+        stage_id: Uuid,
+        round_number: u32,
+        mut pairings: RoundPairings,
+    ) -> CoreResult<RoundPairings> {
+        let overrides = self
+            .database
+            .list_pairing_overrides(stage_id, round_number)
+            .await?;
+        for adjustment in overrides.iter().flat_map(|o| o.get_adjustments()) {
+            pairings.apply(adjustment);
+        }
+        Ok(pairings)
+    }
+
+    async fn ensure_pairings_adjustable(
+        &self,
+        pairing_override: &PairingOverride,
+    ) -> CoreResult<()> {
+        let tournament = self
+            .database
+            .get_tournament_base(pairing_override.get_tournament_id())
+            .await?;
+        if tournament
+            .as_ref()
+            .is_some_and(|t| t.get_tournament_state() == TournamentState::Finished)
+        {
+            return Err(pairing_error(
+                pairing_override,
+                "tournament_id",
+                "Pairings of a finished tournament cannot be adjusted",
+            ));
+        }
+        let stage = self
+            .database
+            .get_stage_by_id(pairing_override.get_stage_id())
+            .await?;
+        match (tournament, stage) {
+            (Some(_), Some(stage))
+                if stage.get_tournament_id() == pairing_override.get_tournament_id() => {}
+            _ => {
+                return Err(pairing_error(
+                    pairing_override,
+                    "stage_id",
+                    "Stage is not part of the tournament",
+                ));
+            }
+        }
+        let matches = self
+            .database
+            .list_matches_of_stage_for_update(pairing_override.get_stage_id())
+            .await?;
+        if is_round_started(&matches, pairing_override.get_round_number()) {
+            return Err(pairing_error(
+                pairing_override,
+                "round_number",
+                "Pairings of a started round cannot be adjusted",
+            ));
+        }
+        Ok(())
+    }
+}
+
+/// Check if a match of round `round_number` of any group of `matches` of one stage has
+/// started or has a result. Byes are not played and do not start a round.
+pub fn is_round_started(matches: &[Match], round_number: u32) -> bool {
+    let Some(index) = round_number.checked_sub(1) else {
+        return false;
+    };
+    let mut group_ids: Vec<Uuid> = matches.iter().map(|m| *m.get_group_id()).collect();
+    group_ids.sort();
+    group_ids.dedup();
+    group_ids.into_iter().any(|group_id| {
+        let group_matches: Vec<Match> = matches
+            .iter()
+            .filter(|m| *m.get_group_id() == group_id)
+            .cloned()
+            .collect();
+        let Some(round_id) = round_ids(&group_matches).get(index as usize).copied() else {
+            return false;
+        };
+        group_matches.iter().any(|m| {
+            *m.get_round_id() == round_id
+                && m.get_bye_entrant().is_none()
+                && (m.is_decided() || m.get_live_score().is_some())
+        })
+    })
+}
+
+fn pairing_error(
+    pairing_override: &PairingOverride,
+    field: &str,
+    message: impl Into<String>,
+) -> CoreError {
+    let mut errs = ValidationErrors::new();
+    errs.add(
+        FieldError::builder()
+            .set_field(String::from(field))
+            .add_message(message.into())
+            .set_object_id(pairing_override.get_id())
+            .build(),
+    );
+    CoreError::from(errs)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::ScheduledEntrant;
+    use chrono::Local;
+
+    fn ids<const N: usize>() -> [Uuid; N] {
+        std::array::from_fn(|_| Uuid::new_v4())
+    }
+
+    #[test]
+    fn ring_pairings_have_no_rematches() {
+        let entrants: [Uuid; 8] = ids();
+        let rounds = ring_pairings(&entrants, 2);
+        let mut history = PairingHistory::default();
+        for round in &rounds {
+            assert!(round.check(&history).is_empty());
+            for p in &round.pairings {
+                history.add_match(p.entrant_a, p.entrant_b.unwrap());
+            }
+        }
+        assert!(history.have_played(entrants[0], entrants[6]));
+        assert!(!history.have_played(entrants[0], entrants[4]));
+        assert_eq!(rounds.iter().map(|r| r.pairings.len()).sum::<usize>(), 16);
+    }
+
+    #[test]
+    fn swiss_pairings_avoid_rematches_and_repeated_byes() {
+        let [a, b, c, d, e] = ids();
+        let mut history = PairingHistory::default();
+        history.add_match(a, b).add_match(c, d).add_bye(e);
+
+        let round = swiss_pairings(&[a, b, c, d, e], &history);
+
+        // e already had a bye, so the lowest ranked entrant without bye pauses
+        assert_eq!(
+            round.pairings,
+            vec![
+                Pairing {
+                    entrant_a: a,
+                    entrant_b: Some(c),
+                },
+                Pairing {
+                    entrant_a: b,
+                    entrant_b: Some(e),
+                },
+                Pairing {
+                    entrant_a: d,
+                    entrant_b: None,
+                },
+            ]
+        );
+        assert!(round.check(&history).is_empty());
+    }
+
+    #[test]
+    fn swiss_pairings_backtrack_and_fall_back_to_neighbors() {
+        let [a, b, c, d] = ids();
+        let mut history = PairingHistory::default();
+        // a-c would leave b and d, which already played each other
+        history.add_match(a, b).add_match(b, d);
+        let round = swiss_pairings(&[a, b, c, d], &history);
+        assert!(round.check(&history).is_empty());
+        assert!(round.pairings.contains(&Pairing {
+            entrant_a: a,
+            entrant_b: Some(d),
+        }));
+
+        // everybody played everybody
+        for (x, y) in [(a, c), (a, d), (b, c), (c, d)] {
+            history.add_match(x, y);
+        }
+        let round = swiss_pairings(&[a, b, c, d], &history);
+        assert_eq!(round.pairings[0].entrant_b, Some(b));
+        assert_eq!(round.pairings[1].entrant_b, Some(d));
+    }
+
+    #[test]
+    fn swap_opponents_and_move_bye() {
+        let [a, b, c, d, e] = ids();
+        let mut round = RoundPairings::new(vec![
+            Pairing {
+                entrant_a: a,
+                entrant_b: Some(b),
+            },
+            Pairing {
+                entrant_a: c,
+                entrant_b: Some(d),
+            },
+            Pairing {
+                entrant_a: e,
+                entrant_b: None,
+            },
+        ]);
+
+        assert!(round.apply(&PairingAdjustment {
+            first: b,
+            second: d
+        }));
+        assert_eq!(round.pairings[0].entrant_b, Some(d));
+        assert_eq!(round.pairings[1].entrant_b, Some(b));
+
+        assert!(round.apply(&PairingAdjustment {
+            first: e,
+            second: a
+        }));
+        assert_eq!(round.pairings[0].entrant_a, e);
+        assert_eq!(round.pairings[2].entrant_a, a);
+        assert_eq!(round.pairings[2].entrant_b, None);
+
+        // swap within one pairing keeps the pairing
+        assert!(round.apply(&PairingAdjustment {
+            first: c,
+            second: b
+        }));
+        assert_eq!(round.pairings[1].entrant_a, b);
+        assert_eq!(round.pairings[1].entrant_b, Some(c));
+
+        let unchanged = round.clone();
+        assert!(!round.apply(&PairingAdjustment {
+            first: a,
+            second: Uuid::new_v4()
+        }));
+        assert_eq!(round, unchanged);
+    }
+
+    #[test]
+    fn rematches_and_byes_are_warned() {
+        let [a, b, c] = ids();
+        let mut history = PairingHistory::default();
+        history.add_match(b, a).add_bye(c);
+        let round = RoundPairings::new(vec![
+            Pairing {
+                entrant_a: a,
+                entrant_b: Some(b),
+            },
+            Pairing {
+                entrant_a: c,
+                entrant_b: None,
+            },
+        ]);
+
+        let warnings = round.check(&history);
+        assert_eq!(
+            warnings,
+            vec![
+                PairingWarning::Rematch {
+                    entrant_a: a,
+                    entrant_b: b
+                },
+                PairingWarning::ByeLimitExceeded {
+                    entrant_id: c,
+                    byes: 2
+                },
+            ]
+        );
+        assert!(round.check(&PairingHistory::default()).is_empty());
+    }
+
+    #[test]
+    fn given_empty_override_when_validate_then_all_errors_are_collected() {
+        let errs = PairingOverride::default().validate().unwrap_err();
+        let fields: Vec<_> = errs.errors.iter().map(|e| e.get_field()).collect();
+        assert_eq!(
+            fields,
+            vec!["tournament_id", "stage_id", "adjustments", "author", "note"]
+        );
+    }
+
+    #[test]
+    fn given_results_when_is_round_started_then_only_rounds_with_played_matches_started() {
+        let (group, first, second) = (Uuid::new_v4(), Uuid::new_v4(), Uuid::new_v4());
+        let at = |hour| chrono::TimeZone::with_ymd_and_hms(&Local, 2026, 5, 1, hour, 0, 0).unwrap();
+        let new_match = |round_id, hour| {
+            let mut m = Match::new_scheduled(
+                Uuid::new_v4(),
+                group,
+                round_id,
+                1,
+                ScheduledEntrant::Entrant(Uuid::new_v4()),
+                ScheduledEntrant::Entrant(Uuid::new_v4()),
+            );
+            m.set_slot(1, at(hour));
+            m
+        };
+        let mut matches = vec![new_match(first, 9), new_match(second, 10)];
+        assert!(!is_round_started(&matches, 1));
+
+        matches[0].set_live_score(vec![3], vec![1], Utc::now());
+        assert!(is_round_started(&matches, 1));
+        assert!(!is_round_started(&matches, 2));
+
+        matches[1].set_scores(vec![11], vec![7]);
+        assert!(is_round_started(&matches, 2));
+        assert!(!is_round_started(&matches, 0));
+        assert!(!is_round_started(&matches, 3));
+    }
+}
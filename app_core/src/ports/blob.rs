@@ -0,0 +1,67 @@
+// blob storage port
+
+use async_trait::async_trait;
+use serde::{Deserialize, Serialize};
+use std::any::Any;
+use thiserror::Error;
+
+/// binary large object with its content type
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct Blob {
+    /// MIME type of `data`, e.g. `application/pdf`
+    pub content_type: String,
+    pub data: Vec<u8>,
+}
+
+/// blob storage port trait
+#[async_trait]
+pub trait BlobStoragePort: Send + Sync + Any {
+    /// Store `blob` with `key`. An existing blob with `key` is replaced.
+    /// Keys are relative paths like `reports/<id>/final_report.pdf`.
+    async fn put_blob(&self, key: &str, blob: &Blob) -> BlobResult<()>;
+
+    /// Get blob with `key`. Returns `None`, if no blob with `key` exists.
+    async fn get_blob(&self, key: &str) -> BlobResult<Option<Blob>>;
+}
+
+#[derive(Debug, Clone, Error, Serialize, Deserialize)]
+pub enum BlobError {
+    /// key is empty or leaves the storage, e.g. with `..`
+    #[error("invalid blob key: {0}")]
+    InvalidKey(String),
+
+    /// errors of the underlying storage
+    #[error("blob storage failed: {0}")]
+    Storage(String),
+}
+
+pub type BlobResult<T> = Result<T, BlobError>;
+
+/// Check if `key` is a valid blob key: non empty segments separated by `/`, which consist
+/// of ASCII alphanumerics, `-`, `_` and `.`, but are not `.` or `..`.
+pub fn is_valid_blob_key(key: &str) -> bool {
+    !key.is_empty()
+        && key.split('/').all(|segment| {
+            !segment.is_empty()
+                && segment != "."
+                && segment != ".."
+                && segment
+                    .chars()
+                    .all(|c| c.is_ascii_alphanumeric() || matches!(c, '-' | '_' | '.'))
+        })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn blob_keys() {
+        assert!(is_valid_blob_key("reports/1234/final_report.pdf"));
+        assert!(!is_valid_blob_key(""));
+        assert!(!is_valid_blob_key("/etc/passwd"));
+        assert!(!is_valid_blob_key("reports/../secret"));
+        assert!(!is_valid_blob_key("reports//final.pdf"));
+        assert!(!is_valid_blob_key("reports/final report.pdf"));
+    }
+}
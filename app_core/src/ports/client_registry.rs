@@ -1,5 +1,6 @@
 // client registry port types
 
+use crate::PresenceEditor;
 use async_trait::async_trait;
 use serde::{Deserialize, Serialize};
 use std::any::Any;
@@ -10,22 +11,170 @@ use uuid::Uuid;
 #[derive(Clone, Copy, Debug, Eq, PartialEq, Hash, Serialize, Deserialize)]
 pub enum CrTopic {
     NewAddress,
-    Address { address_id: Uuid },
-    NewSportConfig { sport_id: Uuid },
-    SportConfig { sport_config_id: Uuid },
-    NewTournamentBase { sport_id: Uuid },
-    TournamentBase { tournament_base_id: Uuid },
-    NewStage { tournament_base_id: Uuid },
-    Stage { stage_id: Uuid },
+    Address {
+        address_id: Uuid,
+    },
+    NewSportConfig {
+        sport_id: Uuid,
+    },
+    SportConfig {
+        sport_config_id: Uuid,
+    },
+    NewTournamentBase {
+        sport_id: Uuid,
+    },
+    TournamentBase {
+        tournament_base_id: Uuid,
+    },
+    NewStage {
+        tournament_base_id: Uuid,
+    },
+    Stage {
+        stage_id: Uuid,
+    },
+    ShiftLog {
+        tournament_id: Uuid,
+    },
+    MatchNotes {
+        tournament_id: Uuid,
+    },
+    Entrants {
+        tournament_id: Uuid,
+    },
+    ApiTokens,
+    ScorekeeperTokens {
+        tournament_id: Uuid,
+    },
+    Officials {
+        tournament_id: Uuid,
+    },
+    WebhookEndpoints,
+    Venues,
+    WebhookDeliveries {
+        endpoint_id: Uuid,
+    },
+    /// results of matches and recomputed standings of a group, e.g. for live standings
+    GroupMatches {
+        group_id: Uuid,
+    },
+    /// running scores of matches in progress, e.g. for spectator views
+    LiveScores {
+        tournament_id: Uuid,
+    },
+    /// calls of matches to their stations, e.g. for announcer screens
+    CourtCalls {
+        tournament_id: Uuid,
+    },
+    /// explicit reload of display boards at the venue
+    DisplayBoard {
+        tournament_id: Uuid,
+    },
+    /// registered sport plugins of the server, e.g. to refresh the list of sports
+    SportPlugins,
+    /// health checks of the registry, no client subscribes to it
+    Health,
+    /// editors, who currently have the object `object_id` open, see [`PresenceEditor`]
+    Presence {
+        object_id: Uuid,
+    },
 }
 
 /// Domain notices sent to subscribed clients. Keep payloads minimal.
 #[derive(Clone, PartialEq, Debug, Serialize, Deserialize, Hash, Eq)]
 pub enum CrMsg {
-    AddressUpdated { id: Uuid, version: u32 },
-    SportConfigUpdated { id: Uuid, version: u32 },
-    TournamentBaseUpdated { id: Uuid, version: u32 },
-    StageUpdated { id: Uuid, version: u32 },
+    AddressUpdated {
+        id: Uuid,
+        version: u32,
+    },
+    SportConfigUpdated {
+        id: Uuid,
+        version: u32,
+    },
+    TournamentBaseUpdated {
+        id: Uuid,
+        version: u32,
+    },
+    StageUpdated {
+        id: Uuid,
+        version: u32,
+    },
+    ShiftLogUpdated {
+        id: Uuid,
+        version: u32,
+    },
+    MatchNoteUpdated {
+        id: Uuid,
+        version: u32,
+    },
+    EntrantUpdated {
+        id: Uuid,
+        version: u32,
+    },
+    ApiTokenUpdated {
+        id: Uuid,
+        version: u32,
+    },
+    ScorekeeperTokenUpdated {
+        id: Uuid,
+        version: u32,
+    },
+    WebhookEndpointUpdated {
+        id: Uuid,
+        version: u32,
+    },
+    VenueUpdated {
+        id: Uuid,
+        version: u32,
+    },
+    OfficialUpdated {
+        id: Uuid,
+        version: u32,
+    },
+    /// deliveries are never changed, therefore version is always 0
+    WebhookDelivered {
+        id: Uuid,
+        version: u32,
+    },
+    MatchUpdated {
+        id: Uuid,
+        version: u32,
+    },
+    /// live score tick of a match in progress, version is the tick of the live score
+    LiveScoreUpdated {
+        id: Uuid,
+        version: u32,
+    },
+    /// call of a match to its station, version is the number of the station
+    CourtCalled {
+        id: Uuid,
+        version: u32,
+    },
+    /// recomputed cached standings of a group, version is always 0
+    GroupStandingsUpdated {
+        id: Uuid,
+        version: u32,
+    },
+    /// reload of display boards of a tournament, version is always 0
+    DisplayBoardRefreshed {
+        id: Uuid,
+        version: u32,
+    },
+    /// deregistered or replaced sport plugin, version is the plugin version of the
+    /// replacement or 0 after deregistration
+    SportPluginsUpdated {
+        id: Uuid,
+        version: u32,
+    },
+    /// health check of the registry, version is always 0
+    Ping {
+        id: Uuid,
+        version: u32,
+    },
+    /// editors of an object joined or left, version is the number of editors
+    PresenceChanged {
+        id: Uuid,
+        version: u32,
+    },
 }
 
 impl CrMsg {
@@ -35,6 +184,23 @@ impl CrMsg {
             CrMsg::SportConfigUpdated { id, .. } => *id,
             CrMsg::TournamentBaseUpdated { id, .. } => *id,
             CrMsg::StageUpdated { id, .. } => *id,
+            CrMsg::ShiftLogUpdated { id, .. } => *id,
+            CrMsg::MatchNoteUpdated { id, .. } => *id,
+            CrMsg::EntrantUpdated { id, .. } => *id,
+            CrMsg::ApiTokenUpdated { id, .. } => *id,
+            CrMsg::ScorekeeperTokenUpdated { id, .. } => *id,
+            CrMsg::WebhookEndpointUpdated { id, .. } => *id,
+            CrMsg::VenueUpdated { id, .. } => *id,
+            CrMsg::OfficialUpdated { id, .. } => *id,
+            CrMsg::WebhookDelivered { id, .. } => *id,
+            CrMsg::MatchUpdated { id, .. } => *id,
+            CrMsg::LiveScoreUpdated { id, .. } => *id,
+            CrMsg::CourtCalled { id, .. } => *id,
+            CrMsg::GroupStandingsUpdated { id, .. } => *id,
+            CrMsg::DisplayBoardRefreshed { id, .. } => *id,
+            CrMsg::SportPluginsUpdated { id, .. } => *id,
+            CrMsg::Ping { id, .. } => *id,
+            CrMsg::PresenceChanged { id, .. } => *id,
         }
     }
 
@@ -44,15 +210,87 @@ impl CrMsg {
             CrMsg::SportConfigUpdated { version, .. } => *version,
             CrMsg::TournamentBaseUpdated { version, .. } => *version,
             CrMsg::StageUpdated { version, .. } => *version,
+            CrMsg::ShiftLogUpdated { version, .. } => *version,
+            CrMsg::MatchNoteUpdated { version, .. } => *version,
+            CrMsg::EntrantUpdated { version, .. } => *version,
+            CrMsg::ApiTokenUpdated { version, .. } => *version,
+            CrMsg::ScorekeeperTokenUpdated { version, .. } => *version,
+            CrMsg::WebhookEndpointUpdated { version, .. } => *version,
+            CrMsg::VenueUpdated { version, .. } => *version,
+            CrMsg::OfficialUpdated { version, .. } => *version,
+            CrMsg::WebhookDelivered { version, .. } => *version,
+            CrMsg::MatchUpdated { version, .. } => *version,
+            CrMsg::LiveScoreUpdated { version, .. } => *version,
+            CrMsg::CourtCalled { version, .. } => *version,
+            CrMsg::GroupStandingsUpdated { version, .. } => *version,
+            CrMsg::DisplayBoardRefreshed { version, .. } => *version,
+            CrMsg::SportPluginsUpdated { version, .. } => *version,
+            CrMsg::Ping { version, .. } => *version,
+            CrMsg::PresenceChanged { version, .. } => *version,
         }
     }
 }
 
+/// Position of a message in the messages of its topic. Clients use it to detect missed
+/// messages; if the epoch changes, the sequence numbers started again.
+#[derive(Clone, Copy, Debug, Eq, PartialEq, Hash, Serialize, Deserialize)]
+pub struct CrSequence {
+    pub epoch: Uuid,
+    pub seq: u64,
+}
+
 /// client registry port trait
 #[async_trait]
 pub trait ClientRegistryPort: Send + Sync + Any {
     /// Publish a notice to current listeners (no bus is created if none exist).
     async fn publish(&self, topic: CrTopic, msg: CrMsg) -> CrResult<()>;
+
+    /// Publish a message, whose `sequence` was assigned by a client registry shared by
+    /// several server instances. Registries, which count messages, use `sequence` instead
+    /// of their own count.
+    async fn publish_sequenced(
+        &self,
+        topic: CrTopic,
+        msg: CrMsg,
+        _sequence: CrSequence,
+    ) -> CrResult<()> {
+        self.publish(topic, msg).await
+    }
+
+    /// Epoch and current sequence numbers of `topics`, see [`CrSequence`]. Registries, which
+    /// do not count messages, return `None`.
+    async fn topic_sequences(&self, _topics: &[CrTopic]) -> CrResult<Option<(Uuid, Vec<u64>)>> {
+        Ok(None)
+    }
+
+    /// Send a ping through the registry, e.g. for health checks. Fails, if the registry
+    /// cannot deliver messages. Registries, which deliver messages via a broker, wait with a
+    /// timeout until their own ping comes back from the broker.
+    async fn ping(&self) -> CrResult<()> {
+        let msg = CrMsg::Ping {
+            id: Uuid::new_v4(),
+            version: 0,
+        };
+        self.publish(CrTopic::Health, msg).await
+    }
+
+    /// Track that `editor` has the object of presence `topic` open. Editors expire, if they
+    /// do not join again within the expiry time of the registry. Returns true, if the
+    /// editors of the topic changed. Registries without presence tracking track nobody.
+    async fn join_presence(&self, _topic: CrTopic, _editor: PresenceEditor) -> CrResult<bool> {
+        Ok(false)
+    }
+
+    /// Remove editor `client_id` from presence `topic`. Returns true, if the editor was
+    /// tracked.
+    async fn leave_presence(&self, _topic: CrTopic, _client_id: Uuid) -> CrResult<bool> {
+        Ok(false)
+    }
+
+    /// List the editors of presence `topic`, which have not expired, in order of joining.
+    async fn list_presence(&self, _topic: CrTopic) -> CrResult<Vec<PresenceEditor>> {
+        Ok(Vec::new())
+    }
 }
 
 #[derive(Debug, Clone, Error, Serialize, Deserialize)]
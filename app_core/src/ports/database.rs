@@ -1,19 +1,62 @@
 // database port
 
-use crate::{PostalAddress, SportConfig, Stage, TournamentBase, TournamentState};
+use crate::{
+    ApiToken, AuditRecord, CachedGroupStandings, ClientErrorReport, Entrant, Feedback, Group,
+    Match, MatchNote, Official, PairingOverride, PostalAddress, SavedGroupSeeding,
+    ScorekeeperToken, SearchHit, ShiftLogEntry, SportConfig, Stage, StageSnapshot, TournamentBase,
+    Venue, WebhookDelivery, WebhookEndpoint,
+    utils::{filter::Filter, list_order::ListOrder},
+};
 use async_trait::async_trait;
+use chrono::{DateTime, Utc};
 use isocountry::CountryCodeParseErr;
 use serde::{Deserialize, Serialize};
-use std::any::Any;
+use std::{any::Any, sync::Arc};
 use thiserror::Error;
 use uuid::Uuid;
 
 /// database port trait
 #[async_trait]
 pub trait DatabasePort:
-    DbpPostalAddress + DbpSportConfig + DbpTournamentBase + DbpStage + Any
+    DbpPostalAddress
+    + DbpSportConfig
+    + DbpTournamentBase
+    + DbpStage
+    + DbpGroup
+    + DbpShiftLog
+    + DbpMatchNote
+    + DbpPairingOverride
+    + DbpEntrant
+    + DbpOfficial
+    + DbpGroupStandings
+    + DbpGroupSeeding
+    + DbpStageSnapshot
+    + DbpMatch
+    + DbpApiToken
+    + DbpScorekeeperToken
+    + DbpWebhook
+    + DbpVenue
+    + DbpFeedback
+    + DbpClientError
+    + DbpAuditLog
+    + DbpSearch
+    + Any
 {
     async fn ping_db(&self) -> DbResult<()>;
+    /// Begin a transaction. All operations on the returned port are executed in the
+    /// transaction, until it is committed or rolled back. Dropping the returned port without
+    /// commit rolls back the transaction.
+    async fn begin_transaction(&self) -> DbResult<Arc<dyn DbTransaction>>;
+}
+
+/// database port, whose operations are executed in one transaction, see
+/// [`DatabasePort::begin_transaction`]
+#[async_trait]
+pub trait DbTransaction: DatabasePort {
+    /// commit all operations of the transaction
+    async fn commit(&self) -> DbResult<()>;
+    /// roll back all operations of the transaction
+    async fn rollback(&self) -> DbResult<()>;
 }
 
 /// database port trait for postal address
@@ -21,10 +64,12 @@ pub trait DatabasePort:
 pub trait DbpPostalAddress: Send + Sync {
     async fn get_postal_address(&self, id: Uuid) -> DbResult<Option<PostalAddress>>;
     async fn save_postal_address(&self, address: &PostalAddress) -> DbResult<PostalAddress>;
+    /// list ids of postal addresses in `order`; `limit` is the size of a page
     async fn list_postal_address_ids(
         &self,
         name_filter: Option<&str>,
         limit: Option<usize>,
+        order: &ListOrder<PostalAddress>,
     ) -> DbResult<Vec<Uuid>>;
 }
 
@@ -33,11 +78,16 @@ pub trait DbpPostalAddress: Send + Sync {
 pub trait DbpSportConfig: Send + Sync {
     async fn get_sport_config(&self, config_id: Uuid) -> DbResult<Option<SportConfig>>;
     async fn save_sport_config(&self, sport_config: &SportConfig) -> DbResult<SportConfig>;
+    /// set archived_at of sport config with given id and version (optimistic locking)
+    async fn archive_sport_config(&self, config_id: Uuid, version: u32) -> DbResult<SportConfig>;
+    /// list ids of sport configs in `order`; `limit` is the size of a page
     async fn list_sport_config_ids(
         &self,
         sport_id: Uuid,
         name_filter: Option<&str>,
+        include_archived: bool,
         limit: Option<usize>,
+        order: &ListOrder<SportConfig>,
     ) -> DbResult<Vec<Uuid>>;
 }
 /// database port trait for tournament base
@@ -48,13 +98,31 @@ pub trait DbpTournamentBase: Send + Sync {
         &self,
         tournament_base: &TournamentBase,
     ) -> DbResult<TournamentBase>;
+    /// set archived_at of tournament base with given id and version (optimistic locking)
+    async fn archive_tournament_base(
+        &self,
+        base_id: Uuid,
+        version: u32,
+    ) -> DbResult<TournamentBase>;
+    /// delete sandbox tournament base with given id; stages, entrants and further data of the
+    /// tournament are deleted with it
+    async fn purge_sandbox_tournament_base(&self, base_id: Uuid) -> DbResult<()>;
+    /// delete tournament base with given id and version (optimistic locking); stages, entrants
+    /// and further data of the tournament are deleted with it
+    async fn delete_tournament_base(&self, base_id: Uuid, version: u32) -> DbResult<()>;
+    /// save tournament base, stages and groups of one tournament in one transaction: either
+    /// all objects are saved or none of them
+    async fn save_tournament_diff(
+        &self,
+        tournament_base: Option<&TournamentBase>,
+        stages: &[Stage],
+        groups: &[Group],
+    ) -> DbBatchResult<(Option<TournamentBase>, Vec<Stage>, Vec<Group>)>;
+    /// list ids of tournament bases in `order`; the limit of `filter` is the size of a page
     async fn list_tournament_base_ids(
         &self,
-        sport_id: Uuid,
-        name_filter: Option<&str>,
-        state_filter: Option<TournamentState>,
-        include_adhoc: bool,
-        limit: Option<usize>,
+        filter: &Filter<TournamentBase>,
+        order: &ListOrder<TournamentBase>,
     ) -> DbResult<Vec<Uuid>>;
 }
 
@@ -75,6 +143,230 @@ pub trait DbpStage: Send + Sync {
     ) -> DbResult<Vec<(Uuid, u32)>>;
 }
 
+/// database port trait for groups of stages; groups are saved with a tournament diff, see
+/// [`DbpTournamentBase::save_tournament_diff`]
+#[async_trait]
+pub trait DbpGroup: Send + Sync {
+    async fn get_group(&self, group_id: Uuid) -> DbResult<Option<Group>>;
+    /// list groups of stage sorted by number
+    async fn list_groups_of_stage(&self, stage_id: Uuid) -> DbResult<Vec<Group>>;
+}
+
+/// database port trait for shift log of tournament desk
+#[async_trait]
+pub trait DbpShiftLog: Send + Sync {
+    async fn get_shift_log_entry(&self, entry_id: Uuid) -> DbResult<Option<ShiftLogEntry>>;
+    async fn save_shift_log_entry(&self, entry: &ShiftLogEntry) -> DbResult<ShiftLogEntry>;
+    async fn list_shift_log_entries(
+        &self,
+        tournament_id: Uuid,
+        pinned_only: bool,
+        limit: Option<usize>,
+    ) -> DbResult<Vec<ShiftLogEntry>>;
+}
+
+/// database port trait for notes and tags of matches
+#[async_trait]
+pub trait DbpMatchNote: Send + Sync {
+    async fn get_match_note_of_match(&self, match_id: Uuid) -> DbResult<Option<MatchNote>>;
+    async fn save_match_note(&self, note: &MatchNote) -> DbResult<MatchNote>;
+    /// list notes of tournament; if `tag` is set, only notes with this normalized tag
+    async fn list_match_notes(
+        &self,
+        tournament_id: Uuid,
+        tag: Option<&str>,
+    ) -> DbResult<Vec<MatchNote>>;
+}
+
+/// database port trait for manual overrides of pairings; overrides are append only
+#[async_trait]
+pub trait DbpPairingOverride: Send + Sync {
+    async fn save_pairing_override(
+        &self,
+        pairing_override: &PairingOverride,
+    ) -> DbResult<PairingOverride>;
+    /// list overrides of round, oldest first
+    async fn list_pairing_overrides(
+        &self,
+        stage_id: Uuid,
+        round_number: u32,
+    ) -> DbResult<Vec<PairingOverride>>;
+}
+
+/// database port trait for entrants of tournament
+#[async_trait]
+pub trait DbpEntrant: Send + Sync {
+    async fn get_entrant(&self, entrant_id: Uuid) -> DbResult<Option<Entrant>>;
+    async fn save_entrant(&self, entrant: &Entrant) -> DbResult<Entrant>;
+    async fn list_entrants_of_tournament(&self, tournament_id: Uuid) -> DbResult<Vec<Entrant>>;
+}
+
+/// database port trait for officials of tournament, e.g. referees
+#[async_trait]
+pub trait DbpOfficial: Send + Sync {
+    async fn get_official(&self, official_id: Uuid) -> DbResult<Option<Official>>;
+    async fn save_official(&self, official: &Official) -> DbResult<Official>;
+    async fn list_officials_of_tournament(&self, tournament_id: Uuid) -> DbResult<Vec<Official>>;
+}
+
+/// database port trait for cached standings of groups; saving replaces the cached standings
+#[async_trait]
+pub trait DbpGroupStandings: Send + Sync {
+    async fn get_group_standings(&self, group_id: Uuid) -> DbResult<Option<CachedGroupStandings>>;
+    async fn save_group_standings(&self, standings: &CachedGroupStandings) -> DbResult<()>;
+    /// deleting standings, which are not cached, is no error
+    async fn delete_group_standings(&self, group_id: Uuid) -> DbResult<()>;
+    async fn list_group_standings_of_tournament(
+        &self,
+        tournament_id: Uuid,
+    ) -> DbResult<Vec<CachedGroupStandings>>;
+}
+
+/// database port trait for saved group seedings of first stages; saving replaces the seeding
+#[async_trait]
+pub trait DbpGroupSeeding: Send + Sync {
+    async fn get_group_seeding(&self, stage_id: Uuid) -> DbResult<Option<SavedGroupSeeding>>;
+    async fn save_group_seeding(&self, seeding: &SavedGroupSeeding) -> DbResult<()>;
+}
+
+/// database port trait for snapshots of stages; snapshots are immutable once saved
+#[async_trait]
+pub trait DbpStageSnapshot: Send + Sync {
+    async fn get_stage_snapshot(&self, snapshot_id: Uuid) -> DbResult<Option<StageSnapshot>>;
+    async fn save_stage_snapshot(&self, snapshot: &StageSnapshot) -> DbResult<()>;
+    async fn delete_stage_snapshot(&self, snapshot_id: Uuid) -> DbResult<()>;
+    /// snapshots sorted by stage number and time of creation
+    async fn list_stage_snapshots_of_tournament(
+        &self,
+        tournament_id: Uuid,
+    ) -> DbResult<Vec<StageSnapshot>>;
+}
+
+/// database port trait for matches; matches with a version are saved with optimistic
+/// locking, matches without version replace stored matches with the same id
+#[async_trait]
+pub trait DbpMatch: Send + Sync {
+    async fn get_match(&self, match_id: Uuid) -> DbResult<Option<Match>>;
+    /// Saves all `matches` or none of them; returns the saved matches with their new versions.
+    async fn save_matches(&self, matches: &[Match]) -> DbResult<Vec<Match>>;
+    async fn delete_matches_of_stage(&self, stage_id: Uuid) -> DbResult<()>;
+    /// matches sorted by group, round and number
+    async fn list_matches_of_stage(&self, stage_id: Uuid) -> DbResult<Vec<Match>>;
+    /// matches sorted by start and station
+    async fn list_matches_of_tournament(&self, tournament_id: Uuid) -> DbResult<Vec<Match>>;
+    /// like [`DbpMatch::list_matches_of_stage`], but always reads the primary; used by reads,
+    /// which precede writes of the matches or of data derived from them
+    async fn list_matches_of_stage_for_update(&self, stage_id: Uuid) -> DbResult<Vec<Match>>;
+    /// like [`DbpMatch::list_matches_of_tournament`], but always reads the primary; used by
+    /// reads, which precede writes of the matches or of data derived from them
+    async fn list_matches_of_tournament_for_update(
+        &self,
+        tournament_id: Uuid,
+    ) -> DbResult<Vec<Match>>;
+}
+
+/// database port trait for tokens of REST API
+#[async_trait]
+pub trait DbpApiToken: Send + Sync {
+    async fn get_api_token(&self, token_id: Uuid) -> DbResult<Option<ApiToken>>;
+    async fn get_api_token_by_hash(&self, secret_hash: &str) -> DbResult<Option<ApiToken>>;
+    async fn save_api_token(&self, token: &ApiToken) -> DbResult<ApiToken>;
+    /// set last_used_at without changing the version of the token
+    async fn touch_api_token_last_used(&self, token_id: Uuid, at: DateTime<Utc>) -> DbResult<()>;
+    async fn list_api_tokens(&self, include_revoked: bool) -> DbResult<Vec<ApiToken>>;
+}
+
+/// database port trait for access tokens of scorekeepers
+#[async_trait]
+pub trait DbpScorekeeperToken: Send + Sync {
+    async fn get_scorekeeper_token(&self, token_id: Uuid) -> DbResult<Option<ScorekeeperToken>>;
+    async fn get_scorekeeper_token_by_hash(
+        &self,
+        secret_hash: &str,
+    ) -> DbResult<Option<ScorekeeperToken>>;
+    async fn save_scorekeeper_token(&self, token: &ScorekeeperToken) -> DbResult<ScorekeeperToken>;
+    /// set last_used_at without changing the version of the token
+    async fn touch_scorekeeper_token_last_used(
+        &self,
+        token_id: Uuid,
+        at: DateTime<Utc>,
+    ) -> DbResult<()>;
+    /// tokens of tournament sorted by label
+    async fn list_scorekeeper_tokens(
+        &self,
+        tournament_id: Uuid,
+        include_revoked: bool,
+    ) -> DbResult<Vec<ScorekeeperToken>>;
+}
+
+/// database port trait for webhook endpoints and their delivery history
+#[async_trait]
+pub trait DbpWebhook: Send + Sync {
+    async fn get_webhook_endpoint(&self, endpoint_id: Uuid) -> DbResult<Option<WebhookEndpoint>>;
+    async fn save_webhook_endpoint(&self, endpoint: &WebhookEndpoint) -> DbResult<WebhookEndpoint>;
+    /// all endpoints sorted by name
+    async fn list_webhook_endpoints(&self) -> DbResult<Vec<WebhookEndpoint>>;
+    /// deliveries are only inserted, never updated
+    async fn save_webhook_delivery(&self, delivery: &WebhookDelivery) -> DbResult<()>;
+    /// latest deliveries of endpoint, newest first
+    async fn list_webhook_deliveries(
+        &self,
+        endpoint_id: Uuid,
+        limit: usize,
+    ) -> DbResult<Vec<WebhookDelivery>>;
+}
+
+/// database port trait for venues
+#[async_trait]
+pub trait DbpVenue: Send + Sync {
+    async fn get_venue(&self, venue_id: Uuid) -> DbResult<Option<Venue>>;
+    async fn save_venue(&self, venue: &Venue) -> DbResult<Venue>;
+    /// venues sorted by name; `name_filter` matches parts of names case insensitive
+    async fn list_venues(
+        &self,
+        name_filter: Option<&str>,
+        limit: Option<usize>,
+    ) -> DbResult<Vec<Venue>>;
+}
+
+/// database port trait for feedback of users; feedback is append only
+#[async_trait]
+pub trait DbpFeedback: Send + Sync {
+    async fn save_feedback(&self, feedback: &Feedback) -> DbResult<Feedback>;
+    /// list feedback, newest first
+    async fn list_feedback(&self, limit: Option<usize>) -> DbResult<Vec<Feedback>>;
+}
+
+/// database port trait for reports of client errors; reports are append only
+#[async_trait]
+pub trait DbpClientError: Send + Sync {
+    async fn save_client_error(&self, report: &ClientErrorReport) -> DbResult<ClientErrorReport>;
+    /// list reports of client errors, newest first
+    async fn list_client_errors(&self, limit: Option<usize>) -> DbResult<Vec<ClientErrorReport>>;
+}
+
+/// database port trait for the audit log; records are append only
+#[async_trait]
+pub trait DbpAuditLog: Send + Sync {
+    async fn save_audit_record(&self, record: &AuditRecord) -> DbResult<AuditRecord>;
+    /// list audit records of all objects of a tournament, newest first
+    async fn list_audit_records_of_tournament(
+        &self,
+        tournament_id: Uuid,
+        limit: Option<usize>,
+    ) -> DbResult<Vec<AuditRecord>>;
+}
+
+/// database port trait for search across objects
+#[async_trait]
+pub trait DbpSearch: Send + Sync {
+    /// search names of tournaments, entrants, sport configs and postal addresses as well as
+    /// clubs of entrants and streets and localities of postal addresses case insensitive for
+    /// `term`; returns at most `limit_per_kind` hits of each kind. Archived and sandbox
+    /// tournaments, their entrants and archived sport configs are skipped.
+    async fn search_all(&self, term: &str, limit_per_kind: usize) -> DbResult<Vec<SearchHit>>;
+}
+
 #[derive(Debug, Clone, Error, Serialize, Deserialize)]
 pub enum DbError {
     /// row id is nil
@@ -93,6 +385,10 @@ pub enum DbError {
     #[error("optimistic lock conflict")]
     OptimisticLockConflict,
 
+    /// Update could not find matching id + version, since the row was saved by someone else
+    #[error("version conflict, current version of row is {current_version}")]
+    VersionConflict { current_version: u32 },
+
     /// ID does not exist
     #[error("entity not found")]
     NotFound,
@@ -113,6 +409,14 @@ pub enum DbError {
     #[error("serialization failure")]
     SerializationFailure,
 
+    /// database refuses writes, e.g. during a rolling deployment with a newer schema
+    #[error("database is read only, please retry later")]
+    ReadOnly,
+
+    /// statement or connection checkout exceeded its time limit (retry may work)
+    #[error("database timeout, please retry")]
+    Timeout,
+
     /// invalid country code
     #[error("invalid country code: {0}")]
     InvalidCountryCode(String),
@@ -137,3 +441,15 @@ impl From<CountryCodeParseErr> for DbError {
 }
 
 pub type DbResult<T> = Result<T, DbError>;
+
+/// error of saving a batch of objects in one transaction
+#[derive(Debug, Clone, Error, Serialize, Deserialize)]
+#[error("saving batch failed at object {object_id:?}: {error}")]
+pub struct DbBatchError {
+    /// id of object, whose save failed; all other objects of the batch are rolled back.
+    /// `None`, if the transaction failed as a whole, e.g. if no connection is available.
+    pub object_id: Option<Uuid>,
+    pub error: DbError,
+}
+
+pub type DbBatchResult<T> = Result<T, DbBatchError>;
@@ -0,0 +1,23 @@
+// domain event port
+
+use crate::DomainEventEnvelope;
+use async_trait::async_trait;
+use serde::{Deserialize, Serialize};
+use std::any::Any;
+use thiserror::Error;
+
+/// domain event port trait
+#[async_trait]
+pub trait DomainEventPort: Send + Sync + Any {
+    /// Emit `event` to current subscribers. Without subscribers the event is dropped.
+    async fn emit(&self, event: &DomainEventEnvelope) -> DomainEventResult<()>;
+}
+
+#[derive(Debug, Clone, Error, Serialize, Deserialize)]
+pub enum DomainEventError {
+    // Other domain event errors
+    #[error("internal error: {0}")]
+    Other(String),
+}
+
+pub type DomainEventResult<T> = Result<T, DomainEventError>;
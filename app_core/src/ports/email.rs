@@ -0,0 +1,38 @@
+// email port
+
+use async_trait::async_trait;
+use serde::{Deserialize, Serialize};
+use std::any::Any;
+use thiserror::Error;
+
+/// plain text email to one recipient
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct EmailMessage {
+    /// address of recipient
+    pub to: String,
+    /// optional display name of recipient
+    pub to_name: Option<String>,
+    pub subject: String,
+    /// plain text body
+    pub body: String,
+}
+
+/// email port trait
+#[async_trait]
+pub trait EmailPort: Send + Sync + Any {
+    /// Send `message`. The sender is configured by the implementation.
+    async fn send_email(&self, message: &EmailMessage) -> EmailResult<()>;
+}
+
+#[derive(Debug, Clone, Error, Serialize, Deserialize)]
+pub enum EmailError {
+    /// address of recipient or sender is not valid
+    #[error("invalid email address: {0}")]
+    InvalidAddress(String),
+
+    /// connection, authentication or protocol errors of the mail server
+    #[error("email transport failed: {0}")]
+    Transport(String),
+}
+
+pub type EmailResult<T> = Result<T, EmailError>;
@@ -0,0 +1,84 @@
+// geocoding port
+
+use crate::PostalAddress;
+use async_trait::async_trait;
+use serde::{Deserialize, Serialize};
+use std::any::Any;
+use thiserror::Error;
+
+/// mean radius of the earth in kilometers
+const EARTH_RADIUS_KM: f64 = 6371.0;
+
+/// position on earth in degrees (WGS 84)
+#[derive(Debug, Clone, Copy, PartialEq, Serialize, Deserialize)]
+pub struct GeoPoint {
+    /// latitude in degrees; north is positive
+    pub latitude: f64,
+    /// longitude in degrees; east is positive
+    pub longitude: f64,
+}
+
+impl GeoPoint {
+    pub fn new(latitude: f64, longitude: f64) -> Self {
+        GeoPoint {
+            latitude,
+            longitude,
+        }
+    }
+
+    /// Check if latitude is within -90..=90 and longitude within -180..=180 degrees.
+    pub fn is_valid(&self) -> bool {
+        (-90.0..=90.0).contains(&self.latitude) && (-180.0..=180.0).contains(&self.longitude)
+    }
+
+    /// Great-circle distance to `other` in kilometers (haversine formula).
+    pub fn distance_km(&self, other: &GeoPoint) -> f64 {
+        let (lat_a, lat_b) = (self.latitude.to_radians(), other.latitude.to_radians());
+        let d_lat = lat_b - lat_a;
+        let d_lon = (other.longitude - self.longitude).to_radians();
+        let h =
+            (d_lat / 2.0).sin().powi(2) + lat_a.cos() * lat_b.cos() * (d_lon / 2.0).sin().powi(2);
+        2.0 * EARTH_RADIUS_KM * h.sqrt().min(1.0).asin()
+    }
+}
+
+/// geocoding port trait
+#[async_trait]
+pub trait GeocodingPort: Send + Sync + Any {
+    /// Resolve `address` to its position. Returns `None`, if the address is unknown.
+    async fn geocode(&self, address: &PostalAddress) -> GeocodingResult<Option<GeoPoint>>;
+}
+
+#[derive(Debug, Clone, Error, Serialize, Deserialize)]
+pub enum GeocodingError {
+    /// connection or protocol errors of the geocoding service
+    #[error("geocoding request failed: {0}")]
+    Request(String),
+
+    /// response of the geocoding service could not be read
+    #[error("invalid geocoding response: {0}")]
+    InvalidResponse(String),
+}
+
+pub type GeocodingResult<T> = Result<T, GeocodingError>;
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn distance_between_cities() {
+        let berlin = GeoPoint::new(52.5200, 13.4050);
+        let hamburg = GeoPoint::new(53.5511, 9.9937);
+        let distance = berlin.distance_km(&hamburg);
+        assert!((distance - 255.0).abs() < 5.0, "distance was {distance}");
+        assert_eq!(berlin.distance_km(&berlin), 0.0);
+    }
+
+    #[test]
+    fn valid_ranges() {
+        assert!(GeoPoint::new(-90.0, 180.0).is_valid());
+        assert!(!GeoPoint::new(90.5, 0.0).is_valid());
+        assert!(!GeoPoint::new(0.0, -180.5).is_valid());
+    }
+}
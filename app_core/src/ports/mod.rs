@@ -1,11 +1,23 @@
 // trait definitions for ports
 
+mod blob;
 mod client_registry;
 mod database;
+mod domain_event;
+mod email;
+mod geocoding;
 mod plugin_manager;
+mod ranking;
 mod sport;
+mod webhook;
 
+pub use blob::*;
 pub use client_registry::*;
 pub use database::*;
+pub use domain_event::*;
+pub use email::*;
+pub use geocoding::*;
 pub use plugin_manager::*;
+pub use ranking::*;
 pub use sport::*;
+pub use webhook::*;
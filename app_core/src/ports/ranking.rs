@@ -0,0 +1,48 @@
+// ranking system port
+
+use crate::{Entrant, TournamentBase};
+use async_trait::async_trait;
+use serde::{Deserialize, Serialize};
+use std::any::Any;
+use thiserror::Error;
+use uuid::Uuid;
+
+/// rank of an entrant in a ranking system; 1 is the strongest entrant
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+pub struct EntrantRank {
+    pub entrant_id: Uuid,
+    pub rank: u32,
+}
+
+/// Connector to a sport specific ranking system, e.g. the world ranking of a sport
+/// federation. Sport plugins may provide an implementation, see
+/// [`SportPort::ranking_system`](crate::SportPort::ranking_system).
+#[async_trait]
+pub trait RankingSystemPort: Send + Sync + Any {
+    /// Returns a user-friendly name of the ranking system.
+    fn name(&self) -> &'static str;
+
+    /// Fetch ranks of `entrants`. Entrants, which are unknown to the ranking system, are
+    /// omitted in the result.
+    async fn fetch_seed_ranks(&self, entrants: &[Entrant]) -> RankingResult<Vec<EntrantRank>>;
+
+    /// Push final ranking of finished `tournament` to the ranking system.
+    async fn push_final_results(
+        &self,
+        tournament: &TournamentBase,
+        standings: &[EntrantRank],
+    ) -> RankingResult<()>;
+}
+
+#[derive(Debug, Clone, Error, Serialize, Deserialize)]
+pub enum RankingError {
+    /// ranking system does not know the tournament or its sport
+    #[error("ranking system rejected request: {0}")]
+    Rejected(String),
+
+    /// connection or protocol errors
+    #[error("ranking system request failed: {0}")]
+    Request(String),
+}
+
+pub type RankingResult<T> = Result<T, RankingError>;
@@ -5,7 +5,7 @@
 //! timing, and ranking without needing to know the specifics of each sport.
 
 use crate::{
-    EntrantGroupScore, Match, SportConfig,
+    ConfigFieldSpec, EntrantGroupScore, Match, RankingSystemPort, SportConfig,
     utils::{
         id_version::IdVersion,
         traits::ObjectIdVersion,
@@ -14,7 +14,7 @@ use crate::{
 };
 use serde::{Deserialize, Serialize};
 use serde_json::Value;
-use std::{any::Any, sync::Arc, time::Duration};
+use std::{any::Any, fmt::Display, sync::Arc, time::Duration};
 use thiserror::Error;
 use uuid::Uuid;
 
@@ -23,12 +23,16 @@ use uuid::Uuid;
 pub enum SportError {
     #[error("Invalid score format: {0}")]
     InvalidScore(String),
+    #[error("Match has already a final result; correct it with a score correction")]
+    MatchDecided,
     #[error("Unknown Sport ID: {0}")]
     UnknownSportId(Uuid),
     #[error("Invalid Sport ID: {0}, expected sport ID: {1}")]
     InvalidSportId(Uuid, Uuid),
     #[error("Invalid Json configuration: {0}")]
     InvalidJsonConfig(String),
+    #[error("Configuration of plugin version {found} is newer than plugin version {current}")]
+    UnsupportedConfigVersion { found: u32, current: u32 },
     #[error("Configuration is invalid: {0}")]
     InvalidConfig(#[from] ValidationErrors),
     #[error("internal error: {0}")]
@@ -44,6 +48,54 @@ impl From<anyhow::Error> for SportError {
 
 pub type SportResult<T> = Result<T, SportError>;
 
+/// Type of station, on which matches of a sport are played.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+pub enum StationType {
+    Court,
+    Table,
+    Field,
+}
+
+impl Display for StationType {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            StationType::Court => write!(f, "Court"),
+            StationType::Table => write!(f, "Table"),
+            StationType::Field => write!(f, "Field"),
+        }
+    }
+}
+
+/// Capabilities of a sport plugin. The core and the web ui use them to adapt forms and
+/// scheduling to a sport without assumptions about specific plugins.
+///
+/// Capabilities describe, what a plugin supports at all; e.g. draws may still depend upon
+/// the configuration of a sport.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Serialize, Deserialize)]
+pub struct SportCapabilities {
+    /// matches may end in a draw
+    pub supports_draws: bool,
+    /// scores of matches are entered per set (or period); otherwise a match has a
+    /// single score per side
+    pub per_set_scoring: bool,
+    /// handicaps of entrants may be configured
+    pub supports_handicap: bool,
+    /// matches may end at a time cap instead of on points
+    pub supports_time_cap: bool,
+    /// type of station, which matches need; `None`, if the sport does not need a
+    /// specific type of station
+    pub needs_station_type: Option<StationType>,
+}
+
+impl SportCapabilities {
+    /// Returns the label of stations of the sport, e.g. "Table" for table tennis.
+    pub fn station_label(&self) -> String {
+        self.needs_station_type
+            .map(|st| st.to_string())
+            .unwrap_or_else(|| "Station".to_string())
+    }
+}
+
 impl ObjectIdVersion for Arc<dyn SportPort> {
     fn get_id_version(&self) -> IdVersion {
         self.as_ref().get_id_version()
@@ -69,6 +121,12 @@ pub trait SportPort: ObjectIdVersion + Send + Sync + Any {
     /// Estimates the maximum duration of a single match based on the sport-specific configuration.
     fn estimate_match_duration(&self, config: &SportConfig) -> SportResult<Duration>;
 
+    /// Returns the time between two matches at a station, e.g. to change courts or to warm
+    /// up. Plugins without specific rules need no changeover.
+    fn changeover_duration(&self, _config: &SportConfig) -> SportResult<Duration> {
+        Ok(Duration::ZERO)
+    }
+
     /// Validates a final score against the rules defined in the configuration.
     fn validate_final_score(&self, config: &SportConfig, score: &Match) -> SportResult<()>;
 
@@ -80,4 +138,62 @@ pub trait SportPort: ObjectIdVersion + Send + Sync + Any {
         entrant_id: Uuid,
         all_matches: &[Match],
     ) -> SportResult<EntrantGroupScore>;
+
+    /// Returns the capabilities of the plugin. Plugins without specific capabilities
+    /// support neither draws nor sets, handicaps or time caps.
+    fn capabilities(&self) -> SportCapabilities {
+        SportCapabilities::default()
+    }
+
+    /// Returns the maximum number of sets (or periods) of a match with `config`, e.g. for
+    /// the blank set boxes of score sheets. Plugins without sets score a match as one set.
+    fn max_sets(&self, _config: &SportConfig) -> SportResult<u16> {
+        Ok(1)
+    }
+
+    /// Returns the scores per set of a side, which wins by forfeit or bye; its opponent
+    /// scores zero in each set. Plugins without specific rules score a free ticket as one
+    /// set won 1:0.
+    fn free_ticket_score(&self, _config: &SportConfig) -> SportResult<Vec<u16>> {
+        Ok(vec![1])
+    }
+
+    /// Returns the scores per set of both sides of the decided match `m`, by which the match
+    /// counts in standings. Plugins without specific rules score walkovers by the free ticket
+    /// (see [`Match::get_result_scores`]) and played matches by their entered scores; plugins
+    /// with handicaps credit them here.
+    fn standing_scores(
+        &self,
+        config: &SportConfig,
+        m: &Match,
+    ) -> SportResult<(Vec<u16>, Vec<u16>)> {
+        Ok(m.get_result_scores(&self.free_ticket_score(config)?))
+    }
+
+    /// Returns the version of the plugin, which is the version of its `IdVersion`. Bump it,
+    /// if the format of the sport specific configuration changes, and migrate stored configs
+    /// of older versions in `migrate_config()`.
+    fn plugin_version(&self) -> u32 {
+        self.get_id_version().get_version().unwrap_or_default()
+    }
+
+    /// Migrates the sport specific configuration written by plugin version `old_version` to
+    /// the current plugin version. Configs are migrated on load, therefore validation only
+    /// sees configs of the current version. Plugins, which never changed their format, keep
+    /// the config as it is.
+    fn migrate_config(&self, _old_version: u32, config: Value) -> SportResult<Value> {
+        Ok(config)
+    }
+
+    /// Returns the schema of the sport specific configuration. The web ui renders a generic
+    /// configuration form from it. Plugins, which render a form of their own, return an
+    /// empty schema.
+    fn config_schema(&self) -> Vec<ConfigFieldSpec> {
+        Vec::new()
+    }
+
+    /// Returns the connector to the ranking system of the sport, if the plugin provides one.
+    fn ranking_system(&self) -> Option<Arc<dyn RankingSystemPort>> {
+        None
+    }
 }
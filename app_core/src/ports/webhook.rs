@@ -0,0 +1,55 @@
+// webhook transport and queue ports
+
+use crate::{WebhookEndpoint, WebhookPayload};
+use async_trait::async_trait;
+use serde::{Deserialize, Serialize};
+use std::any::Any;
+use thiserror::Error;
+
+/// webhook transport port trait
+#[async_trait]
+pub trait WebhookTransportPort: Send + Sync + Any {
+    /// Post `body` as JSON with additional `headers` to `url`.
+    /// Returns the HTTP status code of the response, including error codes of the receiver.
+    async fn post_json(
+        &self,
+        url: &str,
+        headers: &[(String, String)],
+        body: &str,
+    ) -> WebhookResult<u16>;
+}
+
+/// delivery of a webhook event to one endpoint
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct WebhookJob {
+    pub endpoint: WebhookEndpoint,
+    pub payload: WebhookPayload,
+}
+
+/// webhook queue port trait
+///
+/// Queues deliver webhook jobs in the background, so that slow receivers do not delay the
+/// operation, which triggered the event. Failed deliveries are retried, see
+/// [`Core::deliver_webhook_job`](crate::Core::deliver_webhook_job).
+#[async_trait]
+pub trait WebhookQueuePort: Send + Sync + Any {
+    /// Add `job` to the queue. Fails, if the queue does not accept jobs anymore.
+    async fn enqueue(&self, job: WebhookJob) -> WebhookResult<()>;
+}
+
+#[derive(Debug, Clone, Error, Serialize, Deserialize)]
+pub enum WebhookError {
+    /// receiver did not respond in time
+    #[error("webhook request timed out")]
+    Timeout,
+
+    /// connection or protocol errors
+    #[error("webhook request failed: {0}")]
+    Request(String),
+
+    /// queue of deliveries is full or closed
+    #[error("webhook delivery not queued: {0}")]
+    NotQueued(String),
+}
+
+pub type WebhookResult<T> = Result<T, WebhookError>;
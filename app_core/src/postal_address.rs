@@ -1,15 +1,28 @@
 // data types for postal addresses
 
 use crate::{
-    Core, CoreResult, CrMsg, CrTopic,
-    utils::{id_version::IdVersion, normalize::*, traits::ObjectIdVersion, validation::*},
+    AuditObjectType, Core, CoreError, CoreResult, CrMsg, CrTopic, DbError, GeoPoint, MergeFields,
+    ServerCopy, merge_display_value,
+    utils::{
+        id_version::IdVersion,
+        list_order::{ListOrder, Sortable},
+        normalize::*,
+        traits::ObjectIdVersion,
+        validation::*,
+    },
 };
 // ToDo: should we us isocountry::CountryCode here for country field?
 use isocountry::CountryCode;
 use serde::{Deserialize, Serialize};
+use std::{
+    cmp::Ordering,
+    fmt::{self, Display},
+    str::FromStr,
+};
+use tracing::{info, warn};
 use uuid::Uuid;
 
-#[derive(Clone, Debug, PartialEq, Serialize, Deserialize, Default)]
+#[derive(Clone, Debug, PartialEq, Serialize, Deserialize, Default, ObjectIdVersion)]
 pub struct PostalAddress {
     /// id and optimistic locking version of address
     id_version: IdVersion,
@@ -25,11 +38,51 @@ pub struct PostalAddress {
     region: Option<String>,
     /// country: ISO code
     country: Option<CountryCode>,
+    /// position of address; set by geocoding on save, `None` if not geocoded
+    #[serde(default)]
+    location: Option<GeoPoint>,
 }
 
-impl ObjectIdVersion for PostalAddress {
-    fn get_id_version(&self) -> IdVersion {
-        self.id_version
+impl MergeFields for PostalAddress {
+    fn merge_field_names(&self, _theirs: &Self) -> Vec<String> {
+        [
+            "name",
+            "street",
+            "postal_code",
+            "locality",
+            "region",
+            "country",
+        ]
+        .map(String::from)
+        .to_vec()
+    }
+
+    fn merge_field_value(&self, field: &str) -> Option<String> {
+        let value = match field {
+            "name" => merge_display_value(&self.name),
+            "street" => merge_display_value(&self.street),
+            "postal_code" => merge_display_value(&self.postal_code),
+            "locality" => merge_display_value(&self.locality),
+            "region" => merge_display_value(&self.region),
+            "country" => self
+                .country
+                .map(|c| c.name().to_string())
+                .unwrap_or_default(),
+            _ => return None,
+        };
+        Some(value)
+    }
+
+    fn take_merge_field(&mut self, other: &Self, field: &str) {
+        match field {
+            "name" => self.name = other.name.clone(),
+            "street" => self.street = other.street.clone(),
+            "postal_code" => self.postal_code = other.postal_code.clone(),
+            "locality" => self.locality = other.locality.clone(),
+            "region" => self.region = other.region.clone(),
+            "country" => self.country = other.country,
+            _ => {}
+        }
     }
 }
 
@@ -64,6 +117,19 @@ impl PostalAddress {
     pub fn get_country(&self) -> Option<CountryCode> {
         self.country
     }
+    pub fn get_location(&self) -> Option<GeoPoint> {
+        self.location
+    }
+
+    /// Returns true, if `other` describes the same place, i.e. all fields except the name
+    /// are equal. Addresses of the same place share their location.
+    pub fn is_same_place(&self, other: &PostalAddress) -> bool {
+        self.street == other.street
+            && self.postal_code == other.postal_code
+            && self.locality == other.locality
+            && self.region == other.region
+            && self.country == other.country
+    }
 
     pub fn set_id_version(&mut self, id_version: IdVersion) -> &mut Self {
         self.id_version = id_version;
@@ -186,6 +252,12 @@ impl PostalAddress {
         self
     }
 
+    /// Sets the location, e.g. the result of geocoding
+    pub fn set_location(&mut self, value: Option<GeoPoint>) -> &mut Self {
+        self.location = value;
+        self
+    }
+
     pub fn validate(&self) -> ValidationResult<()> {
         let mut errs = ValidationErrors::new();
         let object_id = self.get_id();
@@ -252,10 +324,81 @@ impl PostalAddress {
             );
         }
 
+        if self.location.is_some_and(|l| !l.is_valid()) {
+            errs.add(
+                FieldError::builder()
+                    .set_field("location")
+                    .add_invalid_format()
+                    .add_message("latitude or longitude is out of range")
+                    .set_object_id(object_id)
+                    .build(),
+            );
+        }
+
         if errs.is_empty() { Ok(()) } else { Err(errs) }
     }
 }
 
+/// column, by which lists of postal addresses are sorted
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default, Serialize, Deserialize)]
+pub enum PostalAddressSortColumn {
+    #[default]
+    Name,
+    PostalCode,
+    Locality,
+}
+
+impl PostalAddressSortColumn {
+    pub const ALL: [PostalAddressSortColumn; 3] = [
+        PostalAddressSortColumn::Name,
+        PostalAddressSortColumn::PostalCode,
+        PostalAddressSortColumn::Locality,
+    ];
+
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            PostalAddressSortColumn::Name => "name",
+            PostalAddressSortColumn::PostalCode => "postal_code",
+            PostalAddressSortColumn::Locality => "locality",
+        }
+    }
+}
+
+impl Display for PostalAddressSortColumn {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{}", self.as_str())
+    }
+}
+
+impl FromStr for PostalAddressSortColumn {
+    type Err = CoreError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        PostalAddressSortColumn::ALL
+            .into_iter()
+            .find(|c| c.as_str() == s)
+            .ok_or_else(|| CoreError::ParsingError(format!("unknown sort column: {s}")))
+    }
+}
+
+impl Sortable for PostalAddress {
+    type SortColumn = PostalAddressSortColumn;
+
+    fn compare(&self, other: &Self, column: PostalAddressSortColumn) -> Ordering {
+        // names and localities are compared case insensitive like in the database
+        match column {
+            PostalAddressSortColumn::Name => {
+                self.name.to_lowercase().cmp(&other.name.to_lowercase())
+            }
+            PostalAddressSortColumn::PostalCode => self.postal_code.cmp(&other.postal_code),
+            PostalAddressSortColumn::Locality => self
+                .locality
+                .to_lowercase()
+                .cmp(&other.locality.to_lowercase()),
+        }
+    }
+}
+
 /// State for postal address operations
 pub struct PostalAddressState {
     address: PostalAddress,
@@ -290,11 +433,26 @@ impl Core<PostalAddressState> {
     pub async fn save(&mut self) -> CoreResult<&PostalAddress> {
         // validate before save
         self.state.address.validate()?;
-        // persist address
-        self.state.address = self
-            .database
-            .save_postal_address(&self.state.address)
-            .await?;
+        // stored copy before the save for the audit log
+        let old = match self.state.address.get_version() {
+            Some(_) => {
+                let id = self.state.address.get_id();
+                self.database.get_postal_address(id).await?
+            }
+            None => None,
+        };
+        self.locate_address(old.as_ref()).await;
+        // persist address; on a version conflict the current server copy is returned
+        self.state.address = match self.database.save_postal_address(&self.state.address).await {
+            Err(DbError::VersionConflict { .. }) => {
+                let id = self.state.address.get_id();
+                return Err(match self.database.get_postal_address(id).await? {
+                    Some(theirs) => CoreError::version_conflict(ServerCopy::PostalAddress(theirs)),
+                    None => CoreError::from(DbError::NotFound),
+                });
+            }
+            res => res?,
+        };
         // publish change of address to client registry
         let id = self.state.address.get_id();
         let version =
@@ -308,19 +466,67 @@ impl Core<PostalAddressState> {
         };
         let msg = CrMsg::AddressUpdated { id, version };
         self.client_registry.publish(notice, msg).await?;
+        self.audit_save(
+            AuditObjectType::PostalAddress,
+            None,
+            old.as_ref(),
+            &self.state.address,
+        )
+        .await;
         Ok(self.get())
     }
+    /// Set location of address. The stored location is kept, if the place of the address did
+    /// not change. Otherwise the address is geocoded, if a geocoding port is configured.
+    /// Failed geocoding does not prevent saving; the address is saved without location.
+    async fn locate_address(&mut self, old: Option<&PostalAddress>) {
+        if let Some(old) = old
+            && old.location.is_some()
+            && old.is_same_place(&self.state.address)
+        {
+            self.state.address.location = old.location;
+            return;
+        }
+        self.state.address.location = None;
+        let Some(geocoding) = self.geocoding.clone() else {
+            return;
+        };
+        match geocoding.geocode(&self.state.address).await {
+            Ok(location) => {
+                if location.is_none() {
+                    info!("address_not_geocoded");
+                }
+                self.state.address.location = location.filter(GeoPoint::is_valid);
+            }
+            Err(e) => warn!(error = %e, "geocoding_failed"),
+        }
+    }
     pub async fn list_address_ids(
         &self,
         name_filter: Option<&str>,
         limit: Option<usize>,
+        order: &ListOrder<PostalAddress>,
     ) -> CoreResult<Vec<Uuid>> {
         let list = self
             .database
-            .list_postal_address_ids(name_filter, limit)
+            .list_postal_address_ids(name_filter, limit, order)
             .await?;
         Ok(list)
     }
+    /// Like [`Self::list_address_ids`], but returns the addresses, e.g. for selections.
+    pub async fn list_addresses(
+        &self,
+        name_filter: Option<&str>,
+        limit: Option<usize>,
+        order: &ListOrder<PostalAddress>,
+    ) -> CoreResult<Vec<PostalAddress>> {
+        let mut addresses = Vec::new();
+        for id in self.list_address_ids(name_filter, limit, order).await? {
+            if let Some(address) = self.database.get_postal_address(id).await? {
+                addresses.push(address);
+            }
+        }
+        Ok(addresses)
+    }
 }
 
 #[cfg(test)]
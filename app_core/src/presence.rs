@@ -0,0 +1,109 @@
+//! presence of editors, who have an object open for editing
+//!
+//! Editors have no accounts. A client identifies itself by a random id and an optional name,
+//! which the user chose in the browser. Clients join the presence of the object they edit
+//! periodically and leave it, when the editor is closed. The client registry tracks the
+//! editors by topic (see [`ClientRegistryPort`](crate::ClientRegistryPort)) and expires
+//! editors, who stopped joining, e.g. because their browser was closed.
+//! Changes of the editors are published as [`CrMsg::PresenceChanged`], so editors can show
+//! who else is editing and version conflicts come less as a surprise.
+
+use crate::{Core, CoreResult, CrMsg, CrTopic};
+use serde::{Deserialize, Serialize};
+use uuid::Uuid;
+
+/// maximum number of characters of the name of an editor
+pub const PRESENCE_NAME_MAX_CHARS: usize = 40;
+
+/// client, which has an object open for editing
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct PresenceEditor {
+    /// random id of the client, e.g. of a browser tab
+    pub client_id: Uuid,
+    /// name chosen by the user; empty, if the user did not choose a name
+    pub name: String,
+}
+
+impl PresenceEditor {
+    /// Create editor `client_id` with `name`. Whitespace of the name is normalized and the
+    /// name is truncated to [`PRESENCE_NAME_MAX_CHARS`].
+    pub fn new(client_id: Uuid, name: &str) -> Self {
+        let name = name
+            .split_whitespace()
+            .collect::<Vec<_>>()
+            .join(" ")
+            .chars()
+            .take(PRESENCE_NAME_MAX_CHARS)
+            .collect::<String>()
+            .trim_end()
+            .to_string();
+        PresenceEditor { client_id, name }
+    }
+}
+
+// presence is available in every core state
+impl<S> Core<S> {
+    /// Join `editor` to the presence of object `object_id` or refresh its presence. Returns
+    /// the editors of the object including `editor`.
+    pub async fn join_presence(
+        &self,
+        object_id: Uuid,
+        editor: PresenceEditor,
+    ) -> CoreResult<Vec<PresenceEditor>> {
+        let topic = CrTopic::Presence { object_id };
+        let editor = PresenceEditor::new(editor.client_id, &editor.name);
+        let changed = self.client_registry.join_presence(topic, editor).await?;
+        let editors = self.client_registry.list_presence(topic).await?;
+        if changed {
+            self.publish_presence_changed(object_id, editors.len())
+                .await?;
+        }
+        Ok(editors)
+    }
+
+    /// Remove editor `client_id` from the presence of object `object_id`.
+    pub async fn leave_presence(&self, object_id: Uuid, client_id: Uuid) -> CoreResult<()> {
+        let topic = CrTopic::Presence { object_id };
+        if self
+            .client_registry
+            .leave_presence(topic, client_id)
+            .await?
+        {
+            let num_editors = self.client_registry.list_presence(topic).await?.len();
+            self.publish_presence_changed(object_id, num_editors)
+                .await?;
+        }
+        Ok(())
+    }
+
+    async fn publish_presence_changed(
+        &self,
+        object_id: Uuid,
+        num_editors: usize,
+    ) -> CoreResult<()> {
+        let notice = CrTopic::Presence { object_id };
+        let msg = CrMsg::PresenceChanged {
+            id: object_id,
+            version: num_editors as u32,
+        };
+        self.client_registry.publish(notice, msg).await?;
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn given_name_with_whitespace_when_new_then_name_is_normalized() {
+        let id = Uuid::new_v4();
+
+        let editor = PresenceEditor::new(id, "  Jane \n  Doe ");
+        assert_eq!(editor.name, "Jane Doe");
+
+        let editor = PresenceEditor::new(id, &"x".repeat(60));
+        assert_eq!(editor.name.chars().count(), PRESENCE_NAME_MAX_CHARS);
+        assert_eq!(editor.client_id, id);
+    }
+}
@@ -1,5 +1,10 @@
 // round of matches in a group
 
+use crate::{
+    Core, CoreResult, Match, MatchOutcome, ScheduledEntrant, SportConfig, SportError, Stage,
+    TournamentBase, TournamentMode, WebhookEventData, WebhookMatch, pairing::*, schedule_matches,
+};
+use chrono::{Duration, Local, Utc};
 use uuid::Uuid;
 
 /// round of matches
@@ -27,3 +32,346 @@ pub enum PausedEntrant {
     /// No entrant to pause
     None,
 }
+
+/// Ids of the rounds of `matches` ordered by the earliest start of their matches.
+pub fn round_ids(matches: &[Match]) -> Vec<Uuid> {
+    let mut rounds: Vec<(Uuid, chrono::DateTime<Local>)> = Vec::new();
+    for m in matches {
+        match rounds.iter_mut().find(|(id, _)| id == m.get_round_id()) {
+            Some((_, start_at)) => *start_at = (*start_at).min(m.get_start_at()),
+            None => rounds.push((*m.get_round_id(), m.get_start_at())),
+        }
+    }
+    rounds.sort_by_key(|(id, start_at)| (*start_at, *id));
+    rounds.into_iter().map(|(id, _)| id).collect()
+}
+
+/// Ids of the groups of `matches` of one stage in order of their group number. Groups with
+/// equal numbers, e.g. of matches stored before groups were numbered, are ordered by start
+/// and station of their earliest match.
+pub fn stage_group_ids(matches: &[Match]) -> Vec<Uuid> {
+    let mut groups: Vec<(Uuid, u32, (chrono::DateTime<Local>, u16))> = Vec::new();
+    for m in matches {
+        let slot = (m.get_start_at(), m.get_station());
+        match groups.iter_mut().find(|(id, ..)| id == m.get_group_id()) {
+            Some((_, _, first)) => *first = (*first).min(slot),
+            None => groups.push((*m.get_group_id(), m.get_group_number(), slot)),
+        }
+    }
+    groups.sort_by_key(|(id, number, first)| (*number, *first, *id));
+    groups.into_iter().map(|(id, ..)| id).collect()
+}
+
+/// Entrants of `matches` in order of their first appearance, including entrants with byes.
+/// Sides, which are not resolved to an entrant yet, are skipped.
+pub fn group_entrants(matches: &[Match]) -> Vec<Uuid> {
+    let mut entrants: Vec<Uuid> = Vec::new();
+    for m in matches {
+        let ids = match (m.get_entrants(), m.get_bye_entrant()) {
+            (Some((a, b)), _) => vec![*a, *b],
+            (None, Some(bye)) => vec![*bye],
+            (None, None) => vec![],
+        };
+        for id in ids {
+            if !entrants.contains(&id) {
+                entrants.push(id);
+            }
+        }
+    }
+    entrants
+}
+
+impl<S> Core<S> {
+    /// Check if the round of the decided match `decided` is complete, i.e. if all matches of
+    /// the round in its group are decided. `matches` are all matches of `stage` with sides
+    /// resolved by the result of `decided`.
+    ///
+    /// If the stage triggers the next round (see [`Stage::triggers_next_round`]), the next
+    /// Swiss round is paired by the current ranking of the group, scheduled and saved. KO
+    /// rounds are already scheduled and resolved by results of their previous matches.
+    /// Integrations are notified of the results of the completed round and of the next
+    /// round, once all its sides are known. Returns the matches of the generated round.
+    pub(crate) async fn advance_round(
+        &self,
+        tournament: &TournamentBase,
+        stage: &Stage,
+        config: &SportConfig,
+        decided: &Match,
+        matches: &[Match],
+    ) -> CoreResult<Vec<Match>> {
+        let group_id = *decided.get_group_id();
+        let round_id = *decided.get_round_id();
+        let group_matches: Vec<Match> = matches
+            .iter()
+            .filter(|m| *m.get_group_id() == group_id)
+            .cloned()
+            .collect();
+        let round_matches: Vec<&Match> = group_matches
+            .iter()
+            .filter(|m| *m.get_round_id() == round_id)
+            .collect();
+        if round_matches.iter().any(|m| !m.is_decided()) {
+            return Ok(vec![]);
+        }
+        let rounds = round_ids(&group_matches);
+        let round_number = rounds
+            .iter()
+            .position(|id| *id == round_id)
+            .unwrap_or_default() as u32
+            + 1;
+        tracing::info!(
+            tournament_id = %tournament.get_id(),
+            %group_id,
+            round_number,
+            "round_completed"
+        );
+
+        // each Swiss entrant plays one match or has a bye per round
+        let is_swiss = matches!(
+            tournament.get_tournament_mode(),
+            TournamentMode::SwissSystem { .. }
+        );
+        let expected_matches =
+            (is_swiss && rounds.last() == Some(&round_id)).then_some(round_number);
+        self.check_group_standings(
+            config,
+            group_id,
+            &group_entrants(&group_matches),
+            &group_matches,
+            expected_matches,
+        )?;
+
+        let generated = if stage.triggers_next_round(tournament) && rounds.last() == Some(&round_id)
+        {
+            self.generate_swiss_round(tournament, stage, config, group_id, &group_matches, &rounds)
+                .await?
+        } else {
+            vec![]
+        };
+
+        // sandbox tournaments must not leak to integrations
+        if !tournament.is_sandbox() {
+            let mut results = Vec::with_capacity(round_matches.len());
+            for m in &round_matches {
+                results.push(self.webhook_match_result(m).await?);
+            }
+            self.dispatch_webhook_event(WebhookEventData::RoundResults {
+                tournament_id: tournament.get_id(),
+                tournament_name: tournament.get_name().to_string(),
+                stage_number: stage.get_number(),
+                round_number,
+                results,
+            })
+            .await;
+
+            let next_round: Vec<&Match> = if generated.is_empty() {
+                let next_id = rounds.get(round_number as usize);
+                group_matches
+                    .iter()
+                    .filter(|m| Some(m.get_round_id()) == next_id)
+                    .collect()
+            } else {
+                generated.iter().collect()
+            };
+            let sides_known =
+                |m: &&Match| m.get_entrants().is_some() || m.get_bye_entrant().is_some();
+            if !next_round.is_empty() && next_round.iter().all(sides_known) {
+                let mut started = Vec::with_capacity(next_round.len());
+                for m in next_round.iter().filter(|m| m.get_bye_entrant().is_none()) {
+                    let result = self.webhook_match_result(m).await?;
+                    started.push(WebhookMatch {
+                        number: m.get_number(),
+                        station: (m.get_station() > 0).then_some(m.get_station() as u32),
+                        side_a: result.side_a,
+                        side_b: result.side_b,
+                    });
+                }
+                self.dispatch_webhook_event(WebhookEventData::RoundStarted {
+                    tournament_id: tournament.get_id(),
+                    tournament_name: tournament.get_name().to_string(),
+                    stage_number: stage.get_number(),
+                    round_number: round_number + 1,
+                    matches: started,
+                })
+                .await;
+            }
+        }
+        Ok(generated)
+    }
+
+    /// Pair, schedule and save the next Swiss round of group `group_id` with its matches
+    /// `group_matches` and `rounds`. No round is generated in other modes or after the
+    /// last Swiss round.
+    async fn generate_swiss_round(
+        &self,
+        tournament: &TournamentBase,
+        stage: &Stage,
+        config: &SportConfig,
+        group_id: Uuid,
+        group_matches: &[Match],
+        rounds: &[Uuid],
+    ) -> CoreResult<Vec<Match>> {
+        let TournamentMode::SwissSystem { num_rounds } = tournament.get_tournament_mode() else {
+            return Ok(vec![]);
+        };
+        if rounds.len() as u32 >= num_rounds {
+            return Ok(vec![]);
+        }
+
+        let entrants = group_entrants(group_matches);
+        let ranking: Vec<Uuid> = self
+            .rank_stage_group(config, stage, group_id, &entrants, group_matches)?
+            .iter()
+            .map(|s| s.get_entrant_id())
+            .collect();
+        let mut history = PairingHistory::from_matches(group_matches);
+        for bye in group_matches.iter().filter_map(|m| m.get_bye_entrant()) {
+            history.add_bye(*bye);
+        }
+        let round_number = rounds.len() as u32 + 1;
+        let pairings = self
+            .apply_pairing_overrides(
+                stage.get_id(),
+                round_number,
+                swiss_pairings(&ranking, &history),
+            )
+            .await?;
+
+        let sport_id = config.get_sport_id();
+        let plugin = self
+            .sport_plugins
+            .get(&sport_id)
+            .ok_or(SportError::UnknownSportId(sport_id))?;
+        let slot_duration =
+            plugin.estimate_match_duration(config)? + plugin.changeover_duration(config)?;
+        let slot_duration =
+            Duration::from_std(slot_duration).map_err(|e| SportError::Other(e.to_string()))?;
+        let num_played = pairings
+            .pairings
+            .iter()
+            .filter(|p| p.entrant_b.is_some())
+            .count();
+        let now = Utc::now();
+        let mut slots =
+            schedule_matches(tournament, stage, now, slot_duration, num_played).into_iter();
+
+        let round_id = Uuid::new_v4();
+        let group_number = group_matches
+            .first()
+            .map(Match::get_group_number)
+            .unwrap_or_default();
+        let mut next_round = Vec::with_capacity(pairings.pairings.len());
+        for (index, pairing) in pairings.pairings.iter().enumerate() {
+            let side_b = pairing
+                .entrant_b
+                .map_or(ScheduledEntrant::Bye, ScheduledEntrant::Entrant);
+            let mut m = Match::new_scheduled(
+                Uuid::new_v4(),
+                group_id,
+                round_id,
+                index as u32 + 1,
+                ScheduledEntrant::Entrant(pairing.entrant_a),
+                side_b,
+            );
+            m.set_tournament(tournament.get_id(), sport_id, stage.get_id())
+                .set_group_number(group_number);
+            if pairing.entrant_b.is_none() {
+                m.set_outcome(MatchOutcome::Bye)
+                    .set_slot(0, now.with_timezone(&Local));
+            } else if let Some(slot) = slots.next() {
+                m.set_slot(slot.station as u16, slot.start_at.with_timezone(&Local));
+            } else {
+                // no station available after the last station window; directors place the match
+                m.set_slot(0, now.with_timezone(&Local));
+            }
+            next_round.push(m);
+        }
+        let next_round = self.database.save_matches(&next_round).await?;
+        self.publish_saved_matches(&next_round).await?;
+        tracing::info!(
+            tournament_id = %tournament.get_id(),
+            %group_id,
+            round_number,
+            num_matches = next_round.len(),
+            "next_round_generated"
+        );
+        Ok(next_round)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn given_matches_of_rounds_when_round_ids_then_ordered_by_earliest_start() {
+        let (first, second) = (Uuid::new_v4(), Uuid::new_v4());
+        let at = |hour| chrono::TimeZone::with_ymd_and_hms(&Local, 2026, 5, 1, hour, 0, 0).unwrap();
+        let new_match = |round_id, hour| {
+            let mut m = Match::new_scheduled(
+                Uuid::new_v4(),
+                Uuid::nil(),
+                round_id,
+                1,
+                ScheduledEntrant::Bye,
+                ScheduledEntrant::Bye,
+            );
+            m.set_slot(1, at(hour));
+            m
+        };
+        let matches = vec![
+            new_match(second, 11),
+            new_match(first, 10),
+            new_match(second, 12),
+            new_match(first, 9),
+        ];
+        assert_eq!(round_ids(&matches), vec![first, second]);
+        assert!(round_ids(&[]).is_empty());
+    }
+
+    #[test]
+    fn given_matches_of_groups_when_stage_group_ids_then_ordered_by_first_slot() {
+        let (a, b) = (Uuid::new_v4(), Uuid::new_v4());
+        let at = |hour| chrono::TimeZone::with_ymd_and_hms(&Local, 2026, 5, 1, hour, 0, 0).unwrap();
+        let new_match = |group_id, station, hour| {
+            let mut m = Match::new_scheduled(
+                Uuid::new_v4(),
+                group_id,
+                Uuid::new_v4(),
+                1,
+                ScheduledEntrant::Bye,
+                ScheduledEntrant::Bye,
+            );
+            m.set_slot(station, at(hour));
+            m
+        };
+        let matches = vec![
+            new_match(b, 2, 9),
+            new_match(a, 1, 10),
+            new_match(a, 1, 9),
+            new_match(b, 2, 10),
+        ];
+        assert_eq!(stage_group_ids(&matches), vec![a, b]);
+    }
+
+    #[test]
+    fn given_group_numbers_when_stage_group_ids_then_ordered_by_number() {
+        let (a, b) = (Uuid::new_v4(), Uuid::new_v4());
+        let at = |hour| chrono::TimeZone::with_ymd_and_hms(&Local, 2026, 5, 1, hour, 0, 0).unwrap();
+        let new_match = |group_id, group_number, hour| {
+            let mut m = Match::new_scheduled(
+                Uuid::new_v4(),
+                group_id,
+                Uuid::new_v4(),
+                1,
+                ScheduledEntrant::Bye,
+                ScheduledEntrant::Bye,
+            );
+            m.set_group_number(group_number).set_slot(1, at(hour));
+            m
+        };
+        // group b starts first, e.g. after its slots were moved
+        let matches = vec![new_match(b, 1, 9), new_match(a, 0, 10)];
+        assert_eq!(stage_group_ids(&matches), vec![a, b]);
+    }
+}
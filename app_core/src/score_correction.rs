@@ -0,0 +1,437 @@
+//! corrections of final scores
+//!
+//! A final score may be corrected within the correction window, i.e. while the stage of the
+//! match is active. Scores of completed stages are corrected after a rollback to the snapshot
+//! of the stage (see [`Core::rollback_to_snapshot`]).
+//!
+//! A correction may change the winner of the match and the ranks of its group and thereby
+//! the entrants of dependent matches, e.g. placements of KO brackets, and the promotion to
+//! the next stage. Therefore corrections take two steps: [`Core::preview_score_correction`]
+//! lists the [`CorrectionImpact`], which directors confirm with
+//! [`Core::apply_score_correction`]. The correction is only applied, if its impact did not
+//! change in the meantime.
+
+use crate::{
+    CachedGroupStandings, Core, CoreError, CoreResult, CrMsg, CrTopic, DomainEvent, GroupStanding,
+    Match, MatchDependencyGraph, MatchWinner, ScheduledEntrant, SportConfig, SportError, Stage,
+    TournamentBase, check_match_result, utils::validation::FieldError,
+};
+use serde::{Deserialize, Serialize};
+use std::collections::{HashMap, HashSet};
+use uuid::Uuid;
+
+/// corrected final score of a match
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct ScoreCorrection {
+    pub match_id: Uuid,
+    pub score_a: Vec<u16>,
+    pub score_b: Vec<u16>,
+}
+
+/// change of the rank of an entrant in its group
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+pub struct RankChange {
+    pub entrant_id: Uuid,
+    pub old_rank: u32,
+    pub new_rank: u32,
+}
+
+/// impact of a score correction, which directors confirm before it is applied
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct CorrectionImpact {
+    pub correction: ScoreCorrection,
+    pub group_id: Uuid,
+    /// winner of the match changes
+    pub winner_changed: bool,
+    /// entrants of the group, whose rank changes, sorted by new rank
+    pub rank_changes: Vec<RankChange>,
+    /// dependent matches without result, whose entrants and therefore schedule may change
+    pub rescheduled_matches: Vec<Uuid>,
+    /// dependent matches with result, which directors must check after the correction
+    pub decided_matches: Vec<Uuid>,
+    /// ranks change in a stage, which promotes its entrants to a next stage
+    pub promotion_changed: bool,
+}
+
+impl CorrectionImpact {
+    /// Check if the correction affects nothing but the score of the match.
+    pub fn is_local(&self) -> bool {
+        !self.winner_changed
+            && self.rank_changes.is_empty()
+            && self.rescheduled_matches.is_empty()
+            && self.decided_matches.is_empty()
+    }
+}
+
+/// concrete entrants of the matches of group `group_id`, sorted by id
+fn group_entrants(matches: &[Match], group_id: Uuid) -> Vec<Uuid> {
+    let mut entrants: Vec<Uuid> = matches
+        .iter()
+        .filter(|m| *m.get_group_id() == group_id)
+        .flat_map(|m| {
+            let (side_a, side_b) = m.get_sides();
+            [side_a, side_b]
+        })
+        .filter_map(|side| match side {
+            ScheduledEntrant::Entrant(id) => Some(*id),
+            _ => None,
+        })
+        .collect();
+    entrants.sort();
+    entrants.dedup();
+    entrants
+}
+
+/// entrants, whose rank differs between `old` and `new` standings, sorted by new rank
+fn rank_changes(old: &[GroupStanding], new: &[GroupStanding]) -> Vec<RankChange> {
+    let old_ranks: HashMap<Uuid, u32> = old.iter().map(|s| (s.get_entrant_id(), s.rank)).collect();
+    new.iter()
+        .filter_map(|s| {
+            let old_rank = *old_ranks.get(&s.get_entrant_id())?;
+            (old_rank != s.rank).then_some(RankChange {
+                entrant_id: s.get_entrant_id(),
+                old_rank,
+                new_rank: s.rank,
+            })
+        })
+        .collect()
+}
+
+/// ids of matches, which depend directly or transitively on one of `sources`
+fn transitive_dependents(graph: &MatchDependencyGraph, sources: &[Uuid]) -> HashSet<Uuid> {
+    let mut dependents = HashSet::new();
+    let mut open: Vec<Uuid> = sources.to_vec();
+    while let Some(source) = open.pop() {
+        for dependent in graph.dependents(source) {
+            if dependents.insert(dependent) {
+                open.push(dependent);
+            }
+        }
+    }
+    dependents
+}
+
+/// corrected match with its standings before and after the correction
+struct CorrectionAnalysis {
+    corrected: Match,
+    new_standings: Vec<GroupStanding>,
+    impact: CorrectionImpact,
+}
+
+// corrections may be entered in every core state, e.g. by api clients
+impl<S> Core<S> {
+    /// Preview the impact of `correction` of a decided match of `stage` of `tournament`.
+    /// `matches` are all matches of the stage; `config` is the sport configuration of the
+    /// tournament. Nothing is changed.
+    pub fn preview_score_correction(
+        &self,
+        tournament: &TournamentBase,
+        stage: &Stage,
+        config: &SportConfig,
+        correction: &ScoreCorrection,
+        matches: &[Match],
+    ) -> CoreResult<CorrectionImpact> {
+        Ok(self
+            .analyze_correction(tournament, stage, config, correction, matches)?
+            .impact)
+    }
+
+    /// Apply the correction of the `confirmed` impact to `matches` of `stage` of
+    /// `tournament`. Standings of the group are recomputed and cached, dependent matches
+    /// without result are resolved again. Dependent matches with result are not changed.
+    /// If the impact changed since its preview, an error is returned and directors must
+    /// confirm the new impact. The corrected and all changed dependent matches are saved,
+    /// therefore `matches` are loaded with
+    /// [`DbpMatch::list_matches_of_stage_for_update`](crate::DbpMatch::list_matches_of_stage_for_update).
+    pub async fn apply_score_correction(
+        &self,
+        tournament: &TournamentBase,
+        stage: &Stage,
+        config: &SportConfig,
+        confirmed: &CorrectionImpact,
+        matches: &mut [Match],
+    ) -> CoreResult<CorrectionImpact> {
+        let analysis =
+            self.analyze_correction(tournament, stage, config, &confirmed.correction, matches)?;
+        if analysis.impact != *confirmed {
+            return Err(correction_error(
+                confirmed.correction.match_id,
+                "impact of correction changed; review and confirm it again",
+            ));
+        }
+        let impact = analysis.impact;
+        let match_id = impact.correction.match_id;
+        let group_id = impact.group_id;
+        let tournament_id = tournament.get_id();
+
+        // reset sides, which were resolved with the old result or old ranks
+        let rescheduled: HashSet<Uuid> = impact.rescheduled_matches.iter().copied().collect();
+        let mut reset_group_ranks = false;
+        for m in matches
+            .iter_mut()
+            .filter(|m| rescheduled.contains(m.get_id()))
+        {
+            m.unresolve_sides(|side| match side {
+                ScheduledEntrant::WinnerOf(id) | ScheduledEntrant::LoserOf(id) => {
+                    *id == match_id || rescheduled.contains(id)
+                }
+                _ => false,
+            });
+            if !impact.rank_changes.is_empty() {
+                reset_group_ranks |= m.unresolve_sides(
+                    |side| matches!(side, ScheduledEntrant::GroupRank(id, _) if *id == group_id),
+                );
+            }
+        }
+        let corrected = matches
+            .iter_mut()
+            .find(|m| *m.get_id() == match_id)
+            .expect("expecting corrected match in matches");
+        *corrected = analysis.corrected.clone();
+
+        let mut changed: HashSet<Uuid> = rescheduled;
+        changed.insert(match_id);
+        changed.extend(
+            self.resolve_match_dependencies(config, &analysis.corrected, matches)
+                .await?,
+        );
+        if reset_group_ranks {
+            changed.extend(
+                self.resolve_group_dependencies(group_id, &analysis.new_standings, matches)
+                    .await?,
+            );
+        }
+        let changed: Vec<Match> = matches
+            .iter()
+            .filter(|m| changed.contains(m.get_id()))
+            .cloned()
+            .collect();
+        let saved = self.database.save_matches(&changed).await?;
+        self.publish_saved_matches(&saved).await?;
+        for saved in saved {
+            if let Some(stored) = matches.iter_mut().find(|m| m.get_id() == saved.get_id()) {
+                *stored = saved;
+            }
+        }
+        let cached =
+            CachedGroupStandings::new(group_id, tournament_id, analysis.new_standings.clone());
+        self.database.save_group_standings(&cached).await?;
+        let notice = CrTopic::GroupMatches { group_id };
+        let msg = CrMsg::GroupStandingsUpdated {
+            id: group_id,
+            version: 0,
+        };
+        self.client_registry.publish(notice, msg).await?;
+
+        // sandbox tournaments must not leak to integrations
+        if !tournament.is_sandbox() {
+            self.emit_domain_event(DomainEvent::MatchScoreCorrected {
+                tournament_id,
+                match_id,
+                winner_changed: impact.winner_changed,
+                num_affected_matches: impact.rescheduled_matches.len()
+                    + impact.decided_matches.len(),
+            })
+            .await;
+        }
+        tracing::info!(
+            %tournament_id,
+            %match_id,
+            winner_changed = impact.winner_changed,
+            "score_corrected"
+        );
+        Ok(impact)
+    }
+
+    fn analyze_correction(
+        &self,
+        tournament: &TournamentBase,
+        stage: &Stage,
+        config: &SportConfig,
+        correction: &ScoreCorrection,
+        matches: &[Match],
+    ) -> CoreResult<CorrectionAnalysis> {
+        let match_id = correction.match_id;
+        let Some(original) = matches.iter().find(|m| *m.get_id() == match_id) else {
+            return Err(correction_error(match_id, "match does not exist"));
+        };
+        if *original.get_stage_id() != stage.get_id() {
+            return Err(correction_error(match_id, "match does not belong to stage"));
+        }
+        if !original.is_decided() {
+            return Err(correction_error(
+                match_id,
+                "match has no final result to correct",
+            ));
+        }
+        if tournament.is_stage_completed(stage.get_number()) {
+            return Err(correction_error(
+                match_id,
+                format!(
+                    "stage {} is completed; roll it back to correct its scores",
+                    stage.get_number() + 1
+                ),
+            ));
+        }
+        let sport_id = config.get_sport_id();
+        let plugin = self
+            .sport_plugins
+            .get(&sport_id)
+            .ok_or(SportError::UnknownSportId(sport_id))?;
+        check_match_result(original, &correction.score_a, &correction.score_b)?;
+        let mut corrected = original.clone();
+        corrected.set_scores(correction.score_a.clone(), correction.score_b.clone());
+        plugin.validate_final_score(config, &corrected)?;
+
+        let old_winner = MatchWinner::of_match(plugin.as_ref(), config, original)?;
+        let new_winner = MatchWinner::of_match(plugin.as_ref(), config, &corrected)?;
+        let winner_changed = old_winner != new_winner;
+
+        let group_id = *original.get_group_id();
+        let entrants = group_entrants(matches, group_id);
+        let old_standings = self.rank_stage_group(config, stage, group_id, &entrants, matches)?;
+        let corrected_matches: Vec<Match> = matches
+            .iter()
+            .map(|m| {
+                if *m.get_id() == match_id {
+                    corrected.clone()
+                } else {
+                    m.clone()
+                }
+            })
+            .collect();
+        let new_standings =
+            self.rank_stage_group(config, stage, group_id, &entrants, &corrected_matches)?;
+        let rank_changes = rank_changes(&old_standings, &new_standings);
+
+        let mut sources = Vec::new();
+        if winner_changed {
+            sources.push(match_id);
+        }
+        if !rank_changes.is_empty() {
+            sources.push(group_id);
+        }
+        let graph = MatchDependencyGraph::new(matches);
+        let dependents = transitive_dependents(&graph, &sources);
+        let (decided, scheduled): (Vec<&Match>, Vec<&Match>) = matches
+            .iter()
+            .filter(|m| dependents.contains(m.get_id()))
+            .partition(|m| m.is_decided());
+        let ids = |matches: Vec<&Match>| {
+            let mut ids: Vec<Uuid> = matches.into_iter().map(|m| *m.get_id()).collect();
+            ids.sort();
+            ids
+        };
+        let promotion_changed = !rank_changes.is_empty()
+            && stage.get_number() + 1 < tournament.get_tournament_mode().get_num_of_stages();
+
+        Ok(CorrectionAnalysis {
+            corrected,
+            new_standings,
+            impact: CorrectionImpact {
+                correction: correction.clone(),
+                group_id,
+                winner_changed,
+                rank_changes,
+                rescheduled_matches: ids(scheduled),
+                decided_matches: ids(decided),
+                promotion_changed,
+            },
+        })
+    }
+}
+
+fn correction_error(match_id: Uuid, message: impl Into<String>) -> CoreError {
+    CoreError::from(
+        FieldError::builder()
+            .set_field(String::from("score"))
+            .add_message(message)
+            .set_object_id(match_id)
+            .build(),
+    )
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::EntrantGroupScore;
+
+    fn standings(group_id: Uuid, ranks: &[(Uuid, u32)]) -> Vec<GroupStanding> {
+        ranks
+            .iter()
+            .map(|&(entrant_id, rank)| GroupStanding {
+                rank,
+                score: EntrantGroupScore::new(entrant_id, group_id),
+            })
+            .collect()
+    }
+
+    #[test]
+    fn given_swapped_ranks_when_rank_changes_then_only_moved_entrants() {
+        let group_id = Uuid::new_v4();
+        let (a, b, c) = (Uuid::new_v4(), Uuid::new_v4(), Uuid::new_v4());
+        let old = standings(group_id, &[(a, 1), (b, 2), (c, 3)]);
+        let new = standings(group_id, &[(b, 1), (a, 2), (c, 3)]);
+
+        assert_eq!(
+            rank_changes(&old, &new),
+            vec![
+                RankChange {
+                    entrant_id: b,
+                    old_rank: 2,
+                    new_rank: 1
+                },
+                RankChange {
+                    entrant_id: a,
+                    old_rank: 1,
+                    new_rank: 2
+                },
+            ]
+        );
+        assert!(rank_changes(&old, &old).is_empty());
+    }
+
+    #[test]
+    fn given_resolved_bracket_when_transitive_dependents_then_all_later_rounds() {
+        let (quarter, semi, last, other) = (
+            Uuid::new_v4(),
+            Uuid::new_v4(),
+            Uuid::new_v4(),
+            Uuid::new_v4(),
+        );
+        let scheduled = |id, side_a, side_b| {
+            Match::new_scheduled(id, Uuid::nil(), Uuid::nil(), 1, side_a, side_b)
+        };
+        let mut matches = vec![
+            scheduled(
+                semi,
+                ScheduledEntrant::WinnerOf(quarter),
+                ScheduledEntrant::WinnerOf(other),
+            ),
+            scheduled(
+                last,
+                ScheduledEntrant::WinnerOf(semi),
+                ScheduledEntrant::Entrant(Uuid::new_v4()),
+            ),
+        ];
+        // resolved sides still depend on their scheduled source
+        let winner = Uuid::new_v4();
+        assert!(matches[0].resolve_sides(|side| {
+            (*side == ScheduledEntrant::WinnerOf(quarter)).then_some(winner)
+        }));
+
+        let graph = MatchDependencyGraph::new(&matches);
+
+        assert_eq!(
+            transitive_dependents(&graph, &[quarter]),
+            HashSet::from([semi, last])
+        );
+        assert!(transitive_dependents(&graph, &[last]).is_empty());
+
+        assert!(matches[0].unresolve_sides(|side| *side == ScheduledEntrant::WinnerOf(quarter)));
+        assert_eq!(
+            matches[0].get_sides().0,
+            &ScheduledEntrant::WinnerOf(quarter)
+        );
+    }
+}
@@ -0,0 +1,104 @@
+// score sheets of matches for referees
+
+use crate::{Core, CoreResult, Match, ScheduledEntrant, utils::list_order::ListOrder};
+use chrono::{DateTime, Local};
+use serde::{Deserialize, Serialize};
+use std::collections::HashMap;
+use uuid::Uuid;
+
+/// Printable score sheet of a match. Referees write the score of each set into blank boxes.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct ScoreSheet {
+    pub match_id: Uuid,
+    pub tournament_id: Uuid,
+    pub tournament_name: String,
+    pub sport_name: String,
+    pub match_number: u32,
+    /// names of both sides; sides, which are not yet resolved to an entrant, are `TBD`
+    pub side_a: String,
+    pub side_b: String,
+    /// name of station, e.g. `Table 3` or the name of a named station
+    pub station: String,
+    pub start_at: DateTime<Local>,
+    /// number of set boxes per side
+    pub num_sets: u16,
+}
+
+impl<S> Core<S> {
+    /// Assemble the score sheet of match `m`. The number of set boxes is taken from the first
+    /// valid configuration of the sport. Returns `None`, if the tournament of the match does
+    /// not exist.
+    pub async fn score_sheet_of_match(&self, m: &Match) -> CoreResult<Option<ScoreSheet>> {
+        let tournament_id = *m.get_tournament_id();
+        let mut base_core = self.as_tournament_base_state();
+        let Some(tournament) = base_core.load(tournament_id).await?.cloned() else {
+            return Ok(None);
+        };
+        let entrant_names: HashMap<Uuid, String> = self
+            .as_entrant_state(tournament_id)
+            .list_entrants()
+            .await?
+            .into_iter()
+            .map(|e| (e.get_id(), e.get_name().to_string()))
+            .collect();
+        let side_name = |side: &ScheduledEntrant| match side {
+            ScheduledEntrant::Entrant(id) => entrant_names
+                .get(id)
+                .cloned()
+                .unwrap_or_else(|| "TBD".to_string()),
+            _ => "TBD".to_string(),
+        };
+        let (side_a, side_b) = m.get_sides();
+
+        let sport_id = tournament.get_sport_id();
+        let plugin = self.sport_plugins.get(&sport_id);
+        let station = match tournament.get_station_name(m.get_station() as u32) {
+            Some(name) => name.to_string(),
+            None => {
+                let label = plugin
+                    .as_ref()
+                    .map(|p| p.capabilities().station_label())
+                    .unwrap_or_else(|| "Station".to_string());
+                format!("{label} {}", m.get_station())
+            }
+        };
+        let mut num_sets = 1;
+        if let Some(plugin) = plugin.as_ref() {
+            for id in self
+                .database
+                .list_sport_config_ids(sport_id, None, false, None, &ListOrder::default())
+                .await?
+            {
+                if let Some(mut config) = self.database.get_sport_config(id).await?
+                    && config.migrate(plugin.as_ref()).is_ok()
+                    && let Ok(max_sets) = plugin.max_sets(&config)
+                {
+                    num_sets = max_sets;
+                    break;
+                }
+            }
+        }
+
+        Ok(Some(ScoreSheet {
+            match_id: *m.get_id(),
+            tournament_id,
+            tournament_name: tournament.get_name().to_string(),
+            sport_name: plugin.map(|p| p.name()).unwrap_or_default().to_string(),
+            match_number: m.get_number(),
+            side_a: side_name(side_a),
+            side_b: side_name(side_b),
+            station,
+            start_at: m.get_start_at(),
+            num_sets,
+        }))
+    }
+
+    /// Load the score sheet of the match with id `match_id`. Returns `None`, if the match
+    /// or its tournament does not exist.
+    pub async fn load_score_sheet(&self, match_id: Uuid) -> CoreResult<Option<ScoreSheet>> {
+        let Some(m) = self.database.get_match(match_id).await? else {
+            return Ok(None);
+        };
+        self.score_sheet_of_match(&m).await
+    }
+}
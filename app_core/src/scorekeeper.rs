@@ -0,0 +1,733 @@
+//! access links for scorekeepers, which grant result entry for single groups or stations
+
+use crate::{
+    AuditAction, AuditObjectType, Core, CoreError, CoreResult, CrMsg, CrTopic, DbError, Match,
+    hash_api_token_secret, stage_group_ids,
+    tournament::slots::group_label,
+    utils::{id_version::IdVersion, normalize::*, traits::ObjectIdVersion, validation::*},
+};
+use chrono::{DateTime, Duration, Utc};
+use serde::{Deserialize, Serialize};
+use std::{
+    collections::HashMap,
+    fmt::{self, Display},
+};
+use uuid::Uuid;
+
+/// prefix of every scorekeeper secret; distinguishes them from api token secrets
+pub const SCOREKEEPER_SECRET_PREFIX: &str = "fks_";
+
+/// number of characters of the secret, which are stored in clear text to identify a token
+const SCOREKEEPER_DISPLAY_PREFIX_LEN: usize = SCOREKEEPER_SECRET_PREFIX.len() + 6;
+
+/// `last_used_at` is only updated, if it is older than this, to avoid a write per request
+const LAST_USED_RESOLUTION_SECONDS: i64 = 60;
+
+/// Part of a tournament, for which a scorekeeper may enter results.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash, Serialize, Deserialize)]
+#[serde(tag = "kind", rename_all = "kebab-case")]
+pub enum ScorekeeperGrant {
+    /// all matches of one group of a stage
+    Group {
+        /// number of stage, starting with 0
+        stage_number: u32,
+        /// index of group in stage, 0 is group `A`
+        group_index: u32,
+    },
+    /// all matches played at one station (e.g. court or table), starting with 1
+    Station { station: u32 },
+}
+
+impl Display for ScorekeeperGrant {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            ScorekeeperGrant::Group {
+                stage_number,
+                group_index,
+            } => write!(
+                f,
+                "Stage {} / Group {}",
+                stage_number + 1,
+                group_label(*group_index as usize)
+            ),
+            ScorekeeperGrant::Station { station } => write!(f, "Station {station}"),
+        }
+    }
+}
+
+/// Access token of a scorekeeper for one tournament. The secret is part of the access link
+/// handed out to the scorekeeper; only its hash is stored.
+#[derive(Clone, Debug, PartialEq, Eq, Serialize, Deserialize, Default, ObjectIdVersion)]
+pub struct ScorekeeperToken {
+    /// id and optimistic locking version of token
+    id_version: IdVersion,
+    /// tournament, the token grants access to
+    tournament_id: Uuid,
+    /// label of token, e.g. name of scorekeeper or station
+    label: String,
+    /// groups and stations the scorekeeper may enter results for
+    grants: Vec<ScorekeeperGrant>,
+    /// first characters of the secret to identify the token in lists
+    secret_prefix: String,
+    /// hash of the secret, see [`hash_api_token_secret`]
+    secret_hash: String,
+    /// end of validity; tokens without expiry are valid until revoked
+    expires_at: Option<DateTime<Utc>>,
+    /// timestamp of creation; set by database
+    created_at: Option<DateTime<Utc>>,
+    /// timestamp of last authenticated request
+    last_used_at: Option<DateTime<Utc>>,
+    /// timestamp of revocation; revoked tokens are rejected
+    revoked_at: Option<DateTime<Utc>>,
+}
+
+impl ScorekeeperToken {
+    /// Create a new `ScorekeeperToken` with the given `IdVersion`.
+    pub fn new(id_version: IdVersion) -> Self {
+        ScorekeeperToken {
+            id_version,
+            ..Default::default()
+        }
+    }
+
+    /// Get the unique identifier of the token.
+    pub fn get_id(&self) -> Uuid {
+        self.id_version.get_id()
+    }
+
+    /// Get the version number of the token.
+    pub fn get_version(&self) -> Option<u32> {
+        self.id_version.get_version()
+    }
+
+    /// Get the id of the tournament.
+    pub fn get_tournament_id(&self) -> Uuid {
+        self.tournament_id
+    }
+
+    /// Get the label of the token.
+    pub fn get_label(&self) -> &str {
+        &self.label
+    }
+
+    /// Get the granted groups and stations.
+    pub fn get_grants(&self) -> &[ScorekeeperGrant] {
+        &self.grants
+    }
+
+    /// Returns true, if results of a match of group `group_index` of stage `stage_number`
+    /// played at `station` may be entered with this token.
+    pub fn allows_match(&self, stage_number: u32, group_index: u32, station: Option<u32>) -> bool {
+        self.grants.iter().any(|grant| match grant {
+            ScorekeeperGrant::Group {
+                stage_number: s,
+                group_index: g,
+            } => *s == stage_number && *g == group_index,
+            ScorekeeperGrant::Station { station: s } => station == Some(*s),
+        })
+    }
+
+    /// Get the first characters of the secret.
+    pub fn get_secret_prefix(&self) -> &str {
+        &self.secret_prefix
+    }
+
+    /// Get the hash of the secret.
+    pub fn get_secret_hash(&self) -> &str {
+        &self.secret_hash
+    }
+
+    /// Get the end of validity.
+    pub fn get_expires_at(&self) -> Option<DateTime<Utc>> {
+        self.expires_at
+    }
+
+    /// Check if the token is expired at `now`.
+    pub fn is_expired_at(&self, now: DateTime<Utc>) -> bool {
+        self.expires_at.is_some_and(|e| e <= now)
+    }
+
+    /// Get the timestamp of creation, if token is persisted.
+    pub fn get_created_at(&self) -> Option<DateTime<Utc>> {
+        self.created_at
+    }
+
+    /// Get the timestamp of the last authenticated request.
+    pub fn get_last_used_at(&self) -> Option<DateTime<Utc>> {
+        self.last_used_at
+    }
+
+    /// Get the timestamp of revocation.
+    pub fn get_revoked_at(&self) -> Option<DateTime<Utc>> {
+        self.revoked_at
+    }
+
+    /// Check if the token is revoked.
+    pub fn is_revoked(&self) -> bool {
+        self.revoked_at.is_some()
+    }
+
+    /// Set the `IdVersion` of the token.
+    pub fn set_id_version(&mut self, id_version: IdVersion) -> &mut Self {
+        self.id_version = id_version;
+        self
+    }
+
+    /// Set the id of the tournament.
+    pub fn set_tournament_id(&mut self, tournament_id: Uuid) -> &mut Self {
+        self.tournament_id = tournament_id;
+        self
+    }
+
+    /// Set the label of the token with whitespace normalization.
+    pub fn set_label(&mut self, label: impl Into<String>) -> &mut Self {
+        self.label = normalize_ws(label);
+        self
+    }
+
+    /// Set the granted groups and stations. Duplicates are removed, order is kept.
+    pub fn set_grants(&mut self, grants: impl IntoIterator<Item = ScorekeeperGrant>) -> &mut Self {
+        self.grants.clear();
+        for grant in grants {
+            if !self.grants.contains(&grant) {
+                self.grants.push(grant);
+            }
+        }
+        self
+    }
+
+    /// Set prefix and hash of the secret. Only used at creation and by database adapters.
+    pub fn set_secret_prefix_and_hash(
+        &mut self,
+        secret_prefix: impl Into<String>,
+        secret_hash: impl Into<String>,
+    ) -> &mut Self {
+        self.secret_prefix = secret_prefix.into();
+        self.secret_hash = secret_hash.into();
+        self
+    }
+
+    /// Set the end of validity.
+    pub fn set_expires_at(&mut self, expires_at: Option<DateTime<Utc>>) -> &mut Self {
+        self.expires_at = expires_at;
+        self
+    }
+
+    /// Set the timestamp of creation. Only used by database adapters.
+    pub fn set_created_at(&mut self, created_at: Option<DateTime<Utc>>) -> &mut Self {
+        self.created_at = created_at;
+        self
+    }
+
+    /// Set the timestamp of the last authenticated request. Only used by database adapters.
+    pub fn set_last_used_at(&mut self, last_used_at: Option<DateTime<Utc>>) -> &mut Self {
+        self.last_used_at = last_used_at;
+        self
+    }
+
+    /// Set the timestamp of revocation.
+    pub fn set_revoked_at(&mut self, revoked_at: Option<DateTime<Utc>>) -> &mut Self {
+        self.revoked_at = revoked_at;
+        self
+    }
+
+    /// Validate the token.
+    pub fn validate(&self) -> ValidationResult<()> {
+        let mut errs = ValidationErrors::new();
+        let object_id = self.get_id();
+
+        if self.tournament_id.is_nil() {
+            errs.add(
+                FieldError::builder()
+                    .set_field(String::from("tournament_id"))
+                    .add_required()
+                    .set_object_id(object_id)
+                    .build(),
+            );
+        }
+        if self.label.is_empty() {
+            errs.add(
+                FieldError::builder()
+                    .set_field(String::from("label"))
+                    .add_required()
+                    .set_object_id(object_id)
+                    .build(),
+            );
+        }
+        if self.grants.is_empty() {
+            errs.add(
+                FieldError::builder()
+                    .set_field(String::from("grants"))
+                    .add_required()
+                    .add_message("At least one group or station is required")
+                    .set_object_id(object_id)
+                    .build(),
+            );
+        }
+        if self
+            .grants
+            .contains(&ScorekeeperGrant::Station { station: 0 })
+        {
+            errs.add(
+                FieldError::builder()
+                    .set_field(String::from("grants"))
+                    .add_user_defined_code("out_of_range")
+                    .add_message("Stations start with 1")
+                    .set_object_id(object_id)
+                    .build(),
+            );
+        }
+        if self.secret_hash.is_empty() {
+            errs.add(
+                FieldError::builder()
+                    .set_field(String::from("secret_hash"))
+                    .add_required()
+                    .set_object_id(object_id)
+                    .build(),
+            );
+        }
+
+        if errs.is_empty() { Ok(()) } else { Err(errs) }
+    }
+}
+
+/// What a scorekeeper sees after opening an access link. Contains no secrets.
+#[derive(Clone, Debug, PartialEq, Eq, Serialize, Deserialize)]
+pub struct ScorekeeperAccess {
+    pub token_id: Uuid,
+    pub tournament_id: Uuid,
+    pub tournament_name: String,
+    pub label: String,
+    pub grants: Vec<ScorekeeperGrant>,
+    pub expires_at: Option<DateTime<Utc>>,
+}
+
+/// Match of a grant, which waits for its result, see [`Core::list_scorekeeper_matches`].
+#[derive(Clone, Debug, PartialEq, Eq, Serialize, Deserialize)]
+pub struct ScorekeeperMatch {
+    pub match_id: Uuid,
+    /// number of match in its round
+    pub number: u32,
+    /// station of match; 0, if the match is not placed yet
+    pub station: u32,
+    pub start_at: DateTime<Utc>,
+    /// name of entrant of side a
+    pub entrant_a: String,
+    /// name of entrant of side b
+    pub entrant_b: String,
+}
+
+/// State for scorekeeper token operations of one tournament
+pub struct ScorekeeperTokenState {
+    tournament_id: Uuid,
+    token: ScorekeeperToken,
+}
+
+// switch state to scorekeeper token state
+impl<S> Core<S> {
+    pub fn as_scorekeeper_token_state(&self, tournament_id: Uuid) -> Core<ScorekeeperTokenState> {
+        let mut token = ScorekeeperToken::new(IdVersion::NewWithId(Uuid::new_v4()));
+        token.set_tournament_id(tournament_id);
+        self.switch_state(ScorekeeperTokenState {
+            tournament_id,
+            token,
+        })
+    }
+
+    /// Authenticate a scorekeeper with the `secret` of an access link.
+    ///
+    /// Returns `None`, if no token with this secret exists or if it is revoked or expired.
+    /// Updates `last_used_at` of the token with a resolution of one minute.
+    pub async fn authenticate_scorekeeper(
+        &self,
+        secret: &str,
+    ) -> CoreResult<Option<ScorekeeperToken>> {
+        if !secret.starts_with(SCOREKEEPER_SECRET_PREFIX) {
+            return Ok(None);
+        }
+        let hash = hash_api_token_secret(secret);
+        let Some(mut token) = self.database.get_scorekeeper_token_by_hash(&hash).await? else {
+            return Ok(None);
+        };
+        let now = Utc::now();
+        if token.is_revoked() || token.is_expired_at(now) {
+            return Ok(None);
+        }
+        if token
+            .get_last_used_at()
+            .is_none_or(|t| now - t >= Duration::seconds(LAST_USED_RESOLUTION_SECONDS))
+        {
+            self.database
+                .touch_scorekeeper_token_last_used(token.get_id(), now)
+                .await?;
+            token.set_last_used_at(Some(now));
+        }
+        Ok(Some(token))
+    }
+
+    /// Load the access of a scorekeeper with the `secret` of an access link.
+    ///
+    /// Returns `None`, if the secret is not valid or the tournament does not exist anymore.
+    pub async fn load_scorekeeper_access(
+        &self,
+        secret: &str,
+    ) -> CoreResult<Option<ScorekeeperAccess>> {
+        let Some(token) = self.authenticate_scorekeeper(secret).await? else {
+            return Ok(None);
+        };
+        let mut base_core = self.as_tournament_base_state();
+        let Some(tournament) = base_core.load(token.get_tournament_id()).await? else {
+            return Ok(None);
+        };
+        Ok(Some(ScorekeeperAccess {
+            token_id: token.get_id(),
+            tournament_id: token.get_tournament_id(),
+            tournament_name: tournament.get_name().to_string(),
+            label: token.get_label().to_string(),
+            grants: token.get_grants().to_vec(),
+            expires_at: token.get_expires_at(),
+        }))
+    }
+
+    /// List the matches of `grant` of the scorekeeper with the `secret` of an access link,
+    /// which have both entrants and wait for their result, in order of their start.
+    ///
+    /// Returns `None`, if the secret is not valid or does not grant `grant`.
+    pub async fn list_scorekeeper_matches(
+        &self,
+        secret: &str,
+        grant: ScorekeeperGrant,
+    ) -> CoreResult<Option<Vec<ScorekeeperMatch>>> {
+        let Some(token) = self.authenticate_scorekeeper(secret).await? else {
+            return Ok(None);
+        };
+        if !token.get_grants().contains(&grant) {
+            return Ok(None);
+        }
+        let tournament_id = token.get_tournament_id();
+        let mut matches = self
+            .database
+            .list_matches_of_tournament(tournament_id)
+            .await?;
+        match grant {
+            ScorekeeperGrant::Station { station } => {
+                matches.retain(|m| m.get_station() as u32 == station);
+            }
+            ScorekeeperGrant::Group {
+                stage_number,
+                group_index,
+            } => {
+                let mut stage_ids: Vec<Uuid> = matches.iter().map(|m| *m.get_stage_id()).collect();
+                stage_ids.sort();
+                stage_ids.dedup();
+                let mut granted_stage_id = None;
+                for stage_id in stage_ids {
+                    if let Some(stage) = self.database.get_stage_by_id(stage_id).await?
+                        && stage.get_number() == stage_number
+                    {
+                        granted_stage_id = Some(stage_id);
+                        break;
+                    }
+                }
+                matches.retain(|m| Some(*m.get_stage_id()) == granted_stage_id);
+                let group_id = stage_group_ids(&matches).get(group_index as usize).copied();
+                matches.retain(|m| Some(*m.get_group_id()) == group_id);
+            }
+        }
+        let names: HashMap<Uuid, String> = self
+            .database
+            .list_entrants_of_tournament(tournament_id)
+            .await?
+            .into_iter()
+            .map(|e| (e.get_id(), e.get_name().to_string()))
+            .collect();
+        let name = |id: &Uuid| names.get(id).cloned().unwrap_or_default();
+        Ok(Some(
+            matches
+                .iter()
+                .filter(|m| !m.is_decided())
+                .filter_map(|m| {
+                    let (entrant_a, entrant_b) = m.get_entrants()?;
+                    Some(ScorekeeperMatch {
+                        match_id: *m.get_id(),
+                        number: m.get_number(),
+                        station: m.get_station() as u32,
+                        start_at: m.get_start_at().with_timezone(&Utc),
+                        entrant_a: name(entrant_a),
+                        entrant_b: name(entrant_b),
+                    })
+                })
+                .collect(),
+        ))
+    }
+
+    /// Enter the final result of the match with id `match_id` for the scorekeeper with the
+    /// `secret` of an access link, see [`Core::enter_match_result`].
+    ///
+    /// Returns `None`, if the secret is not valid or the match does not exist. Matches of
+    /// other tournaments or outside of the granted groups and stations are rejected.
+    pub async fn enter_scorekeeper_match_result(
+        &self,
+        secret: &str,
+        match_id: Uuid,
+        score_a: Vec<u16>,
+        score_b: Vec<u16>,
+    ) -> CoreResult<Option<Match>> {
+        let Some(token) = self.authenticate_scorekeeper(secret).await? else {
+            return Ok(None);
+        };
+        let Some(m) = self.database.get_match(match_id).await? else {
+            return Ok(None);
+        };
+        if !self.scorekeeper_allows_match(&token, &m).await? {
+            tracing::warn!(token_id = %token.get_id(), %match_id, "scorekeeper_match_not_granted");
+            return Err(FieldError::builder()
+                .set_field(String::from("match_id"))
+                .add_user_defined_code("not_granted")
+                .add_message("Match is not granted to this scorekeeper")
+                .set_object_id(match_id)
+                .build()
+                .into());
+        }
+        self.enter_match_result(match_id, score_a, score_b).await
+    }
+
+    /// Check if `token` grants result entry for match `m`.
+    async fn scorekeeper_allows_match(
+        &self,
+        token: &ScorekeeperToken,
+        m: &Match,
+    ) -> CoreResult<bool> {
+        if *m.get_tournament_id() != token.get_tournament_id() {
+            return Ok(false);
+        }
+        let Some(stage) = self.database.get_stage_by_id(*m.get_stage_id()).await? else {
+            return Ok(false);
+        };
+        let matches = self
+            .database
+            .list_matches_of_stage_for_update(stage.get_id())
+            .await?;
+        let Some(group_index) = stage_group_ids(&matches)
+            .iter()
+            .position(|id| id == m.get_group_id())
+        else {
+            return Ok(false);
+        };
+        let station = (m.get_station() > 0).then_some(m.get_station() as u32);
+        Ok(token.allows_match(stage.get_number(), group_index as u32, station))
+    }
+}
+
+impl Core<ScorekeeperTokenState> {
+    pub fn get(&self) -> &ScorekeeperToken {
+        &self.state.token
+    }
+    pub fn get_mut(&mut self) -> &mut ScorekeeperToken {
+        &mut self.state.token
+    }
+    /// Load the token with `id`, if it belongs to the tournament of this state.
+    pub async fn load(&mut self, id: Uuid) -> CoreResult<Option<&ScorekeeperToken>> {
+        match self.database.get_scorekeeper_token(id).await? {
+            Some(token) if token.get_tournament_id() == self.state.tournament_id => {
+                self.state.token = token;
+                Ok(Some(self.get()))
+            }
+            _ => Ok(None),
+        }
+    }
+    /// Create the token prepared with `get_mut()` with a new random secret.
+    ///
+    /// Returns the saved token and its secret. The secret is not stored and cannot be
+    /// retrieved later.
+    pub async fn create(&mut self) -> CoreResult<(&ScorekeeperToken, String)> {
+        if !self.state.token.get_id_version().is_new() {
+            return Err(CoreError::from(DbError::UniqueViolation(Some(
+                "scorekeeper_tokens_pkey".into(),
+            ))));
+        }
+        // uuid v4 uses the random number generator of the OS
+        let secret = format!(
+            "{SCOREKEEPER_SECRET_PREFIX}{}{}",
+            Uuid::new_v4().simple(),
+            Uuid::new_v4().simple()
+        );
+        self.state.token.set_secret_prefix_and_hash(
+            &secret[..SCOREKEEPER_DISPLAY_PREFIX_LEN],
+            hash_api_token_secret(&secret),
+        );
+        self.save().await?;
+        Ok((self.get(), secret))
+    }
+    /// Save changes of label, grants or expiry of the loaded token.
+    ///
+    /// Granted groups must exist in the stages of the tournament.
+    pub async fn save(&mut self) -> CoreResult<&ScorekeeperToken> {
+        self.state.token.set_tournament_id(self.state.tournament_id);
+        self.state.token.validate()?;
+        self.validate_grants().await?;
+        // stored copy before the save for the audit log
+        let old = match self.state.token.get_version() {
+            Some(_) => {
+                let id = self.state.token.get_id();
+                self.database.get_scorekeeper_token(id).await?
+            }
+            None => None,
+        };
+        self.state.token = self
+            .database
+            .save_scorekeeper_token(&self.state.token)
+            .await?;
+
+        // publish change of scorekeeper token to client registry
+        let id = self.state.token.get_id();
+        let version =
+            self.state.token.get_version().expect(
+                "expecting save_scorekeeper_token to return always an existing id and version",
+            );
+        let msg = CrMsg::ScorekeeperTokenUpdated { id, version };
+        self.client_registry
+            .publish(
+                CrTopic::ScorekeeperTokens {
+                    tournament_id: self.state.tournament_id,
+                },
+                msg,
+            )
+            .await?;
+        let action = match &old {
+            None => AuditAction::Create,
+            Some(old) if !old.is_revoked() && self.state.token.is_revoked() => AuditAction::Revoke,
+            Some(_) => AuditAction::Update,
+        };
+        self.audit(
+            action,
+            AuditObjectType::ScorekeeperToken,
+            Some(self.state.tournament_id),
+            old.as_ref(),
+            Some(&self.state.token),
+        )
+        .await;
+        Ok(self.get())
+    }
+    /// Revoke the loaded token. Revoked tokens are kept for auditing.
+    pub async fn revoke(&mut self) -> CoreResult<&ScorekeeperToken> {
+        if self.state.token.get_id_version().is_new() {
+            return Err(CoreError::from(DbError::NotFound));
+        }
+        self.state.token.set_revoked_at(Some(Utc::now()));
+        self.save().await
+    }
+    pub async fn list_tokens(&self, include_revoked: bool) -> CoreResult<Vec<ScorekeeperToken>> {
+        let list = self
+            .database
+            .list_scorekeeper_tokens(self.state.tournament_id, include_revoked)
+            .await?;
+        Ok(list)
+    }
+    async fn validate_grants(&self) -> CoreResult<()> {
+        let mut stage_core = self.as_stage_state(self.state.tournament_id);
+        for grant in self.state.token.get_grants() {
+            let ScorekeeperGrant::Group {
+                stage_number,
+                group_index,
+            } = *grant
+            else {
+                continue;
+            };
+            let exists = stage_core
+                .load_by_number(stage_number)
+                .await?
+                .is_some_and(|stage| group_index < stage.get_num_groups());
+            if !exists {
+                return Err(FieldError::builder()
+                    .set_field(String::from("grants"))
+                    .add_user_defined_code("unknown_group")
+                    .add_message(format!("{grant} does not exist"))
+                    .set_object_id(self.state.token.get_id())
+                    .build()
+                    .into());
+            }
+        }
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn grants_are_deduplicated_in_order() {
+        let mut token = ScorekeeperToken::default();
+        token.set_grants([
+            ScorekeeperGrant::Station { station: 2 },
+            ScorekeeperGrant::Group {
+                stage_number: 0,
+                group_index: 1,
+            },
+            ScorekeeperGrant::Station { station: 2 },
+        ]);
+        assert_eq!(
+            token.get_grants(),
+            &[
+                ScorekeeperGrant::Station { station: 2 },
+                ScorekeeperGrant::Group {
+                    stage_number: 0,
+                    group_index: 1
+                }
+            ]
+        );
+    }
+
+    #[test]
+    fn allows_only_granted_groups_and_stations() {
+        let mut token = ScorekeeperToken::default();
+        token.set_grants([
+            ScorekeeperGrant::Group {
+                stage_number: 0,
+                group_index: 1,
+            },
+            ScorekeeperGrant::Station { station: 3 },
+        ]);
+        assert!(token.allows_match(0, 1, None));
+        assert!(!token.allows_match(1, 1, None));
+        assert!(!token.allows_match(0, 0, Some(2)));
+        assert!(token.allows_match(1, 0, Some(3)));
+    }
+
+    #[test]
+    fn grants_display_and_serialize() {
+        let grant = ScorekeeperGrant::Group {
+            stage_number: 0,
+            group_index: 2,
+        };
+        assert_eq!(grant.to_string(), "Stage 1 / Group C");
+        assert_eq!(
+            serde_json::to_string(&ScorekeeperGrant::Station { station: 4 }).unwrap(),
+            r#"{"kind":"station","station":4}"#
+        );
+    }
+
+    #[test]
+    fn expiry_is_exclusive() {
+        let now = Utc::now();
+        let mut token = ScorekeeperToken::default();
+        assert!(!token.is_expired_at(now));
+        token.set_expires_at(Some(now));
+        assert!(token.is_expired_at(now));
+        assert!(!token.is_expired_at(now - Duration::seconds(1)));
+    }
+
+    #[test]
+    fn given_token_with_station_zero_when_validate_then_errors() {
+        let mut token = ScorekeeperToken::new(IdVersion::NewWithId(Uuid::new_v4()));
+        token
+            .set_tournament_id(Uuid::new_v4())
+            .set_label("Court 1")
+            .set_grants([ScorekeeperGrant::Station { station: 0 }])
+            .set_secret_prefix_and_hash("fks_abcdef", "hash");
+        let errs = token.validate().unwrap_err();
+        let codes: Vec<_> = errs.errors.iter().map(|e| e.get_code()).collect();
+        assert_eq!(codes, vec!["out_of_range"]);
+    }
+}
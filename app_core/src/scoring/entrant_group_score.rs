@@ -1,5 +1,6 @@
 // entrant group scoring
 
+use serde::{Deserialize, Serialize};
 use uuid::Uuid;
 
 /// EntrantGroupScore is used to collect the total score of an entrant over
@@ -10,7 +11,7 @@ use uuid::Uuid;
 ///
 // ToDo: remove allow(dead_code) flag
 #[allow(dead_code)]
-#[derive(Debug, Clone)]
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
 pub struct EntrantGroupScore {
     /// id of entrant
     pub entrant_id: Uuid,
@@ -26,6 +27,15 @@ pub struct EntrantGroupScore {
     pub relative_score: i16,
     /// total own score points over all matches
     pub total_score: u16,
+    /// number of won matches
+    pub wins: u16,
+    /// number of drawn matches
+    pub draws: u16,
+    /// number of lost matches
+    pub losses: u16,
+    /// number of matches won by bye; byes are included in wins
+    #[serde(default)]
+    pub byes: u16,
 }
 
 impl EntrantGroupScore {
@@ -37,6 +47,15 @@ impl EntrantGroupScore {
             victory_points: 0.0,
             relative_score: 0,
             total_score: 0,
+            wins: 0,
+            draws: 0,
+            losses: 0,
+            byes: 0,
         }
     }
+
+    /// Number of played matches according to this score.
+    pub fn played(&self) -> u16 {
+        self.wins + self.draws + self.losses
+    }
 }
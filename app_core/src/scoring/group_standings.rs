@@ -0,0 +1,379 @@
+// ranking of entrants within a group
+
+use crate::{
+    Core, CoreResult, EntrantGroupScore, Match, MatchOutcome, SportConfig, SportError, SportResult,
+    Stage, TieBreaker, TieBreakerData, TieBreakerPolicy,
+};
+use serde::{Deserialize, Serialize};
+use std::collections::{HashMap, HashSet};
+use uuid::Uuid;
+
+/// Rank and score of an entrant in a group.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct GroupStanding {
+    /// rank in group starting at 1; entrants, whose tie could not be broken, share a rank
+    pub rank: u32,
+    pub score: EntrantGroupScore,
+}
+
+impl GroupStanding {
+    pub fn get_entrant_id(&self) -> Uuid {
+        self.score.entrant_id
+    }
+}
+
+/// Rank `entrants` of group `group_id` by the results of `matches`.
+///
+/// `score` calculates the score of an entrant over the given matches, usually with the
+/// sport plugin. Ties are broken by `tie_breakers` in order. Head to head compares the
+/// scores of tied entrants over the matches between them; byes are no matches between
+/// entrants and are excluded from head to head. Coin flip and draw end the chain:
+/// coin flips are done by the officials, therefore remaining ties share a rank. Standings
+/// are sorted by rank; tied entrants keep the order of `entrants`.
+pub fn rank_group_entrants<F>(
+    group_id: Uuid,
+    entrants: &[Uuid],
+    matches: &[Match],
+    tie_breakers: &[TieBreaker],
+    score: F,
+) -> SportResult<Vec<GroupStanding>>
+where
+    F: Fn(Uuid, &[Match]) -> SportResult<EntrantGroupScore>,
+{
+    let scores = entrants
+        .iter()
+        .map(|e| Ok((*e, score(*e, matches)?)))
+        .collect::<SportResult<HashMap<_, _>>>()?;
+    let ranker = Ranker {
+        matches,
+        data: tie_breaker_data(matches, &scores),
+        score: &score,
+    };
+
+    let mut standings = Vec::with_capacity(entrants.len());
+    let mut rank = 1;
+    for tied in ranker.resolve(entrants.to_vec(), tie_breakers)? {
+        for entrant_id in tied.iter() {
+            let mut entrant_score = scores[entrant_id].clone();
+            entrant_score.group_id = group_id;
+            standings.push(GroupStanding {
+                rank,
+                score: entrant_score,
+            });
+        }
+        rank += tied.len() as u32;
+    }
+    Ok(standings)
+}
+
+/// Tie breaker data of all scored entrants. Opponent based values sum up the scores of the
+/// opponents of all played matches; byes have no opponent.
+fn tie_breaker_data(
+    matches: &[Match],
+    scores: &HashMap<Uuid, EntrantGroupScore>,
+) -> HashMap<Uuid, TieBreakerData> {
+    let mut data: HashMap<Uuid, TieBreakerData> = scores
+        .iter()
+        .map(|(entrant_id, s)| {
+            let data = TieBreakerData {
+                victory_points: s.victory_points,
+                relative_score: s.relative_score as i32,
+                total_score: s.total_score as u32,
+                ..Default::default()
+            };
+            (*entrant_id, data)
+        })
+        .collect();
+    for (a, b) in matches
+        .iter()
+        .filter(|m| is_decided_between_entrants(m))
+        .filter_map(|m| m.get_entrants())
+    {
+        for (entrant, opponent) in [(a, b), (b, a)] {
+            if let (Some(data), Some(opponent)) = (data.get_mut(entrant), scores.get(opponent)) {
+                data.buchholz_score += opponent.victory_points;
+                data.sum_opponent_relative_score += opponent.relative_score as i32;
+                data.sum_opponent_total_score += opponent.total_score as u32;
+            }
+        }
+    }
+    data
+}
+
+/// Check if `m` is decided and no bye. Byes may still name an opponent, if they were
+/// scheduled before the opponent withdrew.
+fn is_decided_between_entrants(m: &Match) -> bool {
+    m.is_decided() && m.get_outcome() != MatchOutcome::Bye
+}
+
+struct Ranker<'a, F> {
+    matches: &'a [Match],
+    data: HashMap<Uuid, TieBreakerData>,
+    score: &'a F,
+}
+
+impl<F> Ranker<'_, F>
+where
+    F: Fn(Uuid, &[Match]) -> SportResult<EntrantGroupScore>,
+{
+    /// Split `entrants` by `tie_breakers` into groups of tied entrants, best group first.
+    fn resolve(
+        &self,
+        entrants: Vec<Uuid>,
+        tie_breakers: &[TieBreaker],
+    ) -> SportResult<Vec<Vec<Uuid>>> {
+        let Some((tie_breaker, remaining)) = tie_breakers.split_first() else {
+            return Ok(vec![entrants]);
+        };
+        if entrants.len() < 2 {
+            return Ok(vec![entrants]);
+        }
+        let mut values = match tie_breaker {
+            TieBreaker::CoinFlip | TieBreaker::Draw => return Ok(vec![entrants]),
+            TieBreaker::HeadToHead => self.head_to_head(&entrants)?,
+            _ => entrants
+                .iter()
+                .map(|e| (*e, self.data[e].value(*tie_breaker).unwrap_or_default()))
+                .collect(),
+        };
+        // stable sort keeps order of entrants with equal values
+        values.sort_by(|a, b| b.1.total_cmp(&a.1));
+
+        let mut resolved = Vec::new();
+        for tied in values.chunk_by(|a, b| a.1 == b.1) {
+            let tied = tied.iter().map(|(e, _)| *e).collect();
+            resolved.extend(self.resolve(tied, remaining)?);
+        }
+        Ok(resolved)
+    }
+
+    /// victory points of `entrants` over the decided matches between them
+    fn head_to_head(&self, entrants: &[Uuid]) -> SportResult<Vec<(Uuid, f64)>> {
+        let tied: HashSet<&Uuid> = entrants.iter().collect();
+        let direct: Vec<Match> = self
+            .matches
+            .iter()
+            .filter(|m| is_decided_between_entrants(m))
+            .filter(|m| {
+                m.get_entrants()
+                    .is_some_and(|(a, b)| tied.contains(a) && tied.contains(b))
+            })
+            .cloned()
+            .collect();
+        entrants
+            .iter()
+            .map(|e| Ok((*e, (self.score)(*e, &direct)?.victory_points as f64)))
+            .collect()
+    }
+}
+
+// ranking is available in every core state
+impl<S> Core<S> {
+    /// Rank `entrants` of group `group_id` by the results of `matches` with the sport
+    /// plugin of `config` and the tie breakers of `policy`.
+    pub fn rank_group(
+        &self,
+        config: &SportConfig,
+        group_id: Uuid,
+        entrants: &[Uuid],
+        matches: &[Match],
+        policy: &TieBreakerPolicy,
+    ) -> CoreResult<Vec<GroupStanding>> {
+        let sport_id = config.get_sport_id();
+        let plugin = self
+            .sport_plugins
+            .get(&sport_id)
+            .ok_or(SportError::UnknownSportId(sport_id))?;
+        let standings = rank_group_entrants(
+            group_id,
+            entrants,
+            matches,
+            policy.get_tie_breakers(),
+            |entrant_id, matches| {
+                plugin.get_entrant_group_score(config, group_id, entrant_id, matches)
+            },
+        )?;
+        Ok(standings)
+    }
+
+    /// Rank `entrants` of group `group_id` of `stage` like [`Core::rank_group`], but with
+    /// the scoring policy override of the stage applied to `config` and the tie breakers.
+    pub fn rank_stage_group(
+        &self,
+        config: &SportConfig,
+        stage: &Stage,
+        group_id: Uuid,
+        entrants: &[Uuid],
+        matches: &[Match],
+    ) -> CoreResult<Vec<GroupStanding>> {
+        let scoring = stage.get_scoring_override();
+        self.rank_group(
+            &scoring.apply_to_config(config),
+            group_id,
+            entrants,
+            matches,
+            &scoring.tie_breaker_policy(),
+        )
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    /// one victory point per won set or bye
+    fn score(entrant_id: Uuid, matches: &[Match]) -> SportResult<EntrantGroupScore> {
+        let mut s = EntrantGroupScore::new(entrant_id, Uuid::nil());
+        for m in matches {
+            if m.get_bye_entrant() == Some(&entrant_id) {
+                s.wins += 1;
+                s.byes += 1;
+                s.victory_points += 1.0;
+                continue;
+            }
+            if m.get_outcome() == MatchOutcome::Bye {
+                continue;
+            }
+            let Some((a, b)) = m.get_entrants() else {
+                continue;
+            };
+            let (score_a, score_b) = m.get_scores();
+            let (own, other) = if *a == entrant_id {
+                (score_a, score_b)
+            } else if *b == entrant_id {
+                (score_b, score_a)
+            } else {
+                continue;
+            };
+            let own_total: u16 = own.iter().sum();
+            let other_total: u16 = other.iter().sum();
+            s.total_score += own_total;
+            s.relative_score += own_total as i16 - other_total as i16;
+            match own_total.cmp(&other_total) {
+                std::cmp::Ordering::Greater => {
+                    s.wins += 1;
+                    s.victory_points += 1.0;
+                }
+                std::cmp::Ordering::Equal => {
+                    s.draws += 1;
+                    s.victory_points += 0.5;
+                }
+                std::cmp::Ordering::Less => s.losses += 1,
+            }
+        }
+        Ok(s)
+    }
+
+    fn played(a: Uuid, b: Uuid, score_a: u16, score_b: u16) -> Match {
+        Match::new_played(
+            Uuid::new_v4(),
+            a,
+            b,
+            Uuid::nil(),
+            vec![score_a],
+            vec![score_b],
+        )
+    }
+
+    fn ranks(standings: &[GroupStanding]) -> Vec<(Uuid, u32)> {
+        standings
+            .iter()
+            .map(|s| (s.get_entrant_id(), s.rank))
+            .collect()
+    }
+
+    #[test]
+    fn given_different_victory_points_when_rank_then_sorted_by_victory_points() {
+        let e: Vec<Uuid> = (0..3).map(|_| Uuid::new_v4()).collect();
+        let matches = vec![
+            played(e[0], e[1], 10, 21),
+            played(e[1], e[2], 21, 15),
+            played(e[2], e[0], 21, 19),
+        ];
+        let policy = TieBreakerPolicy::default();
+        let group_id = Uuid::new_v4();
+        let standings =
+            rank_group_entrants(group_id, &e, &matches, policy.get_tie_breakers(), score).unwrap();
+        assert_eq!(ranks(&standings), vec![(e[1], 1), (e[2], 2), (e[0], 3)]);
+        assert!(standings.iter().all(|s| s.score.group_id == group_id));
+        assert_eq!(standings[0].score.wins, 2);
+    }
+
+    #[test]
+    fn given_equal_victory_points_when_rank_then_head_to_head_breaks_tie() {
+        let e: Vec<Uuid> = (0..4).map(|_| Uuid::new_v4()).collect();
+        // e[0] and e[1] both win twice; e[1] won the direct match with a smaller margin
+        // than e[0] won its other matches
+        let matches = vec![
+            played(e[0], e[1], 19, 21),
+            played(e[0], e[2], 21, 1),
+            played(e[0], e[3], 21, 1),
+            played(e[1], e[2], 21, 19),
+            played(e[1], e[3], 19, 21),
+            played(e[2], e[3], 21, 19),
+        ];
+        let policy = TieBreakerPolicy::default();
+        let standings =
+            rank_group_entrants(Uuid::nil(), &e, &matches, policy.get_tie_breakers(), score)
+                .unwrap();
+        assert_eq!(standings[0].get_entrant_id(), e[1]);
+        assert_eq!(standings[1].get_entrant_id(), e[0]);
+
+        // without head to head, score difference decides
+        let policy = [TieBreaker::VictoryPoints, TieBreaker::RelativScore];
+        let standings = rank_group_entrants(Uuid::nil(), &e, &matches, &policy, score).unwrap();
+        assert_eq!(standings[0].get_entrant_id(), e[0]);
+    }
+
+    #[test]
+    fn given_bye_when_rank_then_bye_counts_but_not_in_head_to_head() {
+        let e: Vec<Uuid> = (0..2).map(|_| Uuid::new_v4()).collect();
+        // e[0] won by bye, which was scheduled against e[1] before e[1] withdrew from the
+        // round, and lost the direct match
+        let matches = vec![
+            played(e[0], e[1], 19, 21),
+            Match::new_walkover(Uuid::new_v4(), e[0], e[1], Uuid::nil(), MatchOutcome::Bye),
+            Match::new_bye(Uuid::new_v4(), e[0], Uuid::nil()),
+        ];
+        let policy = [TieBreaker::VictoryPoints, TieBreaker::HeadToHead];
+        let standings = rank_group_entrants(Uuid::nil(), &e, &matches, &policy, score).unwrap();
+
+        // byes count as wins: e[0] has two byes, e[1] one win
+        assert_eq!(ranks(&standings), vec![(e[0], 1), (e[1], 2)]);
+        assert_eq!(standings[0].score.byes, 2);
+        assert_eq!(standings[0].score.wins, 2);
+
+        // with equal victory points head to head ignores the bye between both entrants
+        let matches = &matches[..2];
+        let standings = rank_group_entrants(Uuid::nil(), &e, matches, &policy, score).unwrap();
+        assert_eq!(ranks(&standings), vec![(e[1], 1), (e[0], 2)]);
+    }
+
+    #[test]
+    fn given_unbreakable_tie_when_rank_then_rank_is_shared() {
+        let e: Vec<Uuid> = (0..3).map(|_| Uuid::new_v4()).collect();
+        let matches = vec![played(e[0], e[2], 21, 10), played(e[1], e[2], 21, 10)];
+        let policy = TieBreakerPolicy::default();
+        let standings =
+            rank_group_entrants(Uuid::nil(), &e, &matches, policy.get_tie_breakers(), score)
+                .unwrap();
+        assert_eq!(ranks(&standings), vec![(e[0], 1), (e[1], 1), (e[2], 3)]);
+    }
+
+    #[test]
+    fn given_buchholz_when_rank_then_stronger_opponents_rank_better() {
+        let e: Vec<Uuid> = (0..4).map(|_| Uuid::new_v4()).collect();
+        // e[0], e[1] and e[2] win once; only e[1] beat the winless e[3] alone
+        let matches = vec![
+            played(e[0], e[2], 21, 10),
+            played(e[1], e[3], 21, 10),
+            played(e[2], e[3], 21, 10),
+        ];
+        let policy = [TieBreaker::VictoryPoints, TieBreaker::BuchholzScore];
+        let standings = rank_group_entrants(Uuid::nil(), &e, &matches, &policy, score).unwrap();
+        assert_eq!(
+            ranks(&standings),
+            vec![(e[0], 1), (e[2], 1), (e[1], 3), (e[3], 4)]
+        );
+    }
+}
@@ -1,9 +1,17 @@
 // scoring data types
 
 mod entrant_group_score;
+mod group_standings;
+mod scoring_override;
 mod scoring_policy;
+mod standings_cache;
+mod standings_check;
 mod tie_breaker_policy;
 
 pub use entrant_group_score::*;
+pub use group_standings::*;
+pub use scoring_override::*;
 pub use scoring_policy::*;
+pub use standings_cache::*;
+pub use standings_check::*;
 pub use tie_breaker_policy::*;
@@ -0,0 +1,230 @@
+// scoring policy overrides of stages
+
+use crate::{
+    SportConfig, SportPort, TieBreaker, TieBreakerPolicy,
+    utils::{
+        id_version::IdVersion,
+        validation::{FieldError, ValidationErrors, ValidationResult},
+    },
+};
+use displaydoc::Display;
+use serde::{Deserialize, Serialize};
+use serde_json::Value;
+use std::hash::{Hash, Hasher};
+use uuid::Uuid;
+
+/// Tie breaker rules, which a stage may use instead of the default policy.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Hash, Serialize, Deserialize, Display)]
+pub enum TieBreakerPreset {
+    /// Victory points, head to head, score difference
+    #[default]
+    Standard,
+    /// Victory points, score difference, head to head
+    ScoreFirst,
+    /// Victory points, Buchholz, score difference
+    Buchholz,
+}
+
+impl TieBreakerPreset {
+    /// Tie breaker policy of preset.
+    pub fn policy(&self) -> TieBreakerPolicy {
+        let tie_breakers = match self {
+            TieBreakerPreset::Standard => {
+                return TieBreakerPolicy::default();
+            }
+            TieBreakerPreset::ScoreFirst => vec![
+                TieBreaker::VictoryPoints,
+                TieBreaker::RelativScore,
+                TieBreaker::TotalScore,
+                TieBreaker::HeadToHead,
+                TieBreaker::Draw,
+            ],
+            TieBreakerPreset::Buchholz => vec![
+                TieBreaker::VictoryPoints,
+                TieBreaker::BuchholzScore,
+                TieBreaker::SumOpponentRelativeScore,
+                TieBreaker::RelativScore,
+                TieBreaker::TotalScore,
+                TieBreaker::Draw,
+            ],
+        };
+        TieBreakerPolicy::new(Uuid::nil(), self.to_string(), tie_breakers)
+    }
+}
+
+/// Scoring policy of a stage, which differs from the tournament default, e.g. 3/1/0 in pool
+/// stages and pure win/loss in the final stage. Values, which are `None`, are taken from the
+/// sport configuration respectively the default tie breaker policy.
+///
+/// Victory points replace the values of the same name in the sport specific configuration,
+/// therefore only victory points known by the sport plugin can be overridden.
+#[derive(Debug, Clone, Copy, Default, Serialize, Deserialize)]
+pub struct ScoringOverride {
+    /// victory points gained by a win
+    #[serde(default)]
+    pub victory_points_win: Option<f32>,
+    /// victory points gained by a draw
+    #[serde(default)]
+    pub victory_points_draw: Option<f32>,
+    /// victory points gained by a loss
+    #[serde(default)]
+    pub victory_points_loss: Option<f32>,
+    /// tie breaker rules of stage
+    #[serde(default)]
+    pub tie_breakers: Option<TieBreakerPreset>,
+}
+
+// victory points are compared by their bits to keep stages hashable
+impl PartialEq for ScoringOverride {
+    fn eq(&self, other: &Self) -> bool {
+        self.victory_point_bits() == other.victory_point_bits()
+            && self.tie_breakers == other.tie_breakers
+    }
+}
+
+impl Eq for ScoringOverride {}
+
+impl Hash for ScoringOverride {
+    fn hash<H: Hasher>(&self, state: &mut H) {
+        self.victory_point_bits().hash(state);
+        self.tie_breakers.hash(state);
+    }
+}
+
+impl ScoringOverride {
+    fn victory_point_bits(&self) -> [Option<u32>; 3] {
+        self.victory_points().map(|(_, vp)| vp.map(f32::to_bits))
+    }
+
+    /// victory points by their key in the sport specific configuration
+    fn victory_points(&self) -> [(&'static str, Option<f32>); 3] {
+        [
+            ("victory_points_win", self.victory_points_win),
+            ("victory_points_draw", self.victory_points_draw),
+            ("victory_points_loss", self.victory_points_loss),
+        ]
+    }
+
+    /// Returns true, if nothing is overridden.
+    pub fn is_empty(&self) -> bool {
+        self.tie_breakers.is_none() && self.victory_points().iter().all(|(_, vp)| vp.is_none())
+    }
+
+    /// Returns `config` with the overridden victory points.
+    pub fn apply_to_config(&self, config: &SportConfig) -> SportConfig {
+        let mut config = config.clone();
+        let mut values = config.get_config().clone();
+        if let Value::Object(map) = &mut values {
+            for (key, vp) in self.victory_points() {
+                if let Some(vp) = vp {
+                    map.insert(key.to_string(), Value::from(vp));
+                }
+            }
+        }
+        config.set_config(values);
+        config
+    }
+
+    /// Returns the tie breaker policy of the stage.
+    pub fn tie_breaker_policy(&self) -> TieBreakerPolicy {
+        self.tie_breakers
+            .map_or_else(TieBreakerPolicy::default, |preset| preset.policy())
+    }
+
+    /// Validate the override against the default configuration of `sport_plugin`. Errors
+    /// refer to the stage with `object_id`.
+    pub fn validate(&self, sport_plugin: &dyn SportPort, object_id: Uuid) -> ValidationResult<()> {
+        let mut errs = ValidationErrors::new();
+        let default_config = sport_plugin.get_default_config();
+        let mut has_victory_points = false;
+        for (key, vp) in self.victory_points() {
+            let Some(vp) = vp else {
+                continue;
+            };
+            has_victory_points = true;
+            if vp < 0.0 {
+                errs.add(
+                    FieldError::builder()
+                        .set_field(key)
+                        .add_user_defined_code("invalid_value")
+                        .add_message(format!("{key} must not be negative"))
+                        .set_object_id(object_id)
+                        .build(),
+                );
+            }
+            if default_config.get(key).is_none() {
+                errs.add(
+                    FieldError::builder()
+                        .set_field(key)
+                        .add_user_defined_code("not_supported")
+                        .add_message(format!("{} does not use {key}", sport_plugin.name()))
+                        .set_object_id(object_id)
+                        .build(),
+                );
+            }
+        }
+        if !errs.is_empty() {
+            return Err(errs);
+        }
+        if has_victory_points {
+            // the plugin checks the overridden values in context of its configuration, e.g.
+            // that a win gains more points than a draw
+            let mut config = SportConfig::new(IdVersion::new(object_id, None));
+            config
+                .set_sport_id(sport_plugin.get_id_version().get_id())
+                .set_config(default_config);
+            sport_plugin.validate_config_values(&self.apply_to_config(&config), errs)?;
+        }
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use serde_json::json;
+
+    #[test]
+    fn given_override_when_apply_to_config_then_only_set_values_are_replaced() {
+        let mut config = SportConfig::default();
+        config.set_config(json!({
+            "victory_points_win": 1.0,
+            "victory_points_draw": 0.5,
+            "sets_to_win": 1
+        }));
+        let scoring = ScoringOverride {
+            victory_points_win: Some(3.0),
+            victory_points_draw: Some(1.0),
+            ..Default::default()
+        };
+        let applied = scoring.apply_to_config(&config);
+        assert_eq!(
+            applied.get_config(),
+            &json!({
+                "victory_points_win": 3.0,
+                "victory_points_draw": 1.0,
+                "sets_to_win": 1
+            })
+        );
+        assert!(!scoring.is_empty());
+        assert_eq!(
+            scoring.tie_breaker_policy().get_tie_breakers(),
+            TieBreakerPolicy::default().get_tie_breakers()
+        );
+    }
+
+    #[test]
+    fn given_preset_when_policy_then_preset_tie_breakers() {
+        let scoring = ScoringOverride {
+            tie_breakers: Some(TieBreakerPreset::Buchholz),
+            ..Default::default()
+        };
+        assert_eq!(
+            scoring.tie_breaker_policy().get_tie_breakers()[1],
+            TieBreaker::BuchholzScore
+        );
+        let copy = scoring;
+        assert_eq!(scoring, copy);
+        assert!(ScoringOverride::default().is_empty());
+    }
+}
@@ -0,0 +1,126 @@
+// cache of group standings, which are served to many spectator clients
+
+use crate::{Core, CoreResult, CrMsg, CrTopic, GroupStanding, Match, group_entrants};
+use chrono::{DateTime, Utc};
+use serde::{Deserialize, Serialize};
+use uuid::Uuid;
+
+/// Standings of a group as computed at `computed_at`.
+///
+/// Standings are recomputed, when results of matches of the tournament change, therefore
+/// reading them is cheap compared to scoring all matches of the group on every request.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct CachedGroupStandings {
+    pub group_id: Uuid,
+    pub tournament_id: Uuid,
+    /// standings sorted by rank
+    pub standings: Vec<GroupStanding>,
+    pub computed_at: DateTime<Utc>,
+}
+
+impl CachedGroupStandings {
+    pub fn new(group_id: Uuid, tournament_id: Uuid, standings: Vec<GroupStanding>) -> Self {
+        CachedGroupStandings {
+            group_id,
+            tournament_id,
+            standings,
+            computed_at: Utc::now(),
+        }
+    }
+}
+
+// cached standings are available in every core state
+impl<S> Core<S> {
+    /// Get the current standings of group `group_id` of tournament `tournament_id`, sorted
+    /// by rank.
+    ///
+    /// Standings are read from the cache. On a cache miss they are computed and cached.
+    pub async fn get_group_standings(
+        &self,
+        tournament_id: Uuid,
+        group_id: Uuid,
+    ) -> CoreResult<Vec<GroupStanding>> {
+        if let Some(cached) = self.database.get_group_standings(group_id).await? {
+            if cached.tournament_id != tournament_id {
+                return Ok(Vec::new());
+            }
+            return Ok(cached.standings);
+        }
+        let Some(computed) = self
+            .compute_group_standings(tournament_id, group_id)
+            .await?
+        else {
+            return Ok(Vec::new());
+        };
+        self.database.save_group_standings(&computed).await?;
+        Ok(computed.standings)
+    }
+
+    /// Recompute all cached standings of tournament `tournament_id`, e.g. after a result of
+    /// a match changed. Standings, which cannot be computed, keep their cached value.
+    /// Subscribers of the matches of a recomputed group are notified to reload its standings.
+    /// Returns the number of recomputed groups.
+    pub async fn recompute_group_standings(&self, tournament_id: Uuid) -> CoreResult<usize> {
+        let cached = self
+            .database
+            .list_group_standings_of_tournament(tournament_id)
+            .await?;
+        let mut num_recomputed = 0;
+        for group in cached {
+            let Some(computed) = self
+                .compute_group_standings(tournament_id, group.group_id)
+                .await?
+            else {
+                continue;
+            };
+            self.database.save_group_standings(&computed).await?;
+            let notice = CrTopic::GroupMatches {
+                group_id: computed.group_id,
+            };
+            let msg = CrMsg::GroupStandingsUpdated {
+                id: computed.group_id,
+                version: 0,
+            };
+            self.client_registry.publish(notice, msg).await?;
+            num_recomputed += 1;
+        }
+        Ok(num_recomputed)
+    }
+
+    /// Compute standings of group `group_id` of tournament `tournament_id` from the results
+    /// of its stored matches. Returns `None`, if the group has no matches or the tournament,
+    /// its stage or a valid sport configuration is missing.
+    async fn compute_group_standings(
+        &self,
+        tournament_id: Uuid,
+        group_id: Uuid,
+    ) -> CoreResult<Option<CachedGroupStandings>> {
+        let Some(tournament) = self.database.get_tournament_base(tournament_id).await? else {
+            return Ok(None);
+        };
+        let matches: Vec<Match> = self
+            .database
+            .list_matches_of_tournament_for_update(tournament_id)
+            .await?
+            .into_iter()
+            .filter(|m| *m.get_group_id() == group_id)
+            .collect();
+        let Some(first) = matches.first() else {
+            return Ok(None);
+        };
+        let Some(stage) = self.database.get_stage_by_id(*first.get_stage_id()).await? else {
+            return Ok(None);
+        };
+        let Some(config) = self.load_tournament_sport_config(&tournament).await? else {
+            tracing::warn!(%tournament_id, %group_id, "group_standings_without_sport_config");
+            return Ok(None);
+        };
+        let entrants = group_entrants(&matches);
+        let standings = self.rank_stage_group(&config, &stage, group_id, &entrants, &matches)?;
+        Ok(Some(CachedGroupStandings::new(
+            group_id,
+            tournament_id,
+            standings,
+        )))
+    }
+}
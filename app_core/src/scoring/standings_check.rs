@@ -0,0 +1,551 @@
+// consistency checks of group standings
+
+use crate::{
+    Core, CoreResult, EntrantGroupScore, Match, MatchOutcome, SportConfig, SportError, SportResult,
+    TournamentBase, TournamentState, group_entrants,
+};
+use serde::{Deserialize, Serialize};
+use std::collections::HashMap;
+use uuid::Uuid;
+
+/// Discrepancy found by the consistency checks of group standings.
+///
+/// Discrepancies are usually caused by data-entry errors. Each anomaly references the
+/// suspect matches, so that the dashboard can provide a drill-down to them.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub enum StandingsAnomaly {
+    /// total wins do not equal total losses across the group
+    WinLossImbalance {
+        wins: u32,
+        losses: u32,
+        suspect_matches: Vec<Uuid>,
+    },
+    /// total points for do not equal total points against across the group
+    PointsImbalance {
+        points_for: i64,
+        points_against: i64,
+        suspect_matches: Vec<Uuid>,
+    },
+    /// entrant did not play the expected number of matches
+    MatchCountMismatch {
+        entrant_id: Uuid,
+        expected: u32,
+        actual: u32,
+        suspect_matches: Vec<Uuid>,
+    },
+    /// standing of entrant does not match the scores of its played matches
+    StandingMismatch {
+        entrant_id: Uuid,
+        expected_total_score: u32,
+        actual_total_score: u32,
+        suspect_matches: Vec<Uuid>,
+    },
+    /// match references an entrant, which is not part of the group
+    ForeignEntrant { match_id: Uuid, entrant_id: Uuid },
+}
+
+impl StandingsAnomaly {
+    /// Ids of matches, which should be reviewed to resolve this anomaly.
+    pub fn suspect_matches(&self) -> Vec<Uuid> {
+        match self {
+            StandingsAnomaly::WinLossImbalance {
+                suspect_matches, ..
+            }
+            | StandingsAnomaly::PointsImbalance {
+                suspect_matches, ..
+            }
+            | StandingsAnomaly::MatchCountMismatch {
+                suspect_matches, ..
+            }
+            | StandingsAnomaly::StandingMismatch {
+                suspect_matches, ..
+            } => suspect_matches.clone(),
+            StandingsAnomaly::ForeignEntrant { match_id, .. } => vec![*match_id],
+        }
+    }
+
+    /// Short human readable description of the anomaly.
+    pub fn description(&self) -> String {
+        match self {
+            StandingsAnomaly::WinLossImbalance { wins, losses, .. } => {
+                format!("Total wins ({wins}) do not equal total losses ({losses}).")
+            }
+            StandingsAnomaly::PointsImbalance {
+                points_for,
+                points_against,
+                ..
+            } => format!(
+                "Total points for ({points_for}) do not equal total points against ({points_against})."
+            ),
+            StandingsAnomaly::MatchCountMismatch {
+                entrant_id,
+                expected,
+                actual,
+                ..
+            } => format!("Entrant {entrant_id} played {actual} matches, expected {expected}."),
+            StandingsAnomaly::StandingMismatch {
+                entrant_id,
+                expected_total_score,
+                actual_total_score,
+                ..
+            } => format!(
+                "Standing of entrant {entrant_id} shows {actual_total_score} points, matches sum up to {expected_total_score}."
+            ),
+            StandingsAnomaly::ForeignEntrant {
+                match_id,
+                entrant_id,
+            } => format!(
+                "Match {match_id} references entrant {entrant_id}, who is not in this group."
+            ),
+        }
+    }
+}
+
+/// Result of the consistency checks of one group.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct StandingsCheckReport {
+    /// id of checked group
+    pub group_id: Uuid,
+    /// found anomalies; empty if standings are consistent
+    pub anomalies: Vec<StandingsAnomaly>,
+}
+
+impl StandingsCheckReport {
+    /// Returns true, if no anomalies were found.
+    pub fn is_consistent(&self) -> bool {
+        self.anomalies.is_empty()
+    }
+
+    /// Sorted and deduplicated ids of all suspect matches.
+    pub fn suspect_matches(&self) -> Vec<Uuid> {
+        let mut ids: Vec<Uuid> = self
+            .anomalies
+            .iter()
+            .flat_map(|a| a.suspect_matches())
+            .collect();
+        ids.sort();
+        ids.dedup();
+        ids
+    }
+}
+
+#[derive(Default)]
+struct EntrantTally {
+    played: u32,
+    points_for: u32,
+    matches: Vec<Uuid>,
+}
+
+/// Run consistency checks on the standings of one group after a round.
+///
+/// `standings` are the scores calculated by the sport plugin, `matches` are the matches
+/// of the group and `standing_scores` returns the scores of both sides of a decided match,
+/// by which it counts in standings, i.e. including free tickets and handicaps (see
+/// [`SportPort::standing_scores`](crate::SportPort::standing_scores)). Checks are:
+/// - total wins must equal total losses across the group; double forfeits count as loss
+///   for both sides, byes as win without loss
+/// - total points for must equal total points against across the group, except for the
+///   free tickets of byes
+/// - every entrant must have played `expected_matches_per_entrant` matches (if provided);
+///   byes count as played
+/// - the total score of each standing must match the scores of the played matches
+pub fn check_group_standings(
+    group_id: Uuid,
+    entrants: &[Uuid],
+    matches: &[Match],
+    standing_scores: impl Fn(&Match) -> (Vec<u16>, Vec<u16>),
+    standings: &[EntrantGroupScore],
+    expected_matches_per_entrant: Option<u32>,
+) -> StandingsCheckReport {
+    let mut anomalies = Vec::new();
+    let mut tallies: HashMap<Uuid, EntrantTally> = entrants
+        .iter()
+        .map(|e| (*e, EntrantTally::default()))
+        .collect();
+    // matches with a different number of sets per side are the usual suspects
+    let mut malformed_matches = Vec::new();
+
+    let mut double_forfeits = 0;
+    // byes are won without opponent, who loses or scores
+    let mut byes = 0;
+    let mut bye_points: i64 = 0;
+
+    for m in matches.iter().filter(|m| m.is_decided()) {
+        let (score_a, score_b) = standing_scores(m);
+        let sides = match (m.get_entrants(), m.get_bye_entrant()) {
+            (_, Some(entrant)) => {
+                byes += 1;
+                bye_points += score_a.iter().map(|s| *s as i64).sum::<i64>();
+                vec![(entrant, score_a)]
+            }
+            (Some((a, b)), None) => {
+                if score_a.len() != score_b.len() {
+                    malformed_matches.push(*m.get_id());
+                }
+                vec![(a, score_a), (b, score_b)]
+            }
+            (None, None) => continue,
+        };
+        if m.get_outcome() == MatchOutcome::DoubleForfeit {
+            double_forfeits += 1;
+        }
+        for (entrant, own) in sides {
+            let Some(tally) = tallies.get_mut(entrant) else {
+                anomalies.push(StandingsAnomaly::ForeignEntrant {
+                    match_id: *m.get_id(),
+                    entrant_id: *entrant,
+                });
+                continue;
+            };
+            tally.played += 1;
+            tally.points_for += own.iter().map(|s| *s as u32).sum::<u32>();
+            tally.matches.push(*m.get_id());
+        }
+    }
+
+    // wins vs. losses of standings
+    let wins: u32 = standings.iter().map(|s| s.wins as u32).sum();
+    let losses: u32 = standings.iter().map(|s| s.losses as u32).sum();
+    if wins + 2 * double_forfeits != losses + byes {
+        anomalies.push(StandingsAnomaly::WinLossImbalance {
+            wins,
+            losses,
+            suspect_matches: malformed_matches.clone(),
+        });
+    }
+
+    // points for vs. points against of standings
+    let points_for: i64 = standings.iter().map(|s| s.total_score as i64).sum();
+    let points_against: i64 = standings
+        .iter()
+        .map(|s| s.total_score as i64 - s.relative_score as i64)
+        .sum();
+    if points_for != points_against + bye_points {
+        anomalies.push(StandingsAnomaly::PointsImbalance {
+            points_for,
+            points_against,
+            suspect_matches: malformed_matches,
+        });
+    }
+
+    // per entrant checks in order of entrants
+    for entrant_id in entrants {
+        let tally = &tallies[entrant_id];
+        if let Some(expected) = expected_matches_per_entrant
+            && tally.played != expected
+        {
+            anomalies.push(StandingsAnomaly::MatchCountMismatch {
+                entrant_id: *entrant_id,
+                expected,
+                actual: tally.played,
+                suspect_matches: tally.matches.clone(),
+            });
+        }
+        if let Some(standing) = standings.iter().find(|s| s.entrant_id == *entrant_id)
+            && (standing.total_score as u32 != tally.points_for
+                || standing.played() as u32 != tally.played)
+        {
+            anomalies.push(StandingsAnomaly::StandingMismatch {
+                entrant_id: *entrant_id,
+                expected_total_score: tally.points_for,
+                actual_total_score: standing.total_score as u32,
+                suspect_matches: tally.matches.clone(),
+            });
+        }
+    }
+
+    StandingsCheckReport {
+        group_id,
+        anomalies,
+    }
+}
+
+// consistency checks are available in every core state
+impl<S> Core<S> {
+    /// Calculate the standings of a group with the sport plugin of `config` and run the
+    /// consistency checks on them. Called after each completed round.
+    pub fn check_group_standings(
+        &self,
+        config: &SportConfig,
+        group_id: Uuid,
+        entrants: &[Uuid],
+        matches: &[Match],
+        expected_matches_per_entrant: Option<u32>,
+    ) -> CoreResult<StandingsCheckReport> {
+        let sport_id = config.get_sport_id();
+        let plugin = self
+            .sport_plugins
+            .get(&sport_id)
+            .ok_or(SportError::UnknownSportId(sport_id))?;
+        let standings = entrants
+            .iter()
+            .map(|e| plugin.get_entrant_group_score(config, group_id, *e, matches))
+            .collect::<Result<Vec<_>, _>>()?;
+        let scores = matches
+            .iter()
+            .filter(|m| m.is_decided())
+            .map(|m| Ok((*m.get_id(), plugin.standing_scores(config, m)?)))
+            .collect::<SportResult<HashMap<Uuid, (Vec<u16>, Vec<u16>)>>>()?;
+        let report = check_group_standings(
+            group_id,
+            entrants,
+            matches,
+            |m| scores.get(m.get_id()).cloned().unwrap_or_default(),
+            &standings,
+            expected_matches_per_entrant,
+        );
+        if !report.is_consistent() {
+            tracing::warn!(
+                group_id = %group_id,
+                anomalies = report.anomalies.len(),
+                "standings_inconsistent"
+            );
+        }
+        Ok(report)
+    }
+
+    /// Run the consistency checks on all groups of the active stage of `tournament` with
+    /// its stored matches. Returns the reports of inconsistent groups; tournaments, which
+    /// are not running, have none.
+    pub async fn check_active_stage_standings(
+        &self,
+        tournament: &TournamentBase,
+    ) -> CoreResult<Vec<StandingsCheckReport>> {
+        let TournamentState::ActiveStage(active_stage) = tournament.get_tournament_state() else {
+            return Ok(vec![]);
+        };
+        // Swiss rounds are active stages of the one Swiss stage
+        let stage_number = if tournament.get_tournament_mode().get_num_of_stages() == 1 {
+            0
+        } else {
+            active_stage
+        };
+        let Some(stage) = self
+            .database
+            .get_stage_by_number(tournament.get_id(), stage_number)
+            .await?
+        else {
+            return Ok(vec![]);
+        };
+        let Some(config) = self.load_tournament_sport_config(tournament).await? else {
+            return Ok(vec![]);
+        };
+        let matches = self.database.list_matches_of_stage(stage.get_id()).await?;
+        let mut group_ids: Vec<Uuid> = Vec::new();
+        for m in &matches {
+            if !group_ids.contains(m.get_group_id()) {
+                group_ids.push(*m.get_group_id());
+            }
+        }
+        let mut reports = Vec::new();
+        for group_id in group_ids {
+            let group_matches: Vec<Match> = matches
+                .iter()
+                .filter(|m| *m.get_group_id() == group_id)
+                .cloned()
+                .collect();
+            let entrants = group_entrants(&group_matches);
+            let report =
+                self.check_group_standings(&config, group_id, &entrants, &group_matches, None)?;
+            if !report.is_consistent() {
+                reports.push(report);
+            }
+        }
+        Ok(reports)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn score(entrant_id: Uuid, own: &[u16], other: &[u16]) -> EntrantGroupScore {
+        let mut s = EntrantGroupScore::new(entrant_id, Uuid::nil());
+        for (a, b) in own.iter().zip(other.iter()) {
+            s.total_score += a;
+            s.relative_score += *a as i16 - *b as i16;
+            match a.cmp(b) {
+                std::cmp::Ordering::Greater => s.wins += 1,
+                std::cmp::Ordering::Equal => s.draws += 1,
+                std::cmp::Ordering::Less => s.losses += 1,
+            }
+        }
+        s
+    }
+
+    fn raw(m: &Match) -> (Vec<u16>, Vec<u16>) {
+        m.get_result_scores(&[])
+    }
+
+    fn setup() -> (Vec<Uuid>, Vec<Match>) {
+        let e: Vec<Uuid> = (0..3).map(|_| Uuid::new_v4()).collect();
+        let sport = Uuid::new_v4();
+        let matches = vec![
+            Match::new_played(Uuid::new_v4(), e[0], e[1], sport, vec![21], vec![15]),
+            Match::new_played(Uuid::new_v4(), e[1], e[2], sport, vec![21], vec![19]),
+            Match::new_played(Uuid::new_v4(), e[2], e[0], sport, vec![10], vec![21]),
+        ];
+        (e, matches)
+    }
+
+    #[test]
+    fn given_consistent_standings_when_check_then_no_anomalies() {
+        let (e, matches) = setup();
+        let standings = vec![
+            score(e[0], &[21, 21], &[15, 10]),
+            score(e[1], &[15, 21], &[21, 19]),
+            score(e[2], &[19, 10], &[21, 21]),
+        ];
+        let report = check_group_standings(Uuid::nil(), &e, &matches, raw, &standings, Some(2));
+        assert!(report.is_consistent(), "{:?}", report.anomalies);
+    }
+
+    #[test]
+    fn given_wrong_standing_when_check_then_imbalances_and_suspect_matches() {
+        let (e, matches) = setup();
+        let mut wrong = score(e[1], &[15, 21], &[21, 19]);
+        wrong.wins += 1;
+        // score of one side entered too high: points for grow, points against do not
+        wrong.total_score += 5;
+        wrong.relative_score += 5;
+        let standings = vec![
+            score(e[0], &[21, 21], &[15, 10]),
+            wrong,
+            score(e[2], &[19, 10], &[21, 21]),
+        ];
+        let report = check_group_standings(Uuid::nil(), &e, &matches, raw, &standings, Some(2));
+        assert!(matches!(
+            report.anomalies[0],
+            StandingsAnomaly::WinLossImbalance {
+                wins: 4,
+                losses: 3,
+                ..
+            }
+        ));
+        assert!(matches!(
+            report.anomalies[1],
+            StandingsAnomaly::PointsImbalance { .. }
+        ));
+        assert!(matches!(
+            report.anomalies[2],
+            StandingsAnomaly::StandingMismatch { entrant_id, .. } if entrant_id == e[1]
+        ));
+        let mut expected: Vec<Uuid> = matches[0..2].iter().map(|m| *m.get_id()).collect();
+        expected.sort();
+        assert_eq!(report.suspect_matches(), expected);
+    }
+
+    #[test]
+    fn given_forfeit_when_check_then_free_ticket_is_tallied() {
+        let (e, mut matches) = setup();
+        matches[2] = Match::new_walkover(
+            Uuid::new_v4(),
+            e[2],
+            e[0],
+            Uuid::nil(),
+            MatchOutcome::ForfeitA,
+        );
+        let standings = vec![
+            score(e[0], &[21, 21], &[15, 0]),
+            score(e[1], &[15, 21], &[21, 19]),
+            score(e[2], &[19, 0], &[21, 21]),
+        ];
+        let report = check_group_standings(
+            Uuid::nil(),
+            &e,
+            &matches,
+            |m| m.get_result_scores(&[21]),
+            &standings,
+            Some(2),
+        );
+        assert!(report.is_consistent(), "{:?}", report.anomalies);
+    }
+
+    #[test]
+    fn given_bye_when_check_then_counted_as_played_win_without_opponent() {
+        let (mut e, mut matches) = setup();
+        let fourth = Uuid::new_v4();
+        e.push(fourth);
+        matches.push(Match::new_bye(Uuid::new_v4(), fourth, Uuid::nil()));
+        let mut bye = score(fourth, &[21], &[0]);
+        bye.byes = 1;
+        let standings = vec![
+            score(e[0], &[21, 21], &[15, 10]),
+            score(e[1], &[15, 21], &[21, 19]),
+            score(e[2], &[19, 10], &[21, 21]),
+            bye,
+        ];
+        let report = check_group_standings(
+            Uuid::nil(),
+            &e,
+            &matches,
+            |m| m.get_result_scores(&[21]),
+            &standings,
+            None,
+        );
+        assert!(report.is_consistent(), "{:?}", report.anomalies);
+    }
+
+    #[test]
+    fn given_handicap_when_check_then_credited_scores_are_tallied() {
+        let (e, mut matches) = setup();
+        matches[0].set_handicaps(0, 4);
+        let handicapped = |m: &Match| {
+            let (a, b) = m.get_result_scores(&[]);
+            let (handicap_a, handicap_b) = m.get_handicaps();
+            (
+                a.iter().map(|s| s + handicap_a).collect(),
+                b.iter().map(|s| s + handicap_b).collect(),
+            )
+        };
+        let standings = vec![
+            score(e[0], &[21, 21], &[19, 10]),
+            score(e[1], &[19, 21], &[21, 19]),
+            score(e[2], &[19, 10], &[21, 21]),
+        ];
+        let report =
+            check_group_standings(Uuid::nil(), &e, &matches, handicapped, &standings, Some(2));
+        assert!(report.is_consistent(), "{:?}", report.anomalies);
+
+        let report = check_group_standings(Uuid::nil(), &e, &matches, raw, &standings, Some(2));
+        assert!(matches!(
+            report.anomalies[0],
+            StandingsAnomaly::StandingMismatch { entrant_id, .. } if entrant_id == e[1]
+        ));
+    }
+
+    #[test]
+    fn given_missing_match_when_check_then_match_count_mismatch() {
+        let (e, matches) = setup();
+        let report = check_group_standings(Uuid::nil(), &e, &matches[0..2], raw, &[], Some(2));
+        assert_eq!(report.anomalies.len(), 2);
+        assert!(
+            report
+                .anomalies
+                .iter()
+                .all(|a| matches!(a, StandingsAnomaly::MatchCountMismatch { actual: 1, .. }))
+        );
+    }
+
+    #[test]
+    fn given_match_with_foreign_entrant_when_check_then_reported() {
+        let (e, mut matches) = setup();
+        let stranger = Uuid::new_v4();
+        matches.push(Match::new_played(
+            Uuid::new_v4(),
+            e[0],
+            stranger,
+            Uuid::nil(),
+            vec![21],
+            vec![3],
+        ));
+        let report = check_group_standings(Uuid::nil(), &e, &matches, raw, &[], None);
+        assert_eq!(
+            report.anomalies,
+            vec![StandingsAnomaly::ForeignEntrant {
+                match_id: *matches[3].get_id(),
+                entrant_id: stranger
+            }]
+        );
+    }
+}
@@ -1,5 +1,6 @@
 // tie breaker policy
 
+use serde::{Deserialize, Serialize};
 use uuid::Uuid;
 
 /// policy to break ties. Tie breaker rules are resolved in vec order
@@ -15,7 +16,40 @@ pub struct TieBreakerPolicy {
     tie_breakers: Vec<TieBreaker>,
 }
 
-#[derive(Debug, Clone)]
+impl TieBreakerPolicy {
+    pub fn new(id: Uuid, name: impl Into<String>, tie_breakers: Vec<TieBreaker>) -> Self {
+        TieBreakerPolicy {
+            id,
+            name: name.into(),
+            tie_breakers,
+        }
+    }
+
+    /// tie breaker rules sorted from most to least important rule
+    pub fn get_tie_breakers(&self) -> &[TieBreaker] {
+        &self.tie_breakers
+    }
+}
+
+/// Common policy of group phases: victory points, direct comparison, score difference and
+/// total score. Remaining ties are kept as shared rank.
+impl Default for TieBreakerPolicy {
+    fn default() -> Self {
+        TieBreakerPolicy::new(
+            Uuid::nil(),
+            "Default",
+            vec![
+                TieBreaker::VictoryPoints,
+                TieBreaker::HeadToHead,
+                TieBreaker::RelativScore,
+                TieBreaker::TotalScore,
+                TieBreaker::Draw,
+            ],
+        )
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
 pub enum TieBreaker {
     VictoryPoints,
     BuchholzScore,
@@ -32,7 +66,7 @@ pub enum TieBreaker {
 
 /// Contains all data required to resolve tie-breakers for a single entrant.
 /// The core ranking logic will use this data in the order specified by a `TieBreakerPolicy`.
-#[derive(Debug, Clone, Default)]
+#[derive(Debug, Clone, Default, PartialEq)]
 pub struct TieBreakerData {
     pub victory_points: f32,
     pub buchholz_score: f32,
@@ -42,3 +76,19 @@ pub struct TieBreakerData {
     pub total_score: u32,
     // HeadToHead is resolved by looking at direct matches, not by pre-calculated data.
 }
+
+impl TieBreakerData {
+    /// Value of `tie_breaker` for this entrant; higher values rank better. Tie breakers,
+    /// which are not resolved by pre-calculated data, have no value.
+    pub fn value(&self, tie_breaker: TieBreaker) -> Option<f64> {
+        match tie_breaker {
+            TieBreaker::VictoryPoints => Some(self.victory_points as f64),
+            TieBreaker::BuchholzScore => Some(self.buchholz_score as f64),
+            TieBreaker::SumOpponentRelativeScore => Some(self.sum_opponent_relative_score as f64),
+            TieBreaker::SumOpponentTotalScore => Some(self.sum_opponent_total_score as f64),
+            TieBreaker::RelativScore => Some(self.relative_score as f64),
+            TieBreaker::TotalScore => Some(self.total_score as f64),
+            TieBreaker::HeadToHead | TieBreaker::CoinFlip | TieBreaker::Draw => None,
+        }
+    }
+}
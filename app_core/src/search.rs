@@ -0,0 +1,123 @@
+//! search across tournaments, entrants, sport configs and postal addresses
+
+use crate::{Core, CoreResult};
+use serde::{Deserialize, Serialize};
+use std::cmp::Reverse;
+use uuid::Uuid;
+
+/// minimum number of characters of a search term; shorter terms find nothing
+pub const SEARCH_MIN_TERM_LENGTH: usize = 2;
+/// maximum number of hits of each kind of object
+pub const SEARCH_LIMIT_PER_KIND: usize = 10;
+
+/// kind of object found by [`Core::search_all`]
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash, Serialize, Deserialize)]
+pub enum SearchHitKind {
+    Tournament,
+    Entrant,
+    SportConfig,
+    PostalAddress,
+}
+
+impl SearchHitKind {
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            SearchHitKind::Tournament => "tournament",
+            SearchHitKind::Entrant => "entrant",
+            SearchHitKind::SportConfig => "sport_config",
+            SearchHitKind::PostalAddress => "postal_address",
+        }
+    }
+}
+
+/// object found by a search with the ids needed to open it
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct SearchHit {
+    pub kind: SearchHitKind,
+    pub id: Uuid,
+    /// name of the object
+    pub label: String,
+    /// additional info, e.g. club of an entrant or locality of a postal address
+    pub detail: Option<String>,
+    /// sport of tournaments, entrants and sport configs
+    pub sport_id: Option<Uuid>,
+    /// tournament of entrants
+    pub tournament_id: Option<Uuid>,
+}
+
+/// Order `hits` by relevance for `term`: exact matches of the label first, then labels
+/// starting with the term, then all other hits. Hits of equal relevance are ordered by kind
+/// and label.
+pub fn rank_search_hits(term: &str, hits: &mut [SearchHit]) {
+    let term = term.trim().to_lowercase();
+    hits.sort_by_cached_key(|hit| {
+        let label = hit.label.to_lowercase();
+        let relevance = if label == term {
+            2
+        } else if label.starts_with(&term) {
+            1
+        } else {
+            0
+        };
+        (Reverse(relevance), hit.kind, label)
+    });
+}
+
+/// State for searching all objects
+pub struct SearchState {}
+
+// switch state to search state
+impl<S> Core<S> {
+    pub fn as_search_state(&self) -> Core<SearchState> {
+        self.switch_state(SearchState {})
+    }
+}
+
+impl Core<SearchState> {
+    /// Search names of tournaments, entrants, sport configs and postal addresses as well as
+    /// clubs of entrants and streets and localities of postal addresses for `term`. Archived
+    /// and sandbox tournaments and archived sport configs are not searched.
+    pub async fn search_all(&self, term: &str) -> CoreResult<Vec<SearchHit>> {
+        let term = term.trim();
+        if term.chars().count() < SEARCH_MIN_TERM_LENGTH {
+            return Ok(Vec::new());
+        }
+        let mut hits = self
+            .database
+            .search_all(term, SEARCH_LIMIT_PER_KIND)
+            .await?;
+        rank_search_hits(term, &mut hits);
+        Ok(hits)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn hit(kind: SearchHitKind, label: &str) -> SearchHit {
+        SearchHit {
+            kind,
+            id: Uuid::new_v4(),
+            label: label.to_string(),
+            detail: None,
+            sport_id: None,
+            tournament_id: None,
+        }
+    }
+
+    #[test]
+    fn given_hits_when_rank_then_exact_and_prefix_matches_first() {
+        let mut hits = vec![
+            hit(SearchHitKind::PostalAddress, "Hall of Cup"),
+            hit(SearchHitKind::Entrant, "Cupcakes"),
+            hit(SearchHitKind::Tournament, "Spring Cup"),
+            hit(SearchHitKind::Tournament, "cup"),
+        ];
+
+        rank_search_hits(" Cup", &mut hits);
+
+        let labels: Vec<_> = hits.iter().map(|h| h.label.as_str()).collect();
+        assert_eq!(labels, vec!["cup", "Cupcakes", "Spring Cup", "Hall of Cup"]);
+    }
+}
@@ -0,0 +1,297 @@
+//! shift log of the tournament desk
+
+use crate::{
+    AuditObjectType, Core, CoreResult, CrMsg, CrTopic,
+    utils::{id_version::IdVersion, normalize::*, traits::ObjectIdVersion, validation::*},
+};
+use chrono::{DateTime, Utc};
+use serde::{Deserialize, Serialize};
+use uuid::Uuid;
+
+/// maximum length of a shift log note in characters
+pub const SHIFT_LOG_TEXT_MAX_LEN: usize = 4000;
+
+/// Timestamped free-text note of the tournament desk.
+///
+/// Notes are visible to everybody operating the tournament (directors, scorekeepers).
+/// Pinned notes are handover notes, which are shown on top of the log and are included
+/// in the final report of the tournament.
+#[derive(Clone, Debug, PartialEq, Eq, Serialize, Deserialize, Default, ObjectIdVersion)]
+pub struct ShiftLogEntry {
+    /// id and optimistic locking version of entry
+    id_version: IdVersion,
+    /// id of tournament the entry belongs to
+    tournament_id: Uuid,
+    /// name of author of entry
+    author: String,
+    /// free text of entry
+    text: String,
+    /// pinned entries are handover notes
+    pinned: bool,
+    /// timestamp of creation; set by database
+    created_at: Option<DateTime<Utc>>,
+}
+
+impl ShiftLogEntry {
+    /// Create a new `ShiftLogEntry` with the given `IdVersion`.
+    pub fn new(id_version: IdVersion) -> Self {
+        ShiftLogEntry {
+            id_version,
+            ..Default::default()
+        }
+    }
+
+    /// Get the unique identifier of the entry.
+    pub fn get_id(&self) -> Uuid {
+        self.id_version.get_id()
+    }
+
+    /// Get the version number of the entry.
+    pub fn get_version(&self) -> Option<u32> {
+        self.id_version.get_version()
+    }
+
+    /// Get the tournament ID.
+    pub fn get_tournament_id(&self) -> Uuid {
+        self.tournament_id
+    }
+
+    /// Get the name of the author.
+    pub fn get_author(&self) -> &str {
+        &self.author
+    }
+
+    /// Get the free text of the entry.
+    pub fn get_text(&self) -> &str {
+        &self.text
+    }
+
+    /// Returns true, if entry is a pinned handover note.
+    pub fn is_pinned(&self) -> bool {
+        self.pinned
+    }
+
+    /// Get the timestamp of creation, if entry is persisted.
+    pub fn get_created_at(&self) -> Option<DateTime<Utc>> {
+        self.created_at
+    }
+
+    /// Set the `IdVersion` of the entry.
+    pub fn set_id_version(&mut self, id_version: IdVersion) -> &mut Self {
+        self.id_version = id_version;
+        self
+    }
+
+    /// Set the tournament ID.
+    pub fn set_tournament_id(&mut self, tournament_id: Uuid) -> &mut Self {
+        self.tournament_id = tournament_id;
+        self
+    }
+
+    /// Set the name of the author with whitespace normalization.
+    pub fn set_author(&mut self, author: impl Into<String>) -> &mut Self {
+        self.author = normalize_ws(author);
+        self
+    }
+
+    /// Set the free text of the entry.
+    ///
+    /// Line breaks are kept, only leading and trailing whitespace is removed.
+    pub fn set_text(&mut self, text: impl Into<String>) -> &mut Self {
+        self.text = text.into().trim().to_string();
+        self
+    }
+
+    /// Pin or unpin the entry as handover note.
+    pub fn set_pinned(&mut self, pinned: bool) -> &mut Self {
+        self.pinned = pinned;
+        self
+    }
+
+    /// Set the timestamp of creation. Only used by database adapters.
+    pub fn set_created_at(&mut self, created_at: Option<DateTime<Utc>>) -> &mut Self {
+        self.created_at = created_at;
+        self
+    }
+
+    /// Validate the entry.
+    pub fn validate(&self) -> ValidationResult<()> {
+        let mut errs = ValidationErrors::new();
+        let object_id = self.get_id();
+
+        if self.tournament_id.is_nil() {
+            errs.add(
+                FieldError::builder()
+                    .set_field(String::from("tournament_id"))
+                    .add_required()
+                    .set_object_id(object_id)
+                    .build(),
+            );
+        }
+        if self.author.is_empty() {
+            errs.add(
+                FieldError::builder()
+                    .set_field(String::from("author"))
+                    .add_required()
+                    .set_object_id(object_id)
+                    .build(),
+            );
+        }
+        if self.text.is_empty() {
+            errs.add(
+                FieldError::builder()
+                    .set_field(String::from("text"))
+                    .add_required()
+                    .set_object_id(object_id)
+                    .build(),
+            );
+        } else if self.text.chars().count() > SHIFT_LOG_TEXT_MAX_LEN {
+            errs.add(
+                FieldError::builder()
+                    .set_field(String::from("text"))
+                    .add_user_defined_code("too_long")
+                    .add_message(format!(
+                        "Note must not exceed {SHIFT_LOG_TEXT_MAX_LEN} characters"
+                    ))
+                    .set_object_id(object_id)
+                    .build(),
+            );
+        }
+
+        if errs.is_empty() { Ok(()) } else { Err(errs) }
+    }
+}
+
+/// State for shift log operations of one tournament
+pub struct ShiftLogState {
+    tournament_id: Uuid,
+    entry: ShiftLogEntry,
+}
+
+// switch state to shift log state
+impl<S> Core<S> {
+    pub fn as_shift_log_state(&self, tournament_id: Uuid) -> Core<ShiftLogState> {
+        let mut entry = ShiftLogEntry::default();
+        entry.set_tournament_id(tournament_id);
+        self.switch_state(ShiftLogState {
+            tournament_id,
+            entry,
+        })
+    }
+}
+
+impl Core<ShiftLogState> {
+    pub fn get(&self) -> &ShiftLogEntry {
+        &self.state.entry
+    }
+    pub fn get_mut(&mut self) -> &mut ShiftLogEntry {
+        &mut self.state.entry
+    }
+    pub async fn load(&mut self, id: Uuid) -> CoreResult<Option<&ShiftLogEntry>> {
+        if let Some(entry) = self.database.get_shift_log_entry(id).await? {
+            self.state.entry = entry;
+            Ok(Some(self.get()))
+        } else {
+            Ok(None)
+        }
+    }
+    pub async fn save(&mut self) -> CoreResult<&ShiftLogEntry> {
+        self.state.entry.validate()?;
+        // stored copy before the save for the audit log
+        let old = match self.state.entry.get_version() {
+            Some(_) => {
+                let id = self.state.entry.get_id();
+                self.database.get_shift_log_entry(id).await?
+            }
+            None => None,
+        };
+        self.state.entry = self
+            .database
+            .save_shift_log_entry(&self.state.entry)
+            .await?;
+
+        // publish change of shift log to client registry
+        let id = self.state.entry.get_id();
+        let version =
+            self.state.entry.get_version().expect(
+                "expecting save_shift_log_entry to return always an existing id and version",
+            );
+        let notice = CrTopic::ShiftLog {
+            tournament_id: self.state.tournament_id,
+        };
+        let msg = CrMsg::ShiftLogUpdated { id, version };
+        self.client_registry.publish(notice, msg).await?;
+        self.audit_save(
+            AuditObjectType::ShiftLogEntry,
+            Some(self.state.tournament_id),
+            old.as_ref(),
+            &self.state.entry,
+        )
+        .await;
+        Ok(self.get())
+    }
+    /// List entries of tournament, newest first. Pinned entries are returned first.
+    pub async fn list_entries(
+        &self,
+        pinned_only: bool,
+        limit: Option<usize>,
+    ) -> CoreResult<Vec<ShiftLogEntry>> {
+        let mut list = self
+            .database
+            .list_shift_log_entries(self.state.tournament_id, pinned_only, limit)
+            .await?;
+        list.sort_by(|a, b| {
+            b.is_pinned()
+                .cmp(&a.is_pinned())
+                .then(b.get_created_at().cmp(&a.get_created_at()))
+        });
+        Ok(list)
+    }
+    /// Pinned handover notes, oldest first. Used by the final tournament report.
+    pub async fn handover_notes(&self) -> CoreResult<Vec<ShiftLogEntry>> {
+        let mut list = self
+            .database
+            .list_shift_log_entries(self.state.tournament_id, true, None)
+            .await?;
+        list.sort_by_key(|e| e.get_created_at());
+        Ok(list)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn valid_entry() -> ShiftLogEntry {
+        let mut e = ShiftLogEntry::default();
+        e.set_tournament_id(Uuid::new_v4())
+            .set_author("  Jane   Doe ")
+            .set_text("  Court 3 net is broken.\nUse court 4 instead.  ");
+        e
+    }
+
+    #[test]
+    fn given_valid_entry_when_validate_then_ok() {
+        let e = valid_entry();
+        assert_eq!(e.get_author(), "Jane Doe");
+        assert_eq!(e.get_text(), "Court 3 net is broken.\nUse court 4 instead.");
+        assert!(e.validate().is_ok());
+    }
+
+    #[test]
+    fn given_empty_fields_when_validate_then_all_errors_are_collected() {
+        let e = ShiftLogEntry::default();
+        let errs = e.validate().unwrap_err();
+        let fields: Vec<_> = errs.errors.iter().map(|e| e.get_field()).collect();
+        assert_eq!(fields, vec!["tournament_id", "author", "text"]);
+    }
+
+    #[test]
+    fn given_too_long_text_when_validate_then_err() {
+        let mut e = valid_entry();
+        e.set_text("x".repeat(SHIFT_LOG_TEXT_MAX_LEN + 1));
+        let errs = e.validate().unwrap_err();
+        assert_eq!(errs.errors.len(), 1);
+        assert_eq!(errs.errors[0].get_code(), "too_long");
+    }
+}
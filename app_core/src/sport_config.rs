@@ -1,18 +1,30 @@
 // configuration and handling of sport specific settings
 
 use crate::{
-    Core, CoreError, CoreResult, CrMsg, CrTopic, SportError, SportPort,
+    AuditAction, AuditObjectType, Core, CoreError, CoreResult, CrMsg, CrTopic, DbError,
+    MergeFields, ServerCopy, SportError, SportPort, SportResult, TournamentBase,
+    merge_display_value,
     utils::{
-        id_version::IdVersion, normalize::normalize_ws, traits::ObjectIdVersion, validation::*,
+        id_version::IdVersion,
+        list_order::{ListOrder, Sortable},
+        normalize::normalize_ws,
+        traits::ObjectIdVersion,
+        validation::*,
     },
 };
+use chrono::{DateTime, Utc};
 use serde::{Deserialize, Serialize};
 use serde_json::Value;
-use std::sync::Arc;
+use std::{
+    cmp::Ordering,
+    fmt::{self, Display},
+    str::FromStr,
+    sync::Arc,
+};
 use uuid::Uuid;
 
 /// `SportConfig` represents the configuration for a specific sport.
-#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize, Default)]
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize, Default, ObjectIdVersion)]
 pub struct SportConfig {
     /// Unique identifier for the sport configuration.
     id_version: IdVersion,
@@ -23,11 +35,63 @@ pub struct SportConfig {
     name: String,
     /// JSON value containing sport-specific configuration details.
     config: Value,
+    /// time of archiving; archived configurations are hidden in default listings
+    archived_at: Option<DateTime<Utc>>,
+    /// version of sport plugin, which wrote the sport-specific configuration details
+    #[serde(default)]
+    plugin_version: u32,
 }
 
-impl ObjectIdVersion for SportConfig {
-    fn get_id_version(&self) -> IdVersion {
-        self.id_version
+/// prefix of merge fields of sport-specific configuration details
+const CONFIG_FIELD_PREFIX: &str = "config.";
+
+impl MergeFields for SportConfig {
+    /// Sport-specific configuration details are merged by their top level keys.
+    fn merge_field_names(&self, theirs: &Self) -> Vec<String> {
+        let mut fields = vec![String::from("name")];
+        match (&self.config, &theirs.config) {
+            (Value::Object(mine), Value::Object(theirs)) => {
+                let mut keys = mine.keys().chain(theirs.keys()).collect::<Vec<_>>();
+                keys.sort();
+                keys.dedup();
+                fields.extend(
+                    keys.into_iter()
+                        .map(|k| format!("{CONFIG_FIELD_PREFIX}{k}")),
+                );
+            }
+            _ => fields.push(String::from("config")),
+        }
+        fields
+    }
+
+    fn merge_field_value(&self, field: &str) -> Option<String> {
+        match field {
+            "name" => Some(merge_display_value(&self.name)),
+            "config" => Some(merge_display_value(&self.config)),
+            _ => {
+                let key = field.strip_prefix(CONFIG_FIELD_PREFIX)?;
+                Some(merge_display_value(self.config.get(key)))
+            }
+        }
+    }
+
+    fn take_merge_field(&mut self, other: &Self, field: &str) {
+        match field {
+            "name" => self.name = other.name.clone(),
+            "config" => self.config = other.config.clone(),
+            _ => {
+                let Some(key) = field.strip_prefix(CONFIG_FIELD_PREFIX) else {
+                    return;
+                };
+                let Value::Object(config) = &mut self.config else {
+                    return;
+                };
+                match other.config.get(key) {
+                    Some(value) => config.insert(key.to_string(), value.clone()),
+                    None => config.remove(key),
+                };
+            }
+        }
     }
 }
 
@@ -65,6 +129,22 @@ impl SportConfig {
         &self.config
     }
 
+    /// Get the time, when the sport configuration was archived.
+    pub fn get_archived_at(&self) -> Option<DateTime<Utc>> {
+        self.archived_at
+    }
+
+    /// Check if the sport configuration is archived.
+    pub fn is_archived(&self) -> bool {
+        self.archived_at.is_some()
+    }
+
+    /// Get the version of the sport plugin, which wrote the sport-specific configuration
+    /// details.
+    pub fn get_plugin_version(&self) -> u32 {
+        self.plugin_version
+    }
+
     /// Set the `IdVersion` of the sport configuration.
     pub fn set_id_version(&mut self, id_version: IdVersion) -> &mut Self {
         self.id_version = id_version;
@@ -104,6 +184,37 @@ impl SportConfig {
         self
     }
 
+    /// Set the time of archiving. Archiving itself is done by the database port.
+    pub fn set_archived_at(&mut self, archived_at: Option<DateTime<Utc>>) -> &mut Self {
+        self.archived_at = archived_at;
+        self
+    }
+
+    /// Set the version of the sport plugin, which wrote the sport-specific configuration
+    /// details.
+    pub fn set_plugin_version(&mut self, plugin_version: u32) -> &mut Self {
+        self.plugin_version = plugin_version;
+        self
+    }
+
+    /// Migrate the sport-specific configuration details written by an older version of
+    /// `sport_plugin` to its current version. Returns true, if the details were migrated.
+    pub fn migrate(&mut self, sport_plugin: &dyn SportPort) -> SportResult<bool> {
+        let current = sport_plugin.plugin_version();
+        if self.plugin_version == current {
+            return Ok(false);
+        }
+        if self.plugin_version > current {
+            return Err(SportError::UnsupportedConfigVersion {
+                found: self.plugin_version,
+                current,
+            });
+        }
+        self.config = sport_plugin.migrate_config(self.plugin_version, self.config.take())?;
+        self.plugin_version = current;
+        Ok(true)
+    }
+
     /// Validate the sport configuration.
     /// At this level we can only validate the name.
     /// Sport-specific validation must be done in the SportPort implementation.
@@ -124,6 +235,58 @@ impl SportConfig {
     }
 }
 
+/// column, by which lists of sport configs are sorted
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default, Serialize, Deserialize)]
+pub enum SportConfigSortColumn {
+    #[default]
+    Name,
+    /// version of the sport plugin, which wrote the config; shows configs, which wait for
+    /// migration
+    PluginVersion,
+}
+
+impl SportConfigSortColumn {
+    pub const ALL: [SportConfigSortColumn; 2] = [
+        SportConfigSortColumn::Name,
+        SportConfigSortColumn::PluginVersion,
+    ];
+
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            SportConfigSortColumn::Name => "name",
+            SportConfigSortColumn::PluginVersion => "plugin_version",
+        }
+    }
+}
+
+impl Display for SportConfigSortColumn {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{}", self.as_str())
+    }
+}
+
+impl FromStr for SportConfigSortColumn {
+    type Err = CoreError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        SportConfigSortColumn::ALL
+            .into_iter()
+            .find(|c| c.as_str() == s)
+            .ok_or_else(|| CoreError::ParsingError(format!("unknown sort column: {s}")))
+    }
+}
+
+impl Sortable for SportConfig {
+    type SortColumn = SportConfigSortColumn;
+
+    fn compare(&self, other: &Self, column: SportConfigSortColumn) -> Ordering {
+        match column {
+            SportConfigSortColumn::Name => self.name.to_lowercase().cmp(&other.name.to_lowercase()),
+            SportConfigSortColumn::PluginVersion => self.plugin_version.cmp(&other.plugin_version),
+        }
+    }
+}
+
 /// State for sport config operations
 pub struct SportConfigState {
     config: SportConfig,
@@ -136,6 +299,29 @@ impl<S> Core<S> {
             config: SportConfig::default(),
         })
     }
+
+    /// Load the sport configuration chosen for `tournament`, migrated to the current version
+    /// of the sport plugin. Returns `None`, if no configuration is chosen, the sport is
+    /// unknown or the configuration cannot be migrated.
+    pub async fn load_tournament_sport_config(
+        &self,
+        tournament: &TournamentBase,
+    ) -> CoreResult<Option<SportConfig>> {
+        let sport_id = tournament.get_sport_id();
+        let Some(config_id) = tournament.get_sport_config_id() else {
+            return Ok(None);
+        };
+        let Some(sport_plugin) = self.sport_plugins.get(&sport_id) else {
+            return Ok(None);
+        };
+        let Some(mut config) = self.database.get_sport_config(config_id).await? else {
+            return Ok(None);
+        };
+        if config.get_sport_id() != sport_id || config.migrate(sport_plugin.as_ref()).is_err() {
+            return Ok(None);
+        }
+        Ok(Some(config))
+    }
 }
 
 impl Core<SportConfigState> {
@@ -145,6 +331,15 @@ impl Core<SportConfigState> {
     pub fn get_mut(&mut self) -> &mut SportConfig {
         &mut self.state.config
     }
+    /// Migrate the loaded config to the current version of its sport plugin.
+    fn migrate(&mut self) -> SportResult<()> {
+        let sport_id = self.state.config.sport_id;
+        let Some(sport_plugin) = self.sport_plugins.get(&sport_id) else {
+            return Err(SportError::UnknownSportId(sport_id));
+        };
+        self.state.config.migrate(sport_plugin.as_ref())?;
+        Ok(())
+    }
     fn validate(&self, config: &SportConfig) -> CoreResult<()> {
         let Some(sport_plugin) = self.sport_plugins.get(&config.sport_id) else {
             return Err(CoreError::from(SportError::UnknownSportId(config.sport_id)));
@@ -155,6 +350,7 @@ impl Core<SportConfigState> {
     pub async fn load(&mut self, id: Uuid) -> CoreResult<Option<&SportConfig>> {
         if let Some(config) = self.database.get_sport_config(id).await? {
             self.state.config = config;
+            self.migrate()?;
             self.validate(&self.state.config)?;
 
             Ok(Some(self.get()))
@@ -164,10 +360,33 @@ impl Core<SportConfigState> {
     }
 
     pub async fn save(&mut self) -> CoreResult<&SportConfig> {
+        // configs of older plugin versions, e.g. from imports, are saved in current version
+        self.migrate()?;
         // validate before save
         self.validate(&self.state.config)?;
-        // persist config
-        self.state.config = self.database.save_sport_config(&self.state.config).await?;
+        // stored copy before the save for the audit log
+        let old = match self.state.config.get_version() {
+            Some(_) => {
+                let id = self.state.config.get_id();
+                self.database.get_sport_config(id).await?
+            }
+            None => None,
+        };
+        // persist config; on a version conflict the current server copy is returned
+        self.state.config = match self.database.save_sport_config(&self.state.config).await {
+            Err(DbError::VersionConflict { .. }) => {
+                let id = self.state.config.get_id();
+                let Some(mut theirs) = self.database.get_sport_config(id).await? else {
+                    return Err(CoreError::from(DbError::NotFound));
+                };
+                // server copy is merged with the local copy in the current plugin version
+                if let Some(sport_plugin) = self.sport_plugins.get(&theirs.sport_id) {
+                    theirs.migrate(sport_plugin.as_ref())?;
+                }
+                return Err(CoreError::version_conflict(ServerCopy::SportConfig(theirs)));
+            }
+            res => res?,
+        };
         // publish change of sport config to client registry
         let id = self.state.config.get_id();
         let version = self
@@ -186,20 +405,98 @@ impl Core<SportConfigState> {
         };
         let msg = CrMsg::SportConfigUpdated { id, version };
         self.client_registry.publish(notice, msg).await?;
+        self.audit_save(
+            AuditObjectType::SportConfig,
+            None,
+            old.as_ref(),
+            &self.state.config,
+        )
+        .await;
 
         Ok(self.get())
     }
 
+    /// Archive the currently loaded sport config.
+    /// Archived configs are kept in the database, but are hidden in default listings.
+    pub async fn archive(&mut self) -> CoreResult<&SportConfig> {
+        let Some(version) = self.state.config.get_version() else {
+            return Err(CoreError::from(DbError::NotFound));
+        };
+        let old = self.state.config.clone();
+        self.state.config = self
+            .database
+            .archive_sport_config(self.state.config.get_id(), version)
+            .await?;
+        // publish change of sport config to client registry
+        let id = self.state.config.get_id();
+        let version =
+            self.state.config.get_version().expect(
+                "expecting archive_sport_config to return always an existing id and version",
+            );
+        let notice = CrTopic::SportConfig {
+            sport_config_id: id,
+        };
+        let msg = CrMsg::SportConfigUpdated { id, version };
+        self.client_registry.publish(notice, msg).await?;
+        self.audit(
+            AuditAction::Archive,
+            AuditObjectType::SportConfig,
+            None,
+            Some(&old),
+            Some(&self.state.config),
+        )
+        .await;
+
+        Ok(self.get())
+    }
+
+    /// Archive the sport configs `configs` in one transaction: if one config cannot be
+    /// archived, e.g. because it was changed in the meantime, no config is archived and the
+    /// error is returned. Returns the archived configs.
+    pub async fn archive_many(&self, configs: &[IdVersion]) -> CoreResult<Vec<SportConfig>> {
+        self.with_transaction(|core| async move {
+            let mut config_core = core.as_sport_config_state();
+            let mut archived = Vec::with_capacity(configs.len());
+            for id_version in configs {
+                config_core.get_mut().set_id_version(*id_version);
+                archived.push(config_core.archive().await?.clone());
+            }
+            Ok(archived)
+        })
+        .await
+    }
+
     pub async fn list_sport_config_ids(
         &self,
         sport_id: Uuid,
         name_filter: Option<&str>,
+        include_archived: bool,
         limit: Option<usize>,
+        order: &ListOrder<SportConfig>,
     ) -> CoreResult<Vec<Uuid>> {
         let list = self
             .database
-            .list_sport_config_ids(sport_id, name_filter, limit)
+            .list_sport_config_ids(sport_id, name_filter, include_archived, limit, order)
             .await?;
         Ok(list)
     }
+
+    /// List the active sport configurations of a sport ordered by name, e.g. to choose the
+    /// configuration of a tournament.
+    pub async fn list_sport_configs(
+        &self,
+        sport_id: Uuid,
+        limit: Option<usize>,
+    ) -> CoreResult<Vec<SportConfig>> {
+        let ids = self
+            .list_sport_config_ids(sport_id, None, false, limit, &ListOrder::default())
+            .await?;
+        let mut configs = Vec::with_capacity(ids.len());
+        for id in ids {
+            if let Some(config) = self.database.get_sport_config(id).await? {
+                configs.push(config);
+            }
+        }
+        Ok(configs)
+    }
 }
@@ -0,0 +1,202 @@
+//! Schema of the sport specific part of a sport configuration
+//!
+//! Plugins describe their configuration as a list of [`ConfigFieldSpec`], see
+//! [`SportPort::config_schema`](crate::SportPort::config_schema). The web ui renders a
+//! generic configuration form from it, therefore simple plugins do not need a form of their own.
+
+use serde::{Deserialize, Serialize};
+use serde_json::{Map, Value};
+use std::time::Duration;
+
+/// unit, in which a duration field is entered
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+pub enum ConfigDurationUnit {
+    Seconds,
+    Minutes,
+}
+
+/// option of a select field
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct ConfigFieldOption {
+    /// label shown to the user
+    pub label: String,
+    /// JSON value of the option, e.g. the serialized enum variant
+    pub value: Value,
+}
+
+impl ConfigFieldOption {
+    pub fn new(label: impl Into<String>, value: Value) -> Self {
+        ConfigFieldOption {
+            label: label.into(),
+            value,
+        }
+    }
+
+    /// Name of the variant of the option: the string itself for unit variants or the single
+    /// key of the object for variants with data, e.g. `Custom` of `{"Custom":{"minutes":10}}`.
+    pub fn variant(&self) -> Option<&str> {
+        variant_of(&self.value)
+    }
+
+    /// Check if `value` is of the same variant as the option. The data of the variant is
+    /// ignored, therefore a customized variant still selects its option.
+    pub fn matches(&self, value: &Value) -> bool {
+        self.variant().is_some() && self.variant() == variant_of(value)
+    }
+}
+
+fn variant_of(value: &Value) -> Option<&str> {
+    match value {
+        Value::String(variant) => Some(variant),
+        Value::Object(map) if map.len() == 1 => map.keys().next().map(String::as_str),
+        _ => None,
+    }
+}
+
+/// kind of a configuration field, which determines its input
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub enum ConfigFieldKind {
+    /// whole number
+    Integer { min: Option<i64> },
+    /// decimal number, e.g. victory points
+    Decimal { min: Option<f64>, step: Option<f64> },
+    /// duration, which is stored like `std::time::Duration`
+    Duration { unit: ConfigDurationUnit },
+    /// on/ off switch
+    Toggle,
+    /// free text
+    Text,
+    /// one of a list of options
+    Select { options: Vec<ConfigFieldOption> },
+}
+
+/// Specification of one field of the sport specific configuration.
+///
+/// A field addresses its value with a JSON pointer into the configuration. Fields, whose
+/// value does not exist in the current configuration, are not shown. This way fields of
+/// variant data (e.g. `/expedite_cfg/Custom/minutes`) only show up, if the variant is selected.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct ConfigFieldSpec {
+    /// name of the field; used as input name and for test ids
+    pub name: String,
+    /// label shown to the user
+    pub label: String,
+    /// JSON pointer to the value of the field
+    pub pointer: String,
+    /// name of the field in validation errors of the plugin
+    pub error_field: String,
+    /// kind of the field
+    pub kind: ConfigFieldKind,
+}
+
+impl ConfigFieldSpec {
+    /// Create a field for the top level key `name` of the configuration.
+    pub fn new(name: impl Into<String>, label: impl Into<String>, kind: ConfigFieldKind) -> Self {
+        let name = name.into();
+        ConfigFieldSpec {
+            pointer: format!("/{name}"),
+            error_field: name.clone(),
+            name,
+            label: label.into(),
+            kind,
+        }
+    }
+
+    /// Set the JSON pointer to the value of the field.
+    pub fn at(mut self, pointer: impl Into<String>) -> Self {
+        self.pointer = pointer.into();
+        self
+    }
+
+    /// Set the name of the field in validation errors of the plugin.
+    pub fn reported_as(mut self, error_field: impl Into<String>) -> Self {
+        self.error_field = error_field.into();
+        self
+    }
+
+    /// Get the value of the field in `config`, if it exists.
+    pub fn read<'a>(&self, config: &'a Value) -> Option<&'a Value> {
+        config.pointer(&self.pointer)
+    }
+
+    /// Set the value of the field in `config`. Returns false, if the field does not exist.
+    pub fn write(&self, config: &mut Value, value: Value) -> bool {
+        match config.pointer_mut(&self.pointer) {
+            Some(current) => {
+                *current = value;
+                true
+            }
+            None => false,
+        }
+    }
+
+    /// Get the value of a duration field in `config`.
+    pub fn read_duration(&self, config: &Value) -> Option<Duration> {
+        self.read(config)
+            .and_then(|value| serde_json::from_value(value.clone()).ok())
+    }
+
+    /// Set the value of a duration field in `config`.
+    pub fn write_duration(&self, config: &mut Value, duration: Duration) -> bool {
+        let mut value = Map::new();
+        value.insert("secs".into(), duration.as_secs().into());
+        value.insert("nanos".into(), duration.subsec_nanos().into());
+        self.write(config, Value::Object(value))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use serde_json::json;
+
+    #[test]
+    fn options_match_by_variant() {
+        let custom = ConfigFieldOption::new("Custom", json!({"Custom": {"minutes": 10}}));
+        let off = ConfigFieldOption::new("Off", json!("Off"));
+
+        assert!(custom.matches(&json!({"Custom": {"minutes": 15}})));
+        assert!(!custom.matches(&json!("Off")));
+        assert!(off.matches(&json!("Off")));
+        assert!(!off.matches(&json!(1)));
+    }
+
+    #[test]
+    fn fields_read_and_write_nested_values() {
+        let mut config = json!({"expedite_cfg": {"Custom": {"minutes": 10}}, "points": 1.0});
+        let minutes = ConfigFieldSpec::new(
+            "expedite_minutes",
+            "Minutes",
+            ConfigFieldKind::Integer { min: Some(1) },
+        )
+        .at("/expedite_cfg/Custom/minutes")
+        .reported_as("expedite_cfg");
+
+        assert_eq!(minutes.read(&config), Some(&json!(10)));
+        assert!(minutes.write(&mut config, json!(12)));
+        assert_eq!(config["expedite_cfg"]["Custom"]["minutes"], json!(12));
+
+        config["expedite_cfg"] = json!("Off");
+        assert_eq!(minutes.read(&config), None);
+        assert!(!minutes.write(&mut config, json!(12)));
+    }
+
+    #[test]
+    fn durations_are_stored_like_std_duration() {
+        let mut config = json!({"rally": {"secs": 15, "nanos": 0}});
+        let rally = ConfigFieldSpec::new(
+            "rally",
+            "Rally",
+            ConfigFieldKind::Duration {
+                unit: ConfigDurationUnit::Seconds,
+            },
+        );
+
+        assert_eq!(rally.read_duration(&config), Some(Duration::from_secs(15)));
+        assert!(rally.write_duration(&mut config, Duration::from_secs(20)));
+        assert_eq!(
+            serde_json::from_value::<Duration>(config["rally"].clone()).unwrap(),
+            Duration::from_secs(20)
+        );
+    }
+}
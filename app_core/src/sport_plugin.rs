@@ -1,7 +1,8 @@
 //! Sport Plugin core functionality
 
-use crate::{Core, SportPort};
-use std::sync::Arc;
+use crate::{Core, SportPort, utils::traits::ObjectIdVersion};
+use serde::{Deserialize, Serialize};
+use std::{fmt::Display, sync::Arc};
 use uuid::Uuid;
 
 /// State marker for SportPlugin related functionality
@@ -21,4 +22,130 @@ impl Core<SportPluginState> {
     pub fn get_sport(&self, sport_id: &Uuid) -> Option<Arc<dyn SportPort>> {
         self.sport_plugins.get(sport_id)
     }
+
+    /// Compare the sport plugins of a client, given as pairs of sport id and plugin version,
+    /// with the sport plugins of the server.
+    pub fn check_plugin_parity(&self, client_plugins: &[(Uuid, u32)]) -> SportPluginParity {
+        let server_plugins: Vec<(Uuid, u32)> = self
+            .list_sports()
+            .iter()
+            .map(|plugin| (plugin.get_id_version().get_id(), plugin.plugin_version()))
+            .collect();
+        SportPluginParity::new(&server_plugins, client_plugins)
+    }
+}
+
+/// Plugin known to client and server with different plugin versions
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+pub struct PluginVersionMismatch {
+    pub sport_id: Uuid,
+    pub server_version: u32,
+    pub client_version: u32,
+}
+
+/// Differences between the sport plugins of a client and of the server.
+///
+/// Client and server register their plugins independently, therefore they may drift apart.
+/// Plugins of the client, which are not registered at the server, are no drift: the server
+/// may be configured to register only some plugins and clients only show sports of the
+/// server.
+#[derive(Debug, Clone, Default, PartialEq, Eq, Serialize, Deserialize)]
+pub struct SportPluginParity {
+    /// plugins of the server, which are unknown to the client
+    pub missing_on_client: Vec<Uuid>,
+    /// plugins with different plugin versions at client and server
+    pub version_mismatches: Vec<PluginVersionMismatch>,
+    /// plugins of the client, which are not registered at the server
+    pub not_registered_on_server: Vec<Uuid>,
+}
+
+impl SportPluginParity {
+    /// Compare pairs of sport id and plugin version of server and client.
+    pub fn new(server_plugins: &[(Uuid, u32)], client_plugins: &[(Uuid, u32)]) -> Self {
+        let mut parity = SportPluginParity::default();
+        for (sport_id, server_version) in server_plugins.iter().copied() {
+            match client_plugins.iter().find(|(id, _)| *id == sport_id) {
+                None => parity.missing_on_client.push(sport_id),
+                Some((_, client_version)) if *client_version != server_version => {
+                    parity.version_mismatches.push(PluginVersionMismatch {
+                        sport_id,
+                        server_version,
+                        client_version: *client_version,
+                    })
+                }
+                Some(_) => {}
+            }
+        }
+        parity.not_registered_on_server = client_plugins
+            .iter()
+            .map(|(id, _)| *id)
+            .filter(|id| !server_plugins.iter().any(|(s_id, _)| s_id == id))
+            .collect();
+        parity
+    }
+
+    /// true, if the client knows every plugin of the server in the same version
+    pub fn is_ok(&self) -> bool {
+        self.missing_on_client.is_empty() && self.version_mismatches.is_empty()
+    }
+}
+
+impl Display for SportPluginParity {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        if self.is_ok() {
+            return write!(f, "client and server plugins match");
+        }
+        let mut problems = Vec::new();
+        for sport_id in self.missing_on_client.iter() {
+            problems.push(format!("plugin {sport_id} is missing on client"));
+        }
+        for m in self.version_mismatches.iter() {
+            problems.push(format!(
+                "plugin {} has version {} on server, but {} on client",
+                m.sport_id, m.server_version, m.client_version
+            ));
+        }
+        write!(f, "{}", problems.join("; "))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn given_equal_plugins_when_compare_then_ok() {
+        let a = Uuid::new_v4();
+        let b = Uuid::new_v4();
+        let parity = SportPluginParity::new(&[(a, 0), (b, 2)], &[(b, 2), (a, 0)]);
+        assert!(parity.is_ok());
+        assert_eq!(parity, SportPluginParity::default());
+    }
+
+    #[test]
+    fn given_missing_plugin_and_other_version_when_compare_then_drift() {
+        let a = Uuid::new_v4();
+        let b = Uuid::new_v4();
+        let parity = SportPluginParity::new(&[(a, 0), (b, 2)], &[(b, 1)]);
+        assert!(!parity.is_ok());
+        assert_eq!(parity.missing_on_client, vec![a]);
+        assert_eq!(
+            parity.version_mismatches,
+            vec![PluginVersionMismatch {
+                sport_id: b,
+                server_version: 2,
+                client_version: 1,
+            }]
+        );
+        assert!(parity.to_string().contains("missing on client"));
+    }
+
+    #[test]
+    fn given_plugin_not_registered_on_server_when_compare_then_ok() {
+        let a = Uuid::new_v4();
+        let b = Uuid::new_v4();
+        let parity = SportPluginParity::new(&[(a, 0)], &[(a, 0), (b, 0)]);
+        assert!(parity.is_ok());
+        assert_eq!(parity.not_registered_on_server, vec![b]);
+    }
 }
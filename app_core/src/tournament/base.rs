@@ -1,17 +1,23 @@
 //! Base parameters of a tournament
 
+use super::{Station, StationSetup, StationWindow, is_finishing_transition, is_gated_transition};
 use crate::{
-    Core, CoreError, CoreResult, CrMsg, CrTopic, SportError,
+    AuditAction, AuditObjectType, Core, CoreError, CoreResult, CourtCallPolicy, CrMsg, CrTopic,
+    DbError, DomainEvent, Language, LocalizedText, MAX_CALL_AHEAD, MergeFields, NoShowPolicy,
+    ServerCopy, SportError, Venue, WebhookEventData, merge_display_value,
     utils::{
+        filter::{Filter, Filterable},
         id_version::IdVersion,
+        list_order::{ListOrder, Sortable},
         normalize::normalize_ws,
         traits::ObjectIdVersion,
         validation::{FieldError, ValidationErrors, ValidationResult},
     },
 };
+use chrono::{DateTime, Utc};
 use displaydoc::Display;
 use serde::{Deserialize, Serialize};
-use std::{fmt::Display, str::FromStr};
+use std::{cmp::Ordering, fmt::Display, str::FromStr};
 use uuid::Uuid;
 
 /// mode of tournament
@@ -38,7 +44,7 @@ impl From<String> for TournamentType {
 /// mode of tournament
 /// If there are Mode specific configuration values, which cannot be placed
 /// in sub structures like Stage, Group, Match, etc., we may need to add them here.
-/// For now, Swiss system needs number of rounds.
+/// For now, Swiss system needs number of rounds and ring system the number of neighbors.
 #[derive(Debug, Clone, Copy, PartialEq, Eq, Default, Serialize, Deserialize)]
 pub enum TournamentMode {
     /// Single Stage
@@ -50,6 +56,9 @@ pub enum TournamentMode {
     TwoPoolStagesAndFinalStage,
     /// Swiss System
     SwissSystem { num_rounds: u32 },
+    /// Ring System: entrants are placed in a ring and play their `num_neighbors` next
+    /// neighbors on each side of the ring
+    RingSystem { num_neighbors: u32 },
 }
 
 impl Display for TournamentMode {
@@ -63,6 +72,9 @@ impl Display for TournamentMode {
             TournamentMode::SwissSystem { num_rounds } => {
                 write!(f, "Swiss System ({} rounds)", num_rounds)
             }
+            TournamentMode::RingSystem { num_neighbors } => {
+                write!(f, "Ring System ({} neighbors)", num_neighbors)
+            }
         }
     }
 }
@@ -74,6 +86,15 @@ impl TournamentMode {
             TournamentMode::PoolAndFinalStage => 2,
             TournamentMode::TwoPoolStagesAndFinalStage => 3,
             TournamentMode::SwissSystem { num_rounds: _ } => 1,
+            TournamentMode::RingSystem { num_neighbors: _ } => 1,
+        }
+    }
+    /// Get the number of stages, which are active one after another while the tournament
+    /// runs. In Swiss system each round is an active stage.
+    pub fn get_num_of_active_stages(&self) -> u32 {
+        match self {
+            TournamentMode::SwissSystem { num_rounds } => *num_rounds,
+            _ => self.get_num_of_stages(),
         }
     }
     pub fn get_stage_name(&self, stage_number: u32) -> Option<String> {
@@ -91,6 +112,19 @@ impl TournamentMode {
                 _ => None,
             },
             TournamentMode::SwissSystem { num_rounds: _ } => Some("Swiss System".to_string()),
+            TournamentMode::RingSystem { num_neighbors: _ } => Some("Ring System".to_string()),
+        }
+    }
+    /// Check if matches of next round of stage depend upon results of previous rounds.
+    /// This applies to Swiss pairing and to KO play out of the final stage of multi stage
+    /// modes. Round robin and ring system stages are fully scheduled in advance.
+    pub fn has_result_dependent_rounds(&self, stage_number: u32) -> bool {
+        match self {
+            TournamentMode::SingleStage | TournamentMode::RingSystem { .. } => false,
+            TournamentMode::PoolAndFinalStage | TournamentMode::TwoPoolStagesAndFinalStage => {
+                stage_number + 1 == self.get_num_of_stages()
+            }
+            TournamentMode::SwissSystem { num_rounds: _ } => true,
         }
     }
 }
@@ -146,7 +180,7 @@ impl FromStr for TournamentState {
 }
 
 /// base parameters of a tournament
-#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize, Default)]
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize, ObjectIdVersion)]
 pub struct TournamentBase {
     /// Unique identifier for the tournament
     id_version: IdVersion,
@@ -154,19 +188,176 @@ pub struct TournamentBase {
     name: String,
     /// id of sport
     sport_id: Uuid,
+    /// sport configuration of the tournament, which drives scoring, standings and durations;
+    /// `None`, if no configuration is chosen yet
+    #[serde(default)]
+    sport_config_id: Option<Uuid>,
     /// number of entrants; represents size of tournament
     num_entrants: u32,
+    /// number of stations (e.g. courts or tables), on which matches are played simultaneously
+    #[serde(default = "default_num_stations")]
+    num_stations: u32,
     /// type of tournament
     t_type: TournamentType,
     /// mode of tournament
     mode: TournamentMode,
     /// state of tournament
     state: TournamentState,
+    /// time of archiving; archived tournaments are hidden in default listings
+    archived_at: Option<DateTime<Utc>>,
+    /// time of creation; set by the database port
+    created_at: Option<DateTime<Utc>>,
+    /// sandbox tournaments are for practice: they are freely editable regardless of their
+    /// state, hidden from the public and may be purged at any time
+    #[serde(default)]
+    sandbox: bool,
+    /// display languages of public pages; the first language is the default language.
+    /// No languages means English only.
+    #[serde(default)]
+    languages: Vec<Language>,
+    /// description of tournament shown on public pages
+    #[serde(default)]
+    description: LocalizedText,
+    /// named stations in order of their numbers; may name fewer stations than
+    /// `num_stations`. Unnamed stations are referred to by their number.
+    #[serde(default)]
+    stations: Vec<Station>,
+    /// availability of stations over the tournament ordered by start; before the first window
+    /// all stations are available
+    #[serde(default)]
+    station_windows: Vec<StationWindow>,
+    /// entrants, who are not checked in by the deadline, are handled by `no_show_policy`;
+    /// `None`, if check-in has no deadline
+    #[serde(default)]
+    check_in_deadline: Option<DateTime<Utc>>,
+    /// handling of entrants, who are not checked in by the deadline
+    #[serde(default)]
+    no_show_policy: NoShowPolicy,
+    /// postal address of the location of the tournament, e.g. a sports hall
+    #[serde(default)]
+    address_id: Option<Uuid>,
+    /// venue of the tournament, whose courts were taken as stations
+    #[serde(default)]
+    venue_id: Option<Uuid>,
+    /// calls of matches to their stations
+    #[serde(default)]
+    court_call_policy: CourtCallPolicy,
+}
+
+fn default_num_stations() -> u32 {
+    1
 }
 
-impl ObjectIdVersion for TournamentBase {
-    fn get_id_version(&self) -> IdVersion {
-        self.id_version
+impl Default for TournamentBase {
+    fn default() -> Self {
+        TournamentBase {
+            id_version: IdVersion::default(),
+            name: String::new(),
+            sport_id: Uuid::nil(),
+            sport_config_id: None,
+            num_entrants: 0,
+            num_stations: default_num_stations(),
+            t_type: TournamentType::default(),
+            mode: TournamentMode::default(),
+            state: TournamentState::default(),
+            archived_at: None,
+            created_at: None,
+            sandbox: false,
+            languages: Vec::new(),
+            description: LocalizedText::default(),
+            stations: Vec::new(),
+            station_windows: Vec::new(),
+            check_in_deadline: None,
+            no_show_policy: NoShowPolicy::default(),
+            address_id: None,
+            venue_id: None,
+            court_call_policy: CourtCallPolicy::default(),
+        }
+    }
+}
+
+/// State, sport, sandbox flag, check-in settings and court call policy of a tournament are not
+/// merged, since they are not edited in the editor; they are always taken from the server copy.
+impl MergeFields for TournamentBase {
+    fn merge_field_names(&self, _theirs: &Self) -> Vec<String> {
+        [
+            "name",
+            "sport_config_id",
+            "num_entrants",
+            "num_stations",
+            "stations",
+            "station_windows",
+            "t_type",
+            "mode",
+            "languages",
+            "description",
+            "address_id",
+            "venue_id",
+        ]
+        .map(String::from)
+        .to_vec()
+    }
+
+    fn merge_field_value(&self, field: &str) -> Option<String> {
+        let value = match field {
+            "name" => merge_display_value(&self.name),
+            "sport_config_id" => self
+                .sport_config_id
+                .map(|id| id.to_string())
+                .unwrap_or_default(),
+            "num_entrants" => self.num_entrants.to_string(),
+            "num_stations" => self.num_stations.to_string(),
+            "stations" => self
+                .stations
+                .iter()
+                .map(Station::get_name)
+                .collect::<Vec<_>>()
+                .join(", "),
+            "station_windows" => self
+                .station_windows
+                .iter()
+                .map(|w| format!("{}: {}", w.start.format("%Y-%m-%d %H:%M"), w.num_stations))
+                .collect::<Vec<_>>()
+                .join(", "),
+            "t_type" => self.t_type.to_string(),
+            "mode" => self.mode.to_string(),
+            "languages" => self
+                .languages
+                .iter()
+                .map(|l| l.to_string())
+                .collect::<Vec<_>>()
+                .join(", "),
+            "description" => merge_display_value(&self.description),
+            "address_id" => self.address_id.map(|id| id.to_string()).unwrap_or_default(),
+            "venue_id" => self.venue_id.map(|id| id.to_string()).unwrap_or_default(),
+            _ => return None,
+        };
+        Some(value)
+    }
+
+    fn take_merge_field(&mut self, other: &Self, field: &str) {
+        match field {
+            "name" => self.name = other.name.clone(),
+            "sport_config_id" => self.sport_config_id = other.sport_config_id,
+            "num_entrants" => self.num_entrants = other.num_entrants,
+            // setters keep number of stations and named stations consistent
+            "num_stations" => {
+                self.set_num_stations(other.num_stations);
+            }
+            "stations" => {
+                self.set_stations(other.stations.clone());
+            }
+            "station_windows" => {
+                self.set_station_windows(other.station_windows.clone());
+            }
+            "t_type" => self.t_type = other.t_type,
+            "mode" => self.mode = other.mode,
+            "languages" => self.languages = other.languages.clone(),
+            "description" => self.description = other.description.clone(),
+            "address_id" => self.address_id = other.address_id,
+            "venue_id" => self.venue_id = other.venue_id,
+            _ => {}
+        }
     }
 }
 
@@ -199,11 +390,21 @@ impl TournamentBase {
         self.sport_id
     }
 
+    /// Get the id of the sport configuration of the tournament.
+    pub fn get_sport_config_id(&self) -> Option<Uuid> {
+        self.sport_config_id
+    }
+
     /// Get the number of entrants in the tournament.
     pub fn get_num_entrants(&self) -> u32 {
         self.num_entrants
     }
 
+    /// Get the number of stations of the tournament.
+    pub fn get_num_stations(&self) -> u32 {
+        self.num_stations
+    }
+
     /// Get the type of the tournament.
     pub fn get_tournament_type(&self) -> TournamentType {
         self.t_type
@@ -223,11 +424,133 @@ impl TournamentBase {
         }
     }
 
+    /// Get the number of neighbors on each side of the ring, if mode is Ring System.
+    pub fn get_num_neighbors_ring_system(&self) -> Option<u32> {
+        if let TournamentMode::RingSystem { num_neighbors } = self.mode {
+            Some(num_neighbors)
+        } else {
+            None
+        }
+    }
+
     /// Get the current state of the tournament.
     pub fn get_tournament_state(&self) -> TournamentState {
         self.state
     }
 
+    /// Get the time, when the tournament was archived.
+    pub fn get_archived_at(&self) -> Option<DateTime<Utc>> {
+        self.archived_at
+    }
+
+    /// Check if the tournament is archived.
+    pub fn is_archived(&self) -> bool {
+        self.archived_at.is_some()
+    }
+
+    /// Get the time, when the tournament was created.
+    pub fn get_created_at(&self) -> Option<DateTime<Utc>> {
+        self.created_at
+    }
+
+    /// Check if the tournament is a sandbox tournament.
+    pub fn is_sandbox(&self) -> bool {
+        self.sandbox
+    }
+
+    /// Get the display languages of public pages.
+    pub fn get_languages(&self) -> &[Language] {
+        &self.languages
+    }
+
+    /// Get the default language of public pages, which is the first display language.
+    pub fn get_default_language(&self) -> Language {
+        self.languages.first().copied().unwrap_or_default()
+    }
+
+    /// Check if public pages are displayed in `language`.
+    pub fn has_language(&self, language: Language) -> bool {
+        if self.languages.is_empty() {
+            language == Language::default()
+        } else {
+            self.languages.contains(&language)
+        }
+    }
+
+    /// Get the description of the tournament.
+    pub fn get_description(&self) -> &LocalizedText {
+        &self.description
+    }
+
+    /// Get the named stations of the tournament.
+    pub fn get_stations(&self) -> &[Station] {
+        &self.stations
+    }
+
+    /// Get the availability of stations over the tournament ordered by start.
+    pub fn get_station_windows(&self) -> &[StationWindow] {
+        &self.station_windows
+    }
+
+    /// Get the number of stations, which are available at `time`. Before the first window
+    /// all stations of the tournament are available.
+    pub fn get_num_stations_at(&self, time: DateTime<Utc>) -> u32 {
+        self.station_windows
+            .iter()
+            .rev()
+            .find(|w| w.start <= time)
+            .map_or(self.num_stations, |w| w.num_stations.min(self.num_stations))
+    }
+
+    /// Get the name of station `number` (starting with 1), if the station is named.
+    pub fn get_station_name(&self, number: u32) -> Option<&str> {
+        number
+            .checked_sub(1)
+            .and_then(|index| self.stations.get(index as usize))
+            .map(Station::get_name)
+    }
+
+    /// Get the check-in deadline of the tournament.
+    pub fn get_check_in_deadline(&self) -> Option<DateTime<Utc>> {
+        self.check_in_deadline
+    }
+
+    /// Check if the check-in deadline has passed at `now`; false, if there is no deadline.
+    pub fn is_check_in_deadline_passed(&self, now: DateTime<Utc>) -> bool {
+        self.check_in_deadline
+            .is_some_and(|deadline| deadline <= now)
+    }
+
+    /// Get the handling of entrants, who are not checked in by the deadline.
+    pub fn get_no_show_policy(&self) -> NoShowPolicy {
+        self.no_show_policy
+    }
+
+    /// Get the configuration of calls of matches to their stations.
+    pub fn get_court_call_policy(&self) -> CourtCallPolicy {
+        self.court_call_policy
+    }
+
+    /// Get the id of the postal address of the tournament location.
+    pub fn get_address_id(&self) -> Option<Uuid> {
+        self.address_id
+    }
+
+    /// Get the id of the venue of the tournament.
+    pub fn get_venue_id(&self) -> Option<Uuid> {
+        self.venue_id
+    }
+
+    /// Check if editing the tournament is locked by its state, i.e. if it is running or
+    /// finished. Sandbox tournaments are never locked.
+    pub fn is_locked_by_state(&self) -> bool {
+        !self.sandbox
+            && matches!(
+                self.state,
+                TournamentState::ActiveStage(_) | TournamentState::Finished
+            )
+    }
+
     /// Set the `IdVersion` of the sport configuration.
     pub fn set_id_version(&mut self, id_version: IdVersion) -> &mut Self {
         self.id_version = id_version;
@@ -261,12 +584,67 @@ impl TournamentBase {
         self
     }
 
+    /// Set the id of the sport configuration of the tournament.
+    pub fn set_sport_config_id(&mut self, sport_config_id: Option<Uuid>) -> &mut Self {
+        self.sport_config_id = sport_config_id;
+        self
+    }
+
     /// Set the number of entrants in the tournament.
     pub fn set_num_entrants(&mut self, num_entrants: u32) -> &mut Self {
         self.num_entrants = num_entrants;
         self
     }
 
+    /// Set the number of stations of the tournament. Named stations beyond the number of
+    /// stations are removed.
+    pub fn set_num_stations(&mut self, num_stations: u32) -> &mut Self {
+        self.num_stations = num_stations;
+        self.stations.truncate(num_stations as usize);
+        self
+    }
+
+    /// Set the named stations of the tournament. The number of stations is raised to the
+    /// number of named stations, so that the scheduler uses all of them.
+    pub fn set_stations(&mut self, stations: Vec<Station>) -> &mut Self {
+        self.num_stations = self.num_stations.max(stations.len() as u32);
+        self.stations = stations;
+        self
+    }
+
+    /// Set the availability of stations over the tournament; windows are ordered by start.
+    pub fn set_station_windows(&mut self, mut station_windows: Vec<StationWindow>) -> &mut Self {
+        station_windows.sort_by_key(|w| w.start);
+        self.station_windows = station_windows;
+        self
+    }
+
+    /// Rename station `number` (starting with 1). Returns false, if the station is not named.
+    pub fn rename_station(&mut self, number: u32, name: impl Into<String>) -> bool {
+        match number
+            .checked_sub(1)
+            .and_then(|index| self.stations.get_mut(index as usize))
+        {
+            Some(station) => {
+                station.set_name(name);
+                true
+            }
+            None => false,
+        }
+    }
+
+    /// Move station `number` to position `to` (both starting with 1), which renumbers the
+    /// stations in between. Returns false, if one of the stations is not named.
+    pub fn move_station(&mut self, number: u32, to: u32) -> bool {
+        let len = self.stations.len() as u32;
+        if !(1..=len).contains(&number) || !(1..=len).contains(&to) {
+            return false;
+        }
+        let station = self.stations.remove(number as usize - 1);
+        self.stations.insert(to as usize - 1, station);
+        true
+    }
+
     /// Set the type of the tournament.
     pub fn set_tournament_type(&mut self, t_type: TournamentType) -> &mut Self {
         self.t_type = t_type;
@@ -287,12 +665,106 @@ impl TournamentBase {
         self
     }
 
+    /// Set the number of neighbors on each side of the ring, if mode is Ring System.
+    pub fn set_num_neighbors_ring_system(&mut self, num_neighbors_ring: u32) -> &mut Self {
+        if let TournamentMode::RingSystem {
+            ref mut num_neighbors,
+        } = self.mode
+        {
+            *num_neighbors = num_neighbors_ring;
+        }
+        self
+    }
+
     /// Set the current state of the tournament.
     pub fn set_tournament_state(&mut self, state: TournamentState) -> &mut Self {
         self.state = state;
         self
     }
 
+    /// Set the time of archiving. Archiving itself is done by the database port.
+    pub fn set_archived_at(&mut self, archived_at: Option<DateTime<Utc>>) -> &mut Self {
+        self.archived_at = archived_at;
+        self
+    }
+
+    /// Set the time of creation. Creation time itself is set by the database port.
+    pub fn set_created_at(&mut self, created_at: Option<DateTime<Utc>>) -> &mut Self {
+        self.created_at = created_at;
+        self
+    }
+
+    /// Mark the tournament as sandbox tournament.
+    pub fn set_sandbox(&mut self, sandbox: bool) -> &mut Self {
+        self.sandbox = sandbox;
+        self
+    }
+
+    /// Set the display languages of public pages; duplicates are removed. Translations of
+    /// the description into languages, which are not displayed, are removed as well.
+    pub fn set_languages(&mut self, languages: impl IntoIterator<Item = Language>) -> &mut Self {
+        self.languages.clear();
+        for language in languages {
+            if !self.languages.contains(&language) {
+                self.languages.push(language);
+            }
+        }
+        // text of description is in default language; further languages are translated
+        let translated = self.languages.get(1..).unwrap_or_default().to_vec();
+        self.description.retain_languages(&translated);
+        self
+    }
+
+    /// Set the description of the tournament.
+    pub fn set_description(&mut self, description: LocalizedText) -> &mut Self {
+        self.description = description;
+        self
+    }
+
+    /// Set the check-in deadline of the tournament.
+    pub fn set_check_in_deadline(&mut self, check_in_deadline: Option<DateTime<Utc>>) -> &mut Self {
+        self.check_in_deadline = check_in_deadline;
+        self
+    }
+
+    /// Set the handling of entrants, who are not checked in by the deadline.
+    pub fn set_no_show_policy(&mut self, no_show_policy: NoShowPolicy) -> &mut Self {
+        self.no_show_policy = no_show_policy;
+        self
+    }
+
+    /// Set the configuration of calls of matches to their stations.
+    pub fn set_court_call_policy(&mut self, court_call_policy: CourtCallPolicy) -> &mut Self {
+        self.court_call_policy = court_call_policy;
+        self
+    }
+
+    /// Set the id of the postal address of the tournament location.
+    pub fn set_address_id(&mut self, address_id: Option<Uuid>) -> &mut Self {
+        self.address_id = address_id;
+        self
+    }
+
+    /// Set the id of the venue of the tournament.
+    pub fn set_venue_id(&mut self, venue_id: Option<Uuid>) -> &mut Self {
+        self.venue_id = venue_id;
+        self
+    }
+
+    /// Take stations and location from `venue`: the available courts of the venue replace the
+    /// named stations, the number of stations follows them and the postal address of the
+    /// venue becomes the location of the tournament.
+    pub fn apply_venue(&mut self, venue: &Venue) -> &mut Self {
+        let stations = venue.stations();
+        if !stations.is_empty() {
+            self.set_num_stations(stations.len() as u32);
+        }
+        self.set_stations(stations);
+        self.venue_id = Some(venue.get_id());
+        self.address_id = venue.get_address_id();
+        self
+    }
+
     /// Validate the tournament configuration.
     pub fn validate(&self) -> ValidationResult<()> {
         let mut errs = ValidationErrors::new();
@@ -318,6 +790,64 @@ impl TournamentBase {
             );
         }
 
+        if self.num_stations == 0 {
+            errs.add(
+                FieldError::builder()
+                    .set_field(String::from("num_stations"))
+                    .add_message("number of stations must be at least 1")
+                    .set_object_id(object_id)
+                    .build(),
+            );
+        }
+
+        for (index, station) in self.stations.iter().enumerate() {
+            if station.get_name().is_empty() {
+                errs.add(
+                    FieldError::builder()
+                        .set_field(format!("stations.{}", index + 1))
+                        .add_required()
+                        .set_object_id(object_id)
+                        .build(),
+                );
+            }
+        }
+
+        for (index, window) in self.station_windows.iter().enumerate() {
+            if window.num_stations > self.num_stations {
+                errs.add(
+                    FieldError::builder()
+                        .set_field(format!("station_windows.{}", index + 1))
+                        .add_message(format!(
+                            "number of available stations must not exceed {}",
+                            self.num_stations
+                        ))
+                        .set_object_id(object_id)
+                        .build(),
+                );
+            }
+            if index > 0 && self.station_windows[index - 1].start == window.start {
+                errs.add(
+                    FieldError::builder()
+                        .set_field(format!("station_windows.{}", index + 1))
+                        .add_message("station windows must start at different times")
+                        .set_object_id(object_id)
+                        .build(),
+                );
+            }
+        }
+
+        if self.court_call_policy.call_ahead > MAX_CALL_AHEAD {
+            errs.add(
+                FieldError::builder()
+                    .set_field(String::from("court_call_policy.call_ahead"))
+                    .add_message(format!(
+                        "number of matches called ahead must not exceed {MAX_CALL_AHEAD}"
+                    ))
+                    .set_object_id(object_id)
+                    .build(),
+            );
+        }
+
         match self.mode {
             TournamentMode::SwissSystem { num_rounds } => {
                 if num_rounds == 0 {
@@ -330,21 +860,35 @@ impl TournamentBase {
                     );
                 }
             }
+            TournamentMode::RingSystem { num_neighbors } => {
+                if num_neighbors == 0 {
+                    errs.add(
+                        FieldError::builder()
+                            .set_field(String::from("mode.num_neighbors"))
+                            .add_message("number of neighbors must be > 0")
+                            .set_object_id(object_id)
+                            .build(),
+                    );
+                } else if 2 * num_neighbors + 1 >= self.num_entrants {
+                    // with more neighbors each entrant meets everybody: use round robin
+                    errs.add(
+                        FieldError::builder()
+                            .set_field(String::from("mode.num_neighbors"))
+                            .add_message(format!(
+                                "number of neighbors must be at most {}, otherwise use Single Stage",
+                                self.num_entrants.saturating_sub(2) / 2
+                            ))
+                            .set_object_id(object_id)
+                            .build(),
+                    );
+                }
+            }
             _ => {}
         }
 
-        // ToDo: refine validation of active stage based on mode, when active stage is implemented
-        let max_num_stages = match self.mode {
-            // in Swiss System, each round is a stage
-            TournamentMode::SwissSystem { num_rounds } => num_rounds,
-            TournamentMode::SingleStage => 1,
-            TournamentMode::PoolAndFinalStage => 2,
-            TournamentMode::TwoPoolStagesAndFinalStage => 3,
-        };
-
         if let TournamentState::ActiveStage(active_stage) = self.state {
             // index stages from 0
-            if active_stage >= max_num_stages {
+            if active_stage >= self.mode.get_num_of_active_stages() {
                 errs.add(
                     FieldError::builder()
                         .set_field(String::from("state"))
@@ -364,6 +908,110 @@ impl TournamentBase {
     }
 }
 
+/// condition of a `Filter<TournamentBase>`
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub enum TournamentBaseCondition {
+    /// tournament belongs to sport
+    SportIs(Uuid),
+    /// name contains text (case insensitive); empty text matches all
+    NameContains(String),
+    /// state is one of the given states
+    StateIn(Vec<TournamentState>),
+    /// creation time lies in `[from, to)`; open bounds are `None`
+    CreatedBetween {
+        from: Option<DateTime<Utc>>,
+        to: Option<DateTime<Utc>>,
+    },
+    /// tournament is not an adhoc tournament
+    NotAdhoc,
+    /// tournament is not archived
+    NotArchived,
+    /// tournament is not a sandbox tournament
+    NotSandbox,
+    /// tournament is not a draft
+    NotDraft,
+}
+
+impl Filterable for TournamentBase {
+    type Condition = TournamentBaseCondition;
+
+    fn matches(&self, condition: &TournamentBaseCondition) -> bool {
+        match condition {
+            TournamentBaseCondition::SportIs(sport_id) => self.sport_id == *sport_id,
+            TournamentBaseCondition::NameContains(text) => {
+                self.name.to_lowercase().contains(&text.to_lowercase())
+            }
+            TournamentBaseCondition::StateIn(states) => states.contains(&self.state),
+            TournamentBaseCondition::CreatedBetween { from, to } => {
+                let Some(created_at) = self.created_at else {
+                    return from.is_none() && to.is_none();
+                };
+                from.is_none_or(|from| created_at >= from) && to.is_none_or(|to| created_at < to)
+            }
+            TournamentBaseCondition::NotAdhoc => self.t_type != TournamentType::Adhoc,
+            TournamentBaseCondition::NotArchived => !self.is_archived(),
+            TournamentBaseCondition::NotSandbox => !self.sandbox,
+            TournamentBaseCondition::NotDraft => self.state != TournamentState::Draft,
+        }
+    }
+}
+
+/// column, by which lists of tournaments are sorted
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default, Serialize, Deserialize)]
+pub enum TournamentBaseSortColumn {
+    #[default]
+    Name,
+    CreatedAt,
+    NumEntrants,
+}
+
+impl TournamentBaseSortColumn {
+    pub const ALL: [TournamentBaseSortColumn; 3] = [
+        TournamentBaseSortColumn::Name,
+        TournamentBaseSortColumn::CreatedAt,
+        TournamentBaseSortColumn::NumEntrants,
+    ];
+
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            TournamentBaseSortColumn::Name => "name",
+            TournamentBaseSortColumn::CreatedAt => "created_at",
+            TournamentBaseSortColumn::NumEntrants => "num_entrants",
+        }
+    }
+}
+
+impl Display for TournamentBaseSortColumn {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{}", self.as_str())
+    }
+}
+
+impl FromStr for TournamentBaseSortColumn {
+    type Err = CoreError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        TournamentBaseSortColumn::ALL
+            .into_iter()
+            .find(|c| c.as_str() == s)
+            .ok_or_else(|| CoreError::ParsingError(format!("unknown sort column: {s}")))
+    }
+}
+
+impl Sortable for TournamentBase {
+    type SortColumn = TournamentBaseSortColumn;
+
+    fn compare(&self, other: &Self, column: TournamentBaseSortColumn) -> Ordering {
+        match column {
+            TournamentBaseSortColumn::Name => {
+                self.name.to_lowercase().cmp(&other.name.to_lowercase())
+            }
+            TournamentBaseSortColumn::CreatedAt => self.created_at.cmp(&other.created_at),
+            TournamentBaseSortColumn::NumEntrants => self.num_entrants.cmp(&other.num_entrants),
+        }
+    }
+}
+
 pub struct TournamentBaseState {
     tournament: TournamentBase,
 }
@@ -375,6 +1023,25 @@ impl<S> Core<S> {
             tournament: TournamentBase::default(),
         })
     }
+    /// Chosen sport configuration must be a configuration of the sport of `tournament`.
+    pub(crate) async fn validate_sport_config(
+        &self,
+        tournament: &TournamentBase,
+    ) -> CoreResult<()> {
+        let Some(config_id) = tournament.sport_config_id else {
+            return Ok(());
+        };
+        match self.database.get_sport_config(config_id).await? {
+            Some(config) if config.get_sport_id() == tournament.sport_id => Ok(()),
+            _ => Err(CoreError::from(
+                FieldError::builder()
+                    .set_field(String::from("sport_config_id"))
+                    .add_message("sport configuration must belong to the sport of the tournament")
+                    .set_object_id(tournament.get_id())
+                    .build(),
+            )),
+        }
+    }
 }
 
 impl Core<TournamentBaseState> {
@@ -404,11 +1071,85 @@ impl Core<TournamentBaseState> {
         }
     }
     pub async fn save(&mut self) -> CoreResult<&TournamentBase> {
+        self.save_state(false).await
+    }
+    /// Save the currently loaded tournament, whose state is rolled back to a completed stage,
+    /// see [`Core::rollback_to_snapshot`].
+    pub(crate) async fn save_rollback(&mut self) -> CoreResult<&TournamentBase> {
+        self.save_state(true).await
+    }
+    async fn save_state(&mut self, is_rollback: bool) -> CoreResult<&TournamentBase> {
         self.validate(&self.state.tournament)?;
-        self.state.tournament = self
+        self.validate_sport_config(&self.state.tournament).await?;
+        let next_state = self.state.tournament.get_tournament_state();
+        // stored copy before the save for state transitions and the audit log
+        let old = self.stored_tournament().await?;
+        self.state
+            .tournament
+            .validate_edit(old.as_ref())
+            .map_err(CoreError::from)?;
+        let previous_state = match next_state {
+            TournamentState::Published
+            | TournamentState::ActiveStage(_)
+            | TournamentState::Finished => old.as_ref().map(|t| t.get_tournament_state()),
+            TournamentState::Draft => None,
+        };
+        // sandbox tournaments may change their state freely, rollbacks return to a stage
+        let is_sandbox = self.state.tournament.is_sandbox();
+        let stored_state = old.as_ref().map(|t| t.get_tournament_state());
+        if !is_sandbox
+            && !is_rollback
+            && let Some(stored_state) = stored_state
+        {
+            let num_active_stages = self
+                .state
+                .tournament
+                .get_tournament_mode()
+                .get_num_of_active_stages();
+            if !stored_state.can_change_to(next_state, num_active_stages) {
+                return Err(self.transition_error(format!(
+                    "tournament cannot change from {stored_state} to {next_state}"
+                )));
+            }
+            self.ensure_transition_preconditions(stored_state, next_state)
+                .await?;
+        }
+        if !is_sandbox && is_gated_transition(previous_state, next_state) {
+            self.ensure_ready(&self.state.tournament).await?;
+        }
+        // completed stages are snapshotted for rollbacks; snapshots of sandboxes are skipped
+        let snapshot = match stored_state {
+            Some(TournamentState::ActiveStage(active_stage))
+                if !is_rollback && next_state != TournamentState::ActiveStage(active_stage) =>
+            {
+                self.snapshot_stage(&self.state.tournament, active_stage)
+                    .await?
+            }
+            _ => None,
+        };
+        let is_publishing = next_state == TournamentState::Published
+            && previous_state != Some(TournamentState::Published);
+        let saved = self
             .database
             .save_tournament_base(&self.state.tournament)
-            .await?;
+            .await;
+        // snapshot is discarded, if the tournament cannot be saved
+        if saved.is_err()
+            && let Some(snapshot) = snapshot
+        {
+            self.database.delete_stage_snapshot(snapshot.id).await?;
+        }
+        // on a version conflict the current server copy is returned
+        self.state.tournament = match saved {
+            Err(DbError::VersionConflict { .. }) => {
+                let id = self.state.tournament.get_id();
+                return Err(match self.database.get_tournament_base(id).await? {
+                    Some(theirs) => CoreError::version_conflict(ServerCopy::TournamentBase(theirs)),
+                    None => CoreError::from(DbError::NotFound),
+                });
+            }
+            res => res?,
+        };
 
         // publish change of tournament base to client registry
         let id = self.state.tournament.get_id();
@@ -427,21 +1168,227 @@ impl Core<TournamentBaseState> {
         };
         let msg = CrMsg::TournamentBaseUpdated { id, version };
         self.client_registry.publish(notice, msg).await?;
+        self.audit_save(
+            AuditObjectType::TournamentBase,
+            Some(id),
+            old.as_ref(),
+            &self.state.tournament,
+        )
+        .await;
+        // sandbox tournaments must not leak to integrations or entrants
+        if is_sandbox {
+            return Ok(self.get());
+        }
+        self.dispatch_webhook_event(WebhookEventData::tournament_updated(&self.state.tournament))
+            .await;
+        for data in
+            WebhookEventData::state_transition_events(&self.state.tournament, previous_state)
+        {
+            self.dispatch_webhook_event(data).await;
+        }
+        for event in DomainEvent::tournament_saved(&self.state.tournament, old.as_ref()) {
+            self.emit_domain_event(event).await;
+        }
+        if is_publishing {
+            self.notify_schedule_published(&self.state.tournament).await;
+        }
+        if is_finishing_transition(previous_state, next_state) {
+            self.push_final_standings(&self.state.tournament).await;
+        }
+        Ok(self.get())
+    }
+    /// Get the loaded tournament as stored in database; `None` for new tournaments.
+    async fn stored_tournament(&self) -> CoreResult<Option<TournamentBase>> {
+        if self.state.tournament.get_version().is_none() {
+            return Ok(None);
+        }
+        let id = self.state.tournament.get_id();
+        let stored = self.database.get_tournament_base(id).await?;
+        Ok(stored)
+    }
+    /// Archive the currently loaded tournament.
+    /// Archived tournaments are kept in the database, but are hidden in default listings.
+    pub async fn archive(&mut self) -> CoreResult<&TournamentBase> {
+        let Some(version) = self.state.tournament.get_version() else {
+            return Err(CoreError::from(DbError::NotFound));
+        };
+        let old = self.state.tournament.clone();
+        self.state.tournament = self
+            .database
+            .archive_tournament_base(self.state.tournament.get_id(), version)
+            .await?;
+
+        // publish change of tournament base to client registry
+        let id = self.state.tournament.get_id();
+        let version = self.state.tournament.get_version().expect(
+            "expecting archive_tournament_base to return always an existing id and version",
+        );
+        let notice = CrTopic::TournamentBase {
+            tournament_base_id: id,
+        };
+        let msg = CrMsg::TournamentBaseUpdated { id, version };
+        self.client_registry.publish(notice, msg).await?;
+        self.audit(
+            AuditAction::Archive,
+            AuditObjectType::TournamentBase,
+            Some(id),
+            Some(&old),
+            Some(&self.state.tournament),
+        )
+        .await;
         Ok(self.get())
     }
+    /// Purge the currently loaded sandbox tournament including its stages, entrants and
+    /// all further data of the tournament. Other tournaments cannot be purged.
+    pub async fn purge_sandbox(&mut self) -> CoreResult<()> {
+        let id = self.state.tournament.get_id();
+        if !self.state.tournament.is_sandbox() {
+            return Err(CoreError::from(
+                FieldError::builder()
+                    .set_field(String::from("sandbox"))
+                    .add_message("only sandbox tournaments can be purged")
+                    .set_object_id(id)
+                    .build(),
+            ));
+        }
+        self.database.purge_sandbox_tournament_base(id).await?;
+
+        // publish purge to client registry, so that listings of the sport are refreshed
+        let version = self.state.tournament.get_version().unwrap_or_default();
+        let notice = CrTopic::NewTournamentBase {
+            sport_id: self.state.tournament.get_sport_id(),
+        };
+        let msg = CrMsg::TournamentBaseUpdated { id, version };
+        self.client_registry.publish(notice, msg).await?;
+        self.audit(
+            AuditAction::Delete,
+            AuditObjectType::TournamentBase,
+            Some(id),
+            Some(&self.state.tournament),
+            None,
+        )
+        .await;
+        self.state.tournament = TournamentBase::default();
+        Ok(())
+    }
+    /// Delete the draft tournaments `tournaments` including their stages, entrants and all
+    /// further data in one transaction: if one tournament is not a draft or was changed in
+    /// the meantime, no tournament is deleted and the error is returned. Returns the ids of
+    /// the deleted tournaments.
+    pub async fn delete_drafts(&self, tournaments: &[IdVersion]) -> CoreResult<Vec<Uuid>> {
+        self.with_transaction(|core| async move {
+            let mut deleted = Vec::with_capacity(tournaments.len());
+            for id_version in tournaments {
+                let id = id_version.get_id();
+                let Some(version) = id_version.get_version() else {
+                    return Err(CoreError::from(DbError::NotFound));
+                };
+                let Some(tournament) = core.database.get_tournament_base(id).await? else {
+                    return Err(CoreError::from(DbError::NotFound));
+                };
+                if tournament.get_tournament_state() != TournamentState::Draft {
+                    return Err(CoreError::from(
+                        FieldError::builder()
+                            .set_field(String::from("state"))
+                            .add_message("only draft tournaments can be deleted")
+                            .set_object_id(id)
+                            .build(),
+                    ));
+                }
+                core.database.delete_tournament_base(id, version).await?;
+
+                // publish deletion to client registry, so that listings of the sport are
+                // refreshed
+                let notice = CrTopic::NewTournamentBase {
+                    sport_id: tournament.get_sport_id(),
+                };
+                let msg = CrMsg::TournamentBaseUpdated { id, version };
+                core.client_registry.publish(notice, msg).await?;
+                core.audit(
+                    AuditAction::Delete,
+                    AuditObjectType::TournamentBase,
+                    Some(id),
+                    Some(&tournament),
+                    None,
+                )
+                .await;
+                deleted.push(id);
+            }
+            Ok(deleted)
+        })
+        .await
+    }
+    /// Replace the stations of the currently loaded tournament by the numbered stations of
+    /// `setup` and save the tournament. The number of stations follows the setup.
+    pub async fn create_stations(&mut self, setup: &StationSetup) -> CoreResult<&TournamentBase> {
+        let id = self.state.tournament.get_id();
+        if self.state.tournament.is_locked_by_state() {
+            return Err(CoreError::from(
+                FieldError::builder()
+                    .set_field(String::from("stations"))
+                    .add_message("stations of running or finished tournaments cannot be changed")
+                    .set_object_id(id)
+                    .build(),
+            ));
+        }
+        setup.validate(id)?;
+        self.state
+            .tournament
+            .set_stations(setup.stations())
+            .set_num_stations(setup.count);
+        self.save().await
+    }
+    /// Apply venue `venue_id` to the loaded tournament, see [`TournamentBase::apply_venue`].
+    pub async fn apply_venue(&mut self, venue_id: Uuid) -> CoreResult<&TournamentBase> {
+        let id = self.state.tournament.get_id();
+        if self.state.tournament.is_locked_by_state() {
+            return Err(CoreError::from(
+                FieldError::builder()
+                    .set_field(String::from("venue_id"))
+                    .add_message("venue of running or finished tournaments cannot be changed")
+                    .set_object_id(id)
+                    .build(),
+            ));
+        }
+        let Some(venue) = self.database.get_venue(venue_id).await? else {
+            return Err(CoreError::from(DbError::NotFound));
+        };
+        self.state.tournament.apply_venue(&venue);
+        self.save().await
+    }
     pub async fn list_tournament_base_ids(
         &self,
-        sport_id: Uuid,
-        name_filter: Option<&str>,
-        state_filter: Option<TournamentState>,
-        include_adhoc: bool,
-        limit: Option<usize>,
+        filter: &Filter<TournamentBase>,
+        order: &ListOrder<TournamentBase>,
     ) -> CoreResult<Vec<Uuid>> {
         let tournaments = self
             .database
-            .list_tournament_base_ids(sport_id, name_filter, state_filter, include_adhoc, limit)
+            .list_tournament_base_ids(filter, order)
             .await?;
 
         Ok(tournaments)
     }
+
+    /// Distance in kilometers from the location of postal address `from_address_id` to the
+    /// location of tournament `tournament_id`. Returns `None`, if the tournament has no
+    /// location or one of both addresses is not geocoded.
+    pub async fn tournament_distance_km(
+        &self,
+        tournament_id: Uuid,
+        from_address_id: Uuid,
+    ) -> CoreResult<Option<f64>> {
+        let Some(tournament) = self.database.get_tournament_base(tournament_id).await? else {
+            return Err(CoreError::from(DbError::NotFound));
+        };
+        let Some(address_id) = tournament.get_address_id() else {
+            return Ok(None);
+        };
+        let venue = self.database.get_postal_address(address_id).await?;
+        let from = self.database.get_postal_address(from_address_id).await?;
+        let distance = venue
+            .and_then(|venue| venue.get_location())
+            .zip(from.and_then(|from| from.get_location()))
+            .map(|(venue, from)| from.distance_km(&venue));
+        Ok(distance)
+    }
 }
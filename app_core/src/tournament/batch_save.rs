@@ -0,0 +1,455 @@
+//! batch save of all changes of an edited tournament
+//!
+//! The tournament editor collects the changes of the tournament base and of its stages and
+//! groups in a [`TournamentDiff`], which is saved in one database transaction. Either all
+//! objects of the diff are saved or none of them, so that a failing object does not leave the
+//! tournament partially saved.
+
+use super::{Tournament, TournamentBase};
+use crate::{
+    AuditObjectType, Core, CoreError, CoreResult, CrMsg, CrTopic, DbBatchError, DbError,
+    DomainEvent, Group, ServerCopy, SportError, Stage, TournamentState, WebhookEventData,
+    utils::{traits::ObjectIdVersion, validation::FieldError},
+};
+use serde::{Deserialize, Serialize};
+use uuid::Uuid;
+
+/// changes of a tournament, which are saved at once, see [`Core::save_tournament_diff`]
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct TournamentDiff {
+    pub tournament_id: Uuid,
+    /// changed tournament base, if any
+    pub base: Option<TournamentBase>,
+    /// changed or new stages
+    pub stages: Vec<Stage>,
+    /// changed or new groups
+    pub groups: Vec<Group>,
+}
+
+impl TournamentDiff {
+    /// Check if the diff does not contain any change.
+    pub fn is_empty(&self) -> bool {
+        self.base.is_none() && self.stages.is_empty() && self.groups.is_empty()
+    }
+}
+
+impl Tournament {
+    /// Collect all changes of the tournament compared to `origin`.
+    pub fn collect_diff(&self, origin: &Tournament) -> TournamentDiff {
+        TournamentDiff {
+            tournament_id: self.get_base().get_id(),
+            base: self.collect_base_diff(origin).cloned(),
+            stages: self.collect_stages_diff(origin),
+            groups: self.collect_groups_diff(origin),
+        }
+    }
+}
+
+/// result of saving one object of a [`TournamentDiff`]
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub enum DiffObjectResult<T> {
+    /// object is saved; holds the saved object with its new version
+    Saved(T),
+    /// saving the object failed
+    Failed { id: Uuid, error: CoreError },
+    /// object is not saved, since another object of the diff failed
+    RolledBack { id: Uuid },
+}
+
+impl<T> DiffObjectResult<T> {
+    fn not_saved(id: Uuid, failed_id: Uuid, error: &CoreError) -> Self {
+        if id == failed_id {
+            DiffObjectResult::Failed {
+                id,
+                error: error.clone(),
+            }
+        } else {
+            DiffObjectResult::RolledBack { id }
+        }
+    }
+
+    /// Get the saved object, if it is saved.
+    pub fn get_saved(&self) -> Option<&T> {
+        match self {
+            DiffObjectResult::Saved(object) => Some(object),
+            _ => None,
+        }
+    }
+
+    /// Get the error of the object, if saving it failed.
+    pub fn get_error(&self) -> Option<&CoreError> {
+        match self {
+            DiffObjectResult::Failed { error, .. } => Some(error),
+            _ => None,
+        }
+    }
+}
+
+/// per object results of saving a [`TournamentDiff`]
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct TournamentDiffResult {
+    /// result of the tournament base, if it is part of the diff
+    pub base: Option<DiffObjectResult<TournamentBase>>,
+    pub stages: Vec<DiffObjectResult<Stage>>,
+    pub groups: Vec<DiffObjectResult<Group>>,
+}
+
+impl TournamentDiffResult {
+    /// Result of a diff, which is not saved, since saving object `failed_id` failed with
+    /// `error`.
+    fn failed(diff: &TournamentDiff, failed_id: Uuid, error: CoreError) -> Self {
+        TournamentDiffResult {
+            base: diff
+                .base
+                .as_ref()
+                .map(|b| DiffObjectResult::not_saved(b.get_id(), failed_id, &error)),
+            stages: diff
+                .stages
+                .iter()
+                .map(|s| DiffObjectResult::not_saved(s.get_id(), failed_id, &error))
+                .collect(),
+            groups: diff
+                .groups
+                .iter()
+                .map(|g| {
+                    DiffObjectResult::not_saved(g.get_id_version().get_id(), failed_id, &error)
+                })
+                .collect(),
+        }
+    }
+
+    /// Check if all objects of the diff are saved.
+    pub fn is_saved(&self) -> bool {
+        self.base
+            .iter()
+            .all(|b| matches!(b, DiffObjectResult::Saved(_)))
+            && self
+                .stages
+                .iter()
+                .all(|s| matches!(s, DiffObjectResult::Saved(_)))
+            && self
+                .groups
+                .iter()
+                .all(|g| matches!(g, DiffObjectResult::Saved(_)))
+    }
+
+    /// Get the errors of all failed objects.
+    pub fn errors(&self) -> impl Iterator<Item = &CoreError> {
+        self.base
+            .iter()
+            .filter_map(DiffObjectResult::get_error)
+            .chain(self.stages.iter().filter_map(DiffObjectResult::get_error))
+            .chain(self.groups.iter().filter_map(DiffObjectResult::get_error))
+    }
+}
+
+impl<S> Core<S> {
+    /// Save all changes of `diff` in one transaction. Groups must belong to a stage of the
+    /// tournament, which is part of the diff or already saved. Objects, which fail validation or
+    /// saving, are reported in the result; all other objects of the diff are rolled back in
+    /// this case. Changes of the tournament state are not part of a diff, they are saved with
+    /// a change of the tournament state.
+    pub async fn save_tournament_diff(
+        &self,
+        diff: &TournamentDiff,
+    ) -> CoreResult<TournamentDiffResult> {
+        let stored = self
+            .database
+            .get_tournament_base(diff.tournament_id)
+            .await?;
+        let Some(base) = diff.base.as_ref().or(stored.as_ref()) else {
+            return Err(CoreError::from(DbError::NotFound));
+        };
+        if diff.base.is_some() {
+            let validated = match self.validate_diff_base(diff.tournament_id, base, stored.as_ref())
+            {
+                Ok(()) => self.validate_sport_config(base).await,
+                Err(error) => Err(error),
+            };
+            if let Err(error) = validated {
+                return Ok(TournamentDiffResult::failed(diff, base.get_id(), error));
+            }
+        }
+        let mut old_stages = Vec::with_capacity(diff.stages.len());
+        for stage in diff.stages.iter() {
+            // stored copy before the save for editability checks and the audit log
+            let old = match stage.get_version() {
+                Some(_) => self.database.get_stage_by_id(stage.get_id()).await?,
+                None => None,
+            };
+            if let Err(error) = validate_diff_stage(diff.tournament_id, base, stage, old.as_ref())
+                .and_then(|()| self.validate_stage_scoring(base, stage))
+            {
+                return Ok(TournamentDiffResult::failed(diff, stage.get_id(), error));
+            }
+            old_stages.push(old);
+        }
+        let mut old_groups = Vec::with_capacity(diff.groups.len());
+        for group in diff.groups.iter() {
+            let old = match group.get_version() {
+                Some(_) => self.database.get_group(group.get_id()).await?,
+                None => None,
+            };
+            // stage of group is either part of the diff or already stored
+            let stage = match diff
+                .stages
+                .iter()
+                .find(|s| s.get_id() == group.get_stage_id())
+            {
+                Some(stage) => Some(*stage),
+                None => self.database.get_stage_by_id(group.get_stage_id()).await?,
+            };
+            if let Err(error) = validate_diff_group(diff.tournament_id, base, group, stage.as_ref())
+            {
+                return Ok(TournamentDiffResult::failed(diff, group.get_id(), error));
+            }
+            old_groups.push(old);
+        }
+
+        let (saved_base, saved_stages, saved_groups) = match self
+            .database
+            .save_tournament_diff(diff.base.as_ref(), &diff.stages, &diff.groups)
+            .await
+        {
+            Ok(saved) => saved,
+            Err(DbBatchError {
+                object_id: Some(object_id),
+                error: DbError::VersionConflict { .. },
+            }) if object_id == diff.tournament_id => {
+                // on a version conflict of the base the current server copy is returned
+                let error = match self.database.get_tournament_base(object_id).await? {
+                    Some(theirs) => CoreError::version_conflict(ServerCopy::TournamentBase(theirs)),
+                    None => CoreError::from(DbError::NotFound),
+                };
+                return Ok(TournamentDiffResult::failed(diff, object_id, error));
+            }
+            Err(DbBatchError {
+                object_id: Some(object_id),
+                error,
+            }) => {
+                return Ok(TournamentDiffResult::failed(
+                    diff,
+                    object_id,
+                    CoreError::from(error),
+                ));
+            }
+            Err(DbBatchError {
+                object_id: None,
+                error,
+            }) => return Err(CoreError::from(error)),
+        };
+
+        // publish changes to client registry and record them in the audit log
+        if let Some(base) = saved_base.as_ref() {
+            let id = base.get_id();
+            let version = base.get_version().expect(
+                "expecting save_tournament_diff to return always an existing id and version",
+            );
+            let notice = if version == 0 {
+                CrTopic::NewTournamentBase {
+                    sport_id: base.get_sport_id(),
+                }
+            } else {
+                CrTopic::TournamentBase {
+                    tournament_base_id: id,
+                }
+            };
+            let msg = CrMsg::TournamentBaseUpdated { id, version };
+            self.client_registry.publish(notice, msg).await?;
+            self.audit_save(
+                AuditObjectType::TournamentBase,
+                Some(id),
+                stored.as_ref(),
+                base,
+            )
+            .await;
+        }
+        for (stage, old) in saved_stages.iter().zip(old_stages.iter()) {
+            let id = stage.get_id();
+            let version = stage.get_version().expect(
+                "expecting save_tournament_diff to return always an existing id and version",
+            );
+            let notice = if version == 0 {
+                CrTopic::NewStage {
+                    tournament_base_id: diff.tournament_id,
+                }
+            } else {
+                CrTopic::Stage { stage_id: id }
+            };
+            let msg = CrMsg::StageUpdated { id, version };
+            self.client_registry.publish(notice, msg).await?;
+            self.audit_save(
+                AuditObjectType::Stage,
+                Some(diff.tournament_id),
+                old.as_ref(),
+                stage,
+            )
+            .await;
+        }
+        for (group, old) in saved_groups.iter().zip(old_groups.iter()) {
+            self.audit_save(
+                AuditObjectType::Group,
+                Some(diff.tournament_id),
+                old.as_ref(),
+                group,
+            )
+            .await;
+        }
+        // sandbox tournaments must not leak to integrations
+        if let Some(base) = saved_base.as_ref()
+            && !base.is_sandbox()
+        {
+            self.dispatch_webhook_event(WebhookEventData::tournament_updated(base))
+                .await;
+            for event in DomainEvent::tournament_saved(base, stored.as_ref()) {
+                self.emit_domain_event(event).await;
+            }
+        }
+
+        Ok(TournamentDiffResult {
+            base: saved_base.map(DiffObjectResult::Saved),
+            stages: saved_stages
+                .into_iter()
+                .map(DiffObjectResult::Saved)
+                .collect(),
+            groups: saved_groups
+                .into_iter()
+                .map(DiffObjectResult::Saved)
+                .collect(),
+        })
+    }
+
+    fn validate_diff_base(
+        &self,
+        tournament_id: Uuid,
+        base: &TournamentBase,
+        stored: Option<&TournamentBase>,
+    ) -> CoreResult<()> {
+        if base.get_id() != tournament_id {
+            return Err(CoreError::from(DbError::NotFound));
+        }
+        if self.sport_plugins.get(&base.get_sport_id()).is_none() {
+            return Err(CoreError::from(SportError::UnknownSportId(
+                base.get_sport_id(),
+            )));
+        }
+        base.validate().map_err(CoreError::from)?;
+        base.validate_edit(stored).map_err(CoreError::from)?;
+        let stored_state = stored.map_or(TournamentState::Draft, |t| t.get_tournament_state());
+        if base.get_tournament_state() != stored_state {
+            return Err(CoreError::from(
+                FieldError::builder()
+                    .set_field("state")
+                    .add_user_defined_code("state_change_in_diff")
+                    .add_message("The state of a tournament is changed separately from its edits.")
+                    .set_object_id(base.get_id())
+                    .build(),
+            ));
+        }
+        Ok(())
+    }
+}
+
+fn validate_diff_stage(
+    tournament_id: Uuid,
+    base: &TournamentBase,
+    stage: &Stage,
+    old: Option<&Stage>,
+) -> CoreResult<()> {
+    if stage.get_tournament_id() != tournament_id {
+        return Err(CoreError::from(
+            FieldError::builder()
+                .set_field("tournament_id")
+                .add_user_defined_code("foreign_tournament")
+                .add_message("The stage belongs to another tournament.")
+                .set_object_id(stage.get_id())
+                .build(),
+        ));
+    }
+    stage.validate(base).map_err(CoreError::from)?;
+    stage.validate_edit(old, base).map_err(CoreError::from)
+}
+
+fn validate_diff_group(
+    tournament_id: Uuid,
+    base: &TournamentBase,
+    group: &Group,
+    stage: Option<&Stage>,
+) -> CoreResult<()> {
+    let reject = |field: &str, code: &str, message: &str| {
+        Err(CoreError::from(
+            FieldError::builder()
+                .set_field(String::from(field))
+                .add_user_defined_code(code)
+                .add_message(message)
+                .set_object_id(group.get_id())
+                .build(),
+        ))
+    };
+    let Some(stage) = stage.filter(|s| s.get_tournament_id() == tournament_id) else {
+        return reject(
+            "stage_id",
+            "foreign_stage",
+            "The group belongs to a stage of another tournament.",
+        );
+    };
+    if group.get_number() >= stage.get_num_groups() {
+        return reject(
+            "number",
+            "group_number_out_of_range",
+            "The group number exceeds the number of groups of the stage.",
+        );
+    }
+    if !base
+        .get_stage_editability(stage.get_number())
+        .is_structure_editable()
+    {
+        return reject(
+            "stage_id",
+            "started_stage",
+            "Groups of a started stage cannot be changed.",
+        );
+    }
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::utils::id_version::IdVersion;
+
+    #[test]
+    fn failed_object_rolls_back_all_other_objects() {
+        let base = TournamentBase::default();
+        let stages: Vec<Stage> = (0..2)
+            .map(|_| Stage::new(IdVersion::new(Uuid::new_v4(), None)))
+            .collect();
+        let diff = TournamentDiff {
+            tournament_id: base.get_id(),
+            base: Some(base.clone()),
+            stages: stages.clone(),
+            groups: Vec::new(),
+        };
+
+        let result = TournamentDiffResult::failed(
+            &diff,
+            stages[1].get_id(),
+            CoreError::from(DbError::NotFound),
+        );
+
+        assert!(!result.is_saved());
+        assert!(matches!(
+            result.base,
+            Some(DiffObjectResult::RolledBack { id }) if id == base.get_id()
+        ));
+        assert!(matches!(
+            result.stages[0],
+            DiffObjectResult::RolledBack { .. }
+        ));
+        assert!(matches!(
+            result.stages[1],
+            DiffObjectResult::Failed { id, .. } if id == stages[1].get_id()
+        ));
+        assert_eq!(result.errors().count(), 1);
+    }
+}
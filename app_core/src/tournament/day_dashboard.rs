@@ -0,0 +1,378 @@
+//! operational dashboard of a tournament day
+//!
+//! The dashboard shows per station how long it takes to enter results after the projected
+//! end of a match and how many results are not confirmed yet, and how many entrants are
+//! checked in. It helps the director to spot stations, whose paperwork is lagging, and
+//! groups, whose standings look inconsistent.
+
+use super::TournamentBase;
+use crate::{Core, CoreResult, Entrant, Match, MatchOutcome, StandingsCheckReport};
+use chrono::{DateTime, Duration, Utc};
+use serde::{Deserialize, Serialize};
+use std::collections::BTreeMap;
+use uuid::Uuid;
+
+/// latency of result entry in minutes, from which a station is reported as lagging
+pub const RESULT_ENTRY_LAG_MINUTES: i64 = 15;
+
+/// result entry of a match, which is evaluated by the day dashboard
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct ResultEntry {
+    pub match_id: Uuid,
+    /// number of station (starting with 1), at which the match is played
+    pub station: u32,
+    /// projected end of the match by schedule
+    pub projected_end_at: DateTime<Utc>,
+    /// time of entry of the result; `None`, if no result is entered yet
+    pub entered_at: Option<DateTime<Utc>>,
+    /// result is confirmed, e.g. by the opponent or a referee
+    pub confirmed: bool,
+}
+
+impl ResultEntry {
+    /// Result entry of the stored match `m`, which is planned to take `duration`. Final
+    /// results are confirmed by entering them; results without time of entry count as
+    /// entered at the projected end. Returns `None` for byes and matches without station.
+    pub fn from_match(m: &Match, duration: Duration) -> Option<Self> {
+        if m.get_outcome() == MatchOutcome::Bye || m.get_station() == 0 {
+            return None;
+        }
+        let projected_end_at = m.get_start_at().with_timezone(&Utc) + duration;
+        let entered_at = m
+            .is_decided()
+            .then(|| m.get_result_at().unwrap_or(projected_end_at));
+        Some(ResultEntry {
+            match_id: *m.get_id(),
+            station: m.get_station() as u32,
+            projected_end_at,
+            entered_at,
+            confirmed: entered_at.is_some(),
+        })
+    }
+
+    /// Latency of the result entry in seconds. Outstanding results are measured until `now`.
+    /// Results entered before the projected end have a latency of zero.
+    pub fn latency_seconds(&self, now: DateTime<Utc>) -> i64 {
+        (self.entered_at.unwrap_or(now) - self.projected_end_at)
+            .num_seconds()
+            .max(0)
+    }
+
+    /// Check if the projected end of the match passed at `now` without an entered result.
+    pub fn is_outstanding(&self, now: DateTime<Utc>) -> bool {
+        self.entered_at.is_none() && self.projected_end_at <= now
+    }
+}
+
+/// result entry statistics of one station
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct StationEntryStats {
+    /// number of station, starting with 1
+    pub station: u32,
+    /// name of station; `Station <number>`, if the station is not named
+    pub name: String,
+    /// number of entered results
+    pub num_entered: usize,
+    /// number of matches, whose projected end passed without an entered result
+    pub num_outstanding: usize,
+    /// number of entered results, which are not confirmed yet
+    pub num_unconfirmed: usize,
+    /// mean latency of entered results in seconds; `None`, if no result is entered
+    pub mean_latency_seconds: Option<i64>,
+    /// maximum latency of entered and outstanding results in seconds
+    pub max_latency_seconds: Option<i64>,
+}
+
+impl StationEntryStats {
+    fn new(station: u32, tournament: &TournamentBase) -> Self {
+        StationEntryStats {
+            station,
+            name: tournament
+                .get_station_name(station)
+                .map(str::to_string)
+                .unwrap_or_else(|| format!("Station {station}")),
+            num_entered: 0,
+            num_outstanding: 0,
+            num_unconfirmed: 0,
+            mean_latency_seconds: None,
+            max_latency_seconds: None,
+        }
+    }
+
+    /// Check if entering results at the station lags behind by at least
+    /// [`RESULT_ENTRY_LAG_MINUTES`].
+    pub fn is_lagging(&self) -> bool {
+        self.max_latency_seconds
+            .is_some_and(|latency| latency >= RESULT_ENTRY_LAG_MINUTES * 60)
+    }
+}
+
+/// check-in completeness of the entrants of a tournament
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct CheckInCompleteness {
+    /// number of checked in entrants
+    pub num_checked_in: usize,
+    /// number of registered entrants
+    pub num_entrants: usize,
+    /// names of entrants, who are not checked in yet, sorted by name
+    pub missing: Vec<String>,
+}
+
+impl CheckInCompleteness {
+    /// Evaluate the check-in completeness of `entrants`. Waitlisted entrants and no-shows
+    /// are not part of the field and therefore not counted.
+    pub fn evaluate(entrants: &[Entrant]) -> Self {
+        let entrants: Vec<&Entrant> = entrants.iter().filter(|e| e.is_in_field()).collect();
+        let mut missing: Vec<String> = entrants
+            .iter()
+            .filter(|e| !e.is_checked_in())
+            .map(|e| e.get_name().to_string())
+            .collect();
+        missing.sort_by_key(|name| name.to_lowercase());
+        CheckInCompleteness {
+            num_checked_in: entrants.len() - missing.len(),
+            num_entrants: entrants.len(),
+            missing,
+        }
+    }
+
+    /// Checked in entrants in percent, rounded down; 100, if no entrant is registered.
+    pub fn percent(&self) -> u32 {
+        (self.num_checked_in * 100)
+            .checked_div(self.num_entrants)
+            .map_or(100, |percent| percent as u32)
+    }
+
+    /// Check if all entrants are checked in.
+    pub fn is_complete(&self) -> bool {
+        self.missing.is_empty()
+    }
+}
+
+/// operational dashboard of a tournament day, see [`Core::load_day_dashboard`]
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct DayDashboard {
+    pub tournament_id: Uuid,
+    /// time of evaluation; latencies of outstanding results are measured until this time
+    pub generated_at: DateTime<Utc>,
+    /// result entry statistics of all stations in order of their number
+    pub stations: Vec<StationEntryStats>,
+    pub check_in: CheckInCompleteness,
+    /// groups of the active stage with inconsistent standings, which point to data-entry
+    /// errors, see [`Core::check_active_stage_standings`]
+    #[serde(default)]
+    pub standings_checks: Vec<StandingsCheckReport>,
+}
+
+impl DayDashboard {
+    /// Evaluate the dashboard of `tournament` at `now`. Every station of the tournament is
+    /// listed, even if no result entry belongs to it.
+    pub fn evaluate(
+        tournament: &TournamentBase,
+        entrants: &[Entrant],
+        entries: &[ResultEntry],
+        now: DateTime<Utc>,
+    ) -> Self {
+        let mut stations: BTreeMap<u32, StationEntryStats> = (1..=tournament.get_num_stations())
+            .map(|station| (station, StationEntryStats::new(station, tournament)))
+            .collect();
+        let mut latency_sums: BTreeMap<u32, i64> = BTreeMap::new();
+
+        for entry in entries {
+            let stats = stations
+                .entry(entry.station)
+                .or_insert_with(|| StationEntryStats::new(entry.station, tournament));
+            if entry.entered_at.is_some() {
+                stats.num_entered += 1;
+                if !entry.confirmed {
+                    stats.num_unconfirmed += 1;
+                }
+                *latency_sums.entry(entry.station).or_default() += entry.latency_seconds(now);
+            } else if entry.is_outstanding(now) {
+                stats.num_outstanding += 1;
+            } else {
+                // match is not finished yet
+                continue;
+            }
+            let latency = entry.latency_seconds(now);
+            stats.max_latency_seconds = Some(stats.max_latency_seconds.unwrap_or(0).max(latency));
+        }
+        for (station, sum) in latency_sums {
+            if let Some(stats) = stations.get_mut(&station) {
+                stats.mean_latency_seconds = Some(sum / stats.num_entered as i64);
+            }
+        }
+
+        DayDashboard {
+            tournament_id: tournament.get_id(),
+            generated_at: now,
+            stations: stations.into_values().collect(),
+            check_in: CheckInCompleteness::evaluate(entrants),
+            standings_checks: Vec::new(),
+        }
+    }
+
+    /// Number of entered results of all stations, which are not confirmed yet.
+    pub fn num_unconfirmed(&self) -> usize {
+        self.stations.iter().map(|s| s.num_unconfirmed).sum()
+    }
+
+    /// Number of outstanding results of all stations.
+    pub fn num_outstanding(&self) -> usize {
+        self.stations.iter().map(|s| s.num_outstanding).sum()
+    }
+
+    /// Stations, whose result entry is lagging, see [`StationEntryStats::is_lagging`].
+    pub fn lagging_stations(&self) -> impl Iterator<Item = &StationEntryStats> {
+        self.stations.iter().filter(|s| s.is_lagging())
+    }
+}
+
+impl<S> Core<S> {
+    /// Load the tournament and evaluate its day dashboard with the result entries of its
+    /// stored matches.
+    pub async fn load_day_dashboard(
+        &self,
+        tournament_id: Uuid,
+    ) -> CoreResult<Option<DayDashboard>> {
+        let Some(tournament) = self.database.get_tournament_base(tournament_id).await? else {
+            return Ok(None);
+        };
+        let entrants = self
+            .database
+            .list_entrants_of_tournament(tournament_id)
+            .await?;
+        let timing = self.estimate_match_timing_or_fallback(&tournament).await?;
+        let duration = Duration::from_std(timing.match_duration + timing.changeover)
+            .unwrap_or_else(|_| Duration::zero());
+        let entries: Vec<ResultEntry> = self
+            .database
+            .list_matches_of_tournament(tournament_id)
+            .await?
+            .iter()
+            .filter_map(|m| ResultEntry::from_match(m, duration))
+            .collect();
+        let mut dashboard = DayDashboard::evaluate(&tournament, &entrants, &entries, Utc::now());
+        dashboard.standings_checks = self.check_active_stage_standings(&tournament).await?;
+        Ok(Some(dashboard))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn entry(
+        station: u32,
+        projected_end_at: DateTime<Utc>,
+        entered_after_minutes: Option<i64>,
+        confirmed: bool,
+    ) -> ResultEntry {
+        ResultEntry {
+            match_id: Uuid::new_v4(),
+            station,
+            projected_end_at,
+            entered_at: entered_after_minutes.map(|m| projected_end_at + Duration::minutes(m)),
+            confirmed,
+        }
+    }
+
+    #[test]
+    fn latency_and_lag_are_evaluated_per_station() {
+        let now = Utc::now();
+        let mut tournament = TournamentBase::default();
+        tournament.set_num_stations(3);
+        let entries = vec![
+            // station 1: entered in time and late
+            entry(1, now - Duration::minutes(60), Some(-2), true),
+            entry(1, now - Duration::minutes(40), Some(10), false),
+            // station 2: outstanding for 20 minutes
+            entry(2, now - Duration::minutes(20), None, false),
+            // station 3: not finished yet
+            entry(3, now + Duration::minutes(5), None, false),
+        ];
+
+        let dashboard = DayDashboard::evaluate(&tournament, &[], &entries, now);
+
+        assert_eq!(dashboard.stations.len(), 3);
+        let first = &dashboard.stations[0];
+        assert_eq!(first.name, "Station 1");
+        assert_eq!(first.num_entered, 2);
+        assert_eq!(first.num_unconfirmed, 1);
+        assert_eq!(first.mean_latency_seconds, Some(300));
+        assert_eq!(first.max_latency_seconds, Some(600));
+        assert!(!first.is_lagging());
+        let second = &dashboard.stations[1];
+        assert_eq!(second.num_outstanding, 1);
+        assert_eq!(second.mean_latency_seconds, None);
+        assert_eq!(second.max_latency_seconds, Some(1200));
+        assert!(second.is_lagging());
+        assert_eq!(dashboard.stations[2].max_latency_seconds, None);
+        assert_eq!(dashboard.num_unconfirmed(), 1);
+        assert_eq!(dashboard.num_outstanding(), 1);
+        assert_eq!(
+            dashboard
+                .lagging_stations()
+                .map(|s| s.station)
+                .collect::<Vec<_>>(),
+            vec![2]
+        );
+    }
+
+    #[test]
+    fn result_entries_of_stored_matches() {
+        let start = Utc::now() - Duration::minutes(60);
+        let duration = Duration::minutes(30);
+        let new_match = |station| {
+            let mut m = Match::new_played(
+                Uuid::new_v4(),
+                Uuid::new_v4(),
+                Uuid::new_v4(),
+                Uuid::nil(),
+                vec![],
+                vec![],
+            );
+            m.set_slot(station, start.into());
+            m
+        };
+        let mut played = new_match(1);
+        played
+            .set_scores(vec![11], vec![7])
+            .set_result_at(Some(start + Duration::minutes(40)));
+        let entry = ResultEntry::from_match(&played, duration).expect("match has station");
+        assert_eq!(entry.projected_end_at, start + duration);
+        assert_eq!(entry.entered_at, Some(start + Duration::minutes(40)));
+        assert!(entry.confirmed);
+
+        let pending = ResultEntry::from_match(&new_match(2), duration).expect("match has station");
+        assert_eq!(pending.station, 2);
+        assert_eq!(pending.entered_at, None);
+        assert!(!pending.confirmed);
+
+        assert!(ResultEntry::from_match(&new_match(0), duration).is_none());
+        let mut bye = new_match(1);
+        bye.set_outcome(MatchOutcome::Bye);
+        assert!(ResultEntry::from_match(&bye, duration).is_none());
+    }
+
+    #[test]
+    fn check_in_completeness_lists_missing_entrants() {
+        let mut entrants = Vec::new();
+        for (name, checked_in) in [("Team B", false), ("Team C", true), ("team a", false)] {
+            let mut entrant = Entrant::default();
+            entrant
+                .set_name(name)
+                .set_checked_in_at(checked_in.then(Utc::now));
+            entrants.push(entrant);
+        }
+
+        let check_in = CheckInCompleteness::evaluate(&entrants);
+
+        assert_eq!(check_in.num_checked_in, 1);
+        assert_eq!(check_in.num_entrants, 3);
+        assert_eq!(check_in.percent(), 33);
+        assert_eq!(check_in.missing, vec!["team a", "Team B"]);
+        assert!(!check_in.is_complete());
+        assert_eq!(CheckInCompleteness::evaluate(&[]).percent(), 100);
+    }
+}
@@ -0,0 +1,387 @@
+//! display board of a tournament for screens at the venue
+//!
+//! A display board consists of pages, which kiosk screens show in rotation: current and next
+//! match of each station, standings of groups and upcoming rounds of KO brackets. Like the
+//! public view it contains only data visible to spectators anyway.
+
+use super::{PublicTournamentView, slots::KoRound};
+use crate::{
+    Core, CoreError, CoreResult, CourtCallCandidate, CrMsg, CrTopic, DbError, GroupStanding, Match,
+    round_ids, stage_group_ids,
+};
+use chrono::{DateTime, Utc};
+use serde::{Deserialize, Serialize};
+use std::collections::HashMap;
+use uuid::Uuid;
+
+/// seconds, which each page of a display board is shown
+pub const DISPLAY_PAGE_SECONDS: u32 = 15;
+
+/// match shown on a display board
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct DisplayMatch {
+    pub match_id: Uuid,
+    pub start_at: DateTime<Utc>,
+    /// names of entrants; empty, if the entrants are not decided yet
+    pub entrants: Vec<String>,
+}
+
+/// current and next match of a station
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct DisplayStation {
+    /// number of station, starting with 1
+    pub station: u32,
+    /// name of station; `Station <number>`, if the station is not named
+    pub name: String,
+    /// match in progress
+    pub current: Option<DisplayMatch>,
+    /// next pending match
+    pub next: Option<DisplayMatch>,
+}
+
+/// row of group standings on a display board
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct DisplayStandingRow {
+    pub rank: u32,
+    pub name: String,
+    pub victory_points: f32,
+    pub relative_score: i16,
+    /// number of matches won by bye
+    pub byes: u16,
+}
+
+/// page of a display board
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub enum DisplayPage {
+    /// current and next match of all stations with matches
+    Stations(Vec<DisplayStation>),
+    /// standings of a group
+    Standings {
+        stage_name: String,
+        group_label: String,
+        rows: Vec<DisplayStandingRow>,
+    },
+    /// upcoming rounds of the KO bracket of a group
+    Bracket {
+        stage_name: String,
+        group_label: String,
+        rounds: Vec<KoRound>,
+    },
+}
+
+/// display board of a tournament, see [`Core::load_display_board`]
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct DisplayBoard {
+    pub tournament_id: Uuid,
+    pub tournament_name: String,
+    pub generated_at: DateTime<Utc>,
+    /// seconds, which each page is shown
+    pub page_seconds: u32,
+    /// pages in order of rotation
+    pub pages: Vec<DisplayPage>,
+}
+
+impl DisplayBoard {
+    /// Build the display board of the public `view` of a tournament. `candidates` are the
+    /// scheduled matches of the tournament; `standings` are the current standings of groups
+    /// by stage id and group label. Groups with a KO bracket show the bracket instead of
+    /// standings. Pages without content are skipped.
+    pub fn build(
+        view: &PublicTournamentView,
+        candidates: &[CourtCallCandidate],
+        standings: &HashMap<(Uuid, String), Vec<GroupStanding>>,
+        now: DateTime<Utc>,
+    ) -> Self {
+        let tournament = &view.tournament;
+        let names: HashMap<Uuid, &str> = view
+            .entrants
+            .iter()
+            .map(|e| (e.get_id(), e.get_name()))
+            .collect();
+        let display_match = |c: &CourtCallCandidate| DisplayMatch {
+            match_id: c.match_id,
+            start_at: c.start_at,
+            entrants: c
+                .entrant_ids
+                .iter()
+                .filter_map(|id| names.get(id).map(|name| name.to_string()))
+                .collect(),
+        };
+
+        let mut pages = Vec::new();
+        let mut stations = Vec::new();
+        for station in 1..=tournament.get_num_stations() {
+            let mut matches: Vec<&CourtCallCandidate> = candidates
+                .iter()
+                .filter(|c| c.station == station && !c.decided)
+                .collect();
+            matches.sort_by_key(|m| (m.start_at, m.match_id));
+            let current = matches.iter().find(|m| m.in_progress).copied();
+            let next = matches.iter().find(|m| !m.in_progress).copied();
+            if current.is_none() && next.is_none() {
+                continue;
+            }
+            stations.push(DisplayStation {
+                station,
+                name: tournament
+                    .get_station_name(station)
+                    .map(str::to_string)
+                    .unwrap_or_else(|| format!("Station {station}")),
+                current: current.map(display_match),
+                next: next.map(display_match),
+            });
+        }
+        if !stations.is_empty() {
+            pages.push(DisplayPage::Stations(stations));
+        }
+
+        for stage in view.stages.iter() {
+            for group in stage.groups.iter() {
+                if !group.bracket.is_empty() {
+                    pages.push(DisplayPage::Bracket {
+                        stage_name: stage.name.clone(),
+                        group_label: group.label.clone(),
+                        rounds: group.bracket.clone(),
+                    });
+                    continue;
+                }
+                let Some(group_standings) = standings.get(&(stage.id, group.label.clone())) else {
+                    continue;
+                };
+                if group_standings.is_empty() {
+                    continue;
+                }
+                pages.push(DisplayPage::Standings {
+                    stage_name: stage.name.clone(),
+                    group_label: group.label.clone(),
+                    rows: group_standings
+                        .iter()
+                        .map(|s| DisplayStandingRow {
+                            rank: s.rank,
+                            name: names
+                                .get(&s.get_entrant_id())
+                                .map(|name| name.to_string())
+                                .unwrap_or_default(),
+                            victory_points: s.score.victory_points,
+                            relative_score: s.score.relative_score,
+                            byes: s.score.byes,
+                        })
+                        .collect(),
+                });
+            }
+        }
+
+        DisplayBoard {
+            tournament_id: tournament.get_id(),
+            tournament_name: tournament.get_name().to_string(),
+            generated_at: now,
+            page_seconds: DISPLAY_PAGE_SECONDS,
+            pages,
+        }
+    }
+}
+
+impl<S> Core<S> {
+    /// Load the display board of the tournament with id `tournament_id`. Returns `None`, if
+    /// the tournament is not public, see [`Core::load_public_tournament`]. Brackets show only
+    /// the rounds, which are not played out yet.
+    pub async fn load_display_board(
+        &self,
+        tournament_id: Uuid,
+    ) -> CoreResult<Option<DisplayBoard>> {
+        let Some(mut view) = self.load_public_tournament(tournament_id).await? else {
+            return Ok(None);
+        };
+        let matches = self
+            .database
+            .list_matches_of_tournament(tournament_id)
+            .await?;
+        let candidates: Vec<CourtCallCandidate> = matches
+            .iter()
+            // matches without station are not placed yet
+            .filter(|m| m.get_station() > 0)
+            .map(CourtCallCandidate::from)
+            .collect();
+        let mut standings: HashMap<(Uuid, String), Vec<GroupStanding>> = HashMap::new();
+        for stage in view.stages.iter_mut() {
+            let stage_matches: Vec<Match> = matches
+                .iter()
+                .filter(|m| *m.get_stage_id() == stage.id)
+                .cloned()
+                .collect();
+            for (group, group_id) in stage.groups.iter_mut().zip(stage_group_ids(&stage_matches)) {
+                if group.bracket.is_empty() {
+                    let group_standings = self.get_group_standings(tournament_id, group_id).await?;
+                    standings.insert((stage.id, group.label.clone()), group_standings);
+                    continue;
+                }
+                let group_matches: Vec<Match> = stage_matches
+                    .iter()
+                    .filter(|m| *m.get_group_id() == group_id)
+                    .cloned()
+                    .collect();
+                let played_out = round_ids(&group_matches)
+                    .into_iter()
+                    .take_while(|round_id| {
+                        group_matches
+                            .iter()
+                            .filter(|m| m.get_round_id() == round_id)
+                            .all(|m| m.is_decided())
+                    })
+                    .count();
+                group.bracket.drain(..played_out.min(group.bracket.len()));
+            }
+        }
+        Ok(Some(DisplayBoard::build(
+            &view,
+            &candidates,
+            &standings,
+            Utc::now(),
+        )))
+    }
+
+    /// Ask all display boards of the tournament with id `tournament_id` to reload, e.g.
+    /// after corrections, which are not announced otherwise.
+    pub async fn refresh_display_boards(&self, tournament_id: Uuid) -> CoreResult<()> {
+        if self
+            .database
+            .get_tournament_base(tournament_id)
+            .await?
+            .is_none()
+        {
+            return Err(CoreError::from(DbError::NotFound));
+        }
+        let notice = CrTopic::DisplayBoard { tournament_id };
+        let msg = CrMsg::DisplayBoardRefreshed {
+            id: tournament_id,
+            version: 0,
+        };
+        self.client_registry.publish(notice, msg).await?;
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{
+        Entrant, EntrantGroupScore, PublicGroup, PublicStage, TournamentBase, slots::ko_bracket,
+        utils::id_version::IdVersion,
+    };
+    use chrono::TimeZone;
+
+    fn at(hour: u32) -> DateTime<Utc> {
+        Utc.with_ymd_and_hms(2026, 7, 11, hour, 0, 0).unwrap()
+    }
+
+    fn entrant(name: &str) -> Entrant {
+        let mut e = Entrant::new(IdVersion::new(Uuid::new_v4(), Some(0)));
+        e.set_name(name);
+        e
+    }
+
+    fn view(stages: Vec<PublicStage>, entrants: Vec<Entrant>) -> PublicTournamentView {
+        let mut tournament = TournamentBase::default();
+        tournament.set_name("Cup").set_num_stations(2);
+        PublicTournamentView {
+            tournament,
+            stages,
+            entrants,
+        }
+    }
+
+    #[test]
+    fn given_matches_at_stations_when_build_then_current_and_next_match_per_station() {
+        let (a, b, c, d) = (entrant("A"), entrant("B"), entrant("C"), entrant("D"));
+        let candidate = |hour, in_progress, entrant_ids: Vec<Uuid>| CourtCallCandidate {
+            match_id: Uuid::new_v4(),
+            station: 1,
+            start_at: at(hour),
+            in_progress,
+            decided: false,
+            entrant_ids,
+        };
+        let candidates = vec![
+            candidate(11, false, vec![c.get_id(), d.get_id()]),
+            candidate(10, true, vec![a.get_id(), b.get_id()]),
+        ];
+        let view = view(Vec::new(), vec![a, b, c, d]);
+
+        let board = DisplayBoard::build(&view, &candidates, &HashMap::new(), at(10));
+
+        assert_eq!(board.page_seconds, DISPLAY_PAGE_SECONDS);
+        let [DisplayPage::Stations(stations)] = board.pages.as_slice() else {
+            panic!("expected only a stations page, got {:?}", board.pages);
+        };
+        // station 2 has no matches
+        assert_eq!(stations.len(), 1);
+        assert_eq!(stations[0].name, "Station 1");
+        assert_eq!(
+            stations[0].current.as_ref().unwrap().entrants,
+            vec!["A", "B"]
+        );
+        assert_eq!(stations[0].next.as_ref().unwrap().entrants, vec!["C", "D"]);
+    }
+
+    #[test]
+    fn given_groups_when_build_then_standings_or_bracket_per_group() {
+        let (a, b) = (entrant("A"), entrant("B"));
+        let stage_id = Uuid::new_v4();
+        let stage = PublicStage {
+            id: stage_id,
+            number: 0,
+            name: "Group Stage".to_string(),
+            groups: vec![
+                PublicGroup {
+                    label: "A".to_string(),
+                    num_entrants: 2,
+                    bracket: Vec::new(),
+                },
+                // no standings yet
+                PublicGroup {
+                    label: "B".to_string(),
+                    num_entrants: 2,
+                    bracket: Vec::new(),
+                },
+                PublicGroup {
+                    label: "C".to_string(),
+                    num_entrants: 4,
+                    bracket: ko_bracket(4),
+                },
+            ],
+        };
+        let standing = |rank, entrant_id, victory_points| {
+            let mut score = EntrantGroupScore::new(entrant_id, Uuid::new_v4());
+            score.victory_points = victory_points;
+            GroupStanding { rank, score }
+        };
+        let standings = HashMap::from([(
+            (stage_id, "A".to_string()),
+            vec![standing(1, b.get_id(), 1.0), standing(2, a.get_id(), 0.0)],
+        )]);
+        let view = view(vec![stage], vec![a, b]);
+
+        let board = DisplayBoard::build(&view, &[], &standings, at(10));
+
+        assert_eq!(board.pages.len(), 2);
+        let DisplayPage::Standings {
+            group_label, rows, ..
+        } = &board.pages[0]
+        else {
+            panic!("expected standings, got {:?}", board.pages[0]);
+        };
+        assert_eq!(group_label, "A");
+        let names: Vec<&str> = rows.iter().map(|r| r.name.as_str()).collect();
+        assert_eq!(names, vec!["B", "A"]);
+        let DisplayPage::Bracket {
+            group_label,
+            rounds,
+            ..
+        } = &board.pages[1]
+        else {
+            panic!("expected bracket, got {:?}", board.pages[1]);
+        };
+        assert_eq!(group_label, "C");
+        assert_eq!(rounds.len(), 2);
+    }
+}
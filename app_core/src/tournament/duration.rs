@@ -0,0 +1,318 @@
+//! estimated duration of stages and tournaments
+//!
+//! Before scheduling, directors check, if a tournament is feasible at all. The estimate
+//! combines the estimated match duration of the sport, the changeover between two matches
+//! at a station, the number of matches and rounds of each stage and the stations allowed for
+//! the stage. As in [`planning`](super::planning), a stage needs at least one time slot per
+//! round and enough slots to play all matches on its stations.
+
+use super::{
+    PublicStage, Stage, TournamentBase, TournamentMode, num_matches_of_stage, slots::ring_rounds,
+};
+use crate::{Core, CoreResult};
+use serde::{Deserialize, Serialize};
+use std::time::Duration;
+use uuid::Uuid;
+
+/// estimated timing of a single match at a station
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+pub struct MatchSlotTiming {
+    /// estimated duration of a match, see `SportPort::estimate_match_duration()`
+    pub match_duration: Duration,
+    /// time between two matches at a station, see `SportPort::changeover_duration()`
+    pub changeover: Duration,
+}
+
+impl MatchSlotTiming {
+    /// timing of a match, if the sport of the tournament has no valid configuration to
+    /// estimate it
+    pub const FALLBACK: MatchSlotTiming = MatchSlotTiming {
+        match_duration: Duration::from_secs(30 * 60),
+        changeover: Duration::ZERO,
+    };
+
+    /// Duration of `num_slots` consecutive matches at a station; the last match is not
+    /// followed by a changeover.
+    pub fn duration_of_slots(&self, num_slots: u32) -> Duration {
+        (self.match_duration + self.changeover) * num_slots - self.changeover * num_slots.min(1)
+    }
+}
+
+/// estimated duration of a stage
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct StageDurationEstimate {
+    pub stage_id: Uuid,
+    pub stage_number: u32,
+    /// name of stage depending on tournament mode, e.g. `Final Stage`
+    pub stage_name: String,
+    pub num_matches: u32,
+    /// number of rounds of the group with most rounds
+    pub num_rounds: u32,
+    /// number of stations, at which matches of the stage may be played
+    pub num_stations: u32,
+    /// number of consecutive matches at a station
+    pub num_slots: u32,
+    pub duration: Duration,
+}
+
+impl StageDurationEstimate {
+    /// Estimate duration of `stage` in tournament `mode`, if its matches are played on
+    /// `num_stations` stations with `timing`.
+    pub fn new(
+        mode: TournamentMode,
+        stage: &PublicStage,
+        num_stations: u32,
+        timing: MatchSlotTiming,
+    ) -> Self {
+        let num_stations = num_stations.max(1);
+        let num_matches = num_matches_of_stage(mode, stage);
+        let num_rounds = num_rounds_of_stage(mode, stage);
+        let num_slots = num_matches.div_ceil(num_stations).max(num_rounds);
+        StageDurationEstimate {
+            stage_id: stage.id,
+            stage_number: stage.number,
+            stage_name: stage.name.clone(),
+            num_matches,
+            num_rounds,
+            num_stations,
+            num_slots,
+            duration: timing.duration_of_slots(num_slots),
+        }
+    }
+}
+
+/// estimated duration of a tournament, see [`Core::estimate_tournament_duration`]
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct TournamentDurationEstimate {
+    pub tournament_id: Uuid,
+    pub timing: MatchSlotTiming,
+    /// stages sorted by stage number
+    pub stages: Vec<StageDurationEstimate>,
+    /// sum of durations of all stages, which are played one after another
+    pub duration: Duration,
+}
+
+impl TournamentDurationEstimate {
+    /// Number of matches of all stages.
+    pub fn num_matches(&self) -> u32 {
+        self.stages.iter().map(|s| s.num_matches).sum()
+    }
+}
+
+/// Number of rounds of `stage` in tournament `mode`, i.e. the rounds of the group with most
+/// rounds: KO brackets play `log2(n)` rounds, Swiss system groups the configured number of
+/// rounds, ring system groups the rounds of their ring and round robin groups `n - 1`
+/// rounds (`n` rounds with an odd number of entrants, since one entrant pauses each round).
+pub fn num_rounds_of_stage(mode: TournamentMode, stage: &PublicStage) -> u32 {
+    stage
+        .groups
+        .iter()
+        .map(|group| {
+            let size = group.num_entrants;
+            if size < 2 {
+                0
+            } else if !group.bracket.is_empty() {
+                group.bracket.len() as u32
+            } else if let TournamentMode::SwissSystem { num_rounds } = mode {
+                num_rounds
+            } else if let TournamentMode::RingSystem { num_neighbors } = mode
+                && 2 * num_neighbors + 1 < size
+            {
+                ring_rounds(size, num_neighbors).len() as u32
+            } else {
+                size - 1 + size % 2
+            }
+        })
+        .max()
+        .unwrap_or(0)
+}
+
+impl<S> Core<S> {
+    /// Estimate the duration of the stage with id `stage_id`.
+    /// Returns `None`, if the stage or its tournament does not exist or if no valid sport
+    /// configuration of the sport of the tournament exists.
+    pub async fn estimate_stage_duration(
+        &self,
+        stage_id: Uuid,
+    ) -> CoreResult<Option<StageDurationEstimate>> {
+        let Some(stage) = self.database.get_stage_by_id(stage_id).await? else {
+            return Ok(None);
+        };
+        let Some(tournament) = self
+            .database
+            .get_tournament_base(stage.get_tournament_id())
+            .await?
+        else {
+            return Ok(None);
+        };
+        let Some(timing) = self.estimate_match_timing(&tournament).await? else {
+            return Ok(None);
+        };
+        Ok(Some(estimate_stage(&tournament, &stage, timing)))
+    }
+
+    /// Estimate the duration of the tournament with id `tournament_id`, which plays its
+    /// stages one after another.
+    /// Returns `None`, if the tournament does not exist or if no valid sport configuration
+    /// of the sport of the tournament exists.
+    pub async fn estimate_tournament_duration(
+        &self,
+        tournament_id: Uuid,
+    ) -> CoreResult<Option<TournamentDurationEstimate>> {
+        let Some(tournament) = self.database.get_tournament_base(tournament_id).await? else {
+            return Ok(None);
+        };
+        let Some(timing) = self.estimate_match_timing(&tournament).await? else {
+            return Ok(None);
+        };
+        let mut stages = Vec::new();
+        for (stage_id, _number) in self
+            .database
+            .list_stage_ids_of_tournament(
+                tournament_id,
+                tournament.get_tournament_mode().get_num_of_stages(),
+            )
+            .await?
+        {
+            if let Some(stage) = self.database.get_stage_by_id(stage_id).await? {
+                stages.push(estimate_stage(&tournament, &stage, timing));
+            }
+        }
+        stages.sort_by_key(|s| s.stage_number);
+        let duration = stages.iter().map(|s| s.duration).sum();
+        Ok(Some(TournamentDurationEstimate {
+            tournament_id,
+            timing,
+            stages,
+            duration,
+        }))
+    }
+
+    /// Estimate timing of a match of `tournament` with its sport configuration, see
+    /// [`Core::load_tournament_sport_config`]; `None`, if the tournament has no valid
+    /// configuration.
+    pub async fn estimate_match_timing(
+        &self,
+        tournament: &TournamentBase,
+    ) -> CoreResult<Option<MatchSlotTiming>> {
+        let Some(sport_plugin) = self.sport_plugins.get(&tournament.get_sport_id()) else {
+            return Ok(None);
+        };
+        let Some(config) = self.load_tournament_sport_config(tournament).await? else {
+            return Ok(None);
+        };
+        let Ok(match_duration) = sport_plugin.estimate_match_duration(&config) else {
+            return Ok(None);
+        };
+        Ok(Some(MatchSlotTiming {
+            match_duration,
+            changeover: sport_plugin
+                .changeover_duration(&config)
+                .unwrap_or_default(),
+        }))
+    }
+
+    /// Estimate timing of a match of `tournament`, see [`Core::estimate_match_timing`];
+    /// [`MatchSlotTiming::FALLBACK`], if no estimate is available.
+    pub async fn estimate_match_timing_or_fallback(
+        &self,
+        tournament: &TournamentBase,
+    ) -> CoreResult<MatchSlotTiming> {
+        Ok(self
+            .estimate_match_timing(tournament)
+            .await?
+            .unwrap_or(MatchSlotTiming::FALLBACK))
+    }
+}
+
+fn estimate_stage(
+    tournament: &TournamentBase,
+    stage: &Stage,
+    timing: MatchSlotTiming,
+) -> StageDurationEstimate {
+    StageDurationEstimate::new(
+        tournament.get_tournament_mode(),
+        &PublicStage::new(tournament, stage),
+        *stage.get_allowed_stations(tournament).end(),
+        timing,
+    )
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::tournament::{PublicGroup, slots::ko_bracket};
+
+    fn stage(sizes: &[u32], ko: bool) -> PublicStage {
+        PublicStage {
+            id: Uuid::new_v4(),
+            number: 0,
+            name: "Stage".to_string(),
+            groups: sizes
+                .iter()
+                .map(|&size| PublicGroup {
+                    label: "A".to_string(),
+                    num_entrants: size,
+                    bracket: if ko { ko_bracket(size) } else { Vec::new() },
+                })
+                .collect(),
+        }
+    }
+
+    fn timing() -> MatchSlotTiming {
+        MatchSlotTiming {
+            match_duration: Duration::from_secs(20 * 60),
+            changeover: Duration::from_secs(5 * 60),
+        }
+    }
+
+    #[test]
+    fn given_slots_when_duration_of_slots_then_no_changeover_after_last_match() {
+        assert_eq!(timing().duration_of_slots(0), Duration::ZERO);
+        assert_eq!(timing().duration_of_slots(1), Duration::from_secs(20 * 60));
+        assert_eq!(
+            timing().duration_of_slots(3),
+            Duration::from_secs((3 * 20 + 2 * 5) * 60)
+        );
+    }
+
+    #[test]
+    fn given_groups_when_num_rounds_of_stage_then_group_with_most_rounds_counts() {
+        let round_robin = stage(&[4, 5], false);
+        assert_eq!(
+            num_rounds_of_stage(TournamentMode::SingleStage, &round_robin),
+            5
+        );
+        assert_eq!(
+            num_rounds_of_stage(TournamentMode::SwissSystem { num_rounds: 3 }, &round_robin),
+            3
+        );
+        let ko = stage(&[8], true);
+        assert_eq!(num_rounds_of_stage(TournamentMode::SingleStage, &ko), 3);
+        assert_eq!(
+            num_rounds_of_stage(TournamentMode::SingleStage, &stage(&[1], false)),
+            0
+        );
+    }
+
+    #[test]
+    fn given_few_stations_when_estimate_stage_then_matches_limit_slots() {
+        // 2 groups of 4: 12 matches in 3 rounds
+        let stage = stage(&[4, 4], false);
+
+        let few = StageDurationEstimate::new(TournamentMode::SingleStage, &stage, 2, timing());
+        assert_eq!(few.num_matches, 12);
+        assert_eq!(few.num_rounds, 3);
+        assert_eq!(few.num_slots, 6);
+        assert_eq!(few.duration, Duration::from_secs((6 * 20 + 5 * 5) * 60));
+
+        // more stations than matches per round: rounds limit slots
+        let many = StageDurationEstimate::new(TournamentMode::SingleStage, &stage, 10, timing());
+        assert_eq!(many.num_slots, 3);
+
+        // without stations the stage is played on a single station
+        let none = StageDurationEstimate::new(TournamentMode::SingleStage, &stage, 0, timing());
+        assert_eq!(none.num_stations, 1);
+        assert_eq!(none.num_slots, 12);
+    }
+}
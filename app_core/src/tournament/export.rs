@@ -0,0 +1,221 @@
+//! export of a tournament to a self-contained document and import with new ids
+
+use super::{Stage, TournamentBase, TournamentState};
+use crate::{
+    Core, CoreError, CoreResult, Entrant, Match, SportConfig,
+    utils::{id_version::IdVersion, list_order::ListOrder},
+};
+use chrono::{DateTime, Utc};
+use serde::{Deserialize, Serialize};
+use std::collections::HashMap;
+use uuid::Uuid;
+
+/// current format version of `TournamentExport`; version 2 added entrants and matches
+pub const TOURNAMENT_EXPORT_FORMAT_VERSION: u32 = 2;
+
+/// Self-contained document of a tournament, e.g. for backups or transfer between instances.
+///
+/// Groups are defined by the number of groups of each stage and are therefore part of the
+/// exported stages. Postal address and venue of the tournament belong to the exporting
+/// instance and are not part of the document.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct TournamentExport {
+    /// format version of document; import rejects unknown (newer) versions
+    pub format_version: u32,
+    /// time of export
+    pub exported_at: DateTime<Utc>,
+    /// tournament base
+    pub tournament: TournamentBase,
+    /// stages of tournament, sorted by stage number
+    pub stages: Vec<Stage>,
+    /// snapshot of the sport config of the tournament at time of export, see
+    /// [`Core::load_tournament_sport_config`]; empty, if the sport has no valid config
+    pub sport_configs: Vec<SportConfig>,
+    /// entrants of tournament
+    #[serde(default)]
+    pub entrants: Vec<Entrant>,
+    /// matches of all stages of tournament, sorted by start and station
+    #[serde(default)]
+    pub matches: Vec<Match>,
+}
+
+impl TournamentExport {
+    /// Serialize document to pretty printed JSON.
+    pub fn to_json(&self) -> CoreResult<String> {
+        serde_json::to_string_pretty(self).map_err(|e| CoreError::ParsingError(e.to_string()))
+    }
+
+    /// Parse document from JSON.
+    pub fn from_json(json: &str) -> CoreResult<Self> {
+        serde_json::from_str(json).map_err(|e| CoreError::ParsingError(e.to_string()))
+    }
+}
+
+impl<S> Core<S> {
+    /// Export the tournament with id `tournament_id` including its stages, entrants, matches
+    /// and a snapshot of its sport config.
+    /// Returns `None`, if no tournament with `tournament_id` exists.
+    pub async fn export_tournament(
+        &self,
+        tournament_id: Uuid,
+    ) -> CoreResult<Option<TournamentExport>> {
+        let mut base_core = self.as_tournament_base_state();
+        let Some(tournament) = base_core.load(tournament_id).await?.cloned() else {
+            return Ok(None);
+        };
+
+        let mut stage_core = self.as_stage_state(tournament_id);
+        let mut stages = Vec::new();
+        for (stage_id, _number) in stage_core.list_stage_ids_of_tournament().await? {
+            if let Some(stage) = stage_core.load_by_id(stage_id).await? {
+                stages.push(*stage);
+            }
+        }
+
+        let sport_configs = self
+            .load_tournament_sport_config(&tournament)
+            .await?
+            .into_iter()
+            .collect();
+        let entrants = self
+            .database
+            .list_entrants_of_tournament(tournament_id)
+            .await?;
+        let matches = self
+            .database
+            .list_matches_of_tournament(tournament_id)
+            .await?;
+
+        Ok(Some(TournamentExport {
+            format_version: TOURNAMENT_EXPORT_FORMAT_VERSION,
+            exported_at: Utc::now(),
+            tournament,
+            stages,
+            sport_configs,
+            entrants,
+            matches,
+        }))
+    }
+
+    /// Import an exported tournament as new tournament.
+    ///
+    /// Tournament, stages, entrants and matches get fresh ids, therefore a document may be
+    /// imported multiple times. Matches reference the fresh ids of their stages, groups,
+    /// rounds, entrants and previous matches. Since tournament names are unique per sport, the
+    /// imported tournament may be renamed with `new_name`. The sport config of the snapshot is
+    /// only imported with a fresh id, if no config of the same sport with the same name
+    /// exists; the imported tournament uses the imported or the existing config. Postal address and venue are reset, since they belong to the exporting
+    /// instance. The imported tournament is a draft, which directors review before they
+    /// publish it again.
+    ///
+    /// All objects are saved in one transaction: if saving an object fails, no object of the
+    /// import is kept and the error is returned.
+    pub async fn import_tournament(
+        &self,
+        export: TournamentExport,
+        new_name: Option<String>,
+    ) -> CoreResult<TournamentBase> {
+        if export.format_version > TOURNAMENT_EXPORT_FORMAT_VERSION {
+            return Err(CoreError::ParsingError(format!(
+                "unsupported tournament export format version {}",
+                export.format_version
+            )));
+        }
+
+        // one fresh id per exported id, so that references between objects are kept
+        let mut ids: HashMap<Uuid, Uuid> = HashMap::new();
+        let mut new_id = move |id: Uuid| *ids.entry(id).or_insert_with(Uuid::new_v4);
+
+        self.with_transaction(|core| async move {
+            // import missing sport configs; ids of exported configs map to imported or
+            // existing configs
+            let mut config_ids: HashMap<Uuid, Uuid> = HashMap::new();
+            let mut config_core = core.as_sport_config_state();
+            for mut config in export.sport_configs {
+                let exported_id = config.get_id();
+                let config_id = match core.find_sport_config_by_name(&config).await? {
+                    Some(existing_id) => existing_id,
+                    None => {
+                        config
+                            .set_id_version(IdVersion::NewWithId(Uuid::new_v4()))
+                            .set_archived_at(None);
+                        *config_core.get_mut() = config;
+                        config_core.save().await?.get_id()
+                    }
+                };
+                config_ids.insert(exported_id, config_id);
+            }
+
+            // import tournament base
+            let mut base_core = core.as_tournament_base_state();
+            let mut tournament = export.tournament;
+            tournament
+                .set_id_version(IdVersion::NewWithId(new_id(tournament.get_id())))
+                .set_tournament_state(TournamentState::Draft)
+                .set_archived_at(None)
+                .set_created_at(None)
+                .set_address_id(None)
+                .set_venue_id(None);
+            let sport_config_id = tournament
+                .get_sport_config_id()
+                .and_then(|id| config_ids.get(&id).copied());
+            tournament.set_sport_config_id(sport_config_id);
+            if let Some(new_name) = new_name {
+                tournament.set_name(new_name);
+            }
+            *base_core.get_mut() = tournament;
+            let tournament = base_core.save().await?.clone();
+
+            // import stages
+            let mut stage_core = core.as_stage_state(tournament.get_id());
+            for mut stage in export.stages {
+                stage
+                    .set_id_version(IdVersion::NewWithId(new_id(stage.get_id())))
+                    .set_tournament_id(tournament.get_id());
+                *stage_core.get_mut() = stage;
+                stage_core.save().await?;
+            }
+
+            // import entrants without registration notifications
+            for mut entrant in export.entrants {
+                entrant
+                    .set_id_version(IdVersion::NewWithId(new_id(entrant.get_id())))
+                    .set_tournament_id(tournament.get_id());
+                core.database.save_entrant(&entrant).await?;
+            }
+
+            // import matches
+            let mut matches = export.matches;
+            for m in matches.iter_mut() {
+                m.map_ids(&mut new_id);
+            }
+            core.database.save_matches(&matches).await?;
+
+            Ok(tournament)
+        })
+        .await
+    }
+
+    /// Id of the config of the same sport with the same name as `config`, if any.
+    async fn find_sport_config_by_name(&self, config: &SportConfig) -> CoreResult<Option<Uuid>> {
+        let mut config_core = self.as_sport_config_state();
+        // name filter matches substrings, therefore compare names of candidates
+        for id in config_core
+            .list_sport_config_ids(
+                config.get_sport_id(),
+                Some(config.get_name()),
+                true,
+                None,
+                &ListOrder::default(),
+            )
+            .await?
+        {
+            if let Some(existing) = config_core.load(id).await?
+                && existing.get_name() == config.get_name()
+            {
+                return Ok(Some(id));
+            }
+        }
+        Ok(None)
+    }
+}
@@ -0,0 +1,310 @@
+//! final report of finished tournaments
+//!
+//! When a tournament finishes, its final report is assembled from tournament, stages,
+//! entrants and match notes. The report crate renders it to HTML and PDF; both documents are
+//! stored via the [`BlobStoragePort`](crate::BlobStoragePort) and linked from the tournament
+//! page. Handover notes of the shift log are internal; they are only part of reports, which
+//! are rendered on demand for the organization.
+
+use super::{PublicStage, TournamentBase, TournamentMode, TournamentState};
+use crate::{Blob, Core, CoreResult, ShiftLogEntry};
+use chrono::{DateTime, Utc};
+use serde::{Deserialize, Serialize};
+use std::{collections::HashSet, time::Duration};
+use uuid::Uuid;
+
+/// number of most used tags of match notes listed in the final report
+pub const FINAL_REPORT_TOP_TAGS: usize = 5;
+
+/// format of a stored final report
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+pub enum ReportFormat {
+    Html,
+    Pdf,
+}
+
+impl ReportFormat {
+    pub fn content_type(&self) -> &'static str {
+        match self {
+            ReportFormat::Html => "text/html; charset=utf-8",
+            ReportFormat::Pdf => "application/pdf",
+        }
+    }
+
+    pub fn extension(&self) -> &'static str {
+        match self {
+            ReportFormat::Html => "html",
+            ReportFormat::Pdf => "pdf",
+        }
+    }
+}
+
+/// Blob key of the final report of tournament `tournament_id` in `format`.
+pub fn final_report_key(tournament_id: Uuid, format: ReportFormat) -> String {
+    format!(
+        "reports/{tournament_id}/final_report.{}",
+        format.extension()
+    )
+}
+
+/// participation numbers of a tournament
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+pub struct Participation {
+    /// number of registered entrants
+    pub registered: u32,
+    /// number of entrants the tournament was planned for
+    pub planned: u32,
+    /// number of distinct clubs of registered entrants
+    pub clubs: u32,
+}
+
+/// final rank of an entrant
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct FinalStanding {
+    pub rank: u32,
+    pub entrant_id: Uuid,
+    pub name: String,
+}
+
+/// notable statistics of a tournament
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct ReportStatistics {
+    /// number of matches of all stages
+    pub num_matches: u32,
+    /// number of matches with notes
+    pub num_match_notes: usize,
+    /// most used tags of match notes with their number of matches
+    pub top_tags: Vec<(String, usize)>,
+}
+
+/// planned and actual duration of a tournament
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+pub struct DurationAccuracy {
+    /// estimated duration of all matches, played in parallel on all stations
+    pub planned: Option<Duration>,
+    /// duration from first to last match
+    pub actual: Option<Duration>,
+}
+
+impl DurationAccuracy {
+    /// Deviation of actual from planned duration in percent; positive, if the tournament
+    /// took longer than planned.
+    pub fn deviation_percent(&self) -> Option<i64> {
+        let planned = self.planned?.as_secs();
+        let actual = self.actual?.as_secs();
+        if planned == 0 {
+            return None;
+        }
+        Some((actual as i64 - planned as i64) * 100 / planned as i64)
+    }
+}
+
+/// final report of a tournament, see [`Core::assemble_final_report`]
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct FinalReport {
+    pub tournament: TournamentBase,
+    pub generated_at: DateTime<Utc>,
+    pub participation: Participation,
+    /// final ranking; empty as long as results of matches are not persisted
+    pub standings: Vec<FinalStanding>,
+    /// stages sorted by stage number
+    pub stages: Vec<PublicStage>,
+    pub statistics: ReportStatistics,
+    pub duration: DurationAccuracy,
+    /// pinned handover notes of the shift log, oldest first; `None` in reports for the
+    /// public, since notes of directors are internal
+    pub handover_notes: Option<Vec<ShiftLogEntry>>,
+}
+
+/// Number of matches of `stage` in tournament `mode`: KO brackets play out `n - 1` matches,
+/// Swiss system groups play `n / 2` matches per round, ring system groups `n` matches per
+/// neighbor and all other groups round robin.
+pub fn num_matches_of_stage(mode: TournamentMode, stage: &PublicStage) -> u32 {
+    stage
+        .groups
+        .iter()
+        .map(|group| {
+            let size = group.num_entrants;
+            if !group.bracket.is_empty() {
+                group.bracket.iter().map(|r| r.matches.len() as u32).sum()
+            } else if let TournamentMode::SwissSystem { num_rounds } = mode {
+                num_rounds * (size / 2)
+            } else if let TournamentMode::RingSystem { num_neighbors } = mode
+                && 2 * num_neighbors + 1 < size
+            {
+                num_neighbors * size
+            } else {
+                size * size.saturating_sub(1) / 2
+            }
+        })
+        .sum()
+}
+
+impl<S> Core<S> {
+    /// Assemble the final report of the tournament with id `tournament_id`. Handover notes
+    /// of the shift log are only added with `include_handover_notes`.
+    /// Returns `None`, if no tournament with `tournament_id` exists or if it is not public.
+    pub async fn assemble_final_report(
+        &self,
+        tournament_id: Uuid,
+        include_handover_notes: bool,
+    ) -> CoreResult<Option<FinalReport>> {
+        let Some(view) = self.load_public_tournament(tournament_id).await? else {
+            return Ok(None);
+        };
+        let tournament = view.tournament;
+        let mode = tournament.get_tournament_mode();
+
+        let clubs: HashSet<String> = view
+            .entrants
+            .iter()
+            .filter_map(|e| e.get_club())
+            .map(|club| club.to_lowercase())
+            .collect();
+        let participation = Participation {
+            registered: view.entrants.len() as u32,
+            planned: tournament.get_num_entrants(),
+            clubs: clubs.len() as u32,
+        };
+
+        let note_core = self.as_match_note_state(tournament_id);
+        let mut top_tags = note_core.tag_counts().await?;
+        top_tags.truncate(FINAL_REPORT_TOP_TAGS);
+        let num_matches = view
+            .stages
+            .iter()
+            .map(|stage| num_matches_of_stage(mode, stage))
+            .sum();
+        let statistics = ReportStatistics {
+            num_matches,
+            num_match_notes: note_core.list_notes(None, None).await?.len(),
+            top_tags,
+        };
+
+        let planned = self
+            .estimate_match_timing(&tournament)
+            .await?
+            .map(|timing| {
+                let stations = tournament.get_num_stations().max(1);
+                timing.match_duration * num_matches.div_ceil(stations)
+            });
+        let handover_notes = if include_handover_notes {
+            let notes = self
+                .as_shift_log_state(tournament_id)
+                .handover_notes()
+                .await?;
+            Some(notes)
+        } else {
+            None
+        };
+
+        Ok(Some(FinalReport {
+            generated_at: Utc::now(),
+            participation,
+            // ToDo: add final standings and actual duration, when matches and their results
+            // are persisted
+            standings: Vec::new(),
+            stages: view.stages,
+            statistics,
+            duration: DurationAccuracy {
+                planned,
+                actual: None,
+            },
+            handover_notes,
+            tournament,
+        }))
+    }
+
+    /// Store rendered final report of tournament `tournament_id` via the blob storage port.
+    pub async fn store_final_report(
+        &self,
+        tournament_id: Uuid,
+        format: ReportFormat,
+        data: Vec<u8>,
+    ) -> CoreResult<()> {
+        let blob = Blob {
+            content_type: format.content_type().to_string(),
+            data,
+        };
+        self.blobs
+            .put_blob(&final_report_key(tournament_id, format), &blob)
+            .await?;
+        Ok(())
+    }
+
+    /// Load stored final report of tournament `tournament_id`. Returns `None`, if the
+    /// report was not generated yet or if the tournament is not public (anymore), see
+    /// [`Core::load_public_tournament`].
+    pub async fn load_final_report(
+        &self,
+        tournament_id: Uuid,
+        format: ReportFormat,
+    ) -> CoreResult<Option<Blob>> {
+        if self.load_public_tournament(tournament_id).await?.is_none() {
+            return Ok(None);
+        }
+        Ok(self
+            .blobs
+            .get_blob(&final_report_key(tournament_id, format))
+            .await?)
+    }
+}
+
+/// Check if a change of the tournament state from `previous` to `next` finishes the
+/// tournament, which triggers generation of the final report.
+pub fn is_finishing_transition(previous: Option<TournamentState>, next: TournamentState) -> bool {
+    next == TournamentState::Finished && previous != Some(TournamentState::Finished)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::tournament::PublicGroup;
+
+    fn stage(sizes: &[u32]) -> PublicStage {
+        PublicStage {
+            id: Uuid::new_v4(),
+            number: 0,
+            name: "Stage".to_string(),
+            groups: sizes
+                .iter()
+                .map(|&size| PublicGroup {
+                    label: "A".to_string(),
+                    num_entrants: size,
+                    bracket: Vec::new(),
+                })
+                .collect(),
+        }
+    }
+
+    #[test]
+    fn matches_of_round_robin_and_swiss_stages() {
+        let stage = stage(&[4, 5]);
+        assert_eq!(
+            num_matches_of_stage(TournamentMode::SingleStage, &stage),
+            6 + 10
+        );
+        assert_eq!(
+            num_matches_of_stage(TournamentMode::SwissSystem { num_rounds: 3 }, &stage),
+            3 * 2 + 3 * 2
+        );
+        assert_eq!(
+            num_matches_of_stage(TournamentMode::RingSystem { num_neighbors: 1 }, &stage),
+            4 + 5
+        );
+    }
+
+    #[test]
+    fn duration_deviation() {
+        let duration = DurationAccuracy {
+            planned: Some(Duration::from_secs(3600)),
+            actual: Some(Duration::from_secs(4500)),
+        };
+        assert_eq!(duration.deviation_percent(), Some(25));
+        let unknown = DurationAccuracy {
+            planned: Some(Duration::from_secs(3600)),
+            actual: None,
+        };
+        assert_eq!(unknown.deviation_percent(), None);
+    }
+}
@@ -0,0 +1,423 @@
+//! lifecycle of a tournament
+//!
+//! A tournament runs through `Draft -> Published -> ActiveStage(0) -> ... -> Finished`.
+//! Publishing is optional. States never go back once a tournament is started.
+//!
+//! Editing a running tournament is restricted per stage (see [`StageEditability`]): stages,
+//! which are not started yet, remain fully editable, including their number of groups and
+//! seeding. The active stage only allows changes of its schedule, e.g. station limit and time
+//! cap. Completed stages and finished tournaments are locked. Mode and number of entrants of
+//! running tournaments are fixed.
+//!
+//! Directors use the explicit transitions [`Core::start_tournament`],
+//! [`Core::complete_stage`] and [`Core::finish_tournament`]. Saving a tournament base with a
+//! changed state applies the same rules: the state advances one step at a time and the
+//! stored matches must meet the preconditions of the step. Completed stages are snapshotted.
+//! Sandbox tournaments may change their state freely. The only way back is a rollback to the
+//! snapshot of a completed stage (see [`stage_snapshot`](super::stage_snapshot)).
+
+use super::{Stage, TournamentBase, TournamentBaseState, TournamentState};
+use crate::{
+    Core, CoreError, CoreResult, Match,
+    utils::validation::{FieldError, ValidationErrors, ValidationResult},
+};
+use serde::{Deserialize, Serialize};
+
+/// editability of a stage, which depends upon the state of its tournament
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+pub enum StageEditability {
+    /// stage is not started: all settings, number of groups and seeding may change
+    Editable,
+    /// stage is active: only schedule settings may change, i.e. station limit, time cap and
+    /// auto advance
+    ScheduleOnly,
+    /// stage is completed or tournament is finished
+    Locked,
+}
+
+impl StageEditability {
+    /// Check if number of groups, seeding and scoring of the stage may change.
+    pub fn is_structure_editable(self) -> bool {
+        self == StageEditability::Editable
+    }
+    /// Check if schedule settings of the stage may change.
+    pub fn is_schedule_editable(self) -> bool {
+        self != StageEditability::Locked
+    }
+}
+
+impl TournamentState {
+    /// Check if a tournament with `num_active_stages` may change from this state to `next`
+    /// by saving it. Started tournaments only advance one stage at a time and finish from
+    /// their last stage. Drafts may be published and withdrawn.
+    pub fn can_change_to(self, next: TournamentState, num_active_stages: u32) -> bool {
+        match (self, next) {
+            (previous, next) if previous == next => true,
+            (
+                TournamentState::Draft | TournamentState::Published,
+                TournamentState::Draft
+                | TournamentState::Published
+                | TournamentState::ActiveStage(0),
+            ) => true,
+            (TournamentState::ActiveStage(active), TournamentState::ActiveStage(next)) => {
+                next == active + 1 && next < num_active_stages
+            }
+            (TournamentState::ActiveStage(active), TournamentState::Finished) => {
+                active + 1 == num_active_stages
+            }
+            _ => false,
+        }
+    }
+}
+
+impl TournamentBase {
+    /// Check if stage `stage_number` is started, i.e. if it is the active stage or a stage
+    /// before it, or if the tournament is finished. Stages of sandbox tournaments are never
+    /// started.
+    pub fn is_stage_started(&self, stage_number: u32) -> bool {
+        if self.is_sandbox() {
+            return false;
+        }
+        match self.get_tournament_state() {
+            TournamentState::ActiveStage(active_stage) => stage_number <= active_stage,
+            TournamentState::Finished => true,
+            TournamentState::Draft | TournamentState::Published => false,
+        }
+    }
+
+    /// Check if stage `stage_number` is the active stage. Swiss rounds are active stages of
+    /// the one Swiss stage.
+    pub fn is_stage_active(&self, stage_number: u32) -> bool {
+        match self.get_tournament_state() {
+            TournamentState::ActiveStage(active_stage) => {
+                active_stage == stage_number || self.get_tournament_mode().get_num_of_stages() == 1
+            }
+            _ => false,
+        }
+    }
+
+    /// Get the editability of stage `stage_number` in the current state of the tournament.
+    pub fn get_stage_editability(&self, stage_number: u32) -> StageEditability {
+        if !self.is_stage_started(stage_number) {
+            return StageEditability::Editable;
+        }
+        match self.get_tournament_state() {
+            TournamentState::ActiveStage(active_stage) if active_stage == stage_number => {
+                StageEditability::ScheduleOnly
+            }
+            // Swiss rounds are active stages of the one Swiss stage
+            TournamentState::ActiveStage(_)
+                if self.get_tournament_mode().get_num_of_stages() == 1 =>
+            {
+                StageEditability::ScheduleOnly
+            }
+            _ => StageEditability::Locked,
+        }
+    }
+
+    /// Check changes of the stored tournament `old` against the rules of running tournaments:
+    /// mode and number of entrants are fixed, once the tournament is started.
+    pub fn validate_edit(&self, old: Option<&TournamentBase>) -> ValidationResult<()> {
+        let mut errs = ValidationErrors::new();
+        let Some(old) = old.filter(|old| old.is_locked_by_state()) else {
+            return Ok(());
+        };
+        let object_id = self.get_id();
+        if self.get_tournament_mode() != old.get_tournament_mode() {
+            errs.add(
+                FieldError::builder()
+                    .set_field(String::from("mode"))
+                    .add_message("mode of a running or finished tournament cannot be changed")
+                    .set_object_id(object_id)
+                    .build(),
+            );
+        }
+        if self.get_num_entrants() != old.get_num_entrants() {
+            errs.add(
+                FieldError::builder()
+                    .set_field(String::from("num_entrants"))
+                    .add_message(
+                        "number of entrants of a running or finished tournament cannot be changed",
+                    )
+                    .set_object_id(object_id)
+                    .build(),
+            );
+        }
+        if self.get_sport_config_id() != old.get_sport_config_id() {
+            errs.add(
+                FieldError::builder()
+                    .set_field(String::from("sport_config_id"))
+                    .add_message(
+                        "sport configuration of a running or finished tournament cannot be changed",
+                    )
+                    .set_object_id(object_id)
+                    .build(),
+            );
+        }
+        if !errs.is_empty() {
+            return Err(errs);
+        }
+        Ok(())
+    }
+}
+
+impl Stage {
+    /// Check changes of the stored stage `old` against the editability of the stage in
+    /// `tournament`. New stages may only be created, if they are not started yet.
+    pub fn validate_edit(
+        &self,
+        old: Option<&Stage>,
+        tournament: &TournamentBase,
+    ) -> ValidationResult<()> {
+        let mut errs = ValidationErrors::new();
+        let object_id = self.get_id();
+        let editability = tournament.get_stage_editability(self.get_number());
+        let mut reject = |field: &str, message: &str| {
+            errs.add(
+                FieldError::builder()
+                    .set_field(String::from(field))
+                    .add_message(message)
+                    .set_object_id(object_id)
+                    .build(),
+            );
+        };
+        match old {
+            _ if editability.is_structure_editable() => {}
+            None => reject("number", "started stage cannot be created"),
+            Some(old) => {
+                if self.get_num_groups() != old.get_num_groups() {
+                    reject(
+                        "num_groups",
+                        "number of groups of a started stage cannot be changed",
+                    );
+                }
+                if self.get_scoring_override() != old.get_scoring_override() {
+                    reject(
+                        "scoring_override",
+                        "scoring of a started stage cannot be changed",
+                    );
+                }
+                let schedule_changed = self.get_max_stations() != old.get_max_stations()
+                    || self.get_latest_end() != old.get_latest_end()
+                    || self.is_auto_advance() != old.is_auto_advance();
+                if schedule_changed && !editability.is_schedule_editable() {
+                    reject(
+                        "number",
+                        "completed stages and stages of finished tournaments cannot be changed",
+                    );
+                }
+            }
+        }
+        if !errs.is_empty() {
+            return Err(errs);
+        }
+        Ok(())
+    }
+}
+
+impl Core<TournamentBaseState> {
+    /// Start the currently loaded draft or published tournament with its first stage.
+    /// The tournament must pass its readiness checklist and all matches of its first stage
+    /// must be seeded.
+    pub async fn start_tournament(&mut self) -> CoreResult<&TournamentBase> {
+        match self.get().get_tournament_state() {
+            TournamentState::Draft | TournamentState::Published => {}
+            _ => return Err(self.transition_error("tournament is already started")),
+        }
+        self.get_mut()
+            .set_tournament_state(TournamentState::ActiveStage(0));
+        self.save().await
+    }
+    /// Complete the active stage of the currently loaded tournament and start the next stage.
+    /// All matches of the active stage must be decided. The last stage is completed by
+    /// [`Core::finish_tournament`].
+    pub async fn complete_stage(&mut self) -> CoreResult<&TournamentBase> {
+        let TournamentState::ActiveStage(active_stage) = self.get().get_tournament_state() else {
+            return Err(self.transition_error("tournament is not running"));
+        };
+        let num_active_stages = self.get().get_tournament_mode().get_num_of_active_stages();
+        if active_stage + 1 >= num_active_stages {
+            return Err(
+                self.transition_error("last stage is completed by finishing the tournament")
+            );
+        }
+        self.get_mut()
+            .set_tournament_state(TournamentState::ActiveStage(active_stage + 1));
+        self.save().await
+    }
+    /// Finish the currently loaded tournament, which must run its last stage. All matches
+    /// of the last stage must be decided.
+    pub async fn finish_tournament(&mut self) -> CoreResult<&TournamentBase> {
+        let TournamentState::ActiveStage(active_stage) = self.get().get_tournament_state() else {
+            return Err(self.transition_error("tournament is not running"));
+        };
+        let num_active_stages = self.get().get_tournament_mode().get_num_of_active_stages();
+        if active_stage + 1 < num_active_stages {
+            return Err(self.transition_error(format!(
+                "stage {} of {num_active_stages} must be completed first",
+                active_stage + 1
+            )));
+        }
+        self.get_mut()
+            .set_tournament_state(TournamentState::Finished);
+        self.save().await
+    }
+    /// Check the preconditions of changing the currently loaded tournament from its stored
+    /// state `previous` to `next` with its stored matches: matches of the first stage must
+    /// be seeded on start and matches of the active stage must be decided on completion.
+    pub(super) async fn ensure_transition_preconditions(
+        &self,
+        previous: TournamentState,
+        next: TournamentState,
+    ) -> CoreResult<()> {
+        match (previous, next) {
+            (
+                TournamentState::Draft | TournamentState::Published,
+                TournamentState::ActiveStage(_),
+            ) => {
+                let unseeded = self
+                    .matches_of_stage(0)
+                    .await?
+                    .iter()
+                    .filter(|m| m.get_entrants().is_none() && m.get_bye_entrant().is_none())
+                    .count();
+                if unseeded > 0 {
+                    return Err(self.transition_error(format!(
+                        "{unseeded} matches of the first stage are not seeded"
+                    )));
+                }
+            }
+            (TournamentState::ActiveStage(active_stage), _) if previous != next => {
+                // Swiss rounds are active stages of the one Swiss stage
+                let stage_number = if self.get().get_tournament_mode().get_num_of_stages() == 1 {
+                    0
+                } else {
+                    active_stage
+                };
+                let undecided = self
+                    .matches_of_stage(stage_number)
+                    .await?
+                    .iter()
+                    .filter(|m| !m.is_decided())
+                    .count();
+                if undecided > 0 {
+                    return Err(self.transition_error(format!(
+                        "{undecided} matches of stage {} are not decided",
+                        stage_number + 1
+                    )));
+                }
+            }
+            _ => {}
+        }
+        Ok(())
+    }
+    /// Stored matches of stage `stage_number` of the currently loaded tournament.
+    async fn matches_of_stage(&self, stage_number: u32) -> CoreResult<Vec<Match>> {
+        let Some(stage) = self
+            .database
+            .get_stage_by_number(self.get().get_id(), stage_number)
+            .await?
+        else {
+            return Ok(Vec::new());
+        };
+        Ok(self
+            .database
+            .list_matches_of_stage_for_update(stage.get_id())
+            .await?)
+    }
+    pub(super) fn transition_error(&self, message: impl Into<String>) -> CoreError {
+        CoreError::from(
+            FieldError::builder()
+                .set_field(String::from("state"))
+                .add_message(message)
+                .set_object_id(self.get().get_id())
+                .build(),
+        )
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::TournamentMode;
+
+    #[test]
+    fn given_states_when_can_change_to_then_only_single_forward_steps() {
+        use TournamentState::*;
+        assert!(Draft.can_change_to(Published, 3));
+        assert!(Published.can_change_to(Draft, 3));
+        assert!(Published.can_change_to(ActiveStage(0), 3));
+        assert!(ActiveStage(0).can_change_to(ActiveStage(1), 3));
+        assert!(ActiveStage(2).can_change_to(Finished, 3));
+        assert!(Finished.can_change_to(Finished, 3));
+
+        assert!(!ActiveStage(1).can_change_to(ActiveStage(0), 3));
+        assert!(!ActiveStage(0).can_change_to(Draft, 3));
+        assert!(!ActiveStage(0).can_change_to(Published, 3));
+        assert!(!Finished.can_change_to(ActiveStage(0), 3));
+        assert!(!Finished.can_change_to(Draft, 3));
+
+        // stages are not skipped
+        assert!(!Draft.can_change_to(Finished, 3));
+        assert!(!Published.can_change_to(ActiveStage(1), 3));
+        assert!(!ActiveStage(0).can_change_to(ActiveStage(2), 3));
+        assert!(!ActiveStage(1).can_change_to(ActiveStage(2), 2));
+        assert!(!ActiveStage(0).can_change_to(Finished, 3));
+    }
+
+    #[test]
+    fn given_running_tournament_when_is_stage_started_then_up_to_active_stage() {
+        let mut tournament = TournamentBase::default();
+        assert!(!tournament.is_stage_started(0));
+
+        tournament.set_tournament_state(TournamentState::ActiveStage(1));
+        assert!(tournament.is_stage_started(0));
+        assert!(tournament.is_stage_started(1));
+        assert!(!tournament.is_stage_started(2));
+
+        tournament.set_sandbox(true);
+        assert!(!tournament.is_stage_started(0));
+    }
+
+    #[test]
+    fn given_running_tournament_when_validate_stage_edit_then_per_stage_rules() {
+        use StageEditability::*;
+        let mut tournament = TournamentBase::default();
+        tournament
+            .set_num_entrants(16)
+            .set_tournament_mode(TournamentMode::TwoPoolStagesAndFinalStage)
+            .set_tournament_state(TournamentState::ActiveStage(1));
+        let editability: Vec<_> = (0..3)
+            .map(|n| tournament.get_stage_editability(n))
+            .collect();
+        assert_eq!(editability, vec![Locked, ScheduleOnly, Editable]);
+
+        let mut old = Stage::default();
+        old.set_tournament_id(tournament.get_id()).set_number(1);
+        let mut stage = old;
+        stage.set_max_stations(Some(1)).set_auto_advance(false);
+        assert!(stage.validate_edit(Some(&old), &tournament).is_ok());
+        stage.set_num_groups(2);
+        assert!(stage.validate_edit(Some(&old), &tournament).is_err());
+        assert!(old.validate_edit(None, &tournament).is_err());
+
+        // completed stage is locked, next stage is fully editable
+        for (number, is_ok) in [(0, false), (2, true)] {
+            old.set_number(number);
+            stage.set_number(number);
+            assert_eq!(stage.validate_edit(Some(&old), &tournament).is_ok(), is_ok);
+        }
+    }
+
+    #[test]
+    fn given_running_swiss_tournament_when_get_stage_editability_then_schedule_only() {
+        let mut tournament = TournamentBase::default();
+        tournament
+            .set_tournament_mode(TournamentMode::SwissSystem { num_rounds: 5 })
+            .set_tournament_state(TournamentState::ActiveStage(3));
+        assert_eq!(
+            tournament.get_stage_editability(0),
+            StageEditability::ScheduleOnly
+        );
+    }
+}
@@ -13,8 +13,9 @@
 /// 5 groups, you count trough from top rank to lowest rank from 1 to 5,
 /// in which the number represents the mapped group), or random. When moving
 /// to next stage, current stage rank decides group mapping (see below).
-/// Snake muster anschauen. Eher der Standard international.
-/// ToDo: die number of courts können über das Turnier ggf. variieren.
+/// International standard is snake seeding: the direction of counting through flips after
+/// each row of groups (see [`SeedingStrategy`]).
+/// The number of available stations may vary over the tournament (see [`StationWindow`]).
 /// Knock Out mit Wild Card? Recherchieren...
 /// Ein Turnierort sollte optional sein, für quick and dirty tournaments
 /// Dafür sollten dann auh einfach dummy entrants möglich sein, die einfach per Nummer durchgezählt sind.
@@ -52,11 +53,15 @@
 /// The maximum number of rounds is equal to round robin.
 /// Hier ggf. mit Buffern arbeiten. Nochmal recherchieren.
 /// Double elimination wird durchaus verwendet (z.B. Free Style)
-/// Ring System. Man stellt die Mannschaften in einem Ring auf und spielt gegen die Nachbarn
-/// -> Recherchieren
+/// In ring system the entrants are placed in a ring, usually by seeding, and each entrant
+/// plays a configurable number of next neighbors on each side of the ring. It is an
+/// alternative to full round robin for large single group events: all entrants play the same
+/// number of matches, therefore ranking works like round robin (see [`slots::ring_rounds`]).
 ///
-/// Harte Time caps im Timing ergänzen
-/// Noch nicht gestartete stages sollten auch bei gestarteten Turnier nur bearbeitbar im schedule sein.
+/// Stages may have a hard time cap, by which all their matches must end (see
+/// [`check_time_cap`]).
+/// Stages, which are not started yet, remain editable in a running tournament, while
+/// started stages are structurally fixed (see [`lifecycle`]).
 /// Tie Breaker sollen durch den turnierdirektor konfigurierbar sein.
 ///
 /// The Swiss system can be integrated in the generic tournament structure by using a stage
@@ -67,7 +72,7 @@
 /// after each stage or during KO mode. KO mode is normally used in final stage,
 /// if at all. KO vs KO Play Out: in KO the loser drops out of tournament,
 /// while in KO Play Out the losers match against each other to play out
-/// lower ranking.
+/// lower ranking (see [`play_out`]).
 ///
 /// Ranking is resolved usually by comparing wins, losses, and draws, if applicable.
 /// Normally wins and draws give some amount of victory points (e.g. 1 for wins and
@@ -102,19 +107,53 @@
 /// 4. tournament organization: name, location, stations, officials
 /// For a simple adhoc tournament only parts 1 and 2 are required.
 pub mod base;
+pub mod batch_save;
+pub mod day_dashboard;
+pub mod display_board;
+pub mod duration;
+pub mod export;
+pub mod final_report;
+pub mod lifecycle;
+pub mod planning;
+pub mod play_out;
+pub mod public_view;
+pub mod readiness;
+pub mod schedule;
+pub mod seeding;
+pub mod slots;
 pub mod stage;
+pub mod stage_snapshot;
+pub mod stage_timing;
+pub mod station;
+pub mod template;
 
 pub use base::*;
+pub use batch_save::*;
+pub use day_dashboard::*;
+pub use display_board::*;
+pub use duration::*;
+pub use export::*;
+pub use final_report::*;
+pub use lifecycle::*;
+pub use planning::*;
+pub use play_out::*;
+pub use public_view::*;
+pub use readiness::*;
+pub use schedule::*;
+pub use seeding::*;
 pub use stage::*;
+pub use stage_snapshot::*;
+pub use stage_timing::*;
+pub use station::*;
 
 use crate::{
-    Group,
+    Group, Language, LocalizedText, ScoringOverride, Venue,
     utils::{
-        id_version::IdVersion,
         traits::{Diffable, ObjectIdVersion, ObjectNumber},
         validation::{ValidationErrors, ValidationResult},
     },
 };
+use chrono::{DateTime, Utc};
 use petgraph::{
     Direction,
     graphmap::DiGraphMap,
@@ -202,9 +241,10 @@ pub enum DependencyType {
 /// Conclusion: it is better to leave "orphaned" objects in structure and HashMaps, as long
 /// as you are not creating tournaments with hundreds of stages or groups, which would
 /// consume too much memory.
-#[derive(Clone, Debug, Deserialize, Serialize)]
+#[derive(Clone, Debug, Deserialize, Serialize, ObjectIdVersion)]
 pub struct Tournament {
-    /// base of tournament
+    /// base of tournament; tournament id and version are determined by the tournament base.
+    #[object(id_version)]
     pub base: TournamentBase,
     /// map of tournament dependencies
     pub structure: DiGraphMap<Uuid, DependencyType>,
@@ -214,13 +254,6 @@ pub struct Tournament {
     pub groups: HashMap<Uuid, Group>,
 }
 
-// tournament id and version are determined by the tournament base.
-impl ObjectIdVersion for Tournament {
-    fn get_id_version(&self) -> IdVersion {
-        self.base.get_id_version()
-    }
-}
-
 impl Tournament {
     pub fn new() -> Self {
         Tournament {
@@ -280,12 +313,64 @@ impl Tournament {
     }
 
     /// Sets the number of entrants of the tournament base.
+    /// The number of entrants of running or finished tournaments is fixed.
     pub fn set_base_num_entrants(&mut self, num_entrants: u32) {
+        if self.base.is_locked_by_state() {
+            return;
+        }
         self.base.set_num_entrants(num_entrants);
     }
 
+    /// Sets the number of stations of the tournament base.
+    pub fn set_base_num_stations(&mut self, num_stations: u32) {
+        self.base.set_num_stations(num_stations);
+    }
+
+    /// Sets the named stations of the tournament base.
+    pub fn set_base_stations(&mut self, stations: Vec<Station>) {
+        self.base.set_stations(stations);
+    }
+
+    /// Sets the availability of stations over the tournament of the tournament base.
+    pub fn set_base_station_windows(&mut self, station_windows: Vec<StationWindow>) {
+        self.base.set_station_windows(station_windows);
+    }
+
+    /// Marks the tournament base as sandbox tournament.
+    pub fn set_base_sandbox(&mut self, sandbox: bool) {
+        self.base.set_sandbox(sandbox);
+    }
+
+    /// Sets the display languages of public pages of the tournament base.
+    pub fn set_base_languages(&mut self, languages: Vec<Language>) {
+        self.base.set_languages(languages);
+    }
+
+    /// Sets the description of the tournament base.
+    pub fn set_base_description(&mut self, description: LocalizedText) {
+        self.base.set_description(description);
+    }
+
+    /// Sets the postal address of the tournament location of the tournament base.
+    pub fn set_base_address_id(&mut self, address_id: Option<Uuid>) {
+        self.base.set_address_id(address_id);
+    }
+
+    pub fn set_base_sport_config_id(&mut self, sport_config_id: Option<Uuid>) {
+        self.base.set_sport_config_id(sport_config_id);
+    }
+
+    /// Takes stations and location of the tournament base from `venue`.
+    pub fn apply_base_venue(&mut self, venue: &Venue) {
+        self.base.apply_venue(venue);
+    }
+
     /// Sets the tournament mode of the tournament base.
+    /// The mode of running or finished tournaments is fixed.
     pub fn set_base_mode(&mut self, mode: TournamentMode) {
+        if self.base.is_locked_by_state() {
+            return;
+        }
         self.base.set_tournament_mode(mode);
 
         // Validation: Check if changes invalidate child objects (e.g. Mode change -> fewer stages)
@@ -293,14 +378,27 @@ impl Tournament {
     }
 
     pub fn set_base_num_rounds_swiss_system(&mut self, num_rounds_swiss: u32) {
-        if matches!(
-            self.base.get_tournament_mode(),
-            TournamentMode::SwissSystem { .. }
-        ) {
+        if !self.base.is_locked_by_state()
+            && matches!(
+                self.base.get_tournament_mode(),
+                TournamentMode::SwissSystem { .. }
+            )
+        {
             self.base.set_num_rounds_swiss_system(num_rounds_swiss);
         }
     }
 
+    pub fn set_base_num_neighbors_ring_system(&mut self, num_neighbors_ring: u32) {
+        if !self.base.is_locked_by_state()
+            && matches!(
+                self.base.get_tournament_mode(),
+                TournamentMode::RingSystem { .. }
+            )
+        {
+            self.base.set_num_neighbors_ring_system(num_neighbors_ring);
+        }
+    }
+
     /// Sets a stage to the state and links it to the tournament.
     /// If a stage with the same number but different ID already exists,
     /// it is not replaced and new stage is not added.
@@ -337,10 +435,12 @@ impl Tournament {
         self.stages.remove(&stage_id)
     }
 
-    /// Sets the number of groups for a stage.
-    /// Returns true if stage is not present.
+    /// Sets the number of groups for a stage, which is not started yet.
+    /// Returns true if stage is not present or started.
     pub fn set_stage_number_of_groups(&mut self, stage_id: Uuid, num_groups: u32) -> bool {
-        let Some(stage) = self.stages.get_mut(&stage_id) else {
+        let Some(stage) =
+            self.get_editable_stage_mut(stage_id, StageEditability::is_structure_editable)
+        else {
             return true;
         };
         stage.set_num_groups(num_groups);
@@ -351,6 +451,85 @@ impl Tournament {
         false
     }
 
+    /// Sets the station limit of a stage.
+    /// Returns false if set was successful, true otherwise.
+    pub fn set_stage_max_stations(&mut self, stage_id: Uuid, max_stations: Option<u32>) -> bool {
+        let Some(stage) =
+            self.get_editable_stage_mut(stage_id, StageEditability::is_schedule_editable)
+        else {
+            return true;
+        };
+        stage.set_max_stations(max_stations);
+        false
+    }
+
+    /// Sets the hard time cap of a stage.
+    /// Returns false if set was successful, true otherwise.
+    pub fn set_stage_latest_end(
+        &mut self,
+        stage_id: Uuid,
+        latest_end: Option<DateTime<Utc>>,
+    ) -> bool {
+        let Some(stage) =
+            self.get_editable_stage_mut(stage_id, StageEditability::is_schedule_editable)
+        else {
+            return true;
+        };
+        stage.set_latest_end(latest_end);
+        false
+    }
+
+    /// Sets the auto advance flag of a stage.
+    /// Returns false if set was successful, true otherwise.
+    pub fn set_stage_auto_advance(&mut self, stage_id: Uuid, auto_advance: bool) -> bool {
+        let Some(stage) =
+            self.get_editable_stage_mut(stage_id, StageEditability::is_schedule_editable)
+        else {
+            return true;
+        };
+        stage.set_auto_advance(auto_advance);
+        false
+    }
+
+    /// Sets the scoring policy override of a stage.
+    /// Returns false if set was successful, true otherwise.
+    pub fn set_stage_scoring_override(
+        &mut self,
+        stage_id: Uuid,
+        scoring_override: ScoringOverride,
+    ) -> bool {
+        let Some(stage) =
+            self.get_editable_stage_mut(stage_id, StageEditability::is_structure_editable)
+        else {
+            return true;
+        };
+        stage.set_scoring_override(scoring_override);
+        false
+    }
+
+    /// Restores the structure of `snapshot`, i.e. the tournament mode, the linked stages and
+    /// their number of groups, e.g. to undo structural edits in the editor. IDs and versions
+    /// of objects, which exist in both, are kept, so that the restored objects can be saved
+    /// with optimistic locking.
+    pub fn restore_structure(&mut self, snapshot: &Tournament) {
+        self.base
+            .set_tournament_mode(snapshot.base.get_tournament_mode());
+        self.structure = snapshot.structure.clone();
+        for (id, stage) in &snapshot.stages {
+            match self.stages.get_mut(id) {
+                Some(current) => {
+                    current.set_num_groups(stage.get_num_groups());
+                }
+                None => {
+                    self.stages.insert(*id, *stage);
+                }
+            }
+        }
+        for (id, group) in &snapshot.groups {
+            self.groups.entry(*id).or_insert_with(|| group.clone());
+        }
+    }
+
     // --- Getters for keeping state of new tournament & dependencies ---
     pub fn get_base(&self) -> &TournamentBase {
         &self.base
@@ -580,6 +759,17 @@ impl Tournament {
         bfs.iter(&self.structure).collect()
     }
 
+    /// Returns the stage for editing, if its editability in the current state of the
+    /// tournament allows the edit.
+    fn get_editable_stage_mut(
+        &mut self,
+        stage_id: Uuid,
+        allows_edit: fn(StageEditability) -> bool,
+    ) -> Option<&mut Stage> {
+        let stage = self.stages.get_mut(&stage_id)?;
+        allows_edit(self.base.get_stage_editability(stage.get_number())).then_some(stage)
+    }
+
     /// Checks if the new tournament configuration requires removing stages.
     fn unlink_excess_stages(&mut self) {
         let root_id = self.base.get_id();
@@ -639,6 +829,7 @@ impl Tournament {
 #[cfg(test)]
 mod tests {
     use super::*;
+    use crate::utils::id_version::IdVersion;
 
     #[test]
     fn test_serde_di_graph_map() {
@@ -691,4 +882,115 @@ mod tests {
             assert_eq!(stage, deserialized_stage);
         }
     }
+
+    #[test]
+    fn test_stage_triggers_next_round() {
+        let mut tournament = Tournament::new();
+        tournament.new_base(Uuid::new_v4());
+        tournament.set_base_num_entrants(16);
+        tournament.set_base_mode(TournamentMode::PoolAndFinalStage);
+        tournament.new_stage(0);
+        tournament.new_stage(1);
+
+        let base = tournament.get_base().clone();
+        let pool_stage = *tournament.get_stage_by_number(0).unwrap();
+        let final_stage_id = tournament.get_stage_by_number(1).unwrap().get_id();
+
+        // round robin is scheduled in advance, KO play out depends on results
+        assert!(!pool_stage.triggers_next_round(&base));
+        assert!(
+            tournament
+                .get_stage_by_number(1)
+                .unwrap()
+                .triggers_next_round(&base)
+        );
+
+        tournament.set_stage_auto_advance(final_stage_id, false);
+        assert!(
+            !tournament
+                .get_stage_by_number(1)
+                .unwrap()
+                .triggers_next_round(&base)
+        );
+
+        let swiss = TournamentMode::SwissSystem { num_rounds: 5 };
+        assert!(swiss.has_result_dependent_rounds(0));
+        assert!(!TournamentMode::SingleStage.has_result_dependent_rounds(0));
+        assert!(!TournamentMode::RingSystem { num_neighbors: 2 }.has_result_dependent_rounds(0));
+    }
+
+    #[test]
+    fn test_running_tournament_keeps_structure_of_started_stages() {
+        let mut tournament = Tournament::new();
+        tournament.new_base(Uuid::new_v4());
+        tournament.set_base_num_entrants(16);
+        tournament.set_base_mode(TournamentMode::PoolAndFinalStage);
+        tournament.new_stage(0);
+        tournament.new_stage(1);
+        let pool_stage_id = tournament.get_stage_by_number(0).unwrap().get_id();
+        let final_stage_id = tournament.get_stage_by_number(1).unwrap().get_id();
+        let mut base = tournament.get_base().clone();
+        base.set_tournament_state(TournamentState::ActiveStage(0));
+        tournament.set_base(base);
+
+        tournament.set_base_mode(TournamentMode::SingleStage);
+        tournament.set_base_num_entrants(8);
+        assert_eq!(
+            tournament.get_base().get_tournament_mode(),
+            TournamentMode::PoolAndFinalStage
+        );
+        assert_eq!(tournament.get_base().get_num_entrants(), 16);
+        assert!(tournament.get_stage_by_number(1).is_some());
+
+        // active stage only allows schedule edits, future stages remain editable
+        assert!(tournament.set_stage_number_of_groups(pool_stage_id, 2));
+        assert!(!tournament.set_stage_max_stations(pool_stage_id, Some(1)));
+        assert!(!tournament.set_stage_number_of_groups(final_stage_id, 2));
+        assert_eq!(
+            tournament
+                .get_stage_by_id(pool_stage_id)
+                .unwrap()
+                .get_num_groups(),
+            1
+        );
+        assert_eq!(
+            tournament
+                .get_stage_by_id(final_stage_id)
+                .unwrap()
+                .get_num_groups(),
+            2
+        );
+    }
+
+    #[test]
+    fn test_restore_structure_keeps_versions() {
+        let mut tournament = Tournament::new();
+        tournament.new_base(Uuid::new_v4());
+        tournament.set_base_num_entrants(16);
+        tournament.set_base_mode(TournamentMode::PoolAndFinalStage);
+        tournament.new_stage(0);
+        let pool_stage_id = tournament.get_stage_by_number(0).unwrap().get_id();
+        tournament.set_stage_number_of_groups(pool_stage_id, 4);
+        let snapshot = tournament.clone();
+
+        // structural edits, then the pool stage is saved
+        tournament.set_base_mode(TournamentMode::TwoPoolStagesAndFinalStage);
+        tournament.new_stage(2);
+        tournament.set_stage_number_of_groups(pool_stage_id, 2);
+        let mut saved = *tournament.get_stage_by_id(pool_stage_id).unwrap();
+        saved.set_id_version(IdVersion::new(pool_stage_id, Some(3)));
+        tournament.set_stage(saved);
+
+        tournament.restore_structure(&snapshot);
+
+        assert_eq!(
+            tournament.get_base().get_tournament_mode(),
+            TournamentMode::PoolAndFinalStage
+        );
+        assert!(tournament.get_stage_by_number(2).is_none());
+        let pool_stage = tournament.get_stage_by_number(0).unwrap();
+        assert_eq!(pool_stage.get_num_groups(), 4);
+        assert_eq!(pool_stage.get_version(), Some(3));
+        assert_eq!(tournament.collect_stages_diff(&snapshot), vec![*pool_stage]);
+    }
 }
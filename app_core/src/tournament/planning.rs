@@ -0,0 +1,250 @@
+//! planning of new tournaments
+//!
+//! Before setting up a tournament directors know the number of entrants and stations, the
+//! available time and the sport, which gives an estimated match duration. [`suggest_plans`]
+//! proposes modes and group sizes, in which each entrant plays at least a minimum number of
+//! matches, and estimates their duration.
+//!
+//! Durations are estimated by time slots of one match: a stage needs at least one slot per
+//! round and enough slots to play all matches on the available stations.
+
+use super::{
+    TournamentMode,
+    slots::{group_sizes, is_ko_size, ring_rounds},
+};
+use chrono::Duration;
+use serde::{Deserialize, Serialize};
+
+/// key figures of a tournament to plan
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+pub struct PlanningRequest {
+    pub num_entrants: u32,
+    pub num_stations: u32,
+    /// available time for all matches of the tournament
+    pub available_time: Duration,
+    /// estimated duration of a match, e.g. by the sport plugin
+    pub match_duration: Duration,
+    /// minimum number of matches of each entrant
+    pub min_matches: u32,
+}
+
+/// proposed mode and group sizes of a tournament
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct PlanSuggestion {
+    pub mode: TournamentMode,
+    /// sizes of the groups of each stage
+    pub group_sizes: Vec<Vec<u32>>,
+    /// number of matches, which each entrant plays at least
+    pub min_matches_per_entrant: u32,
+    /// number of matches of the tournament
+    pub num_matches: u32,
+    pub estimated_duration: Duration,
+    pub fits_available_time: bool,
+}
+
+/// key figures of a planned stage
+#[derive(Debug, Clone)]
+struct StagePlan {
+    group_sizes: Vec<u32>,
+    min_matches: u32,
+    num_matches: u32,
+    num_rounds: u32,
+}
+
+impl StagePlan {
+    /// all groups play round robin
+    fn round_robin(num_entrants: u32, num_groups: u32) -> Self {
+        let group_sizes = group_sizes(num_entrants, num_groups);
+        StagePlan {
+            min_matches: group_sizes
+                .iter()
+                .map(|s| s.saturating_sub(1))
+                .min()
+                .unwrap_or(0),
+            num_matches: group_sizes
+                .iter()
+                .map(|s| s * s.saturating_sub(1) / 2)
+                .sum(),
+            // with an odd group size one entrant pauses each round
+            num_rounds: group_sizes.iter().map(|s| s - 1 + s % 2).max().unwrap_or(0),
+            group_sizes,
+        }
+    }
+
+    /// final stage in groups of up to four entrants: groups of KO size play KO, in which each
+    /// entrant plays at least one match, the other groups play round robin
+    fn final_stage(num_entrants: u32) -> Self {
+        let group_sizes = group_sizes(num_entrants, num_entrants.div_ceil(4));
+        let per_group = group_sizes.iter().map(|&s| {
+            if is_ko_size(s) {
+                // (min matches, matches, rounds)
+                (1, s - 1, s.ilog2())
+            } else {
+                (
+                    s.saturating_sub(1),
+                    s * s.saturating_sub(1) / 2,
+                    s - 1 + s % 2,
+                )
+            }
+        });
+        StagePlan {
+            min_matches: per_group.clone().map(|g| g.0).min().unwrap_or(0),
+            num_matches: per_group.clone().map(|g| g.1).sum(),
+            num_rounds: per_group.map(|g| g.2).max().unwrap_or(0),
+            group_sizes,
+        }
+    }
+
+    fn duration(&self, request: &PlanningRequest) -> Duration {
+        let num_slots = self
+            .num_matches
+            .div_ceil(request.num_stations)
+            .max(self.num_rounds);
+        request.match_duration * num_slots as i32
+    }
+}
+
+impl PlanSuggestion {
+    fn new(mode: TournamentMode, stages: Vec<StagePlan>, request: &PlanningRequest) -> Self {
+        let estimated_duration = stages
+            .iter()
+            .map(|s| s.duration(request))
+            .fold(Duration::zero(), |total, d| total + d);
+        PlanSuggestion {
+            mode,
+            min_matches_per_entrant: stages.iter().map(|s| s.min_matches).sum(),
+            num_matches: stages.iter().map(|s| s.num_matches).sum(),
+            group_sizes: stages.into_iter().map(|s| s.group_sizes).collect(),
+            estimated_duration,
+            fits_available_time: estimated_duration <= request.available_time,
+        }
+    }
+}
+
+/// Propose one plan per tournament mode, in which each entrant plays at least
+/// `request.min_matches` matches. Pool stages use the number of groups with the shortest
+/// duration. Plans, which fit the available time, come first, each sorted by duration.
+pub fn suggest_plans(request: &PlanningRequest) -> Vec<PlanSuggestion> {
+    let n = request.num_entrants;
+    if n < 2 || request.num_stations == 0 {
+        return Vec::new();
+    }
+    let mut suggestions = vec![PlanSuggestion::new(
+        TournamentMode::SingleStage,
+        vec![StagePlan::round_robin(n, 1)],
+        request,
+    )];
+
+    // with an odd number of entrants each round one entrant gets a free win
+    let num_rounds = request.min_matches.max(1) + n % 2;
+    if num_rounds < n - 1 {
+        suggestions.push(PlanSuggestion::new(
+            TournamentMode::SwissSystem { num_rounds },
+            vec![StagePlan {
+                group_sizes: vec![n],
+                min_matches: num_rounds - n % 2,
+                num_matches: num_rounds * (n / 2),
+                num_rounds,
+            }],
+            request,
+        ));
+    }
+
+    let num_neighbors = request.min_matches.div_ceil(2).max(1);
+    let rounds = ring_rounds(n, num_neighbors);
+    if !rounds.is_empty() {
+        suggestions.push(PlanSuggestion::new(
+            TournamentMode::RingSystem { num_neighbors },
+            vec![StagePlan {
+                group_sizes: vec![n],
+                min_matches: 2 * num_neighbors,
+                num_matches: n * num_neighbors,
+                num_rounds: rounds.len() as u32,
+            }],
+            request,
+        ));
+    }
+
+    // pool groups with at least 3 entrants
+    for (mode, num_pool_stages) in [
+        (TournamentMode::PoolAndFinalStage, 1),
+        (TournamentMode::TwoPoolStagesAndFinalStage, 2),
+    ] {
+        let best = (2..=n / 3)
+            .map(|num_groups| {
+                let mut stages = vec![StagePlan::round_robin(n, num_groups); num_pool_stages];
+                stages.push(StagePlan::final_stage(n));
+                PlanSuggestion::new(mode, stages, request)
+            })
+            .filter(|s| s.min_matches_per_entrant >= request.min_matches)
+            .min_by_key(|s| s.estimated_duration);
+        suggestions.extend(best);
+    }
+
+    suggestions.retain(|s| s.min_matches_per_entrant >= request.min_matches);
+    suggestions.sort_by_key(|s| (!s.fits_available_time, s.estimated_duration));
+    suggestions
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn request(num_entrants: u32, min_matches: u32) -> PlanningRequest {
+        PlanningRequest {
+            num_entrants,
+            num_stations: 4,
+            available_time: Duration::hours(6),
+            match_duration: Duration::minutes(20),
+            min_matches,
+        }
+    }
+
+    #[test]
+    fn given_many_entrants_when_suggest_plans_then_round_robin_does_not_fit() {
+        let suggestions = suggest_plans(&request(24, 5));
+
+        assert!(suggestions.iter().all(|s| s.min_matches_per_entrant >= 5));
+        // 276 matches on 4 stations take 69 slots of 20 minutes
+        let single = suggestions
+            .iter()
+            .find(|s| s.mode == TournamentMode::SingleStage)
+            .unwrap();
+        assert_eq!(single.num_matches, 276);
+        assert_eq!(single.estimated_duration, Duration::minutes(69 * 20));
+        assert!(!single.fits_available_time);
+        assert_eq!(
+            suggestions.last().unwrap().mode,
+            TournamentMode::SingleStage
+        );
+
+        // 5 rounds of 12 matches on 4 stations take 15 slots
+        let swiss = suggestions
+            .iter()
+            .find(|s| s.mode == TournamentMode::SwissSystem { num_rounds: 5 })
+            .unwrap();
+        assert_eq!(swiss.estimated_duration, Duration::minutes(15 * 20));
+        assert!(swiss.fits_available_time);
+        assert!(
+            suggestions
+                .iter()
+                .any(|s| s.mode == TournamentMode::RingSystem { num_neighbors: 3 })
+        );
+
+        // pools of 6 entrants play 5 matches, plus at least one match in the final stage
+        let pools = suggestions
+            .iter()
+            .find(|s| s.mode == TournamentMode::PoolAndFinalStage)
+            .unwrap();
+        assert_eq!(pools.group_sizes[0], vec![6, 6, 6, 6]);
+        assert_eq!(pools.min_matches_per_entrant, 6);
+    }
+
+    #[test]
+    fn given_few_entrants_when_suggest_plans_then_only_round_robin() {
+        let suggestions = suggest_plans(&request(5, 4));
+        assert_eq!(suggestions.len(), 1);
+        assert_eq!(suggestions[0].mode, TournamentMode::SingleStage);
+        assert!(suggest_plans(&request(5, 5)).is_empty());
+    }
+}
@@ -0,0 +1,219 @@
+//! matches of KO play out
+//!
+//! In KO play out losers are not eliminated, but continue in placement brackets, so that
+//! every final place of a group is decided by a match (see [`ko_play_out`]). Before the
+//! stage starts, only the slots of the group are known. [`PlayOutGroup::matches`] creates
+//! all matches of the play out with sides depending on the ranks of the previous stage and
+//! on the results of previous matches. Core resolves these sides as results are entered (see
+//! [`MatchDependencyGraph`](crate::MatchDependencyGraph)); rounds are placed one after
+//! another by [`schedule_rounds`](super::schedule_rounds).
+
+use super::{
+    MatchSlot, Stage, TournamentBase, schedule_rounds,
+    slots::{KoSide, ko_play_out},
+};
+use crate::{Match, MatchWinner, ScheduledEntrant, SportConfig, SportPort, SportResult};
+use chrono::{DateTime, Duration, Utc};
+use std::collections::HashMap;
+use uuid::Uuid;
+
+/// group of a stage, whose entrants play out all places
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct PlayOutGroup {
+    pub tournament_id: Uuid,
+    pub sport_id: Uuid,
+    pub stage_id: Uuid,
+    pub group_id: Uuid,
+    /// number of group in its stage, starting with 0
+    pub group_number: u32,
+}
+
+/// match of the last round of a play out and the places it decides
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct PlayOutPlacement {
+    pub match_id: Uuid,
+    /// final place of winner, starting with 1
+    pub winner_place: u32,
+    /// final place of loser
+    pub loser_place: u32,
+}
+
+/// matches of a KO play out
+#[derive(Debug, Clone)]
+pub struct PlayOutMatches {
+    /// matches sorted by round and bracket
+    pub matches: Vec<Match>,
+    /// number of matches of each round
+    pub round_sizes: Vec<usize>,
+    /// placements sorted by place
+    pub placements: Vec<PlayOutPlacement>,
+}
+
+impl PlayOutGroup {
+    /// Create the matches of the KO play out of the group, whose slots `1..=n` are filled by
+    /// `slots`, e.g. by [`ScheduledEntrant::StageRank`] of the previous stage. Returns `None`,
+    /// if the number of slots is no KO size.
+    pub fn matches(&self, slots: &[ScheduledEntrant]) -> Option<PlayOutMatches> {
+        let play_out = ko_play_out(slots.len() as u32);
+        if play_out.rounds.is_empty() {
+            return None;
+        }
+        let mut ids: HashMap<u32, Uuid> = HashMap::new();
+        let mut matches = Vec::new();
+        let mut round_sizes = Vec::new();
+        for round in &play_out.rounds {
+            let round_id = Uuid::new_v4();
+            for (ko_match, number) in round.matches.iter().zip(1..) {
+                let side = |side: KoSide| match side {
+                    KoSide::Slot(slot) => slots[slot as usize - 1].clone(),
+                    KoSide::WinnerOf(number) => ScheduledEntrant::WinnerOf(ids[&number]),
+                    KoSide::LoserOf(number) => ScheduledEntrant::LoserOf(ids[&number]),
+                };
+                let mut m = Match::new_scheduled(
+                    Uuid::new_v4(),
+                    self.group_id,
+                    round_id,
+                    number,
+                    side(ko_match.side_a),
+                    side(ko_match.side_b),
+                );
+                m.set_tournament(self.tournament_id, self.sport_id, self.stage_id)
+                    .set_group_number(self.group_number);
+                ids.insert(ko_match.number, *m.get_id());
+                matches.push(m);
+            }
+            round_sizes.push(round.matches.len());
+        }
+        let placements = play_out
+            .placements
+            .iter()
+            .map(|p| PlayOutPlacement {
+                match_id: ids[&p.number],
+                winner_place: p.winner_place,
+                loser_place: p.loser_place,
+            })
+            .collect();
+        Some(PlayOutMatches {
+            matches,
+            round_sizes,
+            placements,
+        })
+    }
+}
+
+impl PlayOutMatches {
+    /// Place the rounds of the play out one after another, see [`schedule_rounds`].
+    pub fn schedule(
+        &self,
+        tournament: &TournamentBase,
+        stage: &Stage,
+        start_at: DateTime<Utc>,
+        slot_duration: Duration,
+    ) -> Vec<MatchSlot> {
+        schedule_rounds(
+            tournament,
+            stage,
+            start_at,
+            slot_duration,
+            &self.round_sizes,
+        )
+    }
+
+    /// Final places of the play out by the results of its last round: index `i` holds the
+    /// entrant of place `i + 1`; places of undecided matches are `None`.
+    pub fn final_places(
+        &self,
+        plugin: &dyn SportPort,
+        config: &SportConfig,
+    ) -> SportResult<Vec<Option<Uuid>>> {
+        let mut places = vec![None; 2 * self.placements.len()];
+        for placement in &self.placements {
+            let Some(m) = self
+                .matches
+                .iter()
+                .find(|m| *m.get_id() == placement.match_id)
+            else {
+                continue;
+            };
+            if let Some(result) = MatchWinner::of_match(plugin, config, m)? {
+                places[placement.winner_place as usize - 1] = Some(result.winner);
+                places[placement.loser_place as usize - 1] = result.loser;
+            }
+        }
+        Ok(places)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::MatchDependencyGraph;
+
+    fn group() -> PlayOutGroup {
+        PlayOutGroup {
+            tournament_id: Uuid::new_v4(),
+            sport_id: Uuid::new_v4(),
+            stage_id: Uuid::new_v4(),
+            group_id: Uuid::new_v4(),
+            group_number: 1,
+        }
+    }
+
+    fn slots(previous_stage_id: Uuid, size: usize) -> Vec<ScheduledEntrant> {
+        (0..size)
+            .map(|index| ScheduledEntrant::StageRank(previous_stage_id, index))
+            .collect()
+    }
+
+    #[test]
+    fn given_eight_slots_when_create_matches_then_full_placement_tree() {
+        let group = group();
+        let previous_stage_id = Uuid::new_v4();
+
+        let play_out = group
+            .matches(&slots(previous_stage_id, 8))
+            .expect("KO size");
+
+        assert_eq!(play_out.round_sizes, vec![4, 4, 4]);
+        assert_eq!(play_out.matches.len(), 12);
+        assert_eq!(play_out.placements.len(), 4);
+        assert!(play_out.matches.iter().all(|m| {
+            *m.get_group_id() == group.group_id
+                && m.get_group_number() == group.group_number
+                && *m.get_tournament_id() == group.tournament_id
+        }));
+        assert_eq!(
+            play_out.matches[0].get_sides(),
+            (
+                &ScheduledEntrant::StageRank(previous_stage_id, 0),
+                &ScheduledEntrant::StageRank(previous_stage_id, 7)
+            )
+        );
+        // matches of a round share their round
+        assert_eq!(
+            play_out.matches[0].get_round_id(),
+            play_out.matches[3].get_round_id()
+        );
+        assert_ne!(
+            play_out.matches[0].get_round_id(),
+            play_out.matches[4].get_round_id()
+        );
+        assert_eq!(play_out.matches[4].get_number(), 1);
+
+        // winner and loser of every match before the last round play on
+        let graph = MatchDependencyGraph::new(&play_out.matches);
+        assert!(graph.is_acyclic());
+        for m in &play_out.matches[..8] {
+            assert_eq!(graph.dependents(*m.get_id()).len(), 2);
+        }
+        for m in &play_out.matches[8..] {
+            assert!(graph.dependents(*m.get_id()).is_empty());
+        }
+    }
+
+    #[test]
+    fn given_no_ko_size_when_create_matches_then_none() {
+        assert!(group().matches(&slots(Uuid::new_v4(), 6)).is_none());
+        assert!(group().matches(&[]).is_none());
+    }
+}
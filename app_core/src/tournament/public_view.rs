@@ -0,0 +1,218 @@
+//! read-only view of a tournament for spectators
+
+use super::{
+    Stage, TournamentBase, TournamentBaseCondition, TournamentBaseSortColumn, TournamentMode,
+    TournamentState,
+    slots::{KoRound, group_label, group_sizes, is_ko_size, ko_bracket},
+};
+use crate::{
+    Core, CoreResult, Entrant, GroupStanding, Match, stage_group_ids,
+    utils::{
+        filter::Filter,
+        list_order::{ListOrder, SortDirection},
+    },
+};
+use serde::{Deserialize, Serialize};
+use uuid::Uuid;
+
+/// Snapshot of a tournament, which may be shown to the public.
+///
+/// The view contains only data, which is visible to spectators anyway: tournament base,
+/// slot layout of stages and entrants. Matches are not persisted yet; they will be added
+/// with the scheduling of matches.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct PublicTournamentView {
+    pub tournament: TournamentBase,
+    /// stages sorted by stage number
+    pub stages: Vec<PublicStage>,
+    /// entrants sorted by seed and name; email addresses are removed
+    pub entrants: Vec<Entrant>,
+}
+
+/// stage of a public tournament view
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct PublicStage {
+    pub id: Uuid,
+    pub number: u32,
+    /// name of stage depending on tournament mode, e.g. `Final Stage`
+    pub name: String,
+    pub groups: Vec<PublicGroup>,
+}
+
+/// group of a public stage
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct PublicGroup {
+    /// e.g. `B`; slots of group are `B1`, `B2`, ...
+    pub label: String,
+    pub num_entrants: u32,
+    /// KO bracket, if group plays out the final stage in KO mode; empty otherwise
+    pub bracket: Vec<KoRound>,
+}
+
+/// current standings of a group of a public stage
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct PublicGroupStandings {
+    pub stage_id: Uuid,
+    pub stage_number: u32,
+    pub group_label: String,
+    /// standings sorted by rank; empty, if no match of the group is decided yet
+    pub standings: Vec<GroupStanding>,
+}
+
+impl PublicStage {
+    pub(crate) fn new(tournament: &TournamentBase, stage: &Stage) -> Self {
+        let mode = tournament.get_tournament_mode();
+        let num_stages = mode.get_num_of_stages();
+        let is_final_stage = num_stages > 1 && stage.get_number() + 1 == num_stages;
+        let is_swiss = matches!(mode, TournamentMode::SwissSystem { .. });
+        let groups = group_sizes(tournament.get_num_entrants(), stage.get_num_groups())
+            .into_iter()
+            .enumerate()
+            .map(|(index, size)| PublicGroup {
+                label: group_label(index),
+                num_entrants: size,
+                bracket: if is_final_stage && !is_swiss && is_ko_size(size) {
+                    ko_bracket(size)
+                } else {
+                    Vec::new()
+                },
+            })
+            .collect();
+        PublicStage {
+            id: stage.get_id(),
+            number: stage.get_number(),
+            name: mode
+                .get_stage_name(stage.get_number())
+                .unwrap_or_else(|| format!("Stage {}", stage.get_number() + 1)),
+            groups,
+        }
+    }
+}
+
+impl TournamentBase {
+    /// Check if the tournament may be shown to the public, i.e. if it is neither a draft
+    /// nor a sandbox tournament.
+    pub fn is_public(&self) -> bool {
+        self.get_tournament_state() != TournamentState::Draft && !self.is_sandbox()
+    }
+}
+
+impl<S> Core<S> {
+    /// Load the public view of the tournament with id `tournament_id`.
+    /// Returns `None`, if no tournament with `tournament_id` exists, if it is still a draft
+    /// or if it is a sandbox tournament, since neither must be visible to the public.
+    pub async fn load_public_tournament(
+        &self,
+        tournament_id: Uuid,
+    ) -> CoreResult<Option<PublicTournamentView>> {
+        let mut base_core = self.as_tournament_base_state();
+        let Some(tournament) = base_core.load(tournament_id).await?.cloned() else {
+            return Ok(None);
+        };
+        if !tournament.is_public() {
+            return Ok(None);
+        }
+
+        let mut stage_core = self.as_stage_state(tournament_id);
+        let mut stages = Vec::new();
+        for (stage_id, _number) in stage_core.list_stage_ids_of_tournament().await? {
+            if let Some(stage) = stage_core.load_by_id(stage_id).await? {
+                stages.push(PublicStage::new(&tournament, stage));
+            }
+        }
+        stages.sort_by_key(|s| s.number);
+
+        let mut entrants = self.as_entrant_state(tournament_id).list_entrants().await?;
+        for entrant in entrants.iter_mut() {
+            entrant.set_email(None::<String>);
+        }
+
+        Ok(Some(PublicTournamentView {
+            tournament,
+            stages,
+            entrants,
+        }))
+    }
+
+    /// List public tournaments, i.e. neither drafts nor sandbox tournaments, newest first.
+    /// `offset` tournaments are skipped, at most `limit` are returned.
+    pub async fn list_public_tournaments(
+        &self,
+        limit: Option<usize>,
+        offset: usize,
+    ) -> CoreResult<Vec<TournamentBase>> {
+        let filter = Filter::<TournamentBase>::new()
+            .with(TournamentBaseCondition::NotDraft)
+            .with(TournamentBaseCondition::NotSandbox)
+            .limit(limit);
+        let order = ListOrder::<TournamentBase>::new()
+            .sort_by(
+                TournamentBaseSortColumn::CreatedAt,
+                SortDirection::Descending,
+            )
+            .offset(offset);
+        let mut tournaments = Vec::new();
+        for id in self
+            .database
+            .list_tournament_base_ids(&filter, &order)
+            .await?
+        {
+            if let Some(tournament) = self.database.get_tournament_base(id).await? {
+                tournaments.push(tournament);
+            }
+        }
+        Ok(tournaments)
+    }
+
+    /// Load the matches of the public tournament with id `tournament_id`. Returns `None`,
+    /// if the tournament is not public, see [`Core::load_public_tournament`].
+    pub async fn load_public_matches(&self, tournament_id: Uuid) -> CoreResult<Option<Vec<Match>>> {
+        if self.load_public_tournament(tournament_id).await?.is_none() {
+            return Ok(None);
+        }
+        Ok(Some(
+            self.database
+                .list_matches_of_tournament(tournament_id)
+                .await?,
+        ))
+    }
+
+    /// Load the current standings of all groups of the public tournament with id
+    /// `tournament_id` in order of stage and group. Returns `None`, if the tournament is
+    /// not public, see [`Core::load_public_tournament`].
+    pub async fn load_public_standings(
+        &self,
+        tournament_id: Uuid,
+    ) -> CoreResult<Option<Vec<PublicGroupStandings>>> {
+        let Some(view) = self.load_public_tournament(tournament_id).await? else {
+            return Ok(None);
+        };
+        let matches = self
+            .database
+            .list_matches_of_tournament(tournament_id)
+            .await?;
+        let mut standings = Vec::new();
+        for stage in view.stages.iter() {
+            let stage_matches: Vec<Match> = matches
+                .iter()
+                .filter(|m| *m.get_stage_id() == stage.id)
+                .cloned()
+                .collect();
+            let group_ids = stage_group_ids(&stage_matches);
+            for (index, group) in stage.groups.iter().enumerate() {
+                let group_standings = match group_ids.get(index) {
+                    Some(group_id) => self.get_group_standings(tournament_id, *group_id).await?,
+                    // no match of group is scheduled yet
+                    None => Vec::new(),
+                };
+                standings.push(PublicGroupStandings {
+                    stage_id: stage.id,
+                    stage_number: stage.number,
+                    group_label: group.label.clone(),
+                    standings: group_standings,
+                });
+            }
+        }
+        Ok(Some(standings))
+    }
+}
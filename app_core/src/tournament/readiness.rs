@@ -0,0 +1,372 @@
+//! readiness checklist of a tournament
+//!
+//! A tournament may only be published or started, if all items of its checklist pass. Items,
+//! which cannot be checked yet, are reported as unavailable and do not block transitions.
+
+use super::{TournamentBase, TournamentState};
+use crate::{
+    Core, CoreError, CoreResult, MatchOutcome,
+    utils::validation::{FieldError, ValidationErrors},
+};
+use serde::{Deserialize, Serialize};
+use std::fmt::{self, Display};
+use uuid::Uuid;
+
+/// item of the readiness checklist
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Serialize, Deserialize)]
+pub enum ReadinessCheck {
+    /// at least one valid configuration of the sport of the tournament exists
+    SportConfig,
+    /// all stages of the tournament mode exist with a valid number of groups
+    Stages,
+    /// matches of the first stage are scheduled; matches of later stages may depend on
+    /// results and are scheduled while the tournament runs
+    Schedule,
+    /// all scheduled matches except byes are assigned to stations of the tournament
+    Stations,
+    /// at least the configured number of entrants is registered
+    Entrants,
+}
+
+impl ReadinessCheck {
+    /// all checks in order of the checklist
+    pub const ALL: [ReadinessCheck; 5] = [
+        ReadinessCheck::SportConfig,
+        ReadinessCheck::Stages,
+        ReadinessCheck::Schedule,
+        ReadinessCheck::Stations,
+        ReadinessCheck::Entrants,
+    ];
+}
+
+impl Display for ReadinessCheck {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            ReadinessCheck::SportConfig => write!(f, "Sport configuration"),
+            ReadinessCheck::Stages => write!(f, "Stages and groups"),
+            ReadinessCheck::Schedule => write!(f, "Schedule"),
+            ReadinessCheck::Stations => write!(f, "Stations"),
+            ReadinessCheck::Entrants => write!(f, "Entrants"),
+        }
+    }
+}
+
+/// result of a readiness check
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+pub enum ReadinessStatus {
+    Passed,
+    Failed,
+    /// check is not supported yet and does not block transitions
+    Unavailable,
+}
+
+/// evaluated item of a [`ReadinessChecklist`]
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct ReadinessItem {
+    pub check: ReadinessCheck,
+    pub status: ReadinessStatus,
+    /// what passed or what must be fixed
+    pub message: String,
+    /// number of first stage, which must be fixed; only set for failed stage checks
+    pub stage_number: Option<u32>,
+}
+
+impl ReadinessItem {
+    fn new(check: ReadinessCheck, status: ReadinessStatus, message: impl Into<String>) -> Self {
+        ReadinessItem {
+            check,
+            status,
+            message: message.into(),
+            stage_number: None,
+        }
+    }
+
+    fn passed(check: ReadinessCheck, message: impl Into<String>) -> Self {
+        Self::new(check, ReadinessStatus::Passed, message)
+    }
+
+    fn failed(check: ReadinessCheck, message: impl Into<String>) -> Self {
+        Self::new(check, ReadinessStatus::Failed, message)
+    }
+
+    pub fn is_failed(&self) -> bool {
+        self.status == ReadinessStatus::Failed
+    }
+}
+
+/// readiness checklist of a tournament, see [`Core::evaluate_readiness`]
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct ReadinessChecklist {
+    pub tournament_id: Uuid,
+    /// items in order of [`ReadinessCheck::ALL`]
+    pub items: Vec<ReadinessItem>,
+}
+
+impl ReadinessChecklist {
+    /// Check if no item failed.
+    pub fn is_ready(&self) -> bool {
+        !self.items.iter().any(|i| i.is_failed())
+    }
+
+    /// Get the item of `check`.
+    pub fn get(&self, check: ReadinessCheck) -> Option<&ReadinessItem> {
+        self.items.iter().find(|i| i.check == check)
+    }
+
+    /// Convert failed items into validation errors of the tournament state.
+    pub fn to_validation_errors(&self) -> ValidationErrors {
+        let mut errs = ValidationErrors::new();
+        for item in self.items.iter().filter(|i| i.is_failed()) {
+            errs.add(
+                FieldError::builder()
+                    .set_field(String::from("state"))
+                    .add_message(format!("{}: {}", item.check, item.message))
+                    .set_object_id(self.tournament_id)
+                    .build(),
+            );
+        }
+        errs
+    }
+}
+
+/// Check if changing the state of a tournament from `previous` to `next` requires a passed
+/// readiness checklist. `previous` is `None` for new tournaments.
+pub fn is_gated_transition(previous: Option<TournamentState>, next: TournamentState) -> bool {
+    match next {
+        TournamentState::Published => previous != Some(TournamentState::Published),
+        TournamentState::ActiveStage(_) => matches!(
+            previous,
+            None | Some(TournamentState::Draft) | Some(TournamentState::Published)
+        ),
+        TournamentState::Draft | TournamentState::Finished => false,
+    }
+}
+
+impl<S> Core<S> {
+    /// Load the tournament and evaluate its readiness checklist.
+    pub async fn load_readiness(
+        &self,
+        tournament_id: Uuid,
+    ) -> CoreResult<Option<ReadinessChecklist>> {
+        let Some(tournament) = self.database.get_tournament_base(tournament_id).await? else {
+            return Ok(None);
+        };
+        Ok(Some(self.evaluate_readiness(&tournament).await?))
+    }
+
+    /// Evaluate the readiness checklist of `tournament`. Stages, matches, entrants and sport
+    /// configurations are read from database.
+    pub async fn evaluate_readiness(
+        &self,
+        tournament: &TournamentBase,
+    ) -> CoreResult<ReadinessChecklist> {
+        let items = vec![
+            self.check_sport_config(tournament).await?,
+            self.check_stages(tournament).await?,
+            self.check_schedule(tournament).await?,
+            self.check_stations(tournament).await?,
+            self.check_entrants(tournament).await?,
+        ];
+        Ok(ReadinessChecklist {
+            tournament_id: tournament.get_id(),
+            items,
+        })
+    }
+
+    /// Return an error listing all failed items, if `tournament` is not ready.
+    pub async fn ensure_ready(&self, tournament: &TournamentBase) -> CoreResult<()> {
+        let checklist = self.evaluate_readiness(tournament).await?;
+        if checklist.is_ready() {
+            Ok(())
+        } else {
+            Err(CoreError::from(checklist.to_validation_errors()))
+        }
+    }
+
+    async fn check_sport_config(&self, tournament: &TournamentBase) -> CoreResult<ReadinessItem> {
+        let check = ReadinessCheck::SportConfig;
+        let sport_id = tournament.get_sport_id();
+        let Some(sport_plugin) = self.sport_plugins.get(&sport_id) else {
+            return Ok(ReadinessItem::failed(
+                check,
+                "Sport of tournament is unknown",
+            ));
+        };
+        let Some(config_id) = tournament.get_sport_config_id() else {
+            return Ok(ReadinessItem::failed(
+                check,
+                "Choose a sport configuration for the tournament",
+            ));
+        };
+        let Some(mut config) = self.database.get_sport_config(config_id).await? else {
+            return Ok(ReadinessItem::failed(
+                check,
+                "Sport configuration of the tournament does not exist",
+            ));
+        };
+        if config.migrate(sport_plugin.as_ref()).is_ok()
+            && config.validate(sport_plugin.clone()).is_ok()
+        {
+            Ok(ReadinessItem::passed(
+                check,
+                format!("{} is valid", config.get_name()),
+            ))
+        } else {
+            Ok(ReadinessItem::failed(
+                check,
+                format!("{} is not valid", config.get_name()),
+            ))
+        }
+    }
+
+    async fn check_stages(&self, tournament: &TournamentBase) -> CoreResult<ReadinessItem> {
+        let check = ReadinessCheck::Stages;
+        let num_stages = tournament.get_tournament_mode().get_num_of_stages();
+        for number in 0..num_stages {
+            let name = tournament
+                .get_tournament_mode()
+                .get_stage_name(number)
+                .unwrap_or_else(|| format!("Stage {}", number + 1));
+            let message = match self
+                .database
+                .get_stage_by_number(tournament.get_id(), number)
+                .await?
+            {
+                None => format!("{name} is not configured"),
+                Some(stage) => match stage.validate(tournament) {
+                    Ok(()) => continue,
+                    Err(_) => format!("{name} has an invalid number of groups"),
+                },
+            };
+            let mut item = ReadinessItem::failed(check, message);
+            item.stage_number = Some(number);
+            return Ok(item);
+        }
+        Ok(ReadinessItem::passed(
+            check,
+            format!("{num_stages} of {num_stages} stages configured"),
+        ))
+    }
+
+    async fn check_schedule(&self, tournament: &TournamentBase) -> CoreResult<ReadinessItem> {
+        let check = ReadinessCheck::Schedule;
+        let name = tournament
+            .get_tournament_mode()
+            .get_stage_name(0)
+            .unwrap_or_else(|| String::from("Stage 1"));
+        let num_matches = match self
+            .database
+            .get_stage_by_number(tournament.get_id(), 0)
+            .await?
+        {
+            Some(stage) => self
+                .database
+                .list_matches_of_stage(stage.get_id())
+                .await?
+                .len(),
+            None => 0,
+        };
+        if num_matches == 0 {
+            let mut item = ReadinessItem::failed(check, format!("{name} has no scheduled matches"));
+            item.stage_number = Some(0);
+            return Ok(item);
+        }
+        Ok(ReadinessItem::passed(
+            check,
+            format!("{num_matches} matches of {name} scheduled"),
+        ))
+    }
+
+    async fn check_stations(&self, tournament: &TournamentBase) -> CoreResult<ReadinessItem> {
+        let check = ReadinessCheck::Stations;
+        let matches = self
+            .database
+            .list_matches_of_tournament(tournament.get_id())
+            .await?;
+        if matches.is_empty() {
+            return Ok(ReadinessItem::failed(check, "No matches are scheduled"));
+        }
+        let num_stations = tournament.get_num_stations();
+        let (mut unassigned, mut unknown, mut assigned) = (0, 0, 0);
+        for m in matches
+            .iter()
+            .filter(|m| m.get_outcome() != MatchOutcome::Bye)
+        {
+            match m.get_station() as u32 {
+                0 => unassigned += 1,
+                station if station > num_stations => unknown += 1,
+                _ => assigned += 1,
+            }
+        }
+        Ok(if unassigned > 0 {
+            ReadinessItem::failed(
+                check,
+                format!("{unassigned} matches are not assigned to a station"),
+            )
+        } else if unknown > 0 {
+            ReadinessItem::failed(
+                check,
+                format!("{unknown} matches are assigned to stations beyond {num_stations}"),
+            )
+        } else {
+            ReadinessItem::passed(check, format!("{assigned} matches assigned to stations"))
+        })
+    }
+
+    async fn check_entrants(&self, tournament: &TournamentBase) -> CoreResult<ReadinessItem> {
+        let check = ReadinessCheck::Entrants;
+        let required = tournament.get_num_entrants() as usize;
+        let registered = self
+            .database
+            .list_entrants_of_tournament(tournament.get_id())
+            .await?
+            .len();
+        let message = format!("{registered} of {required} entrants registered");
+        Ok(if registered >= required {
+            ReadinessItem::passed(check, message)
+        } else {
+            ReadinessItem::failed(check, message)
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn item(check: ReadinessCheck, status: ReadinessStatus) -> ReadinessItem {
+        ReadinessItem::new(check, status, "message")
+    }
+
+    #[test]
+    fn publish_and_first_start_are_gated() {
+        use TournamentState::*;
+        assert!(is_gated_transition(None, Published));
+        assert!(is_gated_transition(Some(Draft), Published));
+        assert!(!is_gated_transition(Some(Published), Published));
+        assert!(is_gated_transition(Some(Draft), ActiveStage(0)));
+        assert!(is_gated_transition(Some(Published), ActiveStage(0)));
+        assert!(!is_gated_transition(Some(ActiveStage(0)), ActiveStage(1)));
+        assert!(!is_gated_transition(Some(Published), Draft));
+        assert!(!is_gated_transition(Some(ActiveStage(2)), Finished));
+    }
+
+    #[test]
+    fn unavailable_items_do_not_block() {
+        let mut checklist = ReadinessChecklist {
+            tournament_id: Uuid::new_v4(),
+            items: vec![
+                item(ReadinessCheck::SportConfig, ReadinessStatus::Passed),
+                item(ReadinessCheck::Schedule, ReadinessStatus::Unavailable),
+            ],
+        };
+        assert!(checklist.is_ready());
+        assert!(checklist.to_validation_errors().is_empty());
+
+        checklist
+            .items
+            .push(item(ReadinessCheck::Entrants, ReadinessStatus::Failed));
+        assert!(!checklist.is_ready());
+        assert!(!checklist.to_validation_errors().is_empty());
+    }
+}
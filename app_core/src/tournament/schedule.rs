@@ -0,0 +1,309 @@
+//! placement of matches of a stage on stations and start times
+//!
+//! Matches are placed in time slots of equal duration. In each slot matches are assigned to
+//! the allowed stations of the stage (see [`Stage::get_allowed_stations_at`]). A match only
+//! uses a station, if the station is available for the whole slot, i.e. if it is not dropped
+//! by a [`StationWindow`](super::StationWindow) starting during the slot. Slots without
+//! available stations are skipped up to the start of the next window.
+//!
+//! Stages may have a hard time cap (see [`Stage::get_latest_end`]). [`check_time_cap`] flags
+//! matches, which cannot end by the cap, and offers the director options to fit them in.
+
+use super::{Stage, TournamentBase};
+use chrono::{DateTime, Duration, Utc};
+use displaydoc::Display;
+use serde::{Deserialize, Serialize};
+
+/// station and start time of a scheduled match
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+pub struct MatchSlot {
+    /// number of station, starting with 1
+    pub station: u32,
+    /// start of match
+    pub start_at: DateTime<Utc>,
+}
+
+/// Number of stations, which matches of `stage` may use from `start` to `end`.
+fn num_stations_between(
+    tournament: &TournamentBase,
+    stage: &Stage,
+    start: DateTime<Utc>,
+    end: DateTime<Utc>,
+) -> u32 {
+    let at_start = *stage.get_allowed_stations_at(tournament, start).end();
+    tournament
+        .get_station_windows()
+        .iter()
+        .filter(|w| w.start > start && w.start < end)
+        .map(|w| *stage.get_allowed_stations_at(tournament, w.start).end())
+        .fold(at_start, u32::min)
+}
+
+/// Place `num_matches` matches of `stage` in slots of `slot_duration` starting at `start_at`.
+/// Returns fewer slots, if no stations are available after the last station window.
+pub fn schedule_matches(
+    tournament: &TournamentBase,
+    stage: &Stage,
+    start_at: DateTime<Utc>,
+    slot_duration: Duration,
+    num_matches: usize,
+) -> Vec<MatchSlot> {
+    let mut slots = Vec::with_capacity(num_matches);
+    if slot_duration <= Duration::zero() {
+        return slots;
+    }
+    let mut time = start_at;
+    while slots.len() < num_matches {
+        let num_stations = num_stations_between(tournament, stage, time, time + slot_duration);
+        if num_stations == 0 {
+            // wait for next window, which may provide stations again
+            match tournament
+                .get_station_windows()
+                .iter()
+                .find(|w| w.start > time)
+            {
+                Some(window) => {
+                    time = window.start;
+                    continue;
+                }
+                None => break,
+            }
+        }
+        let remaining = (num_matches - slots.len()) as u32;
+        slots.extend((1..=num_stations.min(remaining)).map(|station| MatchSlot {
+            station,
+            start_at: time,
+        }));
+        time += slot_duration;
+    }
+    slots
+}
+
+/// Place matches of `stage`, which are played in rounds of `round_sizes` matches, in slots
+/// of `slot_duration` starting at `start_at`. Each round starts after the last slot of the
+/// previous round, since its entrants depend on the results of the previous round, e.g. in
+/// KO play out. Returns fewer slots, if no stations are available after the last station
+/// window.
+pub fn schedule_rounds(
+    tournament: &TournamentBase,
+    stage: &Stage,
+    start_at: DateTime<Utc>,
+    slot_duration: Duration,
+    round_sizes: &[usize],
+) -> Vec<MatchSlot> {
+    let mut slots = Vec::with_capacity(round_sizes.iter().sum());
+    let mut time = start_at;
+    for &num_matches in round_sizes {
+        let round = schedule_matches(tournament, stage, time, slot_duration, num_matches);
+        let is_complete = round.len() == num_matches;
+        if let Some(last) = round.last() {
+            time = last.start_at + slot_duration;
+        }
+        slots.extend(round);
+        if !is_complete {
+            break;
+        }
+    }
+    slots
+}
+
+/// option of the director to fit the matches of a stage into its time cap
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize, Display)]
+pub enum TimeCapOption {
+    /// Shorten the estimated match duration to {minutes} minutes
+    ShortenMatches { minutes: i64 },
+    /// Reduce the number of sets of matches in the sport configuration
+    ReduceSets,
+    /// Drop the last {num_matches} matches, e.g. consolation matches
+    DropMatches { num_matches: usize },
+}
+
+/// matches of a stage, which cannot end by its time cap
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct TimeCapReport {
+    /// time cap of stage
+    pub latest_end: DateTime<Utc>,
+    /// number of matches, which end by the time cap
+    pub num_fitting: usize,
+    /// placed matches, which end after the time cap
+    pub overflowing: Vec<MatchSlot>,
+    /// number of matches, which could not be placed at all, since no stations are available
+    pub num_unplaced: usize,
+    /// options to fit all matches into the time cap
+    pub options: Vec<TimeCapOption>,
+}
+
+/// Check if `num_matches` matches of `stage` in slots of `slot_duration` starting at `start_at`
+/// end by the time cap of the stage. Returns `None`, if the stage has no time cap or all
+/// matches fit.
+pub fn check_time_cap(
+    tournament: &TournamentBase,
+    stage: &Stage,
+    start_at: DateTime<Utc>,
+    slot_duration: Duration,
+    num_matches: usize,
+) -> Option<TimeCapReport> {
+    let latest_end = stage.get_latest_end()?;
+    let fits = |slot: &MatchSlot, duration: Duration| slot.start_at + duration <= latest_end;
+    let slots = schedule_matches(tournament, stage, start_at, slot_duration, num_matches);
+    let (fitting, overflowing): (Vec<MatchSlot>, Vec<MatchSlot>) = slots
+        .into_iter()
+        .partition(|slot| fits(slot, slot_duration));
+    let num_unplaced = num_matches - fitting.len() - overflowing.len();
+    if overflowing.is_empty() && num_unplaced == 0 {
+        return None;
+    }
+
+    // longest match duration in whole minutes, with which all matches fit
+    let all_fit = |minutes: i64| {
+        let duration = Duration::minutes(minutes);
+        let slots = schedule_matches(tournament, stage, start_at, duration, num_matches);
+        slots.len() == num_matches && slots.iter().all(|slot| fits(slot, duration))
+    };
+    let (mut low, mut high) = (0, slot_duration.num_minutes());
+    while high - low > 1 {
+        let mid = (low + high) / 2;
+        if all_fit(mid) {
+            low = mid;
+        } else {
+            high = mid;
+        }
+    }
+
+    let mut options = Vec::new();
+    if low > 0 && all_fit(low) {
+        options.push(TimeCapOption::ShortenMatches { minutes: low });
+    }
+    options.push(TimeCapOption::ReduceSets);
+    options.push(TimeCapOption::DropMatches {
+        num_matches: num_matches - fitting.len(),
+    });
+
+    Some(TimeCapReport {
+        latest_end,
+        num_fitting: fitting.len(),
+        overflowing,
+        num_unplaced,
+        options,
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::StationWindow;
+    use chrono::{TimeZone, Timelike};
+
+    fn at(hour: u32) -> DateTime<Utc> {
+        Utc.with_ymd_and_hms(2026, 7, 11, hour, 0, 0).unwrap()
+    }
+
+    fn tournament(num_stations: u32, windows: Vec<StationWindow>) -> TournamentBase {
+        let mut tournament = TournamentBase::default();
+        tournament
+            .set_num_stations(num_stations)
+            .set_station_windows(windows);
+        tournament
+    }
+
+    #[test]
+    fn given_windows_when_schedule_matches_then_stations_follow_windows() {
+        // 4 courts in the morning, midday break, 6 courts in the afternoon
+        let tournament = tournament(
+            6,
+            vec![
+                StationWindow::new(at(13), 6),
+                StationWindow::new(at(9), 4),
+                StationWindow::new(at(12), 0),
+            ],
+        );
+        assert_eq!(tournament.get_station_windows()[0].start, at(9));
+        assert_eq!(tournament.get_num_stations_at(at(8)), 6);
+
+        let slots = schedule_matches(
+            &tournament,
+            &Stage::default(),
+            at(9),
+            Duration::hours(1),
+            20,
+        );
+
+        let per_hour = |hour| slots.iter().filter(|s| s.start_at == at(hour)).count();
+        assert_eq!(
+            (9..=14).map(per_hour).collect::<Vec<_>>(),
+            vec![4, 4, 4, 0, 6, 2]
+        );
+    }
+
+    #[test]
+    fn given_window_during_slot_when_schedule_matches_then_dropped_stations_are_not_used() {
+        let half_past_ten = at(10) + Duration::minutes(30);
+        let tournament = tournament(4, vec![StationWindow::new(half_past_ten, 2)]);
+        let mut stage = Stage::default();
+        stage.set_max_stations(Some(3));
+
+        let slots = schedule_matches(&tournament, &stage, at(10), Duration::hours(1), 5);
+
+        assert_eq!(slots.len(), 5);
+        assert!(slots.iter().all(|s| s.station <= 2));
+        assert_eq!(slots[2].start_at, at(11));
+    }
+
+    #[test]
+    fn given_rounds_when_schedule_rounds_then_each_round_starts_after_previous_round() {
+        // KO play out of 8 entrants on 3 courts
+        let tournament = tournament(3, Vec::new());
+
+        let slots = schedule_rounds(
+            &tournament,
+            &Stage::default(),
+            at(9),
+            Duration::hours(1),
+            &[4, 4, 4],
+        );
+
+        let starts: Vec<u32> = slots.iter().map(|s| s.start_at.hour()).collect();
+        assert_eq!(starts, vec![9, 9, 9, 10, 11, 11, 11, 12, 13, 13, 13, 14]);
+    }
+
+    #[test]
+    fn given_no_stations_after_last_window_when_schedule_matches_then_stops() {
+        let tournament = tournament(2, vec![StationWindow::new(at(10), 0)]);
+        let slots = schedule_matches(&tournament, &Stage::default(), at(9), Duration::hours(1), 5);
+        assert_eq!(slots.len(), 2);
+
+        let mut invalid = tournament.clone();
+        invalid.set_station_windows(vec![StationWindow::new(at(10), 3)]);
+        assert!(invalid.validate().is_err_and(|errs| {
+            errs.errors
+                .iter()
+                .any(|e| e.get_field() == "station_windows.1")
+        }));
+    }
+
+    #[test]
+    fn given_time_cap_when_check_time_cap_then_overflowing_matches_and_options() {
+        let tournament = tournament(2, Vec::new());
+        let mut stage = Stage::default();
+        let slot = Duration::minutes(40);
+        assert!(check_time_cap(&tournament, &stage, at(9), slot, 7).is_none());
+
+        // 7 matches on 2 stations need 4 slots; only 3 slots of 40 minutes end by 11:00
+        stage.set_latest_end(Some(at(11)));
+        let report = check_time_cap(&tournament, &stage, at(9), slot, 7).unwrap();
+        assert_eq!(report.num_fitting, 6);
+        assert_eq!(report.overflowing.len(), 1);
+        assert_eq!(report.num_unplaced, 0);
+        assert_eq!(
+            report.options,
+            vec![
+                TimeCapOption::ShortenMatches { minutes: 30 },
+                TimeCapOption::ReduceSets,
+                TimeCapOption::DropMatches { num_matches: 1 },
+            ]
+        );
+
+        stage.set_latest_end(Some(at(12)));
+        assert!(check_time_cap(&tournament, &stage, at(9), slot, 7).is_none());
+    }
+}
@@ -0,0 +1,621 @@
+//! initial group seeding of first stage
+//!
+//! Entrants are ordered by their rank in the ranking system of the sport, if the sport plugin
+//! provides a [`RankingSystemPort`](crate::RankingSystemPort). Entrants without rank follow,
+//! ordered by their own seed and name. The ordered entrants are mapped to the groups of the
+//! first stage by a [`SeedingStrategy`]. Before stage 0 is locked by starting the tournament,
+//! the director may move entrants between groups, see [`GroupSeeding::move_entrant`]. A saved
+//! seeding is stored as [`SavedGroupSeeding`] and loaded again by [`Core::load_group_seeding`].
+
+use super::TournamentBase;
+use crate::{
+    Core, CoreError, CoreResult, Entrant, EntrantRank, stage_group_ids,
+    utils::validation::{FieldError, ValidationErrors, ValidationResult},
+};
+use displaydoc::Display;
+use serde::{Deserialize, Serialize};
+use std::collections::{HashMap, HashSet};
+use tracing::{info, warn};
+use uuid::Uuid;
+
+/// strategy to map ordered entrants to the groups of the first stage
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Hash, Serialize, Deserialize, Display)]
+pub enum SeedingStrategy {
+    /// Snake
+    #[default]
+    Snake,
+    /// Ranked Blocks
+    RankedBlock,
+    /// Counting Through
+    CountingThrough,
+    /// Random
+    Random,
+}
+
+impl SeedingStrategy {
+    /// Map ordered `entrants` to `num_groups` groups.
+    pub fn map_to_groups(&self, entrants: Vec<Entrant>, num_groups: u32) -> Vec<Vec<Entrant>> {
+        match self {
+            SeedingStrategy::Snake => snake(entrants, num_groups),
+            SeedingStrategy::RankedBlock => ranked_block(entrants, num_groups),
+            SeedingStrategy::CountingThrough => count_through(entrants, num_groups),
+            SeedingStrategy::Random => count_through(shuffle(entrants), num_groups),
+        }
+    }
+}
+
+/// mapping of entrants to groups of first stage
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct GroupSeeding {
+    pub tournament_id: Uuid,
+    pub stage_id: Uuid,
+    /// entrants of each group in seeding order
+    pub groups: Vec<Vec<Entrant>>,
+    /// name of ranking system, if its ranks were used for seeding
+    pub ranking_system: Option<String>,
+    /// strategy, which mapped the entrants to the groups
+    pub strategy: SeedingStrategy,
+}
+
+/// group seeding of the first stage as saved by the director; entrants are referenced by id
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct SavedGroupSeeding {
+    pub tournament_id: Uuid,
+    pub stage_id: Uuid,
+    /// ids of entrants of each group in seeding order
+    pub groups: Vec<Vec<Uuid>>,
+    pub ranking_system: Option<String>,
+    pub strategy: SeedingStrategy,
+}
+
+impl From<&GroupSeeding> for SavedGroupSeeding {
+    fn from(seeding: &GroupSeeding) -> Self {
+        SavedGroupSeeding {
+            tournament_id: seeding.tournament_id,
+            stage_id: seeding.stage_id,
+            groups: seeding
+                .groups
+                .iter()
+                .map(|group| group.iter().map(|e| e.get_id()).collect())
+                .collect(),
+            ranking_system: seeding.ranking_system.clone(),
+            strategy: seeding.strategy,
+        }
+    }
+}
+
+impl GroupSeeding {
+    /// Resolve the entrant ids of `saved` with `entrants`. Returns `None`, if one of the saved
+    /// entrants does not exist anymore.
+    pub fn from_saved(saved: SavedGroupSeeding, entrants: &[Entrant]) -> Option<Self> {
+        let by_id: HashMap<Uuid, &Entrant> = entrants.iter().map(|e| (e.get_id(), e)).collect();
+        let groups = saved
+            .groups
+            .iter()
+            .map(|group| {
+                group
+                    .iter()
+                    .map(|id| by_id.get(id).map(|e| (*e).clone()))
+                    .collect::<Option<Vec<Entrant>>>()
+            })
+            .collect::<Option<Vec<_>>>()?;
+        Some(GroupSeeding {
+            tournament_id: saved.tournament_id,
+            stage_id: saved.stage_id,
+            groups,
+            ranking_system: saved.ranking_system,
+            strategy: saved.strategy,
+        })
+    }
+
+    /// Move entrant `entrant_id` to `group` at `position`; positions beyond the end of the
+    /// group append the entrant. Returns false, if entrant or group do not exist.
+    pub fn move_entrant(&mut self, entrant_id: Uuid, group: usize, position: usize) -> bool {
+        if group >= self.groups.len() {
+            return false;
+        }
+        let Some((from_group, from_position)) =
+            self.groups.iter().enumerate().find_map(|(g, entrants)| {
+                entrants
+                    .iter()
+                    .position(|e| e.get_id() == entrant_id)
+                    .map(|p| (g, p))
+            })
+        else {
+            return false;
+        };
+        let entrant = self.groups[from_group].remove(from_position);
+        let position = position.min(self.groups[group].len());
+        self.groups[group].insert(position, entrant);
+        true
+    }
+
+    /// Move entrants `entrant_ids` in their order to the end of `group`. Either all entrants
+    /// are moved or none of them: returns false, if the group or one of the entrants does not
+    /// exist.
+    pub fn move_entrants(&mut self, entrant_ids: &[Uuid], group: usize) -> bool {
+        let mut moved = self.clone();
+        for entrant_id in entrant_ids {
+            if !moved.move_entrant(*entrant_id, group, usize::MAX) {
+                return false;
+            }
+        }
+        *self = moved;
+        true
+    }
+
+    /// Validate, that the seeding has `num_groups` groups and contains each of `entrants`
+    /// exactly once.
+    pub fn validate(&self, entrants: &[Entrant], num_groups: u32) -> ValidationResult<()> {
+        let mut errs = ValidationErrors::new();
+        if self.groups.len() != num_groups as usize {
+            errs.add(
+                FieldError::builder()
+                    .set_field(String::from("groups"))
+                    .add_message(format!(
+                        "seeding has {} groups, but stage has {num_groups} groups",
+                        self.groups.len()
+                    ))
+                    .set_object_id(self.stage_id)
+                    .build(),
+            );
+        }
+        let mut seeded = HashSet::new();
+        for entrant in self.groups.iter().flatten() {
+            if !seeded.insert(entrant.get_id()) {
+                errs.add(
+                    FieldError::builder()
+                        .set_field(String::from("groups"))
+                        .add_message(format!("{} is seeded more than once", entrant.get_name()))
+                        .set_object_id(self.stage_id)
+                        .build(),
+                );
+            }
+        }
+        for entrant in entrants.iter().filter(|e| !seeded.contains(&e.get_id())) {
+            errs.add(
+                FieldError::builder()
+                    .set_field(String::from("groups"))
+                    .add_message(format!("{} is not seeded", entrant.get_name()))
+                    .set_object_id(self.stage_id)
+                    .build(),
+            );
+        }
+        if errs.is_empty() { Ok(()) } else { Err(errs) }
+    }
+}
+
+/// Order `entrants` by `ranks`; entrants without rank keep their order after ranked entrants.
+pub fn order_by_rank(mut entrants: Vec<Entrant>, ranks: &[EntrantRank]) -> Vec<Entrant> {
+    let ranks: HashMap<Uuid, u32> = ranks.iter().map(|r| (r.entrant_id, r.rank)).collect();
+    // stable sort keeps order of entrants with equal or without rank
+    entrants.sort_by_key(|e| {
+        let rank = ranks.get(&e.get_id());
+        (rank.is_none(), rank.copied())
+    });
+    entrants
+}
+
+/// Map ordered `entrants` to `num_groups` groups by counting through: with 20 entrants and
+/// 5 groups, entrants 1, 6, 11 and 16 are mapped to the first group.
+pub fn count_through(entrants: Vec<Entrant>, num_groups: u32) -> Vec<Vec<Entrant>> {
+    let mut groups: Vec<Vec<Entrant>> = (0..num_groups).map(|_| Vec::new()).collect();
+    if groups.is_empty() {
+        return groups;
+    }
+    for (index, entrant) in entrants.into_iter().enumerate() {
+        groups[index % num_groups as usize].push(entrant);
+    }
+    groups
+}
+
+/// Map ordered `entrants` to `num_groups` groups in snake order: with 20 entrants and 5 groups,
+/// entrants 1, 10, 11 and 20 are mapped to the first group.
+pub fn snake(entrants: Vec<Entrant>, num_groups: u32) -> Vec<Vec<Entrant>> {
+    let mut groups: Vec<Vec<Entrant>> = (0..num_groups).map(|_| Vec::new()).collect();
+    if groups.is_empty() {
+        return groups;
+    }
+    let num_groups = num_groups as usize;
+    for (index, entrant) in entrants.into_iter().enumerate() {
+        let column = index % num_groups;
+        let group = if (index / num_groups).is_multiple_of(2) {
+            column
+        } else {
+            num_groups - 1 - column
+        };
+        groups[group].push(entrant);
+    }
+    groups
+}
+
+/// Map ordered `entrants` to `num_groups` groups in blocks of consecutive ranks: with 20
+/// entrants and 5 groups, the top 4 are mapped to the first group. If entrants cannot be
+/// divided evenly, the first groups get one entrant more.
+pub fn ranked_block(entrants: Vec<Entrant>, num_groups: u32) -> Vec<Vec<Entrant>> {
+    let mut groups: Vec<Vec<Entrant>> = (0..num_groups).map(|_| Vec::new()).collect();
+    if groups.is_empty() {
+        return groups;
+    }
+    let num_groups = num_groups as usize;
+    let (size, remainder) = (entrants.len() / num_groups, entrants.len() % num_groups);
+    let mut entrants = entrants.into_iter();
+    for (index, group) in groups.iter_mut().enumerate() {
+        let block = size + usize::from(index < remainder);
+        group.extend(entrants.by_ref().take(block));
+    }
+    groups
+}
+
+/// Shuffle `entrants` into random order.
+fn shuffle(entrants: Vec<Entrant>) -> Vec<Entrant> {
+    let mut keyed: Vec<(Uuid, Entrant)> =
+        entrants.into_iter().map(|e| (Uuid::new_v4(), e)).collect();
+    keyed.sort_by_key(|(key, _)| *key);
+    keyed.into_iter().map(|(_, e)| e).collect()
+}
+
+impl<S> Core<S> {
+    /// Map the entrants of the field of the tournament to the groups of its first stage with
+    /// `strategy`.
+    /// Returns `None`, if tournament or first stage do not exist.
+    ///
+    /// Failures of the ranking system are logged; seeding falls back to seeds of entrants.
+    pub async fn seed_first_stage(
+        &self,
+        tournament_id: Uuid,
+        strategy: SeedingStrategy,
+    ) -> CoreResult<Option<GroupSeeding>> {
+        let Some(tournament) = self.database.get_tournament_base(tournament_id).await? else {
+            return Ok(None);
+        };
+        let Some(stage) = self.database.get_stage_by_number(tournament_id, 0).await? else {
+            return Ok(None);
+        };
+        let entrants = self.list_field_entrants(tournament_id).await?;
+
+        let mut ranking_system = None;
+        let entrants = match self.fetch_seed_ranks(&tournament, &entrants).await {
+            Some((name, ranks)) => {
+                ranking_system = Some(name.to_string());
+                order_by_rank(entrants, &ranks)
+            }
+            None => entrants,
+        };
+
+        Ok(Some(GroupSeeding {
+            tournament_id,
+            stage_id: stage.get_id(),
+            groups: strategy.map_to_groups(entrants, stage.get_num_groups()),
+            ranking_system,
+            strategy,
+        }))
+    }
+
+    /// Load the saved group seeding of the first stage. If no seeding was saved or entrants
+    /// changed since, the entrants are seeded with the default strategy like
+    /// [`Core::seed_first_stage`]. Returns `None`, if tournament or first stage do not exist.
+    pub async fn load_group_seeding(
+        &self,
+        tournament_id: Uuid,
+    ) -> CoreResult<Option<GroupSeeding>> {
+        let Some(stage) = self.database.get_stage_by_number(tournament_id, 0).await? else {
+            return Ok(None);
+        };
+        if let Some(saved) = self.database.get_group_seeding(stage.get_id()).await? {
+            let entrants = self.list_field_entrants(tournament_id).await?;
+            if let Some(seeding) = GroupSeeding::from_saved(saved, &entrants)
+                && seeding.validate(&entrants, stage.get_num_groups()).is_ok()
+            {
+                return Ok(Some(seeding));
+            }
+            info!(%tournament_id, "saved_group_seeding_outdated");
+        }
+        self.seed_first_stage(tournament_id, SeedingStrategy::default())
+            .await
+    }
+
+    /// Save the group seeding of the first stage, which the director may have changed by
+    /// moving entrants between groups. Returns `None`, if tournament or first stage do not
+    /// exist. The seeding cannot be changed, once the first stage is started.
+    pub async fn save_group_seeding(
+        &self,
+        seeding: GroupSeeding,
+    ) -> CoreResult<Option<GroupSeeding>> {
+        let tournament_id = seeding.tournament_id;
+        let Some(tournament) = self.database.get_tournament_base(tournament_id).await? else {
+            return Ok(None);
+        };
+        let Some(stage) = self.database.get_stage_by_number(tournament_id, 0).await? else {
+            return Ok(None);
+        };
+        if !tournament
+            .get_stage_editability(stage.get_number())
+            .is_structure_editable()
+        {
+            return Err(CoreError::from(
+                FieldError::builder()
+                    .set_field(String::from("groups"))
+                    .add_message("seeding of a started stage cannot be changed")
+                    .set_object_id(stage.get_id())
+                    .build(),
+            ));
+        }
+        let entrants = self.list_field_entrants(tournament_id).await?;
+        seeding.validate(&entrants, stage.get_num_groups())?;
+        if seeding.stage_id != stage.get_id() {
+            return Err(CoreError::from(
+                FieldError::builder()
+                    .set_field(String::from("stage_id"))
+                    .add_message("seeding does not belong to the first stage")
+                    .set_object_id(seeding.stage_id)
+                    .build(),
+            ));
+        }
+        self.database
+            .save_group_seeding(&SavedGroupSeeding::from(&seeding))
+            .await?;
+        info!(%tournament_id, strategy = %seeding.strategy, "group_seeding_saved");
+        Ok(Some(seeding))
+    }
+
+    /// Assign entrants `entrant_ids` of the group seeding of the first stage to `group` and
+    /// save the changed seeding like [`Core::save_group_seeding`].
+    pub async fn assign_entrants_to_group(
+        &self,
+        mut seeding: GroupSeeding,
+        entrant_ids: &[Uuid],
+        group: usize,
+    ) -> CoreResult<Option<GroupSeeding>> {
+        if !seeding.move_entrants(entrant_ids, group) {
+            return Err(CoreError::from(
+                FieldError::builder()
+                    .set_field(String::from("groups"))
+                    .add_message(format!(
+                        "group {} or one of the selected entrants does not exist",
+                        group + 1
+                    ))
+                    .set_object_id(seeding.stage_id)
+                    .build(),
+            ));
+        }
+        self.save_group_seeding(seeding).await
+    }
+
+    /// Final standings of `tournament`: the entrants of its last stage ranked by group and by
+    /// rank in group, i.e. the entrants of a group are placed behind the entrants of the
+    /// groups before it. Entrants sharing a rank in their group share their final rank.
+    pub async fn final_standings(
+        &self,
+        tournament: &TournamentBase,
+    ) -> CoreResult<Vec<EntrantRank>> {
+        let last_stage = tournament
+            .get_tournament_mode()
+            .get_num_of_stages()
+            .saturating_sub(1);
+        let Some(stage) = self
+            .database
+            .get_stage_by_number(tournament.get_id(), last_stage)
+            .await?
+        else {
+            return Ok(Vec::new());
+        };
+        let matches = self
+            .database
+            .list_matches_of_stage_for_update(stage.get_id())
+            .await?;
+        let mut ranks = Vec::new();
+        let mut num_placed = 0;
+        for group_id in stage_group_ids(&matches) {
+            let standings = self
+                .get_group_standings(tournament.get_id(), group_id)
+                .await?;
+            ranks.extend(standings.iter().map(|s| EntrantRank {
+                entrant_id: s.get_entrant_id(),
+                rank: num_placed + s.rank,
+            }));
+            num_placed += standings.len() as u32;
+        }
+        Ok(ranks)
+    }
+
+    /// Push the final standings of finished `tournament` to the ranking system of its sport,
+    /// see [`Core::final_standings`]. Failures are logged; the tournament stays finished.
+    pub(crate) async fn push_final_standings(&self, tournament: &TournamentBase) {
+        let has_ranking_system = self
+            .sport_plugins
+            .get(&tournament.get_sport_id())
+            .is_some_and(|plugin| plugin.ranking_system().is_some());
+        if !has_ranking_system {
+            return;
+        }
+        match self.final_standings(tournament).await {
+            Ok(standings) if standings.is_empty() => {
+                info!(tournament_id = %tournament.get_id(), "final_standings_empty");
+            }
+            Ok(standings) => {
+                self.push_final_results(tournament, &standings).await;
+            }
+            Err(e) => {
+                warn!(error = %e, tournament_id = %tournament.get_id(), "final_standings_failed");
+            }
+        }
+    }
+
+    /// Push final ranking of `tournament` to the ranking system of its sport. Returns true, if
+    /// the ranking system accepted the results.
+    pub async fn push_final_results(
+        &self,
+        tournament: &TournamentBase,
+        standings: &[EntrantRank],
+    ) -> bool {
+        let Some(ranking) = self
+            .sport_plugins
+            .get(&tournament.get_sport_id())
+            .and_then(|plugin| plugin.ranking_system())
+        else {
+            return false;
+        };
+        match ranking.push_final_results(tournament, standings).await {
+            Ok(()) => {
+                info!(tournament_id = %tournament.get_id(), ranking_system = ranking.name(), "ranking_results_pushed");
+                true
+            }
+            Err(e) => {
+                warn!(error = %e, tournament_id = %tournament.get_id(), ranking_system = ranking.name(), "ranking_results_not_pushed");
+                false
+            }
+        }
+    }
+
+    /// entrants playing in the tournament, i.e. neither waitlisted nor no-shows, sorted by
+    /// seed and name
+    async fn list_field_entrants(&self, tournament_id: Uuid) -> CoreResult<Vec<Entrant>> {
+        let entrants = self.as_entrant_state(tournament_id).list_entrants().await?;
+        Ok(entrants.into_iter().filter(Entrant::is_in_field).collect())
+    }
+
+    async fn fetch_seed_ranks(
+        &self,
+        tournament: &TournamentBase,
+        entrants: &[Entrant],
+    ) -> Option<(&'static str, Vec<EntrantRank>)> {
+        let ranking = self
+            .sport_plugins
+            .get(&tournament.get_sport_id())?
+            .ranking_system()?;
+        match ranking.fetch_seed_ranks(entrants).await {
+            Ok(ranks) => Some((ranking.name(), ranks)),
+            Err(e) => {
+                warn!(error = %e, tournament_id = %tournament.get_id(), ranking_system = ranking.name(), "ranking_seed_ranks_not_fetched");
+                None
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::utils::id_version::IdVersion;
+
+    fn entrant(name: &str) -> Entrant {
+        let mut entrant = Entrant::new(IdVersion::NewWithId(Uuid::new_v4()));
+        entrant.set_name(name);
+        entrant
+    }
+
+    fn names(entrants: &[Entrant]) -> Vec<&str> {
+        entrants.iter().map(|e| e.get_name()).collect()
+    }
+
+    #[test]
+    fn ranked_entrants_come_first() {
+        let entrants: Vec<Entrant> = ["A", "B", "C", "D"].into_iter().map(entrant).collect();
+        let ranks = [
+            EntrantRank {
+                entrant_id: entrants[2].get_id(),
+                rank: 1,
+            },
+            EntrantRank {
+                entrant_id: entrants[3].get_id(),
+                rank: 7,
+            },
+        ];
+        let ordered = order_by_rank(entrants, &ranks);
+        assert_eq!(names(&ordered), vec!["C", "D", "A", "B"]);
+    }
+
+    #[test]
+    fn counting_through_groups() {
+        let entrants: Vec<Entrant> = ["1", "2", "3", "4", "5", "6", "7"]
+            .into_iter()
+            .map(entrant)
+            .collect();
+        let groups = count_through(entrants, 3);
+        assert_eq!(names(&groups[0]), vec!["1", "4", "7"]);
+        assert_eq!(names(&groups[1]), vec!["2", "5"]);
+        assert_eq!(names(&groups[2]), vec!["3", "6"]);
+        assert!(count_through(Vec::new(), 0).is_empty());
+    }
+
+    fn numbered(n: usize) -> Vec<Entrant> {
+        (1..=n).map(|i| entrant(&i.to_string())).collect()
+    }
+
+    #[test]
+    fn snake_and_ranked_block_groups() {
+        let groups = SeedingStrategy::Snake.map_to_groups(numbered(7), 3);
+        assert_eq!(names(&groups[0]), vec!["1", "6", "7"]);
+        assert_eq!(names(&groups[1]), vec!["2", "5"]);
+        assert_eq!(names(&groups[2]), vec!["3", "4"]);
+
+        let groups = SeedingStrategy::RankedBlock.map_to_groups(numbered(7), 3);
+        assert_eq!(names(&groups[0]), vec!["1", "2", "3"]);
+        assert_eq!(names(&groups[1]), vec!["4", "5"]);
+        assert_eq!(names(&groups[2]), vec!["6", "7"]);
+        assert!(ranked_block(Vec::new(), 0).is_empty());
+    }
+
+    #[test]
+    fn random_groups_are_balanced() {
+        let entrants = numbered(10);
+        let groups = SeedingStrategy::Random.map_to_groups(entrants.clone(), 4);
+        let sizes: Vec<usize> = groups.iter().map(Vec::len).collect();
+        assert_eq!(sizes, vec![3, 3, 2, 2]);
+        let seeding = GroupSeeding {
+            tournament_id: Uuid::nil(),
+            stage_id: Uuid::nil(),
+            groups,
+            ranking_system: None,
+            strategy: SeedingStrategy::Random,
+        };
+        assert!(seeding.validate(&entrants, 4).is_ok());
+    }
+
+    #[test]
+    fn moved_entrant_changes_group() {
+        let entrants = numbered(4);
+        let moved = entrants[0].get_id();
+        let mut seeding = GroupSeeding {
+            tournament_id: Uuid::nil(),
+            stage_id: Uuid::nil(),
+            groups: snake(entrants.clone(), 2),
+            ranking_system: None,
+            strategy: SeedingStrategy::Snake,
+        };
+        assert!(seeding.move_entrant(moved, 1, 0));
+        assert_eq!(names(&seeding.groups[0]), vec!["4"]);
+        assert_eq!(names(&seeding.groups[1]), vec!["1", "2", "3"]);
+        assert!(!seeding.move_entrant(moved, 2, 0));
+        assert!(!seeding.move_entrant(Uuid::nil(), 0, 0));
+        assert!(seeding.validate(&entrants, 2).is_ok());
+
+        seeding.groups[1].pop();
+        let errs = seeding.validate(&entrants, 3).unwrap_err();
+        assert_eq!(errs.errors.len(), 2);
+    }
+
+    #[test]
+    fn moved_entrants_change_group_all_or_nothing() {
+        let entrants = numbered(6);
+        let mut seeding = GroupSeeding {
+            tournament_id: Uuid::nil(),
+            stage_id: Uuid::nil(),
+            groups: snake(entrants.clone(), 3),
+            ranking_system: None,
+            strategy: SeedingStrategy::Snake,
+        };
+        let before = seeding.clone();
+        let unknown = [entrants[0].get_id(), Uuid::nil()];
+        assert!(!seeding.move_entrants(&unknown, 2));
+        assert_eq!(seeding, before);
+
+        let moved = [entrants[0].get_id(), entrants[1].get_id()];
+        assert!(seeding.move_entrants(&moved, 2));
+        assert_eq!(names(&seeding.groups[0]), vec!["6"]);
+        assert_eq!(names(&seeding.groups[1]), vec!["5"]);
+        assert_eq!(names(&seeding.groups[2]), vec!["3", "4", "1", "2"]);
+        assert!(seeding.validate(&entrants, 3).is_ok());
+    }
+}
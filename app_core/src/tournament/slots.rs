@@ -0,0 +1,389 @@
+//! slot layout of groups, KO brackets and rings
+//!
+//! Entrants are not yet assigned to groups before a stage starts, therefore groups are
+//! described by slots (e.g. `B3` for the third entrant of group B), which are filled when
+//! the stage starts.
+
+use serde::{Deserialize, Serialize};
+
+/// Distribute `num_entrants` on `num_groups`; leading groups take the remainder.
+pub fn group_sizes(num_entrants: u32, num_groups: u32) -> Vec<u32> {
+    if num_groups == 0 {
+        return Vec::new();
+    }
+    let base = num_entrants / num_groups;
+    let remainder = num_entrants % num_groups;
+    (0..num_groups)
+        .map(|group| base + u32::from(group < remainder))
+        .collect()
+}
+
+/// `A`..`Z`, followed by numbers for large stages
+pub fn group_label(index: usize) -> String {
+    if index < 26 {
+        char::from(b'A' + index as u8).to_string()
+    } else {
+        (index + 1).to_string()
+    }
+}
+
+/// KO play out requires 2^n entrants with n >= 1
+pub fn is_ko_size(size: u32) -> bool {
+    size >= 2 && size.is_power_of_two()
+}
+
+/// side of a match in a KO bracket
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+pub enum KoSide {
+    /// slot of group, starting with 1
+    Slot(u32),
+    /// winner of match with number
+    WinnerOf(u32),
+    /// loser of match with number; only in KO play out, see [`ko_play_out`]
+    LoserOf(u32),
+}
+
+/// match of a KO bracket
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+pub struct KoMatch {
+    /// number of match in bracket, starting with 1
+    pub number: u32,
+    pub side_a: KoSide,
+    pub side_b: KoSide,
+}
+
+/// round of a KO bracket
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct KoRound {
+    /// e.g. `Semi Finals`
+    pub name: String,
+    pub matches: Vec<KoMatch>,
+}
+
+/// Rounds of KO bracket of a group with `size` slots with seeded first round (1 vs n,
+/// 2 vs n-1, ...). Returns an empty bracket, if `size` is no KO size.
+pub fn ko_bracket(size: u32) -> Vec<KoRound> {
+    if !is_ko_size(size) {
+        return Vec::new();
+    }
+    let mut rounds = Vec::new();
+    let mut match_number = 1;
+    let mut previous: Vec<u32> = Vec::new();
+    let mut remaining = size;
+    while remaining >= 2 {
+        let name = ko_round_name(remaining);
+        let mut matches = Vec::new();
+        let mut current = Vec::new();
+        for i in 0..remaining / 2 {
+            let (side_a, side_b) = if previous.is_empty() {
+                (KoSide::Slot(i + 1), KoSide::Slot(remaining - i))
+            } else {
+                (
+                    KoSide::WinnerOf(previous[2 * i as usize]),
+                    KoSide::WinnerOf(previous[2 * i as usize + 1]),
+                )
+            };
+            matches.push(KoMatch {
+                number: match_number,
+                side_a,
+                side_b,
+            });
+            current.push(match_number);
+            match_number += 1;
+        }
+        // bracket order: winner of 1 vs n meets winner of n/2 vs n/2+1
+        if previous.is_empty() {
+            current = bracket_order(&current);
+        }
+        rounds.push(KoRound { name, matches });
+        previous = current;
+        remaining /= 2;
+    }
+    rounds
+}
+
+/// name of KO round with `remaining` entrants, e.g. `Semi Finals`
+fn ko_round_name(remaining: u32) -> String {
+    match remaining {
+        2 => "Final".to_string(),
+        4 => "Semi Finals".to_string(),
+        8 => "Quarter Finals".to_string(),
+        n => format!("Round of {n}"),
+    }
+}
+
+/// places decided by a match of the last round of a KO play out
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+pub struct KoPlacement {
+    /// number of match in bracket
+    pub number: u32,
+    /// final place of winner, starting with 1
+    pub winner_place: u32,
+    /// final place of loser; always `winner_place + 1`
+    pub loser_place: u32,
+}
+
+/// KO play out of a group: rounds of all matches and the places decided in the last round
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct KoPlayOut {
+    pub rounds: Vec<KoRound>,
+    /// placements sorted by place
+    pub placements: Vec<KoPlacement>,
+}
+
+/// KO play out of a group with `size` slots: like in [`ko_bracket`] winners advance, but
+/// losers continue in placement brackets instead of dropping out, so that every place
+/// `1..=size` is decided by a match of the last round. In each round every entrant plays
+/// once. Winners and losers of a bracket form the brackets of the upper and lower half of
+/// its places; the bracket of best places comes first in each round. Returns an empty play
+/// out, if `size` is no KO size.
+pub fn ko_play_out(size: u32) -> KoPlayOut {
+    let mut play_out = KoPlayOut {
+        rounds: Vec::new(),
+        placements: Vec::new(),
+    };
+    if !is_ko_size(size) {
+        return play_out;
+    }
+    // brackets of current round: best place and pairings in bracket order
+    let first_round = (0..size / 2)
+        .map(|i| (KoSide::Slot(i + 1), KoSide::Slot(size - i)))
+        .collect();
+    let mut brackets: Vec<(u32, Vec<(KoSide, KoSide)>)> = vec![(1, first_round)];
+    let mut match_number = 1;
+    let mut remaining = size;
+    while remaining >= 2 {
+        let mut matches = Vec::new();
+        let mut next_brackets = Vec::new();
+        for (best_place, pairings) in brackets {
+            let mut current = Vec::new();
+            for (side_a, side_b) in pairings {
+                matches.push(KoMatch {
+                    number: match_number,
+                    side_a,
+                    side_b,
+                });
+                current.push(match_number);
+                match_number += 1;
+            }
+            if remaining == size {
+                current = bracket_order(&current);
+            }
+            if let [number] = current[..] {
+                play_out.placements.push(KoPlacement {
+                    number,
+                    winner_place: best_place,
+                    loser_place: best_place + 1,
+                });
+                continue;
+            }
+            let pairs = |side: fn(u32) -> KoSide| {
+                current
+                    .chunks(2)
+                    .map(|pair| (side(pair[0]), side(pair[1])))
+                    .collect::<Vec<_>>()
+            };
+            next_brackets.push((best_place, pairs(KoSide::WinnerOf)));
+            next_brackets.push((best_place + current.len() as u32, pairs(KoSide::LoserOf)));
+        }
+        let name = if remaining == 2 {
+            "Finals".to_string()
+        } else {
+            ko_round_name(remaining)
+        };
+        play_out.rounds.push(KoRound { name, matches });
+        brackets = next_brackets;
+        remaining /= 2;
+    }
+    play_out
+}
+
+/// Order first round matches, so that top seeds meet as late as possible.
+fn bracket_order(matches: &[u32]) -> Vec<u32> {
+    let mut order = vec![0usize];
+    while order.len() < matches.len() {
+        let len = order.len();
+        order = order
+            .into_iter()
+            .flat_map(|i| [i, 2 * len - 1 - i])
+            .collect();
+    }
+    order.into_iter().map(|i| matches[i]).collect()
+}
+
+/// Rounds of a ring of `size` slots, in which each slot plays its `num_neighbors` next
+/// neighbors on each side, e.g. with 2 neighbors slot 1 plays slots 2, 3, `size - 1` and
+/// `size`. Each slot plays at most once per round. Pairings are ordered by smaller slot
+/// first. Returns no rounds, if the neighbors cover the whole ring.
+pub fn ring_rounds(size: u32, num_neighbors: u32) -> Vec<Vec<(u32, u32)>> {
+    if num_neighbors == 0 || 2 * num_neighbors + 1 >= size {
+        return Vec::new();
+    }
+    let mut rounds: Vec<Vec<(u32, u32)>> = Vec::new();
+    // slots busy in each round
+    let mut busy: Vec<Vec<bool>> = Vec::new();
+    for distance in 1..=num_neighbors {
+        // walk the cycles of this distance, so that consecutive pairings alternate rounds
+        let num_cycles = gcd(size, distance);
+        for start in 0..num_cycles {
+            let mut slot = start;
+            for _ in 0..size / num_cycles {
+                let next = (slot + distance) % size;
+                let round = match busy
+                    .iter()
+                    .position(|b| !b[slot as usize] && !b[next as usize])
+                {
+                    Some(round) => round,
+                    None => {
+                        rounds.push(Vec::new());
+                        busy.push(vec![false; size as usize]);
+                        rounds.len() - 1
+                    }
+                };
+                busy[round][slot as usize] = true;
+                busy[round][next as usize] = true;
+                rounds[round].push((slot.min(next) + 1, slot.max(next) + 1));
+                slot = next;
+            }
+        }
+    }
+    rounds
+}
+
+fn gcd(a: u32, b: u32) -> u32 {
+    if b == 0 { a } else { gcd(b, a % b) }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn group_sizes_distribute_remainder_on_leading_groups() {
+        assert_eq!(group_sizes(10, 3), vec![4, 3, 3]);
+        assert_eq!(group_sizes(8, 2), vec![4, 4]);
+        assert!(group_sizes(8, 0).is_empty());
+    }
+
+    #[test]
+    fn group_labels() {
+        assert_eq!(group_label(0), "A");
+        assert_eq!(group_label(25), "Z");
+        assert_eq!(group_label(26), "27");
+    }
+
+    #[test]
+    fn ko_sizes() {
+        assert!(is_ko_size(2));
+        assert!(is_ko_size(16));
+        assert!(!is_ko_size(1));
+        assert!(!is_ko_size(6));
+        assert!(ko_bracket(6).is_empty());
+    }
+
+    #[test]
+    fn ko_bracket_of_eight() {
+        let rounds = ko_bracket(8);
+        let names: Vec<&str> = rounds.iter().map(|r| r.name.as_str()).collect();
+        assert_eq!(names, vec!["Quarter Finals", "Semi Finals", "Final"]);
+        assert_eq!(
+            rounds[0].matches[0],
+            KoMatch {
+                number: 1,
+                side_a: KoSide::Slot(1),
+                side_b: KoSide::Slot(8)
+            }
+        );
+        // top seeds 1 and 2 meet in the final at the earliest
+        assert_eq!(rounds[1].matches[0].side_a, KoSide::WinnerOf(1));
+        assert_eq!(rounds[1].matches[0].side_b, KoSide::WinnerOf(4));
+        assert_eq!(rounds[1].matches[1].side_a, KoSide::WinnerOf(2));
+        assert_eq!(rounds[1].matches[1].side_b, KoSide::WinnerOf(3));
+        assert_eq!(rounds[2].matches[0].number, 7);
+    }
+
+    #[test]
+    fn ko_play_out_of_eight_decides_every_place() {
+        let play_out = ko_play_out(8);
+        let names: Vec<&str> = play_out.rounds.iter().map(|r| r.name.as_str()).collect();
+        assert_eq!(names, vec!["Quarter Finals", "Semi Finals", "Finals"]);
+        // every entrant plays once per round
+        assert!(play_out.rounds.iter().all(|r| r.matches.len() == 4));
+        // first round and winners bracket as in KO bracket
+        assert_eq!(play_out.rounds[0].matches, ko_bracket(8)[0].matches);
+        assert_eq!(play_out.rounds[1].matches[0].side_a, KoSide::WinnerOf(1));
+        assert_eq!(play_out.rounds[1].matches[0].side_b, KoSide::WinnerOf(4));
+        // losers of quarter finals play for places 5 to 8
+        assert_eq!(play_out.rounds[1].matches[2].side_a, KoSide::LoserOf(1));
+        assert_eq!(play_out.rounds[1].matches[2].side_b, KoSide::LoserOf(4));
+        let final_match = play_out.rounds[2].matches[0];
+        assert_eq!(final_match.side_a, KoSide::WinnerOf(5));
+        assert_eq!(final_match.side_b, KoSide::WinnerOf(6));
+        assert_eq!(
+            play_out.placements[0],
+            KoPlacement {
+                number: final_match.number,
+                winner_place: 1,
+                loser_place: 2
+            }
+        );
+        let places: Vec<u32> = play_out
+            .placements
+            .iter()
+            .flat_map(|p| [p.winner_place, p.loser_place])
+            .collect();
+        assert_eq!(places, (1..=8).collect::<Vec<_>>());
+        // match for places 7 and 8 is played by the losers of the losers
+        let last = play_out.rounds[2].matches[3];
+        assert_eq!(play_out.placements[3].number, last.number);
+        assert_eq!(last.side_a, KoSide::LoserOf(7));
+        assert_eq!(last.side_b, KoSide::LoserOf(8));
+    }
+
+    #[test]
+    fn ko_play_out_sizes() {
+        assert_eq!(ko_play_out(6), ko_play_out(0));
+        let two = ko_play_out(2);
+        assert_eq!(two.rounds.len(), 1);
+        assert_eq!(two.rounds[0].name, "Finals");
+        assert_eq!(two.placements.len(), 1);
+        let sixteen = ko_play_out(16);
+        assert_eq!(sixteen.rounds.len(), 4);
+        assert_eq!(sixteen.placements.len(), 8);
+        let num_matches: usize = sixteen.rounds.iter().map(|r| r.matches.len()).sum();
+        assert_eq!(num_matches, 32);
+    }
+
+    #[test]
+    fn ring_rounds_pair_each_slot_with_its_neighbors_once() {
+        for (size, num_neighbors) in [(7, 2), (10, 2), (12, 3), (9, 1)] {
+            let rounds = ring_rounds(size, num_neighbors);
+            let pairings: Vec<(u32, u32)> = rounds.iter().flatten().copied().collect();
+            assert_eq!(pairings.len() as u32, size * num_neighbors);
+            for slot in 1..=size {
+                let opponents: Vec<u32> = pairings
+                    .iter()
+                    .filter_map(|&(a, b)| match slot {
+                        _ if a == slot => Some(b),
+                        _ if b == slot => Some(a),
+                        _ => None,
+                    })
+                    .collect();
+                assert_eq!(opponents.len() as u32, 2 * num_neighbors);
+                for opponent in opponents {
+                    let distance = slot.abs_diff(opponent);
+                    assert!(distance.min(size - distance) <= num_neighbors);
+                }
+            }
+            for round in &rounds {
+                let mut slots: Vec<u32> = round.iter().flat_map(|&(a, b)| [a, b]).collect();
+                slots.sort_unstable();
+                slots.dedup();
+                assert_eq!(slots.len(), 2 * round.len());
+            }
+            assert!(rounds.len() as u32 <= 2 * num_neighbors + 1);
+        }
+        // two neighbors on each side of a ring of 5 is a full round robin
+        assert!(ring_rounds(5, 2).is_empty());
+    }
+}
@@ -2,18 +2,22 @@
 
 use super::base::{TournamentBase, TournamentMode};
 use crate::{
-    Core, CoreError, CoreResult, CrMsg, CrTopic,
+    AuditObjectType, Core, CoreError, CoreResult, CrMsg, CrTopic, ScoringOverride, SportError,
     utils::{
         id_version::IdVersion,
         traits::{ObjectIdVersion, ObjectNumber},
         validation::{FieldError, ValidationErrors, ValidationResult},
     },
 };
+use chrono::{DateTime, Utc};
 use serde::{Deserialize, Serialize};
+use std::ops::RangeInclusive;
 use uuid::Uuid;
 
 /// stage of a tournament
-#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Serialize, Deserialize)]
+#[derive(
+    Debug, Clone, Copy, PartialEq, Eq, Hash, Serialize, Deserialize, ObjectIdVersion, ObjectNumber,
+)]
 pub struct Stage {
     /// id and version of stage in tournament
     id_version: IdVersion,
@@ -23,6 +27,23 @@ pub struct Stage {
     number: u32,
     /// number of groups in stage
     num_groups: u32,
+    /// maximum number of stations, which matches of stage may use simultaneously;
+    /// `None` allows all stations of tournament
+    #[serde(default)]
+    max_stations: Option<u32>,
+    /// generate next round automatically, when all results of a round are confirmed
+    #[serde(default = "default_auto_advance")]
+    auto_advance: bool,
+    /// scoring policy of stage, which differs from the tournament default
+    #[serde(default)]
+    scoring_override: ScoringOverride,
+    /// hard time cap: all matches of stage must end by this time; `None`, if stage has no cap
+    #[serde(default)]
+    latest_end: Option<DateTime<Utc>>,
+}
+
+fn default_auto_advance() -> bool {
+    true
 }
 
 impl Default for Stage {
@@ -32,22 +53,14 @@ impl Default for Stage {
             tournament_id: Uuid::nil(),
             number: 0,
             num_groups: 1,
+            max_stations: None,
+            auto_advance: default_auto_advance(),
+            scoring_override: ScoringOverride::default(),
+            latest_end: None,
         }
     }
 }
 
-impl ObjectIdVersion for Stage {
-    fn get_id_version(&self) -> IdVersion {
-        self.id_version
-    }
-}
-
-impl ObjectNumber for Stage {
-    fn get_object_number(&self) -> u32 {
-        self.number
-    }
-}
-
 impl Stage {
     /// Create a new `Stage` with the given `IdVersion`.
     pub fn new(id_version: IdVersion) -> Self {
@@ -82,6 +95,59 @@ impl Stage {
         self.num_groups
     }
 
+    /// Get the maximum number of stations, which matches of stage may use simultaneously.
+    pub fn get_max_stations(&self) -> Option<u32> {
+        self.max_stations
+    }
+
+    /// Get the stations, to which the scheduler may assign matches of stage. Stations are
+    /// numbered from 1; a limited stage uses the first stations of the tournament
+    /// (e.g. finals only on center court, which is station 1).
+    pub fn get_allowed_stations(&self, tournament: &TournamentBase) -> RangeInclusive<u32> {
+        let num_stations = tournament.get_num_stations();
+        let last = self
+            .max_stations
+            .map_or(num_stations, |max| max.min(num_stations));
+        1..=last
+    }
+
+    /// Get the stations, to which the scheduler may assign matches of stage at `time`, which
+    /// respects the availability of stations of the tournament at that time.
+    pub fn get_allowed_stations_at(
+        &self,
+        tournament: &TournamentBase,
+        time: DateTime<Utc>,
+    ) -> RangeInclusive<u32> {
+        let last = *self.get_allowed_stations(tournament).end();
+        1..=last.min(tournament.get_num_stations_at(time))
+    }
+
+    /// Get the hard time cap of stage, by which all matches must end.
+    pub fn get_latest_end(&self) -> Option<DateTime<Utc>> {
+        self.latest_end
+    }
+
+    /// Check if the next round is generated automatically, when all results of a round
+    /// are confirmed.
+    pub fn is_auto_advance(&self) -> bool {
+        self.auto_advance
+    }
+
+    /// Get the scoring policy override of stage.
+    pub fn get_scoring_override(&self) -> ScoringOverride {
+        self.scoring_override
+    }
+
+    /// Check if confirming the last result of a round triggers generation of the next round.
+    /// This requires the auto advance flag and a stage, whose rounds depend upon results of
+    /// previous rounds, see [`TournamentMode::has_result_dependent_rounds`].
+    pub fn triggers_next_round(&self, tournament: &TournamentBase) -> bool {
+        self.auto_advance
+            && tournament
+                .get_tournament_mode()
+                .has_result_dependent_rounds(self.number)
+    }
+
     /// Set the `IdVersion` of the stage.
     pub fn set_id_version(&mut self, id_version: IdVersion) -> &mut Self {
         self.id_version = id_version;
@@ -106,6 +172,30 @@ impl Stage {
         self
     }
 
+    /// Set the maximum number of stations, which matches of stage may use simultaneously.
+    pub fn set_max_stations(&mut self, max_stations: Option<u32>) -> &mut Self {
+        self.max_stations = max_stations;
+        self
+    }
+
+    /// Set if the next round is generated automatically.
+    pub fn set_auto_advance(&mut self, auto_advance: bool) -> &mut Self {
+        self.auto_advance = auto_advance;
+        self
+    }
+
+    /// Set the hard time cap of stage; `None` removes the cap.
+    pub fn set_latest_end(&mut self, latest_end: Option<DateTime<Utc>>) -> &mut Self {
+        self.latest_end = latest_end;
+        self
+    }
+
+    /// Set the scoring policy override of stage.
+    pub fn set_scoring_override(&mut self, scoring_override: ScoringOverride) -> &mut Self {
+        self.scoring_override = scoring_override;
+        self
+    }
+
     /// Validate the stage configuration based on the provided tournament settings.
     pub fn validate(&self, tournament: &TournamentBase) -> ValidationResult<()> {
         let mut errs = ValidationErrors::new();
@@ -159,6 +249,31 @@ impl Stage {
             );
         }
 
+        // Validate station limit against available stations
+        if let Some(max_stations) = self.max_stations {
+            if max_stations == 0 {
+                errs.add(
+                    FieldError::builder()
+                        .set_field(String::from("max_stations"))
+                        .add_message("Station limit must be at least 1")
+                        .set_object_id(object_id)
+                        .build(),
+                );
+            } else if max_stations > tournament.get_num_stations() {
+                errs.add(
+                    FieldError::builder()
+                        .set_field(String::from("max_stations"))
+                        .add_message(format!(
+                            "Station limit ({}) exceeds the number of stations ({}) of the tournament",
+                            max_stations,
+                            tournament.get_num_stations()
+                        ))
+                        .set_object_id(object_id)
+                        .build(),
+                );
+            }
+        }
+
         // Mode specific validation
         let mode = tournament.get_tournament_mode();
 
@@ -190,6 +305,19 @@ impl Stage {
             }
         }
 
+        // Specific constraint: Ring System places the whole field in one ring
+        if let TournamentMode::RingSystem { .. } = mode
+            && self.num_groups > 1
+        {
+            errs.add(
+                FieldError::builder()
+                    .set_field(String::from("num_groups"))
+                    .add_message("Ring System has 1 group in stage (the whole field)")
+                    .set_object_id(object_id)
+                    .build(),
+            );
+        }
+
         if !errs.is_empty() {
             return Err(errs);
         }
@@ -205,6 +333,27 @@ pub struct StageState {
 
 // switch state to sport config state
 impl<S> Core<S> {
+    /// Validate the scoring policy override of `stage` against the sport plugin of
+    /// `tournament`.
+    pub(crate) fn validate_stage_scoring(
+        &self,
+        tournament: &TournamentBase,
+        stage: &Stage,
+    ) -> CoreResult<()> {
+        if stage.scoring_override.is_empty() {
+            return Ok(());
+        }
+        let sport_id = tournament.get_sport_id();
+        let plugin = self
+            .sport_plugins
+            .get(&sport_id)
+            .ok_or(SportError::UnknownSportId(sport_id))?;
+        stage
+            .scoring_override
+            .validate(plugin.as_ref(), stage.get_id())?;
+        Ok(())
+    }
+
     pub fn as_stage_state(&self, tournament_id: Uuid) -> Core<StageState> {
         self.switch_state(StageState {
             tournament_id,
@@ -242,6 +391,7 @@ impl Core<StageState> {
                 .stage
                 .validate(tournament)
                 .map_err(CoreError::from)?;
+            self.validate_stage_scoring(tournament, &self.state.stage)?;
         }
         Ok(())
     }
@@ -276,6 +426,21 @@ impl Core<StageState> {
         // Otherwise saved objects may not be validated before saving.
         self.try_load_tournament().await?;
         self.validate()?;
+        // stored copy before the save for the audit log
+        let old = match self.state.stage.get_version() {
+            Some(_) => {
+                self.database
+                    .get_stage_by_id(self.state.stage.get_id())
+                    .await?
+            }
+            None => None,
+        };
+        if let Some(tournament) = self.state.tournament.as_ref() {
+            self.state
+                .stage
+                .validate_edit(old.as_ref(), tournament)
+                .map_err(CoreError::from)?;
+        }
         self.state.stage = self.database.save_stage(&self.state.stage).await?;
 
         // publish change of stage to client registry
@@ -294,6 +459,13 @@ impl Core<StageState> {
         };
         let msg = CrMsg::StageUpdated { id, version };
         self.client_registry.publish(notice, msg).await?;
+        self.audit_save(
+            AuditObjectType::Stage,
+            Some(self.state.tournament_id),
+            old.as_ref(),
+            &self.state.stage,
+        )
+        .await;
         Ok(self.get())
     }
     pub async fn list_stage_ids_of_tournament(&mut self) -> CoreResult<Vec<(Uuid, u32)>> {
@@ -0,0 +1,265 @@
+//! snapshots of stages before promotion
+//!
+//! Completing a stage promotes its entrants to the next stage by their standings. If a
+//! data-entry mistake is discovered after promotion, directors would have to correct the
+//! seeding of all downstream stages by hand. Therefore Core takes a [`StageSnapshot`] of the
+//! matches and standings of the active stage, before the stage is completed (see
+//! [`Core::complete_stage`] and [`Core::finish_tournament`]).
+//!
+//! [`Core::rollback_to_snapshot`] restores a snapshot and makes its stage the active stage
+//! again. Snapshots of the stage and of later stages are discarded, since they are taken
+//! again, when the corrected stage is completed. Downstream seeding follows the corrected
+//! standings on promotion.
+
+use super::{TournamentBase, TournamentBaseState, TournamentState};
+use crate::{
+    CachedGroupStandings, Core, CoreError, CoreResult, CrMsg, CrTopic, DomainEvent, Match,
+    utils::validation::FieldError,
+};
+use chrono::{DateTime, Utc};
+use serde::{Deserialize, Serialize};
+use tracing::info;
+use uuid::Uuid;
+
+/// state of a stage before it was completed
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct StageSnapshot {
+    pub id: Uuid,
+    pub tournament_id: Uuid,
+    pub stage_id: Uuid,
+    pub stage_number: u32,
+    /// matches of the stage including their scores; they have no version, since a rollback
+    /// replaces the stored matches
+    pub matches: Vec<Match>,
+    /// cached standings of the groups of the tournament
+    pub standings: Vec<CachedGroupStandings>,
+    pub created_at: DateTime<Utc>,
+}
+
+impl StageSnapshot {
+    pub fn new(
+        tournament_id: Uuid,
+        stage_id: Uuid,
+        stage_number: u32,
+        mut matches: Vec<Match>,
+        standings: Vec<CachedGroupStandings>,
+    ) -> Self {
+        for m in matches.iter_mut() {
+            m.set_version(None);
+        }
+        StageSnapshot {
+            id: Uuid::new_v4(),
+            tournament_id,
+            stage_id,
+            stage_number,
+            matches,
+            standings,
+            created_at: Utc::now(),
+        }
+    }
+}
+
+impl TournamentBase {
+    /// Check if stage `stage_number` is completed and may therefore be rolled back, i.e. if
+    /// a later stage is active or the tournament is finished.
+    pub fn is_stage_completed(&self, stage_number: u32) -> bool {
+        match self.get_tournament_state() {
+            TournamentState::ActiveStage(active_stage) => stage_number < active_stage,
+            TournamentState::Finished => true,
+            TournamentState::Draft | TournamentState::Published => false,
+        }
+    }
+}
+
+// snapshots may be listed in every core state
+impl<S> Core<S> {
+    /// List the snapshots of tournament `tournament_id` sorted by stage number.
+    pub async fn list_stage_snapshots(
+        &self,
+        tournament_id: Uuid,
+    ) -> CoreResult<Vec<StageSnapshot>> {
+        Ok(self
+            .database
+            .list_stage_snapshots_of_tournament(tournament_id)
+            .await?)
+    }
+
+    /// Take a snapshot of stage `stage_number` of `tournament`. Returns `None` for sandbox
+    /// tournaments, which may change their state freely, and if the stage does not exist.
+    pub(crate) async fn snapshot_stage(
+        &self,
+        tournament: &TournamentBase,
+        stage_number: u32,
+    ) -> CoreResult<Option<StageSnapshot>> {
+        if tournament.is_sandbox() {
+            return Ok(None);
+        }
+        let tournament_id = tournament.get_id();
+        let Some(stage) = self
+            .database
+            .get_stage_by_number(tournament_id, stage_number)
+            .await?
+        else {
+            return Ok(None);
+        };
+        let standings = self
+            .database
+            .list_group_standings_of_tournament(tournament_id)
+            .await?;
+        let matches = self
+            .database
+            .list_matches_of_stage_for_update(stage.get_id())
+            .await?;
+        let snapshot = StageSnapshot::new(
+            tournament_id,
+            stage.get_id(),
+            stage_number,
+            matches,
+            standings,
+        );
+        self.database.save_stage_snapshot(&snapshot).await?;
+        info!(%tournament_id, stage_number, snapshot_id = %snapshot.id, "stage_snapshot_taken");
+        Ok(Some(snapshot))
+    }
+}
+
+impl Core<TournamentBaseState> {
+    /// Roll back the currently loaded tournament to the snapshot with id `snapshot_id`: the
+    /// matches and standings of the snapshot are restored and its stage is the active stage
+    /// again. Results of later stages are dropped and their sides are unresolved, since they
+    /// depend on the rolled back standings. Cached standings of later stages and of groups
+    /// without standings in the snapshot are dropped and computed again on the next read.
+    /// The stage must be completed. Snapshots of the stage and of later stages are discarded.
+    pub async fn rollback_to_snapshot(&mut self, snapshot_id: Uuid) -> CoreResult<&TournamentBase> {
+        let tournament_id = self.get().get_id();
+        let snapshot = match self.database.get_stage_snapshot(snapshot_id).await? {
+            Some(snapshot) if snapshot.tournament_id == tournament_id => snapshot,
+            _ => {
+                return Err(self.snapshot_error(
+                    snapshot_id,
+                    "snapshot does not exist or belongs to another tournament",
+                ));
+            }
+        };
+        if !self.get().is_stage_completed(snapshot.stage_number) {
+            return Err(self.snapshot_error(
+                snapshot_id,
+                format!("stage {} is not completed", snapshot.stage_number + 1),
+            ));
+        }
+        let mut tournament = self.get().clone();
+        tournament.set_tournament_state(TournamentState::ActiveStage(snapshot.stage_number));
+        let num_stages = tournament.get_tournament_mode().get_num_of_stages();
+
+        let rolled_back = self
+            .with_transaction(|core| async move {
+                for standings in &snapshot.standings {
+                    core.database.save_group_standings(standings).await?;
+                    let notice = CrTopic::GroupMatches {
+                        group_id: standings.group_id,
+                    };
+                    let msg = CrMsg::GroupStandingsUpdated {
+                        id: standings.group_id,
+                        version: 0,
+                    };
+                    core.client_registry.publish(notice, msg).await?;
+                }
+                core.database
+                    .delete_matches_of_stage(snapshot.stage_id)
+                    .await?;
+                let mut changed = core.database.save_matches(&snapshot.matches).await?;
+                // groups, whose cached standings are not restored, e.g. since they were cached
+                // after the snapshot was taken
+                let mut stale_groups: Vec<Uuid> = Vec::new();
+                for m in &snapshot.matches {
+                    let group_id = *m.get_group_id();
+                    if !stale_groups.contains(&group_id)
+                        && !snapshot.standings.iter().any(|s| s.group_id == group_id)
+                    {
+                        stale_groups.push(group_id);
+                    }
+                }
+                for stage_number in snapshot.stage_number + 1..num_stages {
+                    let Some(stage) = core
+                        .database
+                        .get_stage_by_number(tournament_id, stage_number)
+                        .await?
+                    else {
+                        continue;
+                    };
+                    let mut later = core
+                        .database
+                        .list_matches_of_stage_for_update(stage.get_id())
+                        .await?;
+                    for m in later.iter_mut() {
+                        m.reset_result().unresolve_sides(|_| true);
+                        if !stale_groups.contains(m.get_group_id()) {
+                            stale_groups.push(*m.get_group_id());
+                        }
+                    }
+                    changed.extend(core.database.save_matches(&later).await?);
+                }
+                for group_id in stale_groups {
+                    core.database.delete_group_standings(group_id).await?;
+                    let notice = CrTopic::GroupMatches { group_id };
+                    let msg = CrMsg::GroupStandingsUpdated {
+                        id: group_id,
+                        version: 0,
+                    };
+                    core.client_registry.publish(notice, msg).await?;
+                }
+                core.publish_saved_matches(&changed).await?;
+                for later in core
+                    .database
+                    .list_stage_snapshots_of_tournament(tournament_id)
+                    .await?
+                    .into_iter()
+                    .filter(|s| s.stage_number >= snapshot.stage_number)
+                {
+                    core.database.delete_stage_snapshot(later.id).await?;
+                }
+                let mut tb_core = core.as_tournament_base_state();
+                *tb_core.get_mut() = tournament;
+                let rolled_back = tb_core.save_rollback().await?.clone();
+                core.emit_domain_event(DomainEvent::StageRolledBack {
+                    tournament_id,
+                    stage_number: snapshot.stage_number,
+                })
+                .await;
+                Ok(rolled_back)
+            })
+            .await?;
+        info!(%tournament_id, %snapshot_id, "stage_rolled_back");
+        *self.get_mut() = rolled_back;
+        Ok(self.get())
+    }
+
+    fn snapshot_error(&self, snapshot_id: Uuid, message: impl Into<String>) -> CoreError {
+        CoreError::from(
+            FieldError::builder()
+                .set_field(String::from("snapshot"))
+                .add_message(message)
+                .set_object_id(snapshot_id)
+                .build(),
+        )
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::utils::id_version::IdVersion;
+
+    #[test]
+    fn given_states_when_is_stage_completed_then_only_stages_before_active_stage() {
+        let mut tournament = TournamentBase::new(IdVersion::new(Uuid::new_v4(), Some(1)));
+        assert!(!tournament.is_stage_completed(0));
+
+        tournament.set_tournament_state(TournamentState::ActiveStage(1));
+        assert!(tournament.is_stage_completed(0));
+        assert!(!tournament.is_stage_completed(1));
+
+        tournament.set_tournament_state(TournamentState::Finished);
+        assert!(tournament.is_stage_completed(1));
+    }
+}
@@ -0,0 +1,520 @@
+//! timing dashboard of a stage
+//!
+//! The dashboard compares planned and actual start and end of the matches of a stage per
+//! station. Delays propagate: a match cannot start before the previous match at its station
+//! ended. The projected finish of the stage is the latest projected end of all stations.
+//! [`compress_schedule`] re-places the matches, which did not start yet, on the stations,
+//! which become free first, e.g. to use stations finishing ahead of schedule.
+
+use super::{MatchSlot, Stage, TournamentBase};
+use crate::{Core, CoreResult, Match, MatchOutcome, MatchTimeChange};
+use chrono::{DateTime, Duration, Local, Utc};
+use serde::{Deserialize, Serialize};
+use std::collections::BTreeMap;
+use uuid::Uuid;
+
+/// planned and actual times of a match of the stage
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct StageMatchTimes {
+    pub match_id: Uuid,
+    /// number of station (starting with 1), at which the match is played
+    pub station: u32,
+    pub planned_start: DateTime<Utc>,
+    pub planned_end: DateTime<Utc>,
+    /// actual start of match; `None`, if the match did not start yet
+    pub actual_start: Option<DateTime<Utc>>,
+    /// actual end of match, i.e. entry of its result; `None`, if the match is not finished
+    pub actual_end: Option<DateTime<Utc>>,
+}
+
+impl StageMatchTimes {
+    /// Times of the stored match `m`, which is planned to take `duration`. Matches do not
+    /// record their actual start, so a started match counts as started at its planned start.
+    /// The entry of the final result or else the last live score update of a played match
+    /// counts as its actual end; walkovers end at their planned start. Returns `None` for
+    /// byes and matches without station.
+    pub fn from_match(m: &Match, duration: Duration) -> Option<Self> {
+        if m.get_outcome() == MatchOutcome::Bye || m.get_station() == 0 {
+            return None;
+        }
+        let planned_start = m.get_start_at().with_timezone(&Utc);
+        let planned_end = planned_start + duration;
+        let (actual_start, actual_end) = if m.get_outcome().is_walkover() {
+            (Some(planned_start), Some(planned_start))
+        } else if m.is_decided() {
+            let end = m
+                .get_result_at()
+                .or_else(|| m.get_live_score().map(|l| l.updated_at))
+                .unwrap_or(planned_end);
+            (Some(planned_start), Some(end.max(planned_start)))
+        } else if m.is_in_progress() {
+            (Some(planned_start), None)
+        } else {
+            (None, None)
+        };
+        Some(StageMatchTimes {
+            match_id: *m.get_id(),
+            station: m.get_station() as u32,
+            planned_start,
+            planned_end,
+            actual_start,
+            actual_end,
+        })
+    }
+
+    /// Planned duration of the match.
+    pub fn planned_duration(&self) -> Duration {
+        self.planned_end - self.planned_start
+    }
+
+    /// Check if the match did not start yet.
+    pub fn is_pending(&self) -> bool {
+        self.actual_start.is_none() && self.actual_end.is_none()
+    }
+}
+
+/// projected times of a match, see [`StationTiming::matches`]
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct ProjectedMatchTimes {
+    pub times: StageMatchTimes,
+    /// actual start or projected start, if the match did not start yet
+    pub projected_start: DateTime<Utc>,
+    /// actual end or projected end, if the match is not finished yet
+    pub projected_end: DateTime<Utc>,
+}
+
+impl ProjectedMatchTimes {
+    /// Delay of the end of the match in seconds; negative, if the match ends ahead of plan.
+    pub fn delay_seconds(&self) -> i64 {
+        (self.projected_end - self.times.planned_end).num_seconds()
+    }
+}
+
+/// timing of one station
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct StationTiming {
+    /// number of station, starting with 1
+    pub station: u32,
+    /// name of station; `Station <number>`, if the station is not named
+    pub name: String,
+    /// matches of the station in order of their planned start
+    pub matches: Vec<ProjectedMatchTimes>,
+    /// cumulative delay of the station in seconds, i.e. the delay of its last match;
+    /// negative, if the station is ahead of plan
+    pub delay_seconds: i64,
+    /// projected end of the last match of the station; `None`, if the station has no match
+    pub projected_end: Option<DateTime<Utc>>,
+}
+
+impl StationTiming {
+    fn new(station: u32, tournament: &TournamentBase) -> Self {
+        StationTiming {
+            station,
+            name: tournament
+                .get_station_name(station)
+                .map(str::to_string)
+                .unwrap_or_else(|| format!("Station {station}")),
+            matches: Vec::new(),
+            delay_seconds: 0,
+            projected_end: None,
+        }
+    }
+
+    /// Project the times of `matches` of the station at `now`.
+    fn project(&mut self, mut matches: Vec<StageMatchTimes>, now: DateTime<Utc>) {
+        matches.sort_by_key(|m| (m.planned_start, m.match_id));
+        // earliest time, at which the station is free for the next match
+        let mut free_at: Option<DateTime<Utc>> = None;
+        for times in matches {
+            let projected_start = times.actual_start.unwrap_or_else(|| {
+                let earliest = free_at.map_or(times.planned_start, |f| f.max(times.planned_start));
+                if times.actual_end.is_none() {
+                    earliest.max(now)
+                } else {
+                    earliest
+                }
+            });
+            let projected_end = times.actual_end.unwrap_or_else(|| {
+                // a running match does not end before now
+                (projected_start + times.planned_duration()).max(now)
+            });
+            free_at = Some(projected_end);
+            self.matches.push(ProjectedMatchTimes {
+                times,
+                projected_start,
+                projected_end,
+            });
+        }
+        if let Some(last) = self.matches.last() {
+            self.delay_seconds = last.delay_seconds();
+            self.projected_end = Some(last.projected_end);
+        }
+    }
+}
+
+/// timing dashboard of a stage, see [`Core::load_stage_timing_dashboard`]
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct StageTimingDashboard {
+    pub tournament_id: Uuid,
+    pub stage_id: Uuid,
+    /// time of evaluation; delays of running and pending matches are projected from it
+    pub generated_at: DateTime<Utc>,
+    /// timing of all stations in order of their number
+    pub stations: Vec<StationTiming>,
+    /// planned end of the last match of the stage
+    pub planned_finish: Option<DateTime<Utc>>,
+    /// projected end of the last match of the stage
+    pub projected_finish: Option<DateTime<Utc>>,
+}
+
+impl StageTimingDashboard {
+    /// Evaluate the timing of `matches` of `stage` at `now`. Every allowed station of the
+    /// stage is listed, even if no match belongs to it.
+    pub fn evaluate(
+        tournament: &TournamentBase,
+        stage: &Stage,
+        matches: &[StageMatchTimes],
+        now: DateTime<Utc>,
+    ) -> Self {
+        let mut per_station: BTreeMap<u32, Vec<StageMatchTimes>> = stage
+            .get_allowed_stations(tournament)
+            .map(|station| (station, Vec::new()))
+            .collect();
+        for times in matches {
+            per_station
+                .entry(times.station)
+                .or_default()
+                .push(times.clone());
+        }
+        let stations: Vec<StationTiming> = per_station
+            .into_iter()
+            .map(|(station, matches)| {
+                let mut timing = StationTiming::new(station, tournament);
+                timing.project(matches, now);
+                timing
+            })
+            .collect();
+
+        StageTimingDashboard {
+            tournament_id: tournament.get_id(),
+            stage_id: stage.get_id(),
+            generated_at: now,
+            planned_finish: matches.iter().map(|m| m.planned_end).max(),
+            projected_finish: stations.iter().filter_map(|s| s.projected_end).max(),
+            stations,
+        }
+    }
+
+    /// Delay of the projected finish of the stage in seconds; negative, if the stage is
+    /// ahead of plan.
+    pub fn delay_seconds(&self) -> i64 {
+        match (self.planned_finish, self.projected_finish) {
+            (Some(planned), Some(projected)) => (projected - planned).num_seconds(),
+            _ => 0,
+        }
+    }
+
+    /// Check if the projected finish exceeds the hard time cap of `stage`, if any.
+    pub fn exceeds_time_cap(&self, stage: &Stage) -> bool {
+        match (stage.get_latest_end(), self.projected_finish) {
+            (Some(latest_end), Some(projected)) => projected > latest_end,
+            _ => false,
+        }
+    }
+}
+
+/// new slot of a pending match, see [`compress_schedule`]
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+pub struct RescheduledMatch {
+    pub match_id: Uuid,
+    pub slot: MatchSlot,
+}
+
+/// Re-place the pending matches of `dashboard` on the stations, which become free first
+/// after `now`. Matches keep their order of planned start and their planned duration. A
+/// station is only used, if the stage may use it at the new start. Matches, for which no
+/// station is available, keep their planned slot.
+pub fn compress_schedule(
+    tournament: &TournamentBase,
+    stage: &Stage,
+    dashboard: &StageTimingDashboard,
+    now: DateTime<Utc>,
+) -> Vec<RescheduledMatch> {
+    // stations are free after their last started match
+    let mut free_at: BTreeMap<u32, DateTime<Utc>> = stage
+        .get_allowed_stations(tournament)
+        .map(|station| (station, now))
+        .collect();
+    let mut pending: Vec<&StageMatchTimes> = Vec::new();
+    for station in &dashboard.stations {
+        for projected in &station.matches {
+            if projected.times.is_pending() {
+                pending.push(&projected.times);
+            } else {
+                let free = free_at.entry(station.station).or_insert(now);
+                *free = (*free).max(projected.projected_end);
+            }
+        }
+    }
+    pending.sort_by_key(|m| (m.planned_start, m.station, m.match_id));
+
+    let mut rescheduled = Vec::with_capacity(pending.len());
+    for times in pending {
+        let next = free_at
+            .iter()
+            .filter(|(station, free)| {
+                stage
+                    .get_allowed_stations_at(tournament, **free)
+                    .contains(*station)
+            })
+            .min_by_key(|(station, free)| (**free, **station))
+            .map(|(station, free)| (*station, *free));
+        let slot = match next {
+            Some((station, start_at)) => {
+                free_at.insert(station, start_at + times.planned_duration());
+                MatchSlot { station, start_at }
+            }
+            None => MatchSlot {
+                station: times.station,
+                start_at: times.planned_start,
+            },
+        };
+        rescheduled.push(RescheduledMatch {
+            match_id: times.match_id,
+            slot,
+        });
+    }
+    rescheduled
+}
+
+impl<S> Core<S> {
+    /// Load the stage and its tournament and evaluate the timing dashboard of the stage.
+    pub async fn load_stage_timing_dashboard(
+        &self,
+        stage_id: Uuid,
+    ) -> CoreResult<Option<StageTimingDashboard>> {
+        let Some(stage) = self.database.get_stage_by_id(stage_id).await? else {
+            return Ok(None);
+        };
+        let Some(tournament) = self
+            .database
+            .get_tournament_base(stage.get_tournament_id())
+            .await?
+        else {
+            return Ok(None);
+        };
+        let (_, matches) = self.load_stage_match_times(&tournament, &stage).await?;
+        Ok(Some(StageTimingDashboard::evaluate(
+            &tournament,
+            &stage,
+            &matches,
+            Utc::now(),
+        )))
+    }
+
+    /// Compress the remaining schedule of the stage, see [`compress_schedule`], and save the
+    /// new slots. Entrants of matches with a changed start time are notified. Returns the
+    /// new slots of the pending matches and the dashboard evaluated with these slots.
+    pub async fn compress_stage_schedule(
+        &self,
+        stage_id: Uuid,
+    ) -> CoreResult<Option<(Vec<RescheduledMatch>, StageTimingDashboard)>> {
+        let Some(stage) = self.database.get_stage_by_id(stage_id).await? else {
+            return Ok(None);
+        };
+        let Some(tournament) = self
+            .database
+            .get_tournament_base(stage.get_tournament_id())
+            .await?
+        else {
+            return Ok(None);
+        };
+        let now = Utc::now();
+        let (mut stored, mut matches) = self.load_stage_match_times(&tournament, &stage).await?;
+        let dashboard = StageTimingDashboard::evaluate(&tournament, &stage, &matches, now);
+        let rescheduled = compress_schedule(&tournament, &stage, &dashboard, now);
+        apply_rescheduled(&mut matches, &rescheduled);
+
+        let slots: BTreeMap<Uuid, MatchSlot> =
+            rescheduled.iter().map(|r| (r.match_id, r.slot)).collect();
+        let mut changed: Vec<Match> = Vec::new();
+        let mut time_changes: Vec<MatchTimeChange> = Vec::new();
+        for m in stored.iter_mut() {
+            let Some(slot) = slots.get(m.get_id()) else {
+                continue;
+            };
+            let start_at = slot.start_at.with_timezone(&Local);
+            if m.get_station() as u32 == slot.station && m.get_start_at() == start_at {
+                continue;
+            }
+            if m.get_start_at() != start_at
+                && let Some((entrant_a, entrant_b)) = m.get_entrants()
+            {
+                time_changes.push(MatchTimeChange {
+                    tournament_id: tournament.get_id(),
+                    match_number: m.get_number(),
+                    entrant_ids: vec![*entrant_a, *entrant_b],
+                    old_start: m.get_start_at(),
+                    new_start: start_at,
+                    station: Some(slot.station),
+                });
+            }
+            m.set_slot(slot.station as u16, start_at);
+            changed.push(m.clone());
+        }
+        if !changed.is_empty() {
+            let saved = self.database.save_matches(&changed).await?;
+            self.publish_saved_matches(&saved).await?;
+        }
+        // sandbox tournaments must not leak to integrations
+        if !tournament.is_sandbox() {
+            for change in time_changes.iter() {
+                self.notify_match_time_changed(change).await;
+            }
+        }
+
+        let dashboard = StageTimingDashboard::evaluate(&tournament, &stage, &matches, now);
+        Ok(Some((rescheduled, dashboard)))
+    }
+
+    /// Load the stored matches of `stage` and their times. The planned duration of each match
+    /// is its estimated slot, see [`Core::estimate_match_timing_or_fallback`].
+    async fn load_stage_match_times(
+        &self,
+        tournament: &TournamentBase,
+        stage: &Stage,
+    ) -> CoreResult<(Vec<Match>, Vec<StageMatchTimes>)> {
+        let timing = self.estimate_match_timing_or_fallback(tournament).await?;
+        let duration = Duration::from_std(timing.match_duration + timing.changeover)
+            .unwrap_or_else(|_| Duration::zero());
+        let matches = self
+            .database
+            .list_matches_of_stage_for_update(stage.get_id())
+            .await?;
+        let times = matches
+            .iter()
+            .filter_map(|m| StageMatchTimes::from_match(m, duration))
+            .collect();
+        Ok((matches, times))
+    }
+}
+
+/// Move `matches` to the slots of `rescheduled`, keeping their planned duration.
+pub fn apply_rescheduled(matches: &mut [StageMatchTimes], rescheduled: &[RescheduledMatch]) {
+    let slots: BTreeMap<Uuid, MatchSlot> =
+        rescheduled.iter().map(|r| (r.match_id, r.slot)).collect();
+    for times in matches.iter_mut() {
+        if let Some(slot) = slots.get(&times.match_id) {
+            let duration = times.planned_duration();
+            times.station = slot.station;
+            times.planned_start = slot.start_at;
+            times.planned_end = slot.start_at + duration;
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use chrono::TimeZone;
+
+    fn at(hour: u32, minute: u32) -> DateTime<Utc> {
+        Utc.with_ymd_and_hms(2026, 7, 11, hour, minute, 0).unwrap()
+    }
+
+    fn times(
+        station: u32,
+        planned_start: DateTime<Utc>,
+        actual_start: Option<DateTime<Utc>>,
+        actual_end: Option<DateTime<Utc>>,
+    ) -> StageMatchTimes {
+        StageMatchTimes {
+            match_id: Uuid::new_v4(),
+            station,
+            planned_start,
+            planned_end: planned_start + Duration::minutes(30),
+            actual_start,
+            actual_end,
+        }
+    }
+
+    fn tournament(num_stations: u32) -> TournamentBase {
+        let mut tournament = TournamentBase::default();
+        tournament.set_num_stations(num_stations);
+        tournament
+    }
+
+    #[test]
+    fn given_late_match_when_evaluate_then_delay_propagates_to_stage_finish() {
+        let tournament = tournament(3);
+        let now = at(10, 0);
+        let matches = vec![
+            // station 1: first match ended 15 minutes late, second is pending
+            times(1, at(9, 0), Some(at(9, 5)), Some(at(9, 45))),
+            times(1, at(9, 30), None, None),
+            // station 2: ended ahead of plan
+            times(2, at(9, 0), Some(at(9, 0)), Some(at(9, 20))),
+            // station 3: running beyond its planned end
+            times(3, at(9, 0), Some(at(9, 10)), None),
+        ];
+
+        let dashboard =
+            StageTimingDashboard::evaluate(&tournament, &Stage::default(), &matches, now);
+
+        assert_eq!(dashboard.stations.len(), 3);
+        let first = &dashboard.stations[0];
+        assert_eq!(first.name, "Station 1");
+        assert_eq!(first.matches[1].projected_start, at(10, 0));
+        assert_eq!(first.projected_end, Some(at(10, 30)));
+        assert_eq!(first.delay_seconds, 30 * 60);
+        assert_eq!(dashboard.stations[1].delay_seconds, -10 * 60);
+        let third = &dashboard.stations[2];
+        assert_eq!(third.projected_end, Some(at(10, 0)));
+        assert_eq!(third.delay_seconds, 30 * 60);
+        assert_eq!(dashboard.planned_finish, Some(at(10, 0)));
+        assert_eq!(dashboard.projected_finish, Some(at(10, 30)));
+        assert_eq!(dashboard.delay_seconds(), 30 * 60);
+
+        let mut stage = Stage::default();
+        stage.set_latest_end(Some(at(10, 15)));
+        assert!(dashboard.exceeds_time_cap(&stage));
+    }
+
+    #[test]
+    fn given_free_station_when_compress_schedule_then_pending_matches_move_to_it() {
+        let tournament = tournament(2);
+        let now = at(9, 30);
+        let mut matches = vec![
+            // station 1 is blocked by a long running match
+            times(1, at(9, 0), Some(at(9, 0)), None),
+            times(1, at(9, 30), None, None),
+            times(1, at(10, 0), None, None),
+            // station 2 finished its only match
+            times(2, at(9, 0), Some(at(9, 0)), Some(at(9, 25))),
+        ];
+        let stage = Stage::default();
+        let dashboard = StageTimingDashboard::evaluate(&tournament, &stage, &matches, now);
+        assert_eq!(dashboard.projected_finish, Some(at(10, 30)));
+
+        let rescheduled = compress_schedule(&tournament, &stage, &dashboard, now);
+
+        assert_eq!(rescheduled.len(), 2);
+        assert_eq!(
+            rescheduled[0].slot,
+            MatchSlot {
+                station: 1,
+                start_at: at(9, 30)
+            }
+        );
+        assert_eq!(
+            rescheduled[1].slot,
+            MatchSlot {
+                station: 2,
+                start_at: at(9, 30)
+            }
+        );
+        apply_rescheduled(&mut matches, &rescheduled);
+        let compressed = StageTimingDashboard::evaluate(&tournament, &stage, &matches, now);
+        assert_eq!(compressed.projected_finish, Some(at(10, 0)));
+    }
+}
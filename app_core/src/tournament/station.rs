@@ -0,0 +1,176 @@
+//! Named stations (e.g. courts or tables) of a tournament, their bulk setup and their
+//! availability over the tournament
+
+use crate::utils::{
+    normalize::normalize_ws,
+    validation::{FieldError, ValidationErrors, ValidationResult},
+};
+use chrono::{DateTime, Utc};
+use serde::{Deserialize, Serialize};
+use uuid::Uuid;
+
+/// maximum number of stations, which may be created in one setup
+pub const MAX_STATIONS_PER_SETUP: u32 = 200;
+
+/// named station of a tournament; stations are numbered by their position starting with 1
+#[derive(Debug, Clone, Default, PartialEq, Eq, Serialize, Deserialize)]
+pub struct Station {
+    /// name of station, e.g. `Court 1`
+    name: String,
+    /// venue of station, e.g. name of sports hall; empty, if not given
+    #[serde(default)]
+    venue: String,
+}
+
+impl Station {
+    /// Create a new station.
+    pub fn new(name: impl Into<String>, venue: impl Into<String>) -> Self {
+        let mut station = Station::default();
+        station.set_name(name).set_venue(venue);
+        station
+    }
+
+    /// Get the name of the station.
+    pub fn get_name(&self) -> &str {
+        &self.name
+    }
+
+    /// Get the venue of the station.
+    pub fn get_venue(&self) -> &str {
+        &self.venue
+    }
+
+    /// Set the name of the station; whitespace is normalized.
+    pub fn set_name(&mut self, name: impl Into<String>) -> &mut Self {
+        self.name = normalize_ws(name.into());
+        self
+    }
+
+    /// Set the venue of the station; whitespace is normalized.
+    pub fn set_venue(&mut self, venue: impl Into<String>) -> &mut Self {
+        self.venue = normalize_ws(venue.into());
+        self
+    }
+}
+
+/// Setup of numbered stations, e.g. "create 8 courts named Court 1..8 at venue X".
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct StationSetup {
+    /// prefix of station names, which is followed by the number of the station
+    pub name_prefix: String,
+    /// number of stations to create
+    pub count: u32,
+    /// number of the first station
+    pub first_number: u32,
+    /// venue of all stations; may be empty
+    pub venue: String,
+}
+
+impl Default for StationSetup {
+    fn default() -> Self {
+        StationSetup {
+            name_prefix: String::from("Station"),
+            count: 1,
+            first_number: 1,
+            venue: String::new(),
+        }
+    }
+}
+
+impl StationSetup {
+    /// Generate the stations of the setup.
+    pub fn stations(&self) -> Vec<Station> {
+        (self.first_number..self.first_number + self.count)
+            .map(|number| Station::new(format!("{} {number}", self.name_prefix), &self.venue))
+            .collect()
+    }
+
+    /// Validate the setup of the stations of tournament `object_id`.
+    pub fn validate(&self, object_id: Uuid) -> ValidationResult<()> {
+        let mut errs = ValidationErrors::new();
+
+        if self.name_prefix.trim().is_empty() {
+            errs.add(
+                FieldError::builder()
+                    .set_field(String::from("name_prefix"))
+                    .add_required()
+                    .set_object_id(object_id)
+                    .build(),
+            );
+        }
+
+        if self.count == 0 || self.count > MAX_STATIONS_PER_SETUP {
+            errs.add(
+                FieldError::builder()
+                    .set_field(String::from("count"))
+                    .add_message(format!(
+                        "number of stations must be between 1 and {MAX_STATIONS_PER_SETUP}"
+                    ))
+                    .set_object_id(object_id)
+                    .build(),
+            );
+        }
+
+        if !errs.is_empty() {
+            return Err(errs);
+        }
+        Ok(())
+    }
+}
+
+/// Number of stations, which are available from `start` until the start of the next window,
+/// e.g. 4 courts in the morning and 6 courts in the afternoon. The available stations are the
+/// first stations of the tournament.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Serialize, Deserialize)]
+pub struct StationWindow {
+    /// start of window
+    pub start: DateTime<Utc>,
+    /// number of available stations; zero, if no matches are played, e.g. in midday break
+    pub num_stations: u32,
+}
+
+impl StationWindow {
+    /// Create a new station window.
+    pub fn new(start: DateTime<Utc>, num_stations: u32) -> Self {
+        StationWindow {
+            start,
+            num_stations,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn setup_numbers_station_names() {
+        let setup = StationSetup {
+            name_prefix: String::from("Court"),
+            count: 3,
+            first_number: 4,
+            venue: String::from("  Main   Hall "),
+        };
+
+        let stations = setup.stations();
+
+        assert_eq!(
+            stations.iter().map(Station::get_name).collect::<Vec<_>>(),
+            vec!["Court 4", "Court 5", "Court 6"]
+        );
+        assert!(stations.iter().all(|s| s.get_venue() == "Main Hall"));
+    }
+
+    #[test]
+    fn setup_requires_prefix_and_count() {
+        let setup = StationSetup {
+            name_prefix: String::from(" "),
+            count: 0,
+            ..Default::default()
+        };
+
+        let errs = setup.validate(Uuid::nil()).unwrap_err();
+
+        assert_eq!(errs.errors.len(), 2);
+    }
+}
@@ -0,0 +1,65 @@
+//! use an existing tournament as template for a new tournament
+
+use super::{TournamentBase, TournamentState};
+use crate::{Core, CoreResult, utils::id_version::IdVersion};
+use uuid::Uuid;
+
+impl<S> Core<S> {
+    /// Deep copy of the tournament with id `source_id`.
+    ///
+    /// The copy gets fresh ids for the tournament base and all stages, is named `new_name`
+    /// and starts in state `Draft`. Groups are defined by the number of groups of each
+    /// stage and are therefore copied with their stages.
+    ///
+    /// Settings, which are bound to the date or location of the source, are not copied:
+    /// check-in deadline, station windows, address and venue. The copy is no sandbox, even
+    /// if the source is.
+    /// Returns `None`, if no tournament with `source_id` exists.
+    ///
+    /// The tournament base and all stages are saved in one transaction: if saving a stage
+    /// fails, no object of the copy is kept and the error is returned.
+    pub async fn clone_tournament(
+        &self,
+        source_id: Uuid,
+        new_name: impl Into<String>,
+    ) -> CoreResult<Option<TournamentBase>> {
+        self.with_transaction(|core| async move {
+            let mut base_core = core.as_tournament_base_state();
+            let Some(mut copy) = base_core.load(source_id).await?.cloned() else {
+                return Ok(None);
+            };
+
+            // copy tournament base
+            copy.set_id_version(IdVersion::NewWithId(Uuid::new_v4()))
+                .set_name(new_name)
+                .set_tournament_state(TournamentState::Draft)
+                .set_archived_at(None)
+                .set_created_at(None)
+                .set_sandbox(false)
+                .set_check_in_deadline(None)
+                .set_station_windows(Vec::new())
+                .set_address_id(None)
+                .set_venue_id(None);
+            *base_core.get_mut() = copy;
+            let copy = base_core.save().await?.clone();
+
+            // copy stages
+            let mut source_stage_core = core.as_stage_state(source_id);
+            let mut copy_stage_core = core.as_stage_state(copy.get_id());
+            for (stage_id, _number) in source_stage_core.list_stage_ids_of_tournament().await? {
+                let Some(mut stage_copy) = source_stage_core.load_by_id(stage_id).await?.copied()
+                else {
+                    continue;
+                };
+                stage_copy
+                    .set_id_version(IdVersion::NewWithId(Uuid::new_v4()))
+                    .set_tournament_id(copy.get_id());
+                *copy_stage_core.get_mut() = stage_copy;
+                copy_stage_core.save().await?;
+            }
+
+            Ok(Some(copy))
+        })
+        .await
+    }
+}
@@ -0,0 +1,113 @@
+//! database transactions spanning multiple saves of core
+//!
+//! Saves of core touch multiple tables, e.g. a cloned tournament consists of a tournament base
+//! and its stages. [`Core::with_transaction`] executes these saves in one database
+//! transaction, so that a failing save rolls back all previous saves.
+
+use crate::{
+    ClientRegistryPort, Core, CoreResult, CrMsg, CrResult, CrTopic, DomainEvent, InitState,
+    WebhookEventData,
+};
+use async_trait::async_trait;
+use std::{
+    mem,
+    sync::{Arc, Mutex},
+};
+use tracing::warn;
+
+/// Events of a transaction, which are published after commit. Clients, webhook receivers and
+/// subscribers of domain events must not be notified of changes, which are rolled back.
+pub(crate) struct TransactionEvents {
+    messages: Mutex<Vec<(CrTopic, CrMsg)>>,
+    webhook_events: Mutex<Vec<WebhookEventData>>,
+    domain_events: Mutex<Vec<DomainEvent>>,
+}
+
+impl TransactionEvents {
+    fn new() -> Self {
+        TransactionEvents {
+            messages: Mutex::new(Vec::new()),
+            webhook_events: Mutex::new(Vec::new()),
+            domain_events: Mutex::new(Vec::new()),
+        }
+    }
+
+    /// Defer dispatch of webhook event `data` until commit of the transaction.
+    pub(crate) fn defer_webhook_event(&self, data: WebhookEventData) {
+        self.webhook_events.lock().unwrap().push(data);
+    }
+
+    /// Defer emission of domain `event` until commit of the transaction.
+    pub(crate) fn defer_domain_event(&self, event: DomainEvent) {
+        self.domain_events.lock().unwrap().push(event);
+    }
+
+    fn take_messages(&self) -> Vec<(CrTopic, CrMsg)> {
+        mem::take(&mut *self.messages.lock().unwrap())
+    }
+
+    fn take_webhook_events(&self) -> Vec<WebhookEventData> {
+        mem::take(&mut *self.webhook_events.lock().unwrap())
+    }
+
+    fn take_domain_events(&self) -> Vec<DomainEvent> {
+        mem::take(&mut *self.domain_events.lock().unwrap())
+    }
+}
+
+/// client registry of a transaction: messages are collected until commit
+#[async_trait]
+impl ClientRegistryPort for TransactionEvents {
+    async fn publish(&self, topic: CrTopic, msg: CrMsg) -> CrResult<()> {
+        self.messages.lock().unwrap().push((topic, msg));
+        Ok(())
+    }
+}
+
+impl<S> Core<S> {
+    /// Execute `work` in one database transaction.
+    ///
+    /// `work` gets a core, whose database operations are part of the transaction. If `work`
+    /// returns `Ok`, the transaction is committed, otherwise it is rolled back and the error
+    /// of `work` is returned. Messages to the client registry, webhook events and domain
+    /// events of `work` are published after commit; they are discarded on rollback.
+    ///
+    /// If this core is already part of a transaction, `work` is executed in this transaction.
+    pub async fn with_transaction<T, F, Fut>(&self, work: F) -> CoreResult<T>
+    where
+        F: FnOnce(Core<InitState>) -> Fut,
+        Fut: Future<Output = CoreResult<T>>,
+    {
+        if self.transaction_events.is_some() {
+            return work(self.switch_state(InitState {})).await;
+        }
+        let transaction = self.database.begin_transaction().await?;
+        let events = Arc::new(TransactionEvents::new());
+        let mut core = self.switch_state(InitState {});
+        core.database = transaction.clone();
+        core.client_registry = events.clone();
+        core.transaction_events = Some(events.clone());
+
+        match work(core).await {
+            Ok(value) => {
+                transaction.commit().await?;
+                for (topic, msg) in events.take_messages() {
+                    self.client_registry.publish(topic, msg).await?;
+                }
+                for data in events.take_webhook_events() {
+                    self.dispatch_webhook_event(data).await;
+                }
+                for event in events.take_domain_events() {
+                    self.emit_domain_event(event).await;
+                }
+                Ok(value)
+            }
+            Err(e) => {
+                if let Err(rollback_err) = transaction.rollback().await {
+                    warn!(error = %rollback_err, "transaction_rollback_failed");
+                }
+                Err(e)
+            }
+        }
+    }
+}
@@ -0,0 +1,108 @@
+//! undo/redo history of edits of an object in an editor
+
+/// maximum number of edits, which can be undone
+pub const EDIT_HISTORY_LIMIT: usize = 50;
+
+/// History of snapshots of an object before and after edits.
+///
+/// Before each edit the current state is recorded. Undo returns the state before the last
+/// edit and keeps the current state for redo. A new edit discards all edits, which may be
+/// redone.
+#[derive(Debug, Clone)]
+pub struct EditHistory<T> {
+    undo: Vec<T>,
+    redo: Vec<T>,
+}
+
+impl<T> Default for EditHistory<T> {
+    fn default() -> Self {
+        EditHistory {
+            undo: Vec::new(),
+            redo: Vec::new(),
+        }
+    }
+}
+
+impl<T> EditHistory<T> {
+    /// Record the state `before` an edit. The oldest state is dropped, if more than
+    /// [`EDIT_HISTORY_LIMIT`] states are recorded.
+    pub fn record(&mut self, before: T) {
+        self.undo.push(before);
+        if self.undo.len() > EDIT_HISTORY_LIMIT {
+            self.undo.remove(0);
+        }
+        self.redo.clear();
+    }
+
+    /// Undo the last edit: returns the state before the last edit, if any. `current` is kept
+    /// for redo.
+    pub fn undo(&mut self, current: T) -> Option<T> {
+        let previous = self.undo.pop()?;
+        self.redo.push(current);
+        Some(previous)
+    }
+
+    /// Redo the last undone edit: returns the state after the edit, if any. `current` is kept
+    /// for undo.
+    pub fn redo(&mut self, current: T) -> Option<T> {
+        let next = self.redo.pop()?;
+        self.undo.push(current);
+        Some(next)
+    }
+
+    /// Check if an edit can be undone.
+    pub fn can_undo(&self) -> bool {
+        !self.undo.is_empty()
+    }
+
+    /// Check if an undone edit can be redone.
+    pub fn can_redo(&self) -> bool {
+        !self.redo.is_empty()
+    }
+
+    /// Remove all recorded states, e.g. if another object is loaded in the editor.
+    pub fn clear(&mut self) {
+        self.undo.clear();
+        self.redo.clear();
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn undo_and_redo_walk_through_recorded_states() {
+        let mut history = EditHistory::default();
+        history.record(1);
+        history.record(2);
+
+        assert_eq!(history.undo(3), Some(2));
+        assert_eq!(history.undo(2), Some(1));
+        assert_eq!(history.undo(1), None);
+        assert!(history.can_redo());
+        assert_eq!(history.redo(1), Some(2));
+
+        // new edit discards redo
+        history.record(2);
+        assert!(!history.can_redo());
+        assert_eq!(history.undo(4), Some(2));
+        assert_eq!(history.undo(2), Some(1));
+        assert!(!history.can_undo());
+    }
+
+    #[test]
+    fn oldest_state_is_dropped_at_limit() {
+        let mut history = EditHistory::default();
+        for state in 0..=EDIT_HISTORY_LIMIT {
+            history.record(state);
+        }
+        let mut current = EDIT_HISTORY_LIMIT + 1;
+        let mut oldest = current;
+        while let Some(previous) = history.undo(current) {
+            oldest = previous;
+            current = previous;
+        }
+        assert_eq!(oldest, 1);
+    }
+}
@@ -0,0 +1,182 @@
+//! typed filters for list queries
+//!
+//! A `Filter<T>` is a conjunction of conditions on objects of type `T`. Database ports
+//! translate the conditions to queries, while `Filter::matches` defines their semantics
+//! for in memory implementations (e.g. fakes). Adding a new condition only requires a new
+//! variant of `T::Condition` and its translation in the database port.
+
+use serde::{Deserialize, Serialize, de::DeserializeOwned};
+use std::fmt::Debug;
+
+/// Objects, which can be listed with a `Filter`.
+pub trait Filterable {
+    /// single condition on an object
+    type Condition: Debug + Clone + PartialEq + Serialize + DeserializeOwned;
+
+    /// Returns true, if `self` fulfills `condition`.
+    fn matches(&self, condition: &Self::Condition) -> bool;
+}
+
+/// Conjunction of conditions with an optional limit of results.
+#[derive(Serialize, Deserialize)]
+#[serde(bound = "")]
+pub struct Filter<T: Filterable> {
+    conditions: Vec<T::Condition>,
+    limit: Option<usize>,
+}
+
+impl<T: Filterable> Filter<T> {
+    /// Filter without conditions and limit.
+    pub fn new() -> Self {
+        Filter {
+            conditions: Vec::new(),
+            limit: None,
+        }
+    }
+
+    /// Add `condition` to the filter.
+    pub fn with(mut self, condition: T::Condition) -> Self {
+        self.conditions.push(condition);
+        self
+    }
+
+    /// Add `condition` to the filter, if `add` is true.
+    pub fn with_if(self, add: bool, condition: T::Condition) -> Self {
+        if add { self.with(condition) } else { self }
+    }
+
+    /// Limit number of results.
+    pub fn limit(mut self, limit: Option<usize>) -> Self {
+        self.limit = limit;
+        self
+    }
+
+    /// Get conditions of the filter.
+    pub fn get_conditions(&self) -> &[T::Condition] {
+        &self.conditions
+    }
+
+    /// Get limit of results.
+    pub fn get_limit(&self) -> Option<usize> {
+        self.limit
+    }
+
+    /// Returns true, if `object` fulfills all conditions.
+    pub fn matches(&self, object: &T) -> bool {
+        self.conditions.iter().all(|c| object.matches(c))
+    }
+}
+
+impl<T: Filterable> Default for Filter<T> {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+// manual impls, since derives would require `T` itself to implement the traits
+
+impl<T: Filterable> Debug for Filter<T> {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("Filter")
+            .field("conditions", &self.conditions)
+            .field("limit", &self.limit)
+            .finish()
+    }
+}
+
+impl<T: Filterable> Clone for Filter<T> {
+    fn clone(&self) -> Self {
+        Filter {
+            conditions: self.conditions.clone(),
+            limit: self.limit,
+        }
+    }
+}
+
+impl<T: Filterable> PartialEq for Filter<T> {
+    fn eq(&self, other: &Self) -> bool {
+        self.conditions == other.conditions && self.limit == other.limit
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{TournamentBase, TournamentBaseCondition, TournamentState, TournamentType};
+    use chrono::{Duration, Utc};
+    use uuid::Uuid;
+
+    fn tournament(name: &str, state: TournamentState) -> TournamentBase {
+        let mut tb = TournamentBase::default();
+        tb.set_name(name)
+            .set_tournament_state(state)
+            .set_created_at(Some(Utc::now()));
+        tb
+    }
+
+    #[test]
+    fn empty_filter_matches_all() {
+        let filter = Filter::<TournamentBase>::new();
+        assert!(filter.matches(&tournament("Cup", TournamentState::Draft)));
+    }
+
+    #[test]
+    fn conditions_are_combined_with_and() {
+        let tb = tournament("Summer Cup", TournamentState::Finished);
+        let filter = Filter::new()
+            .with(TournamentBaseCondition::NameContains("cup".to_string()))
+            .with(TournamentBaseCondition::StateIn(vec![
+                TournamentState::Draft,
+                TournamentState::Finished,
+            ]));
+        assert!(filter.matches(&tb));
+
+        let filter = filter.with(TournamentBaseCondition::SportIs(Uuid::new_v4()));
+        assert!(!filter.matches(&tb));
+    }
+
+    #[test]
+    fn created_between_is_half_open() {
+        let tb = tournament("Cup", TournamentState::Draft);
+        let created_at = tb.get_created_at().unwrap();
+        let matches = |from, to| {
+            Filter::new()
+                .with(TournamentBaseCondition::CreatedBetween { from, to })
+                .matches(&tb)
+        };
+        assert!(matches(Some(created_at), None));
+        assert!(!matches(None, Some(created_at)));
+        assert!(matches(None, Some(created_at + Duration::seconds(1))));
+        assert!(matches(None, None));
+    }
+
+    #[test]
+    fn exclusions_and_limit() {
+        let mut tb = tournament("Cup", TournamentState::Draft);
+        tb.set_tournament_type(TournamentType::Adhoc);
+        let filter = Filter::new()
+            .with_if(true, TournamentBaseCondition::NotAdhoc)
+            .limit(Some(5));
+        assert!(!filter.matches(&tb));
+        assert_eq!(filter.get_limit(), Some(5));
+
+        tb.set_tournament_type(TournamentType::Scheduled)
+            .set_archived_at(Some(Utc::now()));
+        assert!(filter.matches(&tb));
+        assert!(
+            !filter
+                .with_if(true, TournamentBaseCondition::NotArchived)
+                .matches(&tb)
+        );
+    }
+
+    #[test]
+    fn serde_roundtrip() {
+        let filter = Filter::<TournamentBase>::new()
+            .with(TournamentBaseCondition::NameContains("cup".to_string()))
+            .limit(Some(10));
+        let json = serde_json::to_string(&filter).unwrap();
+        let back: Filter<TournamentBase> = serde_json::from_str(&json).unwrap();
+        assert_eq!(back, filter);
+    }
+}
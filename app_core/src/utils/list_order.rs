@@ -0,0 +1,260 @@
+//! sort column, direction and offset of list queries
+//!
+//! Long lists are loaded page by page: a `ListOrder<T>` sorts objects of type `T` by one of
+//! their columns and skips the objects of previous pages, while the limit of the query is the
+//! size of a page. Database ports translate the sort column to queries and break ties by id,
+//! so that pages neither overlap nor miss objects. `ListOrder::apply` defines the order for
+//! in memory implementations (e.g. fakes).
+
+use crate::{CoreError, utils::traits::ObjectIdVersion};
+use serde::{Deserialize, Serialize, de::DeserializeOwned};
+use std::{
+    cmp::Ordering,
+    fmt::{self, Debug, Display},
+    str::FromStr,
+};
+
+/// Objects, which can be listed in a `ListOrder`.
+pub trait Sortable: ObjectIdVersion {
+    /// column, by which objects are sorted; displayed and parsed as query parameter
+    type SortColumn: Debug
+        + Clone
+        + Copy
+        + PartialEq
+        + Default
+        + Display
+        + FromStr
+        + Serialize
+        + DeserializeOwned;
+
+    /// Compare `self` with `other` by `column` in ascending order.
+    fn compare(&self, other: &Self, column: Self::SortColumn) -> Ordering;
+}
+
+/// direction of sorted lists
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default, Serialize, Deserialize)]
+pub enum SortDirection {
+    #[default]
+    Ascending,
+    Descending,
+}
+
+impl SortDirection {
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            SortDirection::Ascending => "asc",
+            SortDirection::Descending => "desc",
+        }
+    }
+
+    /// Get the opposite direction.
+    pub fn toggled(self) -> Self {
+        match self {
+            SortDirection::Ascending => SortDirection::Descending,
+            SortDirection::Descending => SortDirection::Ascending,
+        }
+    }
+}
+
+impl Display for SortDirection {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{}", self.as_str())
+    }
+}
+
+impl FromStr for SortDirection {
+    type Err = CoreError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        [SortDirection::Ascending, SortDirection::Descending]
+            .into_iter()
+            .find(|d| d.as_str() == s)
+            .ok_or_else(|| CoreError::ParsingError(format!("unknown sort direction: {s}")))
+    }
+}
+
+/// Sort column, direction and number of skipped objects of a list.
+#[derive(Serialize, Deserialize)]
+#[serde(bound = "")]
+pub struct ListOrder<T: Sortable> {
+    sort_by: T::SortColumn,
+    direction: SortDirection,
+    offset: usize,
+}
+
+impl<T: Sortable> ListOrder<T> {
+    /// Order by the default column in ascending direction without offset.
+    pub fn new() -> Self {
+        ListOrder {
+            sort_by: T::SortColumn::default(),
+            direction: SortDirection::default(),
+            offset: 0,
+        }
+    }
+
+    /// Sort by `column` in `direction`.
+    pub fn sort_by(mut self, column: T::SortColumn, direction: SortDirection) -> Self {
+        self.sort_by = column;
+        self.direction = direction;
+        self
+    }
+
+    /// Skip `offset` objects, e.g. the objects of previous pages.
+    pub fn offset(mut self, offset: usize) -> Self {
+        self.offset = offset;
+        self
+    }
+
+    /// Get sort column.
+    pub fn get_sort_by(&self) -> T::SortColumn {
+        self.sort_by
+    }
+
+    /// Get sort direction.
+    pub fn get_direction(&self) -> SortDirection {
+        self.direction
+    }
+
+    /// Get number of skipped objects.
+    pub fn get_offset(&self) -> usize {
+        self.offset
+    }
+
+    /// Returns true, if objects are sorted in descending direction.
+    pub fn is_descending(&self) -> bool {
+        self.direction == SortDirection::Descending
+    }
+
+    /// Sort `objects`, skip `offset` of them and keep at most `limit`. Ties are broken by id
+    /// in ascending direction.
+    pub fn apply(&self, mut objects: Vec<T>, limit: Option<usize>) -> Vec<T> {
+        objects.sort_by(|a, b| {
+            let ordering = a.compare(b, self.sort_by);
+            let ordering = if self.is_descending() {
+                ordering.reverse()
+            } else {
+                ordering
+            };
+            ordering.then_with(|| {
+                a.get_id_version()
+                    .get_id()
+                    .cmp(&b.get_id_version().get_id())
+            })
+        });
+        objects
+            .into_iter()
+            .skip(self.offset)
+            .take(limit.unwrap_or(usize::MAX))
+            .collect()
+    }
+}
+
+impl<T: Sortable> Default for ListOrder<T> {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+// manual impls, since derives would require `T` itself to implement the traits
+
+impl<T: Sortable> Debug for ListOrder<T> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("ListOrder")
+            .field("sort_by", &self.sort_by)
+            .field("direction", &self.direction)
+            .field("offset", &self.offset)
+            .finish()
+    }
+}
+
+impl<T: Sortable> Clone for ListOrder<T> {
+    fn clone(&self) -> Self {
+        *self
+    }
+}
+
+impl<T: Sortable> Copy for ListOrder<T> {}
+
+impl<T: Sortable> PartialEq for ListOrder<T> {
+    fn eq(&self, other: &Self) -> bool {
+        self.sort_by == other.sort_by
+            && self.direction == other.direction
+            && self.offset == other.offset
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{PostalAddress, PostalAddressSortColumn, utils::id_version::IdVersion};
+    use uuid::Uuid;
+
+    fn address(name: &str, locality: &str) -> PostalAddress {
+        let mut pa = PostalAddress::new(IdVersion::new(Uuid::new_v4(), Some(0)));
+        pa.set_name(name).set_locality(locality);
+        pa
+    }
+
+    fn names(addresses: &[PostalAddress]) -> Vec<&str> {
+        addresses.iter().map(|a| a.get_name()).collect()
+    }
+
+    #[test]
+    fn default_order_sorts_by_default_column_ascending() {
+        let addresses = vec![address("b", "x"), address("C", "y"), address("a", "z")];
+        let sorted = ListOrder::new().apply(addresses, None);
+        assert_eq!(names(&sorted), vec!["a", "b", "C"]);
+    }
+
+    #[test]
+    fn column_direction_offset_and_limit() {
+        let addresses = vec![
+            address("a", "Berlin"),
+            address("b", "Aachen"),
+            address("c", "Dresden"),
+            address("d", "Cottbus"),
+        ];
+        let order = ListOrder::new()
+            .sort_by(PostalAddressSortColumn::Locality, SortDirection::Descending)
+            .offset(1);
+        let page = order.apply(addresses, Some(2));
+        assert_eq!(names(&page), vec!["d", "a"]);
+    }
+
+    #[test]
+    fn ties_are_broken_by_id() {
+        let addresses = vec![
+            address("Cup", "x"),
+            address("Cup", "y"),
+            address("Cup", "z"),
+        ];
+        let mut ids: Vec<_> = addresses.iter().map(|a| a.get_id()).collect();
+        ids.sort();
+        for direction in [SortDirection::Ascending, SortDirection::Descending] {
+            let sorted = ListOrder::new()
+                .sort_by(PostalAddressSortColumn::Name, direction)
+                .apply(addresses.clone(), None);
+            let sorted_ids: Vec<_> = sorted.iter().map(|a| a.get_id()).collect();
+            assert_eq!(sorted_ids, ids);
+        }
+    }
+
+    #[test]
+    fn serde_roundtrip_and_parse_direction() {
+        let order = ListOrder::<PostalAddress>::new()
+            .sort_by(
+                PostalAddressSortColumn::PostalCode,
+                SortDirection::Descending,
+            )
+            .offset(20);
+        let json = serde_json::to_string(&order).unwrap();
+        let back: ListOrder<PostalAddress> = serde_json::from_str(&json).unwrap();
+        assert_eq!(back, order);
+
+        assert_eq!(
+            "desc".parse::<SortDirection>().unwrap(),
+            SortDirection::Descending
+        );
+        assert!("down".parse::<SortDirection>().is_err());
+    }
+}
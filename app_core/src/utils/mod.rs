@@ -1,6 +1,9 @@
 // utils for core
 
+pub mod edit_history;
+pub mod filter;
 pub mod id_version;
+pub mod list_order;
 pub mod namespace;
 pub mod normalize;
 pub mod traits;
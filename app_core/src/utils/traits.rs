@@ -6,6 +6,9 @@ use uuid::Uuid;
 
 // --- Traits for Object Identification ---
 
+// derive macros of the traits, e.g. `#[derive(ObjectIdVersion, ObjectNumber)]`
+pub use app_core_derive::{ObjectIdVersion, ObjectNumber};
+
 pub trait ObjectIdVersion {
     fn get_id_version(&self) -> IdVersion;
 }
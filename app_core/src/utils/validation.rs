@@ -1,5 +1,6 @@
 // tools for validation of input
 
+use crate::Language;
 use serde::{Deserialize, Serialize};
 use std::{
     collections::HashMap,
@@ -59,6 +60,84 @@ impl FieldError {
     pub fn get_params(&self) -> &HashMap<String, String> {
         &self.params
     }
+    /// Render the error in `language` from the message template of its code.
+    ///
+    /// English keeps the message given at creation of the error, since it is usually more
+    /// specific than the template. Errors without template fall back to their message.
+    pub fn render(&self, language: Language) -> String {
+        let template = match language {
+            Language::En if !self.message.is_empty() => None,
+            _ => message_template(&self.code, language),
+        };
+        let Some(template) = template else {
+            if self.message.is_empty() {
+                return format!("{}: {}", self.field, self.code);
+            }
+            return self.message.clone();
+        };
+        let mut rendered = template.replace("{field}", &self.field.replace('_', " "));
+        let mut unused_params: Vec<_> = self
+            .params
+            .iter()
+            .filter(|(key, _)| !template.contains(&format!("{{{key}}}")))
+            .map(|(key, value)| format!("{key}: {value}"))
+            .collect();
+        for (key, value) in self.params.iter() {
+            rendered = rendered.replace(&format!("{{{key}}}"), value);
+        }
+        if !unused_params.is_empty() {
+            unused_params.sort();
+            rendered = format!("{rendered} ({})", unused_params.join(", "));
+        }
+        rendered
+    }
+}
+
+/// Get the message template of error `code` in `language`.
+///
+/// Templates refer to the field by `{field}` and to params by their key, e.g. `{row}`.
+/// Languages without translations fall back to English.
+pub fn message_template(code: &str, language: Language) -> Option<&'static str> {
+    let (en, de) = match code {
+        "required" => ("{field} is required", "{field} ist erforderlich"),
+        "invalid_format" => (
+            "{field} has an invalid format",
+            "{field} hat ein ungültiges Format",
+        ),
+        "invalid_value" => (
+            "{field} has an invalid value",
+            "{field} hat einen ungültigen Wert",
+        ),
+        "too_long" => ("{field} is too long", "{field} ist zu lang"),
+        "too_many" => (
+            "{field} has too many entries",
+            "{field} hat zu viele Einträge",
+        ),
+        "out_of_range" => (
+            "{field} is out of range",
+            "{field} liegt außerhalb des erlaubten Bereichs",
+        ),
+        "duplicate" => ("{field} is already in use", "{field} ist bereits vergeben"),
+        "not_supported" => ("{field} is not supported", "{field} wird nicht unterstützt"),
+        "empty_note" => ("note must not be empty", "Notiz darf nicht leer sein"),
+        "unknown_group" => (
+            "{field} refers to an unknown group",
+            "{field} verweist auf eine unbekannte Gruppe",
+        ),
+        "foreign_tournament" => (
+            "{field} belongs to another tournament",
+            "{field} gehört zu einem anderen Turnier",
+        ),
+        "state_change_in_diff" => (
+            "tournament state cannot be changed together with other changes",
+            "Turnierstatus kann nicht zusammen mit anderen Änderungen geändert werden",
+        ),
+        _ => return None,
+    };
+    match language {
+        Language::De => Some(de),
+        Language::En | Language::Fr | Language::Es => Some(en),
+    }
 }
 
 impl FieldError {
@@ -100,6 +179,10 @@ impl ValidationErrors {
     pub fn is_empty(&self) -> bool {
         self.errors.is_empty()
     }
+    /// Render all errors in `language`, see [`FieldError::render`].
+    pub fn render(&self, language: Language) -> Vec<String> {
+        self.errors.iter().map(|e| e.render(language)).collect()
+    }
 }
 
 pub type ValidationResult<T> = Result<T, ValidationErrors>;
@@ -178,3 +261,75 @@ impl FieldErrorBuilder<Field, Uuid> {
         }
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn field_error(code: &str, message: &str) -> FieldError {
+        FieldError::builder()
+            .set_field("num_entrants")
+            .add_user_defined_code(code)
+            .add_message(message)
+            .set_object_id(Uuid::nil())
+            .build()
+    }
+
+    #[test]
+    fn given_code_when_render_then_template_in_language() {
+        let err = field_error("required", "");
+        assert_eq!(err.render(Language::En), "num entrants is required");
+        assert_eq!(err.render(Language::De), "num entrants ist erforderlich");
+        // no french catalog yet
+        assert_eq!(err.render(Language::Fr), "num entrants is required");
+    }
+
+    #[test]
+    fn given_message_when_render_then_english_keeps_message() {
+        let err = field_error("out_of_range", "must be at least 2");
+        assert_eq!(err.render(Language::En), "must be at least 2");
+        assert_eq!(
+            err.render(Language::De),
+            "num entrants liegt außerhalb des erlaubten Bereichs"
+        );
+    }
+
+    #[test]
+    fn given_unknown_code_when_render_then_display() {
+        let err = field_error("custom", "custom message");
+        assert_eq!(err.render(Language::De), "custom message");
+    }
+
+    #[test]
+    fn given_params_when_render_then_unused_params_are_appended() {
+        let err = FieldError::builder()
+            .set_field("name")
+            .add_required()
+            .add_params(String::from("row"), "3")
+            .set_object_id(Uuid::nil())
+            .build();
+        assert_eq!(err.render(Language::De), "name ist erforderlich (row: 3)");
+    }
+
+    #[test]
+    fn all_codes_of_catalog_are_translated() {
+        for code in [
+            "required",
+            "invalid_format",
+            "invalid_value",
+            "too_long",
+            "too_many",
+            "out_of_range",
+            "duplicate",
+            "not_supported",
+            "empty_note",
+            "unknown_group",
+            "foreign_tournament",
+            "state_change_in_diff",
+        ] {
+            let en = message_template(code, Language::En).unwrap();
+            let de = message_template(code, Language::De).unwrap();
+            assert_ne!(en, de, "code {code} is not translated");
+        }
+    }
+}
@@ -0,0 +1,413 @@
+//! venues, e.g. sports halls, with their postal address and courts
+//!
+//! A venue is picked in the organization of a tournament: its available courts become the
+//! named stations of the tournament and its postal address the location of the tournament.
+
+use crate::{
+    AuditObjectType, Core, CoreError, CoreResult, CrMsg, CrTopic, MAX_STATIONS_PER_SETUP, Station,
+    utils::{id_version::IdVersion, normalize::*, traits::ObjectIdVersion, validation::*},
+};
+use serde::{Deserialize, Serialize};
+use std::{
+    collections::HashSet,
+    fmt::{self, Display},
+    str::FromStr,
+};
+use uuid::Uuid;
+
+/// surface of a court
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Default, Serialize, Deserialize)]
+#[serde(rename_all = "kebab-case")]
+pub enum CourtSurface {
+    #[default]
+    Unspecified,
+    /// indoor wooden floor, e.g. of a sports hall
+    Wood,
+    /// indoor synthetic floor
+    Synthetic,
+    /// outdoor hard court, e.g. asphalt or acrylic
+    Hard,
+    Clay,
+    Grass,
+    ArtificialTurf,
+    Sand,
+}
+
+impl CourtSurface {
+    pub const ALL: [CourtSurface; 8] = [
+        CourtSurface::Unspecified,
+        CourtSurface::Wood,
+        CourtSurface::Synthetic,
+        CourtSurface::Hard,
+        CourtSurface::Clay,
+        CourtSurface::Grass,
+        CourtSurface::ArtificialTurf,
+        CourtSurface::Sand,
+    ];
+
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            CourtSurface::Unspecified => "unspecified",
+            CourtSurface::Wood => "wood",
+            CourtSurface::Synthetic => "synthetic",
+            CourtSurface::Hard => "hard",
+            CourtSurface::Clay => "clay",
+            CourtSurface::Grass => "grass",
+            CourtSurface::ArtificialTurf => "artificial-turf",
+            CourtSurface::Sand => "sand",
+        }
+    }
+}
+
+impl Display for CourtSurface {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{}", self.as_str())
+    }
+}
+
+impl FromStr for CourtSurface {
+    type Err = CoreError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        CourtSurface::ALL
+            .into_iter()
+            .find(|c| c.as_str() == s)
+            .ok_or_else(|| CoreError::ParsingError(format!("unknown court surface: {s}")))
+    }
+}
+
+/// Court (or table, pitch, ...) of a venue, which is used as station of tournaments.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct Court {
+    /// name of court, e.g. `Court 1`
+    name: String,
+    /// surface of court
+    #[serde(default)]
+    surface: CourtSurface,
+    /// courts, which are not available, e.g. because of repairs, are not used as stations
+    available: bool,
+}
+
+impl Default for Court {
+    fn default() -> Self {
+        Court {
+            name: String::new(),
+            surface: CourtSurface::default(),
+            available: true,
+        }
+    }
+}
+
+impl Court {
+    /// Create a new available court.
+    pub fn new(name: impl Into<String>, surface: CourtSurface) -> Self {
+        let mut court = Court::default();
+        court.set_name(name).set_surface(surface);
+        court
+    }
+
+    /// Get the name of the court.
+    pub fn get_name(&self) -> &str {
+        &self.name
+    }
+
+    /// Get the surface of the court.
+    pub fn get_surface(&self) -> CourtSurface {
+        self.surface
+    }
+
+    /// Check if the court is available for tournaments.
+    pub fn is_available(&self) -> bool {
+        self.available
+    }
+
+    /// Set the name of the court; whitespace is normalized.
+    pub fn set_name(&mut self, name: impl Into<String>) -> &mut Self {
+        self.name = normalize_ws(name);
+        self
+    }
+
+    /// Set the surface of the court.
+    pub fn set_surface(&mut self, surface: CourtSurface) -> &mut Self {
+        self.surface = surface;
+        self
+    }
+
+    /// Set if the court is available for tournaments.
+    pub fn set_available(&mut self, available: bool) -> &mut Self {
+        self.available = available;
+        self
+    }
+}
+
+/// Venue of tournaments, e.g. a sports hall with its courts.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize, Default, ObjectIdVersion)]
+pub struct Venue {
+    /// id and optimistic locking version of venue
+    id_version: IdVersion,
+    /// name of venue, e.g. `Main Hall`
+    name: String,
+    /// postal address of venue, if any
+    address_id: Option<Uuid>,
+    /// courts of venue in order of their numbers
+    courts: Vec<Court>,
+}
+
+impl Venue {
+    pub fn new(id_version: IdVersion) -> Self {
+        Venue {
+            id_version,
+            ..Default::default()
+        }
+    }
+
+    /// Get the unique identifier of the venue.
+    pub fn get_id(&self) -> Uuid {
+        self.id_version.get_id()
+    }
+
+    /// Get the version number of the venue.
+    pub fn get_version(&self) -> Option<u32> {
+        self.id_version.get_version()
+    }
+
+    /// Get the name of the venue.
+    pub fn get_name(&self) -> &str {
+        &self.name
+    }
+
+    /// Get the id of the postal address of the venue.
+    pub fn get_address_id(&self) -> Option<Uuid> {
+        self.address_id
+    }
+
+    /// Get the courts of the venue.
+    pub fn get_courts(&self) -> &[Court] {
+        &self.courts
+    }
+
+    /// Stations of tournaments at the venue: all available courts in order.
+    pub fn stations(&self) -> Vec<Station> {
+        self.courts
+            .iter()
+            .filter(|c| c.is_available())
+            .map(|c| Station::new(c.get_name(), &self.name))
+            .collect()
+    }
+
+    /// Set the `IdVersion` of the venue.
+    pub fn set_id_version(&mut self, id_version: IdVersion) -> &mut Self {
+        self.id_version = id_version;
+        self
+    }
+
+    /// Set the name of the venue; whitespace is normalized.
+    pub fn set_name(&mut self, name: impl Into<String>) -> &mut Self {
+        self.name = normalize_ws(name);
+        self
+    }
+
+    /// Set the id of the postal address of the venue.
+    pub fn set_address_id(&mut self, address_id: Option<Uuid>) -> &mut Self {
+        self.address_id = address_id;
+        self
+    }
+
+    /// Set the courts of the venue.
+    pub fn set_courts(&mut self, courts: Vec<Court>) -> &mut Self {
+        self.courts = courts;
+        self
+    }
+
+    /// Validate the venue.
+    pub fn validate(&self) -> ValidationResult<()> {
+        let mut errs = ValidationErrors::new();
+        let object_id = self.get_id();
+
+        if self.name.is_empty() {
+            errs.add(
+                FieldError::builder()
+                    .set_field(String::from("name"))
+                    .add_required()
+                    .set_object_id(object_id)
+                    .build(),
+            );
+        }
+
+        if self.courts.len() > MAX_STATIONS_PER_SETUP as usize {
+            errs.add(
+                FieldError::builder()
+                    .set_field(String::from("courts"))
+                    .add_message(format!(
+                        "number of courts must not exceed {MAX_STATIONS_PER_SETUP}"
+                    ))
+                    .set_object_id(object_id)
+                    .build(),
+            );
+        }
+
+        // court names are compared case insensitive, since they become station names
+        let mut names = HashSet::new();
+        for (index, court) in self.courts.iter().enumerate() {
+            if court.name.is_empty() {
+                errs.add(
+                    FieldError::builder()
+                        .set_field(format!("courts.{}", index + 1))
+                        .add_required()
+                        .set_object_id(object_id)
+                        .build(),
+                );
+            } else if !names.insert(court.name.to_lowercase()) {
+                errs.add(
+                    FieldError::builder()
+                        .set_field(format!("courts.{}", index + 1))
+                        .add_message("names of courts must be unique")
+                        .set_object_id(object_id)
+                        .build(),
+                );
+            }
+        }
+
+        if errs.is_empty() { Ok(()) } else { Err(errs) }
+    }
+}
+
+/// State for venue operations
+pub struct VenueState {
+    venue: Venue,
+}
+
+// switch state to venue state
+impl<S> Core<S> {
+    pub fn as_venue_state(&self) -> Core<VenueState> {
+        self.switch_state(VenueState {
+            venue: Venue::new(IdVersion::NewWithId(Uuid::new_v4())),
+        })
+    }
+}
+
+impl Core<VenueState> {
+    pub fn get(&self) -> &Venue {
+        &self.state.venue
+    }
+    pub fn get_mut(&mut self) -> &mut Venue {
+        &mut self.state.venue
+    }
+    pub async fn load(&mut self, id: Uuid) -> CoreResult<Option<&Venue>> {
+        if let Some(venue) = self.database.get_venue(id).await? {
+            self.state.venue = venue;
+            Ok(Some(self.get()))
+        } else {
+            Ok(None)
+        }
+    }
+    /// Save the venue. The postal address of the venue must exist.
+    pub async fn save(&mut self) -> CoreResult<&Venue> {
+        self.state.venue.validate()?;
+        if let Some(address_id) = self.state.venue.get_address_id()
+            && self
+                .database
+                .get_postal_address(address_id)
+                .await?
+                .is_none()
+        {
+            return Err(CoreError::from(
+                FieldError::builder()
+                    .set_field(String::from("address_id"))
+                    .add_message("postal address does not exist")
+                    .set_object_id(self.state.venue.get_id())
+                    .build(),
+            ));
+        }
+        // stored copy before the save for the audit log
+        let old = match self.state.venue.get_version() {
+            Some(_) => {
+                let id = self.state.venue.get_id();
+                self.database.get_venue(id).await?
+            }
+            None => None,
+        };
+        self.state.venue = self.database.save_venue(&self.state.venue).await?;
+
+        // publish change of venue to client registry
+        let id = self.state.venue.get_id();
+        let version = self
+            .state
+            .venue
+            .get_version()
+            .expect("expecting save_venue to return always an existing id and version");
+        let msg = CrMsg::VenueUpdated { id, version };
+        self.client_registry.publish(CrTopic::Venues, msg).await?;
+        self.audit_save(
+            AuditObjectType::Venue,
+            None,
+            old.as_ref(),
+            &self.state.venue,
+        )
+        .await;
+        Ok(self.get())
+    }
+    /// List venues sorted by name; `name_filter` matches parts of names case insensitive.
+    pub async fn list_venues(
+        &self,
+        name_filter: Option<&str>,
+        limit: Option<usize>,
+    ) -> CoreResult<Vec<Venue>> {
+        let list = self.database.list_venues(name_filter, limit).await?;
+        Ok(list)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn hall() -> Venue {
+        let mut venue = Venue::new(IdVersion::new(Uuid::new_v4(), Some(0)));
+        venue.set_name("  Main   Hall ").set_courts(vec![
+            Court::new("Court 1", CourtSurface::Wood),
+            Court::new("Court 2", CourtSurface::Wood),
+            Court::new("Court 3", CourtSurface::Synthetic),
+        ]);
+        venue
+    }
+
+    #[test]
+    fn given_unavailable_court_when_stations_then_it_is_skipped() {
+        let mut venue = hall();
+        let mut courts = venue.get_courts().to_vec();
+        courts[1].set_available(false);
+        venue.set_courts(courts);
+
+        let stations = venue.stations();
+
+        assert_eq!(
+            stations.iter().map(Station::get_name).collect::<Vec<_>>(),
+            vec!["Court 1", "Court 3"]
+        );
+        assert!(stations.iter().all(|s| s.get_venue() == "Main Hall"));
+    }
+
+    #[test]
+    fn given_empty_and_duplicate_court_names_when_validate_then_all_errors_are_collected() {
+        let mut venue = hall();
+        venue.set_name(" ").set_courts(vec![
+            Court::new("Court 1", CourtSurface::Clay),
+            Court::new("", CourtSurface::Clay),
+            Court::new("court 1", CourtSurface::Clay),
+        ]);
+
+        let errs = venue.validate().unwrap_err();
+
+        let fields: Vec<_> = errs.errors.iter().map(|e| e.get_field()).collect();
+        assert_eq!(fields, vec!["name", "courts.2", "courts.3"]);
+    }
+
+    #[test]
+    fn court_surface_round_trips_as_str() {
+        for surface in CourtSurface::ALL {
+            assert_eq!(CourtSurface::from_str(surface.as_str()).unwrap(), surface);
+        }
+        assert!(CourtSurface::from_str("ice").is_err());
+    }
+}
@@ -0,0 +1,289 @@
+//! chat messages for incoming webhooks of Discord and Slack
+
+use super::WebhookEventData;
+use crate::{CoreError, CoreResult};
+use serde_json::json;
+
+/// name shown as sender of Discord messages
+const CHAT_USERNAME: &str = "FK Tournament Planer";
+/// Discord rejects messages with more than 2000 characters
+const DISCORD_MAX_CONTENT_CHARS: usize = 2000;
+
+/// markup of the chat services differs in bold text and escaping
+#[derive(Clone, Copy)]
+enum Markup {
+    Discord,
+    Slack,
+}
+
+impl Markup {
+    fn escape(&self, text: &str) -> String {
+        match self {
+            Markup::Discord => {
+                let mut escaped = String::with_capacity(text.len());
+                for c in text.chars() {
+                    if matches!(c, '\\' | '*' | '_' | '~' | '`' | '|' | '>' | '#') {
+                        escaped.push('\\');
+                    }
+                    escaped.push(c);
+                }
+                escaped
+            }
+            Markup::Slack => text
+                .replace('&', "&amp;")
+                .replace('<', "&lt;")
+                .replace('>', "&gt;"),
+        }
+    }
+
+    fn bold(&self, text: &str) -> String {
+        match self {
+            Markup::Discord => format!("**{}**", self.escape(text)),
+            Markup::Slack => format!("*{}*", self.escape(text)),
+        }
+    }
+}
+
+fn message_text(data: &WebhookEventData, markup: Markup) -> String {
+    let mut lines = Vec::new();
+    match data {
+        WebhookEventData::TournamentUpdated { name, state, .. } => {
+            lines.push(format!(
+                "{} was updated: {}",
+                markup.bold(name),
+                markup.escape(state)
+            ));
+        }
+        WebhookEventData::EntrantsUpdated { num_changed, .. } => {
+            lines.push(format!("{num_changed} entrant(s) were added or changed."));
+        }
+        WebhookEventData::RoundStarted {
+            tournament_name,
+            stage_number,
+            round_number,
+            matches,
+            ..
+        } => {
+            lines.push(format!(
+                "{}: round {round_number} of stage {stage_number} started",
+                markup.bold(tournament_name),
+            ));
+            for m in matches {
+                let station = m
+                    .station
+                    .map(|s| format!(" at station {s}"))
+                    .unwrap_or_default();
+                lines.push(format!(
+                    "• Match {}{station}: {} vs {}",
+                    m.number,
+                    markup.escape(&m.side_a),
+                    markup.escape(&m.side_b)
+                ));
+            }
+        }
+        WebhookEventData::RoundResults {
+            tournament_name,
+            stage_number,
+            round_number,
+            results,
+            ..
+        } => {
+            lines.push(format!(
+                "{}: results of round {round_number} of stage {stage_number}",
+                markup.bold(tournament_name),
+            ));
+            for r in results {
+                lines.push(format!(
+                    "• {} vs {}: {}",
+                    markup.escape(&r.side_a),
+                    markup.escape(&r.side_b),
+                    markup.escape(&r.result)
+                ));
+            }
+        }
+        WebhookEventData::FinalStandings {
+            tournament_name,
+            standings,
+            ..
+        } => {
+            lines.push(format!("{}: final standings", markup.bold(tournament_name)));
+            for s in standings {
+                let rank = match s.rank {
+                    1 => "🥇".to_string(),
+                    2 => "🥈".to_string(),
+                    3 => "🥉".to_string(),
+                    rank => format!("{rank}."),
+                };
+                lines.push(format!("{rank} {}", markup.escape(&s.name)));
+            }
+        }
+        WebhookEventData::MatchFinished {
+            tournament_name,
+            stage_number,
+            result,
+            ..
+        } => {
+            lines.push(format!(
+                "{}: match {} of stage {stage_number} finished",
+                markup.bold(tournament_name),
+                result.number,
+            ));
+            lines.push(format!(
+                "{} vs {}: {}",
+                markup.escape(&result.side_a),
+                markup.escape(&result.side_b),
+                markup.escape(&result.result)
+            ));
+        }
+        WebhookEventData::StageCompleted {
+            tournament_name,
+            stage_name,
+            ..
+        } => {
+            lines.push(format!(
+                "{}: {} completed",
+                markup.bold(tournament_name),
+                markup.escape(stage_name)
+            ));
+        }
+        WebhookEventData::TournamentFinished {
+            tournament_name, ..
+        } => {
+            lines.push(format!("{} is finished", markup.bold(tournament_name)));
+        }
+        WebhookEventData::Test { message } => {
+            lines.push(markup.escape(message));
+        }
+    }
+    lines.join("\n")
+}
+
+fn truncate_chars(text: String, max_chars: usize) -> String {
+    if text.chars().count() <= max_chars {
+        return text;
+    }
+    let mut truncated: String = text.chars().take(max_chars - 1).collect();
+    truncated.push('…');
+    truncated
+}
+
+/// Body for Discord incoming webhooks.
+pub(super) fn discord_body(data: &WebhookEventData) -> CoreResult<String> {
+    let content = truncate_chars(
+        message_text(data, Markup::Discord),
+        DISCORD_MAX_CONTENT_CHARS,
+    );
+    // names of tournaments and entrants must not ping users, roles or everyone
+    serde_json::to_string(&json!({
+        "username": CHAT_USERNAME,
+        "content": content,
+        "allowed_mentions": { "parse": [] },
+    }))
+    .map_err(|e| CoreError::ParsingError(e.to_string()))
+}
+
+/// Body for Slack incoming webhooks.
+pub(super) fn slack_body(data: &WebhookEventData) -> CoreResult<String> {
+    serde_json::to_string(&json!({ "text": message_text(data, Markup::Slack) }))
+        .map_err(|e| CoreError::ParsingError(e.to_string()))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{WebhookMatch, WebhookMatchResult, WebhookStanding};
+    use uuid::Uuid;
+
+    fn body_text(body: &str, field: &str) -> String {
+        let json: serde_json::Value = serde_json::from_str(body).unwrap();
+        json[field].as_str().unwrap().to_string()
+    }
+
+    #[test]
+    fn round_started_lists_matches() {
+        let data = WebhookEventData::RoundStarted {
+            tournament_id: Uuid::nil(),
+            tournament_name: "Spring Cup".into(),
+            stage_number: 1,
+            round_number: 2,
+            matches: vec![
+                WebhookMatch {
+                    number: 7,
+                    station: Some(3),
+                    side_a: "Team A".into(),
+                    side_b: "Team B".into(),
+                },
+                WebhookMatch {
+                    number: 8,
+                    station: None,
+                    side_a: "Team C".into(),
+                    side_b: "Team D".into(),
+                },
+            ],
+        };
+        assert_eq!(
+            body_text(&discord_body(&data).unwrap(), "content"),
+            "**Spring Cup**: round 2 of stage 1 started\n\
+             • Match 7 at station 3: Team A vs Team B\n\
+             • Match 8: Team C vs Team D"
+        );
+    }
+
+    #[test]
+    fn round_results_and_standings_for_slack() {
+        let data = WebhookEventData::RoundResults {
+            tournament_id: Uuid::nil(),
+            tournament_name: "Spring Cup".into(),
+            stage_number: 1,
+            round_number: 2,
+            results: vec![WebhookMatchResult {
+                number: 7,
+                side_a: "A & B".into(),
+                side_b: "<C>".into(),
+                result: "2:1".into(),
+            }],
+        };
+        assert_eq!(
+            body_text(&slack_body(&data).unwrap(), "text"),
+            "*Spring Cup*: results of round 2 of stage 1\n• A &amp; B vs &lt;C&gt;: 2:1"
+        );
+
+        let data = WebhookEventData::FinalStandings {
+            tournament_id: Uuid::nil(),
+            tournament_name: "Spring Cup".into(),
+            standings: (1..=4)
+                .map(|rank| WebhookStanding {
+                    rank,
+                    name: format!("Team {rank}"),
+                })
+                .collect(),
+        };
+        assert_eq!(
+            body_text(&slack_body(&data).unwrap(), "text"),
+            "*Spring Cup*: final standings\n🥇 Team 1\n🥈 Team 2\n🥉 Team 3\n4. Team 4"
+        );
+    }
+
+    #[test]
+    fn discord_escapes_markdown_and_truncates() {
+        let data = WebhookEventData::Test {
+            message: "*bold* _x_".into(),
+        };
+        let body = discord_body(&data).unwrap();
+        assert_eq!(body_text(&body, "content"), r"\*bold\* \_x\_");
+        assert_eq!(body_text(&body, "username"), CHAT_USERNAME);
+
+        let data = WebhookEventData::Test {
+            message: "@everyone <@&42>".into(),
+        };
+        let json: serde_json::Value = serde_json::from_str(&discord_body(&data).unwrap()).unwrap();
+        assert_eq!(json["allowed_mentions"]["parse"], json!([]));
+
+        let data = WebhookEventData::Test {
+            message: "x".repeat(3000),
+        };
+        let content = body_text(&discord_body(&data).unwrap(), "content");
+        assert_eq!(content.chars().count(), DISCORD_MAX_CONTENT_CHARS);
+        assert!(content.ends_with('…'));
+    }
+}
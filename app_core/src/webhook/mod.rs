@@ -0,0 +1,1109 @@
+//! webhooks notify external receivers about tournament events
+//!
+//! Every delivery is a JSON [`WebhookPayload`] posted to the url of a [`WebhookEndpoint`].
+//! The payload is signed with the secret of the endpoint, see [`sign_webhook_payload`], so
+//! that receivers can verify its authenticity. Each delivery attempt is recorded as
+//! [`WebhookDelivery`] including the response code of the receiver.
+//!
+//! With a [`WebhookQueuePort`](crate::WebhookQueuePort), events are delivered in the
+//! background and failed deliveries are retried after [`WEBHOOK_RETRY_DELAYS`].
+//!
+//! Instead of the JSON payload, endpoints may receive chat messages formatted for incoming
+//! webhooks of Discord or Slack, see [`WebhookFormat`].
+
+mod chat;
+
+use crate::{
+    AuditObjectType, Core, CoreError, CoreResult, CrMsg, CrTopic, DbError, TournamentBase,
+    TournamentState, WebhookJob,
+    domain_event::{completed_stage, is_finishing},
+    utils::{id_version::IdVersion, normalize::*, traits::ObjectIdVersion, validation::*},
+};
+use chrono::{DateTime, Utc};
+use hmac::{Hmac, Mac};
+use serde::{Deserialize, Serialize};
+use sha2::Sha256;
+use std::{
+    fmt::{self, Display},
+    str::FromStr,
+    time::{Duration, Instant},
+};
+use tracing::{info, warn};
+use uuid::Uuid;
+
+/// current version of the payload schema; incremented on incompatible changes
+pub const WEBHOOK_PAYLOAD_SCHEMA_VERSION: u32 = 1;
+
+/// header with signature of payload, see [`sign_webhook_payload`]
+pub const WEBHOOK_SIGNATURE_HEADER: &str = "X-Fkt-Signature";
+/// header with event type of payload
+pub const WEBHOOK_EVENT_HEADER: &str = "X-Fkt-Event";
+/// header with event id of payload; equal for all deliveries of an event
+pub const WEBHOOK_DELIVERY_HEADER: &str = "X-Fkt-Delivery";
+
+/// prefix of every endpoint secret
+pub const WEBHOOK_SECRET_PREFIX: &str = "whsec_";
+
+/// Events, which may be sent to webhook endpoints.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash, Serialize, Deserialize)]
+pub enum WebhookEventType {
+    /// tournament base was created or changed, e.g. its state
+    #[serde(rename = "tournament.updated")]
+    TournamentUpdated,
+    /// entrants of tournament were added or changed
+    #[serde(rename = "entrants.updated")]
+    EntrantsUpdated,
+    /// matches of a round were released
+    #[serde(rename = "round.started")]
+    RoundStarted,
+    /// all matches of a round are finished
+    #[serde(rename = "round.results")]
+    RoundResults,
+    /// tournament is finished and final ranking is available
+    #[serde(rename = "tournament.final_standings")]
+    FinalStandings,
+    /// result of a match was entered
+    #[serde(rename = "match.finished")]
+    MatchFinished,
+    /// all matches of a stage are finished and the next stage started
+    #[serde(rename = "stage.completed")]
+    StageCompleted,
+    /// state of tournament changed to finished
+    #[serde(rename = "tournament.finished")]
+    TournamentFinished,
+    /// test delivery triggered by user; always sent regardless of subscribed events
+    #[serde(rename = "webhook.test")]
+    Test,
+}
+
+impl WebhookEventType {
+    /// event types, which endpoints may subscribe to
+    pub const SUBSCRIBABLE: [WebhookEventType; 8] = [
+        WebhookEventType::TournamentUpdated,
+        WebhookEventType::EntrantsUpdated,
+        WebhookEventType::RoundStarted,
+        WebhookEventType::RoundResults,
+        WebhookEventType::FinalStandings,
+        WebhookEventType::MatchFinished,
+        WebhookEventType::StageCompleted,
+        WebhookEventType::TournamentFinished,
+    ];
+
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            WebhookEventType::TournamentUpdated => "tournament.updated",
+            WebhookEventType::EntrantsUpdated => "entrants.updated",
+            WebhookEventType::RoundStarted => "round.started",
+            WebhookEventType::RoundResults => "round.results",
+            WebhookEventType::FinalStandings => "tournament.final_standings",
+            WebhookEventType::MatchFinished => "match.finished",
+            WebhookEventType::StageCompleted => "stage.completed",
+            WebhookEventType::TournamentFinished => "tournament.finished",
+            WebhookEventType::Test => "webhook.test",
+        }
+    }
+}
+
+impl Display for WebhookEventType {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{}", self.as_str())
+    }
+}
+
+/// Data of an event. The variant defines the event type of the payload.
+///
+/// Variants are untagged: a variant must not be a subset of the fields of a preceding
+/// variant, otherwise deserialization picks the wrong variant.
+#[derive(Clone, Debug, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(untagged)]
+pub enum WebhookEventData {
+    TournamentUpdated {
+        tournament_id: Uuid,
+        name: String,
+        /// state as displayed, e.g. `Running (Stage 1)`
+        state: String,
+    },
+    EntrantsUpdated {
+        tournament_id: Uuid,
+        /// number of entrants, which were added or changed
+        num_changed: usize,
+    },
+    RoundStarted {
+        tournament_id: Uuid,
+        tournament_name: String,
+        stage_number: u32,
+        round_number: u32,
+        matches: Vec<WebhookMatch>,
+    },
+    RoundResults {
+        tournament_id: Uuid,
+        tournament_name: String,
+        stage_number: u32,
+        round_number: u32,
+        results: Vec<WebhookMatchResult>,
+    },
+    FinalStandings {
+        tournament_id: Uuid,
+        tournament_name: String,
+        /// sorted by rank
+        standings: Vec<WebhookStanding>,
+    },
+    MatchFinished {
+        tournament_id: Uuid,
+        tournament_name: String,
+        stage_number: u32,
+        result: WebhookMatchResult,
+    },
+    StageCompleted {
+        tournament_id: Uuid,
+        tournament_name: String,
+        stage_number: u32,
+        stage_name: String,
+    },
+    TournamentFinished {
+        tournament_id: Uuid,
+        tournament_name: String,
+    },
+    Test {
+        message: String,
+    },
+}
+
+impl WebhookEventData {
+    pub fn event_type(&self) -> WebhookEventType {
+        match self {
+            WebhookEventData::TournamentUpdated { .. } => WebhookEventType::TournamentUpdated,
+            WebhookEventData::EntrantsUpdated { .. } => WebhookEventType::EntrantsUpdated,
+            WebhookEventData::RoundStarted { .. } => WebhookEventType::RoundStarted,
+            WebhookEventData::RoundResults { .. } => WebhookEventType::RoundResults,
+            WebhookEventData::FinalStandings { .. } => WebhookEventType::FinalStandings,
+            WebhookEventData::MatchFinished { .. } => WebhookEventType::MatchFinished,
+            WebhookEventData::StageCompleted { .. } => WebhookEventType::StageCompleted,
+            WebhookEventData::TournamentFinished { .. } => WebhookEventType::TournamentFinished,
+            WebhookEventData::Test { .. } => WebhookEventType::Test,
+        }
+    }
+
+    pub fn tournament_updated(tournament: &TournamentBase) -> Self {
+        WebhookEventData::TournamentUpdated {
+            tournament_id: tournament.get_id(),
+            name: tournament.get_name().to_string(),
+            state: tournament.get_tournament_state().to_string(),
+        }
+    }
+
+    pub fn stage_completed(tournament: &TournamentBase, stage_number: u32) -> Self {
+        WebhookEventData::StageCompleted {
+            tournament_id: tournament.get_id(),
+            tournament_name: tournament.get_name().to_string(),
+            stage_number,
+            stage_name: tournament
+                .get_tournament_mode()
+                .get_stage_name(stage_number)
+                .unwrap_or_else(|| format!("Stage {}", stage_number + 1)),
+        }
+    }
+
+    pub fn tournament_finished(tournament: &TournamentBase) -> Self {
+        WebhookEventData::TournamentFinished {
+            tournament_id: tournament.get_id(),
+            tournament_name: tournament.get_name().to_string(),
+        }
+    }
+
+    /// Events caused by changing the state of `tournament` from `previous`, e.g. completion
+    /// of the active stage. `previous` is `None` for new tournaments.
+    pub fn state_transition_events(
+        tournament: &TournamentBase,
+        previous: Option<TournamentState>,
+    ) -> Vec<Self> {
+        let mut events = Vec::new();
+        let next = tournament.get_tournament_state();
+        if let Some(completed) = completed_stage(previous, next) {
+            events.push(Self::stage_completed(tournament, completed));
+        }
+        if is_finishing(previous, next) {
+            events.push(Self::tournament_finished(tournament));
+        }
+        events
+    }
+}
+
+/// match of a started round
+#[derive(Clone, Debug, PartialEq, Eq, Serialize, Deserialize)]
+pub struct WebhookMatch {
+    /// number of match in tournament
+    pub number: u32,
+    /// station (e.g. court or table) the match is played at, if assigned
+    pub station: Option<u32>,
+    /// display names of both sides
+    pub side_a: String,
+    pub side_b: String,
+}
+
+/// result of a finished match
+#[derive(Clone, Debug, PartialEq, Eq, Serialize, Deserialize)]
+pub struct WebhookMatchResult {
+    pub number: u32,
+    pub side_a: String,
+    pub side_b: String,
+    /// result as displayed by the sport plugin, e.g. `2:1 (11:9, 8:11, 11:5)`
+    pub result: String,
+}
+
+/// entry of final standings
+#[derive(Clone, Debug, PartialEq, Eq, Serialize, Deserialize)]
+pub struct WebhookStanding {
+    pub rank: u32,
+    pub name: String,
+}
+
+/// Format of the body posted to an endpoint.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, Hash, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum WebhookFormat {
+    /// versioned JSON payload, see [`WebhookPayload`]
+    #[default]
+    Json,
+    /// message for Discord incoming webhooks
+    Discord,
+    /// message for Slack incoming webhooks
+    Slack,
+}
+
+impl WebhookFormat {
+    pub const ALL: [WebhookFormat; 3] = [
+        WebhookFormat::Json,
+        WebhookFormat::Discord,
+        WebhookFormat::Slack,
+    ];
+
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            WebhookFormat::Json => "json",
+            WebhookFormat::Discord => "discord",
+            WebhookFormat::Slack => "slack",
+        }
+    }
+
+    /// Body of a delivery of `payload` in this format.
+    pub fn render_body(&self, payload: &WebhookPayload) -> CoreResult<String> {
+        match self {
+            WebhookFormat::Json => payload.to_json(),
+            WebhookFormat::Discord => chat::discord_body(&payload.data),
+            WebhookFormat::Slack => chat::slack_body(&payload.data),
+        }
+    }
+}
+
+impl Display for WebhookFormat {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{}", self.as_str())
+    }
+}
+
+impl FromStr for WebhookFormat {
+    type Err = CoreError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        WebhookFormat::ALL
+            .into_iter()
+            .find(|f| f.as_str() == s)
+            .ok_or_else(|| CoreError::ParsingError(format!("unknown webhook format: {s}")))
+    }
+}
+
+/// JSON body of every webhook delivery.
+///
+/// Schema version 1:
+/// ```json
+/// {
+///   "schema_version": 1,
+///   "event_id": "<uuid>",
+///   "type": "tournament.updated",
+///   "occurred_at": "2026-03-21T09:30:00Z",
+///   "data": { "tournament_id": "<uuid>", "name": "Spring Cup", "state": "Published" }
+/// }
+/// ```
+/// Fields of `data` depend on `type`, see [`WebhookEventData`]. Receivers should ignore
+/// unknown fields; new fields may be added without increasing `schema_version`.
+#[derive(Clone, Debug, PartialEq, Eq, Serialize, Deserialize)]
+pub struct WebhookPayload {
+    pub schema_version: u32,
+    /// id of event; receivers may use it to detect duplicate deliveries
+    pub event_id: Uuid,
+    #[serde(rename = "type")]
+    pub event_type: WebhookEventType,
+    pub occurred_at: DateTime<Utc>,
+    pub data: WebhookEventData,
+}
+
+impl WebhookPayload {
+    pub fn new(data: WebhookEventData) -> Self {
+        WebhookPayload {
+            schema_version: WEBHOOK_PAYLOAD_SCHEMA_VERSION,
+            event_id: Uuid::new_v4(),
+            event_type: data.event_type(),
+            occurred_at: Utc::now(),
+            data,
+        }
+    }
+
+    pub fn to_json(&self) -> CoreResult<String> {
+        serde_json::to_string(self).map_err(|e| CoreError::ParsingError(e.to_string()))
+    }
+}
+
+type HmacSha256 = Hmac<Sha256>;
+
+fn payload_mac(secret: &str, timestamp: i64, body: &str) -> HmacSha256 {
+    let mut mac =
+        HmacSha256::new_from_slice(secret.as_bytes()).expect("HMAC accepts keys of any size");
+    mac.update(timestamp.to_string().as_bytes());
+    mac.update(b".");
+    mac.update(body.as_bytes());
+    mac
+}
+
+/// Value of signature header of `body` signed at unix `timestamp` with `secret`.
+///
+/// Format: `t=<timestamp>,v1=<signature>`, with signature being the hex encoded
+/// HMAC-SHA256 of `<timestamp>.<body>`. The timestamp protects against replay of old
+/// deliveries.
+pub fn sign_webhook_payload(secret: &str, timestamp: i64, body: &str) -> String {
+    let signature: String = payload_mac(secret, timestamp, body)
+        .finalize()
+        .into_bytes()
+        .iter()
+        .map(|b| format!("{b:02x}"))
+        .collect();
+    format!("t={timestamp},v1={signature}")
+}
+
+/// Verify `signature_header` of `body` with `secret`. Signatures older or newer than
+/// `tolerance_seconds` relative to unix timestamp `now` are rejected.
+pub fn verify_webhook_signature(
+    secret: &str,
+    signature_header: &str,
+    body: &str,
+    now: i64,
+    tolerance_seconds: i64,
+) -> bool {
+    let mut timestamp = None;
+    let mut signature = None;
+    for part in signature_header.split(',') {
+        match part.trim().split_once('=') {
+            Some(("t", t)) => timestamp = t.parse::<i64>().ok(),
+            Some(("v1", s)) => signature = decode_hex(s),
+            _ => {}
+        }
+    }
+    let (Some(timestamp), Some(signature)) = (timestamp, signature) else {
+        return false;
+    };
+    if (now - timestamp).abs() > tolerance_seconds {
+        return false;
+    }
+    // constant time comparison
+    payload_mac(secret, timestamp, body)
+        .verify_slice(&signature)
+        .is_ok()
+}
+
+fn decode_hex(hex: &str) -> Option<Vec<u8>> {
+    if !hex.len().is_multiple_of(2) {
+        return None;
+    }
+    (0..hex.len())
+        .step_by(2)
+        .map(|i| u8::from_str_radix(hex.get(i..i + 2)?, 16).ok())
+        .collect()
+}
+
+/// Endpoint, which receives webhook deliveries.
+#[derive(Clone, Debug, PartialEq, Eq, Serialize, Deserialize, Default, ObjectIdVersion)]
+pub struct WebhookEndpoint {
+    /// id and optimistic locking version of endpoint
+    id_version: IdVersion,
+    /// name of endpoint, e.g. the receiving service
+    name: String,
+    /// url of receiver; must be http or https
+    url: String,
+    /// secret to sign payloads; shared with receiver
+    secret: String,
+    /// subscribed events
+    event_types: Vec<WebhookEventType>,
+    /// format of posted body
+    format: WebhookFormat,
+    /// inactive endpoints receive no events except test deliveries
+    active: bool,
+    /// timestamp of creation; set by database
+    created_at: Option<DateTime<Utc>>,
+}
+
+impl WebhookEndpoint {
+    /// Create a new active `WebhookEndpoint` with the given `IdVersion` and a new random secret.
+    pub fn new(id_version: IdVersion) -> Self {
+        WebhookEndpoint {
+            id_version,
+            // uuid v4 uses the random number generator of the OS
+            secret: format!(
+                "{WEBHOOK_SECRET_PREFIX}{}{}",
+                Uuid::new_v4().simple(),
+                Uuid::new_v4().simple()
+            ),
+            active: true,
+            ..Default::default()
+        }
+    }
+
+    /// Get the unique identifier of the endpoint.
+    pub fn get_id(&self) -> Uuid {
+        self.id_version.get_id()
+    }
+
+    /// Get the version number of the endpoint.
+    pub fn get_version(&self) -> Option<u32> {
+        self.id_version.get_version()
+    }
+
+    /// Get the name of the endpoint.
+    pub fn get_name(&self) -> &str {
+        &self.name
+    }
+
+    /// Get the url of the receiver.
+    pub fn get_url(&self) -> &str {
+        &self.url
+    }
+
+    /// Get the signing secret.
+    pub fn get_secret(&self) -> &str {
+        &self.secret
+    }
+
+    /// Get the subscribed events.
+    pub fn get_event_types(&self) -> &[WebhookEventType] {
+        &self.event_types
+    }
+
+    /// Get the format of the posted body.
+    pub fn get_format(&self) -> WebhookFormat {
+        self.format
+    }
+
+    /// Returns true, if the endpoint is active and subscribed to `event_type`.
+    /// Test deliveries are always accepted.
+    pub fn accepts(&self, event_type: WebhookEventType) -> bool {
+        event_type == WebhookEventType::Test
+            || (self.active && self.event_types.contains(&event_type))
+    }
+
+    /// Check if the endpoint is active.
+    pub fn is_active(&self) -> bool {
+        self.active
+    }
+
+    /// Get the timestamp of creation, if endpoint is persisted.
+    pub fn get_created_at(&self) -> Option<DateTime<Utc>> {
+        self.created_at
+    }
+
+    /// Set the `IdVersion` of the endpoint.
+    pub fn set_id_version(&mut self, id_version: IdVersion) -> &mut Self {
+        self.id_version = id_version;
+        self
+    }
+
+    /// Set the name of the endpoint with whitespace normalization.
+    pub fn set_name(&mut self, name: impl Into<String>) -> &mut Self {
+        self.name = normalize_ws(name);
+        self
+    }
+
+    /// Set the url of the receiver.
+    pub fn set_url(&mut self, url: impl Into<String>) -> &mut Self {
+        self.url = url.into().trim().to_string();
+        self
+    }
+
+    /// Set the signing secret. Only used by database adapters.
+    pub fn set_secret(&mut self, secret: impl Into<String>) -> &mut Self {
+        self.secret = secret.into();
+        self
+    }
+
+    /// Set the subscribed events. Duplicates and test events are removed, events are kept in
+    /// order of `WebhookEventType::SUBSCRIBABLE`.
+    pub fn set_event_types(
+        &mut self,
+        event_types: impl IntoIterator<Item = WebhookEventType>,
+    ) -> &mut Self {
+        let requested: Vec<WebhookEventType> = event_types.into_iter().collect();
+        self.event_types = WebhookEventType::SUBSCRIBABLE
+            .into_iter()
+            .filter(|e| requested.contains(e))
+            .collect();
+        self
+    }
+
+    /// Set the format of the posted body.
+    pub fn set_format(&mut self, format: WebhookFormat) -> &mut Self {
+        self.format = format;
+        self
+    }
+
+    /// Set if the endpoint is active.
+    pub fn set_active(&mut self, active: bool) -> &mut Self {
+        self.active = active;
+        self
+    }
+
+    /// Set the timestamp of creation. Only used by database adapters.
+    pub fn set_created_at(&mut self, created_at: Option<DateTime<Utc>>) -> &mut Self {
+        self.created_at = created_at;
+        self
+    }
+
+    /// Validate the endpoint.
+    pub fn validate(&self) -> ValidationResult<()> {
+        let mut errs = ValidationErrors::new();
+        let object_id = self.get_id();
+
+        if self.name.is_empty() {
+            errs.add(
+                FieldError::builder()
+                    .set_field(String::from("name"))
+                    .add_required()
+                    .set_object_id(object_id)
+                    .build(),
+            );
+        }
+        if self.url.is_empty() {
+            errs.add(
+                FieldError::builder()
+                    .set_field(String::from("url"))
+                    .add_required()
+                    .set_object_id(object_id)
+                    .build(),
+            );
+        } else if !(self.url.starts_with("https://") || self.url.starts_with("http://"))
+            || self.url.contains(char::is_whitespace)
+        {
+            errs.add(
+                FieldError::builder()
+                    .set_field(String::from("url"))
+                    .add_invalid_format()
+                    .add_message("Url must start with https:// or http://")
+                    .set_object_id(object_id)
+                    .build(),
+            );
+        }
+        if self.event_types.is_empty() {
+            errs.add(
+                FieldError::builder()
+                    .set_field(String::from("event_types"))
+                    .add_required()
+                    .add_message("At least one event is required")
+                    .set_object_id(object_id)
+                    .build(),
+            );
+        }
+        if self.secret.is_empty() {
+            errs.add(
+                FieldError::builder()
+                    .set_field(String::from("secret"))
+                    .add_required()
+                    .set_object_id(object_id)
+                    .build(),
+            );
+        }
+
+        if errs.is_empty() { Ok(()) } else { Err(errs) }
+    }
+}
+
+/// Record of a delivery attempt to a webhook endpoint.
+#[derive(Clone, Debug, PartialEq, Eq, Serialize, Deserialize)]
+pub struct WebhookDelivery {
+    pub id: Uuid,
+    pub endpoint_id: Uuid,
+    /// event id of payload
+    pub event_id: Uuid,
+    pub event_type: WebhookEventType,
+    /// body as sent
+    pub payload: String,
+    /// HTTP status code of the response, if the receiver responded
+    pub status_code: Option<u16>,
+    /// error of transport, if the receiver did not respond
+    pub error: Option<String>,
+    pub duration_ms: u32,
+    pub attempted_at: DateTime<Utc>,
+}
+
+impl WebhookDelivery {
+    /// Returns true, if the receiver responded with a 2xx status code.
+    pub fn is_success(&self) -> bool {
+        self.status_code.is_some_and(|c| (200..300).contains(&c))
+    }
+
+    /// Returns true, if the delivery failed temporarily and should be retried: the receiver
+    /// did not respond, responded with a 5xx status code or asked to slow down (429).
+    pub fn is_retryable(&self) -> bool {
+        match self.status_code {
+            None => true,
+            Some(code) => code >= 500 || code == 429,
+        }
+    }
+}
+
+/// delays before the retries of a failed delivery by a
+/// [`WebhookQueuePort`](crate::WebhookQueuePort); the delivery is given up after the last retry
+pub const WEBHOOK_RETRY_DELAYS: [Duration; 3] = [
+    Duration::from_secs(10),
+    Duration::from_secs(60),
+    Duration::from_secs(300),
+];
+
+/// default number of deliveries shown in delivery history
+pub const DEFAULT_WEBHOOK_DELIVERY_HISTORY_LIMIT: usize = 50;
+
+/// State for webhook endpoint operations
+pub struct WebhookState {
+    endpoint: WebhookEndpoint,
+}
+
+// switch state to webhook state
+impl<S> Core<S> {
+    pub fn as_webhook_state(&self) -> Core<WebhookState> {
+        self.switch_state(WebhookState {
+            endpoint: WebhookEndpoint::new(IdVersion::NewWithId(Uuid::new_v4())),
+        })
+    }
+
+    /// Send event with `data` to all active endpoints subscribed to it.
+    ///
+    /// Deliveries are recorded in the delivery history. Failures of receivers or of the
+    /// history are logged and do not fail the operation, which triggered the event. With a
+    /// webhook queue, deliveries are only queued and sent in the background.
+    pub async fn dispatch_webhook_event(&self, data: WebhookEventData) {
+        // webhook events of a transaction are dispatched after commit
+        if let Some(events) = self.transaction_events.as_ref() {
+            events.defer_webhook_event(data);
+            return;
+        }
+        let event_type = data.event_type();
+        let endpoints = match self.database.list_webhook_endpoints().await {
+            Ok(endpoints) => endpoints,
+            Err(e) => {
+                warn!(error = %e, event_type = %event_type, "webhook_endpoints_not_loaded");
+                return;
+            }
+        };
+        let payload = WebhookPayload::new(data);
+        for endpoint in endpoints.into_iter().filter(|e| e.accepts(event_type)) {
+            let endpoint_id = endpoint.get_id();
+            if let Some(queue) = self.webhook_queue.as_ref() {
+                let job = WebhookJob {
+                    endpoint,
+                    payload: payload.clone(),
+                };
+                if let Err(e) = queue.enqueue(job).await {
+                    warn!(error = %e, %endpoint_id, "webhook_delivery_not_queued");
+                }
+            } else if let Err(e) = self.deliver_webhook(&endpoint, &payload).await {
+                warn!(error = %e, %endpoint_id, "webhook_delivery_not_recorded");
+            }
+        }
+    }
+
+    /// Deliver queued `job` once and record the delivery. The endpoint is loaded again, so
+    /// that retries use its current url and secret. Returns `None`, if the endpoint was
+    /// deleted or does not accept the event anymore.
+    ///
+    /// Webhook queues retry deliveries, which are
+    /// [retryable](WebhookDelivery::is_retryable), after [`WEBHOOK_RETRY_DELAYS`].
+    pub async fn deliver_webhook_job(
+        &self,
+        job: &WebhookJob,
+    ) -> CoreResult<Option<WebhookDelivery>> {
+        let Some(endpoint) = self
+            .database
+            .get_webhook_endpoint(job.endpoint.get_id())
+            .await?
+            .filter(|e| e.accepts(job.payload.event_type))
+        else {
+            return Ok(None);
+        };
+        self.deliver_webhook(&endpoint, &job.payload)
+            .await
+            .map(Some)
+    }
+
+    /// Post `payload` in the format of `endpoint` and record the delivery.
+    async fn deliver_webhook(
+        &self,
+        endpoint: &WebhookEndpoint,
+        payload: &WebhookPayload,
+    ) -> CoreResult<WebhookDelivery> {
+        let body = endpoint.get_format().render_body(payload)?;
+        let attempted_at = Utc::now();
+        let headers = vec![
+            (
+                WEBHOOK_SIGNATURE_HEADER.to_string(),
+                sign_webhook_payload(endpoint.get_secret(), attempted_at.timestamp(), &body),
+            ),
+            (
+                WEBHOOK_EVENT_HEADER.to_string(),
+                payload.event_type.to_string(),
+            ),
+            (
+                WEBHOOK_DELIVERY_HEADER.to_string(),
+                payload.event_id.to_string(),
+            ),
+        ];
+        let start = Instant::now();
+        let result = self
+            .webhooks
+            .post_json(endpoint.get_url(), &headers, &body)
+            .await;
+        let duration_ms = start.elapsed().as_millis().min(u32::MAX as u128) as u32;
+        let (status_code, error) = match result {
+            Ok(status_code) => (Some(status_code), None),
+            Err(e) => (None, Some(e.to_string())),
+        };
+        info!(endpoint_id = %endpoint.get_id(), event_type = %payload.event_type, ?status_code, "webhook_delivered");
+
+        let delivery = WebhookDelivery {
+            id: Uuid::new_v4(),
+            endpoint_id: endpoint.get_id(),
+            event_id: payload.event_id,
+            event_type: payload.event_type,
+            payload: body,
+            status_code,
+            error,
+            duration_ms,
+            attempted_at,
+        };
+        self.database.save_webhook_delivery(&delivery).await?;
+        self.client_registry
+            .publish(
+                CrTopic::WebhookDeliveries {
+                    endpoint_id: endpoint.get_id(),
+                },
+                CrMsg::WebhookDelivered {
+                    id: delivery.id,
+                    version: 0,
+                },
+            )
+            .await?;
+        Ok(delivery)
+    }
+}
+
+impl Core<WebhookState> {
+    pub fn get(&self) -> &WebhookEndpoint {
+        &self.state.endpoint
+    }
+    pub fn get_mut(&mut self) -> &mut WebhookEndpoint {
+        &mut self.state.endpoint
+    }
+    pub async fn load(&mut self, id: Uuid) -> CoreResult<Option<&WebhookEndpoint>> {
+        if let Some(endpoint) = self.database.get_webhook_endpoint(id).await? {
+            self.state.endpoint = endpoint;
+            Ok(Some(self.get()))
+        } else {
+            Ok(None)
+        }
+    }
+    pub async fn save(&mut self) -> CoreResult<&WebhookEndpoint> {
+        self.state.endpoint.validate()?;
+        // stored copy before the save for the audit log
+        let old = match self.state.endpoint.get_version() {
+            Some(_) => {
+                let id = self.state.endpoint.get_id();
+                self.database.get_webhook_endpoint(id).await?
+            }
+            None => None,
+        };
+        self.state.endpoint = self
+            .database
+            .save_webhook_endpoint(&self.state.endpoint)
+            .await?;
+
+        // publish change of webhook endpoint to client registry
+        let id = self.state.endpoint.get_id();
+        let version =
+            self.state.endpoint.get_version().expect(
+                "expecting save_webhook_endpoint to return always an existing id and version",
+            );
+        let msg = CrMsg::WebhookEndpointUpdated { id, version };
+        self.client_registry
+            .publish(CrTopic::WebhookEndpoints, msg)
+            .await?;
+        self.audit_save(
+            AuditObjectType::WebhookEndpoint,
+            None,
+            old.as_ref(),
+            &self.state.endpoint,
+        )
+        .await;
+        Ok(self.get())
+    }
+    pub async fn list_endpoints(&self) -> CoreResult<Vec<WebhookEndpoint>> {
+        let list = self.database.list_webhook_endpoints().await?;
+        Ok(list)
+    }
+    /// Send a test event to the loaded endpoint, even if it is inactive.
+    pub async fn send_test_delivery(&self) -> CoreResult<WebhookDelivery> {
+        if self.state.endpoint.get_id_version().is_new() {
+            return Err(CoreError::from(DbError::NotFound));
+        }
+        let payload = WebhookPayload::new(WebhookEventData::Test {
+            message: format!(
+                "Test delivery to webhook endpoint '{}'",
+                self.state.endpoint.get_name()
+            ),
+        });
+        self.deliver_webhook(&self.state.endpoint, &payload).await
+    }
+    /// Latest deliveries to the loaded endpoint, newest first.
+    pub async fn list_deliveries(&self, limit: usize) -> CoreResult<Vec<WebhookDelivery>> {
+        let list = self
+            .database
+            .list_webhook_deliveries(self.state.endpoint.get_id(), limit)
+            .await?;
+        Ok(list)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::TournamentMode;
+
+    #[test]
+    fn signature_roundtrip() {
+        let body = r#"{"schema_version":1}"#;
+        let header = sign_webhook_payload("whsec_test", 1_700_000_000, body);
+        assert!(header.starts_with("t=1700000000,v1="));
+        assert!(verify_webhook_signature(
+            "whsec_test",
+            &header,
+            body,
+            1_700_000_100,
+            300
+        ));
+        // wrong secret, modified body, too old
+        assert!(!verify_webhook_signature(
+            "whsec_other",
+            &header,
+            body,
+            1_700_000_100,
+            300
+        ));
+        assert!(!verify_webhook_signature(
+            "whsec_test",
+            &header,
+            r#"{"schema_version":2}"#,
+            1_700_000_100,
+            300
+        ));
+        assert!(!verify_webhook_signature(
+            "whsec_test",
+            &header,
+            body,
+            1_700_001_000,
+            300
+        ));
+        assert!(!verify_webhook_signature(
+            "whsec_test",
+            "t=1700000000",
+            body,
+            1_700_000_000,
+            300
+        ));
+    }
+
+    #[test]
+    fn signature_is_hmac_sha256_of_timestamp_and_body() {
+        // receivers implement verification independently, therefore the format must not change
+        assert_eq!(
+            sign_webhook_payload("key", 0, "x"),
+            "t=0,v1=01648d624c0b511b0449a63394418e2d5b018e4cc2f753e42127d445f3ca5dd0"
+        );
+    }
+
+    #[test]
+    fn payload_schema() {
+        let payload = WebhookPayload::new(WebhookEventData::EntrantsUpdated {
+            tournament_id: Uuid::nil(),
+            num_changed: 3,
+        });
+        let json: serde_json::Value = serde_json::from_str(&payload.to_json().unwrap()).unwrap();
+        assert_eq!(json["schema_version"], 1);
+        assert_eq!(json["type"], "entrants.updated");
+        assert_eq!(json["data"]["num_changed"], 3);
+        assert_eq!(
+            json["data"]["tournament_id"],
+            "00000000-0000-0000-0000-000000000000"
+        );
+    }
+
+    #[test]
+    fn stage_and_tournament_events_follow_state_transitions() {
+        let mut tournament = TournamentBase::default();
+        tournament
+            .set_name("Spring Cup")
+            .set_tournament_mode(TournamentMode::PoolAndFinalStage)
+            .set_tournament_state(TournamentState::ActiveStage(1));
+        let events = WebhookEventData::state_transition_events(
+            &tournament,
+            Some(TournamentState::ActiveStage(0)),
+        );
+        assert_eq!(
+            events,
+            vec![WebhookEventData::stage_completed(&tournament, 0)]
+        );
+        let WebhookEventData::StageCompleted { stage_name, .. } = &events[0] else {
+            unreachable!()
+        };
+        assert_eq!(stage_name, "Pool Stage");
+
+        tournament.set_tournament_state(TournamentState::Finished);
+        let types: Vec<_> = WebhookEventData::state_transition_events(
+            &tournament,
+            Some(TournamentState::ActiveStage(1)),
+        )
+        .iter()
+        .map(|e| e.event_type())
+        .collect();
+        assert_eq!(
+            types,
+            vec![
+                WebhookEventType::StageCompleted,
+                WebhookEventType::TournamentFinished
+            ]
+        );
+
+        // saving a finished tournament again and entering a stage trigger nothing
+        assert!(
+            WebhookEventData::state_transition_events(&tournament, Some(TournamentState::Finished))
+                .is_empty()
+        );
+        tournament.set_tournament_state(TournamentState::ActiveStage(0));
+        assert!(
+            WebhookEventData::state_transition_events(
+                &tournament,
+                Some(TournamentState::Published)
+            )
+            .is_empty()
+        );
+    }
+
+    #[test]
+    fn untagged_event_data_roundtrip() {
+        let tournament_id = Uuid::new_v4();
+        let events = [
+            WebhookEventData::MatchFinished {
+                tournament_id,
+                tournament_name: "Cup".into(),
+                stage_number: 0,
+                result: WebhookMatchResult {
+                    number: 7,
+                    side_a: "Ants".into(),
+                    side_b: "Zebras".into(),
+                    result: "2:0".into(),
+                },
+            },
+            WebhookEventData::StageCompleted {
+                tournament_id,
+                tournament_name: "Cup".into(),
+                stage_number: 0,
+                stage_name: "Pool Stage".into(),
+            },
+            WebhookEventData::TournamentFinished {
+                tournament_id,
+                tournament_name: "Cup".into(),
+            },
+        ];
+        for data in events {
+            let json = WebhookPayload::new(data.clone()).to_json().unwrap();
+            let payload: WebhookPayload = serde_json::from_str(&json).unwrap();
+            assert_eq!(payload.data, data);
+        }
+    }
+
+    #[test]
+    fn endpoint_accepts_only_subscribed_events_when_active() {
+        let mut endpoint = WebhookEndpoint::new(IdVersion::default());
+        endpoint.set_event_types([WebhookEventType::EntrantsUpdated, WebhookEventType::Test]);
+        assert_eq!(
+            endpoint.get_event_types(),
+            &[WebhookEventType::EntrantsUpdated]
+        );
+        assert!(endpoint.accepts(WebhookEventType::EntrantsUpdated));
+        assert!(!endpoint.accepts(WebhookEventType::TournamentUpdated));
+        endpoint.set_active(false);
+        assert!(!endpoint.accepts(WebhookEventType::EntrantsUpdated));
+        assert!(endpoint.accepts(WebhookEventType::Test));
+    }
+
+    #[test]
+    fn format_defaults_to_json_payload() {
+        let endpoint = WebhookEndpoint::new(IdVersion::default());
+        assert_eq!(endpoint.get_format(), WebhookFormat::Json);
+        for format in WebhookFormat::ALL {
+            assert_eq!(WebhookFormat::from_str(format.as_str()).unwrap(), format);
+        }
+        assert!(WebhookFormat::from_str("teams").is_err());
+
+        let payload = WebhookPayload::new(WebhookEventData::Test {
+            message: "hello".into(),
+        });
+        assert_eq!(
+            WebhookFormat::Json.render_body(&payload).unwrap(),
+            payload.to_json().unwrap()
+        );
+    }
+
+    #[test]
+    fn endpoint_validation() {
+        let mut endpoint = WebhookEndpoint::new(IdVersion::default());
+        assert!(endpoint.get_secret().starts_with(WEBHOOK_SECRET_PREFIX));
+        let errs = endpoint.validate().unwrap_err();
+        let fields: Vec<&str> = errs.errors.iter().map(|e| e.get_field()).collect();
+        assert_eq!(fields, vec!["name", "url", "event_types"]);
+
+        endpoint
+            .set_name("Club Bot")
+            .set_url("ftp://example.org")
+            .set_event_types([WebhookEventType::TournamentUpdated]);
+        let errs = endpoint.validate().unwrap_err();
+        assert_eq!(errs.errors[0].get_code(), "invalid_format");
+
+        endpoint.set_url(" https://example.org/hook ");
+        assert_eq!(endpoint.get_url(), "https://example.org/hook");
+        assert!(endpoint.validate().is_ok());
+    }
+
+    #[test]
+    fn delivery_retries() {
+        let delivery = |status_code: Option<u16>| WebhookDelivery {
+            id: Uuid::new_v4(),
+            endpoint_id: Uuid::new_v4(),
+            event_id: Uuid::new_v4(),
+            event_type: WebhookEventType::Test,
+            payload: String::new(),
+            status_code,
+            error: status_code.is_none().then(|| String::from("timeout")),
+            duration_ms: 0,
+            attempted_at: Utc::now(),
+        };
+        assert!(delivery(None).is_retryable());
+        assert!(delivery(Some(503)).is_retryable());
+        assert!(delivery(Some(429)).is_retryable());
+        assert!(!delivery(Some(204)).is_retryable());
+        assert!(!delivery(Some(404)).is_retryable());
+    }
+}
@@ -0,0 +1,131 @@
+//! derive macros for the object traits of `app_core`
+//!
+//! Persisted tournament objects identify themselves by [`ObjectIdVersion`] and, if they are
+//! numbered within their parent (e.g. stages of a tournament), by [`ObjectNumber`]. Instead of
+//! writing these impls by hand for each new object, derive them from a field:
+//!
+//! ```ignore
+//! use app_core::utils::{id_version::IdVersion, traits::{ObjectIdVersion, ObjectNumber}};
+//!
+//! #[derive(ObjectIdVersion, ObjectNumber)]
+//! pub struct Round {
+//!     id_version: IdVersion,
+//!     #[object(number)]
+//!     round_number: u32,
+//! }
+//! ```
+//!
+//! Fields named `id_version` and `number` are used by default; `#[object(id_version)]` and
+//! `#[object(number)]` select another field. The `id_version` field may be an `IdVersion` or
+//! another object, to which the impl delegates, e.g. the base of a tournament.
+//!
+//! Change detection needs no derive: `Diffable` is implemented for all maps of objects, which
+//! implement `PartialEq` and `Clone`.
+
+use proc_macro::TokenStream;
+use proc_macro2::TokenStream as TokenStream2;
+use quote::quote;
+use syn::{Data, DeriveInput, Fields, Ident, Member, parse_macro_input, spanned::Spanned};
+
+/// Derive `ObjectIdVersion` from the field `id_version` or the field marked with
+/// `#[object(id_version)]`.
+#[proc_macro_derive(ObjectIdVersion, attributes(object))]
+pub fn derive_object_id_version(input: TokenStream) -> TokenStream {
+    let input = parse_macro_input!(input as DeriveInput);
+    expand(&input, "id_version", |field| {
+        quote! {
+            fn get_id_version(&self) -> ::app_core::utils::id_version::IdVersion {
+                ::app_core::utils::traits::ObjectIdVersion::get_id_version(&self.#field)
+            }
+        }
+    })
+    .map(|body| impl_trait(&input, quote!(ObjectIdVersion), body))
+    .unwrap_or_else(syn::Error::into_compile_error)
+    .into()
+}
+
+/// Derive `ObjectNumber` from the `u32` field `number` or the field marked with
+/// `#[object(number)]`.
+#[proc_macro_derive(ObjectNumber, attributes(object))]
+pub fn derive_object_number(input: TokenStream) -> TokenStream {
+    let input = parse_macro_input!(input as DeriveInput);
+    expand(&input, "number", |field| {
+        quote! {
+            fn get_object_number(&self) -> u32 {
+                self.#field
+            }
+        }
+    })
+    .map(|body| impl_trait(&input, quote!(ObjectNumber), body))
+    .unwrap_or_else(syn::Error::into_compile_error)
+    .into()
+}
+
+fn impl_trait(input: &DeriveInput, trait_name: TokenStream2, body: TokenStream2) -> TokenStream2 {
+    let name = &input.ident;
+    let (impl_generics, ty_generics, where_clause) = input.generics.split_for_impl();
+    quote! {
+        impl #impl_generics ::app_core::utils::traits::#trait_name for #name #ty_generics
+        #where_clause
+        {
+            #body
+        }
+    }
+}
+
+/// Find the field for `role` and build the body of the impl from it.
+fn expand(
+    input: &DeriveInput,
+    role: &str,
+    body: impl Fn(&Member) -> TokenStream2,
+) -> syn::Result<TokenStream2> {
+    let Data::Struct(data) = &input.data else {
+        return Err(syn::Error::new(
+            input.span(),
+            "object traits can only be derived for structs",
+        ));
+    };
+    let Fields::Named(fields) = &data.fields else {
+        return Err(syn::Error::new(
+            data.fields.span(),
+            "object traits can only be derived for structs with named fields",
+        ));
+    };
+
+    let mut marked = None;
+    for field in &fields.named {
+        for attr in field.attrs.iter().filter(|a| a.path().is_ident("object")) {
+            let role_ident: Ident = attr.parse_args()?;
+            if role_ident != "id_version" && role_ident != "number" {
+                return Err(syn::Error::new(
+                    role_ident.span(),
+                    "expected #[object(id_version)] or #[object(number)]",
+                ));
+            }
+            if role_ident == role {
+                if marked.is_some() {
+                    return Err(syn::Error::new(
+                        attr.span(),
+                        format!("only one field may be marked with #[object({role})]"),
+                    ));
+                }
+                marked = field.ident.clone();
+            }
+        }
+    }
+    let field = marked
+        .or_else(|| {
+            fields
+                .named
+                .iter()
+                .filter_map(|f| f.ident.clone())
+                .find(|ident| ident == role)
+        })
+        .ok_or_else(|| {
+            syn::Error::new(
+                input.ident.span(),
+                format!("missing field `{role}` or field marked with #[object({role})]"),
+            )
+        })?;
+    Ok(body(&Member::Named(field)))
+}
@@ -0,0 +1,84 @@
+//! selection of a postal address
+
+use crate::{
+    components::inputs::InputCommitAction, server_fn::postal_address::list_postal_addresses,
+};
+use leptos::prelude::*;
+use uuid::Uuid;
+
+/// maximum number of addresses to select from
+const ADDRESS_SELECT_LIMIT: usize = 100;
+
+/// Select of a postal address by name and city. Selecting the empty option clears the
+/// selection; failed loading leaves only the empty option.
+#[component]
+pub fn AddressSelect(
+    /// Label text for the select
+    #[prop(into)]
+    label: String,
+    /// Name attribute for the select; if None, the select is not submitted in forms.
+    #[prop(into, optional)]
+    name: Option<String>,
+    /// Optional data-testid attribute for testing
+    #[prop(into, optional)]
+    data_testid: Option<String>,
+    /// id of selected address
+    #[prop(into)]
+    value: Signal<Option<Uuid>>,
+    /// Defines the action to take when the value changes.
+    action: InputCommitAction<Uuid>,
+    /// Label of the empty option
+    #[prop(into)]
+    clear_label: String,
+) -> impl IntoView {
+    let addresses = Resource::new(
+        || (),
+        |_| async move {
+            list_postal_addresses(String::new(), Some(ADDRESS_SELECT_LIMIT))
+                .await
+                .unwrap_or_default()
+        },
+    );
+
+    view! {
+        <div class="form-control w-full">
+            <label class="label">
+                <span class="label-text">{label}</span>
+            </label>
+            <select
+                class="select select-bordered w-full"
+                name=name
+                data-testid=data_testid
+                prop:value=move || value.get().map(|id| id.to_string()).unwrap_or_default()
+                on:change:target=move |ev| {
+                    let selected = Uuid::parse_str(&ev.target().value()).ok();
+                    if action.execute(selected) {
+                        ev.target().form().map(|f| f.request_submit());
+                    }
+                }
+            >
+                <option value="">{clear_label}</option>
+                <Transition>
+                    {move || {
+                        addresses
+                            .get()
+                            .unwrap_or_default()
+                            .into_iter()
+                            .map(|address| {
+                                let id = address.get_id();
+                                view! {
+                                    <option
+                                        value=id.to_string()
+                                        selected=move || value.get() == Some(id)
+                                    >
+                                        {format!("{}, {}", address.get_name(), address.get_locality())}
+                                    </option>
+                                }
+                            })
+                            .collect_view()
+                    }}
+                </Transition>
+            </select>
+        </div>
+    }
+}
@@ -0,0 +1,112 @@
+//! multi-select of table rows and confirmation of bulk operations
+//!
+//! Lists check rows with [`BulkCheckCell`] and all visible rows with [`BulkCheckAll`]; the
+//! checked ids are kept in the [`ObjectEditorMapContext`] of the list. Bulk operations are
+//! confirmed once for all checked rows with [`BulkConfirmDialog`].
+
+use crate::{
+    hooks::use_ui_language::use_ui_language,
+    i18n::UiText,
+    params::ParamQueryId,
+    state::{EditorContext, object_table::ObjectEditorMapContext},
+};
+use leptos::prelude::*;
+use uuid::Uuid;
+
+/// header cell, which checks or unchecks all visible rows
+#[component]
+pub fn BulkCheckAll<OE, Q>(map: ObjectEditorMapContext<OE, Q>) -> impl IntoView
+where
+    OE: EditorContext,
+    Q: ParamQueryId,
+{
+    let ui_language = use_ui_language();
+
+    view! {
+        <th class="w-8">
+            <input
+                type="checkbox"
+                class="checkbox checkbox-sm"
+                aria-label=move || UiText::SelectAll.get(ui_language.get())
+                data-testid="checkbox-bulk-all"
+                prop:checked=move || map.are_all_checked()
+                on:change=move |ev| map.set_all_checked(event_target_checked(&ev))
+            />
+        </th>
+    }
+}
+
+/// cell, which checks or unchecks row `id`; clicks do not select the row
+#[component]
+pub fn BulkCheckCell<OE, Q>(map: ObjectEditorMapContext<OE, Q>, id: Uuid) -> impl IntoView
+where
+    OE: EditorContext,
+    Q: ParamQueryId,
+{
+    let ui_language = use_ui_language();
+
+    view! {
+        <td class="w-8" on:click=|ev| ev.stop_propagation()>
+            <input
+                type="checkbox"
+                class="checkbox checkbox-sm"
+                aria-label=move || UiText::SelectRow.get(ui_language.get())
+                data-testid=format!("checkbox-bulk-{id}")
+                prop:checked=move || map.is_checked(id)
+                on:change=move |ev| map.set_checked(id, event_target_checked(&ev))
+            />
+        </td>
+    }
+}
+
+/// Dialog, which asks once to confirm a bulk operation on `count` objects. The dialog is
+/// shown, while `open` is true.
+#[component]
+pub fn BulkConfirmDialog(
+    #[prop(into)] open: Signal<bool>,
+    /// title of the dialog, e.g. the name of the operation
+    #[prop(into)]
+    title: Signal<String>,
+    /// number of objects of the operation
+    #[prop(into)]
+    count: Signal<usize>,
+    on_confirm: Callback<()>,
+    on_cancel: Callback<()>,
+) -> impl IntoView {
+    let ui_language = use_ui_language();
+    let text = move |text: UiText| move || text.get(ui_language.get());
+
+    view! {
+        <Show when=move || open.get()>
+            <div class="modal modal-open" role="dialog" data-testid="bulk-confirm-dialog">
+                <div class="modal-box">
+                    <h3 class="font-bold text-lg">{move || title.get()}</h3>
+                    <p class="py-2 text-sm" data-testid="bulk-confirm-hint">
+                        {move || {
+                            UiText::BulkConfirmHint
+                                .fill(ui_language.get(), "count", &count.get().to_string())
+                        }}
+                    </p>
+                    <div class="modal-action">
+                        <button
+                            type="button"
+                            class="btn btn-ghost btn-sm"
+                            data-testid="action-btn-bulk-cancel"
+                            on:click=move |_| on_cancel.run(())
+                        >
+                            {text(UiText::Cancel)}
+                        </button>
+                        <button
+                            type="button"
+                            class="btn btn-warning btn-sm"
+                            data-testid="action-btn-bulk-confirm"
+                            on:click=move |_| on_confirm.run(())
+                        >
+                            {text(UiText::Confirm)}
+                        </button>
+                    </div>
+                </div>
+            </div>
+        </Show>
+    }
+}
@@ -0,0 +1,333 @@
+//! Generic configuration form of sport plugins, which is rendered from the schema of the plugin
+//! config, see `SportPort::config_schema()`
+
+use crate::{
+    components::inputs::{
+        DurationInput, DurationInputUnit, InputCommitAction, NumberInput, TextInput,
+    },
+    hooks::is_field_valid::is_object_field_valid,
+    state::sport_config::SportConfigEditorContext,
+};
+use app_core::{ConfigDurationUnit, ConfigFieldKind, ConfigFieldOption, ConfigFieldSpec};
+use leptos::prelude::*;
+use serde_json::Value;
+use std::time::Duration;
+
+/// Configuration form built from the schema of a sport plugin config.
+///
+/// Needs the `SportConfigEditorContext` of the edited sport configuration.
+#[component]
+pub fn ConfigSchemaForm(
+    /// fields of the plugin config
+    schema: Vec<ConfigFieldSpec>,
+) -> impl IntoView {
+    view! {
+        <div class="space-y-4" data-testid="sport-config-configuration">
+            {schema.into_iter().map(|spec| view! { <ConfigSchemaField spec=spec /> }).collect_view()}
+        </div>
+    }
+}
+
+/// One field of the configuration form. The field is only shown, if its value exists in the
+/// current config.
+#[component]
+fn ConfigSchemaField(spec: ConfigFieldSpec) -> impl IntoView {
+    let sport_config_editor = expect_context::<SportConfigEditorContext>();
+    let spec = StoredValue::new(spec);
+
+    let is_present = Memo::new(move |_| {
+        sport_config_editor.config.with(|config| {
+            config
+                .as_ref()
+                .is_some_and(|config| spec.with_value(|s| s.read(config).is_some()))
+        })
+    });
+
+    view! { <Show when=move || is_present.get()>{move || render_field(spec, sport_config_editor)}</Show> }
+}
+
+/// get the current value of a field
+fn field_value<T>(
+    spec: StoredValue<ConfigFieldSpec>,
+    sport_config_editor: SportConfigEditorContext,
+    map: impl Fn(&Value) -> Option<T> + Send + Sync + 'static,
+) -> Signal<Option<T>>
+where
+    T: Send + Sync + 'static,
+{
+    Signal::derive(move || {
+        sport_config_editor.config.with(|config| {
+            config
+                .as_ref()
+                .and_then(|config| spec.with_value(|s| s.read(config).and_then(&map)))
+        })
+    })
+}
+
+/// write a new value of a field into the config of the editor
+fn write_field(
+    spec: StoredValue<ConfigFieldSpec>,
+    sport_config_editor: SportConfigEditorContext,
+    write: impl FnOnce(&ConfigFieldSpec, &mut Value) -> bool,
+) {
+    if let Some(mut config) = sport_config_editor.config.get_untracked()
+        && spec.with_value(|s| write(s, &mut config))
+    {
+        sport_config_editor.set_config.set(config);
+    }
+}
+
+fn render_field(
+    spec: StoredValue<ConfigFieldSpec>,
+    sport_config_editor: SportConfigEditorContext,
+) -> AnyView {
+    let ConfigFieldSpec {
+        name,
+        label,
+        error_field,
+        kind,
+        ..
+    } = spec.get_value();
+    let data_testid = format!("input-{name}");
+
+    match kind {
+        ConfigFieldKind::Integer { min } => {
+            let value = field_value(spec, sport_config_editor, Value::as_i64);
+            let set_value = Callback::new(move |value: Option<i64>| {
+                if let Some(value) = value {
+                    write_field(spec, sport_config_editor, |s, config| {
+                        s.write(config, value.into())
+                    });
+                }
+            });
+            view! {
+                <NumberInput
+                    label=label
+                    name=name
+                    data_testid=data_testid
+                    value=value
+                    action=InputCommitAction::WriteAndSubmit(set_value)
+                    validation_result=sport_config_editor.validation_result
+                    object_id=sport_config_editor.id
+                    field=error_field
+                    min=min.map(|m| m.to_string()).unwrap_or_default()
+                />
+            }
+            .into_any()
+        }
+        ConfigFieldKind::Decimal { min, step } => {
+            let value = field_value(spec, sport_config_editor, Value::as_f64);
+            let set_value = Callback::new(move |value: Option<f64>| {
+                let value = value.unwrap_or_default();
+                write_field(spec, sport_config_editor, |s, config| {
+                    s.write(config, value.into())
+                });
+            });
+            view! {
+                <NumberInput
+                    label=label
+                    name=name
+                    data_testid=data_testid
+                    value=value
+                    action=InputCommitAction::WriteAndSubmit(set_value)
+                    validation_result=sport_config_editor.validation_result
+                    object_id=sport_config_editor.id
+                    field=error_field
+                    min=min.map(|m| m.to_string()).unwrap_or_default()
+                    step=step.map(|s| s.to_string()).unwrap_or_default()
+                />
+            }
+            .into_any()
+        }
+        ConfigFieldKind::Duration { unit } => {
+            let value = Signal::derive(move || {
+                sport_config_editor.config.with(|config| {
+                    config
+                        .as_ref()
+                        .and_then(|config| spec.with_value(|s| s.read_duration(config)))
+                })
+            });
+            let set_value = Callback::new(move |duration: Option<Duration>| {
+                let duration = duration.unwrap_or(Duration::from_secs(0));
+                write_field(spec, sport_config_editor, |s, config| {
+                    s.write_duration(config, duration)
+                });
+            });
+            let unit = match unit {
+                ConfigDurationUnit::Seconds => DurationInputUnit::Seconds,
+                ConfigDurationUnit::Minutes => DurationInputUnit::Minutes,
+            };
+            view! {
+                <DurationInput
+                    label=label
+                    name=name
+                    data_testid=data_testid
+                    value=value
+                    action=InputCommitAction::WriteAndSubmit(set_value)
+                    unit=unit
+                    validation_result=sport_config_editor.validation_result
+                    object_id=sport_config_editor.id
+                    field=error_field
+                />
+            }
+            .into_any()
+        }
+        ConfigFieldKind::Text => {
+            let value = field_value(spec, sport_config_editor, |v| {
+                v.as_str().map(str::to_string)
+            });
+            let set_value = Callback::new(move |value: Option<String>| {
+                let value = value.unwrap_or_default();
+                write_field(spec, sport_config_editor, |s, config| {
+                    s.write(config, value.into())
+                });
+            });
+            view! {
+                <TextInput
+                    label=label
+                    name=name
+                    data_testid=data_testid
+                    value=value
+                    action=InputCommitAction::WriteAndSubmit(set_value)
+                    validation_result=sport_config_editor.validation_result
+                    object_id=sport_config_editor.id
+                    field=error_field
+                />
+            }
+            .into_any()
+        }
+        ConfigFieldKind::Toggle => {
+            let value = field_value(spec, sport_config_editor, Value::as_bool);
+            view! {
+                <label class="label cursor-pointer gap-2 justify-start">
+                    <input
+                        type="checkbox"
+                        class="toggle"
+                        name=name
+                        data-testid=data_testid
+                        prop:checked=move || value.get().unwrap_or_default()
+                        on:change:target=move |ev| {
+                            let checked = ev.target().checked();
+                            write_field(spec, sport_config_editor, |s, config| {
+                                s.write(config, checked.into())
+                            });
+                            ev.target().form().map(|f| f.request_submit());
+                        }
+                    />
+                    <span class="label-text">{label}</span>
+                </label>
+            }
+            .into_any()
+        }
+        ConfigFieldKind::Select { options } => view! {
+            <ConfigSelect
+                label=label
+                name=name
+                field=error_field
+                options=options
+                spec=spec
+                sport_config_editor=sport_config_editor
+            />
+        }
+        .into_any(),
+    }
+}
+
+/// select of one option of a field; options are matched by their variant, therefore selecting
+/// the option of the current variant keeps the data of the variant
+#[component]
+fn ConfigSelect(
+    label: String,
+    name: String,
+    field: String,
+    options: Vec<ConfigFieldOption>,
+    spec: StoredValue<ConfigFieldSpec>,
+    sport_config_editor: SportConfigEditorContext,
+) -> impl IntoView {
+    let data_testid = format!("select-{name}");
+    let options = StoredValue::new(options);
+
+    // variant of the current value
+    let selected = Signal::derive(move || {
+        sport_config_editor.config.with(|config| {
+            config.as_ref().and_then(|config| {
+                spec.with_value(|s| s.read(config)).and_then(|value| {
+                    options.with_value(|options| {
+                        options
+                            .iter()
+                            .find(|o| o.matches(value))
+                            .and_then(|o| o.variant().map(str::to_string))
+                    })
+                })
+            })
+        })
+    });
+
+    let error = Signal::derive(move || {
+        is_object_field_valid(
+            sport_config_editor.validation_result,
+            sport_config_editor.id,
+            &field,
+        )
+        .err()
+        .map(|e| e.to_string())
+    });
+
+    view! {
+        <div class="form-control w-full">
+            <label class="label">
+                <span class="label-text">{label}</span>
+            </label>
+            <select
+                class="select select-bordered w-full"
+                aria-invalid=move || error.get().is_some().to_string()
+                prop:value=move || selected.get().unwrap_or_default()
+                name=name
+                data-testid=data_testid
+                on:change:target=move |ev| {
+                    let variant = ev.target().value();
+                    if selected.get_untracked().as_deref() == Some(variant.as_str()) {
+                        return;
+                    }
+                    if let Some(option) = options
+                        .with_value(|options| {
+                            options.iter().find(|o| o.variant() == Some(variant.as_str())).cloned()
+                        })
+                    {
+                        write_field(
+                            spec,
+                            sport_config_editor,
+                            |s, config| s.write(config, option.value),
+                        );
+                        ev.target().form().map(|f| f.request_submit());
+                    }
+                }
+            >
+                {options
+                    .get_value()
+                    .into_iter()
+                    .map(|option| {
+                        let variant = option.variant().unwrap_or_default().to_string();
+                        view! {
+                            <option
+                                value=variant.clone()
+                                selected=move || {
+                                    selected.with(|s| s.as_deref() == Some(variant.as_str()))
+                                }
+                            >
+                                {option.label}
+                            </option>
+                        }
+                    })
+                    .collect_view()}
+            </select>
+            <Show when=move || error.get().is_some()>
+                <label class="label">
+                    <span class="label-text-alt text-error w-full text-left block whitespace-normal">
+                        {move || error.get()}
+                    </span>
+                </label>
+            </Show>
+        </div>
+    }
+}
@@ -0,0 +1,118 @@
+//! feedback widget, which submits feedback of users with context of the client
+
+use crate::{
+    params::{ParamQuery, TournamentBaseIdQuery},
+    server_fn::feedback::SubmitFeedback,
+    state::{client_errors::ClientErrorLog, toast_state::ToastContext},
+};
+use app_core::Feedback;
+use leptos::prelude::*;
+use leptos_router::hooks::use_location;
+
+/// version of the app, which is attached to feedback and reports of client errors
+pub const APP_VERSION: &str = env!("CARGO_PKG_VERSION");
+
+#[component]
+pub fn FeedbackWidget() -> impl IntoView {
+    let toast_ctx = expect_context::<ToastContext>();
+    let error_log = expect_context::<ClientErrorLog>();
+    let location = use_location();
+    let tournament_id = TournamentBaseIdQuery::use_param_query();
+
+    let is_open = RwSignal::new(false);
+    let message = RwSignal::new(String::new());
+
+    let submit = ServerAction::<SubmitFeedback>::new();
+    Effect::new(move || match submit.value().get() {
+        Some(Ok(_)) => {
+            message.set(String::new());
+            is_open.set(false);
+            toast_ctx.success("Thank you for your feedback.", None);
+        }
+        Some(Err(err)) => {
+            toast_ctx.error(format!("Could not send feedback: {err}"), None);
+        }
+        None => {}
+    });
+
+    let on_submit = move || {
+        let mut feedback = Feedback::default();
+        feedback
+            .set_message(message.get_untracked())
+            .set_route(location.pathname.get_untracked())
+            .set_tournament_id(tournament_id.get_untracked())
+            .set_app_version(APP_VERSION)
+            .set_recent_errors(error_log.recent());
+        if feedback.validate().is_ok() {
+            submit.dispatch(SubmitFeedback { feedback });
+        } else {
+            toast_ctx.warning("Please describe your feedback.", None);
+        }
+    };
+
+    view! {
+        <div class="fixed bottom-4 left-4 z-50" data-testid="feedback-root">
+            <Show
+                when=move || is_open.get()
+                fallback=move || {
+                    view! {
+                        <button
+                            class="btn btn-sm btn-neutral shadow-lg"
+                            data-testid="action-btn-open-feedback"
+                            on:click=move |_| is_open.set(true)
+                        >
+                            "Feedback"
+                        </button>
+                    }
+                }
+            >
+                <form
+                    class="card w-80 bg-base-100 shadow-xl"
+                    on:submit=move |ev| {
+                        ev.prevent_default();
+                        on_submit();
+                    }
+                >
+                    <div class="card-body gap-2 p-4">
+                        <h2 class="card-title text-base">"Feedback"</h2>
+                        <textarea
+                            class="textarea textarea-bordered w-full"
+                            placeholder="What happened? What did you expect?"
+                            data-testid="input-feedback-message"
+                            prop:value=message
+                            on:input=move |ev| message.set(event_target_value(&ev))
+                        ></textarea>
+                        // context, which is sent with the feedback
+                        <p class="text-xs text-base-content/70" data-testid="feedback-context">
+                            {move || {
+                                format!(
+                                    "Sent with page {}, app version {APP_VERSION} and {} recent errors.",
+                                    location.pathname.get(),
+                                    error_log.count().get(),
+                                )
+                            }}
+                        </p>
+                        <div class="card-actions justify-end">
+                            <button
+                                type="button"
+                                class="btn btn-ghost btn-sm"
+                                data-testid="action-btn-cancel-feedback"
+                                on:click=move |_| is_open.set(false)
+                            >
+                                "Cancel"
+                            </button>
+                            <button
+                                type="submit"
+                                class="btn btn-primary btn-sm"
+                                data-testid="action-btn-submit-feedback"
+                                disabled=move || submit.pending().get()
+                            >
+                                "Send"
+                            </button>
+                        </div>
+                    </div>
+                </form>
+            </Show>
+        </div>
+    }
+}
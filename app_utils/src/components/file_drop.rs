@@ -0,0 +1,97 @@
+//! drop zone for text files, e.g. CSV imports
+
+use leptos::{
+    prelude::*,
+    wasm_bindgen::{JsCast, closure::Closure},
+    web_sys::{DragEvent, File, FileReader, HtmlInputElement},
+};
+
+/// read `file` as text and pass its content to `on_load`
+fn read_text_file(file: File, on_load: Callback<String>) {
+    let Ok(reader) = FileReader::new() else {
+        return;
+    };
+    let reader_in_closure = reader.clone();
+    let onload = Closure::once(move || {
+        if let Some(text) = reader_in_closure
+            .result()
+            .ok()
+            .and_then(|result| result.as_string())
+        {
+            on_load.run(text);
+        }
+    });
+    reader.set_onload(Some(onload.as_ref().unchecked_ref()));
+    // reader keeps the closure until the file is loaded
+    onload.forget();
+    let _ = reader.read_as_text(&file);
+}
+
+/// Drop zone, which accepts a text file per drag and drop or file picker and passes its
+/// content to `on_load`.
+#[component]
+pub fn TextFileDropZone(
+    /// hint shown inside the drop zone
+    #[prop(into)]
+    label: String,
+    /// value of the `accept` attribute of the file picker, e.g. `.csv,text/csv`
+    #[prop(into)]
+    accept: String,
+    #[prop(into)] data_testid: String,
+    on_load: Callback<String>,
+    #[prop(into, optional)] disabled: Signal<bool>,
+) -> impl IntoView {
+    let drag_over = RwSignal::new(false);
+    let zone_testid = format!("{data_testid}-zone");
+
+    let on_drop = move |ev: DragEvent| {
+        ev.prevent_default();
+        drag_over.set(false);
+        if disabled.get_untracked() {
+            return;
+        }
+        if let Some(file) = ev
+            .data_transfer()
+            .and_then(|dt| dt.files())
+            .and_then(|files| files.get(0))
+        {
+            read_text_file(file, on_load);
+        }
+    };
+
+    let on_change = move |ev: leptos::ev::Event| {
+        let input: HtmlInputElement = event_target(&ev);
+        if let Some(file) = input.files().and_then(|files| files.get(0)) {
+            read_text_file(file, on_load);
+        }
+        // allow uploading the same file again
+        input.set_value("");
+    };
+
+    view! {
+        <label
+            class="flex flex-col items-center justify-center w-full p-6 border-2 border-dashed rounded-lg cursor-pointer border-base-300"
+            class:border-primary=move || drag_over.get()
+            class:bg-base-200=move || drag_over.get()
+            class:opacity-50=move || disabled.get()
+            data-testid=zone_testid
+            on:dragover=move |ev: DragEvent| {
+                // required to allow dropping
+                ev.prevent_default();
+                drag_over.set(true);
+            }
+            on:dragleave=move |_| drag_over.set(false)
+            on:drop=on_drop
+        >
+            <span class="text-sm opacity-70">{label}</span>
+            <input
+                type="file"
+                accept=accept
+                class="hidden"
+                data-testid=data_testid
+                disabled=move || disabled.get()
+                on:change=on_change
+            />
+        </label>
+    }
+}
@@ -0,0 +1,149 @@
+//! live standings of a group
+
+use crate::{
+    error::{AppResult, ComponentError, strategy::handle_read_error},
+    hooks::use_on_cancel::use_on_cancel,
+    server_fn::{entrant::list_entrants, group::get_group_standings},
+    state::{activity_tracker::ActivityTracker, error_state::PageErrorContext},
+};
+use app_core::{CrTopic, GroupStanding};
+use cr_leptos_axum_socket::use_client_registry_socket;
+use leptos::prelude::*;
+use std::collections::HashMap;
+use uuid::Uuid;
+
+/// Standings of a group ranked by the sport plugin and the tie breakers of the tournament.
+///
+/// Standings are refreshed, whenever a match of the group is updated. Entrants, whose tie
+/// could not be broken, share a rank.
+#[component]
+pub fn GroupStandingsTable(tournament_id: Uuid, group_id: Uuid) -> impl IntoView {
+    // --- global context and state ---
+    let page_err_ctx = expect_context::<PageErrorContext>();
+    let activity_tracker = expect_context::<ActivityTracker>();
+    let component_id = StoredValue::new(Uuid::new_v4());
+
+    on_cleanup(move || {
+        page_err_ctx.clear_all_for_component(component_id.get_value());
+        activity_tracker.remove_component(component_id.get_value());
+    });
+
+    let standings = Resource::new(
+        || (),
+        move |_| async move {
+            activity_tracker
+                .track_activity_wrapper(
+                    component_id.get_value(),
+                    load_standings(tournament_id, group_id),
+                )
+                .await
+                .map_err(|app_error| ComponentError::new(component_id.get_value(), app_error))
+        },
+    );
+
+    let refetch = Callback::new(move |()| standings.refetch());
+    page_err_ctx.register_retry_handler(component_id.get_value(), refetch);
+
+    // Subscribe to match updates of group
+    use_client_registry_socket(
+        Signal::derive(move || Some(CrTopic::GroupMatches { group_id })),
+        None.into(),
+        refetch,
+    );
+
+    let on_cancel = use_on_cancel();
+
+    view! {
+        <div class="flex flex-col gap-2" data-testid="group-standings">
+            <Transition fallback=move || {
+                view! { <span class="loading loading-spinner loading-md"></span> }
+            }>
+                <ErrorBoundary fallback=move |errors| {
+                    for (_err_id, err) in errors.get().into_iter() {
+                        if let Some(comp_err) = err.into_inner().downcast_ref::<ComponentError>() {
+                            handle_read_error(&page_err_ctx, comp_err, on_cancel);
+                        }
+                    }
+                }>
+                    {move || {
+                        standings
+                            .and_then(|(list, names)| {
+                                if list.is_empty() {
+                                    return view! {
+                                        <p class="italic" data-testid="group-standings-empty">
+                                            "No results yet"
+                                        </p>
+                                    }
+                                        .into_any();
+                                }
+                                let list = list.clone();
+                                let names = names.clone();
+                                view! {
+                                    <table class="table table-xs" data-testid="group-standings-table">
+                                        <thead>
+                                            <tr>
+                                                <th>"Rank"</th>
+                                                <th>"Entrant"</th>
+                                                <th>"Played"</th>
+                                                <th>"W"</th>
+                                                <th>"D"</th>
+                                                <th>"L"</th>
+                                                <th>"Score"</th>
+                                                <th>"Diff"</th>
+                                                <th>"Points"</th>
+                                            </tr>
+                                        </thead>
+                                        <tbody>
+                                            <For
+                                                each=move || list.clone()
+                                                key=|s| s.get_entrant_id()
+                                                children=move |standing| {
+                                                    let score = standing.score;
+                                                    let name = names
+                                                        .get(&score.entrant_id)
+                                                        .cloned()
+                                                        .unwrap_or_else(|| "-".to_string());
+                                                    view! {
+                                                        <tr data-testid="group-standings-row">
+                                                            <td data-testid="group-standings-rank">
+                                                                {standing.rank}
+                                                            </td>
+                                                            <td data-testid="group-standings-name">{name}</td>
+                                                            <td>{score.wins + score.draws + score.losses}</td>
+                                                            <td>{score.wins}</td>
+                                                            <td>{score.draws}</td>
+                                                            <td>{score.losses}</td>
+                                                            <td>{score.total_score}</td>
+                                                            <td>{format!("{:+}", score.relative_score)}</td>
+                                                            <td data-testid="group-standings-points">
+                                                                {score.victory_points}
+                                                            </td>
+                                                        </tr>
+                                                    }
+                                                }
+                                            />
+                                        </tbody>
+                                    </table>
+                                }
+                                    .into_any()
+                            })
+                    }}
+                </ErrorBoundary>
+            </Transition>
+        </div>
+    }
+}
+
+/// standings of group with names of entrants
+async fn load_standings(
+    tournament_id: Uuid,
+    group_id: Uuid,
+) -> AppResult<(Vec<GroupStanding>, HashMap<Uuid, String>)> {
+    let standings = get_group_standings(tournament_id, group_id).await?;
+    let names = list_entrants(tournament_id)
+        .await?
+        .into_iter()
+        .map(|e| (e.get_id(), e.get_name().to_string()))
+        .collect();
+    Ok((standings, names))
+}
@@ -1,7 +1,11 @@
 //! Input components for the app
 
-use crate::{enum_utils::SelectableOption, hooks::is_field_valid::is_object_field_valid};
-use app_core::utils::validation::ValidationResult;
+use crate::{
+    enum_utils::SelectableOption,
+    hooks::{is_field_valid::is_object_field_valid, use_ui_language::use_ui_language},
+    i18n::UiText,
+};
+use app_core::{Language, utils::validation::ValidationResult};
 use displaydoc::Display;
 use leptos::{
     ev::{Event, Targeted},
@@ -30,6 +34,7 @@ impl InputUpdateStrategy {
         set_draft: WriteSignal<Option<String>>,
         action: InputCommitAction<T>,
         set_parse_err: WriteSignal<Option<String>>,
+        language: Language,
     ) where
         T: FromStr + Display + Clone + Send + Sync + 'static,
     {
@@ -40,7 +45,7 @@ impl InputUpdateStrategy {
             }
             InputUpdateStrategy::Input => {
                 // Update and commit on every input event
-                self.commit_update(ev, set_draft, action, set_parse_err);
+                self.commit_update(ev, set_draft, action, set_parse_err, language);
             }
         }
     }
@@ -51,12 +56,13 @@ impl InputUpdateStrategy {
         set_draft: WriteSignal<Option<String>>,
         action: InputCommitAction<T>,
         set_parse_err: WriteSignal<Option<String>>,
+        language: Language,
     ) where
         T: FromStr + Display + Clone + Send + Sync + 'static,
     {
         match self {
             InputUpdateStrategy::Change => {
-                self.commit_update(ev, set_draft, action, set_parse_err);
+                self.commit_update(ev, set_draft, action, set_parse_err, language);
             }
             InputUpdateStrategy::Input => {
                 // No additional action needed on change, since value is already committed on input
@@ -70,6 +76,7 @@ impl InputUpdateStrategy {
         set_draft: WriteSignal<Option<String>>,
         action: InputCommitAction<T>,
         set_parse_err: WriteSignal<Option<String>>,
+        language: Language,
     ) where
         T: FromStr + Display + Clone + Send + Sync + 'static,
     {
@@ -86,7 +93,7 @@ impl InputUpdateStrategy {
                     action.execute(Some(val))
                 }
                 Err(_) => {
-                    set_parse_err.set(Some(format!("Invalid input format, parse failed.")));
+                    set_parse_err.set(Some(UiText::InvalidInputFormat.get(language).to_string()));
                     false
                 }
             }
@@ -264,14 +271,15 @@ where
     // type parse error
     let (parse_err, set_parse_err) = signal(None::<String>);
 
-    // Error state from validation of all objects
+    // Error state from validation of all objects in display language of the UI
+    let ui_language = use_ui_language();
     let error = Signal::derive(move || {
         if let Some(e) = parse_err.get() {
             return Some(e);
         } else {
             is_object_field_valid(validation_result, object_id, &field)
                 .err()
-                .map(|e| e.to_string())
+                .map(|e| e.render(ui_language.get()))
         }
     });
 
@@ -286,7 +294,8 @@ where
     let show_error = move || draft.get().is_none() && error.get().is_some();
 
     // Auto-generate label and placeholder text based on label and optionality
-    let (label, placeholder_text) = generate_label_placeholder(label, optional, placeholder);
+    let (label, placeholder_text) =
+        generate_label_placeholder(label, optional, placeholder, ui_language);
 
     view! {
         <div class="form-control w-full">
@@ -302,10 +311,22 @@ where
                 data-testid=data_testid
                 placeholder=placeholder_text
                 on:input:target=move |ev| {
-                    update_on.commit_input(ev, set_draft, action, set_parse_err)
+                    update_on.commit_input(
+                        ev,
+                        set_draft,
+                        action,
+                        set_parse_err,
+                        ui_language.get_untracked(),
+                    )
                 }
                 on:change:target=move |ev| {
-                    update_on.commit_change(ev, set_draft, action, set_parse_err)
+                    update_on.commit_change(
+                        ev,
+                        set_draft,
+                        action,
+                        set_parse_err,
+                        ui_language.get_untracked(),
+                    )
                 }
                 // USER LEAVES FIELD: Reset draft to sync with core
                 on:blur=move |_| {
@@ -380,14 +401,15 @@ where
     // type parse error
     let (parse_err, set_parse_err) = signal(None::<String>);
 
-    // Error state from validation of all objects
+    // Error state from validation of all objects in display language of the UI
+    let ui_language = use_ui_language();
     let error = Signal::derive(move || {
         if let Some(e) = parse_err.get() {
             return Some(e);
         } else {
             is_object_field_valid(validation_result, object_id, &field)
                 .err()
-                .map(|e| e.to_string())
+                .map(|e| e.render(ui_language.get()))
         }
     });
 
@@ -402,7 +424,8 @@ where
     let show_error = move || draft.get().is_none() && error.get().is_some();
 
     // Auto-generate label and placeholder text based on label and optionality
-    let (label, placeholder_text) = generate_label_placeholder(label, optional, placeholder);
+    let (label, placeholder_text) =
+        generate_label_placeholder(label, optional, placeholder, ui_language);
 
     // Default step to "1" if not provided
     let step_val = if step.is_empty() {
@@ -429,10 +452,22 @@ where
                 data-testid=data_testid
                 placeholder=placeholder_text
                 on:input:target=move |ev| {
-                    update_on.commit_input(ev, set_draft, action, set_parse_err)
+                    update_on.commit_input(
+                        ev,
+                        set_draft,
+                        action,
+                        set_parse_err,
+                        ui_language.get_untracked(),
+                    )
                 }
                 on:change:target=move |ev| {
-                    update_on.commit_change(ev, set_draft, action, set_parse_err)
+                    update_on.commit_change(
+                        ev,
+                        set_draft,
+                        action,
+                        set_parse_err,
+                        ui_language.get_untracked(),
+                    )
                 }
                 // USER LEAVES FIELD: Reset draft to sync with core
                 on:blur=move |_| {
@@ -512,14 +547,15 @@ where
     // type parse error
     let (parse_err, set_parse_err) = signal(None::<String>);
 
-    // Error state from validation of all objects
+    // Error state from validation of all objects in display language of the UI
+    let ui_language = use_ui_language();
     let error = Signal::derive(move || {
         if let Some(e) = parse_err.get() {
             return Some(e);
         } else {
             is_object_field_valid(validation_result, object_id, &field)
                 .err()
-                .map(|e| e.to_string())
+                .map(|e| e.render(ui_language.get()))
         }
     });
 
@@ -541,7 +577,8 @@ where
     let show_error = move || draft.get().is_none() && error.get().is_some();
 
     // Auto-generate label and placeholder text based on label and optionality
-    let (label, placeholder_text) = generate_label_placeholder(label, optional, placeholder);
+    let (label, placeholder_text) =
+        generate_label_placeholder(label, optional, placeholder, ui_language);
 
     view! {
         <div class="form-control w-full">
@@ -632,11 +669,12 @@ where
     // Local state: true while user is interacting with the select
     let (is_selecting, set_is_selecting) = signal(false);
 
-    // Error state from validation of all objects
+    // Error state from validation of all objects in display language of the UI
+    let ui_language = use_ui_language();
     let error = Signal::derive(move || {
         is_object_field_valid(validation_result, object_id, &field)
             .err()
-            .map(|e| e.to_string())
+            .map(|e| e.render(ui_language.get()))
     });
 
     // Derived: Error visibility logic
@@ -644,7 +682,8 @@ where
     let show_error = move || !is_selecting.get() && error.get().is_some();
 
     // Auto-generate data-testid, label, and placeholder text based on name, label, and optionality
-    let (label, placeholder_text) = generate_label_placeholder(label, optional, placeholder);
+    let (label, placeholder_text) =
+        generate_label_placeholder(label, optional, placeholder, ui_language);
 
     view! {
         <div class="form-control w-full">
@@ -768,23 +807,36 @@ where
     }
 }
 
-/// Auto-generate label and placeholder text based on label and optionality
+/// Auto-generate label and placeholder text based on label and optionality in display
+/// language of the UI
 fn generate_label_placeholder(
     label: String,
     optional: bool,
     place_holder: String,
-) -> (String, String) {
-    let (label, placeholder_text) = if optional {
-        (
-            format!("{} (optional)", label.clone()),
-            format!("Enter {} (optional)...", label.to_lowercase()),
-        )
-    } else {
-        (label.clone(), format!("Enter {}...", label.to_lowercase()))
-    };
-    if !place_holder.is_empty() {
-        (label, place_holder)
-    } else {
-        (label, placeholder_text)
-    }
+    ui_language: Signal<Language>,
+) -> (Signal<String>, Signal<String>) {
+    let label = StoredValue::new(label);
+    let full_label = Signal::derive(move || {
+        let optional_text = UiText::Optional.get(ui_language.get());
+        label.with_value(|label| {
+            if optional {
+                format!("{label} ({optional_text})")
+            } else {
+                label.clone()
+            }
+        })
+    });
+    let placeholder_text = Signal::derive(move || {
+        if !place_holder.is_empty() {
+            return place_holder.clone();
+        }
+        // German nouns keep their capital letter
+        let language = ui_language.get();
+        let label = match language {
+            Language::De => full_label.get(),
+            _ => full_label.get().to_lowercase(),
+        };
+        UiText::EnterValue.fill(language, "label", &label)
+    });
+    (full_label, placeholder_text)
 }
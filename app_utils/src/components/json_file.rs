@@ -0,0 +1,72 @@
+//! download and upload of JSON documents
+
+use leptos::{
+    prelude::*,
+    wasm_bindgen::{JsCast, closure::Closure},
+    web_sys::{FileReader, HtmlElement, HtmlInputElement, js_sys::encode_uri_component},
+};
+
+/// Offer `json` as file download with name `file_name`.
+pub fn download_json(file_name: &str, json: &str) {
+    let href = format!(
+        "data:application/json;charset=utf-8,{}",
+        encode_uri_component(json)
+    );
+    let Ok(anchor) = document().create_element("a") else {
+        return;
+    };
+    let _ = anchor.set_attribute("href", &href);
+    let _ = anchor.set_attribute("download", file_name);
+    if let Ok(anchor) = anchor.dyn_into::<HtmlElement>() {
+        anchor.click();
+    }
+}
+
+/// Button, which lets the user pick a JSON file and passes its content to `on_load`.
+#[component]
+pub fn JsonFileUpload(
+    #[prop(into)] label: String,
+    #[prop(into)] data_testid: String,
+    on_load: Callback<String>,
+    #[prop(into, optional)] disabled: Signal<bool>,
+) -> impl IntoView {
+    let on_change = move |ev: leptos::ev::Event| {
+        let input: HtmlInputElement = event_target(&ev);
+        let Some(file) = input.files().and_then(|files| files.get(0)) else {
+            return;
+        };
+        let Ok(reader) = FileReader::new() else {
+            return;
+        };
+        let reader_in_closure = reader.clone();
+        let onload = Closure::once(move || {
+            if let Some(text) = reader_in_closure
+                .result()
+                .ok()
+                .and_then(|result| result.as_string())
+            {
+                on_load.run(text);
+            }
+        });
+        reader.set_onload(Some(onload.as_ref().unchecked_ref()));
+        // reader keeps the closure until the file is loaded
+        onload.forget();
+        let _ = reader.read_as_text(&file);
+        // allow uploading the same file again
+        input.set_value("");
+    };
+
+    view! {
+        <label class="btn btn-sm btn-outline" class:btn-disabled=move || disabled.get()>
+            {label}
+            <input
+                type="file"
+                accept="application/json,.json"
+                class="hidden"
+                data-testid=data_testid
+                disabled=move || disabled.get()
+                on:change=on_change
+            />
+        </label>
+    }
+}
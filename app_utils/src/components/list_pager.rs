@@ -0,0 +1,64 @@
+//! pager of lists, which are loaded page by page
+
+use crate::{
+    hooks::use_url_navigation::use_query_navigation,
+    params::{FilterOffsetQuery, ParamQuery},
+};
+use leptos::prelude::*;
+use leptos_router::{NavigateOptions, hooks::use_navigate};
+
+/// Previous and next buttons of a list. The page is selected by the `filter_offset` query
+/// parameter; a page with less hits than `page_size` is the last page.
+#[component]
+pub fn ListPager(
+    /// number of objects on the current page
+    #[prop(into)]
+    num_hits: Signal<usize>,
+    /// maximum number of objects per page, i.e. the limit of the list query
+    #[prop(into)]
+    page_size: Signal<usize>,
+) -> impl IntoView {
+    let url_update_query = use_query_navigation().url_update_query;
+    let offset = FilterOffsetQuery::use_param_query();
+    let offset = move || offset.get().unwrap_or_default();
+    let page_size = move || page_size.get().max(1);
+    let has_previous = move || offset() > 0;
+    let has_next = move || num_hits.get() >= page_size();
+
+    let go_to = move |new_offset: usize| {
+        let navigate = use_navigate();
+        let nav_url = url_update_query(FilterOffsetQuery::KEY, &new_offset.to_string(), None);
+        navigate(
+            &nav_url,
+            NavigateOptions {
+                scroll: false,
+                replace: true,
+                ..Default::default()
+            },
+        );
+    };
+
+    view! {
+        <div class="flex justify-center items-center gap-2" data-testid="list-pager">
+            <button
+                class="btn btn-sm"
+                data-testid="action-btn-previous-page"
+                disabled=move || !has_previous()
+                on:click=move |_| go_to(offset().saturating_sub(page_size()))
+            >
+                "Previous"
+            </button>
+            <span class="text-sm opacity-70" data-testid="list-pager-page">
+                {move || format!("Page {}", offset() / page_size() + 1)}
+            </span>
+            <button
+                class="btn btn-sm"
+                data-testid="action-btn-next-page"
+                disabled=move || !has_next()
+                on:click=move |_| go_to(offset() + page_size())
+            >
+                "Next"
+            </button>
+        </div>
+    }
+}
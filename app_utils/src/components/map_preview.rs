@@ -0,0 +1,73 @@
+//! map preview of geocoded postal addresses
+//!
+//! The preview embeds the map of OpenStreetMap with a marker at the location; no map library
+//! is loaded by the app itself.
+
+use app_core::GeoPoint;
+use leptos::prelude::*;
+
+/// half width of the shown map section in degrees
+const MAP_SPAN: f64 = 0.005;
+
+/// URL of the embedded map around `location`
+fn embed_url(location: GeoPoint) -> String {
+    let GeoPoint {
+        latitude: lat,
+        longitude: lon,
+    } = location;
+    format!(
+        "https://www.openstreetmap.org/export/embed.html?bbox={},{},{},{}&layer=mapnik&marker={lat},{lon}",
+        lon - MAP_SPAN,
+        lat - MAP_SPAN,
+        lon + MAP_SPAN,
+        lat + MAP_SPAN,
+    )
+}
+
+/// URL of the full map around `location`
+fn map_url(location: GeoPoint) -> String {
+    let GeoPoint {
+        latitude: lat,
+        longitude: lon,
+    } = location;
+    format!("https://www.openstreetmap.org/?mlat={lat}&mlon={lon}#map=17/{lat}/{lon}")
+}
+
+/// Map with a marker at `location`; a hint, if the address has no location.
+#[component]
+pub fn MapPreview(#[prop(into)] location: Signal<Option<GeoPoint>>) -> impl IntoView {
+    view! {
+        <div data-testid="map-preview">
+            {move || match location.get() {
+                Some(location) => {
+                    view! {
+                        <iframe
+                            class="w-full h-64 rounded-box border border-base-content/10"
+                            title="Map"
+                            loading="lazy"
+                            src=embed_url(location)
+                            data-testid="map-preview-frame"
+                        ></iframe>
+                        <a
+                            class="link text-sm"
+                            href=map_url(location)
+                            target="_blank"
+                            rel="noopener noreferrer"
+                        >
+                            "Open larger map"
+                        </a>
+                    }
+                        .into_any()
+                }
+                None => {
+                    view! {
+                        <p class="text-sm opacity-70" data-testid="map-preview-no-location">
+                            "No location known. Addresses are located on save."
+                        </p>
+                    }
+                        .into_any()
+                }
+            }}
+        </div>
+    }
+}
@@ -1,5 +1,20 @@
 //! general components for the app
 
+pub mod address_select;
+pub mod bulk_select;
+pub mod config_schema_form;
+pub mod feedback;
+pub mod file_drop;
 pub mod global_error_banner;
+pub mod group_standings_table;
 pub mod inputs;
+pub mod json_file;
+pub mod list_pager;
+pub mod map_preview;
+pub mod offline_status;
+pub mod presence_banner;
+pub mod sport_config_select;
+pub mod standings_warning;
+pub mod theme_switcher;
 pub mod toast;
+pub mod version_conflict;
@@ -0,0 +1,42 @@
+//! status of offline edits, e.g. on score entry pages used courtside
+
+use crate::{hooks::use_ui_language::use_ui_language, i18n::UiText, offline::OfflineSync};
+use leptos::prelude::*;
+
+/// Badges for lost connectivity and for edits waiting for replay by [`OfflineSync`], and an
+/// alert for edits rejected on replay. Renders nothing, while the client is online and no
+/// edits are pending.
+#[component]
+pub fn OfflineStatus() -> impl IntoView {
+    let sync = expect_context::<OfflineSync>();
+    let ui_language = use_ui_language();
+    let online = sync.is_online();
+    let pending = sync.pending_count();
+    let num_conflicts = Signal::derive(move || sync.conflicts().with(Vec::len));
+
+    view! {
+        <div class="flex flex-wrap items-center gap-2" data-testid="offline-status" aria-live="polite">
+            <Show when=move || !online.get()>
+                <span class="badge badge-warning" data-testid="offline-status-offline">
+                    {move || UiText::Offline.get(ui_language.get())}
+                </span>
+            </Show>
+            <Show when=move || { pending.get() > 0 }>
+                <span class="badge badge-info" data-testid="offline-status-pending">
+                    {move || {
+                        UiText::PendingEdits
+                            .fill(ui_language.get(), "count", &pending.get().to_string())
+                    }}
+                </span>
+            </Show>
+            <Show when=move || { num_conflicts.get() > 0 }>
+                <div class="alert alert-error text-sm" role="alert" data-testid="offline-status-conflicts">
+                    {move || {
+                        UiText::OfflineConflicts
+                            .fill(ui_language.get(), "count", &num_conflicts.get().to_string())
+                    }}
+                </div>
+            </Show>
+        </div>
+    }
+}
@@ -0,0 +1,42 @@
+//! banner showing other editors of an object
+
+use crate::{
+    hooks::{use_presence::use_presence, use_ui_language::use_ui_language},
+    i18n::UiText,
+};
+use leptos::prelude::*;
+use uuid::Uuid;
+
+/// Joins the presence of `object_id` and shows the other editors of the object, so version
+/// conflicts come less as a surprise. Renders nothing, while nobody else edits the object.
+#[component]
+pub fn PresenceBanner(#[prop(into)] object_id: Signal<Option<Uuid>>) -> impl IntoView {
+    let ui_language = use_ui_language();
+    let others = use_presence(object_id);
+    let names = move || {
+        let language = ui_language.get();
+        let names = others.with(|others| {
+            others
+                .iter()
+                .map(|editor| {
+                    if editor.name.is_empty() {
+                        UiText::AnonymousEditor.get(language)
+                    } else {
+                        editor.name.as_str()
+                    }
+                    .to_string()
+                })
+                .collect::<Vec<_>>()
+                .join(", ")
+        });
+        UiText::AlsoEditing.fill(language, "names", &names)
+    };
+
+    view! {
+        <Show when=move || others.with(|others| !others.is_empty())>
+            <div class="alert alert-info text-sm" role="status" data-testid="presence-banner">
+                {names}
+            </div>
+        </Show>
+    }
+}
@@ -0,0 +1,84 @@
+//! selection of the sport configuration of a tournament
+
+use crate::{components::inputs::InputCommitAction, server_fn::sport_config::list_sport_configs};
+use leptos::prelude::*;
+use uuid::Uuid;
+
+/// maximum number of sport configurations to select from
+const SPORT_CONFIG_SELECT_LIMIT: usize = 100;
+
+/// Select of an active sport configuration of a sport by name. Selecting the empty option
+/// clears the selection; failed loading leaves only the empty option.
+#[component]
+pub fn SportConfigSelect(
+    /// Label text for the select
+    #[prop(into)]
+    label: String,
+    /// Optional data-testid attribute for testing
+    #[prop(into, optional)]
+    data_testid: Option<String>,
+    /// id of the sport, whose configurations are listed
+    #[prop(into)]
+    sport_id: Signal<Option<Uuid>>,
+    /// id of selected sport configuration
+    #[prop(into)]
+    value: Signal<Option<Uuid>>,
+    /// Defines the action to take when the value changes.
+    action: InputCommitAction<Uuid>,
+    /// Label of the empty option
+    #[prop(into)]
+    clear_label: String,
+) -> impl IntoView {
+    let configs = Resource::new(
+        move || sport_id.get(),
+        |sport_id| async move {
+            match sport_id {
+                Some(sport_id) => list_sport_configs(sport_id, Some(SPORT_CONFIG_SELECT_LIMIT))
+                    .await
+                    .unwrap_or_default(),
+                None => Vec::new(),
+            }
+        },
+    );
+
+    view! {
+        <div class="form-control w-full">
+            <label class="label">
+                <span class="label-text">{label}</span>
+            </label>
+            <select
+                class="select select-bordered w-full"
+                data-testid=data_testid
+                prop:value=move || value.get().map(|id| id.to_string()).unwrap_or_default()
+                on:change:target=move |ev| {
+                    let selected = Uuid::parse_str(&ev.target().value()).ok();
+                    if action.execute(selected) {
+                        ev.target().form().map(|f| f.request_submit());
+                    }
+                }
+            >
+                <option value="">{clear_label}</option>
+                <Transition>
+                    {move || {
+                        configs
+                            .get()
+                            .unwrap_or_default()
+                            .into_iter()
+                            .map(|config| {
+                                let id = config.get_id();
+                                view! {
+                                    <option
+                                        value=id.to_string()
+                                        selected=move || value.get() == Some(id)
+                                    >
+                                        {config.get_name().to_string()}
+                                    </option>
+                                }
+                            })
+                            .collect_view()
+                    }}
+                </Transition>
+            </select>
+        </div>
+    }
+}
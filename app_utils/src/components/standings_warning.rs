@@ -0,0 +1,66 @@
+use app_core::StandingsCheckReport;
+use leptos::prelude::*;
+use uuid::Uuid;
+
+/// Dashboard warning for inconsistent group standings.
+///
+/// Shows all anomalies of the given reports. Clicking a suspect match calls
+/// `on_select_match`, which is used to drill down to the match.
+#[component]
+pub fn StandingsWarning(
+    #[prop(into)] reports: Signal<Vec<StandingsCheckReport>>,
+    on_select_match: Callback<Uuid>,
+) -> impl IntoView {
+    let inconsistent = move || {
+        reports
+            .get()
+            .into_iter()
+            .filter(|r| !r.is_consistent())
+            .collect::<Vec<_>>()
+    };
+
+    view! {
+        <Show when=move || !inconsistent().is_empty() fallback=|| ()>
+            <div class="alert alert-warning flex-col items-start" data-testid="standings-warning" role="alert">
+                <span class="font-bold">"Standings look inconsistent. Please check the suspect matches."</span>
+                <For
+                    each=inconsistent
+                    key=|r| r.group_id
+                    children=move |report| {
+                        let suspects = report.suspect_matches();
+                        view! {
+                            <details class="w-full" data-testid="standings-warning-group">
+                                <summary class="cursor-pointer">
+                                    {format!("Group {}: {} issue(s)", report.group_id, report.anomalies.len())}
+                                </summary>
+                                <ul class="list-disc ml-6">
+                                    {report
+                                        .anomalies
+                                        .iter()
+                                        .map(|a| view! { <li>{a.description()}</li> })
+                                        .collect_view()}
+                                </ul>
+                                <div class="flex flex-wrap gap-2 mt-2">
+                                    {suspects
+                                        .into_iter()
+                                        .map(|match_id| {
+                                            view! {
+                                                <button
+                                                    class="btn btn-xs btn-outline"
+                                                    data-testid="action-btn-suspect-match"
+                                                    on:click=move |_| on_select_match.run(match_id)
+                                                >
+                                                    {format!("Match {}", &match_id.to_string()[..8])}
+                                                </button>
+                                            }
+                                        })
+                                        .collect_view()}
+                                </div>
+                            </details>
+                        }
+                    }
+                />
+            </div>
+        </Show>
+    }
+}
@@ -0,0 +1,50 @@
+//! switcher of the color theme
+
+use crate::{
+    hooks::use_ui_language::use_ui_language,
+    i18n::UiText,
+    state::{
+        global_state::{GlobalState, GlobalStateStoreFields},
+        theme::{ThemePreference, store_theme_preference},
+    },
+};
+use leptos::prelude::*;
+use reactive_stores::Store;
+
+/// Buttons to select the color theme of the app. The selection is kept in the global state
+/// and stored in the theme cookie.
+#[component]
+pub fn ThemeSwitcher() -> impl IntoView {
+    let state = expect_context::<Store<GlobalState>>();
+    let theme = state.theme();
+    let ui_language = use_ui_language();
+
+    view! {
+        <div class="join" data-testid="theme-switcher">
+            {ThemePreference::ALL
+                .into_iter()
+                .map(|preference| {
+                    let text = match preference {
+                        ThemePreference::System => UiText::ThemeSystem,
+                        ThemePreference::Light => UiText::ThemeLight,
+                        ThemePreference::Dark => UiText::ThemeDark,
+                    };
+                    view! {
+                        <button
+                            type="button"
+                            class="btn btn-xs join-item"
+                            class:btn-active=move || theme.get() == preference
+                            data-testid=format!("action-btn-theme-{}", preference.code())
+                            on:click=move |_| {
+                                theme.set(preference);
+                                store_theme_preference(preference);
+                            }
+                        >
+                            {move || text.get(ui_language.get())}
+                        </button>
+                    }
+                })
+                .collect_view()}
+        </div>
+    }
+}
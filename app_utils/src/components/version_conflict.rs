@@ -0,0 +1,147 @@
+//! dialog to resolve version conflicts of editors
+
+use crate::{
+    hooks::use_ui_language::use_ui_language, i18n::UiText, state::EditorContextWithConflict,
+};
+use app_core::{ConflictResolution, MergeFields};
+use leptos::prelude::*;
+use std::collections::HashSet;
+
+/// Dialog, which is shown if saving the object of `editor` failed, because someone else saved
+/// the object in the meantime. The user may keep the local copy, take the server copy or merge
+/// both copies field by field. Resolved copies, which differ from the server copy, are saved
+/// with `on_submit`.
+#[component]
+pub fn VersionConflictDialog<E>(editor: E, on_submit: Callback<()>) -> impl IntoView
+where
+    E: EditorContextWithConflict,
+{
+    let version_conflict = editor.version_conflict();
+    let conflicting_fields = Memo::new(move |_| {
+        version_conflict.with(|conflict| {
+            conflict
+                .as_ref()
+                .map(|c| c.mine.conflicting_fields(&c.theirs))
+                .unwrap_or_default()
+        })
+    });
+    // fields, which are kept from the local copy during a merge
+    let keep_mine = RwSignal::new(HashSet::<String>::new());
+    Effect::new(move || {
+        let fields = conflicting_fields.get();
+        keep_mine.set(fields.into_iter().map(|f| f.field).collect());
+    });
+
+    let ui_language = use_ui_language();
+    let text = move |text: UiText| move || text.get(ui_language.get());
+
+    let resolve = move |resolution: ConflictResolution| {
+        if editor.resolve_version_conflict(&resolution) {
+            on_submit.run(());
+        }
+    };
+
+    view! {
+        <Show when=move || version_conflict.with(Option::is_some)>
+            <div class="modal modal-open" role="dialog" data-testid="version-conflict-dialog">
+                <div class="modal-box max-w-3xl">
+                    <h3 class="font-bold text-lg">{text(UiText::ConflictTitle)}</h3>
+                    <p class="py-2 text-sm opacity-70">
+                        {text(UiText::ConflictHint)}
+                    </p>
+                    <table class="table table-sm" data-testid="table-version-conflict">
+                        <thead>
+                            <tr>
+                                <th>{text(UiText::ConflictField)}</th>
+                                <th>{text(UiText::ConflictMine)}</th>
+                                <th>{text(UiText::ConflictTheirs)}</th>
+                            </tr>
+                        </thead>
+                        <tbody>
+                            <For
+                                each=move || conflicting_fields.get()
+                                key=|f| f.field.clone()
+                                children=move |f| {
+                                    let field = StoredValue::new(f.field.clone());
+                                    let is_mine = move || {
+                                        keep_mine.with(|k| field.with_value(|f| k.contains(f)))
+                                    };
+                                    let choose = move |mine: bool| {
+                                        keep_mine
+                                            .update(|k| {
+                                                if mine {
+                                                    k.insert(field.get_value());
+                                                } else {
+                                                    k.remove(&field.get_value());
+                                                }
+                                            });
+                                    };
+                                    view! {
+                                        <tr data-testid=format!("conflict-row-{}", f.field)>
+                                            <td class="font-mono">{f.field.clone()}</td>
+                                            <td>
+                                                <label class="flex items-center gap-2">
+                                                    <input
+                                                        type="radio"
+                                                        class="radio radio-sm"
+                                                        name=format!("conflict-{}", f.field)
+                                                        data-testid=format!("radio-conflict-mine-{}", f.field)
+                                                        prop:checked=is_mine
+                                                        on:change=move |_| choose(true)
+                                                    />
+                                                    <span>{f.mine.clone()}</span>
+                                                </label>
+                                            </td>
+                                            <td>
+                                                <label class="flex items-center gap-2">
+                                                    <input
+                                                        type="radio"
+                                                        class="radio radio-sm"
+                                                        name=format!("conflict-{}", f.field)
+                                                        data-testid=format!("radio-conflict-theirs-{}", f.field)
+                                                        prop:checked=move || !is_mine()
+                                                        on:change=move |_| choose(false)
+                                                    />
+                                                    <span>{f.theirs.clone()}</span>
+                                                </label>
+                                            </td>
+                                        </tr>
+                                    }
+                                }
+                            />
+                        </tbody>
+                    </table>
+                    <div class="modal-action">
+                        <button
+                            type="button"
+                            class="btn btn-ghost btn-sm"
+                            data-testid="action-btn-conflict-take-theirs"
+                            on:click=move |_| resolve(ConflictResolution::TakeTheirs)
+                        >
+                            {text(UiText::TakeTheirs)}
+                        </button>
+                        <button
+                            type="button"
+                            class="btn btn-outline btn-sm"
+                            data-testid="action-btn-conflict-merge"
+                            on:click=move |_| {
+                                let fields = keep_mine.get_untracked().into_iter().collect();
+                                resolve(ConflictResolution::Merge(fields));
+                            }
+                        >
+                            {text(UiText::MergeSelected)}
+                        </button>
+                        <button
+                            type="button"
+                            class="btn btn-primary btn-sm"
+                            data-testid="action-btn-conflict-keep-mine"
+                            on:click=move |_| resolve(ConflictResolution::KeepMine)
+                        >
+                            {text(UiText::KeepMine)}
+                        </button>
+                    </div>
+                </div>
+            </div>
+        </Show>
+    }
+}
@@ -1,6 +1,10 @@
 //! preparing enums for usage as select options
 
-use app_core::{CoreError, TournamentMode, TournamentState, TournamentType};
+use app_core::{
+    CoreError, PostalAddressSortColumn, SeedingStrategy, SportConfigSortColumn, TieBreakerPreset,
+    TournamentBaseSortColumn, TournamentMode, TournamentState, TournamentType,
+    utils::list_order::SortDirection,
+};
 use isocountry::CountryCode;
 use std::{num::ParseIntError, str::FromStr};
 
@@ -82,6 +86,7 @@ impl SelectableOption for TournamentMode {
 
     fn options(&self) -> Vec<Self> {
         match self {
+            // keep the configuration of the selected mode
             TournamentMode::SwissSystem { num_rounds } => vec![
                 TournamentMode::SingleStage,
                 TournamentMode::PoolAndFinalStage,
@@ -89,12 +94,23 @@ impl SelectableOption for TournamentMode {
                 TournamentMode::SwissSystem {
                     num_rounds: *num_rounds,
                 },
+                TournamentMode::RingSystem { num_neighbors: 0 },
+            ],
+            TournamentMode::RingSystem { num_neighbors } => vec![
+                TournamentMode::SingleStage,
+                TournamentMode::PoolAndFinalStage,
+                TournamentMode::TwoPoolStagesAndFinalStage,
+                TournamentMode::SwissSystem { num_rounds: 0 },
+                TournamentMode::RingSystem {
+                    num_neighbors: *num_neighbors,
+                },
             ],
             _ => vec![
                 TournamentMode::SingleStage,
                 TournamentMode::PoolAndFinalStage,
                 TournamentMode::TwoPoolStagesAndFinalStage,
                 TournamentMode::SwissSystem { num_rounds: 0 },
+                TournamentMode::RingSystem { num_neighbors: 0 },
             ],
         }
     }
@@ -104,6 +120,51 @@ impl SelectableOption for TournamentMode {
     }
 }
 
+impl SelectableOption for SeedingStrategy {
+    fn value(&self) -> String {
+        format!("{self:?}")
+    }
+
+    fn label(&self) -> String {
+        self.to_string()
+    }
+
+    fn options(&self) -> Vec<Self> {
+        vec![
+            SeedingStrategy::Snake,
+            SeedingStrategy::RankedBlock,
+            SeedingStrategy::CountingThrough,
+            SeedingStrategy::Random,
+        ]
+    }
+
+    fn static_options() -> Vec<Self> {
+        Self::options(&Self::default())
+    }
+}
+
+impl SelectableOption for TieBreakerPreset {
+    fn value(&self) -> String {
+        format!("{self:?}")
+    }
+
+    fn label(&self) -> String {
+        self.to_string()
+    }
+
+    fn options(&self) -> Vec<Self> {
+        vec![
+            TieBreakerPreset::Standard,
+            TieBreakerPreset::ScoreFirst,
+            TieBreakerPreset::Buchholz,
+        ]
+    }
+
+    fn static_options() -> Vec<Self> {
+        Self::options(&Self::default())
+    }
+}
+
 /// SelectableOption implementation for CountryCode from isocountry crate
 // Reason: we want to use CountryCode as select options in various places
 impl SelectableOption for CountryCode {
@@ -176,6 +237,92 @@ impl SelectableOption for FilterLimit {
     }
 }
 
+impl SelectableOption for SortDirection {
+    fn value(&self) -> String {
+        self.as_str().to_string()
+    }
+
+    fn label(&self) -> String {
+        match self {
+            SortDirection::Ascending => "Ascending".to_string(),
+            SortDirection::Descending => "Descending".to_string(),
+        }
+    }
+
+    fn options(&self) -> Vec<Self> {
+        vec![SortDirection::Ascending, SortDirection::Descending]
+    }
+
+    fn static_options() -> Vec<Self> {
+        Self::options(&Self::default())
+    }
+}
+
+impl SelectableOption for PostalAddressSortColumn {
+    fn value(&self) -> String {
+        self.as_str().to_string()
+    }
+
+    fn label(&self) -> String {
+        match self {
+            PostalAddressSortColumn::Name => "Name".to_string(),
+            PostalAddressSortColumn::PostalCode => "Postal Code".to_string(),
+            PostalAddressSortColumn::Locality => "Locality".to_string(),
+        }
+    }
+
+    fn options(&self) -> Vec<Self> {
+        PostalAddressSortColumn::ALL.into()
+    }
+
+    fn static_options() -> Vec<Self> {
+        Self::options(&Self::default())
+    }
+}
+
+impl SelectableOption for SportConfigSortColumn {
+    fn value(&self) -> String {
+        self.as_str().to_string()
+    }
+
+    fn label(&self) -> String {
+        match self {
+            SportConfigSortColumn::Name => "Name".to_string(),
+            SportConfigSortColumn::PluginVersion => "Plugin Version".to_string(),
+        }
+    }
+
+    fn options(&self) -> Vec<Self> {
+        SportConfigSortColumn::ALL.into()
+    }
+
+    fn static_options() -> Vec<Self> {
+        Self::options(&Self::default())
+    }
+}
+
+impl SelectableOption for TournamentBaseSortColumn {
+    fn value(&self) -> String {
+        self.as_str().to_string()
+    }
+
+    fn label(&self) -> String {
+        match self {
+            TournamentBaseSortColumn::Name => "Name".to_string(),
+            TournamentBaseSortColumn::CreatedAt => "Created".to_string(),
+            TournamentBaseSortColumn::NumEntrants => "Entrants".to_string(),
+        }
+    }
+
+    fn options(&self) -> Vec<Self> {
+        TournamentBaseSortColumn::ALL.into()
+    }
+
+    fn static_options() -> Vec<Self> {
+        Self::options(&Self::default())
+    }
+}
+
 /// Actions for editing entities, e.g. postal addresses, players, etc.
 #[derive(Copy, Clone, PartialEq, Eq, Debug, Default, displaydoc::Display)]
 pub enum EditAction {
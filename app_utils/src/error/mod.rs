@@ -1,8 +1,9 @@
 // app error
 
+pub mod reporter;
 pub mod strategy;
 
-use app_core::{CoreError, DbError, utils::validation::FieldError};
+use app_core::{CoreError, DbError, ServerCopy, utils::validation::FieldError};
 use leptos::prelude::*;
 use serde::{Deserialize, Serialize};
 use server_fn::codec::JsonEncoding;
@@ -34,6 +35,18 @@ pub enum AppError {
     /// connection, pool, or other DB errors
     #[error("internal error: {0}")]
     Other(String),
+
+    /// build of client does not match build of server, e.g. after a deploy
+    #[error("A new version of the app is available (client {client}, server {server}).")]
+    UpgradeRequired { client: String, server: String },
+
+    /// request was aborted, since its component started a newer request
+    #[error("request was superseded by a newer request")]
+    Superseded,
+
+    /// request was aborted, since the server did not respond in time
+    #[error("request timed out after {0} s")]
+    RequestTimeout(u64),
 }
 
 // Let Leptos server functions know how to encode this error type
@@ -53,6 +66,29 @@ impl From<serde_json::Error> for AppError {
     }
 }
 
+impl AppError {
+    /// Get the current server copy of an object, whose save failed due to a version conflict.
+    pub fn get_server_copy(&self) -> Option<&ServerCopy> {
+        if let AppError::Core(core_error) = self {
+            core_error.get_server_copy()
+        } else {
+            None
+        }
+    }
+    /// Save failed, because the object was changed by someone else since it was loaded.
+    pub fn is_version_conflict(&self) -> bool {
+        matches!(self, AppError::Core(core_error) if core_error.is_optimistic_lock_conflict())
+    }
+    /// Result was rejected, because the match was decided by someone else in the meantime.
+    pub fn is_match_decided(&self) -> bool {
+        matches!(self, AppError::Core(core_error) if core_error.is_match_decided())
+    }
+    /// Request did not reach the server, e.g. because the network is down.
+    pub fn is_network_error(&self) -> bool {
+        matches!(self, AppError::ServerFn(ServerFnErrorErr::Request(_)))
+    }
+}
+
 pub type AppResult<T> = Result<T, AppError>;
 
 pub fn map_db_unique_violation_to_field_error(
@@ -0,0 +1,160 @@
+//! reporting of uncaught client errors to the server
+//!
+//! Errors caught by error boundaries are sent to the server with the id of the component,
+//! the route and the version of the app, see [`ClientErrorReporter`]. Reports are
+//! deduplicated and rate limited by [`ReportThrottle`], so that a broken page cannot flood
+//! the server.
+
+use crate::{components::feedback::APP_VERSION, server_fn::client_error::report_client_error};
+use app_core::ClientErrorReport;
+use chrono::Utc;
+use leptos::prelude::*;
+use std::collections::{HashMap, VecDeque};
+use uuid::Uuid;
+
+/// equal errors are reported at most once in this window in milliseconds
+pub const REPORT_DEDUP_WINDOW_MS: i64 = 60_000;
+/// maximum number of reports per minute
+pub const REPORT_MAX_PER_MINUTE: usize = 10;
+
+#[derive(Debug, Default)]
+struct ThrottleEntry {
+    /// timestamp of last report in milliseconds
+    last_reported: Option<i64>,
+    /// occurrences since last report
+    pending: u32,
+}
+
+/// deduplication and rate limit of reports
+#[derive(Debug, Default)]
+pub struct ReportThrottle {
+    entries: HashMap<String, ThrottleEntry>,
+    /// timestamps of reports of the last minute
+    reported: VecDeque<i64>,
+}
+
+impl ReportThrottle {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Register an occurrence of the error with `key` at `now_ms`. Returns the number of
+    /// occurrences since the last report, if the error is to be reported now.
+    ///
+    /// Suppressed occurrences are added to the next report of the same error.
+    pub fn admit(&mut self, key: &str, now_ms: i64) -> Option<u32> {
+        // forget errors, which were reported completely and left the window
+        self.entries.retain(|_, e| {
+            e.pending > 0
+                || e.last_reported
+                    .is_some_and(|t| now_ms - t < REPORT_DEDUP_WINDOW_MS)
+        });
+        let entry = self.entries.entry(key.to_string()).or_default();
+        entry.pending += 1;
+        if entry
+            .last_reported
+            .is_some_and(|t| now_ms - t < REPORT_DEDUP_WINDOW_MS)
+        {
+            return None;
+        }
+        while self.reported.front().is_some_and(|t| now_ms - t >= 60_000) {
+            self.reported.pop_front();
+        }
+        if self.reported.len() >= REPORT_MAX_PER_MINUTE {
+            return None;
+        }
+        self.reported.push_back(now_ms);
+        entry.last_reported = Some(now_ms);
+        Some(std::mem::take(&mut entry.pending))
+    }
+}
+
+#[derive(Clone, Copy)]
+pub struct ClientErrorReporter {
+    throttle: StoredValue<ReportThrottle>,
+}
+
+impl ClientErrorReporter {
+    pub fn new() -> Self {
+        Self {
+            throttle: StoredValue::new(ReportThrottle::new()),
+        }
+    }
+
+    /// Report `message` caught by component `component_id` to the server. Only clients
+    /// report errors; errors of server side rendering are logged by the server.
+    pub fn report(&self, component_id: Option<Uuid>, message: impl Into<String>) {
+        let Some(route) = current_route() else {
+            return;
+        };
+        let message = message.into();
+        let key = format!("{route}|{message}");
+        let Some(occurrences) = self
+            .throttle
+            .try_update_value(|t| t.admit(&key, Utc::now().timestamp_millis()))
+            .flatten()
+        else {
+            return;
+        };
+        let mut report = ClientErrorReport::default();
+        report
+            .set_component_id(component_id)
+            .set_route(route)
+            .set_app_version(APP_VERSION)
+            .set_message(message)
+            .set_occurrences(occurrences);
+        leptos::task::spawn_local(async move {
+            // failed reports are dropped; reporting them would report again
+            let _ = report_client_error(report).await;
+        });
+    }
+
+    /// Report with the reporter of the current context, if one is provided.
+    pub fn report_in_context(component_id: Option<Uuid>, message: impl Into<String>) {
+        if let Some(reporter) = use_context::<ClientErrorReporter>() {
+            reporter.report(component_id, message);
+        }
+    }
+}
+
+impl Default for ClientErrorReporter {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+#[cfg(feature = "hydrate")]
+fn current_route() -> Option<String> {
+    window().location().pathname().ok()
+}
+
+#[cfg(not(feature = "hydrate"))]
+fn current_route() -> Option<String> {
+    None
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn equal_errors_are_reported_once_per_window() {
+        let mut throttle = ReportThrottle::new();
+        assert_eq!(throttle.admit("a", 0), Some(1));
+        assert_eq!(throttle.admit("a", 1_000), None);
+        assert_eq!(throttle.admit("a", 2_000), None);
+        assert_eq!(throttle.admit("b", 2_000), Some(1));
+        // suppressed occurrences are added to the next report
+        assert_eq!(throttle.admit("a", REPORT_DEDUP_WINDOW_MS), Some(3));
+    }
+
+    #[test]
+    fn reports_are_rate_limited() {
+        let mut throttle = ReportThrottle::new();
+        for n in 0..REPORT_MAX_PER_MINUTE {
+            assert_eq!(throttle.admit(&n.to_string(), 0), Some(1));
+        }
+        assert_eq!(throttle.admit("late", 1_000), None);
+        assert_eq!(throttle.admit("late", 60_000), Some(2));
+    }
+}
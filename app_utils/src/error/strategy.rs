@@ -1,5 +1,7 @@
 use crate::{
-    error::{AppError, ComponentError},
+    error::{AppError, ComponentError, reporter::ClientErrorReporter},
+    hooks::use_ui_language::get_ui_language_untracked,
+    i18n::UiText,
     state::{
         error_state::{ActiveError, ErrorKey, PageErrorContext},
         toast_state::ToastContext,
@@ -13,13 +15,24 @@ use uuid::Uuid;
 /// Since we use autosave and auto update, all save errors are reported via ToastContext (Popup)
 pub fn handle_write_error(toast_ctx: &ToastContext, error: &AppError) {
     match error {
+        // 0. Outdated client -> Banner
+        // Saving cannot succeed until the page is reloaded with the new version of the app.
+        AppError::UpgradeRequired { .. } => match use_context::<PageErrorContext>() {
+            Some(ctx) => handle_upgrade_required(&ctx),
+            None => toast_ctx.error(error.to_string(), None),
+        },
+
         // 1. Optimistic Lock Conflict -> Toast
         // The client registry and auto saving ensures, that always the latest version is loaded. If a version mismatch
         // occurs during saving, it means that parallel editing is happening. In this case, we still reload "automatically"
         // the current version. Therefore a manual reload by the user is not necessary
         // We inform the user about the parallel editing via a toast.
+        // Editors, which support conflict resolution, show a conflict dialog instead.
         // This should not happen often.
-        AppError::Core(CoreError::Db(DbError::OptimisticLockConflict)) => {
+        AppError::Core(
+            CoreError::Db(DbError::OptimisticLockConflict | DbError::VersionConflict { .. })
+            | CoreError::VersionConflict(_),
+        ) => {
             let msg = format!("{error}");
             toast_ctx.error(msg, None);
         }
@@ -27,10 +40,11 @@ pub fn handle_write_error(toast_ctx: &ToastContext, error: &AppError) {
         // 2. Unique Violation -> Toast
         // Validation error: Input needs correction (e.g. "Name already taken").
         AppError::Core(CoreError::Db(DbError::UniqueViolation(field_opt))) => {
+            let language = get_ui_language_untracked();
             let msg = field_opt
                 .as_ref()
-                .map(|f| format!("A unique value is already in use: '{f}'."))
-                .unwrap_or_else(|| "A unique value is already in use.".to_string());
+                .map(|f| UiText::UniqueFieldInUse.fill(language, "field", f))
+                .unwrap_or_else(|| UiText::UniqueValueInUse.get(language).to_string());
 
             toast_ctx.error(msg, None);
         }
@@ -39,15 +53,36 @@ pub fn handle_write_error(toast_ctx: &ToastContext, error: &AppError) {
         // Validation error: Database constraint failed (e.g. "age >= 0").
         // Treated like UniqueViolation: The user must correct the input.
         AppError::Core(CoreError::Db(DbError::CheckViolation(constraint_opt))) => {
+            let language = get_ui_language_untracked();
             let msg = constraint_opt
                 .as_ref()
-                .map(|c| format!("Data validation failed (Constraint: {}).", c))
-                .unwrap_or_else(|| "Data validation failed.".to_string());
+                .map(|c| UiText::ConstraintFailed.fill(language, "constraint", c))
+                .unwrap_or_else(|| UiText::DataValidationFailed.get(language).to_string());
 
             toast_ctx.error(msg, None);
         }
 
-        // 4. Everything else -> TOAST
+        // 4. Timeout -> Toast
+        // Transient: the database was too busy or the statement took too long.
+        // Autosave retries on the next change, so we only suggest to retry.
+        AppError::Core(CoreError::Db(DbError::Timeout)) => {
+            toast_ctx.warning(
+                UiText::DatabaseTimeout.get(get_ui_language_untracked()),
+                None,
+            );
+        }
+
+        // 5. Aborted requests
+        // Superseded requests are replaced by the newer request of the component.
+        AppError::Superseded => {}
+        AppError::RequestTimeout(_) => {
+            toast_ctx.warning(
+                UiText::RequestTimeout.get(get_ui_language_untracked()),
+                None,
+            );
+        }
+
+        // 6. Everything else -> TOAST
         _ => {
             // "Fire & Forget" Toast
             // AppError implements Display via thiserror, so error.to_string() works fine.
@@ -65,44 +100,81 @@ pub fn handle_read_error(
     // This must navigate "back" to a safe place (e.g. Dashboard)
     back_fn: Callback<()>,
 ) {
+    // outdated clients are not broken; they need to reload the new version of the app
+    if let AppError::UpgradeRequired { .. } = error.app_error {
+        handle_upgrade_required(ctx);
+        return;
+    }
+    // superseded requests are replaced by the newer request of the component
+    if let AppError::Superseded = error.app_error {
+        return;
+    }
     let key = ErrorKey::Read;
+    let language = get_ui_language_untracked();
     let retry_fn = ctx.get_retry_handler(error.component_id);
+    ClientErrorReporter::report_in_context(Some(error.component_id), error.app_error.to_string());
 
     match &error.app_error {
         // Case 1: Specific Entity not found
         AppError::ResourceNotFound(entity, _) => {
-            let msg = format!("'{entity}' could not be found.");
+            let msg = UiText::EntityNotFound.fill(language, "entity", entity);
 
             let mut builder = ActiveError::builder(error.component_id, key.clone(), msg);
             if let Some(retry_fn) = retry_fn {
-                builder = builder.with_retry("Retry", retry_fn);
+                builder = builder.with_retry(UiText::Retry.get(language), retry_fn);
             }
-            let builder = builder.with_cancel("Back", back_fn);
+            let builder = builder.with_cancel(UiText::Back.get(language), back_fn);
 
             ctx.report_error(builder.build());
         }
 
         // Case 2: Generic Database Not Found
         AppError::Core(CoreError::Db(DbError::NotFound)) => {
-            let msg = "The requested data could not be found in database.".to_string();
+            let msg = UiText::NotFoundInDatabase.get(language).to_string();
+
+            let mut builder = ActiveError::builder(error.component_id, key.clone(), msg);
+            if let Some(retry_fn) = retry_fn {
+                builder = builder.with_retry(UiText::Retry.get(language), retry_fn);
+            }
+            let builder = builder.with_cancel(UiText::Back.get(language), back_fn);
+
+            ctx.report_error(builder.build());
+        }
+
+        // Case 3: Timeout: transient, suggest retry
+        AppError::Core(CoreError::Db(DbError::Timeout)) => {
+            let msg = UiText::LoadingTimeout.get(language).to_string();
 
             let mut builder = ActiveError::builder(error.component_id, key.clone(), msg);
             if let Some(retry_fn) = retry_fn {
-                builder = builder.with_retry("Retry", retry_fn);
+                builder = builder.with_retry(UiText::Retry.get(language), retry_fn);
             }
-            let builder = builder.with_cancel("Back", back_fn);
+            let builder = builder.with_cancel(UiText::Back.get(language), back_fn);
 
             ctx.report_error(builder.build());
         }
 
-        // Case 3: All other errors (treat as fatal/blocker for loading)
+        // Case 4: Request timeout: transient, suggest retry
+        AppError::RequestTimeout(_) => {
+            let msg = UiText::RequestTimeout.get(language).to_string();
+
+            let mut builder = ActiveError::builder(error.component_id, key.clone(), msg);
+            if let Some(retry_fn) = retry_fn {
+                builder = builder.with_retry(UiText::Retry.get(language), retry_fn);
+            }
+            let builder = builder.with_cancel(UiText::Back.get(language), back_fn);
+
+            ctx.report_error(builder.build());
+        }
+
+        // Case 5: All other errors (treat as fatal/blocker for loading)
         err => {
             let mut builder =
                 ActiveError::builder(error.component_id, key.clone(), err.to_string());
             if let Some(retry_fn) = retry_fn {
-                builder = builder.with_retry("Retry", retry_fn);
+                builder = builder.with_retry(UiText::Retry.get(language), retry_fn);
             }
-            let builder = builder.with_cancel("Back", back_fn);
+            let builder = builder.with_cancel(UiText::Back.get(language), back_fn);
             ctx.report_error(builder.build());
         }
     }
@@ -116,15 +188,38 @@ pub fn handle_unexpected_ui_error(
     back_fn: Callback<()>,
 ) {
     let key = ErrorKey::General;
+    let language = get_ui_language_untracked();
 
     let error_msg = error_msg.into();
     let retry_fn = ctx.get_retry_handler(component_id);
+    ClientErrorReporter::report_in_context(Some(component_id), &error_msg);
 
-    let mut builder =
-        ActiveError::builder(component_id, key.clone(), error_msg).with_cancel("Back", back_fn);
+    let mut builder = ActiveError::builder(component_id, key.clone(), error_msg)
+        .with_cancel(UiText::Back.get(language), back_fn);
     if let Some(retry_fn) = retry_fn {
-        builder = builder.with_retry("Retry", retry_fn);
+        builder = builder.with_retry(UiText::Retry.get(language), retry_fn);
     }
 
     ctx.report_error(builder.build());
 }
+
+/// Reports, that a new version of the app is available. The error is reported once for the
+/// whole app; its retry action reloads the page.
+pub fn handle_upgrade_required(ctx: &PageErrorContext) {
+    let reload = Callback::new(|()| {
+        #[cfg(feature = "hydrate")]
+        {
+            let _ = window().location().reload();
+        }
+    });
+    let language = get_ui_language_untracked();
+    let error = ActiveError::builder(
+        Uuid::nil(),
+        ErrorKey::Custom("upgrade_required".to_string()),
+        UiText::NewVersionAvailable.get(language),
+    )
+    .with_retry(UiText::Reload.get(language), reload)
+    .with_clear_error_on_cancel(UiText::Later.get(language))
+    .build();
+    ctx.report_error(error);
+}
@@ -2,6 +2,10 @@
 
 pub mod blur_active_element;
 pub mod is_field_valid;
+pub mod use_list_order;
 pub mod use_on_cancel;
+pub mod use_presence;
 pub mod use_scroll_into_view;
+pub mod use_service_worker;
+pub mod use_ui_language;
 pub mod use_url_navigation;
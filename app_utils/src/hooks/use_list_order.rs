@@ -0,0 +1,29 @@
+//! Provides a hook for sort column, direction and offset of lists from query parameters.
+
+use crate::params::{FilterOffsetQuery, ParamQuery, SortByQuery, SortDirectionQuery};
+use app_core::utils::list_order::{ListOrder, Sortable};
+use leptos::prelude::*;
+
+/// Get the order of a list of `T` from the query parameters. Missing or unknown sort columns
+/// fall back to the default column of `T`.
+pub fn use_list_order<T>() -> Memo<ListOrder<T>>
+where
+    T: Sortable + Send + Sync + 'static,
+    T::SortColumn: Send + Sync + 'static,
+{
+    let sort_by = SortByQuery::use_param_query();
+    let direction = SortDirectionQuery::use_param_query();
+    let offset = FilterOffsetQuery::use_param_query();
+
+    Memo::new(move |_| {
+        ListOrder::new()
+            .sort_by(
+                sort_by
+                    .get()
+                    .and_then(|column| column.parse::<T::SortColumn>().ok())
+                    .unwrap_or_default(),
+                direction.get().unwrap_or_default(),
+            )
+            .offset(offset.get().unwrap_or_default())
+    })
+}
@@ -0,0 +1,111 @@
+//! presence of editors, who have the same object open for editing
+//!
+//! Every browser tab joins the presence of the object it edits with a random client id and
+//! the name, which the user chose in the header menu. The presence is refreshed every
+//! [`PRESENCE_HEARTBEAT`] and left, when the editor is closed or switches the object.
+//! Changes of the editors are received via the presence topic of the object.
+
+use crate::server_fn::presence::{join_presence, leave_presence};
+use app_core::{CrTopic, PresenceEditor};
+use cr_leptos_axum_socket::{PRESENCE_HEARTBEAT, use_client_registry_socket};
+use leptos::prelude::*;
+use std::sync::LazyLock;
+use uuid::Uuid;
+
+/// key of the name shown to other editors in local storage
+pub const STORAGE_KEY_PRESENCE_NAME: &str = "presence_name";
+
+/// random id of this client; every browser tab runs its own instance of the app
+static CLIENT_ID: LazyLock<Uuid> = LazyLock::new(Uuid::new_v4);
+
+/// Get the name shown to other editors from local storage; empty, if none was chosen.
+pub fn get_presence_name() -> String {
+    #[cfg(feature = "hydrate")]
+    if let Ok(Some(storage)) = window().local_storage()
+        && let Ok(Some(name)) = storage.get_item(STORAGE_KEY_PRESENCE_NAME)
+    {
+        return name;
+    }
+    String::new()
+}
+
+/// Store the name shown to other editors in local storage. Editors show the new name with
+/// the next heartbeat.
+pub fn set_presence_name(name: &str) {
+    let name = PresenceEditor::new(*CLIENT_ID, name).name;
+    #[cfg(feature = "hydrate")]
+    if let Ok(Some(storage)) = window().local_storage() {
+        let _ = if name.is_empty() {
+            storage.remove_item(STORAGE_KEY_PRESENCE_NAME)
+        } else {
+            storage.set_item(STORAGE_KEY_PRESENCE_NAME, &name)
+        };
+    }
+    #[cfg(not(feature = "hydrate"))]
+    let _ = name;
+}
+
+/// Join the presence of `object_id`, while it is `Some`. Returns the other editors of the
+/// object, i.e. without this client.
+pub fn use_presence(object_id: Signal<Option<Uuid>>) -> Signal<Vec<PresenceEditor>> {
+    let client_id = *CLIENT_ID;
+    let editors = RwSignal::new(Vec::<PresenceEditor>::new());
+
+    let join = move |id: Uuid| {
+        let editor = PresenceEditor::new(client_id, &get_presence_name());
+        leptos::task::spawn_local(async move {
+            match join_presence(id, editor).await {
+                // results of objects, which are not edited anymore, are obsolete
+                Ok(list) if object_id.try_get_untracked().flatten() == Some(id) => {
+                    editors.try_set(list);
+                }
+                Ok(_) => {}
+                Err(err) => tracing::warn!(error = %err, "presence_join_failed"),
+            }
+        });
+    };
+
+    // effects run only in the browser; cleanup runs, if the object changes or the editor
+    // is closed
+    Effect::new(move || {
+        editors.set(Vec::new());
+        let Some(id) = object_id.get() else {
+            return;
+        };
+        join(id);
+        let heartbeat = set_interval_with_handle(move || join(id), PRESENCE_HEARTBEAT).ok();
+        on_cleanup(move || {
+            if let Some(heartbeat) = heartbeat {
+                heartbeat.clear();
+            }
+            leptos::task::spawn_local(async move {
+                if let Err(err) = leave_presence(id, client_id).await {
+                    tracing::warn!(error = %err, "presence_leave_failed");
+                }
+            });
+        });
+    });
+
+    let topic = Signal::derive(move || {
+        object_id
+            .get()
+            .map(|object_id| CrTopic::Presence { object_id })
+    });
+    let refetch = Callback::new(move |()| {
+        if let Some(id) = object_id.get_untracked() {
+            join(id);
+        }
+    });
+    // the version of presence messages is the number of editors, which may decrease
+    use_client_registry_socket(topic, None.into(), refetch);
+
+    Signal::derive(move || {
+        editors.with(|editors| {
+            editors
+                .iter()
+                .filter(|editor| editor.client_id != client_id)
+                .cloned()
+                .collect()
+        })
+    })
+}
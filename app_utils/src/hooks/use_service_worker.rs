@@ -0,0 +1,38 @@
+//! registration of the service worker, which makes the app installable as PWA
+//!
+//! The service worker `/sw.js` is served from the assets dir `public`. It caches the app
+//! shell, so that score entry and entrant schedules open courtside on flaky networks. Edits
+//! made offline are queued by [`OfflineSync`](crate::offline::OfflineSync), not by the
+//! service worker.
+
+use leptos::prelude::*;
+
+/// path of the service worker script, its scope is the whole app
+pub const SERVICE_WORKER_URL: &str = "/sw.js";
+
+/// Register the service worker after hydration. Browsers without service worker support
+/// run the app as before.
+pub fn use_register_service_worker() {
+    Effect::new(move || {
+        #[cfg(feature = "hydrate")]
+        register();
+    });
+}
+
+#[cfg(feature = "hydrate")]
+fn register() {
+    use leptos::wasm_bindgen::JsValue;
+    use wasm_bindgen_futures::JsFuture;
+
+    let navigator = window().navigator();
+    // service workers require a secure context and are missing in some browsers
+    if !js_sys::Reflect::has(&navigator, &JsValue::from_str("serviceWorker")).unwrap_or(false) {
+        return;
+    }
+    let promise = navigator.service_worker().register(SERVICE_WORKER_URL);
+    leptos::task::spawn_local(async move {
+        if let Err(err) = JsFuture::from(promise).await {
+            tracing::warn!(error = ?err, "service_worker_registration_failed");
+        }
+    });
+}
@@ -0,0 +1,82 @@
+//! display language of the UI, e.g. of navigation, forms and validation messages
+//!
+//! The language selected by the user is kept in [`GlobalState`] and stored in the local
+//! storage of the browser. The `lang` query overrides it, e.g. for shared links.
+
+use crate::{
+    i18n::UI_LANGUAGES,
+    params::{LanguageQuery, ParamQuery},
+    state::global_state::{GlobalState, GlobalStateStoreFields},
+};
+use app_core::Language;
+use leptos::prelude::*;
+use reactive_stores::Store;
+
+/// key of the selected language in local storage
+pub const STORAGE_KEY_LANGUAGE: &str = "ui_language";
+
+/// Get the display language of the UI: the `lang` query, if given, otherwise the language
+/// of the global state.
+pub fn use_ui_language() -> Signal<Language> {
+    let lang_query = LanguageQuery::use_param_query();
+    let state = use_context::<Store<GlobalState>>();
+    Signal::derive(move || {
+        lang_query
+            .get()
+            .or_else(|| state.map(|s| s.language().get()))
+            .unwrap_or_default()
+    })
+}
+
+/// Get the display language of the global state without tracking, e.g. in event handlers
+/// and error strategies.
+pub fn get_ui_language_untracked() -> Language {
+    use_context::<Store<GlobalState>>()
+        .map(|s| s.language().get_untracked())
+        .unwrap_or_default()
+}
+
+/// Select the display language of the UI and store it in local storage.
+pub fn set_ui_language(language: Language) {
+    if let Some(state) = use_context::<Store<GlobalState>>() {
+        state.language().set(language);
+    }
+    #[cfg(feature = "hydrate")]
+    if let Ok(Some(storage)) = window().local_storage() {
+        let _ = storage.set_item(STORAGE_KEY_LANGUAGE, language.code());
+    }
+}
+
+/// Load the display language into the global state after hydration: the stored selection of
+/// the user, otherwise the preferred language of the browser, if the UI is translated into
+/// it. Server and first client render therefore agree on English.
+pub fn use_init_ui_language() {
+    let state = expect_context::<Store<GlobalState>>();
+    Effect::new(move || {
+        if let Some(language) = stored_or_browser_language()
+            && UI_LANGUAGES.contains(&language)
+        {
+            state.language().set(language);
+        }
+    });
+}
+
+#[cfg(feature = "hydrate")]
+fn stored_or_browser_language() -> Option<Language> {
+    let stored = window()
+        .local_storage()
+        .ok()
+        .flatten()
+        .and_then(|storage| storage.get_item(STORAGE_KEY_LANGUAGE).ok().flatten());
+    stored
+        .or_else(|| window().navigator().language())?
+        .split('-')
+        .next()?
+        .parse()
+        .ok()
+}
+
+#[cfg(not(feature = "hydrate"))]
+fn stored_or_browser_language() -> Option<Language> {
+    None
+}
@@ -0,0 +1,240 @@
+//! texts of the app UI in all display languages
+//!
+//! The UI starts with English and German; French and Spanish fall back to English. Public
+//! pages keep their own texts, which are translated into all languages (see `PublicText` of
+//! the app). Validation messages are rendered from their error code by
+//! [`FieldError::render`](app_core::utils::validation::FieldError::render).
+//!
+//! Texts with placeholders, e.g. `{label}`, are filled in with [`UiText::fill`]. Navigation,
+//! shared form components and error handling use these texts; pages move their fixed texts
+//! here step by step.
+
+use app_core::Language;
+
+/// languages, into which the UI is translated
+pub const UI_LANGUAGES: [Language; 2] = [Language::En, Language::De];
+
+/// fixed text of the app UI
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum UiText {
+    // --- navigation ---
+    AppTitle,
+    PostalAddresses,
+    Venues,
+    ApiTokens,
+    Webhooks,
+    ClientErrors,
+    AuditLog,
+    SportSelection,
+    SearchPlaceholder,
+    SearchNoHits,
+    SearchTournament,
+    SearchEntrant,
+    SearchSportConfig,
+    SearchPostalAddress,
+    LanguageLabel,
+    ThemeLabel,
+    ThemeSystem,
+    ThemeLight,
+    ThemeDark,
+    AllRightsReserved,
+    // --- forms ---
+    Optional,
+    EnterValue,
+    InvalidInputFormat,
+    ConflictTitle,
+    ConflictHint,
+    ConflictField,
+    ConflictMine,
+    ConflictTheirs,
+    TakeTheirs,
+    MergeSelected,
+    KeepMine,
+    // --- bulk operations ---
+    SelectAll,
+    SelectRow,
+    BulkConfirmHint,
+    Confirm,
+    Cancel,
+    // --- toasts and error banners ---
+    UniqueValueInUse,
+    UniqueFieldInUse,
+    DataValidationFailed,
+    ConstraintFailed,
+    DatabaseTimeout,
+    EntityNotFound,
+    NotFoundInDatabase,
+    LoadingTimeout,
+    RequestTimeout,
+    StillWorking,
+    NewVersionAvailable,
+    Retry,
+    Back,
+    Reload,
+    Later,
+    // --- offline edits ---
+    Offline,
+    PendingEdits,
+    OfflineConflicts,
+    AlsoEditing,
+    AnonymousEditor,
+    PresenceNameLabel,
+}
+
+impl UiText {
+    /// Get the text in `language`.
+    pub fn get(self, language: Language) -> &'static str {
+        use Language::*;
+        use UiText::*;
+        match (self, language) {
+            (_, Fr | Es) => self.get(En),
+            (AppTitle, En) => "Tournament Planner",
+            (AppTitle, De) => "Turnierplaner",
+            (PostalAddresses, En) => "Postal Addresses",
+            (PostalAddresses, De) => "Postanschriften",
+            (Venues, En) => "Venues",
+            (Venues, De) => "Spielstätten",
+            (ApiTokens, En) => "API Tokens",
+            (ApiTokens, De) => "API-Tokens",
+            (Webhooks, En) => "Webhooks",
+            (Webhooks, De) => "Webhooks",
+            (ClientErrors, En) => "Client Errors",
+            (ClientErrors, De) => "Client-Fehler",
+            (AuditLog, En) => "Audit Log",
+            (AuditLog, De) => "Änderungsprotokoll",
+            (SportSelection, En) => "Sport Selection",
+            (SportSelection, De) => "Sportauswahl",
+            (SearchPlaceholder, En) => "Search...",
+            (SearchPlaceholder, De) => "Suchen...",
+            (SearchNoHits, En) => "No results",
+            (SearchNoHits, De) => "Keine Treffer",
+            (SearchTournament, En) => "Tournament",
+            (SearchTournament, De) => "Turnier",
+            (SearchEntrant, En) => "Entrant",
+            (SearchEntrant, De) => "Teilnehmer",
+            (SearchSportConfig, En) => "Sport Configuration",
+            (SearchSportConfig, De) => "Sportkonfiguration",
+            (SearchPostalAddress, En) => "Postal Address",
+            (SearchPostalAddress, De) => "Postanschrift",
+            (LanguageLabel, En) => "Language",
+            (LanguageLabel, De) => "Sprache",
+            (ThemeLabel, En) => "Theme",
+            (ThemeLabel, De) => "Design",
+            (ThemeSystem, En) => "System",
+            (ThemeSystem, De) => "System",
+            (ThemeLight, En) => "Light",
+            (ThemeLight, De) => "Hell",
+            (ThemeDark, En) => "Dark",
+            (ThemeDark, De) => "Dunkel",
+            (AllRightsReserved, En) => "All rights reserved",
+            (AllRightsReserved, De) => "Alle Rechte vorbehalten",
+            (Optional, En) => "optional",
+            (Optional, De) => "optional",
+            (EnterValue, En) => "Enter {label}...",
+            (EnterValue, De) => "{label} eingeben...",
+            (InvalidInputFormat, En) => "Invalid input format, parse failed.",
+            (InvalidInputFormat, De) => "Ungültiges Eingabeformat.",
+            (ConflictTitle, En) => "Someone else changed this in the meantime",
+            (ConflictTitle, De) => "Jemand anderes hat dies zwischenzeitlich geändert",
+            (ConflictHint, En) => {
+                "Choose which values to keep. Fields without a difference are taken from the saved version."
+            }
+            (ConflictHint, De) => {
+                "Wähle, welche Werte erhalten bleiben. Felder ohne Unterschied werden aus der gespeicherten Version übernommen."
+            }
+            (ConflictField, En) => "Field",
+            (ConflictField, De) => "Feld",
+            (ConflictMine, En) => "Mine",
+            (ConflictMine, De) => "Meine",
+            (ConflictTheirs, En) => "Theirs",
+            (ConflictTheirs, De) => "Gespeichert",
+            (TakeTheirs, En) => "Take theirs",
+            (TakeTheirs, De) => "Gespeicherte übernehmen",
+            (MergeSelected, En) => "Merge selected",
+            (MergeSelected, De) => "Auswahl zusammenführen",
+            (KeepMine, En) => "Keep mine",
+            (KeepMine, De) => "Meine behalten",
+            (SelectAll, En) => "Select all",
+            (SelectAll, De) => "Alle auswählen",
+            (SelectRow, En) => "Select row",
+            (SelectRow, De) => "Zeile auswählen",
+            (BulkConfirmHint, En) => {
+                "The action applies to {count} selected items at once. If it fails for one of them, none of them is changed."
+            }
+            (BulkConfirmHint, De) => {
+                "Die Aktion gilt für alle {count} ausgewählten Einträge. Schlägt sie für einen davon fehl, wird keiner geändert."
+            }
+            (Confirm, En) => "Confirm",
+            (Confirm, De) => "Bestätigen",
+            (Cancel, En) => "Cancel",
+            (Cancel, De) => "Abbrechen",
+            (UniqueValueInUse, En) => "A unique value is already in use.",
+            (UniqueValueInUse, De) => "Ein eindeutiger Wert wird bereits verwendet.",
+            (UniqueFieldInUse, En) => "A unique value is already in use: '{field}'.",
+            (UniqueFieldInUse, De) => "Ein eindeutiger Wert wird bereits verwendet: '{field}'.",
+            (DataValidationFailed, En) => "Data validation failed.",
+            (DataValidationFailed, De) => "Datenprüfung fehlgeschlagen.",
+            (ConstraintFailed, En) => "Data validation failed (Constraint: {constraint}).",
+            (ConstraintFailed, De) => "Datenprüfung fehlgeschlagen (Bedingung: {constraint}).",
+            (DatabaseTimeout, En) => {
+                "The database did not respond in time. Please retry in a moment."
+            }
+            (DatabaseTimeout, De) => {
+                "Die Datenbank hat nicht rechtzeitig geantwortet. Bitte gleich erneut versuchen."
+            }
+            (EntityNotFound, En) => "'{entity}' could not be found.",
+            (EntityNotFound, De) => "'{entity}' wurde nicht gefunden.",
+            (NotFoundInDatabase, En) => "The requested data could not be found in database.",
+            (NotFoundInDatabase, De) => {
+                "Die angeforderten Daten wurden in der Datenbank nicht gefunden."
+            }
+            (LoadingTimeout, En) => {
+                "Loading took too long. The database may be busy, please retry."
+            }
+            (LoadingTimeout, De) => {
+                "Das Laden hat zu lange gedauert. Die Datenbank ist evtl. ausgelastet, bitte erneut versuchen."
+            }
+            (RequestTimeout, En) => "The server did not respond in time. Please retry.",
+            (RequestTimeout, De) => {
+                "Der Server hat nicht rechtzeitig geantwortet. Bitte erneut versuchen."
+            }
+            (StillWorking, En) => "Still working…",
+            (StillWorking, De) => "Wird noch bearbeitet…",
+            (NewVersionAvailable, En) => {
+                "A new version of the app is available. Please reload the page."
+            }
+            (NewVersionAvailable, De) => {
+                "Eine neue Version der App ist verfügbar. Bitte die Seite neu laden."
+            }
+            (Retry, En) => "Retry",
+            (Retry, De) => "Erneut versuchen",
+            (Back, En) => "Back",
+            (Back, De) => "Zurück",
+            (Reload, En) => "Reload",
+            (Reload, De) => "Neu laden",
+            (Later, En) => "Later",
+            (Later, De) => "Später",
+            (Offline, En) => "Offline",
+            (Offline, De) => "Offline",
+            (PendingEdits, En) => "{count} changes waiting for connection",
+            (PendingEdits, De) => "{count} Änderungen warten auf Verbindung",
+            (OfflineConflicts, En) => {
+                "{count} changes were rejected, because others changed the data meanwhile."
+            }
+            (OfflineConflicts, De) => {
+                "{count} Änderungen wurden abgelehnt, da andere die Daten zwischenzeitlich geändert haben."
+            }
+            (AlsoEditing, En) => "Also being edited by: {names}",
+            (AlsoEditing, De) => "Wird ebenfalls bearbeitet von: {names}",
+            (AnonymousEditor, En) => "anonymous",
+            (AnonymousEditor, De) => "anonym",
+            (PresenceNameLabel, En) => "Name shown to other editors",
+            (PresenceNameLabel, De) => "Name für andere Bearbeitende",
+        }
+    }
+
+    /// Get the text in `language` and replace its placeholder `{key}` with `value`.
+    pub fn fill(self, language: Language, key: &str, value: &str) -> String {
+        self.get(language).replace(&format!("{{{key}}}"), value)
+    }
+}
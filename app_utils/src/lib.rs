@@ -4,6 +4,9 @@ pub mod components;
 pub mod enum_utils;
 pub mod error;
 pub mod hooks;
+pub mod i18n;
+pub mod offline;
 pub mod params;
 pub mod server_fn;
 pub mod state;
+pub mod version;
@@ -0,0 +1,189 @@
+//! offline first handling of edits
+//!
+//! Edits submitted via [`OfflineSync`] are sent to the server right away, if the client is
+//! online. While the network is down, they are queued in the IndexedDB of the browser and
+//! replayed through the server functions in order, when connectivity returns. Queued edits
+//! keep the version of the object they are based on, therefore changes of others in the
+//! meantime are detected as version conflicts on replay and kept for review. Results of
+//! matches, which were decided by others in the meantime, are kept for review as well.
+
+mod queue;
+mod store;
+
+pub use queue::*;
+
+use crate::{
+    error::{AppError, AppResult, strategy::handle_write_error},
+    state::toast_state::ToastContext,
+};
+use chrono::Utc;
+use leptos::prelude::*;
+use uuid::Uuid;
+
+/// edit, which was rejected on replay, because the object was changed by someone else, e.g.
+/// a result of a match, which was decided in the meantime
+#[derive(Clone, Debug)]
+pub struct OfflineConflict {
+    pub edit: PendingEdit,
+    /// error of the server, holds the server copy of the object, if available
+    pub error: AppError,
+}
+
+#[derive(Clone, Copy)]
+pub struct OfflineSync {
+    queue: RwSignal<EditQueue>,
+    online: RwSignal<bool>,
+    conflicts: RwSignal<Vec<OfflineConflict>>,
+    replaying: StoredValue<bool>,
+    toast_ctx: Option<ToastContext>,
+}
+
+impl OfflineSync {
+    /// Create offline sync, load edits stored by an earlier session and replay them.
+    /// Provide toast context before, to report failed replays.
+    pub fn new() -> Self {
+        let sync = Self {
+            queue: RwSignal::new(EditQueue::new()),
+            online: RwSignal::new(is_navigator_online()),
+            conflicts: RwSignal::new(Vec::new()),
+            replaying: StoredValue::new(false),
+            toast_ctx: use_context::<ToastContext>(),
+        };
+        sync.start();
+        sync
+    }
+
+    /// Send `edit` to the server. Returns the saved object, or None, if the edit was queued
+    /// because the client is offline. Edits are queued as well, while older edits wait for
+    /// replay, to keep their order.
+    pub async fn submit(&self, edit: PendingEdit) -> AppResult<Option<SavedEdit>> {
+        if self.online.get_untracked() && self.queue.with_untracked(EditQueue::is_empty) {
+            match edit.clone().send().await {
+                Err(e) if e.is_network_error() => self.online.set(false),
+                res => return res.map(Some),
+            }
+        }
+        self.queue.update(|queue| {
+            queue.push(edit, Utc::now());
+        });
+        self.persist();
+        Ok(None)
+    }
+
+    /// Replay queued edits in order. Replay stops at the first network error and continues,
+    /// when connectivity returns.
+    pub fn replay(&self) {
+        if self.replaying.get_value() {
+            return;
+        }
+        self.replaying.set_value(true);
+        let sync = *self;
+        leptos::task::spawn_local(async move {
+            while let Some(queued) = sync.queue.with_untracked(|q| q.front().cloned()) {
+                match queued.edit.clone().send().await {
+                    Ok(_) => {}
+                    Err(e) if e.is_network_error() => {
+                        sync.online.set(false);
+                        break;
+                    }
+                    Err(error) => {
+                        if let Some(toast_ctx) = sync.toast_ctx {
+                            handle_write_error(&toast_ctx, &error);
+                        }
+                        if error.is_version_conflict() || error.is_match_decided() {
+                            sync.conflicts.update(|c| {
+                                c.push(OfflineConflict {
+                                    edit: queued.edit.clone(),
+                                    error,
+                                })
+                            });
+                        }
+                    }
+                }
+                sync.queue.update(|q| {
+                    q.remove(queued.seq);
+                });
+                sync.persist();
+            }
+            sync.replaying.set_value(false);
+        });
+    }
+
+    /// client is online, as far as known by the browser and the last request
+    pub fn is_online(&self) -> Signal<bool> {
+        self.online.into()
+    }
+
+    /// number of edits waiting for replay
+    pub fn pending_count(&self) -> Signal<usize> {
+        let queue = self.queue;
+        Signal::derive(move || queue.with(EditQueue::len))
+    }
+
+    /// edits rejected on replay due to version conflicts or decided matches
+    pub fn conflicts(&self) -> Signal<Vec<OfflineConflict>> {
+        self.conflicts.into()
+    }
+
+    /// Remove conflict of object `object_id` after it was reviewed.
+    pub fn dismiss_conflict(&self, object_id: Uuid) {
+        self.conflicts
+            .update(|c| c.retain(|conflict| conflict.edit.object_id() != object_id));
+    }
+
+    fn persist(&self) {
+        let snapshot = self.queue.get_untracked();
+        leptos::task::spawn_local(store::save_queue(snapshot));
+    }
+
+    /// load stored edits and replay them, when connectivity returns
+    #[cfg(feature = "hydrate")]
+    fn start(&self) {
+        let sync = *self;
+        leptos::task::spawn_local(async move {
+            if let Some(stored) = store::load_queue().await
+                && !stored.is_empty()
+            {
+                // edits submitted meanwhile are newer than stored ones
+                sync.queue.update(|queue| {
+                    let submitted = std::mem::replace(queue, stored);
+                    for queued in submitted.iter() {
+                        queue.push(queued.edit.clone(), queued.queued_at);
+                    }
+                });
+                sync.replay();
+            }
+        });
+        let online = window_event_listener(leptos::ev::online, move |_| {
+            sync.online.set(true);
+            sync.replay();
+        });
+        let offline = window_event_listener(leptos::ev::offline, move |_| {
+            sync.online.set(false);
+        });
+        on_cleanup(move || {
+            online.remove();
+            offline.remove();
+        });
+    }
+
+    /// Only clients queue edits.
+    #[cfg(not(feature = "hydrate"))]
+    fn start(&self) {}
+}
+
+impl Default for OfflineSync {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+#[cfg(feature = "hydrate")]
+fn is_navigator_online() -> bool {
+    window().navigator().on_line()
+}
+
+#[cfg(not(feature = "hydrate"))]
+fn is_navigator_online() -> bool {
+    true
+}
@@ -0,0 +1,200 @@
+//! queue of edits, which wait for connectivity
+
+use crate::{
+    error::AppResult,
+    server_fn::{
+        entrant::set_entrant_check_in, match_note::save_match_note,
+        scorekeeper::enter_scorekeeper_result, shift_log::save_shift_log_entry,
+    },
+};
+use app_core::{Entrant, Match, MatchNote, ShiftLogEntry, utils::traits::ObjectIdVersion};
+use chrono::{DateTime, Utc};
+use serde::{Deserialize, Serialize};
+use std::collections::VecDeque;
+use uuid::Uuid;
+
+/// edit, which is sent to the server through its server function
+#[derive(Clone, Debug, PartialEq, Eq, Serialize, Deserialize)]
+pub enum PendingEdit {
+    CheckIn {
+        tournament_id: Uuid,
+        entrant_id: Uuid,
+        checked_in: bool,
+    },
+    MatchNote(MatchNote),
+    ShiftLogEntry(ShiftLogEntry),
+    /// final result entered by a scorekeeper with the secret of its access link
+    ScoreEntry {
+        secret: String,
+        match_id: Uuid,
+        score_a: Vec<u16>,
+        score_b: Vec<u16>,
+    },
+}
+
+/// object returned by the server for a sent edit
+#[derive(Clone, Debug, PartialEq)]
+pub enum SavedEdit {
+    CheckIn(Entrant),
+    MatchNote(MatchNote),
+    ShiftLogEntry(ShiftLogEntry),
+    ScoreEntry(Match),
+}
+
+impl PendingEdit {
+    /// id of the edited object
+    pub fn object_id(&self) -> Uuid {
+        match self {
+            PendingEdit::CheckIn { entrant_id, .. } => *entrant_id,
+            PendingEdit::MatchNote(note) => note.get_id(),
+            PendingEdit::ShiftLogEntry(entry) => entry.get_id(),
+            PendingEdit::ScoreEntry { match_id, .. } => *match_id,
+        }
+    }
+
+    /// Send edit to the server. Objects keep the version they were loaded with, therefore
+    /// the server detects changes of others in the meantime as version conflict.
+    pub async fn send(self) -> AppResult<SavedEdit> {
+        match self {
+            PendingEdit::CheckIn {
+                tournament_id,
+                entrant_id,
+                checked_in,
+            } => set_entrant_check_in(tournament_id, entrant_id, checked_in)
+                .await
+                .map(SavedEdit::CheckIn),
+            PendingEdit::MatchNote(note) => save_match_note(note).await.map(SavedEdit::MatchNote),
+            PendingEdit::ShiftLogEntry(entry) => save_shift_log_entry(entry)
+                .await
+                .map(SavedEdit::ShiftLogEntry),
+            PendingEdit::ScoreEntry {
+                secret,
+                match_id,
+                score_a,
+                score_b,
+            } => enter_scorekeeper_result(secret, match_id, score_a, score_b)
+                .await
+                .map(SavedEdit::ScoreEntry),
+        }
+    }
+}
+
+#[derive(Clone, Debug, PartialEq, Eq, Serialize, Deserialize)]
+pub struct QueuedEdit {
+    /// sequence number of the edit in its queue
+    pub seq: u64,
+    pub queued_at: DateTime<Utc>,
+    pub edit: PendingEdit,
+}
+
+/// edits in order of their first edit
+#[derive(Clone, Debug, Default, PartialEq, Eq, Serialize, Deserialize)]
+pub struct EditQueue {
+    next_seq: u64,
+    edits: VecDeque<QueuedEdit>,
+}
+
+impl EditQueue {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Queue `edit` and return its sequence number. A queued edit of the same object is
+    /// replaced in place, since the later edit contains all changes of the earlier one and
+    /// is based on the same version.
+    pub fn push(&mut self, edit: PendingEdit, queued_at: DateTime<Utc>) -> u64 {
+        let seq = self.next_seq;
+        self.next_seq += 1;
+        let queued = QueuedEdit {
+            seq,
+            queued_at,
+            edit,
+        };
+        match self
+            .edits
+            .iter_mut()
+            .find(|q| q.edit.object_id() == queued.edit.object_id())
+        {
+            Some(existing) => *existing = queued,
+            None => self.edits.push_back(queued),
+        }
+        seq
+    }
+
+    /// oldest queued edit, which is replayed next
+    pub fn front(&self) -> Option<&QueuedEdit> {
+        self.edits.front()
+    }
+
+    /// Remove edit with `seq`, e.g. after it was sent. Returns false, if the edit was
+    /// replaced by a later edit of the same object in the meantime.
+    pub fn remove(&mut self, seq: u64) -> bool {
+        let len = self.edits.len();
+        self.edits.retain(|q| q.seq != seq);
+        self.edits.len() < len
+    }
+
+    pub fn iter(&self) -> impl Iterator<Item = &QueuedEdit> {
+        self.edits.iter()
+    }
+
+    pub fn len(&self) -> usize {
+        self.edits.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.edits.is_empty()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use app_core::utils::id_version::IdVersion;
+
+    fn check_in(entrant_id: Uuid, checked_in: bool) -> PendingEdit {
+        PendingEdit::CheckIn {
+            tournament_id: Uuid::nil(),
+            entrant_id,
+            checked_in,
+        }
+    }
+
+    #[test]
+    fn edits_keep_order_of_first_edit() {
+        let mut queue = EditQueue::new();
+        let (a, b) = (Uuid::new_v4(), Uuid::new_v4());
+        queue.push(check_in(a, true), Utc::now());
+        queue.push(check_in(b, true), Utc::now());
+        let seq = queue.push(check_in(a, false), Utc::now());
+
+        assert_eq!(queue.len(), 2);
+        let front = queue.front().unwrap();
+        assert_eq!(front.seq, seq);
+        assert_eq!(front.edit, check_in(a, false));
+    }
+
+    #[test]
+    fn replaced_edit_is_not_removed() {
+        let mut queue = EditQueue::new();
+        let mut note = MatchNote::new(IdVersion::new(Uuid::new_v4(), Some(3)));
+        let sent = queue.push(PendingEdit::MatchNote(note.clone()), Utc::now());
+        // edited again, while the first edit is sent
+        note.set_text("late change");
+        let later = queue.push(PendingEdit::MatchNote(note.clone()), Utc::now());
+
+        assert!(!queue.remove(sent));
+        assert_eq!(queue.front().map(|q| q.seq), Some(later));
+        assert!(queue.remove(later));
+        assert!(queue.is_empty());
+    }
+
+    #[test]
+    fn queue_survives_serialization() {
+        let mut queue = EditQueue::new();
+        queue.push(check_in(Uuid::new_v4(), true), Utc::now());
+        let json = serde_json::to_string(&queue).unwrap();
+        let restored: EditQueue = serde_json::from_str(&json).unwrap();
+        assert_eq!(restored, queue);
+    }
+}
@@ -0,0 +1,114 @@
+//! persistence of the edit queue in the IndexedDB of the browser
+//!
+//! The queue is stored as one JSON document, so that queued edits survive a reload of the
+//! page or a restart of the device while the network is down.
+
+use super::EditQueue;
+
+#[cfg(feature = "hydrate")]
+mod idb {
+    use leptos::{
+        prelude::window,
+        wasm_bindgen::{JsCast, JsValue, closure::Closure},
+        web_sys::{IdbDatabase, IdbRequest, IdbTransactionMode},
+    };
+    use wasm_bindgen_futures::JsFuture;
+
+    const DB_NAME: &str = "fk_tournament_planer_offline";
+    const DB_VERSION: u32 = 1;
+    const STORE: &str = "edit_queue";
+    const QUEUE_KEY: &str = "queue";
+
+    /// wait for success or error of `request`
+    async fn finished(request: &IdbRequest) -> Result<JsValue, JsValue> {
+        let promise = js_sys::Promise::new(&mut |resolve, reject| {
+            request.set_onsuccess(Some(&resolve));
+            request.set_onerror(Some(&reject));
+        });
+        JsFuture::from(promise).await?;
+        request.result()
+    }
+
+    async fn open() -> Result<IdbDatabase, JsValue> {
+        let factory = window()
+            .indexed_db()?
+            .ok_or_else(|| JsValue::from_str("IndexedDB is not available"))?;
+        let request = factory.open_with_u32(DB_NAME, DB_VERSION)?;
+        let request_in_closure = request.clone();
+        let on_upgrade = Closure::once(move || {
+            if let Some(db) = request_in_closure
+                .result()
+                .ok()
+                .and_then(|db| db.dyn_into::<IdbDatabase>().ok())
+            {
+                let _ = db.create_object_store(STORE);
+            }
+        });
+        request.set_onupgradeneeded(Some(on_upgrade.as_ref().unchecked_ref()));
+        // on_upgrade lives until the database is open
+        let db = finished(&request).await?;
+        request.set_onupgradeneeded(None);
+        db.dyn_into::<IdbDatabase>()
+    }
+
+    pub async fn load() -> Result<Option<String>, JsValue> {
+        let db = open().await?;
+        let store = db.transaction_with_str(STORE)?.object_store(STORE)?;
+        let json = finished(&store.get(&JsValue::from_str(QUEUE_KEY))?).await?;
+        db.close();
+        Ok(json.as_string())
+    }
+
+    pub async fn save(json: String) -> Result<(), JsValue> {
+        let db = open().await?;
+        let store = db
+            .transaction_with_str_and_mode(STORE, IdbTransactionMode::Readwrite)?
+            .object_store(STORE)?;
+        finished(&store.put_with_key(&JsValue::from_str(&json), &JsValue::from_str(QUEUE_KEY))?)
+            .await?;
+        db.close();
+        Ok(())
+    }
+}
+
+/// Load the stored queue. Without storage or with an unreadable queue, nothing is loaded.
+#[cfg(feature = "hydrate")]
+pub async fn load_queue() -> Option<EditQueue> {
+    match idb::load().await {
+        Ok(json) => json.and_then(|json| match serde_json::from_str(&json) {
+            Ok(queue) => Some(queue),
+            Err(e) => {
+                tracing::warn!(error = %e, "stored_edit_queue_unreadable");
+                None
+            }
+        }),
+        Err(e) => {
+            tracing::warn!(error = ?e, "edit_queue_load_failed");
+            None
+        }
+    }
+}
+
+/// Store `queue`, replacing the stored one.
+#[cfg(feature = "hydrate")]
+pub async fn save_queue(queue: EditQueue) {
+    let json = match serde_json::to_string(&queue) {
+        Ok(json) => json,
+        Err(e) => {
+            tracing::warn!(error = %e, "edit_queue_serialization_failed");
+            return;
+        }
+    };
+    if let Err(e) = idb::save(json).await {
+        tracing::warn!(error = ?e, "edit_queue_save_failed");
+    }
+}
+
+/// Only clients store edits.
+#[cfg(not(feature = "hydrate"))]
+pub async fn load_queue() -> Option<EditQueue> {
+    None
+}
+
+#[cfg(not(feature = "hydrate"))]
+pub async fn save_queue(_queue: EditQueue) {}
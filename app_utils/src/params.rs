@@ -1,7 +1,7 @@
 //! Parameters module for shared query parameter definitions and utilities.
 
 use crate::enum_utils::{EditAction, FilterLimit};
-use app_core::TournamentState;
+use app_core::{Language, TournamentState, utils::list_order::SortDirection};
 use leptos::prelude::*;
 use leptos_router::{
     hooks::{use_params, use_query},
@@ -72,6 +72,60 @@ impl ParamQuery<bool> for IncludeAdhocQuery {
     }
 }
 
+#[derive(Params, Clone, PartialEq, Eq, Debug)]
+pub struct IncludeArchivedQuery {
+    pub include_archived: Option<bool>,
+}
+
+impl ParamQuery<bool> for IncludeArchivedQuery {
+    const KEY: &'static str = "include_archived";
+    fn use_param_query() -> Memo<Option<bool>> {
+        let query = use_query::<Self>();
+        Memo::new(move |_| query.get().ok().and_then(|ia| ia.include_archived))
+    }
+}
+
+#[derive(Params, Clone, PartialEq, Eq, Debug)]
+pub struct FilterOffsetQuery {
+    pub filter_offset: Option<usize>,
+}
+
+impl ParamQuery<usize> for FilterOffsetQuery {
+    const KEY: &'static str = "filter_offset";
+    fn use_param_query() -> Memo<Option<usize>> {
+        let query = use_query::<Self>();
+        Memo::new(move |_| query.get().ok().and_then(|fo| fo.filter_offset))
+    }
+}
+
+/// Sort column of a list as string, since columns depend upon the listed objects. Lists parse
+/// it into the sort column of their objects, see `use_list_order`.
+#[derive(Params, Clone, PartialEq, Eq, Debug)]
+pub struct SortByQuery {
+    pub sort_by: Option<String>,
+}
+
+impl ParamQuery<String> for SortByQuery {
+    const KEY: &'static str = "sort_by";
+    fn use_param_query() -> Memo<Option<String>> {
+        let query = use_query::<Self>();
+        Memo::new(move |_| query.get().ok().and_then(|sb| sb.sort_by))
+    }
+}
+
+#[derive(Params, Clone, PartialEq, Eq, Debug)]
+pub struct SortDirectionQuery {
+    pub sort_dir: Option<SortDirection>,
+}
+
+impl ParamQuery<SortDirection> for SortDirectionQuery {
+    const KEY: &'static str = "sort_dir";
+    fn use_param_query() -> Memo<Option<SortDirection>> {
+        let query = use_query::<Self>();
+        Memo::new(move |_| query.get().ok().and_then(|sd| sd.sort_dir))
+    }
+}
+
 // ---------------------- Postal Address ----------------------
 
 #[derive(Params, Clone, PartialEq, Eq, Debug)]
@@ -93,6 +147,20 @@ impl ParamQueryId for AddressIdQuery {
     }
 }
 
+/// postal address, from which the distance to listed tournaments is shown
+#[derive(Params, Clone, PartialEq, Eq, Debug)]
+pub struct DistanceFromQuery {
+    pub distance_from: Option<Uuid>,
+}
+
+impl ParamQuery<Uuid> for DistanceFromQuery {
+    const KEY: &'static str = "distance_from";
+    fn use_param_query() -> Memo<Option<Uuid>> {
+        let query = use_query::<Self>();
+        Memo::new(move |_| query.with(|p| p.as_ref().ok().and_then(|params| params.distance_from)))
+    }
+}
+
 // ---------------------- Sport ----------------------
 
 #[derive(Params, Clone, PartialEq, Eq, Debug)]
@@ -182,6 +250,92 @@ impl ParamQuery<u32> for GroupNumberParams {
     }
 }
 
+// ---------------------- Public Tournament ----------------------
+
+#[derive(Params, Clone, PartialEq, Eq, Debug)]
+pub struct PublicTournamentIdParams {
+    pub public_tournament_id: Option<Uuid>,
+}
+
+impl ParamQuery<Uuid> for PublicTournamentIdParams {
+    const KEY: &'static str = "public_tournament_id";
+    fn use_param_query() -> Memo<Option<Uuid>> {
+        let query = use_params::<Self>();
+        Memo::new(move |_| {
+            query.with(|p| {
+                p.as_ref()
+                    .ok()
+                    .and_then(|params| params.public_tournament_id)
+            })
+        })
+    }
+}
+
+/// display language of public pages
+#[derive(Params, Clone, PartialEq, Eq, Debug)]
+pub struct LanguageQuery {
+    pub lang: Option<Language>,
+}
+
+impl ParamQuery<Language> for LanguageQuery {
+    const KEY: &'static str = "lang";
+    fn use_param_query() -> Memo<Option<Language>> {
+        let query = use_query::<Self>();
+        Memo::new(move |_| query.with(|p| p.as_ref().ok().and_then(|params| params.lang)))
+    }
+}
+
+// ---------------------- Entrant Schedule ----------------------
+
+#[derive(Params, Clone, PartialEq, Eq, Debug)]
+pub struct EntrantIdParams {
+    pub entrant_id: Option<Uuid>,
+}
+
+impl ParamQuery<Uuid> for EntrantIdParams {
+    const KEY: &'static str = "entrant_id";
+    fn use_param_query() -> Memo<Option<Uuid>> {
+        let query = use_params::<Self>();
+        Memo::new(move |_| query.with(|p| p.as_ref().ok().and_then(|params| params.entrant_id)))
+    }
+}
+
+// ---------------------- Score Sheet ----------------------
+
+#[derive(Params, Clone, PartialEq, Eq, Debug)]
+pub struct MatchIdParams {
+    pub match_id: Option<Uuid>,
+}
+
+impl ParamQuery<Uuid> for MatchIdParams {
+    const KEY: &'static str = "match_id";
+    fn use_param_query() -> Memo<Option<Uuid>> {
+        let query = use_params::<Self>();
+        Memo::new(move |_| query.with(|p| p.as_ref().ok().and_then(|params| params.match_id)))
+    }
+}
+
+// ---------------------- Scorekeeper ----------------------
+
+#[derive(Params, Clone, PartialEq, Eq, Debug)]
+pub struct ScorekeeperSecretParams {
+    pub scorekeeper_secret: Option<String>,
+}
+
+impl ParamQuery<String> for ScorekeeperSecretParams {
+    const KEY: &'static str = "scorekeeper_secret";
+    fn use_param_query() -> Memo<Option<String>> {
+        let query = use_params::<Self>();
+        Memo::new(move |_| {
+            query.with(|p| {
+                p.as_ref()
+                    .ok()
+                    .and_then(|params| params.scorekeeper_secret.clone())
+            })
+        })
+    }
+}
+
 // ---------------------- Edit Action ----------------------
 #[derive(Params, Clone, PartialEq, Eq, Debug)]
 pub struct EditActionParams {
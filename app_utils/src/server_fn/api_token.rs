@@ -0,0 +1,113 @@
+//! server functions for management of REST API tokens
+
+#[cfg(any(feature = "ssr", feature = "test-mock"))]
+use crate::error::AppError;
+use crate::error::AppResult;
+use app_core::{ApiScope, ApiToken};
+#[cfg(any(feature = "ssr", feature = "test-mock"))]
+use app_core::{CoreState, utils::id_version::IdVersion};
+use leptos::{prelude::*, server_fn::codec::Json};
+use serde::{Deserialize, Serialize};
+use tracing::instrument;
+#[cfg(any(feature = "ssr", feature = "test-mock"))]
+use tracing::{error, info};
+use uuid::Uuid;
+
+/// newly created token with its secret, which is shown only once
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct CreatedApiToken {
+    pub token: ApiToken,
+    pub secret: String,
+}
+
+#[cfg(not(feature = "test-mock"))]
+#[server(client = crate::version::VersionedClient)]
+#[instrument(name = "api_token.list", skip_all, fields(include_revoked))]
+pub async fn list_api_tokens(include_revoked: bool) -> AppResult<Vec<ApiToken>> {
+    list_api_tokens_inner(include_revoked).await
+}
+
+#[cfg(feature = "test-mock")]
+pub async fn list_api_tokens(include_revoked: bool) -> AppResult<Vec<ApiToken>> {
+    list_api_tokens_inner(include_revoked).await
+}
+
+#[cfg(any(feature = "ssr", feature = "test-mock"))]
+async fn list_api_tokens_inner(include_revoked: bool) -> AppResult<Vec<ApiToken>> {
+    let core = expect_context::<CoreState>().as_api_token_state();
+    let tokens = core.list_tokens(include_revoked).await?;
+    Ok(tokens)
+}
+
+#[server(client = crate::version::VersionedClient, input = Json, output = Json)]
+#[instrument(
+    name = "api_token.create",
+    skip_all,
+    fields(name = %name, scopes = ?scopes, rate_limit_per_minute)
+)]
+pub async fn create_api_token(
+    name: String,
+    scopes: Vec<ApiScope>,
+    rate_limit_per_minute: u32,
+) -> AppResult<CreatedApiToken> {
+    create_api_token_inner(name, scopes, rate_limit_per_minute).await
+}
+
+#[cfg(any(feature = "ssr", feature = "test-mock"))]
+pub async fn create_api_token_inner(
+    name: String,
+    scopes: Vec<ApiScope>,
+    rate_limit_per_minute: u32,
+) -> AppResult<CreatedApiToken> {
+    let mut core = expect_context::<CoreState>().as_api_token_state();
+    core.get_mut()
+        .set_name(name)
+        .set_scopes(scopes)
+        .set_rate_limit_per_minute(rate_limit_per_minute);
+
+    match core.create().await {
+        Ok((token, secret)) => {
+            info!(saved_id = %token.get_id(), "create_ok");
+            Ok(CreatedApiToken {
+                token: token.clone(),
+                secret,
+            })
+        }
+        Err(e) => {
+            error!(error = %e, "create_failed");
+            Err(e.into())
+        }
+    }
+}
+
+#[server(client = crate::version::VersionedClient)]
+#[instrument(
+    name = "api_token.revoke",
+    skip_all,
+    fields(id = %id, version = version)
+)]
+pub async fn revoke_api_token(id: Uuid, version: u32) -> AppResult<ApiToken> {
+    revoke_api_token_inner(id, version).await
+}
+
+#[cfg(any(feature = "ssr", feature = "test-mock"))]
+pub async fn revoke_api_token_inner(id: Uuid, version: u32) -> AppResult<ApiToken> {
+    let mut core = expect_context::<CoreState>().as_api_token_state();
+    if core.load(id).await?.is_none() {
+        return Err(AppError::ResourceNotFound("Api Token".to_string(), id));
+    }
+    // revoke the version the user has seen (optimistic locking)
+    core.get_mut()
+        .set_id_version(IdVersion::new(id, Some(version)));
+
+    match core.revoke().await {
+        Ok(revoked) => {
+            info!(revoked_id = %revoked.get_id(), "revoke_ok");
+            Ok(revoked.clone())
+        }
+        Err(e) => {
+            error!(error = %e, "revoke_failed");
+            Err(e.into())
+        }
+    }
+}
@@ -0,0 +1,35 @@
+//! server functions for the audit log of tournaments
+
+use crate::error::AppResult;
+use app_core::AuditRecord;
+#[cfg(any(feature = "ssr", feature = "test-mock"))]
+use app_core::CoreState;
+use leptos::prelude::*;
+use tracing::instrument;
+use uuid::Uuid;
+
+/// maximum number of audit records listed on the admin page
+pub const AUDIT_LOG_LIST_LIMIT: usize = 500;
+
+#[cfg(not(feature = "test-mock"))]
+#[server(client = crate::version::VersionedClient)]
+#[instrument(
+    name = "audit_log.list",
+    skip_all,
+    fields(tournament_id = %tournament_id)
+)]
+pub async fn list_audit_records(tournament_id: Uuid) -> AppResult<Vec<AuditRecord>> {
+    list_audit_records_inner(tournament_id).await
+}
+
+#[cfg(feature = "test-mock")]
+pub async fn list_audit_records(tournament_id: Uuid) -> AppResult<Vec<AuditRecord>> {
+    list_audit_records_inner(tournament_id).await
+}
+
+#[cfg(any(feature = "ssr", feature = "test-mock"))]
+async fn list_audit_records_inner(tournament_id: Uuid) -> AppResult<Vec<AuditRecord>> {
+    let core = expect_context::<CoreState>().as_audit_log_state(tournament_id);
+    let records = core.list_audit_records(Some(AUDIT_LOG_LIST_LIMIT)).await?;
+    Ok(records)
+}
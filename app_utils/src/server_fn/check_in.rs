@@ -0,0 +1,133 @@
+//! server functions for check-in of entrants on tournament day
+
+use crate::error::AppResult;
+#[cfg(any(feature = "ssr", feature = "test-mock"))]
+use app_core::CoreState;
+use app_core::{CheckInOverview, Entrant, NoShowPolicy};
+use chrono::{DateTime, Utc};
+use leptos::{prelude::*, server_fn::codec::Json};
+use tracing::instrument;
+#[cfg(any(feature = "ssr", feature = "test-mock"))]
+use tracing::{error, info};
+use uuid::Uuid;
+
+#[cfg(not(feature = "test-mock"))]
+#[server(client = crate::version::VersionedClient)]
+#[instrument(
+    name = "check_in.load",
+    skip_all,
+    fields(tournament_id = %tournament_id)
+)]
+pub async fn load_check_in_overview(tournament_id: Uuid) -> AppResult<Option<CheckInOverview>> {
+    load_check_in_overview_inner(tournament_id).await
+}
+
+#[cfg(feature = "test-mock")]
+pub async fn load_check_in_overview(tournament_id: Uuid) -> AppResult<Option<CheckInOverview>> {
+    load_check_in_overview_inner(tournament_id).await
+}
+
+#[cfg(any(feature = "ssr", feature = "test-mock"))]
+async fn load_check_in_overview_inner(tournament_id: Uuid) -> AppResult<Option<CheckInOverview>> {
+    let core = expect_context::<CoreState>().as_check_in_state(tournament_id);
+    let overview = core.load_overview().await?;
+    Ok(overview)
+}
+
+/// Put an entrant on the waitlist at `position` or move it into the field with `None`.
+#[server(client = crate::version::VersionedClient)]
+#[instrument(
+    name = "check_in.waitlist",
+    skip_all,
+    fields(tournament_id = %tournament_id, entrant_id = %entrant_id, position = ?position)
+)]
+pub async fn set_entrant_waitlist_position(
+    tournament_id: Uuid,
+    entrant_id: Uuid,
+    position: Option<u32>,
+) -> AppResult<Entrant> {
+    set_entrant_waitlist_position_inner(tournament_id, entrant_id, position).await
+}
+
+#[cfg(any(feature = "ssr", feature = "test-mock"))]
+pub async fn set_entrant_waitlist_position_inner(
+    tournament_id: Uuid,
+    entrant_id: Uuid,
+    position: Option<u32>,
+) -> AppResult<Entrant> {
+    let core = expect_context::<CoreState>().as_check_in_state(tournament_id);
+
+    match core.set_waitlist_position(entrant_id, position).await {
+        Ok(entrant) => {
+            info!(version = entrant.get_version(), "waitlist_ok");
+            Ok(entrant)
+        }
+        Err(e) => {
+            error!(error = %e, "waitlist_failed");
+            Err(e.into())
+        }
+    }
+}
+
+/// Save check-in deadline and no-show policy of a tournament.
+#[server(client = crate::version::VersionedClient, input = Json, output = Json)]
+#[instrument(
+    name = "check_in.settings",
+    skip_all,
+    fields(tournament_id = %tournament_id, deadline = ?deadline, policy = %policy)
+)]
+pub async fn save_check_in_settings(
+    tournament_id: Uuid,
+    deadline: Option<DateTime<Utc>>,
+    policy: NoShowPolicy,
+) -> AppResult<()> {
+    save_check_in_settings_inner(tournament_id, deadline, policy).await
+}
+
+#[cfg(any(feature = "ssr", feature = "test-mock"))]
+pub async fn save_check_in_settings_inner(
+    tournament_id: Uuid,
+    deadline: Option<DateTime<Utc>>,
+    policy: NoShowPolicy,
+) -> AppResult<()> {
+    let core = expect_context::<CoreState>().as_check_in_state(tournament_id);
+
+    match core.set_settings(deadline, policy).await {
+        Ok(()) => {
+            info!("settings_ok");
+            Ok(())
+        }
+        Err(e) => {
+            error!(error = %e, "settings_failed");
+            Err(e.into())
+        }
+    }
+}
+
+/// Resolve no-shows of a tournament now, if its check-in deadline has passed. Returns the
+/// changed entrants.
+#[server(client = crate::version::VersionedClient)]
+#[instrument(
+    name = "check_in.resolve",
+    skip_all,
+    fields(tournament_id = %tournament_id)
+)]
+pub async fn resolve_no_shows(tournament_id: Uuid) -> AppResult<Vec<Entrant>> {
+    resolve_no_shows_inner(tournament_id).await
+}
+
+#[cfg(any(feature = "ssr", feature = "test-mock"))]
+pub async fn resolve_no_shows_inner(tournament_id: Uuid) -> AppResult<Vec<Entrant>> {
+    let core = expect_context::<CoreState>().as_check_in_state(tournament_id);
+
+    match core.resolve_no_shows(Utc::now()).await {
+        Ok(changed) => {
+            info!(count = changed.len(), "resolve_ok");
+            Ok(changed)
+        }
+        Err(e) => {
+            error!(error = %e, "resolve_failed");
+            Err(e.into())
+        }
+    }
+}
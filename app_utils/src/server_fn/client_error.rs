@@ -0,0 +1,68 @@
+//! server functions for reports of client errors
+
+use crate::error::AppResult;
+use app_core::ClientErrorReport;
+#[cfg(any(feature = "ssr", feature = "test-mock"))]
+use app_core::CoreState;
+use leptos::{prelude::*, server_fn::codec::Json};
+use tracing::instrument;
+#[cfg(any(feature = "ssr", feature = "test-mock"))]
+use tracing::{error, info};
+
+/// maximum number of client errors listed on the admin page
+pub const CLIENT_ERROR_LIST_LIMIT: usize = 200;
+
+#[cfg(not(feature = "test-mock"))]
+#[server(client = crate::version::VersionedClient)]
+#[instrument(name = "client_error.list", skip_all)]
+pub async fn list_client_errors() -> AppResult<Vec<ClientErrorReport>> {
+    list_client_errors_inner().await
+}
+
+#[cfg(feature = "test-mock")]
+pub async fn list_client_errors() -> AppResult<Vec<ClientErrorReport>> {
+    list_client_errors_inner().await
+}
+
+#[cfg(any(feature = "ssr", feature = "test-mock"))]
+async fn list_client_errors_inner() -> AppResult<Vec<ClientErrorReport>> {
+    let core = expect_context::<CoreState>().as_client_error_state();
+    let reports = core
+        .list_client_errors(Some(CLIENT_ERROR_LIST_LIMIT))
+        .await?;
+    Ok(reports)
+}
+
+/// Report an uncaught error of a client.
+#[server(client = crate::version::VersionedClient, input = Json, output = Json)]
+#[instrument(
+    name = "client_error.report",
+    skip_all,
+    fields(
+        id = %report.get_id(),
+        component_id = ?report.get_component_id(),
+        route = report.get_route(),
+        app_version = report.get_app_version(),
+        occurrences = report.get_occurrences(),
+    )
+)]
+pub async fn report_client_error(report: ClientErrorReport) -> AppResult<ClientErrorReport> {
+    report_client_error_inner(report).await
+}
+
+#[cfg(any(feature = "ssr", feature = "test-mock"))]
+pub async fn report_client_error_inner(report: ClientErrorReport) -> AppResult<ClientErrorReport> {
+    let mut core = expect_context::<CoreState>().as_client_error_state();
+    *core.get_mut() = report;
+
+    match core.save().await {
+        Ok(saved) => {
+            info!(saved_id = %saved.get_id(), message = saved.get_message(), "report_ok");
+            Ok(saved.clone())
+        }
+        Err(e) => {
+            error!(error = %e, "report_failed");
+            Err(e.into())
+        }
+    }
+}
@@ -0,0 +1,105 @@
+//! server functions for court calls of matches
+
+use crate::error::AppResult;
+#[cfg(any(feature = "ssr", feature = "test-mock"))]
+use app_core::CoreState;
+use app_core::{CourtCall, CourtCallPolicy, CourtCallQueue, TournamentBase};
+use leptos::{prelude::*, server_fn::codec::Json};
+use tracing::instrument;
+#[cfg(any(feature = "ssr", feature = "test-mock"))]
+use tracing::{error, info};
+use uuid::Uuid;
+
+#[cfg(not(feature = "test-mock"))]
+#[server(client = crate::version::VersionedClient)]
+#[instrument(
+    name = "court_call.load",
+    skip_all,
+    fields(tournament_id = %tournament_id)
+)]
+pub async fn load_court_calls(tournament_id: Uuid) -> AppResult<Option<CourtCallQueue>> {
+    load_court_calls_inner(tournament_id).await
+}
+
+#[cfg(feature = "test-mock")]
+pub async fn load_court_calls(tournament_id: Uuid) -> AppResult<Option<CourtCallQueue>> {
+    load_court_calls_inner(tournament_id).await
+}
+
+#[cfg(any(feature = "ssr", feature = "test-mock"))]
+async fn load_court_calls_inner(tournament_id: Uuid) -> AppResult<Option<CourtCallQueue>> {
+    let core = expect_context::<CoreState>();
+    let queue = core.load_court_calls(tournament_id).await?;
+    Ok(queue)
+}
+
+/// Announce, that `station` of a tournament became free. Returns the call of the next match
+/// of the station, if any.
+#[server(client = crate::version::VersionedClient)]
+#[instrument(
+    name = "court_call.announce",
+    skip_all,
+    fields(tournament_id = %tournament_id, station = station)
+)]
+pub async fn announce_free_station(
+    tournament_id: Uuid,
+    station: u32,
+) -> AppResult<Option<CourtCall>> {
+    announce_free_station_inner(tournament_id, station).await
+}
+
+#[cfg(any(feature = "ssr", feature = "test-mock"))]
+pub async fn announce_free_station_inner(
+    tournament_id: Uuid,
+    station: u32,
+) -> AppResult<Option<CourtCall>> {
+    let core = expect_context::<CoreState>();
+
+    match core.announce_free_station(tournament_id, station).await {
+        Ok(call) => {
+            info!(called = call.is_some(), "announce_ok");
+            Ok(call)
+        }
+        Err(e) => {
+            error!(error = %e, "announce_failed");
+            Err(e.into())
+        }
+    }
+}
+
+/// Save the court call policy of a tournament.
+#[server(client = crate::version::VersionedClient, input = Json, output = Json)]
+#[instrument(
+    name = "court_call.policy",
+    skip_all,
+    fields(
+        tournament_id = %tournament_id,
+        call_ahead = policy.call_ahead,
+        notify_entrants = policy.notify_entrants
+    )
+)]
+pub async fn save_court_call_policy(
+    tournament_id: Uuid,
+    policy: CourtCallPolicy,
+) -> AppResult<TournamentBase> {
+    save_court_call_policy_inner(tournament_id, policy).await
+}
+
+#[cfg(any(feature = "ssr", feature = "test-mock"))]
+pub async fn save_court_call_policy_inner(
+    tournament_id: Uuid,
+    policy: CourtCallPolicy,
+) -> AppResult<TournamentBase> {
+    let core = expect_context::<CoreState>();
+
+    match core.set_court_call_policy(tournament_id, policy).await {
+        Ok(tournament) => {
+            info!(version = ?tournament.get_version(), "policy_ok");
+            Ok(tournament)
+        }
+        Err(e) => {
+            error!(error = %e, "policy_failed");
+            Err(e.into())
+        }
+    }
+}
@@ -0,0 +1,102 @@
+//! server functions for entrants of tournament
+
+use crate::error::AppResult;
+#[cfg(any(feature = "ssr", feature = "test-mock"))]
+use app_core::CoreState;
+use app_core::Entrant;
+use leptos::{prelude::*, server_fn::codec::Json};
+use tracing::instrument;
+#[cfg(any(feature = "ssr", feature = "test-mock"))]
+use tracing::{error, info};
+use uuid::Uuid;
+
+#[cfg(not(feature = "test-mock"))]
+#[server(client = crate::version::VersionedClient)]
+#[instrument(
+    name = "entrant.list",
+    skip_all,
+    fields(tournament_id = %tournament_id)
+)]
+pub async fn list_entrants(tournament_id: Uuid) -> AppResult<Vec<Entrant>> {
+    list_entrants_inner(tournament_id).await
+}
+
+#[cfg(feature = "test-mock")]
+pub async fn list_entrants(tournament_id: Uuid) -> AppResult<Vec<Entrant>> {
+    list_entrants_inner(tournament_id).await
+}
+
+#[cfg(any(feature = "ssr", feature = "test-mock"))]
+async fn list_entrants_inner(tournament_id: Uuid) -> AppResult<Vec<Entrant>> {
+    let core = expect_context::<CoreState>().as_entrant_state(tournament_id);
+    let entrants = core.list_entrants().await?;
+    Ok(entrants)
+}
+
+/// Import entrants from CSV with columns `name, club, seed, email`.
+///
+/// Invalid rows are returned as `CoreError::Validation` with the CSV line number of every
+/// error as param `row`; in this case no entrant is imported.
+#[server(client = crate::version::VersionedClient, input = Json, output = Json)]
+#[instrument(
+    name = "entrant.import_csv",
+    skip_all,
+    fields(tournament_id = %tournament_id, csv_len = csv.len())
+)]
+pub async fn import_entrants_csv(tournament_id: Uuid, csv: String) -> AppResult<Vec<Entrant>> {
+    import_entrants_csv_inner(tournament_id, csv).await
+}
+
+#[cfg(any(feature = "ssr", feature = "test-mock"))]
+pub async fn import_entrants_csv_inner(
+    tournament_id: Uuid,
+    csv: String,
+) -> AppResult<Vec<Entrant>> {
+    let mut core = expect_context::<CoreState>().as_entrant_state(tournament_id);
+
+    match core.import_entrants_csv(&csv).await {
+        Ok(imported) => {
+            info!(count = imported.len(), "import_ok");
+            Ok(imported)
+        }
+        Err(e) => {
+            error!(error = %e, "import_failed");
+            Err(e.into())
+        }
+    }
+}
+
+/// Check an entrant in or out on tournament day.
+#[server(client = crate::version::VersionedClient)]
+#[instrument(
+    name = "entrant.check_in",
+    skip_all,
+    fields(tournament_id = %tournament_id, entrant_id = %entrant_id, checked_in = checked_in)
+)]
+pub async fn set_entrant_check_in(
+    tournament_id: Uuid,
+    entrant_id: Uuid,
+    checked_in: bool,
+) -> AppResult<Entrant> {
+    set_entrant_check_in_inner(tournament_id, entrant_id, checked_in).await
+}
+
+#[cfg(any(feature = "ssr", feature = "test-mock"))]
+pub async fn set_entrant_check_in_inner(
+    tournament_id: Uuid,
+    entrant_id: Uuid,
+    checked_in: bool,
+) -> AppResult<Entrant> {
+    let mut core = expect_context::<CoreState>().as_entrant_state(tournament_id);
+
+    match core.set_check_in(entrant_id, checked_in).await {
+        Ok(entrant) => {
+            info!(version = entrant.get_version(), "check_in_ok");
+            Ok(entrant.clone())
+        }
+        Err(e) => {
+            error!(error = %e, "check_in_failed");
+            Err(e.into())
+        }
+    }
+}
@@ -0,0 +1,44 @@
+//! server functions for feedback of users
+
+use crate::error::AppResult;
+#[cfg(any(feature = "ssr", feature = "test-mock"))]
+use app_core::CoreState;
+use app_core::Feedback;
+use leptos::{prelude::*, server_fn::codec::Json};
+use tracing::instrument;
+#[cfg(any(feature = "ssr", feature = "test-mock"))]
+use tracing::{error, info};
+
+/// Submit feedback of a user with context of the client.
+#[server(client = crate::version::VersionedClient, input = Json, output = Json)]
+#[instrument(
+    name = "feedback.submit",
+    skip_all,
+    fields(
+        id = %feedback.get_id(),
+        route = feedback.get_route(),
+        tournament_id = ?feedback.get_tournament_id(),
+        app_version = feedback.get_app_version(),
+        recent_errors = feedback.get_recent_errors().len(),
+    )
+)]
+pub async fn submit_feedback(feedback: Feedback) -> AppResult<Feedback> {
+    submit_feedback_inner(feedback).await
+}
+
+#[cfg(any(feature = "ssr", feature = "test-mock"))]
+pub async fn submit_feedback_inner(feedback: Feedback) -> AppResult<Feedback> {
+    let mut core = expect_context::<CoreState>().as_feedback_state();
+    *core.get_mut() = feedback;
+
+    match core.save().await {
+        Ok(saved) => {
+            info!(saved_id = %saved.get_id(), "submit_ok");
+            Ok(saved.clone())
+        }
+        Err(e) => {
+            error!(error = %e, "submit_failed");
+            Err(e.into())
+        }
+    }
+}
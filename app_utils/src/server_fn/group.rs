@@ -0,0 +1,41 @@
+//! server functions for groups of stages
+
+use crate::error::AppResult;
+#[cfg(any(feature = "ssr", feature = "test-mock"))]
+use app_core::CoreState;
+use app_core::GroupStanding;
+use leptos::prelude::*;
+use tracing::instrument;
+use uuid::Uuid;
+
+#[cfg(not(feature = "test-mock"))]
+#[server(client = crate::version::VersionedClient)]
+#[instrument(
+    name = "group.standings",
+    skip_all,
+    fields(tournament_id = %tournament_id, group_id = %group_id)
+)]
+pub async fn get_group_standings(
+    tournament_id: Uuid,
+    group_id: Uuid,
+) -> AppResult<Vec<GroupStanding>> {
+    get_group_standings_inner(tournament_id, group_id).await
+}
+
+#[cfg(feature = "test-mock")]
+pub async fn get_group_standings(
+    tournament_id: Uuid,
+    group_id: Uuid,
+) -> AppResult<Vec<GroupStanding>> {
+    get_group_standings_inner(tournament_id, group_id).await
+}
+
+#[cfg(any(feature = "ssr", feature = "test-mock"))]
+async fn get_group_standings_inner(
+    tournament_id: Uuid,
+    group_id: Uuid,
+) -> AppResult<Vec<GroupStanding>> {
+    let core = expect_context::<CoreState>();
+    let standings = core.get_group_standings(tournament_id, group_id).await?;
+    Ok(standings)
+}
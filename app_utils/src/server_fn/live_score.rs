@@ -0,0 +1,70 @@
+//! server functions for running scores of matches in progress
+
+use crate::error::AppResult;
+#[cfg(any(feature = "ssr", feature = "test-mock"))]
+use app_core::CoreState;
+use app_core::LiveScore;
+use leptos::{prelude::*, server_fn::codec::Json};
+use tracing::instrument;
+#[cfg(any(feature = "ssr", feature = "test-mock"))]
+use tracing::{error, info};
+use uuid::Uuid;
+
+#[cfg(not(feature = "test-mock"))]
+#[server(client = crate::version::VersionedClient)]
+#[instrument(
+    name = "live_score.list",
+    skip_all,
+    fields(tournament_id = %tournament_id)
+)]
+pub async fn load_live_scores(tournament_id: Uuid) -> AppResult<Vec<(Uuid, LiveScore)>> {
+    load_live_scores_inner(tournament_id).await
+}
+
+#[cfg(feature = "test-mock")]
+pub async fn load_live_scores(tournament_id: Uuid) -> AppResult<Vec<(Uuid, LiveScore)>> {
+    load_live_scores_inner(tournament_id).await
+}
+
+#[cfg(any(feature = "ssr", feature = "test-mock"))]
+async fn load_live_scores_inner(tournament_id: Uuid) -> AppResult<Vec<(Uuid, LiveScore)>> {
+    let core = expect_context::<CoreState>();
+    let live_scores = core.list_live_scores(tournament_id).await?;
+    Ok(live_scores)
+}
+
+/// Update the running score of a match in progress. The score is not validated against the
+/// rules of the sport; the final score is validated at the end of the match.
+#[server(client = crate::version::VersionedClient, input = Json, output = Json)]
+#[instrument(
+    name = "live_score.update",
+    skip_all,
+    fields(match_id = %match_id, score_a = ?score_a, score_b = ?score_b)
+)]
+pub async fn update_live_score(
+    match_id: Uuid,
+    score_a: Vec<u16>,
+    score_b: Vec<u16>,
+) -> AppResult<Option<LiveScore>> {
+    update_live_score_inner(match_id, score_a, score_b).await
+}
+
+#[cfg(any(feature = "ssr", feature = "test-mock"))]
+pub async fn update_live_score_inner(
+    match_id: Uuid,
+    score_a: Vec<u16>,
+    score_b: Vec<u16>,
+) -> AppResult<Option<LiveScore>> {
+    let core = expect_context::<CoreState>();
+
+    match core.update_live_score(match_id, score_a, score_b).await {
+        Ok(live_score) => {
+            info!(tick = ?live_score.as_ref().map(|l| l.tick), "update_ok");
+            Ok(live_score)
+        }
+        Err(e) => {
+            error!(error = %e, "update_failed");
+            Err(e.into())
+        }
+    }
+}
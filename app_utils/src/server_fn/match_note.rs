@@ -0,0 +1,140 @@
+//! server functions for notes and tags of matches
+
+use crate::error::AppResult;
+use app_core::MatchNote;
+#[cfg(any(feature = "ssr", feature = "test-mock"))]
+use app_core::{
+    CoreState,
+    utils::{id_version::IdVersion, traits::ObjectIdVersion},
+};
+use leptos::{prelude::*, server_fn::codec::Json};
+use tracing::instrument;
+#[cfg(any(feature = "ssr", feature = "test-mock"))]
+use tracing::{error, info};
+use uuid::Uuid;
+
+#[cfg(not(feature = "test-mock"))]
+#[server(client = crate::version::VersionedClient)]
+#[instrument(
+    name = "match_note.list",
+    skip_all,
+    fields(tournament_id = %tournament_id, tag = ?tag)
+)]
+pub async fn list_match_notes(
+    tournament_id: Uuid,
+    tag: Option<String>,
+    query: Option<String>,
+) -> AppResult<Vec<MatchNote>> {
+    list_match_notes_inner(tournament_id, tag, query).await
+}
+
+#[cfg(feature = "test-mock")]
+pub async fn list_match_notes(
+    tournament_id: Uuid,
+    tag: Option<String>,
+    query: Option<String>,
+) -> AppResult<Vec<MatchNote>> {
+    list_match_notes_inner(tournament_id, tag, query).await
+}
+
+#[cfg(any(feature = "ssr", feature = "test-mock"))]
+async fn list_match_notes_inner(
+    tournament_id: Uuid,
+    tag: Option<String>,
+    query: Option<String>,
+) -> AppResult<Vec<MatchNote>> {
+    let core = expect_context::<CoreState>().as_match_note_state(tournament_id);
+    let notes = core.list_notes(tag.as_deref(), query.as_deref()).await?;
+    Ok(notes)
+}
+
+#[cfg(not(feature = "test-mock"))]
+#[server(client = crate::version::VersionedClient)]
+#[instrument(
+    name = "match_note.tags",
+    skip_all,
+    fields(tournament_id = %tournament_id)
+)]
+pub async fn list_match_note_tags(tournament_id: Uuid) -> AppResult<Vec<(String, usize)>> {
+    list_match_note_tags_inner(tournament_id).await
+}
+
+#[cfg(feature = "test-mock")]
+pub async fn list_match_note_tags(tournament_id: Uuid) -> AppResult<Vec<(String, usize)>> {
+    list_match_note_tags_inner(tournament_id).await
+}
+
+#[cfg(any(feature = "ssr", feature = "test-mock"))]
+async fn list_match_note_tags_inner(tournament_id: Uuid) -> AppResult<Vec<(String, usize)>> {
+    let core = expect_context::<CoreState>().as_match_note_state(tournament_id);
+    let tags = core.tag_counts().await?;
+    Ok(tags)
+}
+
+#[cfg(not(feature = "test-mock"))]
+#[server(client = crate::version::VersionedClient)]
+#[instrument(
+    name = "match_note.load",
+    skip_all,
+    fields(tournament_id = %tournament_id, match_id = %match_id)
+)]
+pub async fn load_match_note(tournament_id: Uuid, match_id: Uuid) -> AppResult<Option<MatchNote>> {
+    load_match_note_inner(tournament_id, match_id).await
+}
+
+#[cfg(feature = "test-mock")]
+pub async fn load_match_note(tournament_id: Uuid, match_id: Uuid) -> AppResult<Option<MatchNote>> {
+    load_match_note_inner(tournament_id, match_id).await
+}
+
+#[cfg(any(feature = "ssr", feature = "test-mock"))]
+async fn load_match_note_inner(
+    tournament_id: Uuid,
+    match_id: Uuid,
+) -> AppResult<Option<MatchNote>> {
+    let mut core = expect_context::<CoreState>().as_match_note_state(tournament_id);
+    let note = core.load_of_match(match_id).await?.cloned();
+    Ok(note)
+}
+
+#[server(client = crate::version::VersionedClient, input = Json, output = Json)]
+#[instrument(
+    name = "match_note.save",
+    skip_all,
+    fields(
+        id = %note.get_id(),
+        version = ?note.get_version(),
+        tournament_id = %note.get_tournament_id(),
+        match_id = %note.get_match_id(),
+    )
+)]
+pub async fn save_match_note(note: MatchNote) -> AppResult<MatchNote> {
+    save_match_note_inner(note).await
+}
+
+#[cfg(any(feature = "ssr", feature = "test-mock"))]
+pub async fn save_match_note_inner(note: MatchNote) -> AppResult<MatchNote> {
+    let mut core = expect_context::<CoreState>().as_match_note_state(note.get_tournament_id());
+
+    match note.get_id_version() {
+        IdVersion::Existing(..) => {
+            info!("saving_update");
+        }
+        IdVersion::NewWithId(..) => {
+            info!("saving_create");
+        }
+    }
+
+    *core.get_mut() = note;
+
+    match core.save().await {
+        Ok(saved) => {
+            info!(saved_id = %saved.get_id(), "save_ok");
+            Ok(saved.clone())
+        }
+        Err(e) => {
+            error!(error = %e, "save_failed");
+            Err(e.into())
+        }
+    }
+}
@@ -1,7 +1,28 @@
 //! Server functions module
 
+pub mod api_token;
+pub mod audit_log;
+pub mod check_in;
+pub mod client_error;
+pub mod court_call;
+pub mod entrant;
+pub mod feedback;
+pub mod group;
+pub mod live_score;
+pub mod match_note;
+pub mod official;
 pub mod postal_address;
+pub mod presence;
+pub mod public_tournament;
+pub mod score_sheet;
+pub mod scorekeeper;
+pub mod search;
+pub mod seeding;
+pub mod shift_log;
 pub mod sport_config;
+pub mod sport_plugin;
 pub mod stage;
 pub mod tournament_base;
 pub mod tournament_editor;
+pub mod venue;
+pub mod webhook;
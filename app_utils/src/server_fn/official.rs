@@ -0,0 +1,138 @@
+//! server functions for officials of tournaments and their assignment to matches
+
+#[cfg(any(feature = "ssr", feature = "test-mock"))]
+use crate::error::AppError;
+use crate::error::AppResult;
+use app_core::{AvailabilityWindow, Official, OfficialAssignmentPlan};
+#[cfg(any(feature = "ssr", feature = "test-mock"))]
+use app_core::{CoreState, utils::id_version::IdVersion};
+use leptos::{prelude::*, server_fn::codec::Json};
+use tracing::instrument;
+#[cfg(any(feature = "ssr", feature = "test-mock"))]
+use tracing::{error, info};
+use uuid::Uuid;
+
+#[cfg(not(feature = "test-mock"))]
+#[server(client = crate::version::VersionedClient)]
+#[instrument(name = "official.list", skip_all, fields(tournament_id = %tournament_id))]
+pub async fn list_officials(tournament_id: Uuid) -> AppResult<Vec<Official>> {
+    list_officials_inner(tournament_id).await
+}
+
+#[cfg(feature = "test-mock")]
+pub async fn list_officials(tournament_id: Uuid) -> AppResult<Vec<Official>> {
+    list_officials_inner(tournament_id).await
+}
+
+#[cfg(any(feature = "ssr", feature = "test-mock"))]
+async fn list_officials_inner(tournament_id: Uuid) -> AppResult<Vec<Official>> {
+    let core = expect_context::<CoreState>().as_official_state(tournament_id);
+    let officials = core.list_officials().await?;
+    Ok(officials)
+}
+
+/// Create (`id == None`) or update official of tournament.
+#[server(client = crate::version::VersionedClient, input = Json, output = Json)]
+#[instrument(
+    name = "official.save",
+    skip_all,
+    fields(
+        tournament_id = %tournament_id,
+        id = ?id,
+        version,
+        name = %name,
+        qualifications = qualifications.len(),
+        availability = availability.len()
+    )
+)]
+pub async fn save_official(
+    tournament_id: Uuid,
+    id: Option<Uuid>,
+    version: u32,
+    name: String,
+    club: Option<String>,
+    qualifications: Vec<String>,
+    availability: Vec<AvailabilityWindow>,
+) -> AppResult<Official> {
+    save_official_inner(
+        tournament_id,
+        id,
+        version,
+        name,
+        club,
+        qualifications,
+        availability,
+    )
+    .await
+}
+
+#[cfg(any(feature = "ssr", feature = "test-mock"))]
+pub async fn save_official_inner(
+    tournament_id: Uuid,
+    id: Option<Uuid>,
+    version: u32,
+    name: String,
+    club: Option<String>,
+    qualifications: Vec<String>,
+    availability: Vec<AvailabilityWindow>,
+) -> AppResult<Official> {
+    let mut core = expect_context::<CoreState>().as_official_state(tournament_id);
+    if let Some(id) = id {
+        if core.load(id).await?.is_none() {
+            return Err(AppError::ResourceNotFound("Official".to_string(), id));
+        }
+        // update the version the user has seen (optimistic locking)
+        core.get_mut()
+            .set_id_version(IdVersion::new(id, Some(version)));
+    }
+    core.get_mut()
+        .set_name(name)
+        .set_club(club.filter(|c| !c.trim().is_empty()))
+        .set_qualifications(qualifications)
+        .set_availability(availability);
+
+    match core.save().await {
+        Ok(official) => {
+            info!(saved_id = %official.get_id(), "save_ok");
+            Ok(official.clone())
+        }
+        Err(e) => {
+            error!(error = %e, "save_failed");
+            Err(e.into())
+        }
+    }
+}
+
+#[cfg(not(feature = "test-mock"))]
+#[server(client = crate::version::VersionedClient)]
+#[instrument(
+    name = "official.plan",
+    skip_all,
+    fields(tournament_id = %tournament_id, qualification = ?qualification)
+)]
+pub async fn plan_official_assignments(
+    tournament_id: Uuid,
+    qualification: Option<String>,
+) -> AppResult<OfficialAssignmentPlan> {
+    plan_official_assignments_inner(tournament_id, qualification).await
+}
+
+#[cfg(feature = "test-mock")]
+pub async fn plan_official_assignments(
+    tournament_id: Uuid,
+    qualification: Option<String>,
+) -> AppResult<OfficialAssignmentPlan> {
+    plan_official_assignments_inner(tournament_id, qualification).await
+}
+
+#[cfg(any(feature = "ssr", feature = "test-mock"))]
+async fn plan_official_assignments_inner(
+    tournament_id: Uuid,
+    qualification: Option<String>,
+) -> AppResult<OfficialAssignmentPlan> {
+    let core = expect_context::<CoreState>().as_official_state(tournament_id);
+    let plan = core
+        .plan_assignments(qualification.as_deref().filter(|q| !q.trim().is_empty()))
+        .await?;
+    Ok(plan)
+}
@@ -1,12 +1,12 @@
 //! Postal Address Server Functions Module
 
 use crate::error::AppResult;
-use app_core::PostalAddress;
 #[cfg(any(feature = "ssr", feature = "test-mock"))]
 use app_core::{
     CoreState,
     utils::{id_version::IdVersion, traits::ObjectIdVersion},
 };
+use app_core::{PostalAddress, utils::list_order::ListOrder};
 use leptos::prelude::*;
 use tracing::instrument;
 #[cfg(any(feature = "ssr", feature = "test-mock"))]
@@ -14,7 +14,7 @@ use tracing::{error, info};
 use uuid::Uuid;
 
 #[cfg(not(feature = "test-mock"))]
-#[server]
+#[server(client = crate::version::VersionedClient)]
 #[instrument(
     name = "postal_address.load",
     skip_all,
@@ -37,29 +37,38 @@ pub async fn load_postal_address_inner(id: Uuid) -> AppResult<Option<PostalAddre
 }
 
 #[cfg(not(feature = "test-mock"))]
-#[server]
+#[server(client = crate::version::VersionedClient)]
 #[instrument(
     name = "postal_address.list",
     skip_all,
-    fields(q_len = name.len(), limit = limit.unwrap_or(10))
+    fields(q_len = name.len(), limit = limit.unwrap_or(10), offset = order.get_offset())
 )]
-pub async fn list_postal_address_ids(name: String, limit: Option<usize>) -> AppResult<Vec<Uuid>> {
-    list_postal_address_ids_inner(name, limit).await
+pub async fn list_postal_address_ids(
+    name: String,
+    limit: Option<usize>,
+    order: ListOrder<PostalAddress>,
+) -> AppResult<Vec<Uuid>> {
+    list_postal_address_ids_inner(name, limit, order).await
 }
 
 #[cfg(feature = "test-mock")]
-pub async fn list_postal_address_ids(name: String, limit: Option<usize>) -> AppResult<Vec<Uuid>> {
-    list_postal_address_ids_inner(name, limit).await
+pub async fn list_postal_address_ids(
+    name: String,
+    limit: Option<usize>,
+    order: ListOrder<PostalAddress>,
+) -> AppResult<Vec<Uuid>> {
+    list_postal_address_ids_inner(name, limit, order).await
 }
 
 #[cfg(any(feature = "ssr", feature = "test-mock"))]
 pub async fn list_postal_address_ids_inner(
     name: String,
     limit: Option<usize>,
+    order: ListOrder<PostalAddress>,
 ) -> AppResult<Vec<Uuid>> {
     let core = expect_context::<CoreState>().as_postal_address_state();
     info!("list_request");
-    match core.list_address_ids(Some(&name), limit).await {
+    match core.list_address_ids(Some(&name), limit, &order).await {
         Ok(list) => {
             info!(count = list.len(), "list_ok");
             Ok(list)
@@ -71,7 +80,42 @@ pub async fn list_postal_address_ids_inner(
     }
 }
 
-#[server]
+#[server(client = crate::version::VersionedClient)]
+#[instrument(
+    name = "postal_address.list_addresses",
+    skip_all,
+    fields(q_len = name.len(), limit = limit.unwrap_or(10))
+)]
+pub async fn list_postal_addresses(
+    name: String,
+    limit: Option<usize>,
+) -> AppResult<Vec<PostalAddress>> {
+    list_postal_addresses_inner(name, limit).await
+}
+
+#[cfg(any(feature = "ssr", feature = "test-mock"))]
+pub async fn list_postal_addresses_inner(
+    name: String,
+    limit: Option<usize>,
+) -> AppResult<Vec<PostalAddress>> {
+    let core = expect_context::<CoreState>().as_postal_address_state();
+    info!("list_addresses_request");
+    match core
+        .list_addresses(Some(&name), limit, &ListOrder::default())
+        .await
+    {
+        Ok(list) => {
+            info!(count = list.len(), "list_addresses_ok");
+            Ok(list)
+        }
+        Err(e) => {
+            error!(error = %e, "list_addresses_failed");
+            Err(e.into())
+        }
+    }
+}
+
+#[server(client = crate::version::VersionedClient)]
 #[instrument(
     name = "postal_address.save",
     skip_all,
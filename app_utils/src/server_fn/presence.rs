@@ -0,0 +1,87 @@
+//! server functions for presence of editors
+
+use crate::error::AppResult;
+#[cfg(any(feature = "ssr", feature = "test-mock"))]
+use app_core::CoreState;
+use app_core::PresenceEditor;
+use leptos::{prelude::*, server_fn::codec::Json};
+use tracing::instrument;
+#[cfg(any(feature = "ssr", feature = "test-mock"))]
+use tracing::{error, info};
+use uuid::Uuid;
+
+/// Join `editor` to the presence of object `object_id` or refresh its presence. Returns the
+/// editors of the object including `editor`.
+#[cfg(not(feature = "test-mock"))]
+#[server(client = crate::version::VersionedClient, input = Json, output = Json)]
+#[instrument(
+    name = "presence.join",
+    skip_all,
+    fields(object_id = %object_id, client_id = %editor.client_id)
+)]
+pub async fn join_presence(
+    object_id: Uuid,
+    editor: PresenceEditor,
+) -> AppResult<Vec<PresenceEditor>> {
+    join_presence_inner(object_id, editor).await
+}
+
+#[cfg(feature = "test-mock")]
+pub async fn join_presence(
+    object_id: Uuid,
+    editor: PresenceEditor,
+) -> AppResult<Vec<PresenceEditor>> {
+    join_presence_inner(object_id, editor).await
+}
+
+#[cfg(any(feature = "ssr", feature = "test-mock"))]
+pub async fn join_presence_inner(
+    object_id: Uuid,
+    editor: PresenceEditor,
+) -> AppResult<Vec<PresenceEditor>> {
+    let core = expect_context::<CoreState>();
+
+    match core.join_presence(object_id, editor).await {
+        Ok(editors) => {
+            info!(num_editors = editors.len(), "join_ok");
+            Ok(editors)
+        }
+        Err(e) => {
+            error!(error = %e, "join_failed");
+            Err(e.into())
+        }
+    }
+}
+
+/// Remove editor `client_id` from the presence of object `object_id`.
+#[cfg(not(feature = "test-mock"))]
+#[server(client = crate::version::VersionedClient)]
+#[instrument(
+    name = "presence.leave",
+    skip_all,
+    fields(object_id = %object_id, client_id = %client_id)
+)]
+pub async fn leave_presence(object_id: Uuid, client_id: Uuid) -> AppResult<()> {
+    leave_presence_inner(object_id, client_id).await
+}
+
+#[cfg(feature = "test-mock")]
+pub async fn leave_presence(object_id: Uuid, client_id: Uuid) -> AppResult<()> {
+    leave_presence_inner(object_id, client_id).await
+}
+
+#[cfg(any(feature = "ssr", feature = "test-mock"))]
+pub async fn leave_presence_inner(object_id: Uuid, client_id: Uuid) -> AppResult<()> {
+    let core = expect_context::<CoreState>();
+
+    match core.leave_presence(object_id, client_id).await {
+        Ok(()) => {
+            info!("leave_ok");
+            Ok(())
+        }
+        Err(e) => {
+            error!(error = %e, "leave_failed");
+            Err(e.into())
+        }
+    }
+}
@@ -0,0 +1,82 @@
+//! read-only server functions of the public tournament view
+//!
+//! The public route tree is not authenticated. Therefore this module must only contain
+//! server functions, which read data visible to spectators anyway.
+
+use crate::error::AppResult;
+#[cfg(any(feature = "ssr", feature = "test-mock"))]
+use app_core::CoreState;
+use app_core::{DisplayBoard, EntrantSchedule, PublicTournamentView};
+use leptos::prelude::*;
+#[cfg(not(feature = "test-mock"))]
+use tracing::instrument;
+use uuid::Uuid;
+
+#[cfg(not(feature = "test-mock"))]
+#[server(client = crate::version::VersionedClient)]
+#[instrument(
+    name = "public_tournament.load",
+    skip_all,
+    fields(id = %id)
+)]
+pub async fn load_public_tournament(id: Uuid) -> AppResult<Option<PublicTournamentView>> {
+    load_public_tournament_inner(id).await
+}
+
+#[cfg(feature = "test-mock")]
+pub async fn load_public_tournament(id: Uuid) -> AppResult<Option<PublicTournamentView>> {
+    load_public_tournament_inner(id).await
+}
+
+#[cfg(any(feature = "ssr", feature = "test-mock"))]
+async fn load_public_tournament_inner(id: Uuid) -> AppResult<Option<PublicTournamentView>> {
+    let core = expect_context::<CoreState>();
+    let view = core.load_public_tournament(id).await?;
+    Ok(view)
+}
+
+#[cfg(not(feature = "test-mock"))]
+#[server(client = crate::version::VersionedClient)]
+#[instrument(
+    name = "public_tournament.entrant_schedule",
+    skip_all,
+    fields(entrant_id = %entrant_id)
+)]
+pub async fn load_entrant_schedule(entrant_id: Uuid) -> AppResult<Option<EntrantSchedule>> {
+    load_entrant_schedule_inner(entrant_id).await
+}
+
+#[cfg(feature = "test-mock")]
+pub async fn load_entrant_schedule(entrant_id: Uuid) -> AppResult<Option<EntrantSchedule>> {
+    load_entrant_schedule_inner(entrant_id).await
+}
+
+#[cfg(any(feature = "ssr", feature = "test-mock"))]
+async fn load_entrant_schedule_inner(entrant_id: Uuid) -> AppResult<Option<EntrantSchedule>> {
+    let core = expect_context::<CoreState>();
+    let schedule = core.load_entrant_schedule(entrant_id).await?;
+    Ok(schedule)
+}
+
+#[cfg(not(feature = "test-mock"))]
+#[server(client = crate::version::VersionedClient)]
+#[instrument(
+    name = "public_tournament.display_board",
+    skip_all,
+    fields(id = %id)
+)]
+pub async fn load_display_board(id: Uuid) -> AppResult<Option<DisplayBoard>> {
+    load_display_board_inner(id).await
+}
+
+#[cfg(feature = "test-mock")]
+pub async fn load_display_board(id: Uuid) -> AppResult<Option<DisplayBoard>> {
+    load_display_board_inner(id).await
+}
+
+#[cfg(any(feature = "ssr", feature = "test-mock"))]
+async fn load_display_board_inner(id: Uuid) -> AppResult<Option<DisplayBoard>> {
+    let core = expect_context::<CoreState>();
+    let board = core.load_display_board(id).await?;
+    Ok(board)
+}
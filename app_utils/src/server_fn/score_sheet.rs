@@ -0,0 +1,32 @@
+//! server functions for score sheets of matches
+
+use crate::error::AppResult;
+#[cfg(any(feature = "ssr", feature = "test-mock"))]
+use app_core::CoreState;
+use app_core::ScoreSheet;
+use leptos::prelude::*;
+use tracing::instrument;
+use uuid::Uuid;
+
+#[cfg(not(feature = "test-mock"))]
+#[server(client = crate::version::VersionedClient)]
+#[instrument(
+    name = "score_sheet.load",
+    skip_all,
+    fields(match_id = %match_id)
+)]
+pub async fn load_score_sheet(match_id: Uuid) -> AppResult<Option<ScoreSheet>> {
+    load_score_sheet_inner(match_id).await
+}
+
+#[cfg(feature = "test-mock")]
+pub async fn load_score_sheet(match_id: Uuid) -> AppResult<Option<ScoreSheet>> {
+    load_score_sheet_inner(match_id).await
+}
+
+#[cfg(any(feature = "ssr", feature = "test-mock"))]
+async fn load_score_sheet_inner(match_id: Uuid) -> AppResult<Option<ScoreSheet>> {
+    let core = expect_context::<CoreState>();
+    let sheet = core.load_score_sheet(match_id).await?;
+    Ok(sheet)
+}
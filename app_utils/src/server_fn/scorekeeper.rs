@@ -0,0 +1,222 @@
+//! server functions for access links of scorekeepers
+
+#[cfg(any(feature = "ssr", feature = "test-mock"))]
+use crate::error::AppError;
+use crate::error::AppResult;
+#[cfg(any(feature = "ssr", feature = "test-mock"))]
+use app_core::{CoreState, utils::id_version::IdVersion};
+use app_core::{Match, ScorekeeperAccess, ScorekeeperGrant, ScorekeeperMatch, ScorekeeperToken};
+#[cfg(any(feature = "ssr", feature = "test-mock"))]
+use chrono::{Duration, Utc};
+use leptos::{prelude::*, server_fn::codec::Json};
+use serde::{Deserialize, Serialize};
+use tracing::instrument;
+#[cfg(any(feature = "ssr", feature = "test-mock"))]
+use tracing::{error, info};
+use uuid::Uuid;
+
+/// newly created token with the secret of its access link, which is shown only once
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct CreatedScorekeeperToken {
+    pub token: ScorekeeperToken,
+    pub secret: String,
+}
+
+#[cfg(not(feature = "test-mock"))]
+#[server(client = crate::version::VersionedClient)]
+#[instrument(
+    name = "scorekeeper.list",
+    skip_all,
+    fields(tournament_id = %tournament_id, include_revoked)
+)]
+pub async fn list_scorekeeper_tokens(
+    tournament_id: Uuid,
+    include_revoked: bool,
+) -> AppResult<Vec<ScorekeeperToken>> {
+    list_scorekeeper_tokens_inner(tournament_id, include_revoked).await
+}
+
+#[cfg(feature = "test-mock")]
+pub async fn list_scorekeeper_tokens(
+    tournament_id: Uuid,
+    include_revoked: bool,
+) -> AppResult<Vec<ScorekeeperToken>> {
+    list_scorekeeper_tokens_inner(tournament_id, include_revoked).await
+}
+
+#[cfg(any(feature = "ssr", feature = "test-mock"))]
+async fn list_scorekeeper_tokens_inner(
+    tournament_id: Uuid,
+    include_revoked: bool,
+) -> AppResult<Vec<ScorekeeperToken>> {
+    let core = expect_context::<CoreState>().as_scorekeeper_token_state(tournament_id);
+    let tokens = core.list_tokens(include_revoked).await?;
+    Ok(tokens)
+}
+
+#[server(client = crate::version::VersionedClient, input = Json, output = Json)]
+#[instrument(
+    name = "scorekeeper.create",
+    skip_all,
+    fields(
+        tournament_id = %tournament_id,
+        label = %label,
+        grants = ?grants,
+        valid_for_hours = ?valid_for_hours
+    )
+)]
+pub async fn create_scorekeeper_token(
+    tournament_id: Uuid,
+    label: String,
+    grants: Vec<ScorekeeperGrant>,
+    valid_for_hours: Option<u32>,
+) -> AppResult<CreatedScorekeeperToken> {
+    create_scorekeeper_token_inner(tournament_id, label, grants, valid_for_hours).await
+}
+
+#[cfg(any(feature = "ssr", feature = "test-mock"))]
+pub async fn create_scorekeeper_token_inner(
+    tournament_id: Uuid,
+    label: String,
+    grants: Vec<ScorekeeperGrant>,
+    valid_for_hours: Option<u32>,
+) -> AppResult<CreatedScorekeeperToken> {
+    let mut core = expect_context::<CoreState>().as_scorekeeper_token_state(tournament_id);
+    core.get_mut()
+        .set_label(label)
+        .set_grants(grants)
+        .set_expires_at(valid_for_hours.map(|h| Utc::now() + Duration::hours(h as i64)));
+
+    match core.create().await {
+        Ok((token, secret)) => {
+            info!(saved_id = %token.get_id(), "create_ok");
+            Ok(CreatedScorekeeperToken {
+                token: token.clone(),
+                secret,
+            })
+        }
+        Err(e) => {
+            error!(error = %e, "create_failed");
+            Err(e.into())
+        }
+    }
+}
+
+#[server(client = crate::version::VersionedClient)]
+#[instrument(
+    name = "scorekeeper.revoke",
+    skip_all,
+    fields(tournament_id = %tournament_id, id = %id, version = version)
+)]
+pub async fn revoke_scorekeeper_token(
+    tournament_id: Uuid,
+    id: Uuid,
+    version: u32,
+) -> AppResult<ScorekeeperToken> {
+    revoke_scorekeeper_token_inner(tournament_id, id, version).await
+}
+
+#[cfg(any(feature = "ssr", feature = "test-mock"))]
+pub async fn revoke_scorekeeper_token_inner(
+    tournament_id: Uuid,
+    id: Uuid,
+    version: u32,
+) -> AppResult<ScorekeeperToken> {
+    let mut core = expect_context::<CoreState>().as_scorekeeper_token_state(tournament_id);
+    if core.load(id).await?.is_none() {
+        return Err(AppError::ResourceNotFound(
+            "Scorekeeper Token".to_string(),
+            id,
+        ));
+    }
+    // revoke the version the user has seen (optimistic locking)
+    core.get_mut()
+        .set_id_version(IdVersion::new(id, Some(version)));
+
+    match core.revoke().await {
+        Ok(revoked) => {
+            info!(revoked_id = %revoked.get_id(), "revoke_ok");
+            Ok(revoked.clone())
+        }
+        Err(e) => {
+            error!(error = %e, "revoke_failed");
+            Err(e.into())
+        }
+    }
+}
+
+// never log the secret of the access link
+#[cfg(not(feature = "test-mock"))]
+#[server(client = crate::version::VersionedClient)]
+#[instrument(name = "scorekeeper.access", skip_all)]
+pub async fn load_scorekeeper_access(secret: String) -> AppResult<Option<ScorekeeperAccess>> {
+    load_scorekeeper_access_inner(secret).await
+}
+
+#[cfg(feature = "test-mock")]
+pub async fn load_scorekeeper_access(secret: String) -> AppResult<Option<ScorekeeperAccess>> {
+    load_scorekeeper_access_inner(secret).await
+}
+
+#[cfg(any(feature = "ssr", feature = "test-mock"))]
+async fn load_scorekeeper_access_inner(secret: String) -> AppResult<Option<ScorekeeperAccess>> {
+    let core = expect_context::<CoreState>();
+    let access = core.load_scorekeeper_access(&secret).await?;
+    Ok(access)
+}
+
+// never log the secret of the access link
+#[server(client = crate::version::VersionedClient, input = Json, output = Json)]
+#[instrument(name = "scorekeeper.list_matches", skip_all, fields(grant = ?grant))]
+pub async fn list_scorekeeper_matches(
+    secret: String,
+    grant: ScorekeeperGrant,
+) -> AppResult<Option<Vec<ScorekeeperMatch>>> {
+    list_scorekeeper_matches_inner(secret, grant).await
+}
+
+#[cfg(any(feature = "ssr", feature = "test-mock"))]
+pub async fn list_scorekeeper_matches_inner(
+    secret: String,
+    grant: ScorekeeperGrant,
+) -> AppResult<Option<Vec<ScorekeeperMatch>>> {
+    let core = expect_context::<CoreState>();
+    let matches = core.list_scorekeeper_matches(&secret, grant).await?;
+    Ok(matches)
+}
+
+// never log the secret of the access link
+#[server(client = crate::version::VersionedClient, input = Json, output = Json)]
+#[instrument(name = "scorekeeper.enter_result", skip_all, fields(match_id = %match_id))]
+pub async fn enter_scorekeeper_result(
+    secret: String,
+    match_id: Uuid,
+    score_a: Vec<u16>,
+    score_b: Vec<u16>,
+) -> AppResult<Match> {
+    enter_scorekeeper_result_inner(secret, match_id, score_a, score_b).await
+}
+
+#[cfg(any(feature = "ssr", feature = "test-mock"))]
+pub async fn enter_scorekeeper_result_inner(
+    secret: String,
+    match_id: Uuid,
+    score_a: Vec<u16>,
+    score_b: Vec<u16>,
+) -> AppResult<Match> {
+    let core = expect_context::<CoreState>();
+    match core
+        .enter_scorekeeper_match_result(&secret, match_id, score_a, score_b)
+        .await
+    {
+        Ok(Some(m)) => {
+            info!(match_id = %m.get_id(), "enter_result_ok");
+            Ok(m)
+        }
+        Ok(None) => Err(AppError::ResourceNotFound("Match".to_string(), match_id)),
+        Err(e) => {
+            error!(error = %e, "enter_result_failed");
+            Err(e.into())
+        }
+    }
+}
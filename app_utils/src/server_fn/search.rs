@@ -0,0 +1,27 @@
+//! server functions for the global search
+
+use crate::error::AppResult;
+#[cfg(any(feature = "ssr", feature = "test-mock"))]
+use app_core::CoreState;
+use app_core::SearchHit;
+use leptos::prelude::*;
+use tracing::instrument;
+
+#[cfg(not(feature = "test-mock"))]
+#[server(client = crate::version::VersionedClient)]
+#[instrument(name = "search.all", skip_all, fields(term = %term))]
+pub async fn search_all(term: String) -> AppResult<Vec<SearchHit>> {
+    search_all_inner(term).await
+}
+
+#[cfg(feature = "test-mock")]
+pub async fn search_all(term: String) -> AppResult<Vec<SearchHit>> {
+    search_all_inner(term).await
+}
+
+#[cfg(any(feature = "ssr", feature = "test-mock"))]
+async fn search_all_inner(term: String) -> AppResult<Vec<SearchHit>> {
+    let core = expect_context::<CoreState>().as_search_state();
+    let hits = core.search_all(&term).await?;
+    Ok(hits)
+}
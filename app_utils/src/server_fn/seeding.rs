@@ -0,0 +1,118 @@
+//! server functions for group seeding of the first stage
+
+use crate::error::AppResult;
+#[cfg(any(feature = "ssr", feature = "test-mock"))]
+use app_core::CoreState;
+use app_core::{GroupSeeding, SeedingStrategy};
+use leptos::{prelude::*, server_fn::codec::Json};
+use tracing::instrument;
+#[cfg(any(feature = "ssr", feature = "test-mock"))]
+use tracing::{error, info};
+use uuid::Uuid;
+
+/// Load the group seeding of the first stage. Without `strategy` the saved seeding is loaded,
+/// otherwise the entrants are seeded again with `strategy`.
+#[cfg(not(feature = "test-mock"))]
+#[server(client = crate::version::VersionedClient, input = Json, output = Json)]
+#[instrument(
+    name = "seeding.load",
+    skip_all,
+    fields(tournament_id = %tournament_id, strategy = ?strategy)
+)]
+pub async fn load_group_seeding(
+    tournament_id: Uuid,
+    strategy: Option<SeedingStrategy>,
+) -> AppResult<Option<GroupSeeding>> {
+    load_group_seeding_inner(tournament_id, strategy).await
+}
+
+#[cfg(feature = "test-mock")]
+pub async fn load_group_seeding(
+    tournament_id: Uuid,
+    strategy: Option<SeedingStrategy>,
+) -> AppResult<Option<GroupSeeding>> {
+    load_group_seeding_inner(tournament_id, strategy).await
+}
+
+#[cfg(any(feature = "ssr", feature = "test-mock"))]
+async fn load_group_seeding_inner(
+    tournament_id: Uuid,
+    strategy: Option<SeedingStrategy>,
+) -> AppResult<Option<GroupSeeding>> {
+    let core = expect_context::<CoreState>();
+    let seeding = match strategy {
+        Some(strategy) => core.seed_first_stage(tournament_id, strategy).await?,
+        None => core.load_group_seeding(tournament_id).await?,
+    };
+    Ok(seeding)
+}
+
+/// Save the group seeding of the first stage after the director moved entrants between groups.
+#[server(client = crate::version::VersionedClient, input = Json, output = Json)]
+#[instrument(
+    name = "seeding.save",
+    skip_all,
+    fields(tournament_id = %seeding.tournament_id, strategy = %seeding.strategy)
+)]
+pub async fn save_group_seeding(seeding: GroupSeeding) -> AppResult<Option<GroupSeeding>> {
+    save_group_seeding_inner(seeding).await
+}
+
+#[cfg(any(feature = "ssr", feature = "test-mock"))]
+pub async fn save_group_seeding_inner(seeding: GroupSeeding) -> AppResult<Option<GroupSeeding>> {
+    let core = expect_context::<CoreState>();
+
+    match core.save_group_seeding(seeding).await {
+        Ok(seeding) => {
+            info!(found = seeding.is_some(), "save_ok");
+            Ok(seeding)
+        }
+        Err(e) => {
+            error!(error = %e, "save_failed");
+            Err(e.into())
+        }
+    }
+}
+
+/// Assign several entrants of the group seeding of the first stage to `group`. Returns the
+/// changed seeding.
+#[server(client = crate::version::VersionedClient, input = Json, output = Json)]
+#[instrument(
+    name = "seeding.assign_entrants",
+    skip_all,
+    fields(
+        tournament_id = %seeding.tournament_id,
+        num_entrants = entrant_ids.len(),
+        group = group
+    )
+)]
+pub async fn assign_entrants_to_group(
+    seeding: GroupSeeding,
+    entrant_ids: Vec<Uuid>,
+    group: usize,
+) -> AppResult<Option<GroupSeeding>> {
+    assign_entrants_to_group_inner(seeding, entrant_ids, group).await
+}
+
+#[cfg(any(feature = "ssr", feature = "test-mock"))]
+pub async fn assign_entrants_to_group_inner(
+    seeding: GroupSeeding,
+    entrant_ids: Vec<Uuid>,
+    group: usize,
+) -> AppResult<Option<GroupSeeding>> {
+    let core = expect_context::<CoreState>();
+
+    match core
+        .assign_entrants_to_group(seeding, &entrant_ids, group)
+        .await
+    {
+        Ok(seeding) => {
+            info!(found = seeding.is_some(), "assign_ok");
+            Ok(seeding)
+        }
+        Err(e) => {
+            error!(error = %e, "assign_failed");
+            Err(e.into())
+        }
+    }
+}
@@ -0,0 +1,91 @@
+//! server functions for shift log of tournament desk
+
+use crate::error::AppResult;
+use app_core::ShiftLogEntry;
+#[cfg(any(feature = "ssr", feature = "test-mock"))]
+use app_core::{
+    CoreState,
+    utils::{id_version::IdVersion, traits::ObjectIdVersion},
+};
+use leptos::{prelude::*, server_fn::codec::Json};
+use tracing::instrument;
+#[cfg(any(feature = "ssr", feature = "test-mock"))]
+use tracing::{error, info};
+use uuid::Uuid;
+
+#[cfg(not(feature = "test-mock"))]
+#[server(client = crate::version::VersionedClient)]
+#[instrument(
+    name = "shift_log.list",
+    skip_all,
+    fields(tournament_id = %tournament_id)
+)]
+pub async fn list_shift_log_entries(
+    tournament_id: Uuid,
+    pinned_only: bool,
+    limit: Option<usize>,
+) -> AppResult<Vec<ShiftLogEntry>> {
+    list_shift_log_entries_inner(tournament_id, pinned_only, limit).await
+}
+
+#[cfg(feature = "test-mock")]
+pub async fn list_shift_log_entries(
+    tournament_id: Uuid,
+    pinned_only: bool,
+    limit: Option<usize>,
+) -> AppResult<Vec<ShiftLogEntry>> {
+    list_shift_log_entries_inner(tournament_id, pinned_only, limit).await
+}
+
+#[cfg(any(feature = "ssr", feature = "test-mock"))]
+async fn list_shift_log_entries_inner(
+    tournament_id: Uuid,
+    pinned_only: bool,
+    limit: Option<usize>,
+) -> AppResult<Vec<ShiftLogEntry>> {
+    let core = expect_context::<CoreState>().as_shift_log_state(tournament_id);
+    let entries = core.list_entries(pinned_only, limit).await?;
+    Ok(entries)
+}
+
+#[server(client = crate::version::VersionedClient, input = Json, output = Json)]
+#[instrument(
+    name = "shift_log.save",
+    skip_all,
+    fields(
+        id = %entry.get_id(),
+        version = ?entry.get_version(),
+        tournament_id = %entry.get_tournament_id(),
+        pinned = entry.is_pinned(),
+    )
+)]
+pub async fn save_shift_log_entry(entry: ShiftLogEntry) -> AppResult<ShiftLogEntry> {
+    save_shift_log_entry_inner(entry).await
+}
+
+#[cfg(any(feature = "ssr", feature = "test-mock"))]
+pub async fn save_shift_log_entry_inner(entry: ShiftLogEntry) -> AppResult<ShiftLogEntry> {
+    let mut core = expect_context::<CoreState>().as_shift_log_state(entry.get_tournament_id());
+
+    match entry.get_id_version() {
+        IdVersion::Existing(..) => {
+            info!("saving_update");
+        }
+        IdVersion::NewWithId(..) => {
+            info!("saving_create");
+        }
+    }
+
+    *core.get_mut() = entry;
+
+    match core.save().await {
+        Ok(saved) => {
+            info!(saved_id = %saved.get_id(), "save_ok");
+            Ok(saved.clone())
+        }
+        Err(e) => {
+            error!(error = %e, "save_failed");
+            Err(e.into())
+        }
+    }
+}
@@ -1,11 +1,11 @@
 //! Sport Config Server Functions Module
 
 use crate::error::AppResult;
-use app_core::SportConfig;
 #[cfg(any(feature = "ssr", feature = "test-mock"))]
+use app_core::{CoreState, utils::traits::ObjectIdVersion};
 use app_core::{
-    CoreState,
-    utils::{id_version::IdVersion, traits::ObjectIdVersion},
+    SportConfig,
+    utils::{id_version::IdVersion, list_order::ListOrder},
 };
 use leptos::{prelude::*, server_fn::codec::Json};
 use tracing::instrument;
@@ -14,7 +14,7 @@ use tracing::{error, info};
 use uuid::Uuid;
 
 #[cfg(not(feature = "test-mock"))]
-#[server]
+#[server(client = crate::version::VersionedClient)]
 #[instrument(
     name = "sport_config.load",
     skip_all,
@@ -37,39 +37,73 @@ pub async fn load_sport_config_inner(id: Uuid) -> AppResult<Option<SportConfig>>
 }
 
 #[cfg(not(feature = "test-mock"))]
-#[server]
+#[server(client = crate::version::VersionedClient)]
 #[instrument(name = "sport_config.list_sport_config_ids", skip_all)]
 pub async fn list_sport_config_ids(
     sport_id: Uuid,
     name: String,
+    include_archived: bool,
     limit: Option<usize>,
+    order: ListOrder<SportConfig>,
 ) -> AppResult<Vec<Uuid>> {
-    list_sport_configs_inner(sport_id, name, limit).await
+    list_sport_configs_inner(sport_id, name, include_archived, limit, order).await
 }
 
 #[cfg(feature = "test-mock")]
 pub async fn list_sport_config_ids(
     sport_id: Uuid,
     name: String,
+    include_archived: bool,
     limit: Option<usize>,
+    order: ListOrder<SportConfig>,
 ) -> AppResult<Vec<Uuid>> {
-    list_sport_configs_inner(sport_id, name, limit).await
+    list_sport_configs_inner(sport_id, name, include_archived, limit, order).await
 }
 
 #[cfg(any(feature = "ssr", feature = "test-mock"))]
 async fn list_sport_configs_inner(
     sport_id: Uuid,
     name: String,
+    include_archived: bool,
     limit: Option<usize>,
+    order: ListOrder<SportConfig>,
 ) -> AppResult<Vec<Uuid>> {
     let core = expect_context::<CoreState>().as_sport_config_state();
     let configs = core
-        .list_sport_config_ids(sport_id, Some(&name), limit)
+        .list_sport_config_ids(sport_id, Some(&name), include_archived, limit, &order)
         .await?;
     Ok(configs)
 }
 
-#[server(input = Json, output = Json)]
+#[cfg(not(feature = "test-mock"))]
+#[server(client = crate::version::VersionedClient)]
+#[instrument(name = "sport_config.list_sport_configs", skip_all)]
+pub async fn list_sport_configs(
+    sport_id: Uuid,
+    limit: Option<usize>,
+) -> AppResult<Vec<SportConfig>> {
+    list_sport_configs_of_sport_inner(sport_id, limit).await
+}
+
+#[cfg(feature = "test-mock")]
+pub async fn list_sport_configs(
+    sport_id: Uuid,
+    limit: Option<usize>,
+) -> AppResult<Vec<SportConfig>> {
+    list_sport_configs_of_sport_inner(sport_id, limit).await
+}
+
+#[cfg(any(feature = "ssr", feature = "test-mock"))]
+async fn list_sport_configs_of_sport_inner(
+    sport_id: Uuid,
+    limit: Option<usize>,
+) -> AppResult<Vec<SportConfig>> {
+    let core = expect_context::<CoreState>().as_sport_config_state();
+    let configs = core.list_sport_configs(sport_id, limit).await?;
+    Ok(configs)
+}
+
+#[server(client = crate::version::VersionedClient, input = Json, output = Json)]
 #[instrument(
     name = "sport_config.save",
     skip_all,
@@ -114,3 +148,59 @@ pub async fn save_sport_config_inner(sport_config: SportConfig) -> AppResult<Spo
         }
     }
 }
+
+#[server(client = crate::version::VersionedClient)]
+#[instrument(
+    name = "sport_config.archive",
+    skip_all,
+    fields(id = %id, version = version)
+)]
+pub async fn archive_sport_config(id: Uuid, version: u32) -> AppResult<SportConfig> {
+    archive_sport_config_inner(id, version).await
+}
+
+#[cfg(any(feature = "ssr", feature = "test-mock"))]
+pub async fn archive_sport_config_inner(id: Uuid, version: u32) -> AppResult<SportConfig> {
+    let mut core = expect_context::<CoreState>().as_sport_config_state();
+    // archiving only requires id and version for optimistic locking
+    core.get_mut()
+        .set_id_version(IdVersion::new(id, Some(version)));
+
+    match core.archive().await {
+        Ok(archived) => {
+            info!(archived_id = %archived.get_id(), "archive_ok");
+            Ok(archived.clone())
+        }
+        Err(e) => {
+            error!(error = %e, "archive_failed");
+            Err(e.into())
+        }
+    }
+}
+
+/// Archive several sport configs in one transaction: either all of them are archived or none.
+#[server(client = crate::version::VersionedClient, input = Json, output = Json)]
+#[instrument(
+    name = "sport_config.archive_many",
+    skip_all,
+    fields(num_configs = configs.len())
+)]
+pub async fn archive_sport_configs(configs: Vec<IdVersion>) -> AppResult<Vec<SportConfig>> {
+    archive_sport_configs_inner(configs).await
+}
+
+#[cfg(any(feature = "ssr", feature = "test-mock"))]
+pub async fn archive_sport_configs_inner(configs: Vec<IdVersion>) -> AppResult<Vec<SportConfig>> {
+    let core = expect_context::<CoreState>().as_sport_config_state();
+
+    match core.archive_many(&configs).await {
+        Ok(archived) => {
+            info!(num_archived = archived.len(), "archive_many_ok");
+            Ok(archived)
+        }
+        Err(e) => {
+            error!(error = %e, "archive_many_failed");
+            Err(e.into())
+        }
+    }
+}
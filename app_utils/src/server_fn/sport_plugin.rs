@@ -0,0 +1,66 @@
+//! Sport Plugin Server Functions Module
+
+use crate::error::AppResult;
+use app_core::SportPluginParity;
+#[cfg(any(feature = "ssr", feature = "test-mock"))]
+use app_core::{CoreState, utils::traits::ObjectIdVersion};
+use leptos::{prelude::*, server_fn::codec::Json};
+#[cfg(any(feature = "ssr", feature = "test-mock"))]
+use tracing::error;
+use tracing::instrument;
+use uuid::Uuid;
+
+#[cfg(not(feature = "test-mock"))]
+#[server(client = crate::version::VersionedClient)]
+#[instrument(name = "sport_plugin.list_sport_plugin_ids", skip_all)]
+pub async fn list_sport_plugin_ids() -> AppResult<Vec<Uuid>> {
+    list_sport_plugin_ids_inner().await
+}
+
+#[cfg(feature = "test-mock")]
+pub async fn list_sport_plugin_ids() -> AppResult<Vec<Uuid>> {
+    list_sport_plugin_ids_inner().await
+}
+
+/// ids of the sport plugins currently registered at the server; plugins may be deregistered
+/// after start, while clients still know them
+#[cfg(any(feature = "ssr", feature = "test-mock"))]
+async fn list_sport_plugin_ids_inner() -> AppResult<Vec<Uuid>> {
+    let core = expect_context::<CoreState>().as_sport_plugin_state();
+    let ids = core
+        .list_sports()
+        .iter()
+        .map(|plugin| plugin.get_id_version().get_id())
+        .collect();
+    Ok(ids)
+}
+
+#[cfg(not(feature = "test-mock"))]
+#[server(client = crate::version::VersionedClient, input = Json, output = Json)]
+#[instrument(name = "sport_plugin.check_parity", skip_all)]
+pub async fn check_sport_plugin_parity(
+    client_plugins: Vec<(Uuid, u32)>,
+) -> AppResult<SportPluginParity> {
+    check_sport_plugin_parity_inner(client_plugins).await
+}
+
+#[cfg(feature = "test-mock")]
+pub async fn check_sport_plugin_parity(
+    client_plugins: Vec<(Uuid, u32)>,
+) -> AppResult<SportPluginParity> {
+    check_sport_plugin_parity_inner(client_plugins).await
+}
+
+/// Compare sport id and plugin version of the plugins of a client with the plugins of the
+/// server. Clients call it once after start; drift is logged by server and client.
+#[cfg(any(feature = "ssr", feature = "test-mock"))]
+async fn check_sport_plugin_parity_inner(
+    client_plugins: Vec<(Uuid, u32)>,
+) -> AppResult<SportPluginParity> {
+    let core = expect_context::<CoreState>().as_sport_plugin_state();
+    let parity = core.check_plugin_parity(&client_plugins);
+    if !parity.is_ok() {
+        error!(%parity, "sport_plugin_drift");
+    }
+    Ok(parity)
+}
@@ -1,20 +1,22 @@
 //! server functions for stage entities
 
+#[cfg(any(feature = "ssr", feature = "test-mock"))]
+use crate::error::AppError;
 use crate::error::AppResult;
-use app_core::Stage;
 #[cfg(any(feature = "ssr", feature = "test-mock"))]
 use app_core::{
     CoreState,
     utils::{id_version::IdVersion, traits::ObjectIdVersion},
 };
-use leptos::prelude::*;
+use app_core::{RescheduledMatch, Stage, StageTimingDashboard};
+use leptos::{prelude::*, server_fn::codec::Json};
 use tracing::instrument;
 #[cfg(any(feature = "ssr", feature = "test-mock"))]
 use tracing::{error, info};
 use uuid::Uuid;
 
 #[cfg(not(feature = "test-mock"))]
-#[server]
+#[server(client = crate::version::VersionedClient)]
 #[instrument(
     name = "stage.load_by_id",
     skip_all,
@@ -37,7 +39,7 @@ pub async fn load_stage_by_id_inner(tournament_id: Uuid, id: Uuid) -> AppResult<
 }
 
 #[cfg(not(feature = "test-mock"))]
-#[server]
+#[server(client = crate::version::VersionedClient)]
 #[instrument(
     name = "stage.load_by_number",
     skip_all,
@@ -63,7 +65,7 @@ pub async fn load_stage_by_number_inner(
 }
 
 #[cfg(not(feature = "test-mock"))]
-#[server]
+#[server(client = crate::version::VersionedClient)]
 #[instrument(name = "stage.list_all_of_tournament", skip_all)]
 pub async fn list_stage_ids_of_tournament(tournament_id: Uuid) -> AppResult<Vec<(Uuid, u32)>> {
     list_stage_ids_of_tournament_inner(tournament_id).await
@@ -81,7 +83,7 @@ async fn list_stage_ids_of_tournament_inner(tournament_id: Uuid) -> AppResult<Ve
     Ok(stages)
 }
 
-#[server]
+#[server(client = crate::version::VersionedClient)]
 #[instrument(
     name = "stage.save",
     skip_all,
@@ -127,3 +129,58 @@ pub async fn save_stage_inner(stage: Stage) -> AppResult<Stage> {
         }
     }
 }
+
+#[cfg(not(feature = "test-mock"))]
+#[server(client = crate::version::VersionedClient)]
+#[instrument(
+    name = "stage.timing_dashboard",
+    skip_all,
+    fields(stage_id = %stage_id)
+)]
+pub async fn load_stage_timing_dashboard(stage_id: Uuid) -> AppResult<StageTimingDashboard> {
+    load_stage_timing_dashboard_inner(stage_id).await
+}
+
+#[cfg(feature = "test-mock")]
+pub async fn load_stage_timing_dashboard(stage_id: Uuid) -> AppResult<StageTimingDashboard> {
+    load_stage_timing_dashboard_inner(stage_id).await
+}
+
+#[cfg(any(feature = "ssr", feature = "test-mock"))]
+pub async fn load_stage_timing_dashboard_inner(stage_id: Uuid) -> AppResult<StageTimingDashboard> {
+    let core = expect_context::<CoreState>();
+    core.load_stage_timing_dashboard(stage_id)
+        .await?
+        .ok_or_else(|| AppError::ResourceNotFound("Stage".to_string(), stage_id))
+}
+
+/// Re-place the pending matches of stage on the stations, which become free first.
+#[server(client = crate::version::VersionedClient, input = Json, output = Json)]
+#[instrument(
+    name = "stage.compress_schedule",
+    skip_all,
+    fields(stage_id = %stage_id)
+)]
+pub async fn compress_stage_schedule(
+    stage_id: Uuid,
+) -> AppResult<(Vec<RescheduledMatch>, StageTimingDashboard)> {
+    compress_stage_schedule_inner(stage_id).await
+}
+
+#[cfg(any(feature = "ssr", feature = "test-mock"))]
+pub async fn compress_stage_schedule_inner(
+    stage_id: Uuid,
+) -> AppResult<(Vec<RescheduledMatch>, StageTimingDashboard)> {
+    let core = expect_context::<CoreState>();
+    match core.compress_stage_schedule(stage_id).await {
+        Ok(Some(compressed)) => {
+            info!(num_rescheduled = compressed.0.len(), "compress_ok");
+            Ok(compressed)
+        }
+        Ok(None) => Err(AppError::ResourceNotFound("Stage".to_string(), stage_id)),
+        Err(e) => {
+            error!(error = %e, "compress_failed");
+            Err(e.into())
+        }
+    }
+}
@@ -1,21 +1,27 @@
 //! server functions for tournament base entities
 
+#[cfg(any(feature = "ssr", feature = "test-mock"))]
+use crate::error::AppError;
 use crate::error::AppResult;
 // IdVersion Import wird hier nicht mehr explizit benötigt, da der Client das Objekt fertig liefert
 #[cfg(any(feature = "ssr", feature = "test-mock"))]
 use app_core::{
-    CoreState,
-    utils::{id_version::IdVersion, traits::ObjectIdVersion},
+    CoreState, TournamentBaseCondition, TournamentExport, is_finishing_transition,
+    utils::{filter::Filter, traits::ObjectIdVersion},
+};
+use app_core::{
+    DayDashboard, ReadinessChecklist, StationSetup, TournamentBase, TournamentDiff,
+    TournamentDiffResult, TournamentDurationEstimate, TournamentState,
+    utils::{id_version::IdVersion, list_order::ListOrder},
 };
-use app_core::{TournamentBase, TournamentState};
-use leptos::prelude::*;
+use leptos::{prelude::*, server_fn::codec::Json};
 use tracing::instrument;
 #[cfg(any(feature = "ssr", feature = "test-mock"))]
-use tracing::{error, info};
+use tracing::{error, info, warn};
 use uuid::Uuid;
 
 #[cfg(not(feature = "test-mock"))]
-#[server]
+#[server(client = crate::version::VersionedClient)]
 #[instrument(
     name = "tournament_base.load",
     skip_all,
@@ -38,16 +44,27 @@ pub async fn load_tournament_base_inner(id: Uuid) -> AppResult<Option<Tournament
 }
 
 #[cfg(not(feature = "test-mock"))]
-#[server]
+#[server(client = crate::version::VersionedClient)]
 #[instrument(name = "tournament_base.list", skip_all)]
 pub async fn list_tournament_base_ids(
     sport_id: Uuid,
     name: String,
     state_filter: Option<TournamentState>,
     include_adhoc: bool,
+    include_archived: bool,
     limit: Option<usize>,
+    order: ListOrder<TournamentBase>,
 ) -> AppResult<Vec<Uuid>> {
-    list_tournament_base_ids_inner(sport_id, name, state_filter, include_adhoc, limit).await
+    list_tournament_base_ids_inner(
+        sport_id,
+        name,
+        state_filter,
+        include_adhoc,
+        include_archived,
+        limit,
+        order,
+    )
+    .await
 }
 
 #[cfg(feature = "test-mock")]
@@ -56,9 +73,20 @@ pub async fn list_tournament_base_ids(
     name: String,
     state_filter: Option<TournamentState>,
     include_adhoc: bool,
+    include_archived: bool,
     limit: Option<usize>,
+    order: ListOrder<TournamentBase>,
 ) -> AppResult<Vec<Uuid>> {
-    list_tournament_base_ids_inner(sport_id, name, state_filter, include_adhoc, limit).await
+    list_tournament_base_ids_inner(
+        sport_id,
+        name,
+        state_filter,
+        include_adhoc,
+        include_archived,
+        limit,
+        order,
+    )
+    .await
 }
 
 #[cfg(any(feature = "ssr", feature = "test-mock"))]
@@ -67,16 +95,29 @@ async fn list_tournament_base_ids_inner(
     name: String,
     state_filter: Option<TournamentState>,
     include_adhoc: bool,
+    include_archived: bool,
     limit: Option<usize>,
+    order: ListOrder<TournamentBase>,
 ) -> AppResult<Vec<Uuid>> {
     let core = expect_context::<CoreState>().as_tournament_base_state();
-    let configs = core
-        .list_tournament_base_ids(sport_id, Some(&name), state_filter, include_adhoc, limit)
-        .await?;
+    let filter = Filter::<TournamentBase>::new()
+        .with_if(
+            !sport_id.is_nil(),
+            TournamentBaseCondition::SportIs(sport_id),
+        )
+        .with(TournamentBaseCondition::NameContains(name))
+        .with_if(
+            state_filter.is_some(),
+            TournamentBaseCondition::StateIn(state_filter.into_iter().collect()),
+        )
+        .with_if(!include_adhoc, TournamentBaseCondition::NotAdhoc)
+        .with_if(!include_archived, TournamentBaseCondition::NotArchived)
+        .limit(limit);
+    let configs = core.list_tournament_base_ids(&filter, &order).await?;
     Ok(configs)
 }
 
-#[server]
+#[server(client = crate::version::VersionedClient)]
 #[instrument(
     name = "tournament_base.save",
     skip_all,
@@ -123,3 +164,502 @@ pub async fn save_tournament_base_inner(base: TournamentBase) -> AppResult<Tourn
         }
     }
 }
+
+/// Save all changes of an edited tournament in one transaction. Either all objects of the diff
+/// are saved or none of them; the result reports each object.
+#[server(client = crate::version::VersionedClient)]
+#[instrument(
+    name = "tournament_base.save_diff",
+    skip_all,
+    fields(
+        id = %diff.tournament_id,
+        has_base = diff.base.is_some(),
+        num_stages = diff.stages.len(),
+        num_groups = diff.groups.len(),
+    )
+)]
+pub async fn save_tournament_diff(diff: TournamentDiff) -> AppResult<TournamentDiffResult> {
+    save_tournament_diff_inner(diff).await
+}
+
+#[cfg(any(feature = "ssr", feature = "test-mock"))]
+pub async fn save_tournament_diff_inner(diff: TournamentDiff) -> AppResult<TournamentDiffResult> {
+    let core = expect_context::<CoreState>();
+
+    match core.save_tournament_diff(&diff).await {
+        Ok(result) if result.is_saved() => {
+            info!("save_diff_ok");
+            Ok(result)
+        }
+        Ok(result) => {
+            for e in result.errors() {
+                warn!(error = %e, "save_diff_rolled_back");
+            }
+            Ok(result)
+        }
+        Err(e) => {
+            error!(error = %e, "save_diff_failed");
+            Err(e.into())
+        }
+    }
+}
+
+#[server(client = crate::version::VersionedClient)]
+#[instrument(
+    name = "tournament_base.archive",
+    skip_all,
+    fields(id = %id, version = version)
+)]
+pub async fn archive_tournament(id: Uuid, version: u32) -> AppResult<TournamentBase> {
+    archive_tournament_inner(id, version).await
+}
+
+#[cfg(any(feature = "ssr", feature = "test-mock"))]
+pub async fn archive_tournament_inner(id: Uuid, version: u32) -> AppResult<TournamentBase> {
+    let mut core = expect_context::<CoreState>().as_tournament_base_state();
+    // archiving only requires id and version for optimistic locking
+    core.get_mut()
+        .set_id_version(IdVersion::new(id, Some(version)));
+
+    match core.archive().await {
+        Ok(archived) => {
+            info!(archived_id = %archived.get_id(), "archive_ok");
+            Ok(archived.clone())
+        }
+        Err(e) => {
+            error!(error = %e, "archive_failed");
+            Err(e.into())
+        }
+    }
+}
+
+/// Delete several draft tournaments with all their data in one transaction: either all of them
+/// are deleted or none. Returns the ids of the deleted tournaments.
+#[server(client = crate::version::VersionedClient, input = Json, output = Json)]
+#[instrument(
+    name = "tournament_base.delete_drafts",
+    skip_all,
+    fields(num_tournaments = tournaments.len())
+)]
+pub async fn delete_draft_tournaments(tournaments: Vec<IdVersion>) -> AppResult<Vec<Uuid>> {
+    delete_draft_tournaments_inner(tournaments).await
+}
+
+#[cfg(any(feature = "ssr", feature = "test-mock"))]
+pub async fn delete_draft_tournaments_inner(tournaments: Vec<IdVersion>) -> AppResult<Vec<Uuid>> {
+    let core = expect_context::<CoreState>().as_tournament_base_state();
+
+    match core.delete_drafts(&tournaments).await {
+        Ok(deleted) => {
+            info!(num_deleted = deleted.len(), "delete_drafts_ok");
+            Ok(deleted)
+        }
+        Err(e) => {
+            error!(error = %e, "delete_drafts_failed");
+            Err(e.into())
+        }
+    }
+}
+
+/// Purge a sandbox tournament with all its data. Returns the purged tournament as it was
+/// before purging.
+#[server(client = crate::version::VersionedClient)]
+#[instrument(
+    name = "tournament_base.purge_sandbox",
+    skip_all,
+    fields(id = %id)
+)]
+pub async fn purge_sandbox_tournament(id: Uuid) -> AppResult<TournamentBase> {
+    purge_sandbox_tournament_inner(id).await
+}
+
+#[cfg(any(feature = "ssr", feature = "test-mock"))]
+pub async fn purge_sandbox_tournament_inner(id: Uuid) -> AppResult<TournamentBase> {
+    let mut core = expect_context::<CoreState>().as_tournament_base_state();
+    let Some(purged) = core.load(id).await?.cloned() else {
+        return Err(AppError::ResourceNotFound("Tournament".to_string(), id));
+    };
+
+    match core.purge_sandbox().await {
+        Ok(()) => {
+            info!(purged_id = %id, "purge_sandbox_ok");
+            Ok(purged)
+        }
+        Err(e) => {
+            error!(error = %e, "purge_sandbox_failed");
+            Err(e.into())
+        }
+    }
+}
+
+#[cfg(not(feature = "test-mock"))]
+#[server(client = crate::version::VersionedClient)]
+#[instrument(
+    name = "tournament_base.readiness",
+    skip_all,
+    fields(id = %id)
+)]
+pub async fn load_tournament_readiness(id: Uuid) -> AppResult<ReadinessChecklist> {
+    load_tournament_readiness_inner(id).await
+}
+
+#[cfg(feature = "test-mock")]
+pub async fn load_tournament_readiness(id: Uuid) -> AppResult<ReadinessChecklist> {
+    load_tournament_readiness_inner(id).await
+}
+
+#[cfg(any(feature = "ssr", feature = "test-mock"))]
+pub async fn load_tournament_readiness_inner(id: Uuid) -> AppResult<ReadinessChecklist> {
+    let core = expect_context::<CoreState>();
+    core.load_readiness(id)
+        .await?
+        .ok_or_else(|| AppError::ResourceNotFound("Tournament".to_string(), id))
+}
+
+/// Estimate the duration of all stages of a tournament; `None`, if the sport of the
+/// tournament has no valid configuration to estimate the duration of a match.
+#[cfg(not(feature = "test-mock"))]
+#[server(client = crate::version::VersionedClient)]
+#[instrument(
+    name = "tournament_base.duration_estimate",
+    skip_all,
+    fields(id = %id)
+)]
+pub async fn load_tournament_duration_estimate(
+    id: Uuid,
+) -> AppResult<Option<TournamentDurationEstimate>> {
+    load_tournament_duration_estimate_inner(id).await
+}
+
+#[cfg(feature = "test-mock")]
+pub async fn load_tournament_duration_estimate(
+    id: Uuid,
+) -> AppResult<Option<TournamentDurationEstimate>> {
+    load_tournament_duration_estimate_inner(id).await
+}
+
+#[cfg(any(feature = "ssr", feature = "test-mock"))]
+pub async fn load_tournament_duration_estimate_inner(
+    id: Uuid,
+) -> AppResult<Option<TournamentDurationEstimate>> {
+    let core = expect_context::<CoreState>();
+    Ok(core.estimate_tournament_duration(id).await?)
+}
+
+#[cfg(not(feature = "test-mock"))]
+#[server(client = crate::version::VersionedClient)]
+#[instrument(
+    name = "tournament_base.distance",
+    skip_all,
+    fields(id = %tournament_id, from = %from_address_id)
+)]
+pub async fn load_tournament_distance_km(
+    tournament_id: Uuid,
+    from_address_id: Uuid,
+) -> AppResult<Option<f64>> {
+    load_tournament_distance_km_inner(tournament_id, from_address_id).await
+}
+
+#[cfg(feature = "test-mock")]
+pub async fn load_tournament_distance_km(
+    tournament_id: Uuid,
+    from_address_id: Uuid,
+) -> AppResult<Option<f64>> {
+    load_tournament_distance_km_inner(tournament_id, from_address_id).await
+}
+
+#[cfg(any(feature = "ssr", feature = "test-mock"))]
+pub async fn load_tournament_distance_km_inner(
+    tournament_id: Uuid,
+    from_address_id: Uuid,
+) -> AppResult<Option<f64>> {
+    let core = expect_context::<CoreState>().as_tournament_base_state();
+    let distance = core
+        .tournament_distance_km(tournament_id, from_address_id)
+        .await?;
+    Ok(distance)
+}
+
+#[cfg(not(feature = "test-mock"))]
+#[server(client = crate::version::VersionedClient)]
+#[instrument(
+    name = "tournament_base.day_dashboard",
+    skip_all,
+    fields(id = %id)
+)]
+pub async fn load_day_dashboard(id: Uuid) -> AppResult<DayDashboard> {
+    load_day_dashboard_inner(id).await
+}
+
+#[cfg(feature = "test-mock")]
+pub async fn load_day_dashboard(id: Uuid) -> AppResult<DayDashboard> {
+    load_day_dashboard_inner(id).await
+}
+
+#[cfg(any(feature = "ssr", feature = "test-mock"))]
+pub async fn load_day_dashboard_inner(id: Uuid) -> AppResult<DayDashboard> {
+    let core = expect_context::<CoreState>();
+    core.load_day_dashboard(id)
+        .await?
+        .ok_or_else(|| AppError::ResourceNotFound("Tournament".to_string(), id))
+}
+
+/// Ask all display boards of a tournament at the venue to reload.
+#[server(client = crate::version::VersionedClient)]
+#[instrument(
+    name = "tournament_base.refresh_display_boards",
+    skip_all,
+    fields(id = %id)
+)]
+pub async fn refresh_display_boards(id: Uuid) -> AppResult<()> {
+    refresh_display_boards_inner(id).await
+}
+
+#[cfg(any(feature = "ssr", feature = "test-mock"))]
+pub async fn refresh_display_boards_inner(id: Uuid) -> AppResult<()> {
+    let core = expect_context::<CoreState>();
+
+    match core.refresh_display_boards(id).await {
+        Ok(()) => {
+            info!("refresh_display_boards_ok");
+            Ok(())
+        }
+        Err(e) => {
+            error!(error = %e, "refresh_display_boards_failed");
+            Err(e.into())
+        }
+    }
+}
+
+/// Change the state of a tournament, e.g. publish or start it. Publishing and starting
+/// require a passed readiness checklist. Finishing generates the final report.
+#[server(client = crate::version::VersionedClient)]
+#[instrument(
+    name = "tournament_base.change_state",
+    skip_all,
+    fields(id = %id, version = version, state = %state)
+)]
+pub async fn change_tournament_state(
+    id: Uuid,
+    version: u32,
+    state: TournamentState,
+) -> AppResult<TournamentBase> {
+    change_tournament_state_inner(id, version, state).await
+}
+
+#[cfg(any(feature = "ssr", feature = "test-mock"))]
+pub async fn change_tournament_state_inner(
+    id: Uuid,
+    version: u32,
+    state: TournamentState,
+) -> AppResult<TournamentBase> {
+    let core_state = expect_context::<CoreState>();
+    let mut core = core_state.as_tournament_base_state();
+    let Some(previous_state) = core.load(id).await?.map(|t| t.get_tournament_state()) else {
+        return Err(AppError::ResourceNotFound("Tournament".to_string(), id));
+    };
+    // version of client is used for optimistic locking
+    core.get_mut()
+        .set_id_version(IdVersion::new(id, Some(version)))
+        .set_tournament_state(state);
+
+    match core.save().await {
+        Ok(saved) => {
+            info!(saved_id = %saved.get_id(), "change_state_ok");
+            let saved = saved.clone();
+            if is_finishing_transition(Some(previous_state), state) {
+                // the state change stands, even if the report could not be generated
+                if let Err(e) = report::generate_final_report(&core_state, id).await {
+                    warn!(error = %e, "final_report_failed");
+                }
+            }
+            Ok(saved)
+        }
+        Err(e) => {
+            error!(error = %e, "change_state_failed");
+            Err(e.into())
+        }
+    }
+}
+
+/// Create numbered stations of a tournament in one step, e.g. `Court 1..8` at one venue.
+/// Existing stations are replaced and the number of stations follows the setup.
+#[server(client = crate::version::VersionedClient)]
+#[instrument(
+    name = "tournament_base.create_stations",
+    skip_all,
+    fields(id = %id, version = version, count = setup.count)
+)]
+pub async fn create_stations(
+    id: Uuid,
+    version: u32,
+    setup: StationSetup,
+) -> AppResult<TournamentBase> {
+    create_stations_inner(id, version, setup).await
+}
+
+#[cfg(any(feature = "ssr", feature = "test-mock"))]
+pub async fn create_stations_inner(
+    id: Uuid,
+    version: u32,
+    setup: StationSetup,
+) -> AppResult<TournamentBase> {
+    let mut core = expect_context::<CoreState>().as_tournament_base_state();
+    if core.load(id).await?.is_none() {
+        return Err(AppError::ResourceNotFound("Tournament".to_string(), id));
+    }
+    // version of client is used for optimistic locking
+    core.get_mut()
+        .set_id_version(IdVersion::new(id, Some(version)));
+
+    match core.create_stations(&setup).await {
+        Ok(saved) => {
+            info!(saved_id = %saved.get_id(), "create_stations_ok");
+            Ok(saved.clone())
+        }
+        Err(e) => {
+            error!(error = %e, "create_stations_failed");
+            Err(e.into())
+        }
+    }
+}
+
+/// Apply a venue to a tournament: its available courts become the stations of the
+/// tournament and its postal address the location of the tournament.
+#[server(client = crate::version::VersionedClient)]
+#[instrument(
+    name = "tournament_base.apply_venue",
+    skip_all,
+    fields(id = %id, version = version, venue_id = %venue_id)
+)]
+pub async fn apply_venue(id: Uuid, version: u32, venue_id: Uuid) -> AppResult<TournamentBase> {
+    apply_venue_inner(id, version, venue_id).await
+}
+
+#[cfg(any(feature = "ssr", feature = "test-mock"))]
+pub async fn apply_venue_inner(
+    id: Uuid,
+    version: u32,
+    venue_id: Uuid,
+) -> AppResult<TournamentBase> {
+    let mut core = expect_context::<CoreState>().as_tournament_base_state();
+    if core.load(id).await?.is_none() {
+        return Err(AppError::ResourceNotFound("Tournament".to_string(), id));
+    }
+    // version of client is used for optimistic locking
+    core.get_mut()
+        .set_id_version(IdVersion::new(id, Some(version)));
+
+    match core.apply_venue(venue_id).await {
+        Ok(saved) => {
+            info!(saved_id = %saved.get_id(), "apply_venue_ok");
+            Ok(saved.clone())
+        }
+        Err(e) => {
+            error!(error = %e, "apply_venue_failed");
+            Err(e.into())
+        }
+    }
+}
+
+#[server(client = crate::version::VersionedClient)]
+#[instrument(
+    name = "tournament_base.clone",
+    skip_all,
+    fields(source_id = %source_id)
+)]
+pub async fn clone_tournament(source_id: Uuid, new_name: String) -> AppResult<TournamentBase> {
+    clone_tournament_inner(source_id, new_name).await
+}
+
+#[cfg(any(feature = "ssr", feature = "test-mock"))]
+pub async fn clone_tournament_inner(
+    source_id: Uuid,
+    new_name: String,
+) -> AppResult<TournamentBase> {
+    let core = expect_context::<CoreState>();
+
+    match core.clone_tournament(source_id, new_name).await {
+        Ok(Some(copy)) => {
+            info!(copy_id = %copy.get_id(), "clone_ok");
+            Ok(copy)
+        }
+        Ok(None) => {
+            error!("clone_source_not_found");
+            Err(AppError::ResourceNotFound(
+                "Tournament".to_string(),
+                source_id,
+            ))
+        }
+        Err(e) => {
+            error!(error = %e, "clone_failed");
+            Err(e.into())
+        }
+    }
+}
+
+#[server(client = crate::version::VersionedClient)]
+#[instrument(
+    name = "tournament_base.export",
+    skip_all,
+    fields(id = %id)
+)]
+pub async fn export_tournament(id: Uuid) -> AppResult<String> {
+    export_tournament_inner(id).await
+}
+
+#[cfg(any(feature = "ssr", feature = "test-mock"))]
+pub async fn export_tournament_inner(id: Uuid) -> AppResult<String> {
+    let core = expect_context::<CoreState>();
+
+    match core.export_tournament(id).await {
+        Ok(Some(export)) => {
+            info!(num_stages = export.stages.len(), "export_ok");
+            Ok(export.to_json()?)
+        }
+        Ok(None) => {
+            error!("export_tournament_not_found");
+            Err(AppError::ResourceNotFound("Tournament".to_string(), id))
+        }
+        Err(e) => {
+            error!(error = %e, "export_failed");
+            Err(e.into())
+        }
+    }
+}
+
+#[server(client = crate::version::VersionedClient)]
+#[instrument(
+    name = "tournament_base.import",
+    skip_all,
+    fields(
+        // We only log metadata, not complete payloads
+        document_len = document.len(),
+    )
+)]
+pub async fn import_tournament(
+    document: String,
+    new_name: Option<String>,
+) -> AppResult<TournamentBase> {
+    import_tournament_inner(document, new_name).await
+}
+
+#[cfg(any(feature = "ssr", feature = "test-mock"))]
+pub async fn import_tournament_inner(
+    document: String,
+    new_name: Option<String>,
+) -> AppResult<TournamentBase> {
+    let core = expect_context::<CoreState>();
+    let export = TournamentExport::from_json(&document)?;
+
+    match core.import_tournament(export, new_name).await {
+        Ok(imported) => {
+            info!(imported_id = %imported.get_id(), "import_ok");
+            Ok(imported)
+        }
+        Err(e) => {
+            error!(error = %e, "import_failed");
+            Err(e.into())
+        }
+    }
+}
@@ -10,7 +10,7 @@ use tracing::info;
 use tracing::instrument;
 use uuid::Uuid;
 
-#[server(input = Json, output = Json)]
+#[server(client = crate::version::VersionedClient, input = Json, output = Json)]
 #[instrument(name = "tournament_editor.save_diff", skip_all)]
 pub async fn save_tournament_editor_diff(
     base_id: Uuid,
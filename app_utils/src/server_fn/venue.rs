@@ -0,0 +1,89 @@
+//! server functions for management of venues
+
+#[cfg(any(feature = "ssr", feature = "test-mock"))]
+use crate::error::AppError;
+use crate::error::AppResult;
+#[cfg(any(feature = "ssr", feature = "test-mock"))]
+use app_core::{CoreState, utils::id_version::IdVersion};
+use app_core::{Court, Venue};
+use leptos::{prelude::*, server_fn::codec::Json};
+use tracing::instrument;
+#[cfg(any(feature = "ssr", feature = "test-mock"))]
+use tracing::{error, info};
+use uuid::Uuid;
+
+#[cfg(not(feature = "test-mock"))]
+#[server(client = crate::version::VersionedClient)]
+#[instrument(name = "venue.list", skip_all, fields(name = %name, limit = ?limit))]
+pub async fn list_venues(name: String, limit: Option<usize>) -> AppResult<Vec<Venue>> {
+    list_venues_inner(name, limit).await
+}
+
+#[cfg(feature = "test-mock")]
+pub async fn list_venues(name: String, limit: Option<usize>) -> AppResult<Vec<Venue>> {
+    list_venues_inner(name, limit).await
+}
+
+#[cfg(any(feature = "ssr", feature = "test-mock"))]
+async fn list_venues_inner(name: String, limit: Option<usize>) -> AppResult<Vec<Venue>> {
+    let core = expect_context::<CoreState>().as_venue_state();
+    let venues = core.list_venues(Some(&name), limit).await?;
+    Ok(venues)
+}
+
+/// Create (`id == None`) or update venue.
+#[server(client = crate::version::VersionedClient, input = Json, output = Json)]
+#[instrument(
+    name = "venue.save",
+    skip_all,
+    fields(
+        id = ?id,
+        version,
+        name = %name,
+        address_id = ?address_id,
+        courts = courts.len()
+    )
+)]
+pub async fn save_venue(
+    id: Option<Uuid>,
+    version: u32,
+    name: String,
+    address_id: Option<Uuid>,
+    courts: Vec<Court>,
+) -> AppResult<Venue> {
+    save_venue_inner(id, version, name, address_id, courts).await
+}
+
+#[cfg(any(feature = "ssr", feature = "test-mock"))]
+pub async fn save_venue_inner(
+    id: Option<Uuid>,
+    version: u32,
+    name: String,
+    address_id: Option<Uuid>,
+    courts: Vec<Court>,
+) -> AppResult<Venue> {
+    let mut core = expect_context::<CoreState>().as_venue_state();
+    if let Some(id) = id {
+        if core.load(id).await?.is_none() {
+            return Err(AppError::ResourceNotFound("Venue".to_string(), id));
+        }
+        // update the version the user has seen (optimistic locking)
+        core.get_mut()
+            .set_id_version(IdVersion::new(id, Some(version)));
+    }
+    core.get_mut()
+        .set_name(name)
+        .set_address_id(address_id)
+        .set_courts(courts);
+
+    match core.save().await {
+        Ok(venue) => {
+            info!(saved_id = %venue.get_id(), "save_ok");
+            Ok(venue.clone())
+        }
+        Err(e) => {
+            error!(error = %e, "save_failed");
+            Err(e.into())
+        }
+    }
+}
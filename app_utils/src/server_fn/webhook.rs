@@ -0,0 +1,155 @@
+//! server functions for management of webhook endpoints
+
+#[cfg(any(feature = "ssr", feature = "test-mock"))]
+use crate::error::AppError;
+use crate::error::AppResult;
+#[cfg(any(feature = "ssr", feature = "test-mock"))]
+use app_core::{CoreState, DEFAULT_WEBHOOK_DELIVERY_HISTORY_LIMIT, utils::id_version::IdVersion};
+use app_core::{WebhookDelivery, WebhookEndpoint, WebhookEventType, WebhookFormat};
+use leptos::{prelude::*, server_fn::codec::Json};
+use tracing::instrument;
+#[cfg(any(feature = "ssr", feature = "test-mock"))]
+use tracing::{error, info};
+use uuid::Uuid;
+
+#[cfg(not(feature = "test-mock"))]
+#[server(client = crate::version::VersionedClient)]
+#[instrument(name = "webhook.list", skip_all)]
+pub async fn list_webhook_endpoints() -> AppResult<Vec<WebhookEndpoint>> {
+    list_webhook_endpoints_inner().await
+}
+
+#[cfg(feature = "test-mock")]
+pub async fn list_webhook_endpoints() -> AppResult<Vec<WebhookEndpoint>> {
+    list_webhook_endpoints_inner().await
+}
+
+#[cfg(any(feature = "ssr", feature = "test-mock"))]
+async fn list_webhook_endpoints_inner() -> AppResult<Vec<WebhookEndpoint>> {
+    let core = expect_context::<CoreState>().as_webhook_state();
+    let endpoints = core.list_endpoints().await?;
+    Ok(endpoints)
+}
+
+#[cfg(not(feature = "test-mock"))]
+#[server(client = crate::version::VersionedClient)]
+#[instrument(name = "webhook.list_deliveries", skip_all, fields(endpoint_id = %endpoint_id))]
+pub async fn list_webhook_deliveries(endpoint_id: Uuid) -> AppResult<Vec<WebhookDelivery>> {
+    list_webhook_deliveries_inner(endpoint_id).await
+}
+
+#[cfg(feature = "test-mock")]
+pub async fn list_webhook_deliveries(endpoint_id: Uuid) -> AppResult<Vec<WebhookDelivery>> {
+    list_webhook_deliveries_inner(endpoint_id).await
+}
+
+#[cfg(any(feature = "ssr", feature = "test-mock"))]
+async fn list_webhook_deliveries_inner(endpoint_id: Uuid) -> AppResult<Vec<WebhookDelivery>> {
+    let mut core = expect_context::<CoreState>().as_webhook_state();
+    if core.load(endpoint_id).await?.is_none() {
+        return Err(AppError::ResourceNotFound(
+            "Webhook Endpoint".to_string(),
+            endpoint_id,
+        ));
+    }
+    let deliveries = core
+        .list_deliveries(DEFAULT_WEBHOOK_DELIVERY_HISTORY_LIMIT)
+        .await?;
+    Ok(deliveries)
+}
+
+/// Create (`id == None`) or update webhook endpoint. The secret is generated on creation
+/// and never changed by updates.
+#[server(client = crate::version::VersionedClient, input = Json, output = Json)]
+#[instrument(
+    name = "webhook.save",
+    skip_all,
+    fields(
+        id = ?id,
+        version,
+        name = %name,
+        event_types = ?event_types,
+        format = %format,
+        active
+    )
+)]
+pub async fn save_webhook_endpoint(
+    id: Option<Uuid>,
+    version: u32,
+    name: String,
+    url: String,
+    event_types: Vec<WebhookEventType>,
+    format: WebhookFormat,
+    active: bool,
+) -> AppResult<WebhookEndpoint> {
+    save_webhook_endpoint_inner(id, version, name, url, event_types, format, active).await
+}
+
+#[cfg(any(feature = "ssr", feature = "test-mock"))]
+pub async fn save_webhook_endpoint_inner(
+    id: Option<Uuid>,
+    version: u32,
+    name: String,
+    url: String,
+    event_types: Vec<WebhookEventType>,
+    format: WebhookFormat,
+    active: bool,
+) -> AppResult<WebhookEndpoint> {
+    let mut core = expect_context::<CoreState>().as_webhook_state();
+    if let Some(id) = id {
+        if core.load(id).await?.is_none() {
+            return Err(AppError::ResourceNotFound(
+                "Webhook Endpoint".to_string(),
+                id,
+            ));
+        }
+        // update the version the user has seen (optimistic locking)
+        core.get_mut()
+            .set_id_version(IdVersion::new(id, Some(version)));
+    }
+    core.get_mut()
+        .set_name(name)
+        .set_url(url)
+        .set_event_types(event_types)
+        .set_format(format)
+        .set_active(active);
+
+    match core.save().await {
+        Ok(endpoint) => {
+            info!(saved_id = %endpoint.get_id(), "save_ok");
+            Ok(endpoint.clone())
+        }
+        Err(e) => {
+            error!(error = %e, "save_failed");
+            Err(e.into())
+        }
+    }
+}
+
+#[server(client = crate::version::VersionedClient)]
+#[instrument(name = "webhook.send_test", skip_all, fields(id = %id))]
+pub async fn send_test_webhook(id: Uuid) -> AppResult<WebhookDelivery> {
+    send_test_webhook_inner(id).await
+}
+
+#[cfg(any(feature = "ssr", feature = "test-mock"))]
+pub async fn send_test_webhook_inner(id: Uuid) -> AppResult<WebhookDelivery> {
+    let mut core = expect_context::<CoreState>().as_webhook_state();
+    if core.load(id).await?.is_none() {
+        return Err(AppError::ResourceNotFound(
+            "Webhook Endpoint".to_string(),
+            id,
+        ));
+    }
+
+    match core.send_test_delivery().await {
+        Ok(delivery) => {
+            info!(delivery_id = %delivery.id, status_code = ?delivery.status_code, "send_test_ok");
+            Ok(delivery)
+        }
+        Err(e) => {
+            error!(error = %e, "send_test_failed");
+            Err(e.into())
+        }
+    }
+}
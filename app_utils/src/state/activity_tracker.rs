@@ -1,17 +1,44 @@
 //! context for global activity tracker
+//!
+//! Besides the global loading indicator the tracker bounds pending states: requests of a
+//! component, which are tracked with [`ActivityTracker::track_request`], are aborted, if the
+//! component starts a newer request (e.g. while typing a filter) or if they exceed their
+//! timeout. Aborting drops the request future, which cancels its fetch. Activities, which
+//! are pending longer than [`SLOW_ACTIVITY_THRESHOLD`], show a "still working" hint.
 
+use crate::error::{AppError, AppResult};
+use futures_util::future::{AbortHandle, abortable};
 use leptos::prelude::*;
-use std::collections::HashMap;
+use std::{
+    collections::HashMap,
+    future::Future,
+    sync::{
+        Arc,
+        atomic::{AtomicBool, Ordering},
+    },
+    time::Duration,
+};
 use uuid::Uuid;
 
+/// time, after which pending activities show the "still working" hint
+pub const SLOW_ACTIVITY_THRESHOLD: Duration = Duration::from_secs(3);
+/// timeout of requests tracked with [`ActivityTracker::track_request`]
+pub const DEFAULT_REQUEST_TIMEOUT: Duration = Duration::from_secs(30);
+
 #[derive(Clone, Copy)]
 pub struct ActivityTracker {
     /// Map of activity IDs to their counts
     activity_map: RwSignal<HashMap<Uuid, u32>>,
+    /// Map of component IDs to id and abort handle of their latest request
+    latest_requests: StoredValue<HashMap<Uuid, (Uuid, AbortHandle)>>,
+    /// Number of activities pending longer than `SLOW_ACTIVITY_THRESHOLD`
+    num_slow: RwSignal<u32>,
     /// Setter for router activity
     pub set_router_activity: SignalSetter<bool>,
     /// Signal indicating if any activity is active
     pub is_active: Signal<bool>,
+    /// Signal indicating if any activity is pending longer than `SLOW_ACTIVITY_THRESHOLD`
+    pub is_slow: Signal<bool>,
 }
 
 impl ActivityTracker {
@@ -28,10 +55,15 @@ impl ActivityTracker {
             get_router_activity.get()
                 || activity_map.with(|activity_map| activity_map.values().any(|v| *v > 0))
         });
+        let num_slow = RwSignal::new(0);
+        let is_slow = Signal::derive(move || num_slow.get() > 0);
         Self {
             activity_map,
+            latest_requests: StoredValue::new(HashMap::new()),
+            num_slow,
             set_router_activity,
             is_active,
+            is_slow,
         }
     }
 
@@ -57,6 +89,13 @@ impl ActivityTracker {
         self.activity_map.update(|activity_map| {
             activity_map.remove(&component_id);
         });
+        // pending requests of removed components are obsolete
+        if let Some(Some((_, abort_handle))) = self
+            .latest_requests
+            .try_update_value(|requests| requests.remove(&component_id))
+        {
+            abort_handle.abort();
+        }
     }
 
     /// Track activity for a future
@@ -66,24 +105,140 @@ impl ActivityTracker {
         activity_future: Fut,
     ) -> Fut::Output
     where
-        Fut: std::future::Future,
+        Fut: Future,
+    {
+        // Activity count is decremented, when the guard is dropped, i.e. also if the future
+        // is dropped before it is ready.
+        let _guard = ActivityGuard::new(*self, component_id);
+        activity_future.await
+    }
+
+    /// Track `request` of component like [`Self::track_activity_wrapper`] with
+    /// [`DEFAULT_REQUEST_TIMEOUT`], see [`Self::track_request_with_timeout`].
+    pub async fn track_request<Fut, T>(&self, component_id: Uuid, request: Fut) -> AppResult<T>
+    where
+        Fut: Future<Output = AppResult<T>>,
+    {
+        self.track_request_with_timeout(component_id, DEFAULT_REQUEST_TIMEOUT, request)
+            .await
+    }
+
+    /// Track `request` of component like [`Self::track_activity_wrapper`]. The request
+    /// supersedes the previous request of the component, which is aborted and returns
+    /// [`AppError::Superseded`]. If the request takes longer than `timeout`, it is aborted
+    /// and returns [`AppError::RequestTimeout`]. Timeouts apply only in the browser.
+    pub async fn track_request_with_timeout<Fut, T>(
+        &self,
+        component_id: Uuid,
+        timeout: Duration,
+        request: Fut,
+    ) -> AppResult<T>
+    where
+        Fut: Future<Output = AppResult<T>>,
     {
+        let request_id = Uuid::new_v4();
+        let (request, abort_handle) = abortable(request);
+        if let Some(Some((_, superseded))) = self.latest_requests.try_update_value(|requests| {
+            requests.insert(component_id, (request_id, abort_handle.clone()))
+        }) {
+            superseded.abort();
+        }
+
+        let timed_out = Arc::new(AtomicBool::new(false));
+        #[cfg(feature = "hydrate")]
+        let timer = {
+            let timed_out = timed_out.clone();
+            let abort_handle = abort_handle.clone();
+            set_timeout_with_handle(
+                move || {
+                    timed_out.store(true, Ordering::Relaxed);
+                    abort_handle.abort();
+                },
+                timeout,
+            )
+            .ok()
+        };
+
+        let result = self.track_activity_wrapper(component_id, request).await;
+
+        #[cfg(feature = "hydrate")]
+        if let Some(timer) = timer {
+            timer.clear();
+        }
+        self.latest_requests.try_update_value(|requests| {
+            if requests
+                .get(&component_id)
+                .is_some_and(|(id, _)| *id == request_id)
+            {
+                requests.remove(&component_id);
+            }
+        });
+        match result {
+            Ok(result) => result,
+            Err(_) if timed_out.load(Ordering::Relaxed) => {
+                Err(AppError::RequestTimeout(timeout.as_secs()))
+            }
+            Err(_) => Err(AppError::Superseded),
+        }
+    }
+}
+
+/// pending activity of a component; counts are decremented on drop
+struct ActivityGuard {
+    tracker: ActivityTracker,
+    component_id: Uuid,
+    is_slow: Arc<AtomicBool>,
+    #[cfg(feature = "hydrate")]
+    slow_timer: Option<TimeoutHandle>,
+}
+
+impl ActivityGuard {
+    fn new(tracker: ActivityTracker, component_id: Uuid) -> Self {
         // Increment activity count for the component
-        self.activity_map.update(|activity_map| {
+        tracker.activity_map.update(|activity_map| {
             let count = activity_map.entry(component_id).or_insert(0);
             *count += 1;
         });
+        let is_slow = Arc::new(AtomicBool::new(false));
+        #[cfg(feature = "hydrate")]
+        let slow_timer = {
+            let is_slow = is_slow.clone();
+            set_timeout_with_handle(
+                move || {
+                    is_slow.store(true, Ordering::Relaxed);
+                    tracker.num_slow.try_update(|num_slow| *num_slow += 1);
+                },
+                SLOW_ACTIVITY_THRESHOLD,
+            )
+            .ok()
+        };
+        Self {
+            tracker,
+            component_id,
+            is_slow,
+            #[cfg(feature = "hydrate")]
+            slow_timer,
+        }
+    }
+}
 
-        // Await the provided future
-        let result = activity_future.await;
-
+impl Drop for ActivityGuard {
+    fn drop(&mut self) {
+        #[cfg(feature = "hydrate")]
+        if let Some(slow_timer) = self.slow_timer {
+            slow_timer.clear();
+        }
+        if self.is_slow.load(Ordering::Relaxed) {
+            self.tracker
+                .num_slow
+                .try_update(|num_slow| *num_slow = num_slow.saturating_sub(1));
+        }
         // Decrement activity count for the component
-        self.activity_map.update(|activity_map| {
+        let component_id = self.component_id;
+        self.tracker.activity_map.try_update(|activity_map| {
             if let Some(count) = activity_map.get_mut(&component_id) {
                 *count = count.saturating_sub(1);
             }
         });
-
-        result
     }
 }
@@ -0,0 +1,56 @@
+//! ring buffer of recent client side errors
+//!
+//! Errors reported to the page error context or shown as error toasts are recorded here, so
+//! that feedback of users arrives with the errors they have seen recently.
+
+use app_core::FEEDBACK_MAX_RECENT_ERRORS;
+use chrono::Utc;
+use leptos::prelude::*;
+use std::collections::VecDeque;
+
+#[derive(Clone, Copy)]
+pub struct ClientErrorLog(RwSignal<VecDeque<String>>);
+
+impl ClientErrorLog {
+    pub fn new() -> Self {
+        Self(RwSignal::new(VecDeque::with_capacity(
+            FEEDBACK_MAX_RECENT_ERRORS,
+        )))
+    }
+
+    /// Record `message` with timestamp; the oldest error is dropped, if the buffer is full.
+    pub fn record(&self, message: impl AsRef<str>) {
+        let entry = format!("{} {}", Utc::now().format("%H:%M:%S"), message.as_ref());
+        self.0.update(|errors| {
+            if errors.len() == FEEDBACK_MAX_RECENT_ERRORS {
+                errors.pop_front();
+            }
+            errors.push_back(entry);
+        });
+    }
+
+    /// Record `message` in the error log of the current context, if one is provided.
+    pub fn record_in_context(message: impl AsRef<str>) {
+        if let Some(log) = use_context::<ClientErrorLog>() {
+            log.record(message);
+        }
+    }
+
+    /// Recent errors, oldest first.
+    pub fn recent(&self) -> Vec<String> {
+        self.0
+            .with_untracked(|errors| errors.iter().cloned().collect())
+    }
+
+    /// Number of recorded errors.
+    pub fn count(&self) -> Signal<usize> {
+        let errors = self.0;
+        Signal::derive(move || errors.with(VecDeque::len))
+    }
+}
+
+impl Default for ClientErrorLog {
+    fn default() -> Self {
+        Self::new()
+    }
+}
@@ -1,6 +1,6 @@
 //! handling persistent errors of application
 
-use super::LabeledAction;
+use super::{LabeledAction, client_errors::ClientErrorLog};
 use leptos::prelude::*;
 use std::collections::HashMap;
 use uuid::Uuid;
@@ -202,6 +202,7 @@ impl PageErrorContext {
 
     /// Report an error. Updates existing error if (component_id, key) matches.
     pub fn report_error(&self, new_error: ActiveError) {
+        ClientErrorLog::record_in_context(&new_error.message);
         self.active_error.update(|list| {
             if let Some(existing) = list
                 .iter_mut()
@@ -1,5 +1,7 @@
 //! Global state management for the application
 
+use super::theme::{ThemePreference, initial_theme_preference};
+use app_core::Language;
 use reactive_stores::Store;
 use sport_plugin_manager::SportPluginManagerMap;
 
@@ -9,12 +11,18 @@ use sport_plugin_manager::SportPluginManagerMap;
 pub struct GlobalState {
     /// sport plugin manager
     pub sport_plugin_manager: SportPluginManagerMap,
+    /// display language of the UI, which is selected by the user
+    pub language: Language,
+    /// color theme, which is selected by the user
+    pub theme: ThemePreference,
 }
 
 impl GlobalState {
     pub fn new() -> Self {
         GlobalState {
             sport_plugin_manager: SportPluginManagerMap::new(),
+            language: Language::default(),
+            theme: initial_theme_preference(),
         }
     }
 }
@@ -1,15 +1,17 @@
 //! state structures for the application
 
 pub mod activity_tracker;
+pub mod client_errors;
 pub mod error_state;
 pub mod global_state;
 pub mod object_table;
 pub mod postal_address;
 pub mod sport_config;
+pub mod theme;
 pub mod toast_state;
 pub mod tournament;
 
-use app_core::utils::traits::ObjectIdVersion;
+use app_core::{ConflictResolution, MergeFields, utils::traits::ObjectIdVersion};
 use leptos::prelude::*;
 use uuid::Uuid;
 
@@ -42,6 +44,29 @@ pub trait EditorContextWithResource: EditorContext {
     fn optimistic_version_signal(&self) -> Signal<Option<u32>>;
 }
 
+/// local copy and server copy of an object, whose save failed due to a version conflict
+#[derive(Clone, Debug, PartialEq)]
+pub struct VersionConflict<T> {
+    pub mine: T,
+    pub theirs: T,
+}
+
+pub trait EditorContextWithConflict: EditorContext<ObjectType: MergeFields> {
+    /// Get the signal of the current version conflict of the editor context, if any.
+    fn version_conflict(&self) -> RwSignal<Option<VersionConflict<Self::ObjectType>>>;
+
+    /// Resolve the current version conflict and set the resolved object in the editor context.
+    /// Returns true, if the resolved object must be saved.
+    fn resolve_version_conflict(&self, resolution: &ConflictResolution) -> bool {
+        let Some(conflict) = self.version_conflict().get_untracked() else {
+            return false;
+        };
+        self.version_conflict().set(None);
+        self.set_object(resolution.resolve(&conflict.mine, &conflict.theirs));
+        *resolution != ConflictResolution::TakeTheirs
+    }
+}
+
 #[derive(Clone, Copy)]
 pub struct SimpleEditorOptions {
     pub object_id: Option<Uuid>,
@@ -11,7 +11,7 @@ use leptos_router::{
     NavigateOptions,
     hooks::{use_navigate, use_query},
 };
-use std::collections::HashMap;
+use std::collections::{HashMap, HashSet};
 use uuid::Uuid;
 
 pub struct ObjectEditorMapContext<OE, Q>
@@ -29,6 +29,8 @@ where
     pub selected_id: Signal<Option<Uuid>>,
     /// Callback for updating the currently selected object editor id
     pub set_selected_id: Callback<Option<Uuid>>,
+    /// RwSignal for the ids of objects, which are checked for bulk operations
+    checked_ids: RwSignal<HashSet<Uuid>>,
     /// Trigger to refetch data from server
     refetch_trigger: RwSignal<u64>,
     /// Read slice for getting the current state of the object editor map
@@ -105,6 +107,7 @@ where
             visible_ids_list,
             selected_id,
             set_selected_id,
+            checked_ids: RwSignal::new(HashSet::new()),
             refetch_trigger,
             track_fetch_trigger: refetch_trigger.read_only().into(),
             marker: std::marker::PhantomData,
@@ -185,6 +188,52 @@ where
         self.selected_id
             .with(|selected_id| selected_id == &Some(id))
     }
+
+    // --- multi-select for bulk operations ---
+
+    pub fn is_checked(&self, id: Uuid) -> bool {
+        self.checked_ids.with(|checked| checked.contains(&id))
+    }
+
+    pub fn set_checked(&self, id: Uuid, checked: bool) {
+        self.checked_ids.update(|ids| {
+            if checked {
+                ids.insert(id);
+            } else {
+                ids.remove(&id);
+            }
+        });
+    }
+
+    /// Check all visible objects or uncheck all objects.
+    pub fn set_all_checked(&self, checked: bool) {
+        if checked {
+            let visible = self.visible_ids_list.get_untracked();
+            self.checked_ids.update(|ids| ids.extend(visible));
+        } else {
+            self.checked_ids.update(|ids| ids.clear());
+        }
+    }
+
+    /// Ids of checked objects in list order. Checked objects, which are not visible anymore,
+    /// e.g. after changing the filter, are ignored.
+    pub fn checked_ids(&self) -> Vec<Uuid> {
+        self.checked_ids.with(|checked| {
+            self.visible_ids_list.with(|vids| {
+                vids.iter()
+                    .filter(|id| checked.contains(id))
+                    .copied()
+                    .collect()
+            })
+        })
+    }
+
+    pub fn are_all_checked(&self) -> bool {
+        self.checked_ids.with(|checked| {
+            self.visible_ids_list
+                .with(|vids| !vids.is_empty() && vids.iter().all(|id| checked.contains(id)))
+        })
+    }
 }
 
 impl<OE, Q> ObjectEditorMapContext<OE, Q>
@@ -7,13 +7,13 @@ use crate::{
     },
     server_fn::postal_address::{SavePostalAddress, load_postal_address},
     state::{
-        EditorContext, EditorContextWithResource, SimpleEditorOptions,
-        activity_tracker::ActivityTracker, error_state::PageErrorContext,
+        EditorContext, EditorContextWithConflict, EditorContextWithResource, SimpleEditorOptions,
+        VersionConflict, activity_tracker::ActivityTracker, error_state::PageErrorContext,
         toast_state::ToastContext,
     },
 };
 use app_core::{
-    CrTopic, PostalAddress,
+    CrTopic, GeoPoint, PostalAddress, ServerCopy,
     utils::{
         id_version::IdVersion,
         validation::{FieldError, ValidationResult},
@@ -65,6 +65,8 @@ pub struct PostalAddressEditorContext {
     pub country: Signal<Option<CountryCode>>,
     /// Callback for updating the country field
     pub set_country: Callback<Option<CountryCode>>,
+    /// Read slice for the location, which is set by geocoding on save
+    pub location: Signal<Option<GeoPoint>>,
 
     // --- Resource & server action state ---
     /// WriteSignal for optimistic version handling to prevent unneeded server round after save
@@ -75,6 +77,8 @@ pub struct PostalAddressEditorContext {
     pub save_postal_address: ServerAction<SavePostalAddress>,
     /// Callback after successful save to e.g. navigate to the new postal address or show a success toast.
     pub post_save_callback: StoredValue<Option<Callback<PostalAddress>>>,
+    /// Local copy and server copy of the postal address, if saving failed due to a version conflict
+    version_conflict: RwSignal<Option<VersionConflict<PostalAddress>>>,
 }
 
 impl EditorContext for PostalAddressEditorContext {
@@ -199,6 +203,9 @@ impl EditorContext for PostalAddressEditorContext {
         let set_country = Callback::new(move |country: Option<CountryCode>| {
             set_country.set(country);
         });
+        let location = Signal::derive(move || {
+            local.with(|local| local.as_ref().and_then(|pa| pa.get_location()))
+        });
 
         // ---- address resource ----
         let (resource_id, set_resource_id) = signal(options.object_id);
@@ -263,6 +270,7 @@ impl EditorContext for PostalAddressEditorContext {
         activity_tracker.track_pending_memo(component_id.get_value(), save_postal_address_pending);
 
         let post_save_callback = StoredValue::new(None::<Callback<PostalAddress>>);
+        let version_conflict = RwSignal::new(None::<VersionConflict<PostalAddress>>);
 
         // handle save result
         Effect::new(move || {
@@ -287,6 +295,16 @@ impl EditorContext for PostalAddressEditorContext {
                                 map_db_unique_violation_to_field_error(&err, object_id, "name")
                         {
                             set_unique_violation_error.set(Some(field_error));
+                        } else if let Some(theirs) = err
+                            .get_server_copy()
+                            .and_then(ServerCopy::as_postal_address)
+                            && let Some(mine) = local.get_untracked()
+                        {
+                            // let the user resolve the conflict with the server copy
+                            version_conflict.set(Some(VersionConflict {
+                                mine,
+                                theirs: theirs.clone(),
+                            }));
                         } else {
                             handle_write_error(&toast_ctx, &err);
                         }
@@ -314,10 +332,12 @@ impl EditorContext for PostalAddressEditorContext {
             set_region,
             country,
             set_country,
+            location,
             set_optimistic_version,
             load_postal_address,
             save_postal_address,
             post_save_callback,
+            version_conflict,
         }
     }
 
@@ -378,3 +398,10 @@ impl EditorContextWithResource for PostalAddressEditorContext {
         self.set_optimistic_version.into()
     }
 }
+
+impl EditorContextWithConflict for PostalAddressEditorContext {
+    /// Get the signal of the current version conflict of the postal address, if any.
+    fn version_conflict(&self) -> RwSignal<Option<VersionConflict<PostalAddress>>> {
+        self.version_conflict
+    }
+}
@@ -8,7 +8,8 @@ use crate::{
     params::{ParamQuery, SportIdQuery},
     server_fn::sport_config::{SaveSportConfig, load_sport_config},
     state::{
-        EditorContext, EditorContextWithResource, SimpleEditorOptions,
+        EditorContext, EditorContextWithConflict, EditorContextWithResource, SimpleEditorOptions,
+        VersionConflict,
         activity_tracker::ActivityTracker,
         error_state::PageErrorContext,
         global_state::{GlobalState, GlobalStateStoreFields},
@@ -16,7 +17,7 @@ use crate::{
     },
 };
 use app_core::{
-    CrTopic, SportConfig,
+    CrTopic, ServerCopy, SportConfig,
     utils::{
         id_version::IdVersion,
         validation::{FieldError, ValidationResult},
@@ -63,6 +64,8 @@ pub struct SportConfigEditorContext {
     pub save_sport_config: ServerAction<SaveSportConfig>,
     /// Callback after successful save to e.g. navigate to the new sport configuration or show a success toast.
     pub post_save_callback: StoredValue<Option<Callback<SportConfig>>>,
+    /// Local copy and server copy of the sport config, if saving failed due to a version conflict
+    version_conflict: RwSignal<Option<VersionConflict<SportConfig>>>,
 }
 
 impl EditorContext for SportConfigEditorContext {
@@ -204,6 +207,7 @@ impl EditorContext for SportConfigEditorContext {
         activity_tracker.track_pending_memo(component_id.get_value(), save_sport_config_pending);
 
         let post_save_callback = StoredValue::new(None::<Callback<SportConfig>>);
+        let version_conflict = RwSignal::new(None::<VersionConflict<SportConfig>>);
 
         // handle save result
         Effect::new(move || {
@@ -228,6 +232,15 @@ impl EditorContext for SportConfigEditorContext {
                                 map_db_unique_violation_to_field_error(&err, object_id, "name")
                         {
                             set_unique_violation_error.set(Some(field_error));
+                        } else if let Some(theirs) =
+                            err.get_server_copy().and_then(ServerCopy::as_sport_config)
+                            && let Some(mine) = local.get_untracked()
+                        {
+                            // let the user resolve the conflict with the server copy
+                            version_conflict.set(Some(VersionConflict {
+                                mine,
+                                theirs: theirs.clone(),
+                            }));
                         } else {
                             handle_write_error(&toast_ctx, &err);
                         }
@@ -251,6 +264,7 @@ impl EditorContext for SportConfigEditorContext {
             load_sport_config,
             save_sport_config,
             post_save_callback,
+            version_conflict,
         }
     }
 
@@ -327,3 +341,10 @@ impl EditorContextWithResource for SportConfigEditorContext {
         self.set_optimistic_version.into()
     }
 }
+
+impl EditorContextWithConflict for SportConfigEditorContext {
+    /// Get the signal of the current version conflict of the sport config, if any.
+    fn version_conflict(&self) -> RwSignal<Option<VersionConflict<SportConfig>>> {
+        self.version_conflict
+    }
+}
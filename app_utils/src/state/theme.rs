@@ -0,0 +1,152 @@
+//! color theme of the app, which is selected per user
+//!
+//! The preference is stored in a cookie, therefore the server renders the page already with
+//! the selected theme and hydration does not flash the default theme. Without preference the
+//! theme follows `prefers-color-scheme` of the browser, see the daisyUI themes in
+//! `style/input.css`.
+
+use app_core::CoreError;
+use serde::{Deserialize, Serialize};
+use std::str::FromStr;
+
+/// name of the cookie, which stores the theme preference
+pub const THEME_COOKIE: &str = "theme";
+
+/// daisyUI theme of light mode
+const LIGHT_THEME: &str = "fantasy";
+/// daisyUI theme of dark mode
+const DARK_THEME: &str = "business";
+
+/// color theme selected by the user
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default, Serialize, Deserialize)]
+pub enum ThemePreference {
+    /// follow color scheme of the browser
+    #[default]
+    System,
+    Light,
+    Dark,
+}
+
+impl ThemePreference {
+    /// all theme preferences in order of the theme switcher
+    pub const ALL: [ThemePreference; 3] = [
+        ThemePreference::System,
+        ThemePreference::Light,
+        ThemePreference::Dark,
+    ];
+
+    /// value of the theme cookie
+    pub fn code(&self) -> &'static str {
+        match self {
+            ThemePreference::System => "system",
+            ThemePreference::Light => "light",
+            ThemePreference::Dark => "dark",
+        }
+    }
+
+    /// value of the `data-theme` attribute of the document; `None` leaves the choice to
+    /// `prefers-color-scheme`
+    pub fn data_theme(&self) -> Option<&'static str> {
+        match self {
+            ThemePreference::System => None,
+            ThemePreference::Light => Some(LIGHT_THEME),
+            ThemePreference::Dark => Some(DARK_THEME),
+        }
+    }
+
+    /// Read the theme preference from the value of a `Cookie` header or of
+    /// `document.cookie`.
+    pub fn from_cookies(cookies: &str) -> Option<Self> {
+        cookies
+            .split(';')
+            .filter_map(|cookie| cookie.trim().split_once('='))
+            .find(|(name, _)| *name == THEME_COOKIE)
+            .and_then(|(_, value)| value.parse().ok())
+    }
+}
+
+impl FromStr for ThemePreference {
+    type Err = CoreError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        ThemePreference::ALL
+            .into_iter()
+            .find(|t| t.code() == s)
+            .ok_or_else(|| CoreError::ParsingError(format!("Unknown theme: {s}")))
+    }
+}
+
+/// Get the theme preference of the current request on the server or of the document in the
+/// browser.
+pub fn initial_theme_preference() -> ThemePreference {
+    request_cookies()
+        .and_then(|cookies| ThemePreference::from_cookies(&cookies))
+        .unwrap_or_default()
+}
+
+#[cfg(feature = "ssr")]
+fn request_cookies() -> Option<String> {
+    use leptos::prelude::use_context;
+    let parts = use_context::<http::request::Parts>()?;
+    let cookies = parts
+        .headers
+        .get_all(http::header::COOKIE)
+        .iter()
+        .filter_map(|value| value.to_str().ok())
+        .collect::<Vec<_>>()
+        .join("; ");
+    Some(cookies)
+}
+
+#[cfg(feature = "hydrate")]
+fn request_cookies() -> Option<String> {
+    use leptos::{prelude::document, wasm_bindgen::JsCast};
+    document()
+        .dyn_into::<web_sys::HtmlDocument>()
+        .ok()?
+        .cookie()
+        .ok()
+}
+
+#[cfg(not(any(feature = "ssr", feature = "hydrate")))]
+fn request_cookies() -> Option<String> {
+    None
+}
+
+/// Store `theme` in the theme cookie for one year and apply it to the document.
+#[cfg(feature = "hydrate")]
+pub fn store_theme_preference(theme: ThemePreference) {
+    use leptos::{prelude::document, wasm_bindgen::JsCast};
+    if let Ok(html_document) = document().dyn_into::<web_sys::HtmlDocument>() {
+        let _ = html_document.set_cookie(&format!(
+            "{THEME_COOKIE}={}; path=/; max-age=31536000; SameSite=Lax",
+            theme.code()
+        ));
+    }
+    if let Some(root) = document().document_element() {
+        let _ = match theme.data_theme() {
+            Some(data_theme) => root.set_attribute("data-theme", data_theme),
+            None => root.remove_attribute("data-theme"),
+        };
+    }
+}
+
+#[cfg(not(feature = "hydrate"))]
+pub fn store_theme_preference(_theme: ThemePreference) {}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn given_cookie_header_when_from_cookies_then_theme_preference() {
+        assert_eq!(
+            ThemePreference::from_cookies("session=abc; theme=dark; other=1"),
+            Some(ThemePreference::Dark)
+        );
+        assert_eq!(ThemePreference::from_cookies("theme=unknown"), None);
+        assert_eq!(ThemePreference::from_cookies(""), None);
+        assert_eq!(ThemePreference::Light.data_theme(), Some("fantasy"));
+        assert_eq!(ThemePreference::System.data_theme(), None);
+    }
+}
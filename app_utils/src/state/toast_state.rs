@@ -1,4 +1,4 @@
-use super::LabeledAction;
+use super::{LabeledAction, client_errors::ClientErrorLog};
 use leptos::prelude::*;
 use std::time::Duration;
 use uuid::Uuid;
@@ -103,6 +103,8 @@ impl ToastContext {
     }
 
     pub fn error(&self, msg: impl Into<String>, interactive: Option<LabeledAction>) {
+        let msg = msg.into();
+        ClientErrorLog::record_in_context(&msg);
         self.add(msg, ToastVariant::Error, interactive);
     }
 }
@@ -8,12 +8,14 @@ use crate::{
     params::{ParamQuery, SportIdQuery},
     server_fn::tournament_base::{SaveTournamentBase, load_tournament_base},
     state::{
-        EditorContext, EditorContextWithResource, EditorOptions, activity_tracker::ActivityTracker,
-        error_state::PageErrorContext, toast_state::ToastContext,
+        EditorContext, EditorContextWithConflict, EditorContextWithResource, EditorOptions,
+        VersionConflict, activity_tracker::ActivityTracker, error_state::PageErrorContext,
+        toast_state::ToastContext,
     },
 };
 use app_core::{
-    CrTopic, Tournament, TournamentBase, TournamentMode, TournamentState, TournamentType,
+    CrTopic, Language, LocalizedText, ServerCopy, Station, StationWindow, Tournament,
+    TournamentBase, TournamentMode, TournamentState, TournamentType, Venue,
     utils::{
         id_version::IdVersion,
         validation::{FieldError, ValidationResult},
@@ -46,7 +48,7 @@ pub struct BaseEditorContext {
     /// WriteSignal for setting a unique violation error on the name field, if any
     pub set_unique_violation_error: WriteSignal<Option<FieldError>>,
     /// Read slice for checking if the tournament base is in a state where editing is disabled
-    /// (e.g. when tournament is active or finished)
+    /// (e.g. when tournament is active or finished and not a sandbox)
     pub is_disabled_base_editing: Signal<bool>,
     /// Read slice for checking if the stage editor should be skipped based on the tournament mode
     pub skip_stage_editor: Signal<bool>,
@@ -64,6 +66,18 @@ pub struct BaseEditorContext {
     pub num_entrants: Signal<Option<u32>>,
     /// Write slice for setting the tournament base number of entrants
     pub set_num_entrants: Callback<Option<u32>>,
+    /// Read slice for accessing the tournament base number of stations, if any
+    pub num_stations: Signal<Option<u32>>,
+    /// Write slice for setting the tournament base number of stations
+    pub set_num_stations: Callback<Option<u32>>,
+    /// Read slice for accessing the named stations of the tournament base, if any
+    pub stations: Signal<Option<Vec<Station>>>,
+    /// Write slice for setting the named stations of the tournament base
+    pub set_stations: Callback<Vec<Station>>,
+    /// Read slice for accessing the availability of stations over the tournament, if any
+    pub station_windows: Signal<Option<Vec<StationWindow>>>,
+    /// Write slice for setting the availability of stations over the tournament
+    pub set_station_windows: Callback<Vec<StationWindow>>,
     /// Read slice for accessing the tournament base type, if any
     pub tournament_type: Signal<Option<TournamentType>>,
     /// Read slice for accessing the tournament base mode, if any
@@ -74,8 +88,38 @@ pub struct BaseEditorContext {
     pub num_rounds_swiss_system: Signal<Option<u32>>,
     /// Write slice for setting the tournament base number of rounds for Swiss System
     pub set_num_rounds_swiss_system: Callback<Option<u32>>,
+    /// Read slice for accessing the tournament base number of neighbors for Ring System, if any
+    pub num_neighbors_ring_system: Signal<Option<u32>>,
+    /// Write slice for setting the tournament base number of neighbors for Ring System
+    pub set_num_neighbors_ring_system: Callback<Option<u32>>,
     /// Read slice for accessing the tournament state, if any
     pub tournament_state: Signal<Option<TournamentState>>,
+    /// Read slice for accessing the sandbox flag of the tournament base, if any
+    pub sandbox: Signal<Option<bool>>,
+    /// Write slice for setting the sandbox flag of the tournament base
+    pub set_sandbox: Callback<bool>,
+    /// Read slice for accessing the display languages of public pages, if any
+    pub languages: Signal<Option<Vec<Language>>>,
+    /// Write slice for setting the display languages of public pages
+    pub set_languages: Callback<Vec<Language>>,
+    /// Read slice for accessing the description of the tournament base, if any
+    pub description: Signal<Option<LocalizedText>>,
+    /// Write slice for setting the description of the tournament base
+    pub set_description: Callback<LocalizedText>,
+    /// Read slice for accessing the postal address id of the tournament location, if any
+    pub address_id: Signal<Option<Uuid>>,
+    /// Write slice for setting the postal address id of the tournament location
+    pub set_address_id: Callback<Option<Uuid>>,
+    /// Read slice for accessing the sport id of the tournament base, if any
+    pub sport_id: Signal<Option<Uuid>>,
+    /// Read slice for accessing the id of the chosen sport configuration, if any
+    pub sport_config_id: Signal<Option<Uuid>>,
+    /// Write slice for choosing the sport configuration of the tournament base
+    pub set_sport_config_id: Callback<Option<Uuid>>,
+    /// Read slice for accessing the id of the venue of the tournament, if any
+    pub venue_id: Signal<Option<Uuid>>,
+    /// Write slice for taking stations and location of the tournament from a venue
+    pub apply_venue: Callback<Venue>,
 
     // --- Resource & server action state ---
     /// WriteSignal for optimistic version handling to prevent unneeded server round after save
@@ -88,6 +132,8 @@ pub struct BaseEditorContext {
     pub save_tournament_base: ServerAction<SaveTournamentBase>,
     /// Callback after successful save to e.g. navigate to the new tournament base or show a success toast.
     pub post_save_callback: StoredValue<Option<Callback<TournamentBase>>>,
+    /// Local copy and server copy of the tournament base, if saving failed due to a version conflict
+    version_conflict: RwSignal<Option<VersionConflict<TournamentBase>>>,
 }
 
 impl EditorContext for BaseEditorContext {
@@ -188,6 +234,52 @@ impl EditorContext for BaseEditorContext {
         let set_num_entrants = Callback::new(move |num_entrants: Option<u32>| {
             set_num_entrants.set(num_entrants.unwrap_or_default());
         });
+        let (num_stations, set_num_stations) = create_slice(
+            options.local_tournament,
+            |local_tournament| {
+                local_tournament
+                    .as_ref()
+                    .map(|t| t.get_base().get_num_stations())
+            },
+            |local_tournament, num_stations: u32| {
+                if let Some(t) = local_tournament {
+                    t.set_base_num_stations(num_stations);
+                }
+            },
+        );
+        let set_num_stations = Callback::new(move |num_stations: Option<u32>| {
+            set_num_stations.set(num_stations.unwrap_or_default());
+        });
+        let (stations, set_stations) = create_slice(
+            options.local_tournament,
+            |local_tournament| {
+                local_tournament
+                    .as_ref()
+                    .map(|t| t.get_base().get_stations().to_vec())
+            },
+            |local_tournament, stations: Vec<Station>| {
+                if let Some(t) = local_tournament {
+                    t.set_base_stations(stations);
+                }
+            },
+        );
+        let set_stations = Callback::new(move |stations: Vec<Station>| set_stations.set(stations));
+        let (station_windows, set_station_windows) = create_slice(
+            options.local_tournament,
+            |local_tournament| {
+                local_tournament
+                    .as_ref()
+                    .map(|t| t.get_base().get_station_windows().to_vec())
+            },
+            |local_tournament, station_windows: Vec<StationWindow>| {
+                if let Some(t) = local_tournament {
+                    t.set_base_station_windows(station_windows);
+                }
+            },
+        );
+        let set_station_windows = Callback::new(move |station_windows: Vec<StationWindow>| {
+            set_station_windows.set(station_windows)
+        });
         let tournament_type = create_read_slice(options.local_tournament, |local_tournament| {
             local_tournament
                 .as_ref()
@@ -214,7 +306,9 @@ impl EditorContext for BaseEditorContext {
         let skip_stage_editor = Signal::derive(move || {
             matches!(
                 mode.get(),
-                Some(TournamentMode::SingleStage) | Some(TournamentMode::SwissSystem { .. })
+                Some(TournamentMode::SingleStage)
+                    | Some(TournamentMode::SwissSystem { .. })
+                    | Some(TournamentMode::RingSystem { .. })
             )
         });
         let (num_rounds_swiss_system, set_num_rounds_swiss_system) = create_slice(
@@ -233,17 +327,124 @@ impl EditorContext for BaseEditorContext {
         let set_num_rounds_swiss_system = Callback::new(move |num_rounds_swiss: Option<u32>| {
             set_num_rounds_swiss_system.set(num_rounds_swiss.unwrap_or_default());
         });
+        let (num_neighbors_ring_system, set_num_neighbors_ring_system) = create_slice(
+            options.local_tournament,
+            |local_tournament| {
+                local_tournament
+                    .as_ref()
+                    .and_then(|t| t.get_base().get_num_neighbors_ring_system())
+            },
+            |local_tournament, num_neighbors_ring: u32| {
+                if let Some(t) = local_tournament {
+                    t.set_base_num_neighbors_ring_system(num_neighbors_ring);
+                }
+            },
+        );
+        let set_num_neighbors_ring_system =
+            Callback::new(move |num_neighbors_ring: Option<u32>| {
+                set_num_neighbors_ring_system.set(num_neighbors_ring.unwrap_or_default());
+            });
         let tournament_state = create_read_slice(options.local_tournament, |local_tournament| {
             local_tournament
                 .as_ref()
                 .map(|t| t.get_base().get_tournament_state())
         });
-        let is_disabled_base_editing = Signal::derive(move || {
-            matches!(
-                tournament_state.get(),
-                Some(TournamentState::ActiveStage(_)) | Some(TournamentState::Finished)
-            )
+        let (sandbox, set_sandbox) = create_slice(
+            options.local_tournament,
+            |local_tournament| local_tournament.as_ref().map(|t| t.get_base().is_sandbox()),
+            |local_tournament, sandbox: bool| {
+                if let Some(t) = local_tournament {
+                    t.set_base_sandbox(sandbox);
+                }
+            },
+        );
+        let set_sandbox = Callback::new(move |sandbox: bool| set_sandbox.set(sandbox));
+        let (languages, set_languages) = create_slice(
+            options.local_tournament,
+            |local_tournament| {
+                local_tournament
+                    .as_ref()
+                    .map(|t| t.get_base().get_languages().to_vec())
+            },
+            |local_tournament, languages: Vec<Language>| {
+                if let Some(t) = local_tournament {
+                    t.set_base_languages(languages);
+                }
+            },
+        );
+        let set_languages =
+            Callback::new(move |languages: Vec<Language>| set_languages.set(languages));
+        let (description, set_description) = create_slice(
+            options.local_tournament,
+            |local_tournament| {
+                local_tournament
+                    .as_ref()
+                    .map(|t| t.get_base().get_description().clone())
+            },
+            |local_tournament, description: LocalizedText| {
+                if let Some(t) = local_tournament {
+                    t.set_base_description(description);
+                }
+            },
+        );
+        let set_description =
+            Callback::new(move |description: LocalizedText| set_description.set(description));
+        let (address_id, set_address_id) = create_slice(
+            options.local_tournament,
+            |local_tournament| {
+                local_tournament
+                    .as_ref()
+                    .and_then(|t| t.get_base().get_address_id())
+            },
+            |local_tournament, address_id: Option<Uuid>| {
+                if let Some(t) = local_tournament {
+                    t.set_base_address_id(address_id);
+                }
+            },
+        );
+        let set_address_id =
+            Callback::new(move |address_id: Option<Uuid>| set_address_id.set(address_id));
+        let sport_id = create_read_slice(options.local_tournament, |local_tournament| {
+            local_tournament
+                .as_ref()
+                .map(|t| t.get_base().get_sport_id())
+        });
+        let (sport_config_id, set_sport_config_id) = create_slice(
+            options.local_tournament,
+            |local_tournament| {
+                local_tournament
+                    .as_ref()
+                    .and_then(|t| t.get_base().get_sport_config_id())
+            },
+            |local_tournament, sport_config_id: Option<Uuid>| {
+                if let Some(t) = local_tournament {
+                    t.set_base_sport_config_id(sport_config_id);
+                }
+            },
+        );
+        let set_sport_config_id = Callback::new(move |sport_config_id: Option<Uuid>| {
+            set_sport_config_id.set(sport_config_id)
         });
+        let (venue_id, apply_venue) = create_slice(
+            options.local_tournament,
+            |local_tournament| {
+                local_tournament
+                    .as_ref()
+                    .and_then(|t| t.get_base().get_venue_id())
+            },
+            |local_tournament, venue: Venue| {
+                if let Some(t) = local_tournament {
+                    t.apply_base_venue(&venue);
+                }
+            },
+        );
+        let apply_venue = Callback::new(move |venue: Venue| apply_venue.set(venue));
+        let is_disabled_base_editing =
+            create_read_slice(options.local_tournament, |local_tournament| {
+                local_tournament
+                    .as_ref()
+                    .is_some_and(|t| t.get_base().is_locked_by_state())
+            });
 
         // ---- tournament base resource ----
         let (resource_id, set_resource_id) = signal(options.object_id);
@@ -308,6 +509,7 @@ impl EditorContext for BaseEditorContext {
         activity_tracker.track_pending_memo(component_id.get_value(), save_tournament_base_pending);
 
         let post_save_callback = StoredValue::new(None::<Callback<TournamentBase>>);
+        let version_conflict = RwSignal::new(None::<VersionConflict<TournamentBase>>);
 
         // handle save result
         Effect::new(move || {
@@ -332,6 +534,16 @@ impl EditorContext for BaseEditorContext {
                                 map_db_unique_violation_to_field_error(&err, object_id, "name")
                         {
                             set_unique_violation_error.set(Some(field_error));
+                        } else if let Some(theirs) = err
+                            .get_server_copy()
+                            .and_then(ServerCopy::as_tournament_base)
+                            && let Some(mine) = local.get_untracked()
+                        {
+                            // let the user resolve the conflict with the server copy
+                            version_conflict.set(Some(VersionConflict {
+                                mine,
+                                theirs: theirs.clone(),
+                            }));
                         } else {
                             handle_write_error(&toast_ctx, &err);
                         }
@@ -352,18 +564,40 @@ impl EditorContext for BaseEditorContext {
             set_name,
             num_entrants,
             set_num_entrants,
+            num_stations,
+            set_num_stations,
+            stations,
+            set_stations,
+            station_windows,
+            set_station_windows,
             tournament_type,
             mode,
             set_mode,
             skip_stage_editor,
             num_rounds_swiss_system,
             set_num_rounds_swiss_system,
+            num_neighbors_ring_system,
+            set_num_neighbors_ring_system,
             tournament_state,
+            sandbox,
+            set_sandbox,
+            languages,
+            set_languages,
+            description,
+            set_description,
+            address_id,
+            set_address_id,
+            sport_id,
+            sport_config_id,
+            set_sport_config_id,
+            venue_id,
+            apply_venue,
             set_optimistic_version,
             set_resource_id,
             load_tournament_base,
             save_tournament_base,
             post_save_callback,
+            version_conflict,
         }
     }
 
@@ -435,3 +669,10 @@ impl EditorContextWithResource for BaseEditorContext {
         self.set_optimistic_version.into()
     }
 }
+
+impl EditorContextWithConflict for BaseEditorContext {
+    /// Get the signal of the current version conflict of the tournament base, if any.
+    fn version_conflict(&self) -> RwSignal<Option<VersionConflict<TournamentBase>>> {
+        self.version_conflict
+    }
+}
@@ -2,18 +2,27 @@
 //!
 //! This module provides a context wrapper around `TournamentEditor` to ensure
 //! efficient state updates via `RwSignal` without unnecessary cloning.
+//!
+//! Structural edits (change of mode, new stages and change of number of groups) are recorded
+//! in an undo/redo history of the local tournament. Undo and redo restore the structure of the
+//! recorded tournament and save all objects, which differ from the tournament before, in one
+//! transaction.
 
 pub mod base;
 pub mod stage;
 
 use crate::{
+    error::{AppError, strategy::handle_write_error},
     hooks::use_url_navigation::{UseQueryNavigationReturn, use_query_navigation},
     params::{GroupNumberParams, ParamQuery, StageNumberParams, TournamentBaseIdQuery},
+    server_fn::tournament_base::SaveTournamentDiff,
     state::{
         EditorContext, EditorContextWithResource, SimpleEditorOptions, toast_state::ToastContext,
     },
 };
-use app_core::{Stage, Tournament, TournamentBase};
+use app_core::{
+    Stage, Tournament, TournamentBase, TournamentDiffResult, utils::edit_history::EditHistory,
+};
 use base::{BaseEditorContext, BaseEditorContextOptions};
 use leptos::prelude::*;
 use leptos_router::{NavigateOptions, hooks::use_navigate};
@@ -32,6 +41,14 @@ pub struct TournamentEditorContext {
     pub base_editor: BaseEditorContext,
     /// Map of stage editors for the stages of the tournament, keyed by stage number
     stage_editors: RwSignal<HashMap<u32, StageEditorContext>>,
+    /// undo/redo history of structural edits of the local tournament
+    history: RwSignal<EditHistory<Tournament>>,
+    /// Read slice for checking if a structural edit can be undone
+    pub can_undo: Signal<bool>,
+    /// Read slice for checking if an undone structural edit can be redone
+    pub can_redo: Signal<bool>,
+    /// Server action for saving all changed objects of undo/redo in one transaction
+    save_tournament_diff: ServerAction<SaveTournamentDiff>,
 }
 
 impl EditorContext for TournamentEditorContext {
@@ -59,6 +76,9 @@ impl EditorContext for TournamentEditorContext {
         };
         let base_editor = BaseEditorContext::new(base_editor_options);
         let stage_editors = RwSignal::new(HashMap::new());
+        let history = RwSignal::new(EditHistory::<Tournament>::default());
+        let can_undo = Signal::derive(move || history.with(|h| h.can_undo()));
+        let can_redo = Signal::derive(move || history.with(|h| h.can_redo()));
 
         // --- url parameters & queries & validation ---
         let tournament_base_id = TournamentBaseIdQuery::use_param_query();
@@ -124,22 +144,57 @@ impl EditorContext for TournamentEditorContext {
             }
         });
 
-        Self {
+        // ---- tournament diff server action ----
+        let save_tournament_diff = ServerAction::<SaveTournamentDiff>::new();
+
+        let ctx = Self {
             local,
             owner,
             base_editor,
             stage_editors,
-        }
+            history,
+            can_undo,
+            can_redo,
+            save_tournament_diff,
+        };
+
+        // handle save result
+        Effect::new(move || {
+            if let Some(std_result) = save_tournament_diff.value().get() {
+                save_tournament_diff.clear();
+                match std_result {
+                    Ok(result) if result.is_saved() => ctx.apply_saved_diff(&result),
+                    Ok(result) => {
+                        // nothing is saved; report failed objects
+                        ctx.reset_versions_to_origin();
+                        for err in result.errors() {
+                            handle_write_error(&toast_ctx, &AppError::from(err.clone()));
+                        }
+                    }
+                    Err(err) => {
+                        ctx.reset_versions_to_origin();
+                        handle_write_error(&toast_ctx, &err);
+                    }
+                }
+            }
+        });
+
+        ctx
     }
 
     /// Set the current tournament in the editor context, updating all relevant state accordingly.
     fn set_object(&self, tournament: Tournament) {
+        // history belongs to the edited tournament
+        if self.base_editor.id.get_untracked() != Some(tournament.get_base().get_id()) {
+            self.history.update(|h| h.clear());
+        }
         self.local.set(Some(tournament.clone()));
         self.base_editor.set_object(tournament.get_base().clone());
     }
 
     /// Create a new tournament object in the editor context, returning its unique identifier.
     fn new_object(&self) -> Option<Uuid> {
+        self.history.update(|h| h.clear());
         self.base_editor.new_object();
         self.base_editor.id.get()
     }
@@ -209,6 +264,14 @@ impl TournamentEditorContext {
         if self.get_stage_editor(stage_number).is_some() {
             return; // Editor already exists for this stage number, nothing to prepare
         }
+        if self.local.with_untracked(|may_be_t| {
+            may_be_t
+                .as_ref()
+                .is_some_and(|t| t.get_stage_by_number(stage_number).is_none())
+        }) {
+            // adding a stage is a structural edit
+            self.record_structural_edit();
+        }
         if let Some(stage_editor) = self.spawn_stage_editor(None, stage_number) {
             // create new stage object in editor
             stage_editor.new_object();
@@ -218,6 +281,89 @@ impl TournamentEditorContext {
     pub fn prepare_group(&self, _stage_number: u32, _group_number: u32) {
         // ToDo: implement group editor context and insert into map here
     }
+
+    /// Record the local tournament before a structural edit, e.g. change of mode or number of
+    /// groups. Must be called before the edit is applied.
+    pub fn record_structural_edit(&self) {
+        if let Some(tournament) = self.local.get_untracked() {
+            self.history.update(|h| h.record(tournament));
+        }
+    }
+
+    /// Undo the last structural edit.
+    pub fn undo(&self) {
+        self.restore_from_history(false);
+    }
+
+    /// Redo the last undone structural edit.
+    pub fn redo(&self) {
+        self.restore_from_history(true);
+    }
+
+    fn restore_from_history(&self, redo: bool) {
+        let Some(current) = self.local.get_untracked() else {
+            return;
+        };
+        let snapshot = self
+            .history
+            .try_update(|h| {
+                if redo {
+                    h.redo(current.clone())
+                } else {
+                    h.undo(current.clone())
+                }
+            })
+            .flatten();
+        let Some(snapshot) = snapshot else {
+            return;
+        };
+        let mut restored = current.clone();
+        restored.restore_structure(&snapshot);
+        self.local.set(Some(restored.clone()));
+        self.save_structural_changes(&restored, &current);
+    }
+
+    /// Save all objects of `restored`, which differ from `before`, in one transaction.
+    fn save_structural_changes(&self, restored: &Tournament, before: &Tournament) {
+        let diff = restored.collect_diff(before);
+        if diff.is_empty() {
+            return;
+        }
+        if diff.base.is_some() {
+            self.base_editor.increment_optimistic_version();
+        }
+        for stage in diff.stages.iter() {
+            // only stages, which are already saved, can be loaded by the stage editor
+            let object_id = stage.get_version().map(|_| stage.get_id());
+            if let Some(stage_editor) = self.spawn_stage_editor(object_id, stage.get_number()) {
+                stage_editor.increment_optimistic_version();
+            }
+        }
+        self.save_tournament_diff
+            .dispatch(SaveTournamentDiff { diff });
+    }
+
+    /// Set the saved objects of a diff in their editors.
+    fn apply_saved_diff(&self, result: &TournamentDiffResult) {
+        if let Some(base) = result.base.as_ref().and_then(|b| b.get_saved()) {
+            self.base_editor.set_object(base.clone());
+        }
+        for stage in result.stages.iter().filter_map(|s| s.get_saved()) {
+            if let Some(stage_editor) = self.get_stage_editor(stage.get_number()) {
+                stage_editor.set_object(*stage);
+            }
+        }
+    }
+
+    /// Reset the optimistic versions of all editors, if saving a diff failed.
+    fn reset_versions_to_origin(&self) {
+        self.base_editor.reset_version_to_origin();
+        self.stage_editors.with_untracked(|editors| {
+            for stage_editor in editors.values() {
+                stage_editor.reset_version_to_origin();
+            }
+        });
+    }
 }
 
 #[derive(Clone, Copy)]
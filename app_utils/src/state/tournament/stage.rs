@@ -9,9 +9,10 @@ use crate::{
     },
 };
 use app_core::{
-    CrTopic, Stage, Tournament, TournamentState,
+    CrTopic, ScoringOverride, Stage, Tournament,
     utils::{id_version::IdVersion, validation::ValidationResult},
 };
+use chrono::{DateTime, Utc};
 use cr_leptos_axum_socket::use_client_registry_socket;
 use leptos::prelude::*;
 use uuid::Uuid;
@@ -43,6 +44,9 @@ pub struct StageEditorContext {
     /// Read slice for checking if the stage is in a state where editing is disabled
     /// (e.g. when stage or tournament is finished)
     pub is_disabled_stage_editing: Signal<bool>,
+    /// Read slice for checking if number of groups, seeding and scoring of the stage are
+    /// locked, because the stage is started
+    pub is_disabled_structure_editing: Signal<bool>,
 
     // --- Signals, Slices & Callbacks for form fields ---
     /// Signal slice for the id field
@@ -57,6 +61,22 @@ pub struct StageEditorContext {
     pub num_groups: Signal<Option<u32>>,
     /// Write slice for setting the stage number of groups
     pub set_num_groups: Callback<Option<u32>>,
+    /// Read slice for accessing the stage station limit, if any
+    pub max_stations: Signal<Option<u32>>,
+    /// Write slice for setting the stage station limit; `None` removes the limit
+    pub set_max_stations: Callback<Option<u32>>,
+    /// Read slice for accessing the hard time cap of the stage, if any
+    pub latest_end: Signal<Option<DateTime<Utc>>>,
+    /// Write slice for setting the hard time cap of the stage; `None` removes the cap
+    pub set_latest_end: Callback<Option<DateTime<Utc>>>,
+    /// Read slice for accessing the stage auto advance flag, if any
+    pub auto_advance: Signal<Option<bool>>,
+    /// Write slice for setting the stage auto advance flag
+    pub set_auto_advance: Callback<bool>,
+    /// Read slice for accessing the scoring policy override of the stage, if any
+    pub scoring_override: Signal<Option<ScoringOverride>>,
+    /// Write slice for setting the scoring policy override of the stage
+    pub set_scoring_override: Callback<ScoringOverride>,
 
     // --- Resource & server action state ---
     /// WriteSignal for optimistic version handling to prevent unneeded server round after save
@@ -125,17 +145,19 @@ impl EditorContext for StageEditorContext {
 
         let is_disabled_stage_editing =
             create_read_slice(options.local_tournament, move |local_tournament| {
-                if let Some(t) = local_tournament {
-                    match t.get_base().get_tournament_state() {
-                        TournamentState::ActiveStage(active_stage) => {
-                            active_stage >= options.stage_number
-                        }
-                        TournamentState::Finished => true,
-                        _ => false,
-                    }
-                } else {
-                    false
-                }
+                local_tournament.as_ref().is_some_and(|t| {
+                    !t.get_base()
+                        .get_stage_editability(options.stage_number)
+                        .is_schedule_editable()
+                })
+            });
+        let is_disabled_structure_editing =
+            create_read_slice(options.local_tournament, move |local_tournament| {
+                local_tournament.as_ref().is_some_and(|t| {
+                    !t.get_base()
+                        .get_stage_editability(options.stage_number)
+                        .is_structure_editable()
+                })
             });
 
         let tournament_id = create_read_slice(options.local_tournament, |local_tournament| {
@@ -180,6 +202,90 @@ impl EditorContext for StageEditorContext {
         let set_num_groups = Callback::new(move |num_groups: Option<u32>| {
             set_num_groups.set(num_groups.unwrap_or_default());
         });
+        let (max_stations, set_max_stations) = create_slice(
+            options.local_tournament,
+            move |local_tournament| {
+                id.get().and_then(|id| {
+                    local_tournament
+                        .as_ref()
+                        .and_then(|t| t.get_stage_by_id(id))
+                        .and_then(|s| s.get_max_stations())
+                })
+            },
+            move |local_tournament, max_stations: Option<u32>| {
+                if let Some(id) = id.get()
+                    && let Some(t) = local_tournament
+                {
+                    t.set_stage_max_stations(id, max_stations);
+                }
+            },
+        );
+        let set_max_stations = Callback::new(move |max_stations: Option<u32>| {
+            set_max_stations.set(max_stations);
+        });
+        let (latest_end, set_latest_end) = create_slice(
+            options.local_tournament,
+            move |local_tournament| {
+                id.get().and_then(|id| {
+                    local_tournament
+                        .as_ref()
+                        .and_then(|t| t.get_stage_by_id(id))
+                        .and_then(|s| s.get_latest_end())
+                })
+            },
+            move |local_tournament, latest_end: Option<DateTime<Utc>>| {
+                if let Some(id) = id.get()
+                    && let Some(t) = local_tournament
+                {
+                    t.set_stage_latest_end(id, latest_end);
+                }
+            },
+        );
+        let set_latest_end = Callback::new(move |latest_end: Option<DateTime<Utc>>| {
+            set_latest_end.set(latest_end);
+        });
+        let (auto_advance, set_auto_advance) = create_slice(
+            options.local_tournament,
+            move |local_tournament| {
+                id.get().and_then(|id| {
+                    local_tournament
+                        .as_ref()
+                        .and_then(|t| t.get_stage_by_id(id))
+                        .map(|s| s.is_auto_advance())
+                })
+            },
+            move |local_tournament, auto_advance: bool| {
+                if let Some(id) = id.get()
+                    && let Some(t) = local_tournament
+                {
+                    t.set_stage_auto_advance(id, auto_advance);
+                }
+            },
+        );
+        let set_auto_advance = Callback::new(move |auto_advance: bool| {
+            set_auto_advance.set(auto_advance);
+        });
+        let (scoring_override, set_scoring_override) = create_slice(
+            options.local_tournament,
+            move |local_tournament| {
+                id.get().and_then(|id| {
+                    local_tournament
+                        .as_ref()
+                        .and_then(|t| t.get_stage_by_id(id))
+                        .map(|s| s.get_scoring_override())
+                })
+            },
+            move |local_tournament, scoring_override: ScoringOverride| {
+                if let Some(id) = id.get()
+                    && let Some(t) = local_tournament
+                {
+                    t.set_stage_scoring_override(id, scoring_override);
+                }
+            },
+        );
+        let set_scoring_override = Callback::new(move |scoring_override: ScoringOverride| {
+            set_scoring_override.set(scoring_override);
+        });
 
         // ---- tournament stage resource ----
         let (resource_id, set_resource_id) = signal(options.object_id);
@@ -276,12 +382,21 @@ impl EditorContext for StageEditorContext {
             set_local,
             validation_result,
             is_disabled_stage_editing,
+            is_disabled_structure_editing,
             id,
             version,
             tournament_id,
             number,
             num_groups,
             set_num_groups,
+            max_stations,
+            set_max_stations,
+            latest_end,
+            set_latest_end,
+            auto_advance,
+            set_auto_advance,
+            scoring_override,
+            set_scoring_override,
             set_optimistic_version,
             load_stage,
             save_stage,
@@ -0,0 +1,81 @@
+//! version handshake of client and server
+//!
+//! Clients send their build with every server function call, see [`VersionedClient`]. After
+//! a deploy, the server refuses calls of outdated clients with
+//! [`AppError::UpgradeRequired`](crate::error::AppError::UpgradeRequired), which the ui turns
+//! into a "new version available" banner instead of failing to deserialize changed types.
+
+use futures_util::{Sink, Stream};
+use leptos::server_fn::{
+    Bytes,
+    client::{Client, browser::BrowserClient},
+    error::FromServerFnError,
+    request::browser::BrowserRequest,
+    response::browser::BrowserResponse,
+};
+use std::future::Future;
+
+/// build of the app; set `APP_BUILD_HASH` at compile time (e.g. to the git commit hash) to
+/// distinguish deploys of the same version
+pub const APP_BUILD: &str = match option_env!("APP_BUILD_HASH") {
+    Some(hash) => hash,
+    None => env!("CARGO_PKG_VERSION"),
+};
+
+/// header, which carries the build of the client with server function calls
+pub const APP_BUILD_HEADER: &str = "x-app-build";
+
+/// Returns true, if a client with build `client_build` may call server functions of this
+/// build.
+pub fn is_compatible_build(client_build: &str) -> bool {
+    client_build == APP_BUILD
+}
+
+/// Client of server functions, which adds the build of the client to each call.
+pub struct VersionedClient;
+
+impl<Error, InputStreamError, OutputStreamError> Client<Error, InputStreamError, OutputStreamError>
+    for VersionedClient
+where
+    Error: FromServerFnError,
+    InputStreamError: FromServerFnError,
+    OutputStreamError: FromServerFnError,
+{
+    type Request = BrowserRequest;
+    type Response = BrowserResponse;
+
+    fn send(req: Self::Request) -> impl Future<Output = Result<Self::Response, Error>> + Send {
+        req.headers().set(APP_BUILD_HEADER, APP_BUILD);
+        <BrowserClient as Client<Error, InputStreamError, OutputStreamError>>::send(req)
+    }
+
+    fn open_websocket(
+        path: &str,
+    ) -> impl Future<
+        Output = Result<
+            (
+                impl Stream<Item = Result<Bytes, Bytes>> + Send + 'static,
+                impl Sink<Bytes> + Send + 'static,
+            ),
+            Error,
+        >,
+    > + Send {
+        <BrowserClient as Client<Error, InputStreamError, OutputStreamError>>::open_websocket(path)
+    }
+
+    fn spawn(future: impl Future<Output = ()> + Send + 'static) {
+        <BrowserClient as Client<Error, InputStreamError, OutputStreamError>>::spawn(future)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn only_same_build_is_compatible() {
+        assert!(is_compatible_build(APP_BUILD));
+        assert!(!is_compatible_build(""));
+        assert!(!is_compatible_build("0.0.0-outdated"));
+    }
+}
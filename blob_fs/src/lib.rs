@@ -0,0 +1,91 @@
+// blob storage port storing blobs as files in a local directory
+
+use app_core::{Blob, BlobError, BlobResult, BlobStoragePort, is_valid_blob_key};
+use async_trait::async_trait;
+use std::{
+    env, io,
+    path::{Path, PathBuf},
+};
+use tracing::{instrument, warn};
+
+/// default directory of blobs, if `BLOB_STORAGE_DIR` is not set
+pub const DEFAULT_BLOB_STORAGE_DIR: &str = "blobs";
+
+/// suffix of the file, which stores the content type of a blob
+const CONTENT_TYPE_SUFFIX: &str = ".content-type";
+
+/// Blob storage port writing each blob to `<root>/<key>`. The content type is stored next to
+/// the blob in `<root>/<key>.content-type`.
+#[derive(Clone, Debug)]
+pub struct FsBlobStorage {
+    root: PathBuf,
+}
+
+impl FsBlobStorage {
+    pub fn new(root: impl Into<PathBuf>) -> Self {
+        FsBlobStorage { root: root.into() }
+    }
+
+    /// Read root directory from `BLOB_STORAGE_DIR`, see [`DEFAULT_BLOB_STORAGE_DIR`].
+    pub fn from_env() -> Self {
+        Self::new(
+            env::var("BLOB_STORAGE_DIR").unwrap_or_else(|_| DEFAULT_BLOB_STORAGE_DIR.to_string()),
+        )
+    }
+
+    pub fn root(&self) -> &Path {
+        &self.root
+    }
+
+    fn paths(&self, key: &str) -> BlobResult<(PathBuf, PathBuf)> {
+        if !is_valid_blob_key(key) {
+            return Err(BlobError::InvalidKey(key.to_string()));
+        }
+        let data = self.root.join(key);
+        let content_type = self.root.join(format!("{key}{CONTENT_TYPE_SUFFIX}"));
+        Ok((data, content_type))
+    }
+}
+
+fn storage_error(e: io::Error) -> BlobError {
+    warn!(error = %e, "blob_storage_failed");
+    BlobError::Storage(e.to_string())
+}
+
+#[async_trait]
+impl BlobStoragePort for FsBlobStorage {
+    #[instrument(name = "blob.put", skip(self, blob), fields(bytes = blob.data.len()))]
+    async fn put_blob(&self, key: &str, blob: &Blob) -> BlobResult<()> {
+        let (data, content_type) = self.paths(key)?;
+        if let Some(dir) = data.parent() {
+            tokio::fs::create_dir_all(dir)
+                .await
+                .map_err(storage_error)?;
+        }
+        tokio::fs::write(&data, &blob.data)
+            .await
+            .map_err(storage_error)?;
+        tokio::fs::write(&content_type, blob.content_type.as_bytes())
+            .await
+            .map_err(storage_error)?;
+        Ok(())
+    }
+
+    #[instrument(name = "blob.get", skip(self))]
+    async fn get_blob(&self, key: &str) -> BlobResult<Option<Blob>> {
+        let (data, content_type) = self.paths(key)?;
+        let data = match tokio::fs::read(&data).await {
+            Ok(data) => data,
+            Err(e) if e.kind() == io::ErrorKind::NotFound => return Ok(None),
+            Err(e) => return Err(storage_error(e)),
+        };
+        let content_type = match tokio::fs::read_to_string(&content_type).await {
+            Ok(content_type) => content_type,
+            Err(e) if e.kind() == io::ErrorKind::NotFound => {
+                String::from("application/octet-stream")
+            }
+            Err(e) => return Err(storage_error(e)),
+        };
+        Ok(Some(Blob { content_type, data }))
+    }
+}
@@ -0,0 +1,470 @@
+//! catch-up of messages missed while the websocket was disconnected
+//!
+//! The client registry counts the messages published on each topic. Every message carries
+//! this sequence number and an epoch, which changes, when counting starts again (see
+//! [`CrSocketMsg`](crate::CrSocketMsg)). A single server counts in memory, its epoch changes
+//! on restart. A client registry shared by several server instances (e.g. redis) counts the
+//! messages of all instances, so clients may catch up with any instance. Clients remember
+//! the last sequence number seen for each subscribed topic in [`SocketCatchUp`].
+//!
+//! When connectivity returns, e.g. after the phone was locked or the network was down,
+//! [`SocketCatchUp`] renews the subscriptions of all hooks and sends a handshake to the
+//! server ("I last saw sequence N of topic T"). The server answers with the topics, which
+//! changed in the meantime, and only their subscribers refetch. Failed handshakes are
+//! retried with exponential backoff.
+
+#[cfg(feature = "ssr")]
+use app_core::{ClientRegistryPort, CoreState};
+#[cfg(feature = "ssr")]
+use std::sync::LazyLock;
+#[cfg(any(feature = "ssr", test))]
+use std::sync::Mutex;
+use std::{collections::HashMap, time::Duration};
+
+#[cfg(any(feature = "ssr", test))]
+use app_core::CrSequence;
+use app_core::CrTopic;
+use leptos::{prelude::*, server_fn::codec::Json};
+use serde::{Deserialize, Serialize};
+use uuid::Uuid;
+
+/// delay of the first retry of a failed handshake
+const INITIAL_RETRY_DELAY: Duration = Duration::from_millis(500);
+/// maximum delay between retries of a failed handshake
+const MAX_RETRY_DELAY: Duration = Duration::from_secs(30);
+
+/// last sequence number of a topic seen by a client; `None`, if the client has no baseline
+/// for the topic yet
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Serialize, Deserialize)]
+pub struct TopicSeen {
+    pub topic: CrTopic,
+    pub seq: Option<u64>,
+}
+
+/// answer of the server to a catch-up handshake
+#[derive(Clone, Debug, PartialEq, Eq, Serialize, Deserialize)]
+pub struct CatchUp {
+    /// epoch of the server
+    pub epoch: Uuid,
+    /// topics with messages, which the client has missed
+    pub changed: Vec<CrTopic>,
+    /// current sequence numbers of all requested topics
+    pub sequences: Vec<(CrTopic, u64)>,
+}
+
+/// sequence numbers of published messages by topic
+#[cfg(any(feature = "ssr", test))]
+pub(crate) struct TopicLedger {
+    epoch: Uuid,
+    sequences: Mutex<HashMap<CrTopic, u64>>,
+}
+
+#[cfg(any(feature = "ssr", test))]
+impl TopicLedger {
+    pub(crate) fn new() -> Self {
+        TopicLedger {
+            epoch: Uuid::new_v4(),
+            sequences: Mutex::new(HashMap::new()),
+        }
+    }
+
+    /// Count a message published on `topic`. Returns the position of the message.
+    pub(crate) fn advance(&self, topic: CrTopic) -> CrSequence {
+        let mut sequences = self.sequences.lock().expect("ledger lock is not poisoned");
+        let seq = sequences.entry(topic).or_insert(0);
+        *seq += 1;
+        CrSequence {
+            epoch: self.epoch,
+            seq: *seq,
+        }
+    }
+
+    /// epoch and current sequence numbers of `topics`
+    pub(crate) fn sequences(&self, topics: &[CrTopic]) -> (Uuid, Vec<u64>) {
+        let sequences = self.sequences.lock().expect("ledger lock is not poisoned");
+        let current = topics
+            .iter()
+            .map(|topic| sequences.get(topic).copied().unwrap_or(0))
+            .collect();
+        (self.epoch, current)
+    }
+}
+
+/// Answer the handshake of a client, which knows the server by `client_epoch` and has seen
+/// `seen`. `current` are the sequence numbers of the seen topics in `epoch`. If the epoch
+/// changed since, all topics with a baseline changed.
+#[cfg(any(feature = "ssr", test))]
+pub(crate) fn answer_catch_up(
+    epoch: Uuid,
+    current: &[u64],
+    client_epoch: Option<Uuid>,
+    seen: &[TopicSeen],
+) -> CatchUp {
+    let restarted = client_epoch.is_some_and(|known| known != epoch);
+    CatchUp {
+        epoch,
+        changed: seen
+            .iter()
+            .zip(current)
+            .filter(|(s, current)| s.seq.is_some_and(|seq| restarted || **current > seq))
+            .map(|(s, _)| s.topic)
+            .collect(),
+        sequences: seen
+            .iter()
+            .zip(current)
+            .map(|(s, c)| (s.topic, *c))
+            .collect(),
+    }
+}
+
+/// ledger of all messages published by this server instance
+#[cfg(feature = "ssr")]
+pub(crate) static TOPIC_LEDGER: LazyLock<TopicLedger> = LazyLock::new(TopicLedger::new);
+
+/// Catch-up handshake: get the topics, whose messages were missed by a client, which knows
+/// the server by `epoch` and has seen `seen`.
+#[server(input = Json, output = Json)]
+pub async fn catch_up_topics(
+    epoch: Option<Uuid>,
+    seen: Vec<TopicSeen>,
+) -> Result<CatchUp, ServerFnError> {
+    let topics: Vec<CrTopic> = seen.iter().map(|s| s.topic).collect();
+    let (server_epoch, current) = match expect_context::<CoreState>()
+        .client_registry
+        .topic_sequences(&topics)
+        .await
+        .map_err(ServerFnError::new)?
+    {
+        Some(sequences) => sequences,
+        None => TOPIC_LEDGER.sequences(&topics),
+    };
+    Ok(answer_catch_up(server_epoch, &current, epoch, &seen))
+}
+
+/// hook, which subscribed a topic
+#[derive(Clone, Copy)]
+pub(crate) struct Subscriber {
+    /// subscribe the topic at the socket again
+    pub subscribe: Callback<()>,
+    /// reload the data of the hook
+    pub refetch: Callback<()>,
+}
+
+#[derive(Default)]
+struct TopicState {
+    seq: Option<u64>,
+    subscribers: HashMap<Uuid, Subscriber>,
+}
+
+#[derive(Default)]
+struct CatchUpState {
+    epoch: Option<Uuid>,
+    topics: HashMap<CrTopic, TopicState>,
+    /// a handshake is running
+    running: bool,
+    /// another handshake was requested, while a handshake was running
+    again: bool,
+    retry_delay: Option<Duration>,
+}
+
+impl CatchUpState {
+    fn seen(&self) -> Vec<TopicSeen> {
+        self.topics
+            .iter()
+            .map(|(topic, state)| TopicSeen {
+                topic: *topic,
+                seq: state.seq,
+            })
+            .collect()
+    }
+
+    /// Apply answer of server and return the subscribers of changed topics.
+    fn apply(&mut self, catch_up: CatchUp) -> Vec<Subscriber> {
+        let restarted = self.epoch.is_some_and(|epoch| epoch != catch_up.epoch);
+        self.epoch = Some(catch_up.epoch);
+        for (topic, seq) in catch_up.sequences {
+            if let Some(state) = self.topics.get_mut(&topic) {
+                // messages may have arrived while the handshake was running
+                state.seq = match state.seq {
+                    Some(seen) if !restarted => Some(seen.max(seq)),
+                    _ => Some(seq),
+                };
+            }
+        }
+        catch_up
+            .changed
+            .iter()
+            .filter_map(|topic| self.topics.get(topic))
+            .flat_map(|state| state.subscribers.values().copied())
+            .collect()
+    }
+}
+
+/// client side catch-up of missed messages, see module docs
+#[derive(Clone, Copy)]
+pub struct SocketCatchUp {
+    state: StoredValue<CatchUpState>,
+    unsubscribe: Callback<CrTopic>,
+}
+
+/// Provide [`SocketCatchUp`] for all client registry hooks. Provide the socket context
+/// before.
+pub fn provide_socket_catch_up() {
+    let socket = leptos_axum_socket::expect_socket_context();
+    let catch_up = SocketCatchUp {
+        state: StoredValue::new(CatchUpState::default()),
+        unsubscribe: Callback::new(move |topic| socket.unsubscribe(topic)),
+    };
+    catch_up.start();
+    provide_context(catch_up);
+}
+
+impl SocketCatchUp {
+    /// Register `subscriber` of hook `id` for `topic`. A baseline is requested for new topics.
+    pub(crate) fn register(&self, topic: CrTopic, id: Uuid, subscriber: Subscriber) {
+        let mut needs_baseline = false;
+        self.state.update_value(|state| {
+            let topic_state = state.topics.entry(topic).or_default();
+            topic_state.subscribers.insert(id, subscriber);
+            needs_baseline = topic_state.seq.is_none();
+        });
+        if needs_baseline {
+            self.catch_up();
+        }
+    }
+
+    /// Remove subscriber of hook `id` from `topic`.
+    pub(crate) fn unregister(&self, topic: CrTopic, id: Uuid) {
+        self.state.try_update_value(|state| {
+            if let Some(topic_state) = state.topics.get_mut(&topic) {
+                topic_state.subscribers.remove(&id);
+                if topic_state.subscribers.is_empty() {
+                    state.topics.remove(&topic);
+                }
+            }
+        });
+    }
+
+    /// Record message `seq` of `topic` received from server with `epoch`. A message of a
+    /// restarted server starts a handshake, since other topics may have changed as well.
+    pub(crate) fn seen(&self, topic: CrTopic, epoch: Uuid, seq: u64) {
+        let mut restarted = false;
+        self.state.update_value(|state| {
+            if state.epoch.is_some_and(|known| known != epoch) {
+                restarted = true;
+                return;
+            }
+            state.epoch = Some(epoch);
+            if let Some(topic_state) = state.topics.get_mut(&topic) {
+                topic_state.seq = Some(topic_state.seq.map_or(seq, |seen| seen.max(seq)));
+            }
+        });
+        if restarted {
+            self.catch_up();
+        }
+    }
+
+    /// Renew the subscriptions of all hooks and catch up on missed messages, e.g. after
+    /// connectivity returned.
+    pub fn reconnect(&self) {
+        let topics: Vec<(CrTopic, Vec<Subscriber>)> = self.state.with_value(|state| {
+            state
+                .topics
+                .iter()
+                .map(|(topic, s)| (*topic, s.subscribers.values().copied().collect()))
+                .collect()
+        });
+        for (topic, subscribers) in topics {
+            self.unsubscribe.run(topic);
+            for subscriber in subscribers {
+                subscriber.subscribe.run(());
+            }
+        }
+        self.catch_up();
+    }
+
+    /// Send a handshake to the server and refetch the changed topics. Handshakes requested
+    /// while a handshake is running are combined into one.
+    pub fn catch_up(&self) {
+        let running = self.state.with_value(|state| state.running);
+        if running {
+            self.state.update_value(|state| state.again = true);
+            return;
+        }
+        self.state.update_value(|state| {
+            state.running = true;
+            state.again = false;
+        });
+        let catch_up = *self;
+        leptos::task::spawn_local(async move {
+            let Some((epoch, seen)) = catch_up
+                .state
+                .try_with_value(|state| (state.epoch, state.seen()))
+            else {
+                return;
+            };
+            let result = catch_up_topics(epoch, seen).await;
+            let mut changed = Vec::new();
+            let mut retry_in = None;
+            catch_up.state.try_update_value(|state| {
+                state.running = false;
+                match result {
+                    Ok(answer) => {
+                        state.retry_delay = None;
+                        changed = state.apply(answer);
+                    }
+                    Err(e) => {
+                        let delay = state.retry_delay.map_or(INITIAL_RETRY_DELAY, |delay| {
+                            (delay * 2).min(MAX_RETRY_DELAY)
+                        });
+                        tracing::warn!(
+                            error = %e,
+                            retry_in_ms = delay.as_millis(),
+                            "socket_catch_up_failed"
+                        );
+                        state.retry_delay = Some(delay);
+                        retry_in = Some(delay);
+                    }
+                }
+            });
+            for subscriber in changed {
+                subscriber.refetch.try_run(());
+            }
+            match retry_in {
+                Some(delay) => catch_up.retry_after(delay),
+                None if catch_up.state.try_with_value(|s| s.again) == Some(true) => {
+                    catch_up.catch_up()
+                }
+                None => {}
+            }
+        });
+    }
+
+    /// renew subscriptions, when the browser is online again or the page becomes visible
+    #[cfg(feature = "hydrate")]
+    fn start(&self) {
+        let catch_up = *self;
+        let online = window_event_listener(leptos::ev::online, move |_| catch_up.reconnect());
+        let visible = window_event_listener(leptos::ev::visibilitychange, move |_| {
+            if !document().hidden() {
+                catch_up.reconnect();
+            }
+        });
+        on_cleanup(move || {
+            online.remove();
+            visible.remove();
+        });
+    }
+
+    /// Only clients catch up.
+    #[cfg(not(feature = "hydrate"))]
+    fn start(&self) {}
+
+    #[cfg(feature = "hydrate")]
+    fn retry_after(&self, delay: Duration) {
+        let catch_up = *self;
+        set_timeout(move || catch_up.catch_up(), delay);
+    }
+
+    #[cfg(not(feature = "hydrate"))]
+    fn retry_after(&self, _delay: Duration) {}
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn given_published_messages_when_catch_up_then_only_missed_topics_changed() {
+        let ledger = TopicLedger::new();
+        let (missed, current, new) = (
+            CrTopic::Address {
+                address_id: Uuid::new_v4(),
+            },
+            CrTopic::Address {
+                address_id: Uuid::new_v4(),
+            },
+            CrTopic::NewAddress,
+        );
+        let epoch = ledger.advance(missed).epoch;
+        ledger.advance(current);
+        assert_eq!(ledger.advance(missed).seq, 2);
+
+        let seen = [
+            TopicSeen {
+                topic: missed,
+                seq: Some(1),
+            },
+            TopicSeen {
+                topic: current,
+                seq: Some(1),
+            },
+            TopicSeen {
+                topic: new,
+                seq: None,
+            },
+        ];
+        let topics: Vec<CrTopic> = seen.iter().map(|s| s.topic).collect();
+        let (ledger_epoch, current) = ledger.sequences(&topics);
+        assert_eq!(ledger_epoch, epoch);
+        let answer = answer_catch_up(ledger_epoch, &current, Some(epoch), &seen);
+
+        assert_eq!(answer.epoch, epoch);
+        assert_eq!(answer.changed, vec![missed]);
+        assert_eq!(answer.sequences, vec![(missed, 2), (current, 1), (new, 0)]);
+    }
+
+    #[test]
+    fn given_new_epoch_when_catch_up_then_all_topics_with_baseline_changed() {
+        let (epoch, topic) = (Uuid::new_v4(), CrTopic::NewAddress);
+        let seen = [TopicSeen {
+            topic,
+            seq: Some(7),
+        }];
+
+        let answer = answer_catch_up(epoch, &[0], Some(Uuid::new_v4()), &seen);
+        assert_eq!(answer.changed, vec![topic]);
+
+        // first handshake of a client only sets the baseline
+        let answer = answer_catch_up(epoch, &[0], None, &seen);
+        assert!(answer.changed.is_empty());
+
+        // sequences counted by a shared client registry are kept by all server instances
+        let answer = answer_catch_up(epoch, &[9], Some(epoch), &seen);
+        assert_eq!(answer.changed, vec![topic]);
+        assert_eq!(answer.sequences, vec![(topic, 9)]);
+    }
+
+    #[test]
+    fn given_answer_when_apply_then_subscribers_of_changed_topics_refetch() {
+        let subscriber = Subscriber {
+            subscribe: Callback::new(|()| {}),
+            refetch: Callback::new(|()| {}),
+        };
+        let (changed, unchanged) = (
+            CrTopic::NewAddress,
+            CrTopic::NewSportConfig {
+                sport_id: Uuid::new_v4(),
+            },
+        );
+        let mut state = CatchUpState::default();
+        for topic in [changed, unchanged] {
+            let topic_state = state.topics.entry(topic).or_default();
+            topic_state.seq = Some(3);
+            topic_state.subscribers.insert(Uuid::new_v4(), subscriber);
+        }
+        let epoch = Uuid::new_v4();
+        state.epoch = Some(epoch);
+
+        let refetch = state.apply(CatchUp {
+            epoch,
+            changed: vec![changed],
+            sequences: vec![(changed, 5), (unchanged, 2)],
+        });
+
+        assert_eq!(refetch.len(), 1);
+        assert_eq!(state.topics[&changed].seq, Some(5));
+        // messages received during the handshake are kept
+        assert_eq!(state.topics[&unchanged].seq, Some(3));
+    }
+}
@@ -1,9 +1,17 @@
 // client registry based upon leptos-axum-socket
 
+mod catch_up;
+mod presence;
+
+pub use catch_up::*;
+pub use presence::*;
+
 use std::collections::HashSet;
+#[cfg(feature = "ssr")]
+use std::{sync::LazyLock, time::Instant};
 
 #[cfg(feature = "ssr")]
-use app_core::{ClientRegistryPort, CrResult};
+use app_core::{ClientRegistryPort, CrResult, CrSequence, PresenceEditor};
 use app_core::{CrMsg, CrTopic};
 #[cfg(feature = "ssr")]
 use async_trait::async_trait;
@@ -21,10 +29,15 @@ use serde::{Deserialize, Serialize};
 use shared::AppState;
 #[cfg(feature = "ssr")]
 use tracing::instrument;
+use uuid::Uuid;
 
 #[derive(Clone, Serialize, Deserialize, Debug, Hash, PartialEq, Eq)]
 pub struct CrSocketMsg {
     pub msg: CrMsg,
+    /// epoch of the sequence numbers, see [`SocketCatchUp`]
+    pub epoch: Uuid,
+    /// sequence number of the message in its topic, see [`SocketCatchUp`]
+    pub seq: u64,
 }
 
 impl SocketMsg for CrSocketMsg {
@@ -41,12 +54,47 @@ pub struct ClientRegistrySocket {}
 impl ClientRegistryPort for ClientRegistrySocket {
     #[instrument(name = "cr.publish", skip(self, msg))]
     async fn publish(&self, topic: CrTopic, msg: CrMsg) -> CrResult<()> {
-        let msg = CrSocketMsg { msg };
+        let sequence = TOPIC_LEDGER.advance(topic);
+        self.publish_sequenced(topic, msg, sequence).await
+    }
+
+    #[instrument(name = "cr.publish_sequenced", skip(self, msg))]
+    async fn publish_sequenced(
+        &self,
+        topic: CrTopic,
+        msg: CrMsg,
+        sequence: CrSequence,
+    ) -> CrResult<()> {
+        let msg = CrSocketMsg {
+            msg,
+            epoch: sequence.epoch,
+            seq: sequence.seq,
+        };
         leptos_axum_socket::send(&topic, &msg).await;
         Ok(())
     }
+
+    async fn topic_sequences(&self, topics: &[CrTopic]) -> CrResult<Option<(Uuid, Vec<u64>)>> {
+        Ok(Some(TOPIC_LEDGER.sequences(topics)))
+    }
+
+    async fn join_presence(&self, topic: CrTopic, editor: PresenceEditor) -> CrResult<bool> {
+        Ok(PRESENCE_TRACKER.join(topic, editor, Instant::now()))
+    }
+
+    async fn leave_presence(&self, topic: CrTopic, client_id: Uuid) -> CrResult<bool> {
+        Ok(PRESENCE_TRACKER.leave(topic, client_id))
+    }
+
+    async fn list_presence(&self, topic: CrTopic) -> CrResult<Vec<PresenceEditor>> {
+        Ok(PRESENCE_TRACKER.list(topic, Instant::now()))
+    }
 }
 
+/// editors tracked by this server instance
+#[cfg(feature = "ssr")]
+static PRESENCE_TRACKER: LazyLock<PresenceTracker> = LazyLock::new(PresenceTracker::default);
+
 // Implement the `connect_to_websocket` handler:
 #[cfg(feature = "ssr")]
 pub async fn connect_to_websocket(
@@ -62,34 +110,56 @@ pub async fn connect_to_websocket(
 }
 
 // client registry subscription hook for leptos components
+//
+// If `SocketCatchUp` is provided, the hook catches up on messages missed while the
+// websocket was disconnected.
 pub fn use_client_registry_socket(
     topic: Signal<Option<CrTopic>>,
     version: Signal<Option<u32>>,
     refetch: Callback<()>,
 ) {
     let socket = expect_socket_context();
+    let catch_up = use_context::<SocketCatchUp>();
+    let subscriber_id = Uuid::new_v4();
 
     let prev_topic = StoredValue::new(None::<CrTopic>);
     // we cache received messages to avoid refetching multiple times for the same message,
     // e.g. when refetching triggers resubscribe
     let received_msg = StoredValue::new(HashSet::<CrSocketMsg>::new());
 
-    let subscribe = {
-        move |topic: CrTopic, refetch: Callback<()>| {
-            let version = version.get_untracked();
-            let socket_handler = move |msg: &CrSocketMsg| {
-                if received_msg.with_value(|msgs| !msgs.contains(msg)) {
-                    received_msg.update_value(|msgs| {
-                        msgs.insert(msg.clone());
-                    });
-                } else {
-                    return;
-                }
-                if version.is_none() || Some(msg.msg.version()) > version {
-                    refetch.try_run(());
-                }
+    let subscribe_socket = move |topic: CrTopic| {
+        let version = version.get_untracked();
+        let socket_handler = move |msg: &CrSocketMsg| {
+            if let Some(catch_up) = catch_up {
+                catch_up.seen(topic, msg.epoch, msg.seq);
+            }
+            if received_msg.with_value(|msgs| !msgs.contains(msg)) {
+                received_msg.update_value(|msgs| {
+                    msgs.insert(msg.clone());
+                });
+            } else {
+                return;
+            }
+            if version.is_none() || Some(msg.msg.version()) > version {
+                refetch.try_run(());
+            }
+        };
+        socket.subscribe(topic, socket_handler);
+    };
+    let subscribe = move |topic: CrTopic| {
+        subscribe_socket(topic);
+        if let Some(catch_up) = catch_up {
+            let subscriber = Subscriber {
+                subscribe: Callback::new(move |()| subscribe_socket(topic)),
+                refetch,
             };
-            socket.subscribe(topic, socket_handler);
+            catch_up.register(topic, subscriber_id, subscriber);
+        }
+    };
+    let unsubscribe = move |topic: CrTopic| {
+        socket.unsubscribe(topic);
+        if let Some(catch_up) = catch_up {
+            catch_up.unregister(topic, subscriber_id);
         }
     };
 
@@ -100,11 +170,11 @@ pub fn use_client_registry_socket(
                 if let Some(prev_tp) = prev_topic.get_value()
                     && prev_tp != *topic
                 {
-                    socket.unsubscribe(prev_tp);
-                    subscribe(*topic, refetch);
+                    unsubscribe(prev_tp);
+                    subscribe(*topic);
                     prev_topic.set_value(Some(*topic));
                 } else if prev_topic.get_value().is_none() {
-                    subscribe(*topic, refetch);
+                    subscribe(*topic);
                     prev_topic.set_value(Some(*topic));
                 }
             }
@@ -114,7 +184,7 @@ pub fn use_client_registry_socket(
 
     on_cleanup(move || {
         if let Some(topic) = topic.get_untracked() {
-            socket.unsubscribe(topic);
+            unsubscribe(topic);
         }
     });
 }
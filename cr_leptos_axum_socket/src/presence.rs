@@ -0,0 +1,150 @@
+//! tracking of editors by presence topic
+//!
+//! Editors join the presence topic of the object they edit periodically (see
+//! [`PRESENCE_HEARTBEAT`]). Editors, who did not join again within [`PRESENCE_TTL`], e.g.
+//! because the browser was closed without leaving, are expired.
+
+#[cfg(any(feature = "ssr", test))]
+use app_core::{CrTopic, PresenceEditor};
+use std::time::Duration;
+#[cfg(any(feature = "ssr", test))]
+use std::{collections::HashMap, sync::Mutex, time::Instant};
+#[cfg(any(feature = "ssr", test))]
+use uuid::Uuid;
+
+/// interval, in which clients join the presence of the edited object again
+pub const PRESENCE_HEARTBEAT: Duration = Duration::from_secs(30);
+/// time after the last join, after which an editor expires
+pub const PRESENCE_TTL: Duration = Duration::from_secs(90);
+
+#[cfg(any(feature = "ssr", test))]
+struct Presence {
+    editor: PresenceEditor,
+    joined_at: Instant,
+    seen_at: Instant,
+}
+
+/// editors by presence topic
+#[cfg(any(feature = "ssr", test))]
+#[derive(Default)]
+pub(crate) struct PresenceTracker {
+    topics: Mutex<HashMap<CrTopic, HashMap<Uuid, Presence>>>,
+}
+
+#[cfg(any(feature = "ssr", test))]
+impl PresenceTracker {
+    /// Join or refresh `editor` at `now`. Returns true, if the editors of `topic` changed,
+    /// i.e. if the editor is new, changed its name or other editors expired.
+    pub(crate) fn join(&self, topic: CrTopic, editor: PresenceEditor, now: Instant) -> bool {
+        let mut topics = self.topics.lock().expect("presence lock is not poisoned");
+        let editors = topics.entry(topic).or_default();
+        let expired = expire(editors, now);
+        let changed = match editors.get_mut(&editor.client_id) {
+            Some(presence) => {
+                presence.seen_at = now;
+                let renamed = presence.editor.name != editor.name;
+                presence.editor = editor;
+                renamed
+            }
+            None => {
+                editors.insert(
+                    editor.client_id,
+                    Presence {
+                        editor,
+                        joined_at: now,
+                        seen_at: now,
+                    },
+                );
+                true
+            }
+        };
+        changed || expired
+    }
+
+    /// Remove editor `client_id` from `topic`. Returns true, if the editor was tracked.
+    pub(crate) fn leave(&self, topic: CrTopic, client_id: Uuid) -> bool {
+        let mut topics = self.topics.lock().expect("presence lock is not poisoned");
+        let Some(editors) = topics.get_mut(&topic) else {
+            return false;
+        };
+        let left = editors.remove(&client_id).is_some();
+        if editors.is_empty() {
+            topics.remove(&topic);
+        }
+        left
+    }
+
+    /// List the editors of `topic`, which have not expired at `now`, in order of joining.
+    pub(crate) fn list(&self, topic: CrTopic, now: Instant) -> Vec<PresenceEditor> {
+        let mut topics = self.topics.lock().expect("presence lock is not poisoned");
+        let Some(editors) = topics.get_mut(&topic) else {
+            return Vec::new();
+        };
+        expire(editors, now);
+        let mut presences: Vec<&Presence> = editors.values().collect();
+        presences.sort_by_key(|p| p.joined_at);
+        let list = presences.into_iter().map(|p| p.editor.clone()).collect();
+        if editors.is_empty() {
+            topics.remove(&topic);
+        }
+        list
+    }
+}
+
+/// Remove editors, who were not seen within [`PRESENCE_TTL`]. Returns true, if any expired.
+#[cfg(any(feature = "ssr", test))]
+fn expire(editors: &mut HashMap<Uuid, Presence>, now: Instant) -> bool {
+    let len = editors.len();
+    editors.retain(|_, p| now.saturating_duration_since(p.seen_at) < PRESENCE_TTL);
+    editors.len() < len
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn editor(name: &str) -> PresenceEditor {
+        PresenceEditor::new(Uuid::new_v4(), name)
+    }
+
+    #[test]
+    fn given_editors_when_join_and_leave_then_list_in_order_of_joining() {
+        let tracker = PresenceTracker::default();
+        let topic = CrTopic::Presence {
+            object_id: Uuid::new_v4(),
+        };
+        let (jane, joe) = (editor("Jane"), editor("Joe"));
+        let now = Instant::now();
+
+        assert!(tracker.join(topic, jane.clone(), now));
+        assert!(tracker.join(topic, joe.clone(), now + Duration::from_secs(1)));
+        // heartbeat does not change the editors
+        assert!(!tracker.join(topic, jane.clone(), now + Duration::from_secs(2)));
+        assert_eq!(
+            tracker.list(topic, now + Duration::from_secs(2)),
+            vec![jane.clone(), joe.clone()]
+        );
+
+        assert!(tracker.leave(topic, jane.client_id));
+        assert!(!tracker.leave(topic, jane.client_id));
+        assert_eq!(tracker.list(topic, now + Duration::from_secs(3)), vec![joe]);
+    }
+
+    #[test]
+    fn given_editor_without_heartbeat_when_ttl_passed_then_editor_expired() {
+        let tracker = PresenceTracker::default();
+        let topic = CrTopic::Presence {
+            object_id: Uuid::new_v4(),
+        };
+        let (gone, active) = (editor("Gone"), editor("Active"));
+        let now = Instant::now();
+        tracker.join(topic, gone, now);
+        tracker.join(topic, active.clone(), now);
+
+        let later = now + PRESENCE_TTL - Duration::from_secs(1);
+        assert!(!tracker.join(topic, active.clone(), later));
+        // expiry of other editors changes the editors
+        assert!(tracker.join(topic, active.clone(), now + PRESENCE_TTL));
+        assert_eq!(tracker.list(topic, now + PRESENCE_TTL), vec![active]);
+    }
+}
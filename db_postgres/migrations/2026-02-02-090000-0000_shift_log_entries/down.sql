@@ -0,0 +1,5 @@
+-- This file should undo anything in `up.sql`
+DROP INDEX IF EXISTS idx_shift_log_entries_tournament_created;
+
+-- Drop the table (trigger is dropped implicitly)
+DROP TABLE IF EXISTS shift_log_entries;
@@ -0,0 +1,44 @@
+-- Enable required extensions (idempotent)
+CREATE EXTENSION IF NOT EXISTS pgcrypto;
+
+-- Shift log entries (notes and handover notes) of the tournament desk
+CREATE TABLE IF NOT EXISTS shift_log_entries (
+  id               uuid PRIMARY KEY DEFAULT gen_random_uuid(),
+
+  -- Optimistic locking
+  version          bigint      NOT NULL DEFAULT 0,
+
+  -- Foreign key to the tournament
+  tournament_id    uuid        NOT NULL,
+
+  -- Content
+  author           text        NOT NULL,
+  text             text        NOT NULL,
+  pinned           boolean     NOT NULL DEFAULT false,
+
+  -- Timestamps
+  created_at       timestamptz NOT NULL DEFAULT now(),
+  updated_at       timestamptz NOT NULL DEFAULT now(),
+
+  -- Constraints
+  CONSTRAINT version_non_negative CHECK (version >= 0),
+  CONSTRAINT author_not_blank CHECK (length(btrim(author)) > 0),
+  CONSTRAINT text_not_blank CHECK (length(btrim(text)) > 0),
+
+  -- Foreign Key Constraint
+  CONSTRAINT fk_tournament
+    FOREIGN KEY(tournament_id)
+    REFERENCES tournament_bases(id)
+    ON DELETE CASCADE
+);
+
+-- Entries are always listed per tournament, newest first
+CREATE INDEX IF NOT EXISTS idx_shift_log_entries_tournament_created
+  ON shift_log_entries (tournament_id, created_at DESC);
+
+-- Re-use the existing updated_at maintenance trigger function
+DROP TRIGGER IF EXISTS set_timestamp_shift_log_entries ON shift_log_entries;
+CREATE TRIGGER set_timestamp_shift_log_entries
+BEFORE UPDATE ON shift_log_entries
+FOR EACH ROW
+EXECUTE FUNCTION trg_set_timestamp();
@@ -0,0 +1,6 @@
+-- This file should undo anything in `up.sql`
+DROP INDEX IF EXISTS idx_tournament_bases_not_archived;
+DROP INDEX IF EXISTS idx_sport_configs_not_archived;
+
+ALTER TABLE tournament_bases DROP COLUMN IF EXISTS archived_at;
+ALTER TABLE sport_configs DROP COLUMN IF EXISTS archived_at;
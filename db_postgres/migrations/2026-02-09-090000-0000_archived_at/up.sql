@@ -0,0 +1,7 @@
+-- soft delete / archive for sport configs and tournaments
+ALTER TABLE sport_configs ADD COLUMN archived_at TIMESTAMPTZ NULL;
+ALTER TABLE tournament_bases ADD COLUMN archived_at TIMESTAMPTZ NULL;
+
+-- default listings only show non archived rows
+CREATE INDEX idx_sport_configs_not_archived ON sport_configs (sport_id) WHERE archived_at IS NULL;
+CREATE INDEX idx_tournament_bases_not_archived ON tournament_bases (sport_id) WHERE archived_at IS NULL;
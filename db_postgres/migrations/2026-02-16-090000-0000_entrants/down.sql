@@ -0,0 +1,6 @@
+-- This file should undo anything in `up.sql`
+DROP INDEX IF EXISTS uniq_entrants_seed_per_tournament;
+DROP INDEX IF EXISTS uniq_entrants_name_per_tournament;
+
+-- Drop the table (trigger is dropped implicitly)
+DROP TABLE IF EXISTS entrants;
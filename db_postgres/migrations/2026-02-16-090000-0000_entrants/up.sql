@@ -0,0 +1,49 @@
+-- Enable required extensions (idempotent)
+CREATE EXTENSION IF NOT EXISTS pgcrypto;
+CREATE EXTENSION IF NOT EXISTS citext;
+
+-- Entrants (teams or individual athletes) of a tournament
+CREATE TABLE IF NOT EXISTS entrants (
+  id               uuid PRIMARY KEY DEFAULT gen_random_uuid(),
+
+  -- Optimistic locking
+  version          bigint      NOT NULL DEFAULT 0,
+
+  -- Foreign key to the tournament
+  tournament_id    uuid        NOT NULL,
+
+  -- Entrant data
+  name             citext      NOT NULL,
+  club             text        NULL,
+  seed             integer     NULL,
+
+  -- Timestamps
+  created_at       timestamptz NOT NULL DEFAULT now(),
+  updated_at       timestamptz NOT NULL DEFAULT now(),
+
+  -- Constraints
+  CONSTRAINT version_non_negative CHECK (version >= 0),
+  CONSTRAINT name_not_blank CHECK (length(btrim(name)) > 0),
+  CONSTRAINT seed_positive CHECK (seed IS NULL OR seed > 0),
+
+  -- Foreign Key Constraint
+  CONSTRAINT fk_tournament
+    FOREIGN KEY(tournament_id)
+    REFERENCES tournament_bases(id)
+    ON DELETE CASCADE
+);
+
+-- Enforce uniqueness of entrant names per tournament
+CREATE UNIQUE INDEX IF NOT EXISTS uniq_entrants_name_per_tournament
+  ON entrants (tournament_id, name);
+
+-- Enforce uniqueness of seeds per tournament (NULL seeds are not compared)
+CREATE UNIQUE INDEX IF NOT EXISTS uniq_entrants_seed_per_tournament
+  ON entrants (tournament_id, seed);
+
+-- Re-use the existing updated_at maintenance trigger function
+DROP TRIGGER IF EXISTS set_timestamp_entrants ON entrants;
+CREATE TRIGGER set_timestamp_entrants
+BEFORE UPDATE ON entrants
+FOR EACH ROW
+EXECUTE FUNCTION trg_set_timestamp();
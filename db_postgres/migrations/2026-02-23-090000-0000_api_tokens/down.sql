@@ -0,0 +1,6 @@
+-- This file should undo anything in `up.sql`
+DROP INDEX IF EXISTS uniq_api_tokens_name;
+DROP INDEX IF EXISTS uniq_api_tokens_secret_hash;
+
+-- Drop the table (trigger is dropped implicitly)
+DROP TABLE IF EXISTS api_tokens;
@@ -0,0 +1,44 @@
+-- Enable required extensions (idempotent)
+CREATE EXTENSION IF NOT EXISTS pgcrypto;
+CREATE EXTENSION IF NOT EXISTS citext;
+
+-- Tokens for the REST API; only the hash of the secret is stored
+CREATE TABLE IF NOT EXISTS api_tokens (
+  id                    uuid PRIMARY KEY DEFAULT gen_random_uuid(),
+
+  -- Optimistic locking
+  version               bigint      NOT NULL DEFAULT 0,
+
+  -- Token data
+  name                  citext      NOT NULL,
+  scopes                jsonb       NOT NULL,  -- Vec<ApiScope>
+  secret_prefix         text        NOT NULL,
+  secret_hash           text        NOT NULL,
+  rate_limit_per_minute integer     NOT NULL,
+
+  -- Timestamps
+  created_at            timestamptz NOT NULL DEFAULT now(),
+  updated_at            timestamptz NOT NULL DEFAULT now(),
+  last_used_at          timestamptz NULL,
+  revoked_at            timestamptz NULL,
+
+  -- Constraints
+  CONSTRAINT version_non_negative CHECK (version >= 0),
+  CONSTRAINT name_not_blank CHECK (length(btrim(name)) > 0),
+  CONSTRAINT rate_limit_positive CHECK (rate_limit_per_minute > 0)
+);
+
+-- Tokens are looked up by hash of their secret on every request
+CREATE UNIQUE INDEX IF NOT EXISTS uniq_api_tokens_secret_hash
+  ON api_tokens (secret_hash);
+
+-- Enforce uniqueness of token names
+CREATE UNIQUE INDEX IF NOT EXISTS uniq_api_tokens_name
+  ON api_tokens (name);
+
+-- Re-use the existing updated_at maintenance trigger function
+DROP TRIGGER IF EXISTS set_timestamp_api_tokens ON api_tokens;
+CREATE TRIGGER set_timestamp_api_tokens
+BEFORE UPDATE ON api_tokens
+FOR EACH ROW
+EXECUTE FUNCTION trg_set_timestamp();
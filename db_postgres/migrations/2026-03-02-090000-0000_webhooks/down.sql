@@ -0,0 +1,8 @@
+-- This file should undo anything in `up.sql`
+DROP INDEX IF EXISTS idx_webhook_deliveries_endpoint_attempted_at;
+DROP TABLE IF EXISTS webhook_deliveries;
+
+DROP INDEX IF EXISTS uniq_webhook_endpoints_name;
+
+-- Drop the table (trigger is dropped implicitly)
+DROP TABLE IF EXISTS webhook_endpoints;
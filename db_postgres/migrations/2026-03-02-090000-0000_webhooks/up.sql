@@ -0,0 +1,57 @@
+-- Enable required extensions (idempotent)
+CREATE EXTENSION IF NOT EXISTS pgcrypto;
+CREATE EXTENSION IF NOT EXISTS citext;
+
+-- Receivers of webhook deliveries
+CREATE TABLE IF NOT EXISTS webhook_endpoints (
+  id            uuid PRIMARY KEY DEFAULT gen_random_uuid(),
+
+  -- Optimistic locking
+  version       bigint      NOT NULL DEFAULT 0,
+
+  -- Endpoint data
+  name          citext      NOT NULL,
+  url           text        NOT NULL,
+  secret        text        NOT NULL,  -- required in clear text to sign payloads
+  event_types   jsonb       NOT NULL,  -- Vec<WebhookEventType>
+  active        boolean     NOT NULL DEFAULT true,
+
+  -- Timestamps
+  created_at    timestamptz NOT NULL DEFAULT now(),
+  updated_at    timestamptz NOT NULL DEFAULT now(),
+
+  -- Constraints
+  CONSTRAINT version_non_negative CHECK (version >= 0),
+  CONSTRAINT name_not_blank CHECK (length(btrim(name)) > 0),
+  CONSTRAINT url_http CHECK (url ~ '^https?://')
+);
+
+-- Enforce uniqueness of endpoint names
+CREATE UNIQUE INDEX IF NOT EXISTS uniq_webhook_endpoints_name
+  ON webhook_endpoints (name);
+
+-- Re-use the existing updated_at maintenance trigger function
+DROP TRIGGER IF EXISTS set_timestamp_webhook_endpoints ON webhook_endpoints;
+CREATE TRIGGER set_timestamp_webhook_endpoints
+BEFORE UPDATE ON webhook_endpoints
+FOR EACH ROW
+EXECUTE FUNCTION trg_set_timestamp();
+
+-- History of delivery attempts; rows are never updated
+CREATE TABLE IF NOT EXISTS webhook_deliveries (
+  id            uuid PRIMARY KEY,
+  endpoint_id   uuid        NOT NULL REFERENCES webhook_endpoints (id) ON DELETE CASCADE,
+  event_id      uuid        NOT NULL,
+  event_type    text        NOT NULL,
+  payload       text        NOT NULL,  -- body as sent, signature depends on exact bytes
+  status_code   integer     NULL,
+  error         text        NULL,
+  duration_ms   integer     NOT NULL,
+  attempted_at  timestamptz NOT NULL,
+
+  CONSTRAINT duration_non_negative CHECK (duration_ms >= 0)
+);
+
+-- History is listed per endpoint, newest first
+CREATE INDEX IF NOT EXISTS idx_webhook_deliveries_endpoint_attempted_at
+  ON webhook_deliveries (endpoint_id, attempted_at DESC);
@@ -0,0 +1,2 @@
+-- This file should undo anything in `up.sql`
+ALTER TABLE webhook_endpoints DROP COLUMN IF EXISTS format;
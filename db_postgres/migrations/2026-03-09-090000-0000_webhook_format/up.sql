@@ -0,0 +1,3 @@
+-- format of posted body: versioned JSON payload or chat message for Discord / Slack
+ALTER TABLE webhook_endpoints ADD COLUMN format TEXT NOT NULL DEFAULT 'json'
+  CONSTRAINT webhook_endpoints_format_known CHECK (format IN ('json', 'discord', 'slack'));
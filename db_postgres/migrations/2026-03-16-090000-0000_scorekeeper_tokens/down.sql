@@ -0,0 +1,6 @@
+-- This file should undo anything in `up.sql`
+DROP INDEX IF EXISTS uniq_scorekeeper_tokens_label_per_tournament;
+DROP INDEX IF EXISTS uniq_scorekeeper_tokens_secret_hash;
+
+-- Drop the table (trigger is dropped implicitly)
+DROP TABLE IF EXISTS scorekeeper_tokens;
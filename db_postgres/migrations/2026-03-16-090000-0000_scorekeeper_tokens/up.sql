@@ -0,0 +1,52 @@
+-- Enable required extensions (idempotent)
+CREATE EXTENSION IF NOT EXISTS pgcrypto;
+CREATE EXTENSION IF NOT EXISTS citext;
+
+-- Access tokens of scorekeepers; only the hash of the secret is stored
+CREATE TABLE IF NOT EXISTS scorekeeper_tokens (
+  id               uuid PRIMARY KEY DEFAULT gen_random_uuid(),
+
+  -- Optimistic locking
+  version          bigint      NOT NULL DEFAULT 0,
+
+  -- Foreign key to the tournament
+  tournament_id    uuid        NOT NULL,
+
+  -- Token data
+  label            citext      NOT NULL,
+  grants           jsonb       NOT NULL,  -- Vec<ScorekeeperGrant>
+  secret_prefix    text        NOT NULL,
+  secret_hash      text        NOT NULL,
+
+  -- Timestamps
+  expires_at       timestamptz NULL,
+  created_at       timestamptz NOT NULL DEFAULT now(),
+  updated_at       timestamptz NOT NULL DEFAULT now(),
+  last_used_at     timestamptz NULL,
+  revoked_at       timestamptz NULL,
+
+  -- Constraints
+  CONSTRAINT version_non_negative CHECK (version >= 0),
+  CONSTRAINT label_not_blank CHECK (length(btrim(label)) > 0),
+
+  -- Foreign Key Constraint
+  CONSTRAINT fk_tournament
+    FOREIGN KEY(tournament_id)
+    REFERENCES tournament_bases(id)
+    ON DELETE CASCADE
+);
+
+-- Tokens are looked up by hash of their secret on every request
+CREATE UNIQUE INDEX IF NOT EXISTS uniq_scorekeeper_tokens_secret_hash
+  ON scorekeeper_tokens (secret_hash);
+
+-- Enforce uniqueness of token labels per tournament
+CREATE UNIQUE INDEX IF NOT EXISTS uniq_scorekeeper_tokens_label_per_tournament
+  ON scorekeeper_tokens (tournament_id, label);
+
+-- Re-use the existing updated_at maintenance trigger function
+DROP TRIGGER IF EXISTS set_timestamp_scorekeeper_tokens ON scorekeeper_tokens;
+CREATE TRIGGER set_timestamp_scorekeeper_tokens
+BEFORE UPDATE ON scorekeeper_tokens
+FOR EACH ROW
+EXECUTE FUNCTION trg_set_timestamp();
@@ -0,0 +1,2 @@
+-- optional email address of entrant for notifications
+ALTER TABLE entrants ADD COLUMN email TEXT NULL;
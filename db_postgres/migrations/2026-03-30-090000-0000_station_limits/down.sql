@@ -0,0 +1,3 @@
+-- This file should undo anything in `up.sql`
+ALTER TABLE stages DROP COLUMN IF EXISTS max_stations;
+ALTER TABLE tournament_bases DROP COLUMN IF EXISTS num_stations;
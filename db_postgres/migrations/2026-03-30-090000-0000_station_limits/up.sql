@@ -0,0 +1,3 @@
+-- number of stations of tournament and optional station limit of stages
+ALTER TABLE tournament_bases ADD COLUMN num_stations INT4 NOT NULL DEFAULT 1 CHECK (num_stations >= 1);
+ALTER TABLE stages ADD COLUMN max_stations INT4 NULL CHECK (max_stations >= 1);
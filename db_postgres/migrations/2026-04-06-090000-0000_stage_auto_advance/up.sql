@@ -0,0 +1,2 @@
+-- generate next round of stage automatically, when all results of a round are confirmed
+ALTER TABLE stages ADD COLUMN auto_advance BOOLEAN NOT NULL DEFAULT TRUE;
@@ -0,0 +1,46 @@
+-- Enable required extensions (idempotent)
+CREATE EXTENSION IF NOT EXISTS pgcrypto;
+
+-- Manual overrides of generated pairings; rows are append only and serve as audit trail
+CREATE TABLE IF NOT EXISTS pairing_overrides (
+  id               uuid PRIMARY KEY DEFAULT gen_random_uuid(),
+
+  -- Optimistic locking
+  version          bigint      NOT NULL DEFAULT 0,
+
+  -- Foreign keys to tournament and stage
+  tournament_id    uuid        NOT NULL,
+  stage_id         uuid        NOT NULL,
+
+  -- Round of stage
+  round_number     integer     NOT NULL,
+
+  -- Content
+  adjustments      jsonb       NOT NULL,
+  note             text        NOT NULL,
+  author           text        NOT NULL,
+
+  -- Timestamps
+  created_at       timestamptz NOT NULL DEFAULT now(),
+  updated_at       timestamptz NOT NULL DEFAULT now(),
+
+  -- Constraints
+  CONSTRAINT version_non_negative CHECK (version >= 0),
+  CONSTRAINT round_number_non_negative CHECK (round_number >= 0),
+  CONSTRAINT note_not_blank CHECK (length(btrim(note)) > 0),
+  CONSTRAINT author_not_blank CHECK (length(btrim(author)) > 0),
+
+  -- Foreign Key Constraints
+  CONSTRAINT fk_tournament
+    FOREIGN KEY(tournament_id)
+    REFERENCES tournament_bases(id)
+    ON DELETE CASCADE,
+  CONSTRAINT fk_stage
+    FOREIGN KEY(stage_id)
+    REFERENCES stages(id)
+    ON DELETE CASCADE
+);
+
+-- Overrides are always listed per round, oldest first
+CREATE INDEX IF NOT EXISTS idx_pairing_overrides_stage_round_created
+  ON pairing_overrides (stage_id, round_number, created_at);
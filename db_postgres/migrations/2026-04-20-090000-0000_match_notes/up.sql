@@ -0,0 +1,50 @@
+-- Enable required extensions (idempotent)
+CREATE EXTENSION IF NOT EXISTS pgcrypto;
+
+-- Notes and tags of matches; at most one note per match
+CREATE TABLE IF NOT EXISTS match_notes (
+  id               uuid PRIMARY KEY DEFAULT gen_random_uuid(),
+
+  -- Optimistic locking
+  version          bigint      NOT NULL DEFAULT 0,
+
+  -- Foreign key to the tournament
+  tournament_id    uuid        NOT NULL,
+
+  -- Annotated match; matches are not persisted yet, therefore no foreign key
+  match_id         uuid        NOT NULL,
+
+  -- Content
+  tags             text[]      NOT NULL DEFAULT '{}',
+  text             text        NOT NULL DEFAULT '',
+  author           text        NOT NULL,
+
+  -- Timestamps
+  created_at       timestamptz NOT NULL DEFAULT now(),
+  updated_at       timestamptz NOT NULL DEFAULT now(),
+
+  -- Constraints
+  CONSTRAINT version_non_negative CHECK (version >= 0),
+  CONSTRAINT author_not_blank CHECK (length(btrim(author)) > 0),
+  CONSTRAINT tags_or_text CHECK (cardinality(tags) > 0 OR length(btrim(text)) > 0),
+  CONSTRAINT uq_match_notes_match UNIQUE (match_id),
+
+  -- Foreign Key Constraint
+  CONSTRAINT fk_tournament
+    FOREIGN KEY(tournament_id)
+    REFERENCES tournament_bases(id)
+    ON DELETE CASCADE
+);
+
+-- Notes are listed per tournament and filtered by tag
+CREATE INDEX IF NOT EXISTS idx_match_notes_tournament
+  ON match_notes (tournament_id, updated_at DESC);
+CREATE INDEX IF NOT EXISTS idx_match_notes_tags
+  ON match_notes USING GIN (tags);
+
+-- Re-use the existing updated_at maintenance trigger function
+DROP TRIGGER IF EXISTS set_timestamp_match_notes ON match_notes;
+CREATE TRIGGER set_timestamp_match_notes
+BEFORE UPDATE ON match_notes
+FOR EACH ROW
+EXECUTE FUNCTION trg_set_timestamp();
@@ -0,0 +1,45 @@
+-- Enable required extensions (idempotent)
+CREATE EXTENSION IF NOT EXISTS pgcrypto;
+
+-- Feedback and bug reports of users with context of the client; append only
+CREATE TABLE IF NOT EXISTS feedback (
+  id               uuid PRIMARY KEY DEFAULT gen_random_uuid(),
+
+  -- Optimistic locking
+  version          bigint      NOT NULL DEFAULT 0,
+
+  -- Content
+  message          text        NOT NULL,
+
+  -- Context of the client
+  route            text        NOT NULL,
+  tournament_id    uuid        NULL,
+  app_version      text        NOT NULL DEFAULT '',
+  recent_errors    text[]      NOT NULL DEFAULT '{}',
+
+  -- Timestamps
+  created_at       timestamptz NOT NULL DEFAULT now(),
+  updated_at       timestamptz NOT NULL DEFAULT now(),
+
+  -- Constraints
+  CONSTRAINT version_non_negative CHECK (version >= 0),
+  CONSTRAINT message_not_blank CHECK (length(btrim(message)) > 0),
+  CONSTRAINT route_not_blank CHECK (length(btrim(route)) > 0),
+
+  -- Foreign Key Constraint; feedback outlives deleted tournaments
+  CONSTRAINT fk_tournament
+    FOREIGN KEY(tournament_id)
+    REFERENCES tournament_bases(id)
+    ON DELETE SET NULL
+);
+
+-- Feedback is listed newest first
+CREATE INDEX IF NOT EXISTS idx_feedback_created
+  ON feedback (created_at DESC);
+
+-- Re-use the existing updated_at maintenance trigger function
+DROP TRIGGER IF EXISTS set_timestamp_feedback ON feedback;
+CREATE TRIGGER set_timestamp_feedback
+BEFORE UPDATE ON feedback
+FOR EACH ROW
+EXECUTE FUNCTION trg_set_timestamp();
@@ -0,0 +1,40 @@
+-- Enable required extensions (idempotent)
+CREATE EXTENSION IF NOT EXISTS pgcrypto;
+
+-- Uncaught errors reported by clients; append only
+CREATE TABLE IF NOT EXISTS client_errors (
+  id               uuid PRIMARY KEY DEFAULT gen_random_uuid(),
+
+  -- Optimistic locking
+  version          bigint      NOT NULL DEFAULT 0,
+
+  -- Context of the client
+  component_id     uuid        NULL,
+  route            text        NOT NULL,
+  app_version      text        NOT NULL DEFAULT '',
+
+  -- Content; occurrences since the last report of the same error
+  message          text        NOT NULL,
+  occurrences      integer     NOT NULL DEFAULT 1,
+
+  -- Timestamps
+  created_at       timestamptz NOT NULL DEFAULT now(),
+  updated_at       timestamptz NOT NULL DEFAULT now(),
+
+  -- Constraints
+  CONSTRAINT version_non_negative CHECK (version >= 0),
+  CONSTRAINT message_not_blank CHECK (length(btrim(message)) > 0),
+  CONSTRAINT route_not_blank CHECK (length(btrim(route)) > 0),
+  CONSTRAINT occurrences_positive CHECK (occurrences > 0)
+);
+
+-- Client errors are listed newest first
+CREATE INDEX IF NOT EXISTS idx_client_errors_created
+  ON client_errors (created_at DESC);
+
+-- Re-use the existing updated_at maintenance trigger function
+DROP TRIGGER IF EXISTS set_timestamp_client_errors ON client_errors;
+CREATE TRIGGER set_timestamp_client_errors
+BEFORE UPDATE ON client_errors
+FOR EACH ROW
+EXECUTE FUNCTION trg_set_timestamp();
@@ -0,0 +1,2 @@
+-- This file should undo anything in `up.sql`
+ALTER TABLE tournament_bases DROP COLUMN IF EXISTS sandbox;
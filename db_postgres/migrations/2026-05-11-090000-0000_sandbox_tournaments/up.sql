@@ -0,0 +1,2 @@
+-- sandbox tournaments are for practice and hidden from the public
+ALTER TABLE tournament_bases ADD COLUMN sandbox BOOLEAN NOT NULL DEFAULT FALSE;
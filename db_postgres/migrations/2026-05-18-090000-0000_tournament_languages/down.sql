@@ -0,0 +1,3 @@
+-- This file should undo anything in `up.sql`
+ALTER TABLE tournament_bases DROP COLUMN IF EXISTS description;
+ALTER TABLE tournament_bases DROP COLUMN IF EXISTS languages;
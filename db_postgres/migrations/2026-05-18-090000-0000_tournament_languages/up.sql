@@ -0,0 +1,3 @@
+-- display languages of public pages and description per language
+ALTER TABLE tournament_bases ADD COLUMN languages JSONB NOT NULL DEFAULT '[]'::jsonb;
+ALTER TABLE tournament_bases ADD COLUMN description JSONB NOT NULL DEFAULT '{"text": ""}'::jsonb;
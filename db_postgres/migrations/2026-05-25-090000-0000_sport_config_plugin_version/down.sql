@@ -0,0 +1,2 @@
+-- This file should undo anything in `up.sql`
+ALTER TABLE sport_configs DROP COLUMN IF EXISTS plugin_version;
@@ -0,0 +1,2 @@
+-- version of sport plugin, which wrote the sport specific configuration
+ALTER TABLE sport_configs ADD COLUMN plugin_version INTEGER NOT NULL DEFAULT 0;
@@ -0,0 +1,2 @@
+-- named stations of tournament, e.g. courts of a venue
+ALTER TABLE tournament_bases ADD COLUMN stations JSONB NOT NULL DEFAULT '[]'::jsonb;
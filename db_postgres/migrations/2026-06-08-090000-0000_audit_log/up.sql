@@ -0,0 +1,52 @@
+-- Enable required extensions (idempotent)
+CREATE EXTENSION IF NOT EXISTS pgcrypto;
+
+-- Records of all mutating operations; append only.
+-- No foreign keys: records must outlive purged tournaments and deleted objects.
+CREATE TABLE IF NOT EXISTS audit_log (
+  id               uuid PRIMARY KEY DEFAULT gen_random_uuid(),
+
+  -- Optimistic locking
+  version          bigint      NOT NULL DEFAULT 0,
+
+  -- Who did what
+  actor            text        NOT NULL,
+  action           text        NOT NULL,
+
+  -- Changed object; tournament_id is NULL for global objects
+  object_type      text        NOT NULL,
+  object_id        uuid        NOT NULL,
+  tournament_id    uuid        NULL,
+
+  -- Versions of the object before and after the change
+  old_version      bigint      NULL,
+  new_version      bigint      NULL,
+
+  -- Changed fields: {"<field>": {"old": ..., "new": ...}}
+  diff             jsonb       NOT NULL DEFAULT '{}'::jsonb,
+
+  -- Timestamps
+  created_at       timestamptz NOT NULL DEFAULT now(),
+  updated_at       timestamptz NOT NULL DEFAULT now(),
+
+  -- Constraints
+  CONSTRAINT version_non_negative CHECK (version >= 0),
+  CONSTRAINT actor_not_blank CHECK (length(btrim(actor)) > 0),
+  CONSTRAINT action_known CHECK (action IN ('create', 'update', 'archive', 'revoke', 'delete')),
+  CONSTRAINT diff_is_object CHECK (jsonb_typeof(diff) = 'object')
+);
+
+-- History of a tournament is listed newest first
+CREATE INDEX IF NOT EXISTS idx_audit_log_tournament_created
+  ON audit_log (tournament_id, created_at DESC);
+
+-- History of a single object
+CREATE INDEX IF NOT EXISTS idx_audit_log_object
+  ON audit_log (object_id, created_at DESC);
+
+-- Re-use the existing updated_at maintenance trigger function
+DROP TRIGGER IF EXISTS set_timestamp_audit_log ON audit_log;
+CREATE TRIGGER set_timestamp_audit_log
+BEFORE UPDATE ON audit_log
+FOR EACH ROW
+EXECUTE FUNCTION trg_set_timestamp();
@@ -0,0 +1,2 @@
+-- This file should undo anything in `up.sql`
+ALTER TABLE entrants DROP COLUMN IF EXISTS checked_in_at;
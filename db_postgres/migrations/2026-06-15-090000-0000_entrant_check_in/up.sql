@@ -0,0 +1,2 @@
+-- time of check-in of entrant on tournament day; NULL, if not checked in
+ALTER TABLE entrants ADD COLUMN checked_in_at TIMESTAMPTZ NULL;
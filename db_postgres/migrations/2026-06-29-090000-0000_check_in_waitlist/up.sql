@@ -0,0 +1,7 @@
+-- position of entrant on the waitlist starting at 1; NULL, if entrant is in the field
+ALTER TABLE entrants ADD COLUMN waitlist_position INTEGER NULL;
+-- entrant did not check in by the deadline and was removed from the field
+ALTER TABLE entrants ADD COLUMN no_show BOOLEAN NOT NULL DEFAULT false;
+-- check-in deadline of tournament and handling of entrants, who are not checked in by then
+ALTER TABLE tournament_bases ADD COLUMN check_in_deadline TIMESTAMPTZ NULL;
+ALTER TABLE tournament_bases ADD COLUMN no_show_policy JSONB NOT NULL DEFAULT '"ReplaceFromWaitlist"'::jsonb;
@@ -0,0 +1,2 @@
+-- This file should undo anything in `up.sql`
+ALTER TABLE stages DROP COLUMN IF EXISTS scoring_override;
@@ -0,0 +1,2 @@
+-- availability of stations over the tournament, e.g. 4 courts in the morning and 6 in the afternoon
+ALTER TABLE tournament_bases ADD COLUMN station_windows JSONB NOT NULL DEFAULT '[]'::jsonb;
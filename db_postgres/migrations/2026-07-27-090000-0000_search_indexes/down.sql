@@ -0,0 +1,8 @@
+-- This file should undo anything in `up.sql`
+DROP INDEX IF EXISTS idx_postal_addresses_locality_trgm;
+DROP INDEX IF EXISTS idx_postal_addresses_street_trgm;
+DROP INDEX IF EXISTS idx_postal_addresses_name_trgm;
+DROP INDEX IF EXISTS idx_sport_configs_name_trgm;
+DROP INDEX IF EXISTS idx_entrants_club_trgm;
+DROP INDEX IF EXISTS idx_entrants_name_trgm;
+DROP INDEX IF EXISTS idx_tournament_bases_name_trgm;
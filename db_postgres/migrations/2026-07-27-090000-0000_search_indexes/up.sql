@@ -0,0 +1,24 @@
+-- Trigram indexes for the global search; ILIKE '%term%' queries on text use them.
+-- citext columns are indexed as text, therefore the search casts them to text.
+CREATE EXTENSION IF NOT EXISTS pg_trgm;
+
+CREATE INDEX IF NOT EXISTS idx_tournament_bases_name_trgm
+  ON tournament_bases USING gin ((name::text) gin_trgm_ops);
+
+CREATE INDEX IF NOT EXISTS idx_entrants_name_trgm
+  ON entrants USING gin ((name::text) gin_trgm_ops);
+
+CREATE INDEX IF NOT EXISTS idx_entrants_club_trgm
+  ON entrants USING gin (club gin_trgm_ops);
+
+CREATE INDEX IF NOT EXISTS idx_sport_configs_name_trgm
+  ON sport_configs USING gin ((name::text) gin_trgm_ops);
+
+CREATE INDEX IF NOT EXISTS idx_postal_addresses_name_trgm
+  ON postal_addresses USING gin ((name::text) gin_trgm_ops);
+
+CREATE INDEX IF NOT EXISTS idx_postal_addresses_street_trgm
+  ON postal_addresses USING gin (street gin_trgm_ops);
+
+CREATE INDEX IF NOT EXISTS idx_postal_addresses_locality_trgm
+  ON postal_addresses USING gin (locality gin_trgm_ops);
@@ -0,0 +1,4 @@
+-- This file should undo anything in `up.sql`
+ALTER TABLE tournament_bases DROP COLUMN IF EXISTS address_id;
+ALTER TABLE postal_addresses DROP COLUMN IF EXISTS longitude;
+ALTER TABLE postal_addresses DROP COLUMN IF EXISTS latitude;
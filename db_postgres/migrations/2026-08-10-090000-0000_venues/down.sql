@@ -0,0 +1,4 @@
+-- This file should undo anything in `up.sql`
+ALTER TABLE tournament_bases DROP COLUMN IF EXISTS venue_id;
+DROP TRIGGER IF EXISTS set_timestamp_venues ON venues;
+DROP TABLE IF EXISTS venues;
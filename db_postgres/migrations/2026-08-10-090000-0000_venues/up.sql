@@ -0,0 +1,34 @@
+-- Venues of tournaments, e.g. sports halls with their courts
+CREATE TABLE IF NOT EXISTS venues (
+  id            uuid PRIMARY KEY DEFAULT gen_random_uuid(),
+
+  -- Optimistic locking
+  version       bigint      NOT NULL DEFAULT 0,
+
+  -- Venue data
+  name          citext      NOT NULL,
+  address_id    uuid        NULL REFERENCES postal_addresses (id),
+  courts        jsonb       NOT NULL DEFAULT '[]'::jsonb,  -- Vec<Court>
+
+  -- Timestamps
+  created_at    timestamptz NOT NULL DEFAULT now(),
+  updated_at    timestamptz NOT NULL DEFAULT now(),
+
+  -- Constraints
+  CONSTRAINT version_non_negative CHECK (version >= 0),
+  CONSTRAINT name_not_blank CHECK (length(btrim(name)) > 0)
+);
+
+-- Enforce uniqueness of venue names
+CREATE UNIQUE INDEX IF NOT EXISTS uniq_venues_name
+  ON venues (name);
+
+-- Re-use the existing updated_at maintenance trigger function
+DROP TRIGGER IF EXISTS set_timestamp_venues ON venues;
+CREATE TRIGGER set_timestamp_venues
+BEFORE UPDATE ON venues
+FOR EACH ROW
+EXECUTE FUNCTION trg_set_timestamp();
+
+-- venue of the tournament, whose courts were taken as stations
+ALTER TABLE tournament_bases ADD COLUMN venue_id UUID NULL REFERENCES venues(id);
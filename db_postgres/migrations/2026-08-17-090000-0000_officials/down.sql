@@ -0,0 +1,3 @@
+-- This file should undo anything in `up.sql`
+DROP TRIGGER IF EXISTS set_timestamp_officials ON officials;
+DROP TABLE IF EXISTS officials;
@@ -0,0 +1,41 @@
+-- Officials (e.g. referees) of a tournament
+CREATE TABLE IF NOT EXISTS officials (
+  id               uuid PRIMARY KEY DEFAULT gen_random_uuid(),
+
+  -- Optimistic locking
+  version          bigint      NOT NULL DEFAULT 0,
+
+  -- Foreign key to the tournament
+  tournament_id    uuid        NOT NULL,
+
+  -- Official data
+  name             citext      NOT NULL,
+  club             text        NULL,
+  qualifications   jsonb       NOT NULL DEFAULT '[]'::jsonb,  -- Vec<String>
+  availability     jsonb       NOT NULL DEFAULT '[]'::jsonb,  -- Vec<AvailabilityWindow>
+
+  -- Timestamps
+  created_at       timestamptz NOT NULL DEFAULT now(),
+  updated_at       timestamptz NOT NULL DEFAULT now(),
+
+  -- Constraints
+  CONSTRAINT version_non_negative CHECK (version >= 0),
+  CONSTRAINT name_not_blank CHECK (length(btrim(name)) > 0),
+
+  -- Foreign Key Constraint
+  CONSTRAINT fk_tournament
+    FOREIGN KEY(tournament_id)
+    REFERENCES tournament_bases(id)
+    ON DELETE CASCADE
+);
+
+-- Enforce uniqueness of official names per tournament
+CREATE UNIQUE INDEX IF NOT EXISTS uniq_officials_name_per_tournament
+  ON officials (tournament_id, name);
+
+-- Re-use the existing updated_at maintenance trigger function
+DROP TRIGGER IF EXISTS set_timestamp_officials ON officials;
+CREATE TRIGGER set_timestamp_officials
+BEFORE UPDATE ON officials
+FOR EACH ROW
+EXECUTE FUNCTION trg_set_timestamp();
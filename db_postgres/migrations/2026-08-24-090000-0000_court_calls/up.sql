@@ -0,0 +1,2 @@
+-- calls of matches to their stations: call-ahead and notification of entrants
+ALTER TABLE tournament_bases ADD COLUMN court_call_policy JSONB NOT NULL DEFAULT '{}'::jsonb;  -- CourtCallPolicy
@@ -0,0 +1,31 @@
+-- Cached standings of groups, recomputed when results of matches change
+CREATE TABLE IF NOT EXISTS group_standings (
+  group_id         uuid PRIMARY KEY,
+
+  -- Foreign key to the tournament
+  tournament_id    uuid        NOT NULL,
+
+  -- Cached data
+  standings        jsonb       NOT NULL DEFAULT '[]'::jsonb,  -- Vec<GroupStanding>
+  computed_at      timestamptz NOT NULL,
+
+  -- Timestamps
+  created_at       timestamptz NOT NULL DEFAULT now(),
+  updated_at       timestamptz NOT NULL DEFAULT now(),
+
+  -- Foreign Key Constraint
+  CONSTRAINT fk_tournament
+    FOREIGN KEY(tournament_id)
+    REFERENCES tournament_bases(id)
+    ON DELETE CASCADE
+);
+
+CREATE INDEX IF NOT EXISTS idx_group_standings_tournament
+  ON group_standings (tournament_id);
+
+-- Re-use the existing updated_at maintenance trigger function
+DROP TRIGGER IF EXISTS set_timestamp_group_standings ON group_standings;
+CREATE TRIGGER set_timestamp_group_standings
+BEFORE UPDATE ON group_standings
+FOR EACH ROW
+EXECUTE FUNCTION trg_set_timestamp();
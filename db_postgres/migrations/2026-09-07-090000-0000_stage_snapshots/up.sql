@@ -0,0 +1,29 @@
+-- Snapshots of stages taken before promotion to the next stage
+CREATE TABLE IF NOT EXISTS stage_snapshots (
+  id               uuid PRIMARY KEY,
+
+  -- Foreign keys to the tournament and the stage
+  tournament_id    uuid        NOT NULL,
+  stage_id         uuid        NOT NULL,
+  stage_number     integer     NOT NULL,
+
+  -- Snapshot data
+  matches          jsonb       NOT NULL DEFAULT '[]'::jsonb,  -- Vec<Match>
+  standings        jsonb       NOT NULL DEFAULT '[]'::jsonb,  -- Vec<CachedGroupStandings>
+
+  -- Timestamps
+  created_at       timestamptz NOT NULL DEFAULT now(),
+
+  -- Foreign Key Constraints
+  CONSTRAINT fk_tournament
+    FOREIGN KEY(tournament_id)
+    REFERENCES tournament_bases(id)
+    ON DELETE CASCADE,
+  CONSTRAINT fk_stage
+    FOREIGN KEY(stage_id)
+    REFERENCES stages(id)
+    ON DELETE CASCADE
+);
+
+CREATE INDEX IF NOT EXISTS idx_stage_snapshots_tournament
+  ON stage_snapshots (tournament_id, stage_number);
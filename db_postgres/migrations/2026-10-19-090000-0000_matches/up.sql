@@ -0,0 +1,45 @@
+-- Matches of stages; the match itself is stored as json, the columns are used for lookups
+CREATE TABLE IF NOT EXISTS matches (
+  id               uuid PRIMARY KEY,
+
+  -- Foreign keys to the tournament and the stage
+  tournament_id    uuid        NOT NULL,
+  stage_id         uuid        NOT NULL,
+  group_id         uuid        NOT NULL,
+  round_id         uuid        NOT NULL,
+  number           integer     NOT NULL,
+
+  -- Schedule
+  station          integer     NOT NULL,
+  start_at         timestamptz NOT NULL,
+
+  -- Match data
+  data             jsonb       NOT NULL,  -- Match
+
+  -- Timestamps
+  created_at       timestamptz NOT NULL DEFAULT now(),
+  updated_at       timestamptz NOT NULL DEFAULT now(),
+
+  -- Foreign Key Constraints
+  CONSTRAINT fk_tournament
+    FOREIGN KEY(tournament_id)
+    REFERENCES tournament_bases(id)
+    ON DELETE CASCADE,
+  CONSTRAINT fk_stage
+    FOREIGN KEY(stage_id)
+    REFERENCES stages(id)
+    ON DELETE CASCADE
+);
+
+CREATE INDEX IF NOT EXISTS idx_matches_tournament
+  ON matches (tournament_id, start_at, station);
+
+CREATE INDEX IF NOT EXISTS idx_matches_stage
+  ON matches (stage_id, group_id, round_id, number);
+
+-- Re-use the existing updated_at maintenance trigger function
+DROP TRIGGER IF EXISTS set_timestamp_matches ON matches;
+CREATE TRIGGER set_timestamp_matches
+BEFORE UPDATE ON matches
+FOR EACH ROW
+EXECUTE FUNCTION trg_set_timestamp();
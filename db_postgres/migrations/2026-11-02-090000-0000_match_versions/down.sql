@@ -0,0 +1,3 @@
+-- This file should undo anything in `up.sql`
+ALTER TABLE matches DROP CONSTRAINT IF EXISTS match_version_non_negative;
+ALTER TABLE matches DROP COLUMN IF EXISTS version;
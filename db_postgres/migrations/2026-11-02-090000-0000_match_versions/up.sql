@@ -0,0 +1,3 @@
+-- version of matches for optimistic locking, e.g. of live scores against final results
+ALTER TABLE matches ADD COLUMN version bigint NOT NULL DEFAULT 0;
+ALTER TABLE matches ADD CONSTRAINT match_version_non_negative CHECK (version >= 0);
@@ -0,0 +1,3 @@
+-- This file should undo anything in `up.sql`
+DROP TRIGGER IF EXISTS set_timestamp_group_seedings ON group_seedings;
+DROP TABLE IF EXISTS group_seedings;
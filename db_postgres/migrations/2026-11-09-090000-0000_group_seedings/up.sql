@@ -0,0 +1,34 @@
+-- Group seeding of the first stage, saved by the director before the tournament starts
+CREATE TABLE IF NOT EXISTS group_seedings (
+  stage_id         uuid PRIMARY KEY,
+
+  -- Foreign key to the tournament
+  tournament_id    uuid        NOT NULL,
+
+  -- Seeding data
+  seeding          jsonb       NOT NULL,  -- SavedGroupSeeding
+
+  -- Timestamps
+  created_at       timestamptz NOT NULL DEFAULT now(),
+  updated_at       timestamptz NOT NULL DEFAULT now(),
+
+  -- Foreign Key Constraints
+  CONSTRAINT fk_tournament
+    FOREIGN KEY(tournament_id)
+    REFERENCES tournament_bases(id)
+    ON DELETE CASCADE,
+  CONSTRAINT fk_stage
+    FOREIGN KEY(stage_id)
+    REFERENCES stages(id)
+    ON DELETE CASCADE
+);
+
+CREATE INDEX IF NOT EXISTS idx_group_seedings_tournament
+  ON group_seedings (tournament_id);
+
+-- Re-use the existing updated_at maintenance trigger function
+DROP TRIGGER IF EXISTS set_timestamp_group_seedings ON group_seedings;
+CREATE TRIGGER set_timestamp_group_seedings
+BEFORE UPDATE ON group_seedings
+FOR EACH ROW
+EXECUTE FUNCTION trg_set_timestamp();
@@ -0,0 +1,3 @@
+-- This file should undo anything in `up.sql`
+DROP TRIGGER IF EXISTS set_timestamp_stage_groups ON stage_groups;
+DROP TABLE IF EXISTS stage_groups;
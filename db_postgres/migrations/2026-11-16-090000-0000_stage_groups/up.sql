@@ -0,0 +1,41 @@
+-- Groups of stages; the group itself is stored as json, the columns are used for lookups
+CREATE TABLE IF NOT EXISTS stage_groups (
+  id               uuid PRIMARY KEY,
+
+  -- Optimistic locking
+  version          bigint      NOT NULL DEFAULT 0,
+
+  -- Foreign key to the stage
+  stage_id         uuid        NOT NULL,
+
+  -- Group number in stage (0, 1, 2...)
+  number           integer     NOT NULL,
+
+  -- Group data
+  data             jsonb       NOT NULL,  -- Group
+
+  -- Timestamps
+  created_at       timestamptz NOT NULL DEFAULT now(),
+  updated_at       timestamptz NOT NULL DEFAULT now(),
+
+  -- Constraints
+  CONSTRAINT version_non_negative CHECK (version >= 0),
+  CONSTRAINT number_non_negative CHECK (number >= 0),
+
+  -- Foreign Key Constraint
+  CONSTRAINT fk_stage
+    FOREIGN KEY(stage_id)
+    REFERENCES stages(id)
+    ON DELETE CASCADE
+);
+
+-- Enforce uniqueness of group number per stage
+CREATE UNIQUE INDEX IF NOT EXISTS uniq_stage_groups_number_per_stage
+  ON stage_groups (stage_id, number);
+
+-- Re-use the existing updated_at maintenance trigger function
+DROP TRIGGER IF EXISTS set_timestamp_stage_groups ON stage_groups;
+CREATE TRIGGER set_timestamp_stage_groups
+BEFORE UPDATE ON stage_groups
+FOR EACH ROW
+EXECUTE FUNCTION trg_set_timestamp();
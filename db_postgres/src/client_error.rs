@@ -0,0 +1,144 @@
+//! implementation of client error port
+
+use crate::{
+    PgDb, cancel_on_drop, map_db_err,
+    schema::{client_errors, client_errors::dsl::*},
+};
+use app_core::{
+    ClientErrorReport, DbError, DbResult, DbpClientError,
+    utils::{id_version::IdVersion, traits::ObjectIdVersion},
+};
+use async_trait::async_trait;
+use chrono::{DateTime, Utc};
+use diesel::prelude::{ExpressionMethods, Insertable, QueryDsl, Queryable};
+use diesel_async::RunQueryDsl;
+use tracing::{info, instrument, warn};
+use uuid::Uuid;
+
+// ------------------- DB-Row (SELECT/RETURNING) -------------------
+#[derive(Debug, Queryable)]
+pub struct DbClientError {
+    pub id: Uuid,
+    pub version: i64,
+    pub component_id: Option<Uuid>,
+    pub route: String,
+    pub app_version: String,
+    pub message: String,
+    pub occurrences: i32,
+    pub created_at: DateTime<Utc>,
+    pub updated_at: DateTime<Utc>,
+}
+
+// Mapping DB -> Core
+impl TryFrom<DbClientError> for ClientErrorReport {
+    type Error = DbError;
+
+    fn try_from(r: DbClientError) -> Result<Self, Self::Error> {
+        if r.id.is_nil() {
+            return Err(DbError::NilRowId);
+        }
+        if r.version < 0 {
+            return Err(DbError::NegativeRowVersion);
+        }
+        if r.version > u32::MAX as i64 {
+            return Err(DbError::RowVersionOutOfRange);
+        }
+
+        let id_version = IdVersion::new(r.id, Some(r.version as u32));
+        let mut report = ClientErrorReport::new(id_version);
+
+        report
+            .set_component_id(r.component_id)
+            .set_route(r.route)
+            .set_app_version(r.app_version)
+            .set_message(r.message)
+            .set_occurrences(r.occurrences.max(0) as u32)
+            .set_created_at(Some(r.created_at));
+
+        Ok(report)
+    }
+}
+
+// ------------------- INSERT -------------------
+#[derive(Debug, Insertable)]
+#[diesel(table_name = client_errors)]
+pub struct WriteDbClientError<'a> {
+    pub component_id: Option<Uuid>,
+    pub route: &'a str,
+    pub app_version: &'a str,
+    pub message: &'a str,
+    pub occurrences: i32,
+}
+
+// Mapping Core -> DB
+impl<'a> From<&'a ClientErrorReport> for WriteDbClientError<'a> {
+    fn from(r: &'a ClientErrorReport) -> Self {
+        WriteDbClientError {
+            component_id: r.get_component_id(),
+            route: r.get_route(),
+            app_version: r.get_app_version(),
+            message: r.get_message(),
+            occurrences: r.get_occurrences().min(i32::MAX as u32) as i32,
+        }
+    }
+}
+
+// ------------------- Impl trait --------------------
+
+#[async_trait]
+impl DbpClientError for PgDb {
+    #[instrument(
+        name = "db.client_error.save",
+        skip(self, report),
+        fields(id = ?report.get_id(), component_id = ?report.get_component_id())
+    )]
+    async fn save_client_error(&self, report: &ClientErrorReport) -> DbResult<ClientErrorReport> {
+        let IdVersion::NewWithId(new_id) = report.get_id_version() else {
+            warn!("update_of_append_only_row");
+            return Err(DbError::Other(
+                "client error reports are append only".to_string(),
+            ));
+        };
+        let mut conn = self.new_write_connection().await?;
+        let w = WriteDbClientError::from(report);
+
+        let row = diesel::insert_into(client_errors)
+            .values((id.eq(new_id), w))
+            .returning((
+                id,
+                version,
+                component_id,
+                route,
+                app_version,
+                message,
+                occurrences,
+                created_at,
+                updated_at,
+            ))
+            .get_result::<DbClientError>(&mut conn)
+            .await
+            .map_err(map_db_err)?;
+
+        info!(saved_id = %row.id, "insert_ok");
+        row.try_into()
+    }
+
+    #[instrument(name = "db.client_error.list", skip(self))]
+    async fn list_client_errors(&self, limit: Option<usize>) -> DbResult<Vec<ClientErrorReport>> {
+        let mut conn = self.new_read_connection().await?;
+
+        let mut query = client_errors.order(created_at.desc()).into_boxed();
+        if let Some(lim) = limit {
+            query = query.limit(lim as i64);
+        }
+
+        let cancel_guard = conn.cancel_guard();
+        let rows = cancel_on_drop(cancel_guard, query.load::<DbClientError>(&mut conn))
+            .await?
+            .into_iter()
+            .map(ClientErrorReport::try_from)
+            .collect::<DbResult<Vec<_>>>()?;
+        info!(count = rows.len(), "list_ok");
+        Ok(rows)
+    }
+}
@@ -0,0 +1,190 @@
+//! implementation of group port
+
+use crate::{
+    PgDb, map_db_err,
+    schema::{stage_groups, stage_groups::dsl::*},
+};
+use app_core::{
+    DbError, DbResult, DbpGroup, Group,
+    utils::{id_version::IdVersion, traits::ObjectIdVersion},
+};
+use async_trait::async_trait;
+use chrono::{DateTime, Utc};
+use diesel::{
+    dsl::sql,
+    prelude::{
+        AsChangeset, BoolExpressionMethods, ExpressionMethods, Insertable, OptionalExtension,
+        QueryDsl, Queryable,
+    },
+    sql_types::BigInt,
+};
+use diesel_async::{AsyncPgConnection, RunQueryDsl};
+use tracing::{debug, error, info, instrument, warn};
+use uuid::Uuid;
+
+// ------------------- DB-Row (SELECT/RETURNING) -------------------
+#[derive(Debug, Queryable)]
+pub struct DbGroup {
+    pub id: Uuid,
+    pub version: i64,
+    pub stage_id: Uuid,
+    pub number: i32,
+    pub data: serde_json::Value,
+    pub created_at: DateTime<Utc>,
+    pub updated_at: DateTime<Utc>,
+}
+
+// Mapping DB -> Core
+impl TryFrom<DbGroup> for Group {
+    type Error = DbError;
+
+    fn try_from(r: DbGroup) -> Result<Self, Self::Error> {
+        if r.id.is_nil() {
+            return Err(DbError::NilRowId);
+        }
+        if r.version < 0 {
+            return Err(DbError::NegativeRowVersion);
+        }
+        if r.version > u32::MAX as i64 {
+            return Err(DbError::RowVersionOutOfRange);
+        }
+        let mut group_from_json: Group = serde_json::from_value(r.data)
+            .map_err(|e| DbError::Other(format!("Failed to deserialize group: {e}")))?;
+        if group_from_json.get_id() != r.id {
+            return Err(DbError::Other(format!(
+                "Id of stored group {} does not match row id {}",
+                group_from_json.get_id(),
+                r.id
+            )));
+        }
+        group_from_json.set_id_version(IdVersion::new(r.id, Some(r.version as u32)));
+        Ok(group_from_json)
+    }
+}
+
+// ------------------- INSERT / UPDATE -------------------
+#[derive(Debug, Insertable, AsChangeset)]
+#[diesel(table_name = stage_groups)]
+pub struct WriteDbGroup {
+    pub stage_id: Uuid,
+    pub number: i32,
+    pub data: serde_json::Value,
+}
+
+// Mapping Core -> DB
+impl TryFrom<&Group> for WriteDbGroup {
+    type Error = DbError;
+
+    fn try_from(g: &Group) -> Result<Self, Self::Error> {
+        Ok(WriteDbGroup {
+            stage_id: g.get_stage_id(),
+            number: g.get_number() as i32,
+            data: serde_json::to_value(g)
+                .map_err(|e| DbError::Other(format!("Failed to serialize group: {e}")))?,
+        })
+    }
+}
+
+// ------------------- Impl trait --------------------
+
+#[async_trait]
+impl DbpGroup for PgDb {
+    #[instrument(name = "db.group.get", skip(self), fields(id = %group_id))]
+    async fn get_group(&self, group_id: Uuid) -> DbResult<Option<Group>> {
+        let mut conn = self.new_connection().await?;
+        let res = stage_groups
+            .filter(id.eq(group_id))
+            .first::<DbGroup>(&mut conn)
+            .await
+            .optional()
+            .map_err(map_db_err)?;
+
+        match res {
+            Some(res) => {
+                let res = Group::try_from(res)?;
+                debug!("found_group");
+                Ok(Some(res))
+            }
+            None => {
+                debug!("group_not_found");
+                Ok(None)
+            }
+        }
+    }
+
+    #[instrument(name = "db.group.list_of_stage", skip(self, s_id))]
+    async fn list_groups_of_stage(&self, s_id: Uuid) -> DbResult<Vec<Group>> {
+        let mut conn = self.new_read_connection().await?;
+        let rows = stage_groups
+            .filter(stage_id.eq(s_id))
+            .order(number.asc())
+            .load::<DbGroup>(&mut conn)
+            .await
+            .map_err(map_db_err)?
+            .into_iter()
+            .map(Group::try_from)
+            .collect::<DbResult<Vec<_>>>()?;
+        info!(count = rows.len(), "list_ok");
+        Ok(rows)
+    }
+}
+
+/// insert or update group with optimistic locking on `conn`; groups are saved with the
+/// tournament diff in one transaction
+pub(crate) async fn write_group(conn: &mut AsyncPgConnection, group: &Group) -> DbResult<Group> {
+    let w = WriteDbGroup::try_from(group)?;
+
+    match group.get_id_version() {
+        // Case 1: UPDATE (Optimistic Locking)
+        IdVersion::Existing(inner) => {
+            let res = diesel::update(
+                stage_groups.filter(
+                    id.eq(inner.get_id())
+                        .and(version.eq(inner.get_version() as i64)),
+                ),
+            )
+            .set((w, version.eq(sql::<BigInt>("version + 1"))))
+            .get_result::<DbGroup>(conn)
+            .await;
+
+            match res {
+                Ok(row) => {
+                    info!(saved_id = %row.id, new_version = row.version, "update_ok");
+                    row.try_into()
+                }
+                Err(diesel::result::Error::NotFound) => {
+                    // Check if it exists but version mismatch
+                    let exists = diesel::select(diesel::dsl::exists(
+                        stage_groups.filter(id.eq(inner.get_id())),
+                    ))
+                    .get_result::<bool>(conn)
+                    .await
+                    .map_err(map_db_err)?;
+
+                    if exists {
+                        warn!("optimistic_lock_conflict");
+                        Err(DbError::OptimisticLockConflict)
+                    } else {
+                        warn!("row_missing_on_update");
+                        Err(DbError::NotFound)
+                    }
+                }
+                Err(e) => {
+                    error!(error = %e, "update_failed");
+                    Err(map_db_err(e))
+                }
+            }
+        }
+        // Case 2: INSERT with specific ID
+        IdVersion::NewWithId(new_id) => {
+            let row = diesel::insert_into(stage_groups)
+                .values((id.eq(new_id), w))
+                .get_result::<DbGroup>(conn)
+                .await
+                .map_err(map_db_err)?;
+
+            info!(saved_id = %row.id, "insert_ok");
+            row.try_into()
+        }
+    }
+}
@@ -0,0 +1,113 @@
+//! implementation of group seeding port
+
+use crate::{
+    PgDb, map_db_err,
+    schema::{group_seedings, group_seedings::dsl::*},
+};
+use app_core::{DbError, DbResult, DbpGroupSeeding, SavedGroupSeeding};
+use async_trait::async_trait;
+use chrono::{DateTime, Utc};
+use diesel::{
+    prelude::{ExpressionMethods, Insertable, OptionalExtension, QueryDsl, Queryable},
+    upsert::excluded,
+};
+use diesel_async::RunQueryDsl;
+use tracing::{debug, info, instrument};
+use uuid::Uuid;
+
+// ------------------- DB-Row (SELECT/RETURNING) -------------------
+#[derive(Debug, Queryable)]
+pub struct DbGroupSeeding {
+    pub stage_id: Uuid,
+    pub tournament_id: Uuid,
+    pub seeding: serde_json::Value,
+    pub created_at: DateTime<Utc>,
+    pub updated_at: DateTime<Utc>,
+}
+
+// Mapping DB -> Core
+impl TryFrom<DbGroupSeeding> for SavedGroupSeeding {
+    type Error = DbError;
+
+    fn try_from(r: DbGroupSeeding) -> Result<Self, Self::Error> {
+        if r.stage_id.is_nil() {
+            return Err(DbError::NilRowId);
+        }
+        serde_json::from_value(r.seeding)
+            .map_err(|e| DbError::Other(format!("Failed to deserialize group seeding: {e}")))
+    }
+}
+
+// ------------------- INSERT / UPSERT -------------------
+#[derive(Debug, Insertable)]
+#[diesel(table_name = group_seedings)]
+pub struct WriteDbGroupSeeding {
+    pub stage_id: Uuid,
+    pub tournament_id: Uuid,
+    pub seeding: serde_json::Value,
+}
+
+// Mapping Core -> DB
+impl TryFrom<&SavedGroupSeeding> for WriteDbGroupSeeding {
+    type Error = DbError;
+
+    fn try_from(s: &SavedGroupSeeding) -> Result<Self, Self::Error> {
+        Ok(WriteDbGroupSeeding {
+            stage_id: s.stage_id,
+            tournament_id: s.tournament_id,
+            seeding: serde_json::to_value(s)
+                .map_err(|e| DbError::Other(format!("Failed to serialize group seeding: {e}")))?,
+        })
+    }
+}
+
+// ------------------- Impl trait --------------------
+
+#[async_trait]
+impl DbpGroupSeeding for PgDb {
+    #[instrument(name = "db.group_seeding.get", skip(self), fields(stage_id = %s_id))]
+    async fn get_group_seeding(&self, s_id: Uuid) -> DbResult<Option<SavedGroupSeeding>> {
+        let mut conn = self.new_connection().await?;
+        let res = group_seedings
+            .filter(stage_id.eq(s_id))
+            .first::<DbGroupSeeding>(&mut conn)
+            .await
+            .optional()
+            .map_err(map_db_err)?;
+
+        match res {
+            Some(res) => {
+                let res = SavedGroupSeeding::try_from(res)?;
+                debug!("found_group_seeding");
+                Ok(Some(res))
+            }
+            None => {
+                debug!("group_seeding_not_found");
+                Ok(None)
+            }
+        }
+    }
+
+    #[instrument(
+        name = "db.group_seeding.save",
+        skip(self, saved),
+        fields(stage_id = %saved.stage_id, tournament_id = %saved.tournament_id)
+    )]
+    async fn save_group_seeding(&self, saved: &SavedGroupSeeding) -> DbResult<()> {
+        let mut conn = self.new_write_connection().await?;
+        let w = WriteDbGroupSeeding::try_from(saved)?;
+
+        // the seeding is replaced as a whole until the first stage starts
+        diesel::insert_into(group_seedings)
+            .values(&w)
+            .on_conflict(stage_id)
+            .do_update()
+            .set(seeding.eq(excluded(seeding)))
+            .execute(&mut conn)
+            .await
+            .map_err(map_db_err)?;
+
+        info!("upsert_ok");
+        Ok(())
+    }
+}
@@ -0,0 +1,159 @@
+//! implementation of group standings port
+
+use crate::{
+    PgDb, cancel_on_drop, map_db_err,
+    schema::{group_standings, group_standings::dsl::*},
+};
+use app_core::{CachedGroupStandings, DbError, DbResult, DbpGroupStandings, GroupStanding};
+use async_trait::async_trait;
+use chrono::{DateTime, Utc};
+use diesel::{
+    prelude::{ExpressionMethods, Insertable, OptionalExtension, QueryDsl, Queryable},
+    upsert::excluded,
+};
+use diesel_async::RunQueryDsl;
+use tracing::{debug, info, instrument};
+use uuid::Uuid;
+
+// ------------------- DB-Row (SELECT/RETURNING) -------------------
+#[derive(Debug, Queryable)]
+pub struct DbGroupStandings {
+    pub group_id: Uuid,
+    pub tournament_id: Uuid,
+    pub standings: serde_json::Value,
+    pub computed_at: DateTime<Utc>,
+    pub created_at: DateTime<Utc>,
+    pub updated_at: DateTime<Utc>,
+}
+
+// Mapping DB -> Core
+impl TryFrom<DbGroupStandings> for CachedGroupStandings {
+    type Error = DbError;
+
+    fn try_from(r: DbGroupStandings) -> Result<Self, Self::Error> {
+        if r.group_id.is_nil() {
+            return Err(DbError::NilRowId);
+        }
+        let standings_from_json: Vec<GroupStanding> = serde_json::from_value(r.standings)
+            .map_err(|e| DbError::Other(format!("Failed to deserialize standings: {e}")))?;
+
+        Ok(CachedGroupStandings {
+            group_id: r.group_id,
+            tournament_id: r.tournament_id,
+            standings: standings_from_json,
+            computed_at: r.computed_at,
+        })
+    }
+}
+
+// ------------------- INSERT / UPSERT -------------------
+#[derive(Debug, Insertable)]
+#[diesel(table_name = group_standings)]
+pub struct WriteDbGroupStandings {
+    pub group_id: Uuid,
+    pub tournament_id: Uuid,
+    pub standings: serde_json::Value,
+    pub computed_at: DateTime<Utc>,
+}
+
+// Mapping Core -> DB
+impl TryFrom<&CachedGroupStandings> for WriteDbGroupStandings {
+    type Error = DbError;
+
+    fn try_from(c: &CachedGroupStandings) -> Result<Self, Self::Error> {
+        Ok(WriteDbGroupStandings {
+            group_id: c.group_id,
+            tournament_id: c.tournament_id,
+            standings: serde_json::to_value(&c.standings)
+                .map_err(|e| DbError::Other(format!("Failed to serialize standings: {e}")))?,
+            computed_at: c.computed_at,
+        })
+    }
+}
+
+// ------------------- Impl trait --------------------
+
+#[async_trait]
+impl DbpGroupStandings for PgDb {
+    #[instrument(name = "db.group_standings.get", skip(self), fields(group_id = %g_id))]
+    async fn get_group_standings(&self, g_id: Uuid) -> DbResult<Option<CachedGroupStandings>> {
+        let mut conn = self.new_connection().await?;
+        let res = group_standings
+            .filter(group_id.eq(g_id))
+            .first::<DbGroupStandings>(&mut conn)
+            .await
+            .optional()
+            .map_err(map_db_err)?;
+
+        match res {
+            Some(res) => {
+                let res = CachedGroupStandings::try_from(res)?;
+                debug!("found_group_standings");
+                Ok(Some(res))
+            }
+            None => {
+                debug!("group_standings_not_found");
+                Ok(None)
+            }
+        }
+    }
+
+    #[instrument(
+        name = "db.group_standings.save",
+        skip(self, cached),
+        fields(group_id = %cached.group_id, tournament_id = %cached.tournament_id)
+    )]
+    async fn save_group_standings(&self, cached: &CachedGroupStandings) -> DbResult<()> {
+        let mut conn = self.new_write_connection().await?;
+        let w = WriteDbGroupStandings::try_from(cached)?;
+
+        // cached standings are replaced as a whole, therefore no optimistic locking
+        diesel::insert_into(group_standings)
+            .values(&w)
+            .on_conflict(group_id)
+            .do_update()
+            .set((
+                standings.eq(excluded(standings)),
+                computed_at.eq(excluded(computed_at)),
+            ))
+            .execute(&mut conn)
+            .await
+            .map_err(map_db_err)?;
+
+        info!("upsert_ok");
+        Ok(())
+    }
+
+    #[instrument(name = "db.group_standings.delete", skip(self), fields(group_id = %g_id))]
+    async fn delete_group_standings(&self, g_id: Uuid) -> DbResult<()> {
+        let mut conn = self.new_write_connection().await?;
+        let deleted = diesel::delete(group_standings.filter(group_id.eq(g_id)))
+            .execute(&mut conn)
+            .await
+            .map_err(map_db_err)?;
+
+        info!(deleted, "delete_ok");
+        Ok(())
+    }
+
+    #[instrument(name = "db.group_standings.list", skip(self, t_id))]
+    async fn list_group_standings_of_tournament(
+        &self,
+        t_id: Uuid,
+    ) -> DbResult<Vec<CachedGroupStandings>> {
+        let mut conn = self.new_read_connection().await?;
+
+        let query = group_standings
+            .filter(tournament_id.eq(t_id))
+            .order(group_id.asc());
+
+        let cancel_guard = conn.cancel_guard();
+        let rows = cancel_on_drop(cancel_guard, query.load::<DbGroupStandings>(&mut conn))
+            .await?
+            .into_iter()
+            .map(CachedGroupStandings::try_from)
+            .collect::<DbResult<Vec<_>>>()?;
+        info!(count = rows.len(), "list_ok");
+        Ok(rows)
+    }
+}
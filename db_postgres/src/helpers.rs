@@ -26,9 +26,5 @@ pub fn url_custom_db(database: impl Into<String>) -> Result<Url> {
     Ok(url)
 }
 
-/// get database url
-pub fn url_db() -> Result<Url> {
-    let database = env::var("DATABASE_NAME")
-        .context("DATABASE_NAME must be set. Hint: did you run dotenv()?")?;
-    url_custom_db(database)
-}
+/// default statement timeout of interactive queries in milliseconds
+pub const DEFAULT_STATEMENT_TIMEOUT_MS: u64 = 5_000;
@@ -1,27 +1,60 @@
 // diesel postgres implementation of database port
 
+pub mod api_token;
+pub mod audit_log;
+pub mod client_error;
+pub mod entrant;
+pub mod feedback;
+pub mod group;
+pub mod group_seeding;
+pub mod group_standings;
 pub mod helpers;
+pub mod match_;
+pub mod match_note;
+pub mod migration;
+pub mod official;
+pub mod pairing_override;
 pub mod postal_address;
 pub mod schema;
+pub mod scorekeeper;
+pub mod search;
+pub mod shift_log;
 pub mod sport_config;
 pub mod stage;
+pub mod stage_snapshot;
 pub mod tournament_base;
+pub mod venue;
+pub mod webhook;
 
 pub use helpers::*;
+pub use migration::SchemaCompatibility;
 
 use anyhow::{Context, Error, Result, anyhow};
-use app_core::{DatabasePort, DbError, DbResult};
+use app_core::{DatabasePort, DbError, DbResult, DbTransaction};
 use async_trait::async_trait;
-use diesel::{dsl::sql, select, sql_types::Bool};
+use diesel::migration::MigrationSource;
+use diesel::{ConnectionError, QueryResult, dsl::sql, select, sql_types::Bool};
 use diesel_async::{
-    AsyncMigrationHarness, AsyncPgConnection, RunQueryDsl,
+    AnsiTransactionManager, AsyncConnection, AsyncMigrationHarness, AsyncPgConnection, RunQueryDsl,
+    TransactionManager,
     pooled_connection::{
-        AsyncDieselConnectionManager,
-        bb8::{Pool, PooledConnection},
+        AsyncDieselConnectionManager, ManagerConfig,
+        bb8::{Pool, PooledConnection, RunError},
     },
 };
 use diesel_migrations::{EmbeddedMigrations, MigrationHarness, embed_migrations};
-use tracing::{info, instrument, warn};
+use std::{
+    future::Future,
+    ops::{Deref, DerefMut},
+    sync::{
+        Arc,
+        atomic::{AtomicBool, Ordering},
+    },
+    time::Duration,
+};
+use tokio::sync::{Mutex, MutexGuard};
+use tokio_postgres::{CancelToken, NoTls};
+use tracing::{debug, info, instrument, warn};
 use url::Url;
 
 /// embed migrations
@@ -29,45 +62,237 @@ pub const MIGRATIONS: EmbeddedMigrations = embed_migrations!();
 
 pub struct PgDb {
     pool: Pool<AsyncPgConnection>,
+    /// optional pool of read replica; used for read-only list queries
+    read_pool: Option<Pool<AsyncPgConnection>>,
+    /// refuse writes, e.g. if the database schema is newer than this binary
+    read_only: AtomicBool,
+    /// runtime limit of each statement; applied to every connection of the pools
+    statement_timeout: Option<Duration>,
+    /// connection of the transaction, if this is a transaction of
+    /// [`DatabasePort::begin_transaction`]; all queries use this connection
+    transaction: Option<Arc<Mutex<PooledConnection<'static, AsyncPgConnection>>>>,
+}
+
+/// connection of the pool or of the transaction of [`PgDb`]
+pub struct DbConnection<'a> {
+    conn: PooledOrTransaction<'a>,
+    /// set while a statement runs, which is cancelled if its request is dropped, see
+    /// [`cancel_on_drop`]
+    cancellable_statement: Arc<AtomicBool>,
+    /// runtime limit of statements run with [`cancel_on_drop`]
+    statement_timeout: Option<Duration>,
+}
+
+enum PooledOrTransaction<'a> {
+    Pooled(PooledConnection<'a, AsyncPgConnection>),
+    Transaction(MutexGuard<'a, PooledConnection<'static, AsyncPgConnection>>),
+}
+
+impl<'a> DbConnection<'a> {
+    fn new(conn: PooledOrTransaction<'a>, statement_timeout: Option<Duration>) -> Self {
+        DbConnection {
+            conn,
+            cancellable_statement: Arc::new(AtomicBool::new(false)),
+            statement_timeout,
+        }
+    }
+    /// Guard, which cancels the next statement of this connection, if the statement is
+    /// dropped before it finished or exceeds the statement timeout; see [`cancel_on_drop`].
+    pub fn cancel_guard(&self) -> CancelOnDrop {
+        self.cancellable_statement.store(true, Ordering::Relaxed);
+        CancelOnDrop {
+            token: Some(self.cancel_token()),
+            cancellable_statement: self.cancellable_statement.clone(),
+            statement_timeout: self.statement_timeout,
+        }
+    }
+}
+
+impl Deref for DbConnection<'_> {
+    type Target = AsyncPgConnection;
+
+    fn deref(&self) -> &Self::Target {
+        match &self.conn {
+            PooledOrTransaction::Pooled(conn) => conn,
+            PooledOrTransaction::Transaction(conn) => conn,
+        }
+    }
+}
+
+impl DerefMut for DbConnection<'_> {
+    fn deref_mut(&mut self) -> &mut Self::Target {
+        match &mut self.conn {
+            PooledOrTransaction::Pooled(conn) => conn,
+            PooledOrTransaction::Transaction(conn) => conn,
+        }
+    }
+}
+
+impl Drop for DbConnection<'_> {
+    fn drop(&mut self) {
+        // The cancel request of a dropped statement is sent asynchronously. The connection
+        // is marked as broken, so that the pool discards it instead of handing it to the
+        // next request, whose statement could be cancelled instead.
+        if self.cancellable_statement.load(Ordering::Relaxed) {
+            debug!("connection_of_cancelled_statement_discarded");
+            AnsiTransactionManager::transaction_manager_status_mut(&mut **self).set_in_error();
+        }
+    }
 }
 
 impl PgDb {
     pub async fn new(database: Url) -> Result<Self> {
-        let config = AsyncDieselConnectionManager::new(database);
+        Self::new_with_statement_timeout(database, None).await
+    }
+    /// Like `new`, but list and search queries are cancelled after `statement_timeout` and
+    /// fail with `DbError::Timeout`. The database cancels all other statements shortly after.
+    /// `None` disables the timeout.
+    pub async fn new_with_statement_timeout(
+        database: Url,
+        statement_timeout: Option<Duration>,
+    ) -> Result<Self> {
+        let config = connection_manager(database, statement_timeout);
         Ok(PgDb {
             pool: Pool::builder().build(config).await?,
+            read_pool: None,
+            read_only: AtomicBool::new(false),
+            statement_timeout,
+            transaction: None,
         })
     }
+    /// Use a read replica for read-only queries (lists, standings, public endpoints).
+    /// Writes and reads, which precede writes (optimistic locking), always use the primary.
+    pub async fn with_read_replica(mut self, replica: Url) -> Result<Self> {
+        let config = connection_manager(replica, self.statement_timeout);
+        self.read_pool = Some(Pool::builder().build(config).await?);
+        Ok(self)
+    }
+    /// Returns true, if a read replica is configured.
+    pub fn has_read_replica(&self) -> bool {
+        self.read_pool.is_some()
+    }
     #[instrument(name = "db.migration", skip(self))]
     pub async fn run_migration(&self) -> DbResult<()> {
-        let conn = self
+        let mut conn = self
             .pool
             .get_owned()
             .await
             .map_err(|e| DbError::from(Error::from(e)))?;
-        tokio::task::spawn_blocking(move || -> Result<()> {
+        // migrations may run much longer than interactive queries
+        if self.statement_timeout.is_some() {
+            diesel::sql_query(set_statement_timeout_sql(None))
+                .execute(&mut conn)
+                .await
+                .map_err(map_db_err)?;
+        }
+        let (mut conn, migrated) = tokio::task::spawn_blocking(move || {
             let mut harness = AsyncMigrationHarness::new(conn);
-            harness
+            let migrated = harness
                 .run_pending_migrations(MIGRATIONS)
-                .map_err(|e| anyhow!("migration failed: {e}"))?;
-            Ok(())
+                .map(|_| ())
+                .map_err(|e| anyhow!("migration failed: {e}"));
+            (harness.into_inner(), migrated)
         })
         .await
-        .context("Join error while running migrations")??;
+        .context("Join error while running migrations")?;
+        // connection returns to the pool, therefore restore timeout
+        if self.statement_timeout.is_some() {
+            diesel::sql_query(set_statement_timeout_sql(self.statement_timeout))
+                .execute(&mut conn)
+                .await
+                .map_err(map_db_err)?;
+        }
+        migrated?;
 
         info!("Migrations applied successfully");
         Ok(())
     }
+    /// Compare migrations embedded in this binary with migrations applied to the database.
+    #[instrument(name = "db.migration.check", skip(self))]
+    pub async fn check_schema_compatibility(&self) -> DbResult<SchemaCompatibility> {
+        let conn = self
+            .pool
+            .get_owned()
+            .await
+            .map_err(|e| DbError::from(Error::from(e)))?;
+        let compatibility = tokio::task::spawn_blocking(move || -> Result<SchemaCompatibility> {
+            let mut harness = AsyncMigrationHarness::new(conn);
+            let applied: Vec<String> = harness
+                .applied_migrations()
+                .map_err(|e| anyhow!("reading applied migrations failed: {e}"))?
+                .iter()
+                .map(|v| v.to_string())
+                .collect();
+            let embedded: Vec<String> = MigrationSource::<diesel::pg::Pg>::migrations(&MIGRATIONS)
+                .map_err(|e| anyhow!("reading embedded migrations failed: {e}"))?
+                .iter()
+                .map(|m| m.name().version().to_string())
+                .collect();
+            Ok(SchemaCompatibility::from_versions(&embedded, &applied))
+        })
+        .await
+        .context("Join error while checking migrations")??;
+
+        info!(?compatibility, "schema_compatibility_checked");
+        Ok(compatibility)
+    }
+    /// Refuse (or allow again) all writes to the database.
+    pub fn set_read_only(&self, read_only: bool) {
+        self.read_only.store(read_only, Ordering::Relaxed);
+    }
+    /// Returns true, if writes are refused.
+    pub fn is_read_only(&self) -> bool {
+        // explicit call, RunQueryDsl::load is in scope as well
+        AtomicBool::load(&self.read_only, Ordering::Relaxed)
+    }
+    /// Connection for writes. Fails with `DbError::ReadOnly`, if writes are refused.
+    #[instrument(name = "db.conn.get_write", skip(self))]
+    pub async fn new_write_connection(&self) -> DbResult<DbConnection<'_>> {
+        if self.is_read_only() {
+            warn!("write_refused_read_only");
+            return Err(DbError::ReadOnly);
+        }
+        self.new_connection().await
+    }
+    /// Connection of the primary; inside of a transaction the connection of the transaction.
     #[instrument(name = "db.conn.get", skip(self))]
-    pub async fn new_connection(&self) -> DbResult<PooledConnection<'_, AsyncPgConnection>> {
-        match self.pool.get().await {
-            Ok(conn) => Ok(conn),
-            Err(e) => {
-                // Pool exhausted or database unavailable
-                warn!(error = %e, "pool_get_failed");
-                Err(DbError::from(Error::from(e)))
+    pub async fn new_connection(&self) -> DbResult<DbConnection<'_>> {
+        if let Some(transaction) = self.transaction.as_ref() {
+            return Ok(DbConnection::new(
+                PooledOrTransaction::Transaction(transaction.lock().await),
+                self.statement_timeout,
+            ));
+        }
+        self.pool
+            .get()
+            .await
+            .map(|conn| {
+                DbConnection::new(PooledOrTransaction::Pooled(conn), self.statement_timeout)
+            })
+            .map_err(map_pool_err)
+    }
+    /// Connection for read-only queries. Uses the read replica if configured and falls back
+    /// to the primary if the replica is unavailable.
+    ///
+    /// Replicas may lag behind the primary, therefore only use this for queries which
+    /// tolerate slightly stale data. Inside of a transaction the connection of the transaction
+    /// is used, since the replica does not see uncommitted writes.
+    #[instrument(name = "db.conn.get_read", skip(self))]
+    pub async fn new_read_connection(&self) -> DbResult<DbConnection<'_>> {
+        if self.transaction.is_none()
+            && let Some(read_pool) = self.read_pool.as_ref()
+        {
+            match read_pool.get().await {
+                Ok(conn) => {
+                    let conn = PooledOrTransaction::Pooled(conn);
+                    return Ok(DbConnection::new(conn, self.statement_timeout));
+                }
+                Err(e) => {
+                    warn!(error = %e, "replica_pool_get_failed_fallback_to_primary");
+                }
             }
         }
+        self.new_connection().await
     }
 }
 
@@ -82,6 +307,54 @@ impl DatabasePort for PgDb {
             .map_err(|e| DbError::from(Error::from(e)))?;
         Ok(())
     }
+    #[instrument(name = "db.transaction.begin", skip(self))]
+    async fn begin_transaction(&self) -> DbResult<Arc<dyn DbTransaction>> {
+        if self.transaction.is_some() {
+            return Err(DbError::Other(
+                "nested transactions are not supported".to_string(),
+            ));
+        }
+        if self.is_read_only() {
+            warn!("write_refused_read_only");
+            return Err(DbError::ReadOnly);
+        }
+        // owned connection, since the transaction outlives this borrow of the pool
+        let mut conn = self.pool.get_owned().await.map_err(map_pool_err)?;
+        AnsiTransactionManager::begin_transaction(&mut *conn)
+            .await
+            .map_err(map_db_err)?;
+        Ok(Arc::new(PgDb {
+            pool: self.pool.clone(),
+            read_pool: None,
+            read_only: AtomicBool::new(false),
+            statement_timeout: self.statement_timeout,
+            transaction: Some(Arc::new(Mutex::new(conn))),
+        }))
+    }
+}
+
+#[async_trait]
+impl DbTransaction for PgDb {
+    #[instrument(name = "db.transaction.commit", skip(self))]
+    async fn commit(&self) -> DbResult<()> {
+        let Some(transaction) = self.transaction.as_ref() else {
+            return Err(DbError::Other("no transaction to commit".to_string()));
+        };
+        let mut conn = transaction.lock().await;
+        AnsiTransactionManager::commit_transaction(&mut **conn)
+            .await
+            .map_err(map_db_err)
+    }
+    #[instrument(name = "db.transaction.rollback", skip(self))]
+    async fn rollback(&self) -> DbResult<()> {
+        let Some(transaction) = self.transaction.as_ref() else {
+            return Err(DbError::Other("no transaction to roll back".to_string()));
+        };
+        let mut conn = transaction.lock().await;
+        AnsiTransactionManager::rollback_transaction(&mut **conn)
+            .await
+            .map_err(map_db_err)
+    }
 }
 
 use diesel::result::{DatabaseErrorKind as K, Error as DE};
@@ -102,3 +375,134 @@ fn map_db_err(e: DE) -> DbError {
         _ => DbError::from(anyhow::anyhow!(e)),
     }
 }
+
+fn map_pool_err(e: RunError) -> DbError {
+    match e {
+        RunError::TimedOut => {
+            // all connections busy for longer than the pool timeout
+            warn!("pool_get_timed_out");
+            DbError::Timeout
+        }
+        e => {
+            // Pool exhausted or database unavailable
+            warn!(error = %e, "pool_get_failed");
+            DbError::from(Error::from(e))
+        }
+    }
+}
+
+/// Maps the current version of a row, whose optimistic locking update failed, to a version
+/// conflict.
+fn map_version_conflict(current_version: i64) -> DbError {
+    match u32::try_from(current_version) {
+        Ok(current_version) => DbError::VersionConflict { current_version },
+        Err(_) => DbError::RowVersionOutOfRange,
+    }
+}
+
+/// Grace period of the statement timeout of the database over the statement timeout of
+/// [`cancel_on_drop`].
+///
+/// Statements of [`cancel_on_drop`] time out on the client, which reports
+/// `DbError::Timeout`. diesel does not expose the SQLSTATE 57014 (query_canceled) of
+/// statements cancelled by the database, so the database timeout only stops statements,
+/// which are not run with [`cancel_on_drop`] or whose cancel request got lost.
+const STATEMENT_TIMEOUT_GRACE: Duration = Duration::from_secs(1);
+
+/// `SET` statement for the statement timeout of a session; `0` disables the timeout
+fn set_statement_timeout_sql(statement_timeout: Option<Duration>) -> String {
+    let ms = statement_timeout
+        .map(|t| (t + STATEMENT_TIMEOUT_GRACE).as_millis())
+        .unwrap_or(0);
+    format!("SET statement_timeout = {ms}")
+}
+
+/// Connection manager, which applies `statement_timeout` to every new connection.
+fn connection_manager(
+    database: Url,
+    statement_timeout: Option<Duration>,
+) -> AsyncDieselConnectionManager<AsyncPgConnection> {
+    if statement_timeout.is_none() {
+        return AsyncDieselConnectionManager::new(database);
+    }
+    let mut config = ManagerConfig::default();
+    config.custom_setup = Box::new(move |url| {
+        Box::pin(async move {
+            let mut conn = AsyncPgConnection::establish(url).await?;
+            diesel::sql_query(set_statement_timeout_sql(statement_timeout))
+                .execute(&mut conn)
+                .await
+                .map_err(ConnectionError::CouldntSetupConfiguration)?;
+            Ok(conn)
+        })
+    });
+    AsyncDieselConnectionManager::new_with_config(database, config)
+}
+
+/// Cancels the running statement on the database, if dropped before the statement finished.
+/// Create it with [`DbConnection::cancel_guard`].
+pub struct CancelOnDrop {
+    token: Option<CancelToken>,
+    cancellable_statement: Arc<AtomicBool>,
+    statement_timeout: Option<Duration>,
+}
+
+impl Drop for CancelOnDrop {
+    fn drop(&mut self) {
+        let Some(token) = self.token.take() else {
+            // statement finished, connection may be reused
+            self.cancellable_statement.store(false, Ordering::Relaxed);
+            return;
+        };
+        // drop may happen outside of a runtime, e.g. on shutdown
+        let Ok(handle) = tokio::runtime::Handle::try_current() else {
+            return;
+        };
+        debug!("cancel_query_of_dropped_request");
+        handle.spawn(async move {
+            if let Err(e) = token.cancel_query(NoTls).await {
+                warn!(error = %e, "cancel_query_failed");
+            }
+        });
+    }
+}
+
+/// Run `query` and cancel it on the database, if the future is dropped before completion or
+/// exceeds the statement timeout, which fails with `DbError::Timeout`.
+///
+/// Request futures are dropped, if the client disconnects. Without cancellation the
+/// statement would keep running on the database until it finishes or hits the statement
+/// timeout. The connection of a cancelled statement is discarded by the pool.
+async fn cancel_on_drop<T, F>(mut guard: CancelOnDrop, query: F) -> DbResult<T>
+where
+    F: Future<Output = QueryResult<T>>,
+{
+    let output = match guard.statement_timeout {
+        Some(statement_timeout) => match tokio::time::timeout(statement_timeout, query).await {
+            Ok(output) => output,
+            Err(_) => {
+                // dropping the guard cancels the statement
+                warn!(?statement_timeout, "statement_timed_out");
+                return Err(DbError::Timeout);
+            }
+        },
+        None => query.await,
+    };
+    // statement finished, nothing to cancel
+    guard.token = None;
+    output.map_err(map_db_err)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn statement_timeout_sql() {
+        assert_eq!(
+            set_statement_timeout_sql(Some(Duration::from_millis(1500))),
+            "SET statement_timeout = 2500"
+        );
+        assert_eq!(set_statement_timeout_sql(None), "SET statement_timeout = 0");
+    }
+}
@@ -0,0 +1,324 @@
+//! implementation of match port
+
+use crate::{
+    DbConnection, PgDb, cancel_on_drop, map_db_err, map_version_conflict,
+    schema::{matches, matches::dsl::*},
+    tournament_base::TransactionError,
+};
+use app_core::{DbError, DbResult, DbpMatch, Match, utils::id_version::IdVersion};
+use async_trait::async_trait;
+use chrono::{DateTime, Utc};
+use diesel::{
+    dsl::sql,
+    prelude::{
+        BoolExpressionMethods, ExpressionMethods, Insertable, OptionalExtension, QueryDsl,
+        Queryable,
+    },
+    sql_types::BigInt,
+    upsert::excluded,
+};
+use diesel_async::{
+    AsyncConnection, AsyncPgConnection, RunQueryDsl, scoped_futures::ScopedFutureExt,
+};
+use tracing::{debug, error, info, instrument, warn};
+use uuid::Uuid;
+
+// ------------------- DB-Row (SELECT/RETURNING) -------------------
+#[derive(Debug, Queryable)]
+pub struct DbMatch {
+    pub id: Uuid,
+    pub tournament_id: Uuid,
+    pub stage_id: Uuid,
+    pub group_id: Uuid,
+    pub round_id: Uuid,
+    pub number: i32,
+    pub station: i32,
+    pub start_at: DateTime<Utc>,
+    pub data: serde_json::Value,
+    pub created_at: DateTime<Utc>,
+    pub updated_at: DateTime<Utc>,
+    pub version: i64,
+}
+
+// Mapping DB -> Core
+impl TryFrom<DbMatch> for Match {
+    type Error = DbError;
+
+    fn try_from(r: DbMatch) -> Result<Self, Self::Error> {
+        if r.id.is_nil() {
+            return Err(DbError::NilRowId);
+        }
+        if r.version < 0 {
+            return Err(DbError::NegativeRowVersion);
+        }
+        if r.version > u32::MAX as i64 {
+            return Err(DbError::RowVersionOutOfRange);
+        }
+        let mut match_from_json: Match = serde_json::from_value(r.data)
+            .map_err(|e| DbError::Other(format!("Failed to deserialize match: {e}")))?;
+        if *match_from_json.get_id() != r.id {
+            return Err(DbError::Other(format!(
+                "Id of stored match {} does not match row id {}",
+                match_from_json.get_id(),
+                r.id
+            )));
+        }
+        match_from_json.set_version(Some(r.version as u32));
+        Ok(match_from_json)
+    }
+}
+
+// ------------------- INSERT / UPSERT -------------------
+#[derive(Debug, Insertable)]
+#[diesel(table_name = matches)]
+pub struct WriteDbMatch {
+    pub id: Uuid,
+    pub tournament_id: Uuid,
+    pub stage_id: Uuid,
+    pub group_id: Uuid,
+    pub round_id: Uuid,
+    pub number: i32,
+    pub station: i32,
+    pub start_at: DateTime<Utc>,
+    pub data: serde_json::Value,
+}
+
+// Mapping Core -> DB
+impl TryFrom<&Match> for WriteDbMatch {
+    type Error = DbError;
+
+    fn try_from(m: &Match) -> Result<Self, Self::Error> {
+        if m.get_id().is_nil() {
+            return Err(DbError::NilRowId);
+        }
+        Ok(WriteDbMatch {
+            id: *m.get_id(),
+            tournament_id: *m.get_tournament_id(),
+            stage_id: *m.get_stage_id(),
+            group_id: *m.get_group_id(),
+            round_id: *m.get_round_id(),
+            number: m.get_number() as i32,
+            station: m.get_station() as i32,
+            start_at: m.get_start_at().with_timezone(&Utc),
+            data: serde_json::to_value(m)
+                .map_err(|e| DbError::Other(format!("Failed to serialize match: {e}")))?,
+        })
+    }
+}
+
+// ------------------- Impl trait --------------------
+
+#[async_trait]
+impl DbpMatch for PgDb {
+    #[instrument(name = "db.match.get", skip(self), fields(id = %m_id))]
+    async fn get_match(&self, m_id: Uuid) -> DbResult<Option<Match>> {
+        let mut conn = self.new_connection().await?;
+        let res = matches
+            .filter(id.eq(m_id))
+            .first::<DbMatch>(&mut conn)
+            .await
+            .optional()
+            .map_err(map_db_err)?;
+
+        match res {
+            Some(res) => {
+                let res = Match::try_from(res)?;
+                debug!("found_match");
+                Ok(Some(res))
+            }
+            None => {
+                debug!("match_not_found");
+                Ok(None)
+            }
+        }
+    }
+
+    #[instrument(name = "db.match.save", skip(self, ms), fields(count = ms.len()))]
+    async fn save_matches(&self, ms: &[Match]) -> DbResult<Vec<Match>> {
+        if ms.is_empty() {
+            return Ok(Vec::new());
+        }
+        let mut conn = self.new_write_connection().await?;
+
+        // save all matches in one transaction; first failing match rolls back all others
+        let res = conn
+            .transaction::<_, TransactionError, _>(|conn| {
+                async move {
+                    let mut saved = Vec::with_capacity(ms.len());
+                    for m in ms {
+                        let row = write_match(conn, m)
+                            .await
+                            .map_err(|e| TransactionError::of_object(*m.get_id(), e))?;
+                        saved.push(row);
+                    }
+                    Ok(saved)
+                }
+                .scope_boxed()
+            })
+            .await;
+
+        match res {
+            Ok(saved) => {
+                info!(count = saved.len(), "save_ok");
+                Ok(saved)
+            }
+            Err(TransactionError(e)) => {
+                warn!(match_id = ?e.object_id, error = %e.error, "save_rolled_back");
+                Err(e.error)
+            }
+        }
+    }
+
+    #[instrument(name = "db.match.delete_of_stage", skip(self), fields(stage_id = %s_id))]
+    async fn delete_matches_of_stage(&self, s_id: Uuid) -> DbResult<()> {
+        let mut conn = self.new_write_connection().await?;
+        let deleted = diesel::delete(matches.filter(stage_id.eq(s_id)))
+            .execute(&mut conn)
+            .await
+            .map_err(map_db_err)?;
+
+        info!(count = deleted, "delete_ok");
+        Ok(())
+    }
+
+    #[instrument(name = "db.match.list_of_stage", skip(self, s_id))]
+    async fn list_matches_of_stage(&self, s_id: Uuid) -> DbResult<Vec<Match>> {
+        let mut conn = self.new_read_connection().await?;
+        load_matches_of_stage(&mut conn, s_id).await
+    }
+
+    #[instrument(name = "db.match.list_of_tournament", skip(self, t_id))]
+    async fn list_matches_of_tournament(&self, t_id: Uuid) -> DbResult<Vec<Match>> {
+        let mut conn = self.new_read_connection().await?;
+        load_matches_of_tournament(&mut conn, t_id).await
+    }
+
+    #[instrument(name = "db.match.list_of_stage_for_update", skip(self, s_id))]
+    async fn list_matches_of_stage_for_update(&self, s_id: Uuid) -> DbResult<Vec<Match>> {
+        let mut conn = self.new_connection().await?;
+        load_matches_of_stage(&mut conn, s_id).await
+    }
+
+    #[instrument(name = "db.match.list_of_tournament_for_update", skip(self, t_id))]
+    async fn list_matches_of_tournament_for_update(&self, t_id: Uuid) -> DbResult<Vec<Match>> {
+        let mut conn = self.new_connection().await?;
+        load_matches_of_tournament(&mut conn, t_id).await
+    }
+}
+
+/// Save match `m` on `conn`. Matches with a version are updated with optimistic locking,
+/// matches without version are inserted or replace the stored match with the same id, e.g.
+/// regenerated matches of a stage.
+async fn write_match(conn: &mut AsyncPgConnection, m: &Match) -> DbResult<Match> {
+    let w = WriteDbMatch::try_from(m)?;
+
+    match m.get_id_version() {
+        // Case 1: UPDATE (Optimistic Locking)
+        IdVersion::Existing(inner) => {
+            let res = diesel::update(
+                matches.filter(
+                    id.eq(inner.get_id())
+                        .and(version.eq(inner.get_version() as i64)),
+                ),
+            )
+            .set((
+                group_id.eq(w.group_id),
+                round_id.eq(w.round_id),
+                number.eq(w.number),
+                station.eq(w.station),
+                start_at.eq(w.start_at),
+                data.eq(w.data),
+                version.eq(sql::<BigInt>("version + 1")),
+            ))
+            .get_result::<DbMatch>(conn)
+            .await;
+
+            match res {
+                Ok(row) => {
+                    debug!(saved_id = %row.id, new_version = row.version, "update_ok");
+                    row.try_into()
+                }
+                Err(diesel::result::Error::NotFound) => {
+                    // Distinguish lock conflict from missing row
+                    let current_version = matches
+                        .filter(id.eq(inner.get_id()))
+                        .select(version)
+                        .first::<i64>(conn)
+                        .await
+                        .optional()
+                        .map_err(map_db_err)?;
+
+                    if let Some(current_version) = current_version {
+                        warn!(current_version, "optimistic_lock_conflict");
+                        Err(map_version_conflict(current_version))
+                    } else {
+                        warn!("row_missing_on_update");
+                        Err(DbError::NotFound)
+                    }
+                }
+                Err(e) => {
+                    error!(error = %e, "update_failed");
+                    Err(map_db_err(e))
+                }
+            }
+        }
+        // Case 2: INSERT or replace of a match without version
+        IdVersion::NewWithId(_) => {
+            let row = diesel::insert_into(matches)
+                .values(&w)
+                .on_conflict(id)
+                .do_update()
+                .set((
+                    group_id.eq(excluded(group_id)),
+                    round_id.eq(excluded(round_id)),
+                    number.eq(excluded(number)),
+                    station.eq(excluded(station)),
+                    start_at.eq(excluded(start_at)),
+                    data.eq(excluded(data)),
+                    version.eq(sql::<BigInt>("matches.version + 1")),
+                ))
+                .get_result::<DbMatch>(conn)
+                .await
+                .map_err(map_db_err)?;
+
+            debug!(saved_id = %row.id, new_version = row.version, "upsert_ok");
+            row.try_into()
+        }
+    }
+}
+
+/// matches of stage `s_id` sorted by group, round and number
+async fn load_matches_of_stage(conn: &mut DbConnection<'_>, s_id: Uuid) -> DbResult<Vec<Match>> {
+    let query =
+        matches
+            .filter(stage_id.eq(s_id))
+            .order((group_id.asc(), round_id.asc(), number.asc()));
+
+    let cancel_guard = conn.cancel_guard();
+    let rows = cancel_on_drop(cancel_guard, query.load::<DbMatch>(conn))
+        .await?
+        .into_iter()
+        .map(Match::try_from)
+        .collect::<DbResult<Vec<_>>>()?;
+    info!(count = rows.len(), "list_ok");
+    Ok(rows)
+}
+
+/// matches of tournament `t_id` sorted by start and station
+async fn load_matches_of_tournament(
+    conn: &mut DbConnection<'_>,
+    t_id: Uuid,
+) -> DbResult<Vec<Match>> {
+    let query = matches
+        .filter(tournament_id.eq(t_id))
+        .order((start_at.asc(), station.asc()));
+
+    let cancel_guard = conn.cancel_guard();
+    let rows = cancel_on_drop(cancel_guard, query.load::<DbMatch>(conn))
+        .await?
+        .into_iter()
+        .map(Match::try_from)
+        .collect::<DbResult<Vec<_>>>()?;
+    info!(count = rows.len(), "list_ok");
+    Ok(rows)
+}
@@ -0,0 +1,102 @@
+//! compatibility check of embedded migrations and database schema
+
+use std::collections::HashSet;
+
+/// Compatibility of the migrations embedded in this binary with the migrations applied to
+/// the database.
+///
+/// During rolling deployments old and new server instances run against the same database.
+/// An instance must not write to a schema it does not know, and must not serve at all with
+/// a schema, which lacks migrations it depends on.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum SchemaCompatibility {
+    /// all embedded migrations are applied and the database knows no other migrations
+    UpToDate,
+    /// binary is newer than schema: versions of embedded migrations not yet applied
+    Pending(Vec<String>),
+    /// binary is older than schema: versions of applied migrations unknown to this binary
+    Ahead(Vec<String>),
+}
+
+impl SchemaCompatibility {
+    /// Compare versions of embedded migrations with versions applied to the database.
+    ///
+    /// If both pending and unknown migrations exist, the binary belongs to a different
+    /// branch of the schema. This is reported as `Pending`, since the binary cannot serve
+    /// without its own migrations in any case.
+    pub fn from_versions(embedded: &[String], applied: &[String]) -> Self {
+        let applied_set: HashSet<&String> = applied.iter().collect();
+        let embedded_set: HashSet<&String> = embedded.iter().collect();
+
+        let mut pending: Vec<String> = embedded
+            .iter()
+            .filter(|v| !applied_set.contains(v))
+            .cloned()
+            .collect();
+        if !pending.is_empty() {
+            pending.sort();
+            return SchemaCompatibility::Pending(pending);
+        }
+
+        let mut unknown: Vec<String> = applied
+            .iter()
+            .filter(|v| !embedded_set.contains(v))
+            .cloned()
+            .collect();
+        if !unknown.is_empty() {
+            unknown.sort();
+            return SchemaCompatibility::Ahead(unknown);
+        }
+
+        SchemaCompatibility::UpToDate
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn versions(v: &[&str]) -> Vec<String> {
+        v.iter().map(|s| s.to_string()).collect()
+    }
+
+    #[test]
+    fn equal_sets_are_up_to_date() {
+        let embedded = versions(&["20250101", "20250201"]);
+        let applied = versions(&["20250201", "20250101"]);
+        assert_eq!(
+            SchemaCompatibility::from_versions(&embedded, &applied),
+            SchemaCompatibility::UpToDate
+        );
+    }
+
+    #[test]
+    fn missing_applied_migrations_are_pending() {
+        let embedded = versions(&["20250101", "20250301", "20250201"]);
+        let applied = versions(&["20250101"]);
+        assert_eq!(
+            SchemaCompatibility::from_versions(&embedded, &applied),
+            SchemaCompatibility::Pending(versions(&["20250201", "20250301"]))
+        );
+    }
+
+    #[test]
+    fn unknown_applied_migrations_mean_schema_is_ahead() {
+        let embedded = versions(&["20250101"]);
+        let applied = versions(&["20250101", "20250201"]);
+        assert_eq!(
+            SchemaCompatibility::from_versions(&embedded, &applied),
+            SchemaCompatibility::Ahead(versions(&["20250201"]))
+        );
+    }
+
+    #[test]
+    fn pending_wins_over_unknown() {
+        let embedded = versions(&["20250101", "20250301"]);
+        let applied = versions(&["20250101", "20250201"]);
+        assert_eq!(
+            SchemaCompatibility::from_versions(&embedded, &applied),
+            SchemaCompatibility::Pending(versions(&["20250301"]))
+        );
+    }
+}
@@ -0,0 +1,217 @@
+//! implementation of official port
+
+use crate::{PgDb, cancel_on_drop, map_db_err, schema::officials};
+use app_core::{
+    AvailabilityWindow, DbError, DbResult, DbpOfficial, Official,
+    utils::{id_version::IdVersion, traits::ObjectIdVersion},
+};
+use async_trait::async_trait;
+use chrono::{DateTime, Utc};
+use diesel::{
+    dsl::sql,
+    prelude::{
+        AsChangeset, BoolExpressionMethods, ExpressionMethods, Insertable, OptionalExtension,
+        QueryDsl, Queryable,
+    },
+    sql_types::BigInt,
+};
+use diesel_async::RunQueryDsl;
+use tracing::{debug, error, info, instrument, warn};
+use uuid::Uuid;
+
+// ------------------- DB-Row (SELECT/RETURNING) -------------------
+#[derive(Debug, Queryable)]
+pub struct DbOfficial {
+    pub id: Uuid,
+    pub version: i64,
+    pub tournament_id: Uuid,
+    pub name: String,
+    pub club: Option<String>,
+    pub qualifications: serde_json::Value,
+    pub availability: serde_json::Value,
+    pub created_at: DateTime<Utc>,
+    pub updated_at: DateTime<Utc>,
+}
+
+// Mapping DB -> Core
+impl TryFrom<DbOfficial> for Official {
+    type Error = DbError;
+
+    fn try_from(r: DbOfficial) -> Result<Self, Self::Error> {
+        if r.id.is_nil() {
+            return Err(DbError::NilRowId);
+        }
+        if r.version < 0 {
+            return Err(DbError::NegativeRowVersion);
+        }
+        if r.version > u32::MAX as i64 {
+            return Err(DbError::RowVersionOutOfRange);
+        }
+
+        let qualifications_from_json: Vec<String> = serde_json::from_value(r.qualifications)
+            .map_err(|e| DbError::Other(format!("Failed to deserialize qualifications: {e}")))?;
+        let availability_from_json: Vec<AvailabilityWindow> =
+            serde_json::from_value(r.availability)
+                .map_err(|e| DbError::Other(format!("Failed to deserialize availability: {e}")))?;
+
+        let id_version = IdVersion::new(r.id, Some(r.version as u32));
+        let mut o = Official::new(id_version);
+
+        o.set_tournament_id(r.tournament_id)
+            .set_name(r.name)
+            .set_club(r.club)
+            .set_qualifications(qualifications_from_json)
+            .set_availability(availability_from_json);
+
+        Ok(o)
+    }
+}
+
+// ------------------- INSERT / UPDATE -------------------
+#[derive(Debug, Insertable, AsChangeset)]
+#[diesel(table_name = officials)]
+// write NULL for removed club
+#[diesel(treat_none_as_null = true)]
+pub struct WriteDbOfficial<'a> {
+    pub tournament_id: Uuid,
+    pub name: &'a str,
+    pub club: Option<&'a str>,
+    pub qualifications: serde_json::Value,
+    pub availability: serde_json::Value,
+}
+
+// Mapping Core -> DB
+impl<'a> TryFrom<&'a Official> for WriteDbOfficial<'a> {
+    type Error = DbError;
+
+    fn try_from(o: &'a Official) -> Result<Self, Self::Error> {
+        Ok(WriteDbOfficial {
+            tournament_id: o.get_tournament_id(),
+            name: o.get_name(),
+            club: o.get_club(),
+            qualifications: serde_json::to_value(o.get_qualifications())
+                .map_err(|e| DbError::Other(format!("Failed to serialize qualifications: {e}")))?,
+            availability: serde_json::to_value(o.get_availability())
+                .map_err(|e| DbError::Other(format!("Failed to serialize availability: {e}")))?,
+        })
+    }
+}
+
+// ------------------- Impl trait --------------------
+
+#[async_trait]
+impl DbpOfficial for PgDb {
+    #[instrument(name = "db.official.get", skip(self), fields(id = %official_id))]
+    async fn get_official(&self, official_id: Uuid) -> DbResult<Option<Official>> {
+        use crate::schema::officials::dsl::*;
+        let mut conn = self.new_connection().await?;
+        let res = officials
+            .filter(id.eq(official_id))
+            .first::<DbOfficial>(&mut conn)
+            .await
+            .optional()
+            .map_err(map_db_err)?;
+
+        match res {
+            Some(res) => {
+                let res = Official::try_from(res)?;
+                debug!("found_official");
+                Ok(Some(res))
+            }
+            None => {
+                debug!("official_not_found");
+                Ok(None)
+            }
+        }
+    }
+
+    #[instrument(
+        name = "db.official.save",
+        skip(self, official),
+        fields(
+            id = ?official.get_id(),
+            version = official.get_version(),
+            is_new = official.get_id_version().is_new()
+        )
+    )]
+    async fn save_official(&self, official: &Official) -> DbResult<Official> {
+        use crate::schema::officials::dsl::*;
+        let mut conn = self.new_write_connection().await?;
+        let w = WriteDbOfficial::try_from(official)?;
+
+        match official.get_id_version() {
+            // Case 1: UPDATE (Optimistic Locking)
+            IdVersion::Existing(inner) => {
+                let res = diesel::update(
+                    officials.filter(
+                        id.eq(inner.get_id())
+                            .and(version.eq(inner.get_version() as i64)),
+                    ),
+                )
+                .set((w, version.eq(sql::<BigInt>("version + 1"))))
+                .returning(crate::schema::officials::all_columns)
+                .get_result::<DbOfficial>(&mut conn)
+                .await;
+
+                match res {
+                    Ok(row) => {
+                        info!(saved_id = %row.id, new_version = row.version, "update_ok");
+                        Ok(row.try_into()?)
+                    }
+                    Err(diesel::result::Error::NotFound) => {
+                        // Check if it exists but version mismatch
+                        let exists = diesel::select(diesel::dsl::exists(
+                            officials.filter(id.eq(inner.get_id())),
+                        ))
+                        .get_result::<bool>(&mut conn)
+                        .await
+                        .map_err(map_db_err)?;
+
+                        if exists {
+                            warn!("optimistic_lock_conflict");
+                            Err(DbError::OptimisticLockConflict)
+                        } else {
+                            warn!("row_missing_on_update");
+                            Err(DbError::NotFound)
+                        }
+                    }
+                    Err(e) => {
+                        error!(error = %e, "update_failed");
+                        Err(map_db_err(e))
+                    }
+                }
+            }
+            // Case 2: INSERT with specific ID
+            IdVersion::NewWithId(new_id) => {
+                let row = diesel::insert_into(officials)
+                    .values((id.eq(new_id), w))
+                    .returning(crate::schema::officials::all_columns)
+                    .get_result::<DbOfficial>(&mut conn)
+                    .await
+                    .map_err(map_db_err)?;
+
+                info!(saved_id = %row.id, "insert_ok");
+                Ok(row.try_into()?)
+            }
+        }
+    }
+
+    #[instrument(name = "db.official.list", skip(self, t_id))]
+    async fn list_officials_of_tournament(&self, t_id: Uuid) -> DbResult<Vec<Official>> {
+        use crate::schema::officials::dsl::*;
+        let mut conn = self.new_read_connection().await?;
+
+        let query = officials
+            .filter(tournament_id.eq(t_id))
+            .order((name.asc(), id.asc()));
+
+        let cancel_guard = conn.cancel_guard();
+        let rows = cancel_on_drop(cancel_guard, query.load::<DbOfficial>(&mut conn))
+            .await?
+            .into_iter()
+            .map(Official::try_from)
+            .collect::<DbResult<Vec<_>>>()?;
+        info!(count = rows.len(), "list_ok");
+        Ok(rows)
+    }
+}
@@ -0,0 +1,166 @@
+//! implementation of pairing override port
+
+use crate::{
+    PgDb, cancel_on_drop, map_db_err,
+    schema::{pairing_overrides, pairing_overrides::dsl::*},
+};
+use app_core::{
+    DbError, DbResult, DbpPairingOverride, PairingAdjustment, PairingOverride,
+    utils::{id_version::IdVersion, traits::ObjectIdVersion},
+};
+use async_trait::async_trait;
+use chrono::{DateTime, Utc};
+use diesel::prelude::{ExpressionMethods, Insertable, QueryDsl, Queryable};
+use diesel_async::RunQueryDsl;
+use tracing::{info, instrument, warn};
+use uuid::Uuid;
+
+// ------------------- DB-Row (SELECT/RETURNING) -------------------
+#[derive(Debug, Queryable)]
+pub struct DbPairingOverride {
+    pub id: Uuid,
+    pub version: i64,
+    pub tournament_id: Uuid,
+    pub stage_id: Uuid,
+    pub round_number: i32,
+    pub adjustments: serde_json::Value,
+    pub note: String,
+    pub author: String,
+    pub created_at: DateTime<Utc>,
+    pub updated_at: DateTime<Utc>,
+}
+
+// Mapping DB -> Core
+impl TryFrom<DbPairingOverride> for PairingOverride {
+    type Error = DbError;
+
+    fn try_from(r: DbPairingOverride) -> Result<Self, Self::Error> {
+        if r.id.is_nil() {
+            return Err(DbError::NilRowId);
+        }
+        if r.version < 0 {
+            return Err(DbError::NegativeRowVersion);
+        }
+        if r.version > u32::MAX as i64 {
+            return Err(DbError::RowVersionOutOfRange);
+        }
+
+        let adjustments_from_json: Vec<PairingAdjustment> =
+            serde_json::from_value(r.adjustments)
+                .map_err(|e| DbError::Other(format!("Failed to deserialize adjustments: {e}")))?;
+
+        let id_version = IdVersion::new(r.id, Some(r.version as u32));
+        let mut o = PairingOverride::new(id_version);
+
+        o.set_tournament_id(r.tournament_id)
+            .set_stage_id(r.stage_id)
+            .set_round_number(r.round_number as u32)
+            .set_adjustments(adjustments_from_json)
+            .set_note(r.note)
+            .set_author(r.author)
+            .set_created_at(Some(r.created_at));
+
+        Ok(o)
+    }
+}
+
+// ------------------- INSERT -------------------
+#[derive(Debug, Insertable)]
+#[diesel(table_name = pairing_overrides)]
+pub struct WriteDbPairingOverride<'a> {
+    pub tournament_id: Uuid,
+    pub stage_id: Uuid,
+    pub round_number: i32,
+    pub adjustments: serde_json::Value,
+    pub note: &'a str,
+    pub author: &'a str,
+}
+
+// Mapping Core -> DB
+impl<'a> TryFrom<&'a PairingOverride> for WriteDbPairingOverride<'a> {
+    type Error = DbError;
+
+    fn try_from(o: &'a PairingOverride) -> Result<Self, Self::Error> {
+        Ok(WriteDbPairingOverride {
+            tournament_id: o.get_tournament_id(),
+            stage_id: o.get_stage_id(),
+            round_number: o.get_round_number() as i32,
+            adjustments: serde_json::to_value(o.get_adjustments())
+                .map_err(|e| DbError::Other(format!("Failed to serialize adjustments: {e}")))?,
+            note: o.get_note(),
+            author: o.get_author(),
+        })
+    }
+}
+
+// ------------------- Impl trait --------------------
+
+#[async_trait]
+impl DbpPairingOverride for PgDb {
+    #[instrument(
+        name = "db.pairing_override.save",
+        skip(self, pairing_override),
+        fields(
+            id = ?pairing_override.get_id(),
+            stage_id = %pairing_override.get_stage_id(),
+            round_number = pairing_override.get_round_number()
+        )
+    )]
+    async fn save_pairing_override(
+        &self,
+        pairing_override: &PairingOverride,
+    ) -> DbResult<PairingOverride> {
+        let IdVersion::NewWithId(new_id) = pairing_override.get_id_version() else {
+            warn!("update_of_append_only_row");
+            return Err(DbError::Other(
+                "pairing overrides are append only".to_string(),
+            ));
+        };
+        let mut conn = self.new_write_connection().await?;
+        let w = WriteDbPairingOverride::try_from(pairing_override)?;
+
+        let row = diesel::insert_into(pairing_overrides)
+            .values((id.eq(new_id), w))
+            .returning((
+                id,
+                version,
+                tournament_id,
+                stage_id,
+                round_number,
+                adjustments,
+                note,
+                author,
+                created_at,
+                updated_at,
+            ))
+            .get_result::<DbPairingOverride>(&mut conn)
+            .await
+            .map_err(map_db_err)?;
+
+        info!(saved_id = %row.id, "insert_ok");
+        row.try_into()
+    }
+
+    #[instrument(name = "db.pairing_override.list", skip(self))]
+    async fn list_pairing_overrides(
+        &self,
+        s_id: Uuid,
+        round: u32,
+    ) -> DbResult<Vec<PairingOverride>> {
+        let mut conn = self.new_read_connection().await?;
+
+        let query = pairing_overrides
+            .filter(stage_id.eq(s_id))
+            .filter(round_number.eq(round as i32))
+            .order(created_at.asc());
+
+        let cancel_guard = conn.cancel_guard();
+        let rows = cancel_on_drop(cancel_guard, query.load::<DbPairingOverride>(&mut conn))
+            .await?
+            .into_iter()
+            .map(PairingOverride::try_from)
+            .collect::<DbResult<Vec<_>>>()?;
+        info!(count = rows.len(), "list_ok");
+        Ok(rows)
+    }
+}
@@ -1,12 +1,12 @@
 // implementation of postal address port
 
 use crate::{
-    PgDb, escape_like, map_db_err,
+    PgDb, cancel_on_drop, escape_like, map_db_err, map_version_conflict,
     schema::{postal_addresses, postal_addresses::dsl::*},
 };
 use app_core::{
-    DbError, DbResult, DbpPostalAddress, PostalAddress,
-    utils::{id_version::IdVersion, traits::ObjectIdVersion},
+    DbError, DbResult, DbpPostalAddress, GeoPoint, PostalAddress, PostalAddressSortColumn,
+    utils::{id_version::IdVersion, list_order::ListOrder, traits::ObjectIdVersion},
 };
 use async_trait::async_trait;
 use chrono::{DateTime, Utc};
@@ -14,7 +14,7 @@ use diesel::{
     dsl::sql,
     prelude::{
         AsChangeset, BoolExpressionMethods, ExpressionMethods, Insertable, OptionalExtension,
-        PgSortExpressionMethods, QueryDsl, Queryable, TextExpressionMethods,
+        QueryDsl, Queryable, TextExpressionMethods,
     },
     sql_types::BigInt,
 };
@@ -36,6 +36,8 @@ pub struct DbPostalAddress {
     pub country: String,
     pub created_at: DateTime<Utc>,
     pub updated_at: DateTime<Utc>,
+    pub latitude: Option<f64>,
+    pub longitude: Option<f64>,
 }
 
 // Mapping DB -> Core
@@ -60,7 +62,12 @@ impl TryFrom<DbPostalAddress> for PostalAddress {
             .set_postal_code(r.postal_code)
             .set_locality(r.locality)
             .set_region(r.region.unwrap_or_default())
-            .set_country(Some(country_code));
+            .set_country(Some(country_code))
+            .set_location(
+                r.latitude
+                    .zip(r.longitude)
+                    .map(|(lat, lon)| GeoPoint::new(lat, lon)),
+            );
         Ok(pa)
     }
 }
@@ -78,6 +85,8 @@ pub struct WriteDbPostalAddress<'a> {
     pub locality: &'a str,
     pub region: Option<&'a str>,
     pub country: &'a str,
+    pub latitude: Option<f64>,
+    pub longitude: Option<f64>,
 }
 
 // Mapping Core -> DB
@@ -91,6 +100,8 @@ impl<'a> From<&'a PostalAddress> for WriteDbPostalAddress<'a> {
             locality: p.get_locality(),
             region: p.get_region(),
             country: country_code,
+            latitude: p.get_location().map(|l| l.latitude),
+            longitude: p.get_location().map(|l| l.longitude),
         }
     }
 }
@@ -132,7 +143,7 @@ impl DbpPostalAddress for PgDb {
         )
     )]
     async fn save_postal_address(&self, address: &PostalAddress) -> DbResult<PostalAddress> {
-        let mut conn = self.new_connection().await?;
+        let mut conn = self.new_write_connection().await?;
         let w = WriteDbPostalAddress::from(address);
 
         if let IdVersion::Existing(inner) = address.get_id_version() {
@@ -166,16 +177,17 @@ impl DbpPostalAddress for PgDb {
                 }
                 Err(diesel::result::Error::NotFound) => {
                     // Distinguish lock conflict from missing row
-                    let exists = diesel::select(diesel::dsl::exists(
-                        postal_addresses.filter(id.eq(inner.get_id())),
-                    ))
-                    .get_result::<bool>(&mut conn)
-                    .await
-                    .map_err(map_db_err)?;
-
-                    if exists {
-                        warn!("optimistic_lock_conflict");
-                        Err(DbError::OptimisticLockConflict)
+                    let current_version = postal_addresses
+                        .filter(id.eq(inner.get_id()))
+                        .select(version)
+                        .first::<i64>(&mut conn)
+                        .await
+                        .optional()
+                        .map_err(map_db_err)?;
+
+                    if let Some(current_version) = current_version {
+                        warn!(current_version, "optimistic_lock_conflict");
+                        Err(map_version_conflict(current_version))
                     } else {
                         warn!("row_missing_on_update");
                         Err(DbError::NotFound)
@@ -212,18 +224,20 @@ impl DbpPostalAddress for PgDb {
 
     #[instrument(
         name = "db.pa.list",
-        skip(self, name_filter, limit),
+        skip(self, name_filter, limit, order),
         fields(
             q_len = name_filter.map(|s| s.len()).unwrap_or(0),
-            limit = limit.unwrap_or(10)
+            limit = limit.unwrap_or(10),
+            offset = order.get_offset()
         )
     )]
     async fn list_postal_address_ids(
         &self,
         name_filter: Option<&str>,
         limit: Option<usize>,
+        order: &ListOrder<PostalAddress>,
     ) -> DbResult<Vec<Uuid>> {
-        let mut conn = self.new_connection().await?;
+        let mut conn = self.new_read_connection().await?;
 
         let mut query = postal_addresses.into_boxed::<diesel::pg::Pg>();
 
@@ -239,13 +253,24 @@ impl DbpPostalAddress for PgDb {
         if let Some(lim) = limit {
             query = query.limit(lim as i64);
         }
+        if order.get_offset() > 0 {
+            query = query.offset(order.get_offset() as i64);
+        }
 
-        let rows = query
-            .select(id)
-            .order((name.asc().nulls_last(), created_at.asc()))
-            .load::<Uuid>(&mut conn)
-            .await
-            .map_err(map_db_err)?;
+        let desc = order.is_descending();
+        query = match order.get_sort_by() {
+            PostalAddressSortColumn::Name if desc => query.order(name.desc()),
+            PostalAddressSortColumn::Name => query.order(name.asc()),
+            PostalAddressSortColumn::PostalCode if desc => query.order(postal_code.desc()),
+            PostalAddressSortColumn::PostalCode => query.order(postal_code.asc()),
+            PostalAddressSortColumn::Locality if desc => query.order(locality.desc()),
+            PostalAddressSortColumn::Locality => query.order(locality.asc()),
+        };
+        // ties are broken by id, so that pages neither overlap nor miss rows
+        query = query.then_order_by(id.asc());
+
+        let cancel_guard = conn.cancel_guard();
+        let rows = cancel_on_drop(cancel_guard, query.select(id).load::<Uuid>(&mut conn)).await?;
 
         info!(count = rows.len(), "list_ok");
         Ok(rows)
@@ -1,5 +1,164 @@
 // @generated automatically by Diesel CLI.
 
+diesel::table! {
+    api_tokens (id) {
+        id -> Uuid,
+        version -> Int8,
+        name -> Citext,
+        scopes -> Jsonb,
+        secret_prefix -> Text,
+        secret_hash -> Text,
+        rate_limit_per_minute -> Int4,
+        created_at -> Timestamptz,
+        updated_at -> Timestamptz,
+        last_used_at -> Nullable<Timestamptz>,
+        revoked_at -> Nullable<Timestamptz>,
+    }
+}
+
+diesel::table! {
+    audit_log (id) {
+        id -> Uuid,
+        version -> Int8,
+        actor -> Text,
+        action -> Text,
+        object_type -> Text,
+        object_id -> Uuid,
+        tournament_id -> Nullable<Uuid>,
+        old_version -> Nullable<Int8>,
+        new_version -> Nullable<Int8>,
+        diff -> Jsonb,
+        created_at -> Timestamptz,
+        updated_at -> Timestamptz,
+    }
+}
+
+diesel::table! {
+    client_errors (id) {
+        id -> Uuid,
+        version -> Int8,
+        component_id -> Nullable<Uuid>,
+        route -> Text,
+        app_version -> Text,
+        message -> Text,
+        occurrences -> Int4,
+        created_at -> Timestamptz,
+        updated_at -> Timestamptz,
+    }
+}
+
+diesel::table! {
+    entrants (id) {
+        id -> Uuid,
+        version -> Int8,
+        tournament_id -> Uuid,
+        name -> Citext,
+        club -> Nullable<Text>,
+        seed -> Nullable<Int4>,
+        created_at -> Timestamptz,
+        updated_at -> Timestamptz,
+        email -> Nullable<Text>,
+        checked_in_at -> Nullable<Timestamptz>,
+        waitlist_position -> Nullable<Int4>,
+        no_show -> Bool,
+    }
+}
+
+diesel::table! {
+    feedback (id) {
+        id -> Uuid,
+        version -> Int8,
+        message -> Text,
+        route -> Text,
+        tournament_id -> Nullable<Uuid>,
+        app_version -> Text,
+        recent_errors -> Array<Text>,
+        created_at -> Timestamptz,
+        updated_at -> Timestamptz,
+    }
+}
+
+diesel::table! {
+    group_seedings (stage_id) {
+        stage_id -> Uuid,
+        tournament_id -> Uuid,
+        seeding -> Jsonb,
+        created_at -> Timestamptz,
+        updated_at -> Timestamptz,
+    }
+}
+
+diesel::table! {
+    group_standings (group_id) {
+        group_id -> Uuid,
+        tournament_id -> Uuid,
+        standings -> Jsonb,
+        computed_at -> Timestamptz,
+        created_at -> Timestamptz,
+        updated_at -> Timestamptz,
+    }
+}
+
+diesel::table! {
+    match_notes (id) {
+        id -> Uuid,
+        version -> Int8,
+        tournament_id -> Uuid,
+        match_id -> Uuid,
+        tags -> Array<Text>,
+        text -> Text,
+        author -> Text,
+        created_at -> Timestamptz,
+        updated_at -> Timestamptz,
+    }
+}
+
+diesel::table! {
+    matches (id) {
+        id -> Uuid,
+        tournament_id -> Uuid,
+        stage_id -> Uuid,
+        group_id -> Uuid,
+        round_id -> Uuid,
+        number -> Int4,
+        station -> Int4,
+        start_at -> Timestamptz,
+        data -> Jsonb,
+        created_at -> Timestamptz,
+        updated_at -> Timestamptz,
+        version -> Int8,
+    }
+}
+
+diesel::table! {
+    officials (id) {
+        id -> Uuid,
+        version -> Int8,
+        tournament_id -> Uuid,
+        name -> Citext,
+        club -> Nullable<Text>,
+        qualifications -> Jsonb,
+        availability -> Jsonb,
+        created_at -> Timestamptz,
+        updated_at -> Timestamptz,
+    }
+}
+
+diesel::table! {
+    pairing_overrides (id) {
+        id -> Uuid,
+        version -> Int8,
+        tournament_id -> Uuid,
+        stage_id -> Uuid,
+        round_number -> Int4,
+        adjustments -> Jsonb,
+        note -> Text,
+        author -> Text,
+        created_at -> Timestamptz,
+        updated_at -> Timestamptz,
+    }
+}
+
 diesel::table! {
     postal_addresses (id) {
         id -> Uuid,
@@ -12,6 +171,38 @@ diesel::table! {
         country -> Text,
         created_at -> Timestamptz,
         updated_at -> Timestamptz,
+        latitude -> Nullable<Float8>,
+        longitude -> Nullable<Float8>,
+    }
+}
+
+diesel::table! {
+    scorekeeper_tokens (id) {
+        id -> Uuid,
+        version -> Int8,
+        tournament_id -> Uuid,
+        label -> Citext,
+        grants -> Jsonb,
+        secret_prefix -> Text,
+        secret_hash -> Text,
+        expires_at -> Nullable<Timestamptz>,
+        created_at -> Timestamptz,
+        updated_at -> Timestamptz,
+        last_used_at -> Nullable<Timestamptz>,
+        revoked_at -> Nullable<Timestamptz>,
+    }
+}
+
+diesel::table! {
+    shift_log_entries (id) {
+        id -> Uuid,
+        version -> Int8,
+        tournament_id -> Uuid,
+        author -> Text,
+        text -> Text,
+        pinned -> Bool,
+        created_at -> Timestamptz,
+        updated_at -> Timestamptz,
     }
 }
 
@@ -24,6 +215,32 @@ diesel::table! {
         config -> Jsonb,
         created_at -> Timestamptz,
         updated_at -> Timestamptz,
+        archived_at -> Nullable<Timestamptz>,
+        plugin_version -> Int4,
+    }
+}
+
+diesel::table! {
+    stage_snapshots (id) {
+        id -> Uuid,
+        tournament_id -> Uuid,
+        stage_id -> Uuid,
+        stage_number -> Int4,
+        matches -> Jsonb,
+        standings -> Jsonb,
+        created_at -> Timestamptz,
+    }
+}
+
+diesel::table! {
+    stage_groups (id) {
+        id -> Uuid,
+        version -> Int8,
+        stage_id -> Uuid,
+        number -> Int4,
+        data -> Jsonb,
+        created_at -> Timestamptz,
+        updated_at -> Timestamptz,
     }
 }
 
@@ -36,6 +253,10 @@ diesel::table! {
         num_groups -> Int4,
         created_at -> Timestamptz,
         updated_at -> Timestamptz,
+        max_stations -> Nullable<Int4>,
+        auto_advance -> Bool,
+        scoring_override -> Jsonb,
+        latest_end -> Nullable<Timestamptz>,
     }
 }
 
@@ -51,14 +272,106 @@ diesel::table! {
         state -> Jsonb,
         created_at -> Timestamptz,
         updated_at -> Timestamptz,
+        archived_at -> Nullable<Timestamptz>,
+        num_stations -> Int4,
+        sandbox -> Bool,
+        languages -> Jsonb,
+        description -> Jsonb,
+        stations -> Jsonb,
+        check_in_deadline -> Nullable<Timestamptz>,
+        no_show_policy -> Jsonb,
+        station_windows -> Jsonb,
+        address_id -> Nullable<Uuid>,
+        venue_id -> Nullable<Uuid>,
+        court_call_policy -> Jsonb,
+        sport_config_id -> Nullable<Uuid>,
+    }
+}
+
+diesel::table! {
+    venues (id) {
+        id -> Uuid,
+        version -> Int8,
+        name -> Citext,
+        address_id -> Nullable<Uuid>,
+        courts -> Jsonb,
+        created_at -> Timestamptz,
+        updated_at -> Timestamptz,
+    }
+}
+
+diesel::table! {
+    webhook_deliveries (id) {
+        id -> Uuid,
+        endpoint_id -> Uuid,
+        event_id -> Uuid,
+        event_type -> Text,
+        payload -> Text,
+        status_code -> Nullable<Int4>,
+        error -> Nullable<Text>,
+        duration_ms -> Int4,
+        attempted_at -> Timestamptz,
+    }
+}
+
+diesel::table! {
+    webhook_endpoints (id) {
+        id -> Uuid,
+        version -> Int8,
+        name -> Citext,
+        url -> Text,
+        secret -> Text,
+        event_types -> Jsonb,
+        active -> Bool,
+        created_at -> Timestamptz,
+        updated_at -> Timestamptz,
+        format -> Text,
     }
 }
 
+diesel::joinable!(entrants -> tournament_bases (tournament_id));
+diesel::joinable!(feedback -> tournament_bases (tournament_id));
+diesel::joinable!(group_seedings -> stages (stage_id));
+diesel::joinable!(group_seedings -> tournament_bases (tournament_id));
+diesel::joinable!(group_standings -> tournament_bases (tournament_id));
+diesel::joinable!(match_notes -> tournament_bases (tournament_id));
+diesel::joinable!(matches -> stages (stage_id));
+diesel::joinable!(matches -> tournament_bases (tournament_id));
+diesel::joinable!(officials -> tournament_bases (tournament_id));
+diesel::joinable!(pairing_overrides -> stages (stage_id));
+diesel::joinable!(pairing_overrides -> tournament_bases (tournament_id));
+diesel::joinable!(scorekeeper_tokens -> tournament_bases (tournament_id));
+diesel::joinable!(shift_log_entries -> tournament_bases (tournament_id));
+diesel::joinable!(stage_groups -> stages (stage_id));
+diesel::joinable!(stage_snapshots -> stages (stage_id));
+diesel::joinable!(stage_snapshots -> tournament_bases (tournament_id));
 diesel::joinable!(stages -> tournament_bases (tournament_id));
+diesel::joinable!(tournament_bases -> sport_configs (sport_config_id));
+diesel::joinable!(tournament_bases -> venues (venue_id));
+diesel::joinable!(venues -> postal_addresses (address_id));
+diesel::joinable!(webhook_deliveries -> webhook_endpoints (endpoint_id));
 
 diesel::allow_tables_to_appear_in_same_query!(
+    api_tokens,
+    audit_log,
+    client_errors,
+    entrants,
+    feedback,
+    group_seedings,
+    group_standings,
+    match_notes,
+    matches,
+    officials,
+    pairing_overrides,
     postal_addresses,
+    scorekeeper_tokens,
+    shift_log_entries,
     sport_configs,
+    stage_groups,
+    stage_snapshots,
     stages,
     tournament_bases,
+    venues,
+    webhook_deliveries,
+    webhook_endpoints,
 );
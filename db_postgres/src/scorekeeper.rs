@@ -0,0 +1,265 @@
+//! implementation of scorekeeper token port
+
+use crate::{
+    PgDb, map_db_err,
+    schema::{scorekeeper_tokens, scorekeeper_tokens::dsl::*},
+};
+use app_core::{
+    DbError, DbResult, DbpScorekeeperToken, ScorekeeperGrant, ScorekeeperToken,
+    utils::{id_version::IdVersion, traits::ObjectIdVersion},
+};
+use async_trait::async_trait;
+use chrono::{DateTime, Utc};
+use diesel::{
+    dsl::sql,
+    prelude::{
+        AsChangeset, BoolExpressionMethods, ExpressionMethods, Insertable, OptionalExtension,
+        QueryDsl, Queryable,
+    },
+    sql_types::BigInt,
+};
+use diesel_async::RunQueryDsl;
+use tracing::{debug, error, info, instrument, warn};
+use uuid::Uuid;
+
+// ------------------- DB-Row (SELECT/RETURNING) -------------------
+#[derive(Debug, Queryable)]
+pub struct DbScorekeeperToken {
+    pub id: Uuid,
+    pub version: i64,
+    pub tournament_id: Uuid,
+    pub label: String,
+    pub grants: serde_json::Value,
+    pub secret_prefix: String,
+    pub secret_hash: String,
+    pub expires_at: Option<DateTime<Utc>>,
+    pub created_at: DateTime<Utc>,
+    pub updated_at: DateTime<Utc>,
+    pub last_used_at: Option<DateTime<Utc>>,
+    pub revoked_at: Option<DateTime<Utc>>,
+}
+
+// Mapping DB -> Core
+impl TryFrom<DbScorekeeperToken> for ScorekeeperToken {
+    type Error = DbError;
+
+    fn try_from(r: DbScorekeeperToken) -> Result<Self, Self::Error> {
+        if r.id.is_nil() {
+            return Err(DbError::NilRowId);
+        }
+        if r.version < 0 {
+            return Err(DbError::NegativeRowVersion);
+        }
+        if r.version > u32::MAX as i64 {
+            return Err(DbError::RowVersionOutOfRange);
+        }
+
+        let grants_from_json: Vec<ScorekeeperGrant> = serde_json::from_value(r.grants)
+            .map_err(|e| DbError::Other(format!("Failed to deserialize grants: {e}")))?;
+
+        let id_version = IdVersion::new(r.id, Some(r.version as u32));
+        let mut t = ScorekeeperToken::new(id_version);
+
+        t.set_tournament_id(r.tournament_id)
+            .set_label(r.label)
+            .set_grants(grants_from_json)
+            .set_secret_prefix_and_hash(r.secret_prefix, r.secret_hash)
+            .set_expires_at(r.expires_at)
+            .set_created_at(Some(r.created_at))
+            .set_last_used_at(r.last_used_at)
+            .set_revoked_at(r.revoked_at);
+
+        Ok(t)
+    }
+}
+
+// ------------------- INSERT / UPDATE -------------------
+#[derive(Debug, Insertable, AsChangeset)]
+#[diesel(table_name = scorekeeper_tokens)]
+#[diesel(treat_none_as_null = true)]
+pub struct WriteDbScorekeeperToken<'a> {
+    pub tournament_id: Uuid,
+    pub label: &'a str,
+    pub grants: serde_json::Value,
+    pub expires_at: Option<DateTime<Utc>>,
+    pub revoked_at: Option<DateTime<Utc>>,
+}
+
+// Mapping Core -> DB
+impl<'a> TryFrom<&'a ScorekeeperToken> for WriteDbScorekeeperToken<'a> {
+    type Error = DbError;
+
+    fn try_from(t: &'a ScorekeeperToken) -> Result<Self, Self::Error> {
+        Ok(WriteDbScorekeeperToken {
+            tournament_id: t.get_tournament_id(),
+            label: t.get_label(),
+            grants: serde_json::to_value(t.get_grants())
+                .map_err(|e| DbError::Other(format!("Failed to serialize grants: {e}")))?,
+            expires_at: t.get_expires_at(),
+            revoked_at: t.get_revoked_at(),
+        })
+    }
+}
+
+// ------------------- Impl trait --------------------
+
+#[async_trait]
+impl DbpScorekeeperToken for PgDb {
+    #[instrument(name = "db.scorekeeper_token.get", skip(self), fields(id = %token_id))]
+    async fn get_scorekeeper_token(&self, token_id: Uuid) -> DbResult<Option<ScorekeeperToken>> {
+        let mut conn = self.new_connection().await?;
+        let res = scorekeeper_tokens
+            .filter(id.eq(token_id))
+            .first::<DbScorekeeperToken>(&mut conn)
+            .await
+            .optional()
+            .map_err(map_db_err)?;
+
+        match res {
+            Some(res) => {
+                let res = ScorekeeperToken::try_from(res)?;
+                debug!("found_scorekeeper_token");
+                Ok(Some(res))
+            }
+            None => {
+                debug!("scorekeeper_token_not_found");
+                Ok(None)
+            }
+        }
+    }
+
+    // never log the hash, it identifies the token
+    #[instrument(name = "db.scorekeeper_token.get_by_hash", skip_all)]
+    async fn get_scorekeeper_token_by_hash(
+        &self,
+        hash: &str,
+    ) -> DbResult<Option<ScorekeeperToken>> {
+        // tokens must be revocable without delay, therefore do not read from replica
+        let mut conn = self.new_connection().await?;
+        let res = scorekeeper_tokens
+            .filter(secret_hash.eq(hash))
+            .first::<DbScorekeeperToken>(&mut conn)
+            .await
+            .optional()
+            .map_err(map_db_err)?;
+
+        res.map(ScorekeeperToken::try_from).transpose()
+    }
+
+    #[instrument(
+        name = "db.scorekeeper_token.save",
+        skip(self, token),
+        fields(
+            id = ?token.get_id(),
+            version = token.get_version(),
+            is_new = token.get_id_version().is_new()
+        )
+    )]
+    async fn save_scorekeeper_token(&self, token: &ScorekeeperToken) -> DbResult<ScorekeeperToken> {
+        let mut conn = self.new_write_connection().await?;
+        let w = WriteDbScorekeeperToken::try_from(token)?;
+
+        match token.get_id_version() {
+            // Case 1: UPDATE (Optimistic Locking); secret is never changed
+            IdVersion::Existing(inner) => {
+                let res = diesel::update(
+                    scorekeeper_tokens.filter(
+                        id.eq(inner.get_id())
+                            .and(version.eq(inner.get_version() as i64)),
+                    ),
+                )
+                .set((w, version.eq(sql::<BigInt>("version + 1"))))
+                .returning(scorekeeper_tokens::all_columns)
+                .get_result::<DbScorekeeperToken>(&mut conn)
+                .await;
+
+                match res {
+                    Ok(row) => {
+                        info!(saved_id = %row.id, new_version = row.version, "update_ok");
+                        Ok(row.try_into()?)
+                    }
+                    Err(diesel::result::Error::NotFound) => {
+                        // Check if it exists but version mismatch
+                        let exists = diesel::select(diesel::dsl::exists(
+                            scorekeeper_tokens.filter(id.eq(inner.get_id())),
+                        ))
+                        .get_result::<bool>(&mut conn)
+                        .await
+                        .map_err(map_db_err)?;
+
+                        if exists {
+                            warn!("optimistic_lock_conflict");
+                            Err(DbError::OptimisticLockConflict)
+                        } else {
+                            warn!("row_missing_on_update");
+                            Err(DbError::NotFound)
+                        }
+                    }
+                    Err(e) => {
+                        error!(error = %e, "update_failed");
+                        Err(map_db_err(e))
+                    }
+                }
+            }
+            // Case 2: INSERT with specific ID
+            IdVersion::NewWithId(new_id) => {
+                let row = diesel::insert_into(scorekeeper_tokens)
+                    .values((
+                        id.eq(new_id),
+                        secret_prefix.eq(token.get_secret_prefix()),
+                        secret_hash.eq(token.get_secret_hash()),
+                        w,
+                    ))
+                    .returning(scorekeeper_tokens::all_columns)
+                    .get_result::<DbScorekeeperToken>(&mut conn)
+                    .await
+                    .map_err(map_db_err)?;
+
+                info!(saved_id = %row.id, "insert_ok");
+                Ok(row.try_into()?)
+            }
+        }
+    }
+
+    #[instrument(name = "db.scorekeeper_token.touch", skip(self), fields(id = %token_id))]
+    async fn touch_scorekeeper_token_last_used(
+        &self,
+        token_id: Uuid,
+        at: DateTime<Utc>,
+    ) -> DbResult<()> {
+        let mut conn = self.new_write_connection().await?;
+        diesel::update(scorekeeper_tokens.filter(id.eq(token_id)))
+            .set(last_used_at.eq(at))
+            .execute(&mut conn)
+            .await
+            .map_err(map_db_err)?;
+        Ok(())
+    }
+
+    #[instrument(name = "db.scorekeeper_token.list", skip(self, t_id))]
+    async fn list_scorekeeper_tokens(
+        &self,
+        t_id: Uuid,
+        include_revoked: bool,
+    ) -> DbResult<Vec<ScorekeeperToken>> {
+        let mut conn = self.new_read_connection().await?;
+
+        let mut query = scorekeeper_tokens
+            .filter(tournament_id.eq(t_id))
+            .order(label.asc())
+            .into_boxed();
+        if !include_revoked {
+            query = query.filter(revoked_at.is_null());
+        }
+
+        let rows = query
+            .load::<DbScorekeeperToken>(&mut conn)
+            .await
+            .map_err(map_db_err)?
+            .into_iter()
+            .map(ScorekeeperToken::try_from)
+            .collect::<DbResult<Vec<_>>>()?;
+        info!(count = rows.len(), "list_ok");
+        Ok(rows)
+    }
+}
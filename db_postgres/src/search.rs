@@ -0,0 +1,168 @@
+//! implementation of search port
+
+use crate::{
+    PgDb, cancel_on_drop, escape_like,
+    schema::{entrants, postal_addresses, sport_configs, tournament_bases},
+};
+use app_core::{DbResult, DbpSearch, SearchHit, SearchHitKind};
+use async_trait::async_trait;
+use diesel::{
+    dsl::sql,
+    prelude::{ExpressionMethods, JoinOnDsl, QueryDsl},
+    sql_types::{Bool, Text},
+};
+use diesel_async::RunQueryDsl;
+use tracing::{info, instrument};
+use uuid::Uuid;
+
+#[async_trait]
+impl DbpSearch for PgDb {
+    #[instrument(name = "db.search.all", skip(self, term))]
+    async fn search_all(&self, term: &str, limit_per_kind: usize) -> DbResult<Vec<SearchHit>> {
+        let mut conn = self.new_read_connection().await?;
+        let pattern = format!("%{}%", escape_like(term));
+        let limit = limit_per_kind as i64;
+        let mut hits = Vec::new();
+
+        let cancel_guard = conn.cancel_guard();
+        let tournaments = cancel_on_drop(
+            cancel_guard,
+            tournament_bases::table
+                // citext columns are cast to text to use the trigram indexes of the search
+                .filter(
+                    sql::<Bool>("tournament_bases.name::text ILIKE ")
+                        .bind::<Text, _>(pattern.clone()),
+                )
+                .filter(tournament_bases::archived_at.is_null())
+                .filter(tournament_bases::sandbox.eq(false))
+                .select((
+                    tournament_bases::id,
+                    tournament_bases::name,
+                    tournament_bases::sport_id,
+                ))
+                .order(tournament_bases::name.asc())
+                .limit(limit)
+                .load::<(Uuid, String, Uuid)>(&mut conn),
+        )
+        .await?;
+        hits.extend(
+            tournaments
+                .into_iter()
+                .map(|(t_id, t_name, s_id)| SearchHit {
+                    kind: SearchHitKind::Tournament,
+                    id: t_id,
+                    label: t_name,
+                    detail: None,
+                    sport_id: Some(s_id),
+                    tournament_id: None,
+                }),
+        );
+
+        let cancel_guard = conn.cancel_guard();
+        let entrants = cancel_on_drop(
+            cancel_guard,
+            entrants::table
+                .inner_join(
+                    tournament_bases::table.on(tournament_bases::id.eq(entrants::tournament_id)),
+                )
+                .filter(
+                    sql::<Bool>("(entrants.name::text ILIKE ")
+                        .bind::<Text, _>(pattern.clone())
+                        .sql(" OR entrants.club ILIKE ")
+                        .bind::<Text, _>(pattern.clone())
+                        .sql(")"),
+                )
+                .filter(tournament_bases::archived_at.is_null())
+                .filter(tournament_bases::sandbox.eq(false))
+                .select((
+                    entrants::id,
+                    entrants::name,
+                    entrants::club,
+                    tournament_bases::id,
+                    tournament_bases::sport_id,
+                ))
+                .order(entrants::name.asc())
+                .limit(limit)
+                .load::<(Uuid, String, Option<String>, Uuid, Uuid)>(&mut conn),
+        )
+        .await?;
+        hits.extend(
+            entrants
+                .into_iter()
+                .map(|(e_id, e_name, club, t_id, s_id)| SearchHit {
+                    kind: SearchHitKind::Entrant,
+                    id: e_id,
+                    label: e_name,
+                    detail: club,
+                    sport_id: Some(s_id),
+                    tournament_id: Some(t_id),
+                }),
+        );
+
+        let cancel_guard = conn.cancel_guard();
+        let configs = cancel_on_drop(
+            cancel_guard,
+            sport_configs::table
+                .filter(
+                    sql::<Bool>("sport_configs.name::text ILIKE ").bind::<Text, _>(pattern.clone()),
+                )
+                .filter(sport_configs::archived_at.is_null())
+                .select((
+                    sport_configs::id,
+                    sport_configs::name,
+                    sport_configs::sport_id,
+                ))
+                .order(sport_configs::name.asc())
+                .limit(limit)
+                .load::<(Uuid, String, Uuid)>(&mut conn),
+        )
+        .await?;
+        hits.extend(configs.into_iter().map(|(c_id, c_name, s_id)| SearchHit {
+            kind: SearchHitKind::SportConfig,
+            id: c_id,
+            label: c_name,
+            detail: None,
+            sport_id: Some(s_id),
+            tournament_id: None,
+        }));
+
+        let cancel_guard = conn.cancel_guard();
+        let addresses = cancel_on_drop(
+            cancel_guard,
+            postal_addresses::table
+                .filter(
+                    sql::<Bool>("(postal_addresses.name::text ILIKE ")
+                        .bind::<Text, _>(pattern.clone())
+                        .sql(" OR postal_addresses.street ILIKE ")
+                        .bind::<Text, _>(pattern.clone())
+                        .sql(" OR postal_addresses.locality ILIKE ")
+                        .bind::<Text, _>(pattern)
+                        .sql(")"),
+                )
+                .select((
+                    postal_addresses::id,
+                    postal_addresses::name,
+                    postal_addresses::locality,
+                ))
+                .order(postal_addresses::name.asc())
+                .limit(limit)
+                .load::<(Uuid, String, String)>(&mut conn),
+        )
+        .await?;
+        hits.extend(
+            addresses
+                .into_iter()
+                .map(|(a_id, a_name, a_locality)| SearchHit {
+                    kind: SearchHitKind::PostalAddress,
+                    id: a_id,
+                    label: a_name,
+                    detail: Some(a_locality),
+                    sport_id: None,
+                    tournament_id: None,
+                }),
+        );
+
+        info!(count = hits.len(), "search_ok");
+        Ok(hits)
+    }
+}
@@ -1,12 +1,12 @@
 // implementation of sport config port
 
 use crate::{
-    PgDb, escape_like, map_db_err,
+    PgDb, cancel_on_drop, escape_like, map_db_err, map_version_conflict,
     schema::{sport_configs, sport_configs::dsl::*},
 };
 use app_core::{
-    DbError, DbResult, DbpSportConfig, SportConfig,
-    utils::{id_version::IdVersion, traits::ObjectIdVersion},
+    DbError, DbResult, DbpSportConfig, SportConfig, SportConfigSortColumn,
+    utils::{id_version::IdVersion, list_order::ListOrder, traits::ObjectIdVersion},
 };
 use async_trait::async_trait;
 use chrono::{DateTime, Utc};
@@ -14,7 +14,7 @@ use diesel::{
     dsl::sql,
     prelude::{
         AsChangeset, BoolExpressionMethods, ExpressionMethods, Insertable, OptionalExtension,
-        PgSortExpressionMethods, QueryDsl, Queryable, TextExpressionMethods,
+        QueryDsl, Queryable, TextExpressionMethods,
     },
     sql_types::BigInt,
 };
@@ -33,6 +33,8 @@ pub struct DbSportConfig {
     pub config: Value,
     pub created_at: DateTime<Utc>,
     pub updated_at: DateTime<Utc>,
+    pub archived_at: Option<DateTime<Utc>>,
+    pub plugin_version: i32,
 }
 
 // Mapping DB -> Core
@@ -53,7 +55,9 @@ impl TryFrom<DbSportConfig> for SportConfig {
         let mut sc = SportConfig::new(id_version);
         sc.set_sport_id(r.sport_id)
             .set_name(r.name)
-            .set_config(r.config.clone());
+            .set_config(r.config.clone())
+            .set_archived_at(r.archived_at)
+            .set_plugin_version(r.plugin_version as u32);
         Ok(sc)
     }
 }
@@ -65,6 +69,7 @@ pub struct WriteDbSportConfig<'a> {
     pub sport_id: Uuid,
     pub name: &'a str,
     pub config: &'a Value,
+    pub plugin_version: i32,
 }
 
 // Mapping Core -> DB
@@ -74,6 +79,7 @@ impl<'a> From<&'a SportConfig> for WriteDbSportConfig<'a> {
             sport_id: sc.get_sport_id(),
             name: sc.get_name(),
             config: sc.get_config(),
+            plugin_version: sc.get_plugin_version() as i32,
         }
     }
 }
@@ -115,7 +121,7 @@ impl DbpSportConfig for PgDb {
         )
     )]
     async fn save_sport_config(&self, sport_config: &SportConfig) -> DbResult<SportConfig> {
-        let mut conn = self.new_connection().await?;
+        let mut conn = self.new_write_connection().await?;
         let w = WriteDbSportConfig::from(sport_config);
 
         if let IdVersion::Existing(inner) = sport_config.get_id_version() {
@@ -127,7 +133,17 @@ impl DbpSportConfig for PgDb {
                 ),
             )
             .set((w, version.eq(sql::<BigInt>("version + 1"))))
-            .returning((id, version, sport_id, name, config, created_at, updated_at))
+            .returning((
+                id,
+                version,
+                sport_id,
+                name,
+                config,
+                created_at,
+                updated_at,
+                archived_at,
+                plugin_version,
+            ))
             .get_result::<DbSportConfig>(&mut conn)
             .await;
 
@@ -137,16 +153,18 @@ impl DbpSportConfig for PgDb {
                     Ok(row.try_into()?)
                 }
                 Err(diesel::result::Error::NotFound) => {
-                    let exists = diesel::select(diesel::dsl::exists(
-                        sport_configs.filter(id.eq(inner.get_id())),
-                    ))
-                    .get_result::<bool>(&mut conn)
-                    .await
-                    .map_err(map_db_err)?;
+                    // Distinguish lock conflict from missing row
+                    let current_version = sport_configs
+                        .filter(id.eq(inner.get_id()))
+                        .select(version)
+                        .first::<i64>(&mut conn)
+                        .await
+                        .optional()
+                        .map_err(map_db_err)?;
 
-                    if exists {
-                        warn!("optimistic_lock_conflict");
-                        Err(DbError::OptimisticLockConflict)
+                    if let Some(current_version) = current_version {
+                        warn!(current_version, "optimistic_lock_conflict");
+                        Err(map_version_conflict(current_version))
                     } else {
                         warn!("row_missing_on_update");
                         Err(DbError::NotFound)
@@ -161,7 +179,17 @@ impl DbpSportConfig for PgDb {
             // INSERT
             let row = diesel::insert_into(sport_configs)
                 .values(w)
-                .returning((id, version, sport_id, name, config, created_at, updated_at))
+                .returning((
+                    id,
+                    version,
+                    sport_id,
+                    name,
+                    config,
+                    created_at,
+                    updated_at,
+                    archived_at,
+                    plugin_version,
+                ))
                 .get_result::<DbSportConfig>(&mut conn)
                 .await
                 .map_err(map_db_err)?;
@@ -170,18 +198,82 @@ impl DbpSportConfig for PgDb {
         }
     }
 
-    #[instrument(name = "db.sc.list", skip(self, name_filter, limit))]
+    #[instrument(name = "db.sc.archive", skip(self), fields(id = %sc_id))]
+    async fn archive_sport_config(&self, sc_id: Uuid, sc_version: u32) -> DbResult<SportConfig> {
+        let mut conn = self.new_write_connection().await?;
+        let res =
+            diesel::update(sport_configs.filter(id.eq(sc_id).and(version.eq(sc_version as i64))))
+                .set((
+                    archived_at.eq(diesel::dsl::now),
+                    version.eq(sql::<BigInt>("version + 1")),
+                ))
+                .returning((
+                    id,
+                    version,
+                    sport_id,
+                    name,
+                    config,
+                    created_at,
+                    updated_at,
+                    archived_at,
+                    plugin_version,
+                ))
+                .get_result::<DbSportConfig>(&mut conn)
+                .await;
+
+        match res {
+            Ok(row) => {
+                info!(archived_id = %row.id, new_version = row.version, "archive_ok");
+                Ok(row.try_into()?)
+            }
+            Err(diesel::result::Error::NotFound) => {
+                // Distinguish lock conflict from missing row
+                let current_version = sport_configs
+                    .filter(id.eq(sc_id))
+                    .select(version)
+                    .first::<i64>(&mut conn)
+                    .await
+                    .optional()
+                    .map_err(map_db_err)?;
+
+                if let Some(current_version) = current_version {
+                    warn!(current_version, "optimistic_lock_conflict");
+                    Err(map_version_conflict(current_version))
+                } else {
+                    warn!("row_missing_on_archive");
+                    Err(DbError::NotFound)
+                }
+            }
+            Err(e) => {
+                error!(error = %e, "archive_failed");
+                Err(map_db_err(e))
+            }
+        }
+    }
+
+    #[instrument(
+        name = "db.sc.list",
+        skip(self, name_filter, limit, order),
+        fields(offset = order.get_offset())
+    )]
     async fn list_sport_config_ids(
         &self,
         sport: Uuid,
         name_filter: Option<&str>,
+        include_archived: bool,
         limit: Option<usize>,
+        order: &ListOrder<SportConfig>,
     ) -> DbResult<Vec<Uuid>> {
-        let mut conn = self.new_connection().await?;
+        let mut conn = self.new_read_connection().await?;
         let mut query = sport_configs.into_boxed::<diesel::pg::Pg>();
 
         query = query.filter(sport_id.eq(sport));
 
+        if !include_archived {
+            debug!("excluding_archived_sport_configs");
+            query = query.filter(archived_at.is_null());
+        }
+
         if let Some(f) = name_filter
             && !f.is_empty()
         {
@@ -194,13 +286,22 @@ impl DbpSportConfig for PgDb {
         if let Some(lim) = limit {
             query = query.limit(lim as i64);
         }
+        if order.get_offset() > 0 {
+            query = query.offset(order.get_offset() as i64);
+        }
 
-        let rows = query
-            .select(id)
-            .order((name.asc().nulls_last(), created_at.asc()))
-            .load::<Uuid>(&mut conn)
-            .await
-            .map_err(map_db_err)?;
+        let desc = order.is_descending();
+        query = match order.get_sort_by() {
+            SportConfigSortColumn::Name if desc => query.order(name.desc()),
+            SportConfigSortColumn::Name => query.order(name.asc()),
+            SportConfigSortColumn::PluginVersion if desc => query.order(plugin_version.desc()),
+            SportConfigSortColumn::PluginVersion => query.order(plugin_version.asc()),
+        };
+        // ties are broken by id, so that pages neither overlap nor miss rows
+        query = query.then_order_by(id.asc());
+
+        let cancel_guard = conn.cancel_guard();
+        let rows = cancel_on_drop(cancel_guard, query.select(id).load::<Uuid>(&mut conn)).await?;
 
         info!(count = rows.len(), "list_ok");
         Ok(rows)
@@ -5,7 +5,7 @@ use crate::{
     schema::{stages, stages::dsl::*},
 };
 use app_core::{
-    DbError, DbResult, DbpStage, Stage,
+    DbError, DbResult, DbpStage, ScoringOverride, Stage,
     utils::{id_version::IdVersion, traits::ObjectIdVersion},
 };
 use async_trait::async_trait;
@@ -18,7 +18,7 @@ use diesel::{
     },
     sql_types::BigInt,
 };
-use diesel_async::RunQueryDsl;
+use diesel_async::{AsyncPgConnection, RunQueryDsl};
 use tracing::{debug, error, info, instrument, warn};
 use uuid::Uuid;
 
@@ -32,6 +32,10 @@ pub struct DbStage {
     pub num_groups: i32,
     pub created_at: DateTime<Utc>,
     pub updated_at: DateTime<Utc>,
+    pub max_stations: Option<i32>,
+    pub auto_advance: bool,
+    pub scoring_override: serde_json::Value,
+    pub latest_end: Option<DateTime<Utc>>,
 }
 
 // Mapping DB -> Core
@@ -49,12 +53,21 @@ impl TryFrom<DbStage> for Stage {
             return Err(DbError::RowVersionOutOfRange);
         }
 
+        let scoring_override_from_json: ScoringOverride =
+            serde_json::from_value(r.scoring_override).map_err(|e| {
+                DbError::Other(format!("Failed to deserialize scoring_override: {e}"))
+            })?;
+
         let id_version = IdVersion::new(r.id, Some(r.version as u32));
         let mut s = Stage::new(id_version);
 
         s.set_tournament_id(r.tournament_id)
             .set_number(r.number as u32)
-            .set_num_groups(r.num_groups as u32);
+            .set_num_groups(r.num_groups as u32)
+            .set_max_stations(r.max_stations.map(|m| m as u32))
+            .set_auto_advance(r.auto_advance)
+            .set_scoring_override(scoring_override_from_json)
+            .set_latest_end(r.latest_end);
 
         Ok(s)
     }
@@ -63,10 +76,16 @@ impl TryFrom<DbStage> for Stage {
 // ------------------- INSERT / UPDATE -------------------
 #[derive(Debug, Insertable, AsChangeset)]
 #[diesel(table_name = stages)]
+// write NULL for removed station limit and time cap
+#[diesel(treat_none_as_null = true)]
 pub struct WriteDbStage {
     pub tournament_id: Uuid,
     pub number: i32,
     pub num_groups: i32,
+    pub max_stations: Option<i32>,
+    pub auto_advance: bool,
+    pub scoring_override: serde_json::Value,
+    pub latest_end: Option<DateTime<Utc>>,
 }
 
 // Mapping Core -> DB
@@ -78,6 +97,12 @@ impl<'a> TryFrom<&'a Stage> for WriteDbStage {
             tournament_id: s.get_tournament_id(),
             number: s.get_number() as i32,
             num_groups: s.get_num_groups() as i32,
+            max_stations: s.get_max_stations().map(|m| m as i32),
+            auto_advance: s.is_auto_advance(),
+            scoring_override: serde_json::to_value(s.get_scoring_override()).map_err(|e| {
+                DbError::Other(format!("Failed to serialize scoring_override: {e}"))
+            })?,
+            latest_end: s.get_latest_end(),
         })
     }
 }
@@ -143,80 +168,8 @@ impl DbpStage for PgDb {
         )
     )]
     async fn save_stage(&self, stage: &Stage) -> DbResult<Stage> {
-        let mut conn = self.new_connection().await?;
-        let w = WriteDbStage::try_from(stage)?;
-
-        match stage.get_id_version() {
-            // Case 1: UPDATE (Optimistic Locking)
-            IdVersion::Existing(inner) => {
-                let res = diesel::update(
-                    stages.filter(
-                        id.eq(inner.get_id())
-                            .and(version.eq(inner.get_version() as i64)),
-                    ),
-                )
-                .set((w, version.eq(sql::<BigInt>("version + 1"))))
-                .returning((
-                    id,
-                    version,
-                    tournament_id,
-                    number,
-                    num_groups,
-                    created_at,
-                    updated_at,
-                ))
-                .get_result::<DbStage>(&mut conn)
-                .await;
-
-                match res {
-                    Ok(row) => {
-                        info!(saved_id = %row.id, new_version = row.version, "update_ok");
-                        Ok(row.try_into()?)
-                    }
-                    Err(diesel::result::Error::NotFound) => {
-                        // Check if it exists but version mismatch
-                        let exists = diesel::select(diesel::dsl::exists(
-                            stages.filter(id.eq(inner.get_id())),
-                        ))
-                        .get_result::<bool>(&mut conn)
-                        .await
-                        .map_err(map_db_err)?;
-
-                        if exists {
-                            warn!("optimistic_lock_conflict");
-                            Err(DbError::OptimisticLockConflict)
-                        } else {
-                            warn!("row_missing_on_update");
-                            Err(DbError::NotFound)
-                        }
-                    }
-                    Err(e) => {
-                        error!(error = %e, "update_failed");
-                        Err(map_db_err(e))
-                    }
-                }
-            }
-            // Case 2: INSERT with specific ID (e.g. Migration/Cloning)
-            IdVersion::NewWithId(new_id) => {
-                let row = diesel::insert_into(stages)
-                    .values((id.eq(new_id), w))
-                    .returning((
-                        id,
-                        version,
-                        tournament_id,
-                        number,
-                        num_groups,
-                        created_at,
-                        updated_at,
-                    ))
-                    .get_result::<DbStage>(&mut conn)
-                    .await
-                    .map_err(map_db_err)?;
-
-                info!(saved_id = %row.id, "insert_ok");
-                Ok(row.try_into()?)
-            }
-        }
+        let mut conn = self.new_write_connection().await?;
+        write_stage(&mut conn, stage).await
     }
 
     #[instrument(name = "db.stage.list", skip(self, t_id))]
@@ -242,3 +195,88 @@ impl DbpStage for PgDb {
         Ok(rows)
     }
 }
+
+/// insert or update stage with optimistic locking on `conn`; used by single saves and by
+/// saves of several objects in one transaction
+pub(crate) async fn write_stage(conn: &mut AsyncPgConnection, stage: &Stage) -> DbResult<Stage> {
+    let w = WriteDbStage::try_from(stage)?;
+
+    match stage.get_id_version() {
+        // Case 1: UPDATE (Optimistic Locking)
+        IdVersion::Existing(inner) => {
+            let res = diesel::update(
+                stages.filter(
+                    id.eq(inner.get_id())
+                        .and(version.eq(inner.get_version() as i64)),
+                ),
+            )
+            .set((w, version.eq(sql::<BigInt>("version + 1"))))
+            .returning((
+                id,
+                version,
+                tournament_id,
+                number,
+                num_groups,
+                created_at,
+                updated_at,
+                max_stations,
+                auto_advance,
+                scoring_override,
+                latest_end,
+            ))
+            .get_result::<DbStage>(conn)
+            .await;
+
+            match res {
+                Ok(row) => {
+                    info!(saved_id = %row.id, new_version = row.version, "update_ok");
+                    Ok(row.try_into()?)
+                }
+                Err(diesel::result::Error::NotFound) => {
+                    // Check if it exists but version mismatch
+                    let exists =
+                        diesel::select(diesel::dsl::exists(stages.filter(id.eq(inner.get_id()))))
+                            .get_result::<bool>(conn)
+                            .await
+                            .map_err(map_db_err)?;
+
+                    if exists {
+                        warn!("optimistic_lock_conflict");
+                        Err(DbError::OptimisticLockConflict)
+                    } else {
+                        warn!("row_missing_on_update");
+                        Err(DbError::NotFound)
+                    }
+                }
+                Err(e) => {
+                    error!(error = %e, "update_failed");
+                    Err(map_db_err(e))
+                }
+            }
+        }
+        // Case 2: INSERT with specific ID (e.g. Migration/Cloning)
+        IdVersion::NewWithId(new_id) => {
+            let row = diesel::insert_into(stages)
+                .values((id.eq(new_id), w))
+                .returning((
+                    id,
+                    version,
+                    tournament_id,
+                    number,
+                    num_groups,
+                    created_at,
+                    updated_at,
+                    max_stations,
+                    auto_advance,
+                    scoring_override,
+                    latest_end,
+                ))
+                .get_result::<DbStage>(conn)
+                .await
+                .map_err(map_db_err)?;
+
+            info!(saved_id = %row.id, "insert_ok");
+            Ok(row.try_into()?)
+        }
+    }
+}
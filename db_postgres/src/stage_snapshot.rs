@@ -0,0 +1,164 @@
+//! implementation of stage snapshot port
+
+use crate::{
+    PgDb, cancel_on_drop, map_db_err,
+    schema::{stage_snapshots, stage_snapshots::dsl::*},
+};
+use app_core::{CachedGroupStandings, DbError, DbResult, DbpStageSnapshot, Match, StageSnapshot};
+use async_trait::async_trait;
+use chrono::{DateTime, Utc};
+use diesel::prelude::{ExpressionMethods, Insertable, OptionalExtension, QueryDsl, Queryable};
+use diesel_async::RunQueryDsl;
+use tracing::{debug, info, instrument, warn};
+use uuid::Uuid;
+
+// ------------------- DB-Row (SELECT/RETURNING) -------------------
+#[derive(Debug, Queryable)]
+pub struct DbStageSnapshot {
+    pub id: Uuid,
+    pub tournament_id: Uuid,
+    pub stage_id: Uuid,
+    pub stage_number: i32,
+    pub matches: serde_json::Value,
+    pub standings: serde_json::Value,
+    pub created_at: DateTime<Utc>,
+}
+
+// Mapping DB -> Core
+impl TryFrom<DbStageSnapshot> for StageSnapshot {
+    type Error = DbError;
+
+    fn try_from(r: DbStageSnapshot) -> Result<Self, Self::Error> {
+        if r.id.is_nil() {
+            return Err(DbError::NilRowId);
+        }
+        let matches_from_json: Vec<Match> = serde_json::from_value(r.matches)
+            .map_err(|e| DbError::Other(format!("Failed to deserialize matches: {e}")))?;
+        let standings_from_json: Vec<CachedGroupStandings> = serde_json::from_value(r.standings)
+            .map_err(|e| DbError::Other(format!("Failed to deserialize standings: {e}")))?;
+
+        Ok(StageSnapshot {
+            id: r.id,
+            tournament_id: r.tournament_id,
+            stage_id: r.stage_id,
+            stage_number: r.stage_number as u32,
+            matches: matches_from_json,
+            standings: standings_from_json,
+            created_at: r.created_at,
+        })
+    }
+}
+
+// ------------------- INSERT -------------------
+#[derive(Debug, Insertable)]
+#[diesel(table_name = stage_snapshots)]
+pub struct WriteDbStageSnapshot {
+    pub id: Uuid,
+    pub tournament_id: Uuid,
+    pub stage_id: Uuid,
+    pub stage_number: i32,
+    pub matches: serde_json::Value,
+    pub standings: serde_json::Value,
+    pub created_at: DateTime<Utc>,
+}
+
+// Mapping Core -> DB
+impl TryFrom<&StageSnapshot> for WriteDbStageSnapshot {
+    type Error = DbError;
+
+    fn try_from(s: &StageSnapshot) -> Result<Self, Self::Error> {
+        Ok(WriteDbStageSnapshot {
+            id: s.id,
+            tournament_id: s.tournament_id,
+            stage_id: s.stage_id,
+            stage_number: s.stage_number as i32,
+            matches: serde_json::to_value(&s.matches)
+                .map_err(|e| DbError::Other(format!("Failed to serialize matches: {e}")))?,
+            standings: serde_json::to_value(&s.standings)
+                .map_err(|e| DbError::Other(format!("Failed to serialize standings: {e}")))?,
+            created_at: s.created_at,
+        })
+    }
+}
+
+// ------------------- Impl trait --------------------
+
+#[async_trait]
+impl DbpStageSnapshot for PgDb {
+    #[instrument(name = "db.stage_snapshot.get", skip(self), fields(id = %s_id))]
+    async fn get_stage_snapshot(&self, s_id: Uuid) -> DbResult<Option<StageSnapshot>> {
+        let mut conn = self.new_connection().await?;
+        let res = stage_snapshots
+            .filter(id.eq(s_id))
+            .first::<DbStageSnapshot>(&mut conn)
+            .await
+            .optional()
+            .map_err(map_db_err)?;
+
+        match res {
+            Some(res) => {
+                let res = StageSnapshot::try_from(res)?;
+                debug!("found_stage_snapshot");
+                Ok(Some(res))
+            }
+            None => {
+                debug!("stage_snapshot_not_found");
+                Ok(None)
+            }
+        }
+    }
+
+    #[instrument(
+        name = "db.stage_snapshot.save",
+        skip(self, snapshot),
+        fields(id = %snapshot.id, tournament_id = %snapshot.tournament_id)
+    )]
+    async fn save_stage_snapshot(&self, snapshot: &StageSnapshot) -> DbResult<()> {
+        let mut conn = self.new_write_connection().await?;
+        let w = WriteDbStageSnapshot::try_from(snapshot)?;
+
+        // snapshots are immutable, therefore insert only
+        diesel::insert_into(stage_snapshots)
+            .values(&w)
+            .execute(&mut conn)
+            .await
+            .map_err(map_db_err)?;
+
+        info!("insert_ok");
+        Ok(())
+    }
+
+    #[instrument(name = "db.stage_snapshot.delete", skip(self), fields(id = %s_id))]
+    async fn delete_stage_snapshot(&self, s_id: Uuid) -> DbResult<()> {
+        let mut conn = self.new_write_connection().await?;
+        let deleted = diesel::delete(stage_snapshots.filter(id.eq(s_id)))
+            .execute(&mut conn)
+            .await
+            .map_err(map_db_err)?;
+
+        if deleted == 0 {
+            warn!("row_missing_on_delete");
+            return Err(DbError::NotFound);
+        }
+        info!("delete_ok");
+        Ok(())
+    }
+
+    #[instrument(name = "db.stage_snapshot.list", skip(self, t_id))]
+    async fn list_stage_snapshots_of_tournament(&self, t_id: Uuid) -> DbResult<Vec<StageSnapshot>> {
+        let mut conn = self.new_read_connection().await?;
+
+        let query = stage_snapshots
+            .filter(tournament_id.eq(t_id))
+            .order((stage_number.asc(), created_at.asc()));
+
+        let cancel_guard = conn.cancel_guard();
+        let rows = cancel_on_drop(cancel_guard, query.load::<DbStageSnapshot>(&mut conn))
+            .await?
+            .into_iter()
+            .map(StageSnapshot::try_from)
+            .collect::<DbResult<Vec<_>>>()?;
+        info!(count = rows.len(), "list_ok");
+        Ok(rows)
+    }
+}
@@ -1,13 +1,20 @@
 //! implementation of tournament base port
 
 use crate::{
-    PgDb, escape_like, map_db_err,
+    PgDb, cancel_on_drop, escape_like,
+    group::write_group,
+    map_db_err, map_version_conflict,
     schema::{tournament_bases, tournament_bases::dsl::*},
+    stage::write_stage,
 };
 use app_core::{
-    DbError, DbResult, DbpTournamentBase, TournamentBase, TournamentMode, TournamentState,
+    CourtCallPolicy, DbBatchError, DbBatchResult, DbError, DbResult, DbpTournamentBase, Group,
+    Language, LocalizedText, NoShowPolicy, Stage, Station, StationWindow, TournamentBase,
+    TournamentBaseCondition, TournamentBaseSortColumn, TournamentMode, TournamentState,
     TournamentType,
-    utils::{id_version::IdVersion, traits::ObjectIdVersion},
+    utils::{
+        filter::Filter, id_version::IdVersion, list_order::ListOrder, traits::ObjectIdVersion,
+    },
 };
 use async_trait::async_trait;
 use chrono::{DateTime, Utc};
@@ -15,11 +22,13 @@ use diesel::{
     dsl::sql,
     prelude::{
         AsChangeset, BoolExpressionMethods, ExpressionMethods, Insertable, OptionalExtension,
-        PgSortExpressionMethods, QueryDsl, Queryable, TextExpressionMethods,
+        QueryDsl, Queryable, TextExpressionMethods,
     },
     sql_types::BigInt,
 };
-use diesel_async::RunQueryDsl;
+use diesel_async::{
+    AsyncConnection, AsyncPgConnection, RunQueryDsl, scoped_futures::ScopedFutureExt,
+};
 use tracing::{debug, error, info, instrument, warn};
 use uuid::Uuid;
 
@@ -36,6 +45,19 @@ pub struct DbTournamentBase {
     pub state: serde_json::Value,
     pub created_at: DateTime<Utc>,
     pub updated_at: DateTime<Utc>,
+    pub archived_at: Option<DateTime<Utc>>,
+    pub num_stations: i32,
+    pub sandbox: bool,
+    pub languages: serde_json::Value,
+    pub description: serde_json::Value,
+    pub stations: serde_json::Value,
+    pub check_in_deadline: Option<DateTime<Utc>>,
+    pub no_show_policy: serde_json::Value,
+    pub station_windows: serde_json::Value,
+    pub address_id: Option<Uuid>,
+    pub venue_id: Option<Uuid>,
+    pub court_call_policy: serde_json::Value,
+    pub sport_config_id: Option<Uuid>,
 }
 
 // Mapping DB -> Core
@@ -59,6 +81,22 @@ impl TryFrom<DbTournamentBase> for TournamentBase {
             .map_err(|e| DbError::Other(format!("Failed to deserialize mode: {e}")))?;
         let state_from_json: TournamentState = serde_json::from_value(r.state)
             .map_err(|e| DbError::Other(format!("Failed to deserialize state: {e}")))?;
+        let languages_from_json: Vec<Language> = serde_json::from_value(r.languages)
+            .map_err(|e| DbError::Other(format!("Failed to deserialize languages: {e}")))?;
+        let description_from_json: LocalizedText = serde_json::from_value(r.description)
+            .map_err(|e| DbError::Other(format!("Failed to deserialize description: {e}")))?;
+        let stations_from_json: Vec<Station> = serde_json::from_value(r.stations)
+            .map_err(|e| DbError::Other(format!("Failed to deserialize stations: {e}")))?;
+        let no_show_policy_from_json: NoShowPolicy = serde_json::from_value(r.no_show_policy)
+            .map_err(|e| DbError::Other(format!("Failed to deserialize no_show_policy: {e}")))?;
+        let station_windows_from_json: Vec<StationWindow> =
+            serde_json::from_value(r.station_windows).map_err(|e| {
+                DbError::Other(format!("Failed to deserialize station_windows: {e}"))
+            })?;
+        let court_call_policy_from_json: CourtCallPolicy =
+            serde_json::from_value(r.court_call_policy).map_err(|e| {
+                DbError::Other(format!("Failed to deserialize court_call_policy: {e}"))
+            })?;
 
         let id_version = IdVersion::new(r.id, Some(r.version as u32));
         let mut tb = TournamentBase::new(id_version);
@@ -66,9 +104,24 @@ impl TryFrom<DbTournamentBase> for TournamentBase {
         tb.set_name(r.name)
             .set_sport_id(r.sport_id)
             .set_num_entrants(r.num_entrants as u32)
+            // stations first, since number of stations may exceed number of named stations
+            .set_stations(stations_from_json)
+            .set_num_stations(r.num_stations as u32)
             .set_tournament_type(t_type_from_json)
             .set_tournament_mode(mode_from_json)
-            .set_tournament_state(state_from_json);
+            .set_tournament_state(state_from_json)
+            .set_archived_at(r.archived_at)
+            .set_sandbox(r.sandbox)
+            .set_languages(languages_from_json)
+            .set_description(description_from_json)
+            .set_check_in_deadline(r.check_in_deadline)
+            .set_no_show_policy(no_show_policy_from_json)
+            .set_station_windows(station_windows_from_json)
+            .set_address_id(r.address_id)
+            .set_venue_id(r.venue_id)
+            .set_court_call_policy(court_call_policy_from_json)
+            .set_sport_config_id(r.sport_config_id)
+            .set_created_at(Some(r.created_at));
 
         Ok(tb)
     }
@@ -77,13 +130,27 @@ impl TryFrom<DbTournamentBase> for TournamentBase {
 // ------------------- INSERT / UPDATE -------------------
 #[derive(Debug, Insertable, AsChangeset)]
 #[diesel(table_name = tournament_bases)]
+// write NULL for removed check-in deadline
+#[diesel(treat_none_as_null = true)]
 pub struct WriteDbTournamentBase<'a> {
     pub name: &'a str,
     pub sport_id: Uuid,
     pub num_entrants: i32,
+    pub num_stations: i32,
     pub t_type: serde_json::Value,
     pub mode: serde_json::Value,
     pub state: serde_json::Value,
+    pub sandbox: bool,
+    pub languages: serde_json::Value,
+    pub description: serde_json::Value,
+    pub stations: serde_json::Value,
+    pub check_in_deadline: Option<DateTime<Utc>>,
+    pub no_show_policy: serde_json::Value,
+    pub station_windows: serde_json::Value,
+    pub address_id: Option<Uuid>,
+    pub venue_id: Option<Uuid>,
+    pub court_call_policy: serde_json::Value,
+    pub sport_config_id: Option<Uuid>,
 }
 
 // Mapping Core -> DB
@@ -95,12 +162,31 @@ impl<'a> TryFrom<&'a TournamentBase> for WriteDbTournamentBase<'a> {
             name: tb.get_name(),
             sport_id: tb.get_sport_id(),
             num_entrants: tb.get_num_entrants() as i32,
+            num_stations: tb.get_num_stations() as i32,
             t_type: serde_json::to_value(tb.get_tournament_type())
                 .map_err(|e| DbError::Other(format!("Failed to serialize t_type: {e}")))?,
             mode: serde_json::to_value(tb.get_tournament_mode())
                 .map_err(|e| DbError::Other(format!("Failed to serialize mode: {e}")))?,
             state: serde_json::to_value(tb.get_tournament_state())
                 .map_err(|e| DbError::Other(format!("Failed to serialize state: {e}")))?,
+            sandbox: tb.is_sandbox(),
+            languages: serde_json::to_value(tb.get_languages())
+                .map_err(|e| DbError::Other(format!("Failed to serialize languages: {e}")))?,
+            description: serde_json::to_value(tb.get_description())
+                .map_err(|e| DbError::Other(format!("Failed to serialize description: {e}")))?,
+            stations: serde_json::to_value(tb.get_stations())
+                .map_err(|e| DbError::Other(format!("Failed to serialize stations: {e}")))?,
+            check_in_deadline: tb.get_check_in_deadline(),
+            no_show_policy: serde_json::to_value(tb.get_no_show_policy())
+                .map_err(|e| DbError::Other(format!("Failed to serialize no_show_policy: {e}")))?,
+            station_windows: serde_json::to_value(tb.get_station_windows())
+                .map_err(|e| DbError::Other(format!("Failed to serialize station_windows: {e}")))?,
+            address_id: tb.get_address_id(),
+            venue_id: tb.get_venue_id(),
+            court_call_policy: serde_json::to_value(tb.get_court_call_policy()).map_err(|e| {
+                DbError::Other(format!("Failed to serialize court_call_policy: {e}"))
+            })?,
+            sport_config_id: tb.get_sport_config_id(),
         })
     }
 }
@@ -142,19 +228,94 @@ impl DbpTournamentBase for PgDb {
         )
     )]
     async fn save_tournament_base(&self, tournament: &TournamentBase) -> DbResult<TournamentBase> {
-        let mut conn = self.new_connection().await?;
-        let w = WriteDbTournamentBase::try_from(tournament)?;
-
-        match tournament.get_id_version() {
-            // Case 1: UPDATE (Optimistic Locking)
-            IdVersion::Existing(inner) => {
-                let res = diesel::update(
-                    tournament_bases.filter(
-                        id.eq(inner.get_id())
-                            .and(version.eq(inner.get_version() as i64)),
-                    ),
-                )
-                .set((w, version.eq(sql::<BigInt>("version + 1"))))
+        let mut conn = self.new_write_connection().await?;
+        write_tournament_base(&mut conn, tournament).await
+    }
+
+    #[instrument(
+        name = "db.tb.save_diff",
+        skip(self, tournament, diff_stages, diff_groups),
+        fields(
+            id = ?tournament.map(|t| t.get_id()),
+            num_stages = diff_stages.len(),
+            num_groups = diff_groups.len()
+        )
+    )]
+    async fn save_tournament_diff(
+        &self,
+        tournament: Option<&TournamentBase>,
+        diff_stages: &[Stage],
+        diff_groups: &[Group],
+    ) -> DbBatchResult<(Option<TournamentBase>, Vec<Stage>, Vec<Group>)> {
+        let mut conn = self
+            .new_write_connection()
+            .await
+            .map_err(|error| DbBatchError {
+                object_id: None,
+                error,
+            })?;
+
+        // save all objects in one transaction; first failing object rolls back all others
+        let res = conn
+            .transaction::<_, TransactionError, _>(|conn| {
+                async move {
+                    let saved_base = match tournament {
+                        Some(t) => Some(
+                            write_tournament_base(conn, t)
+                                .await
+                                .map_err(|e| TransactionError::of_object(t.get_id(), e))?,
+                        ),
+                        None => None,
+                    };
+                    let mut saved_stages = Vec::with_capacity(diff_stages.len());
+                    for stage in diff_stages {
+                        let saved = write_stage(conn, stage)
+                            .await
+                            .map_err(|e| TransactionError::of_object(stage.get_id(), e))?;
+                        saved_stages.push(saved);
+                    }
+                    let mut saved_groups = Vec::with_capacity(diff_groups.len());
+                    for group in diff_groups {
+                        let saved = write_group(conn, group)
+                            .await
+                            .map_err(|e| TransactionError::of_object(group.get_id(), e))?;
+                        saved_groups.push(saved);
+                    }
+                    Ok((saved_base, saved_stages, saved_groups))
+                }
+                .scope_boxed()
+            })
+            .await;
+
+        match res {
+            Ok(saved) => {
+                info!(
+                    num_stages = saved.1.len(),
+                    num_groups = saved.2.len(),
+                    "save_diff_ok"
+                );
+                Ok(saved)
+            }
+            Err(TransactionError(e)) => {
+                warn!(object_id = ?e.object_id, error = %e.error, "save_diff_rolled_back");
+                Err(e)
+            }
+        }
+    }
+
+    #[instrument(name = "db.tb.archive", skip(self), fields(id = %t_id))]
+    async fn archive_tournament_base(
+        &self,
+        t_id: Uuid,
+        t_version: u32,
+    ) -> DbResult<TournamentBase> {
+        let mut conn = self.new_write_connection().await?;
+        let res =
+            diesel::update(tournament_bases.filter(id.eq(t_id).and(version.eq(t_version as i64))))
+                .set((
+                    archived_at.eq(diesel::dsl::now),
+                    version.eq(sql::<BigInt>("version + 1")),
+                ))
                 .returning((
                     id,
                     version,
@@ -166,118 +327,316 @@ impl DbpTournamentBase for PgDb {
                     state,
                     created_at,
                     updated_at,
+                    archived_at,
+                    num_stations,
+                    sandbox,
+                    languages,
+                    description,
+                    stations,
+                    check_in_deadline,
+                    no_show_policy,
+                    station_windows,
+                    address_id,
+                    venue_id,
+                    court_call_policy,
+                    sport_config_id,
                 ))
                 .get_result::<DbTournamentBase>(&mut conn)
                 .await;
 
-                match res {
-                    Ok(row) => {
-                        info!(saved_id = %row.id, new_version = row.version, "update_ok");
-                        Ok(row.try_into()?)
-                    }
-                    Err(diesel::result::Error::NotFound) => {
-                        let exists = diesel::select(diesel::dsl::exists(
-                            tournament_bases.filter(id.eq(inner.get_id())),
-                        ))
-                        .get_result::<bool>(&mut conn)
-                        .await
-                        .map_err(map_db_err)?;
-
-                        if exists {
-                            warn!("optimistic_lock_conflict");
-                            Err(DbError::OptimisticLockConflict)
-                        } else {
-                            warn!("row_missing_on_update");
-                            Err(DbError::NotFound)
-                        }
-                    }
-                    Err(e) => {
-                        error!(error = %e, "update_failed");
-                        Err(map_db_err(e))
-                    }
-                }
+        match res {
+            Ok(row) => {
+                info!(archived_id = %row.id, new_version = row.version, "archive_ok");
+                Ok(row.try_into()?)
             }
-            // Case 2: INSERT with specific ID (e.g. Migration)
-            IdVersion::NewWithId(new_id) => {
-                let row = diesel::insert_into(tournament_bases)
-                    .values((id.eq(new_id), w))
-                    .returning((
-                        id,
-                        version,
-                        name,
-                        sport_id,
-                        num_entrants,
-                        t_type,
-                        mode,
-                        state,
-                        created_at,
-                        updated_at,
-                    ))
-                    .get_result::<DbTournamentBase>(&mut conn)
+            Err(diesel::result::Error::NotFound) => {
+                // Distinguish lock conflict from missing row
+                let current_version = tournament_bases
+                    .filter(id.eq(t_id))
+                    .select(version)
+                    .first::<i64>(&mut conn)
                     .await
+                    .optional()
                     .map_err(map_db_err)?;
 
-                info!(saved_id = %row.id, "insert_ok");
-                Ok(row.try_into()?)
+                if let Some(current_version) = current_version {
+                    warn!(current_version, "optimistic_lock_conflict");
+                    Err(map_version_conflict(current_version))
+                } else {
+                    warn!("row_missing_on_archive");
+                    Err(DbError::NotFound)
+                }
+            }
+            Err(e) => {
+                error!(error = %e, "archive_failed");
+                Err(map_db_err(e))
             }
         }
     }
 
-    #[instrument(name = "db.tb.list", skip(self, name_filter, limit))]
-    async fn list_tournament_base_ids(
-        &self,
-        sport: Uuid,
-        name_filter: Option<&str>,
-        state_filter: Option<TournamentState>,
-        include_adhoc: bool,
-        limit: Option<usize>,
-    ) -> DbResult<Vec<Uuid>> {
-        let mut conn = self.new_connection().await?;
-        let mut query = tournament_bases.into_boxed::<diesel::pg::Pg>();
+    #[instrument(name = "db.tb.purge_sandbox", skip(self), fields(id = %t_id))]
+    async fn purge_sandbox_tournament_base(&self, t_id: Uuid) -> DbResult<()> {
+        let mut conn = self.new_write_connection().await?;
+        // dependent rows are removed by ON DELETE CASCADE
+        let deleted = diesel::delete(tournament_bases.filter(id.eq(t_id).and(sandbox.eq(true))))
+            .execute(&mut conn)
+            .await
+            .map_err(map_db_err)?;
 
-        if !sport.is_nil() {
-            query = query.filter(sport_id.eq(sport));
+        if deleted == 0 {
+            warn!("sandbox_row_missing_on_purge");
+            return Err(DbError::NotFound);
         }
+        info!(purged_id = %t_id, "purge_ok");
+        Ok(())
+    }
 
-        if let Some(f) = name_filter
-            && !f.is_empty()
-        {
-            let pattern = format!("%{}%", escape_like(f));
-            debug!("apply_name_filter");
-            query = query.filter(name.like(pattern));
-        }
+    #[instrument(name = "db.tb.delete", skip(self), fields(id = %t_id, version = t_version))]
+    async fn delete_tournament_base(&self, t_id: Uuid, t_version: u32) -> DbResult<()> {
+        let mut conn = self.new_write_connection().await?;
+        // dependent rows are removed by ON DELETE CASCADE
+        let deleted =
+            diesel::delete(tournament_bases.filter(id.eq(t_id).and(version.eq(t_version as i64))))
+                .execute(&mut conn)
+                .await
+                .map_err(map_db_err)?;
+
+        if deleted == 0 {
+            // Distinguish lock conflict from missing row
+            let current_version = tournament_bases
+                .filter(id.eq(t_id))
+                .select(version)
+                .first::<i64>(&mut conn)
+                .await
+                .optional()
+                .map_err(map_db_err)?;
 
-        if let Some(tournament_state) = state_filter {
-            debug!("apply_state_filter");
-            query = query.filter(state.eq(
-                serde_json::to_value(tournament_state).map_err(|e| {
-                    DbError::Other(format!("Failed to serialize state filter: {e}"))
-                })?,
-            ));
+            return if let Some(current_version) = current_version {
+                warn!(current_version, "optimistic_lock_conflict");
+                Err(map_version_conflict(current_version))
+            } else {
+                warn!("row_missing_on_delete");
+                Err(DbError::NotFound)
+            };
         }
+        info!(deleted_id = %t_id, "delete_ok");
+        Ok(())
+    }
+
+    #[instrument(
+        name = "db.tb.list",
+        skip(self, filter, order),
+        fields(offset = order.get_offset())
+    )]
+    async fn list_tournament_base_ids(
+        &self,
+        filter: &Filter<TournamentBase>,
+        order: &ListOrder<TournamentBase>,
+    ) -> DbResult<Vec<Uuid>> {
+        let mut conn = self.new_read_connection().await?;
+        let mut query = tournament_bases.into_boxed::<diesel::pg::Pg>();
 
-        if include_adhoc {
-            debug!("including_adhoc_tournaments");
-        } else {
-            debug!("excluding_adhoc_tournaments");
-            query = query.filter(
-                t_type.ne(serde_json::to_value(TournamentType::Adhoc)
-                    .map_err(|e| DbError::Other(format!("Failed to serialize AdHoc type: {e}")))?),
-            );
+        for condition in filter.get_conditions() {
+            debug!(?condition, "apply_condition");
+            query = match condition {
+                TournamentBaseCondition::SportIs(sport) => query.filter(sport_id.eq(*sport)),
+                TournamentBaseCondition::NameContains(text) => {
+                    // name is citext, therefore like is case insensitive
+                    let pattern = format!("%{}%", escape_like(text));
+                    query.filter(name.like(pattern))
+                }
+                TournamentBaseCondition::StateIn(states) => {
+                    let values = states
+                        .iter()
+                        .map(serde_json::to_value)
+                        .collect::<Result<Vec<_>, _>>()
+                        .map_err(|e| {
+                            DbError::Other(format!("Failed to serialize state filter: {e}"))
+                        })?;
+                    query.filter(state.eq_any(values))
+                }
+                TournamentBaseCondition::CreatedBetween { from, to } => {
+                    if let Some(from) = from {
+                        query = query.filter(created_at.ge(*from));
+                    }
+                    if let Some(to) = to {
+                        query = query.filter(created_at.lt(*to));
+                    }
+                    query
+                }
+                TournamentBaseCondition::NotAdhoc => query.filter(t_type.ne(
+                    serde_json::to_value(TournamentType::Adhoc).map_err(|e| {
+                        DbError::Other(format!("Failed to serialize AdHoc type: {e}"))
+                    })?,
+                )),
+                TournamentBaseCondition::NotArchived => query.filter(archived_at.is_null()),
+                TournamentBaseCondition::NotSandbox => query.filter(sandbox.eq(false)),
+                TournamentBaseCondition::NotDraft => query.filter(state.ne(
+                    serde_json::to_value(TournamentState::Draft).map_err(|e| {
+                        DbError::Other(format!("Failed to serialize Draft state: {e}"))
+                    })?,
+                )),
+            };
         }
 
-        if let Some(lim) = limit {
+        if let Some(lim) = filter.get_limit() {
             query = query.limit(lim as i64);
         }
+        if order.get_offset() > 0 {
+            query = query.offset(order.get_offset() as i64);
+        }
 
-        let rows = query
-            .select(id)
-            .order((name.asc().nulls_last(), created_at.asc()))
-            .load::<Uuid>(&mut conn)
-            .await
-            .map_err(map_db_err)?;
+        let desc = order.is_descending();
+        query = match order.get_sort_by() {
+            TournamentBaseSortColumn::Name if desc => query.order(name.desc()),
+            TournamentBaseSortColumn::Name => query.order(name.asc()),
+            TournamentBaseSortColumn::CreatedAt if desc => query.order(created_at.desc()),
+            TournamentBaseSortColumn::CreatedAt => query.order(created_at.asc()),
+            TournamentBaseSortColumn::NumEntrants if desc => query.order(num_entrants.desc()),
+            TournamentBaseSortColumn::NumEntrants => query.order(num_entrants.asc()),
+        };
+        // ties are broken by id, so that pages neither overlap nor miss rows
+        query = query.then_order_by(id.asc());
+
+        let cancel_guard = conn.cancel_guard();
+        let rows = cancel_on_drop(cancel_guard, query.select(id).load::<Uuid>(&mut conn)).await?;
 
         info!(count = rows.len(), "list_ok");
         Ok(rows)
     }
 }
+
+/// error of a transaction: failing saves are reported with the id of their object, while
+/// errors of begin or commit of the transaction fail the transaction as a whole
+pub(crate) struct TransactionError(pub(crate) DbBatchError);
+
+impl TransactionError {
+    pub(crate) fn of_object(object_id: Uuid, error: DbError) -> Self {
+        TransactionError(DbBatchError {
+            object_id: Some(object_id),
+            error,
+        })
+    }
+}
+
+impl From<diesel::result::Error> for TransactionError {
+    fn from(e: diesel::result::Error) -> Self {
+        TransactionError(DbBatchError {
+            object_id: None,
+            error: map_db_err(e),
+        })
+    }
+}
+
+/// insert or update tournament base with optimistic locking on `conn`
+async fn write_tournament_base(
+    conn: &mut AsyncPgConnection,
+    tournament: &TournamentBase,
+) -> DbResult<TournamentBase> {
+    let w = WriteDbTournamentBase::try_from(tournament)?;
+
+    match tournament.get_id_version() {
+        // Case 1: UPDATE (Optimistic Locking)
+        IdVersion::Existing(inner) => {
+            let res = diesel::update(
+                tournament_bases.filter(
+                    id.eq(inner.get_id())
+                        .and(version.eq(inner.get_version() as i64)),
+                ),
+            )
+            .set((w, version.eq(sql::<BigInt>("version + 1"))))
+            .returning((
+                id,
+                version,
+                name,
+                sport_id,
+                num_entrants,
+                t_type,
+                mode,
+                state,
+                created_at,
+                updated_at,
+                archived_at,
+                num_stations,
+                sandbox,
+                languages,
+                description,
+                stations,
+                check_in_deadline,
+                no_show_policy,
+                station_windows,
+                address_id,
+                venue_id,
+                court_call_policy,
+                sport_config_id,
+            ))
+            .get_result::<DbTournamentBase>(conn)
+            .await;
+
+            match res {
+                Ok(row) => {
+                    info!(saved_id = %row.id, new_version = row.version, "update_ok");
+                    Ok(row.try_into()?)
+                }
+                Err(diesel::result::Error::NotFound) => {
+                    // Distinguish lock conflict from missing row
+                    let current_version = tournament_bases
+                        .filter(id.eq(inner.get_id()))
+                        .select(version)
+                        .first::<i64>(conn)
+                        .await
+                        .optional()
+                        .map_err(map_db_err)?;
+
+                    if let Some(current_version) = current_version {
+                        warn!(current_version, "optimistic_lock_conflict");
+                        Err(map_version_conflict(current_version))
+                    } else {
+                        warn!("row_missing_on_update");
+                        Err(DbError::NotFound)
+                    }
+                }
+                Err(e) => {
+                    error!(error = %e, "update_failed");
+                    Err(map_db_err(e))
+                }
+            }
+        }
+        // Case 2: INSERT with specific ID (e.g. Migration)
+        IdVersion::NewWithId(new_id) => {
+            let row = diesel::insert_into(tournament_bases)
+                .values((id.eq(new_id), w))
+                .returning((
+                    id,
+                    version,
+                    name,
+                    sport_id,
+                    num_entrants,
+                    t_type,
+                    mode,
+                    state,
+                    created_at,
+                    updated_at,
+                    archived_at,
+                    num_stations,
+                    sandbox,
+                    languages,
+                    description,
+                    stations,
+                    check_in_deadline,
+                    no_show_policy,
+                    station_windows,
+                    address_id,
+                    venue_id,
+                    court_call_policy,
+                    sport_config_id,
+                ))
+                .get_result::<DbTournamentBase>(conn)
+                .await
+                .map_err(map_db_err)?;
+
+            info!(saved_id = %row.id, "insert_ok");
+            Ok(row.try_into()?)
+        }
+    }
+}
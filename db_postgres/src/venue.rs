@@ -0,0 +1,219 @@
+//! implementation of venue port
+
+use crate::{PgDb, cancel_on_drop, escape_like, map_db_err, schema::venues};
+use app_core::{
+    Court, DbError, DbResult, DbpVenue, Venue,
+    utils::{id_version::IdVersion, traits::ObjectIdVersion},
+};
+use async_trait::async_trait;
+use chrono::{DateTime, Utc};
+use diesel::{
+    dsl::sql,
+    prelude::{
+        AsChangeset, BoolExpressionMethods, ExpressionMethods, Insertable, OptionalExtension,
+        QueryDsl, Queryable,
+    },
+    sql_types::BigInt,
+};
+use diesel_async::RunQueryDsl;
+use tracing::{debug, error, info, instrument, warn};
+use uuid::Uuid;
+
+// ------------------- DB-Row (SELECT/RETURNING) -------------------
+#[derive(Debug, Queryable)]
+pub struct DbVenue {
+    pub id: Uuid,
+    pub version: i64,
+    pub name: String,
+    pub address_id: Option<Uuid>,
+    pub courts: serde_json::Value,
+    pub created_at: DateTime<Utc>,
+    pub updated_at: DateTime<Utc>,
+}
+
+// Mapping DB -> Core
+impl TryFrom<DbVenue> for Venue {
+    type Error = DbError;
+
+    fn try_from(r: DbVenue) -> Result<Self, Self::Error> {
+        if r.id.is_nil() {
+            return Err(DbError::NilRowId);
+        }
+        if r.version < 0 {
+            return Err(DbError::NegativeRowVersion);
+        }
+        if r.version > u32::MAX as i64 {
+            return Err(DbError::RowVersionOutOfRange);
+        }
+
+        let courts_from_json: Vec<Court> = serde_json::from_value(r.courts)
+            .map_err(|e| DbError::Other(format!("Failed to deserialize courts: {e}")))?;
+
+        let id_version = IdVersion::new(r.id, Some(r.version as u32));
+        let mut v = Venue::new(id_version);
+
+        v.set_name(r.name)
+            .set_address_id(r.address_id)
+            .set_courts(courts_from_json);
+
+        Ok(v)
+    }
+}
+
+// ------------------- INSERT / UPDATE -------------------
+#[derive(Debug, Insertable, AsChangeset)]
+#[diesel(table_name = venues)]
+// write NULL for removed postal address
+#[diesel(treat_none_as_null = true)]
+pub struct WriteDbVenue<'a> {
+    pub name: &'a str,
+    pub address_id: Option<Uuid>,
+    pub courts: serde_json::Value,
+}
+
+// Mapping Core -> DB
+impl<'a> TryFrom<&'a Venue> for WriteDbVenue<'a> {
+    type Error = DbError;
+
+    fn try_from(v: &'a Venue) -> Result<Self, Self::Error> {
+        Ok(WriteDbVenue {
+            name: v.get_name(),
+            address_id: v.get_address_id(),
+            courts: serde_json::to_value(v.get_courts())
+                .map_err(|e| DbError::Other(format!("Failed to serialize courts: {e}")))?,
+        })
+    }
+}
+
+// ------------------- Impl trait --------------------
+
+#[async_trait]
+impl DbpVenue for PgDb {
+    #[instrument(name = "db.venue.get", skip(self), fields(id = %venue_id))]
+    async fn get_venue(&self, venue_id: Uuid) -> DbResult<Option<Venue>> {
+        use crate::schema::venues::dsl::*;
+        let mut conn = self.new_connection().await?;
+        let res = venues
+            .filter(id.eq(venue_id))
+            .first::<DbVenue>(&mut conn)
+            .await
+            .optional()
+            .map_err(map_db_err)?;
+
+        match res {
+            Some(res) => {
+                let res = Venue::try_from(res)?;
+                debug!("found_venue");
+                Ok(Some(res))
+            }
+            None => {
+                debug!("venue_not_found");
+                Ok(None)
+            }
+        }
+    }
+
+    #[instrument(
+        name = "db.venue.save",
+        skip(self, venue),
+        fields(
+            id = ?venue.get_id(),
+            version = venue.get_version(),
+            is_new = venue.get_id_version().is_new()
+        )
+    )]
+    async fn save_venue(&self, venue: &Venue) -> DbResult<Venue> {
+        use crate::schema::venues::dsl::*;
+        let mut conn = self.new_write_connection().await?;
+        let w = WriteDbVenue::try_from(venue)?;
+
+        match venue.get_id_version() {
+            // Case 1: UPDATE (Optimistic Locking)
+            IdVersion::Existing(inner) => {
+                let res = diesel::update(
+                    venues.filter(
+                        id.eq(inner.get_id())
+                            .and(version.eq(inner.get_version() as i64)),
+                    ),
+                )
+                .set((w, version.eq(sql::<BigInt>("version + 1"))))
+                .returning(crate::schema::venues::all_columns)
+                .get_result::<DbVenue>(&mut conn)
+                .await;
+
+                match res {
+                    Ok(row) => {
+                        info!(saved_id = %row.id, new_version = row.version, "update_ok");
+                        Ok(row.try_into()?)
+                    }
+                    Err(diesel::result::Error::NotFound) => {
+                        // Check if it exists but version mismatch
+                        let exists = diesel::select(diesel::dsl::exists(
+                            venues.filter(id.eq(inner.get_id())),
+                        ))
+                        .get_result::<bool>(&mut conn)
+                        .await
+                        .map_err(map_db_err)?;
+
+                        if exists {
+                            warn!("optimistic_lock_conflict");
+                            Err(DbError::OptimisticLockConflict)
+                        } else {
+                            warn!("row_missing_on_update");
+                            Err(DbError::NotFound)
+                        }
+                    }
+                    Err(e) => {
+                        error!(error = %e, "update_failed");
+                        Err(map_db_err(e))
+                    }
+                }
+            }
+            // Case 2: INSERT with specific ID
+            IdVersion::NewWithId(new_id) => {
+                let row = diesel::insert_into(venues)
+                    .values((id.eq(new_id), w))
+                    .returning(crate::schema::venues::all_columns)
+                    .get_result::<DbVenue>(&mut conn)
+                    .await
+                    .map_err(map_db_err)?;
+
+                info!(saved_id = %row.id, "insert_ok");
+                Ok(row.try_into()?)
+            }
+        }
+    }
+
+    #[instrument(name = "db.venue.list", skip(self))]
+    async fn list_venues(
+        &self,
+        name_filter: Option<&str>,
+        limit: Option<usize>,
+    ) -> DbResult<Vec<Venue>> {
+        use crate::schema::venues::dsl::*;
+        let mut conn = self.new_read_connection().await?;
+
+        let mut query = venues.into_boxed::<diesel::pg::Pg>();
+        if let Some(f) = name_filter
+            && !f.is_empty()
+        {
+            // name is citext, therefore like is case insensitive
+            let pattern = format!("%{}%", escape_like(f));
+            debug!("apply_name_filter");
+            query = query.filter(name.like(pattern));
+        }
+        query = query.order((name.asc(), id.asc()));
+        if let Some(lim) = limit {
+            query = query.limit(lim as i64);
+        }
+
+        let cancel_guard = conn.cancel_guard();
+        let rows = cancel_on_drop(cancel_guard, query.load::<DbVenue>(&mut conn))
+            .await?
+            .into_iter()
+            .map(Venue::try_from)
+            .collect::<DbResult<Vec<_>>>()?;
+        info!(count = rows.len(), "list_ok");
+        Ok(rows)
+    }
+}
@@ -0,0 +1,310 @@
+//! implementation of webhook port
+
+use crate::{
+    PgDb, cancel_on_drop, map_db_err,
+    schema::{webhook_deliveries, webhook_endpoints},
+};
+use app_core::{
+    DbError, DbResult, DbpWebhook, WebhookDelivery, WebhookEndpoint, WebhookEventType,
+    WebhookFormat,
+    utils::{id_version::IdVersion, traits::ObjectIdVersion},
+};
+use async_trait::async_trait;
+use chrono::{DateTime, Utc};
+use diesel::{
+    dsl::sql,
+    prelude::{
+        AsChangeset, BoolExpressionMethods, ExpressionMethods, Insertable, OptionalExtension,
+        QueryDsl, Queryable,
+    },
+    sql_types::BigInt,
+};
+use diesel_async::RunQueryDsl;
+use std::str::FromStr;
+use tracing::{debug, error, info, instrument, warn};
+use uuid::Uuid;
+
+// ------------------- DB-Row (SELECT/RETURNING) -------------------
+#[derive(Debug, Queryable)]
+pub struct DbWebhookEndpoint {
+    pub id: Uuid,
+    pub version: i64,
+    pub name: String,
+    pub url: String,
+    pub secret: String,
+    pub event_types: serde_json::Value,
+    pub active: bool,
+    pub created_at: DateTime<Utc>,
+    pub updated_at: DateTime<Utc>,
+    pub format: String,
+}
+
+// Mapping DB -> Core
+impl TryFrom<DbWebhookEndpoint> for WebhookEndpoint {
+    type Error = DbError;
+
+    fn try_from(r: DbWebhookEndpoint) -> Result<Self, Self::Error> {
+        if r.id.is_nil() {
+            return Err(DbError::NilRowId);
+        }
+        if r.version < 0 {
+            return Err(DbError::NegativeRowVersion);
+        }
+        if r.version > u32::MAX as i64 {
+            return Err(DbError::RowVersionOutOfRange);
+        }
+
+        let event_types_from_json: Vec<WebhookEventType> = serde_json::from_value(r.event_types)
+            .map_err(|e| DbError::Other(format!("Failed to deserialize event types: {e}")))?;
+
+        let format_from_str = WebhookFormat::from_str(&r.format)
+            .map_err(|e| DbError::Other(format!("Failed to parse webhook format: {e}")))?;
+
+        let id_version = IdVersion::new(r.id, Some(r.version as u32));
+        let mut e = WebhookEndpoint::new(id_version);
+
+        e.set_name(r.name)
+            .set_url(r.url)
+            .set_secret(r.secret)
+            .set_event_types(event_types_from_json)
+            .set_active(r.active)
+            .set_format(format_from_str)
+            .set_created_at(Some(r.created_at));
+
+        Ok(e)
+    }
+}
+
+#[derive(Debug, Queryable, Insertable)]
+#[diesel(table_name = webhook_deliveries)]
+pub struct DbWebhookDelivery {
+    pub id: Uuid,
+    pub endpoint_id: Uuid,
+    pub event_id: Uuid,
+    pub event_type: String,
+    pub payload: String,
+    pub status_code: Option<i32>,
+    pub error: Option<String>,
+    pub duration_ms: i32,
+    pub attempted_at: DateTime<Utc>,
+}
+
+impl TryFrom<DbWebhookDelivery> for WebhookDelivery {
+    type Error = DbError;
+
+    fn try_from(r: DbWebhookDelivery) -> Result<Self, Self::Error> {
+        // event type is stored as plain text to keep the history readable in SQL
+        let event_type: WebhookEventType =
+            serde_json::from_value(serde_json::Value::String(r.event_type))
+                .map_err(|e| DbError::Other(format!("Failed to deserialize event type: {e}")))?;
+        Ok(WebhookDelivery {
+            id: r.id,
+            endpoint_id: r.endpoint_id,
+            event_id: r.event_id,
+            event_type,
+            payload: r.payload,
+            status_code: r.status_code.map(|c| c as u16),
+            error: r.error,
+            duration_ms: r.duration_ms.max(0) as u32,
+            attempted_at: r.attempted_at,
+        })
+    }
+}
+
+impl<'a> From<&'a WebhookDelivery> for DbWebhookDelivery {
+    fn from(d: &'a WebhookDelivery) -> Self {
+        DbWebhookDelivery {
+            id: d.id,
+            endpoint_id: d.endpoint_id,
+            event_id: d.event_id,
+            event_type: d.event_type.as_str().to_string(),
+            payload: d.payload.clone(),
+            status_code: d.status_code.map(i32::from),
+            error: d.error.clone(),
+            duration_ms: d.duration_ms.min(i32::MAX as u32) as i32,
+            attempted_at: d.attempted_at,
+        }
+    }
+}
+
+// ------------------- INSERT / UPDATE -------------------
+#[derive(Debug, Insertable, AsChangeset)]
+#[diesel(table_name = webhook_endpoints)]
+pub struct WriteDbWebhookEndpoint<'a> {
+    pub name: &'a str,
+    pub url: &'a str,
+    pub event_types: serde_json::Value,
+    pub active: bool,
+    pub format: &'a str,
+}
+
+// Mapping Core -> DB
+impl<'a> TryFrom<&'a WebhookEndpoint> for WriteDbWebhookEndpoint<'a> {
+    type Error = DbError;
+
+    fn try_from(e: &'a WebhookEndpoint) -> Result<Self, Self::Error> {
+        Ok(WriteDbWebhookEndpoint {
+            name: e.get_name(),
+            url: e.get_url(),
+            event_types: serde_json::to_value(e.get_event_types())
+                .map_err(|e| DbError::Other(format!("Failed to serialize event types: {e}")))?,
+            active: e.is_active(),
+            format: e.get_format().as_str(),
+        })
+    }
+}
+
+// ------------------- Impl trait --------------------
+
+#[async_trait]
+impl DbpWebhook for PgDb {
+    #[instrument(name = "db.webhook.get", skip(self), fields(id = %endpoint_id))]
+    async fn get_webhook_endpoint(&self, endpoint_id: Uuid) -> DbResult<Option<WebhookEndpoint>> {
+        use crate::schema::webhook_endpoints::dsl::*;
+        let mut conn = self.new_connection().await?;
+        let res = webhook_endpoints
+            .filter(id.eq(endpoint_id))
+            .first::<DbWebhookEndpoint>(&mut conn)
+            .await
+            .optional()
+            .map_err(map_db_err)?;
+
+        match res {
+            Some(res) => {
+                let res = WebhookEndpoint::try_from(res)?;
+                debug!("found_webhook_endpoint");
+                Ok(Some(res))
+            }
+            None => {
+                debug!("webhook_endpoint_not_found");
+                Ok(None)
+            }
+        }
+    }
+
+    #[instrument(
+        name = "db.webhook.save",
+        skip(self, endpoint),
+        fields(
+            id = ?endpoint.get_id(),
+            version = endpoint.get_version(),
+            is_new = endpoint.get_id_version().is_new()
+        )
+    )]
+    async fn save_webhook_endpoint(&self, endpoint: &WebhookEndpoint) -> DbResult<WebhookEndpoint> {
+        use crate::schema::webhook_endpoints::dsl::*;
+        let mut conn = self.new_write_connection().await?;
+        let w = WriteDbWebhookEndpoint::try_from(endpoint)?;
+
+        match endpoint.get_id_version() {
+            // Case 1: UPDATE (Optimistic Locking); secret is never changed
+            IdVersion::Existing(inner) => {
+                let res = diesel::update(
+                    webhook_endpoints.filter(
+                        id.eq(inner.get_id())
+                            .and(version.eq(inner.get_version() as i64)),
+                    ),
+                )
+                .set((w, version.eq(sql::<BigInt>("version + 1"))))
+                .returning(crate::schema::webhook_endpoints::all_columns)
+                .get_result::<DbWebhookEndpoint>(&mut conn)
+                .await;
+
+                match res {
+                    Ok(row) => {
+                        info!(saved_id = %row.id, new_version = row.version, "update_ok");
+                        Ok(row.try_into()?)
+                    }
+                    Err(diesel::result::Error::NotFound) => {
+                        // Check if it exists but version mismatch
+                        let exists = diesel::select(diesel::dsl::exists(
+                            webhook_endpoints.filter(id.eq(inner.get_id())),
+                        ))
+                        .get_result::<bool>(&mut conn)
+                        .await
+                        .map_err(map_db_err)?;
+
+                        if exists {
+                            warn!("optimistic_lock_conflict");
+                            Err(DbError::OptimisticLockConflict)
+                        } else {
+                            warn!("row_missing_on_update");
+                            Err(DbError::NotFound)
+                        }
+                    }
+                    Err(e) => {
+                        error!(error = %e, "update_failed");
+                        Err(map_db_err(e))
+                    }
+                }
+            }
+            // Case 2: INSERT with specific ID
+            IdVersion::NewWithId(new_id) => {
+                let row = diesel::insert_into(webhook_endpoints)
+                    .values((id.eq(new_id), secret.eq(endpoint.get_secret()), w))
+                    .returning(crate::schema::webhook_endpoints::all_columns)
+                    .get_result::<DbWebhookEndpoint>(&mut conn)
+                    .await
+                    .map_err(map_db_err)?;
+
+                info!(saved_id = %row.id, "insert_ok");
+                Ok(row.try_into()?)
+            }
+        }
+    }
+
+    #[instrument(name = "db.webhook.list", skip(self))]
+    async fn list_webhook_endpoints(&self) -> DbResult<Vec<WebhookEndpoint>> {
+        use crate::schema::webhook_endpoints::dsl::*;
+        let mut conn = self.new_read_connection().await?;
+
+        let query = webhook_endpoints.order(name.asc());
+        let cancel_guard = conn.cancel_guard();
+        let rows = cancel_on_drop(cancel_guard, query.load::<DbWebhookEndpoint>(&mut conn))
+            .await?
+            .into_iter()
+            .map(WebhookEndpoint::try_from)
+            .collect::<DbResult<Vec<_>>>()?;
+        info!(count = rows.len(), "list_ok");
+        Ok(rows)
+    }
+
+    #[instrument(
+        name = "db.webhook.save_delivery",
+        skip(self, delivery),
+        fields(id = %delivery.id, endpoint_id = %delivery.endpoint_id)
+    )]
+    async fn save_webhook_delivery(&self, delivery: &WebhookDelivery) -> DbResult<()> {
+        let mut conn = self.new_write_connection().await?;
+        diesel::insert_into(webhook_deliveries::table)
+            .values(DbWebhookDelivery::from(delivery))
+            .execute(&mut conn)
+            .await
+            .map_err(map_db_err)?;
+        info!("insert_ok");
+        Ok(())
+    }
+
+    #[instrument(name = "db.webhook.list_deliveries", skip(self), fields(endpoint_id = %e_id))]
+    async fn list_webhook_deliveries(
+        &self,
+        e_id: Uuid,
+        limit: usize,
+    ) -> DbResult<Vec<WebhookDelivery>> {
+        use crate::schema::webhook_deliveries::dsl::*;
+        let mut conn = self.new_read_connection().await?;
+
+        let query = webhook_deliveries
+            .filter(endpoint_id.eq(e_id))
+            .order(attempted_at.desc())
+            .limit(limit as i64);
+        let cancel_guard = conn.cancel_guard();
+        let rows = cancel_on_drop(cancel_guard, query.load::<DbWebhookDelivery>(&mut conn))
+            .await?
+            .into_iter()
+            .map(WebhookDelivery::try_from)
+            .collect::<DbResult<Vec<_>>>()?;
+        info!(count = rows.len(), "list_ok");
+        Ok(rows)
+    }
+}
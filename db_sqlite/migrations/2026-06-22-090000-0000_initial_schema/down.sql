@@ -0,0 +1,15 @@
+DROP TABLE IF EXISTS audit_log;
+DROP TABLE IF EXISTS client_errors;
+DROP TABLE IF EXISTS feedback;
+DROP TABLE IF EXISTS match_notes;
+DROP TABLE IF EXISTS pairing_overrides;
+DROP TABLE IF EXISTS scorekeeper_tokens;
+DROP TABLE IF EXISTS webhook_deliveries;
+DROP TABLE IF EXISTS webhook_endpoints;
+DROP TABLE IF EXISTS api_tokens;
+DROP TABLE IF EXISTS entrants;
+DROP TABLE IF EXISTS shift_log_entries;
+DROP TABLE IF EXISTS stages;
+DROP TABLE IF EXISTS tournament_bases;
+DROP TABLE IF EXISTS sport_configs;
+DROP TABLE IF EXISTS postal_addresses;
@@ -0,0 +1,496 @@
+-- Schema of the sqlite port; mirrors all migrations of the postgres port.
+--
+-- Mapping of postgres types:
+--   uuid        -> TEXT (hyphenated, lower case)
+--   citext      -> TEXT COLLATE NOCASE
+--   jsonb       -> TEXT (serialized JSON)
+--   text[]      -> TEXT (serialized JSON array)
+--   timestamptz -> TEXT (RFC 3339 like, UTC)
+--
+-- Ids and timestamps are set by the port, defaults only apply to manual inserts.
+
+CREATE TABLE IF NOT EXISTS postal_addresses (
+  id               TEXT PRIMARY KEY NOT NULL,
+
+  -- Optimistic locking
+  version          INTEGER NOT NULL DEFAULT 0,
+
+  name             TEXT NOT NULL COLLATE NOCASE,
+
+  -- Address fields
+  street           TEXT NOT NULL,
+  postal_code      TEXT NOT NULL,
+  locality         TEXT NOT NULL,
+  region           TEXT,
+  country          TEXT NOT NULL,
+
+  -- Timestamps
+  created_at       TEXT NOT NULL DEFAULT (strftime('%Y-%m-%d %H:%M:%f+00:00', 'now')),
+  updated_at       TEXT NOT NULL DEFAULT (strftime('%Y-%m-%d %H:%M:%f+00:00', 'now')),
+
+  -- Constraints
+  CONSTRAINT name_not_blank CHECK (length(trim(name)) > 0),
+  CONSTRAINT version_non_negative CHECK (version >= 0)
+);
+
+CREATE UNIQUE INDEX IF NOT EXISTS uniq_postal_addresses_name_per_city_zip
+  ON postal_addresses (name, postal_code, locality);
+
+CREATE TABLE IF NOT EXISTS sport_configs (
+  id               TEXT PRIMARY KEY NOT NULL,
+
+  -- Optimistic locking
+  version          INTEGER NOT NULL DEFAULT 0,
+
+  sport_id         TEXT NOT NULL,
+  name             TEXT NOT NULL COLLATE NOCASE,
+
+  -- Sport specific configuration
+  config           TEXT NOT NULL,
+  plugin_version   INTEGER NOT NULL DEFAULT 0,
+
+  -- Timestamps
+  created_at       TEXT NOT NULL DEFAULT (strftime('%Y-%m-%d %H:%M:%f+00:00', 'now')),
+  updated_at       TEXT NOT NULL DEFAULT (strftime('%Y-%m-%d %H:%M:%f+00:00', 'now')),
+  archived_at      TEXT NULL,
+
+  -- Constraints
+  CONSTRAINT version_non_negative CHECK (version >= 0)
+);
+
+CREATE UNIQUE INDEX IF NOT EXISTS uniq_sport_configs_name_per_sport
+  ON sport_configs (sport_id, name);
+CREATE INDEX IF NOT EXISTS idx_sport_configs_not_archived
+  ON sport_configs (sport_id) WHERE archived_at IS NULL;
+
+CREATE TABLE IF NOT EXISTS tournament_bases (
+  id               TEXT PRIMARY KEY NOT NULL,
+
+  -- Optimistic locking
+  version          INTEGER NOT NULL DEFAULT 0,
+
+  name             TEXT NOT NULL COLLATE NOCASE,
+  sport_id         TEXT NOT NULL,
+
+  -- Size of the tournament
+  num_entrants     INTEGER NOT NULL,
+  num_stations     INTEGER NOT NULL DEFAULT 1,
+
+  -- Configuration and state stored as JSON
+  t_type           TEXT NOT NULL,  -- TournamentType
+  mode             TEXT NOT NULL,  -- TournamentMode
+  state            TEXT NOT NULL,  -- TournamentState
+  sandbox          INTEGER NOT NULL DEFAULT 0,
+  languages        TEXT NOT NULL DEFAULT '[]',
+  description      TEXT NOT NULL DEFAULT '{"text": ""}',
+  stations         TEXT NOT NULL DEFAULT '[]',
+
+  -- Timestamps
+  created_at       TEXT NOT NULL DEFAULT (strftime('%Y-%m-%d %H:%M:%f+00:00', 'now')),
+  updated_at       TEXT NOT NULL DEFAULT (strftime('%Y-%m-%d %H:%M:%f+00:00', 'now')),
+  archived_at      TEXT NULL,
+
+  -- Constraints
+  CONSTRAINT version_non_negative CHECK (version >= 0),
+  CONSTRAINT num_stations_positive CHECK (num_stations >= 1)
+);
+
+CREATE UNIQUE INDEX IF NOT EXISTS uniq_tournament_bases_name_per_sport
+  ON tournament_bases (sport_id, name);
+CREATE INDEX IF NOT EXISTS idx_tournament_bases_not_archived
+  ON tournament_bases (sport_id) WHERE archived_at IS NULL;
+
+CREATE TABLE IF NOT EXISTS stages (
+  id               TEXT PRIMARY KEY NOT NULL,
+
+  -- Optimistic locking
+  version          INTEGER NOT NULL DEFAULT 0,
+
+  tournament_id    TEXT NOT NULL,
+
+  -- Scheduled stage number in tournament (0, 1, 2...)
+  number           INTEGER NOT NULL,
+
+  -- Configuration
+  num_groups       INTEGER NOT NULL DEFAULT 1,
+  max_stations     INTEGER NULL,
+  auto_advance     INTEGER NOT NULL DEFAULT 1,
+
+  -- Timestamps
+  created_at       TEXT NOT NULL DEFAULT (strftime('%Y-%m-%d %H:%M:%f+00:00', 'now')),
+  updated_at       TEXT NOT NULL DEFAULT (strftime('%Y-%m-%d %H:%M:%f+00:00', 'now')),
+
+  -- Constraints
+  CONSTRAINT version_non_negative CHECK (version >= 0),
+  CONSTRAINT number_non_negative CHECK (number >= 0),
+  CONSTRAINT num_groups_positive CHECK (num_groups > 0),
+  CONSTRAINT max_stations_positive CHECK (max_stations >= 1),
+
+  CONSTRAINT fk_tournament
+    FOREIGN KEY(tournament_id)
+    REFERENCES tournament_bases(id)
+    ON DELETE CASCADE
+);
+
+CREATE UNIQUE INDEX IF NOT EXISTS uniq_stages_number_per_tournament
+  ON stages (tournament_id, number);
+
+CREATE TABLE IF NOT EXISTS shift_log_entries (
+  id               TEXT PRIMARY KEY NOT NULL,
+
+  -- Optimistic locking
+  version          INTEGER NOT NULL DEFAULT 0,
+
+  tournament_id    TEXT NOT NULL,
+
+  -- Content
+  author           TEXT NOT NULL,
+  text             TEXT NOT NULL,
+  pinned           INTEGER NOT NULL DEFAULT 0,
+
+  -- Timestamps
+  created_at       TEXT NOT NULL DEFAULT (strftime('%Y-%m-%d %H:%M:%f+00:00', 'now')),
+  updated_at       TEXT NOT NULL DEFAULT (strftime('%Y-%m-%d %H:%M:%f+00:00', 'now')),
+
+  -- Constraints
+  CONSTRAINT version_non_negative CHECK (version >= 0),
+  CONSTRAINT author_not_blank CHECK (length(trim(author)) > 0),
+  CONSTRAINT text_not_blank CHECK (length(trim(text)) > 0),
+
+  CONSTRAINT fk_tournament
+    FOREIGN KEY(tournament_id)
+    REFERENCES tournament_bases(id)
+    ON DELETE CASCADE
+);
+
+CREATE INDEX IF NOT EXISTS idx_shift_log_entries_tournament_created
+  ON shift_log_entries (tournament_id, created_at DESC);
+
+CREATE TABLE IF NOT EXISTS entrants (
+  id               TEXT PRIMARY KEY NOT NULL,
+
+  -- Optimistic locking
+  version          INTEGER NOT NULL DEFAULT 0,
+
+  tournament_id    TEXT NOT NULL,
+
+  -- Entrant data
+  name             TEXT NOT NULL COLLATE NOCASE,
+  club             TEXT NULL,
+  seed             INTEGER NULL,
+  email            TEXT NULL,
+  checked_in_at    TEXT NULL,
+
+  -- Timestamps
+  created_at       TEXT NOT NULL DEFAULT (strftime('%Y-%m-%d %H:%M:%f+00:00', 'now')),
+  updated_at       TEXT NOT NULL DEFAULT (strftime('%Y-%m-%d %H:%M:%f+00:00', 'now')),
+
+  -- Constraints
+  CONSTRAINT version_non_negative CHECK (version >= 0),
+  CONSTRAINT name_not_blank CHECK (length(trim(name)) > 0),
+  CONSTRAINT seed_positive CHECK (seed IS NULL OR seed > 0),
+
+  CONSTRAINT fk_tournament
+    FOREIGN KEY(tournament_id)
+    REFERENCES tournament_bases(id)
+    ON DELETE CASCADE
+);
+
+CREATE UNIQUE INDEX IF NOT EXISTS uniq_entrants_name_per_tournament
+  ON entrants (tournament_id, name);
+CREATE UNIQUE INDEX IF NOT EXISTS uniq_entrants_seed_per_tournament
+  ON entrants (tournament_id, seed);
+
+CREATE TABLE IF NOT EXISTS api_tokens (
+  id                    TEXT PRIMARY KEY NOT NULL,
+
+  -- Optimistic locking
+  version               INTEGER NOT NULL DEFAULT 0,
+
+  -- Token data
+  name                  TEXT NOT NULL COLLATE NOCASE,
+  scopes                TEXT NOT NULL,  -- Vec<ApiScope>
+  secret_prefix         TEXT NOT NULL,
+  secret_hash           TEXT NOT NULL,
+  rate_limit_per_minute INTEGER NOT NULL,
+
+  -- Timestamps
+  created_at            TEXT NOT NULL DEFAULT (strftime('%Y-%m-%d %H:%M:%f+00:00', 'now')),
+  updated_at            TEXT NOT NULL DEFAULT (strftime('%Y-%m-%d %H:%M:%f+00:00', 'now')),
+  last_used_at          TEXT NULL,
+  revoked_at            TEXT NULL,
+
+  -- Constraints
+  CONSTRAINT version_non_negative CHECK (version >= 0),
+  CONSTRAINT name_not_blank CHECK (length(trim(name)) > 0),
+  CONSTRAINT rate_limit_positive CHECK (rate_limit_per_minute > 0)
+);
+
+CREATE UNIQUE INDEX IF NOT EXISTS uniq_api_tokens_secret_hash
+  ON api_tokens (secret_hash);
+CREATE UNIQUE INDEX IF NOT EXISTS uniq_api_tokens_name
+  ON api_tokens (name);
+
+CREATE TABLE IF NOT EXISTS webhook_endpoints (
+  id            TEXT PRIMARY KEY NOT NULL,
+
+  -- Optimistic locking
+  version       INTEGER NOT NULL DEFAULT 0,
+
+  -- Endpoint data
+  name          TEXT NOT NULL COLLATE NOCASE,
+  url           TEXT NOT NULL,
+  secret        TEXT NOT NULL,  -- required in clear text to sign payloads
+  event_types   TEXT NOT NULL,  -- Vec<WebhookEventType>
+  active        INTEGER NOT NULL DEFAULT 1,
+  format        TEXT NOT NULL DEFAULT 'json',
+
+  -- Timestamps
+  created_at    TEXT NOT NULL DEFAULT (strftime('%Y-%m-%d %H:%M:%f+00:00', 'now')),
+  updated_at    TEXT NOT NULL DEFAULT (strftime('%Y-%m-%d %H:%M:%f+00:00', 'now')),
+
+  -- Constraints
+  CONSTRAINT version_non_negative CHECK (version >= 0),
+  CONSTRAINT name_not_blank CHECK (length(trim(name)) > 0),
+  CONSTRAINT url_http CHECK (url LIKE 'http://%' OR url LIKE 'https://%'),
+  CONSTRAINT webhook_endpoints_format_known CHECK (format IN ('json', 'discord', 'slack'))
+);
+
+CREATE UNIQUE INDEX IF NOT EXISTS uniq_webhook_endpoints_name
+  ON webhook_endpoints (name);
+
+-- History of delivery attempts; rows are never updated
+CREATE TABLE IF NOT EXISTS webhook_deliveries (
+  id            TEXT PRIMARY KEY NOT NULL,
+  endpoint_id   TEXT NOT NULL REFERENCES webhook_endpoints (id) ON DELETE CASCADE,
+  event_id      TEXT NOT NULL,
+  event_type    TEXT NOT NULL,
+  payload       TEXT NOT NULL,  -- body as sent, signature depends on exact bytes
+  status_code   INTEGER NULL,
+  error         TEXT NULL,
+  duration_ms   INTEGER NOT NULL,
+  attempted_at  TEXT NOT NULL,
+
+  CONSTRAINT duration_non_negative CHECK (duration_ms >= 0)
+);
+
+CREATE INDEX IF NOT EXISTS idx_webhook_deliveries_endpoint_attempted_at
+  ON webhook_deliveries (endpoint_id, attempted_at DESC);
+
+CREATE TABLE IF NOT EXISTS scorekeeper_tokens (
+  id               TEXT PRIMARY KEY NOT NULL,
+
+  -- Optimistic locking
+  version          INTEGER NOT NULL DEFAULT 0,
+
+  tournament_id    TEXT NOT NULL,
+
+  -- Token data
+  label            TEXT NOT NULL COLLATE NOCASE,
+  grants           TEXT NOT NULL,  -- Vec<ScorekeeperGrant>
+  secret_prefix    TEXT NOT NULL,
+  secret_hash      TEXT NOT NULL,
+
+  -- Timestamps
+  expires_at       TEXT NULL,
+  created_at       TEXT NOT NULL DEFAULT (strftime('%Y-%m-%d %H:%M:%f+00:00', 'now')),
+  updated_at       TEXT NOT NULL DEFAULT (strftime('%Y-%m-%d %H:%M:%f+00:00', 'now')),
+  last_used_at     TEXT NULL,
+  revoked_at       TEXT NULL,
+
+  -- Constraints
+  CONSTRAINT version_non_negative CHECK (version >= 0),
+  CONSTRAINT label_not_blank CHECK (length(trim(label)) > 0),
+
+  CONSTRAINT fk_tournament
+    FOREIGN KEY(tournament_id)
+    REFERENCES tournament_bases(id)
+    ON DELETE CASCADE
+);
+
+CREATE UNIQUE INDEX IF NOT EXISTS uniq_scorekeeper_tokens_secret_hash
+  ON scorekeeper_tokens (secret_hash);
+CREATE UNIQUE INDEX IF NOT EXISTS uniq_scorekeeper_tokens_label_per_tournament
+  ON scorekeeper_tokens (tournament_id, label);
+
+-- Manual overrides of generated pairings; rows are append only and serve as audit trail
+CREATE TABLE IF NOT EXISTS pairing_overrides (
+  id               TEXT PRIMARY KEY NOT NULL,
+
+  -- Optimistic locking
+  version          INTEGER NOT NULL DEFAULT 0,
+
+  tournament_id    TEXT NOT NULL,
+  stage_id         TEXT NOT NULL,
+
+  -- Round of stage
+  round_number     INTEGER NOT NULL,
+
+  -- Content
+  adjustments      TEXT NOT NULL,
+  note             TEXT NOT NULL,
+  author           TEXT NOT NULL,
+
+  -- Timestamps
+  created_at       TEXT NOT NULL DEFAULT (strftime('%Y-%m-%d %H:%M:%f+00:00', 'now')),
+  updated_at       TEXT NOT NULL DEFAULT (strftime('%Y-%m-%d %H:%M:%f+00:00', 'now')),
+
+  -- Constraints
+  CONSTRAINT version_non_negative CHECK (version >= 0),
+  CONSTRAINT round_number_non_negative CHECK (round_number >= 0),
+  CONSTRAINT note_not_blank CHECK (length(trim(note)) > 0),
+  CONSTRAINT author_not_blank CHECK (length(trim(author)) > 0),
+
+  CONSTRAINT fk_tournament
+    FOREIGN KEY(tournament_id)
+    REFERENCES tournament_bases(id)
+    ON DELETE CASCADE,
+  CONSTRAINT fk_stage
+    FOREIGN KEY(stage_id)
+    REFERENCES stages(id)
+    ON DELETE CASCADE
+);
+
+CREATE INDEX IF NOT EXISTS idx_pairing_overrides_stage_round_created
+  ON pairing_overrides (stage_id, round_number, created_at);
+
+-- Notes and tags of matches; at most one note per match
+CREATE TABLE IF NOT EXISTS match_notes (
+  id               TEXT PRIMARY KEY NOT NULL,
+
+  -- Optimistic locking
+  version          INTEGER NOT NULL DEFAULT 0,
+
+  tournament_id    TEXT NOT NULL,
+
+  -- Annotated match; matches are not persisted yet, therefore no foreign key
+  match_id         TEXT NOT NULL,
+
+  -- Content
+  tags             TEXT NOT NULL DEFAULT '[]',
+  text             TEXT NOT NULL DEFAULT '',
+  author           TEXT NOT NULL,
+
+  -- Timestamps
+  created_at       TEXT NOT NULL DEFAULT (strftime('%Y-%m-%d %H:%M:%f+00:00', 'now')),
+  updated_at       TEXT NOT NULL DEFAULT (strftime('%Y-%m-%d %H:%M:%f+00:00', 'now')),
+
+  -- Constraints
+  CONSTRAINT version_non_negative CHECK (version >= 0),
+  CONSTRAINT author_not_blank CHECK (length(trim(author)) > 0),
+  CONSTRAINT tags_or_text CHECK (json_array_length(tags) > 0 OR length(trim(text)) > 0),
+  CONSTRAINT uq_match_notes_match UNIQUE (match_id),
+
+  CONSTRAINT fk_tournament
+    FOREIGN KEY(tournament_id)
+    REFERENCES tournament_bases(id)
+    ON DELETE CASCADE
+);
+
+CREATE INDEX IF NOT EXISTS idx_match_notes_tournament
+  ON match_notes (tournament_id, updated_at DESC);
+
+-- Feedback and bug reports of users with context of the client; append only
+CREATE TABLE IF NOT EXISTS feedback (
+  id               TEXT PRIMARY KEY NOT NULL,
+
+  -- Optimistic locking
+  version          INTEGER NOT NULL DEFAULT 0,
+
+  -- Content
+  message          TEXT NOT NULL,
+
+  -- Context of the client
+  route            TEXT NOT NULL,
+  tournament_id    TEXT NULL,
+  app_version      TEXT NOT NULL DEFAULT '',
+  recent_errors    TEXT NOT NULL DEFAULT '[]',
+
+  -- Timestamps
+  created_at       TEXT NOT NULL DEFAULT (strftime('%Y-%m-%d %H:%M:%f+00:00', 'now')),
+  updated_at       TEXT NOT NULL DEFAULT (strftime('%Y-%m-%d %H:%M:%f+00:00', 'now')),
+
+  -- Constraints
+  CONSTRAINT version_non_negative CHECK (version >= 0),
+  CONSTRAINT message_not_blank CHECK (length(trim(message)) > 0),
+  CONSTRAINT route_not_blank CHECK (length(trim(route)) > 0),
+
+  -- feedback outlives deleted tournaments
+  CONSTRAINT fk_tournament
+    FOREIGN KEY(tournament_id)
+    REFERENCES tournament_bases(id)
+    ON DELETE SET NULL
+);
+
+CREATE INDEX IF NOT EXISTS idx_feedback_created
+  ON feedback (created_at DESC);
+
+-- Uncaught errors reported by clients; append only
+CREATE TABLE IF NOT EXISTS client_errors (
+  id               TEXT PRIMARY KEY NOT NULL,
+
+  -- Optimistic locking
+  version          INTEGER NOT NULL DEFAULT 0,
+
+  -- Context of the client
+  component_id     TEXT NULL,
+  route            TEXT NOT NULL,
+  app_version      TEXT NOT NULL DEFAULT '',
+
+  -- Content; occurrences since the last report of the same error
+  message          TEXT NOT NULL,
+  occurrences      INTEGER NOT NULL DEFAULT 1,
+
+  -- Timestamps
+  created_at       TEXT NOT NULL DEFAULT (strftime('%Y-%m-%d %H:%M:%f+00:00', 'now')),
+  updated_at       TEXT NOT NULL DEFAULT (strftime('%Y-%m-%d %H:%M:%f+00:00', 'now')),
+
+  -- Constraints
+  CONSTRAINT version_non_negative CHECK (version >= 0),
+  CONSTRAINT message_not_blank CHECK (length(trim(message)) > 0),
+  CONSTRAINT route_not_blank CHECK (length(trim(route)) > 0),
+  CONSTRAINT occurrences_positive CHECK (occurrences > 0)
+);
+
+CREATE INDEX IF NOT EXISTS idx_client_errors_created
+  ON client_errors (created_at DESC);
+
+-- Records of all mutating operations; append only.
+-- No foreign keys: records must outlive purged tournaments and deleted objects.
+CREATE TABLE IF NOT EXISTS audit_log (
+  id               TEXT PRIMARY KEY NOT NULL,
+
+  -- Optimistic locking
+  version          INTEGER NOT NULL DEFAULT 0,
+
+  -- Who did what
+  actor            TEXT NOT NULL,
+  action           TEXT NOT NULL,
+
+  -- Changed object; tournament_id is NULL for global objects
+  object_type      TEXT NOT NULL,
+  object_id        TEXT NOT NULL,
+  tournament_id    TEXT NULL,
+
+  -- Versions of the object before and after the change
+  old_version      INTEGER NULL,
+  new_version      INTEGER NULL,
+
+  -- Changed fields: {"<field>": {"old": ..., "new": ...}}
+  diff             TEXT NOT NULL DEFAULT '{}',
+
+  -- Timestamps
+  created_at       TEXT NOT NULL DEFAULT (strftime('%Y-%m-%d %H:%M:%f+00:00', 'now')),
+  updated_at       TEXT NOT NULL DEFAULT (strftime('%Y-%m-%d %H:%M:%f+00:00', 'now')),
+
+  -- Constraints
+  CONSTRAINT version_non_negative CHECK (version >= 0),
+  CONSTRAINT actor_not_blank CHECK (length(trim(actor)) > 0),
+  CONSTRAINT action_known CHECK (action IN ('create', 'update', 'archive', 'revoke', 'delete')),
+  CONSTRAINT diff_is_object CHECK (json_type(diff) = 'object')
+);
+
+CREATE INDEX IF NOT EXISTS idx_audit_log_tournament_created
+  ON audit_log (tournament_id, created_at DESC);
+CREATE INDEX IF NOT EXISTS idx_audit_log_object
+  ON audit_log (object_id, created_at DESC);
@@ -0,0 +1,5 @@
+-- This file should undo anything in `up.sql`
+ALTER TABLE tournament_bases DROP COLUMN no_show_policy;
+ALTER TABLE tournament_bases DROP COLUMN check_in_deadline;
+ALTER TABLE entrants DROP COLUMN no_show;
+ALTER TABLE entrants DROP COLUMN waitlist_position;
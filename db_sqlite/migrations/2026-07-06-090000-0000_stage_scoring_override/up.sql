@@ -0,0 +1,2 @@
+-- scoring policy override of stage, e.g. victory points or tie breakers of the final stage
+ALTER TABLE stages ADD COLUMN scoring_override TEXT NOT NULL DEFAULT '{}';
@@ -0,0 +1,2 @@
+-- hard time cap of stage, by which all matches of stage must end
+ALTER TABLE stages ADD COLUMN latest_end TEXT NULL;
@@ -0,0 +1,4 @@
+-- This file should undo anything in `up.sql`
+ALTER TABLE tournament_bases DROP COLUMN address_id;
+ALTER TABLE postal_addresses DROP COLUMN longitude;
+ALTER TABLE postal_addresses DROP COLUMN latitude;
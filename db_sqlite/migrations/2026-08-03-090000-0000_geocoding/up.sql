@@ -0,0 +1,5 @@
+-- location of postal addresses resolved by geocoding; NULL, if not geocoded
+ALTER TABLE postal_addresses ADD COLUMN latitude REAL NULL;
+ALTER TABLE postal_addresses ADD COLUMN longitude REAL NULL;
+-- postal address of the location of the tournament
+ALTER TABLE tournament_bases ADD COLUMN address_id TEXT NULL REFERENCES postal_addresses(id);
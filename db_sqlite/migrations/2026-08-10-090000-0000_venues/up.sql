@@ -0,0 +1,26 @@
+-- Venues of tournaments, e.g. sports halls with their courts
+CREATE TABLE IF NOT EXISTS venues (
+  id            TEXT PRIMARY KEY NOT NULL,
+
+  -- Optimistic locking
+  version       INTEGER NOT NULL DEFAULT 0,
+
+  -- Venue data
+  name          TEXT NOT NULL COLLATE NOCASE,
+  address_id    TEXT NULL REFERENCES postal_addresses (id),
+  courts        TEXT NOT NULL DEFAULT '[]',  -- Vec<Court>
+
+  -- Timestamps
+  created_at    TEXT NOT NULL DEFAULT (strftime('%Y-%m-%d %H:%M:%f+00:00', 'now')),
+  updated_at    TEXT NOT NULL DEFAULT (strftime('%Y-%m-%d %H:%M:%f+00:00', 'now')),
+
+  -- Constraints
+  CONSTRAINT version_non_negative CHECK (version >= 0),
+  CONSTRAINT name_not_blank CHECK (length(trim(name)) > 0)
+);
+
+CREATE UNIQUE INDEX IF NOT EXISTS uniq_venues_name
+  ON venues (name);
+
+-- venue of the tournament, whose courts were taken as stations
+ALTER TABLE tournament_bases ADD COLUMN venue_id TEXT NULL REFERENCES venues(id);
@@ -0,0 +1,31 @@
+-- Officials (e.g. referees) of a tournament
+CREATE TABLE IF NOT EXISTS officials (
+  id               TEXT PRIMARY KEY NOT NULL,
+
+  -- Optimistic locking
+  version          INTEGER NOT NULL DEFAULT 0,
+
+  tournament_id    TEXT NOT NULL,
+
+  -- Official data
+  name             TEXT NOT NULL COLLATE NOCASE,
+  club             TEXT NULL,
+  qualifications   TEXT NOT NULL DEFAULT '[]',  -- Vec<String>
+  availability     TEXT NOT NULL DEFAULT '[]',  -- Vec<AvailabilityWindow>
+
+  -- Timestamps
+  created_at       TEXT NOT NULL DEFAULT (strftime('%Y-%m-%d %H:%M:%f+00:00', 'now')),
+  updated_at       TEXT NOT NULL DEFAULT (strftime('%Y-%m-%d %H:%M:%f+00:00', 'now')),
+
+  -- Constraints
+  CONSTRAINT version_non_negative CHECK (version >= 0),
+  CONSTRAINT name_not_blank CHECK (length(trim(name)) > 0),
+
+  CONSTRAINT fk_tournament
+    FOREIGN KEY(tournament_id)
+    REFERENCES tournament_bases(id)
+    ON DELETE CASCADE
+);
+
+CREATE UNIQUE INDEX IF NOT EXISTS uniq_officials_name_per_tournament
+  ON officials (tournament_id, name);
@@ -0,0 +1,22 @@
+-- Cached standings of groups, recomputed when results of matches change
+CREATE TABLE IF NOT EXISTS group_standings (
+  group_id         TEXT PRIMARY KEY NOT NULL,
+
+  tournament_id    TEXT NOT NULL,
+
+  -- Cached data
+  standings        TEXT NOT NULL DEFAULT '[]',  -- Vec<GroupStanding>
+  computed_at      TEXT NOT NULL,
+
+  -- Timestamps
+  created_at       TEXT NOT NULL DEFAULT (strftime('%Y-%m-%d %H:%M:%f+00:00', 'now')),
+  updated_at       TEXT NOT NULL DEFAULT (strftime('%Y-%m-%d %H:%M:%f+00:00', 'now')),
+
+  CONSTRAINT fk_tournament
+    FOREIGN KEY(tournament_id)
+    REFERENCES tournament_bases(id)
+    ON DELETE CASCADE
+);
+
+CREATE INDEX IF NOT EXISTS idx_group_standings_tournament
+  ON group_standings (tournament_id);
@@ -0,0 +1,27 @@
+-- Snapshots of stages taken before promotion to the next stage
+CREATE TABLE IF NOT EXISTS stage_snapshots (
+  id               TEXT PRIMARY KEY NOT NULL,
+
+  tournament_id    TEXT NOT NULL,
+  stage_id         TEXT NOT NULL,
+  stage_number     INTEGER NOT NULL,
+
+  -- Snapshot data
+  matches          TEXT NOT NULL DEFAULT '[]',  -- Vec<Match>
+  standings        TEXT NOT NULL DEFAULT '[]',  -- Vec<CachedGroupStandings>
+
+  -- Timestamps
+  created_at       TEXT NOT NULL DEFAULT (strftime('%Y-%m-%d %H:%M:%f+00:00', 'now')),
+
+  CONSTRAINT fk_tournament
+    FOREIGN KEY(tournament_id)
+    REFERENCES tournament_bases(id)
+    ON DELETE CASCADE,
+  CONSTRAINT fk_stage
+    FOREIGN KEY(stage_id)
+    REFERENCES stages(id)
+    ON DELETE CASCADE
+);
+
+CREATE INDEX IF NOT EXISTS idx_stage_snapshots_tournament
+  ON stage_snapshots (tournament_id, stage_number);
@@ -0,0 +1,36 @@
+-- Matches of stages; the match itself is stored as json, the columns are used for lookups
+CREATE TABLE IF NOT EXISTS matches (
+  id               TEXT PRIMARY KEY NOT NULL,
+
+  tournament_id    TEXT NOT NULL,
+  stage_id         TEXT NOT NULL,
+  group_id         TEXT NOT NULL,
+  round_id         TEXT NOT NULL,
+  number           INTEGER NOT NULL,
+
+  -- Schedule
+  station          INTEGER NOT NULL,
+  start_at         TEXT NOT NULL,
+
+  -- Match data
+  data             TEXT NOT NULL,  -- Match
+
+  -- Timestamps
+  created_at       TEXT NOT NULL DEFAULT (strftime('%Y-%m-%d %H:%M:%f+00:00', 'now')),
+  updated_at       TEXT NOT NULL DEFAULT (strftime('%Y-%m-%d %H:%M:%f+00:00', 'now')),
+
+  CONSTRAINT fk_tournament
+    FOREIGN KEY(tournament_id)
+    REFERENCES tournament_bases(id)
+    ON DELETE CASCADE,
+  CONSTRAINT fk_stage
+    FOREIGN KEY(stage_id)
+    REFERENCES stages(id)
+    ON DELETE CASCADE
+);
+
+CREATE INDEX IF NOT EXISTS idx_matches_tournament
+  ON matches (tournament_id, start_at, station);
+
+CREATE INDEX IF NOT EXISTS idx_matches_stage
+  ON matches (stage_id, group_id, round_id, number);
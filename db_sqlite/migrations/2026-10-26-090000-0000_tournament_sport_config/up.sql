@@ -0,0 +1,12 @@
+-- sport configuration of the tournament, which drives scoring, standings and durations
+ALTER TABLE tournament_bases ADD COLUMN sport_config_id TEXT NULL REFERENCES sport_configs(id);
+
+-- existing tournaments keep the configuration, which was used for them so far: the first
+-- configuration of their sport by name
+UPDATE tournament_bases
+SET sport_config_id = (
+  SELECT c.id FROM sport_configs c
+  WHERE c.sport_id = tournament_bases.sport_id AND c.archived_at IS NULL
+  ORDER BY c.name
+  LIMIT 1
+);
@@ -0,0 +1,2 @@
+-- version of matches for optimistic locking, e.g. of live scores against final results
+ALTER TABLE matches ADD COLUMN version INTEGER NOT NULL DEFAULT 0 CHECK (version >= 0);
@@ -0,0 +1,25 @@
+-- Group seeding of the first stage, saved by the director before the tournament starts
+CREATE TABLE IF NOT EXISTS group_seedings (
+  stage_id         TEXT PRIMARY KEY NOT NULL,
+
+  tournament_id    TEXT NOT NULL,
+
+  -- Seeding data
+  seeding          TEXT NOT NULL,  -- SavedGroupSeeding
+
+  -- Timestamps
+  created_at       TEXT NOT NULL DEFAULT (strftime('%Y-%m-%d %H:%M:%f+00:00', 'now')),
+  updated_at       TEXT NOT NULL DEFAULT (strftime('%Y-%m-%d %H:%M:%f+00:00', 'now')),
+
+  CONSTRAINT fk_tournament
+    FOREIGN KEY(tournament_id)
+    REFERENCES tournament_bases(id)
+    ON DELETE CASCADE,
+  CONSTRAINT fk_stage
+    FOREIGN KEY(stage_id)
+    REFERENCES stages(id)
+    ON DELETE CASCADE
+);
+
+CREATE INDEX IF NOT EXISTS idx_group_seedings_tournament
+  ON group_seedings (tournament_id);
@@ -0,0 +1,30 @@
+-- Groups of stages; the group itself is stored as json, the columns are used for lookups
+CREATE TABLE IF NOT EXISTS stage_groups (
+  id               TEXT PRIMARY KEY NOT NULL,
+
+  -- Optimistic locking
+  version          INTEGER NOT NULL DEFAULT 0,
+
+  stage_id         TEXT NOT NULL,
+
+  -- Group number in stage (0, 1, 2...)
+  number           INTEGER NOT NULL,
+
+  -- Group data
+  data             TEXT NOT NULL,  -- Group
+
+  -- Timestamps
+  created_at       TEXT NOT NULL DEFAULT (strftime('%Y-%m-%d %H:%M:%f+00:00', 'now')),
+  updated_at       TEXT NOT NULL DEFAULT (strftime('%Y-%m-%d %H:%M:%f+00:00', 'now')),
+
+  CONSTRAINT version_non_negative CHECK (version >= 0),
+  CONSTRAINT number_non_negative CHECK (number >= 0),
+  CONSTRAINT fk_stage
+    FOREIGN KEY(stage_id)
+    REFERENCES stages(id)
+    ON DELETE CASCADE
+);
+
+-- Enforce uniqueness of group number per stage
+CREATE UNIQUE INDEX IF NOT EXISTS uniq_stage_groups_number_per_stage
+  ON stage_groups (stage_id, number);
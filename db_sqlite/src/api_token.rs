@@ -0,0 +1,255 @@
+//! implementation of api token port
+
+use crate::{
+    SqliteDb, map_db_err, parse_uuid,
+    schema::{api_tokens, api_tokens::dsl::*},
+};
+use app_core::{
+    ApiScope, ApiToken, DbError, DbResult, DbpApiToken,
+    utils::{id_version::IdVersion, traits::ObjectIdVersion},
+};
+use async_trait::async_trait;
+use chrono::{DateTime, Utc};
+use diesel::{
+    dsl::sql,
+    prelude::{
+        AsChangeset, BoolExpressionMethods, ExpressionMethods, Insertable, OptionalExtension,
+        QueryDsl, Queryable,
+    },
+    sql_types::BigInt,
+};
+use diesel_async::RunQueryDsl;
+use tracing::{debug, error, info, instrument, warn};
+use uuid::Uuid;
+
+// ------------------- DB-Row (SELECT/RETURNING) -------------------
+#[derive(Debug, Queryable)]
+pub struct DbApiToken {
+    pub id: String,
+    pub version: i64,
+    pub name: String,
+    pub scopes: String,
+    pub secret_prefix: String,
+    pub secret_hash: String,
+    pub rate_limit_per_minute: i32,
+    pub created_at: DateTime<Utc>,
+    pub updated_at: DateTime<Utc>,
+    pub last_used_at: Option<DateTime<Utc>>,
+    pub revoked_at: Option<DateTime<Utc>>,
+}
+
+// Mapping DB -> Core
+impl TryFrom<DbApiToken> for ApiToken {
+    type Error = DbError;
+
+    fn try_from(r: DbApiToken) -> Result<Self, Self::Error> {
+        let row_id = parse_uuid(&r.id)?;
+        if row_id.is_nil() {
+            return Err(DbError::NilRowId);
+        }
+        if r.version < 0 {
+            return Err(DbError::NegativeRowVersion);
+        }
+        if r.version > u32::MAX as i64 {
+            return Err(DbError::RowVersionOutOfRange);
+        }
+
+        let scopes_from_json: Vec<ApiScope> = serde_json::from_str(&r.scopes)
+            .map_err(|e| DbError::Other(format!("Failed to deserialize scopes: {e}")))?;
+
+        let id_version = IdVersion::new(row_id, Some(r.version as u32));
+        let mut t = ApiToken::new(id_version);
+
+        t.set_name(r.name)
+            .set_scopes(scopes_from_json)
+            .set_secret_prefix_and_hash(r.secret_prefix, r.secret_hash)
+            .set_rate_limit_per_minute(r.rate_limit_per_minute as u32)
+            .set_created_at(Some(r.created_at))
+            .set_last_used_at(r.last_used_at)
+            .set_revoked_at(r.revoked_at);
+
+        Ok(t)
+    }
+}
+
+// ------------------- INSERT / UPDATE -------------------
+#[derive(Debug, Insertable, AsChangeset)]
+#[diesel(table_name = api_tokens)]
+pub struct WriteDbApiToken<'a> {
+    pub name: &'a str,
+    pub scopes: String,
+    pub rate_limit_per_minute: i32,
+    pub revoked_at: Option<DateTime<Utc>>,
+}
+
+// Mapping Core -> DB
+impl<'a> TryFrom<&'a ApiToken> for WriteDbApiToken<'a> {
+    type Error = DbError;
+
+    fn try_from(t: &'a ApiToken) -> Result<Self, Self::Error> {
+        Ok(WriteDbApiToken {
+            name: t.get_name(),
+            scopes: serde_json::to_string(t.get_scopes())
+                .map_err(|e| DbError::Other(format!("Failed to serialize scopes: {e}")))?,
+            rate_limit_per_minute: t.get_rate_limit_per_minute() as i32,
+            revoked_at: t.get_revoked_at(),
+        })
+    }
+}
+
+// ------------------- Impl trait --------------------
+
+#[async_trait]
+impl DbpApiToken for SqliteDb {
+    #[instrument(name = "db.api_token.get", skip(self), fields(id = %token_id))]
+    async fn get_api_token(&self, token_id: Uuid) -> DbResult<Option<ApiToken>> {
+        let mut conn = self.new_connection().await?;
+        let res = api_tokens
+            .filter(id.eq(token_id.to_string()))
+            .first::<DbApiToken>(&mut conn)
+            .await
+            .optional()
+            .map_err(map_db_err)?;
+
+        match res {
+            Some(res) => {
+                let res = ApiToken::try_from(res)?;
+                debug!("found_api_token");
+                Ok(Some(res))
+            }
+            None => {
+                debug!("api_token_not_found");
+                Ok(None)
+            }
+        }
+    }
+
+    // never log the hash, it identifies the token
+    #[instrument(name = "db.api_token.get_by_hash", skip_all)]
+    async fn get_api_token_by_hash(&self, hash: &str) -> DbResult<Option<ApiToken>> {
+        let mut conn = self.new_connection().await?;
+        let res = api_tokens
+            .filter(secret_hash.eq(hash))
+            .first::<DbApiToken>(&mut conn)
+            .await
+            .optional()
+            .map_err(map_db_err)?;
+
+        res.map(ApiToken::try_from).transpose()
+    }
+
+    #[instrument(
+        name = "db.api_token.save",
+        skip(self, token),
+        fields(
+            id = ?token.get_id(),
+            version = token.get_version(),
+            is_new = token.get_id_version().is_new()
+        )
+    )]
+    async fn save_api_token(&self, token: &ApiToken) -> DbResult<ApiToken> {
+        let mut conn = self.new_connection().await?;
+        let w = WriteDbApiToken::try_from(token)?;
+
+        match token.get_id_version() {
+            // Case 1: UPDATE (Optimistic Locking); secret is never changed
+            IdVersion::Existing(inner) => {
+                let res = diesel::update(
+                    api_tokens.filter(
+                        id.eq(inner.get_id().to_string())
+                            .and(version.eq(inner.get_version() as i64)),
+                    ),
+                )
+                .set((
+                    w,
+                    version.eq(sql::<BigInt>("version + 1")),
+                    updated_at.eq(Utc::now()),
+                ))
+                .returning(api_tokens::all_columns)
+                .get_result::<DbApiToken>(&mut conn)
+                .await;
+
+                match res {
+                    Ok(row) => {
+                        info!(saved_id = %row.id, new_version = row.version, "update_ok");
+                        Ok(row.try_into()?)
+                    }
+                    Err(diesel::result::Error::NotFound) => {
+                        // Check if it exists but version mismatch
+                        let exists = diesel::select(diesel::dsl::exists(
+                            api_tokens.filter(id.eq(inner.get_id().to_string())),
+                        ))
+                        .get_result::<bool>(&mut conn)
+                        .await
+                        .map_err(map_db_err)?;
+
+                        if exists {
+                            warn!("optimistic_lock_conflict");
+                            Err(DbError::OptimisticLockConflict)
+                        } else {
+                            warn!("row_missing_on_update");
+                            Err(DbError::NotFound)
+                        }
+                    }
+                    Err(e) => {
+                        error!(error = %e, "update_failed");
+                        Err(map_db_err(e))
+                    }
+                }
+            }
+            // Case 2: INSERT with specific ID
+            IdVersion::NewWithId(new_id) => {
+                let now = Utc::now();
+                let row = diesel::insert_into(api_tokens)
+                    .values((
+                        id.eq(new_id.to_string()),
+                        created_at.eq(now),
+                        updated_at.eq(now),
+                        secret_prefix.eq(token.get_secret_prefix()),
+                        secret_hash.eq(token.get_secret_hash()),
+                        w,
+                    ))
+                    .returning(api_tokens::all_columns)
+                    .get_result::<DbApiToken>(&mut conn)
+                    .await
+                    .map_err(map_db_err)?;
+
+                info!(saved_id = %row.id, "insert_ok");
+                Ok(row.try_into()?)
+            }
+        }
+    }
+
+    #[instrument(name = "db.api_token.touch", skip(self), fields(id = %token_id))]
+    async fn touch_api_token_last_used(&self, token_id: Uuid, at: DateTime<Utc>) -> DbResult<()> {
+        let mut conn = self.new_connection().await?;
+        diesel::update(api_tokens.filter(id.eq(token_id.to_string())))
+            .set(last_used_at.eq(at))
+            .execute(&mut conn)
+            .await
+            .map_err(map_db_err)?;
+        Ok(())
+    }
+
+    #[instrument(name = "db.api_token.list", skip(self))]
+    async fn list_api_tokens(&self, include_revoked: bool) -> DbResult<Vec<ApiToken>> {
+        let mut conn = self.new_connection().await?;
+
+        let mut query = api_tokens
+            .order(name.asc())
+            .into_boxed::<diesel::sqlite::Sqlite>();
+        if !include_revoked {
+            query = query.filter(revoked_at.is_null());
+        }
+
+        let rows = query
+            .load::<DbApiToken>(&mut conn)
+            .await
+            .map_err(map_db_err)?
+            .into_iter()
+            .map(ApiToken::try_from)
+            .collect::<DbResult<Vec<_>>>()?;
+        info!(count = rows.len(), "list_ok");
+        Ok(rows)
+    }
+}
@@ -0,0 +1,181 @@
+//! implementation of audit log port
+
+use crate::{
+    SqliteDb, map_db_err, parse_uuid,
+    schema::{audit_log, audit_log::dsl::*},
+};
+use app_core::{
+    AuditAction, AuditObjectType, AuditRecord, DbError, DbResult, DbpAuditLog,
+    utils::{id_version::IdVersion, traits::ObjectIdVersion},
+};
+use async_trait::async_trait;
+use chrono::{DateTime, Utc};
+use diesel::prelude::{ExpressionMethods, Insertable, QueryDsl, Queryable};
+use diesel_async::RunQueryDsl;
+use std::str::FromStr;
+use tracing::{info, instrument, warn};
+use uuid::Uuid;
+
+// ------------------- DB-Row (SELECT/RETURNING) -------------------
+#[derive(Debug, Queryable)]
+pub struct DbAuditRecord {
+    pub id: String,
+    pub version: i64,
+    pub actor: String,
+    pub action: String,
+    pub object_type: String,
+    pub object_id: String,
+    pub tournament_id: Option<String>,
+    pub old_version: Option<i64>,
+    pub new_version: Option<i64>,
+    pub diff: String,
+    pub created_at: DateTime<Utc>,
+    pub updated_at: DateTime<Utc>,
+}
+
+fn object_version_from_row(v: Option<i64>) -> DbResult<Option<u32>> {
+    match v {
+        Some(v) if v < 0 => Err(DbError::NegativeRowVersion),
+        Some(v) if v > u32::MAX as i64 => Err(DbError::RowVersionOutOfRange),
+        v => Ok(v.map(|v| v as u32)),
+    }
+}
+
+// Mapping DB -> Core
+impl TryFrom<DbAuditRecord> for AuditRecord {
+    type Error = DbError;
+
+    fn try_from(r: DbAuditRecord) -> Result<Self, Self::Error> {
+        let row_id = parse_uuid(&r.id)?;
+        if row_id.is_nil() {
+            return Err(DbError::NilRowId);
+        }
+        if r.version < 0 {
+            return Err(DbError::NegativeRowVersion);
+        }
+        if r.version > u32::MAX as i64 {
+            return Err(DbError::RowVersionOutOfRange);
+        }
+        let action_from_str = AuditAction::from_str(&r.action)
+            .map_err(|e| DbError::Other(format!("Failed to parse audit action: {e}")))?;
+        let object_type_from_str = AuditObjectType::from_str(&r.object_type)
+            .map_err(|e| DbError::Other(format!("Failed to parse audit object type: {e}")))?;
+        let diff_from_json: serde_json::Value = serde_json::from_str(&r.diff)
+            .map_err(|e| DbError::Other(format!("Failed to deserialize diff: {e}")))?;
+
+        let id_version = IdVersion::new(row_id, Some(r.version as u32));
+        let mut record = AuditRecord::new(id_version);
+
+        record
+            .set_actor(r.actor)
+            .set_action(action_from_str)
+            .set_object_type(object_type_from_str)
+            .set_object_id(parse_uuid(&r.object_id)?)
+            .set_tournament_id(r.tournament_id.as_deref().map(parse_uuid).transpose()?)
+            .set_old_version(object_version_from_row(r.old_version)?)
+            .set_new_version(object_version_from_row(r.new_version)?)
+            .set_diff(diff_from_json)
+            .set_created_at(Some(r.created_at));
+
+        Ok(record)
+    }
+}
+
+// ------------------- INSERT -------------------
+#[derive(Debug, Insertable)]
+#[diesel(table_name = audit_log)]
+pub struct WriteDbAuditRecord<'a> {
+    pub actor: &'a str,
+    pub action: &'a str,
+    pub object_type: &'a str,
+    pub object_id: String,
+    pub tournament_id: Option<String>,
+    pub old_version: Option<i64>,
+    pub new_version: Option<i64>,
+    pub diff: String,
+}
+
+// Mapping Core -> DB
+impl<'a> TryFrom<&'a AuditRecord> for WriteDbAuditRecord<'a> {
+    type Error = DbError;
+
+    fn try_from(r: &'a AuditRecord) -> Result<Self, Self::Error> {
+        Ok(WriteDbAuditRecord {
+            actor: r.get_actor(),
+            action: r.get_action().as_str(),
+            object_type: r.get_object_type().as_str(),
+            object_id: r.get_object_id().to_string(),
+            tournament_id: r.get_tournament_id().map(|t| t.to_string()),
+            old_version: r.get_old_version().map(i64::from),
+            new_version: r.get_new_version().map(i64::from),
+            diff: serde_json::to_string(r.get_diff())
+                .map_err(|e| DbError::Other(format!("Failed to serialize diff: {e}")))?,
+        })
+    }
+}
+
+// ------------------- Impl trait --------------------
+
+#[async_trait]
+impl DbpAuditLog for SqliteDb {
+    #[instrument(
+        name = "db.audit_log.save",
+        skip(self, record),
+        fields(
+            id = ?record.get_id(),
+            object_type = %record.get_object_type(),
+            object_id = %record.get_object_id(),
+        )
+    )]
+    async fn save_audit_record(&self, record: &AuditRecord) -> DbResult<AuditRecord> {
+        let IdVersion::NewWithId(new_id) = record.get_id_version() else {
+            warn!("update_of_append_only_row");
+            return Err(DbError::Other("audit records are append only".to_string()));
+        };
+        let mut conn = self.new_connection().await?;
+        let w = WriteDbAuditRecord::try_from(record)?;
+
+        let now = Utc::now();
+        let row = diesel::insert_into(audit_log)
+            .values((
+                id.eq(new_id.to_string()),
+                created_at.eq(now),
+                updated_at.eq(now),
+                w,
+            ))
+            .returning(audit_log::all_columns)
+            .get_result::<DbAuditRecord>(&mut conn)
+            .await
+            .map_err(map_db_err)?;
+
+        info!(saved_id = %row.id, "insert_ok");
+        row.try_into()
+    }
+
+    #[instrument(name = "db.audit_log.list", skip(self))]
+    async fn list_audit_records_of_tournament(
+        &self,
+        t_id: Uuid,
+        limit: Option<usize>,
+    ) -> DbResult<Vec<AuditRecord>> {
+        let mut conn = self.new_connection().await?;
+
+        let mut query = audit_log
+            .filter(tournament_id.eq(t_id.to_string()))
+            .order(created_at.desc())
+            .into_boxed::<diesel::sqlite::Sqlite>();
+        if let Some(lim) = limit {
+            query = query.limit(lim as i64);
+        }
+
+        let rows = query
+            .load::<DbAuditRecord>(&mut conn)
+            .await
+            .map_err(map_db_err)?
+            .into_iter()
+            .map(AuditRecord::try_from)
+            .collect::<DbResult<Vec<_>>>()?;
+        info!(count = rows.len(), "list_ok");
+        Ok(rows)
+    }
+}
@@ -0,0 +1,236 @@
+//! implementation of entrant port
+
+use crate::{
+    SqliteDb, map_db_err, parse_uuid,
+    schema::{entrants, entrants::dsl::*},
+};
+use app_core::{
+    DbError, DbResult, DbpEntrant, Entrant,
+    utils::{id_version::IdVersion, traits::ObjectIdVersion},
+};
+use async_trait::async_trait;
+use chrono::{DateTime, Utc};
+use diesel::{
+    dsl::sql,
+    prelude::{
+        AsChangeset, BoolExpressionMethods, ExpressionMethods, Insertable, OptionalExtension,
+        QueryDsl, Queryable,
+    },
+    sql_types::BigInt,
+};
+use diesel_async::RunQueryDsl;
+use tracing::{debug, error, info, instrument, warn};
+use uuid::Uuid;
+
+// ------------------- DB-Row (SELECT/RETURNING) -------------------
+#[derive(Debug, Queryable)]
+pub struct DbEntrant {
+    pub id: String,
+    pub version: i64,
+    pub tournament_id: String,
+    pub name: String,
+    pub club: Option<String>,
+    pub seed: Option<i32>,
+    pub created_at: DateTime<Utc>,
+    pub updated_at: DateTime<Utc>,
+    pub email: Option<String>,
+    pub checked_in_at: Option<DateTime<Utc>>,
+    pub waitlist_position: Option<i32>,
+    pub no_show: bool,
+}
+
+// Mapping DB -> Core
+impl TryFrom<DbEntrant> for Entrant {
+    type Error = DbError;
+
+    fn try_from(r: DbEntrant) -> Result<Self, Self::Error> {
+        let row_id = parse_uuid(&r.id)?;
+        if row_id.is_nil() {
+            return Err(DbError::NilRowId);
+        }
+        if r.version < 0 {
+            return Err(DbError::NegativeRowVersion);
+        }
+        if r.version > u32::MAX as i64 {
+            return Err(DbError::RowVersionOutOfRange);
+        }
+
+        let id_version = IdVersion::new(row_id, Some(r.version as u32));
+        let mut e = Entrant::new(id_version);
+
+        e.set_tournament_id(parse_uuid(&r.tournament_id)?)
+            .set_name(r.name)
+            .set_club(r.club)
+            .set_seed(r.seed.map(|s| s as u32))
+            .set_email(r.email)
+            .set_checked_in_at(r.checked_in_at)
+            .set_waitlist_position(r.waitlist_position.map(|p| p as u32))
+            .set_no_show(r.no_show);
+
+        Ok(e)
+    }
+}
+
+// ------------------- INSERT / UPDATE -------------------
+#[derive(Debug, Insertable, AsChangeset)]
+#[diesel(table_name = entrants)]
+// write NULL for removed club, seed, email, check-in or waitlist position
+#[diesel(treat_none_as_null = true)]
+pub struct WriteDbEntrant {
+    pub tournament_id: String,
+    pub name: String,
+    pub club: Option<String>,
+    pub seed: Option<i32>,
+    pub email: Option<String>,
+    pub checked_in_at: Option<DateTime<Utc>>,
+    pub waitlist_position: Option<i32>,
+    pub no_show: bool,
+}
+
+// Mapping Core -> DB
+impl<'a> TryFrom<&'a Entrant> for WriteDbEntrant {
+    type Error = DbError;
+
+    fn try_from(e: &'a Entrant) -> Result<Self, Self::Error> {
+        Ok(WriteDbEntrant {
+            tournament_id: e.get_tournament_id().to_string(),
+            name: e.get_name().to_string(),
+            club: e.get_club().map(|c| c.to_string()),
+            seed: e.get_seed().map(|s| s as i32),
+            email: e.get_email().map(|m| m.to_string()),
+            checked_in_at: e.get_checked_in_at(),
+            waitlist_position: e.get_waitlist_position().map(|p| p as i32),
+            no_show: e.is_no_show(),
+        })
+    }
+}
+
+// ------------------- Impl trait --------------------
+
+#[async_trait]
+impl DbpEntrant for SqliteDb {
+    #[instrument(name = "db.entrant.get", skip(self), fields(id = %entrant_id))]
+    async fn get_entrant(&self, entrant_id: Uuid) -> DbResult<Option<Entrant>> {
+        let mut conn = self.new_connection().await?;
+        let res = entrants
+            .filter(id.eq(entrant_id.to_string()))
+            .first::<DbEntrant>(&mut conn)
+            .await
+            .optional()
+            .map_err(map_db_err)?;
+
+        match res {
+            Some(res) => {
+                let res = Entrant::try_from(res)?;
+                debug!("found_entrant");
+                Ok(Some(res))
+            }
+            None => {
+                debug!("entrant_not_found");
+                Ok(None)
+            }
+        }
+    }
+
+    #[instrument(
+        name = "db.entrant.save",
+        skip(self, entrant),
+        fields(
+            id = ?entrant.get_id(),
+            version = entrant.get_version(),
+            is_new = entrant.get_id_version().is_new()
+        )
+    )]
+    async fn save_entrant(&self, entrant: &Entrant) -> DbResult<Entrant> {
+        let mut conn = self.new_connection().await?;
+        let w = WriteDbEntrant::try_from(entrant)?;
+
+        match entrant.get_id_version() {
+            // Case 1: UPDATE (Optimistic Locking)
+            IdVersion::Existing(inner) => {
+                let res = diesel::update(
+                    entrants.filter(
+                        id.eq(inner.get_id().to_string())
+                            .and(version.eq(inner.get_version() as i64)),
+                    ),
+                )
+                .set((
+                    w,
+                    version.eq(sql::<BigInt>("version + 1")),
+                    updated_at.eq(Utc::now()),
+                ))
+                .returning(entrants::all_columns)
+                .get_result::<DbEntrant>(&mut conn)
+                .await;
+
+                match res {
+                    Ok(row) => {
+                        info!(saved_id = %row.id, new_version = row.version, "update_ok");
+                        Ok(row.try_into()?)
+                    }
+                    Err(diesel::result::Error::NotFound) => {
+                        // Check if it exists but version mismatch
+                        let exists = diesel::select(diesel::dsl::exists(
+                            entrants.filter(id.eq(inner.get_id().to_string())),
+                        ))
+                        .get_result::<bool>(&mut conn)
+                        .await
+                        .map_err(map_db_err)?;
+
+                        if exists {
+                            warn!("optimistic_lock_conflict");
+                            Err(DbError::OptimisticLockConflict)
+                        } else {
+                            warn!("row_missing_on_update");
+                            Err(DbError::NotFound)
+                        }
+                    }
+                    Err(e) => {
+                        error!(error = %e, "update_failed");
+                        Err(map_db_err(e))
+                    }
+                }
+            }
+            // Case 2: INSERT with specific ID
+            IdVersion::NewWithId(new_id) => {
+                let now = Utc::now();
+                let row = diesel::insert_into(entrants)
+                    .values((
+                        id.eq(new_id.to_string()),
+                        created_at.eq(now),
+                        updated_at.eq(now),
+                        w,
+                    ))
+                    .returning(entrants::all_columns)
+                    .get_result::<DbEntrant>(&mut conn)
+                    .await
+                    .map_err(map_db_err)?;
+
+                info!(saved_id = %row.id, "insert_ok");
+                Ok(row.try_into()?)
+            }
+        }
+    }
+
+    #[instrument(name = "db.entrant.list", skip(self, t_id))]
+    async fn list_entrants_of_tournament(&self, t_id: Uuid) -> DbResult<Vec<Entrant>> {
+        let mut conn = self.new_connection().await?;
+
+        // entrants without seed last
+        let query = entrants.filter(tournament_id.eq(t_id.to_string())).order((
+            seed.is_null().asc(),
+            seed.asc(),
+            name.asc(),
+        ));
+
+        let rows = query
+            .load::<DbEntrant>(&mut conn)
+            .await
+            .map_err(map_db_err)?
+            .into_iter()
+            .map(Entrant::try_from)
+            .collect::<DbResult<Vec<_>>>()?;
+        info!(count = rows.len(), "list_ok");
+        Ok(rows)
+    }
+}
@@ -0,0 +1,146 @@
+//! implementation of feedback port
+
+use crate::{
+    SqliteDb, map_db_err, parse_uuid,
+    schema::{feedback, feedback::dsl::*},
+};
+use app_core::{
+    DbError, DbResult, DbpFeedback, Feedback,
+    utils::{id_version::IdVersion, traits::ObjectIdVersion},
+};
+use async_trait::async_trait;
+use chrono::{DateTime, Utc};
+use diesel::prelude::{ExpressionMethods, Insertable, QueryDsl, Queryable};
+use diesel_async::RunQueryDsl;
+use tracing::{info, instrument, warn};
+
+// ------------------- DB-Row (SELECT/RETURNING) -------------------
+#[derive(Debug, Queryable)]
+pub struct DbFeedback {
+    pub id: String,
+    pub version: i64,
+    pub message: String,
+    pub route: String,
+    pub tournament_id: Option<String>,
+    pub app_version: String,
+    pub recent_errors: String,
+    pub created_at: DateTime<Utc>,
+    pub updated_at: DateTime<Utc>,
+}
+
+// Mapping DB -> Core
+impl TryFrom<DbFeedback> for Feedback {
+    type Error = DbError;
+
+    fn try_from(r: DbFeedback) -> Result<Self, Self::Error> {
+        let row_id = parse_uuid(&r.id)?;
+        if row_id.is_nil() {
+            return Err(DbError::NilRowId);
+        }
+        if r.version < 0 {
+            return Err(DbError::NegativeRowVersion);
+        }
+        if r.version > u32::MAX as i64 {
+            return Err(DbError::RowVersionOutOfRange);
+        }
+
+        let recent_errors_from_json: Vec<String> = serde_json::from_str(&r.recent_errors)
+            .map_err(|e| DbError::Other(format!("Failed to deserialize recent errors: {e}")))?;
+
+        let id_version = IdVersion::new(row_id, Some(r.version as u32));
+        let mut f = Feedback::new(id_version);
+
+        f.set_message(r.message)
+            .set_route(r.route)
+            .set_tournament_id(r.tournament_id.as_deref().map(parse_uuid).transpose()?)
+            .set_app_version(r.app_version)
+            .set_recent_errors(recent_errors_from_json)
+            .set_created_at(Some(r.created_at));
+
+        Ok(f)
+    }
+}
+
+// ------------------- INSERT -------------------
+#[derive(Debug, Insertable)]
+#[diesel(table_name = feedback)]
+pub struct WriteDbFeedback<'a> {
+    pub message: &'a str,
+    pub route: &'a str,
+    pub tournament_id: Option<String>,
+    pub app_version: &'a str,
+    pub recent_errors: String,
+}
+
+// Mapping Core -> DB
+impl<'a> TryFrom<&'a Feedback> for WriteDbFeedback<'a> {
+    type Error = DbError;
+
+    fn try_from(f: &'a Feedback) -> Result<Self, Self::Error> {
+        Ok(WriteDbFeedback {
+            message: f.get_message(),
+            route: f.get_route(),
+            tournament_id: f.get_tournament_id().map(|t| t.to_string()),
+            app_version: f.get_app_version(),
+            recent_errors: serde_json::to_string(f.get_recent_errors())
+                .map_err(|e| DbError::Other(format!("Failed to serialize recent errors: {e}")))?,
+        })
+    }
+}
+
+// ------------------- Impl trait --------------------
+
+#[async_trait]
+impl DbpFeedback for SqliteDb {
+    #[instrument(
+        name = "db.feedback.save",
+        skip(self, fb),
+        fields(id = ?fb.get_id(), tournament_id = ?fb.get_tournament_id())
+    )]
+    async fn save_feedback(&self, fb: &Feedback) -> DbResult<Feedback> {
+        let IdVersion::NewWithId(new_id) = fb.get_id_version() else {
+            warn!("update_of_append_only_row");
+            return Err(DbError::Other("feedback is append only".to_string()));
+        };
+        let mut conn = self.new_connection().await?;
+        let w = WriteDbFeedback::try_from(fb)?;
+
+        let now = Utc::now();
+        let row = diesel::insert_into(feedback)
+            .values((
+                id.eq(new_id.to_string()),
+                created_at.eq(now),
+                updated_at.eq(now),
+                w,
+            ))
+            .returning(feedback::all_columns)
+            .get_result::<DbFeedback>(&mut conn)
+            .await
+            .map_err(map_db_err)?;
+
+        info!(saved_id = %row.id, "insert_ok");
+        row.try_into()
+    }
+
+    #[instrument(name = "db.feedback.list", skip(self))]
+    async fn list_feedback(&self, limit: Option<usize>) -> DbResult<Vec<Feedback>> {
+        let mut conn = self.new_connection().await?;
+
+        let mut query = feedback
+            .order(created_at.desc())
+            .into_boxed::<diesel::sqlite::Sqlite>();
+        if let Some(lim) = limit {
+            query = query.limit(lim as i64);
+        }
+
+        let rows = query
+            .load::<DbFeedback>(&mut conn)
+            .await
+            .map_err(map_db_err)?
+            .into_iter()
+            .map(Feedback::try_from)
+            .collect::<DbResult<Vec<_>>>()?;
+        info!(count = rows.len(), "list_ok");
+        Ok(rows)
+    }
+}
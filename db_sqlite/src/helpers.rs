@@ -0,0 +1,12 @@
+// Some data base helpers
+
+/// default file of the sqlite database
+pub const DEFAULT_SQLITE_PATH: &str = "fk_tournament_planer.sqlite";
+
+/// escaping wild cards in like query strings; use with `escape('\\')`, since sqlite has no
+/// default escape character
+pub fn escape_like(s: &str) -> String {
+    s.replace('\\', "\\\\")
+        .replace('%', "\\%")
+        .replace('_', "\\_")
+}
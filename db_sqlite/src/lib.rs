@@ -0,0 +1,301 @@
+// diesel sqlite implementation of database port
+
+pub mod api_token;
+pub mod audit_log;
+pub mod client_error;
+pub mod entrant;
+pub mod feedback;
+pub mod group;
+pub mod group_seeding;
+pub mod group_standings;
+pub mod helpers;
+pub mod match_;
+pub mod match_note;
+pub mod official;
+pub mod pairing_override;
+pub mod postal_address;
+pub mod schema;
+pub mod scorekeeper;
+pub mod search;
+pub mod shift_log;
+pub mod sport_config;
+pub mod stage;
+pub mod stage_snapshot;
+pub mod tournament_base;
+pub mod venue;
+pub mod webhook;
+
+pub use helpers::*;
+
+use anyhow::{Context, Result, anyhow};
+use app_core::{DatabasePort, DbError, DbResult, DbTransaction};
+use async_trait::async_trait;
+use diesel::{
+    Connection, SqliteConnection, connection::TransactionManagerStatus, dsl::sql, select,
+    sql_types::Bool,
+};
+use diesel_async::{
+    AsyncConnection, RunQueryDsl, SimpleAsyncConnection, TransactionManager,
+    sync_connection_wrapper::SyncConnectionWrapper,
+};
+use diesel_migrations::{EmbeddedMigrations, MigrationHarness, embed_migrations};
+use std::{
+    ops::{Deref, DerefMut},
+    sync::Arc,
+};
+use tokio::sync::{Mutex, MutexGuard, OwnedMutexGuard};
+use tracing::{info, instrument, warn};
+use uuid::Uuid;
+
+/// embed migrations
+pub const MIGRATIONS: EmbeddedMigrations = embed_migrations!();
+
+/// settings of every connection; sqlite does not enforce foreign keys by default
+const CONNECTION_PRAGMAS: &str =
+    "PRAGMA foreign_keys = ON; PRAGMA journal_mode = WAL; PRAGMA busy_timeout = 5000;";
+
+type SqliteConn = SyncConnectionWrapper<SqliteConnection>;
+type SqliteTransactionManager = <SqliteConn as AsyncConnection>::TransactionManager;
+
+/// Database in a single sqlite file, e.g. for running the planer on a laptop at the venue
+/// without a postgres server.
+///
+/// Sqlite allows only one writer at a time, therefore all queries share one connection.
+pub struct SqliteDb {
+    path: String,
+    connection: Arc<Mutex<SqliteConn>>,
+    /// shared connection, if this is a transaction of [`DatabasePort::begin_transaction`];
+    /// it is held until commit or rollback, all other queries wait for the transaction
+    transaction: Option<Arc<Mutex<Option<OwnedMutexGuard<SqliteConn>>>>>,
+}
+
+/// shared connection or connection of the transaction of [`SqliteDb`]
+pub enum DbConnection<'a> {
+    Shared(MutexGuard<'a, SqliteConn>),
+    Transaction(MutexGuard<'a, Option<OwnedMutexGuard<SqliteConn>>>),
+}
+
+impl Deref for DbConnection<'_> {
+    type Target = SqliteConn;
+
+    fn deref(&self) -> &Self::Target {
+        match self {
+            DbConnection::Shared(conn) => conn,
+            DbConnection::Transaction(conn) => (**conn)
+                .as_ref()
+                .expect("expecting open transaction, checked by new_connection"),
+        }
+    }
+}
+
+impl DerefMut for DbConnection<'_> {
+    fn deref_mut(&mut self) -> &mut Self::Target {
+        match self {
+            DbConnection::Shared(conn) => conn,
+            DbConnection::Transaction(conn) => (**conn)
+                .as_mut()
+                .expect("expecting open transaction, checked by new_connection"),
+        }
+    }
+}
+
+impl SqliteDb {
+    /// Open (or create) the sqlite database at `path`.
+    pub async fn new(path: impl Into<String>) -> Result<Self> {
+        let path = path.into();
+        let mut conn = SqliteConn::establish(&path)
+            .await
+            .with_context(|| format!("failed to open sqlite database {path}"))?;
+        conn.batch_execute(CONNECTION_PRAGMAS).await?;
+        Ok(SqliteDb {
+            path,
+            connection: Arc::new(Mutex::new(conn)),
+            transaction: None,
+        })
+    }
+    #[instrument(name = "db.migration", skip(self))]
+    pub async fn run_migration(&self) -> DbResult<()> {
+        let path = self.path.clone();
+        tokio::task::spawn_blocking(move || -> Result<()> {
+            let mut conn = SqliteConnection::establish(&path)?;
+            conn.run_pending_migrations(MIGRATIONS)
+                .map_err(|e| anyhow!("migration failed: {e}"))?;
+            Ok(())
+        })
+        .await
+        .context("Join error while running migrations")??;
+
+        info!("Migrations applied successfully");
+        Ok(())
+    }
+    /// Shared connection; inside of a transaction the connection of the transaction.
+    #[instrument(name = "db.conn.get", skip(self))]
+    pub async fn new_connection(&self) -> DbResult<DbConnection<'_>> {
+        let Some(transaction) = self.transaction.as_ref() else {
+            let mut conn = self.connection.lock().await;
+            rollback_abandoned_transaction(&mut conn).await?;
+            return Ok(DbConnection::Shared(conn));
+        };
+        let conn = transaction.lock().await;
+        if conn.is_none() {
+            warn!("query_after_end_of_transaction");
+            return Err(DbError::Other(
+                "transaction is already finished".to_string(),
+            ));
+        }
+        Ok(DbConnection::Transaction(conn))
+    }
+    /// Take the connection of the transaction; dropping it releases the shared connection.
+    async fn end_transaction(&self) -> DbResult<OwnedMutexGuard<SqliteConn>> {
+        let Some(transaction) = self.transaction.as_ref() else {
+            return Err(DbError::Other("no transaction to end".to_string()));
+        };
+        transaction
+            .lock()
+            .await
+            .take()
+            .ok_or_else(|| DbError::Other("transaction is already finished".to_string()))
+    }
+}
+
+#[async_trait]
+impl DatabasePort for SqliteDb {
+    #[instrument(name = "db.ping", skip(self))]
+    async fn ping_db(&self) -> DbResult<()> {
+        let mut conn = self.new_connection().await?;
+        select(sql::<Bool>("1=1"))
+            .execute(&mut conn)
+            .await
+            .map_err(map_db_err)?;
+        Ok(())
+    }
+    #[instrument(name = "db.transaction.begin", skip(self))]
+    async fn begin_transaction(&self) -> DbResult<Arc<dyn DbTransaction>> {
+        if self.transaction.is_some() {
+            return Err(DbError::Other(
+                "nested transactions are not supported".to_string(),
+            ));
+        }
+        let mut conn = self.connection.clone().lock_owned().await;
+        rollback_abandoned_transaction(&mut conn).await?;
+        SqliteTransactionManager::begin_transaction(&mut *conn)
+            .await
+            .map_err(map_db_err)?;
+        Ok(Arc::new(SqliteDb {
+            path: self.path.clone(),
+            connection: self.connection.clone(),
+            transaction: Some(Arc::new(Mutex::new(Some(conn)))),
+        }))
+    }
+}
+
+#[async_trait]
+impl DbTransaction for SqliteDb {
+    #[instrument(name = "db.transaction.commit", skip(self))]
+    async fn commit(&self) -> DbResult<()> {
+        let mut conn = self.end_transaction().await?;
+        SqliteTransactionManager::commit_transaction(&mut *conn)
+            .await
+            .map_err(map_db_err)
+    }
+    #[instrument(name = "db.transaction.rollback", skip(self))]
+    async fn rollback(&self) -> DbResult<()> {
+        let mut conn = self.end_transaction().await?;
+        SqliteTransactionManager::rollback_transaction(&mut *conn)
+            .await
+            .map_err(map_db_err)
+    }
+}
+
+/// Roll back the transaction of a [`DbTransaction`], which was dropped without commit or
+/// rollback. Its `BEGIN` is still open on the shared connection, which would otherwise add
+/// all following queries to the abandoned transaction.
+async fn rollback_abandoned_transaction(conn: &mut SqliteConn) -> DbResult<()> {
+    let status = SqliteTransactionManager::transaction_manager_status_mut(conn);
+    if matches!(status.transaction_depth(), Ok(None)) {
+        return Ok(());
+    }
+    warn!("rollback_of_abandoned_transaction");
+    // reset the transaction manager, which may be broken by the abandoned transaction
+    *status = TransactionManagerStatus::default();
+    if let Err(e) = conn.batch_execute("ROLLBACK").await {
+        // sqlite already rolled back the transaction, e.g. after a failed statement
+        warn!(error = %e, "rollback_of_abandoned_transaction_failed");
+    }
+    Ok(())
+}
+
+use diesel::result::{DatabaseErrorKind as K, Error as DE};
+
+fn map_db_err(e: DE) -> DbError {
+    match &e {
+        DE::NotFound => DbError::NotFound,
+        DE::DatabaseError(kind, info) => {
+            let c = info.constraint_name().map(|s| s.to_string());
+            match kind {
+                K::UniqueViolation => DbError::UniqueViolation(c),
+                K::ForeignKeyViolation => DbError::ForeignKeyViolation(c),
+                K::CheckViolation => DbError::CheckViolation(c),
+                K::Unknown if is_database_locked(info.message()) => DbError::Timeout,
+                _ => DbError::from(anyhow::anyhow!(e)),
+            }
+        }
+        _ => DbError::from(anyhow::anyhow!(e)),
+    }
+}
+
+/// Maps the current version of a row, whose optimistic locking update failed, to a version
+/// conflict.
+fn map_version_conflict(current_version: i64) -> DbError {
+    match u32::try_from(current_version) {
+        Ok(current_version) => DbError::VersionConflict { current_version },
+        Err(_) => DbError::RowVersionOutOfRange,
+    }
+}
+
+/// sqlite reports a lock, which is not released within `busy_timeout`, with SQLITE_BUSY
+/// ("database is locked"), which diesel maps to `DatabaseErrorKind::Unknown`
+fn is_database_locked(message: &str) -> bool {
+    message.contains("database is locked")
+}
+
+/// Uuids are stored as text.
+fn parse_uuid(value: &str) -> DbResult<Uuid> {
+    Uuid::parse_str(value).map_err(|e| DbError::Other(format!("Failed to parse uuid: {e}")))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn detects_database_locked_message() {
+        assert!(is_database_locked("database is locked"));
+        assert!(!is_database_locked(
+            "UNIQUE constraint failed: entrants.name"
+        ));
+    }
+
+    #[tokio::test]
+    async fn given_dropped_transaction_when_new_connection_then_transaction_rolled_back() {
+        let db = SqliteDb::new(":memory:").await.unwrap();
+        let transaction = db.begin_transaction().await.unwrap();
+        drop(transaction);
+
+        let mut conn = db.new_connection().await.unwrap();
+        let status = SqliteTransactionManager::transaction_manager_status_mut(&mut *conn);
+        assert_eq!(status.transaction_depth().unwrap(), None);
+        drop(conn);
+
+        // a new transaction starts on the released shared connection
+        let transaction = db.begin_transaction().await.unwrap();
+        transaction.commit().await.unwrap();
+    }
+
+    #[test]
+    fn parses_stored_uuid() {
+        let id = Uuid::new_v4();
+        assert_eq!(parse_uuid(&id.to_string()).unwrap(), id);
+        assert!(parse_uuid("no uuid").is_err());
+    }
+}
@@ -0,0 +1,235 @@
+//! implementation of match note port
+
+use crate::{
+    SqliteDb, map_db_err, parse_uuid,
+    schema::{match_notes, match_notes::dsl::*},
+};
+use app_core::{
+    DbError, DbResult, DbpMatchNote, MatchNote,
+    utils::{id_version::IdVersion, traits::ObjectIdVersion},
+};
+use async_trait::async_trait;
+use chrono::{DateTime, Utc};
+use diesel::{
+    dsl::sql,
+    prelude::{
+        AsChangeset, BoolExpressionMethods, ExpressionMethods, Insertable, OptionalExtension,
+        QueryDsl, Queryable,
+    },
+    sql_types::{BigInt, Bool, Text},
+};
+use diesel_async::RunQueryDsl;
+use tracing::{debug, error, info, instrument, warn};
+use uuid::Uuid;
+
+// ------------------- DB-Row (SELECT/RETURNING) -------------------
+#[derive(Debug, Queryable)]
+pub struct DbMatchNote {
+    pub id: String,
+    pub version: i64,
+    pub tournament_id: String,
+    pub match_id: String,
+    pub tags: String,
+    pub text: String,
+    pub author: String,
+    pub created_at: DateTime<Utc>,
+    pub updated_at: DateTime<Utc>,
+}
+
+// Mapping DB -> Core
+impl TryFrom<DbMatchNote> for MatchNote {
+    type Error = DbError;
+
+    fn try_from(r: DbMatchNote) -> Result<Self, Self::Error> {
+        let row_id = parse_uuid(&r.id)?;
+        if row_id.is_nil() {
+            return Err(DbError::NilRowId);
+        }
+        if r.version < 0 {
+            return Err(DbError::NegativeRowVersion);
+        }
+        if r.version > u32::MAX as i64 {
+            return Err(DbError::RowVersionOutOfRange);
+        }
+
+        let tags_from_json: Vec<String> = serde_json::from_str(&r.tags)
+            .map_err(|e| DbError::Other(format!("Failed to deserialize tags: {e}")))?;
+
+        let id_version = IdVersion::new(row_id, Some(r.version as u32));
+        let mut n = MatchNote::new(id_version);
+
+        n.set_tournament_id(parse_uuid(&r.tournament_id)?)
+            .set_match_id(parse_uuid(&r.match_id)?)
+            .set_tags(tags_from_json)
+            .set_text(r.text)
+            .set_author(r.author)
+            .set_updated_at(Some(r.updated_at));
+
+        Ok(n)
+    }
+}
+
+// ------------------- INSERT / UPDATE -------------------
+#[derive(Debug, Insertable, AsChangeset)]
+#[diesel(table_name = match_notes)]
+pub struct WriteDbMatchNote {
+    pub tournament_id: String,
+    pub match_id: String,
+    pub tags: String,
+    pub text: String,
+    pub author: String,
+}
+
+// Mapping Core -> DB
+impl<'a> TryFrom<&'a MatchNote> for WriteDbMatchNote {
+    type Error = DbError;
+
+    fn try_from(n: &'a MatchNote) -> Result<Self, Self::Error> {
+        Ok(WriteDbMatchNote {
+            tournament_id: n.get_tournament_id().to_string(),
+            match_id: n.get_match_id().to_string(),
+            tags: serde_json::to_string(n.get_tags())
+                .map_err(|e| DbError::Other(format!("Failed to serialize tags: {e}")))?,
+            text: n.get_text().to_string(),
+            author: n.get_author().to_string(),
+        })
+    }
+}
+
+// ------------------- Impl trait --------------------
+
+#[async_trait]
+impl DbpMatchNote for SqliteDb {
+    #[instrument(name = "db.match_note.get_of_match", skip(self), fields(match_id = %m_id))]
+    async fn get_match_note_of_match(&self, m_id: Uuid) -> DbResult<Option<MatchNote>> {
+        let mut conn = self.new_connection().await?;
+        let res = match_notes
+            .filter(match_id.eq(m_id.to_string()))
+            .first::<DbMatchNote>(&mut conn)
+            .await
+            .optional()
+            .map_err(map_db_err)?;
+
+        match res {
+            Some(res) => {
+                let res = MatchNote::try_from(res)?;
+                debug!("found_match_note");
+                Ok(Some(res))
+            }
+            None => {
+                debug!("match_note_not_found");
+                Ok(None)
+            }
+        }
+    }
+
+    #[instrument(
+        name = "db.match_note.save",
+        skip(self, note),
+        fields(
+            id = ?note.get_id(),
+            version = note.get_version(),
+            is_new = note.get_id_version().is_new()
+        )
+    )]
+    async fn save_match_note(&self, note: &MatchNote) -> DbResult<MatchNote> {
+        let mut conn = self.new_connection().await?;
+        let w = WriteDbMatchNote::try_from(note)?;
+
+        match note.get_id_version() {
+            // Case 1: UPDATE (Optimistic Locking)
+            IdVersion::Existing(inner) => {
+                let res = diesel::update(
+                    match_notes.filter(
+                        id.eq(inner.get_id().to_string())
+                            .and(version.eq(inner.get_version() as i64)),
+                    ),
+                )
+                .set((
+                    w,
+                    version.eq(sql::<BigInt>("version + 1")),
+                    updated_at.eq(Utc::now()),
+                ))
+                .returning(match_notes::all_columns)
+                .get_result::<DbMatchNote>(&mut conn)
+                .await;
+
+                match res {
+                    Ok(row) => {
+                        info!(saved_id = %row.id, new_version = row.version, "update_ok");
+                        Ok(row.try_into()?)
+                    }
+                    Err(diesel::result::Error::NotFound) => {
+                        // Check if it exists but version mismatch
+                        let exists = diesel::select(diesel::dsl::exists(
+                            match_notes.filter(id.eq(inner.get_id().to_string())),
+                        ))
+                        .get_result::<bool>(&mut conn)
+                        .await
+                        .map_err(map_db_err)?;
+
+                        if exists {
+                            warn!("optimistic_lock_conflict");
+                            Err(DbError::OptimisticLockConflict)
+                        } else {
+                            warn!("row_missing_on_update");
+                            Err(DbError::NotFound)
+                        }
+                    }
+                    Err(e) => {
+                        error!(error = %e, "update_failed");
+                        Err(map_db_err(e))
+                    }
+                }
+            }
+            // Case 2: INSERT with specific ID
+            IdVersion::NewWithId(new_id) => {
+                let now = Utc::now();
+                let row = diesel::insert_into(match_notes)
+                    .values((
+                        id.eq(new_id.to_string()),
+                        created_at.eq(now),
+                        updated_at.eq(now),
+                        w,
+                    ))
+                    .returning(match_notes::all_columns)
+                    .get_result::<DbMatchNote>(&mut conn)
+                    .await
+                    .map_err(map_db_err)?;
+
+                info!(saved_id = %row.id, "insert_ok");
+                Ok(row.try_into()?)
+            }
+        }
+    }
+
+    #[instrument(name = "db.match_note.list", skip(self, t_id))]
+    async fn list_match_notes(&self, t_id: Uuid, tag: Option<&str>) -> DbResult<Vec<MatchNote>> {
+        let mut conn = self.new_connection().await?;
+
+        let mut query = match_notes
+            .filter(tournament_id.eq(t_id.to_string()))
+            .order(updated_at.desc())
+            .into_boxed::<diesel::sqlite::Sqlite>();
+        if let Some(tag) = tag {
+            // tags are stored as JSON array
+            query = query.filter(
+                sql::<Bool>(
+                    "EXISTS (SELECT 1 FROM json_each(match_notes.tags) WHERE json_each.value = ",
+                )
+                .bind::<Text, _>(tag.to_string())
+                .sql(")"),
+            );
+        }
+
+        let rows = query
+            .load::<DbMatchNote>(&mut conn)
+            .await
+            .map_err(map_db_err)?
+            .into_iter()
+            .map(MatchNote::try_from)
+            .collect::<DbResult<Vec<_>>>()?;
+        info!(count = rows.len(), "list_ok");
+        Ok(rows)
+    }
+}
@@ -0,0 +1,275 @@
+// implementation of postal address port
+
+use crate::{
+    SqliteDb, escape_like, map_db_err, map_version_conflict, parse_uuid,
+    schema::{postal_addresses, postal_addresses::dsl::*},
+};
+use app_core::{
+    DbError, DbResult, DbpPostalAddress, GeoPoint, PostalAddress, PostalAddressSortColumn,
+    utils::{id_version::IdVersion, list_order::ListOrder, traits::ObjectIdVersion},
+};
+use async_trait::async_trait;
+use chrono::{DateTime, Utc};
+use diesel::{
+    dsl::sql,
+    prelude::{
+        AsChangeset, BoolExpressionMethods, EscapeExpressionMethods, ExpressionMethods, Insertable,
+        OptionalExtension, QueryDsl, Queryable, TextExpressionMethods,
+    },
+    sql_types::BigInt,
+};
+use diesel_async::RunQueryDsl;
+use isocountry::CountryCode;
+use tracing::{debug, error, info, instrument, warn};
+use uuid::Uuid;
+
+// ------------------- DB-Row (SELECT/RETURNING) -------------------
+#[derive(Debug, Queryable)]
+pub struct DbPostalAddress {
+    pub id: String,
+    pub version: i64,
+    pub name: String,
+    pub street: String,
+    pub postal_code: String,
+    pub locality: String,
+    pub region: Option<String>,
+    pub country: String,
+    pub created_at: DateTime<Utc>,
+    pub updated_at: DateTime<Utc>,
+    pub latitude: Option<f64>,
+    pub longitude: Option<f64>,
+}
+
+// Mapping DB -> Core
+impl TryFrom<DbPostalAddress> for PostalAddress {
+    type Error = DbError;
+
+    fn try_from(r: DbPostalAddress) -> Result<Self, Self::Error> {
+        let row_id = parse_uuid(&r.id)?;
+        if row_id.is_nil() {
+            return Err(DbError::NilRowId);
+        }
+        if r.version < 0 {
+            return Err(DbError::NegativeRowVersion);
+        }
+        if r.version > u32::MAX as i64 {
+            return Err(DbError::RowVersionOutOfRange);
+        }
+        let id_version = IdVersion::new(row_id, Some(r.version as u32));
+        let country_code = CountryCode::for_alpha2(&r.country)?;
+        let mut pa = PostalAddress::new(id_version);
+        pa.set_name(r.name)
+            .set_street(r.street)
+            .set_postal_code(r.postal_code)
+            .set_locality(r.locality)
+            .set_region(r.region.unwrap_or_default())
+            .set_country(Some(country_code))
+            .set_location(
+                r.latitude
+                    .zip(r.longitude)
+                    .map(|(lat, lon)| GeoPoint::new(lat, lon)),
+            );
+        Ok(pa)
+    }
+}
+
+// ------------------- INSERT / UPDATE -------------------
+#[derive(Debug, Insertable, AsChangeset)]
+#[diesel(table_name = postal_addresses)]
+#[diesel(treat_none_as_null = true)]
+pub struct WriteDbPostalAddress<'a> {
+    pub name: &'a str,
+    pub street: &'a str,
+    pub postal_code: &'a str,
+    pub locality: &'a str,
+    pub region: Option<&'a str>,
+    pub country: &'a str,
+    pub latitude: Option<f64>,
+    pub longitude: Option<f64>,
+}
+
+// Mapping Core -> DB
+impl<'a> From<&'a PostalAddress> for WriteDbPostalAddress<'a> {
+    fn from(p: &'a PostalAddress) -> Self {
+        let country_code = p.get_country().map(|c| c.alpha2()).unwrap_or_default();
+        WriteDbPostalAddress {
+            name: p.get_name(),
+            street: p.get_street(),
+            postal_code: p.get_postal_code(),
+            locality: p.get_locality(),
+            region: p.get_region(),
+            country: country_code,
+            latitude: p.get_location().map(|l| l.latitude),
+            longitude: p.get_location().map(|l| l.longitude),
+        }
+    }
+}
+
+// ------------------- Impl trait --------------------
+
+#[async_trait]
+impl DbpPostalAddress for SqliteDb {
+    #[instrument(name = "db.pa.get", skip(self), fields(id = %pa_id))]
+    async fn get_postal_address(&self, pa_id: Uuid) -> DbResult<Option<PostalAddress>> {
+        let mut conn = self.new_connection().await?;
+        let res = postal_addresses
+            .filter(id.eq(pa_id.to_string()))
+            .first::<DbPostalAddress>(&mut conn)
+            .await
+            .optional()
+            .map_err(map_db_err)?;
+
+        match res {
+            Some(res) => {
+                let res = PostalAddress::try_from(res)?;
+                debug!("found_postal_address");
+                Ok(Some(res))
+            }
+            None => {
+                debug!("postal_address_not_found");
+                Ok(None)
+            }
+        }
+    }
+
+    #[instrument(
+        name = "db.pa.save",
+        skip(self, address),
+        fields(
+            id = ?address.get_id(),
+            version = address.get_version(),
+            is_new = address.get_id_version().is_new()
+        )
+    )]
+    async fn save_postal_address(&self, address: &PostalAddress) -> DbResult<PostalAddress> {
+        let mut conn = self.new_connection().await?;
+        let w = WriteDbPostalAddress::from(address);
+
+        match address.get_id_version() {
+            // Case 1: UPDATE (Optimistic Locking)
+            IdVersion::Existing(inner) => {
+                let res = diesel::update(
+                    postal_addresses.filter(
+                        id.eq(inner.get_id().to_string())
+                            .and(version.eq(inner.get_version() as i64)),
+                    ),
+                )
+                .set((
+                    w,
+                    version.eq(sql::<BigInt>("version + 1")),
+                    updated_at.eq(Utc::now()),
+                ))
+                .returning(postal_addresses::all_columns)
+                .get_result::<DbPostalAddress>(&mut conn)
+                .await;
+
+                match res {
+                    Ok(row) => {
+                        info!(saved_id = %row.id, new_version = row.version, "update_ok");
+                        Ok(row.try_into()?)
+                    }
+                    Err(diesel::result::Error::NotFound) => {
+                        // Distinguish lock conflict from missing row
+                        let current_version = postal_addresses
+                            .filter(id.eq(inner.get_id().to_string()))
+                            .select(version)
+                            .first::<i64>(&mut conn)
+                            .await
+                            .optional()
+                            .map_err(map_db_err)?;
+
+                        if let Some(current_version) = current_version {
+                            warn!(current_version, "optimistic_lock_conflict");
+                            Err(map_version_conflict(current_version))
+                        } else {
+                            warn!("row_missing_on_update");
+                            Err(DbError::NotFound)
+                        }
+                    }
+                    Err(e) => {
+                        error!(error = %e, "update_failed");
+                        Err(map_db_err(e))
+                    }
+                }
+            }
+            // Case 2: INSERT with specific ID
+            IdVersion::NewWithId(new_id) => {
+                let now = Utc::now();
+                let row = diesel::insert_into(postal_addresses)
+                    .values((
+                        id.eq(new_id.to_string()),
+                        created_at.eq(now),
+                        updated_at.eq(now),
+                        w,
+                    ))
+                    .returning(postal_addresses::all_columns)
+                    .get_result::<DbPostalAddress>(&mut conn)
+                    .await
+                    .map_err(map_db_err)?;
+                info!(saved_id = %row.id, "insert_ok");
+                Ok(row.try_into()?)
+            }
+        }
+    }
+
+    #[instrument(
+        name = "db.pa.list",
+        skip(self, name_filter, limit, order),
+        fields(
+            q_len = name_filter.map(|s| s.len()).unwrap_or(0),
+            limit = limit.unwrap_or(10),
+            offset = order.get_offset()
+        )
+    )]
+    async fn list_postal_address_ids(
+        &self,
+        name_filter: Option<&str>,
+        limit: Option<usize>,
+        order: &ListOrder<PostalAddress>,
+    ) -> DbResult<Vec<Uuid>> {
+        let mut conn = self.new_connection().await?;
+
+        let mut query = postal_addresses.into_boxed::<diesel::sqlite::Sqlite>();
+
+        if let Some(f) = name_filter
+            && !f.is_empty()
+        {
+            // like of sqlite is case insensitive for ASCII
+            let pattern = format!("%{}%", escape_like(f));
+            debug!("apply_name_filter");
+            query = query.filter(name.like(pattern).escape('\\'));
+        }
+
+        if let Some(lim) = limit {
+            query = query.limit(lim as i64);
+        }
+        if order.get_offset() > 0 {
+            query = query.offset(order.get_offset() as i64);
+        }
+
+        let desc = order.is_descending();
+        query = match order.get_sort_by() {
+            PostalAddressSortColumn::Name if desc => query.order(name.desc()),
+            PostalAddressSortColumn::Name => query.order(name.asc()),
+            PostalAddressSortColumn::PostalCode if desc => query.order(postal_code.desc()),
+            PostalAddressSortColumn::PostalCode => query.order(postal_code.asc()),
+            PostalAddressSortColumn::Locality if desc => query.order(locality.desc()),
+            PostalAddressSortColumn::Locality => query.order(locality.asc()),
+        };
+        // ties are broken by id, so that pages neither overlap nor miss rows; hyphenated
+        // ids sort like uuids
+        query = query.then_order_by(id.asc());
+
+        let rows = query
+            .select(id)
+            .load::<String>(&mut conn)
+            .await
+            .map_err(map_db_err)?
+            .into_iter()
+            .map(|row_id| parse_uuid(&row_id))
+            .collect::<DbResult<Vec<_>>>()?;
+
+        info!(count = rows.len(), "list_ok");
+        Ok(rows)
+    }
+}
@@ -0,0 +1,378 @@
+// diesel schema of the sqlite port; uuids, JSON and arrays are stored as text, timestamps
+// as RFC 3339 like text (see migrations)
+
+diesel::table! {
+    api_tokens (id) {
+        id -> Text,
+        version -> BigInt,
+        name -> Text,
+        scopes -> Text,
+        secret_prefix -> Text,
+        secret_hash -> Text,
+        rate_limit_per_minute -> Integer,
+        created_at -> TimestamptzSqlite,
+        updated_at -> TimestamptzSqlite,
+        last_used_at -> Nullable<TimestamptzSqlite>,
+        revoked_at -> Nullable<TimestamptzSqlite>,
+    }
+}
+
+diesel::table! {
+    audit_log (id) {
+        id -> Text,
+        version -> BigInt,
+        actor -> Text,
+        action -> Text,
+        object_type -> Text,
+        object_id -> Text,
+        tournament_id -> Nullable<Text>,
+        old_version -> Nullable<BigInt>,
+        new_version -> Nullable<BigInt>,
+        diff -> Text,
+        created_at -> TimestamptzSqlite,
+        updated_at -> TimestamptzSqlite,
+    }
+}
+
+diesel::table! {
+    client_errors (id) {
+        id -> Text,
+        version -> BigInt,
+        component_id -> Nullable<Text>,
+        route -> Text,
+        app_version -> Text,
+        message -> Text,
+        occurrences -> Integer,
+        created_at -> TimestamptzSqlite,
+        updated_at -> TimestamptzSqlite,
+    }
+}
+
+diesel::table! {
+    entrants (id) {
+        id -> Text,
+        version -> BigInt,
+        tournament_id -> Text,
+        name -> Text,
+        club -> Nullable<Text>,
+        seed -> Nullable<Integer>,
+        created_at -> TimestamptzSqlite,
+        updated_at -> TimestamptzSqlite,
+        email -> Nullable<Text>,
+        checked_in_at -> Nullable<TimestamptzSqlite>,
+        waitlist_position -> Nullable<Integer>,
+        no_show -> Bool,
+    }
+}
+
+diesel::table! {
+    feedback (id) {
+        id -> Text,
+        version -> BigInt,
+        message -> Text,
+        route -> Text,
+        tournament_id -> Nullable<Text>,
+        app_version -> Text,
+        recent_errors -> Text,
+        created_at -> TimestamptzSqlite,
+        updated_at -> TimestamptzSqlite,
+    }
+}
+
+diesel::table! {
+    group_seedings (stage_id) {
+        stage_id -> Text,
+        tournament_id -> Text,
+        seeding -> Text,
+        created_at -> TimestamptzSqlite,
+        updated_at -> TimestamptzSqlite,
+    }
+}
+
+diesel::table! {
+    group_standings (group_id) {
+        group_id -> Text,
+        tournament_id -> Text,
+        standings -> Text,
+        computed_at -> TimestamptzSqlite,
+        created_at -> TimestamptzSqlite,
+        updated_at -> TimestamptzSqlite,
+    }
+}
+
+diesel::table! {
+    match_notes (id) {
+        id -> Text,
+        version -> BigInt,
+        tournament_id -> Text,
+        match_id -> Text,
+        tags -> Text,
+        text -> Text,
+        author -> Text,
+        created_at -> TimestamptzSqlite,
+        updated_at -> TimestamptzSqlite,
+    }
+}
+
+diesel::table! {
+    matches (id) {
+        id -> Text,
+        tournament_id -> Text,
+        stage_id -> Text,
+        group_id -> Text,
+        round_id -> Text,
+        number -> Integer,
+        station -> Integer,
+        start_at -> TimestamptzSqlite,
+        data -> Text,
+        created_at -> TimestamptzSqlite,
+        updated_at -> TimestamptzSqlite,
+        version -> BigInt,
+    }
+}
+
+diesel::table! {
+    officials (id) {
+        id -> Text,
+        version -> BigInt,
+        tournament_id -> Text,
+        name -> Text,
+        club -> Nullable<Text>,
+        qualifications -> Text,
+        availability -> Text,
+        created_at -> TimestamptzSqlite,
+        updated_at -> TimestamptzSqlite,
+    }
+}
+
+diesel::table! {
+    pairing_overrides (id) {
+        id -> Text,
+        version -> BigInt,
+        tournament_id -> Text,
+        stage_id -> Text,
+        round_number -> Integer,
+        adjustments -> Text,
+        note -> Text,
+        author -> Text,
+        created_at -> TimestamptzSqlite,
+        updated_at -> TimestamptzSqlite,
+    }
+}
+
+diesel::table! {
+    postal_addresses (id) {
+        id -> Text,
+        version -> BigInt,
+        name -> Text,
+        street -> Text,
+        postal_code -> Text,
+        locality -> Text,
+        region -> Nullable<Text>,
+        country -> Text,
+        created_at -> TimestamptzSqlite,
+        updated_at -> TimestamptzSqlite,
+        latitude -> Nullable<Double>,
+        longitude -> Nullable<Double>,
+    }
+}
+
+diesel::table! {
+    scorekeeper_tokens (id) {
+        id -> Text,
+        version -> BigInt,
+        tournament_id -> Text,
+        label -> Text,
+        grants -> Text,
+        secret_prefix -> Text,
+        secret_hash -> Text,
+        expires_at -> Nullable<TimestamptzSqlite>,
+        created_at -> TimestamptzSqlite,
+        updated_at -> TimestamptzSqlite,
+        last_used_at -> Nullable<TimestamptzSqlite>,
+        revoked_at -> Nullable<TimestamptzSqlite>,
+    }
+}
+
+diesel::table! {
+    shift_log_entries (id) {
+        id -> Text,
+        version -> BigInt,
+        tournament_id -> Text,
+        author -> Text,
+        text -> Text,
+        pinned -> Bool,
+        created_at -> TimestamptzSqlite,
+        updated_at -> TimestamptzSqlite,
+    }
+}
+
+diesel::table! {
+    sport_configs (id) {
+        id -> Text,
+        version -> BigInt,
+        sport_id -> Text,
+        name -> Text,
+        config -> Text,
+        created_at -> TimestamptzSqlite,
+        updated_at -> TimestamptzSqlite,
+        archived_at -> Nullable<TimestamptzSqlite>,
+        plugin_version -> Integer,
+    }
+}
+
+diesel::table! {
+    stage_snapshots (id) {
+        id -> Text,
+        tournament_id -> Text,
+        stage_id -> Text,
+        stage_number -> Integer,
+        matches -> Text,
+        standings -> Text,
+        created_at -> TimestamptzSqlite,
+    }
+}
+
+diesel::table! {
+    stage_groups (id) {
+        id -> Text,
+        version -> BigInt,
+        stage_id -> Text,
+        number -> Integer,
+        data -> Text,
+        created_at -> TimestamptzSqlite,
+        updated_at -> TimestamptzSqlite,
+    }
+}
+
+diesel::table! {
+    stages (id) {
+        id -> Text,
+        version -> BigInt,
+        tournament_id -> Text,
+        number -> Integer,
+        num_groups -> Integer,
+        created_at -> TimestamptzSqlite,
+        updated_at -> TimestamptzSqlite,
+        max_stations -> Nullable<Integer>,
+        auto_advance -> Bool,
+        scoring_override -> Text,
+        latest_end -> Nullable<TimestamptzSqlite>,
+    }
+}
+
+diesel::table! {
+    tournament_bases (id) {
+        id -> Text,
+        version -> BigInt,
+        name -> Text,
+        sport_id -> Text,
+        num_entrants -> Integer,
+        t_type -> Text,
+        mode -> Text,
+        state -> Text,
+        created_at -> TimestamptzSqlite,
+        updated_at -> TimestamptzSqlite,
+        archived_at -> Nullable<TimestamptzSqlite>,
+        num_stations -> Integer,
+        sandbox -> Bool,
+        languages -> Text,
+        description -> Text,
+        stations -> Text,
+        check_in_deadline -> Nullable<TimestamptzSqlite>,
+        no_show_policy -> Text,
+        station_windows -> Text,
+        address_id -> Nullable<Text>,
+        venue_id -> Nullable<Text>,
+        court_call_policy -> Text,
+        sport_config_id -> Nullable<Text>,
+    }
+}
+
+diesel::table! {
+    venues (id) {
+        id -> Text,
+        version -> BigInt,
+        name -> Text,
+        address_id -> Nullable<Text>,
+        courts -> Text,
+        created_at -> TimestamptzSqlite,
+        updated_at -> TimestamptzSqlite,
+    }
+}
+
+diesel::table! {
+    webhook_deliveries (id) {
+        id -> Text,
+        endpoint_id -> Text,
+        event_id -> Text,
+        event_type -> Text,
+        payload -> Text,
+        status_code -> Nullable<Integer>,
+        error -> Nullable<Text>,
+        duration_ms -> Integer,
+        attempted_at -> TimestamptzSqlite,
+    }
+}
+
+diesel::table! {
+    webhook_endpoints (id) {
+        id -> Text,
+        version -> BigInt,
+        name -> Text,
+        url -> Text,
+        secret -> Text,
+        event_types -> Text,
+        active -> Bool,
+        created_at -> TimestamptzSqlite,
+        updated_at -> TimestamptzSqlite,
+        format -> Text,
+    }
+}
+
+diesel::joinable!(entrants -> tournament_bases (tournament_id));
+diesel::joinable!(feedback -> tournament_bases (tournament_id));
+diesel::joinable!(group_seedings -> stages (stage_id));
+diesel::joinable!(group_seedings -> tournament_bases (tournament_id));
+diesel::joinable!(group_standings -> tournament_bases (tournament_id));
+diesel::joinable!(match_notes -> tournament_bases (tournament_id));
+diesel::joinable!(matches -> stages (stage_id));
+diesel::joinable!(matches -> tournament_bases (tournament_id));
+diesel::joinable!(officials -> tournament_bases (tournament_id));
+diesel::joinable!(pairing_overrides -> stages (stage_id));
+diesel::joinable!(pairing_overrides -> tournament_bases (tournament_id));
+diesel::joinable!(scorekeeper_tokens -> tournament_bases (tournament_id));
+diesel::joinable!(shift_log_entries -> tournament_bases (tournament_id));
+diesel::joinable!(stage_groups -> stages (stage_id));
+diesel::joinable!(stage_snapshots -> stages (stage_id));
+diesel::joinable!(stage_snapshots -> tournament_bases (tournament_id));
+diesel::joinable!(stages -> tournament_bases (tournament_id));
+diesel::joinable!(tournament_bases -> sport_configs (sport_config_id));
+diesel::joinable!(tournament_bases -> venues (venue_id));
+diesel::joinable!(venues -> postal_addresses (address_id));
+diesel::joinable!(webhook_deliveries -> webhook_endpoints (endpoint_id));
+
+diesel::allow_tables_to_appear_in_same_query!(
+    api_tokens,
+    audit_log,
+    client_errors,
+    entrants,
+    feedback,
+    group_seedings,
+    group_standings,
+    match_notes,
+    matches,
+    officials,
+    pairing_overrides,
+    postal_addresses,
+    scorekeeper_tokens,
+    shift_log_entries,
+    sport_configs,
+    stage_groups,
+    stage_snapshots,
+    stages,
+    tournament_bases,
+    venues,
+    webhook_deliveries,
+    webhook_endpoints,
+);
@@ -0,0 +1,144 @@
+//! implementation of search port
+
+use crate::{
+    SqliteDb, escape_like, map_db_err, parse_uuid,
+    schema::{entrants, postal_addresses, sport_configs, tournament_bases},
+};
+use app_core::{DbResult, DbpSearch, SearchHit, SearchHitKind};
+use async_trait::async_trait;
+use diesel::prelude::{
+    BoolExpressionMethods, EscapeExpressionMethods, ExpressionMethods, JoinOnDsl, QueryDsl,
+    TextExpressionMethods,
+};
+use diesel_async::RunQueryDsl;
+use tracing::{info, instrument};
+
+#[async_trait]
+impl DbpSearch for SqliteDb {
+    #[instrument(name = "db.search.all", skip(self, term))]
+    async fn search_all(&self, term: &str, limit_per_kind: usize) -> DbResult<Vec<SearchHit>> {
+        let mut conn = self.new_connection().await?;
+        // like of sqlite is case insensitive for ASCII
+        let pattern = format!("%{}%", escape_like(term));
+        let limit = limit_per_kind as i64;
+        let mut hits = Vec::new();
+
+        let tournaments = tournament_bases::table
+            .filter(tournament_bases::name.like(pattern.as_str()).escape('\\'))
+            .filter(tournament_bases::archived_at.is_null())
+            .filter(tournament_bases::sandbox.eq(false))
+            .select((
+                tournament_bases::id,
+                tournament_bases::name,
+                tournament_bases::sport_id,
+            ))
+            .order(tournament_bases::name.asc())
+            .limit(limit)
+            .load::<(String, String, String)>(&mut conn)
+            .await
+            .map_err(map_db_err)?;
+        for (t_id, t_name, s_id) in tournaments {
+            hits.push(SearchHit {
+                kind: SearchHitKind::Tournament,
+                id: parse_uuid(&t_id)?,
+                label: t_name,
+                detail: None,
+                sport_id: Some(parse_uuid(&s_id)?),
+                tournament_id: None,
+            });
+        }
+
+        let entrants = entrants::table
+            .inner_join(
+                tournament_bases::table.on(tournament_bases::id.eq(entrants::tournament_id)),
+            )
+            .filter(
+                entrants::name
+                    .like(pattern.as_str())
+                    .escape('\\')
+                    .or(entrants::club.like(pattern.as_str()).escape('\\')),
+            )
+            .filter(tournament_bases::archived_at.is_null())
+            .filter(tournament_bases::sandbox.eq(false))
+            .select((
+                entrants::id,
+                entrants::name,
+                entrants::club,
+                tournament_bases::id,
+                tournament_bases::sport_id,
+            ))
+            .order(entrants::name.asc())
+            .limit(limit)
+            .load::<(String, String, Option<String>, String, String)>(&mut conn)
+            .await
+            .map_err(map_db_err)?;
+        for (e_id, e_name, club, t_id, s_id) in entrants {
+            hits.push(SearchHit {
+                kind: SearchHitKind::Entrant,
+                id: parse_uuid(&e_id)?,
+                label: e_name,
+                detail: club,
+                sport_id: Some(parse_uuid(&s_id)?),
+                tournament_id: Some(parse_uuid(&t_id)?),
+            });
+        }
+
+        let configs = sport_configs::table
+            .filter(sport_configs::name.like(pattern.as_str()).escape('\\'))
+            .filter(sport_configs::archived_at.is_null())
+            .select((
+                sport_configs::id,
+                sport_configs::name,
+                sport_configs::sport_id,
+            ))
+            .order(sport_configs::name.asc())
+            .limit(limit)
+            .load::<(String, String, String)>(&mut conn)
+            .await
+            .map_err(map_db_err)?;
+        for (c_id, c_name, s_id) in configs {
+            hits.push(SearchHit {
+                kind: SearchHitKind::SportConfig,
+                id: parse_uuid(&c_id)?,
+                label: c_name,
+                detail: None,
+                sport_id: Some(parse_uuid(&s_id)?),
+                tournament_id: None,
+            });
+        }
+
+        let addresses = postal_addresses::table
+            .filter(
+                postal_addresses::name
+                    .like(pattern.as_str())
+                    .escape('\\')
+                    .or(postal_addresses::street.like(pattern.as_str()).escape('\\'))
+                    .or(postal_addresses::locality
+                        .like(pattern.as_str())
+                        .escape('\\')),
+            )
+            .select((
+                postal_addresses::id,
+                postal_addresses::name,
+                postal_addresses::locality,
+            ))
+            .order(postal_addresses::name.asc())
+            .limit(limit)
+            .load::<(String, String, String)>(&mut conn)
+            .await
+            .map_err(map_db_err)?;
+        for (a_id, a_name, a_locality) in addresses {
+            hits.push(SearchHit {
+                kind: SearchHitKind::PostalAddress,
+                id: parse_uuid(&a_id)?,
+                label: a_name,
+                detail: Some(a_locality),
+                sport_id: None,
+                tournament_id: None,
+            });
+        }
+
+        info!(count = hits.len(), "search_ok");
+        Ok(hits)
+    }
+}
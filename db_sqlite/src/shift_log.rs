@@ -0,0 +1,228 @@
+//! implementation of shift log port
+
+use crate::{
+    SqliteDb, map_db_err, parse_uuid,
+    schema::{shift_log_entries, shift_log_entries::dsl::*},
+};
+use app_core::{
+    DbError, DbResult, DbpShiftLog, ShiftLogEntry,
+    utils::{id_version::IdVersion, traits::ObjectIdVersion},
+};
+use async_trait::async_trait;
+use chrono::{DateTime, Utc};
+use diesel::{
+    dsl::sql,
+    prelude::{
+        AsChangeset, BoolExpressionMethods, ExpressionMethods, Insertable, OptionalExtension,
+        QueryDsl, Queryable,
+    },
+    sql_types::BigInt,
+};
+use diesel_async::RunQueryDsl;
+use tracing::{debug, error, info, instrument, warn};
+use uuid::Uuid;
+
+// ------------------- DB-Row (SELECT/RETURNING) -------------------
+#[derive(Debug, Queryable)]
+pub struct DbShiftLogEntry {
+    pub id: String,
+    pub version: i64,
+    pub tournament_id: String,
+    pub author: String,
+    pub text: String,
+    pub pinned: bool,
+    pub created_at: DateTime<Utc>,
+    pub updated_at: DateTime<Utc>,
+}
+
+// Mapping DB -> Core
+impl TryFrom<DbShiftLogEntry> for ShiftLogEntry {
+    type Error = DbError;
+
+    fn try_from(r: DbShiftLogEntry) -> Result<Self, Self::Error> {
+        let row_id = parse_uuid(&r.id)?;
+        if row_id.is_nil() {
+            return Err(DbError::NilRowId);
+        }
+        if r.version < 0 {
+            return Err(DbError::NegativeRowVersion);
+        }
+        if r.version > u32::MAX as i64 {
+            return Err(DbError::RowVersionOutOfRange);
+        }
+
+        let id_version = IdVersion::new(row_id, Some(r.version as u32));
+        let mut e = ShiftLogEntry::new(id_version);
+
+        e.set_tournament_id(parse_uuid(&r.tournament_id)?)
+            .set_author(r.author)
+            .set_text(r.text)
+            .set_pinned(r.pinned)
+            .set_created_at(Some(r.created_at));
+
+        Ok(e)
+    }
+}
+
+// ------------------- INSERT / UPDATE -------------------
+#[derive(Debug, Insertable, AsChangeset)]
+#[diesel(table_name = shift_log_entries)]
+pub struct WriteDbShiftLogEntry {
+    pub tournament_id: String,
+    pub author: String,
+    pub text: String,
+    pub pinned: bool,
+}
+
+// Mapping Core -> DB
+impl<'a> TryFrom<&'a ShiftLogEntry> for WriteDbShiftLogEntry {
+    type Error = DbError;
+
+    fn try_from(e: &'a ShiftLogEntry) -> Result<Self, Self::Error> {
+        Ok(WriteDbShiftLogEntry {
+            tournament_id: e.get_tournament_id().to_string(),
+            author: e.get_author().to_string(),
+            text: e.get_text().to_string(),
+            pinned: e.is_pinned(),
+        })
+    }
+}
+
+// ------------------- Impl trait --------------------
+
+#[async_trait]
+impl DbpShiftLog for SqliteDb {
+    #[instrument(name = "db.shift_log.get", skip(self), fields(id = %entry_id))]
+    async fn get_shift_log_entry(&self, entry_id: Uuid) -> DbResult<Option<ShiftLogEntry>> {
+        let mut conn = self.new_connection().await?;
+        let res = shift_log_entries
+            .filter(id.eq(entry_id.to_string()))
+            .first::<DbShiftLogEntry>(&mut conn)
+            .await
+            .optional()
+            .map_err(map_db_err)?;
+
+        match res {
+            Some(res) => {
+                let res = ShiftLogEntry::try_from(res)?;
+                debug!("found_shift_log_entry");
+                Ok(Some(res))
+            }
+            None => {
+                debug!("shift_log_entry_not_found");
+                Ok(None)
+            }
+        }
+    }
+
+    #[instrument(
+        name = "db.shift_log.save",
+        skip(self, entry),
+        fields(
+            id = ?entry.get_id(),
+            version = entry.get_version(),
+            is_new = entry.get_id_version().is_new()
+        )
+    )]
+    async fn save_shift_log_entry(&self, entry: &ShiftLogEntry) -> DbResult<ShiftLogEntry> {
+        let mut conn = self.new_connection().await?;
+        let w = WriteDbShiftLogEntry::try_from(entry)?;
+
+        match entry.get_id_version() {
+            // Case 1: UPDATE (Optimistic Locking)
+            IdVersion::Existing(inner) => {
+                let res = diesel::update(
+                    shift_log_entries.filter(
+                        id.eq(inner.get_id().to_string())
+                            .and(version.eq(inner.get_version() as i64)),
+                    ),
+                )
+                .set((
+                    w,
+                    version.eq(sql::<BigInt>("version + 1")),
+                    updated_at.eq(Utc::now()),
+                ))
+                .returning(shift_log_entries::all_columns)
+                .get_result::<DbShiftLogEntry>(&mut conn)
+                .await;
+
+                match res {
+                    Ok(row) => {
+                        info!(saved_id = %row.id, new_version = row.version, "update_ok");
+                        Ok(row.try_into()?)
+                    }
+                    Err(diesel::result::Error::NotFound) => {
+                        // Check if it exists but version mismatch
+                        let exists = diesel::select(diesel::dsl::exists(
+                            shift_log_entries.filter(id.eq(inner.get_id().to_string())),
+                        ))
+                        .get_result::<bool>(&mut conn)
+                        .await
+                        .map_err(map_db_err)?;
+
+                        if exists {
+                            warn!("optimistic_lock_conflict");
+                            Err(DbError::OptimisticLockConflict)
+                        } else {
+                            warn!("row_missing_on_update");
+                            Err(DbError::NotFound)
+                        }
+                    }
+                    Err(e) => {
+                        error!(error = %e, "update_failed");
+                        Err(map_db_err(e))
+                    }
+                }
+            }
+            // Case 2: INSERT with specific ID
+            IdVersion::NewWithId(new_id) => {
+                let now = Utc::now();
+                let row = diesel::insert_into(shift_log_entries)
+                    .values((
+                        id.eq(new_id.to_string()),
+                        created_at.eq(now),
+                        updated_at.eq(now),
+                        w,
+                    ))
+                    .returning(shift_log_entries::all_columns)
+                    .get_result::<DbShiftLogEntry>(&mut conn)
+                    .await
+                    .map_err(map_db_err)?;
+
+                info!(saved_id = %row.id, "insert_ok");
+                Ok(row.try_into()?)
+            }
+        }
+    }
+
+    #[instrument(name = "db.shift_log.list", skip(self, t_id))]
+    async fn list_shift_log_entries(
+        &self,
+        t_id: Uuid,
+        pinned_only: bool,
+        limit: Option<usize>,
+    ) -> DbResult<Vec<ShiftLogEntry>> {
+        let mut conn = self.new_connection().await?;
+
+        let mut query = shift_log_entries
+            .filter(tournament_id.eq(t_id.to_string()))
+            .order((pinned.desc(), created_at.desc()))
+            .into_boxed::<diesel::sqlite::Sqlite>();
+        if pinned_only {
+            query = query.filter(pinned.eq(true));
+        }
+        if let Some(lim) = limit {
+            query = query.limit(lim as i64);
+        }
+
+        let rows = query
+            .load::<DbShiftLogEntry>(&mut conn)
+            .await
+            .map_err(map_db_err)?
+            .into_iter()
+            .map(ShiftLogEntry::try_from)
+            .collect::<DbResult<Vec<_>>>()?;
+        info!(count = rows.len(), "list_ok");
+        Ok(rows)
+    }
+}
@@ -0,0 +1,307 @@
+// implementation of sport config port
+
+use crate::{
+    SqliteDb, escape_like, map_db_err, map_version_conflict, parse_uuid,
+    schema::{sport_configs, sport_configs::dsl::*},
+};
+use app_core::{
+    DbError, DbResult, DbpSportConfig, SportConfig, SportConfigSortColumn,
+    utils::{id_version::IdVersion, list_order::ListOrder, traits::ObjectIdVersion},
+};
+use async_trait::async_trait;
+use chrono::{DateTime, Utc};
+use diesel::{
+    dsl::sql,
+    prelude::{
+        AsChangeset, BoolExpressionMethods, EscapeExpressionMethods, ExpressionMethods, Insertable,
+        OptionalExtension, QueryDsl, Queryable, TextExpressionMethods,
+    },
+    sql_types::BigInt,
+};
+use diesel_async::RunQueryDsl;
+use tracing::{debug, error, info, instrument, warn};
+use uuid::Uuid;
+
+// ------------------- DB-Row (SELECT/RETURNING) -------------------
+#[derive(Debug, Queryable)]
+pub struct DbSportConfig {
+    pub id: String,
+    pub version: i64,
+    pub sport_id: String,
+    pub name: String,
+    pub config: String,
+    pub created_at: DateTime<Utc>,
+    pub updated_at: DateTime<Utc>,
+    pub archived_at: Option<DateTime<Utc>>,
+    pub plugin_version: i32,
+}
+
+// Mapping DB -> Core
+impl TryFrom<DbSportConfig> for SportConfig {
+    type Error = DbError;
+
+    fn try_from(r: DbSportConfig) -> Result<Self, Self::Error> {
+        let row_id = parse_uuid(&r.id)?;
+        if row_id.is_nil() {
+            return Err(DbError::NilRowId);
+        }
+        if r.version < 0 {
+            return Err(DbError::NegativeRowVersion);
+        }
+        if r.version > u32::MAX as i64 {
+            return Err(DbError::RowVersionOutOfRange);
+        }
+        let config_from_json: serde_json::Value = serde_json::from_str(&r.config)
+            .map_err(|e| DbError::Other(format!("Failed to deserialize config: {e}")))?;
+
+        let id_version = IdVersion::new(row_id, Some(r.version as u32));
+        let mut sc = SportConfig::new(id_version);
+        sc.set_sport_id(parse_uuid(&r.sport_id)?)
+            .set_name(r.name)
+            .set_config(config_from_json)
+            .set_archived_at(r.archived_at)
+            .set_plugin_version(r.plugin_version as u32);
+        Ok(sc)
+    }
+}
+
+// ------------------- INSERT / UPDATE -------------------
+#[derive(Debug, Insertable, AsChangeset)]
+#[diesel(table_name = sport_configs)]
+pub struct WriteDbSportConfig<'a> {
+    pub sport_id: String,
+    pub name: &'a str,
+    pub config: String,
+    pub plugin_version: i32,
+}
+
+// Mapping Core -> DB
+impl<'a> TryFrom<&'a SportConfig> for WriteDbSportConfig<'a> {
+    type Error = DbError;
+
+    fn try_from(sc: &'a SportConfig) -> Result<Self, Self::Error> {
+        Ok(WriteDbSportConfig {
+            sport_id: sc.get_sport_id().to_string(),
+            name: sc.get_name(),
+            config: serde_json::to_string(sc.get_config())
+                .map_err(|e| DbError::Other(format!("Failed to serialize config: {e}")))?,
+            plugin_version: sc.get_plugin_version() as i32,
+        })
+    }
+}
+
+// ------------------- Impl trait --------------------
+
+#[async_trait]
+impl DbpSportConfig for SqliteDb {
+    #[instrument(name = "db.sc.get", skip(self), fields(id = %sc_id))]
+    async fn get_sport_config(&self, sc_id: Uuid) -> DbResult<Option<SportConfig>> {
+        let mut conn = self.new_connection().await?;
+        let res = sport_configs
+            .filter(id.eq(sc_id.to_string()))
+            .first::<DbSportConfig>(&mut conn)
+            .await
+            .optional()
+            .map_err(map_db_err)?;
+
+        match res {
+            Some(res) => {
+                let res = SportConfig::try_from(res)?;
+                debug!("found_sport_config");
+                Ok(Some(res))
+            }
+            None => {
+                debug!("sport_config_not_found");
+                Ok(None)
+            }
+        }
+    }
+
+    #[instrument(
+        name = "db.sc.save",
+        skip(self, sport_config),
+        fields(
+            id = ?sport_config.get_id(),
+            version = sport_config.get_version(),
+            is_new = sport_config.get_id_version().is_new()
+        )
+    )]
+    async fn save_sport_config(&self, sport_config: &SportConfig) -> DbResult<SportConfig> {
+        let mut conn = self.new_connection().await?;
+        let w = WriteDbSportConfig::try_from(sport_config)?;
+
+        match sport_config.get_id_version() {
+            // Case 1: UPDATE (Optimistic Locking)
+            IdVersion::Existing(inner) => {
+                let res = diesel::update(
+                    sport_configs.filter(
+                        id.eq(inner.get_id().to_string())
+                            .and(version.eq(inner.get_version() as i64)),
+                    ),
+                )
+                .set((
+                    w,
+                    version.eq(sql::<BigInt>("version + 1")),
+                    updated_at.eq(Utc::now()),
+                ))
+                .returning(sport_configs::all_columns)
+                .get_result::<DbSportConfig>(&mut conn)
+                .await;
+
+                match res {
+                    Ok(row) => {
+                        info!(saved_id = %row.id, new_version = row.version, "update_ok");
+                        Ok(row.try_into()?)
+                    }
+                    Err(diesel::result::Error::NotFound) => {
+                        // Distinguish lock conflict from missing row
+                        let current_version = sport_configs
+                            .filter(id.eq(inner.get_id().to_string()))
+                            .select(version)
+                            .first::<i64>(&mut conn)
+                            .await
+                            .optional()
+                            .map_err(map_db_err)?;
+
+                        if let Some(current_version) = current_version {
+                            warn!(current_version, "optimistic_lock_conflict");
+                            Err(map_version_conflict(current_version))
+                        } else {
+                            warn!("row_missing_on_update");
+                            Err(DbError::NotFound)
+                        }
+                    }
+                    Err(e) => {
+                        error!(error = %e, "update_failed");
+                        Err(map_db_err(e))
+                    }
+                }
+            }
+            // Case 2: INSERT with specific ID
+            IdVersion::NewWithId(new_id) => {
+                let now = Utc::now();
+                let row = diesel::insert_into(sport_configs)
+                    .values((
+                        id.eq(new_id.to_string()),
+                        created_at.eq(now),
+                        updated_at.eq(now),
+                        w,
+                    ))
+                    .returning(sport_configs::all_columns)
+                    .get_result::<DbSportConfig>(&mut conn)
+                    .await
+                    .map_err(map_db_err)?;
+                info!(saved_id = %row.id, "insert_ok");
+                Ok(row.try_into()?)
+            }
+        }
+    }
+
+    #[instrument(name = "db.sc.archive", skip(self), fields(id = %sc_id))]
+    async fn archive_sport_config(&self, sc_id: Uuid, sc_version: u32) -> DbResult<SportConfig> {
+        let mut conn = self.new_connection().await?;
+        let now = Utc::now();
+        let res = diesel::update(
+            sport_configs.filter(id.eq(sc_id.to_string()).and(version.eq(sc_version as i64))),
+        )
+        .set((
+            archived_at.eq(now),
+            version.eq(sql::<BigInt>("version + 1")),
+            updated_at.eq(now),
+        ))
+        .returning(sport_configs::all_columns)
+        .get_result::<DbSportConfig>(&mut conn)
+        .await;
+
+        match res {
+            Ok(row) => {
+                info!(archived_id = %row.id, new_version = row.version, "archive_ok");
+                Ok(row.try_into()?)
+            }
+            Err(diesel::result::Error::NotFound) => {
+                // Distinguish lock conflict from missing row
+                let current_version = sport_configs
+                    .filter(id.eq(sc_id.to_string()))
+                    .select(version)
+                    .first::<i64>(&mut conn)
+                    .await
+                    .optional()
+                    .map_err(map_db_err)?;
+
+                if let Some(current_version) = current_version {
+                    warn!(current_version, "optimistic_lock_conflict");
+                    Err(map_version_conflict(current_version))
+                } else {
+                    warn!("row_missing_on_archive");
+                    Err(DbError::NotFound)
+                }
+            }
+            Err(e) => {
+                error!(error = %e, "archive_failed");
+                Err(map_db_err(e))
+            }
+        }
+    }
+
+    #[instrument(
+        name = "db.sc.list",
+        skip(self, name_filter, limit, order),
+        fields(offset = order.get_offset())
+    )]
+    async fn list_sport_config_ids(
+        &self,
+        sport: Uuid,
+        name_filter: Option<&str>,
+        include_archived: bool,
+        limit: Option<usize>,
+        order: &ListOrder<SportConfig>,
+    ) -> DbResult<Vec<Uuid>> {
+        let mut conn = self.new_connection().await?;
+        let mut query = sport_configs.into_boxed::<diesel::sqlite::Sqlite>();
+
+        query = query.filter(sport_id.eq(sport.to_string()));
+
+        if !include_archived {
+            debug!("excluding_archived_sport_configs");
+            query = query.filter(archived_at.is_null());
+        }
+
+        if let Some(f) = name_filter
+            && !f.is_empty()
+        {
+            // like of sqlite is case insensitive for ASCII
+            let pattern = format!("%{}%", escape_like(f));
+            debug!("apply_name_filter");
+            query = query.filter(name.like(pattern).escape('\\'));
+        }
+
+        if let Some(lim) = limit {
+            query = query.limit(lim as i64);
+        }
+        if order.get_offset() > 0 {
+            query = query.offset(order.get_offset() as i64);
+        }
+
+        let desc = order.is_descending();
+        query = match order.get_sort_by() {
+            SportConfigSortColumn::Name if desc => query.order(name.desc()),
+            SportConfigSortColumn::Name => query.order(name.asc()),
+            SportConfigSortColumn::PluginVersion if desc => query.order(plugin_version.desc()),
+            SportConfigSortColumn::PluginVersion => query.order(plugin_version.asc()),
+        };
+        // ties are broken by id, so that pages neither overlap nor miss rows
+        query = query.then_order_by(id.asc());
+
+        let rows = query
+            .select(id)
+            .load::<String>(&mut conn)
+            .await
+            .map_err(map_db_err)?
+            .into_iter()
+            .map(|row_id| parse_uuid(&row_id))
+            .collect::<DbResult<Vec<_>>>()?;
+
+        info!(count = rows.len(), "list_ok");
+        Ok(rows)
+    }
+}
@@ -0,0 +1,268 @@
+//! implementation of stage port
+
+use crate::{
+    SqliteConn, SqliteDb, map_db_err, parse_uuid,
+    schema::{stages, stages::dsl::*},
+};
+use app_core::{
+    DbError, DbResult, DbpStage, ScoringOverride, Stage,
+    utils::{id_version::IdVersion, traits::ObjectIdVersion},
+};
+use async_trait::async_trait;
+use chrono::{DateTime, Utc};
+use diesel::{
+    dsl::sql,
+    prelude::{
+        AsChangeset, BoolExpressionMethods, ExpressionMethods, Insertable, OptionalExtension,
+        QueryDsl, Queryable,
+    },
+    sql_types::BigInt,
+};
+use diesel_async::RunQueryDsl;
+use tracing::{debug, error, info, instrument, warn};
+use uuid::Uuid;
+
+// ------------------- DB-Row (SELECT/RETURNING) -------------------
+#[derive(Debug, Queryable)]
+pub struct DbStage {
+    pub id: String,
+    pub version: i64,
+    pub tournament_id: String,
+    pub number: i32,
+    pub num_groups: i32,
+    pub created_at: DateTime<Utc>,
+    pub updated_at: DateTime<Utc>,
+    pub max_stations: Option<i32>,
+    pub auto_advance: bool,
+    pub scoring_override: String,
+    pub latest_end: Option<DateTime<Utc>>,
+}
+
+// Mapping DB -> Core
+impl TryFrom<DbStage> for Stage {
+    type Error = DbError;
+
+    fn try_from(r: DbStage) -> Result<Self, Self::Error> {
+        let row_id = parse_uuid(&r.id)?;
+        if row_id.is_nil() {
+            return Err(DbError::NilRowId);
+        }
+        if r.version < 0 {
+            return Err(DbError::NegativeRowVersion);
+        }
+        if r.version > u32::MAX as i64 {
+            return Err(DbError::RowVersionOutOfRange);
+        }
+
+        let scoring_override_from_json: ScoringOverride = serde_json::from_str(&r.scoring_override)
+            .map_err(|e| DbError::Other(format!("Failed to deserialize scoring_override: {e}")))?;
+
+        let id_version = IdVersion::new(row_id, Some(r.version as u32));
+        let mut s = Stage::new(id_version);
+
+        s.set_tournament_id(parse_uuid(&r.tournament_id)?)
+            .set_number(r.number as u32)
+            .set_num_groups(r.num_groups as u32)
+            .set_max_stations(r.max_stations.map(|m| m as u32))
+            .set_auto_advance(r.auto_advance)
+            .set_scoring_override(scoring_override_from_json)
+            .set_latest_end(r.latest_end);
+
+        Ok(s)
+    }
+}
+
+// ------------------- INSERT / UPDATE -------------------
+#[derive(Debug, Insertable, AsChangeset)]
+#[diesel(table_name = stages)]
+// write NULL for removed station limit and time cap
+#[diesel(treat_none_as_null = true)]
+pub struct WriteDbStage {
+    pub tournament_id: String,
+    pub number: i32,
+    pub num_groups: i32,
+    pub max_stations: Option<i32>,
+    pub auto_advance: bool,
+    pub scoring_override: String,
+    pub latest_end: Option<DateTime<Utc>>,
+}
+
+// Mapping Core -> DB
+impl<'a> TryFrom<&'a Stage> for WriteDbStage {
+    type Error = DbError;
+
+    fn try_from(s: &'a Stage) -> Result<Self, Self::Error> {
+        Ok(WriteDbStage {
+            tournament_id: s.get_tournament_id().to_string(),
+            number: s.get_number() as i32,
+            num_groups: s.get_num_groups() as i32,
+            max_stations: s.get_max_stations().map(|m| m as i32),
+            auto_advance: s.is_auto_advance(),
+            scoring_override: serde_json::to_string(&s.get_scoring_override()).map_err(|e| {
+                DbError::Other(format!("Failed to serialize scoring_override: {e}"))
+            })?,
+            latest_end: s.get_latest_end(),
+        })
+    }
+}
+
+// ------------------- Impl trait --------------------
+
+#[async_trait]
+impl DbpStage for SqliteDb {
+    #[instrument(name = "db.stage.get_id", skip(self), fields(id = %stage_id))]
+    async fn get_stage_by_id(&self, stage_id: Uuid) -> DbResult<Option<Stage>> {
+        let mut conn = self.new_connection().await?;
+        let res = stages
+            .filter(id.eq(stage_id.to_string()))
+            .first::<DbStage>(&mut conn)
+            .await
+            .optional()
+            .map_err(map_db_err)?;
+
+        match res {
+            Some(res) => {
+                let res = Stage::try_from(res)?;
+                debug!("found_stage");
+                Ok(Some(res))
+            }
+            None => {
+                debug!("stage_not_found");
+                Ok(None)
+            }
+        }
+    }
+
+    #[instrument(name = "db.stage.get_num", skip(self), fields(tid = %t_id, num = %num))]
+    async fn get_stage_by_number(&self, t_id: Uuid, num: u32) -> DbResult<Option<Stage>> {
+        let mut conn = self.new_connection().await?;
+        let res = stages
+            .filter(tournament_id.eq(t_id.to_string()))
+            .filter(number.eq(num as i32))
+            .first::<DbStage>(&mut conn)
+            .await
+            .optional()
+            .map_err(map_db_err)?;
+
+        match res {
+            Some(res) => {
+                let res = Stage::try_from(res)?;
+                debug!("found_stage_by_number");
+                Ok(Some(res))
+            }
+            None => {
+                debug!("stage_by_number_not_found");
+                Ok(None)
+            }
+        }
+    }
+
+    #[instrument(
+        name = "db.stage.save",
+        skip(self, stage),
+        fields(
+            id = ?stage.get_id(),
+            version = stage.get_version(),
+            is_new = stage.get_id_version().is_new()
+        )
+    )]
+    async fn save_stage(&self, stage: &Stage) -> DbResult<Stage> {
+        let mut conn = self.new_connection().await?;
+        write_stage(&mut conn, stage).await
+    }
+
+    #[instrument(name = "db.stage.list", skip(self, t_id))]
+    async fn list_stage_ids_of_tournament(
+        &self,
+        t_id: Uuid,
+        number_of_stages: u32,
+    ) -> DbResult<Vec<(Uuid, u32)>> {
+        let mut conn = self.new_connection().await?;
+
+        let rows = stages
+            .filter(tournament_id.eq(t_id.to_string()))
+            .filter(number.lt(number_of_stages as i32))
+            .select((id, number))
+            .order(number.asc())
+            .load::<(String, i32)>(&mut conn)
+            .await
+            .map_err(map_db_err)?
+            .into_iter()
+            .map(|(stage_id, stage_number)| Ok((parse_uuid(&stage_id)?, stage_number as u32)))
+            .collect::<DbResult<Vec<_>>>()?;
+        info!(count = rows.len(), "list_ok");
+        Ok(rows)
+    }
+}
+
+/// insert or update stage with optimistic locking on `conn`; used by single saves and by
+/// saves of several objects in one transaction
+pub(crate) async fn write_stage(conn: &mut SqliteConn, stage: &Stage) -> DbResult<Stage> {
+    let w = WriteDbStage::try_from(stage)?;
+
+    match stage.get_id_version() {
+        // Case 1: UPDATE (Optimistic Locking)
+        IdVersion::Existing(inner) => {
+            let res = diesel::update(
+                stages.filter(
+                    id.eq(inner.get_id().to_string())
+                        .and(version.eq(inner.get_version() as i64)),
+                ),
+            )
+            .set((
+                w,
+                version.eq(sql::<BigInt>("version + 1")),
+                updated_at.eq(Utc::now()),
+            ))
+            .returning(stages::all_columns)
+            .get_result::<DbStage>(conn)
+            .await;
+
+            match res {
+                Ok(row) => {
+                    info!(saved_id = %row.id, new_version = row.version, "update_ok");
+                    Ok(row.try_into()?)
+                }
+                Err(diesel::result::Error::NotFound) => {
+                    // Check if it exists but version mismatch
+                    let exists = diesel::select(diesel::dsl::exists(
+                        stages.filter(id.eq(inner.get_id().to_string())),
+                    ))
+                    .get_result::<bool>(conn)
+                    .await
+                    .map_err(map_db_err)?;
+
+                    if exists {
+                        warn!("optimistic_lock_conflict");
+                        Err(DbError::OptimisticLockConflict)
+                    } else {
+                        warn!("row_missing_on_update");
+                        Err(DbError::NotFound)
+                    }
+                }
+                Err(e) => {
+                    error!(error = %e, "update_failed");
+                    Err(map_db_err(e))
+                }
+            }
+        }
+        // Case 2: INSERT with specific ID (e.g. Migration/Cloning)
+        IdVersion::NewWithId(new_id) => {
+            let now = Utc::now();
+            let row = diesel::insert_into(stages)
+                .values((
+                    id.eq(new_id.to_string()),
+                    created_at.eq(now),
+                    updated_at.eq(now),
+                    w,
+                ))
+                .returning(stages::all_columns)
+                .get_result::<DbStage>(conn)
+                .await
+                .map_err(map_db_err)?;
+
+            info!(saved_id = %row.id, "insert_ok");
+            Ok(row.try_into()?)
+        }
+    }
+}
@@ -0,0 +1,590 @@
+//! implementation of tournament base port
+
+use crate::{
+    SqliteConn, SqliteDb, escape_like,
+    group::write_group,
+    map_db_err, map_version_conflict, parse_uuid,
+    schema::{tournament_bases, tournament_bases::dsl::*},
+    stage::write_stage,
+};
+use app_core::{
+    CourtCallPolicy, DbBatchError, DbBatchResult, DbError, DbResult, DbpTournamentBase, Group,
+    Language, LocalizedText, NoShowPolicy, Stage, Station, StationWindow, TournamentBase,
+    TournamentBaseCondition, TournamentBaseSortColumn, TournamentMode, TournamentState,
+    TournamentType,
+    utils::{
+        filter::Filter, id_version::IdVersion, list_order::ListOrder, traits::ObjectIdVersion,
+    },
+};
+use async_trait::async_trait;
+use chrono::{DateTime, Utc};
+use diesel::{
+    dsl::sql,
+    prelude::{
+        AsChangeset, BoolExpressionMethods, EscapeExpressionMethods, ExpressionMethods, Insertable,
+        OptionalExtension, QueryDsl, Queryable, TextExpressionMethods,
+    },
+    sql_types::BigInt,
+};
+use diesel_async::{AsyncConnection, RunQueryDsl, scoped_futures::ScopedFutureExt};
+use tracing::{debug, error, info, instrument, warn};
+use uuid::Uuid;
+
+// ------------------- DB-Row (SELECT/RETURNING) -------------------
+#[derive(Debug, Queryable)]
+pub struct DbTournamentBase {
+    pub id: String,
+    pub version: i64,
+    pub name: String,
+    pub sport_id: String,
+    pub num_entrants: i32,
+    pub t_type: String,
+    pub mode: String,
+    pub state: String,
+    pub created_at: DateTime<Utc>,
+    pub updated_at: DateTime<Utc>,
+    pub archived_at: Option<DateTime<Utc>>,
+    pub num_stations: i32,
+    pub sandbox: bool,
+    pub languages: String,
+    pub description: String,
+    pub stations: String,
+    pub check_in_deadline: Option<DateTime<Utc>>,
+    pub no_show_policy: String,
+    pub station_windows: String,
+    pub address_id: Option<String>,
+    pub venue_id: Option<String>,
+    pub court_call_policy: String,
+    pub sport_config_id: Option<String>,
+}
+
+// Mapping DB -> Core
+impl TryFrom<DbTournamentBase> for TournamentBase {
+    type Error = DbError;
+
+    fn try_from(r: DbTournamentBase) -> Result<Self, Self::Error> {
+        let row_id = parse_uuid(&r.id)?;
+        if row_id.is_nil() {
+            return Err(DbError::NilRowId);
+        }
+        if r.version < 0 {
+            return Err(DbError::NegativeRowVersion);
+        }
+        if r.version > u32::MAX as i64 {
+            return Err(DbError::RowVersionOutOfRange);
+        }
+
+        let t_type_from_json: TournamentType = serde_json::from_str(&r.t_type)
+            .map_err(|e| DbError::Other(format!("Failed to deserialize t_type: {e}")))?;
+        let mode_from_json: TournamentMode = serde_json::from_str(&r.mode)
+            .map_err(|e| DbError::Other(format!("Failed to deserialize mode: {e}")))?;
+        let state_from_json: TournamentState = serde_json::from_str(&r.state)
+            .map_err(|e| DbError::Other(format!("Failed to deserialize state: {e}")))?;
+        let languages_from_json: Vec<Language> = serde_json::from_str(&r.languages)
+            .map_err(|e| DbError::Other(format!("Failed to deserialize languages: {e}")))?;
+        let description_from_json: LocalizedText = serde_json::from_str(&r.description)
+            .map_err(|e| DbError::Other(format!("Failed to deserialize description: {e}")))?;
+        let stations_from_json: Vec<Station> = serde_json::from_str(&r.stations)
+            .map_err(|e| DbError::Other(format!("Failed to deserialize stations: {e}")))?;
+        let no_show_policy_from_json: NoShowPolicy = serde_json::from_str(&r.no_show_policy)
+            .map_err(|e| DbError::Other(format!("Failed to deserialize no_show_policy: {e}")))?;
+        let station_windows_from_json: Vec<StationWindow> =
+            serde_json::from_str(&r.station_windows).map_err(|e| {
+                DbError::Other(format!("Failed to deserialize station_windows: {e}"))
+            })?;
+        let court_call_policy_from_json: CourtCallPolicy =
+            serde_json::from_str(&r.court_call_policy).map_err(|e| {
+                DbError::Other(format!("Failed to deserialize court_call_policy: {e}"))
+            })?;
+
+        let id_version = IdVersion::new(row_id, Some(r.version as u32));
+        let mut tb = TournamentBase::new(id_version);
+
+        tb.set_name(r.name)
+            .set_sport_id(parse_uuid(&r.sport_id)?)
+            .set_num_entrants(r.num_entrants as u32)
+            // stations first, since number of stations may exceed number of named stations
+            .set_stations(stations_from_json)
+            .set_num_stations(r.num_stations as u32)
+            .set_tournament_type(t_type_from_json)
+            .set_tournament_mode(mode_from_json)
+            .set_tournament_state(state_from_json)
+            .set_archived_at(r.archived_at)
+            .set_sandbox(r.sandbox)
+            .set_languages(languages_from_json)
+            .set_description(description_from_json)
+            .set_check_in_deadline(r.check_in_deadline)
+            .set_no_show_policy(no_show_policy_from_json)
+            .set_station_windows(station_windows_from_json)
+            .set_address_id(r.address_id.as_deref().map(parse_uuid).transpose()?)
+            .set_venue_id(r.venue_id.as_deref().map(parse_uuid).transpose()?)
+            .set_court_call_policy(court_call_policy_from_json)
+            .set_sport_config_id(r.sport_config_id.as_deref().map(parse_uuid).transpose()?)
+            .set_created_at(Some(r.created_at));
+
+        Ok(tb)
+    }
+}
+
+// ------------------- INSERT / UPDATE -------------------
+#[derive(Debug, Insertable, AsChangeset)]
+#[diesel(table_name = tournament_bases)]
+// write NULL for removed check-in deadline
+#[diesel(treat_none_as_null = true)]
+pub struct WriteDbTournamentBase<'a> {
+    pub name: &'a str,
+    pub sport_id: String,
+    pub num_entrants: i32,
+    pub num_stations: i32,
+    pub t_type: String,
+    pub mode: String,
+    pub state: String,
+    pub sandbox: bool,
+    pub languages: String,
+    pub description: String,
+    pub stations: String,
+    pub check_in_deadline: Option<DateTime<Utc>>,
+    pub no_show_policy: String,
+    pub station_windows: String,
+    pub address_id: Option<String>,
+    pub venue_id: Option<String>,
+    pub court_call_policy: String,
+    pub sport_config_id: Option<String>,
+}
+
+// Mapping Core -> DB
+impl<'a> TryFrom<&'a TournamentBase> for WriteDbTournamentBase<'a> {
+    type Error = DbError;
+
+    fn try_from(tb: &'a TournamentBase) -> Result<Self, Self::Error> {
+        Ok(WriteDbTournamentBase {
+            name: tb.get_name(),
+            sport_id: tb.get_sport_id().to_string(),
+            num_entrants: tb.get_num_entrants() as i32,
+            num_stations: tb.get_num_stations() as i32,
+            t_type: serde_json::to_string(&tb.get_tournament_type())
+                .map_err(|e| DbError::Other(format!("Failed to serialize t_type: {e}")))?,
+            mode: serde_json::to_string(&tb.get_tournament_mode())
+                .map_err(|e| DbError::Other(format!("Failed to serialize mode: {e}")))?,
+            state: serde_json::to_string(&tb.get_tournament_state())
+                .map_err(|e| DbError::Other(format!("Failed to serialize state: {e}")))?,
+            sandbox: tb.is_sandbox(),
+            languages: serde_json::to_string(tb.get_languages())
+                .map_err(|e| DbError::Other(format!("Failed to serialize languages: {e}")))?,
+            description: serde_json::to_string(tb.get_description())
+                .map_err(|e| DbError::Other(format!("Failed to serialize description: {e}")))?,
+            stations: serde_json::to_string(tb.get_stations())
+                .map_err(|e| DbError::Other(format!("Failed to serialize stations: {e}")))?,
+            check_in_deadline: tb.get_check_in_deadline(),
+            no_show_policy: serde_json::to_string(&tb.get_no_show_policy())
+                .map_err(|e| DbError::Other(format!("Failed to serialize no_show_policy: {e}")))?,
+            station_windows: serde_json::to_string(tb.get_station_windows())
+                .map_err(|e| DbError::Other(format!("Failed to serialize station_windows: {e}")))?,
+            address_id: tb.get_address_id().map(|a_id| a_id.to_string()),
+            venue_id: tb.get_venue_id().map(|v_id| v_id.to_string()),
+            court_call_policy: serde_json::to_string(&tb.get_court_call_policy()).map_err(|e| {
+                DbError::Other(format!("Failed to serialize court_call_policy: {e}"))
+            })?,
+            sport_config_id: tb.get_sport_config_id().map(|c_id| c_id.to_string()),
+        })
+    }
+}
+
+// ------------------- Impl trait --------------------
+
+#[async_trait]
+impl DbpTournamentBase for SqliteDb {
+    #[instrument(name = "db.tb.get", skip(self), fields(id = %t_id))]
+    async fn get_tournament_base(&self, t_id: Uuid) -> DbResult<Option<TournamentBase>> {
+        let mut conn = self.new_connection().await?;
+        let res = tournament_bases
+            .filter(id.eq(t_id.to_string()))
+            .first::<DbTournamentBase>(&mut conn)
+            .await
+            .optional()
+            .map_err(map_db_err)?;
+
+        match res {
+            Some(res) => {
+                let res = TournamentBase::try_from(res)?;
+                debug!("found_tournament_base");
+                Ok(Some(res))
+            }
+            None => {
+                debug!("tournament_base_not_found");
+                Ok(None)
+            }
+        }
+    }
+
+    #[instrument(
+        name = "db.tb.save",
+        skip(self, tournament),
+        fields(
+            id = ?tournament.get_id(),
+            version = tournament.get_version(),
+            is_new = tournament.get_id_version().is_new()
+        )
+    )]
+    async fn save_tournament_base(&self, tournament: &TournamentBase) -> DbResult<TournamentBase> {
+        let mut conn = self.new_connection().await?;
+        write_tournament_base(&mut conn, tournament).await
+    }
+
+    #[instrument(
+        name = "db.tb.save_diff",
+        skip(self, tournament, diff_stages, diff_groups),
+        fields(
+            id = ?tournament.map(|t| t.get_id()),
+            num_stages = diff_stages.len(),
+            num_groups = diff_groups.len()
+        )
+    )]
+    async fn save_tournament_diff(
+        &self,
+        tournament: Option<&TournamentBase>,
+        diff_stages: &[Stage],
+        diff_groups: &[Group],
+    ) -> DbBatchResult<(Option<TournamentBase>, Vec<Stage>, Vec<Group>)> {
+        let mut conn = self.new_connection().await.map_err(|error| DbBatchError {
+            object_id: None,
+            error,
+        })?;
+
+        // save all objects in one transaction; first failing object rolls back all others
+        let res = conn
+            .transaction::<_, TransactionError, _>(|conn| {
+                async move {
+                    let saved_base = match tournament {
+                        Some(t) => Some(
+                            write_tournament_base(conn, t)
+                                .await
+                                .map_err(|e| TransactionError::of_object(t.get_id(), e))?,
+                        ),
+                        None => None,
+                    };
+                    let mut saved_stages = Vec::with_capacity(diff_stages.len());
+                    for stage in diff_stages {
+                        let saved = write_stage(conn, stage)
+                            .await
+                            .map_err(|e| TransactionError::of_object(stage.get_id(), e))?;
+                        saved_stages.push(saved);
+                    }
+                    let mut saved_groups = Vec::with_capacity(diff_groups.len());
+                    for group in diff_groups {
+                        let saved = write_group(conn, group)
+                            .await
+                            .map_err(|e| TransactionError::of_object(group.get_id(), e))?;
+                        saved_groups.push(saved);
+                    }
+                    Ok((saved_base, saved_stages, saved_groups))
+                }
+                .scope_boxed()
+            })
+            .await;
+
+        match res {
+            Ok(saved) => {
+                info!(
+                    num_stages = saved.1.len(),
+                    num_groups = saved.2.len(),
+                    "save_diff_ok"
+                );
+                Ok(saved)
+            }
+            Err(TransactionError(e)) => {
+                warn!(object_id = ?e.object_id, error = %e.error, "save_diff_rolled_back");
+                Err(e)
+            }
+        }
+    }
+
+    #[instrument(name = "db.tb.archive", skip(self), fields(id = %t_id))]
+    async fn archive_tournament_base(
+        &self,
+        t_id: Uuid,
+        t_version: u32,
+    ) -> DbResult<TournamentBase> {
+        let mut conn = self.new_connection().await?;
+        let now = Utc::now();
+        let res = diesel::update(
+            tournament_bases.filter(id.eq(t_id.to_string()).and(version.eq(t_version as i64))),
+        )
+        .set((
+            archived_at.eq(now),
+            version.eq(sql::<BigInt>("version + 1")),
+            updated_at.eq(now),
+        ))
+        .returning(tournament_bases::all_columns)
+        .get_result::<DbTournamentBase>(&mut conn)
+        .await;
+
+        match res {
+            Ok(row) => {
+                info!(archived_id = %row.id, new_version = row.version, "archive_ok");
+                Ok(row.try_into()?)
+            }
+            Err(diesel::result::Error::NotFound) => {
+                // Distinguish lock conflict from missing row
+                let current_version = tournament_bases
+                    .filter(id.eq(t_id.to_string()))
+                    .select(version)
+                    .first::<i64>(&mut conn)
+                    .await
+                    .optional()
+                    .map_err(map_db_err)?;
+
+                if let Some(current_version) = current_version {
+                    warn!(current_version, "optimistic_lock_conflict");
+                    Err(map_version_conflict(current_version))
+                } else {
+                    warn!("row_missing_on_archive");
+                    Err(DbError::NotFound)
+                }
+            }
+            Err(e) => {
+                error!(error = %e, "archive_failed");
+                Err(map_db_err(e))
+            }
+        }
+    }
+
+    #[instrument(name = "db.tb.purge_sandbox", skip(self), fields(id = %t_id))]
+    async fn purge_sandbox_tournament_base(&self, t_id: Uuid) -> DbResult<()> {
+        let mut conn = self.new_connection().await?;
+        // dependent rows are removed by ON DELETE CASCADE
+        let deleted =
+            diesel::delete(tournament_bases.filter(id.eq(t_id.to_string()).and(sandbox.eq(true))))
+                .execute(&mut conn)
+                .await
+                .map_err(map_db_err)?;
+
+        if deleted == 0 {
+            warn!("sandbox_row_missing_on_purge");
+            return Err(DbError::NotFound);
+        }
+        info!(purged_id = %t_id, "purge_ok");
+        Ok(())
+    }
+
+    #[instrument(name = "db.tb.delete", skip(self), fields(id = %t_id, version = t_version))]
+    async fn delete_tournament_base(&self, t_id: Uuid, t_version: u32) -> DbResult<()> {
+        let mut conn = self.new_connection().await?;
+        // dependent rows are removed by ON DELETE CASCADE
+        let deleted = diesel::delete(
+            tournament_bases.filter(id.eq(t_id.to_string()).and(version.eq(t_version as i64))),
+        )
+        .execute(&mut conn)
+        .await
+        .map_err(map_db_err)?;
+
+        if deleted == 0 {
+            // Distinguish lock conflict from missing row
+            let current_version = tournament_bases
+                .filter(id.eq(t_id.to_string()))
+                .select(version)
+                .first::<i64>(&mut conn)
+                .await
+                .optional()
+                .map_err(map_db_err)?;
+
+            return if let Some(current_version) = current_version {
+                warn!(current_version, "optimistic_lock_conflict");
+                Err(map_version_conflict(current_version))
+            } else {
+                warn!("row_missing_on_delete");
+                Err(DbError::NotFound)
+            };
+        }
+        info!(deleted_id = %t_id, "delete_ok");
+        Ok(())
+    }
+
+    #[instrument(
+        name = "db.tb.list",
+        skip(self, filter, order),
+        fields(offset = order.get_offset())
+    )]
+    async fn list_tournament_base_ids(
+        &self,
+        filter: &Filter<TournamentBase>,
+        order: &ListOrder<TournamentBase>,
+    ) -> DbResult<Vec<Uuid>> {
+        let mut conn = self.new_connection().await?;
+        let mut query = tournament_bases.into_boxed::<diesel::sqlite::Sqlite>();
+
+        for condition in filter.get_conditions() {
+            debug!(?condition, "apply_condition");
+            query = match condition {
+                TournamentBaseCondition::SportIs(sport) => {
+                    query.filter(sport_id.eq(sport.to_string()))
+                }
+                TournamentBaseCondition::NameContains(text) => {
+                    // like of sqlite is case insensitive for ASCII
+                    let pattern = format!("%{}%", escape_like(text));
+                    query.filter(name.like(pattern).escape('\\'))
+                }
+                TournamentBaseCondition::StateIn(states) => {
+                    // states are serialized like on write, therefore text comparison matches
+                    let values = states
+                        .iter()
+                        .map(serde_json::to_string)
+                        .collect::<Result<Vec<_>, _>>()
+                        .map_err(|e| {
+                            DbError::Other(format!("Failed to serialize state filter: {e}"))
+                        })?;
+                    query.filter(state.eq_any(values))
+                }
+                TournamentBaseCondition::CreatedBetween { from, to } => {
+                    if let Some(from) = from {
+                        query = query.filter(created_at.ge(*from));
+                    }
+                    if let Some(to) = to {
+                        query = query.filter(created_at.lt(*to));
+                    }
+                    query
+                }
+                TournamentBaseCondition::NotAdhoc => query.filter(t_type.ne(
+                    serde_json::to_string(&TournamentType::Adhoc).map_err(|e| {
+                        DbError::Other(format!("Failed to serialize AdHoc type: {e}"))
+                    })?,
+                )),
+                TournamentBaseCondition::NotArchived => query.filter(archived_at.is_null()),
+                TournamentBaseCondition::NotSandbox => query.filter(sandbox.eq(false)),
+                TournamentBaseCondition::NotDraft => query.filter(state.ne(
+                    serde_json::to_string(&TournamentState::Draft).map_err(|e| {
+                        DbError::Other(format!("Failed to serialize Draft state: {e}"))
+                    })?,
+                )),
+            };
+        }
+
+        if let Some(lim) = filter.get_limit() {
+            query = query.limit(lim as i64);
+        }
+        if order.get_offset() > 0 {
+            query = query.offset(order.get_offset() as i64);
+        }
+
+        let desc = order.is_descending();
+        query = match order.get_sort_by() {
+            TournamentBaseSortColumn::Name if desc => query.order(name.desc()),
+            TournamentBaseSortColumn::Name => query.order(name.asc()),
+            TournamentBaseSortColumn::CreatedAt if desc => query.order(created_at.desc()),
+            TournamentBaseSortColumn::CreatedAt => query.order(created_at.asc()),
+            TournamentBaseSortColumn::NumEntrants if desc => query.order(num_entrants.desc()),
+            TournamentBaseSortColumn::NumEntrants => query.order(num_entrants.asc()),
+        };
+        // ties are broken by id, so that pages neither overlap nor miss rows
+        query = query.then_order_by(id.asc());
+
+        let rows = query
+            .select(id)
+            .load::<String>(&mut conn)
+            .await
+            .map_err(map_db_err)?
+            .into_iter()
+            .map(|row_id| parse_uuid(&row_id))
+            .collect::<DbResult<Vec<_>>>()?;
+
+        info!(count = rows.len(), "list_ok");
+        Ok(rows)
+    }
+}
+
+/// error of a transaction: failing saves are reported with the id of their object, while
+/// errors of begin or commit of the transaction fail the transaction as a whole
+pub(crate) struct TransactionError(pub(crate) DbBatchError);
+
+impl TransactionError {
+    pub(crate) fn of_object(object_id: Uuid, error: DbError) -> Self {
+        TransactionError(DbBatchError {
+            object_id: Some(object_id),
+            error,
+        })
+    }
+}
+
+impl From<diesel::result::Error> for TransactionError {
+    fn from(e: diesel::result::Error) -> Self {
+        TransactionError(DbBatchError {
+            object_id: None,
+            error: map_db_err(e),
+        })
+    }
+}
+
+/// insert or update tournament base with optimistic locking on `conn`
+async fn write_tournament_base(
+    conn: &mut SqliteConn,
+    tournament: &TournamentBase,
+) -> DbResult<TournamentBase> {
+    let w = WriteDbTournamentBase::try_from(tournament)?;
+
+    match tournament.get_id_version() {
+        // Case 1: UPDATE (Optimistic Locking)
+        IdVersion::Existing(inner) => {
+            let res = diesel::update(
+                tournament_bases.filter(
+                    id.eq(inner.get_id().to_string())
+                        .and(version.eq(inner.get_version() as i64)),
+                ),
+            )
+            .set((
+                w,
+                version.eq(sql::<BigInt>("version + 1")),
+                updated_at.eq(Utc::now()),
+            ))
+            .returning(tournament_bases::all_columns)
+            .get_result::<DbTournamentBase>(conn)
+            .await;
+
+            match res {
+                Ok(row) => {
+                    info!(saved_id = %row.id, new_version = row.version, "update_ok");
+                    Ok(row.try_into()?)
+                }
+                Err(diesel::result::Error::NotFound) => {
+                    // Distinguish lock conflict from missing row
+                    let current_version = tournament_bases
+                        .filter(id.eq(inner.get_id().to_string()))
+                        .select(version)
+                        .first::<i64>(conn)
+                        .await
+                        .optional()
+                        .map_err(map_db_err)?;
+
+                    if let Some(current_version) = current_version {
+                        warn!(current_version, "optimistic_lock_conflict");
+                        Err(map_version_conflict(current_version))
+                    } else {
+                        warn!("row_missing_on_update");
+                        Err(DbError::NotFound)
+                    }
+                }
+                Err(e) => {
+                    error!(error = %e, "update_failed");
+                    Err(map_db_err(e))
+                }
+            }
+        }
+        // Case 2: INSERT with specific ID (e.g. Migration)
+        IdVersion::NewWithId(new_id) => {
+            let now = Utc::now();
+            let row = diesel::insert_into(tournament_bases)
+                .values((
+                    id.eq(new_id.to_string()),
+                    created_at.eq(now),
+                    updated_at.eq(now),
+                    w,
+                ))
+                .returning(tournament_bases::all_columns)
+                .get_result::<DbTournamentBase>(conn)
+                .await
+                .map_err(map_db_err)?;
+
+            info!(saved_id = %row.id, "insert_ok");
+            Ok(row.try_into()?)
+        }
+    }
+}
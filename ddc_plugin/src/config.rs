@@ -5,9 +5,13 @@ use app_core::{
 use app_utils::enum_utils::SelectableOption;
 use serde::{Deserialize, Serialize};
 use serde_json::Value;
+use shared::SportConfigSummary;
 use std::{fmt::Display, time::Duration};
 use uuid::Uuid;
 
+/// time between two matches at a court, e.g. to change teams and warm up
+pub const CHANGEOVER: Duration = Duration::from_secs(3 * 60);
+
 /// DdcSetCfg - configuration for sets in Double Disc Court (DDC)
 #[derive(Debug, Clone, Copy, Serialize, Deserialize, Default, PartialEq)]
 pub enum DdcSetCfg {
@@ -129,6 +133,99 @@ impl DdcSetCfg {
             DdcSetCfg::CustomTotalSets { total_sets } => (*total_sets, *total_sets),
         }
     }
+
+    /// Returns true, if a match may end in a draw, i.e. an even number of total sets.
+    pub fn draws_possible(&self) -> bool {
+        matches!(self, DdcSetCfg::CustomTotalSets { total_sets } if total_sets % 2 == 0)
+    }
+}
+
+/// DdcTeamCfg – composition of teams in Double Disc Court (DDC)
+/// A DDC team always consists of two players.
+#[derive(Debug, Clone, Copy, Serialize, Deserialize, Default, PartialEq)]
+pub enum DdcTeamCfg {
+    /// Pairs register as one entrant and play together for the whole tournament
+    #[default]
+    FixedPairs,
+    /// Co-op scoring: players register as single entrants and are paired with a new partner
+    /// each round, see [`rotate_partners`](crate::pairs::rotate_partners). Each player is
+    /// credited with the score of their pair.
+    CoopRotation,
+}
+
+impl Display for DdcTeamCfg {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            DdcTeamCfg::FixedPairs => write!(f, "Fixed pairs"),
+            DdcTeamCfg::CoopRotation => write!(f, "Co-op (rotating partners)"),
+        }
+    }
+}
+
+impl SelectableOption for DdcTeamCfg {
+    fn value(&self) -> String {
+        self.to_string()
+    }
+
+    fn label(&self) -> String {
+        self.to_string()
+    }
+
+    fn options(&self) -> Vec<Self> {
+        vec![DdcTeamCfg::FixedPairs, DdcTeamCfg::CoopRotation]
+    }
+
+    fn static_options() -> Vec<Self> {
+        Self::options(&Self::default())
+    }
+}
+
+/// DdcDoublePoints – points scored by a "double", if one team touches both discs at the
+/// same time
+#[derive(Debug, Clone, Copy, Serialize, Deserialize, Default, PartialEq)]
+pub enum DdcDoublePoints {
+    /// a double scores 2 points
+    #[default]
+    TwoPoints,
+    /// a double scores 1 point, like any other rally
+    OnePoint,
+}
+
+impl Display for DdcDoublePoints {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            DdcDoublePoints::TwoPoints => write!(f, "Doubles: 2 points"),
+            DdcDoublePoints::OnePoint => write!(f, "Doubles: 1 point"),
+        }
+    }
+}
+
+impl SelectableOption for DdcDoublePoints {
+    fn value(&self) -> String {
+        self.to_string()
+    }
+
+    fn label(&self) -> String {
+        self.to_string()
+    }
+
+    fn options(&self) -> Vec<Self> {
+        vec![DdcDoublePoints::TwoPoints, DdcDoublePoints::OnePoint]
+    }
+
+    fn static_options() -> Vec<Self> {
+        Self::options(&Self::default())
+    }
+}
+
+impl DdcDoublePoints {
+    /// Returns the maximum number of points a team may score in one rally.
+    pub fn max_points_per_rally(&self) -> u16 {
+        match self {
+            DdcDoublePoints::TwoPoints => 2,
+            DdcDoublePoints::OnePoint => 1,
+        }
+    }
 }
 
 /// DdcSetWinningCfg – configuration for winning a single set in Double Disc Court (DDC)
@@ -289,26 +386,50 @@ impl DdcSetWinningCfg {
         errs
     }
 
-    pub fn validate_final_set_score(&self, score_a: u16, score_b: u16) -> SportResult<()> {
+    /// Returns true, if a set with scores `score_a` and `score_b` is finished.
+    pub fn is_set_finished(&self, score_a: u16, score_b: u16) -> bool {
         let (score_to_win, win_by_margin, hard_cap) = self.get_win_cfg();
         let max_score = score_a.max(score_b);
         let min_score = score_a.min(score_b);
+        max_score >= hard_cap
+            || (max_score >= score_to_win && max_score - min_score >= win_by_margin)
+    }
+
+    pub fn validate_final_set_score(
+        &self,
+        score_a: u16,
+        score_b: u16,
+        double_points: DdcDoublePoints,
+    ) -> SportResult<()> {
+        let (score_to_win, _, hard_cap) = self.get_win_cfg();
+        let max_score = score_a.max(score_b);
+        let min_score = score_a.min(score_b);
+        let max_points_per_rally = double_points.max_points_per_rally();
         if max_score < score_to_win {
             return Err(SportError::InvalidScore(
                 "No entrant has reached the score to win".to_string(),
             ));
         }
-        // DDC specific: with a double point in the last rally, the score may exceed the hard cap by 1
-        if max_score > hard_cap + 1 {
+        // DDC specific: with a double in the last rally, the score may exceed the hard cap
+        if max_score > hard_cap + max_points_per_rally - 1 {
             return Err(SportError::InvalidScore(
                 "Score exceeds hard cap".to_string(),
             ));
         }
-        if max_score < hard_cap && max_score.saturating_sub(min_score) < win_by_margin {
+        if !self.is_set_finished(max_score, min_score) {
             return Err(SportError::InvalidScore(
                 "Winning margin not reached".to_string(),
             ));
         }
+        // the last rally is scored by the winner; the set must not be finished before it
+        if (1..=max_points_per_rally)
+            .all(|points| points > max_score || self.is_set_finished(max_score - points, min_score))
+        {
+            return Err(SportError::InvalidScore(format!(
+                "Set is finished before last rally ({})",
+                double_points
+            )));
+        }
         Ok(())
     }
 
@@ -342,6 +463,12 @@ pub struct DdcSportConfig {
     pub victory_points_win: f32,
     /// victory points gained by a draw
     pub victory_points_draw: f32,
+    /// composition of teams
+    #[serde(default)]
+    pub team_cfg: DdcTeamCfg,
+    /// points scored by a double
+    #[serde(default)]
+    pub double_points: DdcDoublePoints,
     /// expected mean duration of a rally in seconds
     /// Used to estimate match duration by multiplying with max number of rallies
     /// without exceeding hard cap and no doubles.
@@ -358,6 +485,8 @@ impl Default for DdcSportConfig {
             set_winning_cfg: DdcSetWinningCfg::Sw15Hc21M2,
             victory_points_win: 1.0,
             victory_points_draw: 0.5,
+            team_cfg: DdcTeamCfg::FixedPairs,
+            double_points: DdcDoublePoints::TwoPoints,
             expected_rally_duration_seconds: Duration::from_secs(45),
         }
     }
@@ -418,6 +547,18 @@ impl DdcSportConfig {
         }
         if errs.is_empty() { Ok(()) } else { Err(errs) }
     }
+    /// Returns facts derived from the configuration, see [`SportConfigSummary`].
+    pub fn summary(&self) -> SportConfigSummary {
+        let (min_sets, max_sets) = self.sets_cfg.sets_to_play();
+        SportConfigSummary {
+            min_sets,
+            max_sets,
+            sets_label: "sets",
+            match_duration: self.estimate_match_duration(),
+            changeover: CHANGEOVER,
+            draws_possible: self.sets_cfg.draws_possible(),
+        }
+    }
     pub fn estimate_match_duration(&self) -> Duration {
         let max_sets = self.sets_cfg.sets_to_play().1;
         self.expected_rally_duration_seconds
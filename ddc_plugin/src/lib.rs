@@ -3,6 +3,7 @@
 //! if no specific sport plugin is available.
 
 pub mod config;
+pub mod pairs;
 pub mod sport_port;
 pub mod sport_web_ui;
 
@@ -80,7 +81,9 @@ impl DdcSportPlugin {
             ));
         }
         for (&a, &b) in score_a.iter().zip(score_b.iter()) {
-            config.set_winning_cfg.validate_final_set_score(a, b)?;
+            config
+                .set_winning_cfg
+                .validate_final_set_score(a, b, config.double_points)?;
         }
 
         Ok(())
@@ -287,4 +290,138 @@ mod tests {
                 .is_err()
         );
     }
+
+    #[test]
+    fn test_validate_final_score_double_points() {
+        let plugin = DdcSportPlugin::new();
+        let config = |double_points: &str| {
+            let id_version = IdVersion::new(Uuid::new_v4(), Some(1));
+            let mut sport_config = SportConfig::new(id_version);
+            sport_config
+                .set_sport_id(plugin.id())
+                .set_name("DDC Doubles")
+                .set_config(json!({
+                    "sets_cfg": "BestOf1",
+                    "set_winning_cfg": "Sw15Hc21M2",
+                    "victory_points_win": 1.0,
+                    "victory_points_draw": 0.5,
+                    "team_cfg": "CoopRotation",
+                    "double_points": double_points,
+                    "expected_rally_duration_seconds": { "secs": 45, "nanos": 0 }
+                }));
+            sport_config
+        };
+        let score = |a: u16, b: u16| {
+            Match::new_played(
+                Uuid::new_v4(),
+                Uuid::new_v4(),
+                Uuid::new_v4(),
+                plugin.id(),
+                vec![a],
+                vec![b],
+            )
+        };
+        let two_points = config("TwoPoints");
+        let one_point = config("OnePoint");
+
+        // 16:10 requires a double from 14:10
+        assert!(
+            plugin
+                .validate_final_score(&two_points, &score(16, 10))
+                .is_ok()
+        );
+        assert!(
+            plugin
+                .validate_final_score(&one_point, &score(16, 10))
+                .is_err()
+        );
+        // 17:10 is finished before the last rally in both variants
+        assert!(
+            plugin
+                .validate_final_score(&two_points, &score(17, 10))
+                .is_err()
+        );
+        // 17:14 requires a double from 15:14
+        assert!(
+            plugin
+                .validate_final_score(&two_points, &score(17, 14))
+                .is_ok()
+        );
+        assert!(
+            plugin
+                .validate_final_score(&one_point, &score(17, 14))
+                .is_err()
+        );
+        assert!(
+            plugin
+                .validate_final_score(&one_point, &score(16, 14))
+                .is_ok()
+        );
+        // hard cap may only be exceeded by a double
+        assert!(
+            plugin
+                .validate_final_score(&two_points, &score(22, 20))
+                .is_ok()
+        );
+        assert!(
+            plugin
+                .validate_final_score(&one_point, &score(22, 20))
+                .is_err()
+        );
+        assert!(
+            plugin
+                .validate_final_score(&one_point, &score(21, 20))
+                .is_ok()
+        );
+    }
+
+    #[test]
+    fn test_rotate_partners() {
+        let players = [1, 2, 3, 4];
+        let mut partners = std::collections::HashSet::new();
+        for round in 0..3 {
+            let (pairs, sitting_out) = pairs::rotate_partners(&players, round);
+            assert_eq!(pairs.len(), 2);
+            assert!(sitting_out.is_none());
+            for (a, b) in pairs {
+                assert!(partners.insert((a.min(b), a.max(b))));
+            }
+        }
+        // each player partnered each other player exactly once
+        assert_eq!(partners.len(), 6);
+
+        let (pairs, sitting_out) = pairs::rotate_partners(&[1, 2, 3], 0);
+        assert_eq!(pairs.len(), 1);
+        assert!(sitting_out.is_some());
+        assert_eq!(pairs::rotate_partners(&[1], 0), (vec![], Some(1)));
+        assert_eq!(pairs::rotate_partners::<u8>(&[], 0), (vec![], None));
+    }
+
+    #[test]
+    fn test_summary() {
+        let default = DdcSportConfig::default().summary();
+        assert_eq!((default.min_sets, default.max_sets), (1, 1));
+        assert!(!default.draws_possible);
+        assert_eq!(default.changeover, config::CHANGEOVER);
+
+        let best_of_3 = DdcSportConfig {
+            sets_cfg: config::DdcSetCfg::BestOf3,
+            ..Default::default()
+        }
+        .summary();
+        assert_eq!(best_of_3.display_sets(), "2–3 sets");
+        assert_eq!(best_of_3.match_duration, 3 * default.match_duration);
+
+        let even_total_sets = DdcSportConfig {
+            sets_cfg: config::DdcSetCfg::CustomTotalSets { total_sets: 2 },
+            ..Default::default()
+        }
+        .summary();
+        assert!(even_total_sets.draws_possible);
+    }
+
+    #[test]
+    fn test_sport_plugin_conformance() {
+        sport_plugin_testkit::assert_conformance(std::sync::Arc::new(DdcSportPlugin::new()));
+    }
 }
@@ -0,0 +1,31 @@
+//! rotation of partners for the co-op variant of Double Disc Court (DDC)
+//!
+//! In the co-op variant players register as single entrants. Each round, players are paired
+//! to teams by the circle method: the first player keeps their slot, all other players
+//! rotate by one slot per round.
+
+/// Pair `players` to teams for `round` (starting at 0). Over `n - 1` rounds with an even
+/// number `n` of players, each player partners each other player exactly once. With an odd
+/// number of players one player sits out each round; this player is returned separately.
+pub fn rotate_partners<T: Copy>(players: &[T], round: u32) -> (Vec<(T, T)>, Option<T>) {
+    let mut slots: Vec<Option<T>> = players.iter().copied().map(Some).collect();
+    if slots.len() % 2 == 1 {
+        slots.push(None);
+    }
+    if slots.is_empty() {
+        return (Vec::new(), None);
+    }
+    let num_slots = slots.len();
+    slots[1..].rotate_right(round as usize % (num_slots - 1));
+
+    let mut pairs = Vec::with_capacity(num_slots / 2);
+    let mut sitting_out = None;
+    for index in 0..num_slots / 2 {
+        match (slots[index], slots[num_slots - 1 - index]) {
+            (Some(a), Some(b)) => pairs.push((a, b)),
+            (Some(player), None) | (None, Some(player)) => sitting_out = Some(player),
+            (None, None) => {}
+        }
+    }
+    (pairs, sitting_out)
+}
@@ -1,8 +1,12 @@
 //! Implementation of SportPort for Generic Sport Plugin
 
-use super::{DdcSportPlugin, config::DdcSportConfig};
+use super::{
+    DdcSportPlugin,
+    config::{CHANGEOVER, DdcSportConfig},
+};
 use app_core::{
-    EntrantGroupScore, Match, SportConfig, SportError, SportPort, SportResult,
+    EntrantGroupScore, Match, MatchOutcome, SportCapabilities, SportConfig, SportError, SportPort,
+    SportResult, StationType,
     utils::validation::{ValidationErrors, ValidationResult},
 };
 use serde_json::Value;
@@ -16,10 +20,36 @@ impl SportPort for DdcSportPlugin {
     fn get_default_config(&self) -> Value {
         serde_json::to_value(DdcSportConfig::default()).unwrap()
     }
+    fn capabilities(&self) -> SportCapabilities {
+        SportCapabilities {
+            supports_draws: true,
+            per_set_scoring: true,
+            supports_handicap: false,
+            supports_time_cap: false,
+            needs_station_type: Some(StationType::Court),
+        }
+    }
     fn estimate_match_duration(&self, config: &SportConfig) -> SportResult<Duration> {
         let generic_config = self.validate_config(config, ValidationErrors::new())?;
         Ok(generic_config.estimate_match_duration())
     }
+    fn changeover_duration(&self, config: &SportConfig) -> SportResult<Duration> {
+        self.validate_config(config, ValidationErrors::new())?;
+        Ok(CHANGEOVER)
+    }
+    fn max_sets(&self, config: &SportConfig) -> SportResult<u16> {
+        let ddc_config = self.validate_config(config, ValidationErrors::new())?;
+        Ok(ddc_config.sets_cfg.sets_to_play().1)
+    }
+    fn free_ticket_score(&self, config: &SportConfig) -> SportResult<Vec<u16>> {
+        let ddc_config = self.validate_config(config, ValidationErrors::new())?;
+        // a free ticket wins the minimum number of sets to play with the score to win
+        let (score_to_win, _, _) = ddc_config.set_winning_cfg.get_win_cfg();
+        Ok(vec![
+            score_to_win;
+            ddc_config.sets_cfg.sets_to_play().0 as usize
+        ])
+    }
     fn validate_config_values(
         &self,
         config: &SportConfig,
@@ -31,8 +61,10 @@ impl SportPort for DdcSportPlugin {
 
     /// Validates a final score against the rules defined in the configuration.
     /// For sports with multiple sets, each set score is validated.
-    /// For sports with multiple sets, only one score point per turn is expected.
-    /// Therefore constraints like win_by_margin and hard_cap may be reached, but not exceeded.
+    /// A rally scores one point, or two points if doubles score two points.
+    /// Therefore constraints like win_by_margin and hard_cap may only be exceeded by a double
+    /// in the last rally of a set.
+    /// Matches decided by forfeit or bye have no scores to validate.
     fn validate_final_score(&self, config: &SportConfig, score: &Match) -> SportResult<()> {
         if score.get_sport_id() != &self.id() {
             return Err(SportError::InvalidScore(
@@ -45,6 +77,9 @@ impl SportPort for DdcSportPlugin {
             ));
         }
         let generic_config = self.validate_config(config, ValidationErrors::new())?;
+        if score.get_outcome().is_walkover() {
+            return Ok(());
+        }
         self.validate_final_score_internal(&generic_config, score)?;
         Ok(())
     }
@@ -58,22 +93,27 @@ impl SportPort for DdcSportPlugin {
         all_matches: &[Match],
     ) -> SportResult<EntrantGroupScore> {
         let generic_config = self.validate_config(config, ValidationErrors::new())?;
+        let free_ticket = self.free_ticket_score(config)?;
         let mut group_score = EntrantGroupScore::new(entrant_id, group_id);
         for m in all_matches.iter().filter(|m| {
             if let Some((id_a, id_b)) = m.get_entrants() {
                 (id_a == &entrant_id || id_b == &entrant_id)
                     && m.get_group_id() == &group_id
-                    && m.is_played()
+                    && m.is_decided()
             } else {
                 false
             }
         }) {
+            if m.get_outcome() == MatchOutcome::DoubleForfeit {
+                group_score.losses += 1;
+                continue;
+            }
             // unwrap is safe due to filter
             let (id_a, _id_b) = m.get_entrants().unwrap();
             let entrant_is_a = id_a == &entrant_id;
-            let (score_a, score_b) = m.get_scores();
-            let entrant_score = if entrant_is_a { score_a } else { score_b };
-            let opponent_score = if entrant_is_a { score_b } else { score_a };
+            let (score_a, score_b) = m.get_result_scores(&free_ticket);
+            let entrant_score = if entrant_is_a { &score_a } else { &score_b };
+            let opponent_score = if entrant_is_a { &score_b } else { &score_a };
             let mut sets_won = 0;
             let mut sets_lost = 0;
             for (&a, &b) in entrant_score.iter().zip(opponent_score.iter()) {
@@ -87,8 +127,12 @@ impl SportPort for DdcSportPlugin {
             }
             if sets_won > sets_lost {
                 group_score.victory_points += generic_config.victory_points_win;
+                group_score.wins += 1;
             } else if sets_won == sets_lost {
                 group_score.victory_points += generic_config.victory_points_draw;
+                group_score.draws += 1;
+            } else {
+                group_score.losses += 1;
             }
         }
         Ok(group_score)
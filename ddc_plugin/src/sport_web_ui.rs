@@ -1,7 +1,7 @@
 //! Implementation of sport preview for the generic sport plugin
 
 use super::DdcSportPlugin;
-use crate::config::{DdcSetCfg, DdcSetWinningCfg, DdcSportConfig};
+use crate::config::{DdcDoublePoints, DdcSetCfg, DdcSetWinningCfg, DdcSportConfig, DdcTeamCfg};
 use app_core::{
     SportConfig, SportPort,
     utils::validation::{ValidationErrors, ValidationResult},
@@ -65,6 +65,15 @@ impl SportPortWebUi for DdcSportPlugin {
                     <span class="text-base-content/80" data-testid="preview-set-winning-config">
                         {generic_config.set_winning_cfg.to_string()}
                     </span>
+
+                    <span class="hidden sm:inline text-base-content/30">"|"</span>
+
+                    <span class="text-base-content/80" data-testid="preview-team-config">
+                        {generic_config.team_cfg.to_string()}
+                    </span>
+                    <span class="text-base-content/80" data-testid="preview-double-points">
+                        {generic_config.double_points.to_string()}
+                    </span>
                 </div>
 
                 // Spacer to push meta info to the right on larger screens if desired,
@@ -121,6 +130,12 @@ impl SportPortWebUi for DdcSportPlugin {
         }
         .into_any()
     }
+    fn render_summary(&self, config: &SportConfig) -> AnyView {
+        match self.validate_config(config, ValidationErrors::new()) {
+            Ok(ddc_config) => ddc_config.summary().render(),
+            Err(_) => view! { <div>{"Invalid Configuration"}</div> }.into_any(),
+        }
+    }
     fn render_configuration(&self) -> AnyView {
         // get editor context
         let sport_config_editor = expect_context::<SportConfigEditorContext>();
@@ -313,6 +328,31 @@ impl SportPortWebUi for DdcSportPlugin {
                     .set(serde_json::to_value(cfg).unwrap());
             }
         });
+        let team_cfg =
+            Signal::derive(move || current_config.with(|cfg| cfg.as_ref().map(|c| c.team_cfg)));
+        let set_team_cfg = Callback::new(move |new_cfg: Option<DdcTeamCfg>| {
+            if let Some(mut cfg) = current_config.get()
+                && let Some(new_cfg) = new_cfg
+            {
+                cfg.team_cfg = new_cfg;
+                sport_config_editor
+                    .set_config
+                    .set(serde_json::to_value(cfg).unwrap());
+            }
+        });
+        let double_points = Signal::derive(move || {
+            current_config.with(|cfg| cfg.as_ref().map(|c| c.double_points))
+        });
+        let set_double_points = Callback::new(move |new_points: Option<DdcDoublePoints>| {
+            if let Some(mut cfg) = current_config.get()
+                && let Some(new_points) = new_points
+            {
+                cfg.double_points = new_points;
+                sport_config_editor
+                    .set_config
+                    .set(serde_json::to_value(cfg).unwrap());
+            }
+        });
         let victory_points_win = Signal::derive(move || {
             current_config.with(|cfg| cfg.as_ref().map(|c| c.victory_points_win))
         });
@@ -447,6 +487,22 @@ impl SportPortWebUi for DdcSportPlugin {
                         _ => ().into_any(),
                     }
                 }}
+                <div class="grid grid-cols-2 gap-4">
+                    <EnumSelect
+                        label="Team Composition"
+                        name="team_cfg"
+                        data_testid="select-team_cfg"
+                        value=team_cfg
+                        action=InputCommitAction::WriteAndSubmit(set_team_cfg)
+                    />
+                    <EnumSelect
+                        label="Points of a Double"
+                        name="double_points"
+                        data_testid="select-double_points"
+                        value=double_points
+                        action=InputCommitAction::WriteAndSubmit(set_double_points)
+                    />
+                </div>
                 <div class="grid grid-cols-2 gap-4">
                     <NumberInput
                         label="Victory Points for Win"
@@ -0,0 +1,153 @@
+// email port sending plain text emails via smtp
+
+use app_core::{EmailError, EmailMessage, EmailPort, EmailResult};
+use async_trait::async_trait;
+use lettre::{
+    AsyncSmtpTransport, AsyncTransport, Message, Tokio1Executor,
+    message::{Mailbox, header::ContentType},
+    transport::smtp::authentication::Credentials,
+};
+use std::{env, time::Duration};
+use tracing::{info, instrument, warn};
+
+/// default timeout of a single smtp connection
+pub const DEFAULT_TIMEOUT: Duration = Duration::from_secs(10);
+
+/// encryption of the connection to the smtp server
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub enum SmtpTls {
+    /// upgrade plain connection with STARTTLS, usually port 587
+    #[default]
+    StartTls,
+    /// implicit TLS, usually port 465
+    Tls,
+    /// no encryption; only for local relays and testing
+    None,
+}
+
+/// configuration of [`SmtpEmail`]
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct SmtpConfig {
+    pub host: String,
+    /// port of server; default port of `tls` if not set
+    pub port: Option<u16>,
+    pub tls: SmtpTls,
+    /// username and password, if server requires authentication
+    pub credentials: Option<(String, String)>,
+    /// sender of all emails, e.g. `Tournament <noreply@example.org>`
+    pub from: String,
+    pub timeout: Duration,
+}
+
+impl SmtpConfig {
+    /// Read configuration from environment. Returns `None`, if `SMTP_HOST` is not set.
+    ///
+    /// - `SMTP_HOST`: host name of smtp server
+    /// - `SMTP_PORT`: optional port of smtp server
+    /// - `SMTP_TLS`: optional `starttls` (default), `tls` or `none`
+    /// - `SMTP_USERNAME`, `SMTP_PASSWORD`: optional credentials
+    /// - `SMTP_FROM`: sender of all emails
+    pub fn from_env() -> EmailResult<Option<Self>> {
+        let Ok(host) = env::var("SMTP_HOST") else {
+            return Ok(None);
+        };
+        let port = match env::var("SMTP_PORT") {
+            Ok(port) => Some(
+                port.parse::<u16>()
+                    .map_err(|_| EmailError::Transport(format!("invalid SMTP_PORT: {port}")))?,
+            ),
+            Err(_) => None,
+        };
+        let tls = match env::var("SMTP_TLS").as_deref() {
+            Err(_) | Ok("starttls") => SmtpTls::StartTls,
+            Ok("tls") => SmtpTls::Tls,
+            Ok("none") => SmtpTls::None,
+            Ok(other) => {
+                return Err(EmailError::Transport(format!("invalid SMTP_TLS: {other}")));
+            }
+        };
+        let credentials = match (env::var("SMTP_USERNAME"), env::var("SMTP_PASSWORD")) {
+            (Ok(username), Ok(password)) => Some((username, password)),
+            _ => None,
+        };
+        let from = env::var("SMTP_FROM")
+            .map_err(|_| EmailError::InvalidAddress("SMTP_FROM must be set".into()))?;
+        Ok(Some(SmtpConfig {
+            host,
+            port,
+            tls,
+            credentials,
+            from,
+            timeout: DEFAULT_TIMEOUT,
+        }))
+    }
+}
+
+/// Email port based upon the async smtp transport of lettre.
+#[derive(Clone)]
+pub struct SmtpEmail {
+    transport: AsyncSmtpTransport<Tokio1Executor>,
+    from: Mailbox,
+}
+
+impl SmtpEmail {
+    pub fn new(config: SmtpConfig) -> EmailResult<Self> {
+        let from = config
+            .from
+            .parse::<Mailbox>()
+            .map_err(|e| EmailError::InvalidAddress(format!("{}: {e}", config.from)))?;
+        let mut builder = match config.tls {
+            SmtpTls::StartTls => AsyncSmtpTransport::<Tokio1Executor>::starttls_relay(&config.host)
+                .map_err(|e| EmailError::Transport(e.to_string()))?,
+            SmtpTls::Tls => AsyncSmtpTransport::<Tokio1Executor>::relay(&config.host)
+                .map_err(|e| EmailError::Transport(e.to_string()))?,
+            SmtpTls::None => AsyncSmtpTransport::<Tokio1Executor>::builder_dangerous(&config.host),
+        };
+        if let Some(port) = config.port {
+            builder = builder.port(port);
+        }
+        if let Some((username, password)) = config.credentials {
+            builder = builder.credentials(Credentials::new(username, password));
+        }
+        let transport = builder.timeout(Some(config.timeout)).build();
+        Ok(SmtpEmail { transport, from })
+    }
+}
+
+#[async_trait]
+impl EmailPort for SmtpEmail {
+    #[instrument(name = "email.send", skip(self, message), fields(subject = %message.subject))]
+    async fn send_email(&self, message: &EmailMessage) -> EmailResult<()> {
+        let to = Mailbox::new(
+            message.to_name.clone(),
+            message
+                .to
+                .parse()
+                .map_err(|_| EmailError::InvalidAddress(message.to.clone()))?,
+        );
+        let email = Message::builder()
+            .from(self.from.clone())
+            .to(to)
+            .subject(message.subject.as_str())
+            .header(ContentType::TEXT_PLAIN)
+            .body(message.body.clone())
+            .map_err(|e| EmailError::InvalidAddress(e.to_string()))?;
+        self.transport.send(email).await.map_err(|e| {
+            warn!(error = %e, "email_send_failed");
+            EmailError::Transport(e.to_string())
+        })?;
+        Ok(())
+    }
+}
+
+/// Email port, which only logs emails. Used if no smtp server is configured.
+#[derive(Clone, Copy, Debug, Default)]
+pub struct LogOnlyEmail;
+
+#[async_trait]
+impl EmailPort for LogOnlyEmail {
+    async fn send_email(&self, message: &EmailMessage) -> EmailResult<()> {
+        info!(subject = %message.subject, "email_not_sent_smtp_not_configured");
+        Ok(())
+    }
+}
@@ -4,9 +4,13 @@ use app_core::{
 };
 use serde::{Deserialize, Serialize};
 use serde_json::Value;
+use shared::SportConfigSummary;
 use std::time::Duration;
 use uuid::Uuid;
 
+/// time between two matches at a station, e.g. to change entrants and warm up
+pub const CHANGEOVER: Duration = Duration::from_secs(5 * 60);
+
 /// Configuration for the Generic Sport Plugin
 ///
 /// Example configurations for GenericSportConfig
@@ -25,6 +29,45 @@ use uuid::Uuid;
 /// }
 /// ```
 ///
+/// Volleyball league example configuration with bonus points: 3:0 and 3:1 wins gain
+/// 3 points, 3:2 wins 2 points and 2:3 losses 1 point:
+/// ```json
+/// {
+///     "sets_to_win": 3,
+///     "score_to_win": 25,
+///     "win_by_margin": 2,
+///     "hard_cap": 30,
+///     "victory_points_win": 2.0,
+///     "victory_points_draw": 1.0,
+///     "victory_points_bonus": 1.0,
+///     "bonus_win_margin": 2,
+///     "bonus_loss_margin": 1,
+///     "expected_match_duration_minutes": { "secs": 1800, "nanos": 0 }
+/// }
+/// ```
+///
+/// Basketball example configuration with 4 periods of 10 minutes and overtime of 5 minutes
+/// to resolve ties:
+/// ```json
+/// {
+///     "sets_to_win": 1,
+///     "score_to_win": null,
+///     "win_by_margin": null,
+///     "hard_cap": null,
+///     "victory_points_win": 2.0,
+///     "victory_points_draw": 1.0,
+///     "scoring_mode": {
+///         "TimedPeriods": {
+///             "periods": 4,
+///             "period_minutes": 10,
+///             "overtime_minutes": 5,
+///             "draws_allowed": false
+///         }
+///     },
+///     "expected_match_duration_minutes": { "secs": 5400, "nanos": 0 }
+/// }
+/// ```
+///
 /// Table Tennis example configuration:
 /// ```json
 /// {
@@ -54,10 +97,77 @@ pub struct GenericSportConfig {
     pub victory_points_win: f32,
     /// victory points gained by a draw
     pub victory_points_draw: f32,
+    /// bonus victory points gained by a win or loss within the bonus margins
+    #[serde(default)]
+    pub victory_points_bonus: f32,
+    /// minimum margin of a win, which gains bonus victory points
+    /// margin is counted in sets, if sets_to_win > 1, in score otherwise
+    #[serde(default)]
+    pub bonus_win_margin: Option<u16>,
+    /// maximum margin of a loss, which gains bonus victory points
+    /// margin is counted like bonus_win_margin
+    #[serde(default)]
+    pub bonus_loss_margin: Option<u16>,
+    /// maximum handicap offset of a side in a match; handicaps are not allowed, if None
+    /// handicap offsets are credited to the score of each set
+    #[serde(default)]
+    pub max_handicap: Option<u16>,
+    /// scoring of matches in sets or in timed periods
+    #[serde(default)]
+    pub scoring_mode: ScoringMode,
+    /// score per set of a side, which wins by forfeit or bye; its opponent scores 0
+    #[serde(default = "default_score_free_ticket")]
+    pub score_free_ticket: u16,
     /// expected maximum duration of a match in minutes
     pub expected_match_duration_minutes: Duration,
 }
 
+/// Scoring mode of matches
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Serialize, Deserialize)]
+pub enum ScoringMode {
+    /// matches are won by winning sets_to_win sets, each set is scored on its own
+    #[default]
+    Sets,
+    /// matches are played in timed periods, scores of all periods are cumulative
+    TimedPeriods(TimedPeriods),
+}
+
+/// Timed periods of sports like handball or basketball
+///
+/// The score of a match contains one entry per period and one additional entry for overtime.
+/// The winner is the entrant with the higher total score.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+pub struct TimedPeriods {
+    /// number of regular periods (min 1)
+    pub periods: u16,
+    /// playing time of each period in minutes
+    pub period_minutes: u16,
+    /// playing time of overtime in minutes; overtime is only played after a tie
+    pub overtime_minutes: Option<u16>,
+    /// if false, a tie must be resolved by overtime
+    pub draws_allowed: bool,
+}
+
+impl Default for TimedPeriods {
+    fn default() -> Self {
+        Self {
+            periods: 2,
+            period_minutes: 30,
+            overtime_minutes: None,
+            draws_allowed: true,
+        }
+    }
+}
+
+impl TimedPeriods {
+    /// Playing time of a match including overtime.
+    pub fn playing_time(&self) -> Duration {
+        let minutes = self.periods as u64 * self.period_minutes as u64
+            + self.overtime_minutes.unwrap_or(0) as u64;
+        Duration::from_secs(minutes * 60)
+    }
+}
+
 impl Default for GenericSportConfig {
     fn default() -> Self {
         Self {
@@ -67,11 +177,21 @@ impl Default for GenericSportConfig {
             hard_cap: None,
             victory_points_win: 1.0,
             victory_points_draw: 0.5,
+            victory_points_bonus: 0.0,
+            bonus_win_margin: None,
+            bonus_loss_margin: None,
+            max_handicap: None,
+            scoring_mode: ScoringMode::Sets,
+            score_free_ticket: default_score_free_ticket(),
             expected_match_duration_minutes: Duration::from_secs(30 * 60),
         }
     }
 }
 
+fn default_score_free_ticket() -> u16 {
+    1
+}
+
 impl GenericSportConfig {
     pub fn parse_config(config: Value) -> SportResult<Self> {
         match serde_json::from_value(config) {
@@ -82,6 +202,54 @@ impl GenericSportConfig {
             ))),
         }
     }
+    /// Returns the scores of a side, which wins by forfeit or bye: `score_free_ticket` for
+    /// each set to win or in a single entry with timed periods.
+    pub fn free_ticket_score(&self) -> Vec<u16> {
+        match self.scoring_mode {
+            ScoringMode::Sets => vec![self.score_free_ticket; self.sets_to_win as usize],
+            ScoringMode::TimedPeriods(_) => vec![self.score_free_ticket],
+        }
+    }
+    /// Returns the minimum number of sets of a match; with timed periods the number of
+    /// regular periods.
+    pub fn min_sets(&self) -> u16 {
+        match &self.scoring_mode {
+            ScoringMode::Sets => self.sets_to_win,
+            ScoringMode::TimedPeriods(timed) => timed.periods,
+        }
+    }
+    /// Returns true, if a match may end in a draw: with sets, a single set without win by
+    /// margin may end level; with timed periods, if draws are allowed.
+    pub fn draws_possible(&self) -> bool {
+        match &self.scoring_mode {
+            ScoringMode::Sets => self.sets_to_win == 1 && self.win_by_margin.is_none(),
+            ScoringMode::TimedPeriods(timed) => timed.draws_allowed,
+        }
+    }
+    /// Returns facts derived from the configuration, see [`SportConfigSummary`].
+    pub fn summary(&self) -> SportConfigSummary {
+        SportConfigSummary {
+            min_sets: self.min_sets(),
+            max_sets: self.max_sets(),
+            sets_label: match self.scoring_mode {
+                ScoringMode::Sets => "sets",
+                ScoringMode::TimedPeriods(_) => "periods",
+            },
+            match_duration: self.expected_match_duration_minutes,
+            changeover: CHANGEOVER,
+            draws_possible: self.draws_possible(),
+        }
+    }
+    /// Returns the maximum number of sets of a match; with timed periods the number of
+    /// periods including overtime.
+    pub fn max_sets(&self) -> u16 {
+        match &self.scoring_mode {
+            ScoringMode::Sets => (self.sets_to_win * 2).saturating_sub(1),
+            ScoringMode::TimedPeriods(timed) => {
+                timed.periods + u16::from(timed.overtime_minutes.is_some())
+            }
+        }
+    }
     pub fn validate(&self, object_id: Uuid, mut errs: ValidationErrors) -> ValidationResult<()> {
         // Basic validation logic
         if self.sets_to_win == 0 {
@@ -188,6 +356,81 @@ impl GenericSportConfig {
                     .build(),
             );
         }
+        let has_bonus_margin = self.bonus_win_margin.is_some() || self.bonus_loss_margin.is_some();
+        if has_bonus_margin && self.victory_points_bonus <= 0.0 {
+            errs.add(
+                FieldError::builder()
+                    .set_field("victory_points_bonus")
+                    .add_user_defined_code("invalid_value")
+                    .add_message(
+                        "victory_points_bonus must be greater than 0 if a bonus margin is set",
+                    )
+                    .set_object_id(object_id)
+                    .build(),
+            );
+        }
+        if !has_bonus_margin && self.victory_points_bonus != 0.0 {
+            errs.add(
+                FieldError::builder()
+                    .set_field("victory_points_bonus")
+                    .add_user_defined_code("invalid_value")
+                    .add_message(
+                        "victory_points_bonus requires bonus_win_margin or bonus_loss_margin",
+                    )
+                    .set_object_id(object_id)
+                    .build(),
+            );
+        }
+        if let Some(margin) = self.bonus_win_margin
+            && (margin == 0 || (self.sets_to_win > 1 && margin > self.sets_to_win))
+        {
+            errs.add(
+                FieldError::builder()
+                    .set_field("bonus_win_margin")
+                    .add_user_defined_code("invalid_value")
+                    .add_message("bonus_win_margin must be between 1 and sets_to_win")
+                    .set_object_id(object_id)
+                    .build(),
+            );
+        }
+        if let Some(margin) = self.bonus_loss_margin
+            && (margin == 0 || (self.sets_to_win > 1 && margin >= self.sets_to_win))
+        {
+            errs.add(
+                FieldError::builder()
+                    .set_field("bonus_loss_margin")
+                    .add_user_defined_code("invalid_value")
+                    .add_message("bonus_loss_margin must be at least 1 and less than sets_to_win")
+                    .set_object_id(object_id)
+                    .build(),
+            );
+        }
+        if let Some(max_handicap) = self.max_handicap
+            && let Some(score_to_win) = self.score_to_win
+            && max_handicap >= score_to_win
+        {
+            errs.add(
+                FieldError::builder()
+                    .set_field("max_handicap")
+                    .add_user_defined_code("invalid_value")
+                    .add_message("max_handicap must be less than score_to_win")
+                    .set_object_id(object_id)
+                    .build(),
+            );
+        }
+        if self.score_free_ticket == 0 {
+            errs.add(
+                FieldError::builder()
+                    .set_field("score_free_ticket")
+                    .add_user_defined_code("invalid_value")
+                    .add_message("score_free_ticket must be at least 1")
+                    .set_object_id(object_id)
+                    .build(),
+            );
+        }
+        if let ScoringMode::TimedPeriods(timed) = self.scoring_mode {
+            self.validate_timed_periods(&timed, object_id, &mut errs);
+        }
         if self.expected_match_duration_minutes.as_secs() == 0 {
             errs.add(
                 FieldError::builder()
@@ -200,7 +443,113 @@ impl GenericSportConfig {
         }
         if errs.is_empty() { Ok(()) } else { Err(errs) }
     }
+    fn validate_timed_periods(
+        &self,
+        timed: &TimedPeriods,
+        object_id: Uuid,
+        errs: &mut ValidationErrors,
+    ) {
+        if self.sets_to_win != 1 {
+            errs.add(
+                FieldError::builder()
+                    .set_field("sets_to_win")
+                    .add_user_defined_code("invalid_value")
+                    .add_message("sets_to_win must be 1 for timed periods")
+                    .set_object_id(object_id)
+                    .build(),
+            );
+        }
+        if self.score_to_win.is_some() {
+            errs.add(
+                FieldError::builder()
+                    .set_field("score_to_win")
+                    .add_user_defined_code("invalid_value")
+                    .add_message("score_to_win cannot be set for timed periods")
+                    .set_object_id(object_id)
+                    .build(),
+            );
+        }
+        if timed.periods == 0 {
+            errs.add(
+                FieldError::builder()
+                    .set_field("periods")
+                    .add_user_defined_code("invalid_value")
+                    .add_message("periods must be at least 1")
+                    .set_object_id(object_id)
+                    .build(),
+            );
+        }
+        if timed.period_minutes == 0 {
+            errs.add(
+                FieldError::builder()
+                    .set_field("period_minutes")
+                    .add_user_defined_code("invalid_value")
+                    .add_message("period_minutes must be greater than 0")
+                    .set_object_id(object_id)
+                    .build(),
+            );
+        }
+        if timed.overtime_minutes == Some(0) {
+            errs.add(
+                FieldError::builder()
+                    .set_field("overtime_minutes")
+                    .add_user_defined_code("invalid_value")
+                    .add_message("overtime_minutes must be greater than 0")
+                    .set_object_id(object_id)
+                    .build(),
+            );
+        }
+        if self.expected_match_duration_minutes < timed.playing_time() {
+            errs.add(
+                FieldError::builder()
+                    .set_field("expected_match_duration_minutes")
+                    .add_user_defined_code("invalid_value")
+                    .add_message(format!(
+                        "expected_match_duration_minutes must be at least the playing time of {} minutes",
+                        timed.playing_time().as_secs() / 60
+                    ))
+                    .set_object_id(object_id)
+                    .build(),
+            );
+        }
+    }
+    /// Bonus victory points of a match with `margin` from view of an entrant; positive
+    /// margins are wins, negative margins losses.
+    pub fn bonus_points(&self, margin: i32) -> f32 {
+        let gains_bonus = if margin > 0 {
+            self.bonus_win_margin.is_some_and(|m| margin >= m as i32)
+        } else if margin < 0 {
+            self.bonus_loss_margin.is_some_and(|m| -margin <= m as i32)
+        } else {
+            false
+        };
+        if gains_bonus {
+            self.victory_points_bonus
+        } else {
+            0.0
+        }
+    }
+    pub fn display_match_format(&self) -> String {
+        match self.scoring_mode {
+            ScoringMode::Sets => format!("Sets to win: {}", self.sets_to_win),
+            ScoringMode::TimedPeriods(timed) => {
+                format!("Periods: {} × {} min", timed.periods, timed.period_minutes)
+            }
+        }
+    }
     pub fn display_score_limit(&self) -> String {
+        if let ScoringMode::TimedPeriods(timed) = self.scoring_mode {
+            let overtime = match timed.overtime_minutes {
+                Some(minutes) => format!("Overtime: {} min", minutes),
+                None => "No overtime".to_string(),
+            };
+            let draws = if timed.draws_allowed {
+                "draws allowed"
+            } else {
+                "no draws"
+            };
+            return format!("{}, {}", overtime, draws);
+        }
         match self.score_to_win {
             Some(score) => {
                 let mut details = Vec::new();
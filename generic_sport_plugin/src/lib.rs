@@ -15,7 +15,7 @@ use app_core::{
         validation::{FieldError, ValidationErrors, ValidationResult},
     },
 };
-use config::GenericSportConfig;
+use config::{GenericSportConfig, ScoringMode, TimedPeriods};
 use uuid::Uuid;
 
 /// A generic implementation of the `SportPort`, which may be used,
@@ -109,7 +109,26 @@ impl GenericSportPlugin {
         config: &GenericSportConfig,
         score: &Match,
     ) -> SportResult<()> {
-        let (score_a, score_b) = score.get_scores();
+        let (handicap_a, handicap_b) = score.get_handicaps();
+        if handicap_a > 0 || handicap_b > 0 {
+            match config.max_handicap {
+                None => {
+                    return Err(SportError::InvalidScore(
+                        "Handicaps are not allowed by configuration".to_string(),
+                    ));
+                }
+                Some(max_handicap) if handicap_a.max(handicap_b) > max_handicap => {
+                    return Err(SportError::InvalidScore(
+                        "Handicap exceeds maximum handicap".to_string(),
+                    ));
+                }
+                Some(_) => {}
+            }
+        }
+        let (score_a, score_b) = handicapped_scores(config, score);
+        if let ScoringMode::TimedPeriods(timed) = config.scoring_mode {
+            return validate_timed_score(&timed, &score_a, &score_b);
+        }
         if score_a.len() != score_b.len() {
             return Err(SportError::InvalidScore(
                 "Score vectors for both entrants must have the same length".to_string(),
@@ -156,6 +175,72 @@ impl GenericSportPlugin {
     }
 }
 
+/// Scores of both sides of the decided match `score`, by which it counts in standings:
+/// walkovers score the free ticket, played matches their scores including handicap offsets.
+fn standing_scores(config: &GenericSportConfig, score: &Match) -> (Vec<u16>, Vec<u16>) {
+    if score.get_outcome().is_walkover() {
+        score.get_result_scores(&config.free_ticket_score())
+    } else {
+        handicapped_scores(config, score)
+    }
+}
+
+/// Scores of both sides of `score` including handicap offsets. With sets, handicaps are
+/// credited to each set, with timed periods once to the first period.
+fn handicapped_scores(config: &GenericSportConfig, score: &Match) -> (Vec<u16>, Vec<u16>) {
+    let (handicap_a, handicap_b) = score.get_handicaps();
+    let (score_a, score_b) = score.get_scores();
+    // with timed periods, handicap is credited once to first period
+    let per_set = config.scoring_mode == ScoringMode::Sets;
+    let credit = |scores: &Vec<u16>, handicap: u16| -> Vec<u16> {
+        scores
+            .iter()
+            .enumerate()
+            .map(|(index, s)| {
+                if per_set || index == 0 {
+                    s + handicap
+                } else {
+                    *s
+                }
+            })
+            .collect()
+    };
+    (credit(score_a, handicap_a), credit(score_b, handicap_b))
+}
+
+/// Validate cumulative scores of timed periods.
+fn validate_timed_score(timed: &TimedPeriods, score_a: &[u16], score_b: &[u16]) -> SportResult<()> {
+    if score_a.len() != score_b.len() {
+        return Err(SportError::InvalidScore(
+            "Score vectors for both entrants must have the same length".to_string(),
+        ));
+    }
+    let periods = timed.periods as usize;
+    let total = |scores: &[u16]| scores.iter().map(|&s| s as u32).sum::<u32>();
+    if score_a.len() == periods + 1 {
+        if timed.overtime_minutes.is_none() {
+            return Err(SportError::InvalidScore(
+                "Overtime is not allowed by configuration".to_string(),
+            ));
+        }
+        if total(&score_a[..periods]) != total(&score_b[..periods]) {
+            return Err(SportError::InvalidScore(
+                "Overtime is only played after a tie in regular periods".to_string(),
+            ));
+        }
+    } else if score_a.len() != periods {
+        return Err(SportError::InvalidScore(
+            "Score does not have the correct number of periods".to_string(),
+        ));
+    }
+    if !timed.draws_allowed && total(score_a) == total(score_b) {
+        return Err(SportError::InvalidScore(
+            "Draws are not allowed by configuration".to_string(),
+        ));
+    }
+    Ok(())
+}
+
 impl ObjectIdVersion for GenericSportPlugin {
     fn get_id_version(&self) -> IdVersion {
         // we can increment version later if changes are made to the sport plugin
@@ -165,7 +250,10 @@ impl ObjectIdVersion for GenericSportPlugin {
 #[cfg(test)]
 mod tests {
     use super::*;
-    use app_core::{SportPort, utils::id_version::IdVersion};
+    use app_core::{
+        MatchOutcome, MatchWinner, PlayOutMatches, PlayOutPlacement, ScoringOverride, SportPort,
+        TieBreaker, rank_group_entrants, utils::id_version::IdVersion,
+    };
     use serde_json::json;
 
     #[test]
@@ -379,4 +467,490 @@ mod tests {
                 .is_err()
         );
     }
+
+    fn volleyball_bonus_config(plugin: &GenericSportPlugin) -> SportConfig {
+        let config = json!({
+            "sets_to_win": 3,
+            "score_to_win": 25,
+            "win_by_margin": 2,
+            "hard_cap": 30,
+            "victory_points_win": 2.0,
+            "victory_points_draw": 1.0,
+            "victory_points_bonus": 1.0,
+            "bonus_win_margin": 2,
+            "bonus_loss_margin": 1,
+            "max_handicap": 5,
+            "expected_match_duration_minutes": { "secs": 1800, "nanos": 0 }
+        });
+        let id_version = IdVersion::new(Uuid::new_v4(), Some(1));
+        let mut sport_config = SportConfig::new(id_version);
+        sport_config
+            .set_sport_id(plugin.id())
+            .set_name("Volleyball League")
+            .set_config(config);
+        sport_config
+    }
+
+    #[test]
+    fn test_entrant_group_score_bonus_points() {
+        let plugin = GenericSportPlugin::new();
+        let sport_config = volleyball_bonus_config(&plugin);
+        let (a, b, c) = (Uuid::new_v4(), Uuid::new_v4(), Uuid::new_v4());
+        let matches = vec![
+            // a wins 3:0 -> win and bonus
+            Match::new_played(
+                Uuid::new_v4(),
+                a,
+                b,
+                plugin.id(),
+                vec![25, 25, 25],
+                vec![20, 20, 20],
+            ),
+            // a wins 3:2 -> win without bonus, c gains bonus for close loss
+            Match::new_played(
+                Uuid::new_v4(),
+                c,
+                a,
+                plugin.id(),
+                vec![25, 20, 25, 20, 13],
+                vec![20, 25, 20, 25, 15],
+            ),
+        ];
+
+        let score_a = plugin
+            .get_entrant_group_score(&sport_config, Uuid::nil(), a, &matches)
+            .unwrap();
+        assert_eq!(score_a.victory_points, 5.0);
+        assert_eq!(score_a.wins, 2);
+
+        let score_b = plugin
+            .get_entrant_group_score(&sport_config, Uuid::nil(), b, &matches)
+            .unwrap();
+        assert_eq!(score_b.victory_points, 0.0);
+
+        let score_c = plugin
+            .get_entrant_group_score(&sport_config, Uuid::nil(), c, &matches)
+            .unwrap();
+        assert_eq!(score_c.victory_points, 1.0);
+        assert_eq!(score_c.losses, 1);
+    }
+
+    #[test]
+    fn test_entrant_group_score_forfeits() {
+        let plugin = GenericSportPlugin::new();
+        let sport_config = volleyball_bonus_config(&plugin);
+        let (a, b, c) = (Uuid::new_v4(), Uuid::new_v4(), Uuid::new_v4());
+        let matches = vec![
+            // b forfeits -> a wins 3:0 with default free ticket score of 1 per set
+            Match::new_walkover(Uuid::new_v4(), a, b, plugin.id(), MatchOutcome::ForfeitB),
+            // double forfeit -> loss for both
+            Match::new_walkover(
+                Uuid::new_v4(),
+                c,
+                a,
+                plugin.id(),
+                MatchOutcome::DoubleForfeit,
+            ),
+        ];
+        assert!(
+            plugin
+                .validate_final_score(&sport_config, &matches[0])
+                .is_ok()
+        );
+
+        let score_a = plugin
+            .get_entrant_group_score(&sport_config, Uuid::nil(), a, &matches)
+            .unwrap();
+        assert_eq!(score_a.victory_points, 3.0);
+        assert_eq!((score_a.wins, score_a.losses), (1, 1));
+        assert_eq!(score_a.total_score, 3);
+
+        let score_b = plugin
+            .get_entrant_group_score(&sport_config, Uuid::nil(), b, &matches)
+            .unwrap();
+        assert_eq!(score_b.victory_points, 0.0);
+        assert_eq!(score_b.relative_score, -3);
+
+        let score_c = plugin
+            .get_entrant_group_score(&sport_config, Uuid::nil(), c, &matches)
+            .unwrap();
+        assert_eq!(score_c.losses, 1);
+        assert_eq!(score_c.victory_points, 0.0);
+    }
+
+    #[test]
+    fn test_match_winner_and_play_out_places() {
+        let plugin = GenericSportPlugin::new();
+        let sport_config = volleyball_bonus_config(&plugin);
+        let (a, b, c, d) = (
+            Uuid::new_v4(),
+            Uuid::new_v4(),
+            Uuid::new_v4(),
+            Uuid::new_v4(),
+        );
+        let final_match = Match::new_played(
+            Uuid::new_v4(),
+            a,
+            b,
+            plugin.id(),
+            vec![20, 20, 20],
+            vec![25, 25, 25],
+        );
+        let third_place =
+            Match::new_walkover(Uuid::new_v4(), c, d, plugin.id(), MatchOutcome::ForfeitB);
+
+        let winner = MatchWinner::of_match(&plugin, &sport_config, &final_match)
+            .unwrap()
+            .unwrap();
+        assert_eq!((winner.winner, winner.loser), (b, Some(a)));
+        let bye = Match::new_bye(Uuid::new_v4(), c, plugin.id());
+        let bye_winner = MatchWinner::of_match(&plugin, &sport_config, &bye)
+            .unwrap()
+            .unwrap();
+        assert_eq!((bye_winner.winner, bye_winner.loser), (c, None));
+        let double_forfeit = Match::new_walkover(
+            Uuid::new_v4(),
+            c,
+            d,
+            plugin.id(),
+            MatchOutcome::DoubleForfeit,
+        );
+        assert!(
+            MatchWinner::of_match(&plugin, &sport_config, &double_forfeit)
+                .unwrap()
+                .is_none()
+        );
+
+        let play_out = PlayOutMatches {
+            placements: vec![
+                PlayOutPlacement {
+                    match_id: *final_match.get_id(),
+                    winner_place: 1,
+                    loser_place: 2,
+                },
+                PlayOutPlacement {
+                    match_id: *third_place.get_id(),
+                    winner_place: 3,
+                    loser_place: 4,
+                },
+            ],
+            matches: vec![final_match, third_place],
+            round_sizes: vec![2],
+        };
+        assert_eq!(
+            play_out.final_places(&plugin, &sport_config).unwrap(),
+            vec![Some(b), Some(a), Some(c), Some(d)]
+        );
+    }
+
+    #[test]
+    fn test_entrant_group_score_byes() {
+        let plugin = GenericSportPlugin::new();
+        let sport_config = volleyball_bonus_config(&plugin);
+        let (a, b) = (Uuid::new_v4(), Uuid::new_v4());
+        let matches = vec![
+            // a pauses -> a wins 3:0 with default free ticket score of 1 per set
+            Match::new_bye(Uuid::new_v4(), a, plugin.id()),
+            Match::new_played(
+                Uuid::new_v4(),
+                a,
+                b,
+                plugin.id(),
+                vec![20, 20, 20],
+                vec![25, 25, 25],
+            ),
+        ];
+
+        let score_a = plugin
+            .get_entrant_group_score(&sport_config, Uuid::nil(), a, &matches)
+            .unwrap();
+        assert_eq!((score_a.wins, score_a.losses, score_a.byes), (1, 1, 1));
+        assert_eq!(score_a.victory_points, 3.0);
+        assert_eq!(score_a.total_score, 3 + 60);
+        assert_eq!(score_a.relative_score, 3 - 15);
+
+        let score_b = plugin
+            .get_entrant_group_score(&sport_config, Uuid::nil(), b, &matches)
+            .unwrap();
+        assert_eq!((score_b.wins, score_b.byes), (1, 0));
+        assert_eq!(score_b.victory_points, 3.0);
+
+        // equal victory points: the bye is excluded from head to head
+        let standings = rank_group_entrants(
+            Uuid::nil(),
+            &[a, b],
+            &matches,
+            &[TieBreaker::VictoryPoints, TieBreaker::HeadToHead],
+            |entrant_id, matches| {
+                plugin.get_entrant_group_score(&sport_config, Uuid::nil(), entrant_id, matches)
+            },
+        )
+        .unwrap();
+        let ranks: Vec<(Uuid, u32)> = standings
+            .iter()
+            .map(|s| (s.get_entrant_id(), s.rank))
+            .collect();
+        assert_eq!(ranks, vec![(b, 1), (a, 2)]);
+        assert_eq!(standings[1].score.byes, 1);
+    }
+
+    #[test]
+    fn test_validate_scoring_override() {
+        let plugin = GenericSportPlugin::new();
+        let stage_id = Uuid::new_v4();
+        let mut scoring = ScoringOverride {
+            victory_points_win: Some(3.0),
+            victory_points_draw: Some(1.0),
+            ..Default::default()
+        };
+        assert!(scoring.validate(&plugin, stage_id).is_ok());
+
+        // draw must gain less victory points than a win
+        scoring.victory_points_draw = Some(3.0);
+        assert!(scoring.validate(&plugin, stage_id).is_err());
+
+        // generic sport configuration does not know victory points for losses
+        scoring.victory_points_draw = None;
+        scoring.victory_points_loss = Some(1.0);
+        let errs = scoring.validate(&plugin, stage_id).unwrap_err();
+        assert!(errs.errors.iter().all(|e| e.get_object_id() == stage_id));
+    }
+
+    #[test]
+    fn test_handicap_scores() {
+        let plugin = GenericSportPlugin::new();
+        let sport_config = volleyball_bonus_config(&plugin);
+        let (a, b) = (Uuid::new_v4(), Uuid::new_v4());
+
+        // b starts each set with 5 points, recorded scores exclude the handicap
+        let mut handicap_match = Match::new_played(
+            Uuid::new_v4(),
+            a,
+            b,
+            plugin.id(),
+            vec![25, 25, 25],
+            vec![18, 18, 15],
+        );
+        handicap_match.set_handicaps(0, 5);
+        assert!(
+            plugin
+                .validate_final_score(&sport_config, &handicap_match)
+                .is_ok()
+        );
+        let score_b = plugin
+            .get_entrant_group_score(
+                &sport_config,
+                Uuid::nil(),
+                b,
+                std::slice::from_ref(&handicap_match),
+            )
+            .unwrap();
+        assert_eq!(score_b.total_score, 66);
+        assert_eq!(score_b.relative_score, -9);
+
+        // handicap changes winner of third set: 24:26 including handicap
+        let mut close_match = Match::new_played(
+            Uuid::new_v4(),
+            a,
+            b,
+            plugin.id(),
+            vec![25, 25, 24, 25],
+            vec![18, 18, 21, 18],
+        );
+        close_match.set_handicaps(0, 5);
+        assert!(
+            plugin
+                .validate_final_score(&sport_config, &close_match)
+                .is_ok()
+        );
+        let score_b = plugin
+            .get_entrant_group_score(&sport_config, Uuid::nil(), b, &[close_match])
+            .unwrap();
+        assert_eq!(score_b.victory_points, 0.0);
+        assert_eq!(score_b.losses, 1);
+
+        // handicap exceeds maximum
+        handicap_match.set_handicaps(0, 6);
+        assert!(
+            plugin
+                .validate_final_score(&sport_config, &handicap_match)
+                .is_err()
+        );
+    }
+
+    fn basketball_config(plugin: &GenericSportPlugin) -> SportConfig {
+        let config = json!({
+            "sets_to_win": 1,
+            "score_to_win": null,
+            "win_by_margin": null,
+            "hard_cap": null,
+            "victory_points_win": 2.0,
+            "victory_points_draw": 1.0,
+            "scoring_mode": {
+                "TimedPeriods": {
+                    "periods": 4,
+                    "period_minutes": 10,
+                    "overtime_minutes": 5,
+                    "draws_allowed": false
+                }
+            },
+            "expected_match_duration_minutes": { "secs": 5400, "nanos": 0 }
+        });
+        let id_version = IdVersion::new(Uuid::new_v4(), Some(1));
+        let mut sport_config = SportConfig::new(id_version);
+        sport_config
+            .set_sport_id(plugin.id())
+            .set_name("Basketball")
+            .set_config(config);
+        sport_config
+    }
+
+    #[test]
+    fn test_validate_config_timed_periods() {
+        let plugin = GenericSportPlugin::new();
+        let mut sport_config = basketball_config(&plugin);
+        assert!(
+            plugin
+                .validate_config(&sport_config, ValidationErrors::new())
+                .is_ok()
+        );
+
+        // expected match duration shorter than playing time of 45 minutes
+        let mut config = sport_config.get_config().clone();
+        config["expected_match_duration_minutes"] = json!({ "secs": 1800, "nanos": 0 });
+        config["sets_to_win"] = json!(2);
+        config["score_to_win"] = json!(25);
+        sport_config.set_config(config);
+        let errs = plugin
+            .validate_config(&sport_config, ValidationErrors::new())
+            .unwrap_err();
+        let fields: Vec<_> = errs.errors.iter().map(|e| e.get_field()).collect();
+        assert!(fields.contains(&"sets_to_win"));
+        assert!(fields.contains(&"score_to_win"));
+        assert!(fields.contains(&"expected_match_duration_minutes"));
+    }
+
+    #[test]
+    fn test_validate_final_score_timed_periods() {
+        let plugin = GenericSportPlugin::new();
+        let sport_config = basketball_config(&plugin);
+        let played = |score_a: Vec<u16>, score_b: Vec<u16>| {
+            Match::new_played(
+                Uuid::new_v4(),
+                Uuid::new_v4(),
+                Uuid::new_v4(),
+                plugin.id(),
+                score_a,
+                score_b,
+            )
+        };
+
+        // Valid score: scores of periods are cumulative, 80:75
+        let valid = played(vec![20, 18, 22, 20], vec![25, 15, 20, 15]);
+        assert!(plugin.validate_final_score(&sport_config, &valid).is_ok());
+
+        // Invalid score: wrong number of periods
+        let invalid_periods = played(vec![20, 18], vec![25, 15]);
+        assert!(
+            plugin
+                .validate_final_score(&sport_config, &invalid_periods)
+                .is_err()
+        );
+
+        // Invalid score: draws are not allowed
+        let draw = played(vec![20, 20, 20, 20], vec![25, 15, 20, 20]);
+        assert!(plugin.validate_final_score(&sport_config, &draw).is_err());
+
+        // Valid score: tie of 80:80 resolved by overtime
+        let overtime = played(vec![20, 20, 20, 20, 12], vec![25, 15, 20, 20, 9]);
+        assert!(
+            plugin
+                .validate_final_score(&sport_config, &overtime)
+                .is_ok()
+        );
+
+        // Invalid score: overtime without tie
+        let invalid_overtime = played(vec![20, 18, 22, 20, 12], vec![25, 15, 20, 15, 9]);
+        assert!(
+            plugin
+                .validate_final_score(&sport_config, &invalid_overtime)
+                .is_err()
+        );
+    }
+
+    #[test]
+    fn test_entrant_group_score_timed_periods() {
+        let plugin = GenericSportPlugin::new();
+        let sport_config = basketball_config(&plugin);
+        let (a, b) = (Uuid::new_v4(), Uuid::new_v4());
+        // b wins more periods, but a has the higher total score 80:75
+        let matches = vec![Match::new_played(
+            Uuid::new_v4(),
+            a,
+            b,
+            plugin.id(),
+            vec![20, 18, 30, 12],
+            vec![25, 20, 15, 15],
+        )];
+
+        let score_a = plugin
+            .get_entrant_group_score(&sport_config, Uuid::nil(), a, &matches)
+            .unwrap();
+        assert_eq!(score_a.wins, 1);
+        assert_eq!(score_a.victory_points, 2.0);
+        assert_eq!(score_a.total_score, 80);
+        assert_eq!(score_a.relative_score, 5);
+
+        let score_b = plugin
+            .get_entrant_group_score(&sport_config, Uuid::nil(), b, &matches)
+            .unwrap();
+        assert_eq!(score_b.losses, 1);
+        assert_eq!(score_b.victory_points, 0.0);
+    }
+
+    #[test]
+    fn test_summary() {
+        let volleyball = GenericSportConfig {
+            sets_to_win: 3,
+            score_to_win: Some(25),
+            win_by_margin: Some(2),
+            hard_cap: Some(30),
+            ..Default::default()
+        };
+        let summary = volleyball.summary();
+        assert_eq!((summary.min_sets, summary.max_sets), (3, 5));
+        assert!(!summary.draws_possible);
+        assert_eq!(summary.display_sets(), "3–5 sets");
+        assert_eq!(
+            summary.slot_duration(),
+            std::time::Duration::from_secs(35 * 60)
+        );
+
+        let plugin = GenericSportPlugin::new();
+        let basketball =
+            GenericSportConfig::parse_config(basketball_config(&plugin).get_config().clone())
+                .unwrap();
+        let summary = basketball.summary();
+        assert_eq!((summary.min_sets, summary.max_sets), (4, 5));
+        assert_eq!(summary.sets_label, "periods");
+        assert!(!summary.draws_possible);
+
+        // a single set without margin may end level
+        assert!(GenericSportConfig::default().summary().draws_possible);
+    }
+
+    #[test]
+    fn test_changeover_duration() {
+        let plugin = GenericSportPlugin::new();
+        let config = basketball_config(&plugin);
+        assert_eq!(
+            plugin.changeover_duration(&config).unwrap(),
+            config::CHANGEOVER
+        );
+    }
+
+    #[test]
+    fn test_sport_plugin_conformance() {
+        sport_plugin_testkit::assert_conformance(std::sync::Arc::new(GenericSportPlugin::new()));
+    }
 }
@@ -1,8 +1,13 @@
 //! Implementation of SportPort for Generic Sport Plugin
 
-use super::{GenericSportPlugin, config::GenericSportConfig};
+use super::{
+    GenericSportPlugin,
+    config::{CHANGEOVER, GenericSportConfig, ScoringMode},
+    standing_scores,
+};
 use app_core::{
-    EntrantGroupScore, Match, SportConfig, SportError, SportPort, SportResult,
+    EntrantGroupScore, Match, MatchOutcome, SportCapabilities, SportConfig, SportError, SportPort,
+    SportResult,
     utils::validation::{ValidationErrors, ValidationResult},
 };
 use serde_json::Value;
@@ -16,10 +21,39 @@ impl SportPort for GenericSportPlugin {
     fn get_default_config(&self) -> Value {
         serde_json::to_value(GenericSportConfig::default()).unwrap()
     }
+    fn capabilities(&self) -> SportCapabilities {
+        SportCapabilities {
+            supports_draws: true,
+            per_set_scoring: true,
+            supports_handicap: true,
+            supports_time_cap: false,
+            needs_station_type: None,
+        }
+    }
     fn estimate_match_duration(&self, config: &SportConfig) -> SportResult<Duration> {
         let generic_config = self.validate_config(config, ValidationErrors::new())?;
         Ok(generic_config.expected_match_duration_minutes)
     }
+    fn changeover_duration(&self, config: &SportConfig) -> SportResult<Duration> {
+        self.validate_config(config, ValidationErrors::new())?;
+        Ok(CHANGEOVER)
+    }
+    fn max_sets(&self, config: &SportConfig) -> SportResult<u16> {
+        let generic_config = self.validate_config(config, ValidationErrors::new())?;
+        Ok(generic_config.max_sets())
+    }
+    fn free_ticket_score(&self, config: &SportConfig) -> SportResult<Vec<u16>> {
+        let generic_config = self.validate_config(config, ValidationErrors::new())?;
+        Ok(generic_config.free_ticket_score())
+    }
+    fn standing_scores(
+        &self,
+        config: &SportConfig,
+        m: &Match,
+    ) -> SportResult<(Vec<u16>, Vec<u16>)> {
+        let generic_config = self.validate_config(config, ValidationErrors::new())?;
+        Ok(standing_scores(&generic_config, m))
+    }
     fn validate_config_values(
         &self,
         config: &SportConfig,
@@ -33,6 +67,8 @@ impl SportPort for GenericSportPlugin {
     /// For sports with multiple sets, each set score is validated.
     /// For sports with multiple sets, only one score point per turn is expected.
     /// Therefore constraints like win_by_margin and hard_cap may be reached, but not exceeded.
+    /// For timed periods, scores of all periods are cumulative, see [`TimedPeriods`](crate::config::TimedPeriods).
+    /// Matches decided by forfeit or bye have no scores to validate.
     fn validate_final_score(&self, config: &SportConfig, score: &Match) -> SportResult<()> {
         if score.get_sport_id() != &self.id() {
             return Err(SportError::InvalidScore(
@@ -45,6 +81,9 @@ impl SportPort for GenericSportPlugin {
             ));
         }
         let generic_config = self.validate_config(config, ValidationErrors::new())?;
+        if score.get_outcome().is_walkover() {
+            return Ok(());
+        }
         self.validate_final_score_internal(&generic_config, score)?;
         Ok(())
     }
@@ -60,22 +99,30 @@ impl SportPort for GenericSportPlugin {
         let generic_config = self.validate_config(config, ValidationErrors::new())?;
         let mut group_score = EntrantGroupScore::new(entrant_id, group_id);
         for m in all_matches.iter().filter(|m| {
-            if let Some((id_a, id_b)) = m.get_entrants() {
-                (id_a == &entrant_id || id_b == &entrant_id)
-                    && m.get_group_id() == &group_id
-                    && m.is_played()
-            } else {
-                false
-            }
+            m.get_group_id() == &group_id
+                && m.is_decided()
+                && (m.get_bye_entrant() == Some(&entrant_id)
+                    || m.get_entrants()
+                        .is_some_and(|(id_a, id_b)| id_a == &entrant_id || id_b == &entrant_id))
         }) {
-            // unwrap is safe due to filter
-            let (id_a, _id_b) = m.get_entrants().unwrap();
-            let entrant_is_a = id_a == &entrant_id;
-            let (score_a, score_b) = m.get_scores();
-            let entrant_score = if entrant_is_a { score_a } else { score_b };
-            let opponent_score = if entrant_is_a { score_b } else { score_a };
+            if m.get_outcome() == MatchOutcome::DoubleForfeit {
+                group_score.losses += 1;
+                continue;
+            }
+            let is_bye = m.get_bye_entrant() == Some(&entrant_id);
+            if is_bye {
+                group_score.byes += 1;
+            }
+            // a bye is won by side a and may have no opponent
+            let entrant_is_a = is_bye
+                || m.get_entrants()
+                    .is_some_and(|(id_a, _)| id_a == &entrant_id);
+            let (score_a, score_b) = standing_scores(&generic_config, m);
+            let entrant_score = if entrant_is_a { &score_a } else { &score_b };
+            let opponent_score = if entrant_is_a { &score_b } else { &score_a };
             let mut sets_won = 0;
             let mut sets_lost = 0;
+            let mut score_margin = 0;
             for (&a, &b) in entrant_score.iter().zip(opponent_score.iter()) {
                 if a > b {
                     sets_won += 1;
@@ -84,11 +131,27 @@ impl SportPort for GenericSportPlugin {
                 }
                 group_score.total_score += a;
                 group_score.relative_score += a as i16 - b as i16;
+                score_margin += a as i32 - b as i32;
+            }
+            if let ScoringMode::TimedPeriods(_) = generic_config.scoring_mode {
+                // scores of periods are cumulative, winner has the higher total score
+                sets_won = (score_margin > 0) as i32;
+                sets_lost = (score_margin < 0) as i32;
             }
+            let margin = if generic_config.sets_to_win > 1 {
+                sets_won - sets_lost
+            } else {
+                score_margin
+            };
+            group_score.victory_points += generic_config.bonus_points(margin);
             if sets_won > sets_lost {
                 group_score.victory_points += generic_config.victory_points_win;
+                group_score.wins += 1;
             } else if sets_won == sets_lost {
                 group_score.victory_points += generic_config.victory_points_draw;
+                group_score.draws += 1;
+            } else {
+                group_score.losses += 1;
             }
         }
         Ok(group_score)
@@ -1,6 +1,6 @@
 //! Implementation of sport preview for the generic sport plugin
 
-use crate::config::GenericSportConfig;
+use crate::config::{GenericSportConfig, ScoringMode, TimedPeriods};
 
 use super::GenericSportPlugin;
 use app_core::{
@@ -49,7 +49,7 @@ impl SportPortWebUi for GenericSportPlugin {
                     <div class="flex items-center gap-1 font-semibold text-base-content">
                         <span class="icon-[heroicons--trophy] w-4 h-4 opacity-70"></span>
                         <span data-testid="preview-sets-to-win">
-                            {format!("Sets to win: {}", generic_config.sets_to_win)}
+                            {generic_config.display_match_format()}
                         </span>
                     </div>
 
@@ -80,6 +80,19 @@ impl SportPortWebUi for GenericSportPlugin {
                             <span class="font-bold">"D"</span>
                             {generic_config.victory_points_draw}
                         </span>
+                        {(generic_config.victory_points_bonus > 0.0)
+                            .then(|| {
+                                view! {
+                                    <span
+                                        class="badge badge-sm badge-info badge-outline gap-1"
+                                        title="Bonus Victory Points"
+                                        data-testid="preview-victory-points-bonus"
+                                    >
+                                        <span class="font-bold">"B"</span>
+                                        {generic_config.victory_points_bonus}
+                                    </span>
+                                }
+                            })}
                     </div>
 
                     // Duration
@@ -104,15 +117,19 @@ impl SportPortWebUi for GenericSportPlugin {
         };
         view! {
             <div class="p-2">
-                <span class="font-medium">
-                    {format!("Sets to win: {}", generic_config.sets_to_win)}
-                </span>
+                <span class="font-medium">{generic_config.display_match_format()}</span>
                 <span class="hidden sm:inline text-base-content/30">"|"</span>
                 <span class="font-medium">{generic_config.display_score_limit()}</span>
             </div>
         }
         .into_any()
     }
+    fn render_summary(&self, config: &SportConfig) -> AnyView {
+        match self.validate_config(config, ValidationErrors::new()) {
+            Ok(generic_config) => generic_config.summary().render(),
+            Err(_) => view! { <div>{"Invalid Configuration"}</div> }.into_any(),
+        }
+    }
     fn render_configuration(&self) -> AnyView {
         // get editor context
         let sport_config_editor = expect_context::<SportConfigEditorContext>();
@@ -205,6 +222,111 @@ impl SportPortWebUi for GenericSportPlugin {
                     .set(serde_json::to_value(cfg).unwrap());
             }
         });
+        let victory_points_bonus = Signal::derive(move || {
+            current_config.with(|cfg| cfg.as_ref().map(|c| c.victory_points_bonus))
+        });
+        let set_victory_points_bonus = Callback::new(move |value: Option<f32>| {
+            if let Some(mut cfg) = current_config.get() {
+                cfg.victory_points_bonus = value.unwrap_or_default();
+                sport_config_editor
+                    .set_config
+                    .set(serde_json::to_value(cfg).unwrap());
+            }
+        });
+        let bonus_win_margin = Signal::derive(move || {
+            current_config.with(|cfg| cfg.as_ref().and_then(|c| c.bonus_win_margin))
+        });
+        let set_bonus_win_margin = Callback::new(move |value: Option<u16>| {
+            if let Some(mut cfg) = current_config.get() {
+                cfg.bonus_win_margin = value;
+                sport_config_editor
+                    .set_config
+                    .set(serde_json::to_value(cfg).unwrap());
+            }
+        });
+        let bonus_loss_margin = Signal::derive(move || {
+            current_config.with(|cfg| cfg.as_ref().and_then(|c| c.bonus_loss_margin))
+        });
+        let set_bonus_loss_margin = Callback::new(move |value: Option<u16>| {
+            if let Some(mut cfg) = current_config.get() {
+                cfg.bonus_loss_margin = value;
+                sport_config_editor
+                    .set_config
+                    .set(serde_json::to_value(cfg).unwrap());
+            }
+        });
+        let max_handicap = Signal::derive(move || {
+            current_config.with(|cfg| cfg.as_ref().and_then(|c| c.max_handicap))
+        });
+        let set_max_handicap = Callback::new(move |value: Option<u16>| {
+            if let Some(mut cfg) = current_config.get() {
+                cfg.max_handicap = value;
+                sport_config_editor
+                    .set_config
+                    .set(serde_json::to_value(cfg).unwrap());
+            }
+        });
+        let score_free_ticket = Signal::derive(move || {
+            current_config.with(|cfg| cfg.as_ref().map(|c| c.score_free_ticket))
+        });
+        let set_score_free_ticket = Callback::new(move |value: Option<u16>| {
+            if let Some(mut cfg) = current_config.get() {
+                cfg.score_free_ticket = value.unwrap_or_default();
+                sport_config_editor
+                    .set_config
+                    .set(serde_json::to_value(cfg).unwrap());
+            }
+        });
+        let timed_periods = Signal::derive(move || {
+            current_config.with(|cfg| match cfg.as_ref().map(|c| c.scoring_mode) {
+                Some(ScoringMode::TimedPeriods(timed)) => Some(timed),
+                _ => None,
+            })
+        });
+        // switching to timed periods resets set based scoring; expected match duration
+        // is raised to the playing time of periods
+        let set_timed_periods = Callback::new(move |timed: Option<TimedPeriods>| {
+            if let Some(mut cfg) = current_config.get() {
+                match timed {
+                    Some(timed) => {
+                        cfg.sets_to_win = 1;
+                        cfg.score_to_win = None;
+                        cfg.win_by_margin = None;
+                        cfg.hard_cap = None;
+                        cfg.scoring_mode = ScoringMode::TimedPeriods(timed);
+                        cfg.expected_match_duration_minutes = cfg
+                            .expected_match_duration_minutes
+                            .max(timed.playing_time());
+                    }
+                    None => cfg.scoring_mode = ScoringMode::Sets,
+                }
+                sport_config_editor
+                    .set_config
+                    .set(serde_json::to_value(cfg).unwrap());
+            }
+        });
+        let periods = Signal::derive(move || timed_periods.get().map(|t| t.periods));
+        let set_periods = Callback::new(move |value: Option<u16>| {
+            if let Some(mut timed) = timed_periods.get() {
+                timed.periods = value.unwrap_or_default();
+                set_timed_periods.run(Some(timed));
+            }
+        });
+        let period_minutes = Signal::derive(move || timed_periods.get().map(|t| t.period_minutes));
+        let set_period_minutes = Callback::new(move |value: Option<u16>| {
+            if let Some(mut timed) = timed_periods.get() {
+                timed.period_minutes = value.unwrap_or_default();
+                set_timed_periods.run(Some(timed));
+            }
+        });
+        let overtime_minutes =
+            Signal::derive(move || timed_periods.get().and_then(|t| t.overtime_minutes));
+        let set_overtime_minutes = Callback::new(move |value: Option<u16>| {
+            if let Some(mut timed) = timed_periods.get() {
+                timed.overtime_minutes = value;
+                set_timed_periods.run(Some(timed));
+            }
+        });
         let expected_match_duration_minutes = Signal::derive(move || {
             current_config.with(|cfg| cfg.as_ref().map(|c| c.expected_match_duration_minutes))
         });
@@ -221,52 +343,131 @@ impl SportPortWebUi for GenericSportPlugin {
 
         view! {
             <div class="space-y-4" data-testid="sport-config-configuration">
-                <NumberInput
-                    label="Sets to Win"
-                    name="sets_to_win"
-                    data_testid="input-sets_to_win"
-                    value=sets_to_win
-                    action=InputCommitAction::WriteAndSubmit(set_sets_to_win)
-                    validation_result=validation_result
-                    object_id=sport_config_editor.id
-                    field="sets_to_win"
-                    min="1"
-                />
-                <div class="grid grid-cols-3 gap-4">
-                    <NumberInput
-                        label="Score to Win a Set"
-                        name="score_to_win"
-                        data_testid="input-score_to_win"
-                        value=score_to_win
-                        action=InputCommitAction::WriteAndSubmit(set_score_to_win)
-                        validation_result=validation_result
-                        object_id=sport_config_editor.id
-                        field="score_to_win"
-                        min="1"
-                    />
-                    <NumberInput
-                        label="Win by Margin"
-                        name="win_by_margin"
-                        data_testid="input-win_by_margin"
-                        value=win_by_margin
-                        action=InputCommitAction::WriteAndSubmit(set_win_by_margin)
-                        validation_result=validation_result
-                        object_id=sport_config_editor.id
-                        field="win_by_margin"
-                        min="1"
+                <label class="label cursor-pointer gap-2 justify-start">
+                    <input
+                        type="checkbox"
+                        class="toggle"
+                        name="timed_periods"
+                        data-testid="input-timed_periods"
+                        prop:checked=move || timed_periods.get().is_some()
+                        on:change:target=move |ev| {
+                            set_timed_periods
+                                .run(ev.target().checked().then(TimedPeriods::default));
+                            ev.target().form().map(|f| f.request_submit());
+                        }
                     />
-                    <NumberInput
-                        label="Hard Cap"
-                        name="hard_cap"
-                        data_testid="input-hard_cap"
-                        value=hard_cap
-                        action=InputCommitAction::WriteAndSubmit(set_hard_cap)
-                        validation_result=validation_result
-                        object_id=sport_config_editor.id
-                        field="hard_cap"
-                        min="1"
-                    />
-                </div>
+                    <span class="label-text">"Timed Periods"</span>
+                </label>
+                <Show
+                    when=move || timed_periods.get().is_some()
+                    fallback=move || {
+                        view! {
+                            <NumberInput
+                                label="Sets to Win"
+                                name="sets_to_win"
+                                data_testid="input-sets_to_win"
+                                value=sets_to_win
+                                action=InputCommitAction::WriteAndSubmit(set_sets_to_win)
+                                validation_result=validation_result
+                                object_id=sport_config_editor.id
+                                field="sets_to_win"
+                                min="1"
+                            />
+                            <div class="grid grid-cols-3 gap-4">
+                                <NumberInput
+                                    label="Score to Win a Set"
+                                    name="score_to_win"
+                                    data_testid="input-score_to_win"
+                                    value=score_to_win
+                                    action=InputCommitAction::WriteAndSubmit(set_score_to_win)
+                                    validation_result=validation_result
+                                    object_id=sport_config_editor.id
+                                    field="score_to_win"
+                                    min="1"
+                                />
+                                <NumberInput
+                                    label="Win by Margin"
+                                    name="win_by_margin"
+                                    data_testid="input-win_by_margin"
+                                    value=win_by_margin
+                                    action=InputCommitAction::WriteAndSubmit(set_win_by_margin)
+                                    validation_result=validation_result
+                                    object_id=sport_config_editor.id
+                                    field="win_by_margin"
+                                    min="1"
+                                />
+                                <NumberInput
+                                    label="Hard Cap"
+                                    name="hard_cap"
+                                    data_testid="input-hard_cap"
+                                    value=hard_cap
+                                    action=InputCommitAction::WriteAndSubmit(set_hard_cap)
+                                    validation_result=validation_result
+                                    object_id=sport_config_editor.id
+                                    field="hard_cap"
+                                    min="1"
+                                />
+                            </div>
+                        }
+                    }
+                >
+                    <div class="grid grid-cols-3 gap-4">
+                        <NumberInput
+                            label="Periods"
+                            name="periods"
+                            data_testid="input-periods"
+                            value=periods
+                            action=InputCommitAction::WriteAndSubmit(set_periods)
+                            validation_result=validation_result
+                            object_id=sport_config_editor.id
+                            field="periods"
+                            min="1"
+                        />
+                        <NumberInput
+                            label="Minutes per Period"
+                            name="period_minutes"
+                            data_testid="input-period_minutes"
+                            value=period_minutes
+                            action=InputCommitAction::WriteAndSubmit(set_period_minutes)
+                            validation_result=validation_result
+                            object_id=sport_config_editor.id
+                            field="period_minutes"
+                            min="1"
+                        />
+                        <NumberInput
+                            label="Overtime Minutes"
+                            name="overtime_minutes"
+                            data_testid="input-overtime_minutes"
+                            value=overtime_minutes
+                            action=InputCommitAction::WriteAndSubmit(set_overtime_minutes)
+                            validation_result=validation_result
+                            object_id=sport_config_editor.id
+                            field="overtime_minutes"
+                            optional=true
+                            placeholder="no overtime"
+                            min="1"
+                        />
+                    </div>
+                    <label class="label cursor-pointer gap-2 justify-start">
+                        <input
+                            type="checkbox"
+                            class="toggle"
+                            name="draws_allowed"
+                            data-testid="input-draws_allowed"
+                            prop:checked=move || {
+                                timed_periods.get().is_some_and(|t| t.draws_allowed)
+                            }
+                            on:change:target=move |ev| {
+                                if let Some(mut timed) = timed_periods.get() {
+                                    timed.draws_allowed = ev.target().checked();
+                                    set_timed_periods.run(Some(timed));
+                                }
+                                ev.target().form().map(|f| f.request_submit());
+                            }
+                        />
+                        <span class="label-text">"Draws Allowed"</span>
+                    </label>
+                </Show>
                 <div class="grid grid-cols-2 gap-4">
                     <NumberInput
                         label="Victory Points for Win"
@@ -293,6 +494,72 @@ impl SportPortWebUi for GenericSportPlugin {
                         step="0.1"
                     />
                 </div>
+                <div class="grid grid-cols-3 gap-4">
+                    <NumberInput
+                        label="Bonus Victory Points"
+                        name="victory_points_bonus"
+                        data_testid="input-victory_points_bonus"
+                        value=victory_points_bonus
+                        action=InputCommitAction::WriteAndSubmit(set_victory_points_bonus)
+                        validation_result=validation_result
+                        object_id=sport_config_editor.id
+                        field="victory_points_bonus"
+                        min="0"
+                        step="0.1"
+                    />
+                    <NumberInput
+                        label="Bonus Win Margin"
+                        name="bonus_win_margin"
+                        data_testid="input-bonus_win_margin"
+                        value=bonus_win_margin
+                        action=InputCommitAction::WriteAndSubmit(set_bonus_win_margin)
+                        validation_result=validation_result
+                        object_id=sport_config_editor.id
+                        field="bonus_win_margin"
+                        optional=true
+                        placeholder="no bonus"
+                        min="1"
+                    />
+                    <NumberInput
+                        label="Bonus Loss Margin"
+                        name="bonus_loss_margin"
+                        data_testid="input-bonus_loss_margin"
+                        value=bonus_loss_margin
+                        action=InputCommitAction::WriteAndSubmit(set_bonus_loss_margin)
+                        validation_result=validation_result
+                        object_id=sport_config_editor.id
+                        field="bonus_loss_margin"
+                        optional=true
+                        placeholder="no bonus"
+                        min="1"
+                    />
+                </div>
+                <div class="grid grid-cols-2 gap-4">
+                    <NumberInput
+                        label="Maximum Handicap"
+                        name="max_handicap"
+                        data_testid="input-max_handicap"
+                        value=max_handicap
+                        action=InputCommitAction::WriteAndSubmit(set_max_handicap)
+                        validation_result=validation_result
+                        object_id=sport_config_editor.id
+                        field="max_handicap"
+                        optional=true
+                        placeholder="no handicaps"
+                        min="1"
+                    />
+                    <NumberInput
+                        label="Free Ticket Score"
+                        name="score_free_ticket"
+                        data_testid="input-score_free_ticket"
+                        value=score_free_ticket
+                        action=InputCommitAction::WriteAndSubmit(set_score_free_ticket)
+                        validation_result=validation_result
+                        object_id=sport_config_editor.id
+                        field="score_free_ticket"
+                        min="1"
+                    />
+                </div>
                 <DurationInput
                     label="Expected Match Duration"
                     name="expected_match_duration_minutes"
@@ -304,6 +571,10 @@ impl SportPortWebUi for GenericSportPlugin {
                     field="expected_match_duration_minutes"
                     unit=DurationInputUnit::Minutes
                 />
+                <div class="space-y-1" data-testid="sport-config-live-preview">
+                    <span class="label-text">"Preview"</span>
+                    {preview}
+                </div>
             </div>
         }
         .into_any()
@@ -0,0 +1,101 @@
+// geocoding of postal addresses with the search API of a Nominatim server
+
+use app_core::{GeoPoint, GeocodingError, GeocodingPort, GeocodingResult, PostalAddress};
+use async_trait::async_trait;
+use reqwest::Client;
+use serde::Deserialize;
+use std::{env, time::Duration};
+use tracing::{instrument, warn};
+
+/// default timeout of a single geocoding request
+pub const DEFAULT_TIMEOUT: Duration = Duration::from_secs(10);
+
+/// one hit of the Nominatim search API; coordinates are returned as strings
+#[derive(Debug, Deserialize)]
+struct SearchHit {
+    lat: String,
+    lon: String,
+}
+
+/// Geocoding with the structured search of a Nominatim server, e.g.
+/// `https://nominatim.openstreetmap.org`. Mind the usage policy of public servers:
+/// addresses are only geocoded on save of changed addresses.
+#[derive(Clone)]
+pub struct NominatimGeocoding {
+    client: Client,
+    base_url: String,
+}
+
+impl NominatimGeocoding {
+    pub fn new(base_url: impl Into<String>, timeout: Duration) -> GeocodingResult<Self> {
+        let client = Client::builder()
+            .timeout(timeout)
+            .user_agent(concat!("fk_tournament_planer/", env!("CARGO_PKG_VERSION")))
+            .build()
+            .map_err(|e| GeocodingError::Request(e.to_string()))?;
+        Ok(NominatimGeocoding {
+            client,
+            base_url: base_url.into().trim_end_matches('/').to_string(),
+        })
+    }
+
+    /// Geocoding with the server of `NOMINATIM_URL`; `None`, if the variable is not set.
+    pub fn from_env() -> GeocodingResult<Option<Self>> {
+        match env::var("NOMINATIM_URL") {
+            Ok(url) if !url.trim().is_empty() => Self::new(url.trim(), DEFAULT_TIMEOUT).map(Some),
+            _ => Ok(None),
+        }
+    }
+}
+
+/// Parse the first hit of a search response.
+fn parse_response(body: &str) -> GeocodingResult<Option<GeoPoint>> {
+    let hits: Vec<SearchHit> =
+        serde_json::from_str(body).map_err(|e| GeocodingError::InvalidResponse(e.to_string()))?;
+    let Some(hit) = hits.into_iter().next() else {
+        return Ok(None);
+    };
+    let parse = |value: &str| {
+        value
+            .parse::<f64>()
+            .map_err(|e| GeocodingError::InvalidResponse(format!("{value}: {e}")))
+    };
+    Ok(Some(GeoPoint::new(parse(&hit.lat)?, parse(&hit.lon)?)))
+}
+
+#[async_trait]
+impl GeocodingPort for NominatimGeocoding {
+    #[instrument(name = "geocoding.search", skip_all, fields(locality = address.get_locality()))]
+    async fn geocode(&self, address: &PostalAddress) -> GeocodingResult<Option<GeoPoint>> {
+        let mut query = vec![
+            ("format", "jsonv2"),
+            ("limit", "1"),
+            ("street", address.get_street()),
+            ("postalcode", address.get_postal_code()),
+            ("city", address.get_locality()),
+        ];
+        if let Some(region) = address.get_region() {
+            query.push(("state", region));
+        }
+        if let Some(country) = address.get_country() {
+            query.push(("countrycodes", country.alpha2()));
+        }
+        let response = self
+            .client
+            .get(format!("{}/search", self.base_url))
+            .query(&query)
+            .send()
+            .await
+            .and_then(|r| r.error_for_status())
+            .map_err(|e| {
+                warn!(error = %e, "geocoding_request_failed");
+                GeocodingError::Request(e.to_string())
+            })?;
+        let body = response
+            .text()
+            .await
+            .map_err(|e| GeocodingError::Request(e.to_string()))?;
+        parse_response(&body)
+    }
+}
+
@@ -0,0 +1,56 @@
+//! Fake for BlobStoragePort
+
+use app_core::{Blob, BlobError, BlobResult, BlobStoragePort, is_valid_blob_key};
+use async_trait::async_trait;
+use std::{
+    collections::HashMap,
+    sync::{Arc, Mutex},
+};
+
+/// Stores blobs in memory.
+#[derive(Clone, Default)]
+pub struct FakeBlobStorage {
+    blobs: Arc<Mutex<HashMap<String, Blob>>>,
+    fail_next_put: Arc<Mutex<bool>>,
+}
+
+impl FakeBlobStorage {
+    pub fn new() -> Self {
+        Self::default()
+    }
+    /// keys of all stored blobs, sorted
+    pub fn keys(&self) -> Vec<String> {
+        let mut keys: Vec<String> = self.blobs.lock().unwrap().keys().cloned().collect();
+        keys.sort();
+        keys
+    }
+    pub fn fail_put_once(&self) {
+        *self.fail_next_put.lock().unwrap() = true;
+    }
+}
+
+#[async_trait]
+impl BlobStoragePort for FakeBlobStorage {
+    async fn put_blob(&self, key: &str, blob: &Blob) -> BlobResult<()> {
+        if !is_valid_blob_key(key) {
+            return Err(BlobError::InvalidKey(key.to_string()));
+        }
+        let mut guard = self.fail_next_put.lock().unwrap();
+        if *guard {
+            *guard = false;
+            return Err(BlobError::Storage("injected put failure".into()));
+        }
+        self.blobs
+            .lock()
+            .unwrap()
+            .insert(key.to_string(), blob.clone());
+        Ok(())
+    }
+
+    async fn get_blob(&self, key: &str) -> BlobResult<Option<Blob>> {
+        if !is_valid_blob_key(key) {
+            return Err(BlobError::InvalidKey(key.to_string()));
+        }
+        Ok(self.blobs.lock().unwrap().get(key).cloned())
+    }
+}
@@ -0,0 +1,125 @@
+//! Fakes for DbpApiToken port
+
+use super::FakeDatabasePort;
+use app_core::{
+    ApiToken, DbError, DbResult, DbpApiToken,
+    utils::{id_version::IdVersion, traits::ObjectIdVersion},
+};
+use async_trait::async_trait;
+use chrono::{DateTime, Utc};
+use uuid::Uuid;
+
+#[async_trait]
+impl DbpApiToken for FakeDatabasePort {
+    async fn get_api_token(&self, token_id: Uuid) -> DbResult<Option<ApiToken>> {
+        let mut guard = self.fail_next_get_token.lock().unwrap();
+        if *guard {
+            *guard = false;
+            return Err(DbError::Other("injected get failure".into()));
+        }
+        Ok(self.api_tokens.lock().unwrap().get(&token_id).cloned())
+    }
+
+    async fn get_api_token_by_hash(&self, hash: &str) -> DbResult<Option<ApiToken>> {
+        let mut guard = self.fail_next_get_token.lock().unwrap();
+        if *guard {
+            *guard = false;
+            return Err(DbError::Other("injected get failure".into()));
+        }
+        Ok(self
+            .api_tokens
+            .lock()
+            .unwrap()
+            .values()
+            .find(|t| t.get_secret_hash() == hash)
+            .cloned())
+    }
+
+    async fn save_api_token(&self, token: &ApiToken) -> DbResult<ApiToken> {
+        let mut guard = self.fail_next_save_token.lock().unwrap();
+        if *guard {
+            *guard = false;
+            return Err(DbError::Other("injected save failure".into()));
+        }
+
+        let mut guard = self.api_tokens.lock().unwrap();
+
+        // Simulate unique index on name; name is citext
+        if guard.values().any(|t| {
+            t.get_id() != token.get_id()
+                && t.get_name().to_lowercase() == token.get_name().to_lowercase()
+        }) {
+            return Err(DbError::UniqueViolation(Some(
+                "uniq_api_tokens_name".into(),
+            )));
+        }
+
+        let mut new = token.clone();
+        match token.get_id_version() {
+            IdVersion::Existing(inner) => {
+                if let Some(existing) = guard.get(&inner.get_id()) {
+                    // Check Optimistic Locking
+                    let existing_v = existing.get_version().unwrap_or(0);
+                    if existing_v != inner.get_version() {
+                        return Err(DbError::OptimisticLockConflict);
+                    }
+                    // secret and timestamps are not changed by updates
+                    new.set_id_version(IdVersion::new(inner.get_id(), Some(existing_v + 1)))
+                        .set_secret_prefix_and_hash(
+                            existing.get_secret_prefix(),
+                            existing.get_secret_hash(),
+                        )
+                        .set_created_at(existing.get_created_at())
+                        .set_last_used_at(existing.get_last_used_at());
+                } else {
+                    return Err(DbError::NotFound);
+                }
+            }
+            IdVersion::NewWithId(id) => {
+                if guard.contains_key(&id) {
+                    return Err(DbError::UniqueViolation(Some("api_tokens_pkey".into())));
+                }
+                new.set_id_version(IdVersion::new(id, Some(0)))
+                    .set_created_at(Some(Utc::now()))
+                    .set_last_used_at(None);
+            }
+        }
+
+        guard.insert(new.get_id(), new.clone());
+        Ok(new)
+    }
+
+    async fn touch_api_token_last_used(&self, token_id: Uuid, at: DateTime<Utc>) -> DbResult<()> {
+        let mut guard = self.read_only_touch_token.lock().unwrap();
+        if *guard {
+            *guard = false;
+            return Err(DbError::ReadOnly);
+        }
+        match self.api_tokens.lock().unwrap().get_mut(&token_id) {
+            Some(token) => {
+                token.set_last_used_at(Some(at));
+                Ok(())
+            }
+            None => Err(DbError::NotFound),
+        }
+    }
+
+    async fn list_api_tokens(&self, include_revoked: bool) -> DbResult<Vec<ApiToken>> {
+        let mut guard = self.fail_next_list_token.lock().unwrap();
+        if *guard {
+            *guard = false;
+            return Err(DbError::Other("injected list failure".into()));
+        }
+
+        let mut rows: Vec<_> = self
+            .api_tokens
+            .lock()
+            .unwrap()
+            .values()
+            .filter(|t| include_revoked || !t.is_revoked())
+            .cloned()
+            .collect();
+        rows.sort_by_key(|t| t.get_name().to_lowercase());
+        Ok(rows)
+    }
+}
@@ -0,0 +1,55 @@
+//! Fakes for DbpAuditLog port
+
+use super::FakeDatabasePort;
+use app_core::{
+    AuditRecord, DbError, DbResult, DbpAuditLog,
+    utils::{id_version::IdVersion, traits::ObjectIdVersion},
+};
+use async_trait::async_trait;
+use chrono::Utc;
+use uuid::Uuid;
+
+#[async_trait]
+impl DbpAuditLog for FakeDatabasePort {
+    async fn save_audit_record(&self, record: &AuditRecord) -> DbResult<AuditRecord> {
+        let mut guard = self.fail_next_save_audit_record.lock().unwrap();
+        if *guard {
+            *guard = false;
+            return Err(DbError::Other("injected save failure".into()));
+        }
+
+        let IdVersion::NewWithId(id) = record.get_id_version() else {
+            return Err(DbError::Other("audit records are append only".into()));
+        };
+        let mut guard = self.audit_records.lock().unwrap();
+        if guard.iter().any(|r| r.get_id() == id) {
+            return Err(DbError::UniqueViolation(Some("audit_log_pkey".into())));
+        }
+        let mut new = record.clone();
+        new.set_id_version(IdVersion::new(id, Some(0)))
+            .set_created_at(Some(Utc::now()));
+        guard.push(new.clone());
+        Ok(new)
+    }
+
+    async fn list_audit_records_of_tournament(
+        &self,
+        tournament_id: Uuid,
+        limit: Option<usize>,
+    ) -> DbResult<Vec<AuditRecord>> {
+        // insertion order is order of creation; list newest first
+        let mut rows: Vec<_> = self
+            .audit_records
+            .lock()
+            .unwrap()
+            .iter()
+            .rev()
+            .filter(|r| r.get_tournament_id() == Some(tournament_id))
+            .cloned()
+            .collect();
+        if let Some(lim) = limit {
+            rows.truncate(lim);
+        }
+        Ok(rows)
+    }
+}
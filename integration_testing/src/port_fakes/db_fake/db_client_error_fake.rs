@@ -0,0 +1,51 @@
+//! Fakes for DbpClientError port
+
+use super::FakeDatabasePort;
+use app_core::{
+    ClientErrorReport, DbError, DbResult, DbpClientError,
+    utils::{id_version::IdVersion, traits::ObjectIdVersion},
+};
+use async_trait::async_trait;
+use chrono::Utc;
+
+#[async_trait]
+impl DbpClientError for FakeDatabasePort {
+    async fn save_client_error(&self, report: &ClientErrorReport) -> DbResult<ClientErrorReport> {
+        let mut guard = self.fail_next_save_client_error.lock().unwrap();
+        if *guard {
+            *guard = false;
+            return Err(DbError::Other("injected save failure".into()));
+        }
+
+        let IdVersion::NewWithId(id) = report.get_id_version() else {
+            return Err(DbError::Other(
+                "client error reports are append only".into(),
+            ));
+        };
+        let mut guard = self.client_errors.lock().unwrap();
+        if guard.iter().any(|r| r.get_id() == id) {
+            return Err(DbError::UniqueViolation(Some("client_errors_pkey".into())));
+        }
+        let mut new = report.clone();
+        new.set_id_version(IdVersion::new(id, Some(0)))
+            .set_created_at(Some(Utc::now()));
+        guard.push(new.clone());
+        Ok(new)
+    }
+
+    async fn list_client_errors(&self, limit: Option<usize>) -> DbResult<Vec<ClientErrorReport>> {
+        // insertion order is order of creation; list newest first
+        let mut rows: Vec<_> = self
+            .client_errors
+            .lock()
+            .unwrap()
+            .iter()
+            .rev()
+            .cloned()
+            .collect();
+        if let Some(lim) = limit {
+            rows.truncate(lim);
+        }
+        Ok(rows)
+    }
+}
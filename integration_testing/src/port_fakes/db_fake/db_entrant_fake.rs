@@ -0,0 +1,84 @@
+//! Fakes for DbpEntrant port
+
+use super::FakeDatabasePort;
+use app_core::{
+    DbError, DbResult, DbpEntrant, Entrant,
+    utils::{id_version::IdVersion, traits::ObjectIdVersion},
+};
+use async_trait::async_trait;
+use uuid::Uuid;
+
+#[async_trait]
+impl DbpEntrant for FakeDatabasePort {
+    async fn get_entrant(&self, entrant_id: Uuid) -> DbResult<Option<Entrant>> {
+        let mut guard = self.fail_next_get_entrant.lock().unwrap();
+        if *guard {
+            *guard = false;
+            return Err(DbError::Other("injected get failure".into()));
+        }
+        Ok(self.entrants.lock().unwrap().get(&entrant_id).cloned())
+    }
+
+    async fn save_entrant(&self, entrant: &Entrant) -> DbResult<Entrant> {
+        let mut guard = self.fail_next_save_entrant.lock().unwrap();
+        if *guard {
+            *guard = false;
+            return Err(DbError::Other("injected save failure".into()));
+        }
+
+        let mut guard = self.entrants.lock().unwrap();
+
+        // Simulate unique index on (tournament_id, name); name is citext
+        if guard.values().any(|e| {
+            e.get_id() != entrant.get_id()
+                && e.get_tournament_id() == entrant.get_tournament_id()
+                && e.get_name().to_lowercase() == entrant.get_name().to_lowercase()
+        }) {
+            return Err(DbError::UniqueViolation(Some(
+                "uniq_entrants_name_per_tournament".into(),
+            )));
+        }
+
+        let mut new = entrant.clone();
+        match entrant.get_id_version() {
+            IdVersion::Existing(inner) => {
+                if let Some(existing) = guard.get(&inner.get_id()) {
+                    // Check Optimistic Locking
+                    let existing_v = existing.get_version().unwrap_or(0);
+                    if existing_v != inner.get_version() {
+                        return Err(DbError::OptimisticLockConflict);
+                    }
+                    new.set_id_version(IdVersion::new(inner.get_id(), Some(existing_v + 1)));
+                } else {
+                    return Err(DbError::NotFound);
+                }
+            }
+            IdVersion::NewWithId(id) => {
+                if guard.contains_key(&id) {
+                    return Err(DbError::UniqueViolation(Some("entrants_pkey".into())));
+                }
+                new.set_id_version(IdVersion::new(id, Some(0)));
+            }
+        }
+
+        guard.insert(new.get_id(), new.clone());
+        Ok(new)
+    }
+
+    async fn list_entrants_of_tournament(&self, t_id: Uuid) -> DbResult<Vec<Entrant>> {
+        let mut guard = self.fail_next_list_entrant.lock().unwrap();
+        if *guard {
+            *guard = false;
+            return Err(DbError::Other("injected list failure".into()));
+        }
+
+        Ok(self
+            .entrants
+            .lock()
+            .unwrap()
+            .values()
+            .filter(|e| e.get_tournament_id() == t_id)
+            .cloned()
+            .collect())
+    }
+}
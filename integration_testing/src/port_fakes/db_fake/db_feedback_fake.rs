@@ -0,0 +1,49 @@
+//! Fakes for DbpFeedback port
+
+use super::FakeDatabasePort;
+use app_core::{
+    DbError, DbResult, DbpFeedback, Feedback,
+    utils::{id_version::IdVersion, traits::ObjectIdVersion},
+};
+use async_trait::async_trait;
+use chrono::Utc;
+
+#[async_trait]
+impl DbpFeedback for FakeDatabasePort {
+    async fn save_feedback(&self, feedback: &Feedback) -> DbResult<Feedback> {
+        let mut guard = self.fail_next_save_feedback.lock().unwrap();
+        if *guard {
+            *guard = false;
+            return Err(DbError::Other("injected save failure".into()));
+        }
+
+        let IdVersion::NewWithId(id) = feedback.get_id_version() else {
+            return Err(DbError::Other("feedback is append only".into()));
+        };
+        let mut guard = self.feedback.lock().unwrap();
+        if guard.iter().any(|f| f.get_id() == id) {
+            return Err(DbError::UniqueViolation(Some("feedback_pkey".into())));
+        }
+        let mut new = feedback.clone();
+        new.set_id_version(IdVersion::new(id, Some(0)))
+            .set_created_at(Some(Utc::now()));
+        guard.push(new.clone());
+        Ok(new)
+    }
+
+    async fn list_feedback(&self, limit: Option<usize>) -> DbResult<Vec<Feedback>> {
+        // insertion order is order of creation; list newest first
+        let mut rows: Vec<_> = self
+            .feedback
+            .lock()
+            .unwrap()
+            .iter()
+            .rev()
+            .cloned()
+            .collect();
+        if let Some(lim) = limit {
+            rows.truncate(lim);
+        }
+        Ok(rows)
+    }
+}
@@ -0,0 +1,61 @@
+//! Fakes for DbpGroup port
+
+use super::FakeDatabasePort;
+use app_core::{
+    DbError, DbResult, DbpGroup, Group,
+    utils::{id_version::IdVersion, traits::ObjectIdVersion},
+};
+use async_trait::async_trait;
+use uuid::Uuid;
+
+#[async_trait]
+impl DbpGroup for FakeDatabasePort {
+    async fn get_group(&self, group_id: Uuid) -> DbResult<Option<Group>> {
+        Ok(self.groups.lock().unwrap().get(&group_id).cloned())
+    }
+
+    async fn list_groups_of_stage(&self, stage_id: Uuid) -> DbResult<Vec<Group>> {
+        Ok(self.groups_of(stage_id))
+    }
+}
+
+impl FakeDatabasePort {
+    /// save group like a save of a tournament diff
+    pub(super) fn write_group(&self, group: &Group) -> DbResult<Group> {
+        let mut guard = self.groups.lock().unwrap();
+        let mut new = group.clone();
+
+        match group.get_id_version() {
+            IdVersion::Existing(inner) => {
+                let Some(existing) = guard.get(&inner.get_id()) else {
+                    return Err(DbError::NotFound);
+                };
+                let existing_v = existing.get_version().unwrap_or(0);
+                if existing_v != inner.get_version() {
+                    return Err(DbError::OptimisticLockConflict);
+                }
+                new.set_id_version(IdVersion::new(inner.get_id(), Some(existing_v + 1)));
+            }
+            IdVersion::NewWithId(id) => {
+                if guard.contains_key(&id) {
+                    return Err(DbError::Other(format!(
+                        "Group with ID {} already exists",
+                        id
+                    )));
+                }
+                new.set_id_version(IdVersion::new(id, Some(0)));
+            }
+        }
+        // number of group is unique per stage
+        if guard.values().any(|g| {
+            g.get_id() != new.get_id()
+                && g.get_stage_id() == new.get_stage_id()
+                && g.get_number() == new.get_number()
+        }) {
+            return Err(DbError::UniqueViolation(Some("number".into())));
+        }
+
+        guard.insert(new.get_id(), new.clone());
+        Ok(new)
+    }
+}
@@ -0,0 +1,21 @@
+//! Fakes for DbpGroupSeeding port
+
+use super::FakeDatabasePort;
+use app_core::{DbResult, DbpGroupSeeding, SavedGroupSeeding};
+use async_trait::async_trait;
+use uuid::Uuid;
+
+#[async_trait]
+impl DbpGroupSeeding for FakeDatabasePort {
+    async fn get_group_seeding(&self, stage_id: Uuid) -> DbResult<Option<SavedGroupSeeding>> {
+        Ok(self.group_seedings.lock().unwrap().get(&stage_id).cloned())
+    }
+
+    async fn save_group_seeding(&self, seeding: &SavedGroupSeeding) -> DbResult<()> {
+        self.group_seedings
+            .lock()
+            .unwrap()
+            .insert(seeding.stage_id, seeding.clone());
+        Ok(())
+    }
+}
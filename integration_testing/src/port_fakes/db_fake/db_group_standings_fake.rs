@@ -0,0 +1,48 @@
+//! Fakes for DbpGroupStandings port
+
+use super::FakeDatabasePort;
+use app_core::{CachedGroupStandings, DbError, DbResult, DbpGroupStandings};
+use async_trait::async_trait;
+use uuid::Uuid;
+
+#[async_trait]
+impl DbpGroupStandings for FakeDatabasePort {
+    async fn get_group_standings(&self, group_id: Uuid) -> DbResult<Option<CachedGroupStandings>> {
+        Ok(self.group_standings.lock().unwrap().get(&group_id).cloned())
+    }
+
+    async fn save_group_standings(&self, standings: &CachedGroupStandings) -> DbResult<()> {
+        let mut guard = self.fail_next_save_gs.lock().unwrap();
+        if *guard {
+            *guard = false;
+            return Err(DbError::Other("injected save failure".into()));
+        }
+
+        self.group_standings
+            .lock()
+            .unwrap()
+            .insert(standings.group_id, standings.clone());
+        Ok(())
+    }
+
+    async fn delete_group_standings(&self, group_id: Uuid) -> DbResult<()> {
+        self.group_standings.lock().unwrap().remove(&group_id);
+        Ok(())
+    }
+
+    async fn list_group_standings_of_tournament(
+        &self,
+        tournament_id: Uuid,
+    ) -> DbResult<Vec<CachedGroupStandings>> {
+        let mut rows: Vec<_> = self
+            .group_standings
+            .lock()
+            .unwrap()
+            .values()
+            .filter(|s| s.tournament_id == tournament_id)
+            .cloned()
+            .collect();
+        rows.sort_by_key(|s| s.group_id);
+        Ok(rows)
+    }
+}
@@ -0,0 +1,94 @@
+//! Fakes for DbpMatch port
+
+use super::FakeDatabasePort;
+use app_core::{DbError, DbResult, DbpMatch, Match};
+use async_trait::async_trait;
+use uuid::Uuid;
+
+#[async_trait]
+impl DbpMatch for FakeDatabasePort {
+    async fn get_match(&self, match_id: Uuid) -> DbResult<Option<Match>> {
+        Ok(self.matches.lock().unwrap().get(&match_id).cloned())
+    }
+
+    async fn save_matches(&self, matches: &[Match]) -> DbResult<Vec<Match>> {
+        let mut guard = self.fail_next_save_match.lock().unwrap();
+        if *guard {
+            *guard = false;
+            return Err(DbError::Other("injected save failure".into()));
+        }
+
+        let mut stored = self.matches.lock().unwrap();
+        // check all versions first, since either all or none of the matches are saved
+        for m in matches {
+            if let Some(version) = m.get_version() {
+                match stored.get(m.get_id()) {
+                    Some(existing) if existing.get_version().unwrap_or(0) == version => {}
+                    Some(existing) => {
+                        return Err(DbError::VersionConflict {
+                            current_version: existing.get_version().unwrap_or(0),
+                        });
+                    }
+                    None => return Err(DbError::NotFound),
+                }
+            }
+        }
+        let mut saved = Vec::with_capacity(matches.len());
+        for m in matches {
+            let mut new = m.clone();
+            let version = match stored.get(m.get_id()) {
+                Some(existing) => existing.get_version().unwrap_or(0) + 1,
+                None => 0,
+            };
+            new.set_version(Some(version));
+            stored.insert(*m.get_id(), new.clone());
+            saved.push(new);
+        }
+        Ok(saved)
+    }
+
+    async fn delete_matches_of_stage(&self, stage_id: Uuid) -> DbResult<()> {
+        self.matches
+            .lock()
+            .unwrap()
+            .retain(|_, m| *m.get_stage_id() != stage_id);
+        Ok(())
+    }
+
+    async fn list_matches_of_stage(&self, stage_id: Uuid) -> DbResult<Vec<Match>> {
+        let mut rows: Vec<_> = self
+            .matches
+            .lock()
+            .unwrap()
+            .values()
+            .filter(|m| *m.get_stage_id() == stage_id)
+            .cloned()
+            .collect();
+        rows.sort_by_key(|m| (*m.get_group_id(), *m.get_round_id(), m.get_number()));
+        Ok(rows)
+    }
+
+    async fn list_matches_of_tournament(&self, tournament_id: Uuid) -> DbResult<Vec<Match>> {
+        let mut rows: Vec<_> = self
+            .matches
+            .lock()
+            .unwrap()
+            .values()
+            .filter(|m| *m.get_tournament_id() == tournament_id)
+            .cloned()
+            .collect();
+        rows.sort_by_key(|m| (m.get_start_at(), m.get_station()));
+        Ok(rows)
+    }
+
+    async fn list_matches_of_stage_for_update(&self, stage_id: Uuid) -> DbResult<Vec<Match>> {
+        self.list_matches_of_stage(stage_id).await
+    }
+
+    async fn list_matches_of_tournament_for_update(
+        &self,
+        tournament_id: Uuid,
+    ) -> DbResult<Vec<Match>> {
+        self.list_matches_of_tournament(tournament_id).await
+    }
+}
@@ -0,0 +1,83 @@
+//! Fakes for DbpMatchNote port
+
+use super::FakeDatabasePort;
+use app_core::{
+    DbError, DbResult, DbpMatchNote, MatchNote,
+    utils::{id_version::IdVersion, traits::ObjectIdVersion},
+};
+use async_trait::async_trait;
+use chrono::Utc;
+use uuid::Uuid;
+
+#[async_trait]
+impl DbpMatchNote for FakeDatabasePort {
+    async fn get_match_note_of_match(&self, match_id: Uuid) -> DbResult<Option<MatchNote>> {
+        Ok(self
+            .match_notes
+            .lock()
+            .unwrap()
+            .values()
+            .find(|n| n.get_match_id() == match_id)
+            .cloned())
+    }
+
+    async fn save_match_note(&self, note: &MatchNote) -> DbResult<MatchNote> {
+        let mut guard = self.fail_next_save_mn.lock().unwrap();
+        if *guard {
+            *guard = false;
+            return Err(DbError::Other("injected save failure".into()));
+        }
+
+        let mut guard = self.match_notes.lock().unwrap();
+        let mut new = note.clone();
+
+        match note.get_id_version() {
+            IdVersion::Existing(inner) => {
+                if let Some(existing) = guard.get(&inner.get_id()) {
+                    // Check Optimistic Locking
+                    let existing_v = existing.get_version().unwrap_or(0);
+                    if existing_v != inner.get_version() {
+                        return Err(DbError::OptimisticLockConflict);
+                    }
+                    new.set_id_version(IdVersion::new(inner.get_id(), Some(existing_v + 1)));
+                } else {
+                    return Err(DbError::NotFound);
+                }
+            }
+            IdVersion::NewWithId(id) => {
+                if guard.contains_key(&id) {
+                    return Err(DbError::UniqueViolation(Some("match_notes_pkey".into())));
+                }
+                if guard
+                    .values()
+                    .any(|n| n.get_match_id() == note.get_match_id())
+                {
+                    return Err(DbError::UniqueViolation(Some(
+                        "uq_match_notes_match".into(),
+                    )));
+                }
+                new.set_id_version(IdVersion::new(id, Some(0)));
+            }
+        }
+        new.set_updated_at(Some(Utc::now()));
+
+        guard.insert(new.get_id(), new.clone());
+        Ok(new)
+    }
+
+    async fn list_match_notes(&self, t_id: Uuid, tag: Option<&str>) -> DbResult<Vec<MatchNote>> {
+        let mut rows: Vec<_> = self
+            .match_notes
+            .lock()
+            .unwrap()
+            .values()
+            .filter(|n| n.get_tournament_id() == t_id)
+            .filter(|n| tag.is_none_or(|t| n.get_tags().iter().any(|nt| nt == t)))
+            .cloned()
+            .collect();
+
+        // Simulate DB order by updated_at DESC
+        rows.sort_by_key(|n| std::cmp::Reverse(n.get_updated_at()));
+        Ok(rows)
+    }
+}
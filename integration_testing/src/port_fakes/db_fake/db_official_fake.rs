@@ -0,0 +1,75 @@
+//! Fakes for DbpOfficial port
+
+use super::FakeDatabasePort;
+use app_core::{
+    DbError, DbResult, DbpOfficial, Official,
+    utils::{id_version::IdVersion, traits::ObjectIdVersion},
+};
+use async_trait::async_trait;
+use uuid::Uuid;
+
+#[async_trait]
+impl DbpOfficial for FakeDatabasePort {
+    async fn get_official(&self, official_id: Uuid) -> DbResult<Option<Official>> {
+        Ok(self.officials.lock().unwrap().get(&official_id).cloned())
+    }
+
+    async fn save_official(&self, official: &Official) -> DbResult<Official> {
+        let mut guard = self.fail_next_save_official.lock().unwrap();
+        if *guard {
+            *guard = false;
+            return Err(DbError::Other("injected save failure".into()));
+        }
+
+        let mut guard = self.officials.lock().unwrap();
+
+        // Simulate unique index on (tournament_id, name); name is citext
+        if guard.values().any(|o| {
+            o.get_id() != official.get_id()
+                && o.get_tournament_id() == official.get_tournament_id()
+                && o.get_name().to_lowercase() == official.get_name().to_lowercase()
+        }) {
+            return Err(DbError::UniqueViolation(Some(
+                "uniq_officials_name_per_tournament".into(),
+            )));
+        }
+
+        let mut new = official.clone();
+        match official.get_id_version() {
+            IdVersion::Existing(inner) => {
+                if let Some(existing) = guard.get(&inner.get_id()) {
+                    // Check Optimistic Locking
+                    let existing_v = existing.get_version().unwrap_or(0);
+                    if existing_v != inner.get_version() {
+                        return Err(DbError::OptimisticLockConflict);
+                    }
+                    new.set_id_version(IdVersion::new(inner.get_id(), Some(existing_v + 1)));
+                } else {
+                    return Err(DbError::NotFound);
+                }
+            }
+            IdVersion::NewWithId(id) => {
+                if guard.contains_key(&id) {
+                    return Err(DbError::UniqueViolation(Some("officials_pkey".into())));
+                }
+                new.set_id_version(IdVersion::new(id, Some(0)));
+            }
+        }
+
+        guard.insert(new.get_id(), new.clone());
+        Ok(new)
+    }
+
+    async fn list_officials_of_tournament(&self, tournament_id: Uuid) -> DbResult<Vec<Official>> {
+        let mut rows: Vec<_> = self
+            .officials
+            .lock()
+            .unwrap()
+            .values()
+            .filter(|o| o.get_tournament_id() == tournament_id)
+            .cloned()
+            .collect();
+        rows.sort_by_key(|o| (o.get_name().to_lowercase(), o.get_id()));
+        Ok(rows)
+    }
+}
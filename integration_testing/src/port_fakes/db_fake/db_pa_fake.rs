@@ -3,7 +3,7 @@
 use super::FakeDatabasePort;
 use app_core::{
     DbError, DbResult, DbpPostalAddress, PostalAddress,
-    utils::{id_version::IdVersion, traits::ObjectIdVersion},
+    utils::{id_version::IdVersion, list_order::ListOrder, traits::ObjectIdVersion},
 };
 use async_trait::async_trait;
 use uuid::Uuid;
@@ -36,7 +36,9 @@ impl DbpPostalAddress for FakeDatabasePort {
                     let update_v = inner.get_version();
 
                     if existing_v != update_v {
-                        return Err(DbError::OptimisticLockConflict);
+                        return Err(DbError::VersionConflict {
+                            current_version: existing_v,
+                        });
                     }
 
                     new.set_id_version(IdVersion::new(inner.get_id(), Some(existing_v + 1)));
@@ -63,6 +65,7 @@ impl DbpPostalAddress for FakeDatabasePort {
         &self,
         name_filter: Option<&str>,
         limit: Option<usize>,
+        order: &ListOrder<PostalAddress>,
     ) -> DbResult<Vec<Uuid>> {
         let mut guard = self.fail_next_list_pa.lock().unwrap();
         if *guard {
@@ -71,7 +74,7 @@ impl DbpPostalAddress for FakeDatabasePort {
         }
 
         let filter = name_filter.map(|s| s.to_lowercase());
-        let mut rows: Vec<_> = self
+        let rows: Vec<_> = self
             .postal_addresses
             .lock()
             .unwrap()
@@ -86,14 +89,10 @@ impl DbpPostalAddress for FakeDatabasePort {
             .cloned()
             .collect();
 
-        rows.sort_by(|a, b| match a.get_name().cmp(b.get_name()) {
-            std::cmp::Ordering::Equal => a.get_id().cmp(&b.get_id()),
-            cmp => cmp,
-        });
-
-        if let Some(l) = limit {
-            rows.truncate(l);
-        }
-        Ok(rows.into_iter().map(|a| a.get_id()).collect())
+        Ok(order
+            .apply(rows, limit)
+            .into_iter()
+            .map(|a| a.get_id())
+            .collect())
     }
 }
@@ -0,0 +1,55 @@
+//! Fakes for DbpPairingOverride port
+
+use super::FakeDatabasePort;
+use app_core::{
+    DbError, DbResult, DbpPairingOverride, PairingOverride,
+    utils::{id_version::IdVersion, traits::ObjectIdVersion},
+};
+use async_trait::async_trait;
+use chrono::Utc;
+use uuid::Uuid;
+
+#[async_trait]
+impl DbpPairingOverride for FakeDatabasePort {
+    async fn save_pairing_override(
+        &self,
+        pairing_override: &PairingOverride,
+    ) -> DbResult<PairingOverride> {
+        let mut guard = self.fail_next_save_po.lock().unwrap();
+        if *guard {
+            *guard = false;
+            return Err(DbError::Other("injected save failure".into()));
+        }
+
+        let IdVersion::NewWithId(id) = pairing_override.get_id_version() else {
+            return Err(DbError::Other("pairing overrides are append only".into()));
+        };
+        let mut guard = self.pairing_overrides.lock().unwrap();
+        if guard.iter().any(|o| o.get_id() == id) {
+            return Err(DbError::UniqueViolation(Some(
+                "pairing_overrides_pkey".into(),
+            )));
+        }
+        let mut new = pairing_override.clone();
+        new.set_id_version(IdVersion::new(id, Some(0)))
+            .set_created_at(Some(Utc::now()));
+        guard.push(new.clone());
+        Ok(new)
+    }
+
+    async fn list_pairing_overrides(
+        &self,
+        stage_id: Uuid,
+        round_number: u32,
+    ) -> DbResult<Vec<PairingOverride>> {
+        // insertion order is order of creation
+        Ok(self
+            .pairing_overrides
+            .lock()
+            .unwrap()
+            .iter()
+            .filter(|o| o.get_stage_id() == stage_id && o.get_round_number() == round_number)
+            .cloned()
+            .collect())
+    }
+}
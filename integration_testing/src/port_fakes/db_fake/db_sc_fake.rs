@@ -3,9 +3,10 @@
 use super::FakeDatabasePort;
 use app_core::{
     DbError, DbResult, DbpSportConfig, SportConfig,
-    utils::{id_version::IdVersion, traits::ObjectIdVersion},
+    utils::{id_version::IdVersion, list_order::ListOrder, traits::ObjectIdVersion},
 };
 use async_trait::async_trait;
+use chrono::Utc;
 use uuid::Uuid;
 
 #[async_trait]
@@ -36,7 +37,9 @@ impl DbpSportConfig for FakeDatabasePort {
                     let update_v = inner.get_version(); // This unwrap is safe for Existing, but version is u32
 
                     if existing_v != update_v {
-                        return Err(DbError::OptimisticLockConflict);
+                        return Err(DbError::VersionConflict {
+                            current_version: existing_v,
+                        });
                     }
 
                     // Increment version
@@ -60,11 +63,35 @@ impl DbpSportConfig for FakeDatabasePort {
         Ok(new)
     }
 
+    async fn archive_sport_config(&self, id: Uuid, version: u32) -> DbResult<SportConfig> {
+        let mut guard = self.fail_next_save_sc.lock().unwrap();
+        if *guard {
+            *guard = false;
+            return Err(DbError::Other("injected archive failure".into()));
+        }
+
+        let mut guard = self.sport_configs.lock().unwrap();
+        let Some(existing) = guard.get_mut(&id) else {
+            return Err(DbError::NotFound);
+        };
+        if existing.get_version() != Some(version) {
+            return Err(DbError::VersionConflict {
+                current_version: existing.get_version().unwrap_or_default(),
+            });
+        }
+        existing
+            .set_id_version(IdVersion::new(id, Some(version + 1)))
+            .set_archived_at(Some(Utc::now()));
+        Ok(existing.clone())
+    }
+
     async fn list_sport_config_ids(
         &self,
         sport_id: Uuid,
         name_filter: Option<&str>,
+        include_archived: bool,
         limit: Option<usize>,
+        order: &ListOrder<SportConfig>,
     ) -> DbResult<Vec<Uuid>> {
         let mut guard = self.fail_next_list_sc.lock().unwrap();
         if *guard {
@@ -73,12 +100,13 @@ impl DbpSportConfig for FakeDatabasePort {
         }
 
         let filter = name_filter.map(|s| s.to_lowercase());
-        let mut rows: Vec<_> = self
+        let rows: Vec<_> = self
             .sport_configs
             .lock()
             .unwrap()
             .values()
             .filter(|sc| sc.get_sport_id() == sport_id)
+            .filter(|sc| include_archived || !sc.is_archived())
             .filter(|sc| {
                 if let Some(ref f) = filter {
                     sc.get_name().to_lowercase().contains(f)
@@ -89,14 +117,10 @@ impl DbpSportConfig for FakeDatabasePort {
             .cloned()
             .collect();
 
-        rows.sort_by(|a, b| match a.get_name().cmp(&b.get_name()) {
-            std::cmp::Ordering::Equal => a.get_id().cmp(&b.get_id()),
-            cmp => cmp,
-        });
-
-        if let Some(l) = limit {
-            rows.truncate(l);
-        }
-        Ok(rows.into_iter().map(|sc| sc.get_id()).collect())
+        Ok(order
+            .apply(rows, limit)
+            .into_iter()
+            .map(|sc| sc.get_id())
+            .collect())
     }
 }
@@ -0,0 +1,97 @@
+//! Fakes for DbpSearch port
+
+use super::FakeDatabasePort;
+use app_core::{DbResult, DbpSearch, SearchHit, SearchHitKind};
+use async_trait::async_trait;
+
+fn contains(text: &str, term: &str) -> bool {
+    text.to_lowercase().contains(term)
+}
+
+#[async_trait]
+impl DbpSearch for FakeDatabasePort {
+    async fn search_all(&self, term: &str, limit_per_kind: usize) -> DbResult<Vec<SearchHit>> {
+        let term = term.to_lowercase();
+        let mut hits = Vec::new();
+        let tournaments = self.tournament_bases.lock().unwrap().clone();
+        let is_searchable = |t_id| {
+            tournaments
+                .get(&t_id)
+                .is_some_and(|t| !t.is_archived() && !t.is_sandbox())
+        };
+
+        hits.extend(
+            tournaments
+                .values()
+                .filter(|t| is_searchable(t.get_id()) && contains(t.get_name(), &term))
+                .take(limit_per_kind)
+                .map(|t| SearchHit {
+                    kind: SearchHitKind::Tournament,
+                    id: t.get_id(),
+                    label: t.get_name().to_string(),
+                    detail: None,
+                    sport_id: Some(t.get_sport_id()),
+                    tournament_id: None,
+                }),
+        );
+        hits.extend(
+            self.entrants
+                .lock()
+                .unwrap()
+                .values()
+                .filter(|e| {
+                    is_searchable(e.get_tournament_id())
+                        && (contains(e.get_name(), &term)
+                            || e.get_club().is_some_and(|c| contains(c, &term)))
+                })
+                .take(limit_per_kind)
+                .map(|e| SearchHit {
+                    kind: SearchHitKind::Entrant,
+                    id: e.get_id(),
+                    label: e.get_name().to_string(),
+                    detail: e.get_club().map(str::to_string),
+                    sport_id: tournaments
+                        .get(&e.get_tournament_id())
+                        .map(|t| t.get_sport_id()),
+                    tournament_id: Some(e.get_tournament_id()),
+                }),
+        );
+        hits.extend(
+            self.sport_configs
+                .lock()
+                .unwrap()
+                .values()
+                .filter(|c| !c.is_archived() && contains(c.get_name(), &term))
+                .take(limit_per_kind)
+                .map(|c| SearchHit {
+                    kind: SearchHitKind::SportConfig,
+                    id: c.get_id(),
+                    label: c.get_name().to_string(),
+                    detail: None,
+                    sport_id: Some(c.get_sport_id()),
+                    tournament_id: None,
+                }),
+        );
+        hits.extend(
+            self.postal_addresses
+                .lock()
+                .unwrap()
+                .values()
+                .filter(|a| {
+                    contains(a.get_name(), &term)
+                        || contains(a.get_street(), &term)
+                        || contains(a.get_locality(), &term)
+                })
+                .take(limit_per_kind)
+                .map(|a| SearchHit {
+                    kind: SearchHitKind::PostalAddress,
+                    id: a.get_id(),
+                    label: a.get_name().to_string(),
+                    detail: Some(a.get_locality().to_string()),
+                    sport_id: None,
+                    tournament_id: None,
+                }),
+        );
+        Ok(hits)
+    }
+}
@@ -0,0 +1,100 @@
+//! Fakes for DbpShiftLog port
+
+use super::FakeDatabasePort;
+use app_core::{
+    DbError, DbResult, DbpShiftLog, ShiftLogEntry,
+    utils::{id_version::IdVersion, traits::ObjectIdVersion},
+};
+use async_trait::async_trait;
+use chrono::Utc;
+use uuid::Uuid;
+
+#[async_trait]
+impl DbpShiftLog for FakeDatabasePort {
+    async fn get_shift_log_entry(&self, entry_id: Uuid) -> DbResult<Option<ShiftLogEntry>> {
+        let mut guard = self.fail_next_get_sl.lock().unwrap();
+        if *guard {
+            *guard = false;
+            return Err(DbError::Other("injected get failure".into()));
+        }
+        Ok(self
+            .shift_log_entries
+            .lock()
+            .unwrap()
+            .get(&entry_id)
+            .cloned())
+    }
+
+    async fn save_shift_log_entry(&self, entry: &ShiftLogEntry) -> DbResult<ShiftLogEntry> {
+        let mut guard = self.fail_next_save_sl.lock().unwrap();
+        if *guard {
+            *guard = false;
+            return Err(DbError::Other("injected save failure".into()));
+        }
+
+        let mut guard = self.shift_log_entries.lock().unwrap();
+        let mut new = entry.clone();
+
+        match entry.get_id_version() {
+            IdVersion::Existing(inner) => {
+                if let Some(existing) = guard.get(&inner.get_id()) {
+                    // Check Optimistic Locking
+                    let existing_v = existing.get_version().unwrap_or(0);
+                    if existing_v != inner.get_version() {
+                        return Err(DbError::OptimisticLockConflict);
+                    }
+                    new.set_id_version(IdVersion::new(inner.get_id(), Some(existing_v + 1)))
+                        .set_created_at(existing.get_created_at());
+                } else {
+                    return Err(DbError::NotFound);
+                }
+            }
+            IdVersion::NewWithId(id) => {
+                if guard.contains_key(&id) {
+                    return Err(DbError::UniqueViolation(Some(
+                        "shift_log_entries_pkey".into(),
+                    )));
+                }
+                new.set_id_version(IdVersion::new(id, Some(0)))
+                    .set_created_at(Some(Utc::now()));
+            }
+        }
+
+        guard.insert(new.get_id(), new.clone());
+        Ok(new)
+    }
+
+    async fn list_shift_log_entries(
+        &self,
+        t_id: Uuid,
+        pinned_only: bool,
+        limit: Option<usize>,
+    ) -> DbResult<Vec<ShiftLogEntry>> {
+        let mut guard = self.fail_next_list_sl.lock().unwrap();
+        if *guard {
+            *guard = false;
+            return Err(DbError::Other("injected list failure".into()));
+        }
+
+        let mut rows: Vec<_> = self
+            .shift_log_entries
+            .lock()
+            .unwrap()
+            .values()
+            .filter(|e| e.get_tournament_id() == t_id)
+            .filter(|e| !pinned_only || e.is_pinned())
+            .cloned()
+            .collect();
+
+        // Simulate DB order by pinned DESC, created_at DESC
+        rows.sort_by(|a, b| {
+            b.is_pinned()
+                .cmp(&a.is_pinned())
+                .then(b.get_created_at().cmp(&a.get_created_at()))
+        });
+        if let Some(lim) = limit {
+            rows.truncate(lim);
+        }
+        Ok(rows)
+    }
+}
@@ -0,0 +1,57 @@
+//! Fakes for DbpStageSnapshot port
+
+use super::FakeDatabasePort;
+use app_core::{DbError, DbResult, DbpStageSnapshot, StageSnapshot};
+use async_trait::async_trait;
+use uuid::Uuid;
+
+#[async_trait]
+impl DbpStageSnapshot for FakeDatabasePort {
+    async fn get_stage_snapshot(&self, snapshot_id: Uuid) -> DbResult<Option<StageSnapshot>> {
+        Ok(self
+            .stage_snapshots
+            .lock()
+            .unwrap()
+            .get(&snapshot_id)
+            .cloned())
+    }
+
+    async fn save_stage_snapshot(&self, snapshot: &StageSnapshot) -> DbResult<()> {
+        let mut guard = self.fail_next_save_snapshot.lock().unwrap();
+        if *guard {
+            *guard = false;
+            return Err(DbError::Other("injected save failure".into()));
+        }
+
+        self.stage_snapshots
+            .lock()
+            .unwrap()
+            .insert(snapshot.id, snapshot.clone());
+        Ok(())
+    }
+
+    async fn delete_stage_snapshot(&self, snapshot_id: Uuid) -> DbResult<()> {
+        self.stage_snapshots
+            .lock()
+            .unwrap()
+            .remove(&snapshot_id)
+            .map(|_| ())
+            .ok_or(DbError::NotFound)
+    }
+
+    async fn list_stage_snapshots_of_tournament(
+        &self,
+        tournament_id: Uuid,
+    ) -> DbResult<Vec<StageSnapshot>> {
+        let mut rows: Vec<_> = self
+            .stage_snapshots
+            .lock()
+            .unwrap()
+            .values()
+            .filter(|s| s.tournament_id == tournament_id)
+            .cloned()
+            .collect();
+        rows.sort_by_key(|s| (s.stage_number, s.created_at));
+        Ok(rows)
+    }
+}
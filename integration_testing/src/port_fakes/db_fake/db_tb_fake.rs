@@ -2,10 +2,14 @@
 
 use super::FakeDatabasePort;
 use app_core::{
-    DbError, DbResult, DbpTournamentBase, TournamentBase, TournamentState, TournamentType,
-    utils::{id_version::IdVersion, traits::ObjectIdVersion},
+    DbBatchError, DbBatchResult, DbError, DbResult, DbpStage, DbpTournamentBase, Group, Stage,
+    TournamentBase,
+    utils::{
+        filter::Filter, id_version::IdVersion, list_order::ListOrder, traits::ObjectIdVersion,
+    },
 };
 use async_trait::async_trait;
+use chrono::Utc;
 use uuid::Uuid;
 
 #[async_trait]
@@ -36,10 +40,13 @@ impl DbpTournamentBase for FakeDatabasePort {
                     let update_v = inner.get_version();
 
                     if existing_v != update_v {
-                        return Err(DbError::OptimisticLockConflict);
+                        return Err(DbError::VersionConflict {
+                            current_version: existing_v,
+                        });
                     }
 
-                    new.set_id_version(IdVersion::new(inner.get_id(), Some(existing_v + 1)));
+                    new.set_id_version(IdVersion::new(inner.get_id(), Some(existing_v + 1)))
+                        .set_created_at(existing.get_created_at());
                 } else {
                     return Err(DbError::NotFound);
                 }
@@ -51,7 +58,8 @@ impl DbpTournamentBase for FakeDatabasePort {
                         id
                     )));
                 }
-                new.set_id_version(IdVersion::new(id, Some(0)));
+                new.set_id_version(IdVersion::new(id, Some(0)))
+                    .set_created_at(Some(Utc::now()));
             }
         }
 
@@ -59,13 +67,120 @@ impl DbpTournamentBase for FakeDatabasePort {
         Ok(new)
     }
 
+    async fn save_tournament_diff(
+        &self,
+        tournament: Option<&TournamentBase>,
+        stages: &[Stage],
+        groups: &[Group],
+    ) -> DbBatchResult<(Option<TournamentBase>, Vec<Stage>, Vec<Group>)> {
+        // emulate transaction: restore tournament bases, stages and groups, if any save fails
+        let bases_snapshot = self.tournament_bases.lock().unwrap().clone();
+        let stages_snapshot = self.stages.lock().unwrap().clone();
+        let groups_snapshot = self.groups.lock().unwrap().clone();
+
+        let res =
+            async {
+                let saved_base =
+                    match tournament {
+                        Some(t) => Some(self.save_tournament_base(t).await.map_err(|error| {
+                            DbBatchError {
+                                object_id: Some(t.get_id()),
+                                error,
+                            }
+                        })?),
+                        None => None,
+                    };
+                let mut saved_stages = Vec::with_capacity(stages.len());
+                for stage in stages {
+                    let saved = self.save_stage(stage).await.map_err(|error| DbBatchError {
+                        object_id: Some(stage.get_id()),
+                        error,
+                    })?;
+                    saved_stages.push(saved);
+                }
+                let mut saved_groups = Vec::with_capacity(groups.len());
+                for group in groups {
+                    let saved = self.write_group(group).map_err(|error| DbBatchError {
+                        object_id: Some(group.get_id()),
+                        error,
+                    })?;
+                    saved_groups.push(saved);
+                }
+                Ok((saved_base, saved_stages, saved_groups))
+            }
+            .await;
+
+        if res.is_err() {
+            *self.tournament_bases.lock().unwrap() = bases_snapshot;
+            *self.stages.lock().unwrap() = stages_snapshot;
+            *self.groups.lock().unwrap() = groups_snapshot;
+        }
+        res
+    }
+
+    async fn archive_tournament_base(&self, id: Uuid, version: u32) -> DbResult<TournamentBase> {
+        let mut guard = self.fail_next_save_tb.lock().unwrap();
+        if *guard {
+            *guard = false;
+            return Err(DbError::Other("injected archive failure".into()));
+        }
+
+        let mut guard = self.tournament_bases.lock().unwrap();
+        let Some(existing) = guard.get_mut(&id) else {
+            return Err(DbError::NotFound);
+        };
+        if existing.get_version() != Some(version) {
+            return Err(DbError::VersionConflict {
+                current_version: existing.get_version().unwrap_or_default(),
+            });
+        }
+        existing
+            .set_id_version(IdVersion::new(id, Some(version + 1)))
+            .set_archived_at(Some(Utc::now()));
+        Ok(existing.clone())
+    }
+
+    async fn purge_sandbox_tournament_base(&self, id: Uuid) -> DbResult<()> {
+        let mut guard = self.fail_next_save_tb.lock().unwrap();
+        if *guard {
+            *guard = false;
+            return Err(DbError::Other("injected purge failure".into()));
+        }
+
+        let mut guard = self.tournament_bases.lock().unwrap();
+        if !guard.get(&id).is_some_and(|tb| tb.is_sandbox()) {
+            return Err(DbError::NotFound);
+        }
+        guard.remove(&id);
+        self.remove_tournament_data(id);
+        Ok(())
+    }
+
+    async fn delete_tournament_base(&self, id: Uuid, version: u32) -> DbResult<()> {
+        let mut guard = self.fail_next_save_tb.lock().unwrap();
+        if *guard {
+            *guard = false;
+            return Err(DbError::Other("injected delete failure".into()));
+        }
+
+        let mut guard = self.tournament_bases.lock().unwrap();
+        let Some(existing) = guard.get(&id) else {
+            return Err(DbError::NotFound);
+        };
+        if existing.get_version() != Some(version) {
+            return Err(DbError::VersionConflict {
+                current_version: existing.get_version().unwrap_or_default(),
+            });
+        }
+        guard.remove(&id);
+        self.remove_tournament_data(id);
+        Ok(())
+    }
+
     async fn list_tournament_base_ids(
         &self,
-        sport_id: Uuid,
-        name_filter: Option<&str>,
-        state_filter: Option<TournamentState>,
-        include_adhoc: bool,
-        limit: Option<usize>,
+        filter: &Filter<TournamentBase>,
+        order: &ListOrder<TournamentBase>,
     ) -> DbResult<Vec<Uuid>> {
         let mut guard = self.fail_next_list_tb.lock().unwrap();
         if *guard {
@@ -73,32 +188,63 @@ impl DbpTournamentBase for FakeDatabasePort {
             return Err(DbError::Other("injected list failure".into()));
         }
 
-        let filter = name_filter.map(|s| s.to_lowercase());
-        let mut rows: Vec<_> = self
+        let rows: Vec<_> = self
             .tournament_bases
             .lock()
             .unwrap()
             .values()
-            .filter(|sc| sc.get_sport_id() == sport_id)
-            .filter(|sc| {
-                if let Some(sf) = state_filter {
-                    sc.get_tournament_state() == sf
-                } else {
-                    true
-                }
-            })
-            .filter(|sc| include_adhoc || sc.get_tournament_type() != TournamentType::Adhoc)
+            .filter(|tb| filter.matches(tb))
             .cloned()
             .collect();
 
-        if let Some(f) = filter {
-            rows.retain(|tb| tb.get_name().to_lowercase().contains(&f));
-        }
+        Ok(order
+            .apply(rows, filter.get_limit())
+            .into_iter()
+            .map(|tb| tb.get_id())
+            .collect())
+    }
+}
 
-        if let Some(lim) = limit {
-            rows.truncate(lim);
+impl FakeDatabasePort {
+    /// emulate ON DELETE CASCADE of the tables, which depend upon tournament `id`
+    fn remove_tournament_data(&self, id: Uuid) {
+        self.stages
+            .lock()
+            .unwrap()
+            .retain(|_, s| s.get_tournament_id() != id);
+        self.entrants
+            .lock()
+            .unwrap()
+            .retain(|_, e| e.get_tournament_id() != id);
+        self.shift_log_entries
+            .lock()
+            .unwrap()
+            .retain(|_, e| e.get_tournament_id() != id);
+        self.match_notes
+            .lock()
+            .unwrap()
+            .retain(|_, n| n.get_tournament_id() != id);
+        self.matches
+            .lock()
+            .unwrap()
+            .retain(|_, m| *m.get_tournament_id() != id);
+        self.pairing_overrides
+            .lock()
+            .unwrap()
+            .retain(|o| o.get_tournament_id() != id);
+        self.scorekeeper_tokens
+            .lock()
+            .unwrap()
+            .retain(|_, t| t.get_tournament_id() != id);
+        // feedback is kept (ON DELETE SET NULL)
+        for feedback in self
+            .feedback
+            .lock()
+            .unwrap()
+            .iter_mut()
+            .filter(|f| f.get_tournament_id() == Some(id))
+        {
+            feedback.set_tournament_id(None);
         }
-
-        Ok(rows.into_iter().map(|tb| tb.get_id()).collect())
     }
 }
@@ -0,0 +1,131 @@
+//! Fakes for database transactions
+
+use super::FakeDatabasePort;
+use app_core::{
+    ApiToken, AuditRecord, CachedGroupStandings, ClientErrorReport, DbError, DbResult,
+    DbTransaction, Entrant, Feedback, Group, Match, MatchNote, Official, PairingOverride,
+    PostalAddress, SavedGroupSeeding, ScorekeeperToken, ShiftLogEntry, SportConfig, Stage,
+    StageSnapshot, TournamentBase, Venue, WebhookDelivery, WebhookEndpoint,
+};
+use async_trait::async_trait;
+use std::{
+    collections::HashMap,
+    sync::{Arc, Mutex},
+};
+use uuid::Uuid;
+
+/// Copy of all data of the fake at begin of a transaction; restored on rollback.
+pub(super) struct FakeSnapshot {
+    postal_addresses: HashMap<Uuid, PostalAddress>,
+    sport_configs: HashMap<Uuid, SportConfig>,
+    tournament_bases: HashMap<Uuid, TournamentBase>,
+    stages: HashMap<Uuid, Stage>,
+    shift_log_entries: HashMap<Uuid, ShiftLogEntry>,
+    match_notes: HashMap<Uuid, MatchNote>,
+    pairing_overrides: Vec<PairingOverride>,
+    entrants: HashMap<Uuid, Entrant>,
+    officials: HashMap<Uuid, Official>,
+    group_standings: HashMap<Uuid, CachedGroupStandings>,
+    groups: HashMap<Uuid, Group>,
+    group_seedings: HashMap<Uuid, SavedGroupSeeding>,
+    stage_snapshots: HashMap<Uuid, StageSnapshot>,
+    matches: HashMap<Uuid, Match>,
+    api_tokens: HashMap<Uuid, ApiToken>,
+    scorekeeper_tokens: HashMap<Uuid, ScorekeeperToken>,
+    webhook_endpoints: HashMap<Uuid, WebhookEndpoint>,
+    webhook_deliveries: Vec<WebhookDelivery>,
+    venues: HashMap<Uuid, Venue>,
+    feedback: Vec<Feedback>,
+    client_errors: Vec<ClientErrorReport>,
+    audit_records: Vec<AuditRecord>,
+}
+
+impl FakeDatabasePort {
+    fn snapshot(&self) -> FakeSnapshot {
+        FakeSnapshot {
+            postal_addresses: self.postal_addresses.lock().unwrap().clone(),
+            sport_configs: self.sport_configs.lock().unwrap().clone(),
+            tournament_bases: self.tournament_bases.lock().unwrap().clone(),
+            stages: self.stages.lock().unwrap().clone(),
+            shift_log_entries: self.shift_log_entries.lock().unwrap().clone(),
+            match_notes: self.match_notes.lock().unwrap().clone(),
+            pairing_overrides: self.pairing_overrides.lock().unwrap().clone(),
+            entrants: self.entrants.lock().unwrap().clone(),
+            officials: self.officials.lock().unwrap().clone(),
+            group_standings: self.group_standings.lock().unwrap().clone(),
+            groups: self.groups.lock().unwrap().clone(),
+            group_seedings: self.group_seedings.lock().unwrap().clone(),
+            stage_snapshots: self.stage_snapshots.lock().unwrap().clone(),
+            matches: self.matches.lock().unwrap().clone(),
+            api_tokens: self.api_tokens.lock().unwrap().clone(),
+            scorekeeper_tokens: self.scorekeeper_tokens.lock().unwrap().clone(),
+            webhook_endpoints: self.webhook_endpoints.lock().unwrap().clone(),
+            webhook_deliveries: self.webhook_deliveries.lock().unwrap().clone(),
+            venues: self.venues.lock().unwrap().clone(),
+            feedback: self.feedback.lock().unwrap().clone(),
+            client_errors: self.client_errors.lock().unwrap().clone(),
+            audit_records: self.audit_records.lock().unwrap().clone(),
+        }
+    }
+
+    fn restore(&self, snapshot: FakeSnapshot) {
+        *self.postal_addresses.lock().unwrap() = snapshot.postal_addresses;
+        *self.sport_configs.lock().unwrap() = snapshot.sport_configs;
+        *self.tournament_bases.lock().unwrap() = snapshot.tournament_bases;
+        *self.stages.lock().unwrap() = snapshot.stages;
+        *self.shift_log_entries.lock().unwrap() = snapshot.shift_log_entries;
+        *self.match_notes.lock().unwrap() = snapshot.match_notes;
+        *self.pairing_overrides.lock().unwrap() = snapshot.pairing_overrides;
+        *self.entrants.lock().unwrap() = snapshot.entrants;
+        *self.officials.lock().unwrap() = snapshot.officials;
+        *self.group_standings.lock().unwrap() = snapshot.group_standings;
+        *self.groups.lock().unwrap() = snapshot.groups;
+        *self.group_seedings.lock().unwrap() = snapshot.group_seedings;
+        *self.stage_snapshots.lock().unwrap() = snapshot.stage_snapshots;
+        *self.matches.lock().unwrap() = snapshot.matches;
+        *self.api_tokens.lock().unwrap() = snapshot.api_tokens;
+        *self.scorekeeper_tokens.lock().unwrap() = snapshot.scorekeeper_tokens;
+        *self.webhook_endpoints.lock().unwrap() = snapshot.webhook_endpoints;
+        *self.webhook_deliveries.lock().unwrap() = snapshot.webhook_deliveries;
+        *self.venues.lock().unwrap() = snapshot.venues;
+        *self.feedback.lock().unwrap() = snapshot.feedback;
+        *self.client_errors.lock().unwrap() = snapshot.client_errors;
+        *self.audit_records.lock().unwrap() = snapshot.audit_records;
+    }
+
+    /// Emulate a transaction: writes go directly to the shared data of the fake and are
+    /// reverted on rollback. Other clients of the fake see uncommitted writes.
+    pub(super) fn fake_begin_transaction(&self) -> DbResult<Arc<dyn DbTransaction>> {
+        if self.transaction.lock().unwrap().is_some() {
+            return Err(DbError::Other(
+                "nested transactions are not supported".to_string(),
+            ));
+        }
+        let transaction = FakeDatabasePort {
+            transaction: Arc::new(Mutex::new(Some(self.snapshot()))),
+            ..self.clone()
+        };
+        Ok(Arc::new(transaction))
+    }
+}
+
+#[async_trait]
+impl DbTransaction for FakeDatabasePort {
+    async fn commit(&self) -> DbResult<()> {
+        match self.transaction.lock().unwrap().take() {
+            Some(_) => Ok(()),
+            None => Err(DbError::Other("no transaction to commit".to_string())),
+        }
+    }
+
+    async fn rollback(&self) -> DbResult<()> {
+        let snapshot = self.transaction.lock().unwrap().take();
+        match snapshot {
+            Some(snapshot) => {
+                self.restore(snapshot);
+                Ok(())
+            }
+            None => Err(DbError::Other("no transaction to roll back".to_string())),
+        }
+    }
+}
@@ -0,0 +1,80 @@
+//! Fakes for DbpVenue port
+
+use super::FakeDatabasePort;
+use app_core::{
+    DbError, DbResult, DbpVenue, Venue,
+    utils::{id_version::IdVersion, traits::ObjectIdVersion},
+};
+use async_trait::async_trait;
+use uuid::Uuid;
+
+#[async_trait]
+impl DbpVenue for FakeDatabasePort {
+    async fn get_venue(&self, venue_id: Uuid) -> DbResult<Option<Venue>> {
+        Ok(self.venues.lock().unwrap().get(&venue_id).cloned())
+    }
+
+    async fn save_venue(&self, venue: &Venue) -> DbResult<Venue> {
+        let mut guard = self.fail_next_save_venue.lock().unwrap();
+        if *guard {
+            *guard = false;
+            return Err(DbError::Other("injected save failure".into()));
+        }
+
+        let mut guard = self.venues.lock().unwrap();
+
+        // Simulate unique index on name; name is citext
+        if guard.values().any(|v| {
+            v.get_id() != venue.get_id()
+                && v.get_name().to_lowercase() == venue.get_name().to_lowercase()
+        }) {
+            return Err(DbError::UniqueViolation(Some("uniq_venues_name".into())));
+        }
+
+        let mut new = venue.clone();
+        match venue.get_id_version() {
+            IdVersion::Existing(inner) => {
+                if let Some(existing) = guard.get(&inner.get_id()) {
+                    // Check Optimistic Locking
+                    let existing_v = existing.get_version().unwrap_or(0);
+                    if existing_v != inner.get_version() {
+                        return Err(DbError::OptimisticLockConflict);
+                    }
+                    new.set_id_version(IdVersion::new(inner.get_id(), Some(existing_v + 1)));
+                } else {
+                    return Err(DbError::NotFound);
+                }
+            }
+            IdVersion::NewWithId(id) => {
+                if guard.contains_key(&id) {
+                    return Err(DbError::UniqueViolation(Some("venues_pkey".into())));
+                }
+                new.set_id_version(IdVersion::new(id, Some(0)));
+            }
+        }
+
+        guard.insert(new.get_id(), new.clone());
+        Ok(new)
+    }
+
+    async fn list_venues(
+        &self,
+        name_filter: Option<&str>,
+        limit: Option<usize>,
+    ) -> DbResult<Vec<Venue>> {
+        let filter = name_filter.map(str::to_lowercase).unwrap_or_default();
+        let mut rows: Vec<_> = self
+            .venues
+            .lock()
+            .unwrap()
+            .values()
+            .filter(|v| v.get_name().to_lowercase().contains(&filter))
+            .cloned()
+            .collect();
+        rows.sort_by_key(|v| (v.get_name().to_lowercase(), v.get_id()));
+        if let Some(limit) = limit {
+            rows.truncate(limit);
+        }
+        Ok(rows)
+    }
+}
@@ -0,0 +1,129 @@
+//! Fakes for DbpWebhook port
+
+use super::FakeDatabasePort;
+use app_core::{
+    DbError, DbResult, DbpWebhook, WebhookDelivery, WebhookEndpoint,
+    utils::{id_version::IdVersion, traits::ObjectIdVersion},
+};
+use async_trait::async_trait;
+use chrono::Utc;
+use uuid::Uuid;
+
+#[async_trait]
+impl DbpWebhook for FakeDatabasePort {
+    async fn get_webhook_endpoint(&self, endpoint_id: Uuid) -> DbResult<Option<WebhookEndpoint>> {
+        Ok(self
+            .webhook_endpoints
+            .lock()
+            .unwrap()
+            .get(&endpoint_id)
+            .cloned())
+    }
+
+    async fn save_webhook_endpoint(&self, endpoint: &WebhookEndpoint) -> DbResult<WebhookEndpoint> {
+        let mut guard = self.fail_next_save_webhook.lock().unwrap();
+        if *guard {
+            *guard = false;
+            return Err(DbError::Other("injected save failure".into()));
+        }
+
+        let mut guard = self.webhook_endpoints.lock().unwrap();
+
+        // Simulate unique index on name; name is citext
+        if guard.values().any(|e| {
+            e.get_id() != endpoint.get_id()
+                && e.get_name().to_lowercase() == endpoint.get_name().to_lowercase()
+        }) {
+            return Err(DbError::UniqueViolation(Some(
+                "uniq_webhook_endpoints_name".into(),
+            )));
+        }
+
+        let mut new = endpoint.clone();
+        match endpoint.get_id_version() {
+            IdVersion::Existing(inner) => {
+                if let Some(existing) = guard.get(&inner.get_id()) {
+                    // Check Optimistic Locking
+                    let existing_v = existing.get_version().unwrap_or(0);
+                    if existing_v != inner.get_version() {
+                        return Err(DbError::OptimisticLockConflict);
+                    }
+                    // secret and timestamps are not changed by updates
+                    new.set_id_version(IdVersion::new(inner.get_id(), Some(existing_v + 1)))
+                        .set_secret(existing.get_secret())
+                        .set_created_at(existing.get_created_at());
+                } else {
+                    return Err(DbError::NotFound);
+                }
+            }
+            IdVersion::NewWithId(id) => {
+                if guard.contains_key(&id) {
+                    return Err(DbError::UniqueViolation(Some(
+                        "webhook_endpoints_pkey".into(),
+                    )));
+                }
+                new.set_id_version(IdVersion::new(id, Some(0)))
+                    .set_created_at(Some(Utc::now()));
+            }
+        }
+
+        guard.insert(new.get_id(), new.clone());
+        Ok(new)
+    }
+
+    async fn list_webhook_endpoints(&self) -> DbResult<Vec<WebhookEndpoint>> {
+        let mut guard = self.fail_next_list_webhook.lock().unwrap();
+        if *guard {
+            *guard = false;
+            return Err(DbError::Other("injected list failure".into()));
+        }
+
+        let mut rows: Vec<_> = self
+            .webhook_endpoints
+            .lock()
+            .unwrap()
+            .values()
+            .cloned()
+            .collect();
+        rows.sort_by_key(|e| e.get_name().to_lowercase());
+        Ok(rows)
+    }
+
+    async fn save_webhook_delivery(&self, delivery: &WebhookDelivery) -> DbResult<()> {
+        // Simulate foreign key of endpoint
+        if !self
+            .webhook_endpoints
+            .lock()
+            .unwrap()
+            .contains_key(&delivery.endpoint_id)
+        {
+            return Err(DbError::ForeignKeyViolation(Some(
+                "webhook_deliveries_endpoint_id_fkey".into(),
+            )));
+        }
+        self.webhook_deliveries
+            .lock()
+            .unwrap()
+            .push(delivery.clone());
+        Ok(())
+    }
+
+    async fn list_webhook_deliveries(
+        &self,
+        endpoint_id: Uuid,
+        limit: usize,
+    ) -> DbResult<Vec<WebhookDelivery>> {
+        let mut rows: Vec<_> = self
+            .webhook_deliveries
+            .lock()
+            .unwrap()
+            .iter()
+            .filter(|d| d.endpoint_id == endpoint_id)
+            .cloned()
+            .collect();
+        // deliveries are appended in order of attempts; newest first
+        rows.reverse();
+        rows.truncate(limit);
+        Ok(rows)
+    }
+}
@@ -1,16 +1,45 @@
+mod db_api_token_fake;
+mod db_audit_log_fake;
+mod db_client_error_fake;
+mod db_entrant_fake;
+mod db_feedback_fake;
+mod db_group_fake;
+mod db_group_seeding_fake;
+mod db_group_standings_fake;
+mod db_match_fake;
+mod db_match_note_fake;
+mod db_official_fake;
 mod db_pa_fake;
+mod db_pairing_override_fake;
 mod db_sc_fake;
+mod db_scorekeeper_fake;
+mod db_search_fake;
+mod db_shift_log_fake;
 mod db_stage_fake;
+mod db_stage_snapshot_fake;
 mod db_tb_fake;
+mod db_transaction_fake;
+mod db_venue_fake;
+mod db_webhook_fake;
 
-use crate::port_fakes::MockSport;
+use crate::port_fakes::{
+    FakeBlobStorage, FakeDomainEventPort, FakeEmailPort, FakeRankingSystem, FakeWebhookQueue,
+    FakeWebhookTransport, MockSport, RankedMockSport,
+};
 use app_core::{
-    ClientRegistryPort, Core, CoreBuilder, CrError, CrMsg, CrResult, CrTopic, DatabasePort,
-    DbResult, InitState, PostalAddress, PostalAddressState, SportConfig, SportConfigState,
-    SportPluginManagerPort, Stage, StageState, TournamentBase, TournamentBaseState, TournamentMode,
+    ApiToken, ApiTokenState, AuditRecord, CacheConfig, CachedGroupStandings, ClientErrorReport,
+    ClientErrorState, ClientRegistryPort, Core, CoreBuilder, CrError, CrMsg, CrResult, CrTopic,
+    DatabasePort, DbResult, DbTransaction, Entrant, EntrantState, Feedback, FeedbackState, Group,
+    InitState, Match, MatchNote, MatchNoteState, Official, OfficialState, PairingOverride,
+    PostalAddress, PostalAddressState, PresenceEditor, SavedGroupSeeding, ScorekeeperToken,
+    ScorekeeperTokenState, SearchState, ShiftLogEntry, ShiftLogState, SportConfig,
+    SportConfigState, SportPluginManagerPort, Stage, StageSnapshot, StageState, TournamentBase,
+    TournamentBaseState, TournamentMode, Venue, VenueState, WebhookDelivery, WebhookEndpoint,
+    WebhookState,
     utils::{id_version::IdVersion, traits::ObjectIdVersion},
 };
 use async_trait::async_trait;
+use chrono::Utc;
 use isocountry::CountryCode;
 use sport_plugin_manager::SportPluginManagerMap;
 use std::{
@@ -42,6 +71,67 @@ pub struct FakeDatabasePort {
     fail_next_get_stage: Arc<Mutex<bool>>,
     fail_next_save_stage: Arc<Mutex<bool>>,
     fail_next_list_stage: Arc<Mutex<bool>>,
+    // for shift log
+    shift_log_entries: Arc<Mutex<HashMap<Uuid, ShiftLogEntry>>>,
+    fail_next_get_sl: Arc<Mutex<bool>>,
+    fail_next_save_sl: Arc<Mutex<bool>>,
+    fail_next_list_sl: Arc<Mutex<bool>>,
+    // for match notes
+    match_notes: Arc<Mutex<HashMap<Uuid, MatchNote>>>,
+    fail_next_save_mn: Arc<Mutex<bool>>,
+    // for pairing overrides
+    pairing_overrides: Arc<Mutex<Vec<PairingOverride>>>,
+    fail_next_save_po: Arc<Mutex<bool>>,
+    // for entrants
+    entrants: Arc<Mutex<HashMap<Uuid, Entrant>>>,
+    fail_next_get_entrant: Arc<Mutex<bool>>,
+    fail_next_save_entrant: Arc<Mutex<bool>>,
+    fail_next_list_entrant: Arc<Mutex<bool>>,
+    // for officials
+    officials: Arc<Mutex<HashMap<Uuid, Official>>>,
+    fail_next_save_official: Arc<Mutex<bool>>,
+    // for cached group standings
+    group_standings: Arc<Mutex<HashMap<Uuid, CachedGroupStandings>>>,
+    fail_next_save_gs: Arc<Mutex<bool>>,
+    // for groups of stages
+    groups: Arc<Mutex<HashMap<Uuid, Group>>>,
+    // for saved group seedings
+    group_seedings: Arc<Mutex<HashMap<Uuid, SavedGroupSeeding>>>,
+    // for stage snapshots
+    stage_snapshots: Arc<Mutex<HashMap<Uuid, StageSnapshot>>>,
+    fail_next_save_snapshot: Arc<Mutex<bool>>,
+    // for matches
+    matches: Arc<Mutex<HashMap<Uuid, Match>>>,
+    fail_next_save_match: Arc<Mutex<bool>>,
+    // for api tokens
+    api_tokens: Arc<Mutex<HashMap<Uuid, ApiToken>>>,
+    fail_next_get_token: Arc<Mutex<bool>>,
+    fail_next_save_token: Arc<Mutex<bool>>,
+    fail_next_list_token: Arc<Mutex<bool>>,
+    read_only_touch_token: Arc<Mutex<bool>>,
+    // for scorekeeper tokens
+    scorekeeper_tokens: Arc<Mutex<HashMap<Uuid, ScorekeeperToken>>>,
+    fail_next_get_scorekeeper: Arc<Mutex<bool>>,
+    fail_next_save_scorekeeper: Arc<Mutex<bool>>,
+    // for webhooks
+    webhook_endpoints: Arc<Mutex<HashMap<Uuid, WebhookEndpoint>>>,
+    webhook_deliveries: Arc<Mutex<Vec<WebhookDelivery>>>,
+    fail_next_save_webhook: Arc<Mutex<bool>>,
+    fail_next_list_webhook: Arc<Mutex<bool>>,
+    // for venues
+    venues: Arc<Mutex<HashMap<Uuid, Venue>>>,
+    fail_next_save_venue: Arc<Mutex<bool>>,
+    // for feedback
+    feedback: Arc<Mutex<Vec<Feedback>>>,
+    fail_next_save_feedback: Arc<Mutex<bool>>,
+    // for client errors
+    client_errors: Arc<Mutex<Vec<ClientErrorReport>>>,
+    fail_next_save_client_error: Arc<Mutex<bool>>,
+    // for audit log
+    audit_records: Arc<Mutex<Vec<AuditRecord>>>,
+    fail_next_save_audit_record: Arc<Mutex<bool>>,
+    // for transactions: data at begin of the transaction
+    transaction: Arc<Mutex<Option<db_transaction_fake::FakeSnapshot>>>,
 }
 
 impl FakeDatabasePort {
@@ -99,11 +189,98 @@ impl FakeDatabasePort {
         assert!(tb.get_id_version().is_new());
         let id = Uuid::new_v4();
         let id_version = IdVersion::new(id, Some(0));
-        tb.set_id_version(id_version);
+        tb.set_id_version(id_version)
+            .set_created_at(Some(Utc::now()));
         self.tournament_bases.lock().unwrap().insert(id, tb);
         id
     }
 
+    /// Choose sport configuration `config_id` for tournament `t_id`.
+    pub fn link_sport_config(&self, t_id: Uuid, config_id: Uuid) {
+        self.tournament_bases
+            .lock()
+            .unwrap()
+            .get_mut(&t_id)
+            .expect("tournament must be seeded")
+            .set_sport_config_id(Some(config_id));
+    }
+
+    /// Seed and choose a sport configuration, missing stages with one group, missing
+    /// entrants and a bye of the first stage, if it has no matches, so that the readiness
+    /// checklist of tournament `t_id` passes.
+    pub fn seed_readiness(&self, t_id: Uuid) {
+        let tb = self
+            .tournament_bases
+            .lock()
+            .unwrap()
+            .get(&t_id)
+            .cloned()
+            .expect("tournament must be seeded");
+        if tb.get_sport_config_id().is_none() {
+            let mut config = SportConfig::default();
+            config
+                .set_name("Readiness Config")
+                .set_sport_id(tb.get_sport_id());
+            let config_id = self.seed_sport_config(config);
+            self.link_sport_config(t_id, config_id);
+        }
+
+        let existing_stages: Vec<u32> = self
+            .stages
+            .lock()
+            .unwrap()
+            .values()
+            .filter(|s| s.get_tournament_id() == t_id)
+            .map(|s| s.get_number())
+            .collect();
+        for number in 0..tb.get_tournament_mode().get_num_of_stages() {
+            if !existing_stages.contains(&number) {
+                let mut stage = Stage::default();
+                stage
+                    .set_tournament_id(t_id)
+                    .set_number(number)
+                    .set_num_groups(1);
+                self.seed_stage(stage);
+            }
+        }
+
+        let num_entrants = self
+            .entrants
+            .lock()
+            .unwrap()
+            .values()
+            .filter(|e| e.get_tournament_id() == t_id)
+            .count() as u32;
+        for n in num_entrants..tb.get_num_entrants() {
+            let mut entrant = Entrant::default();
+            entrant
+                .set_tournament_id(t_id)
+                .set_name(format!("Readiness Entrant {}", n + 1));
+            self.seed_entrant(entrant);
+        }
+
+        let first_stage_id = self
+            .stages
+            .lock()
+            .unwrap()
+            .values()
+            .find(|s| s.get_tournament_id() == t_id && s.get_number() == 0)
+            .map(|s| s.get_id())
+            .expect("first stage is seeded");
+        let is_scheduled = self
+            .matches
+            .lock()
+            .unwrap()
+            .values()
+            .any(|m| *m.get_stage_id() == first_stage_id);
+        if !is_scheduled {
+            // bye of an unknown entrant does not show up in schedules of seeded entrants
+            let mut bye = Match::new_bye(Uuid::new_v4(), Uuid::new_v4(), tb.get_sport_id());
+            bye.set_tournament(t_id, tb.get_sport_id(), first_stage_id);
+            self.seed_matches(vec![bye]);
+        }
+    }
+
     pub fn fail_get_tb_once(&self) {
         *self.fail_next_get_tb.lock().unwrap() = true;
     }
@@ -133,6 +310,180 @@ impl FakeDatabasePort {
     pub fn fail_list_stage_once(&self) {
         *self.fail_next_list_stage.lock().unwrap() = true;
     }
+
+    // --- Shift Log Helpers ---
+    pub fn fail_get_sl_once(&self) {
+        *self.fail_next_get_sl.lock().unwrap() = true;
+    }
+    pub fn fail_save_sl_once(&self) {
+        *self.fail_next_save_sl.lock().unwrap() = true;
+    }
+    pub fn fail_list_sl_once(&self) {
+        *self.fail_next_list_sl.lock().unwrap() = true;
+    }
+
+    // --- Match Note Helpers ---
+    pub fn fail_save_mn_once(&self) {
+        *self.fail_next_save_mn.lock().unwrap() = true;
+    }
+
+    // --- Pairing Override Helpers ---
+    pub fn fail_save_po_once(&self) {
+        *self.fail_next_save_po.lock().unwrap() = true;
+    }
+
+    // --- Feedback Helpers ---
+    pub fn fail_save_feedback_once(&self) {
+        *self.fail_next_save_feedback.lock().unwrap() = true;
+    }
+
+    // --- Client Error Helpers ---
+    pub fn fail_save_client_error_once(&self) {
+        *self.fail_next_save_client_error.lock().unwrap() = true;
+    }
+
+    // --- Audit Log Helpers ---
+    pub fn audit_records(&self) -> Vec<AuditRecord> {
+        self.audit_records.lock().unwrap().clone()
+    }
+    pub fn fail_save_audit_record_once(&self) {
+        *self.fail_next_save_audit_record.lock().unwrap() = true;
+    }
+
+    // --- Entrant Helpers ---
+    pub fn seed_entrant(&self, mut entrant: Entrant) -> Uuid {
+        assert!(entrant.get_id_version().is_new());
+        let id = Uuid::new_v4();
+        let id_version = IdVersion::new(id, Some(0));
+        entrant.set_id_version(id_version);
+        self.entrants.lock().unwrap().insert(id, entrant);
+        id
+    }
+    pub fn fail_get_entrant_once(&self) {
+        *self.fail_next_get_entrant.lock().unwrap() = true;
+    }
+    pub fn fail_save_entrant_once(&self) {
+        *self.fail_next_save_entrant.lock().unwrap() = true;
+    }
+    pub fn fail_list_entrant_once(&self) {
+        *self.fail_next_list_entrant.lock().unwrap() = true;
+    }
+
+    // --- Official Helpers ---
+    pub fn fail_save_official_once(&self) {
+        *self.fail_next_save_official.lock().unwrap() = true;
+    }
+
+    // --- Group Standings Helpers ---
+    pub fn seed_group_standings(&self, standings: CachedGroupStandings) {
+        self.group_standings
+            .lock()
+            .unwrap()
+            .insert(standings.group_id, standings);
+    }
+    pub fn group_standings_of(&self, group_id: Uuid) -> Option<CachedGroupStandings> {
+        self.group_standings.lock().unwrap().get(&group_id).cloned()
+    }
+    pub fn fail_save_gs_once(&self) {
+        *self.fail_next_save_gs.lock().unwrap() = true;
+    }
+
+    // --- Group Seeding Helpers ---
+    /// groups of stage sorted by number
+    pub fn groups_of(&self, stage_id: Uuid) -> Vec<Group> {
+        let mut rows: Vec<_> = self
+            .groups
+            .lock()
+            .unwrap()
+            .values()
+            .filter(|g| g.get_stage_id() == stage_id)
+            .cloned()
+            .collect();
+        rows.sort_by_key(|g| g.get_number());
+        rows
+    }
+    pub fn group_seeding_of(&self, stage_id: Uuid) -> Option<SavedGroupSeeding> {
+        self.group_seedings.lock().unwrap().get(&stage_id).cloned()
+    }
+
+    // --- Stage Snapshot Helpers ---
+    pub fn stage_snapshots_of(&self, tournament_id: Uuid) -> Vec<StageSnapshot> {
+        let mut rows: Vec<_> = self
+            .stage_snapshots
+            .lock()
+            .unwrap()
+            .values()
+            .filter(|s| s.tournament_id == tournament_id)
+            .cloned()
+            .collect();
+        rows.sort_by_key(|s| (s.stage_number, s.created_at));
+        rows
+    }
+    pub fn fail_save_snapshot_once(&self) {
+        *self.fail_next_save_snapshot.lock().unwrap() = true;
+    }
+
+    // --- Match Helpers ---
+    pub fn seed_matches(&self, matches: Vec<Match>) {
+        let mut stored = self.matches.lock().unwrap();
+        for m in matches {
+            stored.insert(*m.get_id(), m);
+        }
+    }
+    pub fn matches_of(&self, tournament_id: Uuid) -> Vec<Match> {
+        let mut rows: Vec<_> = self
+            .matches
+            .lock()
+            .unwrap()
+            .values()
+            .filter(|m| *m.get_tournament_id() == tournament_id)
+            .cloned()
+            .collect();
+        rows.sort_by_key(|m| (m.get_start_at(), m.get_station()));
+        rows
+    }
+    pub fn fail_save_match_once(&self) {
+        *self.fail_next_save_match.lock().unwrap() = true;
+    }
+
+    // --- Api Token Helpers ---
+    pub fn fail_get_token_once(&self) {
+        *self.fail_next_get_token.lock().unwrap() = true;
+    }
+    pub fn fail_save_token_once(&self) {
+        *self.fail_next_save_token.lock().unwrap() = true;
+    }
+    pub fn fail_list_token_once(&self) {
+        *self.fail_next_list_token.lock().unwrap() = true;
+    }
+    /// Next update of `last_used_at` of an api token fails, as if the db is read only.
+    pub fn fail_touch_token_read_only_once(&self) {
+        *self.read_only_touch_token.lock().unwrap() = true;
+    }
+
+    // --- Scorekeeper Token Helpers ---
+    pub fn fail_get_scorekeeper_once(&self) {
+        *self.fail_next_get_scorekeeper.lock().unwrap() = true;
+    }
+    pub fn fail_save_scorekeeper_once(&self) {
+        *self.fail_next_save_scorekeeper.lock().unwrap() = true;
+    }
+
+    // --- Webhook Helpers ---
+    pub fn webhook_deliveries(&self) -> Vec<WebhookDelivery> {
+        self.webhook_deliveries.lock().unwrap().clone()
+    }
+    pub fn fail_save_webhook_once(&self) {
+        *self.fail_next_save_webhook.lock().unwrap() = true;
+    }
+    pub fn fail_list_webhook_once(&self) {
+        *self.fail_next_list_webhook.lock().unwrap() = true;
+    }
+
+    // --- Venue Helpers ---
+    pub fn fail_save_venue_once(&self) {
+        *self.fail_next_save_venue.lock().unwrap() = true;
+    }
 }
 
 // Blanket impl: your DatabasePort is a supertrait of DbpPostalAddress and DbpSportConfig.
@@ -141,6 +492,9 @@ impl DatabasePort for FakeDatabasePort {
     async fn ping_db(&self) -> DbResult<()> {
         Ok(())
     }
+    async fn begin_transaction(&self) -> DbResult<Arc<dyn DbTransaction>> {
+        self.fake_begin_transaction()
+    }
 }
 
 /// Minimal ClientRegistry fake.
@@ -148,6 +502,8 @@ impl DatabasePort for FakeDatabasePort {
 pub struct FakeClientRegistryPort {
     published: Arc<Mutex<Vec<CrMsg>>>,
     fail_next_publish: Arc<Mutex<bool>>,
+    /// editors by presence topic in order of joining; editors never expire
+    presence: Arc<Mutex<HashMap<CrTopic, Vec<PresenceEditor>>>>,
 }
 
 impl FakeClientRegistryPort {
@@ -176,6 +532,39 @@ impl ClientRegistryPort for FakeClientRegistryPort {
         self.published.lock().unwrap().push(notice);
         Ok(())
     }
+    async fn join_presence(&self, topic: CrTopic, editor: PresenceEditor) -> CrResult<bool> {
+        let mut presence = self.presence.lock().unwrap();
+        let editors = presence.entry(topic).or_default();
+        match editors.iter_mut().find(|e| e.client_id == editor.client_id) {
+            Some(existing) if *existing == editor => Ok(false),
+            Some(existing) => {
+                *existing = editor;
+                Ok(true)
+            }
+            None => {
+                editors.push(editor);
+                Ok(true)
+            }
+        }
+    }
+
+    async fn leave_presence(&self, topic: CrTopic, client_id: Uuid) -> CrResult<bool> {
+        let mut presence = self.presence.lock().unwrap();
+        let editors = presence.entry(topic).or_default();
+        let len = editors.len();
+        editors.retain(|e| e.client_id != client_id);
+        Ok(editors.len() < len)
+    }
+
+    async fn list_presence(&self, topic: CrTopic) -> CrResult<Vec<PresenceEditor>> {
+        Ok(self
+            .presence
+            .lock()
+            .unwrap()
+            .get(&topic)
+            .cloned()
+            .unwrap_or_default())
+    }
 }
 
 /// Convenience: construct a realistic PostalAddress for seeding.
@@ -206,7 +595,7 @@ pub fn make_core_with_fakes() -> (
 ) {
     let db = Arc::new(FakeDatabasePort::new());
     let cr = Arc::new(FakeClientRegistryPort::new());
-    let mut spm = SportPluginManagerMap::new();
+    let spm = SportPluginManagerMap::new();
     spm.register(Arc::new(MockSport {
         id: Uuid::new_v4(),
         name: "Mock Sport",
@@ -217,10 +606,40 @@ pub fn make_core_with_fakes() -> (
         .set_db(db.clone())
         .set_cr(cr.clone())
         .set_spm(spm.clone())
+        .set_wh(Arc::new(FakeWebhookTransport::new()))
+        .set_em(Arc::new(FakeEmailPort::new()))
+        .set_bs(Arc::new(FakeBlobStorage::new()))
+        .set_ev(Arc::new(FakeDomainEventPort::new()))
         .build();
     (core, db, cr, spm)
 }
 
+/// Helper: build a Core<InitState> with a cached database port wrapping our db fake.
+pub fn make_core_with_cached_db_fakes() -> (
+    Core<InitState>,
+    Arc<FakeDatabasePort>,
+    Arc<FakeClientRegistryPort>,
+) {
+    let db = Arc::new(FakeDatabasePort::new());
+    let cr = Arc::new(FakeClientRegistryPort::new());
+    let spm = SportPluginManagerMap::new();
+    spm.register(Arc::new(MockSport {
+        id: Uuid::new_v4(),
+        name: "Mock Sport",
+    }))
+    .unwrap();
+    let core = CoreBuilder::new()
+        .set_cached_db(db.clone(), CacheConfig::default())
+        .set_cr(cr.clone())
+        .set_spm(Arc::new(spm))
+        .set_wh(Arc::new(FakeWebhookTransport::new()))
+        .set_em(Arc::new(FakeEmailPort::new()))
+        .set_bs(Arc::new(FakeBlobStorage::new()))
+        .set_ev(Arc::new(FakeDomainEventPort::new()))
+        .build();
+    (core, db, cr)
+}
+
 pub fn make_core_postal_address_state_with_fakes() -> (
     Core<PostalAddressState>,
     Arc<FakeDatabasePort>,
@@ -291,3 +710,331 @@ pub fn make_core_stage_state_with_fakes() -> (
 
     (core_stage_state, db, cr)
 }
+
+pub fn make_core_shift_log_state_with_fakes() -> (
+    Core<ShiftLogState>,
+    Arc<FakeDatabasePort>,
+    Arc<FakeClientRegistryPort>,
+) {
+    let (core, db, cr, spm) = make_core_with_fakes();
+
+    let sport_id = spm.list()[0].get_id_version().get_id();
+    let mut tb = TournamentBase::default();
+    tb.set_name("Shift Log Tournament")
+        .set_sport_id(sport_id)
+        .set_num_entrants(8);
+    let t_id = db.seed_tournament_base(tb);
+
+    (core.as_shift_log_state(t_id), db, cr)
+}
+
+pub fn make_core_match_note_state_with_fakes() -> (
+    Core<MatchNoteState>,
+    Arc<FakeDatabasePort>,
+    Arc<FakeClientRegistryPort>,
+) {
+    let (core, db, cr, spm) = make_core_with_fakes();
+
+    let sport_id = spm.list()[0].get_id_version().get_id();
+    let mut tb = TournamentBase::default();
+    tb.set_name("Match Note Tournament")
+        .set_sport_id(sport_id)
+        .set_num_entrants(8);
+    let t_id = db.seed_tournament_base(tb);
+
+    (core.as_match_note_state(t_id), db, cr)
+}
+
+pub fn make_core_entrant_state_with_fakes() -> (
+    Core<EntrantState>,
+    Arc<FakeDatabasePort>,
+    Arc<FakeClientRegistryPort>,
+) {
+    let (core, db, cr, spm) = make_core_with_fakes();
+
+    let sport_id = spm.list()[0].get_id_version().get_id();
+    let mut tb = TournamentBase::default();
+    tb.set_name("Entrant Tournament")
+        .set_sport_id(sport_id)
+        .set_num_entrants(16);
+    let t_id = db.seed_tournament_base(tb);
+
+    (core.as_entrant_state(t_id), db, cr)
+}
+
+pub fn make_core_official_state_with_fakes() -> (
+    Core<OfficialState>,
+    Arc<FakeDatabasePort>,
+    Arc<FakeClientRegistryPort>,
+) {
+    let (core, db, cr, spm) = make_core_with_fakes();
+
+    let sport_id = spm.list()[0].get_id_version().get_id();
+    let mut tb = TournamentBase::default();
+    tb.set_name("Official Tournament")
+        .set_sport_id(sport_id)
+        .set_num_entrants(16);
+    let t_id = db.seed_tournament_base(tb);
+
+    (core.as_official_state(t_id), db, cr)
+}
+
+pub fn make_core_api_token_state_with_fakes() -> (
+    Core<ApiTokenState>,
+    Arc<FakeDatabasePort>,
+    Arc<FakeClientRegistryPort>,
+) {
+    let (core, db, cr, _spm) = make_core_with_fakes();
+    (core.as_api_token_state(), db, cr)
+}
+
+pub fn make_core_client_error_state_with_fakes() -> (
+    Core<ClientErrorState>,
+    Arc<FakeDatabasePort>,
+    Arc<FakeClientRegistryPort>,
+) {
+    let (core, db, cr, _spm) = make_core_with_fakes();
+    (core.as_client_error_state(), db, cr)
+}
+
+pub fn make_core_feedback_state_with_fakes() -> (
+    Core<FeedbackState>,
+    Arc<FakeDatabasePort>,
+    Arc<FakeClientRegistryPort>,
+) {
+    let (core, db, cr, _spm) = make_core_with_fakes();
+    (core.as_feedback_state(), db, cr)
+}
+
+pub fn make_core_search_state_with_fakes() -> (
+    Core<SearchState>,
+    Arc<FakeDatabasePort>,
+    Arc<FakeClientRegistryPort>,
+) {
+    let (core, db, cr, _spm) = make_core_with_fakes();
+    (core.as_search_state(), db, cr)
+}
+
+/// Core in scorekeeper token state for a tournament, whose first stage has 4 groups.
+pub fn make_core_scorekeeper_token_state_with_fakes() -> (
+    Core<ScorekeeperTokenState>,
+    Arc<FakeDatabasePort>,
+    Arc<FakeClientRegistryPort>,
+) {
+    let (core, db, cr, spm) = make_core_with_fakes();
+
+    let sport_id = spm.list()[0].get_id_version().get_id();
+    let mut tb = TournamentBase::default();
+    tb.set_name("Scorekeeper Tournament")
+        .set_sport_id(sport_id)
+        .set_num_entrants(16)
+        .set_tournament_mode(TournamentMode::TwoPoolStagesAndFinalStage);
+    let t_id = db.seed_tournament_base(tb);
+
+    let mut stage = Stage::default();
+    stage
+        .set_tournament_id(t_id)
+        .set_number(0)
+        .set_num_groups(4);
+    db.seed_stage(stage);
+
+    (core.as_scorekeeper_token_state(t_id), db, cr)
+}
+
+pub fn make_core_webhook_state_with_fakes() -> (
+    Core<WebhookState>,
+    Arc<FakeDatabasePort>,
+    Arc<FakeClientRegistryPort>,
+    Arc<FakeWebhookTransport>,
+) {
+    let (core, db, cr, spm) = make_core_with_fakes();
+    let wh = Arc::new(FakeWebhookTransport::new());
+    let core = CoreBuilder::new()
+        .set_db(core.database.clone())
+        .set_cr(core.client_registry.clone())
+        .set_spm(spm)
+        .set_wh(wh.clone())
+        .set_em(core.email.clone())
+        .set_bs(core.blobs.clone())
+        .set_ev(core.domain_events.clone())
+        .build();
+    (core.as_webhook_state(), db, cr, wh)
+}
+
+/// Core with webhook state, whose webhooks are queued instead of being delivered.
+pub fn make_core_webhook_state_with_queue_fake() -> (
+    Core<WebhookState>,
+    Arc<FakeDatabasePort>,
+    Arc<FakeWebhookTransport>,
+    Arc<FakeWebhookQueue>,
+) {
+    let (core, db, _cr, wh) = make_core_webhook_state_with_fakes();
+    let queue = Arc::new(FakeWebhookQueue::new());
+    let core = CoreBuilder::new()
+        .set_db(core.database.clone())
+        .set_cr(core.client_registry.clone())
+        .set_spm(core.sport_plugins.clone())
+        .set_wh(wh.clone())
+        .set_em(core.email.clone())
+        .set_bs(core.blobs.clone())
+        .set_ev(core.domain_events.clone())
+        .set_webhook_queue(queue.clone())
+        .build();
+    (core.as_webhook_state(), db, wh, queue)
+}
+
+pub fn make_core_venue_state_with_fakes() -> (
+    Core<VenueState>,
+    Arc<FakeDatabasePort>,
+    Arc<FakeClientRegistryPort>,
+) {
+    let (core, db, cr, _spm) = make_core_with_fakes();
+    (core.as_venue_state(), db, cr)
+}
+
+/// Core with a tournament of 16 entrants, whose email port records all sent emails.
+pub fn make_core_with_email_fake() -> (
+    Core<InitState>,
+    Arc<FakeDatabasePort>,
+    Arc<FakeEmailPort>,
+    Uuid,
+) {
+    let (core, db, cr, spm) = make_core_with_fakes();
+
+    let sport_id = spm.list()[0].get_id_version().get_id();
+    let mut tb = TournamentBase::default();
+    tb.set_name("Notification Tournament")
+        .set_sport_id(sport_id)
+        .set_num_entrants(16);
+    let t_id = db.seed_tournament_base(tb);
+
+    let em = Arc::new(FakeEmailPort::new());
+    let core = CoreBuilder::new()
+        .set_db(core.database.clone())
+        .set_cr(cr)
+        .set_spm(spm)
+        .set_wh(core.webhooks.clone())
+        .set_em(em.clone())
+        .set_bs(core.blobs.clone())
+        .set_ev(core.domain_events.clone())
+        .build();
+    (core, db, em, t_id)
+}
+
+/// Core with a tournament of 16 entrants, whose blob storage port keeps all blobs in memory.
+pub fn make_core_with_blob_fake() -> (
+    Core<InitState>,
+    Arc<FakeDatabasePort>,
+    Arc<FakeBlobStorage>,
+    Uuid,
+) {
+    let (core, db, cr, spm) = make_core_with_fakes();
+
+    let sport_id = spm.list()[0].get_id_version().get_id();
+    let mut tb = TournamentBase::default();
+    tb.set_name("Report Tournament")
+        .set_sport_id(sport_id)
+        .set_num_entrants(16);
+    let t_id = db.seed_tournament_base(tb);
+
+    let bs = Arc::new(FakeBlobStorage::new());
+    let core = CoreBuilder::new()
+        .set_db(core.database.clone())
+        .set_cr(cr)
+        .set_spm(spm)
+        .set_wh(core.webhooks.clone())
+        .set_em(core.email.clone())
+        .set_bs(bs.clone())
+        .set_ev(core.domain_events.clone())
+        .build();
+    (core, db, bs, t_id)
+}
+
+/// Core, whose domain event port records all emitted events.
+pub fn make_core_with_domain_event_fake() -> (
+    Core<InitState>,
+    Arc<FakeDatabasePort>,
+    Arc<FakeDomainEventPort>,
+) {
+    let (core, db, cr, spm) = make_core_with_fakes();
+
+    let ev = Arc::new(FakeDomainEventPort::new());
+    let core = CoreBuilder::new()
+        .set_db(core.database.clone())
+        .set_cr(cr)
+        .set_spm(spm)
+        .set_wh(core.webhooks.clone())
+        .set_em(core.email.clone())
+        .set_bs(core.blobs.clone())
+        .set_ev(ev.clone())
+        .build();
+    (core, db, ev)
+}
+
+/// Core with a tournament of 8 entrants in 2 groups of first stage, whose sport provides a
+/// ranking system. Entrants are not seeded.
+pub fn make_core_with_ranking_fake() -> (
+    Core<InitState>,
+    Arc<FakeDatabasePort>,
+    Arc<FakeRankingSystem>,
+    Uuid,
+) {
+    let db = Arc::new(FakeDatabasePort::new());
+    let ranking = Arc::new(FakeRankingSystem::new());
+    let sport_id = Uuid::new_v4();
+    let spm = SportPluginManagerMap::new();
+    spm.register(Arc::new(RankedMockSport {
+        sport: MockSport {
+            id: sport_id,
+            name: "Ranked Mock Sport",
+        },
+        ranking: ranking.clone(),
+    }))
+    .unwrap();
+    let core = CoreBuilder::new()
+        .set_db(db.clone())
+        .set_cr(Arc::new(FakeClientRegistryPort::new()))
+        .set_spm(Arc::new(spm))
+        .set_wh(Arc::new(FakeWebhookTransport::new()))
+        .set_em(Arc::new(FakeEmailPort::new()))
+        .set_bs(Arc::new(FakeBlobStorage::new()))
+        .set_ev(Arc::new(FakeDomainEventPort::new()))
+        .build();
+
+    let mut tb = TournamentBase::default();
+    tb.set_name("Ranking Tournament")
+        .set_sport_id(sport_id)
+        .set_num_entrants(8)
+        .set_tournament_mode(TournamentMode::PoolAndFinalStage);
+    let t_id = db.seed_tournament_base(tb);
+    let mut stage = Stage::default();
+    stage
+        .set_tournament_id(t_id)
+        .set_number(0)
+        .set_num_groups(2);
+    db.seed_stage(stage);
+
+    (core, db, ranking, t_id)
+}
+
+/// Core with a Swiss system tournament of 6 entrants and its single stage. Returns ids of
+/// tournament and stage.
+pub fn make_core_pairing_with_fakes() -> (Core<InitState>, Arc<FakeDatabasePort>, Uuid, Uuid) {
+    let (core, db, _cr, spm) = make_core_with_fakes();
+
+    let sport_id = spm.list()[0].get_id_version().get_id();
+    let mut tb = TournamentBase::default();
+    tb.set_name("Pairing Tournament")
+        .set_sport_id(sport_id)
+        .set_num_entrants(6)
+        .set_tournament_mode(TournamentMode::SwissSystem { num_rounds: 3 });
+    let t_id = db.seed_tournament_base(tb);
+    let mut stage = Stage::default();
+    stage
+        .set_tournament_id(t_id)
+        .set_number(0)
+        .set_num_groups(1);
+    let stage_id = db.seed_stage(stage);
+
+    (core, db, t_id, stage_id)
+}
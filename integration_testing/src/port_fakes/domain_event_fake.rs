@@ -0,0 +1,36 @@
+//! Fake for DomainEventPort
+
+use app_core::{DomainEvent, DomainEventEnvelope, DomainEventPort, DomainEventResult};
+use async_trait::async_trait;
+use std::sync::{Arc, Mutex};
+
+/// Records all emitted domain events.
+#[derive(Clone, Default)]
+pub struct FakeDomainEventPort {
+    emitted: Arc<Mutex<Vec<DomainEventEnvelope>>>,
+}
+
+impl FakeDomainEventPort {
+    pub fn new() -> Self {
+        Self::default()
+    }
+    pub fn emitted(&self) -> Vec<DomainEvent> {
+        self.emitted
+            .lock()
+            .unwrap()
+            .iter()
+            .map(|envelope| envelope.event.clone())
+            .collect()
+    }
+    pub fn clear(&self) {
+        self.emitted.lock().unwrap().clear();
+    }
+}
+
+#[async_trait]
+impl DomainEventPort for FakeDomainEventPort {
+    async fn emit(&self, event: &DomainEventEnvelope) -> DomainEventResult<()> {
+        self.emitted.lock().unwrap().push(event.clone());
+        Ok(())
+    }
+}
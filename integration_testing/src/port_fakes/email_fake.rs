@@ -0,0 +1,37 @@
+//! Fake for EmailPort
+
+use app_core::{EmailError, EmailMessage, EmailPort, EmailResult};
+use async_trait::async_trait;
+use std::sync::{Arc, Mutex};
+
+/// Records all sent emails.
+#[derive(Clone, Default)]
+pub struct FakeEmailPort {
+    sent: Arc<Mutex<Vec<EmailMessage>>>,
+    fail_next_send: Arc<Mutex<bool>>,
+}
+
+impl FakeEmailPort {
+    pub fn new() -> Self {
+        Self::default()
+    }
+    pub fn sent(&self) -> Vec<EmailMessage> {
+        self.sent.lock().unwrap().clone()
+    }
+    pub fn fail_send_once(&self) {
+        *self.fail_next_send.lock().unwrap() = true;
+    }
+}
+
+#[async_trait]
+impl EmailPort for FakeEmailPort {
+    async fn send_email(&self, message: &EmailMessage) -> EmailResult<()> {
+        let mut guard = self.fail_next_send.lock().unwrap();
+        if *guard {
+            *guard = false;
+            return Err(EmailError::Transport("injected send failure".into()));
+        }
+        self.sent.lock().unwrap().push(message.clone());
+        Ok(())
+    }
+}
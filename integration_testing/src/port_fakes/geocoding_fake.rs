@@ -0,0 +1,55 @@
+//! Fake for GeocodingPort
+
+use app_core::{GeoPoint, GeocodingError, GeocodingPort, GeocodingResult, PostalAddress};
+use async_trait::async_trait;
+use std::{
+    collections::HashMap,
+    sync::{Arc, Mutex},
+};
+
+/// Resolves addresses by their locality; unknown localities are not geocoded.
+#[derive(Clone, Default)]
+pub struct FakeGeocodingPort {
+    locations: Arc<Mutex<HashMap<String, GeoPoint>>>,
+    requests: Arc<Mutex<usize>>,
+    fail_next_geocode: Arc<Mutex<bool>>,
+}
+
+impl FakeGeocodingPort {
+    pub fn new() -> Self {
+        Self::default()
+    }
+    /// Resolve all addresses in `locality` to `location`.
+    pub fn with_locality(self, locality: &str, location: GeoPoint) -> Self {
+        self.locations
+            .lock()
+            .unwrap()
+            .insert(locality.to_string(), location);
+        self
+    }
+    /// number of geocoding requests
+    pub fn requests(&self) -> usize {
+        *self.requests.lock().unwrap()
+    }
+    pub fn fail_geocode_once(&self) {
+        *self.fail_next_geocode.lock().unwrap() = true;
+    }
+}
+
+#[async_trait]
+impl GeocodingPort for FakeGeocodingPort {
+    async fn geocode(&self, address: &PostalAddress) -> GeocodingResult<Option<GeoPoint>> {
+        *self.requests.lock().unwrap() += 1;
+        let mut guard = self.fail_next_geocode.lock().unwrap();
+        if *guard {
+            *guard = false;
+            return Err(GeocodingError::Request("injected geocoding failure".into()));
+        }
+        Ok(self
+            .locations
+            .lock()
+            .unwrap()
+            .get(address.get_locality())
+            .copied())
+    }
+}
@@ -1,7 +1,19 @@
 //! port fakes for integration testing
 
+mod blob_fake;
 mod db_fake;
+mod domain_event_fake;
+mod email_fake;
+mod geocoding_fake;
+mod ranking_fake;
 mod sport_fake;
+mod webhook_fake;
 
+pub use blob_fake::*;
 pub use db_fake::*;
+pub use domain_event_fake::*;
+pub use email_fake::*;
+pub use geocoding_fake::*;
+pub use ranking_fake::*;
 pub use sport_fake::*;
+pub use webhook_fake::*;
@@ -0,0 +1,77 @@
+//! Fake for RankingSystemPort
+
+use app_core::{
+    Entrant, EntrantRank, RankingError, RankingResult, RankingSystemPort, TournamentBase,
+};
+use async_trait::async_trait;
+use std::{
+    collections::HashMap,
+    sync::{Arc, Mutex},
+};
+use uuid::Uuid;
+
+/// final standings pushed to the ranking system by tournament id
+type PushedResults = Vec<(Uuid, Vec<EntrantRank>)>;
+
+/// Ranking system with ranks by entrant name; records all pushed results.
+#[derive(Clone, Default)]
+pub struct FakeRankingSystem {
+    ranks: Arc<Mutex<HashMap<String, u32>>>,
+    pushed: Arc<Mutex<PushedResults>>,
+    fail_next_fetch: Arc<Mutex<bool>>,
+}
+
+impl FakeRankingSystem {
+    pub fn new() -> Self {
+        Self::default()
+    }
+    pub fn set_rank(&self, name: &str, rank: u32) {
+        self.ranks.lock().unwrap().insert(name.to_string(), rank);
+    }
+    /// pushed results by tournament id
+    pub fn pushed(&self) -> PushedResults {
+        self.pushed.lock().unwrap().clone()
+    }
+    pub fn fail_fetch_once(&self) {
+        *self.fail_next_fetch.lock().unwrap() = true;
+    }
+}
+
+#[async_trait]
+impl RankingSystemPort for FakeRankingSystem {
+    fn name(&self) -> &'static str {
+        "Fake Ranking"
+    }
+
+    async fn fetch_seed_ranks(&self, entrants: &[Entrant]) -> RankingResult<Vec<EntrantRank>> {
+        {
+            let mut guard = self.fail_next_fetch.lock().unwrap();
+            if *guard {
+                *guard = false;
+                return Err(RankingError::Request("injected fetch failure".into()));
+            }
+        }
+        let ranks = self.ranks.lock().unwrap();
+        Ok(entrants
+            .iter()
+            .filter_map(|e| {
+                ranks.get(e.get_name()).map(|rank| EntrantRank {
+                    entrant_id: e.get_id(),
+                    rank: *rank,
+                })
+            })
+            .collect())
+    }
+
+    async fn push_final_results(
+        &self,
+        tournament: &TournamentBase,
+        standings: &[EntrantRank],
+    ) -> RankingResult<()> {
+        self.pushed
+            .lock()
+            .unwrap()
+            .push((tournament.get_id(), standings.to_vec()));
+        Ok(())
+    }
+}
@@ -1,7 +1,8 @@
 //! sport port fake and testing of SportPluginManagerMap
 
+use crate::port_fakes::FakeRankingSystem;
 use app_core::{
-    EntrantGroupScore, Match, SportConfig, SportPort, SportResult,
+    EntrantGroupScore, Match, RankingSystemPort, SportConfig, SportPort, SportResult,
     utils::{
         id_version::IdVersion,
         traits::ObjectIdVersion,
@@ -11,7 +12,7 @@ use app_core::{
 use leptos::prelude::*;
 use serde_json::Value;
 use shared::SportPortWebUi;
-use std::time::Duration;
+use std::{sync::Arc, time::Duration};
 use uuid::Uuid;
 
 // A mock sport plugin for testing purposes.
@@ -78,25 +79,153 @@ impl SportPort for MockSport {
         entrant_id: Uuid,
         _all_matches: &[Match],
     ) -> SportResult<EntrantGroupScore> {
-        Ok(EntrantGroupScore {
-            entrant_id,
-            group_id,
-            victory_points: 0.0,
-            relative_score: 0,
-            total_score: 0,
-        })
+        Ok(EntrantGroupScore::new(entrant_id, group_id))
     }
 }
+// A mock sport plugin, which provides a ranking system.
+pub struct RankedMockSport {
+    pub sport: MockSport,
+    pub ranking: Arc<FakeRankingSystem>,
+}
+
+impl ObjectIdVersion for RankedMockSport {
+    fn get_id_version(&self) -> IdVersion {
+        self.sport.get_id_version()
+    }
+}
+
+impl SportPortWebUi for RankedMockSport {
+    fn render_plugin_selection(&self) -> AnyView {
+        self.sport.render_plugin_selection()
+    }
+    fn render_preview(&self, config: &SportConfig) -> AnyView {
+        self.sport.render_preview(config)
+    }
+    fn render_detailed_preview(&self, config: &SportConfig) -> AnyView {
+        self.sport.render_detailed_preview(config)
+    }
+    fn render_configuration(&self) -> AnyView {
+        self.sport.render_configuration()
+    }
+}
+
+impl SportPort for RankedMockSport {
+    fn name(&self) -> &'static str {
+        self.sport.name()
+    }
+
+    fn get_default_config(&self) -> Value {
+        self.sport.get_default_config()
+    }
+
+    fn validate_config_values(
+        &self,
+        config: &SportConfig,
+        err: ValidationErrors,
+    ) -> ValidationResult<()> {
+        self.sport.validate_config_values(config, err)
+    }
+
+    fn estimate_match_duration(&self, config: &SportConfig) -> SportResult<Duration> {
+        self.sport.estimate_match_duration(config)
+    }
+
+    fn validate_final_score(&self, config: &SportConfig, score: &Match) -> SportResult<()> {
+        self.sport.validate_final_score(config, score)
+    }
+
+    fn get_entrant_group_score(
+        &self,
+        config: &SportConfig,
+        group_id: Uuid,
+        entrant_id: Uuid,
+        all_matches: &[Match],
+    ) -> SportResult<EntrantGroupScore> {
+        self.sport
+            .get_entrant_group_score(config, group_id, entrant_id, all_matches)
+    }
+
+    fn ranking_system(&self) -> Option<Arc<dyn RankingSystemPort>> {
+        Some(self.ranking.clone())
+    }
+}
+
+// A mock sport plugin with a plugin version > 0. Migration of configs of older versions
+// records the migrated version in key `migrated_from`.
+pub struct VersionedMockSport {
+    pub sport: MockSport,
+    pub version: u32,
+}
+
+impl ObjectIdVersion for VersionedMockSport {
+    fn get_id_version(&self) -> IdVersion {
+        IdVersion::new(self.sport.id(), Some(self.version))
+    }
+}
+
+impl SportPortWebUi for VersionedMockSport {
+    fn render_plugin_selection(&self) -> AnyView {
+        self.sport.render_plugin_selection()
+    }
+    fn render_preview(&self, config: &SportConfig) -> AnyView {
+        self.sport.render_preview(config)
+    }
+    fn render_detailed_preview(&self, config: &SportConfig) -> AnyView {
+        self.sport.render_detailed_preview(config)
+    }
+}
+
+impl SportPort for VersionedMockSport {
+    fn name(&self) -> &'static str {
+        self.sport.name()
+    }
+
+    fn get_default_config(&self) -> Value {
+        self.sport.get_default_config()
+    }
+
+    fn validate_config_values(
+        &self,
+        config: &SportConfig,
+        err: ValidationErrors,
+    ) -> ValidationResult<()> {
+        self.sport.validate_config_values(config, err)
+    }
+
+    fn estimate_match_duration(&self, config: &SportConfig) -> SportResult<Duration> {
+        self.sport.estimate_match_duration(config)
+    }
+
+    fn validate_final_score(&self, config: &SportConfig, score: &Match) -> SportResult<()> {
+        self.sport.validate_final_score(config, score)
+    }
+
+    fn get_entrant_group_score(
+        &self,
+        config: &SportConfig,
+        group_id: Uuid,
+        entrant_id: Uuid,
+        all_matches: &[Match],
+    ) -> SportResult<EntrantGroupScore> {
+        self.sport
+            .get_entrant_group_score(config, group_id, entrant_id, all_matches)
+    }
+
+    fn migrate_config(&self, old_version: u32, mut config: Value) -> SportResult<Value> {
+        config["migrated_from"] = old_version.into();
+        Ok(config)
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
     use app_core::{SportPluginManagerPort, utils::traits::ObjectIdVersion};
     use sport_plugin_manager::SportPluginManagerMap;
-    use std::sync::Arc;
 
     #[test]
     fn test_register_and_get() {
-        let mut manager = SportPluginManagerMap::new();
+        let manager = SportPluginManagerMap::new();
         let sport_id = Uuid::new_v4();
         let plugin = Arc::new(MockSport {
             id: sport_id,
@@ -120,7 +249,7 @@ mod tests {
 
     #[test]
     fn test_list_plugins() {
-        let mut manager = SportPluginManagerMap::new();
+        let manager = SportPluginManagerMap::new();
         let sport_id1 = Uuid::new_v4();
         let sport_id2 = Uuid::new_v4();
         let plugin1 = Arc::new(MockSport {
@@ -149,7 +278,7 @@ mod tests {
 
     #[test]
     fn test_overwrite_plugin_should_fail() {
-        let mut manager = SportPluginManagerMap::new();
+        let manager = SportPluginManagerMap::new();
         let sport_id = Uuid::new_v4();
         let plugin1 = Arc::new(MockSport {
             id: sport_id,
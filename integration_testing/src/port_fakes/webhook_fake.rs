@@ -0,0 +1,100 @@
+//! Fakes for WebhookTransportPort and WebhookQueuePort
+
+use app_core::{WebhookError, WebhookJob, WebhookQueuePort, WebhookResult, WebhookTransportPort};
+use async_trait::async_trait;
+use std::sync::{Arc, Mutex};
+
+/// request as received by [`FakeWebhookTransport`]
+#[derive(Clone, Debug)]
+pub struct SentWebhook {
+    pub url: String,
+    pub headers: Vec<(String, String)>,
+    pub body: String,
+}
+
+impl SentWebhook {
+    pub fn header(&self, name: &str) -> Option<&str> {
+        self.headers
+            .iter()
+            .find(|(n, _)| n == name)
+            .map(|(_, v)| v.as_str())
+    }
+}
+
+/// Records all posted webhooks and responds with a configurable status code.
+#[derive(Clone)]
+pub struct FakeWebhookTransport {
+    sent: Arc<Mutex<Vec<SentWebhook>>>,
+    status_code: Arc<Mutex<u16>>,
+    fail_next_post: Arc<Mutex<bool>>,
+}
+
+impl Default for FakeWebhookTransport {
+    fn default() -> Self {
+        FakeWebhookTransport {
+            sent: Arc::default(),
+            status_code: Arc::new(Mutex::new(200)),
+            fail_next_post: Arc::default(),
+        }
+    }
+}
+
+impl FakeWebhookTransport {
+    pub fn new() -> Self {
+        Self::default()
+    }
+    pub fn sent(&self) -> Vec<SentWebhook> {
+        self.sent.lock().unwrap().clone()
+    }
+    pub fn set_status_code(&self, status_code: u16) {
+        *self.status_code.lock().unwrap() = status_code;
+    }
+    pub fn fail_post_once(&self) {
+        *self.fail_next_post.lock().unwrap() = true;
+    }
+}
+
+#[async_trait]
+impl WebhookTransportPort for FakeWebhookTransport {
+    async fn post_json(
+        &self,
+        url: &str,
+        headers: &[(String, String)],
+        body: &str,
+    ) -> WebhookResult<u16> {
+        let mut guard = self.fail_next_post.lock().unwrap();
+        if *guard {
+            *guard = false;
+            return Err(WebhookError::Request("injected post failure".into()));
+        }
+        self.sent.lock().unwrap().push(SentWebhook {
+            url: url.to_string(),
+            headers: headers.to_vec(),
+            body: body.to_string(),
+        });
+        Ok(*self.status_code.lock().unwrap())
+    }
+}
+
+/// Records all queued jobs without delivering them.
+#[derive(Clone, Default)]
+pub struct FakeWebhookQueue {
+    jobs: Arc<Mutex<Vec<WebhookJob>>>,
+}
+
+impl FakeWebhookQueue {
+    pub fn new() -> Self {
+        Self::default()
+    }
+    pub fn jobs(&self) -> Vec<WebhookJob> {
+        self.jobs.lock().unwrap().clone()
+    }
+}
+
+#[async_trait]
+impl WebhookQueuePort for FakeWebhookQueue {
+    async fn enqueue(&self, job: WebhookJob) -> WebhookResult<()> {
+        self.jobs.lock().unwrap().push(job);
+        Ok(())
+    }
+}
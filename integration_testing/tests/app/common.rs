@@ -5,7 +5,10 @@ use futures_util::lock::{Mutex, MutexGuard};
 use generic_sport_plugin::GenericSportPlugin;
 use generic_sport_plugin::config::GenericSportConfig;
 use gloo_timers::future::sleep;
-use integration_testing::port_fakes::{FakeClientRegistryPort, FakeDatabasePort, make_addr};
+use integration_testing::port_fakes::{
+    FakeBlobStorage, FakeClientRegistryPort, FakeDatabasePort, FakeDomainEventPort, FakeEmailPort,
+    FakeWebhookTransport, make_addr,
+};
 use isocountry::CountryCode;
 use leptos::{
     prelude::*,
@@ -125,7 +128,7 @@ pub fn init_test_state() -> InitialTestState {
     let cr = Arc::new(FakeClientRegistryPort::new());
 
     // Register Generic Sport Plugin
-    let mut spm_map = SportPluginManagerMap::new();
+    let spm_map = SportPluginManagerMap::new();
     let generic_plugin = Arc::new(GenericSportPlugin::new());
     let generic_sport_id = generic_plugin.get_id_version().get_id();
     spm_map.register(generic_plugin).unwrap();
@@ -135,6 +138,10 @@ pub fn init_test_state() -> InitialTestState {
         .set_db(db.clone())
         .set_cr(cr.clone())
         .set_spm(spm.clone())
+        .set_wh(Arc::new(FakeWebhookTransport::new()))
+        .set_em(Arc::new(FakeEmailPort::new()))
+        .set_bs(Arc::new(FakeBlobStorage::new()))
+        .set_ev(Arc::new(FakeDomainEventPort::new()))
         .build();
 
     let core_arc = Arc::new(core);
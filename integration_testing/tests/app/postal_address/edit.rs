@@ -3,7 +3,7 @@ use crate::common::{
     set_select_value, set_url,
 };
 use app::{postal_addresses::EditPostalAddress, provide_global_context};
-use app_core::{DbpPostalAddress, PostalAddress};
+use app_core::{DbpPostalAddress, PostalAddress, utils::list_order::ListOrder};
 use app_utils::{
     enum_utils::EditAction,
     params::AddressIdQuery,
@@ -112,7 +112,7 @@ async fn test_new_postal_address() {
 
     let new_address = ts
         .db
-        .list_postal_address_ids(Some("New"), None)
+        .list_postal_address_ids(Some("New"), None, &ListOrder::default())
         .await
         .unwrap();
     assert_eq!(new_address.len(), 1);
@@ -242,7 +242,7 @@ async fn test_copy_new_postal_address() {
 
     let cloned_addresses = ts
         .db
-        .list_postal_address_ids(Some("Cloned"), None)
+        .list_postal_address_ids(Some("Cloned"), None, &ListOrder::default())
         .await
         .unwrap();
     assert_eq!(cloned_addresses.len(), 1);
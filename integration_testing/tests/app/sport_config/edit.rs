@@ -2,7 +2,7 @@ use crate::common::{
     get_element_by_test_id, get_test_root, init_test_state, lock_test, set_input_value, set_url,
 };
 use app::{home::EditSportConfiguration, provide_global_context};
-use app_core::{DbpSportConfig, SportConfig};
+use app_core::{DbpSportConfig, SportConfig, utils::list_order::ListOrder};
 use app_utils::{
     enum_utils::EditAction,
     params::SportConfigIdQuery,
@@ -103,7 +103,13 @@ async fn test_new_sport_config() {
 
     let new_configs = ts
         .db
-        .list_sport_config_ids(ts.generic_sport_id, Some("New"), None)
+        .list_sport_config_ids(
+            ts.generic_sport_id,
+            Some("New"),
+            false,
+            None,
+            &ListOrder::default(),
+        )
         .await
         .unwrap();
     assert_eq!(new_configs.len(), 1);
@@ -230,7 +236,13 @@ async fn test_copy_new_sport_config() {
 
     let cloned_configs = ts
         .db
-        .list_sport_config_ids(ts.generic_sport_id, Some("Cloned"), None)
+        .list_sport_config_ids(
+            ts.generic_sport_id,
+            Some("Cloned"),
+            false,
+            None,
+            &ListOrder::default(),
+        )
         .await
         .unwrap();
     assert_eq!(cloned_configs.len(), 1);
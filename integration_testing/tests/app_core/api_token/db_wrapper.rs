@@ -0,0 +1,110 @@
+use app_core::{ApiScope, CoreError, CrMsg, DbError};
+
+use integration_testing::port_fakes::*;
+
+/// 1) create(): token is persisted with hash only and the secret authenticates
+#[tokio::test]
+async fn given_new_token_when_create_then_secret_authenticates() {
+    let (mut core, _db_fake, cr_fake) = make_core_api_token_state_with_fakes();
+
+    core.get_mut()
+        .set_name("Score Board")
+        .set_scopes([ApiScope::ReadPublic]);
+    let (token, secret) = core.create().await.expect("create should succeed");
+    let token = token.clone();
+
+    assert_eq!(token.get_version(), Some(0));
+    assert!(secret.starts_with(token.get_secret_prefix()));
+    assert_ne!(token.get_secret_hash(), secret);
+    assert_eq!(
+        cr_fake.published(),
+        vec![CrMsg::ApiTokenUpdated {
+            id: token.get_id(),
+            version: 0
+        }]
+    );
+
+    let authenticated = core
+        .authenticate_api_token(&secret)
+        .await
+        .unwrap()
+        .expect("token is valid");
+    assert_eq!(authenticated.get_id(), token.get_id());
+    assert!(authenticated.get_last_used_at().is_some());
+
+    assert!(
+        core.authenticate_api_token("fkt_unknown")
+            .await
+            .unwrap()
+            .is_none()
+    );
+}
+
+/// 2) revoke(): revoked tokens are rejected and hidden in default listing
+#[tokio::test]
+async fn given_revoked_token_when_authenticate_then_rejected() {
+    let (mut core, _db_fake, _cr_fake) = make_core_api_token_state_with_fakes();
+
+    core.get_mut()
+        .set_name("Ranking Export")
+        .set_scopes([ApiScope::ReadOrg]);
+    let (_, secret) = core.create().await.unwrap();
+    let revoked = core.revoke().await.unwrap().clone();
+
+    assert!(revoked.is_revoked());
+    assert_eq!(revoked.get_version(), Some(1));
+    assert!(
+        core.authenticate_api_token(&secret)
+            .await
+            .unwrap()
+            .is_none()
+    );
+    assert!(core.list_tokens(false).await.unwrap().is_empty());
+    assert_eq!(core.list_tokens(true).await.unwrap().len(), 1);
+}
+
+/// 3) create(): token without scopes is rejected before touching the db
+#[tokio::test]
+async fn given_token_without_scopes_when_create_then_validation_error() {
+    let (mut core, _db_fake, cr_fake) = make_core_api_token_state_with_fakes();
+
+    core.get_mut().set_name("No Scopes");
+    let err = core.create().await.unwrap_err();
+
+    assert!(matches!(err, CoreError::Validation(_)));
+    assert!(cr_fake.published().is_empty());
+}
+
+/// 4) authenticate: db failure is propagated
+#[tokio::test]
+async fn given_db_failure_when_authenticate_then_error_is_propagated() {
+    let (core, db_fake, _cr_fake) = make_core_api_token_state_with_fakes();
+
+    db_fake.fail_get_token_once();
+    let err = core.authenticate_api_token("fkt_secret").await.unwrap_err();
+
+    assert!(matches!(err, CoreError::Db(DbError::Other(_))));
+}
+
+/// 5) authenticate: failed update of last used time does not reject the token
+#[tokio::test]
+async fn given_read_only_db_when_authenticate_then_token_is_still_valid() {
+    let (mut core, db_fake, _cr_fake) = make_core_api_token_state_with_fakes();
+
+    core.get_mut()
+        .set_name("Live Ticker")
+        .set_scopes([ApiScope::ReadPublic]);
+    let (_, secret) = core.create().await.unwrap();
+
+    db_fake.fail_touch_token_read_only_once();
+    let authenticated = core
+        .authenticate_api_token(&secret)
+        .await
+        .expect("read only db must not fail authentication")
+        .expect("token is valid");
+    assert!(authenticated.get_last_used_at().is_none());
+
+    // last used time is updated, as soon as the db is writable again
+    let authenticated = core.authenticate_api_token(&secret).await.unwrap().unwrap();
+    assert!(authenticated.get_last_used_at().is_some());
+}
@@ -0,0 +1,3 @@
+//! testing app core api for api tokens with fakes
+
+mod db_wrapper;
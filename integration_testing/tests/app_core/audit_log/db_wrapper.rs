@@ -0,0 +1,145 @@
+use app_core::{AUDIT_ACTOR_WEB, ApiScope, AuditAction, AuditObjectType};
+use isocountry::CountryCode;
+use serde_json::json;
+
+use integration_testing::port_fakes::*;
+
+/// 1) save(): create and update of a tournament are recorded with versions and diff
+#[tokio::test]
+async fn given_saved_tournament_when_updated_then_create_and_update_are_recorded() {
+    let (mut core, db_fake, _cr_fake) = make_core_tournament_base_state_with_fakes();
+
+    *core.get_mut() = make_tournament_base("Spring Cup", &core);
+    let id = core.save().await.expect("create should succeed").get_id();
+    core.get_mut().set_name("Summer Cup");
+    core.save().await.expect("update should succeed");
+
+    let records = db_fake.audit_records();
+    assert_eq!(records.len(), 2);
+
+    let created = &records[0];
+    assert_eq!(created.get_action(), AuditAction::Create);
+    assert_eq!(created.get_object_type(), AuditObjectType::TournamentBase);
+    assert_eq!(created.get_object_id(), id);
+    assert_eq!(created.get_tournament_id(), Some(id));
+    assert_eq!(created.get_actor(), AUDIT_ACTOR_WEB);
+    assert_eq!(created.get_old_version(), None);
+    assert_eq!(created.get_new_version(), Some(0));
+
+    let updated = &records[1];
+    assert_eq!(updated.get_action(), AuditAction::Update);
+    assert_eq!(updated.get_old_version(), Some(0));
+    assert_eq!(updated.get_new_version(), Some(1));
+    assert_eq!(
+        updated.get_diff(),
+        &json!({"name": {"old": "Spring Cup", "new": "Summer Cup"}})
+    );
+}
+
+/// 2) archive(): archive is recorded as own action
+#[tokio::test]
+async fn given_saved_tournament_when_archived_then_archive_is_recorded() {
+    let (mut core, db_fake, _cr_fake) = make_core_tournament_base_state_with_fakes();
+
+    *core.get_mut() = make_tournament_base("Spring Cup", &core);
+    core.save().await.expect("create should succeed");
+    core.archive().await.expect("archive should succeed");
+
+    let records = db_fake.audit_records();
+    let archived = records.last().unwrap();
+    assert_eq!(archived.get_action(), AuditAction::Archive);
+    assert_eq!(archived.get_changed_fields(), vec!["archived_at"]);
+}
+
+/// 3) with_actor(): actor of core is recorded
+#[tokio::test]
+async fn given_core_with_actor_when_save_then_actor_is_recorded() {
+    let (core, db_fake, _cr_fake) = make_core_postal_address_state_with_fakes();
+    let mut core = core.with_actor("api-token:Importer");
+
+    *core.get_mut() = make_addr(
+        "Main Arena",
+        "Main Street 1",
+        "12345",
+        "Springfield",
+        "",
+        CountryCode::DEU,
+    );
+    core.save().await.expect("save should succeed");
+
+    let records = db_fake.audit_records();
+    assert_eq!(records.len(), 1);
+    assert_eq!(records[0].get_actor(), "api-token:Importer");
+    assert_eq!(records[0].get_object_type(), AuditObjectType::PostalAddress);
+    assert_eq!(records[0].get_tournament_id(), None);
+}
+
+/// 4) save(): failure of audit log does not fail the persisted save
+#[tokio::test]
+async fn given_failing_audit_log_when_save_then_save_succeeds() {
+    let (mut core, db_fake, _cr_fake) = make_core_tournament_base_state_with_fakes();
+
+    db_fake.fail_save_audit_record_once();
+    *core.get_mut() = make_tournament_base("Spring Cup", &core);
+    let saved = core.save().await.expect("save should succeed");
+
+    assert_eq!(saved.get_version(), Some(0));
+    assert!(db_fake.audit_records().is_empty());
+}
+
+/// 5) list_audit_records(): records of all objects of tournament, newest first
+#[tokio::test]
+async fn given_changes_of_tournament_when_list_then_newest_first_and_filtered() {
+    let (mut core, db_fake, _cr_fake) = make_core_entrant_state_with_fakes();
+    let t_id = core.get().get_tournament_id();
+
+    core.get_mut().set_name("Team A");
+    let entrant_id = core.save().await.expect("save should succeed").get_id();
+    core.get_mut().set_name("Team B");
+    core.save().await.expect("save should succeed");
+
+    // changes of global objects are not part of the history of the tournament
+    let mut pa_core = core.as_postal_address_state();
+    *pa_core.get_mut() = make_addr("Hall", "Street 1", "12345", "Town", "", CountryCode::DEU);
+    pa_core.save().await.expect("save should succeed");
+    assert_eq!(db_fake.audit_records().len(), 3);
+
+    let list = core
+        .as_audit_log_state(t_id)
+        .list_audit_records(None)
+        .await
+        .unwrap();
+
+    assert_eq!(list.len(), 2);
+    assert!(list.iter().all(|r| r.get_object_id() == entrant_id));
+    assert_eq!(list[0].get_action(), AuditAction::Update);
+    assert_eq!(list[1].get_action(), AuditAction::Create);
+
+    let limited = core
+        .as_audit_log_state(t_id)
+        .list_audit_records(Some(1))
+        .await
+        .unwrap();
+    assert_eq!(limited.len(), 1);
+}
+
+/// 6) revoke(): revoke is recorded and secrets are redacted
+#[tokio::test]
+async fn given_api_token_when_created_and_revoked_then_secret_is_redacted() {
+    let (mut core, db_fake, _cr_fake) = make_core_api_token_state_with_fakes();
+
+    core.get_mut()
+        .set_name("Scoreboard")
+        .set_scopes([ApiScope::ReadPublic]);
+    let (_, secret) = core.create().await.expect("create should succeed");
+    core.revoke().await.expect("revoke should succeed");
+
+    let records = db_fake.audit_records();
+    assert_eq!(records.len(), 2);
+    assert_eq!(records[0].get_action(), AuditAction::Create);
+    assert_eq!(records[1].get_action(), AuditAction::Revoke);
+    assert_eq!(records[1].get_changed_fields(), vec!["revoked_at"]);
+    let diff = records[0].get_diff().to_string();
+    assert!(diff.contains("[redacted]"));
+    assert!(!diff.contains(&secret[..]));
+}
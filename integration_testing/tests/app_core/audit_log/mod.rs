@@ -0,0 +1,3 @@
+//! testing app core audit log of mutating operations with fakes
+
+mod db_wrapper;
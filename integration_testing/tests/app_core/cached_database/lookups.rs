@@ -0,0 +1,140 @@
+use app_core::{
+    CacheConfig, CacheInvalidatingClientRegistry, ClientRegistryPort, Core, CoreBuilder, CrMsg,
+    CrTopic, DbpTournamentBase, Entrant, TournamentBaseState,
+};
+use std::sync::Arc;
+use uuid::Uuid;
+
+use integration_testing::port_fakes::*;
+
+fn seed_tournament(core: &Core<TournamentBaseState>, db_fake: &FakeDatabasePort) -> Uuid {
+    db_fake.seed_tournament_base(make_tournament_base("Cached Cup", core))
+}
+
+/// 1) load(): second lookup is served from the cache
+#[tokio::test]
+async fn given_loaded_tournament_when_load_again_then_cached_copy_is_returned() {
+    let (core, db_fake, _cr_fake) = make_core_with_cached_db_fakes();
+    let mut tb_core = core.as_tournament_base_state();
+    let t_id = seed_tournament(&tb_core, &db_fake);
+    tb_core
+        .load(t_id)
+        .await
+        .expect("db ok")
+        .expect("tournament");
+
+    // database is not asked again
+    db_fake.fail_get_tb_once();
+    let cached = tb_core
+        .load(t_id)
+        .await
+        .expect("cache hit")
+        .expect("tournament");
+
+    assert_eq!(cached.get_name(), "Cached Cup");
+}
+
+/// 2) with_transaction(): published message of committed save invalidates the cache
+#[tokio::test]
+async fn given_cached_tournament_when_saved_in_transaction_then_cache_is_invalidated() {
+    let (core, db_fake, cr_fake) = make_core_with_cached_db_fakes();
+    let mut tb_core = core.as_tournament_base_state();
+    let t_id = seed_tournament(&tb_core, &db_fake);
+    tb_core
+        .load(t_id)
+        .await
+        .expect("db ok")
+        .expect("tournament");
+
+    // writes of transactions bypass the cache
+    core.with_transaction(|tx| async move {
+        let mut tx_core = tx.as_tournament_base_state();
+        tx_core.load(t_id).await?;
+        tx_core.get_mut().set_name("Renamed Cup");
+        tx_core.save().await?;
+        Ok(())
+    })
+    .await
+    .expect("transaction committed");
+
+    assert!(!cr_fake.published().is_empty());
+    let reloaded = tb_core
+        .load(t_id)
+        .await
+        .expect("db ok")
+        .expect("tournament");
+    assert_eq!(reloaded.get_name(), "Renamed Cup");
+    assert_eq!(reloaded.get_version(), Some(1));
+}
+
+/// 3) list_entrants(): saving an entrant invalidates the cached list of the tournament
+#[tokio::test]
+async fn given_cached_entrant_list_when_entrant_saved_then_list_is_reloaded() {
+    let (core, db_fake, _cr_fake) = make_core_with_cached_db_fakes();
+    let tb_core = core.as_tournament_base_state();
+    let t_id = seed_tournament(&tb_core, &db_fake);
+    let mut entrant_core = core.as_entrant_state(t_id);
+    assert!(
+        entrant_core
+            .list_entrants()
+            .await
+            .expect("db ok")
+            .is_empty()
+    );
+
+    let mut entrant = Entrant::default();
+    entrant.set_tournament_id(t_id).set_name("Team A");
+    *entrant_core.get_mut() = entrant;
+    entrant_core.save().await.expect("entrant saved");
+
+    let entrants = entrant_core.list_entrants().await.expect("db ok");
+    assert_eq!(entrants.len(), 1);
+    assert_eq!(entrants[0].get_name(), "Team A");
+}
+
+/// 4) publish(): messages of other instances forwarded into the invalidating registry
+///    invalidate the cache
+#[tokio::test]
+async fn given_cached_tournament_when_forwarded_message_then_cache_is_invalidated() {
+    let (core, db_fake, _cr_fake) = make_core_with_cached_db_fakes();
+    let t_id = seed_tournament(&core.as_tournament_base_state(), &db_fake);
+    let builder = CoreBuilder::new().set_cached_db(db_fake.clone(), CacheConfig::default());
+    let cache = builder.get_cache().expect("database is cached");
+    // local registry of a client registry, which forwards messages of other instances
+    let forwarded = CacheInvalidatingClientRegistry::new(
+        Arc::new(FakeClientRegistryPort::new()),
+        cache.clone(),
+    );
+    let cached = cache
+        .get_tournament_base(t_id)
+        .await
+        .expect("db ok")
+        .expect("tournament");
+
+    // another instance renames the tournament
+    let mut renamed = cached.clone();
+    renamed.set_name("Renamed Cup");
+    let saved = db_fake
+        .save_tournament_base(&renamed)
+        .await
+        .expect("save ok");
+    forwarded
+        .publish(
+            CrTopic::TournamentBase {
+                tournament_base_id: t_id,
+            },
+            CrMsg::TournamentBaseUpdated {
+                id: t_id,
+                version: saved.get_version().unwrap(),
+            },
+        )
+        .await
+        .expect("publish ok");
+
+    let reloaded = cache
+        .get_tournament_base(t_id)
+        .await
+        .expect("db ok")
+        .expect("tournament");
+    assert_eq!(reloaded.get_name(), "Renamed Cup");
+}
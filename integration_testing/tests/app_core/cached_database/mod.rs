@@ -0,0 +1,3 @@
+//! testing app core with cached database port and fakes
+
+mod lookups;
@@ -0,0 +1,3 @@
+//! testing app core reports of client errors with fakes
+
+mod db_wrapper;
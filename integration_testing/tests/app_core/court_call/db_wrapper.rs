@@ -0,0 +1,124 @@
+use app_core::{CoreError, CourtCallPolicy, DbError, Entrant, MAX_CALL_AHEAD, Match};
+use chrono::Local;
+use uuid::Uuid;
+
+use integration_testing::port_fakes::*;
+
+/// 1) set_court_call_policy(): valid policy is saved with the tournament
+#[tokio::test]
+async fn given_valid_policy_when_set_court_call_policy_then_saved() {
+    let (core, _db_fake, _em_fake, t_id) = make_core_with_email_fake();
+    let policy = CourtCallPolicy {
+        call_ahead: 1,
+        notify_entrants: true,
+    };
+
+    let saved = core
+        .set_court_call_policy(t_id, policy)
+        .await
+        .expect("save should succeed");
+
+    assert_eq!(saved.get_court_call_policy(), policy);
+    let queue = core
+        .load_court_calls(t_id)
+        .await
+        .expect("db ok")
+        .expect("tournament exists");
+    assert_eq!(queue.tournament_name, "Notification Tournament");
+}
+
+/// 2) set_court_call_policy(): too many calls ahead are rejected
+#[tokio::test]
+async fn given_too_many_calls_ahead_when_set_court_call_policy_then_validation_error() {
+    let (core, _db_fake, _em_fake, t_id) = make_core_with_email_fake();
+    let policy = CourtCallPolicy {
+        call_ahead: MAX_CALL_AHEAD + 1,
+        notify_entrants: false,
+    };
+
+    let err = core.set_court_call_policy(t_id, policy).await.unwrap_err();
+
+    assert!(matches!(err, CoreError::Validation(_)));
+}
+
+/// 3) announce_free_station(): nothing is called or sent without pending match
+#[tokio::test]
+async fn given_no_pending_match_when_announce_free_station_then_none_and_no_email() {
+    let (core, _db_fake, em_fake, t_id) = make_core_with_email_fake();
+    core.set_court_call_policy(
+        t_id,
+        CourtCallPolicy {
+            call_ahead: 0,
+            notify_entrants: true,
+        },
+    )
+    .await
+    .expect("save should succeed");
+
+    let call = core.announce_free_station(t_id, 1).await.expect("db ok");
+
+    assert!(call.is_none());
+    assert!(em_fake.sent().is_empty());
+}
+
+/// 4) announce_free_station(): unknown tournament → NotFound
+#[tokio::test]
+async fn given_unknown_tournament_when_announce_free_station_then_not_found() {
+    let (core, _db_fake, _em_fake, _t_id) = make_core_with_email_fake();
+
+    let err = core
+        .announce_free_station(Uuid::new_v4(), 1)
+        .await
+        .unwrap_err();
+
+    assert!(matches!(err, CoreError::Db(DbError::NotFound)));
+}
+
+/// 5) announce_free_station(): next pending stored match of the station is called
+#[tokio::test]
+async fn given_pending_match_when_announce_free_station_then_called_and_email_sent() {
+    let (core, db_fake, em_fake, t_id) = make_core_with_email_fake();
+    core.set_court_call_policy(
+        t_id,
+        CourtCallPolicy {
+            call_ahead: 0,
+            notify_entrants: true,
+        },
+    )
+    .await
+    .expect("save should succeed");
+    let mut entrant_ids = Vec::new();
+    for (name, email) in [
+        ("Team A", "team-a@example.org"),
+        ("Team B", "team-b@example.org"),
+    ] {
+        let mut entrant = Entrant::default();
+        entrant
+            .set_tournament_id(t_id)
+            .set_name(name)
+            .set_email(Some(email));
+        entrant_ids.push(db_fake.seed_entrant(entrant));
+    }
+    let sport_id = Uuid::new_v4();
+    let mut m = Match::new_played(
+        Uuid::new_v4(),
+        entrant_ids[0],
+        entrant_ids[1],
+        sport_id,
+        vec![],
+        vec![],
+    );
+    m.set_tournament(t_id, sport_id, Uuid::new_v4())
+        .set_slot(1, Local::now());
+    db_fake.seed_matches(vec![m.clone()]);
+
+    let call = core
+        .announce_free_station(t_id, 1)
+        .await
+        .expect("db ok")
+        .expect("station has a pending match");
+
+    assert_eq!(call.match_id, *m.get_id());
+    assert_eq!(call.entrants, vec!["Team A", "Team B"]);
+    assert_eq!(em_fake.sent().len(), 2);
+}
@@ -0,0 +1,3 @@
+//! testing app core api for court calls with fakes
+
+mod db_wrapper;
@@ -0,0 +1,120 @@
+use app_core::{DomainEvent, Entrant, TournamentMode, TournamentState};
+use integration_testing::port_fakes::*;
+
+/// 1) save of tournament: created on insert, updated on update
+#[tokio::test]
+async fn given_new_tournament_when_saved_twice_then_created_and_updated_are_emitted() {
+    let (core, _db_fake, ev_fake) = make_core_with_domain_event_fake();
+    let mut tb_core = core.as_tournament_base_state();
+    *tb_core.get_mut() = make_tournament_base("Event Cup", &tb_core);
+    let tournament = tb_core.save().await.expect("save should succeed").clone();
+
+    tb_core.get_mut().set_name("Event Cup 2026");
+    tb_core.save().await.expect("update should succeed");
+
+    assert_eq!(
+        ev_fake.emitted(),
+        vec![
+            DomainEvent::TournamentCreated {
+                tournament_id: tournament.get_id(),
+                sport_id: tournament.get_sport_id(),
+                name: "Event Cup".to_string(),
+            },
+            DomainEvent::TournamentUpdated {
+                tournament_id: tournament.get_id(),
+                name: "Event Cup 2026".to_string(),
+                state: TournamentState::Draft.to_string(),
+            },
+        ]
+    );
+}
+
+/// 2) finishing a tournament: stage completed and tournament finished are emitted
+#[tokio::test]
+async fn given_active_tournament_when_finished_then_stage_completed_and_finished_are_emitted() {
+    let (core, db_fake, ev_fake) = make_core_with_domain_event_fake();
+    let mut tb_core = core.as_tournament_base_state();
+    let mut tb = make_tournament_base("League Cup", &tb_core);
+    tb.set_tournament_mode(TournamentMode::PoolAndFinalStage)
+        .set_tournament_state(TournamentState::ActiveStage(1));
+    let t_id = db_fake.seed_tournament_base(tb);
+
+    tb_core.load(t_id).await.unwrap();
+    tb_core
+        .get_mut()
+        .set_tournament_state(TournamentState::Finished);
+    tb_core.save().await.expect("save should succeed");
+
+    let emitted = ev_fake.emitted();
+    let types: Vec<_> = emitted.iter().map(DomainEvent::event_type).collect();
+    assert_eq!(
+        types,
+        vec![
+            "tournament_updated",
+            "stage_completed",
+            "tournament_finished"
+        ]
+    );
+    assert_eq!(
+        emitted[1],
+        DomainEvent::StageCompleted {
+            tournament_id: t_id,
+            stage_number: 1,
+            stage_name: "Final Stage".to_string(),
+        }
+    );
+}
+
+/// 3) sandbox tournaments emit no events
+#[tokio::test]
+async fn given_sandbox_tournament_when_saved_then_no_event_is_emitted() {
+    let (core, _db_fake, ev_fake) = make_core_with_domain_event_fake();
+    let mut tb_core = core.as_tournament_base_state();
+    *tb_core.get_mut() = make_tournament_base("Sandbox Cup", &tb_core);
+    tb_core.get_mut().set_sandbox(true);
+    tb_core.save().await.expect("save should succeed");
+
+    assert!(ev_fake.emitted().is_empty());
+}
+
+/// 4) save of entrant: entrants updated is emitted
+#[tokio::test]
+async fn given_entrants_when_saved_then_entrants_updated_is_emitted() {
+    let (core, _db_fake, ev_fake) = make_core_with_domain_event_fake();
+    let mut tb_core = core.as_tournament_base_state();
+    *tb_core.get_mut() = make_tournament_base("Entrant Cup", &tb_core);
+    let t_id = tb_core.save().await.expect("save should succeed").get_id();
+    ev_fake.clear();
+
+    let mut core = core.as_entrant_state(t_id);
+    let mut entrant = Entrant::default();
+    entrant.set_tournament_id(t_id).set_name("Team A");
+    *core.get_mut() = entrant;
+    core.save().await.expect("save should succeed");
+
+    assert_eq!(
+        ev_fake.emitted(),
+        vec![DomainEvent::EntrantsUpdated {
+            tournament_id: t_id,
+            num_changed: 1,
+        }]
+    );
+}
+
+/// 5) save of entrant of sandbox tournament: no event is emitted
+#[tokio::test]
+async fn given_sandbox_tournament_when_entrant_saved_then_no_event_is_emitted() {
+    let (core, _db_fake, ev_fake) = make_core_with_domain_event_fake();
+    let mut tb_core = core.as_tournament_base_state();
+    *tb_core.get_mut() = make_tournament_base("Sandbox Cup", &tb_core);
+    tb_core.get_mut().set_sandbox(true);
+    let t_id = tb_core.save().await.expect("save should succeed").get_id();
+
+    let mut core = core.as_entrant_state(t_id);
+    let mut entrant = Entrant::default();
+    entrant.set_tournament_id(t_id).set_name("Team A");
+    *core.get_mut() = entrant;
+    core.save().await.expect("save should succeed");
+
+    assert!(ev_fake.emitted().is_empty());
+}
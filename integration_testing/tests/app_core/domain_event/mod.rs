@@ -0,0 +1,3 @@
+//! testing app core domain events with fakes
+
+mod emission;
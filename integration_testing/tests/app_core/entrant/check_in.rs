@@ -0,0 +1,254 @@
+use app_core::{CoreError, DbError, NoShowPolicy, SeedingStrategy, Stage, TournamentState};
+use chrono::{Duration, Utc};
+
+use integration_testing::port_fakes::*;
+use uuid::Uuid;
+
+/// 1) check-in: time of first check-in is kept and completeness is shown by the dashboard
+#[tokio::test]
+async fn given_imported_entrants_when_check_in_then_dashboard_shows_completeness() {
+    let (mut core, _db_fake, cr_fake) = make_core_entrant_state_with_fakes();
+    let imported = core
+        .import_entrants_csv("Team A\nTeam B\nTeam C\n")
+        .await
+        .expect("import succeeds");
+    let tournament_id = imported[0].get_tournament_id();
+    let published_before = cr_fake.published().len();
+
+    let checked_in = core
+        .set_check_in(imported[0].get_id(), true)
+        .await
+        .expect("check-in succeeds")
+        .clone();
+    assert!(checked_in.is_checked_in());
+    assert_eq!(checked_in.get_version(), Some(1));
+    assert_eq!(cr_fake.published().len(), published_before + 1);
+
+    // checking in again keeps the first check-in and does not save
+    let again = core
+        .set_check_in(imported[0].get_id(), true)
+        .await
+        .expect("check-in succeeds")
+        .clone();
+    assert_eq!(again.get_checked_in_at(), checked_in.get_checked_in_at());
+    assert_eq!(again.get_version(), Some(1));
+    core.set_check_in(imported[2].get_id(), true)
+        .await
+        .expect("check-in succeeds");
+
+    let dashboard = core
+        .load_day_dashboard(tournament_id)
+        .await
+        .expect("db ok")
+        .expect("tournament exists");
+    assert_eq!(dashboard.check_in.num_checked_in, 2);
+    assert_eq!(dashboard.check_in.num_entrants, 3);
+    assert_eq!(dashboard.check_in.missing, vec!["Team B"]);
+
+    // check-out
+    let checked_out = core
+        .set_check_in(imported[0].get_id(), false)
+        .await
+        .expect("check-out succeeds");
+    assert!(!checked_out.is_checked_in());
+}
+
+/// 2) check-in of unknown entrant or entrant of other tournament fails
+#[tokio::test]
+async fn given_unknown_entrant_when_check_in_then_not_found() {
+    let (mut core, _db_fake, _cr_fake) = make_core_entrant_state_with_fakes();
+    let imported = core
+        .import_entrants_csv("Team A\n")
+        .await
+        .expect("import succeeds");
+
+    let err = core.set_check_in(Uuid::new_v4(), true).await.unwrap_err();
+    assert!(matches!(err, CoreError::Db(DbError::NotFound)));
+
+    let mut other = core.as_entrant_state(Uuid::new_v4());
+    let err = other
+        .set_check_in(imported[0].get_id(), true)
+        .await
+        .unwrap_err();
+    assert!(matches!(err, CoreError::Db(DbError::NotFound)));
+}
+
+/// 3) after the deadline, present waitlisted entrants replace no-shows and take their seed
+#[tokio::test]
+async fn given_passed_deadline_when_resolve_no_shows_then_waitlist_moves_up() {
+    let (mut core, _db_fake, _cr_fake) = make_core_entrant_state_with_fakes();
+    let imported = core
+        .import_entrants_csv("Team A,,1\nTeam B,,2\nTeam C,,3\nTeam D\n")
+        .await
+        .expect("import succeeds");
+    let tournament_id = imported[0].get_tournament_id();
+    let check_in_core = core.as_check_in_state(tournament_id);
+    check_in_core
+        .set_waitlist_position(imported[3].get_id(), Some(1))
+        .await
+        .expect("waitlist succeeds");
+    for entrant in [&imported[0], &imported[3]] {
+        check_in_core
+            .set_present(entrant.get_id(), true)
+            .await
+            .expect("check-in succeeds");
+    }
+
+    // before the deadline nothing is resolved
+    let now = Utc::now();
+    check_in_core
+        .set_settings(
+            Some(now + Duration::minutes(5)),
+            NoShowPolicy::ReplaceFromWaitlist,
+        )
+        .await
+        .expect("settings saved");
+    let changed = check_in_core.resolve_no_shows(now).await.expect("db ok");
+    assert!(changed.is_empty());
+
+    let changed = check_in_core
+        .resolve_no_shows(now + Duration::minutes(10))
+        .await
+        .expect("resolve succeeds");
+    assert_eq!(changed.len(), 3);
+
+    let overview = check_in_core
+        .load_overview()
+        .await
+        .expect("db ok")
+        .expect("tournament exists");
+    let field: Vec<(&str, Option<u32>)> = overview
+        .field
+        .iter()
+        .map(|e| (e.get_name(), e.get_seed()))
+        .collect();
+    assert_eq!(field, vec![("Team A", Some(1)), ("Team D", Some(2))]);
+    assert!(overview.waitlist.is_empty());
+    assert_eq!(overview.no_shows.len(), 2);
+    assert!(overview.no_shows.iter().all(|e| e.get_seed().is_none()));
+
+    // no-shows are not counted by the dashboard
+    let dashboard = core
+        .load_day_dashboard(tournament_id)
+        .await
+        .expect("db ok")
+        .expect("tournament exists");
+    assert_eq!(dashboard.check_in.num_entrants, 2);
+    assert!(dashboard.check_in.is_complete());
+}
+
+/// 4) no-shows of a running tournament are not resolved
+#[tokio::test]
+async fn given_running_tournament_when_resolve_no_shows_then_error() {
+    let (mut core, _db_fake, _cr_fake) = make_core_entrant_state_with_fakes();
+    let imported = core
+        .import_entrants_csv("Team A\nTeam B\n")
+        .await
+        .expect("import succeeds");
+    let tournament_id = imported[0].get_tournament_id();
+    // sandbox tournaments may start without readiness checks
+    let mut base_core = core.as_tournament_base_state();
+    base_core.load(tournament_id).await.expect("db ok");
+    base_core
+        .get_mut()
+        .set_sandbox(true)
+        .set_tournament_state(TournamentState::ActiveStage(0))
+        .set_check_in_deadline(Some(Utc::now()));
+    base_core.save().await.expect("save succeeds");
+
+    let err = core
+        .as_check_in_state(tournament_id)
+        .resolve_no_shows(Utc::now())
+        .await
+        .unwrap_err();
+    assert!(matches!(err, CoreError::Field(_)));
+}
+
+/// 5) resolving no-shows reassigns the saved groups of stage 0
+#[tokio::test]
+async fn given_saved_groups_when_resolve_no_shows_then_replacements_take_over_groups() {
+    let (mut core, db_fake, _cr_fake) = make_core_entrant_state_with_fakes();
+    let imported = core
+        .import_entrants_csv("Team A,,1\nTeam B,,2\nTeam C,,3\nTeam D,,4\nTeam W\n")
+        .await
+        .expect("import succeeds");
+    let tournament_id = imported[0].get_tournament_id();
+    let id = |name: &str| {
+        imported
+            .iter()
+            .find(|e| e.get_name() == name)
+            .unwrap()
+            .get_id()
+    };
+    let mut stage = Stage::default();
+    stage
+        .set_tournament_id(tournament_id)
+        .set_number(0)
+        .set_num_groups(2);
+    let stage_id = db_fake.seed_stage(stage);
+    let check_in_core = core.as_check_in_state(tournament_id);
+    check_in_core
+        .set_waitlist_position(id("Team W"), Some(1))
+        .await
+        .expect("waitlist succeeds");
+
+    // waitlisted entrants are not seeded into groups
+    let seeding = core
+        .seed_first_stage(tournament_id, SeedingStrategy::Snake)
+        .await
+        .expect("db ok")
+        .expect("tournament and stage exist");
+    core.save_group_seeding(seeding)
+        .await
+        .expect("seeding is valid")
+        .expect("tournament and stage exist");
+    let before = db_fake
+        .group_seeding_of(stage_id)
+        .expect("seeding is saved");
+    assert!(!before.groups.iter().flatten().any(|e| *e == id("Team W")));
+
+    for name in ["Team A", "Team D", "Team W"] {
+        check_in_core
+            .set_present(id(name), true)
+            .await
+            .expect("check-in succeeds");
+    }
+    check_in_core
+        .set_settings(Some(Utc::now()), NoShowPolicy::ReplaceFromWaitlist)
+        .await
+        .expect("settings saved");
+    check_in_core
+        .resolve_no_shows(Utc::now() + Duration::minutes(1))
+        .await
+        .expect("resolve succeeds");
+
+    // Team W replaces Team B, the best seeded no-show; Team C leaves a bye
+    let expected: Vec<Vec<_>> = before
+        .groups
+        .iter()
+        .map(|group| {
+            group
+                .iter()
+                .filter(|e| **e != id("Team C"))
+                .map(|e| if *e == id("Team B") { id("Team W") } else { *e })
+                .collect()
+        })
+        .collect();
+    let after = db_fake
+        .group_seeding_of(stage_id)
+        .expect("seeding is saved");
+    assert_eq!(after.groups, expected);
+
+    // the reassigned groups match the field and are loaded instead of seeding again
+    let loaded = core
+        .load_group_seeding(tournament_id)
+        .await
+        .expect("db ok")
+        .expect("tournament and stage exist");
+    let loaded_ids: Vec<Vec<_>> = loaded
+        .groups
+        .iter()
+        .map(|group| group.iter().map(|e| e.get_id()).collect())
+        .collect();
+    assert_eq!(loaded_ids, expected);
+}
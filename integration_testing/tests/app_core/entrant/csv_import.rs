@@ -0,0 +1,98 @@
+use app_core::{CoreError, CrMsg, DbError, Entrant};
+
+use integration_testing::port_fakes::*;
+
+/// 1) valid CSV: all entrants are persisted, published and listed by seed
+#[tokio::test]
+async fn given_valid_csv_when_import_then_entrants_persisted_and_published() {
+    let (mut core, _db_fake, cr_fake) = make_core_entrant_state_with_fakes();
+
+    let csv = "name,club,seed\nTeam B,TV Nord,2\nTeam C,,\nTeam A,SV Süd,1\n";
+    let imported = core
+        .import_entrants_csv(csv)
+        .await
+        .expect("import succeeds");
+
+    assert_eq!(imported.len(), 3);
+    assert!(imported.iter().all(|e| e.get_version() == Some(0)));
+    let notices = cr_fake.published();
+    assert_eq!(notices.len(), 3);
+    assert_eq!(
+        notices[0],
+        CrMsg::EntrantUpdated {
+            id: imported[0].get_id(),
+            version: 0
+        }
+    );
+
+    let names: Vec<_> = core
+        .list_entrants()
+        .await
+        .unwrap()
+        .into_iter()
+        .map(|e| e.get_name().to_string())
+        .collect();
+    assert_eq!(names, vec!["Team A", "Team B", "Team C"]);
+}
+
+/// 2) invalid rows: errors of all rows are returned and nothing is saved
+#[tokio::test]
+async fn given_invalid_rows_when_import_then_validation_errors_and_nothing_saved() {
+    let (mut core, _db_fake, cr_fake) = make_core_entrant_state_with_fakes();
+
+    let csv = "Team A,,1\n,Club,2\nTeam C,,abc\n";
+    let err = core.import_entrants_csv(csv).await.unwrap_err();
+
+    let CoreError::Validation(errs) = err else {
+        panic!("expected validation errors, got {err:?}");
+    };
+    let rows: Vec<_> = errs
+        .errors
+        .iter()
+        .map(|e| e.get_params()["row"].clone())
+        .collect();
+    assert_eq!(rows, vec!["2", "3"]);
+    assert!(cr_fake.published().is_empty());
+    assert!(core.list_entrants().await.unwrap().is_empty());
+}
+
+/// 3) names and seeds of existing entrants are rejected with the CSV line number
+#[tokio::test]
+async fn given_existing_entrants_when_import_duplicates_then_validation_errors() {
+    let (mut core, db_fake, _cr_fake) = make_core_entrant_state_with_fakes();
+    let mut existing = Entrant::default();
+    existing
+        .set_tournament_id(core.get().get_tournament_id())
+        .set_name("Team A")
+        .set_seed(Some(1));
+    db_fake.seed_entrant(existing);
+
+    let csv = "name,club,seed\nTeam B,,1\nteam a,,\n";
+    let err = core.import_entrants_csv(csv).await.unwrap_err();
+
+    let CoreError::Validation(errs) = err else {
+        panic!("expected validation errors, got {err:?}");
+    };
+    let fields: Vec<_> = errs
+        .errors
+        .iter()
+        .map(|e| (e.get_params()["row"].as_str(), e.get_field(), e.get_code()))
+        .collect();
+    assert_eq!(
+        fields,
+        vec![("2", "seed", "duplicate"), ("3", "name", "duplicate")]
+    );
+    assert_eq!(core.list_entrants().await.unwrap().len(), 1);
+}
+
+/// 4) list failure is propagated before anything is saved
+#[tokio::test]
+async fn given_db_failure_when_import_then_error_is_propagated() {
+    let (mut core, db_fake, cr_fake) = make_core_entrant_state_with_fakes();
+
+    db_fake.fail_list_entrant_once();
+    let err = core.import_entrants_csv("Team A").await.unwrap_err();
+
+    assert!(matches!(err, CoreError::Db(DbError::Other(_))));
+    assert!(cr_fake.published().is_empty());
+}
@@ -0,0 +1,5 @@
+//! testing app core api for entrants with fakes
+
+mod check_in;
+mod csv_import;
+mod schedule;
@@ -0,0 +1,95 @@
+use app_core::{Entrant, Match, ScheduledEntrant, TournamentState};
+use uuid::Uuid;
+
+use integration_testing::port_fakes::*;
+
+/// 1) load_entrant_schedule(): entrants of drafts are hidden from the public
+#[tokio::test]
+async fn given_entrant_of_draft_when_load_schedule_then_none() {
+    let (mut core, _db_fake, _cr_fake) = make_core_entrant_state_with_fakes();
+    core.get_mut().set_name("Team A");
+    let entrant_id = core.save().await.expect("save entrant").get_id();
+
+    let schedule = core.load_entrant_schedule(entrant_id).await.expect("db ok");
+
+    assert!(schedule.is_none());
+    assert!(
+        core.load_entrant_schedule(Uuid::new_v4())
+            .await
+            .expect("db ok")
+            .is_none()
+    );
+}
+
+/// 2) load_entrant_schedule(): schedule of published tournament without email address
+#[tokio::test]
+async fn given_entrant_of_published_tournament_when_load_schedule_then_public_entrant() {
+    let (mut core, db_fake, _cr_fake) = make_core_entrant_state_with_fakes();
+    core.get_mut()
+        .set_name("Team A")
+        .set_email(Some("team@example.org"));
+    let entrant = core.save().await.expect("save entrant").clone();
+    let tournament_id = entrant.get_tournament_id();
+    db_fake.seed_readiness(tournament_id);
+    let mut base_core = core.as_tournament_base_state();
+    base_core.load(tournament_id).await.expect("db ok");
+    base_core
+        .get_mut()
+        .set_tournament_state(TournamentState::Published);
+    base_core.save().await.expect("publish");
+
+    let schedule = core
+        .load_entrant_schedule(entrant.get_id())
+        .await
+        .expect("db ok")
+        .expect("entrant of published tournament is public");
+
+    assert_eq!(schedule.tournament_id, tournament_id);
+    assert_eq!(schedule.tournament_name, "Entrant Tournament");
+    assert_eq!(schedule.entrant.get_name(), "Team A");
+    assert_eq!(schedule.entrant.get_email(), None);
+    assert!(schedule.upcoming.is_empty());
+    assert!(schedule.results.is_empty());
+}
+
+/// 3) load_entrant_schedule(): stored matches of the entrant are listed with their opponent
+#[tokio::test]
+async fn given_stored_match_when_load_schedule_then_upcoming_match_with_opponent() {
+    let (mut core, db_fake, _cr_fake) = make_core_entrant_state_with_fakes();
+    core.get_mut().set_name("Team A");
+    let entrant = core.save().await.expect("save entrant").clone();
+    let tournament_id = entrant.get_tournament_id();
+    let mut rival = Entrant::default();
+    rival.set_tournament_id(tournament_id).set_name("Team B");
+    let rival_id = db_fake.seed_entrant(rival);
+    let mut m = Match::new_scheduled(
+        Uuid::new_v4(),
+        Uuid::new_v4(),
+        Uuid::new_v4(),
+        1,
+        ScheduledEntrant::Entrant(rival_id),
+        ScheduledEntrant::Entrant(entrant.get_id()),
+    );
+    let start_at = m.get_start_at();
+    m.set_tournament(tournament_id, Uuid::new_v4(), Uuid::new_v4())
+        .set_slot(1, start_at);
+    db_fake.seed_matches(vec![m.clone()]);
+    db_fake.seed_readiness(tournament_id);
+    let mut base_core = core.as_tournament_base_state();
+    base_core.load(tournament_id).await.expect("db ok");
+    base_core
+        .get_mut()
+        .set_tournament_state(TournamentState::Published);
+    base_core.save().await.expect("publish");
+
+    let schedule = core
+        .load_entrant_schedule(entrant.get_id())
+        .await
+        .expect("db ok")
+        .expect("entrant of published tournament is public");
+
+    let next = schedule.next_match().expect("match is upcoming");
+    assert_eq!(next.match_id, *m.get_id());
+    assert_eq!(next.opponent.as_deref(), Some("Team B"));
+    assert!(schedule.results.is_empty());
+}
@@ -0,0 +1,74 @@
+use app_core::{CoreError, DbError, Feedback};
+use uuid::Uuid;
+
+use integration_testing::port_fakes::*;
+
+/// 1) save(): feedback is persisted with its context
+#[tokio::test]
+async fn given_feedback_with_context_when_save_then_persisted() {
+    let (mut core, _db_fake, cr_fake) = make_core_feedback_state_with_fakes();
+    let t_id = Uuid::new_v4();
+
+    core.get_mut()
+        .set_message("  Standings do not update after result entry.  ")
+        .set_route("/tournaments/edit")
+        .set_tournament_id(Some(t_id))
+        .set_app_version("0.12.1")
+        .set_recent_errors(vec!["Network error: timeout".to_string()]);
+    let saved = core.save().await.expect("save should succeed").clone();
+
+    assert_eq!(saved.get_version(), Some(0));
+    assert!(saved.get_created_at().is_some());
+    assert_eq!(
+        saved.get_message(),
+        "Standings do not update after result entry."
+    );
+    assert_eq!(saved.get_tournament_id(), Some(t_id));
+    assert_eq!(saved.get_recent_errors(), ["Network error: timeout"]);
+    // feedback is not published to clients
+    assert!(cr_fake.published().is_empty());
+
+    let list = core.list_feedback(None).await.unwrap();
+    assert_eq!(list, vec![saved]);
+}
+
+/// 2) save(): invalid feedback is rejected before touching the db
+#[tokio::test]
+async fn given_empty_message_when_save_then_validation_error() {
+    let (mut core, _db_fake, _cr_fake) = make_core_feedback_state_with_fakes();
+
+    core.get_mut().set_route("/");
+    let err = core.save().await.unwrap_err();
+
+    assert!(matches!(err, CoreError::Validation(_)));
+    assert!(core.list_feedback(None).await.unwrap().is_empty());
+}
+
+/// 3) list_feedback(): newest first and limited
+#[tokio::test]
+async fn given_multiple_feedback_when_list_with_limit_then_newest_first() {
+    let (mut core, _db_fake, _cr_fake) = make_core_feedback_state_with_fakes();
+
+    for message in ["first", "second", "third"] {
+        let mut feedback = Feedback::default();
+        feedback.set_message(message).set_route("/");
+        *core.get_mut() = feedback;
+        core.save().await.unwrap();
+    }
+
+    let list = core.list_feedback(Some(2)).await.unwrap();
+    let messages: Vec<_> = list.iter().map(|f| f.get_message()).collect();
+    assert_eq!(messages, vec!["third", "second"]);
+}
+
+/// 4) save(): db failure is propagated
+#[tokio::test]
+async fn given_db_failure_when_save_then_error_is_propagated() {
+    let (mut core, db_fake, _cr_fake) = make_core_feedback_state_with_fakes();
+
+    db_fake.fail_save_feedback_once();
+    core.get_mut().set_message("broken").set_route("/");
+    let err = core.save().await.unwrap_err();
+
+    assert!(matches!(err, CoreError::Db(DbError::Other(_))));
+}
@@ -0,0 +1,3 @@
+//! testing app core feedback of users with fakes
+
+mod db_wrapper;
@@ -0,0 +1,155 @@
+use app_core::{Core, Entrant, InitState, ReportFormat, TournamentState, final_report_key};
+use std::time::Duration;
+use uuid::Uuid;
+
+use integration_testing::port_fakes::*;
+
+fn make_entrant(tournament_id: Uuid, name: &str, club: Option<&str>) -> Entrant {
+    let mut entrant = Entrant::default();
+    entrant
+        .set_tournament_id(tournament_id)
+        .set_name(name)
+        .set_club(club);
+    entrant
+}
+
+async fn publish(core: &Core<InitState>, db_fake: &FakeDatabasePort, t_id: Uuid) {
+    db_fake.seed_readiness(t_id);
+    let mut tb_core = core.as_tournament_base_state();
+    tb_core
+        .load(t_id)
+        .await
+        .unwrap()
+        .expect("tournament exists");
+    tb_core
+        .get_mut()
+        .set_tournament_state(TournamentState::Published);
+    tb_core.save().await.expect("publish should succeed");
+}
+
+/// 1) assemble_final_report(): drafts have no final report
+#[tokio::test]
+async fn given_draft_tournament_when_assemble_final_report_then_none() {
+    let (core, _db_fake, _bs_fake, t_id) = make_core_with_blob_fake();
+
+    let report = core.assemble_final_report(t_id, true).await.unwrap();
+
+    assert!(report.is_none());
+}
+
+/// 2) assemble_final_report(): participation, stages and statistics of finished tournament
+#[tokio::test]
+async fn given_finished_tournament_when_assemble_final_report_then_report_is_complete() {
+    let (core, db_fake, _bs_fake, t_id) = make_core_with_blob_fake();
+    db_fake.seed_entrant(make_entrant(t_id, "Team A", Some("FC Example")));
+    db_fake.seed_entrant(make_entrant(t_id, "Team B", Some("fc example")));
+    db_fake.seed_entrant(make_entrant(t_id, "Team C", Some("SV Other")));
+    publish(&core, &db_fake, t_id).await;
+
+    // tournaments advance one state at a time
+    let mut tb_core = core.as_tournament_base_state();
+    for state in [TournamentState::ActiveStage(0), TournamentState::Finished] {
+        tb_core
+            .load(t_id)
+            .await
+            .unwrap()
+            .expect("tournament exists");
+        tb_core.get_mut().set_tournament_state(state);
+        tb_core.save().await.expect("state change should succeed");
+    }
+
+    let report = core
+        .assemble_final_report(t_id, true)
+        .await
+        .unwrap()
+        .expect("report of finished tournament");
+
+    assert_eq!(report.tournament.get_id(), t_id);
+    assert_eq!(report.participation.registered, 16);
+    assert_eq!(report.participation.planned, 16);
+    // clubs are compared case insensitive; readiness entrants have no club
+    assert_eq!(report.participation.clubs, 2);
+    assert_eq!(report.stages.len(), 1);
+    // one round robin group of 16 entrants
+    assert_eq!(report.statistics.num_matches, 120);
+    assert_eq!(report.statistics.num_match_notes, 0);
+    // mock sport estimates zero minutes per match
+    assert_eq!(report.duration.planned, Some(Duration::ZERO));
+    assert_eq!(report.duration.actual, None);
+    assert!(report.standings.is_empty());
+    assert_eq!(report.handover_notes, Some(vec![]));
+
+    // reports for the public contain no handover notes
+    let public_report = core
+        .assemble_final_report(t_id, false)
+        .await
+        .unwrap()
+        .expect("report of finished tournament");
+    assert_eq!(public_report.handover_notes, None);
+}
+
+/// 3) store_final_report() / load_final_report(): round trip via blob storage port
+#[tokio::test]
+async fn given_stored_final_report_when_load_then_blob_is_returned() {
+    let (core, db_fake, bs_fake, t_id) = make_core_with_blob_fake();
+    publish(&core, &db_fake, t_id).await;
+
+    assert!(
+        core.load_final_report(t_id, ReportFormat::Pdf)
+            .await
+            .unwrap()
+            .is_none()
+    );
+
+    core.store_final_report(t_id, ReportFormat::Pdf, b"%PDF-1.4".to_vec())
+        .await
+        .expect("store should succeed");
+
+    let blob = core
+        .load_final_report(t_id, ReportFormat::Pdf)
+        .await
+        .unwrap()
+        .expect("report is stored");
+    assert_eq!(blob.content_type, "application/pdf");
+    assert_eq!(blob.data, b"%PDF-1.4");
+    assert_eq!(
+        bs_fake.keys(),
+        vec![final_report_key(t_id, ReportFormat::Pdf)]
+    );
+    assert!(
+        core.load_final_report(t_id, ReportFormat::Html)
+            .await
+            .unwrap()
+            .is_none()
+    );
+}
+
+/// 4) store_final_report(): failing blob storage port returns error
+#[tokio::test]
+async fn given_failing_blob_storage_when_store_final_report_then_error() {
+    let (core, _db_fake, bs_fake, t_id) = make_core_with_blob_fake();
+
+    bs_fake.fail_put_once();
+    let result = core
+        .store_final_report(t_id, ReportFormat::Html, b"<html></html>".to_vec())
+        .await;
+
+    assert!(result.is_err());
+    assert!(bs_fake.keys().is_empty());
+}
+
+/// 5) load_final_report(): reports of tournaments, which are not public, are not served
+#[tokio::test]
+async fn given_draft_tournament_when_load_final_report_then_none() {
+    let (core, _db_fake, _bs_fake, t_id) = make_core_with_blob_fake();
+    core.store_final_report(t_id, ReportFormat::Pdf, b"%PDF-1.4".to_vec())
+        .await
+        .expect("store should succeed");
+
+    let blob = core
+        .load_final_report(t_id, ReportFormat::Pdf)
+        .await
+        .unwrap();
+
+    assert!(blob.is_none());
+}
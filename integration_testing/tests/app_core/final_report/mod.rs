@@ -0,0 +1,3 @@
+//! testing app core final report of finished tournaments with fakes
+
+mod db_wrapper;
@@ -0,0 +1,158 @@
+//! testing app core api for cached group standings with fakes
+
+use app_core::{
+    CachedGroupStandings, CoreBuilder, CrMsg, EntrantGroupScore, GroupStanding, Match,
+    ScheduledEntrant, SportConfig, SportPluginManagerMap, Stage, TournamentBase,
+    utils::traits::ObjectIdVersion,
+};
+use generic_sport_plugin::{GenericSportPlugin, config::GenericSportConfig};
+use std::sync::Arc;
+use uuid::Uuid;
+
+use integration_testing::port_fakes::*;
+
+fn cached_standings(tournament_id: Uuid, group_id: Uuid) -> CachedGroupStandings {
+    let standings = (1..=3)
+        .map(|rank| GroupStanding {
+            rank,
+            score: EntrantGroupScore::new(Uuid::new_v4(), group_id),
+        })
+        .collect();
+    CachedGroupStandings::new(group_id, tournament_id, standings)
+}
+
+/// 1) get_group_standings(): cached standings are served without recomputation
+#[tokio::test]
+async fn given_cached_standings_when_get_group_standings_then_cached_returned() {
+    let (core, db_fake, _cr_fake, _spm) = make_core_with_fakes();
+    let cached = cached_standings(Uuid::new_v4(), Uuid::new_v4());
+    db_fake.seed_group_standings(cached.clone());
+
+    let standings = core
+        .get_group_standings(cached.tournament_id, cached.group_id)
+        .await
+        .expect("db ok");
+
+    assert_eq!(standings, cached.standings);
+}
+
+/// 2) get_group_standings(): without cache and results there are no standings to cache
+#[tokio::test]
+async fn given_no_cached_standings_when_get_group_standings_then_empty_and_not_cached() {
+    let (core, db_fake, _cr_fake, _spm) = make_core_with_fakes();
+    let group_id = Uuid::new_v4();
+
+    let standings = core
+        .get_group_standings(Uuid::new_v4(), group_id)
+        .await
+        .expect("db ok");
+
+    assert!(standings.is_empty());
+    assert!(db_fake.group_standings_of(group_id).is_none());
+}
+
+/// 3) recompute_group_standings(): standings, which cannot be computed, keep their cache
+#[tokio::test]
+async fn given_uncomputable_standings_when_recompute_then_cache_kept_and_nothing_published() {
+    let (core, db_fake, cr_fake, _spm) = make_core_with_fakes();
+    let tournament_id = Uuid::new_v4();
+    let cached = cached_standings(tournament_id, Uuid::new_v4());
+    db_fake.seed_group_standings(cached.clone());
+    cr_fake.clear();
+
+    let num_recomputed = core
+        .recompute_group_standings(tournament_id)
+        .await
+        .expect("db ok");
+
+    assert_eq!(num_recomputed, 0);
+    assert_eq!(db_fake.group_standings_of(cached.group_id), Some(cached));
+    assert!(
+        !cr_fake
+            .published()
+            .iter()
+            .any(|m| matches!(m, CrMsg::GroupStandingsUpdated { .. }))
+    );
+}
+
+/// 4) recompute_group_standings(): standings of other tournaments are not touched
+#[tokio::test]
+async fn given_other_tournament_when_recompute_then_nothing_recomputed() {
+    let (core, db_fake, _cr_fake, _spm) = make_core_with_fakes();
+    let cached = cached_standings(Uuid::new_v4(), Uuid::new_v4());
+    db_fake.seed_group_standings(cached.clone());
+
+    let num_recomputed = core
+        .recompute_group_standings(Uuid::new_v4())
+        .await
+        .expect("db ok");
+
+    assert_eq!(num_recomputed, 0);
+    assert_eq!(db_fake.group_standings_of(cached.group_id), Some(cached));
+}
+
+/// 5) get_group_standings(): standings are computed from the stored matches and cached
+#[tokio::test]
+async fn given_stored_results_when_get_group_standings_then_computed_and_cached() {
+    let (core, db_fake, _cr_fake, _spm) = make_core_with_fakes();
+    let spm = SportPluginManagerMap::new();
+    let plugin = Arc::new(GenericSportPlugin::new());
+    let sport_id = plugin.get_id_version().get_id();
+    spm.register(plugin).unwrap();
+    let core = CoreBuilder::new()
+        .set_db(db_fake.clone())
+        .set_cr(core.client_registry.clone())
+        .set_spm(Arc::new(spm))
+        .set_wh(core.webhooks.clone())
+        .set_em(core.email.clone())
+        .set_bs(core.blobs.clone())
+        .set_ev(core.domain_events.clone())
+        .build();
+    let mut config = SportConfig::default();
+    config
+        .set_sport_id(sport_id)
+        .set_config(serde_json::to_value(GenericSportConfig::default()).unwrap());
+    let config_id = db_fake.seed_sport_config(config);
+    let mut tournament = TournamentBase::default();
+    tournament
+        .set_name("Standings Cup")
+        .set_sport_id(sport_id)
+        .set_sport_config_id(Some(config_id))
+        .set_num_entrants(2);
+    let tournament_id = db_fake.seed_tournament_base(tournament);
+    let mut stage = Stage::default();
+    stage.set_tournament_id(tournament_id).set_number(0);
+    let stage_id = db_fake.seed_stage(stage);
+    let (group_id, winner, loser) = (Uuid::new_v4(), Uuid::new_v4(), Uuid::new_v4());
+    let mut m = Match::new_scheduled(
+        Uuid::new_v4(),
+        group_id,
+        Uuid::new_v4(),
+        1,
+        ScheduledEntrant::Entrant(winner),
+        ScheduledEntrant::Entrant(loser),
+    );
+    m.set_tournament(tournament_id, sport_id, stage_id)
+        .set_scores(vec![21], vec![10]);
+    db_fake.seed_matches(vec![m]);
+
+    let standings = core
+        .get_group_standings(tournament_id, group_id)
+        .await
+        .expect("db ok");
+
+    let ranking: Vec<Uuid> = standings.iter().map(|s| s.get_entrant_id()).collect();
+    assert_eq!(ranking, vec![winner, loser]);
+    assert_eq!(
+        db_fake
+            .group_standings_of(group_id)
+            .map(|cached| cached.standings),
+        Some(standings)
+    );
+    assert!(
+        core.get_group_standings(Uuid::new_v4(), group_id)
+            .await
+            .expect("db ok")
+            .is_empty()
+    );
+}
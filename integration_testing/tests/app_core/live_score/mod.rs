@@ -0,0 +1,108 @@
+//! testing app core api for live scores with fakes
+
+use app_core::{CoreError, CrMsg, DbError, DbpMatch, Match, MatchOutcome};
+use uuid::Uuid;
+
+use integration_testing::port_fakes::*;
+
+fn running_match(tournament_id: Uuid) -> Match {
+    let mut m = Match::new_played(
+        Uuid::new_v4(),
+        Uuid::new_v4(),
+        Uuid::new_v4(),
+        Uuid::new_v4(),
+        vec![],
+        vec![],
+    );
+    m.set_tournament(tournament_id, Uuid::new_v4(), Uuid::new_v4());
+    m
+}
+
+/// 1) update_live_score(): running score is saved and a tick is published
+#[tokio::test]
+async fn given_stored_match_when_update_live_score_then_saved_and_published() {
+    let (core, db_fake, cr_fake, _spm) = make_core_with_fakes();
+    let tournament_id = Uuid::new_v4();
+    let m = running_match(tournament_id);
+    db_fake.seed_matches(vec![m.clone()]);
+    cr_fake.clear();
+
+    let live_score = core
+        .update_live_score(*m.get_id(), vec![11, 3], vec![9, 5])
+        .await
+        .expect("db ok")
+        .expect("match exists");
+
+    assert_eq!(live_score.tick, 1);
+    let stored = db_fake.matches_of(tournament_id);
+    assert_eq!(stored[0].get_live_score(), Some(&live_score));
+    assert!(cr_fake.published().iter().any(|msg| matches!(
+        msg,
+        CrMsg::LiveScoreUpdated { id, version: 1 } if id == m.get_id()
+    )));
+
+    let live_scores = core.list_live_scores(tournament_id).await.expect("db ok");
+    assert_eq!(live_scores, vec![(*m.get_id(), live_score)]);
+}
+
+/// 2) update_live_score(): unknown matches are reported as missing
+#[tokio::test]
+async fn given_unknown_match_when_update_live_score_then_none() {
+    let (core, _db_fake, cr_fake, _spm) = make_core_with_fakes();
+    cr_fake.clear();
+
+    let live_score = core
+        .update_live_score(Uuid::new_v4(), vec![1], vec![0])
+        .await
+        .expect("db ok");
+
+    assert!(live_score.is_none());
+    assert!(cr_fake.published().is_empty());
+}
+
+/// 3) update_live_score(): decided matches reject running scores and are not saved
+#[tokio::test]
+async fn given_decided_match_when_update_live_score_then_error_and_unchanged() {
+    let (core, db_fake, _cr_fake, _spm) = make_core_with_fakes();
+    let tournament_id = Uuid::new_v4();
+    let mut m = running_match(tournament_id);
+    m.set_outcome(MatchOutcome::ForfeitB);
+    db_fake.seed_matches(vec![m.clone()]);
+
+    let res = core.update_live_score(*m.get_id(), vec![1], vec![0]).await;
+
+    assert!(res.is_err());
+    assert_eq!(db_fake.matches_of(tournament_id), vec![m]);
+}
+
+/// 4) record_live_score(): a running score of a stale copy does not overwrite a final result,
+/// which was saved in the meantime
+#[tokio::test]
+async fn given_result_saved_meanwhile_when_record_live_score_then_conflict_and_unchanged() {
+    let (core, db_fake, cr_fake, _spm) = make_core_with_fakes();
+    let tournament_id = Uuid::new_v4();
+    let mut m = running_match(tournament_id);
+    m.set_version(Some(0));
+    db_fake.seed_matches(vec![m.clone()]);
+    let mut stale = m.clone();
+    let mut decided = m;
+    decided.set_scores(vec![11], vec![7]);
+    let decided = db_fake
+        .save_matches(std::slice::from_ref(&decided))
+        .await
+        .expect("db ok")
+        .remove(0);
+    assert_eq!(decided.get_version(), Some(1));
+    cr_fake.clear();
+
+    let res = core.record_live_score(&mut stale, vec![3], vec![2]).await;
+
+    assert!(matches!(
+        res,
+        Err(CoreError::Db(DbError::VersionConflict {
+            current_version: 1
+        }))
+    ));
+    assert_eq!(db_fake.matches_of(tournament_id), vec![decided]);
+    assert!(cr_fake.published().is_empty());
+}
@@ -1,6 +1,30 @@
 #![cfg(feature = "ssr")]
 
+mod api_token;
+mod audit_log;
+mod cached_database;
+mod client_error;
+mod court_call;
+mod domain_event;
+mod entrant;
+mod feedback;
+mod group_standings;
+mod live_score;
+mod final_report;
+mod match_dependency;
+mod match_result;
+mod match_note;
+mod notification;
+mod official;
+mod pairing;
 mod postal_address;
+mod presence;
+mod score_correction;
+mod scorekeeper;
+mod search;
+mod shift_log;
 mod sport_config;
 mod stage;
 mod tournament_base;
+mod venue;
+mod webhook;
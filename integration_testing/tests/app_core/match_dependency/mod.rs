@@ -0,0 +1,124 @@
+//! testing app core api for resolving dependencies of KO matches with fakes
+
+use app_core::{
+    CoreBuilder, DomainEvent, EntrantGroupScore, GroupStanding, Match, ScheduledEntrant,
+};
+use std::sync::Arc;
+use uuid::Uuid;
+
+use integration_testing::port_fakes::*;
+
+fn standings(group_id: Uuid, entrants: &[Uuid]) -> Vec<GroupStanding> {
+    entrants
+        .iter()
+        .zip(1..)
+        .map(|(&entrant_id, rank)| GroupStanding {
+            rank,
+            score: EntrantGroupScore::new(entrant_id, group_id),
+        })
+        .collect()
+}
+
+fn scheduled(side_a: ScheduledEntrant, side_b: ScheduledEntrant) -> Match {
+    let mut m = Match::new_played(
+        Uuid::new_v4(),
+        Uuid::nil(),
+        Uuid::nil(),
+        Uuid::nil(),
+        vec![],
+        vec![],
+    );
+    m.set_sides(side_a, side_b);
+    m
+}
+
+/// 1) resolve_group_dependencies(): ranks of group fill semi finals, final stays scheduled
+#[tokio::test]
+async fn given_final_standings_when_resolve_group_dependencies_then_semi_finals_resolved() {
+    let (core, _db_fake, cr_fake, spm) = make_core_with_fakes();
+    let ev_fake = Arc::new(FakeDomainEventPort::new());
+    let core = CoreBuilder::new()
+        .set_db(core.database.clone())
+        .set_cr(cr_fake.clone())
+        .set_spm(spm)
+        .set_wh(core.webhooks.clone())
+        .set_em(core.email.clone())
+        .set_bs(core.blobs.clone())
+        .set_ev(ev_fake.clone())
+        .build();
+    let group_id = Uuid::new_v4();
+    let entrants: Vec<_> = (0..4).map(|_| Uuid::new_v4()).collect();
+    let mut matches = vec![
+        scheduled(
+            ScheduledEntrant::GroupRank(group_id, 0),
+            ScheduledEntrant::GroupRank(group_id, 3),
+        ),
+        scheduled(
+            ScheduledEntrant::GroupRank(group_id, 1),
+            ScheduledEntrant::GroupRank(group_id, 2),
+        ),
+    ];
+    let (semi_1, semi_2) = (*matches[0].get_id(), *matches[1].get_id());
+    matches.push(scheduled(
+        ScheduledEntrant::WinnerOf(semi_1),
+        ScheduledEntrant::WinnerOf(semi_2),
+    ));
+    cr_fake.clear();
+
+    let resolved = core
+        .resolve_group_dependencies(group_id, &standings(group_id, &entrants), &mut matches)
+        .await
+        .expect("resolve ok");
+
+    assert_eq!(resolved.len(), 2);
+    assert!(resolved.contains(&semi_1) && resolved.contains(&semi_2));
+    assert_eq!(
+        matches[0].get_entrants(),
+        Some((&entrants[0], &entrants[3]))
+    );
+    assert_eq!(
+        matches[1].get_entrants(),
+        Some((&entrants[1], &entrants[2]))
+    );
+    assert!(matches[2].get_entrants().is_none());
+    // clients are notified, when the caller saves the resolved matches
+    assert!(cr_fake.published().is_empty());
+    assert!(
+        ev_fake
+            .emitted()
+            .contains(&DomainEvent::MatchEntrantsResolved {
+                tournament_id: Uuid::nil(),
+                match_id: semi_1,
+                entrant_a: Some(entrants[0]),
+                entrant_b: Some(entrants[3]),
+            })
+    );
+}
+
+/// 2) resolve_group_dependencies(): ranks beyond the standings stay scheduled
+#[tokio::test]
+async fn given_short_standings_when_resolve_group_dependencies_then_missing_rank_stays_scheduled() {
+    let (core, _db_fake, cr_fake, _spm) = make_core_with_fakes();
+    let group_id = Uuid::new_v4();
+    let entrant_id = Uuid::new_v4();
+    let mut matches = vec![scheduled(
+        ScheduledEntrant::GroupRank(group_id, 0),
+        ScheduledEntrant::GroupRank(group_id, 1),
+    )];
+    cr_fake.clear();
+
+    let resolved = core
+        .resolve_group_dependencies(group_id, &standings(group_id, &[entrant_id]), &mut matches)
+        .await
+        .expect("resolve ok");
+
+    assert_eq!(resolved, vec![*matches[0].get_id()]);
+    assert_eq!(
+        matches[0].get_sides(),
+        (
+            &ScheduledEntrant::Entrant(entrant_id),
+            &ScheduledEntrant::GroupRank(group_id, 1)
+        )
+    );
+    assert!(cr_fake.published().is_empty());
+}
@@ -0,0 +1,113 @@
+//! testing app core api for match notes with fakes
+
+use app_core::{CoreError, CrMsg, DbError, MatchNote};
+use uuid::Uuid;
+
+use integration_testing::port_fakes::*;
+
+/// 1) save(): new note is persisted with normalized tags and publishes exactly once
+#[tokio::test]
+async fn given_new_note_when_save_then_persisted_and_published() {
+    let (mut core, _db_fake, cr_fake) = make_core_match_note_state_with_fakes();
+    let match_id = Uuid::new_v4();
+
+    core.get_mut()
+        .set_match_id(match_id)
+        .set_author("Director")
+        .set_tags(["Protest Pending"])
+        .set_text("Team B protests set 2.");
+    let saved = core.save().await.expect("save should succeed").clone();
+
+    assert_eq!(saved.get_version(), Some(0));
+    assert_eq!(saved.get_tags(), ["protest pending"]);
+    let notices = cr_fake.published();
+    assert_eq!(notices.len(), 1);
+    assert_eq!(
+        notices[0],
+        CrMsg::MatchNoteUpdated {
+            id: saved.get_id(),
+            version: 0
+        }
+    );
+
+    let loaded = core
+        .load_of_match(match_id)
+        .await
+        .unwrap()
+        .expect("note of match exists");
+    assert_eq!(loaded, &saved);
+}
+
+/// 2) notes are filtered by tag and searched by text
+#[tokio::test]
+async fn given_notes_when_list_then_filtered_by_tag_and_query() {
+    let (mut core, _db_fake, _cr_fake) = make_core_match_note_state_with_fakes();
+    let t_id = core.get().get_tournament_id();
+
+    for (tags, text) in [
+        (vec!["protest pending"], "Score of set 2 disputed"),
+        (vec!["injury delay", "protest pending"], "Player injured"),
+        (vec!["injury delay"], ""),
+    ] {
+        let mut note = MatchNote::default();
+        note.set_tournament_id(t_id)
+            .set_match_id(Uuid::new_v4())
+            .set_author("Director")
+            .set_tags(tags)
+            .set_text(text);
+        *core.get_mut() = note;
+        core.save().await.unwrap();
+    }
+
+    let protests = core
+        .list_notes(Some("Protest Pending"), None)
+        .await
+        .unwrap();
+    assert_eq!(protests.len(), 2);
+    let injured = core.list_notes(None, Some("INJURED")).await.unwrap();
+    assert_eq!(injured.len(), 1);
+    let both = core
+        .list_notes(Some("injury delay"), Some("disputed"))
+        .await
+        .unwrap();
+    assert!(both.is_empty());
+
+    let counts = core.tag_counts().await.unwrap();
+    assert_eq!(
+        counts,
+        vec![
+            ("injury delay".to_string(), 2),
+            ("protest pending".to_string(), 2)
+        ]
+    );
+}
+
+/// 3) second note of same match and empty notes are rejected
+#[tokio::test]
+async fn given_invalid_note_when_save_then_error_and_no_publish() {
+    let (mut core, db_fake, cr_fake) = make_core_match_note_state_with_fakes();
+    let t_id = core.get().get_tournament_id();
+    let match_id = Uuid::new_v4();
+
+    core.get_mut().set_match_id(match_id).set_author("Director");
+    let err = core.save().await.unwrap_err();
+    assert!(matches!(err, CoreError::Validation(_)));
+
+    core.get_mut().add_tag("injury delay");
+    core.save().await.unwrap();
+
+    let mut second = MatchNote::default();
+    second
+        .set_tournament_id(t_id)
+        .set_match_id(match_id)
+        .set_author("Director")
+        .set_text("duplicate");
+    *core.get_mut() = second;
+    let err = core.save().await.unwrap_err();
+    assert!(matches!(err, CoreError::Db(DbError::UniqueViolation(_))));
+
+    db_fake.fail_save_mn_once();
+    core.get_mut().set_match_id(Uuid::new_v4());
+    assert!(core.save().await.is_err());
+    assert_eq!(cr_fake.published().len(), 1);
+}
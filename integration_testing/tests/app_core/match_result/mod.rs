@@ -0,0 +1,437 @@
+//! testing app core api for final results of matches with fakes
+
+use app_core::{
+    Core, CoreBuilder, CoreError, CrMsg, DomainEvent, InitState, Match, MatchOutcome,
+    PairingHistory, ScheduledEntrant, ScorekeeperGrant, SportConfig, SportPluginManagerMap, Stage,
+    TournamentBase, TournamentMode, TournamentState, WEBHOOK_EVENT_HEADER, WebhookEventType,
+    utils::traits::ObjectIdVersion,
+};
+use generic_sport_plugin::{GenericSportPlugin, config::GenericSportConfig};
+use std::sync::Arc;
+use uuid::Uuid;
+
+use integration_testing::port_fakes::*;
+
+struct Bracket {
+    tournament_id: Uuid,
+    entrants: Vec<Uuid>,
+    /// stored matches, e.g. semi finals and final
+    matches: Vec<Match>,
+}
+
+fn make_core_with_generic_sport() -> (
+    Core<InitState>,
+    Arc<FakeDatabasePort>,
+    Arc<FakeClientRegistryPort>,
+    Arc<FakeDomainEventPort>,
+    Arc<FakeWebhookTransport>,
+    Uuid,
+) {
+    let (core, db_fake, cr_fake, _spm) = make_core_with_fakes();
+    let spm = SportPluginManagerMap::new();
+    let plugin = Arc::new(GenericSportPlugin::new());
+    let sport_id = plugin.get_id_version().get_id();
+    spm.register(plugin).unwrap();
+    let ev_fake = Arc::new(FakeDomainEventPort::new());
+    let wh_fake = Arc::new(FakeWebhookTransport::new());
+    let core = CoreBuilder::new()
+        .set_db(db_fake.clone())
+        .set_cr(cr_fake.clone())
+        .set_spm(Arc::new(spm))
+        .set_wh(wh_fake.clone())
+        .set_em(core.email.clone())
+        .set_bs(core.blobs.clone())
+        .set_ev(ev_fake.clone())
+        .build();
+    (core, db_fake, cr_fake, ev_fake, wh_fake, sport_id)
+}
+
+/// stored KO bracket of 4 entrants without results in the active first stage
+fn seed_bracket(db_fake: &FakeDatabasePort, sport_id: Uuid, state: TournamentState) -> Bracket {
+    let mut config = SportConfig::default();
+    config
+        .set_sport_id(sport_id)
+        .set_config(serde_json::to_value(GenericSportConfig::default()).unwrap());
+    let config_id = db_fake.seed_sport_config(config);
+    let mut tournament = TournamentBase::default();
+    tournament
+        .set_name("Result Cup")
+        .set_sport_id(sport_id)
+        .set_sport_config_id(Some(config_id))
+        .set_num_entrants(4)
+        .set_tournament_state(state);
+    let tournament_id = db_fake.seed_tournament_base(tournament);
+    let mut stage = Stage::default();
+    stage.set_tournament_id(tournament_id).set_number(0);
+    let stage_id = db_fake.seed_stage(stage);
+
+    let group_id = Uuid::new_v4();
+    let entrants: Vec<Uuid> = (0..4).map(|_| Uuid::new_v4()).collect();
+    let new_match = |number, side_a, side_b| {
+        let mut m = Match::new_scheduled(
+            Uuid::new_v4(),
+            group_id,
+            Uuid::new_v4(),
+            number,
+            side_a,
+            side_b,
+        );
+        m.set_tournament(tournament_id, sport_id, stage_id);
+        m
+    };
+    let semi_1 = new_match(
+        1,
+        ScheduledEntrant::Entrant(entrants[0]),
+        ScheduledEntrant::Entrant(entrants[1]),
+    );
+    let semi_2 = new_match(
+        2,
+        ScheduledEntrant::Entrant(entrants[2]),
+        ScheduledEntrant::Entrant(entrants[3]),
+    );
+    let last = new_match(
+        3,
+        ScheduledEntrant::WinnerOf(*semi_1.get_id()),
+        ScheduledEntrant::WinnerOf(*semi_2.get_id()),
+    );
+    let matches = vec![semi_1, semi_2, last];
+    db_fake.seed_matches(matches.clone());
+    Bracket {
+        tournament_id,
+        entrants,
+        matches,
+    }
+}
+
+/// stored first round of a Swiss stage of 4 entrants without results
+fn seed_swiss_round(db_fake: &FakeDatabasePort, sport_id: Uuid, auto_advance: bool) -> Bracket {
+    let mut config = SportConfig::default();
+    config
+        .set_sport_id(sport_id)
+        .set_config(serde_json::to_value(GenericSportConfig::default()).unwrap());
+    let config_id = db_fake.seed_sport_config(config);
+    let mut tournament = TournamentBase::default();
+    tournament
+        .set_name("Swiss Cup")
+        .set_sport_id(sport_id)
+        .set_sport_config_id(Some(config_id))
+        .set_num_entrants(4)
+        .set_tournament_mode(TournamentMode::SwissSystem { num_rounds: 3 })
+        .set_tournament_state(TournamentState::ActiveStage(0));
+    let tournament_id = db_fake.seed_tournament_base(tournament);
+    let mut stage = Stage::default();
+    stage
+        .set_tournament_id(tournament_id)
+        .set_number(0)
+        .set_auto_advance(auto_advance);
+    let stage_id = db_fake.seed_stage(stage);
+
+    let (group_id, round_id) = (Uuid::new_v4(), Uuid::new_v4());
+    let entrants: Vec<Uuid> = (0..4).map(|_| Uuid::new_v4()).collect();
+    let matches: Vec<Match> = [(1, 0, 1), (2, 2, 3)]
+        .into_iter()
+        .map(|(number, a, b)| {
+            let mut m = Match::new_scheduled(
+                Uuid::new_v4(),
+                group_id,
+                round_id,
+                number,
+                ScheduledEntrant::Entrant(entrants[a]),
+                ScheduledEntrant::Entrant(entrants[b]),
+            );
+            m.set_tournament(tournament_id, sport_id, stage_id);
+            m
+        })
+        .collect();
+    db_fake.seed_matches(matches.clone());
+    Bracket {
+        tournament_id,
+        entrants,
+        matches,
+    }
+}
+
+fn stored(db_fake: &FakeDatabasePort, bracket: &Bracket, index: usize) -> Match {
+    db_fake
+        .matches_of(bracket.tournament_id)
+        .into_iter()
+        .find(|m| m.get_id() == bracket.matches[index].get_id())
+        .expect("match is stored")
+}
+
+/// 1) enter_match_result(): result is saved, dependent final is resolved and saved
+#[tokio::test]
+async fn given_semi_final_when_enter_result_then_saved_and_final_resolved() {
+    let (core, db_fake, cr_fake, ev_fake, _wh_fake, sport_id) = make_core_with_generic_sport();
+    let bracket = seed_bracket(&db_fake, sport_id, TournamentState::ActiveStage(0));
+    let semi_1 = *bracket.matches[0].get_id();
+    cr_fake.clear();
+
+    let entered = core
+        .enter_match_result(semi_1, vec![5], vec![11])
+        .await
+        .expect("result is valid")
+        .expect("match exists");
+
+    assert!(entered.is_decided());
+    assert_eq!(stored(&db_fake, &bracket, 0), entered);
+    let last = stored(&db_fake, &bracket, 2);
+    assert_eq!(
+        last.get_sides().0,
+        &ScheduledEntrant::Entrant(bracket.entrants[1])
+    );
+    // clients are notified with the saved version
+    assert!(entered.get_version().is_some());
+    assert!(cr_fake.published().iter().any(|msg| matches!(
+        msg,
+        CrMsg::MatchUpdated { id, version } if *id == semi_1 && Some(*version) == entered.get_version()
+    )));
+    assert!(ev_fake.emitted().iter().any(|e| matches!(
+        e,
+        DomainEvent::MatchResultEntered { tournament_id, match_number: 1, .. }
+            if *tournament_id == bracket.tournament_id
+    )));
+}
+
+/// 2) enter_match_result(): decided matches are corrected, not entered again
+#[tokio::test]
+async fn given_decided_match_when_enter_result_then_rejected() {
+    let (core, db_fake, _cr_fake, _ev_fake, _wh_fake, sport_id) = make_core_with_generic_sport();
+    let bracket = seed_bracket(&db_fake, sport_id, TournamentState::ActiveStage(0));
+    let semi_1 = *bracket.matches[0].get_id();
+    core.enter_match_result(semi_1, vec![11], vec![5])
+        .await
+        .expect("result is valid");
+
+    let err = core
+        .enter_match_result(semi_1, vec![5], vec![11])
+        .await
+        .unwrap_err();
+
+    // replayed offline results keep this error as conflict
+    assert!(err.is_match_decided());
+    assert_eq!(stored(&db_fake, &bracket, 0).get_scores().0, &vec![11]);
+}
+
+/// 3) enter_match_result(): results of stages, which are not active, are rejected
+#[tokio::test]
+async fn given_published_tournament_when_enter_result_then_rejected_and_unchanged() {
+    let (core, db_fake, _cr_fake, ev_fake, _wh_fake, sport_id) = make_core_with_generic_sport();
+    let bracket = seed_bracket(&db_fake, sport_id, TournamentState::Published);
+
+    let res = core
+        .enter_match_result(*bracket.matches[0].get_id(), vec![11], vec![5])
+        .await;
+
+    assert!(res.is_err());
+    assert_eq!(stored(&db_fake, &bracket, 0), bracket.matches[0]);
+    assert!(ev_fake.emitted().is_empty());
+}
+
+/// 4) enter_match_result(): unknown matches are reported as missing
+#[tokio::test]
+async fn given_unknown_match_when_enter_result_then_none() {
+    let (core, _db_fake, _cr_fake, _ev_fake, _wh_fake, _sport_id) = make_core_with_generic_sport();
+
+    let entered = core
+        .enter_match_result(Uuid::new_v4(), vec![11], vec![5])
+        .await
+        .expect("db ok");
+
+    assert!(entered.is_none());
+}
+
+/// 5) enter_match_result(): last result of a Swiss round of an auto advancing stage
+/// generates the next round without rematches
+#[tokio::test]
+async fn given_auto_advancing_swiss_stage_when_round_completed_then_next_round_saved() {
+    let (core, db_fake, cr_fake, _ev_fake, wh_fake, sport_id) = make_core_with_generic_sport();
+    let swiss = seed_swiss_round(&db_fake, sport_id, true);
+    let mut wh_core = core.as_webhook_state();
+    wh_core
+        .get_mut()
+        .set_name("Round feed")
+        .set_url("https://example.com/rounds")
+        .set_event_types([
+            WebhookEventType::RoundResults,
+            WebhookEventType::RoundStarted,
+        ]);
+    wh_core.save().await.expect("endpoint is valid");
+
+    core.enter_match_result(*swiss.matches[0].get_id(), vec![11], vec![5])
+        .await
+        .expect("result is valid");
+    assert_eq!(db_fake.matches_of(swiss.tournament_id).len(), 2);
+
+    cr_fake.clear();
+    core.enter_match_result(*swiss.matches[1].get_id(), vec![11], vec![7])
+        .await
+        .expect("result is valid");
+
+    let stored = db_fake.matches_of(swiss.tournament_id);
+    let next_round: Vec<&Match> = stored
+        .iter()
+        .filter(|m| m.get_round_id() != swiss.matches[0].get_round_id())
+        .collect();
+    assert_eq!(next_round.len(), 2);
+    let history = PairingHistory::from_matches(&swiss.matches);
+    for m in &next_round {
+        let (a, b) = m.get_entrants().expect("entrants are paired");
+        assert!(!history.have_played(*a, *b));
+        assert!(!m.is_decided());
+        assert_eq!(m.get_group_id(), swiss.matches[0].get_group_id());
+    }
+    // winners of the first round play each other
+    assert!(next_round.iter().any(|m| {
+        m.get_entrants() == Some((&swiss.entrants[0], &swiss.entrants[2]))
+            || m.get_entrants() == Some((&swiss.entrants[2], &swiss.entrants[0]))
+    }));
+    assert!(next_round.iter().all(|m| {
+        cr_fake
+            .published()
+            .iter()
+            .any(|msg| matches!(msg, CrMsg::MatchUpdated { id, .. } if id == m.get_id()))
+    }));
+
+    let sent = wh_fake.sent();
+    let events: Vec<_> = sent
+        .iter()
+        .filter_map(|w| w.header(WEBHOOK_EVENT_HEADER))
+        .collect();
+    assert_eq!(events, vec!["round.results", "round.started"]);
+    let results: serde_json::Value = serde_json::from_str(&sent[0].body).unwrap();
+    assert_eq!(results["data"]["round_number"], 1);
+    assert_eq!(results["data"]["results"].as_array().unwrap().len(), 2);
+    let started: serde_json::Value = serde_json::from_str(&sent[1].body).unwrap();
+    assert_eq!(started["data"]["round_number"], 2);
+    assert_eq!(started["data"]["matches"].as_array().unwrap().len(), 2);
+}
+
+/// 6) enter_match_result(): Swiss stages without auto advance wait for the director
+#[tokio::test]
+async fn given_swiss_stage_without_auto_advance_when_round_completed_then_no_round_generated() {
+    let (core, db_fake, _cr_fake, _ev_fake, _wh_fake, sport_id) = make_core_with_generic_sport();
+    let swiss = seed_swiss_round(&db_fake, sport_id, false);
+
+    for m in &swiss.matches {
+        core.enter_match_result(*m.get_id(), vec![11], vec![5])
+            .await
+            .expect("result is valid");
+    }
+
+    assert_eq!(db_fake.matches_of(swiss.tournament_id).len(), 2);
+}
+
+/// 7) load_day_dashboard(): standings of a Swiss round with a bye are consistent
+#[tokio::test]
+async fn given_round_with_bye_when_load_day_dashboard_then_no_standings_warning() {
+    let (core, db_fake, _cr_fake, _ev_fake, _wh_fake, sport_id) = make_core_with_generic_sport();
+    let swiss = seed_swiss_round(&db_fake, sport_id, false);
+    let mut bye = Match::new_scheduled(
+        Uuid::new_v4(),
+        *swiss.matches[0].get_group_id(),
+        *swiss.matches[0].get_round_id(),
+        3,
+        ScheduledEntrant::Entrant(Uuid::new_v4()),
+        ScheduledEntrant::Bye,
+    );
+    bye.set_tournament(
+        swiss.tournament_id,
+        sport_id,
+        *swiss.matches[0].get_stage_id(),
+    )
+    .set_outcome(MatchOutcome::Bye);
+    db_fake.seed_matches(vec![bye]);
+
+    core.enter_match_result(*swiss.matches[0].get_id(), vec![11], vec![5])
+        .await
+        .expect("result is valid");
+    core.enter_match_result(*swiss.matches[1].get_id(), vec![7], vec![11])
+        .await
+        .expect("result is valid");
+
+    let dashboard = core
+        .load_day_dashboard(swiss.tournament_id)
+        .await
+        .expect("db ok")
+        .expect("tournament exists");
+    assert!(dashboard.standings_checks.is_empty());
+}
+
+/// 8) enter_scorekeeper_match_result(): matches outside of the granted station are rejected
+#[tokio::test]
+async fn given_station_grant_when_enter_scorekeeper_result_then_only_granted_match_saved() {
+    let (core, db_fake, _cr_fake, _ev_fake, _wh_fake, sport_id) = make_core_with_generic_sport();
+    let bracket = seed_bracket(&db_fake, sport_id, TournamentState::ActiveStage(0));
+    let mut semis = bracket.matches[..2].to_vec();
+    for (station, m) in (1..).zip(semis.iter_mut()) {
+        let start_at = m.get_start_at();
+        m.set_slot(station, start_at);
+    }
+    db_fake.seed_matches(semis.clone());
+    let mut token_core = core.as_scorekeeper_token_state(bracket.tournament_id);
+    token_core
+        .get_mut()
+        .set_label("Station 1")
+        .set_grants([ScorekeeperGrant::Station { station: 1 }]);
+    let (_, secret) = token_core.create().await.expect("token is valid");
+
+    let res = core
+        .enter_scorekeeper_match_result(&secret, *semis[1].get_id(), vec![11], vec![5])
+        .await;
+
+    assert!(matches!(res, Err(CoreError::Field(_))));
+    assert_eq!(stored(&db_fake, &bracket, 1), semis[1]);
+
+    let entered = core
+        .enter_scorekeeper_match_result(&secret, *semis[0].get_id(), vec![11], vec![5])
+        .await
+        .expect("match is granted")
+        .expect("match exists");
+    assert!(entered.is_decided());
+
+    // invalid secrets are reported as missing
+    let entered = core
+        .enter_scorekeeper_match_result("fks_invalid", *semis[1].get_id(), vec![11], vec![5])
+        .await
+        .expect("db ok");
+    assert!(entered.is_none());
+}
+
+/// 9) load_day_dashboard(): result entries are evaluated from stored matches
+#[tokio::test]
+async fn given_entered_and_overdue_results_when_load_day_dashboard_then_stations_are_evaluated() {
+    let (core, db_fake, _cr_fake, _ev_fake, _wh_fake, sport_id) = make_core_with_generic_sport();
+    let bracket = seed_bracket(&db_fake, sport_id, TournamentState::ActiveStage(0));
+    let mut semis = bracket.matches[..2].to_vec();
+    let start_at = chrono::Local::now() - chrono::Duration::hours(2);
+    for (station, m) in (1..).zip(semis.iter_mut()) {
+        m.set_slot(station, start_at);
+    }
+    db_fake.seed_matches(semis.clone());
+
+    let entered = core
+        .enter_match_result(*semis[0].get_id(), vec![11], vec![5])
+        .await
+        .expect("result is valid")
+        .expect("match exists");
+    assert!(entered.get_result_at().is_some());
+
+    let dashboard = core
+        .load_day_dashboard(bracket.tournament_id)
+        .await
+        .expect("db ok")
+        .expect("tournament exists");
+    let station = |number| {
+        dashboard
+            .stations
+            .iter()
+            .find(|s| s.station == number)
+            .expect("station is listed")
+    };
+    assert_eq!(station(1).num_entered, 1);
+    assert_eq!(station(1).num_outstanding, 0);
+    assert_eq!(station(2).num_entered, 0);
+    assert_eq!(station(2).num_outstanding, 1);
+    assert!(station(2).is_lagging());
+}
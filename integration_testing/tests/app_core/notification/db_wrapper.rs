@@ -0,0 +1,192 @@
+use app_core::{
+    CourtCall, CourtCallState, DbpEntrant, DbpTournamentBase, Entrant, Match, MatchTimeChange,
+    ScheduledEntrant, Stage, TournamentState,
+};
+use chrono::{Duration, Local, Utc};
+use uuid::Uuid;
+
+use integration_testing::port_fakes::*;
+
+fn make_entrant(tournament_id: Uuid, name: &str, email: Option<&str>) -> Entrant {
+    let mut entrant = Entrant::default();
+    entrant
+        .set_tournament_id(tournament_id)
+        .set_name(name)
+        .set_email(email);
+    entrant
+}
+
+/// 1) save(): new entrants with email receive a registration confirmation, updates do not
+#[tokio::test]
+async fn given_new_entrant_with_email_when_save_then_registration_is_confirmed() {
+    let (core, _db_fake, em_fake, t_id) = make_core_with_email_fake();
+    let mut core = core.as_entrant_state(t_id);
+
+    *core.get_mut() = make_entrant(t_id, "Team A", Some("team-a@example.org"));
+    core.save().await.expect("save should succeed");
+
+    let sent = em_fake.sent();
+    assert_eq!(sent.len(), 1);
+    assert_eq!(sent[0].to, "team-a@example.org");
+    assert_eq!(sent[0].to_name.as_deref(), Some("Team A"));
+    assert_eq!(
+        sent[0].subject,
+        "Registration confirmed: Notification Tournament"
+    );
+    assert!(sent[0].body.starts_with("Hello Team A,"));
+
+    // update of existing entrant
+    core.get_mut().set_club(Some("FC Example"));
+    core.save().await.expect("update should succeed");
+    // entrant without email
+    *core.get_mut() = make_entrant(t_id, "Team B", None);
+    core.save().await.expect("save should succeed");
+
+    assert_eq!(em_fake.sent().len(), 1);
+}
+
+/// 2) failing email port does not fail save()
+#[tokio::test]
+async fn given_failing_email_port_when_save_entrant_then_save_succeeds() {
+    let (core, db_fake, em_fake, t_id) = make_core_with_email_fake();
+    let mut core = core.as_entrant_state(t_id);
+
+    em_fake.fail_send_once();
+    *core.get_mut() = make_entrant(t_id, "Team A", Some("team-a@example.org"));
+    let saved = core.save().await.expect("save should succeed").clone();
+
+    assert!(em_fake.sent().is_empty());
+    assert_eq!(
+        db_fake.list_entrants_of_tournament(t_id).await.unwrap(),
+        vec![saved]
+    );
+}
+
+/// 3) save() of tournament: publishing notifies all entrants with email once
+#[tokio::test]
+async fn given_draft_tournament_when_published_then_entrants_are_notified() {
+    let (core, db_fake, em_fake, t_id) = make_core_with_email_fake();
+    db_fake.seed_entrant(make_entrant(t_id, "Team A", Some("team-a@example.org")));
+    db_fake.seed_entrant(make_entrant(t_id, "Team B", None));
+    db_fake.seed_entrant(make_entrant(t_id, "Team C", Some("team-c@example.org")));
+    db_fake.seed_readiness(t_id);
+
+    let mut core = core.as_tournament_base_state();
+    core.load(t_id).await.unwrap().expect("tournament exists");
+    core.get_mut()
+        .set_tournament_state(TournamentState::Published);
+    core.save().await.expect("publish should succeed");
+
+    let mut recipients: Vec<String> = em_fake.sent().into_iter().map(|m| m.to).collect();
+    recipients.sort();
+    assert_eq!(recipients, vec!["team-a@example.org", "team-c@example.org"]);
+    assert!(
+        em_fake
+            .sent()
+            .iter()
+            .all(|m| m.subject == "Schedule published: Notification Tournament")
+    );
+
+    // saving the published tournament again does not notify again
+    core.get_mut().set_name("Notification Tournament 2026");
+    core.save().await.expect("update should succeed");
+    assert_eq!(em_fake.sent().len(), 2);
+}
+
+/// 4) notify_match_time_changed(): only entrants of the match are notified
+#[tokio::test]
+async fn given_match_time_change_when_notify_then_entrants_of_match_are_notified() {
+    let (core, db_fake, em_fake, t_id) = make_core_with_email_fake();
+    let a = db_fake.seed_entrant(make_entrant(t_id, "Team A", Some("team-a@example.org")));
+    let b = db_fake.seed_entrant(make_entrant(t_id, "Team B", Some("team-b@example.org")));
+    db_fake.seed_entrant(make_entrant(t_id, "Team C", Some("team-c@example.org")));
+
+    let old_start = Local::now();
+    let change = MatchTimeChange {
+        tournament_id: t_id,
+        match_number: 7,
+        entrant_ids: vec![a, b],
+        old_start,
+        new_start: old_start + Duration::minutes(30),
+        station: Some(3),
+    };
+    assert_eq!(core.notify_match_time_changed(&change).await, 2);
+
+    let sent = em_fake.sent();
+    assert_eq!(sent[0].to, "team-a@example.org");
+    assert_eq!(sent[1].to, "team-b@example.org");
+    assert!(
+        sent[0]
+            .body
+            .contains("match 7 of the tournament Notification Tournament")
+    );
+    assert!(sent[0].body.contains(" at station 3.\n"));
+}
+
+/// 5) notify_court_call(): entrants of the called match are asked to come to the station
+#[tokio::test]
+async fn given_court_call_when_notify_then_entrants_of_match_are_notified() {
+    let (core, db_fake, em_fake, t_id) = make_core_with_email_fake();
+    let a = db_fake.seed_entrant(make_entrant(t_id, "Team A", Some("team-a@example.org")));
+    let b = db_fake.seed_entrant(make_entrant(t_id, "Team B", None));
+    let tournament = db_fake.get_tournament_base(t_id).await.unwrap().unwrap();
+
+    let call = CourtCall {
+        match_id: Uuid::new_v4(),
+        station: 2,
+        station_name: String::from("Court 2"),
+        start_at: Utc::now(),
+        entrant_ids: vec![a, b],
+        entrants: vec![String::from("Team A"), String::from("Team B")],
+        state: CourtCallState::Called,
+    };
+    assert_eq!(core.notify_court_call(&tournament, &call).await, 1);
+
+    let sent = em_fake.sent();
+    assert_eq!(sent[0].to, "team-a@example.org");
+    assert_eq!(
+        sent[0].subject,
+        "Your match is called: Notification Tournament"
+    );
+    assert!(sent[0].body.contains("Please come to Court 2 now."));
+}
+
+/// 6) compress_stage_schedule(): entrants of moved matches are notified about the new start
+#[tokio::test]
+async fn given_late_planned_match_when_compress_stage_schedule_then_entrants_are_notified() {
+    let (core, db_fake, em_fake, t_id) = make_core_with_email_fake();
+    let a = db_fake.seed_entrant(make_entrant(t_id, "Team A", Some("team-a@example.org")));
+    let b = db_fake.seed_entrant(make_entrant(t_id, "Team B", Some("team-b@example.org")));
+    let mut stage = Stage::default();
+    stage
+        .set_tournament_id(t_id)
+        .set_number(0)
+        .set_num_groups(1);
+    let stage_id = db_fake.seed_stage(stage);
+    let mut m = Match::new_scheduled(
+        Uuid::new_v4(),
+        Uuid::new_v4(),
+        Uuid::new_v4(),
+        4,
+        ScheduledEntrant::Entrant(a),
+        ScheduledEntrant::Entrant(b),
+    );
+    m.set_tournament(t_id, Uuid::new_v4(), stage_id)
+        .set_slot(1, Local::now() + Duration::hours(2));
+    db_fake.seed_matches(vec![m]);
+
+    core.compress_stage_schedule(stage_id)
+        .await
+        .expect("db ok")
+        .expect("stage exists");
+
+    let sent = em_fake.sent();
+    assert_eq!(sent.len(), 2);
+    assert_eq!(sent[0].to, "team-a@example.org");
+    assert!(
+        sent[0]
+            .body
+            .contains("match 4 of the tournament Notification Tournament")
+    );
+    assert!(sent[0].body.contains(" at station 1.\n"));
+}
@@ -0,0 +1,3 @@
+//! testing app core email notifications of entrants with fakes
+
+mod db_wrapper;
@@ -0,0 +1,135 @@
+use app_core::{AuditObjectType, CoreError, CrMsg, DbError, Entrant, Match, Official};
+use integration_testing::port_fakes::*;
+
+/// 1) save(): official is saved, published and written to the audit log of the tournament
+#[tokio::test]
+async fn given_valid_official_when_save_then_published_and_audited() {
+    let (mut core, db_fake, cr_fake) = make_core_official_state_with_fakes();
+    core.get_mut()
+        .set_name("Jane Referee")
+        .set_club(Some("TV Musterstadt"))
+        .set_qualifications(["Referee", "referee", "Umpire"]);
+
+    let saved = core.save().await.expect("save should succeed").clone();
+
+    assert_eq!(saved.get_version(), Some(0));
+    assert_eq!(saved.get_qualifications(), ["Referee", "Umpire"]);
+    assert_eq!(
+        cr_fake.published(),
+        vec![CrMsg::OfficialUpdated {
+            id: saved.get_id(),
+            version: 0
+        }]
+    );
+    let records = db_fake.audit_records();
+    assert_eq!(records.len(), 1);
+    assert_eq!(records[0].get_object_type(), AuditObjectType::Official);
+    assert_eq!(records[0].get_object_id(), saved.get_id());
+}
+
+/// 2) save(): official without name is rejected and nothing is published
+#[tokio::test]
+async fn given_official_without_name_when_save_then_validation_error() {
+    let (mut core, _db_fake, cr_fake) = make_core_official_state_with_fakes();
+    core.get_mut().set_name("  ");
+
+    let err = core.save().await.unwrap_err();
+
+    assert!(matches!(err, CoreError::Validation(_)));
+    assert!(cr_fake.published().is_empty());
+}
+
+/// 3) save(): names of officials are unique per tournament
+#[tokio::test]
+async fn given_existing_name_when_save_new_official_then_unique_violation() {
+    let (mut core, _db_fake, _cr_fake) = make_core_official_state_with_fakes();
+    core.get_mut().set_name("Jane Referee");
+    core.save().await.expect("first save should succeed");
+
+    let tournament_id = core.get().get_tournament_id();
+    let mut other = core.as_official_state(tournament_id);
+    other.get_mut().set_name("jane referee");
+    let err = other.save().await.unwrap_err();
+
+    assert!(matches!(err, CoreError::Db(DbError::UniqueViolation(_))));
+}
+
+/// 4) load(): officials of other tournaments are not loaded
+#[tokio::test]
+async fn given_official_of_other_tournament_when_load_then_none() {
+    let (mut core, _db_fake, _cr_fake) = make_core_official_state_with_fakes();
+    core.get_mut().set_name("Jane Referee");
+    let official_id = core.save().await.unwrap().get_id();
+
+    let mut other = core.as_official_state(uuid::Uuid::new_v4());
+
+    assert!(other.load(official_id).await.unwrap().is_none());
+    assert!(other.list_officials().await.unwrap().is_empty());
+    assert!(core.load(official_id).await.unwrap().is_some());
+}
+
+/// 5) list_officials() and plan_assignments(): all officials of the tournament are listed
+/// by name and get a workload
+#[tokio::test]
+async fn given_officials_when_plan_assignments_then_workload_of_all_officials() {
+    let (core, _db_fake, _cr_fake) = make_core_official_state_with_fakes();
+    let tournament_id = core.get().get_tournament_id();
+    for name in ["Zoe", "Adam", "Mia"] {
+        let mut official_core = core.as_official_state(tournament_id);
+        official_core.get_mut().set_name(name);
+        official_core.save().await.unwrap();
+    }
+
+    let officials = core.list_officials().await.unwrap();
+    let plan = core.plan_assignments(None).await.unwrap();
+
+    let names: Vec<_> = officials.iter().map(Official::get_name).collect();
+    assert_eq!(names, vec!["Adam", "Mia", "Zoe"]);
+    assert_eq!(plan.workload.len(), 3);
+    assert!(plan.workload.iter().all(|w| w.num_matches == 0));
+    assert!(plan.assignments.is_empty());
+    assert!(plan.unassigned.is_empty());
+}
+
+/// 6) plan_assignments(): undecided stored matches get an official without conflict of
+/// interest
+#[tokio::test]
+async fn given_stored_matches_when_plan_assignments_then_undecided_match_assigned() {
+    let (core, db_fake, _cr_fake) = make_core_official_state_with_fakes();
+    let tournament_id = core.get().get_tournament_id();
+    let mut official_ids = Vec::new();
+    for (name, club) in [("Adam", Some("FC Home")), ("Mia", None)] {
+        let mut official_core = core.as_official_state(tournament_id);
+        official_core.get_mut().set_name(name).set_club(club);
+        official_ids.push(official_core.save().await.unwrap().get_id());
+    }
+    let mut home = Entrant::default();
+    home.set_tournament_id(tournament_id)
+        .set_name("Home")
+        .set_club(Some("FC Home"));
+    let home = db_fake.seed_entrant(home);
+    let mut away = Entrant::default();
+    away.set_tournament_id(tournament_id).set_name("Away");
+    let away = db_fake.seed_entrant(away);
+    let sport_id = uuid::Uuid::new_v4();
+    let mut upcoming =
+        Match::new_played(uuid::Uuid::new_v4(), home, away, sport_id, vec![], vec![]);
+    upcoming.set_tournament(tournament_id, sport_id, uuid::Uuid::new_v4());
+    let mut played = Match::new_played(
+        uuid::Uuid::new_v4(),
+        home,
+        away,
+        sport_id,
+        vec![11],
+        vec![5],
+    );
+    played.set_tournament(tournament_id, sport_id, uuid::Uuid::new_v4());
+    db_fake.seed_matches(vec![upcoming.clone(), played]);
+
+    let plan = core.plan_assignments(None).await.unwrap();
+
+    assert_eq!(plan.assignments.len(), 1);
+    assert_eq!(plan.assignments[0].match_id, *upcoming.get_id());
+    assert_eq!(plan.assignments[0].official_id, official_ids[1]);
+    assert!(plan.unassigned.is_empty());
+}
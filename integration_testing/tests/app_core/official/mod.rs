@@ -0,0 +1,3 @@
+//! testing app core api for officials with fakes
+
+mod db_wrapper;
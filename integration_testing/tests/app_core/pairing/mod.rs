@@ -0,0 +1,177 @@
+//! testing app core api for manual pairing overrides with fakes
+
+use app_core::{
+    CoreError, Match, Pairing, PairingHistory, PairingOverride, PairingWarning, RoundPairings,
+    ScheduledEntrant,
+};
+use uuid::Uuid;
+
+use integration_testing::port_fakes::*;
+
+fn round_of_three() -> ([Uuid; 6], RoundPairings) {
+    let ids: [Uuid; 6] = std::array::from_fn(|_| Uuid::new_v4());
+    let pairings = RoundPairings::new(vec![
+        Pairing {
+            entrant_a: ids[0],
+            entrant_b: Some(ids[1]),
+        },
+        Pairing {
+            entrant_a: ids[2],
+            entrant_b: Some(ids[3]),
+        },
+        Pairing {
+            entrant_a: ids[4],
+            entrant_b: Some(ids[5]),
+        },
+    ]);
+    (ids, pairings)
+}
+
+fn pairing_override(t_id: Uuid, stage_id: Uuid) -> PairingOverride {
+    let mut o = PairingOverride::default();
+    o.set_tournament_id(t_id)
+        .set_stage_id(stage_id)
+        .set_round_number(1)
+        .set_author("Director")
+        .set_note("Entrants of same club should not meet in round 2");
+    o
+}
+
+/// 1) override with rematch is persisted and returns a warning instead of failing
+#[tokio::test]
+async fn given_rematch_when_override_then_persisted_with_warning() {
+    let (core, _db, t_id, stage_id) = make_core_pairing_with_fakes();
+    let (ids, pairings) = round_of_three();
+    let mut history = PairingHistory::default();
+    history.add_match(ids[0], ids[3]);
+
+    let mut o = pairing_override(t_id, stage_id);
+    o.add_adjustment(ids[1], ids[3]);
+    let adjusted = core
+        .override_pairings(&pairings, &history, &o)
+        .await
+        .expect("override should succeed");
+
+    assert_eq!(adjusted.pairings.pairings[0].entrant_b, Some(ids[3]));
+    assert_eq!(
+        adjusted.warnings,
+        vec![PairingWarning::Rematch {
+            entrant_a: ids[0],
+            entrant_b: ids[3]
+        }]
+    );
+    assert_eq!(adjusted.pairing_override.get_version(), Some(0));
+    assert!(adjusted.pairing_override.get_created_at().is_some());
+}
+
+/// 2) persisted overrides are re-applied in order to generated pairings
+#[tokio::test]
+async fn given_persisted_overrides_when_apply_then_pairings_are_adjusted_in_order() {
+    let (core, _db, t_id, stage_id) = make_core_pairing_with_fakes();
+    let (ids, pairings) = round_of_three();
+    let history = PairingHistory::default();
+
+    let mut first = pairing_override(t_id, stage_id);
+    first.add_adjustment(ids[1], ids[3]);
+    let adjusted = core
+        .override_pairings(&pairings, &history, &first)
+        .await
+        .unwrap();
+    let mut second = pairing_override(t_id, stage_id);
+    second.add_adjustment(ids[3], ids[5]);
+    let adjusted = core
+        .override_pairings(&adjusted.pairings, &history, &second)
+        .await
+        .unwrap();
+
+    let reapplied = core
+        .apply_pairing_overrides(stage_id, 1, pairings.clone())
+        .await
+        .unwrap();
+    assert_eq!(reapplied, adjusted.pairings);
+    assert_eq!(reapplied.pairings[0].entrant_b, Some(ids[5]));
+
+    // other rounds are not affected
+    let other_round = core
+        .apply_pairing_overrides(stage_id, 2, pairings.clone())
+        .await
+        .unwrap();
+    assert_eq!(other_round, pairings);
+}
+
+/// 3) override without audit note or with unknown entrant is rejected and not persisted
+#[tokio::test]
+async fn given_invalid_override_when_override_then_validation_error() {
+    let (core, _db, t_id, stage_id) = make_core_pairing_with_fakes();
+    let (ids, pairings) = round_of_three();
+    let history = PairingHistory::default();
+
+    let mut without_note = pairing_override(t_id, stage_id);
+    without_note.set_note("  ").add_adjustment(ids[0], ids[2]);
+    let err = core
+        .override_pairings(&pairings, &history, &without_note)
+        .await
+        .unwrap_err();
+    assert!(matches!(err, CoreError::Validation(_)));
+
+    let mut unknown = pairing_override(t_id, stage_id);
+    unknown.add_adjustment(ids[0], Uuid::new_v4());
+    let err = core
+        .override_pairings(&pairings, &history, &unknown)
+        .await
+        .unwrap_err();
+    assert!(matches!(err, CoreError::Validation(_)));
+
+    let mut foreign_stage = pairing_override(t_id, Uuid::new_v4());
+    foreign_stage.add_adjustment(ids[0], ids[2]);
+    let err = core
+        .override_pairings(&pairings, &history, &foreign_stage)
+        .await
+        .unwrap_err();
+    assert!(matches!(err, CoreError::Validation(_)));
+
+    let persisted = core
+        .apply_pairing_overrides(stage_id, 1, pairings.clone())
+        .await
+        .unwrap();
+    assert_eq!(persisted, pairings);
+}
+
+/// 4) override of a round with a played match is rejected and not persisted
+#[tokio::test]
+async fn given_played_match_in_round_when_override_then_validation_error() {
+    let (core, db, t_id, stage_id) = make_core_pairing_with_fakes();
+    let (ids, pairings) = round_of_three();
+    let history = PairingHistory::default();
+    let mut played = Match::new_scheduled(
+        Uuid::new_v4(),
+        Uuid::new_v4(),
+        Uuid::new_v4(),
+        1,
+        ScheduledEntrant::Entrant(ids[0]),
+        ScheduledEntrant::Entrant(ids[1]),
+    );
+    played
+        .set_tournament(t_id, Uuid::new_v4(), stage_id)
+        .set_scores(vec![11], vec![5]);
+    db.seed_matches(vec![played]);
+
+    let mut o = pairing_override(t_id, stage_id);
+    o.add_adjustment(ids[1], ids[3]);
+    let err = core
+        .override_pairings(&pairings, &history, &o)
+        .await
+        .unwrap_err();
+    assert!(matches!(err, CoreError::Validation(_)));
+
+    // the next round has not started yet
+    o.set_round_number(2);
+    core.override_pairings(&pairings, &history, &o)
+        .await
+        .expect("override of next round should succeed");
+    let persisted = core
+        .apply_pairing_overrides(stage_id, 1, pairings.clone())
+        .await
+        .unwrap();
+    assert_eq!(persisted, pairings);
+}
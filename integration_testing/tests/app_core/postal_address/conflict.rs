@@ -0,0 +1,90 @@
+use app_core::{ConflictResolution, Core, MergeFields, PostalAddress, PostalAddressState};
+use integration_testing::port_fakes::*;
+use isocountry::CountryCode;
+
+/// saves an address and a concurrent edit of its street; returns the stale local copy
+async fn seed_concurrent_edit(core: &mut Core<PostalAddressState>) -> PostalAddress {
+    core.get_mut()
+        .set_name("Gamma")
+        .set_street("Street 3")
+        .set_postal_code("10117")
+        .set_locality("Berlin")
+        .set_region("BE")
+        .set_country(Some(CountryCode::DEU));
+    let stale = core.save().await.expect("seed save").clone();
+
+    // somebody else changes the street in the meantime
+    core.get_mut().set_street("Street 4");
+    core.save().await.expect("concurrent save");
+
+    stale
+}
+
+/// 1) save(): stale version → version conflict with current server copy
+#[tokio::test]
+async fn given_stale_version_when_save_then_server_copy_is_returned() {
+    let (mut core, _db_fake, _cr_fake) = make_core_postal_address_state_with_fakes();
+    let mut mine = seed_concurrent_edit(&mut core).await;
+    mine.set_locality("Potsdam");
+
+    // Act
+    *core.get_mut() = mine.clone();
+    let err = core.save().await.expect_err("expected conflict");
+
+    // Assert
+    assert!(err.is_optimistic_lock_conflict());
+    let theirs = err
+        .get_server_copy()
+        .and_then(|copy| copy.as_postal_address())
+        .expect("server copy of postal address");
+    assert_eq!(theirs.get_version(), Some(1));
+    assert_eq!(theirs.get_street(), "Street 4");
+    let fields = mine
+        .conflicting_fields(theirs)
+        .into_iter()
+        .map(|f| f.field)
+        .collect::<Vec<_>>();
+    assert_eq!(fields, vec!["street", "locality"]);
+}
+
+/// 2) merged copy has the server version and can be saved
+#[tokio::test]
+async fn given_conflict_when_merge_then_merged_copy_is_saved() {
+    let (mut core, _db_fake, _cr_fake) = make_core_postal_address_state_with_fakes();
+    let mut mine = seed_concurrent_edit(&mut core).await;
+    mine.set_locality("Potsdam");
+    *core.get_mut() = mine.clone();
+    let err = core.save().await.expect_err("expected conflict");
+    let theirs = err
+        .get_server_copy()
+        .and_then(|copy| copy.as_postal_address())
+        .expect("server copy of postal address")
+        .clone();
+
+    // Act: keep my locality, take their street
+    let merged = ConflictResolution::Merge(vec![String::from("locality")]).resolve(&mine, &theirs);
+    *core.get_mut() = merged;
+    let saved = core.save().await.expect("merged save").clone();
+
+    // Assert
+    assert_eq!(saved.get_version(), Some(2));
+    assert_eq!(saved.get_street(), "Street 4");
+    assert_eq!(saved.get_locality(), "Potsdam");
+}
+
+/// 3) keep mine overwrites, take theirs discards all local changes
+#[tokio::test]
+async fn given_conflict_when_keep_mine_or_take_theirs_then_fields_follow_choice() {
+    let (mut core, _db_fake, _cr_fake) = make_core_postal_address_state_with_fakes();
+    let mut mine = seed_concurrent_edit(&mut core).await;
+    mine.set_locality("Potsdam");
+    let theirs = core.get().clone();
+
+    let kept = ConflictResolution::KeepMine.resolve(&mine, &theirs);
+    let taken = ConflictResolution::TakeTheirs.resolve(&mine, &theirs);
+
+    assert_eq!(kept.get_version(), theirs.get_version());
+    assert_eq!(kept.get_street(), "Street 3");
+    assert_eq!(kept.get_locality(), "Potsdam");
+    assert_eq!(taken, theirs);
+}
@@ -1,4 +1,7 @@
-use app_core::{CoreError, DbError};
+use app_core::{
+    CoreError, DbError, PostalAddressSortColumn,
+    utils::list_order::{ListOrder, SortDirection},
+};
 use integration_testing::port_fakes::*;
 use isocountry::CountryCode;
 use uuid::Uuid;
@@ -162,7 +165,7 @@ async fn given_filter_and_limit_when_list_addresses_then_db_fake_results_are_for
 
     // Act
     let got = core
-        .list_address_ids(Some("ma"), Some(2))
+        .list_address_ids(Some("ma"), Some(2), &ListOrder::default())
         .await
         .expect("db ok");
 
@@ -193,7 +196,10 @@ async fn given_only_limit_when_list_addresses_then_limit_is_respected() {
         core.save().await.expect("seed save");
     }
 
-    let got = core.list_address_ids(None, Some(3)).await.expect("db ok");
+    let got = core
+        .list_address_ids(None, Some(3), &ListOrder::default())
+        .await
+        .expect("db ok");
     assert_eq!(got.len(), 3);
 }
 
@@ -206,7 +212,7 @@ async fn given_db_fake_failure_when_list_addresses_then_error_propagates() {
     db_fake.fail_list_pa_once();
 
     let err = core
-        .list_address_ids(None, None)
+        .list_address_ids(None, None, &ListOrder::default())
         .await
         .expect_err("expected DB error");
 
@@ -216,3 +222,38 @@ async fn given_db_fake_failure_when_list_addresses_then_error_propagates() {
         other => panic!("unexpected error variant: {other:?}"),
     }
 }
+
+/// 9) list_addresses(): pages sorted by column and direction neither overlap nor miss rows
+#[tokio::test]
+async fn given_sort_and_offset_when_list_addresses_then_pages_are_consecutive() {
+    let (mut core, _db_fake, _cr_fake) = make_core_postal_address_state_with_fakes();
+
+    for (nm, pc) in [
+        ("Anna", "10117"),
+        ("Bert", "10115"),
+        ("Carl", "10119"),
+        ("Dora", "10116"),
+        ("Emil", "10118"),
+    ] {
+        *core.get_mut() = make_addr(nm, "S", pc, "Berlin", "BE", CountryCode::DEU);
+        core.save().await.expect("seed save");
+    }
+
+    let order = ListOrder::new().sort_by(
+        PostalAddressSortColumn::PostalCode,
+        SortDirection::Descending,
+    );
+    let mut names = Vec::new();
+    for offset in [0, 2, 4] {
+        let page = core
+            .list_address_ids(None, Some(2), &order.offset(offset))
+            .await
+            .expect("db ok");
+        for id in page {
+            let pa = core.load(id).await.expect("load ok").expect("listed id");
+            names.push(pa.get_name().to_string());
+        }
+    }
+
+    assert_eq!(names, vec!["Carl", "Emil", "Anna", "Dora", "Bert"]);
+}
@@ -0,0 +1,188 @@
+use app_core::{DbpPostalAddress, GeoPoint};
+use integration_testing::port_fakes::*;
+use isocountry::CountryCode;
+use std::sync::Arc;
+
+fn berlin() -> GeoPoint {
+    GeoPoint::new(52.52, 13.405)
+}
+
+fn hamburg() -> GeoPoint {
+    GeoPoint::new(53.5511, 9.9937)
+}
+
+fn make_geocoding() -> FakeGeocodingPort {
+    FakeGeocodingPort::new()
+        .with_locality("Berlin", berlin())
+        .with_locality("Hamburg", hamburg())
+}
+
+/// 1) save(): address is geocoded
+#[tokio::test]
+async fn given_geocoding_when_save_then_location_is_stored() {
+    let (mut core, _db_fake, _cr_fake) = make_core_postal_address_state_with_fakes();
+    let geocoding = make_geocoding();
+    core.geocoding = Some(Arc::new(geocoding.clone()));
+
+    *core.get_mut() = make_addr(
+        "Hall",
+        "Street 1",
+        "10115",
+        "Berlin",
+        "BE",
+        CountryCode::DEU,
+    );
+    let saved = core.save().await.expect("save ok").clone();
+
+    assert_eq!(saved.get_location(), Some(berlin()));
+    assert_eq!(geocoding.requests(), 1);
+}
+
+/// 2) save(): same place keeps location without geocoding again; a new place is geocoded
+#[tokio::test]
+async fn given_geocoded_address_when_save_changes_then_only_new_places_are_geocoded() {
+    let (mut core, _db_fake, _cr_fake) = make_core_postal_address_state_with_fakes();
+    let geocoding = make_geocoding();
+    core.geocoding = Some(Arc::new(geocoding.clone()));
+
+    *core.get_mut() = make_addr(
+        "Hall",
+        "Street 1",
+        "10115",
+        "Berlin",
+        "BE",
+        CountryCode::DEU,
+    );
+    core.save().await.expect("save ok");
+
+    // renaming does not change the place
+    core.get_mut().set_name("Main Hall");
+    let renamed = core.save().await.expect("save ok").clone();
+    assert_eq!(renamed.get_location(), Some(berlin()));
+    assert_eq!(geocoding.requests(), 1);
+
+    // moving to another city does
+    core.get_mut()
+        .set_postal_code("20095")
+        .set_locality("Hamburg")
+        .set_region("HH");
+    let moved = core.save().await.expect("save ok").clone();
+    assert_eq!(moved.get_location(), Some(hamburg()));
+    assert_eq!(geocoding.requests(), 2);
+
+    // unknown places have no location
+    core.get_mut()
+        .set_postal_code("80331")
+        .set_locality("München");
+    let unknown = core.save().await.expect("save ok").clone();
+    assert_eq!(unknown.get_location(), None);
+}
+
+/// 3) save(): failed geocoding does not prevent saving
+#[tokio::test]
+async fn given_geocoding_failure_when_save_then_saved_without_location() {
+    let (mut core, db_fake, _cr_fake) = make_core_postal_address_state_with_fakes();
+    let geocoding = make_geocoding();
+    geocoding.fail_geocode_once();
+    core.geocoding = Some(Arc::new(geocoding.clone()));
+
+    *core.get_mut() = make_addr(
+        "Hall",
+        "Street 1",
+        "10115",
+        "Berlin",
+        "BE",
+        CountryCode::DEU,
+    );
+    let saved = core.save().await.expect("save ok").clone();
+
+    assert_eq!(saved.get_location(), None);
+    let stored = db_fake
+        .get_postal_address(saved.get_id())
+        .await
+        .expect("db ok")
+        .expect("address is saved");
+    assert_eq!(stored.get_location(), None);
+
+    // next save retries geocoding
+    let retried = core.save().await.expect("save ok").clone();
+    assert_eq!(retried.get_location(), Some(berlin()));
+}
+
+/// 4) save(): without geocoding port addresses have no location, even if the client sends one
+#[tokio::test]
+async fn given_no_geocoding_when_save_then_location_is_none() {
+    let (mut core, _db_fake, _cr_fake) = make_core_postal_address_state_with_fakes();
+
+    *core.get_mut() = make_addr(
+        "Hall",
+        "Street 1",
+        "10115",
+        "Berlin",
+        "BE",
+        CountryCode::DEU,
+    );
+    core.get_mut().set_location(Some(hamburg()));
+    let saved = core.save().await.expect("save ok").clone();
+
+    assert_eq!(saved.get_location(), None);
+}
+
+/// 5) tournament_distance_km(): distance between geocoded addresses
+#[tokio::test]
+async fn given_tournament_location_when_distance_then_km_between_addresses() {
+    let (mut core, _db_fake, _cr_fake) = make_core_tournament_base_state_with_fakes();
+    core.geocoding = Some(Arc::new(make_geocoding()));
+
+    let mut address_core = core.as_postal_address_state();
+    *address_core.get_mut() = make_addr(
+        "Hall",
+        "Street 1",
+        "20095",
+        "Hamburg",
+        "HH",
+        CountryCode::DEU,
+    );
+    let venue_id = address_core.save().await.expect("save ok").get_id();
+    *address_core.get_mut() = make_addr(
+        "Home",
+        "Street 2",
+        "10115",
+        "Berlin",
+        "BE",
+        CountryCode::DEU,
+    );
+    let home_id = address_core.save().await.expect("save ok").get_id();
+    *address_core.get_mut() = make_addr(
+        "Unknown",
+        "Street 3",
+        "80331",
+        "München",
+        "BY",
+        CountryCode::DEU,
+    );
+    let unknown_id = address_core.save().await.expect("save ok").get_id();
+
+    *core.get_mut() = make_tournament_base("Cup", &core);
+    let tournament_id = core.save().await.expect("save ok").get_id();
+    let distance = core
+        .tournament_distance_km(tournament_id, home_id)
+        .await
+        .expect("db ok");
+    assert_eq!(distance, None, "tournament without location");
+
+    core.get_mut().set_address_id(Some(venue_id));
+    core.save().await.expect("save ok");
+    let distance = core
+        .tournament_distance_km(tournament_id, home_id)
+        .await
+        .expect("db ok")
+        .expect("both addresses are geocoded");
+    assert!((distance - 255.0).abs() < 5.0, "distance was {distance}");
+
+    let distance = core
+        .tournament_distance_km(tournament_id, unknown_id)
+        .await
+        .expect("db ok");
+    assert_eq!(distance, None);
+}
@@ -1,4 +1,6 @@
 //! testing core api for postal address with fake
 
+mod conflict;
 mod db_wrapper;
+mod geocoding;
 mod registry_wrapper;
@@ -0,0 +1,87 @@
+//! testing app core api for presence of editors with fakes
+
+use app_core::{CrMsg, PresenceEditor};
+use uuid::Uuid;
+
+use integration_testing::port_fakes::*;
+
+/// 1) join_presence(): new editors are listed in order of joining and announced
+#[tokio::test]
+async fn given_two_editors_when_join_presence_then_listed_and_announced() {
+    let (core, _db, cr, _spm) = make_core_with_fakes();
+    let object_id = Uuid::new_v4();
+    let jane = PresenceEditor::new(Uuid::new_v4(), "Jane");
+    let joe = PresenceEditor::new(Uuid::new_v4(), "Joe");
+
+    core.join_presence(object_id, jane.clone())
+        .await
+        .expect("join should succeed");
+    let editors = core
+        .join_presence(object_id, joe.clone())
+        .await
+        .expect("join should succeed");
+
+    assert_eq!(editors, vec![jane, joe]);
+    assert_eq!(
+        cr.published(),
+        vec![
+            CrMsg::PresenceChanged {
+                id: object_id,
+                version: 1
+            },
+            CrMsg::PresenceChanged {
+                id: object_id,
+                version: 2
+            },
+        ]
+    );
+}
+
+/// 2) join_presence(): heartbeat of a known editor is not announced
+#[tokio::test]
+async fn given_joined_editor_when_join_presence_again_then_no_message() {
+    let (core, _db, cr, _spm) = make_core_with_fakes();
+    let object_id = Uuid::new_v4();
+    let jane = PresenceEditor::new(Uuid::new_v4(), "  Jane  ");
+    core.join_presence(object_id, jane.clone())
+        .await
+        .expect("join should succeed");
+    cr.clear();
+
+    let editors = core
+        .join_presence(object_id, jane.clone())
+        .await
+        .expect("join should succeed");
+
+    // name is normalized
+    assert_eq!(editors[0].name, "Jane");
+    assert!(cr.published().is_empty());
+}
+
+/// 3) leave_presence(): editor is removed and remaining editors are announced
+#[tokio::test]
+async fn given_joined_editor_when_leave_presence_then_removed_and_announced() {
+    let (core, _db, cr, _spm) = make_core_with_fakes();
+    let object_id = Uuid::new_v4();
+    let jane = PresenceEditor::new(Uuid::new_v4(), "Jane");
+    core.join_presence(object_id, jane.clone())
+        .await
+        .expect("join should succeed");
+    cr.clear();
+
+    core.leave_presence(object_id, jane.client_id)
+        .await
+        .expect("leave should succeed");
+    // leaving twice is no change
+    core.leave_presence(object_id, jane.client_id)
+        .await
+        .expect("leave should succeed");
+
+    assert_eq!(
+        cr.published(),
+        vec![CrMsg::PresenceChanged {
+            id: object_id,
+            version: 0
+        }]
+    );
+}
@@ -0,0 +1,276 @@
+//! testing app core api for corrections of final scores with fakes
+
+use app_core::{
+    Core, CoreBuilder, CoreError, CrMsg, DomainEvent, InitState, Match, ScheduledEntrant,
+    ScoreCorrection, SportConfig, SportPluginManagerMap, Stage, TournamentBase, TournamentState,
+    utils::{id_version::IdVersion, traits::ObjectIdVersion},
+};
+use generic_sport_plugin::{GenericSportPlugin, config::GenericSportConfig};
+use std::sync::Arc;
+use uuid::Uuid;
+
+use integration_testing::port_fakes::*;
+
+struct Bracket {
+    tournament: TournamentBase,
+    stage: Stage,
+    config: SportConfig,
+    group_id: Uuid,
+    entrants: Vec<Uuid>,
+    /// semi finals, final and match for third place
+    matches: Vec<Match>,
+}
+
+fn make_core_with_generic_sport() -> (
+    Core<InitState>,
+    Arc<FakeDatabasePort>,
+    Arc<FakeClientRegistryPort>,
+    Arc<FakeDomainEventPort>,
+    Uuid,
+) {
+    let (core, db_fake, cr_fake, _spm) = make_core_with_fakes();
+    let spm = SportPluginManagerMap::new();
+    let plugin = Arc::new(GenericSportPlugin::new());
+    let sport_id = plugin.get_id_version().get_id();
+    spm.register(plugin).unwrap();
+    let ev_fake = Arc::new(FakeDomainEventPort::new());
+    let core = CoreBuilder::new()
+        .set_db(db_fake.clone())
+        .set_cr(cr_fake.clone())
+        .set_spm(Arc::new(spm))
+        .set_wh(core.webhooks.clone())
+        .set_em(core.email.clone())
+        .set_bs(core.blobs.clone())
+        .set_ev(ev_fake.clone())
+        .build();
+    (core, db_fake, cr_fake, ev_fake, sport_id)
+}
+
+/// KO bracket of 4 entrants with played semi finals: entrant 0 beats 1, entrant 2 beats 3
+async fn played_semi_finals(core: &Core<InitState>, sport_id: Uuid) -> Bracket {
+    let tournament = TournamentBase::new(IdVersion::new(Uuid::new_v4(), Some(0)));
+    let stage = Stage::new(IdVersion::new(Uuid::new_v4(), Some(0)));
+    let mut config = SportConfig::default();
+    config
+        .set_sport_id(sport_id)
+        .set_config(serde_json::to_value(GenericSportConfig::default()).unwrap());
+    let group_id = Uuid::new_v4();
+    let entrants: Vec<Uuid> = (0..4).map(|_| Uuid::new_v4()).collect();
+    let new_match = |side_a, side_b| {
+        let mut m =
+            Match::new_scheduled(Uuid::new_v4(), group_id, Uuid::new_v4(), 1, side_a, side_b);
+        m.set_tournament(tournament.get_id(), sport_id, stage.get_id());
+        m
+    };
+    let mut semi_1 = new_match(
+        ScheduledEntrant::Entrant(entrants[0]),
+        ScheduledEntrant::Entrant(entrants[1]),
+    );
+    semi_1.set_scores(vec![11], vec![5]);
+    let mut semi_2 = new_match(
+        ScheduledEntrant::Entrant(entrants[2]),
+        ScheduledEntrant::Entrant(entrants[3]),
+    );
+    semi_2.set_scores(vec![11], vec![3]);
+    let last = new_match(
+        ScheduledEntrant::WinnerOf(*semi_1.get_id()),
+        ScheduledEntrant::WinnerOf(*semi_2.get_id()),
+    );
+    let third = new_match(
+        ScheduledEntrant::LoserOf(*semi_1.get_id()),
+        ScheduledEntrant::LoserOf(*semi_2.get_id()),
+    );
+    let mut matches = vec![semi_1, semi_2, last, third];
+    for semi in 0..2 {
+        let decided = matches[semi].clone();
+        core.resolve_match_dependencies(&config, &decided, &mut matches)
+            .await
+            .expect("publish ok");
+    }
+    assert_eq!(
+        matches[2].get_entrants(),
+        Some((&entrants[0], &entrants[2]))
+    );
+    Bracket {
+        tournament,
+        stage,
+        config,
+        group_id,
+        entrants,
+        matches,
+    }
+}
+
+fn correction(m: &Match, score_a: u16, score_b: u16) -> ScoreCorrection {
+    ScoreCorrection {
+        match_id: *m.get_id(),
+        score_a: vec![score_a],
+        score_b: vec![score_b],
+    }
+}
+
+/// 1) preview and apply: changed winner moves entrants of final and match for third place
+#[tokio::test]
+async fn given_changed_winner_when_apply_correction_then_dependent_matches_resolved_again() {
+    let (core, db_fake, cr_fake, ev_fake, sport_id) = make_core_with_generic_sport();
+    let mut bracket = played_semi_finals(&core, sport_id).await;
+    let semi_1 = bracket.matches[0].clone();
+    let (last, third) = (*bracket.matches[2].get_id(), *bracket.matches[3].get_id());
+    cr_fake.clear();
+
+    let impact = core
+        .preview_score_correction(
+            &bracket.tournament,
+            &bracket.stage,
+            &bracket.config,
+            &correction(&semi_1, 5, 11),
+            &bracket.matches,
+        )
+        .expect("valid correction");
+
+    assert!(impact.winner_changed);
+    assert!(!impact.is_local());
+    let mut expected = vec![last, third];
+    expected.sort();
+    assert_eq!(impact.rescheduled_matches, expected);
+    assert!(impact.decided_matches.is_empty());
+    assert_eq!(impact.rank_changes.len(), 2);
+    // preview changes nothing
+    assert!(cr_fake.published().is_empty());
+    assert_eq!(bracket.matches[0], semi_1);
+
+    let applied = core
+        .apply_score_correction(
+            &bracket.tournament,
+            &bracket.stage,
+            &bracket.config,
+            &impact,
+            &mut bracket.matches,
+        )
+        .await
+        .expect("confirmed impact");
+
+    assert_eq!(applied, impact);
+    assert_eq!(bracket.matches[0].get_scores(), (&vec![5], &vec![11]));
+    assert_eq!(
+        bracket.matches[2].get_entrants(),
+        Some((&bracket.entrants[1], &bracket.entrants[2]))
+    );
+    assert_eq!(
+        bracket.matches[3].get_entrants(),
+        Some((&bracket.entrants[0], &bracket.entrants[3]))
+    );
+    let cached = db_fake
+        .group_standings_of(bracket.group_id)
+        .expect("standings cached");
+    assert_eq!(cached.standings.len(), 4);
+    // corrected and resolved matches are saved; the other semi final is unchanged
+    let saved = db_fake.matches_of(bracket.tournament.get_id());
+    assert_eq!(saved.len(), 3);
+    for m in [
+        &bracket.matches[0],
+        &bracket.matches[2],
+        &bracket.matches[3],
+    ] {
+        assert!(saved.contains(m));
+    }
+    assert!(cr_fake.published().contains(&CrMsg::MatchUpdated {
+        id: *semi_1.get_id(),
+        version: 0
+    }));
+    assert!(
+        ev_fake
+            .emitted()
+            .contains(&DomainEvent::MatchScoreCorrected {
+                tournament_id: bracket.tournament.get_id(),
+                match_id: *semi_1.get_id(),
+                winner_changed: true,
+                num_affected_matches: 2,
+            })
+    );
+}
+
+/// 2) preview: correction without new winner or ranks only changes the score
+#[tokio::test]
+async fn given_same_winner_when_preview_correction_then_impact_is_local() {
+    let (core, _db_fake, _cr_fake, _ev_fake, sport_id) = make_core_with_generic_sport();
+    let bracket = played_semi_finals(&core, sport_id).await;
+
+    let impact = core
+        .preview_score_correction(
+            &bracket.tournament,
+            &bracket.stage,
+            &bracket.config,
+            &correction(&bracket.matches[0], 11, 9),
+            &bracket.matches,
+        )
+        .expect("valid correction");
+
+    assert!(impact.is_local());
+    assert!(!impact.promotion_changed);
+}
+
+/// 3) apply: impact, which changed since preview, must be confirmed again
+#[tokio::test]
+async fn given_changed_impact_when_apply_correction_then_error_and_nothing_changed() {
+    let (core, _db_fake, _cr_fake, _ev_fake, sport_id) = make_core_with_generic_sport();
+    let mut bracket = played_semi_finals(&core, sport_id).await;
+    let semi_1 = bracket.matches[0].clone();
+    let impact = core
+        .preview_score_correction(
+            &bracket.tournament,
+            &bracket.stage,
+            &bracket.config,
+            &correction(&semi_1, 5, 11),
+            &bracket.matches,
+        )
+        .expect("valid correction");
+
+    // final is played in the meantime
+    bracket.matches[2].set_scores(vec![11], vec![7]);
+    let err = core
+        .apply_score_correction(
+            &bracket.tournament,
+            &bracket.stage,
+            &bracket.config,
+            &impact,
+            &mut bracket.matches,
+        )
+        .await
+        .unwrap_err();
+
+    assert!(matches!(err, CoreError::Field(_)));
+    assert_eq!(bracket.matches[0], semi_1);
+}
+
+/// 4) preview: undecided matches and completed stages are outside of the correction window
+#[tokio::test]
+async fn given_outside_of_correction_window_when_preview_correction_then_error() {
+    let (core, _db_fake, _cr_fake, _ev_fake, sport_id) = make_core_with_generic_sport();
+    let mut bracket = played_semi_finals(&core, sport_id).await;
+
+    let err = core
+        .preview_score_correction(
+            &bracket.tournament,
+            &bracket.stage,
+            &bracket.config,
+            &correction(&bracket.matches[2], 11, 7),
+            &bracket.matches,
+        )
+        .unwrap_err();
+    assert!(matches!(err, CoreError::Field(_)));
+
+    bracket
+        .tournament
+        .set_tournament_state(TournamentState::Finished);
+    let err = core
+        .preview_score_correction(
+            &bracket.tournament,
+            &bracket.stage,
+            &bracket.config,
+            &correction(&bracket.matches[0], 5, 11),
+            &bracket.matches,
+        )
+        .unwrap_err();
+    assert!(matches!(err, CoreError::Field(_)));
+}
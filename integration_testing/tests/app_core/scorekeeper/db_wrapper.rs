@@ -0,0 +1,192 @@
+use app_core::{CoreError, CrMsg, DbError, Entrant, Match, ScorekeeperGrant};
+use chrono::{Duration, Local, Utc};
+use uuid::Uuid;
+
+use integration_testing::port_fakes::*;
+
+/// 1) create(): secret of access link authenticates and grants only the chosen group
+#[tokio::test]
+async fn given_new_token_when_create_then_link_grants_group() {
+    let (mut core, _db_fake, cr_fake) = make_core_scorekeeper_token_state_with_fakes();
+
+    core.get_mut()
+        .set_label("Court A")
+        .set_grants([ScorekeeperGrant::Group {
+            stage_number: 0,
+            group_index: 1,
+        }]);
+    let (token, secret) = core.create().await.expect("create should succeed");
+    let token = token.clone();
+
+    assert_eq!(token.get_version(), Some(0));
+    assert!(secret.starts_with(token.get_secret_prefix()));
+    assert_ne!(token.get_secret_hash(), secret);
+    assert_eq!(
+        cr_fake.published(),
+        vec![CrMsg::ScorekeeperTokenUpdated {
+            id: token.get_id(),
+            version: 0
+        }]
+    );
+
+    let authenticated = core
+        .authenticate_scorekeeper(&secret)
+        .await
+        .unwrap()
+        .expect("token is valid");
+    assert!(authenticated.allows_match(0, 1, None));
+    assert!(!authenticated.allows_match(0, 0, None));
+
+    let access = core
+        .load_scorekeeper_access(&secret)
+        .await
+        .unwrap()
+        .expect("access is valid");
+    assert_eq!(access.tournament_name, "Scorekeeper Tournament");
+    assert_eq!(access.grants, token.get_grants());
+
+    // api token secrets are no scorekeeper secrets
+    assert!(
+        core.authenticate_scorekeeper(&secret.replacen("fks_", "fkt_", 1))
+            .await
+            .unwrap()
+            .is_none()
+    );
+}
+
+/// 2) revoke() and expiry: link is rejected afterwards
+#[tokio::test]
+async fn given_revoked_or_expired_token_when_authenticate_then_rejected() {
+    let (mut core, _db_fake, _cr_fake) = make_core_scorekeeper_token_state_with_fakes();
+
+    core.get_mut()
+        .set_label("Station 1")
+        .set_grants([ScorekeeperGrant::Station { station: 1 }]);
+    let (_, secret) = core.create().await.unwrap();
+    let revoked = core.revoke().await.unwrap().clone();
+
+    assert!(revoked.is_revoked());
+    assert_eq!(revoked.get_version(), Some(1));
+    assert!(
+        core.authenticate_scorekeeper(&secret)
+            .await
+            .unwrap()
+            .is_none()
+    );
+    assert!(core.list_tokens(false).await.unwrap().is_empty());
+    assert_eq!(core.list_tokens(true).await.unwrap().len(), 1);
+
+    let mut core = core.as_scorekeeper_token_state(revoked.get_tournament_id());
+    core.get_mut()
+        .set_label("Station 2")
+        .set_grants([ScorekeeperGrant::Station { station: 2 }])
+        .set_expires_at(Some(Utc::now() - Duration::minutes(1)));
+    let (_, secret) = core.create().await.unwrap();
+    assert!(
+        core.load_scorekeeper_access(&secret)
+            .await
+            .unwrap()
+            .is_none()
+    );
+}
+
+/// 3) create(): groups must exist in the stages of the tournament
+#[tokio::test]
+async fn given_unknown_group_when_create_then_field_error() {
+    let (mut core, _db_fake, cr_fake) = make_core_scorekeeper_token_state_with_fakes();
+
+    core.get_mut()
+        .set_label("Group E")
+        .set_grants([ScorekeeperGrant::Group {
+            stage_number: 0,
+            group_index: 4,
+        }]);
+    let err = core.create().await.unwrap_err();
+
+    let field_error = err.get_field_error().expect("expecting field error");
+    assert_eq!(field_error.get_field(), "grants");
+    assert_eq!(field_error.get_code(), "unknown_group");
+    assert!(cr_fake.published().is_empty());
+}
+
+/// 4) load(): tokens of other tournaments are not loaded
+#[tokio::test]
+async fn given_token_of_other_tournament_when_load_then_none() {
+    let (mut core, _db_fake, _cr_fake) = make_core_scorekeeper_token_state_with_fakes();
+
+    core.get_mut()
+        .set_label("Court B")
+        .set_grants([ScorekeeperGrant::Station { station: 2 }]);
+    let (token, _) = core.create().await.unwrap();
+    let token_id = token.get_id();
+
+    let mut other = core.as_scorekeeper_token_state(uuid::Uuid::new_v4());
+    assert!(other.load(token_id).await.unwrap().is_none());
+    assert!(core.load(token_id).await.unwrap().is_some());
+}
+
+/// 5) authenticate: db failure is propagated
+#[tokio::test]
+async fn given_db_failure_when_authenticate_then_error_is_propagated() {
+    let (core, db_fake, _cr_fake) = make_core_scorekeeper_token_state_with_fakes();
+
+    db_fake.fail_get_scorekeeper_once();
+    let err = core
+        .authenticate_scorekeeper("fks_secret")
+        .await
+        .unwrap_err();
+
+    assert!(matches!(err, CoreError::Db(DbError::Other(_))));
+}
+
+/// 6) list_scorekeeper_matches(): only matches of the grant waiting for their result
+#[tokio::test]
+async fn given_station_grant_when_list_scorekeeper_matches_then_open_matches_of_station() {
+    let (mut core, db_fake, _cr_fake) = make_core_scorekeeper_token_state_with_fakes();
+    let grant = ScorekeeperGrant::Station { station: 2 };
+    core.get_mut().set_label("Court B").set_grants([grant]);
+    let (token, secret) = core.create().await.unwrap();
+    let tournament_id = token.get_tournament_id();
+    let mut entrant_ids = Vec::new();
+    for name in ["Team A", "Team B"] {
+        let mut entrant = Entrant::default();
+        entrant.set_tournament_id(tournament_id).set_name(name);
+        entrant_ids.push(db_fake.seed_entrant(entrant));
+    }
+    let new_match = |station, score_a: Vec<u16>, score_b: Vec<u16>| {
+        let sport_id = Uuid::new_v4();
+        let mut m = Match::new_played(
+            Uuid::new_v4(),
+            entrant_ids[0],
+            entrant_ids[1],
+            sport_id,
+            score_a,
+            score_b,
+        );
+        m.set_tournament(tournament_id, sport_id, Uuid::new_v4())
+            .set_slot(station, Local::now());
+        m
+    };
+    let open = new_match(2, vec![], vec![]);
+    let decided = new_match(2, vec![11], vec![7]);
+    let other_station = new_match(1, vec![], vec![]);
+    db_fake.seed_matches(vec![open.clone(), decided, other_station]);
+
+    let matches = core
+        .list_scorekeeper_matches(&secret, grant)
+        .await
+        .unwrap()
+        .expect("grant is valid");
+
+    assert_eq!(matches.len(), 1);
+    assert_eq!(matches[0].match_id, *open.get_id());
+    assert_eq!(matches[0].station, 2);
+    assert_eq!(matches[0].entrant_a, "Team A");
+    assert_eq!(matches[0].entrant_b, "Team B");
+    assert!(
+        core.list_scorekeeper_matches(&secret, ScorekeeperGrant::Station { station: 1 })
+            .await
+            .unwrap()
+            .is_none()
+    );
+}
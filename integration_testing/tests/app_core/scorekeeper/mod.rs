@@ -0,0 +1,3 @@
+//! testing app core api for scorekeeper tokens with fakes
+
+mod db_wrapper;
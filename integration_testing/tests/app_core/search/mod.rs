@@ -0,0 +1,91 @@
+//! testing app core search across objects with fakes
+
+use app_core::{Entrant, SearchHitKind, utils::id_version::IdVersion};
+use chrono::Utc;
+use isocountry::CountryCode;
+use uuid::Uuid;
+
+use integration_testing::port_fakes::*;
+
+fn make_entrant(tournament_id: Uuid, name: &str, club: Option<&str>) -> Entrant {
+    let mut entrant = Entrant::new(IdVersion::default());
+    entrant
+        .set_tournament_id(tournament_id)
+        .set_name(name)
+        .set_club(club);
+    entrant
+}
+
+/// 1) search_all(): finds objects of all kinds, best matches first
+#[tokio::test]
+async fn given_objects_of_all_kinds_when_search_all_then_type_tagged_hits() {
+    let (core, db_fake, _cr_fake) = make_core_search_state_with_fakes();
+    let (tb_core, _, _) = make_core_tournament_base_state_with_fakes();
+    let (sc_core, _, _) = make_core_sport_config_state_with_fakes();
+
+    let t_id = db_fake.seed_tournament_base(make_tournament_base("Nord Cup", &tb_core));
+    let e_id = db_fake.seed_entrant(make_entrant(t_id, "Team A", Some("TV Nord")));
+    let c_id = db_fake.seed_sport_config(make_sport_config("Nord rules", &sc_core));
+    let a_id = db_fake.seed_postal_address(make_addr(
+        "Sports Hall",
+        "Nordstraße 1",
+        "12345",
+        "Hamburg",
+        "",
+        CountryCode::DEU,
+    ));
+
+    let hits = core
+        .search_all(" nord ")
+        .await
+        .expect("search should succeed");
+
+    let kinds: Vec<_> = hits.iter().map(|h| (h.kind, h.id)).collect();
+    assert_eq!(
+        kinds,
+        vec![
+            // prefix matches of label first
+            (SearchHitKind::Tournament, t_id),
+            (SearchHitKind::SportConfig, c_id),
+            (SearchHitKind::Entrant, e_id),
+            (SearchHitKind::PostalAddress, a_id),
+        ]
+    );
+    let entrant = &hits[2];
+    assert_eq!(entrant.tournament_id, Some(t_id));
+    assert_eq!(entrant.detail.as_deref(), Some("TV Nord"));
+    assert!(entrant.sport_id.is_some());
+}
+
+/// 2) search_all(): short terms find nothing
+#[tokio::test]
+async fn given_short_term_when_search_all_then_no_hits() {
+    let (core, db_fake, _cr_fake) = make_core_search_state_with_fakes();
+    let (tb_core, _, _) = make_core_tournament_base_state_with_fakes();
+    db_fake.seed_tournament_base(make_tournament_base("Nord Cup", &tb_core));
+
+    let hits = core.search_all(" n ").await.expect("search should succeed");
+
+    assert!(hits.is_empty());
+}
+
+/// 3) search_all(): archived and sandbox tournaments and their entrants are skipped
+#[tokio::test]
+async fn given_archived_and_sandbox_tournaments_when_search_all_then_skipped() {
+    let (core, db_fake, _cr_fake) = make_core_search_state_with_fakes();
+    let (tb_core, _, _) = make_core_tournament_base_state_with_fakes();
+
+    let mut archived = make_tournament_base("Cup archived", &tb_core);
+    archived.set_archived_at(Some(Utc::now()));
+    let archived_id = db_fake.seed_tournament_base(archived);
+    db_fake.seed_entrant(make_entrant(archived_id, "Cup Team", None));
+    let mut sandbox = make_tournament_base("Cup sandbox", &tb_core);
+    sandbox.set_sandbox(true);
+    db_fake.seed_tournament_base(sandbox);
+    let active_id = db_fake.seed_tournament_base(make_tournament_base("Cup active", &tb_core));
+
+    let hits = core.search_all("cup").await.expect("search should succeed");
+
+    let ids: Vec<_> = hits.iter().map(|h| h.id).collect();
+    assert_eq!(ids, vec![active_id]);
+}
@@ -0,0 +1,83 @@
+use app_core::{CoreError, CrMsg, DbError};
+use uuid::Uuid;
+
+use integration_testing::port_fakes::*;
+
+/// 1) save(): new entry is persisted with version 0 and publishes exactly once
+#[tokio::test]
+async fn given_new_entry_when_save_then_persisted_and_published() {
+    let (mut core, _db_fake, cr_fake) = make_core_shift_log_state_with_fakes();
+
+    core.get_mut()
+        .set_author("Desk A")
+        .set_text("Court 2 lights flicker.");
+    let saved = core.save().await.expect("save should succeed").clone();
+
+    assert_eq!(saved.get_version(), Some(0));
+    assert!(saved.get_created_at().is_some());
+    let notices = cr_fake.published();
+    assert_eq!(notices.len(), 1);
+    assert_eq!(
+        notices[0],
+        CrMsg::ShiftLogUpdated {
+            id: saved.get_id(),
+            version: 0
+        }
+    );
+}
+
+/// 2) save(): invalid entry is rejected before touching the db
+#[tokio::test]
+async fn given_empty_text_when_save_then_validation_error_and_no_publish() {
+    let (mut core, _db_fake, cr_fake) = make_core_shift_log_state_with_fakes();
+
+    core.get_mut().set_author("Desk A");
+    let err = core.save().await.unwrap_err();
+
+    assert!(matches!(err, CoreError::Validation(_)));
+    assert!(cr_fake.published().is_empty());
+}
+
+/// 3) pinning an entry makes it a handover note, listed first
+#[tokio::test]
+async fn given_pinned_entry_when_list_then_pinned_first_and_in_handover_notes() {
+    let (mut core, _db_fake, _cr_fake) = make_core_shift_log_state_with_fakes();
+    let t_id = core.get().get_tournament_id();
+
+    core.get_mut().set_author("Desk A").set_text("first note");
+    let first_id = core.save().await.unwrap().get_id();
+
+    // fresh entry for second note
+    let mut second = app_core::ShiftLogEntry::default();
+    second
+        .set_tournament_id(t_id)
+        .set_author("Desk B")
+        .set_text("second note");
+    *core.get_mut() = second;
+    core.save().await.unwrap();
+
+    // pin first note
+    core.load(first_id).await.unwrap().expect("entry exists");
+    core.get_mut().set_pinned(true);
+    let pinned = core.save().await.unwrap().clone();
+    assert_eq!(pinned.get_version(), Some(1));
+
+    let list = core.list_entries(false, None).await.unwrap();
+    assert_eq!(list.len(), 2);
+    assert_eq!(list[0].get_id(), first_id);
+
+    let notes = core.handover_notes().await.unwrap();
+    assert_eq!(notes.len(), 1);
+    assert_eq!(notes[0].get_text(), "first note");
+}
+
+/// 4) load(): db failure is propagated
+#[tokio::test]
+async fn given_db_failure_when_load_then_error_is_propagated() {
+    let (mut core, db_fake, _cr_fake) = make_core_shift_log_state_with_fakes();
+
+    db_fake.fail_get_sl_once();
+    let err = core.load(Uuid::new_v4()).await.unwrap_err();
+
+    assert!(matches!(err, CoreError::Db(DbError::Other(_))));
+}
@@ -0,0 +1,3 @@
+//! testing app core api for shift log with fakes
+
+mod db_wrapper;
@@ -1,4 +1,7 @@
-use app_core::{CoreError, DbError};
+use app_core::{
+    CoreError, DbError, DbpSportConfig,
+    utils::{id_version::IdVersion, list_order::ListOrder},
+};
 use uuid::Uuid;
 
 use integration_testing::port_fakes::*;
@@ -138,7 +141,7 @@ async fn given_filter_and_limit_when_list_sport_configs_then_db_fake_results_are
 
     // Act
     let got = core
-        .list_sport_config_ids(sport_id, Some("ma"), Some(2))
+        .list_sport_config_ids(sport_id, Some("ma"), false, Some(2), &ListOrder::default())
         .await
         .expect("db ok");
 
@@ -171,7 +174,7 @@ async fn given_only_limit_when_list_sport_config_ids_then_limit_is_respected() {
     }
 
     let got = core
-        .list_sport_config_ids(sport_id, None, Some(3))
+        .list_sport_config_ids(sport_id, None, false, Some(3), &ListOrder::default())
         .await
         .expect("db ok");
     assert_eq!(got.len(), 3);
@@ -185,7 +188,7 @@ async fn given_db_fake_failure_when_list_sport_config_ids_then_error_propagates(
     db_fake.fail_list_sc_once();
 
     let err = core
-        .list_sport_config_ids(Uuid::new_v4(), None, None)
+        .list_sport_config_ids(Uuid::new_v4(), None, false, None, &ListOrder::default())
         .await
         .expect_err("expected DB error");
 
@@ -195,3 +198,116 @@ async fn given_db_fake_failure_when_list_sport_config_ids_then_error_propagates(
         other => panic!("unexpected error variant: {other:?}"),
     }
 }
+
+/// 9) archive(): archived config is hidden in default listing
+#[tokio::test]
+async fn given_archived_config_when_list_then_only_included_on_request() {
+    let (mut core, _db_fake, _cr_fake) = make_core_sport_config_state_with_fakes();
+    let sport_id = core.sport_plugins.list()[0].get_id_version().get_id();
+
+    *core.get_mut() = make_sport_config("Keep Config", &core);
+    core.save().await.expect("seed save");
+    *core.get_mut() = make_sport_config("Old Config", &core);
+    let archived_id = core.save().await.expect("seed save").get_id();
+
+    // Act
+    let archived = core.archive().await.expect("archive ok");
+    assert!(archived.is_archived());
+    assert_eq!(
+        archived.get_version(),
+        Some(1),
+        "archive increments version"
+    );
+
+    // Assert
+    let active = core
+        .list_sport_config_ids(sport_id, None, false, None, &ListOrder::default())
+        .await
+        .expect("db ok");
+    assert_eq!(active.len(), 1);
+    assert!(!active.contains(&archived_id));
+
+    let all = core
+        .list_sport_config_ids(sport_id, None, true, None, &ListOrder::default())
+        .await
+        .expect("db ok");
+    assert_eq!(all.len(), 2);
+    assert!(all.contains(&archived_id));
+}
+
+/// 10) archive(): stale version → optimistic lock conflict
+#[tokio::test]
+async fn given_stale_version_when_archive_then_optimistic_lock_conflict() {
+    let (mut core, db_fake, _cr_fake) = make_core_sport_config_state_with_fakes();
+
+    *core.get_mut() = make_sport_config("Config A", &core);
+    let saved = core.save().await.expect("seed save").clone();
+
+    // somebody else archives the config in the meantime
+    db_fake
+        .archive_sport_config(saved.get_id(), 0)
+        .await
+        .expect("archive ok");
+
+    let err = core.archive().await.expect_err("expected conflict");
+    match err {
+        CoreError::Db(DbError::VersionConflict { current_version: 1 }) => {}
+        other => panic!("unexpected error variant: {other:?}"),
+    }
+}
+
+/// 11) archive_many(): all given configs are archived at once
+#[tokio::test]
+async fn given_configs_when_archive_many_then_all_archived() {
+    let (mut core, db_fake, _cr_fake) = make_core_sport_config_state_with_fakes();
+
+    *core.get_mut() = make_sport_config("Config A", &core);
+    let a = core.save().await.expect("seed save").get_id_version();
+    *core.get_mut() = make_sport_config("Config B", &core);
+    let b = core.save().await.expect("seed save").get_id_version();
+
+    // Act
+    let archived = core.archive_many(&[a, b]).await.expect("archive ok");
+
+    // Assert
+    assert_eq!(archived.len(), 2);
+    for id_version in [a, b] {
+        let stored = db_fake
+            .get_sport_config(id_version.get_id())
+            .await
+            .expect("db ok")
+            .expect("config exists");
+        assert!(stored.is_archived());
+    }
+}
+
+/// 12) archive_many(): one stale version → no config is archived
+#[tokio::test]
+async fn given_one_stale_config_when_archive_many_then_none_archived() {
+    let (mut core, db_fake, _cr_fake) = make_core_sport_config_state_with_fakes();
+
+    *core.get_mut() = make_sport_config("Config A", &core);
+    let a = core.save().await.expect("seed save").get_id_version();
+    *core.get_mut() = make_sport_config("Config B", &core);
+    let b = core.save().await.expect("seed save").get_id_version();
+    let stale_b = IdVersion::new(b.get_id(), Some(7));
+
+    // Act
+    let err = core
+        .archive_many(&[a, stale_b])
+        .await
+        .expect_err("expected conflict");
+
+    // Assert: conflict propagates and the archive of config A is rolled back
+    match err {
+        CoreError::Db(DbError::VersionConflict { .. }) => {}
+        other => panic!("unexpected error variant: {other:?}"),
+    }
+    let stored = db_fake
+        .get_sport_config(a.get_id())
+        .await
+        .expect("db ok")
+        .expect("config exists");
+    assert!(!stored.is_archived());
+    assert_eq!(stored.get_version(), a.get_version());
+}
@@ -0,0 +1,103 @@
+use app_core::{Core, CoreBuilder, CoreError, SportConfig, SportConfigState, SportError};
+use serde_json::json;
+use sport_plugin_manager::SportPluginManagerMap;
+use std::sync::Arc;
+use uuid::Uuid;
+
+use integration_testing::port_fakes::*;
+
+/// core with a sport plugin of version 2
+fn make_core_with_versioned_sport() -> (Core<SportConfigState>, Arc<FakeDatabasePort>, Uuid) {
+    let db = Arc::new(FakeDatabasePort::new());
+    let sport_id = Uuid::new_v4();
+    let spm = SportPluginManagerMap::new();
+    spm.register(Arc::new(VersionedMockSport {
+        sport: MockSport {
+            id: sport_id,
+            name: "Versioned Mock Sport",
+        },
+        version: 2,
+    }))
+    .unwrap();
+    let core = CoreBuilder::new()
+        .set_db(db.clone())
+        .set_cr(Arc::new(FakeClientRegistryPort::new()))
+        .set_spm(Arc::new(spm))
+        .set_wh(Arc::new(FakeWebhookTransport::new()))
+        .set_em(Arc::new(FakeEmailPort::new()))
+        .set_bs(Arc::new(FakeBlobStorage::new()))
+        .set_ev(Arc::new(FakeDomainEventPort::new()))
+        .build();
+    (core.as_sport_config_state(), db, sport_id)
+}
+
+fn make_versioned_config(sport_id: Uuid, plugin_version: u32) -> SportConfig {
+    let mut sc = SportConfig::default();
+    sc.set_name("Stored Config")
+        .set_sport_id(sport_id)
+        .set_config(json!({ "sets": 3 }))
+        .set_plugin_version(plugin_version);
+    sc
+}
+
+/// 1) load(): config of older plugin version is migrated
+#[tokio::test]
+async fn given_config_of_older_plugin_version_when_load_then_config_is_migrated() {
+    let (mut core, db_fake, sport_id) = make_core_with_versioned_sport();
+    let id = db_fake.seed_sport_config(make_versioned_config(sport_id, 1));
+
+    // Act
+    let loaded = core
+        .load(id)
+        .await
+        .expect("migration ok")
+        .expect("config exists")
+        .clone();
+
+    // Assert
+    assert_eq!(loaded.get_plugin_version(), 2);
+    assert_eq!(
+        loaded.get_config(),
+        &json!({ "sets": 3, "migrated_from": 1 })
+    );
+}
+
+/// 2) load(): config of current plugin version is not touched
+#[tokio::test]
+async fn given_config_of_current_plugin_version_when_load_then_config_is_unchanged() {
+    let (mut core, db_fake, sport_id) = make_core_with_versioned_sport();
+    let id = db_fake.seed_sport_config(make_versioned_config(sport_id, 2));
+
+    let loaded = core.load(id).await.expect("db ok").expect("config exists");
+
+    assert_eq!(loaded.get_config(), &json!({ "sets": 3 }));
+}
+
+/// 3) load(): config of newer plugin version cannot be read
+#[tokio::test]
+async fn given_config_of_newer_plugin_version_when_load_then_error() {
+    let (mut core, db_fake, sport_id) = make_core_with_versioned_sport();
+    let id = db_fake.seed_sport_config(make_versioned_config(sport_id, 3));
+
+    let err = core.load(id).await.unwrap_err();
+
+    assert!(matches!(
+        err,
+        CoreError::Sport(SportError::UnsupportedConfigVersion {
+            found: 3,
+            current: 2
+        })
+    ));
+}
+
+/// 4) save(): imported config of older plugin version is saved in current version
+#[tokio::test]
+async fn given_config_of_older_plugin_version_when_save_then_migrated_config_is_saved() {
+    let (mut core, _db_fake, sport_id) = make_core_with_versioned_sport();
+    *core.get_mut() = make_versioned_config(sport_id, 0);
+
+    let saved = core.save().await.expect("save ok").clone();
+
+    assert_eq!(saved.get_plugin_version(), 2);
+    assert_eq!(saved.get_config()["migrated_from"], json!(0));
+}
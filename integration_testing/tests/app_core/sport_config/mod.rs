@@ -1,4 +1,6 @@
 //! testing app core api for sport config with fakes
 
 mod db_wrapper;
+mod migration;
+mod plugin_manager;
 mod registry_wrapper;
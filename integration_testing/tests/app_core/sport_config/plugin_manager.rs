@@ -0,0 +1,181 @@
+//! deregistration, replacement and parity of sport plugins after start
+
+use super::*;
+use app_core::{CrMsg, PluginVersionMismatch, SportPluginManagerPort, SportPort};
+
+fn make_manager() -> (SportPluginManagerMap, Arc<FakeClientRegistryPort>, Uuid) {
+    let cr = Arc::new(FakeClientRegistryPort::new());
+    let spm = SportPluginManagerMap::new().with_client_registry(cr.clone());
+    let sport_id = Uuid::new_v4();
+    spm.register(Arc::new(MockSport {
+        id: sport_id,
+        name: "Mock Sport",
+    }))
+    .unwrap();
+    (spm, cr, sport_id)
+}
+
+/// 1) deregister(): plugin is removed and clients are notified
+#[tokio::test]
+async fn given_registered_plugin_when_deregister_then_plugin_is_removed_and_clients_notified() {
+    let (spm, cr, sport_id) = make_manager();
+
+    let removed = spm.deregister(&sport_id).await.expect("plugin registered");
+
+    assert_eq!(removed.name(), "Mock Sport");
+    assert!(spm.get(&sport_id).is_none());
+    assert!(spm.list().is_empty());
+    assert_eq!(
+        cr.published(),
+        vec![CrMsg::SportPluginsUpdated {
+            id: sport_id,
+            version: 0
+        }]
+    );
+}
+
+/// 2) deregister(): unknown plugin is an error without notification
+#[tokio::test]
+async fn given_unknown_plugin_when_deregister_then_error() {
+    let (spm, cr, _) = make_manager();
+
+    assert!(spm.deregister(&Uuid::new_v4()).await.is_err());
+    assert_eq!(spm.list().len(), 1);
+    assert!(cr.published().is_empty());
+}
+
+/// 3) replace(): new version of plugin replaces the old one in all clones of the manager
+#[tokio::test]
+async fn given_registered_plugin_when_replace_then_new_version_is_used_by_clones() {
+    let (spm, cr, sport_id) = make_manager();
+    let shared = spm.clone();
+
+    let replaced = spm
+        .replace(Arc::new(VersionedMockSport {
+            sport: MockSport {
+                id: sport_id,
+                name: "Mock Sport",
+            },
+            version: 2,
+        }))
+        .await
+        .expect("plugin registered");
+
+    assert_eq!(replaced.plugin_version(), 0);
+    assert_eq!(shared.get(&sport_id).unwrap().plugin_version(), 2);
+    assert_eq!(
+        cr.published(),
+        vec![CrMsg::SportPluginsUpdated {
+            id: sport_id,
+            version: 2
+        }]
+    );
+}
+
+/// 4) replace(): plugin must be registered and its name must be unique
+#[tokio::test]
+async fn given_unknown_or_duplicate_name_when_replace_then_error() {
+    let (spm, cr, sport_id) = make_manager();
+    let other_id = Uuid::new_v4();
+    spm.register(Arc::new(MockSport {
+        id: other_id,
+        name: "Other Sport",
+    }))
+    .unwrap();
+
+    let unknown = spm
+        .replace(Arc::new(MockSport {
+            id: Uuid::new_v4(),
+            name: "Unknown Sport",
+        }))
+        .await;
+    let duplicate_name = spm
+        .replace(Arc::new(MockSport {
+            id: sport_id,
+            name: "Other Sport",
+        }))
+        .await;
+
+    assert!(unknown.is_err());
+    assert!(duplicate_name.is_err());
+    assert_eq!(spm.get(&sport_id).unwrap().name(), "Mock Sport");
+    assert!(cr.published().is_empty());
+}
+
+/// 5) replace(): core of a running server migrates configs with the replacing plugin
+#[tokio::test]
+async fn given_replaced_plugin_when_load_config_then_config_is_migrated() {
+    let db = Arc::new(FakeDatabasePort::new());
+    let (spm, _, sport_id) = make_manager();
+    let mut core = CoreBuilder::new()
+        .set_db(db.clone())
+        .set_cr(Arc::new(FakeClientRegistryPort::new()))
+        .set_spm(Arc::new(spm.clone()))
+        .set_wh(Arc::new(FakeWebhookTransport::new()))
+        .set_em(Arc::new(FakeEmailPort::new()))
+        .set_bs(Arc::new(FakeBlobStorage::new()))
+        .set_ev(Arc::new(FakeDomainEventPort::new()))
+        .build()
+        .as_sport_config_state();
+    let mut sc = SportConfig::default();
+    sc.set_name("Stored Config")
+        .set_sport_id(sport_id)
+        .set_config(json!({ "sets": 3 }))
+        .set_plugin_version(1);
+    let id = db.seed_sport_config(sc);
+
+    spm.replace(Arc::new(VersionedMockSport {
+        sport: MockSport {
+            id: sport_id,
+            name: "Mock Sport",
+        },
+        version: 2,
+    }))
+    .await
+    .unwrap();
+    let loaded = core
+        .load(id)
+        .await
+        .expect("migration ok")
+        .expect("config exists");
+
+    assert_eq!(loaded.get_plugin_version(), 2);
+}
+
+/// 6) check_plugin_parity(): client with the version before replacement drifted
+#[tokio::test]
+async fn given_replaced_plugin_when_check_parity_of_old_client_then_version_mismatch() {
+    let (spm, _, sport_id) = make_manager();
+    let core = CoreBuilder::new()
+        .set_db(Arc::new(FakeDatabasePort::new()))
+        .set_cr(Arc::new(FakeClientRegistryPort::new()))
+        .set_spm(Arc::new(spm.clone()))
+        .set_wh(Arc::new(FakeWebhookTransport::new()))
+        .set_em(Arc::new(FakeEmailPort::new()))
+        .set_bs(Arc::new(FakeBlobStorage::new()))
+        .set_ev(Arc::new(FakeDomainEventPort::new()))
+        .build()
+        .as_sport_plugin_state();
+    assert!(core.check_plugin_parity(&[(sport_id, 0)]).is_ok());
+
+    spm.replace(Arc::new(VersionedMockSport {
+        sport: MockSport {
+            id: sport_id,
+            name: "Mock Sport",
+        },
+        version: 2,
+    }))
+    .await
+    .unwrap();
+    let parity = core.check_plugin_parity(&[(sport_id, 0)]);
+
+    assert!(!parity.is_ok());
+    assert_eq!(
+        parity.version_mismatches,
+        vec![PluginVersionMismatch {
+            sport_id,
+            server_version: 2,
+            client_version: 0,
+        }]
+    );
+}
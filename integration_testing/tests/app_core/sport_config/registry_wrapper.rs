@@ -1,4 +1,4 @@
-use app_core::{CoreError, CrError, CrMsg, DbError};
+use app_core::{CoreError, CrError, CrMsg, DbError, utils::list_order::ListOrder};
 
 use integration_testing::port_fakes::*;
 
@@ -134,7 +134,7 @@ async fn given_read_operations_when_invoked_then_never_publish_anything() {
     let any_id = core.get().get_id();
     let _ = core.load(any_id).await.expect("load ok");
     let _ = core
-        .list_sport_config_ids(sport_id, None, Some(10))
+        .list_sport_config_ids(sport_id, None, false, Some(10), &ListOrder::default())
         .await
         .expect("list ok");
 
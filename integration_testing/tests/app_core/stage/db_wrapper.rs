@@ -225,3 +225,39 @@ async fn given_db_fake_failure_when_list_stages_then_error_propagates() {
         other => panic!("unexpected error variant: {other:?}"),
     }
 }
+
+/// 10) save(): station limit above stations of tournament → validation error, nothing saved
+#[tokio::test]
+async fn given_station_limit_above_tournament_stations_when_save_then_validation_error() {
+    let (mut core, _db_fake, cr_fake) = make_core_stage_state_with_fakes();
+
+    // tournament of fake context has 1 station (default)
+    core.get_mut().set_number(2).set_max_stations(Some(2));
+
+    let err = core.save().await.expect_err("expected validation error");
+
+    let CoreError::Validation(errs) = err else {
+        panic!("expected validation errors, got {err:?}");
+    };
+    assert!(errs.errors.iter().all(|e| e.get_field() == "max_stations"));
+    assert!(!errs.errors.is_empty());
+    assert!(cr_fake.published().is_empty());
+}
+
+/// 11) save(): station limit within stations of tournament → persisted and restricts stations
+#[tokio::test]
+async fn given_station_limit_when_save_then_limit_is_persisted() {
+    let (mut core, _db_fake, _cr_fake) = make_core_stage_state_with_fakes();
+
+    core.get_mut().set_number(2).set_max_stations(Some(1));
+    let id = core.save().await.expect("save should succeed").get_id();
+
+    let stage = *core
+        .load_by_id(id)
+        .await
+        .expect("db ok")
+        .expect("stage exists");
+    assert_eq!(stage.get_max_stations(), Some(1));
+    let tournament = core.get_tournament().expect("tournament is loaded");
+    assert_eq!(stage.get_allowed_stations(tournament), 1..=1);
+}
@@ -2,3 +2,4 @@
 
 mod db_wrapper;
 mod registry_wrapper;
+mod timing;
@@ -0,0 +1,94 @@
+use app_core::{CrMsg, Match, ScheduledEntrant};
+use chrono::{Duration, Local, Utc};
+use integration_testing::port_fakes::*;
+use uuid::Uuid;
+
+#[tokio::test]
+async fn given_saved_stage_when_load_stage_timing_dashboard_then_dashboard_of_stage() {
+    let (mut core, _db_fake, _cr_fake) = make_core_stage_state_with_fakes();
+    let t_id = core.get().get_tournament_id();
+    core.get_mut().set_number(0).set_num_groups(2);
+    let stage_id = core.save().await.expect("save should succeed").get_id();
+
+    let dashboard = core
+        .load_stage_timing_dashboard(stage_id)
+        .await
+        .expect("db ok")
+        .expect("stage exists");
+
+    assert_eq!(dashboard.stage_id, stage_id);
+    assert_eq!(dashboard.tournament_id, t_id);
+    assert_eq!(dashboard.projected_finish, None);
+    assert_eq!(dashboard.delay_seconds(), 0);
+
+    let (rescheduled, compressed) = core
+        .compress_stage_schedule(stage_id)
+        .await
+        .expect("db ok")
+        .expect("stage exists");
+    assert!(rescheduled.is_empty());
+    assert_eq!(compressed.stage_id, stage_id);
+}
+
+#[tokio::test]
+async fn given_missing_stage_when_load_stage_timing_dashboard_then_none() {
+    let (core, _db_fake, _cr_fake) = make_core_stage_state_with_fakes();
+
+    let res = core
+        .load_stage_timing_dashboard(Uuid::new_v4())
+        .await
+        .expect("db ok");
+    assert!(res.is_none());
+    let res = core
+        .compress_stage_schedule(Uuid::new_v4())
+        .await
+        .expect("db ok");
+    assert!(res.is_none());
+}
+
+#[tokio::test]
+async fn given_late_planned_match_when_compress_stage_schedule_then_new_slot_saved() {
+    let (mut core, db_fake, cr_fake) = make_core_stage_state_with_fakes();
+    let t_id = core.get().get_tournament_id();
+    core.get_mut().set_number(0).set_num_groups(1);
+    let stage_id = core.save().await.expect("save should succeed").get_id();
+    let planned_start = Local::now() + Duration::hours(2);
+    let mut m = Match::new_scheduled(
+        Uuid::new_v4(),
+        Uuid::new_v4(),
+        Uuid::new_v4(),
+        1,
+        ScheduledEntrant::Entrant(Uuid::new_v4()),
+        ScheduledEntrant::Entrant(Uuid::new_v4()),
+    );
+    m.set_tournament(t_id, Uuid::new_v4(), stage_id)
+        .set_slot(1, planned_start);
+    db_fake.seed_matches(vec![m.clone()]);
+
+    let dashboard = core
+        .load_stage_timing_dashboard(stage_id)
+        .await
+        .expect("db ok")
+        .expect("stage exists");
+    assert_eq!(dashboard.stations[0].matches.len(), 1);
+    assert!(dashboard.stations[0].matches[0].times.is_pending());
+    cr_fake.clear();
+
+    let (rescheduled, _) = core
+        .compress_stage_schedule(stage_id)
+        .await
+        .expect("db ok")
+        .expect("stage exists");
+
+    assert_eq!(rescheduled.len(), 1);
+    let stored = db_fake.matches_of(t_id);
+    assert!(stored[0].get_start_at() < planned_start);
+    assert_eq!(
+        stored[0].get_start_at().with_timezone(&Utc),
+        rescheduled[0].slot.start_at
+    );
+    assert!(cr_fake.published().iter().any(|msg| matches!(
+        msg,
+        CrMsg::MatchUpdated { id, .. } if id == m.get_id()
+    )));
+}
@@ -0,0 +1,298 @@
+use app_core::{
+    AuditObjectType, Core, DiffObjectResult, Group, Mode, Stage, StageState, TournamentBase,
+    TournamentDiff, utils::id_version::IdVersion,
+};
+
+use integration_testing::port_fakes::*;
+use uuid::Uuid;
+
+fn new_stage(tournament_id: Uuid, number: u32) -> Stage {
+    let mut stage = Stage::default();
+    stage
+        .set_tournament_id(tournament_id)
+        .set_number(number)
+        .set_num_groups(4);
+    stage
+}
+
+fn new_group(stage_id: Uuid, number: u32) -> Group {
+    Group::new(
+        IdVersion::default(),
+        stage_id,
+        number,
+        Mode::RoundRobin,
+        Uuid::new_v4(),
+        Uuid::new_v4(),
+    )
+}
+
+async fn load_base(core: &Core<StageState>, id: Uuid) -> TournamentBase {
+    core.as_tournament_base_state()
+        .load(id)
+        .await
+        .expect("db ok")
+        .expect("tournament exists")
+        .clone()
+}
+
+/// 1) save_tournament_diff(): base and stages are saved together
+#[tokio::test]
+async fn given_diff_when_save_then_all_objects_are_saved() {
+    let (stage_core, db_fake, _cr_fake) = make_core_stage_state_with_fakes();
+    let tournament_id = stage_core.get().get_tournament_id();
+    let mut base = load_base(&stage_core, tournament_id).await;
+    base.set_name("Renamed Tournament");
+    let diff = TournamentDiff {
+        tournament_id,
+        base: Some(base),
+        stages: vec![new_stage(tournament_id, 0), new_stage(tournament_id, 1)],
+        groups: Vec::new(),
+    };
+
+    let result = stage_core
+        .save_tournament_diff(&diff)
+        .await
+        .expect("diff is processed");
+
+    assert!(result.is_saved());
+    let saved_base = result.base.as_ref().and_then(|b| b.get_saved()).unwrap();
+    assert_eq!(saved_base.get_version(), Some(1));
+    assert!(result.stages.iter().all(|s| {
+        s.get_saved()
+            .is_some_and(|stage| stage.get_version() == Some(0))
+    }));
+    assert_eq!(
+        load_base(&stage_core, tournament_id).await.get_name(),
+        "Renamed Tournament"
+    );
+    assert_eq!(db_fake.audit_records().len(), 3);
+}
+
+/// 2) save_tournament_diff(): failing stage rolls back the base
+#[tokio::test]
+async fn given_failing_stage_when_save_then_nothing_is_saved() {
+    let (stage_core, db_fake, _cr_fake) = make_core_stage_state_with_fakes();
+    let tournament_id = stage_core.get().get_tournament_id();
+    let mut base = load_base(&stage_core, tournament_id).await;
+    base.set_name("Renamed Tournament");
+    let stage = new_stage(tournament_id, 0);
+    let diff = TournamentDiff {
+        tournament_id,
+        base: Some(base),
+        stages: vec![stage],
+        groups: Vec::new(),
+    };
+    db_fake.fail_save_stage_once();
+
+    let result = stage_core
+        .save_tournament_diff(&diff)
+        .await
+        .expect("diff is processed");
+
+    assert!(!result.is_saved());
+    assert!(matches!(
+        result.base,
+        Some(DiffObjectResult::RolledBack { id }) if id == tournament_id
+    ));
+    assert!(matches!(
+        result.stages[0],
+        DiffObjectResult::Failed { id, .. } if id == stage.get_id()
+    ));
+    let stored = load_base(&stage_core, tournament_id).await;
+    assert_eq!(stored.get_name(), "Stage Context Tournament");
+    assert_eq!(stored.get_version(), Some(0));
+    assert!(db_fake.audit_records().is_empty());
+}
+
+/// 3) save_tournament_diff(): stale base returns the server copy and rolls back stages
+#[tokio::test]
+async fn given_stale_base_when_save_then_version_conflict_with_server_copy() {
+    let (stage_core, _db_fake, _cr_fake) = make_core_stage_state_with_fakes();
+    let tournament_id = stage_core.get().get_tournament_id();
+    let stale = load_base(&stage_core, tournament_id).await;
+    let mut base_core = stage_core.as_tournament_base_state();
+    base_core.load(tournament_id).await.expect("db ok");
+    base_core.get_mut().set_name("Changed by someone else");
+    base_core.save().await.expect("concurrent save");
+
+    let mut base = stale;
+    base.set_name("My Name");
+    let diff = TournamentDiff {
+        tournament_id,
+        base: Some(base),
+        stages: vec![new_stage(tournament_id, 0)],
+        groups: Vec::new(),
+    };
+
+    let result = stage_core
+        .save_tournament_diff(&diff)
+        .await
+        .expect("diff is processed");
+
+    let error = result
+        .base
+        .as_ref()
+        .and_then(|b| b.get_error())
+        .expect("base failed");
+    let theirs = error
+        .get_server_copy()
+        .and_then(|copy| copy.as_tournament_base())
+        .expect("server copy");
+    assert_eq!(theirs.get_name(), "Changed by someone else");
+    assert!(matches!(
+        result.stages[0],
+        DiffObjectResult::RolledBack { .. }
+    ));
+    let stages = stage_core
+        .as_stage_state(tournament_id)
+        .list_stage_ids_of_tournament()
+        .await
+        .expect("db ok");
+    assert!(stages.is_empty());
+}
+
+/// 4) save_tournament_diff(): edits of running tournaments follow the lifecycle rules
+#[tokio::test]
+async fn given_running_tournament_when_save_locked_edits_then_failed() {
+    let (stage_core, db_fake, _cr_fake) = make_core_stage_state_with_fakes();
+    let tournament_id = stage_core.get().get_tournament_id();
+    db_fake.seed_readiness(tournament_id);
+    let mut base_core = stage_core.as_tournament_base_state();
+    base_core.load(tournament_id).await.expect("db ok");
+    base_core.start_tournament().await.expect("start ok");
+
+    let mut base = load_base(&stage_core, tournament_id).await;
+    base.set_num_entrants(16);
+    let diff = TournamentDiff {
+        tournament_id,
+        base: Some(base),
+        stages: Vec::new(),
+        groups: Vec::new(),
+    };
+    let result = stage_core
+        .save_tournament_diff(&diff)
+        .await
+        .expect("diff is processed");
+    assert!(matches!(
+        result.base,
+        Some(DiffObjectResult::Failed { id, .. }) if id == tournament_id
+    ));
+
+    let mut stage_core = stage_core.as_stage_state(tournament_id);
+    let mut started = stage_core
+        .load_by_number(0)
+        .await
+        .expect("db ok")
+        .expect("stage exists")
+        .clone();
+    started.set_num_groups(started.get_num_groups() + 1);
+    let diff = TournamentDiff {
+        tournament_id,
+        base: None,
+        stages: vec![started.clone()],
+        groups: Vec::new(),
+    };
+    let result = stage_core
+        .save_tournament_diff(&diff)
+        .await
+        .expect("diff is processed");
+    assert!(matches!(
+        result.stages[0],
+        DiffObjectResult::Failed { id, .. } if id == started.get_id()
+    ));
+}
+
+/// 5) save_tournament_diff(): groups are saved with their new stage and updated by version
+#[tokio::test]
+async fn given_diff_with_groups_when_save_then_groups_are_saved() {
+    let (stage_core, db_fake, _cr_fake) = make_core_stage_state_with_fakes();
+    let tournament_id = stage_core.get().get_tournament_id();
+    let stage = new_stage(tournament_id, 0);
+    let diff = TournamentDiff {
+        tournament_id,
+        base: None,
+        stages: vec![stage.clone()],
+        groups: vec![new_group(stage.get_id(), 0), new_group(stage.get_id(), 1)],
+    };
+
+    let result = stage_core
+        .save_tournament_diff(&diff)
+        .await
+        .expect("diff is processed");
+
+    assert!(result.is_saved());
+    let saved = db_fake.groups_of(stage.get_id());
+    assert_eq!(saved.len(), 2);
+    assert!(saved.iter().all(|g| g.get_version() == Some(0)));
+    let group_records = db_fake
+        .audit_records()
+        .into_iter()
+        .filter(|r| r.get_object_type() == AuditObjectType::Group)
+        .count();
+    assert_eq!(group_records, 2);
+
+    let mut changed = saved[1].clone();
+    changed.set_number(2);
+    let diff = TournamentDiff {
+        tournament_id,
+        base: None,
+        stages: Vec::new(),
+        groups: vec![changed],
+    };
+    let result = stage_core
+        .save_tournament_diff(&diff)
+        .await
+        .expect("diff is processed");
+    assert!(result.is_saved());
+    let updated = result.groups[0].get_saved().unwrap();
+    assert_eq!(updated.get_version(), Some(1));
+    assert_eq!(updated.get_number(), 2);
+}
+
+/// 6) save_tournament_diff(): invalid group rolls back its stage
+#[tokio::test]
+async fn given_group_beyond_groups_of_stage_when_save_then_nothing_is_saved() {
+    let (stage_core, db_fake, _cr_fake) = make_core_stage_state_with_fakes();
+    let tournament_id = stage_core.get().get_tournament_id();
+    let stage = new_stage(tournament_id, 0);
+    let group = new_group(stage.get_id(), 4);
+    let diff = TournamentDiff {
+        tournament_id,
+        base: None,
+        stages: vec![stage.clone()],
+        groups: vec![group.clone()],
+    };
+
+    let result = stage_core
+        .save_tournament_diff(&diff)
+        .await
+        .expect("diff is processed");
+
+    assert!(!result.is_saved());
+    assert!(matches!(
+        result.stages[0],
+        DiffObjectResult::RolledBack { .. }
+    ));
+    assert!(matches!(
+        result.groups[0],
+        DiffObjectResult::Failed { id, .. } if id == group.get_id()
+    ));
+    assert!(db_fake.groups_of(stage.get_id()).is_empty());
+
+    // group of a stage of another tournament
+    let foreign = new_group(Uuid::new_v4(), 0);
+    let diff = TournamentDiff {
+        tournament_id,
+        base: None,
+        stages: Vec::new(),
+        groups: vec![foreign.clone()],
+    };
+    let result = stage_core
+        .save_tournament_diff(&diff)
+        .await
+        .expect("diff is processed");
+    assert!(matches!(
+        result.groups[0],
+        DiffObjectResult::Failed { id, .. } if id == foreign.get_id()
+    ));
+}
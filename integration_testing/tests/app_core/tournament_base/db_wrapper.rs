@@ -1,4 +1,7 @@
-use app_core::{CoreError, DbError};
+use app_core::{
+    CoreError, DbError, DbpTournamentBase, SportConfig, TournamentBaseCondition, TournamentState,
+    utils::{filter::Filter, id_version::IdVersion, list_order::ListOrder},
+};
 use uuid::Uuid;
 
 use integration_testing::port_fakes::*;
@@ -140,7 +143,15 @@ async fn given_filter_and_limit_when_list_tournament_bases_then_db_fake_results_
 
     // Act
     let got = core
-        .list_tournament_base_ids(sport_id, Some("ma"), None, false, Some(2))
+        .list_tournament_base_ids(
+            &Filter::new()
+                .with(TournamentBaseCondition::SportIs(sport_id))
+                .with(TournamentBaseCondition::NameContains("ma".to_string()))
+                .with(TournamentBaseCondition::NotAdhoc)
+                .with(TournamentBaseCondition::NotArchived)
+                .limit(Some(2)),
+            &ListOrder::default(),
+        )
         .await
         .expect("db ok");
 
@@ -173,7 +184,14 @@ async fn given_only_limit_when_list_tournament_bases_then_limit_is_respected() {
     }
 
     let got = core
-        .list_tournament_base_ids(sport_id, None, None, false, Some(3))
+        .list_tournament_base_ids(
+            &Filter::new()
+                .with(TournamentBaseCondition::SportIs(sport_id))
+                .with(TournamentBaseCondition::NotAdhoc)
+                .with(TournamentBaseCondition::NotArchived)
+                .limit(Some(3)),
+            &ListOrder::default(),
+        )
         .await
         .expect("db ok");
     assert_eq!(got.len(), 3);
@@ -187,7 +205,13 @@ async fn given_db_fake_failure_when_list_tournament_bases_then_error_propagates(
     db_fake.fail_list_tb_once();
 
     let err = core
-        .list_tournament_base_ids(Uuid::new_v4(), None, None, false, None)
+        .list_tournament_base_ids(
+            &Filter::new()
+                .with(TournamentBaseCondition::SportIs(Uuid::new_v4()))
+                .with(TournamentBaseCondition::NotAdhoc)
+                .with(TournamentBaseCondition::NotArchived),
+            &ListOrder::default(),
+        )
         .await
         .expect_err("expected DB error");
 
@@ -197,3 +221,149 @@ async fn given_db_fake_failure_when_list_tournament_bases_then_error_propagates(
         other => panic!("unexpected error variant: {other:?}"),
     }
 }
+
+/// 9) archive(): archived tournament is hidden in default listing
+#[tokio::test]
+async fn given_archived_tournament_when_list_then_only_included_on_request() {
+    let (mut core, _db_fake, _cr_fake) = make_core_tournament_base_state_with_fakes();
+    let sport_id = core.sport_plugins.list()[0].get_id_version().get_id();
+
+    *core.get_mut() = make_tournament_base("Keep Tournament", &core);
+    core.save().await.expect("seed save");
+    *core.get_mut() = make_tournament_base("Old Tournament", &core);
+    let archived_id = core.save().await.expect("seed save").get_id();
+
+    // Act
+    let archived = core.archive().await.expect("archive ok");
+    assert!(archived.is_archived());
+    assert_eq!(
+        archived.get_version(),
+        Some(1),
+        "archive increments version"
+    );
+
+    // Assert
+    let active = core
+        .list_tournament_base_ids(
+            &Filter::new()
+                .with(TournamentBaseCondition::SportIs(sport_id))
+                .with(TournamentBaseCondition::NotAdhoc)
+                .with(TournamentBaseCondition::NotArchived),
+            &ListOrder::default(),
+        )
+        .await
+        .expect("db ok");
+    assert_eq!(active.len(), 1);
+    assert!(!active.contains(&archived_id));
+
+    let all = core
+        .list_tournament_base_ids(
+            &Filter::new()
+                .with(TournamentBaseCondition::SportIs(sport_id))
+                .with(TournamentBaseCondition::NotAdhoc),
+            &ListOrder::default(),
+        )
+        .await
+        .expect("db ok");
+    assert_eq!(all.len(), 2);
+    assert!(all.contains(&archived_id));
+}
+
+/// 10) archive(): unsaved tournament → not found
+#[tokio::test]
+async fn given_unsaved_tournament_when_archive_then_not_found() {
+    let (mut core, _db_fake, _cr_fake) = make_core_tournament_base_state_with_fakes();
+
+    *core.get_mut() = make_tournament_base("Tournament A", &core);
+
+    let err = core.archive().await.expect_err("expected not found");
+    match err {
+        CoreError::Db(DbError::NotFound) => {}
+        other => panic!("unexpected error variant: {other:?}"),
+    }
+}
+
+/// 11) delete_drafts(): all given draft tournaments are deleted at once
+#[tokio::test]
+async fn given_draft_tournaments_when_delete_drafts_then_all_deleted() {
+    let (mut core, db_fake, _cr_fake) = make_core_tournament_base_state_with_fakes();
+
+    *core.get_mut() = make_tournament_base("Draft A", &core);
+    let a = core.save().await.expect("seed save").get_id_version();
+    *core.get_mut() = make_tournament_base("Draft B", &core);
+    let b = core.save().await.expect("seed save").get_id_version();
+
+    // Act
+    let deleted = core.delete_drafts(&[a, b]).await.expect("delete ok");
+
+    // Assert
+    assert_eq!(deleted, vec![a.get_id(), b.get_id()]);
+    for id_version in [a, b] {
+        let stored = db_fake
+            .get_tournament_base(id_version.get_id())
+            .await
+            .expect("db ok");
+        assert!(stored.is_none());
+    }
+}
+
+/// 12) delete_drafts(): one published tournament → no tournament is deleted
+#[tokio::test]
+async fn given_one_published_tournament_when_delete_drafts_then_none_deleted() {
+    let (mut core, db_fake, _cr_fake) = make_core_tournament_base_state_with_fakes();
+
+    *core.get_mut() = make_tournament_base("Draft A", &core);
+    let a = core.save().await.expect("seed save").get_id_version();
+    let mut published = make_tournament_base("Published B", &core);
+    published.set_tournament_state(TournamentState::Published);
+    let b = IdVersion::new(db_fake.seed_tournament_base(published), Some(0));
+
+    // Act
+    let err = core
+        .delete_drafts(&[a, b])
+        .await
+        .expect_err("expected validation error");
+
+    // Assert: validation error and the deletion of draft A is rolled back
+    assert!(matches!(err, CoreError::Validation(_)));
+    let stored = db_fake
+        .get_tournament_base(a.get_id())
+        .await
+        .expect("db ok");
+    assert!(stored.is_some());
+}
+
+/// 13) save() + load_tournament_sport_config(): the chosen config of the sport is loaded,
+/// configs of other sports are rejected
+#[tokio::test]
+async fn given_chosen_sport_config_when_save_then_config_of_tournament_is_loaded() {
+    let (mut core, db_fake, _cr_fake) = make_core_tournament_base_state_with_fakes();
+    let tournament = make_tournament_base("Config Cup", &core);
+    let sport_id = tournament.get_sport_id();
+    let mut config_ids = Vec::new();
+    for name in ["Alpha rules", "Beta rules"] {
+        let mut config = SportConfig::default();
+        config.set_name(name).set_sport_id(sport_id);
+        config_ids.push(db_fake.seed_sport_config(config));
+    }
+    let mut other_sport = SportConfig::default();
+    other_sport
+        .set_name("Other rules")
+        .set_sport_id(Uuid::new_v4());
+    let other_sport_id = db_fake.seed_sport_config(other_sport);
+
+    *core.get_mut() = tournament;
+    core.get_mut().set_sport_config_id(Some(other_sport_id));
+    let err = core.save().await.expect_err("config of other sport");
+    assert!(matches!(err, CoreError::Validation(_)));
+
+    core.get_mut().set_sport_config_id(Some(config_ids[1]));
+    let saved = core.save().await.expect("save ok").clone();
+    let config = core
+        .load_tournament_sport_config(&saved)
+        .await
+        .expect("db ok")
+        .expect("config is chosen");
+    assert_eq!(config.get_id(), config_ids[1]);
+    assert_eq!(config.get_name(), "Beta rules");
+}
@@ -0,0 +1,159 @@
+use app_core::{
+    CoreError, CrMsg, DISPLAY_PAGE_SECONDS, DbError, DisplayPage, Match, ScheduledEntrant,
+    TournamentState,
+};
+use chrono::Local;
+use uuid::Uuid;
+
+use integration_testing::port_fakes::*;
+
+/// 1) load_display_board(): drafts are not shown at the venue
+#[tokio::test]
+async fn given_draft_tournament_when_load_display_board_then_none() {
+    let (stage_core, _db_fake, _cr_fake) = make_core_stage_state_with_fakes();
+    let tournament_id = stage_core.get().get_tournament_id();
+
+    let board = stage_core
+        .load_display_board(tournament_id)
+        .await
+        .expect("db ok");
+
+    assert!(board.is_none());
+}
+
+/// 2) load_display_board(): groups of final stage show their KO bracket
+#[tokio::test]
+async fn given_published_tournament_when_load_display_board_then_bracket_pages() {
+    let (mut stage_core, db_fake, _cr_fake) = make_core_stage_state_with_fakes();
+    let tournament_id = stage_core.get().get_tournament_id();
+
+    // 32 entrants: 8 groups of 4, 4 groups of 8, final stage with 4 groups of 8
+    for (number, num_groups) in [(0, 8), (1, 4), (2, 4)] {
+        stage_core
+            .get_mut()
+            .set_id_version(Default::default())
+            .set_number(number)
+            .set_num_groups(num_groups);
+        stage_core.save().await.expect("seed stage");
+    }
+    db_fake.seed_readiness(tournament_id);
+    let mut base_core = stage_core.as_tournament_base_state();
+    base_core.load(tournament_id).await.expect("db ok");
+    base_core
+        .get_mut()
+        .set_tournament_state(TournamentState::Published);
+    base_core.save().await.expect("publish");
+
+    // Act
+    let board = stage_core
+        .load_display_board(tournament_id)
+        .await
+        .expect("db ok")
+        .expect("published tournament is public");
+
+    // Assert
+    assert_eq!(board.tournament_id, tournament_id);
+    assert_eq!(board.page_seconds, DISPLAY_PAGE_SECONDS);
+    // only the bye seeded for readiness is scheduled: no stations, at most its standings
+    let labels: Vec<&str> = board
+        .pages
+        .iter()
+        .filter_map(|page| match page {
+            DisplayPage::Bracket {
+                stage_name,
+                group_label,
+                rounds,
+            } => {
+                assert_eq!(stage_name, "Final Stage");
+                assert_eq!(rounds.len(), 3);
+                Some(group_label.as_str())
+            }
+            DisplayPage::Standings { stage_name, .. } => {
+                assert_eq!(stage_name, "First Pool Stage");
+                None
+            }
+            other => panic!("expected bracket or standings page, got {other:?}"),
+        })
+        .collect();
+    assert_eq!(labels, vec!["A", "B", "C", "D"]);
+}
+
+/// 3) refresh_display_boards(): display boards are notified
+#[tokio::test]
+async fn given_tournament_when_refresh_display_boards_then_published() {
+    let (stage_core, _db_fake, cr_fake) = make_core_stage_state_with_fakes();
+    let tournament_id = stage_core.get().get_tournament_id();
+    cr_fake.clear();
+
+    stage_core
+        .refresh_display_boards(tournament_id)
+        .await
+        .expect("refresh should succeed");
+
+    assert_eq!(
+        cr_fake.published(),
+        vec![CrMsg::DisplayBoardRefreshed {
+            id: tournament_id,
+            version: 0
+        }]
+    );
+}
+
+/// 4) refresh_display_boards(): unknown tournament → NotFound
+#[tokio::test]
+async fn given_unknown_tournament_when_refresh_display_boards_then_not_found() {
+    let (stage_core, _db_fake, cr_fake) = make_core_stage_state_with_fakes();
+    cr_fake.clear();
+
+    let err = stage_core
+        .refresh_display_boards(Uuid::new_v4())
+        .await
+        .unwrap_err();
+
+    assert!(matches!(err, CoreError::Db(DbError::NotFound)));
+    assert!(cr_fake.published().is_empty());
+}
+
+/// 5) load_display_board(): stored matches on stations are shown as next match
+#[tokio::test]
+async fn given_stored_match_when_load_display_board_then_next_match_of_station() {
+    let (mut stage_core, db_fake, _cr_fake) = make_core_stage_state_with_fakes();
+    let tournament_id = stage_core.get().get_tournament_id();
+    stage_core.get_mut().set_number(0).set_num_groups(8);
+    let stage_id = stage_core.save().await.expect("seed stage").get_id();
+    let mut m = Match::new_scheduled(
+        Uuid::new_v4(),
+        Uuid::new_v4(),
+        Uuid::new_v4(),
+        1,
+        ScheduledEntrant::Entrant(Uuid::new_v4()),
+        ScheduledEntrant::Entrant(Uuid::new_v4()),
+    );
+    m.set_tournament(tournament_id, Uuid::new_v4(), stage_id)
+        .set_slot(1, Local::now());
+    db_fake.seed_matches(vec![m.clone()]);
+    db_fake.seed_readiness(tournament_id);
+    let mut base_core = stage_core.as_tournament_base_state();
+    base_core.load(tournament_id).await.expect("db ok");
+    base_core
+        .get_mut()
+        .set_tournament_state(TournamentState::Published);
+    base_core.save().await.expect("publish");
+
+    let board = stage_core
+        .load_display_board(tournament_id)
+        .await
+        .expect("db ok")
+        .expect("published tournament is public");
+
+    let Some(DisplayPage::Stations(stations)) = board.pages.first() else {
+        panic!("expected stations page, got {:?}", board.pages);
+    };
+    assert_eq!(stations.len(), 1);
+    assert_eq!(stations[0].station, 1);
+    assert_eq!(stations[0].current, None);
+    assert_eq!(
+        stations[0].next.as_ref().map(|next| next.match_id),
+        Some(*m.get_id())
+    );
+}
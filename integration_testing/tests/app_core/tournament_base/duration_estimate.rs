@@ -0,0 +1,125 @@
+//! estimated duration of stages and tournaments
+
+use app_core::{SportConfig, Stage, TournamentBase};
+use std::time::Duration;
+use uuid::Uuid;
+
+use integration_testing::port_fakes::*;
+
+/// tournament of 16 entrants in a single round robin group at `num_stations` stations
+fn seed_tournament(db: &FakeDatabasePort, sport_id: Uuid, num_stations: u32) -> (Uuid, Uuid) {
+    let mut tb = TournamentBase::default();
+    tb.set_name("Estimated Tournament")
+        .set_sport_id(sport_id)
+        .set_num_entrants(16)
+        .set_num_stations(num_stations);
+    let t_id = db.seed_tournament_base(tb);
+    let mut stage = Stage::default();
+    stage
+        .set_tournament_id(t_id)
+        .set_number(0)
+        .set_num_groups(1);
+    let stage_id = db.seed_stage(stage);
+    (t_id, stage_id)
+}
+
+/// sport config of tournament `t_id`
+fn seed_sport_config(db: &FakeDatabasePort, t_id: Uuid, sport_id: Uuid) {
+    let mut config = SportConfig::default();
+    config.set_name("Estimate Config").set_sport_id(sport_id);
+    let config_id = db.seed_sport_config(config);
+    db.link_sport_config(t_id, config_id);
+}
+
+/// 1) estimate_tournament_duration(): without valid sport config no estimate is possible
+#[tokio::test]
+async fn given_no_sport_config_when_estimate_tournament_duration_then_none() {
+    let (core, db_fake, _cr_fake, spm) = make_core_with_fakes();
+    let sport_id = spm.list()[0].get_id_version().get_id();
+    let (t_id, stage_id) = seed_tournament(&db_fake, sport_id, 4);
+
+    assert!(
+        core.estimate_tournament_duration(t_id)
+            .await
+            .unwrap()
+            .is_none()
+    );
+    assert!(
+        core.estimate_stage_duration(stage_id)
+            .await
+            .unwrap()
+            .is_none()
+    );
+}
+
+/// 2) estimate_tournament_duration(): matches of stage are spread over its stations
+#[tokio::test]
+async fn given_stations_when_estimate_tournament_duration_then_matches_are_spread() {
+    let (core, db_fake, _cr_fake, spm) = make_core_with_fakes();
+    let sport_id = spm.list()[0].get_id_version().get_id();
+    let (t_id, stage_id) = seed_tournament(&db_fake, sport_id, 4);
+    seed_sport_config(&db_fake, t_id, sport_id);
+
+    let estimate = core
+        .estimate_tournament_duration(t_id)
+        .await
+        .unwrap()
+        .expect("tournament with valid sport config");
+
+    assert_eq!(estimate.tournament_id, t_id);
+    assert_eq!(estimate.stages.len(), 1);
+    let stage = &estimate.stages[0];
+    assert_eq!(stage.stage_id, stage_id);
+    // round robin of 16 entrants: 120 matches in 15 rounds
+    assert_eq!(stage.num_matches, 120);
+    assert_eq!(stage.num_rounds, 15);
+    assert_eq!(stage.num_stations, 4);
+    assert_eq!(stage.num_slots, 30);
+    assert_eq!(estimate.num_matches(), 120);
+    // mock sport estimates zero minutes per match without changeover
+    assert_eq!(estimate.duration, Duration::ZERO);
+
+    let single = core
+        .estimate_stage_duration(stage_id)
+        .await
+        .unwrap()
+        .expect("stage exists");
+    assert_eq!(&single, stage);
+}
+
+/// 3) estimate_stage_duration(): more stations than matches per round do not shorten a stage
+#[tokio::test]
+async fn given_many_stations_when_estimate_stage_duration_then_rounds_limit_slots() {
+    let (core, db_fake, _cr_fake, spm) = make_core_with_fakes();
+    let sport_id = spm.list()[0].get_id_version().get_id();
+    let (t_id, stage_id) = seed_tournament(&db_fake, sport_id, 20);
+    seed_sport_config(&db_fake, t_id, sport_id);
+
+    let estimate = core
+        .estimate_stage_duration(stage_id)
+        .await
+        .unwrap()
+        .expect("stage exists");
+
+    assert_eq!(estimate.num_stations, 20);
+    assert_eq!(estimate.num_slots, 15);
+}
+
+/// 4) estimate_*_duration(): unknown ids have no estimate
+#[tokio::test]
+async fn given_unknown_ids_when_estimate_duration_then_none() {
+    let (core, _db_fake, _cr_fake, _spm) = make_core_with_fakes();
+
+    assert!(
+        core.estimate_tournament_duration(Uuid::new_v4())
+            .await
+            .unwrap()
+            .is_none()
+    );
+    assert!(
+        core.estimate_stage_duration(Uuid::new_v4())
+            .await
+            .unwrap()
+            .is_none()
+    );
+}
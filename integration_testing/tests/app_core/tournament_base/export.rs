@@ -0,0 +1,203 @@
+use app_core::{
+    CoreError, Entrant, Match, ScheduledEntrant, SportConfig, Stage,
+    TOURNAMENT_EXPORT_FORMAT_VERSION, TournamentExport,
+};
+use uuid::Uuid;
+
+use integration_testing::port_fakes::*;
+
+/// 1) export_tournament() + import_tournament(): JSON roundtrip creates copy with fresh ids
+#[tokio::test]
+async fn given_exported_tournament_when_import_then_copy_with_new_ids_is_saved() {
+    let (mut stage_core, _db_fake, _cr_fake) = make_core_stage_state_with_fakes();
+    let source_id = stage_core.get().get_tournament_id();
+
+    // Seed two stages of source tournament
+    for (number, num_groups) in [(0, 4), (1, 2)] {
+        stage_core
+            .get_mut()
+            .set_id_version(Default::default())
+            .set_number(number)
+            .set_num_groups(num_groups);
+        stage_core.save().await.expect("seed stage");
+    }
+
+    // Act: export, transfer as JSON, import
+    let export = stage_core
+        .export_tournament(source_id)
+        .await
+        .expect("export ok")
+        .expect("source exists");
+    assert_eq!(export.format_version, TOURNAMENT_EXPORT_FORMAT_VERSION);
+    assert_eq!(export.stages.len(), 2);
+    let json = export.to_json().expect("serialize");
+    let parsed = TournamentExport::from_json(&json).expect("parse");
+    assert_eq!(parsed, export);
+
+    let imported = stage_core
+        .import_tournament(parsed, Some("Imported".to_string()))
+        .await
+        .expect("import ok");
+
+    // Assert base
+    assert_ne!(imported.get_id(), source_id);
+    assert_eq!(imported.get_version(), Some(0));
+    assert_eq!(imported.get_name(), "Imported");
+    assert_eq!(
+        imported.get_num_entrants(),
+        export.tournament.get_num_entrants()
+    );
+    assert_eq!(
+        imported.get_tournament_mode(),
+        export.tournament.get_tournament_mode()
+    );
+
+    // Assert stages
+    let mut imported_stages = stage_core.as_stage_state(imported.get_id());
+    let imported_ids = imported_stages
+        .list_stage_ids_of_tournament()
+        .await
+        .expect("db ok");
+    assert_eq!(imported_ids.len(), 2);
+    for ((stage_id, number), source_stage) in imported_ids.into_iter().zip(&export.stages) {
+        assert_ne!(stage_id, source_stage.get_id());
+        assert_eq!(number, source_stage.get_number());
+        let stage = *imported_stages
+            .load_by_id(stage_id)
+            .await
+            .expect("db ok")
+            .expect("imported stage");
+        assert_eq!(stage.get_tournament_id(), imported.get_id());
+        assert_eq!(stage.get_num_groups(), source_stage.get_num_groups());
+    }
+}
+
+/// 2) export_tournament(): unknown tournament → None
+#[tokio::test]
+async fn given_unknown_tournament_when_export_then_none() {
+    let (core, _db_fake, _cr_fake) = make_core_tournament_base_state_with_fakes();
+
+    let res = core.export_tournament(Uuid::new_v4()).await.expect("db ok");
+    assert!(res.is_none());
+}
+
+/// 3) import_tournament(): newer format version is rejected before anything is saved
+#[tokio::test]
+async fn given_newer_format_version_when_import_then_parsing_error() {
+    let (stage_core, _db_fake, _cr_fake) = make_core_stage_state_with_fakes();
+    let source_id = stage_core.get().get_tournament_id();
+    let mut export = stage_core
+        .export_tournament(source_id)
+        .await
+        .expect("export ok")
+        .expect("source exists");
+    export.format_version = TOURNAMENT_EXPORT_FORMAT_VERSION + 1;
+
+    let err = stage_core
+        .import_tournament(export, Some("Future".to_string()))
+        .await
+        .expect_err("expected parsing error");
+    assert!(matches!(err, CoreError::ParsingError(_)));
+}
+
+/// 4) export_tournament() + import_tournament(): entrants and matches are copied with fresh
+/// ids and keep their references, address and venue of the exporting instance are reset and
+/// the sport config is kept
+#[tokio::test]
+async fn given_tournament_with_matches_when_import_then_references_use_fresh_ids() {
+    let (core, db_fake, _cr_fake) = make_core_tournament_base_state_with_fakes();
+    let mut tournament = make_tournament_base("Match Cup", &core);
+    let mut config = SportConfig::default();
+    config
+        .set_name("Match Cup rules")
+        .set_sport_id(tournament.get_sport_id());
+    let config_id = db_fake.seed_sport_config(config);
+    tournament
+        .set_sport_config_id(Some(config_id))
+        .set_venue_id(Some(Uuid::new_v4()))
+        .set_address_id(Some(Uuid::new_v4()));
+    let source_id = db_fake.seed_tournament_base(tournament);
+    let mut stage = Stage::default();
+    stage.set_tournament_id(source_id).set_number(0);
+    let stage_id = db_fake.seed_stage(stage);
+    let entrants: Vec<Uuid> = ["Ann", "Bob"]
+        .into_iter()
+        .map(|name| {
+            let mut entrant = Entrant::default();
+            entrant.set_tournament_id(source_id).set_name(name);
+            db_fake.seed_entrant(entrant)
+        })
+        .collect();
+    let (group_id, sport_id) = (Uuid::new_v4(), Uuid::new_v4());
+    let mut first = Match::new_scheduled(
+        Uuid::new_v4(),
+        group_id,
+        Uuid::new_v4(),
+        1,
+        ScheduledEntrant::Entrant(entrants[0]),
+        ScheduledEntrant::Entrant(entrants[1]),
+    );
+    first.set_tournament(source_id, sport_id, stage_id);
+    let mut next = Match::new_scheduled(
+        Uuid::new_v4(),
+        group_id,
+        Uuid::new_v4(),
+        1,
+        ScheduledEntrant::WinnerOf(*first.get_id()),
+        ScheduledEntrant::Bye,
+    );
+    next.set_tournament(source_id, sport_id, stage_id);
+    db_fake.seed_matches(vec![first.clone(), next.clone()]);
+
+    let export = core
+        .export_tournament(source_id)
+        .await
+        .expect("export ok")
+        .expect("source exists");
+    assert_eq!(export.entrants.len(), 2);
+    assert_eq!(export.matches.len(), 2);
+
+    let imported = core
+        .import_tournament(export, Some("Match Cup Copy".to_string()))
+        .await
+        .expect("import ok");
+
+    assert_eq!(imported.get_venue_id(), None);
+    assert_eq!(imported.get_address_id(), None);
+    // config of the same name exists on this instance and is used by the copy
+    assert_eq!(imported.get_sport_config_id(), Some(config_id));
+    let copy = core
+        .export_tournament(imported.get_id())
+        .await
+        .expect("export ok")
+        .expect("copy exists");
+    let copied_entrants: Vec<Uuid> = copy.entrants.iter().map(|e| e.get_id()).collect();
+    assert_eq!(copied_entrants.len(), 2);
+    assert!(copied_entrants.iter().all(|id| !entrants.contains(id)));
+    let copied_stage_id = copy.stages[0].get_id();
+    assert_ne!(copied_stage_id, stage_id);
+
+    let copied_first = copy
+        .matches
+        .iter()
+        .find(|m| matches!(m.get_sides().0, ScheduledEntrant::Entrant(_)))
+        .expect("first match is copied");
+    let copied_next = copy
+        .matches
+        .iter()
+        .find(|m| matches!(m.get_sides().0, ScheduledEntrant::WinnerOf(_)))
+        .expect("next match is copied");
+    assert_ne!(copied_first.get_id(), first.get_id());
+    assert_eq!(*copied_first.get_stage_id(), copied_stage_id);
+    assert_ne!(*copied_first.get_group_id(), group_id);
+    assert_eq!(copied_first.get_group_id(), copied_next.get_group_id());
+    let (ScheduledEntrant::Entrant(a), ScheduledEntrant::Entrant(b)) = copied_first.get_sides()
+    else {
+        panic!("sides of first match are entrants");
+    };
+    assert!(copied_entrants.contains(a) && copied_entrants.contains(b));
+    assert_eq!(
+        copied_next.get_sides().0,
+        &ScheduledEntrant::WinnerOf(*copied_first.get_id())
+    );
+}
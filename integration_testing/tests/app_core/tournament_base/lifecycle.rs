@@ -0,0 +1,190 @@
+use app_core::{CoreError, Match, ScheduledEntrant, TournamentState};
+use uuid::Uuid;
+
+use integration_testing::port_fakes::*;
+
+/// 1) start, complete and finish a tournament step by step
+#[tokio::test]
+async fn given_ready_tournament_when_run_transitions_then_states_advance_in_order() {
+    let (stage_core, db_fake, _cr_fake) = make_core_stage_state_with_fakes();
+    let tournament_id = stage_core.get().get_tournament_id();
+    db_fake.seed_readiness(tournament_id);
+    let mut base_core = stage_core.as_tournament_base_state();
+    base_core.load(tournament_id).await.expect("db ok");
+
+    let err = base_core.complete_stage().await.unwrap_err();
+    assert!(matches!(err, CoreError::Field(_)));
+
+    let started = base_core.start_tournament().await.expect("start ok");
+    assert_eq!(
+        started.get_tournament_state(),
+        TournamentState::ActiveStage(0)
+    );
+    let err = base_core.start_tournament().await.unwrap_err();
+    assert!(matches!(err, CoreError::Field(_)));
+    let err = base_core.finish_tournament().await.unwrap_err();
+    assert!(matches!(err, CoreError::Field(_)));
+
+    for active_stage in [1, 2] {
+        let saved = base_core.complete_stage().await.expect("complete ok");
+        assert_eq!(
+            saved.get_tournament_state(),
+            TournamentState::ActiveStage(active_stage)
+        );
+    }
+    let err = base_core.complete_stage().await.unwrap_err();
+    assert!(matches!(err, CoreError::Field(_)));
+
+    let finished = base_core.finish_tournament().await.expect("finish ok");
+    assert_eq!(finished.get_tournament_state(), TournamentState::Finished);
+}
+
+/// 2) save(): started tournaments cannot go back to an earlier state
+#[tokio::test]
+async fn given_running_tournament_when_save_earlier_state_then_error() {
+    let (stage_core, db_fake, _cr_fake) = make_core_stage_state_with_fakes();
+    let tournament_id = stage_core.get().get_tournament_id();
+    db_fake.seed_readiness(tournament_id);
+    let mut base_core = stage_core.as_tournament_base_state();
+    base_core.load(tournament_id).await.expect("db ok");
+    base_core.start_tournament().await.expect("start ok");
+    base_core.complete_stage().await.expect("complete ok");
+
+    for state in [TournamentState::Draft, TournamentState::ActiveStage(0)] {
+        base_core.get_mut().set_tournament_state(state);
+        let err = base_core.save().await.unwrap_err();
+        assert!(matches!(err, CoreError::Field(_)));
+        base_core.load(tournament_id).await.expect("db ok");
+    }
+    assert_eq!(
+        base_core.get().get_tournament_state(),
+        TournamentState::ActiveStage(1)
+    );
+}
+
+/// 3) stage save(): groups of started stages are fixed, later stages remain editable
+#[tokio::test]
+async fn given_started_stage_when_change_num_groups_then_error() {
+    let (stage_core, db_fake, _cr_fake) = make_core_stage_state_with_fakes();
+    let tournament_id = stage_core.get().get_tournament_id();
+    db_fake.seed_readiness(tournament_id);
+    let mut base_core = stage_core.as_tournament_base_state();
+    base_core.load(tournament_id).await.expect("db ok");
+    base_core.start_tournament().await.expect("start ok");
+
+    let mut stage_core = stage_core.as_stage_state(tournament_id);
+    stage_core.load_by_number(0).await.expect("db ok");
+    stage_core.get_mut().set_num_groups(4);
+    let err = stage_core.save().await.unwrap_err();
+    assert!(matches!(err, CoreError::Validation(_)));
+
+    // schedule settings of the active stage remain editable
+    stage_core.load_by_number(0).await.expect("db ok");
+    stage_core.get_mut().set_auto_advance(false);
+    stage_core.save().await.expect("setting of started stage");
+
+    stage_core.load_by_number(1).await.expect("db ok");
+    stage_core.get_mut().set_num_groups(4);
+    let saved = stage_core.save().await.expect("stage is not started");
+    assert_eq!(saved.get_num_groups(), 4);
+}
+
+/// 4) stage save(): completed stages are locked
+#[tokio::test]
+async fn given_completed_stage_when_change_schedule_then_error() {
+    let (stage_core, db_fake, _cr_fake) = make_core_stage_state_with_fakes();
+    let tournament_id = stage_core.get().get_tournament_id();
+    db_fake.seed_readiness(tournament_id);
+    let mut base_core = stage_core.as_tournament_base_state();
+    base_core.load(tournament_id).await.expect("db ok");
+    base_core.start_tournament().await.expect("start ok");
+    base_core.complete_stage().await.expect("complete ok");
+
+    let mut stage_core = stage_core.as_stage_state(tournament_id);
+    stage_core.load_by_number(0).await.expect("db ok");
+    stage_core.get_mut().set_auto_advance(false);
+    let err = stage_core.save().await.unwrap_err();
+    assert!(matches!(err, CoreError::Validation(_)));
+
+    stage_core.load_by_number(1).await.expect("db ok");
+    stage_core.get_mut().set_auto_advance(false);
+    stage_core.save().await.expect("setting of active stage");
+}
+
+/// 5) save(): mode and number of entrants of running tournaments are fixed
+#[tokio::test]
+async fn given_running_tournament_when_change_num_entrants_then_error() {
+    let (stage_core, db_fake, _cr_fake) = make_core_stage_state_with_fakes();
+    let tournament_id = stage_core.get().get_tournament_id();
+    db_fake.seed_readiness(tournament_id);
+    let mut base_core = stage_core.as_tournament_base_state();
+    base_core.load(tournament_id).await.expect("db ok");
+    base_core.start_tournament().await.expect("start ok");
+
+    base_core.get_mut().set_num_entrants(16);
+    let err = base_core.save().await.unwrap_err();
+    assert!(matches!(err, CoreError::Validation(_)));
+
+    base_core.load(tournament_id).await.expect("db ok");
+    base_core.get_mut().set_name("Renamed while running");
+    base_core.save().await.expect("name is not structural");
+}
+
+/// 6) save(): states advance one step at a time, stages complete with decided matches only
+#[tokio::test]
+async fn given_undecided_match_when_complete_stage_then_error() {
+    let (stage_core, db_fake, _cr_fake) = make_core_stage_state_with_fakes();
+    let tournament_id = stage_core.get().get_tournament_id();
+    db_fake.seed_readiness(tournament_id);
+    let mut base_core = stage_core.as_tournament_base_state();
+    base_core.load(tournament_id).await.expect("db ok");
+
+    base_core
+        .get_mut()
+        .set_tournament_state(TournamentState::Finished);
+    let err = base_core.save().await.unwrap_err();
+    assert!(matches!(err, CoreError::Field(_)));
+    base_core.load(tournament_id).await.expect("db ok");
+    base_core.start_tournament().await.expect("start ok");
+    base_core
+        .get_mut()
+        .set_tournament_state(TournamentState::ActiveStage(2));
+    let err = base_core.save().await.unwrap_err();
+    assert!(matches!(err, CoreError::Field(_)));
+    base_core.load(tournament_id).await.expect("db ok");
+
+    let stage_id = stage_core
+        .as_stage_state(tournament_id)
+        .load_by_number(0)
+        .await
+        .expect("db ok")
+        .expect("stage exists")
+        .get_id();
+    let mut open = Match::new_scheduled(
+        Uuid::new_v4(),
+        Uuid::new_v4(),
+        Uuid::new_v4(),
+        1,
+        ScheduledEntrant::Entrant(Uuid::new_v4()),
+        ScheduledEntrant::Entrant(Uuid::new_v4()),
+    );
+    open.set_tournament(tournament_id, Uuid::nil(), stage_id);
+    db_fake.seed_matches(vec![open.clone()]);
+
+    let err = base_core.complete_stage().await.unwrap_err();
+    assert!(matches!(err, CoreError::Field(_)));
+    base_core.load(tournament_id).await.expect("db ok");
+    assert_eq!(
+        base_core.get().get_tournament_state(),
+        TournamentState::ActiveStage(0)
+    );
+    assert!(db_fake.stage_snapshots_of(tournament_id).is_empty());
+
+    open.set_scores(vec![11], vec![7]);
+    db_fake.seed_matches(vec![open]);
+    let saved = base_core.complete_stage().await.expect("complete ok");
+    assert_eq!(
+        saved.get_tournament_state(),
+        TournamentState::ActiveStage(1)
+    );
+}
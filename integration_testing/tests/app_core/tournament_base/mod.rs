@@ -1,4 +1,16 @@
 //! testing app core api for tournament base with fakes
 
+mod batch_save;
 mod db_wrapper;
+mod display_board;
+mod duration_estimate;
+mod export;
+mod lifecycle;
+mod public_view;
+mod readiness;
 mod registry_wrapper;
+mod sandbox;
+mod seeding;
+mod stage_snapshot;
+mod stations;
+mod template;
@@ -0,0 +1,241 @@
+use app_core::{
+    Entrant, Language, LocalizedText, Match, ScheduledEntrant, TournamentState,
+    utils::id_version::IdVersion,
+};
+use uuid::Uuid;
+
+use integration_testing::port_fakes::*;
+
+/// 1) load_public_tournament(): drafts are hidden from the public
+#[tokio::test]
+async fn given_draft_tournament_when_load_public_then_none() {
+    let (stage_core, _db_fake, _cr_fake) = make_core_stage_state_with_fakes();
+    let tournament_id = stage_core.get().get_tournament_id();
+
+    let view = stage_core
+        .load_public_tournament(tournament_id)
+        .await
+        .expect("db ok");
+
+    assert!(view.is_none());
+}
+
+/// 2) load_public_tournament(): unknown tournament → None
+#[tokio::test]
+async fn given_unknown_tournament_when_load_public_then_none() {
+    let (stage_core, _db_fake, _cr_fake) = make_core_stage_state_with_fakes();
+
+    let view = stage_core
+        .load_public_tournament(Uuid::new_v4())
+        .await
+        .expect("db ok");
+
+    assert!(view.is_none());
+}
+
+/// 3) load_public_tournament(): stages with slot layout, KO bracket in final stage, entrants
+#[tokio::test]
+async fn given_published_tournament_when_load_public_then_stages_and_entrants_are_included() {
+    let (mut stage_core, db_fake, _cr_fake) = make_core_stage_state_with_fakes();
+    let tournament_id = stage_core.get().get_tournament_id();
+
+    // 32 entrants: 8 groups of 4, 4 groups of 8, final stage with 4 groups of 8
+    for (number, num_groups) in [(0, 8), (1, 4), (2, 4)] {
+        stage_core
+            .get_mut()
+            .set_id_version(Default::default())
+            .set_number(number)
+            .set_num_groups(num_groups);
+        stage_core.save().await.expect("seed stage");
+    }
+    for (name, seed) in [("Zebras", Some(1)), ("Ants", None)] {
+        let mut entrant = Entrant::new(IdVersion::default());
+        entrant
+            .set_tournament_id(tournament_id)
+            .set_name(name)
+            .set_seed(seed)
+            .set_email(Some("entrant@example.org"));
+        db_fake.seed_entrant(entrant);
+    }
+    db_fake.seed_readiness(tournament_id);
+    let mut base_core = stage_core.as_tournament_base_state();
+    base_core.load(tournament_id).await.expect("db ok");
+    base_core
+        .get_mut()
+        .set_tournament_state(TournamentState::Published);
+    base_core.save().await.expect("publish");
+
+    // Act
+    let view = stage_core
+        .load_public_tournament(tournament_id)
+        .await
+        .expect("db ok")
+        .expect("published tournament is public");
+
+    // Assert
+    assert_eq!(view.tournament.get_id(), tournament_id);
+    let names: Vec<&str> = view.stages.iter().map(|s| s.name.as_str()).collect();
+    assert_eq!(
+        names,
+        vec!["First Pool Stage", "Second Pool Stage", "Final Stage"]
+    );
+    assert_eq!(view.stages[0].groups.len(), 8);
+    assert_eq!(view.stages[0].groups[1].label, "B");
+    assert_eq!(view.stages[0].groups[1].num_entrants, 4);
+    // pool stages are played round robin, groups of final stage as KO bracket
+    assert!(view.stages[1].groups.iter().all(|g| g.bracket.is_empty()));
+    let bracket = &view.stages[2].groups[0].bracket;
+    assert_eq!(bracket.len(), 3);
+    assert_eq!(bracket[2].name, "Final");
+
+    // seeded entrants first, followed by entrants added for readiness
+    let entrants: Vec<&str> = view.entrants.iter().map(|e| e.get_name()).collect();
+    assert_eq!(entrants.len(), 32);
+    assert_eq!(entrants[..2], ["Zebras", "Ants"]);
+    // email addresses are not public
+    assert!(view.entrants.iter().all(|e| e.get_email().is_none()));
+}
+
+/// 4) load_public_tournament(): display languages and translated description
+#[tokio::test]
+async fn given_tournament_with_languages_when_load_public_then_description_falls_back() {
+    let (stage_core, db_fake, _cr_fake) = make_core_stage_state_with_fakes();
+    let tournament_id = stage_core.get().get_tournament_id();
+
+    db_fake.seed_readiness(tournament_id);
+    let mut base_core = stage_core.as_tournament_base_state();
+    base_core.load(tournament_id).await.expect("db ok");
+    let mut description = LocalizedText::new("Willkommen");
+    description
+        .set_translation(Language::En, "Welcome")
+        .set_translation(Language::Fr, "Bienvenue");
+    base_core
+        .get_mut()
+        .set_tournament_state(TournamentState::Published)
+        .set_description(description)
+        // French is not displayed; its translation is dropped
+        .set_languages([Language::De, Language::En]);
+    base_core.save().await.expect("publish");
+
+    // Act
+    let view = stage_core
+        .load_public_tournament(tournament_id)
+        .await
+        .expect("db ok")
+        .expect("published tournament is public");
+
+    // Assert
+    let tournament = &view.tournament;
+    assert_eq!(tournament.get_default_language(), Language::De);
+    assert!(tournament.has_language(Language::En));
+    assert!(!tournament.has_language(Language::Fr));
+    let description = tournament.get_description();
+    assert_eq!(description.get(Language::De), "Willkommen");
+    assert_eq!(description.get(Language::En), "Welcome");
+    assert_eq!(description.get_translation(Language::Fr), None);
+    assert_eq!(description.get(Language::Fr), "Willkommen");
+}
+
+/// 5) list_public_tournaments(): drafts are not listed
+#[tokio::test]
+async fn given_draft_and_published_tournament_when_list_public_then_only_published() {
+    let (stage_core, db_fake, _cr_fake) = make_core_stage_state_with_fakes();
+    let tournament_id = stage_core.get().get_tournament_id();
+
+    let listed = stage_core
+        .list_public_tournaments(None, 0)
+        .await
+        .expect("db ok");
+    assert!(listed.is_empty());
+
+    db_fake.seed_readiness(tournament_id);
+    let mut base_core = stage_core.as_tournament_base_state();
+    base_core.load(tournament_id).await.expect("db ok");
+    base_core
+        .get_mut()
+        .set_tournament_state(TournamentState::Published);
+    base_core.save().await.expect("publish");
+
+    // Act
+    let listed = stage_core
+        .list_public_tournaments(None, 0)
+        .await
+        .expect("db ok");
+    let skipped = stage_core
+        .list_public_tournaments(Some(10), 1)
+        .await
+        .expect("db ok");
+
+    // Assert
+    let ids: Vec<Uuid> = listed.iter().map(|t| t.get_id()).collect();
+    assert_eq!(ids, vec![tournament_id]);
+    assert!(skipped.is_empty());
+}
+
+/// 6) load_public_matches() and load_public_standings(): None for drafts, stored matches and
+/// one entry of standings per group of each stage for public tournaments
+#[tokio::test]
+async fn given_published_tournament_when_load_public_standings_then_one_entry_per_group() {
+    let (mut stage_core, db_fake, _cr_fake) = make_core_stage_state_with_fakes();
+    let tournament_id = stage_core.get().get_tournament_id();
+
+    stage_core.get_mut().set_number(0).set_num_groups(2);
+    stage_core.save().await.expect("seed stage");
+    let stage_id = stage_core.get().get_id();
+    let mut scheduled = Match::new_scheduled(
+        Uuid::new_v4(),
+        Uuid::new_v4(),
+        Uuid::new_v4(),
+        1,
+        ScheduledEntrant::Entrant(Uuid::new_v4()),
+        ScheduledEntrant::Entrant(Uuid::new_v4()),
+    );
+    let start_at = scheduled.get_start_at();
+    scheduled
+        .set_tournament(tournament_id, Uuid::new_v4(), stage_id)
+        .set_slot(1, start_at);
+    db_fake.seed_matches(vec![scheduled.clone()]);
+    db_fake.seed_readiness(tournament_id);
+
+    assert!(
+        stage_core
+            .load_public_matches(tournament_id)
+            .await
+            .expect("db ok")
+            .is_none()
+    );
+    assert!(
+        stage_core
+            .load_public_standings(tournament_id)
+            .await
+            .expect("db ok")
+            .is_none()
+    );
+
+    let mut base_core = stage_core.as_tournament_base_state();
+    base_core.load(tournament_id).await.expect("db ok");
+    base_core
+        .get_mut()
+        .set_tournament_state(TournamentState::Published);
+    base_core.save().await.expect("publish");
+
+    // Act
+    let matches = stage_core
+        .load_public_matches(tournament_id)
+        .await
+        .expect("db ok")
+        .expect("published tournament is public");
+    let standings = stage_core
+        .load_public_standings(tournament_id)
+        .await
+        .expect("db ok")
+        .expect("published tournament is public");
+
+    // Assert
+    assert_eq!(matches, vec![scheduled]);
+    let labels: Vec<(u32, &str)> = standings
+        .iter()
+        .map(|s| (s.stage_number, s.group_label.as_str()))
+        .collect();
+    assert_eq!(labels, vec![(0, "A"), (0, "B")]);
+}
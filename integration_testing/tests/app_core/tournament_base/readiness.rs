@@ -0,0 +1,173 @@
+use app_core::{
+    CoreError, Match, MatchOutcome, ReadinessCheck, ReadinessStatus, ScheduledEntrant,
+    TournamentState,
+};
+use uuid::Uuid;
+
+use integration_testing::port_fakes::*;
+
+/// 1) evaluate_readiness(): empty tournament fails all checkable items
+#[tokio::test]
+async fn given_empty_tournament_when_evaluate_then_checkable_items_fail() {
+    let (stage_core, _db_fake, _cr_fake) = make_core_stage_state_with_fakes();
+    let tournament_id = stage_core.get().get_tournament_id();
+
+    let checklist = stage_core
+        .load_readiness(tournament_id)
+        .await
+        .expect("db ok")
+        .expect("tournament exists");
+
+    assert!(!checklist.is_ready());
+    let checks: Vec<ReadinessCheck> = checklist.items.iter().map(|i| i.check).collect();
+    assert_eq!(checks, ReadinessCheck::ALL);
+    for check in ReadinessCheck::ALL {
+        assert_eq!(
+            checklist.get(check).unwrap().status,
+            ReadinessStatus::Failed
+        );
+    }
+    let schedule = checklist.get(ReadinessCheck::Schedule).unwrap();
+    assert_eq!(
+        schedule.message,
+        "First Pool Stage has no scheduled matches"
+    );
+    assert_eq!(schedule.stage_number, Some(0));
+    assert_eq!(
+        checklist.get(ReadinessCheck::Entrants).unwrap().message,
+        "0 of 32 entrants registered"
+    );
+}
+
+/// 2) evaluate_readiness(): first missing stage is linked
+#[tokio::test]
+async fn given_missing_second_stage_when_evaluate_then_stage_number_is_set() {
+    let (mut stage_core, _db_fake, _cr_fake) = make_core_stage_state_with_fakes();
+    let tournament_id = stage_core.get().get_tournament_id();
+    for number in [0, 2] {
+        stage_core
+            .get_mut()
+            .set_id_version(Default::default())
+            .set_number(number)
+            .set_num_groups(4);
+        stage_core.save().await.expect("seed stage");
+    }
+
+    let checklist = stage_core
+        .load_readiness(tournament_id)
+        .await
+        .expect("db ok")
+        .expect("tournament exists");
+
+    let stages = checklist.get(ReadinessCheck::Stages).unwrap();
+    assert_eq!(stages.status, ReadinessStatus::Failed);
+    assert_eq!(stages.stage_number, Some(1));
+    assert_eq!(stages.message, "Second Pool Stage is not configured");
+}
+
+/// 3) save(): publish and start are blocked until checklist passes
+#[tokio::test]
+async fn given_unready_tournament_when_publish_then_blocked_until_ready() {
+    let (stage_core, db_fake, _cr_fake) = make_core_stage_state_with_fakes();
+    let tournament_id = stage_core.get().get_tournament_id();
+    let mut base_core = stage_core.as_tournament_base_state();
+    base_core.load(tournament_id).await.expect("db ok");
+
+    for state in [TournamentState::Published, TournamentState::ActiveStage(0)] {
+        base_core.get_mut().set_tournament_state(state);
+        let err = base_core
+            .save()
+            .await
+            .expect_err("transition must be blocked");
+        assert!(matches!(err, CoreError::Validation(_)));
+    }
+    base_core.load(tournament_id).await.expect("db ok");
+    assert_eq!(
+        base_core.get().get_tournament_state(),
+        TournamentState::Draft
+    );
+
+    // drafts may still be edited
+    base_core.get_mut().set_name("Still Draft");
+    base_core.save().await.expect("draft is not gated");
+
+    db_fake.seed_readiness(tournament_id);
+    base_core
+        .get_mut()
+        .set_tournament_state(TournamentState::Published);
+    base_core
+        .save()
+        .await
+        .expect("ready tournament is published");
+    base_core
+        .get_mut()
+        .set_tournament_state(TournamentState::ActiveStage(0));
+    base_core.save().await.expect("ready tournament is started");
+}
+
+/// 4) evaluate_readiness(): matches of the first stage must be placed on existing stations
+#[tokio::test]
+async fn given_matches_without_station_when_evaluate_then_stations_fail() {
+    let (mut stage_core, db_fake, _cr_fake) = make_core_stage_state_with_fakes();
+    let tournament_id = stage_core.get().get_tournament_id();
+    stage_core.get_mut().set_number(0).set_num_groups(8);
+    let stage_id = stage_core.save().await.expect("seed stage").get_id();
+    let new_match = |station| {
+        let mut m = Match::new_scheduled(
+            Uuid::new_v4(),
+            Uuid::new_v4(),
+            Uuid::new_v4(),
+            1,
+            ScheduledEntrant::Entrant(Uuid::new_v4()),
+            ScheduledEntrant::Entrant(Uuid::new_v4()),
+        );
+        let start_at = m.get_start_at();
+        m.set_tournament(tournament_id, Uuid::new_v4(), stage_id)
+            .set_slot(station, start_at);
+        m
+    };
+    let mut bye = new_match(0);
+    bye.set_outcome(MatchOutcome::Bye);
+    let mut unassigned = new_match(0);
+    db_fake.seed_matches(vec![new_match(1), unassigned.clone(), bye]);
+
+    let checklist = stage_core
+        .load_readiness(tournament_id)
+        .await
+        .expect("db ok")
+        .expect("tournament exists");
+
+    let schedule = checklist.get(ReadinessCheck::Schedule).unwrap();
+    assert_eq!(schedule.status, ReadinessStatus::Passed);
+    assert_eq!(schedule.message, "3 matches of First Pool Stage scheduled");
+    let stations = checklist.get(ReadinessCheck::Stations).unwrap();
+    assert_eq!(stations.status, ReadinessStatus::Failed);
+    assert_eq!(stations.message, "1 matches are not assigned to a station");
+
+    // the tournament has one station
+    let start_at = unassigned.get_start_at();
+    unassigned.set_slot(2, start_at);
+    db_fake.seed_matches(vec![unassigned.clone()]);
+    let checklist = stage_core
+        .load_readiness(tournament_id)
+        .await
+        .expect("db ok")
+        .expect("tournament exists");
+    let stations = checklist.get(ReadinessCheck::Stations).unwrap();
+    assert_eq!(stations.status, ReadinessStatus::Failed);
+    assert_eq!(
+        stations.message,
+        "1 matches are assigned to stations beyond 1"
+    );
+
+    unassigned.set_slot(1, start_at);
+    db_fake.seed_matches(vec![unassigned]);
+    let checklist = stage_core
+        .load_readiness(tournament_id)
+        .await
+        .expect("db ok")
+        .expect("tournament exists");
+    let stations = checklist.get(ReadinessCheck::Stations).unwrap();
+    assert_eq!(stations.status, ReadinessStatus::Passed);
+    assert_eq!(stations.message, "2 matches assigned to stations");
+}
@@ -1,4 +1,7 @@
-use app_core::{CoreError, CrError, CrMsg, DbError};
+use app_core::{
+    CoreError, CrError, CrMsg, DbError, TournamentBaseCondition,
+    utils::{filter::Filter, list_order::ListOrder},
+};
 
 use integration_testing::port_fakes::*;
 
@@ -134,7 +137,14 @@ async fn given_read_operations_when_invoked_then_never_publish_anything() {
     let any_id = core.get().get_id();
     let _ = core.load(any_id).await.expect("load ok");
     let _ = core
-        .list_tournament_base_ids(sport_id, None, None, false, Some(10))
+        .list_tournament_base_ids(
+            &Filter::new()
+                .with(TournamentBaseCondition::SportIs(sport_id))
+                .with(TournamentBaseCondition::NotAdhoc)
+                .with(TournamentBaseCondition::NotArchived)
+                .limit(Some(10)),
+            &ListOrder::default(),
+        )
         .await
         .expect("list ok");
 
@@ -0,0 +1,117 @@
+use app_core::{CoreError, Entrant, TournamentState, utils::id_version::IdVersion};
+
+use integration_testing::port_fakes::*;
+
+/// 1) save(): sandbox tournaments start without passing the readiness checklist
+#[tokio::test]
+async fn given_sandbox_tournament_when_start_without_readiness_then_ok() {
+    let (stage_core, _db_fake, _cr_fake) = make_core_stage_state_with_fakes();
+    let tournament_id = stage_core.get().get_tournament_id();
+    let mut base_core = stage_core.as_tournament_base_state();
+    base_core.load(tournament_id).await.expect("db ok");
+
+    base_core
+        .get_mut()
+        .set_tournament_state(TournamentState::ActiveStage(0));
+    let err = base_core
+        .save()
+        .await
+        .expect_err("real tournament is gated");
+    assert!(matches!(err, CoreError::Validation(_)));
+
+    base_core.load(tournament_id).await.expect("db ok");
+    base_core
+        .get_mut()
+        .set_sandbox(true)
+        .set_tournament_state(TournamentState::ActiveStage(0));
+    let saved = base_core.save().await.expect("sandbox is not gated");
+    assert_eq!(
+        saved.get_tournament_state(),
+        TournamentState::ActiveStage(0)
+    );
+    assert!(!saved.is_locked_by_state());
+}
+
+/// 2) load_public_tournament(): sandbox tournaments are hidden from the public
+#[tokio::test]
+async fn given_published_sandbox_tournament_when_load_public_then_none() {
+    let (stage_core, _db_fake, _cr_fake) = make_core_stage_state_with_fakes();
+    let tournament_id = stage_core.get().get_tournament_id();
+    let mut base_core = stage_core.as_tournament_base_state();
+    base_core.load(tournament_id).await.expect("db ok");
+    base_core
+        .get_mut()
+        .set_sandbox(true)
+        .set_tournament_state(TournamentState::Published);
+    base_core.save().await.expect("save ok");
+
+    let view = base_core
+        .load_public_tournament(tournament_id)
+        .await
+        .expect("db ok");
+
+    assert!(view.is_none());
+}
+
+/// 3) purge_sandbox(): tournament, stages and entrants are removed
+#[tokio::test]
+async fn given_sandbox_tournament_when_purge_then_all_data_is_removed() {
+    let (mut stage_core, db_fake, _cr_fake) = make_core_stage_state_with_fakes();
+    let tournament_id = stage_core.get().get_tournament_id();
+    stage_core.get_mut().set_number(0).set_num_groups(4);
+    stage_core.save().await.expect("seed stage");
+    let mut entrant = Entrant::new(IdVersion::default());
+    entrant.set_tournament_id(tournament_id).set_name("Ants");
+    db_fake.seed_entrant(entrant);
+
+    let mut base_core = stage_core.as_tournament_base_state();
+    base_core.load(tournament_id).await.expect("db ok");
+    base_core.get_mut().set_sandbox(true);
+    base_core.save().await.expect("save ok");
+
+    // Act
+    base_core.purge_sandbox().await.expect("purge ok");
+
+    // Assert
+    assert!(
+        base_core
+            .load(tournament_id)
+            .await
+            .expect("db ok")
+            .is_none()
+    );
+    let stages = stage_core
+        .list_stage_ids_of_tournament()
+        .await
+        .expect("db ok");
+    assert!(stages.is_empty());
+    let entrants = stage_core
+        .as_entrant_state(tournament_id)
+        .list_entrants()
+        .await
+        .expect("db ok");
+    assert!(entrants.is_empty());
+}
+
+/// 4) purge_sandbox(): real tournaments cannot be purged
+#[tokio::test]
+async fn given_real_tournament_when_purge_then_field_error_and_tournament_is_kept() {
+    let (stage_core, _db_fake, _cr_fake) = make_core_stage_state_with_fakes();
+    let tournament_id = stage_core.get().get_tournament_id();
+    let mut base_core = stage_core.as_tournament_base_state();
+    base_core.load(tournament_id).await.expect("db ok");
+
+    let err = base_core.purge_sandbox().await.expect_err("expected error");
+
+    match err {
+        CoreError::Field(field_error) => assert_eq!(field_error.get_field(), "sandbox"),
+        other => panic!("unexpected error variant: {other:?}"),
+    }
+    assert!(
+        base_core
+            .load(tournament_id)
+            .await
+            .expect("db ok")
+            .is_some()
+    );
+}
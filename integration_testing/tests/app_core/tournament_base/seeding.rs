@@ -0,0 +1,251 @@
+use app_core::{
+    CachedGroupStandings, Entrant, EntrantGroupScore, EntrantRank, GroupStanding, Match,
+    SavedGroupSeeding, ScheduledEntrant, SeedingStrategy, Stage, TournamentBase, TournamentMode,
+    TournamentState,
+};
+use chrono::{Local, TimeZone};
+use uuid::Uuid;
+
+use integration_testing::port_fakes::*;
+
+fn seed_entrants(db: &FakeDatabasePort, t_id: Uuid) {
+    for (n, name) in [
+        "Ants", "Bees", "Cats", "Dogs", "Eels", "Foxes", "Gnus", "Hens",
+    ]
+    .into_iter()
+    .enumerate()
+    {
+        let mut entrant = Entrant::default();
+        entrant
+            .set_tournament_id(t_id)
+            .set_name(name)
+            .set_seed(Some(n as u32 + 1));
+        db.seed_entrant(entrant);
+    }
+}
+
+fn group_names(groups: &[Vec<Entrant>]) -> Vec<Vec<&str>> {
+    groups
+        .iter()
+        .map(|g| g.iter().map(|e| e.get_name()).collect())
+        .collect()
+}
+
+/// 1) seed_first_stage(): ranks of ranking system decide order before own seeds
+#[tokio::test]
+async fn given_ranking_system_when_seed_first_stage_then_ranks_are_used() {
+    let (core, db, ranking, t_id) = make_core_with_ranking_fake();
+    seed_entrants(&db, t_id);
+    ranking.set_rank("Hens", 1);
+    ranking.set_rank("Gnus", 2);
+
+    let seeding = core
+        .seed_first_stage(t_id, SeedingStrategy::CountingThrough)
+        .await
+        .expect("db ok")
+        .expect("tournament and stage exist");
+
+    assert_eq!(seeding.ranking_system.as_deref(), Some("Fake Ranking"));
+    assert_eq!(
+        group_names(&seeding.groups),
+        vec![
+            vec!["Hens", "Ants", "Cats", "Eels"],
+            vec!["Gnus", "Bees", "Dogs", "Foxes"],
+        ]
+    );
+}
+
+/// 2) seed_first_stage(): failure of ranking system falls back to seeds of entrants
+#[tokio::test]
+async fn given_failing_ranking_system_when_seed_first_stage_then_seeds_are_used() {
+    let (core, db, ranking, t_id) = make_core_with_ranking_fake();
+    seed_entrants(&db, t_id);
+    ranking.set_rank("Hens", 1);
+    ranking.fail_fetch_once();
+
+    let seeding = core
+        .seed_first_stage(t_id, SeedingStrategy::CountingThrough)
+        .await
+        .expect("ranking failure must not fail seeding")
+        .expect("tournament and stage exist");
+
+    assert_eq!(seeding.ranking_system, None);
+    assert_eq!(
+        group_names(&seeding.groups),
+        vec![
+            vec!["Ants", "Cats", "Eels", "Gnus"],
+            vec!["Bees", "Dogs", "Foxes", "Hens"],
+        ]
+    );
+}
+
+/// 3) save_group_seeding(): moved entrants are saved, missing entrants are rejected
+#[tokio::test]
+async fn given_moved_entrant_when_save_group_seeding_then_missing_entrants_are_rejected() {
+    let (core, db, _ranking, t_id) = make_core_with_ranking_fake();
+    seed_entrants(&db, t_id);
+
+    let mut seeding = core
+        .seed_first_stage(t_id, SeedingStrategy::Snake)
+        .await
+        .expect("db ok")
+        .expect("tournament and stage exist");
+    assert_eq!(
+        group_names(&seeding.groups),
+        vec![
+            vec!["Ants", "Dogs", "Eels", "Hens"],
+            vec!["Bees", "Cats", "Foxes", "Gnus"],
+        ]
+    );
+
+    let ants = seeding.groups[0][0].get_id();
+    assert!(seeding.move_entrant(ants, 1, 4));
+    let saved = core
+        .save_group_seeding(seeding.clone())
+        .await
+        .expect("complete seeding is valid")
+        .expect("tournament and stage exist");
+    assert_eq!(group_names(&saved.groups)[1].last(), Some(&"Ants"));
+    assert_eq!(
+        db.group_seeding_of(seeding.stage_id),
+        Some(SavedGroupSeeding::from(&seeding))
+    );
+
+    seeding.groups[1].pop();
+    assert!(core.save_group_seeding(seeding.clone()).await.is_err());
+    assert_eq!(
+        db.group_seeding_of(seeding.stage_id)
+            .map(|s| s.groups[1].len()),
+        Some(5)
+    );
+}
+
+/// 4) push_final_results(): standings are pushed to ranking system of sport
+#[tokio::test]
+async fn given_ranking_system_when_push_final_results_then_standings_are_pushed() {
+    let (core, _db, ranking, t_id) = make_core_with_ranking_fake();
+    let tournament = core
+        .as_tournament_base_state()
+        .load(t_id)
+        .await
+        .expect("db ok")
+        .expect("tournament exists")
+        .clone();
+    let standings = vec![EntrantRank {
+        entrant_id: Uuid::new_v4(),
+        rank: 1,
+    }];
+
+    assert!(core.push_final_results(&tournament, &standings).await);
+    assert_eq!(ranking.pushed(), vec![(t_id, standings)]);
+}
+
+/// 5) load_group_seeding(): saved seeding is loaded, until entrants change
+#[tokio::test]
+async fn given_saved_seeding_when_load_group_seeding_then_saved_groups_are_loaded() {
+    let (core, db, _ranking, t_id) = make_core_with_ranking_fake();
+    seed_entrants(&db, t_id);
+
+    let mut seeding = core
+        .seed_first_stage(t_id, SeedingStrategy::RankedBlock)
+        .await
+        .expect("db ok")
+        .expect("tournament and stage exist");
+    let ants = seeding.groups[0][0].get_id();
+    assert!(seeding.move_entrant(ants, 1, 0));
+    core.save_group_seeding(seeding.clone())
+        .await
+        .expect("complete seeding is valid");
+
+    let loaded = core
+        .load_group_seeding(t_id)
+        .await
+        .expect("db ok")
+        .expect("tournament and stage exist");
+    assert_eq!(loaded, seeding);
+
+    // a new entrant is not part of the saved seeding: seed again with default strategy
+    let mut late = Entrant::default();
+    late.set_tournament_id(t_id).set_name("Ibis");
+    db.seed_entrant(late);
+    let loaded = core
+        .load_group_seeding(t_id)
+        .await
+        .expect("db ok")
+        .expect("tournament and stage exist");
+    assert_eq!(loaded.strategy, SeedingStrategy::Snake);
+    assert_eq!(loaded.groups.iter().flatten().count(), 9);
+}
+
+/// 6) finish_tournament(): final standings of the last stage are pushed to ranking system
+#[tokio::test]
+async fn given_finished_tournament_when_finish_then_final_standings_are_pushed() {
+    let (core, db, ranking, ranked_id) = make_core_with_ranking_fake();
+    let sport_id = core
+        .as_tournament_base_state()
+        .load(ranked_id)
+        .await
+        .expect("db ok")
+        .expect("tournament exists")
+        .get_sport_id();
+    let mut tb = TournamentBase::default();
+    tb.set_name("Final Ranking Tournament")
+        .set_sport_id(sport_id)
+        .set_num_entrants(4)
+        .set_tournament_mode(TournamentMode::PoolAndFinalStage)
+        .set_tournament_state(TournamentState::ActiveStage(1));
+    let t_id = db.seed_tournament_base(tb);
+    let mut stage = Stage::default();
+    stage
+        .set_tournament_id(t_id)
+        .set_number(1)
+        .set_num_groups(2);
+    let stage_id = db.seed_stage(stage);
+
+    // final stage with a group for places 1-2 and a group for places 3-4; the order of groups
+    // is given by their numbers, not by their slots
+    let [a, b, c, d]: [Uuid; 4] = std::array::from_fn(|_| Uuid::new_v4());
+    let (upper, lower) = (Uuid::new_v4(), Uuid::new_v4());
+    let mut matches = Vec::new();
+    for (group_id, group_number, hour, side_a, side_b) in
+        [(upper, 0, 10, a, b), (lower, 1, 9, c, d)]
+    {
+        let mut m = Match::new_scheduled(
+            Uuid::new_v4(),
+            group_id,
+            Uuid::new_v4(),
+            1,
+            ScheduledEntrant::Entrant(side_a),
+            ScheduledEntrant::Entrant(side_b),
+        );
+        m.set_tournament(t_id, sport_id, stage_id)
+            .set_group_number(group_number)
+            .set_slot(1, Local.with_ymd_and_hms(2026, 5, 1, hour, 0, 0).unwrap())
+            .set_scores(vec![11], vec![7]);
+        matches.push(m);
+    }
+    db.seed_matches(matches);
+    for (group_id, ranks) in [(upper, [(a, 1), (b, 2)]), (lower, [(c, 1), (d, 1)])] {
+        let standings = ranks
+            .iter()
+            .map(|(entrant_id, rank)| GroupStanding {
+                rank: *rank,
+                score: EntrantGroupScore::new(*entrant_id, group_id),
+            })
+            .collect();
+        db.seed_group_standings(CachedGroupStandings::new(group_id, t_id, standings));
+    }
+
+    let mut base_core = core.as_tournament_base_state();
+    base_core.load(t_id).await.expect("db ok");
+    base_core
+        .finish_tournament()
+        .await
+        .expect("last stage is decided");
+
+    let rank = |entrant_id, rank| EntrantRank { entrant_id, rank };
+    assert_eq!(
+        ranking.pushed(),
+        vec![(t_id, vec![rank(a, 1), rank(b, 2), rank(c, 3), rank(d, 3)])]
+    );
+}
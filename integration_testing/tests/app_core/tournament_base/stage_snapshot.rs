@@ -0,0 +1,224 @@
+use app_core::{
+    CachedGroupStandings, CoreError, EntrantGroupScore, GroupStanding, Match, ScheduledEntrant,
+    Stage, TournamentState,
+};
+use uuid::Uuid;
+
+use integration_testing::port_fakes::*;
+
+fn cached_standings(tournament_id: Uuid, group_id: Uuid) -> CachedGroupStandings {
+    let standings = (1..=3)
+        .map(|rank| GroupStanding {
+            rank,
+            score: EntrantGroupScore::new(Uuid::new_v4(), group_id),
+        })
+        .collect();
+    CachedGroupStandings::new(group_id, tournament_id, standings)
+}
+
+fn seed_stage(db_fake: &FakeDatabasePort, tournament_id: Uuid, number: u32) -> Uuid {
+    let mut stage = Stage::default();
+    stage
+        .set_tournament_id(tournament_id)
+        .set_number(number)
+        .set_num_groups(1);
+    db_fake.seed_stage(stage)
+}
+
+fn stored_match(db_fake: &FakeDatabasePort, tournament_id: Uuid, id: &Uuid) -> Match {
+    db_fake
+        .matches_of(tournament_id)
+        .into_iter()
+        .find(|m| m.get_id() == id)
+        .expect("match is stored")
+}
+
+/// 1) complete_stage(): standings of the completed stage are snapshotted
+#[tokio::test]
+async fn given_active_stage_when_complete_stage_then_snapshot_taken() {
+    let (stage_core, db_fake, _cr_fake) = make_core_stage_state_with_fakes();
+    let tournament_id = stage_core.get().get_tournament_id();
+    db_fake.seed_readiness(tournament_id);
+    let cached = cached_standings(tournament_id, Uuid::new_v4());
+    db_fake.seed_group_standings(cached.clone());
+    let mut base_core = stage_core.as_tournament_base_state();
+    base_core.load(tournament_id).await.expect("db ok");
+    base_core.start_tournament().await.expect("start ok");
+
+    base_core.complete_stage().await.expect("complete ok");
+    base_core.complete_stage().await.expect("complete ok");
+
+    let snapshots = base_core
+        .list_stage_snapshots(tournament_id)
+        .await
+        .expect("db ok");
+    assert_eq!(
+        snapshots.iter().map(|s| s.stage_number).collect::<Vec<_>>(),
+        vec![0, 1]
+    );
+    assert_eq!(snapshots[0].standings, vec![cached]);
+}
+
+/// 2) rollback_to_snapshot(): standings and active stage are restored, later snapshots dropped
+#[tokio::test]
+async fn given_completed_stages_when_rollback_then_stage_active_again() {
+    let (stage_core, db_fake, _cr_fake) = make_core_stage_state_with_fakes();
+    let tournament_id = stage_core.get().get_tournament_id();
+    db_fake.seed_readiness(tournament_id);
+    let cached = cached_standings(tournament_id, Uuid::new_v4());
+    db_fake.seed_group_standings(cached.clone());
+    let mut base_core = stage_core.as_tournament_base_state();
+    base_core.load(tournament_id).await.expect("db ok");
+    base_core.start_tournament().await.expect("start ok");
+    base_core.complete_stage().await.expect("complete ok");
+    base_core.complete_stage().await.expect("complete ok");
+    let snapshot = db_fake.stage_snapshots_of(tournament_id)[0].clone();
+
+    // data-entry mistake is corrected after promotion
+    db_fake.seed_group_standings(cached_standings(tournament_id, cached.group_id));
+
+    let rolled_back = base_core
+        .rollback_to_snapshot(snapshot.id)
+        .await
+        .expect("rollback ok");
+
+    assert_eq!(
+        rolled_back.get_tournament_state(),
+        TournamentState::ActiveStage(0)
+    );
+    assert_eq!(db_fake.group_standings_of(cached.group_id), Some(cached));
+    assert!(db_fake.stage_snapshots_of(tournament_id).is_empty());
+
+    // stage is completed again with a new snapshot
+    base_core.complete_stage().await.expect("complete ok");
+    assert_eq!(db_fake.stage_snapshots_of(tournament_id).len(), 1);
+}
+
+/// 3) rollback_to_snapshot(): discarded and foreign snapshots are rejected
+#[tokio::test]
+async fn given_unknown_snapshot_when_rollback_then_error() {
+    let (stage_core, db_fake, _cr_fake) = make_core_stage_state_with_fakes();
+    let tournament_id = stage_core.get().get_tournament_id();
+    db_fake.seed_readiness(tournament_id);
+    let mut base_core = stage_core.as_tournament_base_state();
+    base_core.load(tournament_id).await.expect("db ok");
+    base_core.start_tournament().await.expect("start ok");
+    base_core.complete_stage().await.expect("complete ok");
+    let snapshot = db_fake.stage_snapshots_of(tournament_id)[0].clone();
+    base_core
+        .rollback_to_snapshot(snapshot.id)
+        .await
+        .expect("rollback ok");
+
+    let err = base_core
+        .rollback_to_snapshot(snapshot.id)
+        .await
+        .unwrap_err();
+    assert!(matches!(err, CoreError::Field(_)));
+    let err = base_core
+        .rollback_to_snapshot(Uuid::new_v4())
+        .await
+        .unwrap_err();
+    assert!(matches!(err, CoreError::Field(_)));
+    assert_eq!(
+        base_core.get().get_tournament_state(),
+        TournamentState::ActiveStage(0)
+    );
+}
+
+/// 4) complete_stage(): stage is not completed, if its snapshot cannot be saved
+#[tokio::test]
+async fn given_failing_snapshot_when_complete_stage_then_error_and_stage_active() {
+    let (stage_core, db_fake, _cr_fake) = make_core_stage_state_with_fakes();
+    let tournament_id = stage_core.get().get_tournament_id();
+    db_fake.seed_readiness(tournament_id);
+    let mut base_core = stage_core.as_tournament_base_state();
+    base_core.load(tournament_id).await.expect("db ok");
+    base_core.start_tournament().await.expect("start ok");
+
+    db_fake.fail_save_snapshot_once();
+    let err = base_core.complete_stage().await.unwrap_err();
+    assert!(matches!(err, CoreError::Db(_)));
+
+    base_core.load(tournament_id).await.expect("db ok");
+    assert_eq!(
+        base_core.get().get_tournament_state(),
+        TournamentState::ActiveStage(0)
+    );
+    assert!(db_fake.stage_snapshots_of(tournament_id).is_empty());
+}
+
+/// 5) rollback_to_snapshot(): matches of the stage are restored, results and cached standings
+///    of later stages dropped
+#[tokio::test]
+async fn given_played_matches_when_rollback_then_matches_restored_and_later_results_dropped() {
+    let (stage_core, db_fake, _cr_fake) = make_core_stage_state_with_fakes();
+    let tournament_id = stage_core.get().get_tournament_id();
+    let first_stage_id = seed_stage(&db_fake, tournament_id, 0);
+    let second_stage_id = seed_stage(&db_fake, tournament_id, 1);
+    let (group_id, later_group_id) = (Uuid::new_v4(), Uuid::new_v4());
+    let (a, b) = (Uuid::new_v4(), Uuid::new_v4());
+    let mut played = Match::new_scheduled(
+        Uuid::new_v4(),
+        group_id,
+        Uuid::new_v4(),
+        1,
+        ScheduledEntrant::Entrant(a),
+        ScheduledEntrant::Entrant(b),
+    );
+    let start_at = played.get_start_at();
+    played
+        .set_tournament(tournament_id, Uuid::nil(), first_stage_id)
+        .set_slot(1, start_at)
+        .set_scores(vec![11], vec![5]);
+    let mut promoted = Match::new_scheduled(
+        Uuid::new_v4(),
+        later_group_id,
+        Uuid::new_v4(),
+        1,
+        ScheduledEntrant::GroupRank(group_id, 0),
+        ScheduledEntrant::GroupRank(group_id, 1),
+    );
+    promoted
+        .set_tournament(tournament_id, Uuid::nil(), second_stage_id)
+        .set_slot(1, start_at);
+    db_fake.seed_matches(vec![played.clone(), promoted.clone()]);
+    db_fake.seed_readiness(tournament_id);
+    let mut base_core = stage_core.as_tournament_base_state();
+    base_core.load(tournament_id).await.expect("db ok");
+    base_core.start_tournament().await.expect("start ok");
+    base_core.complete_stage().await.expect("complete ok");
+    let snapshot = db_fake.stage_snapshots_of(tournament_id)[0].clone();
+    assert_eq!(snapshot.matches, vec![played.clone()]);
+
+    // promoted match is played, then a data-entry mistake is discovered
+    promoted.resolve_sides(|side| match side {
+        ScheduledEntrant::GroupRank(_, 0) => Some(a),
+        _ => Some(b),
+    });
+    promoted.set_scores(vec![11], vec![9]);
+    let mut mistaken = played.clone();
+    mistaken.set_scores(vec![5], vec![11]);
+    db_fake.seed_matches(vec![mistaken, promoted.clone()]);
+    db_fake.seed_group_standings(cached_standings(tournament_id, later_group_id));
+
+    base_core
+        .rollback_to_snapshot(snapshot.id)
+        .await
+        .expect("rollback ok");
+
+    assert_eq!(
+        stored_match(&db_fake, tournament_id, played.get_id()),
+        played
+    );
+    let reset = stored_match(&db_fake, tournament_id, promoted.get_id());
+    assert!(!reset.is_decided());
+    assert_eq!(
+        reset.get_sides(),
+        (
+            &ScheduledEntrant::GroupRank(group_id, 0),
+            &ScheduledEntrant::GroupRank(group_id, 1)
+        )
+    );
+    assert_eq!(db_fake.group_standings_of(later_group_id), None);
+}
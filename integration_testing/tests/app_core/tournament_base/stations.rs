@@ -0,0 +1,100 @@
+use app_core::{CoreError, Station, StationSetup, TournamentState};
+
+use integration_testing::port_fakes::*;
+
+fn court_setup(count: u32) -> StationSetup {
+    StationSetup {
+        name_prefix: String::from("Court"),
+        count,
+        first_number: 1,
+        venue: String::from("Main Hall"),
+    }
+}
+
+/// 1) create_stations(): numbered stations are saved and set the number of stations
+#[tokio::test]
+async fn given_setup_when_create_stations_then_stations_and_count_are_saved() {
+    let (mut core, _db_fake, _cr_fake) = make_core_tournament_base_state_with_fakes();
+    *core.get_mut() = make_tournament_base("Tournament A", &core);
+    let id = core.save().await.expect("initial save").get_id();
+
+    // Act
+    core.create_stations(&court_setup(8))
+        .await
+        .expect("create stations");
+
+    // Assert
+    let loaded = core
+        .load(id)
+        .await
+        .expect("db ok")
+        .expect("tournament exists");
+    assert_eq!(loaded.get_num_stations(), 8);
+    assert_eq!(loaded.get_stations().len(), 8);
+    assert_eq!(loaded.get_station_name(1), Some("Court 1"));
+    assert_eq!(loaded.get_station_name(8), Some("Court 8"));
+    assert!(
+        loaded
+            .get_stations()
+            .iter()
+            .all(|s| s.get_venue() == "Main Hall")
+    );
+}
+
+/// 2) create_stations(): invalid setup is rejected and nothing is saved
+#[tokio::test]
+async fn given_invalid_setup_when_create_stations_then_validation_error() {
+    let (mut core, _db_fake, _cr_fake) = make_core_tournament_base_state_with_fakes();
+    *core.get_mut() = make_tournament_base("Tournament A", &core);
+    let id = core.save().await.expect("initial save").get_id();
+
+    let err = core.create_stations(&court_setup(0)).await.unwrap_err();
+
+    assert!(matches!(err, CoreError::Validation(_)));
+    let loaded = core
+        .load(id)
+        .await
+        .expect("db ok")
+        .expect("tournament exists");
+    assert!(loaded.get_stations().is_empty());
+}
+
+/// 3) create_stations(): stations of running tournaments cannot be replaced
+#[tokio::test]
+async fn given_running_tournament_when_create_stations_then_error() {
+    let (mut core, _db_fake, _cr_fake) = make_core_tournament_base_state_with_fakes();
+    *core.get_mut() = make_tournament_base("Tournament A", &core);
+    core.get_mut()
+        .set_tournament_state(TournamentState::ActiveStage(0));
+
+    let err = core.create_stations(&court_setup(4)).await.unwrap_err();
+
+    assert!(matches!(err, CoreError::Field(_)));
+}
+
+/// 4) renamed and reordered stations are saved in their new order
+#[tokio::test]
+async fn given_created_stations_when_rename_and_move_then_order_is_saved() {
+    let (mut core, _db_fake, _cr_fake) = make_core_tournament_base_state_with_fakes();
+    *core.get_mut() = make_tournament_base("Tournament A", &core);
+    core.save().await.expect("initial save");
+    core.create_stations(&court_setup(3))
+        .await
+        .expect("create stations");
+
+    // Act
+    assert!(core.get_mut().rename_station(3, "Center Court"));
+    assert!(core.get_mut().move_station(3, 1));
+    let saved = core.save().await.expect("save ok").clone();
+
+    // Assert
+    assert_eq!(
+        saved.get_stations(),
+        &[
+            Station::new("Center Court", "Main Hall"),
+            Station::new("Court 1", "Main Hall"),
+            Station::new("Court 2", "Main Hall"),
+        ]
+    );
+    assert_eq!(saved.get_num_stations(), 3);
+}
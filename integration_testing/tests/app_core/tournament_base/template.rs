@@ -0,0 +1,189 @@
+use app_core::{
+    CoreError, DbError, DbpTournamentBase, StationWindow, TournamentBaseCondition, TournamentState,
+    utils::{filter::Filter, list_order::ListOrder},
+};
+use chrono::{Duration, Utc};
+use uuid::Uuid;
+
+use integration_testing::port_fakes::*;
+
+/// 1) clone_tournament(): base and stages are copied with fresh ids
+#[tokio::test]
+async fn given_tournament_with_stages_when_clone_then_deep_copy_is_saved_as_draft() {
+    let (mut stage_core, db_fake, _cr_fake) = make_core_stage_state_with_fakes();
+    let source_id = stage_core.get().get_tournament_id();
+
+    // Seed two stages of source tournament; readiness adds the final stage
+    for (number, num_groups) in [(0, 4), (1, 2)] {
+        stage_core
+            .get_mut()
+            .set_id_version(Default::default())
+            .set_number(number)
+            .set_num_groups(num_groups);
+        stage_core.save().await.expect("seed stage");
+    }
+    db_fake.seed_readiness(source_id);
+
+    // Source is already running; tournaments advance one state at a time
+    let mut base_core = stage_core.as_tournament_base_state();
+    for state in [
+        TournamentState::ActiveStage(0),
+        TournamentState::ActiveStage(1),
+    ] {
+        base_core.load(source_id).await.expect("db ok");
+        base_core.get_mut().set_tournament_state(state);
+        base_core.save().await.expect("update source");
+    }
+
+    // Act
+    let copy = stage_core
+        .clone_tournament(source_id, "Next Year")
+        .await
+        .expect("clone ok")
+        .expect("source exists");
+
+    // Assert base
+    assert_ne!(copy.get_id(), source_id);
+    assert_eq!(copy.get_version(), Some(0));
+    assert_eq!(copy.get_name(), "Next Year");
+    assert_eq!(copy.get_tournament_state(), TournamentState::Draft);
+    let source = base_core.get().clone();
+    assert_eq!(copy.get_num_entrants(), source.get_num_entrants());
+    assert_eq!(copy.get_tournament_mode(), source.get_tournament_mode());
+
+    // Assert stages
+    let mut source_stages = stage_core.as_stage_state(source_id);
+    let source_ids = source_stages
+        .list_stage_ids_of_tournament()
+        .await
+        .expect("db ok");
+    let mut copy_stages = stage_core.as_stage_state(copy.get_id());
+    let copy_ids = copy_stages
+        .list_stage_ids_of_tournament()
+        .await
+        .expect("db ok");
+    assert_eq!(copy_ids.len(), 3);
+    for ((source_stage_id, source_number), (copy_stage_id, copy_number)) in
+        source_ids.into_iter().zip(copy_ids)
+    {
+        assert_ne!(source_stage_id, copy_stage_id);
+        assert_eq!(source_number, copy_number);
+        let source_stage = *source_stages
+            .load_by_id(source_stage_id)
+            .await
+            .expect("db ok")
+            .expect("source stage");
+        let copy_stage = *copy_stages
+            .load_by_id(copy_stage_id)
+            .await
+            .expect("db ok")
+            .expect("copied stage");
+        assert_eq!(copy_stage.get_tournament_id(), copy.get_id());
+        assert_eq!(copy_stage.get_num_groups(), source_stage.get_num_groups());
+    }
+}
+
+/// 2) clone_tournament(): unknown source → None
+#[tokio::test]
+async fn given_unknown_source_when_clone_then_none() {
+    let (core, _db_fake, _cr_fake) = make_core_tournament_base_state_with_fakes();
+
+    let res = core
+        .clone_tournament(Uuid::new_v4(), "Copy")
+        .await
+        .expect("db ok");
+    assert!(res.is_none());
+}
+
+/// 3) clone_tournament(): DB error while copying stages propagates
+#[tokio::test]
+async fn given_db_failure_on_stage_save_when_clone_then_error_propagates() {
+    let (mut stage_core, db_fake, _cr_fake) = make_core_stage_state_with_fakes();
+    let source_id = stage_core.get().get_tournament_id();
+    stage_core.get_mut().set_number(0).set_num_groups(2);
+    stage_core.save().await.expect("seed stage");
+
+    db_fake.fail_save_stage_once();
+
+    let err = stage_core
+        .clone_tournament(source_id, "Copy")
+        .await
+        .expect_err("expected DB error");
+    match err {
+        CoreError::Db(DbError::Other(e)) => assert!(e.contains("injected save failure")),
+        other => panic!("unexpected error variant: {other:?}"),
+    }
+}
+
+/// 4) clone_tournament(): DB error while copying stages rolls back the copied base
+#[tokio::test]
+async fn given_db_failure_on_stage_save_when_clone_then_no_copy_is_kept() {
+    let (mut stage_core, db_fake, cr_fake) = make_core_stage_state_with_fakes();
+    let source_id = stage_core.get().get_tournament_id();
+    stage_core.get_mut().set_number(0).set_num_groups(2);
+    stage_core.save().await.expect("seed stage");
+    let mut base_core = stage_core.as_tournament_base_state();
+    let sport_id = base_core
+        .load(source_id)
+        .await
+        .expect("db ok")
+        .expect("source tournament")
+        .get_sport_id();
+    let filter = Filter::new().with(TournamentBaseCondition::SportIs(sport_id));
+    let tournaments_before = base_core
+        .list_tournament_base_ids(&filter, &ListOrder::default())
+        .await
+        .expect("list ok");
+    let num_audit_records = db_fake.audit_records().len();
+    cr_fake.clear();
+
+    db_fake.fail_save_stage_once();
+    stage_core
+        .clone_tournament(source_id, "Copy")
+        .await
+        .expect_err("expected DB error");
+
+    let tournaments_after = base_core
+        .list_tournament_base_ids(&filter, &ListOrder::default())
+        .await
+        .expect("list ok");
+    assert_eq!(tournaments_after.len(), tournaments_before.len());
+    assert_eq!(db_fake.audit_records().len(), num_audit_records);
+    assert!(cr_fake.published().is_empty());
+}
+
+/// 5) clone_tournament(): settings bound to date and location of the source are cleared
+#[tokio::test]
+async fn given_sandbox_source_with_dates_and_venue_when_clone_then_they_are_cleared() {
+    let (core, db_fake, _cr_fake) = make_core_tournament_base_state_with_fakes();
+    let start = Utc::now() + Duration::days(7);
+    let mut source = make_tournament_base("Summer Cup", &core);
+    source
+        .set_num_stations(4)
+        .set_sandbox(true)
+        .set_check_in_deadline(Some(start - Duration::hours(1)))
+        .set_station_windows(vec![StationWindow::new(start, 2)])
+        .set_address_id(Some(Uuid::new_v4()))
+        .set_venue_id(Some(Uuid::new_v4()));
+    let source_id = db_fake.seed_tournament_base(source);
+    let source_created_at = db_fake
+        .get_tournament_base(source_id)
+        .await
+        .expect("db ok")
+        .expect("source")
+        .get_created_at();
+
+    let copy = core
+        .clone_tournament(source_id, "Summer Cup 2027")
+        .await
+        .expect("clone ok")
+        .expect("source exists");
+
+    assert_eq!(copy.get_num_stations(), 4);
+    assert!(!copy.is_sandbox());
+    assert_eq!(copy.get_check_in_deadline(), None);
+    assert!(copy.get_station_windows().is_empty());
+    assert_eq!(copy.get_address_id(), None);
+    assert_eq!(copy.get_venue_id(), None);
+    assert_ne!(copy.get_created_at(), source_created_at);
+}
@@ -0,0 +1,153 @@
+use app_core::{
+    AuditObjectType, CoreError, Court, CourtSurface, CrMsg, DbError, TournamentState, Venue,
+};
+use integration_testing::port_fakes::*;
+use isocountry::CountryCode;
+use uuid::Uuid;
+
+fn main_hall(venue: &mut Venue) {
+    venue.set_name("Main Hall").set_courts(vec![
+        Court::new("Court 1", CourtSurface::Wood),
+        Court::new("Court 2", CourtSurface::Wood),
+        Court::new("Court 3", CourtSurface::Wood),
+    ]);
+}
+
+/// 1) save(): venue is saved, published and written to the audit log
+#[tokio::test]
+async fn given_valid_venue_when_save_then_published_and_audited() {
+    let (mut core, db_fake, cr_fake) = make_core_venue_state_with_fakes();
+    let address_id = db_fake.seed_postal_address(make_addr(
+        "Main Hall",
+        "Hallenweg 1",
+        "10115",
+        "Berlin",
+        "BE",
+        CountryCode::DEU,
+    ));
+    main_hall(core.get_mut());
+    core.get_mut().set_address_id(Some(address_id));
+
+    let saved = core.save().await.expect("save should succeed").clone();
+
+    assert_eq!(saved.get_version(), Some(0));
+    assert_eq!(saved.get_address_id(), Some(address_id));
+    assert_eq!(
+        cr_fake.published(),
+        vec![CrMsg::VenueUpdated {
+            id: saved.get_id(),
+            version: 0
+        }]
+    );
+    let records = db_fake.audit_records();
+    assert_eq!(records.len(), 1);
+    assert_eq!(records[0].get_object_type(), AuditObjectType::Venue);
+    assert_eq!(records[0].get_object_id(), saved.get_id());
+}
+
+/// 2) save(): unknown postal address is rejected and nothing is saved
+#[tokio::test]
+async fn given_unknown_address_when_save_then_validation_error() {
+    let (mut core, _db_fake, cr_fake) = make_core_venue_state_with_fakes();
+    main_hall(core.get_mut());
+    core.get_mut().set_address_id(Some(Uuid::new_v4()));
+
+    let err = core.save().await.unwrap_err();
+
+    assert!(matches!(err, CoreError::Validation(_)));
+    assert!(cr_fake.published().is_empty());
+    assert!(core.list_venues(None, None).await.unwrap().is_empty());
+}
+
+/// 3) save(): names of venues are unique case insensitive
+#[tokio::test]
+async fn given_existing_name_when_save_new_venue_then_unique_violation() {
+    let (mut core, _db_fake, _cr_fake) = make_core_venue_state_with_fakes();
+    main_hall(core.get_mut());
+    core.save().await.expect("first save should succeed");
+
+    let mut other = core.as_venue_state();
+    main_hall(other.get_mut());
+    other.get_mut().set_name("main hall");
+    let err = other.save().await.unwrap_err();
+
+    assert!(matches!(err, CoreError::Db(DbError::UniqueViolation(_))));
+}
+
+/// 4) list_venues(): name filter matches parts of names case insensitive
+#[tokio::test]
+async fn given_name_filter_when_list_venues_then_matching_venues_sorted_by_name() {
+    let (core, _db_fake, _cr_fake) = make_core_venue_state_with_fakes();
+    for name in ["South Hall", "Arena", "North Hall"] {
+        let mut venue_core = core.as_venue_state();
+        venue_core.get_mut().set_name(name);
+        venue_core.save().await.unwrap();
+    }
+
+    let venues = core.list_venues(Some("hall"), None).await.unwrap();
+
+    let names: Vec<_> = venues.iter().map(Venue::get_name).collect();
+    assert_eq!(names, vec!["North Hall", "South Hall"]);
+    assert_eq!(core.list_venues(None, Some(1)).await.unwrap().len(), 1);
+}
+
+/// 5) apply_venue(): available courts become the stations of the tournament
+#[tokio::test]
+async fn given_venue_when_apply_to_tournament_then_stations_and_location_are_saved() {
+    let (mut core, db_fake, _cr_fake) = make_core_venue_state_with_fakes();
+    let address_id = db_fake.seed_postal_address(make_addr(
+        "Main Hall",
+        "Hallenweg 1",
+        "10115",
+        "Berlin",
+        "BE",
+        CountryCode::DEU,
+    ));
+    main_hall(core.get_mut());
+    let mut courts = core.get().get_courts().to_vec();
+    courts[2].set_available(false);
+    core.get_mut()
+        .set_address_id(Some(address_id))
+        .set_courts(courts);
+    let venue_id = core.save().await.unwrap().get_id();
+
+    let mut tb_core = core.as_tournament_base_state();
+    *tb_core.get_mut() = make_tournament_base("Tournament A", &tb_core);
+    tb_core.save().await.expect("initial save");
+
+    let tournament = tb_core.apply_venue(venue_id).await.expect("apply venue");
+
+    assert_eq!(tournament.get_venue_id(), Some(venue_id));
+    assert_eq!(tournament.get_address_id(), Some(address_id));
+    assert_eq!(tournament.get_num_stations(), 2);
+    assert_eq!(tournament.get_station_name(1), Some("Court 1"));
+    assert_eq!(tournament.get_station_name(2), Some("Court 2"));
+    assert!(
+        tournament
+            .get_stations()
+            .iter()
+            .all(|s| s.get_venue() == "Main Hall")
+    );
+}
+
+/// 6) apply_venue(): unknown venues and running tournaments are rejected
+#[tokio::test]
+async fn given_unknown_venue_or_running_tournament_when_apply_venue_then_error() {
+    let (mut core, _db_fake, _cr_fake) = make_core_venue_state_with_fakes();
+    main_hall(core.get_mut());
+    let venue_id = core.save().await.unwrap().get_id();
+
+    let mut tb_core = core.as_tournament_base_state();
+    *tb_core.get_mut() = make_tournament_base("Tournament A", &tb_core);
+    tb_core.save().await.expect("initial save");
+
+    let err = tb_core.apply_venue(Uuid::new_v4()).await.unwrap_err();
+    assert!(matches!(err, CoreError::Db(DbError::NotFound)));
+
+    tb_core
+        .get_mut()
+        .set_tournament_state(TournamentState::ActiveStage(0));
+    let err = tb_core.apply_venue(venue_id).await.unwrap_err();
+    assert!(matches!(err, CoreError::Validation(_)));
+    assert_eq!(tb_core.get().get_venue_id(), None);
+}
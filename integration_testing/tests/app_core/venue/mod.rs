@@ -0,0 +1,3 @@
+//! testing app core api for venues with fakes
+
+mod db_wrapper;
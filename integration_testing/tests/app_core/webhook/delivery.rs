@@ -0,0 +1,273 @@
+use app_core::{
+    CrMsg, TournamentMode, TournamentState, WEBHOOK_EVENT_HEADER, WEBHOOK_SIGNATURE_HEADER,
+    WebhookEventType, WebhookFormat, verify_webhook_signature,
+};
+use chrono::Utc;
+use integration_testing::port_fakes::*;
+
+/// 1) save of tournament: signed payload is delivered to subscribed endpoint and recorded
+#[tokio::test]
+async fn given_subscribed_endpoint_when_tournament_saved_then_signed_delivery_is_recorded() {
+    let (mut core, db_fake, _cr_fake, wh_fake) = make_core_webhook_state_with_fakes();
+
+    core.get_mut()
+        .set_name("Scoreboard")
+        .set_url("https://example.com/hook")
+        .set_event_types([WebhookEventType::TournamentUpdated]);
+    let endpoint = core.save().await.expect("save should succeed").clone();
+
+    let mut tb_core = core.as_tournament_base_state();
+    *tb_core.get_mut() = make_tournament_base("Webhook Cup", &tb_core);
+    tb_core.save().await.expect("save should succeed");
+
+    let sent = wh_fake.sent();
+    assert_eq!(sent.len(), 1);
+    assert_eq!(sent[0].url, "https://example.com/hook");
+    assert_eq!(
+        sent[0].header(WEBHOOK_EVENT_HEADER),
+        Some("tournament.updated")
+    );
+    let signature = sent[0].header(WEBHOOK_SIGNATURE_HEADER).unwrap();
+    assert!(verify_webhook_signature(
+        endpoint.get_secret(),
+        signature,
+        &sent[0].body,
+        Utc::now().timestamp(),
+        300
+    ));
+    let body: serde_json::Value = serde_json::from_str(&sent[0].body).unwrap();
+    assert_eq!(body["schema_version"], 1);
+    assert_eq!(body["type"], "tournament.updated");
+    assert_eq!(body["data"]["name"], "Webhook Cup");
+
+    let deliveries = core.list_deliveries(10).await.unwrap();
+    assert_eq!(deliveries.len(), 1);
+    assert_eq!(deliveries[0].status_code, Some(200));
+    assert!(deliveries[0].is_success());
+    assert_eq!(db_fake.webhook_deliveries().len(), 1);
+}
+
+/// 2) endpoints, which are inactive or not subscribed, receive no events
+#[tokio::test]
+async fn given_inactive_or_unsubscribed_endpoint_when_event_dispatched_then_nothing_is_sent() {
+    let (mut core, _db_fake, _cr_fake, wh_fake) = make_core_webhook_state_with_fakes();
+
+    core.get_mut()
+        .set_name("Inactive")
+        .set_url("https://example.com/inactive")
+        .set_event_types([WebhookEventType::TournamentUpdated])
+        .set_active(false);
+    core.save().await.unwrap();
+
+    let mut other = core.as_webhook_state();
+    other
+        .get_mut()
+        .set_name("Entrants only")
+        .set_url("https://example.com/entrants")
+        .set_event_types([WebhookEventType::EntrantsUpdated]);
+    other.save().await.unwrap();
+
+    let mut tb_core = core.as_tournament_base_state();
+    *tb_core.get_mut() = make_tournament_base("Quiet Cup", &tb_core);
+    tb_core.save().await.unwrap();
+
+    assert!(wh_fake.sent().is_empty());
+}
+
+/// 3) send_test_delivery(): test event reaches inactive endpoint and is published
+#[tokio::test]
+async fn given_inactive_endpoint_when_send_test_delivery_then_test_event_is_sent() {
+    let (mut core, _db_fake, cr_fake, wh_fake) = make_core_webhook_state_with_fakes();
+
+    core.get_mut()
+        .set_name("Paused")
+        .set_url("https://example.com/paused")
+        .set_event_types([WebhookEventType::EntrantsUpdated])
+        .set_active(false);
+    core.save().await.unwrap();
+    cr_fake.clear();
+
+    let delivery = core
+        .send_test_delivery()
+        .await
+        .expect("test should be sent");
+
+    assert_eq!(delivery.event_type, WebhookEventType::Test);
+    assert_eq!(
+        wh_fake.sent()[0].header(WEBHOOK_EVENT_HEADER),
+        Some("webhook.test")
+    );
+    assert_eq!(
+        cr_fake.published(),
+        vec![CrMsg::WebhookDelivered {
+            id: delivery.id,
+            version: 0
+        }]
+    );
+}
+
+/// 4) failing receiver: error and status code are recorded, caller is not failed
+#[tokio::test]
+async fn given_failing_receiver_when_event_dispatched_then_failure_is_recorded() {
+    let (mut core, _db_fake, _cr_fake, wh_fake) = make_core_webhook_state_with_fakes();
+
+    core.get_mut()
+        .set_name("Flaky")
+        .set_url("https://example.com/flaky")
+        .set_event_types([WebhookEventType::TournamentUpdated]);
+    core.save().await.unwrap();
+
+    wh_fake.fail_post_once();
+    let mut tb_core = core.as_tournament_base_state();
+    *tb_core.get_mut() = make_tournament_base("Flaky Cup", &tb_core);
+    tb_core.save().await.expect("save must not fail by webhook");
+
+    wh_fake.set_status_code(500);
+    core.send_test_delivery().await.unwrap();
+
+    let deliveries = core.list_deliveries(10).await.unwrap();
+    assert_eq!(deliveries.len(), 2);
+    // newest first
+    assert_eq!(deliveries[0].status_code, Some(500));
+    assert!(!deliveries[0].is_success());
+    assert_eq!(deliveries[1].status_code, None);
+    assert!(deliveries[1].error.is_some());
+}
+
+/// 5) chat preset: endpoint with discord format receives a chat message
+#[tokio::test]
+async fn given_discord_endpoint_when_send_test_delivery_then_chat_message_is_sent() {
+    let (mut core, _db_fake, _cr_fake, wh_fake) = make_core_webhook_state_with_fakes();
+
+    core.get_mut()
+        .set_name("Club Discord")
+        .set_url("https://discord.com/api/webhooks/1/abc")
+        .set_event_types([
+            WebhookEventType::RoundStarted,
+            WebhookEventType::FinalStandings,
+        ])
+        .set_format(WebhookFormat::Discord);
+    let endpoint = core.save().await.unwrap().clone();
+    assert_eq!(endpoint.get_format(), WebhookFormat::Discord);
+
+    let delivery = core.send_test_delivery().await.unwrap();
+
+    let body: serde_json::Value = serde_json::from_str(&wh_fake.sent()[0].body).unwrap();
+    assert_eq!(
+        body["content"],
+        "Test delivery to webhook endpoint 'Club Discord'"
+    );
+    assert!(body.get("schema_version").is_none());
+    assert_eq!(delivery.payload, wh_fake.sent()[0].body);
+}
+
+/// 6) finishing a tournament: stage completed and tournament finished are delivered
+#[tokio::test]
+async fn given_active_tournament_when_finished_then_stage_and_tournament_events_are_sent() {
+    let (mut core, db_fake, _cr_fake, wh_fake) = make_core_webhook_state_with_fakes();
+
+    core.get_mut()
+        .set_name("League Sync")
+        .set_url("https://example.com/league")
+        .set_event_types([
+            WebhookEventType::StageCompleted,
+            WebhookEventType::TournamentFinished,
+        ]);
+    core.save().await.unwrap();
+
+    let mut tb_core = core.as_tournament_base_state();
+    let mut tb = make_tournament_base("League Cup", &tb_core);
+    tb.set_tournament_mode(TournamentMode::PoolAndFinalStage)
+        .set_tournament_state(TournamentState::ActiveStage(1));
+    let t_id = db_fake.seed_tournament_base(tb);
+
+    tb_core.load(t_id).await.unwrap();
+    tb_core
+        .get_mut()
+        .set_tournament_state(TournamentState::Finished);
+    tb_core.save().await.expect("save should succeed");
+
+    let sent = wh_fake.sent();
+    let events: Vec<_> = sent
+        .iter()
+        .map(|s| s.header(WEBHOOK_EVENT_HEADER).unwrap())
+        .collect();
+    assert_eq!(events, vec!["stage.completed", "tournament.finished"]);
+    let body: serde_json::Value = serde_json::from_str(&sent[0].body).unwrap();
+    assert_eq!(body["data"]["stage_number"], 1);
+    assert_eq!(body["data"]["stage_name"], "Final Stage");
+    assert_eq!(body["data"]["tournament_id"], t_id.to_string());
+}
+
+/// 7) webhook queue: events are queued and delivered by deliver_webhook_job()
+#[tokio::test]
+async fn given_webhook_queue_when_event_dispatched_then_job_is_queued_and_delivered_later() {
+    let (mut core, db_fake, wh_fake, queue_fake) = make_core_webhook_state_with_queue_fake();
+
+    core.get_mut()
+        .set_name("Scoreboard")
+        .set_url("https://example.com/hook")
+        .set_event_types([WebhookEventType::TournamentUpdated]);
+    let endpoint = core.save().await.expect("save should succeed").clone();
+
+    let mut tb_core = core.as_tournament_base_state();
+    *tb_core.get_mut() = make_tournament_base("Queued Cup", &tb_core);
+    tb_core.save().await.expect("save should succeed");
+
+    // nothing is sent by the operation, which triggered the event
+    assert!(wh_fake.sent().is_empty());
+    let jobs = queue_fake.jobs();
+    assert_eq!(jobs.len(), 1);
+    assert_eq!(jobs[0].endpoint.get_id(), endpoint.get_id());
+    assert_eq!(
+        jobs[0].payload.event_type,
+        WebhookEventType::TournamentUpdated
+    );
+
+    // receiver is unavailable: delivery is recorded and may be retried
+    wh_fake.set_status_code(503);
+    let delivery = core
+        .deliver_webhook_job(&jobs[0])
+        .await
+        .unwrap()
+        .expect("endpoint accepts event");
+    assert!(delivery.is_retryable());
+
+    wh_fake.set_status_code(200);
+    let delivery = core
+        .deliver_webhook_job(&jobs[0])
+        .await
+        .unwrap()
+        .expect("endpoint accepts event");
+    assert!(delivery.is_success());
+    assert!(!delivery.is_retryable());
+    assert_eq!(wh_fake.sent().len(), 2);
+    assert_eq!(db_fake.webhook_deliveries().len(), 2);
+}
+
+/// 8) deliver_webhook_job(): endpoints deactivated after queueing receive nothing
+#[tokio::test]
+async fn given_deactivated_endpoint_when_deliver_webhook_job_then_nothing_is_sent() {
+    let (mut core, _db_fake, wh_fake, queue_fake) = make_core_webhook_state_with_queue_fake();
+
+    core.get_mut()
+        .set_name("Scoreboard")
+        .set_url("https://example.com/hook")
+        .set_event_types([WebhookEventType::TournamentUpdated]);
+    core.save().await.expect("save should succeed");
+
+    let mut tb_core = core.as_tournament_base_state();
+    *tb_core.get_mut() = make_tournament_base("Queued Cup", &tb_core);
+    tb_core.save().await.expect("save should succeed");
+
+    core.get_mut().set_active(false);
+    core.save().await.expect("save should succeed");
+
+    let delivery = core
+        .deliver_webhook_job(&queue_fake.jobs()[0])
+        .await
+        .unwrap();
+
+    assert!(delivery.is_none());
+    assert!(wh_fake.sent().is_empty());
+}
@@ -0,0 +1,3 @@
+//! testing app core api for webhooks with fakes
+
+mod delivery;
@@ -1,5 +1,6 @@
 #![cfg(feature = "ssr")]
 
+mod migration;
 mod postal_address;
 mod sport_config;
 mod stage;
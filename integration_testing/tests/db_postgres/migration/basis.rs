@@ -0,0 +1,48 @@
+//! Tests for schema compatibility check and read only mode.
+
+use anyhow::Result;
+use app_core::{DbError, DbpSportConfig, utils::list_order::ListOrder};
+use db_postgres::SchemaCompatibility;
+use integration_testing::db_postgres_test_support::{common::*, sport_config::*};
+use uuid::Uuid;
+
+#[tokio::test(flavor = "multi_thread")]
+async fn given_migrated_db_when_check_schema_then_up_to_date() -> Result<()> {
+    init_db_testing();
+    let tdb = TestDb::new().await?;
+    let db = tdb.adapter();
+
+    let compatibility = db.check_schema_compatibility().await?;
+    assert_eq!(compatibility, SchemaCompatibility::UpToDate);
+
+    Ok(())
+}
+
+#[tokio::test(flavor = "multi_thread")]
+async fn given_read_only_db_when_save_then_write_is_refused() -> Result<()> {
+    init_db_testing();
+    let tdb = TestDb::new().await?;
+    let db = tdb.adapter();
+
+    let sport_id = Uuid::new_v4();
+    let saved = db
+        .save_sport_config(&make_new_sport_config("A", sport_id))
+        .await?;
+
+    // Act
+    db.set_read_only(true);
+    let err = db
+        .save_sport_config(&make_new_sport_config("B", sport_id))
+        .await
+        .unwrap_err();
+
+    // Assert: writes refused, reads still served
+    assert!(matches!(err, DbError::ReadOnly));
+    assert!(db.get_sport_config(saved.get_id()).await?.is_some());
+    let listed = db
+        .list_sport_config_ids(sport_id, None, false, None, &ListOrder::default())
+        .await?;
+    assert_eq!(listed, vec![saved.get_id()]);
+
+    Ok(())
+}
@@ -0,0 +1,3 @@
+//! schema compatibility guard of the postgres adapter
+
+mod basis;
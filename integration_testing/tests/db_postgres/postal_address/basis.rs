@@ -3,7 +3,7 @@
 //! conflict on stale version, not-found read, simple list with filter & limit.
 
 use anyhow::Result;
-use app_core::{DatabasePort, DbError, DbpPostalAddress};
+use app_core::{DatabasePort, DbError, DbpPostalAddress, utils::list_order::ListOrder};
 use integration_testing::db_postgres_test_support::{common::*, postal_address::*};
 use isocountry::CountryCode;
 use tracing::info;
@@ -98,14 +98,17 @@ async fn given_stale_version_when_update_then_conflict_error() -> Result<()> {
     assert_eq!(v1.get_version(), Some(1));
 
     // Try to update again using the *stale* v0 snapshot (Existing(id,0))
-    // This should hit the optimistic-lock branch and return DbError::OptimisticLockConflict.
+    // This should hit the optimistic-lock branch and return DbError::VersionConflict.
     let stale = mutate_address_v3(v0); // still carries version=0 internally
     let err = db
         .save_postal_address(&stale)
         .await
         .expect_err("must conflict");
     // Pattern match the domain error
-    assert!(matches!(err, DbError::OptimisticLockConflict));
+    assert!(matches!(
+        err,
+        DbError::VersionConflict { current_version: 1 }
+    ));
 
     // Row remains at v1
     let fetched = db.get_postal_address(id).await?.expect("row present");
@@ -139,7 +142,9 @@ async fn given_name_filter_and_limit_when_list_then_ordered_and_bounded() -> Res
     let _ = db.save_postal_address(&make_new_address("Charlie")).await?;
 
     // Filter: name contains 'a' (case-insensitive due to citext column)
-    let listed = db.list_postal_address_ids(Some("a"), Some(2)).await?;
+    let listed = db
+        .list_postal_address_ids(Some("a"), Some(2), &ListOrder::default())
+        .await?;
     // Expect at most 2 rows
     assert!(listed.len() <= 2, "must respect limit");
 
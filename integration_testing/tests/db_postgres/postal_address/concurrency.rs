@@ -68,7 +68,10 @@ async fn given_two_parallel_updates_from_v0_then_only_one_succeeds_and_version_i
 
     // Loser must be optimistic lock domain error
     let loser_err = r1.err().or(r2.err()).expect("one loser error expected");
-    assert!(matches!(loser_err, DbError::OptimisticLockConflict));
+    assert!(matches!(
+        loser_err,
+        DbError::VersionConflict { current_version: 1 }
+    ));
 
     // Final state: version == 1 and content equals exactly one of the candidates
     let fetched = db.get_postal_address(id).await?.expect("row must exist");
@@ -1,7 +1,7 @@
 //! Basic correctness tests for the SportConfig DB adapter.
 
 use anyhow::Result;
-use app_core::{DbError, DbpSportConfig};
+use app_core::{DbError, DbpSportConfig, utils::list_order::ListOrder};
 
 use integration_testing::db_postgres_test_support::{common::*, sport_config::*};
 use tracing::info;
@@ -97,7 +97,10 @@ async fn given_stale_version_when_update_then_conflict_error() -> Result<()> {
         .expect_err("must conflict");
 
     // Pattern match the domain error
-    assert!(matches!(err, DbError::OptimisticLockConflict));
+    assert!(matches!(
+        err,
+        DbError::VersionConflict { current_version: 1 }
+    ));
 
     // Row remains at v1
     let fetched = db.get_sport_config(id).await?.expect("row present");
@@ -145,7 +148,7 @@ async fn given_name_filter_and_limit_when_list_then_ordered_and_bounded() -> Res
 
     // Filter: name contains 'a' (case-insensitive)
     let listed = db
-        .list_sport_config_ids(sport_id, Some("a"), Some(2))
+        .list_sport_config_ids(sport_id, Some("a"), false, Some(2), &ListOrder::default())
         .await?;
 
     // Expect at most 2 rows, and only from sport_id
@@ -186,3 +189,52 @@ async fn given_name_filter_and_limit_when_list_then_ordered_and_bounded() -> Res
 
     Ok(())
 }
+
+#[tokio::test(flavor = "multi_thread")]
+async fn given_archived_config_when_list_then_hidden_unless_included() -> Result<()> {
+    init_db_testing();
+    let tdb = TestDb::new().await?;
+    let db = tdb.adapter();
+
+    let sport_id = Uuid::new_v4();
+    let keep = db
+        .save_sport_config(&make_new_sport_config("Keep", sport_id))
+        .await?;
+    let old = db
+        .save_sport_config(&make_new_sport_config("Old", sport_id))
+        .await?;
+
+    // Act
+    let archived = db.archive_sport_config(old.get_id(), 0).await?;
+    assert!(archived.is_archived());
+    assert_eq!(
+        archived.get_version(),
+        Some(1),
+        "archive increments version"
+    );
+
+    // stale version is rejected
+    let err = db.archive_sport_config(old.get_id(), 0).await.unwrap_err();
+    assert!(matches!(
+        err,
+        DbError::VersionConflict { current_version: 1 }
+    ));
+
+    // Assert listings
+    let active = db
+        .list_sport_config_ids(sport_id, None, false, None, &ListOrder::default())
+        .await?;
+    assert_eq!(active, vec![keep.get_id()]);
+    let all = db
+        .list_sport_config_ids(sport_id, None, true, None, &ListOrder::default())
+        .await?;
+    assert_eq!(all.len(), 2);
+
+    // saving an archived config keeps it archived
+    let mut renamed = archived.clone();
+    renamed.set_name("Old renamed");
+    let saved = db.save_sport_config(&renamed).await?;
+    assert!(saved.is_archived());
+
+    Ok(())
+}
@@ -1,7 +1,11 @@
 //! Basic correctness tests for the TournamentBase DB adapter.
 
 use anyhow::Result;
-use app_core::{DbError, DbpTournamentBase, TournamentState};
+use app_core::{
+    DbError, DbpTournamentBase, Language, LocalizedText, StationSetup, TournamentBaseCondition,
+    TournamentState,
+    utils::{filter::Filter, list_order::ListOrder},
+};
 use integration_testing::db_postgres_test_support::{common::*, tournament_base::*};
 use tracing::info;
 use uuid::Uuid;
@@ -100,7 +104,10 @@ async fn given_stale_version_when_update_then_conflict_error() -> Result<()> {
         .expect_err("must conflict");
 
     // Pattern match the domain error
-    assert!(matches!(err, DbError::OptimisticLockConflict));
+    assert!(matches!(
+        err,
+        DbError::VersionConflict { current_version: 1 }
+    ));
 
     // Row remains at v1
     let fetched = db.get_tournament_base(id).await?.expect("row present");
@@ -152,7 +159,15 @@ async fn given_name_filter_when_list_then_ordered_and_bounded() -> Result<()> {
     // Filter: name contains 'a' (case-insensitive)
     // Alice (matches), Bob (no), Charlie (matches)
     let listed = db
-        .list_tournament_base_ids(sport_id, Some("a"), None, false, Some(2))
+        .list_tournament_base_ids(
+            &Filter::new()
+                .with(TournamentBaseCondition::SportIs(sport_id))
+                .with(TournamentBaseCondition::NameContains("a".to_string()))
+                .with(TournamentBaseCondition::NotAdhoc)
+                .with(TournamentBaseCondition::NotArchived)
+                .limit(Some(2)),
+            &ListOrder::default(),
+        )
         .await?;
 
     // Expect at most 2 rows, and only from sport_id
@@ -187,3 +202,222 @@ async fn given_name_filter_when_list_then_ordered_and_bounded() -> Result<()> {
 
     Ok(())
 }
+
+#[tokio::test(flavor = "multi_thread")]
+async fn given_archived_tournament_when_list_then_hidden_unless_included() -> Result<()> {
+    init_db_testing();
+    let tdb = TestDb::new().await?;
+    let db = tdb.adapter();
+
+    let sport_id = Uuid::new_v4();
+    let keep = db
+        .save_tournament_base(&make_new_tournament_base("Keep", sport_id))
+        .await?;
+    let old = db
+        .save_tournament_base(&make_new_tournament_base("Old", sport_id))
+        .await?;
+
+    // Act
+    let archived = db.archive_tournament_base(old.get_id(), 0).await?;
+    assert!(archived.is_archived());
+    assert_eq!(
+        archived.get_version(),
+        Some(1),
+        "archive increments version"
+    );
+
+    // missing row is reported as not found
+    let err = db
+        .archive_tournament_base(Uuid::new_v4(), 0)
+        .await
+        .unwrap_err();
+    assert!(matches!(err, DbError::NotFound));
+
+    // Assert listings
+    let active = db
+        .list_tournament_base_ids(
+            &Filter::new()
+                .with(TournamentBaseCondition::SportIs(sport_id))
+                .with(TournamentBaseCondition::NotAdhoc)
+                .with(TournamentBaseCondition::NotArchived),
+            &ListOrder::default(),
+        )
+        .await?;
+    assert_eq!(active, vec![keep.get_id()]);
+    let all = db
+        .list_tournament_base_ids(
+            &Filter::new()
+                .with(TournamentBaseCondition::SportIs(sport_id))
+                .with(TournamentBaseCondition::NotAdhoc),
+            &ListOrder::default(),
+        )
+        .await?;
+    assert_eq!(all.len(), 2);
+
+    Ok(())
+}
+
+#[tokio::test(flavor = "multi_thread")]
+async fn given_state_and_date_conditions_when_list_then_only_matching_rows() -> Result<()> {
+    init_db_testing();
+    let tdb = TestDb::new().await?;
+    let db = tdb.adapter();
+
+    let sport_id = Uuid::new_v4();
+    let draft = db
+        .save_tournament_base(&make_new_tournament_base("Draft", sport_id))
+        .await?;
+    let mut finished = make_new_tournament_base("Finished", sport_id);
+    finished.set_tournament_state(TournamentState::Finished);
+    let finished = db.save_tournament_base(&finished).await?;
+    let mut running = make_new_tournament_base("Running", sport_id);
+    running.set_tournament_state(TournamentState::ActiveStage(0));
+    let running = db.save_tournament_base(&running).await?;
+
+    // state in
+    let mut listed = db
+        .list_tournament_base_ids(
+            &Filter::new()
+                .with(TournamentBaseCondition::SportIs(sport_id))
+                .with(TournamentBaseCondition::StateIn(vec![
+                    TournamentState::Draft,
+                    TournamentState::ActiveStage(0),
+                ])),
+            &ListOrder::default(),
+        )
+        .await?;
+    listed.sort();
+    let mut expected = vec![draft.get_id(), running.get_id()];
+    expected.sort();
+    assert_eq!(listed, expected);
+    assert!(!listed.contains(&finished.get_id()));
+
+    // created between: all rows are created within [from, to)
+    let created_at = draft.get_created_at().expect("db sets created_at");
+    let from = created_at - chrono::Duration::minutes(1);
+    let to = created_at + chrono::Duration::minutes(1);
+    let listed = db
+        .list_tournament_base_ids(
+            &Filter::new()
+                .with(TournamentBaseCondition::SportIs(sport_id))
+                .with(TournamentBaseCondition::CreatedBetween {
+                    from: Some(from),
+                    to: Some(to),
+                }),
+            &ListOrder::default(),
+        )
+        .await?;
+    assert_eq!(listed.len(), 3);
+    let listed = db
+        .list_tournament_base_ids(
+            &Filter::new()
+                .with(TournamentBaseCondition::SportIs(sport_id))
+                .with(TournamentBaseCondition::CreatedBetween {
+                    from: Some(to),
+                    to: None,
+                }),
+            &ListOrder::default(),
+        )
+        .await?;
+    assert!(listed.is_empty());
+
+    Ok(())
+}
+
+#[tokio::test(flavor = "multi_thread")]
+async fn given_sandbox_tournament_when_purge_then_row_is_deleted() -> Result<()> {
+    init_db_testing();
+    let tdb = TestDb::new().await?;
+    let db = tdb.adapter();
+
+    let sport_id = Uuid::new_v4();
+    let real = db
+        .save_tournament_base(&make_new_tournament_base("Real", sport_id))
+        .await?;
+    let mut sandbox = make_new_tournament_base("Sandbox", sport_id);
+    sandbox.set_sandbox(true);
+    let sandbox = db.save_tournament_base(&sandbox).await?;
+    assert!(sandbox.is_sandbox(), "sandbox flag roundtrips");
+
+    // sandbox is hidden by NotSandbox condition
+    let listed = db
+        .list_tournament_base_ids(
+            &Filter::new()
+                .with(TournamentBaseCondition::SportIs(sport_id))
+                .with(TournamentBaseCondition::NotSandbox),
+            &ListOrder::default(),
+        )
+        .await?;
+    assert_eq!(listed, vec![real.get_id()]);
+
+    // real tournaments are never purged
+    let err = db
+        .purge_sandbox_tournament_base(real.get_id())
+        .await
+        .unwrap_err();
+    assert!(matches!(err, DbError::NotFound));
+    assert!(db.get_tournament_base(real.get_id()).await?.is_some());
+
+    // Act
+    db.purge_sandbox_tournament_base(sandbox.get_id()).await?;
+
+    // Assert
+    assert!(db.get_tournament_base(sandbox.get_id()).await?.is_none());
+
+    Ok(())
+}
+
+#[tokio::test(flavor = "multi_thread")]
+async fn given_languages_and_description_when_save_then_roundtrip() -> Result<()> {
+    init_db_testing();
+    let tdb = TestDb::new().await?;
+    let db = tdb.adapter();
+
+    let mut description = LocalizedText::new("Welcome");
+    description.set_translation(Language::De, "Willkommen");
+    let mut tb = make_new_tournament_base("Languages", Uuid::new_v4());
+    tb.set_languages([Language::En, Language::De])
+        .set_description(description.clone());
+
+    // Act
+    let saved = db.save_tournament_base(&tb).await?;
+
+    // Assert
+    let fetched = db
+        .get_tournament_base(saved.get_id())
+        .await?
+        .expect("row must exist");
+    assert_eq!(fetched.get_languages(), &[Language::En, Language::De]);
+    assert_eq!(fetched.get_description(), &description);
+
+    Ok(())
+}
+
+#[tokio::test(flavor = "multi_thread")]
+async fn given_named_stations_when_save_then_roundtrip() -> Result<()> {
+    init_db_testing();
+    let tdb = TestDb::new().await?;
+    let db = tdb.adapter();
+
+    let setup = StationSetup {
+        name_prefix: String::from("Court"),
+        count: 3,
+        first_number: 1,
+        venue: String::from("Main Hall"),
+    };
+    let mut tb = make_new_tournament_base("Stations", Uuid::new_v4());
+    tb.set_stations(setup.stations()).set_num_stations(5);
+
+    // Act
+    let saved = db.save_tournament_base(&tb).await?;
+
+    // Assert
+    let fetched = db
+        .get_tournament_base(saved.get_id())
+        .await?
+        .expect("row must exist");
+    assert_eq!(fetched.get_stations(), setup.stations().as_slice());
+    assert_eq!(fetched.get_num_stations(), 5, "unnamed stations are kept");
+
+    Ok(())
+}
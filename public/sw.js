@@ -0,0 +1,60 @@
+// Service worker of the FK Tournament Planer.
+//
+// Makes the app installable and keeps score entry and entrant schedules usable on flaky
+// networks courtside: pages and compiled assets are loaded from the network and fall back
+// to their last cached copy while offline. Edits are not handled here; the app queues them
+// in IndexedDB and replays them when connectivity returns.
+
+const CACHE = "fk-tournament-planer-v1";
+const SHELL = ["/", "/manifest.webmanifest", "/icon.svg", "/favicon.ico"];
+
+self.addEventListener("install", (event) => {
+  event.waitUntil(caches.open(CACHE).then((cache) => cache.addAll(SHELL)));
+  self.skipWaiting();
+});
+
+self.addEventListener("activate", (event) => {
+  // drop caches of older versions
+  event.waitUntil(
+    caches
+      .keys()
+      .then((keys) => Promise.all(keys.filter((key) => key !== CACHE).map((key) => caches.delete(key))))
+      .then(() => self.clients.claim()),
+  );
+});
+
+self.addEventListener("fetch", (event) => {
+  const request = event.request;
+  const url = new URL(request.url);
+  // server functions, websockets and other origins always go to the network
+  if (request.method !== "GET" || url.origin !== self.location.origin || url.pathname.startsWith("/api")) {
+    return;
+  }
+  // compiled assets must match the rendered pages of the server, therefore the cache is
+  // only used while the network is unavailable
+  if (url.pathname.startsWith("/pkg/") || request.mode === "navigate") {
+    event.respondWith(networkFirst(request));
+  }
+});
+
+async function networkFirst(request) {
+  try {
+    const response = await fetch(request);
+    await store(request, response.clone());
+    return response;
+  } catch (error) {
+    const fallback = request.mode === "navigate" ? await caches.match("/") : undefined;
+    const cached = (await caches.match(request)) || fallback;
+    if (cached) {
+      return cached;
+    }
+    throw error;
+  }
+}
+
+async function store(request, response) {
+  if (response.ok) {
+    const cache = await caches.open(CACHE);
+    await cache.put(request, response);
+  }
+}
@@ -0,0 +1,449 @@
+//! image of group tables and KO brackets of a stage for sharing in social media or chats
+
+use crate::raster::{Canvas, Rgb};
+use app_core::{
+    PublicGroup, PublicStage, TournamentBase,
+    slots::{KoMatch, KoRound, KoSide},
+};
+use sha2::{Digest, Sha256};
+use std::{
+    collections::HashMap,
+    sync::{Arc, Mutex},
+};
+use uuid::Uuid;
+
+// --- branding ---
+const BRAND: Rgb = [37, 99, 235];
+const BACKGROUND: Rgb = [255, 255, 255];
+const TEXT: Rgb = [17, 24, 39];
+const MUTED: Rgb = [107, 114, 128];
+const BORDER: Rgb = [209, 213, 219];
+const ON_BRAND: Rgb = [255, 255, 255];
+const FOOTER: &str = "fk_tournament_planer";
+
+// --- layout in pixels ---
+const MARGIN: u32 = 24;
+const GAP: u32 = 32;
+const HEADER_HEIGHT: u32 = 76;
+const FOOTER_HEIGHT: u32 = 28;
+const MIN_WIDTH: u32 = 640;
+/// images are clipped beyond this size
+const MAX_SIZE: u32 = 8192;
+const SCALE: u32 = 2;
+const CHAR_WIDTH: u32 = 8 * SCALE;
+const LINE_HEIGHT: u32 = 24;
+/// columns of group table with width in characters
+const TABLE_COLUMNS: [(&str, u32); 4] = [("Rank", 6), ("Slot", 8), ("Matches", 9), ("Score", 7)];
+const MATCH_WIDTH: u32 = 13 * CHAR_WIDTH;
+const MATCH_HEIGHT: u32 = 3 * 20 + 8;
+const MATCH_GAP: u32 = 12;
+
+/// maximum number of cached images; cache is cleared, if it is exceeded
+const MAX_CACHED_IMAGES: usize = 256;
+
+/// rendered PNG image of a stage
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct StageImage {
+    pub png: Arc<Vec<u8>>,
+    /// fingerprint of the data shown in the image; usable as HTTP entity tag
+    pub etag: String,
+}
+
+/// Cache of rendered stage images by tournament and stage number.
+///
+/// An image is rendered again, if the fingerprint of the shown data changed, e.g. by new
+/// results. Clones share the cache.
+#[derive(Debug, Clone, Default)]
+pub struct StageImageCache {
+    images: Arc<Mutex<HashMap<(Uuid, u32), StageImage>>>,
+}
+
+impl StageImageCache {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Cached image of `stage`, if it was rendered with the same data; otherwise the image
+    /// is rendered and cached.
+    pub fn get_or_render(&self, tournament: &TournamentBase, stage: &PublicStage) -> StageImage {
+        let key = (tournament.get_id(), stage.number);
+        let etag = fingerprint(tournament, stage);
+        if let Some(image) = self.images.lock().unwrap().get(&key)
+            && image.etag == etag
+        {
+            return image.clone();
+        }
+
+        // render without holding the lock
+        let image = StageImage {
+            png: Arc::new(render_stage(tournament, stage).to_png()),
+            etag,
+        };
+        let mut images = self.images.lock().unwrap();
+        if images.len() >= MAX_CACHED_IMAGES && !images.contains_key(&key) {
+            images.clear();
+        }
+        images.insert(key, image.clone());
+        image
+    }
+}
+
+/// hex encoded SHA-256 of the data shown in the image of `stage`
+fn fingerprint(tournament: &TournamentBase, stage: &PublicStage) -> String {
+    let data = serde_json::to_vec(&(tournament, stage))
+        .expect("serializing tournament and stage never fails");
+    Sha256::digest(&data)
+        .iter()
+        .map(|b| format!("{b:02x}"))
+        .collect()
+}
+
+fn table_width() -> u32 {
+    TABLE_COLUMNS.iter().map(|(_, w)| w * CHAR_WIDTH).sum()
+}
+
+fn bracket_size(bracket: &[KoRound]) -> (u32, u32) {
+    if bracket.is_empty() {
+        return (0, 0);
+    }
+    let num_rounds = bracket.len() as u32;
+    let first_round = bracket[0].matches.len() as u32;
+    (
+        num_rounds * MATCH_WIDTH + (num_rounds - 1) * GAP,
+        LINE_HEIGHT + first_round * (MATCH_HEIGHT + MATCH_GAP),
+    )
+}
+
+fn group_size(group: &PublicGroup) -> (u32, u32) {
+    let (bracket_width, bracket_height) = bracket_size(&group.bracket);
+    let table_height = (group.num_entrants + 1) * LINE_HEIGHT;
+    let mut height = LINE_HEIGHT + table_height;
+    if bracket_height > 0 {
+        height += LINE_HEIGHT + bracket_height;
+    }
+    (table_width().max(bracket_width), height)
+}
+
+/// Render group tables and KO brackets of `stage` with tournament branding.
+///
+/// Groups are laid out in two columns like in the public view. Matches are not persisted
+/// yet, therefore tables show slots without results.
+pub fn render_stage(tournament: &TournamentBase, stage: &PublicStage) -> Canvas {
+    let sizes: Vec<(u32, u32)> = stage.groups.iter().map(group_size).collect();
+    let columns = if stage.groups.len() > 1 { 2 } else { 1 };
+    let column_width = sizes.iter().map(|(w, _)| *w).max().unwrap_or(0);
+    let row_heights: Vec<u32> = sizes
+        .chunks(columns)
+        .map(|row| row.iter().map(|(_, h)| *h).max().unwrap_or(0))
+        .collect();
+
+    let content_width = columns as u32 * column_width + (columns as u32 - 1) * GAP;
+    let content_height =
+        row_heights.iter().sum::<u32>() + row_heights.len().saturating_sub(1) as u32 * GAP;
+    let width = (content_width + 2 * MARGIN).clamp(MIN_WIDTH, MAX_SIZE);
+    let height = (HEADER_HEIGHT + content_height + 2 * MARGIN + FOOTER_HEIGHT).min(MAX_SIZE);
+
+    let mut canvas = Canvas::new(width, height, BACKGROUND);
+
+    // header with tournament name and stage
+    canvas.fill_rect(0, 0, width, HEADER_HEIGHT, BRAND);
+    canvas.text(
+        MARGIN,
+        14,
+        tournament.get_name(),
+        3,
+        width - 2 * MARGIN,
+        ON_BRAND,
+    );
+    canvas.text(MARGIN, 48, &stage.name, SCALE, width - 2 * MARGIN, ON_BRAND);
+
+    // groups
+    let mut y = HEADER_HEIGHT + MARGIN;
+    for (row, row_height) in stage.groups.chunks(columns).zip(&row_heights) {
+        for (column, group) in row.iter().enumerate() {
+            let x = MARGIN + column as u32 * (column_width + GAP);
+            draw_group(&mut canvas, x, y, group);
+        }
+        y += row_height + GAP;
+    }
+
+    // footer
+    canvas.fill_rect(0, height - FOOTER_HEIGHT, width, FOOTER_HEIGHT, BORDER);
+    canvas.text(
+        MARGIN,
+        height - FOOTER_HEIGHT + 10,
+        FOOTER,
+        1,
+        width - 2 * MARGIN,
+        MUTED,
+    );
+
+    canvas
+}
+
+fn draw_group(canvas: &mut Canvas, x: u32, y: u32, group: &PublicGroup) {
+    let title = format!("Group {} ({} entrants)", group.label, group.num_entrants);
+    canvas.text(x, y + 4, &title, SCALE, table_width(), TEXT);
+
+    // table of slots
+    let mut row_y = y + LINE_HEIGHT;
+    let mut column_x = x;
+    for (header, chars) in TABLE_COLUMNS {
+        canvas.text(
+            column_x,
+            row_y + 4,
+            header,
+            SCALE,
+            chars * CHAR_WIDTH,
+            MUTED,
+        );
+        column_x += chars * CHAR_WIDTH;
+    }
+    for slot in 1..=group.num_entrants {
+        canvas.fill_rect(x, row_y + LINE_HEIGHT - 1, table_width(), 1, BORDER);
+        row_y += LINE_HEIGHT;
+        let cells = [
+            "-".to_string(),
+            format!("{}{slot}", group.label),
+            "0".into(),
+            "0".into(),
+        ];
+        let mut column_x = x;
+        for ((_, chars), cell) in TABLE_COLUMNS.iter().zip(cells) {
+            canvas.text(column_x, row_y + 4, &cell, SCALE, chars * CHAR_WIDTH, TEXT);
+            column_x += chars * CHAR_WIDTH;
+        }
+    }
+
+    if !group.bracket.is_empty() {
+        draw_bracket(
+            canvas,
+            x,
+            row_y + 2 * LINE_HEIGHT,
+            &group.label,
+            &group.bracket,
+        );
+    }
+}
+
+fn side_label(label: &str, side: KoSide) -> String {
+    match side {
+        KoSide::Slot(slot) => format!("{label}{slot}"),
+        KoSide::WinnerOf(number) => format!("Winner M{number}"),
+        KoSide::LoserOf(number) => format!("Loser M{number}"),
+    }
+}
+
+/// Numbers of first round matches from top to bottom, such that the feeding matches of each
+/// match are adjacent. Seeded brackets do not play their first round in this order.
+fn first_round_order(bracket: &[KoRound]) -> Vec<u32> {
+    fn collect(number: u32, matches: &HashMap<u32, KoMatch>, order: &mut Vec<u32>) {
+        let Some(m) = matches.get(&number) else {
+            return;
+        };
+        let mut is_first_round = true;
+        for side in [m.side_a, m.side_b] {
+            if let KoSide::WinnerOf(feeder) = side {
+                is_first_round = false;
+                collect(feeder, matches, order);
+            }
+        }
+        if is_first_round {
+            order.push(number);
+        }
+    }
+
+    let matches: HashMap<u32, KoMatch> = bracket
+        .iter()
+        .flat_map(|r| r.matches.iter().map(|m| (m.number, *m)))
+        .collect();
+    let mut order = Vec::new();
+    if let Some(last) = bracket.last() {
+        for m in &last.matches {
+            collect(m.number, &matches, &mut order);
+        }
+    }
+    order
+}
+
+fn draw_bracket(canvas: &mut Canvas, x: u32, y: u32, label: &str, bracket: &[KoRound]) {
+    let first_round = first_round_order(bracket);
+    // vertical center of each match by match number
+    let mut centers: HashMap<u32, u32> = HashMap::new();
+    let top = y + LINE_HEIGHT;
+    for (round_index, round) in bracket.iter().enumerate() {
+        let round_x = x + round_index as u32 * (MATCH_WIDTH + GAP);
+        canvas.text(
+            round_x,
+            y,
+            &round.name,
+            SCALE,
+            MATCH_WIDTH + GAP - CHAR_WIDTH,
+            MUTED,
+        );
+        for m in &round.matches {
+            let feeders: Vec<u32> = [m.side_a, m.side_b]
+                .into_iter()
+                .filter_map(|side| match side {
+                    KoSide::WinnerOf(number) => centers.get(&number).copied(),
+                    KoSide::Slot(_) | KoSide::LoserOf(_) => None,
+                })
+                .collect();
+            let center = if feeders.is_empty() {
+                let position = first_round
+                    .iter()
+                    .position(|n| *n == m.number)
+                    .unwrap_or_default() as u32;
+                top + position * (MATCH_HEIGHT + MATCH_GAP) + MATCH_HEIGHT / 2
+            } else {
+                feeders.iter().sum::<u32>() / feeders.len() as u32
+            };
+            // connectors from feeding matches
+            for feeder in &feeders {
+                let mid_x = round_x - GAP / 2;
+                canvas.fill_rect(round_x - GAP, *feeder, GAP / 2, 2, BORDER);
+                canvas.fill_rect(
+                    mid_x,
+                    (*feeder).min(center),
+                    2,
+                    feeder.abs_diff(center) + 2,
+                    BORDER,
+                );
+                canvas.fill_rect(mid_x, center, GAP / 2, 2, BORDER);
+            }
+            let box_y = center - MATCH_HEIGHT / 2;
+            canvas.stroke_rect(round_x, box_y, MATCH_WIDTH, MATCH_HEIGHT, 2, BORDER);
+            let text_x = round_x + 8;
+            let text_width = MATCH_WIDTH - 16;
+            canvas.text(
+                text_x,
+                box_y + 6,
+                &format!("M{}", m.number),
+                SCALE,
+                text_width,
+                MUTED,
+            );
+            canvas.text(
+                text_x,
+                box_y + 26,
+                &side_label(label, m.side_a),
+                SCALE,
+                text_width,
+                TEXT,
+            );
+            canvas.text(
+                text_x,
+                box_y + 46,
+                &side_label(label, m.side_b),
+                SCALE,
+                text_width,
+                TEXT,
+            );
+            centers.insert(m.number, center);
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use app_core::slots::ko_bracket;
+
+    fn stage(groups: Vec<PublicGroup>) -> PublicStage {
+        PublicStage {
+            id: Uuid::nil(),
+            number: 0,
+            name: "Final Stage".into(),
+            groups,
+        }
+    }
+
+    fn group(label: &str, num_entrants: u32, bracket: Vec<KoRound>) -> PublicGroup {
+        PublicGroup {
+            label: label.into(),
+            num_entrants,
+            bracket,
+        }
+    }
+
+    #[test]
+    fn header_shows_brand_and_footer() {
+        let mut tournament = TournamentBase::default();
+        tournament.set_name("Spring Cup");
+        let canvas = render_stage(&tournament, &stage(vec![group("A", 4, Vec::new())]));
+
+        assert_eq!(canvas.width(), MIN_WIDTH);
+        assert_eq!(canvas.pixel(0, 0), Some(BRAND));
+        assert_eq!(canvas.pixel(0, canvas.height() - 1), Some(BORDER));
+        assert_eq!(
+            canvas.height(),
+            HEADER_HEIGHT + 2 * MARGIN + FOOTER_HEIGHT + LINE_HEIGHT + 5 * LINE_HEIGHT
+        );
+    }
+
+    #[test]
+    fn groups_are_laid_out_in_two_columns() {
+        let tournament = TournamentBase::default();
+        let one = render_stage(&tournament, &stage(vec![group("A", 4, Vec::new())]));
+        let three = render_stage(
+            &tournament,
+            &stage(vec![
+                group("A", 4, Vec::new()),
+                group("B", 4, Vec::new()),
+                group("C", 3, Vec::new()),
+            ]),
+        );
+        assert_eq!(three.width(), 2 * MARGIN + 2 * table_width() + GAP);
+        assert_eq!(
+            three.height(),
+            one.height() + GAP + LINE_HEIGHT + 4 * LINE_HEIGHT
+        );
+    }
+
+    #[test]
+    fn bracket_extends_image() {
+        let tournament = TournamentBase::default();
+        let canvas = render_stage(&tournament, &stage(vec![group("A", 8, ko_bracket(8))]));
+        let (bracket_width, bracket_height) = bracket_size(&ko_bracket(8));
+        assert_eq!(bracket_width, 3 * MATCH_WIDTH + 2 * GAP);
+        assert_eq!(canvas.width(), 2 * MARGIN + bracket_width);
+        assert_eq!(
+            canvas.height(),
+            HEADER_HEIGHT + 2 * MARGIN + FOOTER_HEIGHT + 11 * LINE_HEIGHT + bracket_height
+        );
+    }
+
+    #[test]
+    fn feeding_matches_are_adjacent() {
+        let bracket = ko_bracket(8);
+        let order = first_round_order(&bracket);
+        assert_eq!(order.len(), 4);
+        // each semi final is fed by two neighbouring quarter finals
+        for (i, semi) in bracket[1].matches.iter().enumerate() {
+            assert_eq!(
+                [semi.side_a, semi.side_b],
+                [
+                    KoSide::WinnerOf(order[2 * i]),
+                    KoSide::WinnerOf(order[2 * i + 1])
+                ]
+            );
+        }
+    }
+
+    #[test]
+    fn cache_renders_again_on_changed_data() {
+        let cache = StageImageCache::new();
+        let tournament = TournamentBase::default();
+        let mut stage = stage(vec![group("A", 4, Vec::new())]);
+
+        let first = cache.get_or_render(&tournament, &stage);
+        assert_eq!(&first.png[1..4], b"PNG");
+        let cached = cache.get_or_render(&tournament, &stage);
+        assert!(Arc::ptr_eq(&first.png, &cached.png));
+
+        stage.groups[0].num_entrants = 5;
+        let changed = cache.get_or_render(&tournament, &stage);
+        assert_ne!(changed.etag, first.etag);
+        assert!(!Arc::ptr_eq(&first.png, &changed.png));
+    }
+}
@@ -0,0 +1,56 @@
+//! calendar events of scheduled matches
+
+use crate::ics::IcsEvent;
+use app_core::{Match, ScheduledEntrant};
+use chrono::{Duration, Utc};
+use std::collections::HashMap;
+use uuid::Uuid;
+
+/// Map `matches` to calendar events of `tournament_name`.
+///
+/// `entrant_names` maps entrant ids to names. Sides, which are not yet resolved to an
+/// entrant (e.g. winner of a group), are shown as `TBD`. Events last `match_duration`.
+pub fn match_events(
+    tournament_name: &str,
+    matches: &[Match],
+    entrant_names: &HashMap<Uuid, String>,
+    match_duration: Duration,
+) -> Vec<IcsEvent> {
+    matches
+        .iter()
+        .map(|m| {
+            let (side_a, side_b) = m.get_sides();
+            let start = m.get_start_at().with_timezone(&Utc);
+            IcsEvent {
+                uid: format!("{}@fk-tournament-planer", m.get_id()),
+                start,
+                end: start + match_duration,
+                summary: format!(
+                    "{} vs. {}",
+                    side_name(side_a, entrant_names),
+                    side_name(side_b, entrant_names)
+                ),
+                location: Some(format!("Station {}", m.get_station())),
+                description: Some(format!("{tournament_name}, match {}", m.get_number())),
+            }
+        })
+        .collect()
+}
+
+/// Returns true, if `entrant_id` plays in `m`.
+pub fn is_match_of_entrant(m: &Match, entrant_id: Uuid) -> bool {
+    let (side_a, side_b) = m.get_sides();
+    [side_a, side_b]
+        .into_iter()
+        .any(|side| matches!(side, ScheduledEntrant::Entrant(id) if *id == entrant_id))
+}
+
+fn side_name(side: &ScheduledEntrant, entrant_names: &HashMap<Uuid, String>) -> String {
+    match side {
+        ScheduledEntrant::Entrant(id) => entrant_names
+            .get(id)
+            .cloned()
+            .unwrap_or_else(|| "TBD".to_string()),
+        _ => "TBD".to_string(),
+    }
+}
@@ -0,0 +1,189 @@
+//! final report of a finished tournament as HTML and PDF
+//!
+//! Both documents render the same sections of [`FinalReport`]: participation, final
+//! standings, stages, statistics, the accuracy of the planned duration and handover notes,
+//! if the report includes them.
+
+use crate::pdf::{PdfDocument, TextStyle};
+use app_core::{FinalReport, num_matches_of_stage};
+use std::{fmt::Write, time::Duration};
+
+/// Render `report` to PDF.
+pub fn render_final_report_pdf(report: &FinalReport) -> Vec<u8> {
+    let mut doc = PdfDocument::new();
+    doc.line(TextStyle::Title, report.tournament.get_name())
+        .line(TextStyle::Body, "Final Report")
+        .line(
+            TextStyle::Body,
+            &format!(
+                "Generated: {}",
+                report.generated_at.format("%Y-%m-%d %H:%M UTC")
+            ),
+        );
+    for (heading, lines) in sections(report) {
+        doc.blank().line(TextStyle::Heading, heading);
+        for line in lines {
+            doc.line(TextStyle::Body, &line);
+        }
+    }
+    doc.finish()
+}
+
+/// Render `report` to a standalone HTML document.
+pub fn render_final_report_html(report: &FinalReport) -> String {
+    let name = escape_html(report.tournament.get_name());
+    let mut html = String::new();
+    let _ = write!(
+        html,
+        "<!DOCTYPE html>\n<html lang=\"en\">\n<head>\n<meta charset=\"utf-8\">\n\
+         <title>{name} - Final Report</title>\n</head>\n<body>\n<h1>{name}</h1>\n\
+         <p>Final Report, generated {}</p>\n",
+        report.generated_at.format("%Y-%m-%d %H:%M UTC")
+    );
+    for (heading, lines) in sections(report) {
+        let _ = writeln!(html, "<h2>{}</h2>\n<ul>", escape_html(heading));
+        for line in lines {
+            let _ = writeln!(html, "<li>{}</li>", escape_html(&line));
+        }
+        html.push_str("</ul>\n");
+    }
+    html.push_str("</body>\n</html>\n");
+    html
+}
+
+/// headings and lines of all sections of `report`
+fn sections(report: &FinalReport) -> Vec<(&'static str, Vec<String>)> {
+    let participation = &report.participation;
+    let participation = vec![
+        format!(
+            "Entrants: {} of {} planned",
+            participation.registered, participation.planned
+        ),
+        format!("Clubs: {}", participation.clubs),
+    ];
+
+    let standings = if report.standings.is_empty() {
+        vec![String::from("No results available.")]
+    } else {
+        report
+            .standings
+            .iter()
+            .map(|s| format!("{:>3}. {}", s.rank, s.name))
+            .collect()
+    };
+
+    let mode = report.tournament.get_tournament_mode();
+    let stages = if report.stages.is_empty() {
+        vec![String::from("No stages configured.")]
+    } else {
+        report
+            .stages
+            .iter()
+            .map(|stage| {
+                format!(
+                    "{}: {} groups, {} matches",
+                    stage.name,
+                    stage.groups.len(),
+                    num_matches_of_stage(mode, stage)
+                )
+            })
+            .collect()
+    };
+
+    let statistics = &report.statistics;
+    let mut statistics_lines = vec![
+        format!("Matches: {}", statistics.num_matches),
+        format!("Matches with notes: {}", statistics.num_match_notes),
+    ];
+    if !statistics.top_tags.is_empty() {
+        let tags: Vec<String> = statistics
+            .top_tags
+            .iter()
+            .map(|(tag, count)| format!("{tag} ({count})"))
+            .collect();
+        statistics_lines.push(format!("Most used tags: {}", tags.join(", ")));
+    }
+
+    let duration = &report.duration;
+    let mut duration_lines = vec![
+        format!("Planned: {}", format_duration(duration.planned)),
+        format!("Actual: {}", format_duration(duration.actual)),
+    ];
+    if let Some(deviation) = duration.deviation_percent() {
+        duration_lines.push(format!("Deviation: {deviation:+}%"));
+    }
+
+    let mut sections = vec![
+        ("Participation", participation),
+        ("Final Standings", standings),
+        ("Stages", stages),
+        ("Statistics", statistics_lines),
+        ("Duration", duration_lines),
+    ];
+    if let Some(handover_notes) = &report.handover_notes {
+        let lines = if handover_notes.is_empty() {
+            vec![String::from("No handover notes.")]
+        } else {
+            handover_notes
+                .iter()
+                .map(|note| {
+                    format!(
+                        "{}: {}",
+                        note.get_author(),
+                        note.get_text().replace('\n', " ")
+                    )
+                })
+                .collect()
+        };
+        sections.push(("Handover Notes", lines));
+    }
+    sections
+}
+
+fn format_duration(duration: Option<Duration>) -> String {
+    match duration {
+        Some(duration) => {
+            let minutes = duration.as_secs().div_ceil(60);
+            format!("{}h {:02}min", minutes / 60, minutes % 60)
+        }
+        None => String::from("unknown"),
+    }
+}
+
+fn escape_html(text: &str) -> String {
+    let mut escaped = String::with_capacity(text.len());
+    for c in text.chars() {
+        match c {
+            '&' => escaped.push_str("&amp;"),
+            '<' => escaped.push_str("&lt;"),
+            '>' => escaped.push_str("&gt;"),
+            '"' => escaped.push_str("&quot;"),
+            '\'' => escaped.push_str("&#39;"),
+            c => escaped.push(c),
+        }
+    }
+    escaped
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn escapes_html() {
+        assert_eq!(
+            escape_html("<b>Tom & Jerry's \"Cup\"</b>"),
+            "&lt;b&gt;Tom &amp; Jerry&#39;s &quot;Cup&quot;&lt;/b&gt;"
+        );
+    }
+
+    #[test]
+    fn formats_durations() {
+        assert_eq!(format_duration(None), "unknown");
+        assert_eq!(
+            format_duration(Some(Duration::from_secs(3 * 3600 + 5 * 60))),
+            "3h 05min"
+        );
+        assert_eq!(format_duration(Some(Duration::from_secs(30))), "0h 01min");
+    }
+}
@@ -0,0 +1,151 @@
+//! minimal iCalendar (RFC 5545) writer for calendar subscriptions
+//!
+//! Only the properties needed for match schedules are supported. Lines are terminated with
+//! CRLF, text values are escaped and lines longer than 75 octets are folded.
+
+use chrono::{DateTime, Utc};
+
+/// product identifier of generated calendars
+const PRODID: &str = "-//fk_tournament_planer//Tournament Schedule//EN";
+
+/// maximum length of a content line in octets without line break
+const MAX_LINE_OCTETS: usize = 75;
+
+/// event of a calendar
+#[derive(Debug, Clone, PartialEq)]
+pub struct IcsEvent {
+    /// globally unique id; must stay stable, so that calendar clients update events
+    pub uid: String,
+    pub start: DateTime<Utc>,
+    pub end: DateTime<Utc>,
+    pub summary: String,
+    pub location: Option<String>,
+    pub description: Option<String>,
+}
+
+/// Render calendar with name `name` and `events`.
+pub fn render_calendar(name: &str, events: &[IcsEvent]) -> String {
+    let dtstamp = format_date_time(Utc::now());
+    let mut ics = String::new();
+    push_line(&mut ics, "BEGIN:VCALENDAR");
+    push_line(&mut ics, "VERSION:2.0");
+    push_line(&mut ics, &format!("PRODID:{PRODID}"));
+    push_line(&mut ics, "CALSCALE:GREGORIAN");
+    push_line(&mut ics, "METHOD:PUBLISH");
+    // de facto standard for the display name of subscribed calendars
+    push_line(&mut ics, &format!("X-WR-CALNAME:{}", escape_text(name)));
+    for event in events {
+        push_line(&mut ics, "BEGIN:VEVENT");
+        push_line(&mut ics, &format!("UID:{}", escape_text(&event.uid)));
+        push_line(&mut ics, &format!("DTSTAMP:{dtstamp}"));
+        push_line(
+            &mut ics,
+            &format!("DTSTART:{}", format_date_time(event.start)),
+        );
+        push_line(&mut ics, &format!("DTEND:{}", format_date_time(event.end)));
+        push_line(
+            &mut ics,
+            &format!("SUMMARY:{}", escape_text(&event.summary)),
+        );
+        if let Some(location) = event.location.as_ref() {
+            push_line(&mut ics, &format!("LOCATION:{}", escape_text(location)));
+        }
+        if let Some(description) = event.description.as_ref() {
+            push_line(
+                &mut ics,
+                &format!("DESCRIPTION:{}", escape_text(description)),
+            );
+        }
+        push_line(&mut ics, "END:VEVENT");
+    }
+    push_line(&mut ics, "END:VCALENDAR");
+    ics
+}
+
+/// date time in UTC form, e.g. `20260321T093000Z`
+fn format_date_time(t: DateTime<Utc>) -> String {
+    t.format("%Y%m%dT%H%M%SZ").to_string()
+}
+
+/// escape TEXT value (RFC 5545, 3.3.11)
+fn escape_text(text: &str) -> String {
+    let mut escaped = String::with_capacity(text.len());
+    for c in text.chars() {
+        match c {
+            '\\' => escaped.push_str("\\\\"),
+            ';' => escaped.push_str("\\;"),
+            ',' => escaped.push_str("\\,"),
+            '\n' => escaped.push_str("\\n"),
+            '\r' => {}
+            c => escaped.push(c),
+        }
+    }
+    escaped
+}
+
+/// append content line with folding (RFC 5545, 3.1); never splits UTF-8 characters
+fn push_line(ics: &mut String, line: &str) {
+    let mut octets = 0;
+    for c in line.chars() {
+        // continuation lines start with a space, which counts to their length
+        if octets + c.len_utf8() > MAX_LINE_OCTETS {
+            ics.push_str("\r\n ");
+            octets = 1;
+        }
+        ics.push(c);
+        octets += c.len_utf8();
+    }
+    ics.push_str("\r\n");
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use chrono::TimeZone;
+
+    #[test]
+    fn calendar_contains_escaped_events() {
+        let start = Utc.with_ymd_and_hms(2026, 3, 21, 9, 30, 0).unwrap();
+        let event = IcsEvent {
+            uid: "m1@fk_tournament_planer".into(),
+            start,
+            end: start + chrono::Duration::minutes(20),
+            summary: "Team A vs. Team B; Group A, Round 1".into(),
+            location: Some("Station 3".into()),
+            description: None,
+        };
+        let ics = render_calendar("Spring Cup", &[event]);
+        assert!(ics.starts_with("BEGIN:VCALENDAR\r\nVERSION:2.0\r\n"));
+        assert!(ics.contains("X-WR-CALNAME:Spring Cup\r\n"));
+        assert!(ics.contains("DTSTART:20260321T093000Z\r\n"));
+        assert!(ics.contains("DTEND:20260321T095000Z\r\n"));
+        assert!(ics.contains("SUMMARY:Team A vs. Team B\\; Group A\\, Round 1\r\n"));
+        assert!(ics.contains("LOCATION:Station 3\r\n"));
+        assert!(!ics.contains("DESCRIPTION"));
+        assert!(ics.ends_with("END:VEVENT\r\nEND:VCALENDAR\r\n"));
+    }
+
+    #[test]
+    fn empty_calendar_is_valid() {
+        let ics = render_calendar("Cup", &[]);
+        assert!(!ics.contains("BEGIN:VEVENT"));
+        assert!(ics.ends_with("METHOD:PUBLISH\r\nX-WR-CALNAME:Cup\r\nEND:VCALENDAR\r\n"));
+    }
+
+    #[test]
+    fn long_lines_are_folded_at_75_octets() {
+        let mut ics = String::new();
+        push_line(&mut ics, &format!("SUMMARY:{}", "ä".repeat(60)));
+        let lines: Vec<&str> = ics.trim_end_matches("\r\n").split("\r\n").collect();
+        assert_eq!(lines.len(), 2);
+        assert!(lines.iter().all(|l| l.len() <= MAX_LINE_OCTETS));
+        assert!(lines[1].starts_with(' '));
+        let unfolded = ics.replace("\r\n ", "");
+        assert_eq!(unfolded, format!("SUMMARY:{}\r\n", "ä".repeat(60)));
+    }
+
+    #[test]
+    fn newlines_are_escaped() {
+        assert_eq!(escape_text("a\r\nb\\c"), "a\\nb\\\\c");
+    }
+}
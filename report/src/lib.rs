@@ -0,0 +1,239 @@
+// printable reports of tournaments
+
+pub mod bracket;
+pub mod calendar;
+pub mod final_report;
+mod font;
+pub mod ics;
+pub mod pdf;
+pub mod raster;
+pub mod schedule;
+pub mod score_sheet;
+
+use app_core::{Core, CoreResult, Match, ReportFormat, TournamentBase, TournamentState};
+use bracket::{StageImage, StageImageCache};
+use chrono::Duration;
+use std::collections::HashMap;
+use tracing::{info, instrument};
+use uuid::Uuid;
+
+/// Render schedule, group tables, KO brackets and match notes of the tournament with id
+/// `tournament_id` to PDF. Returns `None`, if no tournament with `tournament_id` exists, is
+/// a draft or a sandbox tournament, since the schedule is served to the public. Match notes
+/// are internal to directors and only printed with `include_notes`.
+#[instrument(name = "report.schedule_pdf", skip(core))]
+pub async fn render_schedule_pdf<S>(
+    core: &Core<S>,
+    tournament_id: Uuid,
+    include_notes: bool,
+) -> CoreResult<Option<Vec<u8>>> {
+    let Some(view) = core.load_public_tournament(tournament_id).await? else {
+        return Ok(None);
+    };
+    let mut stage_core = core.as_stage_state(tournament_id);
+    let mut stages = Vec::with_capacity(view.stages.len());
+    for public_stage in &view.stages {
+        if let Some(stage) = stage_core.load_by_id(public_stage.id).await? {
+            stages.push(stage.clone());
+        }
+    }
+    let match_notes = if include_notes {
+        core.as_match_note_state(tournament_id)
+            .list_notes(None, None)
+            .await?
+    } else {
+        Vec::new()
+    };
+    let pdf = schedule::render_schedule(&view.tournament, &stages, &match_notes);
+    info!(bytes = pdf.len(), include_notes, "schedule_pdf_rendered");
+    Ok(Some(pdf))
+}
+
+/// Render the score sheet of the match with id `match_id` to PDF. Returns `None`, if no
+/// match with `match_id` exists or if its tournament is not public.
+#[instrument(name = "report.score_sheet_pdf", skip(core))]
+pub async fn render_score_sheet_pdf<S>(
+    core: &Core<S>,
+    match_id: Uuid,
+) -> CoreResult<Option<Vec<u8>>> {
+    let Some(sheet) = core.load_score_sheet(match_id).await? else {
+        return Ok(None);
+    };
+    if public_tournament(core, sheet.tournament_id)
+        .await?
+        .is_none()
+    {
+        return Ok(None);
+    }
+    let pdf = score_sheet::render_score_sheet_pdf(&sheet);
+    info!(bytes = pdf.len(), "score_sheet_pdf_rendered");
+    Ok(Some(pdf))
+}
+
+/// Render group tables and KO brackets of stage `stage_number` of the tournament with id
+/// `tournament_id` to PNG. Without `stage_number` the active stage is rendered, the last
+/// stage of finished tournaments and the first stage otherwise.
+/// Returns `None`, if the tournament does not exist, is a draft or has no such stage.
+#[instrument(name = "report.stage_image", skip(core, cache))]
+pub async fn render_stage_png<S>(
+    core: &Core<S>,
+    tournament_id: Uuid,
+    stage_number: Option<u32>,
+    cache: &StageImageCache,
+) -> CoreResult<Option<StageImage>> {
+    let Some(view) = core.load_public_tournament(tournament_id).await? else {
+        return Ok(None);
+    };
+    let stage_number = stage_number.unwrap_or(match view.tournament.get_tournament_state() {
+        TournamentState::ActiveStage(number) => number,
+        TournamentState::Finished => view.stages.last().map(|s| s.number).unwrap_or(0),
+        TournamentState::Draft | TournamentState::Published => 0,
+    });
+    let Some(stage) = view.stages.iter().find(|s| s.number == stage_number) else {
+        return Ok(None);
+    };
+    let image = cache.get_or_render(&view.tournament, stage);
+    info!(bytes = image.png.len(), "stage_image_rendered");
+    Ok(Some(image))
+}
+
+/// Assemble the final report of the tournament with id `tournament_id`, render it to HTML
+/// and PDF and store both documents via the blob storage port. The stored documents are
+/// public and contain no handover notes. Returns false, if the tournament does not exist or
+/// is not public.
+#[instrument(name = "report.final_report", skip(core))]
+pub async fn generate_final_report<S>(core: &Core<S>, tournament_id: Uuid) -> CoreResult<bool> {
+    let Some(report) = core.assemble_final_report(tournament_id, false).await? else {
+        return Ok(false);
+    };
+    let html = final_report::render_final_report_html(&report);
+    core.store_final_report(tournament_id, ReportFormat::Html, html.into_bytes())
+        .await?;
+    let pdf = final_report::render_final_report_pdf(&report);
+    let bytes = pdf.len();
+    core.store_final_report(tournament_id, ReportFormat::Pdf, pdf)
+        .await?;
+    info!(bytes, "final_report_generated");
+    Ok(true)
+}
+
+/// Render the final report of the tournament with id `tournament_id` in `format` including
+/// the handover notes of its shift log, e.g. for organization tokens. Returns `None`, if the
+/// tournament does not exist or is not public.
+#[instrument(name = "report.final_report_with_notes", skip(core))]
+pub async fn render_final_report_with_notes<S>(
+    core: &Core<S>,
+    tournament_id: Uuid,
+    format: ReportFormat,
+) -> CoreResult<Option<Vec<u8>>> {
+    let Some(report) = core.assemble_final_report(tournament_id, true).await? else {
+        return Ok(None);
+    };
+    let data = match format {
+        ReportFormat::Html => final_report::render_final_report_html(&report).into_bytes(),
+        ReportFormat::Pdf => final_report::render_final_report_pdf(&report),
+    };
+    info!(bytes = data.len(), "final_report_with_notes_rendered");
+    Ok(Some(data))
+}
+
+/// Render ICS calendar of all scheduled matches of the tournament with id `tournament_id`.
+/// Returns `None`, if no tournament with `tournament_id` exists or if it is not public.
+#[instrument(name = "report.tournament_calendar", skip(core))]
+pub async fn render_tournament_calendar<S>(
+    core: &Core<S>,
+    tournament_id: Uuid,
+) -> CoreResult<Option<String>> {
+    let Some(tournament) = public_tournament(core, tournament_id).await? else {
+        return Ok(None);
+    };
+    let matches = scheduled_matches(core, tournament_id).await?;
+    let events = calendar::match_events(
+        tournament.get_name(),
+        &matches,
+        &entrant_names(core, tournament_id).await?,
+        calendar_match_duration(core, &tournament).await?,
+    );
+    info!(events = events.len(), "tournament_calendar_rendered");
+    Ok(Some(ics::render_calendar(tournament.get_name(), &events)))
+}
+
+/// Render ICS calendar of all scheduled matches of the entrant with id `entrant_id`.
+/// Returns `None`, if no entrant with `entrant_id` exists or if the entrant belongs to a
+/// tournament, which is not public.
+#[instrument(name = "report.entrant_calendar", skip(core))]
+pub async fn render_entrant_calendar<S>(
+    core: &Core<S>,
+    entrant_id: Uuid,
+) -> CoreResult<Option<String>> {
+    let mut entrant_core = core.as_entrant_state(Uuid::nil());
+    let Some(entrant) = entrant_core.load(entrant_id).await?.cloned() else {
+        return Ok(None);
+    };
+    let Some(tournament) = public_tournament(core, entrant.get_tournament_id()).await? else {
+        return Ok(None);
+    };
+    let matches: Vec<Match> = scheduled_matches(core, tournament.get_id())
+        .await?
+        .into_iter()
+        .filter(|m| calendar::is_match_of_entrant(m, entrant_id))
+        .collect();
+    let events = calendar::match_events(
+        tournament.get_name(),
+        &matches,
+        &entrant_names(core, tournament.get_id()).await?,
+        calendar_match_duration(core, &tournament).await?,
+    );
+    info!(events = events.len(), "entrant_calendar_rendered");
+    let name = format!("{} - {}", tournament.get_name(), entrant.get_name());
+    Ok(Some(ics::render_calendar(&name, &events)))
+}
+
+/// Scheduled matches of tournament in order of their start. Matches with a bye are not
+/// played and therefore skipped.
+async fn scheduled_matches<S>(core: &Core<S>, tournament_id: Uuid) -> CoreResult<Vec<Match>> {
+    let mut matches = core
+        .database
+        .list_matches_of_tournament(tournament_id)
+        .await?;
+    matches.retain(|m| m.get_bye_entrant().is_none());
+    matches.sort_by_key(|m| (m.get_start_at(), m.get_station()));
+    Ok(matches)
+}
+
+/// Estimated duration of a match of `tournament` with the sport configuration of the
+/// tournament, see [`Core::estimate_match_timing_or_fallback`].
+async fn calendar_match_duration<S>(
+    core: &Core<S>,
+    tournament: &TournamentBase,
+) -> CoreResult<Duration> {
+    let timing = core.estimate_match_timing_or_fallback(tournament).await?;
+    Ok(Duration::from_std(timing.match_duration).unwrap_or_else(|_| Duration::zero()))
+}
+
+/// Tournament with id `tournament_id`, if it exists and is public, i.e. neither a draft nor
+/// a sandbox tournament. Reports are served to anonymous clients.
+async fn public_tournament<S>(
+    core: &Core<S>,
+    tournament_id: Uuid,
+) -> CoreResult<Option<TournamentBase>> {
+    let mut base_core = core.as_tournament_base_state();
+    Ok(base_core
+        .load(tournament_id)
+        .await?
+        .filter(|t| t.is_public())
+        .cloned())
+}
+
+async fn entrant_names<S>(
+    core: &Core<S>,
+    tournament_id: Uuid,
+) -> CoreResult<HashMap<Uuid, String>> {
+    Ok(core
+        .as_entrant_state(tournament_id)
+        .list_entrants()
+        .await?
+        .into_iter()
+        .map(|e| (e.get_id(), e.get_name().to_string()))
+        .collect())
+}
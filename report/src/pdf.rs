@@ -0,0 +1,190 @@
+//! PDF writer for text documents
+//!
+//! Documents consist of lines of text in DejaVu Sans Mono and DejaVu Sans Mono Bold, which
+//! are embedded into each PDF, so names with characters outside of Latin-1 print correctly.
+//! The monospaced font allows simple tables.
+
+use printpdf::{IndirectFontRef, Mm, PdfDocumentReference, Pt};
+
+/// A4 page size in points
+const PAGE_WIDTH: f32 = 595.0;
+const PAGE_HEIGHT: f32 = 842.0;
+const MARGIN: f32 = 50.0;
+/// width of a DejaVu Sans Mono character relative to font size
+const CHAR_WIDTH: f32 = 0.602;
+
+const REGULAR_FONT: &[u8] = include_bytes!("../fonts/DejaVuSansMono.ttf");
+const BOLD_FONT: &[u8] = include_bytes!("../fonts/DejaVuSansMono-Bold.ttf");
+
+/// style of a line of text
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum TextStyle {
+    Title,
+    Heading,
+    Body,
+}
+
+impl TextStyle {
+    fn is_bold(&self) -> bool {
+        matches!(self, TextStyle::Title | TextStyle::Heading)
+    }
+    fn size(&self) -> f32 {
+        match self {
+            TextStyle::Title => 16.0,
+            TextStyle::Heading => 12.0,
+            TextStyle::Body => 10.0,
+        }
+    }
+    fn line_height(&self) -> f32 {
+        self.size() * 1.4
+    }
+}
+
+/// line of text placed on a page
+#[derive(Debug)]
+struct PlacedLine {
+    style: TextStyle,
+    /// vertical position of baseline in points
+    y: f32,
+    text: String,
+}
+
+/// Text document, which is laid out line by line with automatic page breaks.
+///
+/// Layout is kept as plain data, the PDF is only built in [`PdfDocument::finish`].
+#[derive(Debug, Default)]
+pub struct PdfDocument {
+    /// lines of finished pages
+    pages: Vec<Vec<PlacedLine>>,
+    /// lines of current page
+    current: Vec<PlacedLine>,
+    /// vertical position of next line
+    y: f32,
+}
+
+impl PdfDocument {
+    pub fn new() -> Self {
+        PdfDocument {
+            pages: Vec::new(),
+            current: Vec::new(),
+            y: PAGE_HEIGHT - MARGIN,
+        }
+    }
+
+    /// number of characters of a line in `style`, which fit on a page
+    pub fn max_chars(style: TextStyle) -> usize {
+        ((PAGE_WIDTH - 2.0 * MARGIN) / (style.size() * CHAR_WIDTH)) as usize
+    }
+
+    /// Add a line of text. Lines exceeding the page width are truncated.
+    pub fn line(&mut self, style: TextStyle, text: &str) -> &mut Self {
+        let height = style.line_height();
+        if self.y - height < MARGIN {
+            self.page_break();
+        }
+        self.y -= height;
+        self.current.push(PlacedLine {
+            style,
+            y: self.y,
+            text: text.chars().take(Self::max_chars(style)).collect(),
+        });
+        self
+    }
+
+    /// Add an empty line.
+    pub fn blank(&mut self) -> &mut Self {
+        self.y -= TextStyle::Body.line_height();
+        self
+    }
+
+    /// Start a new page, unless current page is empty.
+    pub fn page_break(&mut self) -> &mut Self {
+        if !self.current.is_empty() {
+            self.pages.push(std::mem::take(&mut self.current));
+        }
+        self.y = PAGE_HEIGHT - MARGIN;
+        self
+    }
+
+    /// Finish document and return the PDF file.
+    pub fn finish(mut self) -> Vec<u8> {
+        self.page_break();
+        if self.pages.is_empty() {
+            self.pages.push(Vec::new());
+        }
+
+        let width = Mm::from(Pt(PAGE_WIDTH));
+        let height = Mm::from(Pt(PAGE_HEIGHT));
+        let (doc, first_page, first_layer) = printpdf::PdfDocument::new("", width, height, "Text");
+        let regular = embed_font(&doc, REGULAR_FONT);
+        let bold = embed_font(&doc, BOLD_FONT);
+        for (index, lines) in self.pages.iter().enumerate() {
+            let (page, layer) = if index == 0 {
+                (first_page, first_layer)
+            } else {
+                doc.add_page(width, height, "Text")
+            };
+            let layer = doc.get_page(page).get_layer(layer);
+            for line in lines {
+                let font = if line.style.is_bold() {
+                    &bold
+                } else {
+                    &regular
+                };
+                layer.use_text(
+                    line.text.as_str(),
+                    line.style.size(),
+                    Mm::from(Pt(MARGIN)),
+                    Mm::from(Pt(line.y)),
+                    font,
+                );
+            }
+        }
+        doc.save_to_bytes().expect("writing to a Vec never fails")
+    }
+}
+
+fn embed_font(doc: &PdfDocumentReference, font: &'static [u8]) -> IndirectFontRef {
+    doc.add_external_font(font)
+        .expect("embedded fonts are valid TrueType fonts")
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn keeps_characters_outside_of_latin1() {
+        let mut doc = PdfDocument::new();
+        doc.line(TextStyle::Body, "Dvořák, Łukasz, Erdős");
+        doc.page_break();
+        assert_eq!(doc.pages[0][0].text, "Dvořák, Łukasz, Erdős");
+
+        let pdf = doc.finish();
+        assert!(pdf.starts_with(b"%PDF-"));
+        let embedded_fonts = pdf.windows(10).filter(|w| w == b"/FontFile2").count();
+        assert_eq!(embedded_fonts, 2);
+    }
+
+    #[test]
+    fn long_lines_are_truncated_by_characters() {
+        let mut doc = PdfDocument::new();
+        let max = PdfDocument::max_chars(TextStyle::Body);
+        doc.line(TextStyle::Body, &"ő".repeat(max + 10));
+        assert_eq!(doc.current[0].text.chars().count(), max);
+    }
+
+    #[test]
+    fn long_documents_break_into_pages() {
+        let mut doc = PdfDocument::new();
+        for i in 0..200 {
+            doc.line(TextStyle::Body, &format!("line {i}"));
+        }
+        doc.page_break();
+        let pages = doc.pages.len();
+        assert!(pages > 1);
+        let pdf = doc.finish();
+        let count = format!("/Count {pages}");
+        assert!(pdf.windows(count.len()).any(|w| w == count.as_bytes()));
+    }
+}
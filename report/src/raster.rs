@@ -0,0 +1,202 @@
+//! minimal raster canvas with PNG encoding
+//!
+//! Supports filled rectangles, axis-parallel lines and text in the built-in bitmap font,
+//! which is all needed for brackets and tables.
+
+use crate::font::{GLYPH_SIZE, glyph};
+use flate2::{Compression, write::ZlibEncoder};
+use std::io::Write;
+
+/// RGB color
+pub type Rgb = [u8; 3];
+
+/// RGB image with origin in the top left corner
+#[derive(Debug, Clone)]
+pub struct Canvas {
+    width: u32,
+    height: u32,
+    pixels: Vec<Rgb>,
+}
+
+impl Canvas {
+    pub fn new(width: u32, height: u32, background: Rgb) -> Self {
+        Canvas {
+            width,
+            height,
+            pixels: vec![background; (width * height) as usize],
+        }
+    }
+
+    pub fn width(&self) -> u32 {
+        self.width
+    }
+
+    pub fn height(&self) -> u32 {
+        self.height
+    }
+
+    pub fn pixel(&self, x: u32, y: u32) -> Option<Rgb> {
+        (x < self.width && y < self.height).then(|| self.pixels[(y * self.width + x) as usize])
+    }
+
+    /// Fill rectangle; parts outside of the canvas are clipped.
+    pub fn fill_rect(&mut self, x: u32, y: u32, width: u32, height: u32, color: Rgb) {
+        let x_end = x.saturating_add(width).min(self.width);
+        let y_end = y.saturating_add(height).min(self.height);
+        for py in y.min(y_end)..y_end {
+            let row = (py * self.width) as usize;
+            self.pixels[row + x.min(x_end) as usize..row + x_end as usize].fill(color);
+        }
+    }
+
+    /// Draw border of rectangle with line width `stroke`.
+    pub fn stroke_rect(
+        &mut self,
+        x: u32,
+        y: u32,
+        width: u32,
+        height: u32,
+        stroke: u32,
+        color: Rgb,
+    ) {
+        self.fill_rect(x, y, width, stroke, color);
+        self.fill_rect(x, y + height.saturating_sub(stroke), width, stroke, color);
+        self.fill_rect(x, y, stroke, height, color);
+        self.fill_rect(x + width.saturating_sub(stroke), y, stroke, height, color);
+    }
+
+    /// width of `text` in pixels at `scale`
+    pub fn text_width(text: &str, scale: u32) -> u32 {
+        text.chars().count() as u32 * GLYPH_SIZE * scale
+    }
+
+    /// Draw `text` with top left corner at `x`, `y`. Glyphs are scaled by `scale`; text is
+    /// cut at the first glyph, which does not fit into `max_width`.
+    pub fn text(&mut self, x: u32, y: u32, text: &str, scale: u32, max_width: u32, color: Rgb) {
+        let advance = GLYPH_SIZE * scale;
+        let max_chars = (max_width / advance) as usize;
+        for (i, c) in text.chars().take(max_chars).enumerate() {
+            let gx = x + i as u32 * advance;
+            for (row, bits) in glyph(c).into_iter().enumerate() {
+                for col in 0..GLYPH_SIZE {
+                    if bits & (1 << col) != 0 {
+                        self.fill_rect(
+                            gx + col * scale,
+                            y + row as u32 * scale,
+                            scale,
+                            scale,
+                            color,
+                        );
+                    }
+                }
+            }
+        }
+    }
+
+    /// Encode canvas as 8 bit RGB PNG.
+    pub fn to_png(&self) -> Vec<u8> {
+        // scanlines with filter type 0 (none)
+        let mut raw = Vec::with_capacity((self.width * 3 + 1) as usize * self.height as usize);
+        for row in self.pixels.chunks(self.width.max(1) as usize) {
+            raw.push(0);
+            for pixel in row {
+                raw.extend_from_slice(pixel);
+            }
+        }
+        let mut encoder = ZlibEncoder::new(Vec::new(), Compression::default());
+        encoder
+            .write_all(&raw)
+            .expect("writing to a Vec never fails");
+        let data = encoder.finish().expect("writing to a Vec never fails");
+
+        let mut ihdr = Vec::with_capacity(13);
+        ihdr.extend_from_slice(&self.width.to_be_bytes());
+        ihdr.extend_from_slice(&self.height.to_be_bytes());
+        // bit depth 8, color type 2 (RGB), compression 0, filter 0, no interlace
+        ihdr.extend_from_slice(&[8, 2, 0, 0, 0]);
+
+        let mut png = b"\x89PNG\r\n\x1a\n".to_vec();
+        write_chunk(&mut png, b"IHDR", &ihdr);
+        write_chunk(&mut png, b"IDAT", &data);
+        write_chunk(&mut png, b"IEND", &[]);
+        png
+    }
+}
+
+fn write_chunk(png: &mut Vec<u8>, kind: &[u8; 4], data: &[u8]) {
+    png.extend_from_slice(&(data.len() as u32).to_be_bytes());
+    png.extend_from_slice(kind);
+    png.extend_from_slice(data);
+    let mut crc = crc32fast::Hasher::new();
+    crc.update(kind);
+    crc.update(data);
+    png.extend_from_slice(&crc.finalize().to_be_bytes());
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use flate2::read::ZlibDecoder;
+    use std::io::Read;
+
+    const WHITE: Rgb = [255, 255, 255];
+    const BLACK: Rgb = [0, 0, 0];
+
+    #[test]
+    fn shapes_are_clipped_to_canvas() {
+        let mut canvas = Canvas::new(10, 5, WHITE);
+        canvas.fill_rect(8, 3, 10, 10, BLACK);
+        assert_eq!(canvas.pixel(9, 4), Some(BLACK));
+        assert_eq!(canvas.pixel(7, 4), Some(WHITE));
+        assert_eq!(canvas.pixel(10, 4), None);
+
+        canvas.stroke_rect(0, 0, 4, 4, 1, BLACK);
+        assert_eq!(canvas.pixel(0, 2), Some(BLACK));
+        assert_eq!(canvas.pixel(3, 3), Some(BLACK));
+        assert_eq!(canvas.pixel(1, 1), Some(WHITE));
+    }
+
+    #[test]
+    fn text_is_scaled_and_cut_at_max_width() {
+        let mut canvas = Canvas::new(40, 16, WHITE);
+        // 'I' has a vertical bar in columns 2 and 3
+        canvas.text(0, 0, "II", 2, 20, BLACK);
+        assert_eq!(canvas.pixel(4, 4), Some(BLACK));
+        assert_eq!(canvas.pixel(7, 4), Some(BLACK));
+        assert_eq!(canvas.pixel(8, 4), Some(WHITE));
+        // second glyph does not fit into 20 pixels
+        assert_eq!(canvas.pixel(20, 4), Some(WHITE));
+        assert_eq!(Canvas::text_width("II", 2), 32);
+    }
+
+    #[test]
+    fn png_encoding() {
+        let mut canvas = Canvas::new(3, 2, WHITE);
+        canvas.fill_rect(1, 1, 1, 1, [255, 0, 0]);
+        let png = canvas.to_png();
+
+        assert_eq!(&png[..8], b"\x89PNG\r\n\x1a\n");
+        // IHDR chunk: length 13, width 3, height 2
+        assert_eq!(&png[8..16], b"\x00\x00\x00\x0dIHDR");
+        assert_eq!(&png[16..24], &[0, 0, 0, 3, 0, 0, 0, 2]);
+        assert_eq!(
+            &png[png.len() - 12..],
+            b"\x00\x00\x00\x00IEND\xae\x42\x60\x82"
+        );
+
+        // decode image data
+        let idat_len = u32::from_be_bytes(png[33..37].try_into().unwrap()) as usize;
+        assert_eq!(&png[37..41], b"IDAT");
+        let mut raw = Vec::new();
+        ZlibDecoder::new(&png[41..41 + idat_len])
+            .read_to_end(&mut raw)
+            .unwrap();
+        assert_eq!(
+            raw,
+            vec![
+                0, 255, 255, 255, 255, 255, 255, 255, 255, 255, //
+                0, 255, 255, 255, 255, 0, 0, 255, 255, 255
+            ]
+        );
+    }
+}
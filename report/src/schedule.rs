@@ -0,0 +1,248 @@
+//! schedule, group tables and KO brackets of a tournament
+//!
+//! Entrants are not yet assigned to groups before a stage starts, therefore the report uses
+//! slots (see [`app_core::slots`]), which organizers fill in by hand.
+//! Groups of the final stage of a multi stage tournament are printed as KO bracket, if
+//! their size allows a KO play out. Ring system groups are printed with the matches of
+//! each slot against its neighbors. All other groups are printed as round robin.
+//! Notes and tags of matches are printed on the last page.
+
+use crate::pdf::{PdfDocument, TextStyle};
+use app_core::{
+    MatchNote, Stage, TournamentBase, TournamentMode,
+    slots::{self, KoSide, group_label, group_sizes, is_ko_size},
+};
+use chrono::Utc;
+
+/// Render schedule of `tournament` with its `stages` and `match_notes` to PDF.
+pub fn render_schedule(
+    tournament: &TournamentBase,
+    stages: &[Stage],
+    match_notes: &[MatchNote],
+) -> Vec<u8> {
+    let mode = tournament.get_tournament_mode();
+    let mut doc = PdfDocument::new();
+    doc.line(TextStyle::Title, tournament.get_name())
+        .line(TextStyle::Body, &format!("Mode: {mode}"))
+        .line(
+            TextStyle::Body,
+            &format!("Entrants: {}", tournament.get_num_entrants()),
+        )
+        .line(
+            TextStyle::Body,
+            &format!("State: {}", tournament.get_tournament_state()),
+        )
+        .line(
+            TextStyle::Body,
+            &format!("Printed: {}", Utc::now().format("%Y-%m-%d %H:%M UTC")),
+        );
+
+    if stages.is_empty() {
+        doc.blank()
+            .line(TextStyle::Body, "No stages configured yet.");
+    }
+
+    let num_stages = mode.get_num_of_stages();
+    for stage in stages {
+        let stage_name = mode
+            .get_stage_name(stage.get_number())
+            .unwrap_or_else(|| format!("Stage {}", stage.get_number() + 1));
+        let is_final_stage = num_stages > 1 && stage.get_number() + 1 == num_stages;
+        doc.page_break().line(TextStyle::Title, &stage_name);
+
+        let sizes = group_sizes(tournament.get_num_entrants(), stage.get_num_groups());
+        for (index, size) in sizes.into_iter().enumerate() {
+            let group = group_label(index);
+            doc.blank().line(
+                TextStyle::Heading,
+                &format!("Group {group} ({size} entrants)"),
+            );
+            render_group_table(&mut doc, &group, size);
+            doc.blank();
+            let ring_rounds = match mode {
+                TournamentMode::RingSystem { num_neighbors } => {
+                    slots::ring_rounds(size, num_neighbors)
+                }
+                _ => Vec::new(),
+            };
+            if let TournamentMode::SwissSystem { num_rounds } = mode {
+                render_swiss_rounds(&mut doc, size, num_rounds);
+            } else if is_final_stage && is_ko_size(size) {
+                render_ko_bracket(&mut doc, &group, size);
+            } else if !ring_rounds.is_empty() {
+                render_rounds(&mut doc, &group, ring_rounds);
+            } else {
+                render_rounds(&mut doc, &group, round_robin(size));
+            }
+        }
+    }
+
+    if !match_notes.is_empty() {
+        doc.page_break().line(TextStyle::Title, "Match Notes");
+        for note in match_notes {
+            render_match_note(&mut doc, note);
+        }
+    }
+
+    doc.finish()
+}
+
+fn render_match_note(doc: &mut PdfDocument, note: &MatchNote) {
+    let match_id = note.get_match_id().to_string();
+    doc.blank().line(
+        TextStyle::Heading,
+        &format!("Match {} ({})", &match_id[..8], note.get_author()),
+    );
+    if !note.get_tags().is_empty() {
+        doc.line(
+            TextStyle::Body,
+            &format!("Tags: {}", note.get_tags().join(", ")),
+        );
+    }
+    for line in note.get_text().lines() {
+        doc.line(TextStyle::Body, line);
+    }
+}
+
+fn render_group_table(doc: &mut PdfDocument, group: &str, size: u32) {
+    doc.line(
+        TextStyle::Body,
+        &format!(
+            "{:<6}{:<40}{:>8}{:>8}{:>8}",
+            "Slot", "Entrant", "Matches", "Score", "Rank"
+        ),
+    );
+    for pos in 1..=size {
+        doc.line(
+            TextStyle::Body,
+            &format!(
+                "{:<6}{:<40}{:>8}{:>8}{:>8}",
+                format!("{group}{pos}"),
+                "_".repeat(36),
+                "____",
+                "____",
+                "____"
+            ),
+        );
+    }
+}
+
+fn render_rounds(doc: &mut PdfDocument, group: &str, rounds: Vec<Vec<(u32, u32)>>) {
+    let mut match_number = 1;
+    for (round, pairings) in rounds.into_iter().enumerate() {
+        doc.line(TextStyle::Heading, &format!("Round {}", round + 1));
+        for (a, b) in pairings {
+            doc.line(
+                TextStyle::Body,
+                &format!("M{match_number:<4}{group}{a} - {group}{b}    Result: ____ : ____"),
+            );
+            match_number += 1;
+        }
+    }
+}
+
+fn render_ko_bracket(doc: &mut PdfDocument, group: &str, size: u32) {
+    for (round_name, pairings) in ko_bracket(group, size) {
+        doc.line(TextStyle::Heading, &round_name);
+        for pairing in pairings {
+            doc.line(
+                TextStyle::Body,
+                &format!("{pairing}    Result: ____ : ____"),
+            );
+        }
+    }
+}
+
+fn render_swiss_rounds(doc: &mut PdfDocument, size: u32, num_rounds: u32) {
+    for round in 1..=num_rounds {
+        doc.line(
+            TextStyle::Heading,
+            &format!("Round {round} (pairings by current ranking)"),
+        );
+        for _ in 0..size / 2 {
+            doc.line(
+                TextStyle::Body,
+                "________________ - ________________    Result: ____ : ____",
+            );
+        }
+        if size % 2 == 1 {
+            doc.line(TextStyle::Body, "Free win: ________________");
+        }
+    }
+}
+
+/// Round robin pairings of slots `1..=size` with the circle method. With an odd number of
+/// entrants one entrant pauses each round.
+fn round_robin(size: u32) -> Vec<Vec<(u32, u32)>> {
+    if size < 2 {
+        return Vec::new();
+    }
+    // slot 0 represents the pause for odd sizes
+    let n = size + size % 2;
+    let mut slots: Vec<u32> = (1..=n).map(|s| if s > size { 0 } else { s }).collect();
+    let mut rounds = Vec::with_capacity(n as usize - 1);
+    for _ in 0..n - 1 {
+        let pairings = (0..n as usize / 2)
+            .map(|i| (slots[i], slots[n as usize - 1 - i]))
+            .filter(|(a, b)| *a != 0 && *b != 0)
+            .map(|(a, b)| (a.min(b), a.max(b)))
+            .collect();
+        rounds.push(pairings);
+        // keep first slot fixed, rotate the others
+        slots[1..].rotate_right(1);
+    }
+    rounds
+}
+
+/// Rounds of KO bracket with match labels like `M5   Winner M1 - Winner M4`.
+fn ko_bracket(group: &str, size: u32) -> Vec<(String, Vec<String>)> {
+    let side = |side: KoSide| match side {
+        KoSide::Slot(slot) => format!("{group}{slot}"),
+        KoSide::WinnerOf(number) => format!("Winner M{number}"),
+        KoSide::LoserOf(number) => format!("Loser M{number}"),
+    };
+    slots::ko_bracket(size)
+        .into_iter()
+        .map(|round| {
+            let pairings = round
+                .matches
+                .into_iter()
+                .map(|m| format!("M{:<4}{} - {}", m.number, side(m.side_a), side(m.side_b)))
+                .collect();
+            (round.name, pairings)
+        })
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::collections::HashSet;
+
+    #[test]
+    fn round_robin_pairs_every_slot_once() {
+        for size in 2..=9 {
+            let rounds = round_robin(size);
+            let pairings: Vec<(u32, u32)> = rounds.iter().flatten().copied().collect();
+            let unique: HashSet<(u32, u32)> = pairings.iter().copied().collect();
+            assert_eq!(pairings.len(), unique.len());
+            assert_eq!(pairings.len() as u32, size * (size - 1) / 2);
+            for round in &rounds {
+                let slots: HashSet<u32> = round.iter().flat_map(|(a, b)| [*a, *b]).collect();
+                assert_eq!(slots.len(), round.len() * 2);
+            }
+        }
+    }
+
+    #[test]
+    fn ko_bracket_of_eight() {
+        let rounds = ko_bracket("A", 8);
+        let names: Vec<&str> = rounds.iter().map(|(n, _)| n.as_str()).collect();
+        assert_eq!(names, vec!["Quarter Finals", "Semi Finals", "Final"]);
+        assert_eq!(rounds[0].1[0], "M1   A1 - A8");
+        // top seeds 1 and 2 meet in the final at the earliest
+        assert_eq!(rounds[1].1[0], "M5   Winner M1 - Winner M4");
+        assert_eq!(rounds[1].1[1], "M6   Winner M2 - Winner M3");
+        assert_eq!(rounds[2].1[0], "M7   Winner M5 - Winner M6");
+    }
+}
@@ -0,0 +1,117 @@
+//! score sheet of a match for referees at the station
+
+use crate::pdf::{PdfDocument, TextStyle};
+use app_core::ScoreSheet;
+
+/// width of the name column of the set table
+const NAME_WIDTH: usize = 20;
+/// blank set box
+const SET_BOX: &str = "[     ]";
+
+/// Render `sheet` to PDF with one blank box per set and side.
+pub fn render_score_sheet_pdf(sheet: &ScoreSheet) -> Vec<u8> {
+    let mut doc = PdfDocument::new();
+    doc.line(TextStyle::Title, &sheet.tournament_name)
+        .line(
+            TextStyle::Body,
+            &format!("Score Sheet - {}", sheet.sport_name),
+        )
+        .blank()
+        .line(TextStyle::Heading, &format!("Match {}", sheet.match_number))
+        .line(TextStyle::Body, &format!("Station: {}", sheet.station))
+        .line(
+            TextStyle::Body,
+            &format!("Start: {}", sheet.start_at.format("%Y-%m-%d %H:%M")),
+        )
+        .blank();
+    for line in set_table(sheet) {
+        doc.line(TextStyle::Body, &line);
+    }
+    doc.blank()
+        .line(TextStyle::Body, "Winner: ______________________________")
+        .blank()
+        .line(TextStyle::Body, "Referee: _____________________________")
+        .blank()
+        .line(
+            TextStyle::Body,
+            &format!("Signature {}: ____________________", sheet.side_a),
+        )
+        .blank()
+        .line(
+            TextStyle::Body,
+            &format!("Signature {}: ____________________", sheet.side_b),
+        );
+    doc.finish()
+}
+
+/// Header and one row per side with blank set boxes. Rows longer than a page are wrapped
+/// into blocks of sets.
+fn set_table(sheet: &ScoreSheet) -> Vec<String> {
+    let box_width = SET_BOX.len() + 1;
+    let sets_per_block =
+        ((PdfDocument::max_chars(TextStyle::Body) - NAME_WIDTH) / box_width).max(1);
+    let sets: Vec<u16> = (1..=sheet.num_sets.max(1)).collect();
+    let mut lines = Vec::new();
+    for block in sets.chunks(sets_per_block) {
+        let header: String = block
+            .iter()
+            .map(|set| format!("{:^width$}", format!("Set {set}"), width = box_width))
+            .collect();
+        let boxes = format!("{SET_BOX} ").repeat(block.len());
+        lines.push(format!("{:NAME_WIDTH$}{header}", ""));
+        lines.push(format!("{:NAME_WIDTH$}{boxes}", column_name(&sheet.side_a)));
+        lines.push(format!("{:NAME_WIDTH$}{boxes}", column_name(&sheet.side_b)));
+    }
+    lines
+}
+
+fn column_name(name: &str) -> String {
+    name.chars().take(NAME_WIDTH - 1).collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use chrono::Local;
+    use uuid::Uuid;
+
+    fn sheet(num_sets: u16) -> ScoreSheet {
+        ScoreSheet {
+            match_id: Uuid::new_v4(),
+            tournament_id: Uuid::new_v4(),
+            tournament_name: "Spring Cup".to_string(),
+            sport_name: "Table Tennis".to_string(),
+            match_number: 7,
+            side_a: "Anna".to_string(),
+            side_b: "A very long name of a doubles team".to_string(),
+            station: "Table 3".to_string(),
+            start_at: Local::now(),
+            num_sets,
+        }
+    }
+
+    #[test]
+    fn renders_one_box_per_set_and_side() {
+        let lines = set_table(&sheet(5));
+        assert_eq!(lines.len(), 3);
+        assert!(lines[0].contains("Set 5"));
+        assert!(lines[1].starts_with("Anna"));
+        assert_eq!(lines[1].matches(SET_BOX).count(), 5);
+        assert_eq!(lines[2].matches(SET_BOX).count(), 5);
+        assert!(lines[2].len() <= PdfDocument::max_chars(TextStyle::Body));
+    }
+
+    #[test]
+    fn wraps_many_sets_into_blocks() {
+        let lines = set_table(&sheet(9));
+        assert_eq!(lines.len(), 6);
+        let boxes: usize = lines.iter().map(|l| l.matches(SET_BOX).count()).sum();
+        assert_eq!(boxes, 18);
+    }
+
+    #[test]
+    fn renders_pdf() {
+        let pdf = render_score_sheet_pdf(&sheet(3));
+        assert!(pdf.starts_with(b"%PDF-1.4"));
+    }
+}
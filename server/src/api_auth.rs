@@ -0,0 +1,168 @@
+//! authentication of REST API requests with api tokens
+
+use app_core::{ApiScope, ApiToken, CoreState};
+use axum::{
+    extract::{FromRequestParts, Request, State},
+    http::{StatusCode, header, request::Parts},
+    middleware::{Next, from_fn_with_state},
+    response::{IntoResponse, Response},
+    routing::MethodRouter,
+};
+use std::{
+    collections::HashMap,
+    convert::Infallible,
+    sync::{Arc, Mutex},
+    time::{Duration, Instant},
+};
+use tracing::{debug, error, warn};
+use uuid::Uuid;
+
+/// length of rate limit window
+const RATE_LIMIT_WINDOW: Duration = Duration::from_secs(60);
+
+/// Fixed window rate limiter per api token.
+///
+/// Counters are kept in memory, therefore the limit applies per server instance.
+#[derive(Clone, Default)]
+pub struct ApiRateLimiter {
+    windows: Arc<Mutex<HashMap<Uuid, (Instant, u32)>>>,
+}
+
+impl ApiRateLimiter {
+    /// Count a request of `token`. Returns the time until the current window ends, if the
+    /// limit of the token is exceeded.
+    fn check(&self, token: &ApiToken) -> Result<(), Duration> {
+        let now = Instant::now();
+        let mut windows = self
+            .windows
+            .lock()
+            .expect("rate limiter lock is not poisoned");
+        let (start, count) = windows.entry(token.get_id()).or_insert((now, 0));
+        if now.duration_since(*start) >= RATE_LIMIT_WINDOW {
+            *start = now;
+            *count = 0;
+        }
+        if *count >= token.get_rate_limit_per_minute() {
+            return Err(RATE_LIMIT_WINDOW - now.duration_since(*start));
+        }
+        *count += 1;
+        Ok(())
+    }
+}
+
+/// State of [`require_api_scope`] middleware
+#[derive(Clone)]
+pub struct ApiAuthState {
+    core: CoreState,
+    limiter: ApiRateLimiter,
+    scope: ApiScope,
+}
+
+impl ApiAuthState {
+    pub fn new(core: CoreState, limiter: ApiRateLimiter, scope: ApiScope) -> Self {
+        ApiAuthState {
+            core,
+            limiter,
+            scope,
+        }
+    }
+}
+
+/// Require an api token with `scope` for requests to `route`, see [`require_api_scope`].
+pub fn with_api_scope<S>(
+    route: MethodRouter<S>,
+    core: &CoreState,
+    limiter: &ApiRateLimiter,
+    scope: ApiScope,
+) -> MethodRouter<S>
+where
+    S: Clone + Send + Sync + 'static,
+{
+    route.route_layer(from_fn_with_state(
+        ApiAuthState::new(core.clone(), limiter.clone(), scope),
+        require_api_scope,
+    ))
+}
+
+/// Middleware, which requires an api token with the scope of `auth` as bearer token.
+///
+/// Routes with scope `ReadPublic` serve public data and may be requested without token.
+/// The authenticated token is available to handlers via the [`ApiAuth`] extractor.
+pub async fn require_api_scope(
+    State(auth): State<ApiAuthState>,
+    mut req: Request,
+    next: Next,
+) -> Response {
+    let secret = req
+        .headers()
+        .get(header::AUTHORIZATION)
+        .and_then(|v| v.to_str().ok())
+        .and_then(|v| v.strip_prefix("Bearer "))
+        .map(|v| v.trim().to_string());
+
+    let Some(secret) = secret else {
+        if auth.scope == ApiScope::ReadPublic {
+            return next.run(req).await;
+        }
+        return (
+            StatusCode::UNAUTHORIZED,
+            [(header::WWW_AUTHENTICATE, "Bearer")],
+            "api token required",
+        )
+            .into_response();
+    };
+
+    let token = match auth.core.authenticate_api_token(&secret).await {
+        Ok(Some(token)) => token,
+        Ok(None) => {
+            warn!("api_token_invalid");
+            return (
+                StatusCode::UNAUTHORIZED,
+                [(header::WWW_AUTHENTICATE, "Bearer error=\"invalid_token\"")],
+                "invalid or revoked api token",
+            )
+                .into_response();
+        }
+        Err(e) => {
+            error!(error = %e, "api_token_authentication_failed");
+            return (StatusCode::SERVICE_UNAVAILABLE, "could not authenticate").into_response();
+        }
+    };
+
+    if !token.has_scope(auth.scope) {
+        warn!(token_id = %token.get_id(), scope = %auth.scope, "api_token_scope_missing");
+        return (
+            StatusCode::FORBIDDEN,
+            format!("api token lacks scope {}", auth.scope),
+        )
+            .into_response();
+    }
+    if let Err(retry_after) = auth.limiter.check(&token) {
+        warn!(token_id = %token.get_id(), "api_token_rate_limited");
+        return (
+            StatusCode::TOO_MANY_REQUESTS,
+            [(
+                header::RETRY_AFTER,
+                retry_after.as_secs().max(1).to_string(),
+            )],
+            "rate limit exceeded",
+        )
+            .into_response();
+    }
+
+    debug!(token_id = %token.get_id(), "api_token_authenticated");
+    req.extensions_mut().insert(token);
+    next.run(req).await
+}
+
+/// Extractor of the api token authenticated by [`require_api_scope`]; `None` for anonymous
+/// requests to public routes.
+pub struct ApiAuth(pub Option<ApiToken>);
+
+impl<S: Send + Sync> FromRequestParts<S> for ApiAuth {
+    type Rejection = Infallible;
+
+    async fn from_request_parts(parts: &mut Parts, _state: &S) -> Result<Self, Self::Rejection> {
+        Ok(ApiAuth(parts.extensions.get::<ApiToken>().cloned()))
+    }
+}
@@ -0,0 +1,559 @@
+//! versioned REST API for third-party clients, e.g. mobile apps and scoreboards
+//!
+//! All routes live under `/api/v1/` and serve data, which is visible on public pages anyway.
+//! They reuse the public views of the core; drafts and sandbox tournaments are never
+//! returned. Clients may send an api token as bearer token, which is required for higher
+//! rate limits. The OpenAPI document is served at `/api/v1/openapi.json`.
+//!
+//! Response bodies are stable data transfer objects of this module, not the core types, so
+//! the core may evolve without breaking clients. Breaking changes require `/api/v2/`.
+
+use crate::api_auth::{ApiAuthState, ApiRateLimiter, require_api_scope};
+use app_core::{
+    ApiScope, CoreError, CoreState, Entrant, GroupStanding, Match, PublicGroup,
+    PublicGroupStandings, PublicStage, TournamentBase, slots::KoSide,
+};
+use axum::{
+    Json, Router,
+    extract::{Path, Query, State},
+    http::StatusCode,
+    middleware::from_fn_with_state,
+    response::{IntoResponse, Response},
+    routing::get,
+};
+use chrono::{DateTime, Utc};
+use serde::{Deserialize, Serialize};
+use shared::AppState;
+use tracing::{error, instrument};
+use utoipa::{
+    IntoParams, Modify, OpenApi, ToSchema,
+    openapi::security::{HttpAuthScheme, HttpBuilder, SecurityScheme},
+};
+use uuid::Uuid;
+
+/// maximum number of tournaments per page of the tournament list
+const MAX_PAGE_SIZE: usize = 100;
+
+/// Routes of the REST API, to be nested at `/api/v1`.
+pub fn api_v1_routes(core: CoreState, limiter: ApiRateLimiter) -> Router<AppState> {
+    Router::new()
+        .route("/openapi.json", get(openapi_json))
+        .route("/tournaments", get(list_tournaments))
+        .route("/tournaments/{id}", get(get_tournament))
+        .route("/tournaments/{id}/entrants", get(list_entrants))
+        .route("/tournaments/{id}/stages", get(list_stages))
+        .route("/tournaments/{id}/stages/{number}/groups", get(list_groups))
+        .route("/tournaments/{id}/matches", get(list_matches))
+        .route("/tournaments/{id}/standings", get(list_standings))
+        .route_layer(from_fn_with_state(
+            ApiAuthState::new(core, limiter, ApiScope::ReadPublic),
+            require_api_scope,
+        ))
+}
+
+// --- OpenAPI document ---
+
+#[derive(OpenApi)]
+#[openapi(
+    info(
+        title = "FK Tournament Planer API",
+        version = "1",
+        description = "Read-only access to public tournaments, their stages, groups, matches and standings."
+    ),
+    paths(
+        list_tournaments,
+        get_tournament,
+        list_entrants,
+        list_stages,
+        list_groups,
+        list_matches,
+        list_standings
+    ),
+    components(schemas(
+        ApiErrorBody,
+        TournamentDto,
+        EntrantDto,
+        StageDto,
+        GroupDto,
+        KoRoundDto,
+        KoMatchDto,
+        MatchDto,
+        GroupStandingsDto,
+        StandingDto
+    )),
+    modifiers(&BearerToken),
+    tags((name = "tournaments", description = "public tournaments"))
+)]
+pub struct ApiDoc;
+
+/// optional api token as bearer token
+struct BearerToken;
+
+impl Modify for BearerToken {
+    fn modify(&self, openapi: &mut utoipa::openapi::OpenApi) {
+        if let Some(components) = openapi.components.as_mut() {
+            components.add_security_scheme(
+                "api_token",
+                SecurityScheme::Http(HttpBuilder::new().scheme(HttpAuthScheme::Bearer).build()),
+            );
+        }
+    }
+}
+
+async fn openapi_json() -> Json<utoipa::openapi::OpenApi> {
+    Json(ApiDoc::openapi())
+}
+
+// --- errors ---
+
+/// error response of the REST API
+#[derive(Debug, Serialize, ToSchema)]
+pub struct ApiErrorBody {
+    /// human readable description of the error
+    pub error: String,
+}
+
+/// error of a REST API handler
+enum ApiError {
+    NotFound(&'static str),
+    Core(CoreError),
+}
+
+impl From<CoreError> for ApiError {
+    fn from(e: CoreError) -> Self {
+        ApiError::Core(e)
+    }
+}
+
+impl IntoResponse for ApiError {
+    fn into_response(self) -> Response {
+        let (status, error) = match self {
+            ApiError::NotFound(what) => (StatusCode::NOT_FOUND, format!("{what} not found")),
+            ApiError::Core(e) => {
+                error!(error = %e, "api_v1_failed");
+                (
+                    StatusCode::INTERNAL_SERVER_ERROR,
+                    "internal server error".to_string(),
+                )
+            }
+        };
+        (status, Json(ApiErrorBody { error })).into_response()
+    }
+}
+
+type ApiResult<T> = Result<Json<T>, ApiError>;
+
+// --- data transfer objects ---
+
+/// public tournament
+#[derive(Debug, Serialize, ToSchema)]
+pub struct TournamentDto {
+    pub id: Uuid,
+    pub name: String,
+    pub sport_id: Uuid,
+    /// e.g. `Pool and Final Stage`
+    pub mode: String,
+    /// e.g. `Published`, `Running (Stage 1)` or `Finished`
+    pub state: String,
+    pub num_entrants: u32,
+    pub num_stations: u32,
+    pub created_at: Option<DateTime<Utc>>,
+}
+
+impl From<&TournamentBase> for TournamentDto {
+    fn from(t: &TournamentBase) -> Self {
+        TournamentDto {
+            id: t.get_id(),
+            name: t.get_name().to_string(),
+            sport_id: t.get_sport_id(),
+            mode: t.get_tournament_mode().to_string(),
+            state: t.get_tournament_state().to_string(),
+            num_entrants: t.get_num_entrants(),
+            num_stations: t.get_num_stations(),
+            created_at: t.get_created_at(),
+        }
+    }
+}
+
+/// entrant of a public tournament; email addresses are never returned
+#[derive(Debug, Serialize, ToSchema)]
+pub struct EntrantDto {
+    pub id: Uuid,
+    pub name: String,
+    pub club: Option<String>,
+    pub seed: Option<u32>,
+}
+
+impl From<&Entrant> for EntrantDto {
+    fn from(e: &Entrant) -> Self {
+        EntrantDto {
+            id: e.get_id(),
+            name: e.get_name().to_string(),
+            club: e.get_club().map(str::to_string),
+            seed: e.get_seed(),
+        }
+    }
+}
+
+/// stage of a public tournament
+#[derive(Debug, Serialize, ToSchema)]
+pub struct StageDto {
+    pub id: Uuid,
+    /// number of stage, starting with 0
+    pub number: u32,
+    /// e.g. `Final Stage`
+    pub name: String,
+    pub num_groups: u32,
+}
+
+impl From<&PublicStage> for StageDto {
+    fn from(s: &PublicStage) -> Self {
+        StageDto {
+            id: s.id,
+            number: s.number,
+            name: s.name.clone(),
+            num_groups: s.groups.len() as u32,
+        }
+    }
+}
+
+/// match of a KO bracket; sides are slots of the group (e.g. `A1`) or winners of previous
+/// matches (e.g. `Winner M1`)
+#[derive(Debug, Serialize, ToSchema)]
+pub struct KoMatchDto {
+    pub number: u32,
+    pub side_a: String,
+    pub side_b: String,
+}
+
+/// round of a KO bracket
+#[derive(Debug, Serialize, ToSchema)]
+pub struct KoRoundDto {
+    /// e.g. `Semi Finals`
+    pub name: String,
+    pub matches: Vec<KoMatchDto>,
+}
+
+/// group of a public stage
+#[derive(Debug, Serialize, ToSchema)]
+pub struct GroupDto {
+    /// e.g. `B`; slots of group are `B1`, `B2`, ...
+    pub label: String,
+    pub num_entrants: u32,
+    /// KO bracket, if the group plays out the final stage in KO mode; empty otherwise
+    pub bracket: Vec<KoRoundDto>,
+}
+
+impl From<&PublicGroup> for GroupDto {
+    fn from(g: &PublicGroup) -> Self {
+        let side = |side: KoSide| match side {
+            KoSide::Slot(slot) => format!("{}{slot}", g.label),
+            KoSide::WinnerOf(number) => format!("Winner M{number}"),
+            KoSide::LoserOf(number) => format!("Loser M{number}"),
+        };
+        GroupDto {
+            label: g.label.clone(),
+            num_entrants: g.num_entrants,
+            bracket: g
+                .bracket
+                .iter()
+                .map(|round| KoRoundDto {
+                    name: round.name.clone(),
+                    matches: round
+                        .matches
+                        .iter()
+                        .map(|m| KoMatchDto {
+                            number: m.number,
+                            side_a: side(m.side_a),
+                            side_b: side(m.side_b),
+                        })
+                        .collect(),
+                })
+                .collect(),
+        }
+    }
+}
+
+/// match of a public tournament
+#[derive(Debug, Serialize, ToSchema)]
+pub struct MatchDto {
+    pub id: Uuid,
+    pub stage_id: Uuid,
+    pub group_id: Uuid,
+    pub number: u32,
+    pub station: u32,
+    pub start_at: DateTime<Utc>,
+    /// entrants of side a and b; `None`, if the entrants are not decided yet
+    pub entrant_ids: Option<(Uuid, Uuid)>,
+    pub in_progress: bool,
+    pub decided: bool,
+    /// e.g. `Played`, `Forfeit by A` or `Bye`
+    pub outcome: String,
+    /// scores of each set of side a
+    pub scores_a: Vec<u16>,
+    /// scores of each set of side b
+    pub scores_b: Vec<u16>,
+}
+
+impl From<&Match> for MatchDto {
+    fn from(m: &Match) -> Self {
+        let (scores_a, scores_b) = m.get_scores();
+        MatchDto {
+            id: *m.get_id(),
+            stage_id: *m.get_stage_id(),
+            group_id: *m.get_group_id(),
+            number: m.get_number(),
+            station: m.get_station() as u32,
+            start_at: m.get_start_at().with_timezone(&Utc),
+            entrant_ids: m.get_entrants().map(|(a, b)| (*a, *b)),
+            in_progress: m.is_in_progress(),
+            decided: m.is_decided(),
+            outcome: m.get_outcome().to_string(),
+            scores_a: scores_a.clone(),
+            scores_b: scores_b.clone(),
+        }
+    }
+}
+
+/// rank and score of an entrant in its group
+#[derive(Debug, Serialize, ToSchema)]
+pub struct StandingDto {
+    /// rank in group starting at 1; entrants with unbroken ties share a rank
+    pub rank: u32,
+    pub entrant_id: Uuid,
+    pub victory_points: f32,
+    pub relative_score: i16,
+    pub total_score: u16,
+    pub wins: u16,
+    pub draws: u16,
+    pub losses: u16,
+}
+
+impl From<&GroupStanding> for StandingDto {
+    fn from(s: &GroupStanding) -> Self {
+        StandingDto {
+            rank: s.rank,
+            entrant_id: s.get_entrant_id(),
+            victory_points: s.score.victory_points,
+            relative_score: s.score.relative_score,
+            total_score: s.score.total_score,
+            wins: s.score.wins,
+            draws: s.score.draws,
+            losses: s.score.losses,
+        }
+    }
+}
+
+/// standings of a group
+#[derive(Debug, Serialize, ToSchema)]
+pub struct GroupStandingsDto {
+    pub stage_id: Uuid,
+    pub stage_number: u32,
+    pub group_label: String,
+    /// standings sorted by rank; empty, if no match of the group is decided yet
+    pub standings: Vec<StandingDto>,
+}
+
+impl From<&PublicGroupStandings> for GroupStandingsDto {
+    fn from(g: &PublicGroupStandings) -> Self {
+        GroupStandingsDto {
+            stage_id: g.stage_id,
+            stage_number: g.stage_number,
+            group_label: g.group_label.clone(),
+            standings: g.standings.iter().map(StandingDto::from).collect(),
+        }
+    }
+}
+
+// --- handlers ---
+
+/// paging of lists
+#[derive(Debug, Deserialize, IntoParams)]
+pub struct PageQuery {
+    /// maximum number of items, at most 100; defaults to 100
+    limit: Option<usize>,
+    /// number of items to skip; defaults to 0
+    offset: Option<usize>,
+}
+
+/// List public tournaments, newest first.
+#[utoipa::path(
+    get,
+    path = "/api/v1/tournaments",
+    params(PageQuery),
+    responses((status = 200, body = [TournamentDto])),
+    security((), ("api_token" = [])),
+    tag = "tournaments"
+)]
+#[instrument(name = "api_v1.list_tournaments", skip(app_state))]
+async fn list_tournaments(
+    State(app_state): State<AppState>,
+    Query(page): Query<PageQuery>,
+) -> ApiResult<Vec<TournamentDto>> {
+    let limit = page.limit.unwrap_or(MAX_PAGE_SIZE).min(MAX_PAGE_SIZE);
+    let tournaments = app_state
+        .core
+        .list_public_tournaments(Some(limit), page.offset.unwrap_or_default())
+        .await?;
+    Ok(Json(tournaments.iter().map(TournamentDto::from).collect()))
+}
+
+/// Get a public tournament.
+#[utoipa::path(
+    get,
+    path = "/api/v1/tournaments/{id}",
+    params(("id" = Uuid, Path, description = "id of tournament")),
+    responses(
+        (status = 200, body = TournamentDto),
+        (status = 404, body = ApiErrorBody, description = "tournament not found or not public")
+    ),
+    security((), ("api_token" = [])),
+    tag = "tournaments"
+)]
+#[instrument(name = "api_v1.get_tournament", skip(app_state))]
+async fn get_tournament(
+    State(app_state): State<AppState>,
+    Path(id): Path<Uuid>,
+) -> ApiResult<TournamentDto> {
+    let view = app_state
+        .core
+        .load_public_tournament(id)
+        .await?
+        .ok_or(ApiError::NotFound("tournament"))?;
+    Ok(Json(TournamentDto::from(&view.tournament)))
+}
+
+/// List entrants of a public tournament, sorted by seed and name.
+#[utoipa::path(
+    get,
+    path = "/api/v1/tournaments/{id}/entrants",
+    params(("id" = Uuid, Path, description = "id of tournament")),
+    responses(
+        (status = 200, body = [EntrantDto]),
+        (status = 404, body = ApiErrorBody, description = "tournament not found or not public")
+    ),
+    security((), ("api_token" = [])),
+    tag = "tournaments"
+)]
+#[instrument(name = "api_v1.list_entrants", skip(app_state))]
+async fn list_entrants(
+    State(app_state): State<AppState>,
+    Path(id): Path<Uuid>,
+) -> ApiResult<Vec<EntrantDto>> {
+    let view = app_state
+        .core
+        .load_public_tournament(id)
+        .await?
+        .ok_or(ApiError::NotFound("tournament"))?;
+    Ok(Json(view.entrants.iter().map(EntrantDto::from).collect()))
+}
+
+/// List stages of a public tournament, sorted by number.
+#[utoipa::path(
+    get,
+    path = "/api/v1/tournaments/{id}/stages",
+    params(("id" = Uuid, Path, description = "id of tournament")),
+    responses(
+        (status = 200, body = [StageDto]),
+        (status = 404, body = ApiErrorBody, description = "tournament not found or not public")
+    ),
+    security((), ("api_token" = [])),
+    tag = "tournaments"
+)]
+#[instrument(name = "api_v1.list_stages", skip(app_state))]
+async fn list_stages(
+    State(app_state): State<AppState>,
+    Path(id): Path<Uuid>,
+) -> ApiResult<Vec<StageDto>> {
+    let view = app_state
+        .core
+        .load_public_tournament(id)
+        .await?
+        .ok_or(ApiError::NotFound("tournament"))?;
+    Ok(Json(view.stages.iter().map(StageDto::from).collect()))
+}
+
+/// List groups of a stage of a public tournament.
+#[utoipa::path(
+    get,
+    path = "/api/v1/tournaments/{id}/stages/{number}/groups",
+    params(
+        ("id" = Uuid, Path, description = "id of tournament"),
+        ("number" = u32, Path, description = "number of stage, starting with 0")
+    ),
+    responses(
+        (status = 200, body = [GroupDto]),
+        (status = 404, body = ApiErrorBody, description = "tournament or stage not found")
+    ),
+    security((), ("api_token" = [])),
+    tag = "tournaments"
+)]
+#[instrument(name = "api_v1.list_groups", skip(app_state))]
+async fn list_groups(
+    State(app_state): State<AppState>,
+    Path((id, number)): Path<(Uuid, u32)>,
+) -> ApiResult<Vec<GroupDto>> {
+    let view = app_state
+        .core
+        .load_public_tournament(id)
+        .await?
+        .ok_or(ApiError::NotFound("tournament"))?;
+    let stage = view
+        .stages
+        .iter()
+        .find(|s| s.number == number)
+        .ok_or(ApiError::NotFound("stage"))?;
+    Ok(Json(stage.groups.iter().map(GroupDto::from).collect()))
+}
+
+/// List matches of a public tournament.
+#[utoipa::path(
+    get,
+    path = "/api/v1/tournaments/{id}/matches",
+    params(("id" = Uuid, Path, description = "id of tournament")),
+    responses(
+        (status = 200, body = [MatchDto]),
+        (status = 404, body = ApiErrorBody, description = "tournament not found or not public")
+    ),
+    security((), ("api_token" = [])),
+    tag = "tournaments"
+)]
+#[instrument(name = "api_v1.list_matches", skip(app_state))]
+async fn list_matches(
+    State(app_state): State<AppState>,
+    Path(id): Path<Uuid>,
+) -> ApiResult<Vec<MatchDto>> {
+    let matches = app_state
+        .core
+        .load_public_matches(id)
+        .await?
+        .ok_or(ApiError::NotFound("tournament"))?;
+    Ok(Json(matches.iter().map(MatchDto::from).collect()))
+}
+
+/// List current standings of all groups of a public tournament.
+#[utoipa::path(
+    get,
+    path = "/api/v1/tournaments/{id}/standings",
+    params(("id" = Uuid, Path, description = "id of tournament")),
+    responses(
+        (status = 200, body = [GroupStandingsDto]),
+        (status = 404, body = ApiErrorBody, description = "tournament not found or not public")
+    ),
+    security((), ("api_token" = [])),
+    tag = "tournaments"
+)]
+#[instrument(name = "api_v1.list_standings", skip(app_state))]
+async fn list_standings(
+    State(app_state): State<AppState>,
+    Path(id): Path<Uuid>,
+) -> ApiResult<Vec<GroupStandingsDto>> {
+    let standings = app_state
+        .core
+        .load_public_standings(id)
+        .await?
+        .ok_or(ApiError::NotFound("tournament"))?;
+    Ok(Json(
+        standings.iter().map(GroupStandingsDto::from).collect(),
+    ))
+}
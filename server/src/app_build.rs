@@ -0,0 +1,53 @@
+//! refusing server function calls of outdated clients
+
+use app_utils::{
+    error::AppError,
+    version::{APP_BUILD, APP_BUILD_HEADER, is_compatible_build},
+};
+use axum::{
+    extract::Request,
+    http::{StatusCode, header},
+    middleware::Next,
+    response::{IntoResponse, Response},
+};
+use tracing::{error, warn};
+
+/// Middleware, which compares the build of the client with the build of the server.
+///
+/// Only requests carrying the build header are checked, i.e. server function calls of the app.
+/// On mismatch the server responds with [`AppError::UpgradeRequired`], which the client
+/// decodes like any other error of a server function.
+pub async fn require_app_build(req: Request, next: Next) -> Response {
+    let Some(client_build) = req
+        .headers()
+        .get(APP_BUILD_HEADER)
+        .map(|value| value.to_str().unwrap_or_default().to_string())
+    else {
+        return next.run(req).await;
+    };
+    if is_compatible_build(&client_build) {
+        return next.run(req).await;
+    }
+    warn!(
+        client_build,
+        server_build = APP_BUILD,
+        uri = %req.uri(),
+        "refusing server function call of outdated client"
+    );
+    let error = AppError::UpgradeRequired {
+        client: client_build,
+        server: APP_BUILD.to_string(),
+    };
+    match serde_json::to_string(&error) {
+        Ok(body) => (
+            StatusCode::CONFLICT,
+            [(header::CONTENT_TYPE, "application/json")],
+            body,
+        )
+            .into_response(),
+        Err(err) => {
+            error!(error = %err, "failed to serialize upgrade required error");
+            StatusCode::CONFLICT.into_response()
+        }
+    }
+}
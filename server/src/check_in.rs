@@ -0,0 +1,25 @@
+//! background resolution of no-shows after the check-in deadline
+
+use app_core::CoreState;
+use std::time::Duration;
+use tracing::{error, info};
+
+/// interval between two checks for passed check-in deadlines
+const CHECK_IN_INTERVAL: Duration = Duration::from_secs(60);
+
+/// Periodically replace or remove entrants, who are not checked in by the deadline of their
+/// tournament. Errors are logged and the check is repeated in the next interval.
+pub fn spawn_check_in_resolver(core: CoreState) {
+    tokio::spawn(async move {
+        let mut interval = tokio::time::interval(CHECK_IN_INTERVAL);
+        interval.set_missed_tick_behavior(tokio::time::MissedTickBehavior::Delay);
+        loop {
+            interval.tick().await;
+            match core.resolve_due_check_ins().await {
+                Ok(0) => {}
+                Ok(num_changed) => info!(num_changed, "resolved_no_shows"),
+                Err(err) => error!(error = %err, "resolve_no_shows_failed"),
+            }
+        }
+    });
+}
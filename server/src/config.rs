@@ -0,0 +1,358 @@
+//! typed configuration of the server
+//!
+//! All settings are read once at startup from environment variables (`.env` is loaded by
+//! dotenvy before) and validated together, so that a misconfigured server reports every
+//! problem at once instead of failing at the first one. See `.env` for all variables.
+
+use anyhow::{Result, bail};
+use app::sport_plugins::SportPluginKind;
+use app_core::CacheConfig;
+use db_postgres::DEFAULT_STATEMENT_TIMEOUT_MS;
+use db_sqlite::DEFAULT_SQLITE_PATH;
+use leptos::config::LeptosOptions;
+use std::{env, fmt::Display, net::SocketAddr, str::FromStr, time::Duration};
+use tracing_subscriber::EnvFilter;
+use url::Url;
+
+/// default filter of tracing, if `RUST_LOG` is not set
+pub const DEFAULT_LOG_FILTER: &str = "info,axum=info";
+
+/// configuration of the server
+#[derive(Debug, Clone)]
+pub struct AppConfig {
+    /// options of leptos; `LEPTOS_SITE_ADDR` etc. are handled by leptos
+    pub leptos_options: LeptosOptions,
+    pub database: DatabaseConfig,
+    pub tracing: TracingConfig,
+    pub client_registry: ClientRegistryBackend,
+    pub plugins: PluginConfig,
+    pub features: FeatureToggles,
+}
+
+/// configuration of the database
+#[derive(Debug, Clone)]
+pub struct DatabaseConfig {
+    pub backend: DatabaseBackend,
+    /// cache hot lookups in memory; with multiple server instances changes of other
+    /// instances are only visible after the cache entries expire
+    pub cache: Option<CacheConfig>,
+}
+
+/// database backend
+#[derive(Debug, Clone)]
+pub enum DatabaseBackend {
+    Postgres {
+        /// url of database, i.e. `POSTGRES_URL` joined with `DATABASE_NAME`
+        url: Url,
+        /// optional url of read replica for read-only queries
+        read_replica: Option<Url>,
+        /// apply pending migrations on startup; disable for rolling deployments, where
+        /// migrations are applied by a separate `--migrate-only` run
+        migrate_on_startup: bool,
+        /// statement timeout of interactive queries; `None` disables the timeout
+        statement_timeout: Option<Duration>,
+    },
+    /// local sqlite file, e.g. for offline use at the venue
+    Sqlite { path: String },
+}
+
+/// configuration of tracing
+#[derive(Debug, Clone)]
+pub struct TracingConfig {
+    /// filter directives of `EnvFilter`, e.g. `info,app_core=debug`
+    pub filter: String,
+}
+
+/// backend of the client registry
+#[derive(Debug, Clone)]
+pub enum ClientRegistryBackend {
+    /// single server instance
+    Local,
+    /// multiple server instances: distribute client registry messages via redis
+    Redis(Url),
+}
+
+/// configuration of sport plugins
+#[derive(Debug, Clone)]
+pub struct PluginConfig {
+    /// registered sport plugins in order of registration
+    pub sports: Vec<SportPluginKind>,
+}
+
+/// optional features of the server
+#[derive(Debug, Clone)]
+pub struct FeatureToggles {
+    /// replace or remove entrants, who are not checked in by the deadline
+    pub check_in_resolver: bool,
+    /// serve GraphQL endpoint (default: disabled); requires server built with feature
+    /// `graphql`
+    pub graphql: bool,
+}
+
+impl AppConfig {
+    /// Read configuration from environment.
+    pub fn from_env(leptos_options: LeptosOptions) -> Result<Self> {
+        Self::from_lookup(leptos_options, |key| env::var(key).ok())
+    }
+
+    /// Read configuration with `lookup` of variables. All invalid or missing variables are
+    /// reported in one error.
+    pub fn from_lookup(
+        leptos_options: LeptosOptions,
+        lookup: impl Fn(&str) -> Option<String>,
+    ) -> Result<Self> {
+        let mut vars = Vars {
+            lookup: &lookup,
+            errors: Vec::new(),
+        };
+
+        let backend = match vars.get("DATABASE_BACKEND").as_deref() {
+            None | Some("postgres") => {
+                let base = vars.require("POSTGRES_URL");
+                let name = vars.require("DATABASE_NAME");
+                let url = match (base, name) {
+                    (Some(base), Some(name)) => vars.check("POSTGRES_URL", url_of_db(&base, &name)),
+                    _ => None,
+                };
+                DatabaseBackend::Postgres {
+                    url: url.unwrap_or_else(placeholder_url),
+                    read_replica: vars.parse::<Url>("DATABASE_READ_REPLICA_URL", "a url"),
+                    migrate_on_startup: vars
+                        .parse("DATABASE_MIGRATE_ON_STARTUP", "true or false")
+                        .unwrap_or(true),
+                    statement_timeout: {
+                        let ms = vars
+                            .parse::<u64>(
+                                "DATABASE_STATEMENT_TIMEOUT_MS",
+                                "a number of milliseconds",
+                            )
+                            .unwrap_or(DEFAULT_STATEMENT_TIMEOUT_MS);
+                        (ms > 0).then(|| Duration::from_millis(ms))
+                    },
+                }
+            }
+            Some("sqlite") => DatabaseBackend::Sqlite {
+                path: vars
+                    .get("SQLITE_PATH")
+                    .unwrap_or_else(|| DEFAULT_SQLITE_PATH.to_string()),
+            },
+            Some(_) => {
+                vars.error("DATABASE_BACKEND", "postgres or sqlite");
+                DatabaseBackend::Sqlite {
+                    path: DEFAULT_SQLITE_PATH.to_string(),
+                }
+            }
+        };
+        let database = DatabaseConfig {
+            backend,
+            cache: vars
+                .parse::<bool>("DATABASE_CACHE", "true or false")
+                .unwrap_or(false)
+                .then(CacheConfig::default),
+        };
+
+        let filter = vars
+            .get("RUST_LOG")
+            .unwrap_or_else(|| DEFAULT_LOG_FILTER.to_string());
+        vars.check("RUST_LOG", EnvFilter::try_new(&filter));
+        let tracing = TracingConfig { filter };
+
+        let client_registry = match vars.parse::<Url>("REDIS_URL", "a redis url") {
+            Some(url) => ClientRegistryBackend::Redis(url),
+            None => ClientRegistryBackend::Local,
+        };
+
+        let sports = match vars.get("SPORT_PLUGINS") {
+            Some(list) => {
+                let mut sports = Vec::new();
+                for kind in list.split(',').filter(|s| !s.trim().is_empty()) {
+                    match kind.parse::<SportPluginKind>() {
+                        Ok(kind) if !sports.contains(&kind) => sports.push(kind),
+                        Ok(_) => {}
+                        Err(e) => vars.errors.push(format!("SPORT_PLUGINS: {e}")),
+                    }
+                }
+                if sports.is_empty() {
+                    vars.error("SPORT_PLUGINS", "at least one sport plugin");
+                }
+                sports
+            }
+            None => SportPluginKind::ALL.to_vec(),
+        };
+
+        let features = FeatureToggles {
+            check_in_resolver: vars
+                .parse("FEATURE_CHECK_IN_RESOLVER", "true or false")
+                .unwrap_or(true),
+            graphql: vars
+                .parse("FEATURE_GRAPHQL", "true or false")
+                .unwrap_or(false),
+        };
+
+        if !vars.errors.is_empty() {
+            bail!(
+                "invalid configuration (see .env for all variables):\n  - {}",
+                vars.errors.join("\n  - ")
+            );
+        }
+        Ok(AppConfig {
+            leptos_options,
+            database,
+            tracing,
+            client_registry,
+            plugins: PluginConfig { sports },
+            features,
+        })
+    }
+
+    /// address of http server
+    pub fn site_addr(&self) -> SocketAddr {
+        self.leptos_options.site_addr
+    }
+}
+
+/// lookup of variables, which collects errors
+struct Vars<'a, L: Fn(&str) -> Option<String>> {
+    lookup: &'a L,
+    errors: Vec<String>,
+}
+
+impl<L: Fn(&str) -> Option<String>> Vars<'_, L> {
+    /// optional variable; empty values count as not set
+    fn get(&self, key: &str) -> Option<String> {
+        (self.lookup)(key).filter(|value| !value.trim().is_empty())
+    }
+
+    fn require(&mut self, key: &str) -> Option<String> {
+        let value = self.get(key);
+        if value.is_none() {
+            self.errors.push(format!("{key} must be set"));
+        }
+        value
+    }
+
+    /// optional variable parsed as `T`; `expected` describes valid values
+    fn parse<T: FromStr>(&mut self, key: &str, expected: &str) -> Option<T> {
+        let value = self.get(key)?;
+        match value.parse::<T>() {
+            Ok(parsed) => Some(parsed),
+            Err(_) => {
+                self.errors
+                    .push(format!("{key} must be {expected}, got `{value}`"));
+                None
+            }
+        }
+    }
+
+    fn check<T, E: Display>(&mut self, key: &str, result: Result<T, E>) -> Option<T> {
+        result
+            .map_err(|e| self.errors.push(format!("{key} is invalid: {e}")))
+            .ok()
+    }
+
+    fn error(&mut self, key: &str, expected: &str) {
+        let value = self.get(key).unwrap_or_default();
+        self.errors
+            .push(format!("{key} must be {expected}, got `{value}`"));
+    }
+}
+
+/// url of database `name` at postgres server `base`
+fn url_of_db(base: &str, name: &str) -> Result<Url> {
+    Ok(Url::parse(base)?.join(name)?)
+}
+
+/// url of configurations with errors, which are never used
+fn placeholder_url() -> Url {
+    Url::parse("postgres://localhost/").expect("valid url")
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::collections::HashMap;
+
+    fn config(vars: &[(&str, &str)]) -> Result<AppConfig> {
+        let vars: HashMap<String, String> = vars
+            .iter()
+            .map(|(k, v)| (k.to_string(), v.to_string()))
+            .collect();
+        AppConfig::from_lookup(LeptosOptions::default(), |key| vars.get(key).cloned())
+    }
+
+    #[test]
+    fn given_minimal_postgres_env_when_load_then_defaults_apply() {
+        let config = config(&[
+            ("POSTGRES_URL", "postgres://user:pw@db:5432/"),
+            ("DATABASE_NAME", "planer"),
+        ])
+        .unwrap();
+
+        let DatabaseBackend::Postgres {
+            url,
+            read_replica,
+            migrate_on_startup,
+            statement_timeout,
+        } = &config.database.backend
+        else {
+            panic!("expected postgres backend");
+        };
+        assert_eq!(url.as_str(), "postgres://user:pw@db:5432/planer");
+        assert!(read_replica.is_none());
+        assert!(migrate_on_startup);
+        assert_eq!(
+            *statement_timeout,
+            Some(Duration::from_millis(DEFAULT_STATEMENT_TIMEOUT_MS))
+        );
+        assert!(config.database.cache.is_none());
+        assert_eq!(config.tracing.filter, DEFAULT_LOG_FILTER);
+        assert!(matches!(
+            config.client_registry,
+            ClientRegistryBackend::Local
+        ));
+        assert_eq!(config.plugins.sports, SportPluginKind::ALL.to_vec());
+        assert!(config.features.check_in_resolver);
+        assert!(!config.features.graphql);
+    }
+
+    #[test]
+    fn given_invalid_env_when_load_then_all_errors_are_reported() {
+        let err = config(&[
+            ("DATABASE_MIGRATE_ON_STARTUP", "maybe"),
+            ("REDIS_URL", "not a url"),
+            ("SPORT_PLUGINS", "ddc,chess"),
+        ])
+        .unwrap_err()
+        .to_string();
+
+        for expected in [
+            "POSTGRES_URL must be set",
+            "DATABASE_NAME must be set",
+            "DATABASE_MIGRATE_ON_STARTUP must be true or false, got `maybe`",
+            "REDIS_URL must be a redis url",
+            "unknown sport plugin `chess`",
+        ] {
+            assert!(err.contains(expected), "missing `{expected}` in: {err}");
+        }
+    }
+
+    #[test]
+    fn given_sqlite_backend_when_load_then_postgres_is_not_required() {
+        let config = config(&[
+            ("DATABASE_BACKEND", "sqlite"),
+            ("SPORT_PLUGINS", "generic, ultimate"),
+            ("FEATURE_CHECK_IN_RESOLVER", "false"),
+        ])
+        .unwrap();
+
+        assert!(matches!(
+            &config.database.backend,
+            DatabaseBackend::Sqlite { path } if path == DEFAULT_SQLITE_PATH
+        ));
+        assert_eq!(
+            config.plugins.sports,
+            vec![SportPluginKind::Generic, SportPluginKind::Ultimate]
+        );
+        assert!(!config.features.check_in_resolver);
+    }
+}
@@ -0,0 +1,120 @@
+//! stream of domain events for integrations as server-sent events
+
+use crate::api_auth::ApiAuth;
+use app_core::{ApiScope, CoreState, DomainEventEnvelope, DomainEventPort, DomainEventResult};
+use async_trait::async_trait;
+use axum::{
+    extract::Query,
+    response::{
+        IntoResponse,
+        sse::{Event, KeepAlive, Sse},
+    },
+};
+use futures_util::StreamExt;
+use serde::Deserialize;
+use tokio::sync::broadcast;
+use tokio_stream::wrappers::{BroadcastStream, errors::BroadcastStreamRecvError};
+use tracing::{instrument, warn};
+use uuid::Uuid;
+
+/// number of events buffered per subscriber; slower subscribers skip events
+const EVENT_BUFFER: usize = 256;
+
+/// Broadcasts domain events to all subscribed event streams.
+///
+/// Events are kept in memory, therefore subscribers only receive events of this server
+/// instance.
+#[derive(Clone)]
+pub struct BroadcastDomainEvents {
+    sender: broadcast::Sender<DomainEventEnvelope>,
+}
+
+impl BroadcastDomainEvents {
+    pub fn new() -> Self {
+        let (sender, _) = broadcast::channel(EVENT_BUFFER);
+        BroadcastDomainEvents { sender }
+    }
+
+    pub fn subscribe(&self) -> broadcast::Receiver<DomainEventEnvelope> {
+        self.sender.subscribe()
+    }
+}
+
+impl Default for BroadcastDomainEvents {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+#[async_trait]
+impl DomainEventPort for BroadcastDomainEvents {
+    async fn emit(&self, event: &DomainEventEnvelope) -> DomainEventResult<()> {
+        // sending only fails without subscribers
+        let _ = self.sender.send(event.clone());
+        Ok(())
+    }
+}
+
+#[derive(Deserialize)]
+pub struct EventStreamQuery {
+    /// only stream events of this tournament
+    tournament_id: Option<Uuid>,
+}
+
+/// Stream domain events as server-sent events. The SSE event name is the type of the domain
+/// event, the SSE id is the event id and the data is the JSON of [`DomainEventEnvelope`].
+///
+/// Events of tournaments, which are not public (drafts and sandboxes), are only streamed to
+/// tokens with scope [`ApiScope::ReadOrg`].
+#[instrument(
+    name = "domain_event_stream",
+    skip(events, core, api_auth, query),
+    fields(tournament_id = ?query.tournament_id)
+)]
+pub async fn domain_event_stream(
+    events: BroadcastDomainEvents,
+    core: CoreState,
+    api_auth: ApiAuth,
+    Query(query): Query<EventStreamQuery>,
+) -> impl IntoResponse {
+    let read_org = api_auth
+        .0
+        .is_some_and(|token| token.has_scope(ApiScope::ReadOrg));
+    let only_tournament_id = query.tournament_id;
+    let stream = BroadcastStream::new(events.subscribe()).filter_map(move |received| {
+        let core = core.clone();
+        async move {
+            let envelope = match received {
+                Ok(envelope) => envelope,
+                Err(BroadcastStreamRecvError::Lagged(skipped)) => {
+                    warn!(skipped, "domain_event_stream_lagged");
+                    return None;
+                }
+            };
+            let tournament_id = envelope.event.tournament_id();
+            if only_tournament_id.is_some_and(|id| id != tournament_id) {
+                return None;
+            }
+            if !read_org && !is_public_tournament(&core, tournament_id).await {
+                return None;
+            }
+            Some(
+                Event::default()
+                    .event(envelope.event.event_type())
+                    .id(envelope.event_id.to_string())
+                    .json_data(&envelope),
+            )
+        }
+    });
+    Sse::new(stream).keep_alive(KeepAlive::default())
+}
+
+async fn is_public_tournament(core: &CoreState, tournament_id: Uuid) -> bool {
+    match core.load_public_tournament(tournament_id).await {
+        Ok(view) => view.is_some(),
+        Err(err) => {
+            warn!(%tournament_id, error = %err, "domain_event_stream_tournament_unavailable");
+            false
+        }
+    }
+}
@@ -0,0 +1,616 @@
+//! GraphQL endpoint for flexible queries of third-party clients
+//!
+//! The schema exposes the object graph of public tournaments, i.e. tournament → stages →
+//! groups → matches → entrants, so that e.g. a bracket view is fetched with a single
+//! request instead of polling several REST routes. Like the REST API it serves only public
+//! tournaments. Mutations require an api token with scope `write-scores`.
+//!
+//! The endpoint requires the server built with feature `graphql` and `FEATURE_GRAPHQL=true`.
+//! It is served at `/api/graphql`; a GET request opens GraphiQL.
+
+use crate::api_auth::{ApiAuth, ApiAuthState, ApiRateLimiter, require_api_scope};
+use app_core::{
+    ApiScope, ApiToken, Core, CoreState, Entrant, LiveScore, Match, PublicGroup,
+    PublicGroupStandings, PublicStage, PublicTournamentView, TournamentBase, TournamentBaseState,
+    slots::KoSide, stage_group_ids,
+};
+use async_graphql::{
+    ComplexObject, Context, EmptySubscription, Error, Object, Result, Schema, SimpleObject,
+    http::GraphiQLSource,
+};
+use async_graphql_axum::{GraphQLRequest, GraphQLResponse};
+use axum::{
+    Router,
+    extract::State,
+    middleware::from_fn_with_state,
+    response::{Html, IntoResponse},
+    routing::get,
+};
+use chrono::{DateTime, Utc};
+use shared::AppState;
+use std::{collections::HashMap, sync::Arc};
+use tokio::sync::OnceCell;
+use tracing::{info, instrument, warn};
+use uuid::Uuid;
+
+/// maximum depth of queries; the object graph is at most 6 levels deep
+const MAX_QUERY_DEPTH: usize = 8;
+/// maximum complexity of queries
+const MAX_QUERY_COMPLEXITY: usize = 1_000;
+/// maximum number of tournaments per query
+const MAX_PAGE_SIZE: usize = 100;
+
+pub type ApiSchema = Schema<QueryRoot, MutationRoot, EmptySubscription>;
+
+/// Route of the GraphQL endpoint and GraphiQL.
+pub fn graphql_routes(core: CoreState, limiter: ApiRateLimiter) -> Router<AppState> {
+    let schema = Schema::build(QueryRoot, MutationRoot, EmptySubscription)
+        .limit_depth(MAX_QUERY_DEPTH)
+        .limit_complexity(MAX_QUERY_COMPLEXITY)
+        .finish();
+    Router::new()
+        .route(
+            "/api/graphql",
+            get(graphiql).post(
+                move |state: State<AppState>, api_auth: ApiAuth, req: GraphQLRequest| {
+                    graphql(state, api_auth, req, schema.clone())
+                },
+            ),
+        )
+        .route_layer(from_fn_with_state(
+            ApiAuthState::new(core, limiter, ApiScope::ReadPublic),
+            require_api_scope,
+        ))
+}
+
+async fn graphiql() -> impl IntoResponse {
+    Html(GraphiQLSource::build().endpoint("/api/graphql").finish())
+}
+
+#[instrument(name = "graphql.execute", skip_all)]
+async fn graphql(
+    State(app_state): State<AppState>,
+    api_auth: ApiAuth,
+    req: GraphQLRequest,
+    schema: ApiSchema,
+) -> GraphQLResponse {
+    let req = req.into_inner().data(app_state.core).data(api_auth.0);
+    schema.execute(req).await.into()
+}
+
+/// core of the server; queries need no actor, since they do not change anything
+fn core_of<'a>(ctx: &Context<'a>) -> Result<&'a CoreState> {
+    ctx.data::<CoreState>()
+}
+
+/// Core for changes, which require an api token with `scope`; changes of api clients are
+/// recorded with the name of their token.
+fn authorized_core(ctx: &Context<'_>, scope: ApiScope) -> Result<Core<TournamentBaseState>> {
+    match ctx.data::<Option<ApiToken>>()? {
+        Some(token) if token.has_scope(scope) => Ok(core_of(ctx)?
+            .as_tournament_base_state()
+            .with_actor(format!("api-token:{}", token.get_name()))),
+        Some(token) => {
+            warn!(token_id = %token.get_id(), %scope, "graphql_scope_missing");
+            Err(Error::new(format!("api token lacks scope {scope}")))
+        }
+        None => Err(Error::new(format!("api token with scope {scope} required"))),
+    }
+}
+
+// --- queries ---
+
+pub struct QueryRoot;
+
+#[Object]
+impl QueryRoot {
+    /// Public tournament with `id`; null, if it does not exist or is not public.
+    async fn tournament(&self, ctx: &Context<'_>, id: Uuid) -> Result<Option<Tournament>> {
+        let core = core_of(ctx)?;
+        let view = core.load_public_tournament(id).await?;
+        Ok(view.map(Tournament::from_view))
+    }
+
+    /// Public tournaments, newest first; at most 100 per query.
+    async fn tournaments(
+        &self,
+        ctx: &Context<'_>,
+        #[graphql(default = 100)] limit: usize,
+        #[graphql(default = 0)] offset: usize,
+    ) -> Result<Vec<Tournament>> {
+        let core = core_of(ctx)?;
+        let tournaments = core
+            .list_public_tournaments(Some(limit.min(MAX_PAGE_SIZE)), offset)
+            .await?;
+        Ok(tournaments.into_iter().map(Tournament::new).collect())
+    }
+}
+
+/// public tournament; stages, entrants and matches are loaded on demand
+pub struct Tournament {
+    base: TournamentBase,
+    view: OnceCell<Option<Arc<PublicTournamentView>>>,
+    matches: OnceCell<Arc<Vec<Match>>>,
+}
+
+impl Tournament {
+    fn new(base: TournamentBase) -> Self {
+        Tournament {
+            base,
+            view: OnceCell::new(),
+            matches: OnceCell::new(),
+        }
+    }
+
+    fn from_view(view: PublicTournamentView) -> Self {
+        Tournament {
+            base: view.tournament.clone(),
+            view: OnceCell::new_with(Some(Some(Arc::new(view)))),
+            matches: OnceCell::new(),
+        }
+    }
+
+    async fn load_view(&self, ctx: &Context<'_>) -> Result<Option<Arc<PublicTournamentView>>> {
+        let view = self
+            .view
+            .get_or_try_init(|| async {
+                let core = core_of(ctx)?;
+                let view = core.load_public_tournament(self.base.get_id()).await?;
+                Ok::<_, Error>(view.map(Arc::new))
+            })
+            .await?;
+        Ok(view.clone())
+    }
+
+    async fn load_matches(&self, ctx: &Context<'_>) -> Result<Arc<Vec<Match>>> {
+        let matches = self
+            .matches
+            .get_or_try_init(|| async {
+                let core = core_of(ctx)?;
+                let matches = core
+                    .load_public_matches(self.base.get_id())
+                    .await?
+                    .unwrap_or_default();
+                Ok::<_, Error>(Arc::new(matches))
+            })
+            .await?;
+        Ok(matches.clone())
+    }
+
+    async fn load_entrants(&self, ctx: &Context<'_>) -> Result<Arc<HashMap<Uuid, Entrant>>> {
+        let entrants = self
+            .load_view(ctx)
+            .await?
+            .map(|view| {
+                view.entrants
+                    .iter()
+                    .map(|e| (e.get_id(), e.clone()))
+                    .collect()
+            })
+            .unwrap_or_default();
+        Ok(Arc::new(entrants))
+    }
+}
+
+#[Object]
+impl Tournament {
+    async fn id(&self) -> Uuid {
+        self.base.get_id()
+    }
+
+    async fn name(&self) -> &str {
+        self.base.get_name()
+    }
+
+    async fn sport_id(&self) -> Uuid {
+        self.base.get_sport_id()
+    }
+
+    /// e.g. `Pool and Final Stage`
+    async fn mode(&self) -> String {
+        self.base.get_tournament_mode().to_string()
+    }
+
+    /// e.g. `Published`, `Running (Stage 1)` or `Finished`
+    async fn state(&self) -> String {
+        self.base.get_tournament_state().to_string()
+    }
+
+    async fn num_entrants(&self) -> u32 {
+        self.base.get_num_entrants()
+    }
+
+    async fn num_stations(&self) -> u32 {
+        self.base.get_num_stations()
+    }
+
+    async fn created_at(&self) -> Option<DateTime<Utc>> {
+        self.base.get_created_at()
+    }
+
+    /// entrants sorted by seed and name
+    async fn entrants(&self, ctx: &Context<'_>) -> Result<Vec<EntrantNode>> {
+        let view = self.load_view(ctx).await?;
+        Ok(view
+            .map(|view| view.entrants.iter().map(EntrantNode::from).collect())
+            .unwrap_or_default())
+    }
+
+    /// stages sorted by number
+    async fn stages(&self, ctx: &Context<'_>) -> Result<Vec<StageNode>> {
+        let Some(view) = self.load_view(ctx).await? else {
+            return Ok(Vec::new());
+        };
+        let matches = self.load_matches(ctx).await?;
+        let entrants = self.load_entrants(ctx).await?;
+        Ok(view
+            .stages
+            .iter()
+            .map(|stage| StageNode {
+                stage: stage.clone(),
+                matches: matches.clone(),
+                entrants: entrants.clone(),
+            })
+            .collect())
+    }
+
+    /// all matches of the tournament
+    async fn matches(&self, ctx: &Context<'_>) -> Result<Vec<MatchNode>> {
+        let matches = self.load_matches(ctx).await?;
+        let entrants = self.load_entrants(ctx).await?;
+        Ok(matches
+            .iter()
+            .map(|m| MatchNode::new(m.clone(), entrants.clone()))
+            .collect())
+    }
+
+    /// current standings of all groups in order of stage and group
+    async fn standings(&self, ctx: &Context<'_>) -> Result<Vec<GroupStandingsNode>> {
+        let core = core_of(ctx)?;
+        let standings = core
+            .load_public_standings(self.base.get_id())
+            .await?
+            .unwrap_or_default();
+        let entrants = self.load_entrants(ctx).await?;
+        Ok(standings
+            .into_iter()
+            .map(|standings| GroupStandingsNode {
+                standings,
+                entrants: entrants.clone(),
+            })
+            .collect())
+    }
+}
+
+/// entrant of a public tournament; email addresses are never returned
+#[derive(SimpleObject)]
+pub struct EntrantNode {
+    id: Uuid,
+    name: String,
+    club: Option<String>,
+    seed: Option<u32>,
+}
+
+impl From<&Entrant> for EntrantNode {
+    fn from(e: &Entrant) -> Self {
+        EntrantNode {
+            id: e.get_id(),
+            name: e.get_name().to_string(),
+            club: e.get_club().map(str::to_string),
+            seed: e.get_seed(),
+        }
+    }
+}
+
+/// stage of a public tournament
+pub struct StageNode {
+    stage: PublicStage,
+    matches: Arc<Vec<Match>>,
+    entrants: Arc<HashMap<Uuid, Entrant>>,
+}
+
+#[Object]
+impl StageNode {
+    async fn id(&self) -> Uuid {
+        self.stage.id
+    }
+
+    /// number of stage, starting with 0
+    async fn number(&self) -> u32 {
+        self.stage.number
+    }
+
+    /// e.g. `Final Stage`
+    async fn name(&self) -> &str {
+        &self.stage.name
+    }
+
+    async fn groups(&self) -> Vec<GroupNode> {
+        let stage_matches = self.stage_matches();
+        let group_ids = stage_group_ids(&stage_matches);
+        self.stage
+            .groups
+            .iter()
+            .enumerate()
+            .map(|(index, group)| GroupNode {
+                group: group.clone(),
+                // groups without an id have no scheduled match yet
+                matches: group_ids
+                    .get(index)
+                    .map(|group_id| {
+                        stage_matches
+                            .iter()
+                            .filter(|m| m.get_group_id() == group_id)
+                            .cloned()
+                            .collect()
+                    })
+                    .unwrap_or_default(),
+                entrants: self.entrants.clone(),
+            })
+            .collect()
+    }
+
+    /// matches of the stage
+    async fn matches(&self) -> Vec<MatchNode> {
+        self.stage_matches()
+            .into_iter()
+            .map(|m| MatchNode::new(m, self.entrants.clone()))
+            .collect()
+    }
+}
+
+impl StageNode {
+    fn stage_matches(&self) -> Vec<Match> {
+        self.matches
+            .iter()
+            .filter(|m| *m.get_stage_id() == self.stage.id)
+            .cloned()
+            .collect()
+    }
+}
+
+/// group of a public stage
+pub struct GroupNode {
+    group: PublicGroup,
+    matches: Vec<Match>,
+    entrants: Arc<HashMap<Uuid, Entrant>>,
+}
+
+#[Object]
+impl GroupNode {
+    /// e.g. `B`; slots of group are `B1`, `B2`, ...
+    async fn label(&self) -> &str {
+        &self.group.label
+    }
+
+    async fn num_entrants(&self) -> u32 {
+        self.group.num_entrants
+    }
+
+    /// KO bracket, if the group plays out the final stage in KO mode; empty otherwise
+    async fn bracket(&self) -> Vec<KoRoundNode> {
+        let side = |side: KoSide| match side {
+            KoSide::Slot(slot) => format!("{}{slot}", self.group.label),
+            KoSide::WinnerOf(number) => format!("Winner M{number}"),
+            KoSide::LoserOf(number) => format!("Loser M{number}"),
+        };
+        self.group
+            .bracket
+            .iter()
+            .map(|round| KoRoundNode {
+                name: round.name.clone(),
+                matches: round
+                    .matches
+                    .iter()
+                    .map(|m| KoMatchNode {
+                        number: m.number,
+                        side_a: side(m.side_a),
+                        side_b: side(m.side_b),
+                    })
+                    .collect(),
+            })
+            .collect()
+    }
+
+    /// matches of the group
+    async fn matches(&self) -> Vec<MatchNode> {
+        self.matches
+            .iter()
+            .map(|m| MatchNode::new(m.clone(), self.entrants.clone()))
+            .collect()
+    }
+}
+
+/// round of a KO bracket
+#[derive(SimpleObject)]
+pub struct KoRoundNode {
+    /// e.g. `Semi Finals`
+    name: String,
+    matches: Vec<KoMatchNode>,
+}
+
+/// match of a KO bracket; sides are slots of the group (e.g. `A1`) or winners of previous
+/// matches (e.g. `Winner M1`)
+#[derive(SimpleObject)]
+pub struct KoMatchNode {
+    number: u32,
+    side_a: String,
+    side_b: String,
+}
+
+/// match of a public tournament
+#[derive(SimpleObject)]
+#[graphql(complex)]
+pub struct MatchNode {
+    id: Uuid,
+    stage_id: Uuid,
+    group_id: Uuid,
+    number: u32,
+    station: u32,
+    start_at: DateTime<Utc>,
+    in_progress: bool,
+    decided: bool,
+    /// e.g. `Played`, `Forfeit by A` or `Bye`
+    outcome: String,
+    /// scores of each set of side a
+    scores_a: Vec<u16>,
+    /// scores of each set of side b
+    scores_b: Vec<u16>,
+    #[graphql(skip)]
+    entrant_ids: Option<(Uuid, Uuid)>,
+    #[graphql(skip)]
+    entrants: Arc<HashMap<Uuid, Entrant>>,
+}
+
+impl MatchNode {
+    fn new(m: Match, entrants: Arc<HashMap<Uuid, Entrant>>) -> Self {
+        let (scores_a, scores_b) = m.get_scores();
+        MatchNode {
+            id: *m.get_id(),
+            stage_id: *m.get_stage_id(),
+            group_id: *m.get_group_id(),
+            number: m.get_number(),
+            station: m.get_station() as u32,
+            start_at: m.get_start_at().with_timezone(&Utc),
+            in_progress: m.is_in_progress(),
+            decided: m.is_decided(),
+            outcome: m.get_outcome().to_string(),
+            scores_a: scores_a.clone(),
+            scores_b: scores_b.clone(),
+            entrant_ids: m.get_entrants().map(|(a, b)| (*a, *b)),
+            entrants,
+        }
+    }
+}
+
+#[ComplexObject]
+impl MatchNode {
+    /// entrant of side a; null, if the entrants are not decided yet
+    async fn entrant_a(&self) -> Option<EntrantNode> {
+        self.entrant_ids
+            .and_then(|(a, _)| self.entrants.get(&a))
+            .map(EntrantNode::from)
+    }
+
+    /// entrant of side b; null, if the entrants are not decided yet
+    async fn entrant_b(&self) -> Option<EntrantNode> {
+        self.entrant_ids
+            .and_then(|(_, b)| self.entrants.get(&b))
+            .map(EntrantNode::from)
+    }
+}
+
+/// standings of a group
+pub struct GroupStandingsNode {
+    standings: PublicGroupStandings,
+    entrants: Arc<HashMap<Uuid, Entrant>>,
+}
+
+#[Object]
+impl GroupStandingsNode {
+    async fn stage_id(&self) -> Uuid {
+        self.standings.stage_id
+    }
+
+    async fn stage_number(&self) -> u32 {
+        self.standings.stage_number
+    }
+
+    async fn group_label(&self) -> &str {
+        &self.standings.group_label
+    }
+
+    /// standings sorted by rank; empty, if no match of the group is decided yet
+    async fn standings(&self) -> Vec<StandingNode> {
+        self.standings
+            .standings
+            .iter()
+            .map(|s| StandingNode {
+                rank: s.rank,
+                entrant: self
+                    .entrants
+                    .get(&s.get_entrant_id())
+                    .map(EntrantNode::from),
+                victory_points: s.score.victory_points,
+                relative_score: s.score.relative_score,
+                total_score: s.score.total_score,
+                wins: s.score.wins,
+                draws: s.score.draws,
+                losses: s.score.losses,
+            })
+            .collect()
+    }
+}
+
+/// rank and score of an entrant in its group
+#[derive(SimpleObject)]
+pub struct StandingNode {
+    /// rank in group starting at 1; entrants with unbroken ties share a rank
+    rank: u32,
+    entrant: Option<EntrantNode>,
+    victory_points: f32,
+    relative_score: i16,
+    total_score: u16,
+    wins: u16,
+    draws: u16,
+    losses: u16,
+}
+
+// --- mutations ---
+
+pub struct MutationRoot;
+
+#[Object]
+impl MutationRoot {
+    /// Enter the final result of the match with `match_id`; null, if the match does not
+    /// exist. Requires scope `write-scores`.
+    async fn enter_result(
+        &self,
+        ctx: &Context<'_>,
+        match_id: Uuid,
+        scores_a: Vec<u16>,
+        scores_b: Vec<u16>,
+    ) -> Result<Option<MatchNode>> {
+        let core = authorized_core(ctx, ApiScope::WriteScores)?;
+        let entered = core
+            .enter_match_result(match_id, scores_a, scores_b)
+            .await?;
+        info!(%match_id, entered = entered.is_some(), "graphql_enter_result");
+        Ok(entered.map(|m| MatchNode::new(m, Arc::default())))
+    }
+
+    /// Update the running score of the match with `match_id`; null, if the match does not
+    /// exist. Requires scope `write-scores`.
+    async fn update_live_score(
+        &self,
+        ctx: &Context<'_>,
+        match_id: Uuid,
+        scores_a: Vec<u16>,
+        scores_b: Vec<u16>,
+    ) -> Result<Option<LiveScoreNode>> {
+        let core = authorized_core(ctx, ApiScope::WriteScores)?;
+        let live_score = core.update_live_score(match_id, scores_a, scores_b).await?;
+        Ok(live_score.map(LiveScoreNode::from))
+    }
+}
+
+/// running score of a match in progress
+#[derive(SimpleObject)]
+pub struct LiveScoreNode {
+    scores_a: Vec<u16>,
+    scores_b: Vec<u16>,
+    /// number of updates of the live score
+    tick: u32,
+    updated_at: DateTime<Utc>,
+}
+
+impl From<LiveScore> for LiveScoreNode {
+    fn from(live_score: LiveScore) -> Self {
+        LiveScoreNode {
+            scores_a: live_score.score_a,
+            scores_b: live_score.score_b,
+            tick: live_score.tick,
+            updated_at: live_score.updated_at,
+        }
+    }
+}
@@ -1,27 +1,50 @@
 #![recursion_limit = "512"]
 
-use anyhow::{Context, Result};
-use app::*;
-use app_core::*;
+mod api_auth;
+mod api_v1;
+mod app_build;
+mod check_in;
+mod config;
+mod domain_events;
+#[cfg(feature = "graphql")]
+mod graphql;
+mod standings;
+mod webhook_queue;
+
+use anyhow::{Context, Result, bail};
+use api_auth::{ApiAuth, ApiRateLimiter, with_api_scope};
+use api_v1::api_v1_routes;
+use app::{sport_plugins::register_sport_plugins, *};
+use app_build::require_app_build;
+use app_core::{utils::traits::ObjectIdVersion, *};
 use axum::{
     Router,
     ServiceExt, // Needed for into_make_service() on the layered service (NormalizePath)
-    extract::State,
+    extract::{Path, Query, State},
     http,
-    http::{HeaderMap, HeaderName, StatusCode},
-    response::IntoResponse,
-    routing::get,
+    http::{HeaderMap, HeaderName, StatusCode, header},
+    middleware::from_fn,
+    response::{IntoResponse, Response},
+    routing::{get, post},
 };
+use blob_fs::FsBlobStorage;
+use check_in::spawn_check_in_resolver;
+use config::{AppConfig, ClientRegistryBackend, DatabaseBackend, TracingConfig};
 use cr_leptos_axum_socket::{ClientRegistrySocket, connect_to_websocket};
+use cr_redis::{DEFAULT_CHANNEL, RedisClientRegistry};
 use db_postgres::*;
-use ddc_plugin::DdcSportPlugin;
-use generic_sport_plugin::GenericSportPlugin;
+use db_sqlite::SqliteDb;
+use domain_events::{BroadcastDomainEvents, EventStreamQuery, domain_event_stream};
+use email_smtp::{LogOnlyEmail, SmtpConfig, SmtpEmail};
+use geocoding_nominatim::NominatimGeocoding;
 use leptos::prelude::*;
 use leptos_axum::{LeptosRoutes, generate_route_list};
 use leptos_axum_socket::{ServerSocket, SocketRoute};
-use serde::Serialize;
+use report::bracket::StageImageCache;
+use serde::{Deserialize, Serialize};
 use shared::*;
 use sport_plugin_manager::SportPluginManagerMap;
+use standings::spawn_standings_recompute;
 use std::env;
 use std::{sync::Arc, time::Duration};
 use tower::Layer;
@@ -30,21 +53,18 @@ use tower_http::{
     request_id::{MakeRequestUuid, PropagateRequestIdLayer, SetRequestIdLayer},
     trace::{DefaultOnRequest, DefaultOnResponse, TraceLayer},
 };
-use tracing::{Level, Span, info, info_span, instrument};
+use tracing::{Level, Span, error, info, info_span, instrument, warn};
 use tracing_bunyan_formatter::{BunyanFormattingLayer, JsonStorageLayer};
 use tracing_error::ErrorLayer;
 use tracing_log::LogTracer;
 use tracing_subscriber::{EnvFilter, Registry, prelude::*};
+use url::Url;
+use uuid::Uuid;
+use webhook_http::{DEFAULT_TIMEOUT, HttpWebhookTransport};
+use webhook_queue::{ChannelWebhookQueue, spawn_webhook_delivery};
 
-fn init_tracing_bunyan() -> Result<()> {
-    // Read level configuration from env (.env via dotenvy or docker sets env)
-    let rust_log =
-        env::var("RUST_LOG").context("POSTGRES_URL must be set. Hint: did you run dotenv()?")?;
-    let database_name = env::var("DATABASE_NAME")
-        .context("POSTGRES_URL must be set. Hint: did you run dotenv()?")?;
-    dbg!(rust_log, database_name);
-    let env_filter =
-        EnvFilter::try_from_default_env().unwrap_or_else(|_| EnvFilter::new("info,axum=info"));
+fn init_tracing_bunyan(config: &TracingConfig) -> Result<()> {
+    let env_filter = EnvFilter::try_new(&config.filter)?;
 
     // Name identifies the service in log streams (use your app/service name)
     let formatting_layer = BunyanFormattingLayer::new(
@@ -90,44 +110,560 @@ async fn health_db(State(app_state): State<AppState>) -> impl IntoResponse {
     }
 }
 
+// --- /health/plugins (registered sport plugins) ---
+#[derive(Serialize)]
+struct PluginStatus {
+    id: Uuid,
+    name: &'static str,
+    version: u32,
+}
+
+#[derive(Serialize)]
+struct PluginsStatus {
+    plugins: &'static str,
+    registered: Vec<PluginStatus>,
+}
+
+#[instrument(name = "health_plugins", skip(app_state))]
+async fn health_plugins(State(app_state): State<AppState>) -> impl IntoResponse {
+    let registered: Vec<PluginStatus> = app_state
+        .core
+        .sport_plugins
+        .list()
+        .iter()
+        .map(|plugin| PluginStatus {
+            id: plugin.get_id(),
+            name: plugin.name(),
+            version: plugin.plugin_version(),
+        })
+        .collect();
+    // without plugins no sport can be configured
+    let (status, plugins) = if registered.is_empty() {
+        (StatusCode::SERVICE_UNAVAILABLE, "none")
+    } else {
+        (StatusCode::OK, "ok")
+    };
+    (
+        status,
+        axum::Json(PluginsStatus {
+            plugins,
+            registered,
+        }),
+    )
+}
+
+// --- /health/cr (client registry readiness) ---
+#[derive(Serialize)]
+struct CrStatus {
+    cr: &'static str,
+}
+
+#[instrument(name = "health_cr", skip(app_state))]
+async fn health_cr(State(app_state): State<AppState>) -> impl IntoResponse {
+    match app_state.core.client_registry.ping().await {
+        Ok(_) => (StatusCode::OK, axum::Json(CrStatus { cr: "ok" })),
+        Err(_) => (
+            StatusCode::SERVICE_UNAVAILABLE,
+            axum::Json(CrStatus { cr: "down" }),
+        ),
+    }
+}
+
+// --- /api/tournament/{id}/calendar.ics and /api/entrant/{id}/calendar.ics ---
+fn calendar_response(ics: CoreResult<Option<String>>, not_found: &'static str) -> Response {
+    match ics {
+        Ok(Some(ics)) => (
+            [(header::CONTENT_TYPE, "text/calendar; charset=utf-8")],
+            ics,
+        )
+            .into_response(),
+        Ok(None) => (StatusCode::NOT_FOUND, not_found).into_response(),
+        Err(e) => {
+            error!(error = %e, "calendar_failed");
+            (
+                StatusCode::INTERNAL_SERVER_ERROR,
+                "could not render calendar",
+            )
+                .into_response()
+        }
+    }
+}
+
+#[instrument(name = "tournament_calendar", skip(app_state))]
+async fn tournament_calendar(State(app_state): State<AppState>, Path(id): Path<Uuid>) -> Response {
+    calendar_response(
+        report::render_tournament_calendar(&app_state.core, id).await,
+        "tournament not found",
+    )
+}
+
+#[instrument(name = "entrant_calendar", skip(app_state))]
+async fn entrant_calendar(State(app_state): State<AppState>, Path(id): Path<Uuid>) -> Response {
+    calendar_response(
+        report::render_entrant_calendar(&app_state.core, id).await,
+        "entrant not found",
+    )
+}
+
+// --- /api/tournament/{id}/stage.png (image of stage for social sharing) ---
+#[derive(Debug, Deserialize)]
+struct StageImageQuery {
+    /// stage number; defaults to the current stage
+    stage: Option<u32>,
+}
+
+#[instrument(name = "stage_png", skip(app_state, cache, headers))]
+async fn stage_png(
+    State(app_state): State<AppState>,
+    Path(id): Path<Uuid>,
+    Query(query): Query<StageImageQuery>,
+    headers: HeaderMap,
+    cache: StageImageCache,
+) -> Response {
+    match report::render_stage_png(&app_state.core, id, query.stage, &cache).await {
+        Ok(Some(image)) => {
+            let etag = format!("\"{}\"", image.etag);
+            let cache_headers = [
+                (header::ETAG, etag.clone()),
+                // images change with results; clients revalidate with the etag
+                (header::CACHE_CONTROL, "public, max-age=60".to_string()),
+            ];
+            if headers
+                .get(header::IF_NONE_MATCH)
+                .is_some_and(|v| v.as_bytes() == etag.as_bytes())
+            {
+                return (StatusCode::NOT_MODIFIED, cache_headers).into_response();
+            }
+            (
+                cache_headers,
+                [(header::CONTENT_TYPE, "image/png")],
+                image.png.as_ref().clone(),
+            )
+                .into_response()
+        }
+        Ok(None) => (StatusCode::NOT_FOUND, "tournament or stage not found").into_response(),
+        Err(e) => {
+            error!(error = %e, "stage_png_failed");
+            (
+                StatusCode::INTERNAL_SERVER_ERROR,
+                "could not render stage image",
+            )
+                .into_response()
+        }
+    }
+}
+
+// --- /api/tournament/{id}/schedule.pdf (printable schedule) ---
+#[instrument(name = "schedule_pdf", skip(app_state, api_auth))]
+async fn schedule_pdf(
+    State(app_state): State<AppState>,
+    Path(id): Path<Uuid>,
+    api_auth: ApiAuth,
+) -> Response {
+    // match notes of directors are printed for organization tokens only
+    let include_notes = api_auth
+        .0
+        .is_some_and(|token| token.has_scope(ApiScope::ReadOrg));
+    match report::render_schedule_pdf(&app_state.core, id, include_notes).await {
+        Ok(Some(pdf)) => (
+            [
+                (header::CONTENT_TYPE, "application/pdf".to_string()),
+                (
+                    header::CONTENT_DISPOSITION,
+                    format!("inline; filename=\"schedule_{id}.pdf\""),
+                ),
+            ],
+            pdf,
+        )
+            .into_response(),
+        Ok(None) => (StatusCode::NOT_FOUND, "tournament not found").into_response(),
+        Err(e) => {
+            error!(error = %e, "schedule_pdf_failed");
+            (
+                StatusCode::INTERNAL_SERVER_ERROR,
+                "could not render schedule",
+            )
+                .into_response()
+        }
+    }
+}
+
+// --- /api/match/{id}/scoresheet.pdf (score sheet for referees) ---
+#[instrument(name = "score_sheet_pdf", skip(app_state))]
+async fn score_sheet_pdf(State(app_state): State<AppState>, Path(id): Path<Uuid>) -> Response {
+    match report::render_score_sheet_pdf(&app_state.core, id).await {
+        Ok(Some(pdf)) => (
+            [
+                (header::CONTENT_TYPE, "application/pdf".to_string()),
+                (
+                    header::CONTENT_DISPOSITION,
+                    format!("inline; filename=\"scoresheet_{id}.pdf\""),
+                ),
+            ],
+            pdf,
+        )
+            .into_response(),
+        Ok(None) => (StatusCode::NOT_FOUND, "match not found").into_response(),
+        Err(e) => {
+            error!(error = %e, "score_sheet_pdf_failed");
+            (
+                StatusCode::INTERNAL_SERVER_ERROR,
+                "could not render score sheet",
+            )
+                .into_response()
+        }
+    }
+}
+
+// --- /api/tournament/{id}/final_report.{pdf,html} (report of finished tournament) ---
+#[instrument(name = "final_report", skip(app_state, api_auth))]
+async fn final_report(
+    app_state: AppState,
+    id: Uuid,
+    format: ReportFormat,
+    api_auth: ApiAuth,
+) -> Response {
+    // handover notes of directors are rendered for organization tokens only; the stored
+    // report is public
+    let report = if api_auth
+        .0
+        .is_some_and(|token| token.has_scope(ApiScope::ReadOrg))
+    {
+        report::render_final_report_with_notes(&app_state.core, id, format)
+            .await
+            .map(|data| {
+                data.map(|data| Blob {
+                    content_type: format.content_type().to_string(),
+                    data,
+                })
+            })
+    } else {
+        app_state.core.load_final_report(id, format).await
+    };
+    match report {
+        Ok(Some(blob)) => (
+            [
+                (header::CONTENT_TYPE, blob.content_type),
+                (
+                    header::CONTENT_DISPOSITION,
+                    format!(
+                        "inline; filename=\"final_report_{id}.{}\"",
+                        format.extension()
+                    ),
+                ),
+            ],
+            blob.data,
+        )
+            .into_response(),
+        Ok(None) => (StatusCode::NOT_FOUND, "final report not found").into_response(),
+        Err(e) => {
+            error!(error = %e, "final_report_failed");
+            (
+                StatusCode::INTERNAL_SERVER_ERROR,
+                "could not load final report",
+            )
+                .into_response()
+        }
+    }
+}
+
+// --- /api/tournament/{id}/entrants.csv (bulk import of entrants) ---
+#[instrument(
+    name = "import_entrants_csv",
+    skip(app_state, api_auth, csv),
+    fields(csv_len = csv.len())
+)]
+async fn import_entrants_csv(
+    State(app_state): State<AppState>,
+    Path(id): Path<Uuid>,
+    api_auth: ApiAuth,
+    csv: String,
+) -> Response {
+    let token = api_auth.0;
+    let mut core = app_state.core.as_entrant_state(id);
+    if let Some(token) = &token {
+        // changes of api clients are recorded with the name of their token
+        core = core.with_actor(format!("api-token:{}", token.get_name()));
+    }
+    match core.import_entrants_csv(&csv).await {
+        Ok(imported) => {
+            let token_id = token.map(|t| t.get_id());
+            info!(?token_id, count = imported.len(), "import_entrants_csv_ok");
+            (StatusCode::OK, axum::Json(imported)).into_response()
+        }
+        Err(CoreError::Validation(errs)) => {
+            (StatusCode::UNPROCESSABLE_ENTITY, axum::Json(errs)).into_response()
+        }
+        Err(CoreError::Db(DbError::ForeignKeyViolation(_))) => {
+            (StatusCode::NOT_FOUND, "tournament not found").into_response()
+        }
+        Err(e) => {
+            error!(error = %e, "import_entrants_csv_failed");
+            (
+                StatusCode::INTERNAL_SERVER_ERROR,
+                "could not import entrants",
+            )
+                .into_response()
+        }
+    }
+}
+
+async fn postgres_db(
+    url: &Url,
+    read_replica: Option<&Url>,
+    migrate_on_startup: bool,
+    statement_timeout: Option<Duration>,
+) -> Result<PgDb> {
+    let mut db = PgDb::new_with_statement_timeout(url.clone(), statement_timeout).await?;
+    // guard against serving with an incompatible schema during rolling deployments
+    match db.check_schema_compatibility().await? {
+        SchemaCompatibility::UpToDate => {}
+        SchemaCompatibility::Pending(versions) => {
+            if !migrate_on_startup {
+                bail!(
+                    "database schema is older than this server (pending migrations: {}); run with --migrate-only first",
+                    versions.join(", ")
+                );
+            }
+            db.run_migration().await?;
+        }
+        SchemaCompatibility::Ahead(versions) => {
+            // older instance during rolling deployment: keep serving reads, refuse writes
+            warn!(unknown_migrations = ?versions, "database schema is newer than this server, refusing writes");
+            db.set_read_only(true);
+        }
+    }
+    if let Some(replica) = read_replica {
+        db = db.with_read_replica(replica.clone()).await?;
+        info!("using read replica for read-only queries");
+    }
+    Ok(db)
+}
+
 #[tokio::main(flavor = "multi_thread")]
 async fn main() -> Result<()> {
     // Load .env first if present; ignore if missing (Docker sets envs)
     dotenvy::dotenv().ok();
+    // load and validate configuration before constructing anything else
+    let config = AppConfig::from_env(get_configuration(None)?.leptos_options)?;
     // map all log! calls in dependencies to tracing
     LogTracer::init()?;
     // Initialize Bunyan-only tracing before constructing anything else.
-    init_tracing_bunyan()?;
+    init_tracing_bunyan(&config.tracing)?;
+
+    // --migrate-only: apply pending migrations and exit, e.g. as job before a rolling deployment
+    if env::args().any(|arg| arg == "--migrate-only") {
+        match &config.database.backend {
+            DatabaseBackend::Sqlite { path } => {
+                SqliteDb::new(path.as_str()).await?.run_migration().await?
+            }
+            DatabaseBackend::Postgres { url, .. } => {
+                PgDb::new(url.clone()).await?.run_migration().await?
+            }
+        }
+        return Ok(());
+    }
 
-    // load leptos options
-    let conf = get_configuration(None)?;
-    let addr = conf.leptos_options.site_addr;
-    let leptos_options = conf.leptos_options;
+    let addr = config.site_addr();
+    let leptos_options = config.leptos_options.clone();
     // initialize core state
-    let db = PgDb::new(url_db()?).await?;
-    db.run_migration().await?;
-    let cr = Arc::new(ClientRegistrySocket {});
-    let mut spm = SportPluginManagerMap::new();
-    // register sport plugins
-    spm.register(Arc::new(GenericSportPlugin::new()))?;
-    spm.register(Arc::new(DdcSportPlugin::new()))?;
-
-    let core = CoreBuilder::new()
-        .set_db(Arc::new(db))
+    let db: Arc<dyn DatabasePort> = match &config.database.backend {
+        // single instance without database server, e.g. offline at the venue
+        DatabaseBackend::Sqlite { path } => {
+            let db = SqliteDb::new(path.as_str()).await?;
+            db.run_migration().await?;
+            info!(%path, "using sqlite database");
+            Arc::new(db)
+        }
+        DatabaseBackend::Postgres {
+            url,
+            read_replica,
+            migrate_on_startup,
+            statement_timeout,
+        } => Arc::new(
+            postgres_db(
+                url,
+                read_replica.as_ref(),
+                *migrate_on_startup,
+                *statement_timeout,
+            )
+            .await?,
+        ),
+    };
+    let em: Arc<dyn EmailPort> = match SmtpConfig::from_env().context("SMTP config is invalid")? {
+        Some(smtp) => {
+            info!(host = %smtp.host, "sending email notifications via smtp");
+            Arc::new(SmtpEmail::new(smtp).context("failed to build smtp transport")?)
+        }
+        // no smtp server: notifications are only logged
+        None => Arc::new(LogOnlyEmail),
+    };
+    let core_builder = match config.database.cache.clone() {
+        Some(cache) => {
+            info!("caching hot lookups of database");
+            CoreBuilder::new().set_cached_db(db, cache)
+        }
+        None => CoreBuilder::new().set_db(db),
+    };
+    let cr: Arc<dyn ClientRegistryPort> = match &config.client_registry {
+        // multiple server instances: distribute client registry messages via redis
+        ClientRegistryBackend::Redis(redis_url) => {
+            // messages of other instances invalidate the cache of this instance
+            let local: Arc<dyn ClientRegistryPort> = match core_builder.get_cache() {
+                Some(cache) => Arc::new(CacheInvalidatingClientRegistry::new(
+                    Arc::new(ClientRegistrySocket {}),
+                    cache,
+                )),
+                None => Arc::new(ClientRegistrySocket {}),
+            };
+            let (cr, _bridge) = RedisClientRegistry::new(redis_url, DEFAULT_CHANNEL, local).await?;
+            cr
+        }
+        // single server instance
+        ClientRegistryBackend::Local => Arc::new(ClientRegistrySocket {}),
+    };
+    // clients refresh their list of sports, if plugins are deregistered or replaced
+    let spm = SportPluginManagerMap::new().with_client_registry(cr.clone());
+    // register configured sport plugins; clients register plugins with the same function
+    register_sport_plugins(&spm, &config.plugins.sports)?;
+
+    // domain events are streamed to integrations at /api/events
+    let domain_events = BroadcastDomainEvents::new();
+    let core_builder = match NominatimGeocoding::from_env().context("NOMINATIM_URL is invalid")? {
+        Some(geocoding) => {
+            info!("geocoding postal addresses via nominatim");
+            core_builder.set_geo(Arc::new(geocoding))
+        }
+        // no geocoding: addresses have no location
+        None => core_builder,
+    };
+    // webhooks are delivered in the background, so slow receivers do not delay requests
+    let (webhook_queue, webhook_jobs) = ChannelWebhookQueue::new();
+    let core = core_builder
+        .set_webhook_queue(Arc::new(webhook_queue))
         .set_cr(cr.clone())
         .set_spm(Arc::new(spm))
+        .set_wh(Arc::new(
+            HttpWebhookTransport::new(DEFAULT_TIMEOUT)
+                .context("failed to build webhook http client")?,
+        ))
+        .set_em(em)
+        .set_bs(Arc::new(FsBlobStorage::from_env()))
+        .set_ev(Arc::new(domain_events.clone()))
         .build();
     let app_state = AppState {
         core: Arc::new(core),
         leptos_options: leptos_options.clone(),
         socket: ServerSocket::new(),
     };
+    // queued webhooks are delivered with retries
+    spawn_webhook_delivery(app_state.core.clone(), webhook_jobs);
+    // entrants, who are not checked in by the deadline, are replaced or removed
+    if config.features.check_in_resolver {
+        spawn_check_in_resolver(app_state.core.clone());
+    }
+    // cached group standings are recomputed, when results of matches are entered
+    spawn_standings_recompute(app_state.core.clone(), domain_events.clone());
     // Generate the list of routes in your Leptos App
     let routes = generate_route_list(App);
+    // rate limits of api tokens are shared by all REST API routes
+    let api_limiter = ApiRateLimiter::default();
+    let stage_images = StageImageCache::new();
+    // GraphQL endpoint is optional
+    #[cfg(feature = "graphql")]
+    let graphql_routes = if config.features.graphql {
+        graphql::graphql_routes(app_state.core.clone(), api_limiter.clone())
+    } else {
+        Router::new()
+    };
+    #[cfg(not(feature = "graphql"))]
+    let graphql_routes = {
+        if config.features.graphql {
+            warn!("FEATURE_GRAPHQL is ignored, since server is built without feature graphql");
+        }
+        Router::<AppState>::new()
+    };
 
+    // event stream checks, whether tournaments of events are public
+    let events_core = app_state.core.clone();
+    // routes of the REST API require an api token with their scope
+    let scoped = |route, scope| with_api_scope(route, &app_state.core, &api_limiter, scope);
     let app = Router::new()
         .route("/health", get(health))
         .route("/health/db", get(health_db))
+        .route("/health/plugins", get(health_plugins))
+        .route("/health/cr", get(health_cr))
+        .route(
+            "/api/tournament/{id}/schedule.pdf",
+            scoped(get(schedule_pdf), ApiScope::ReadPublic),
+        )
+        .route(
+            "/api/match/{id}/scoresheet.pdf",
+            scoped(get(score_sheet_pdf), ApiScope::ReadPublic),
+        )
+        .route(
+            "/api/tournament/{id}/final_report.pdf",
+            scoped(
+                get(
+                    |State(state): State<AppState>, Path(id): Path<Uuid>, api_auth: ApiAuth| {
+                        final_report(state, id, ReportFormat::Pdf, api_auth)
+                    },
+                ),
+                ApiScope::ReadPublic,
+            ),
+        )
+        .route(
+            "/api/tournament/{id}/final_report.html",
+            scoped(
+                get(
+                    |State(state): State<AppState>, Path(id): Path<Uuid>, api_auth: ApiAuth| {
+                        final_report(state, id, ReportFormat::Html, api_auth)
+                    },
+                ),
+                ApiScope::ReadPublic,
+            ),
+        )
+        .route(
+            "/api/tournament/{id}/stage.png",
+            scoped(
+                get(
+                    move |state: State<AppState>,
+                          path: Path<Uuid>,
+                          query: Query<StageImageQuery>,
+                          headers: HeaderMap| {
+                        stage_png(state, path, query, headers, stage_images.clone())
+                    },
+                ),
+                ApiScope::ReadPublic,
+            ),
+        )
+        .route(
+            "/api/tournament/{id}/calendar.ics",
+            scoped(get(tournament_calendar), ApiScope::ReadPublic),
+        )
+        .route(
+            "/api/entrant/{id}/calendar.ics",
+            scoped(get(entrant_calendar), ApiScope::ReadPublic),
+        )
+        .route(
+            "/api/events",
+            scoped(
+                get(move |api_auth: ApiAuth, query: Query<EventStreamQuery>| {
+                    domain_event_stream(domain_events.clone(), events_core.clone(), api_auth, query)
+                }),
+                ApiScope::ReadPublic,
+            ),
+        )
+        .route(
+            "/api/tournament/{id}/entrants.csv",
+            scoped(post(import_entrants_csv), ApiScope::WriteOrg),
+        )
+        // versioned REST API for third-party clients
+        .nest(
+            "/api/v1",
+            api_v1_routes(app_state.core.clone(), api_limiter.clone()),
+        )
+        .merge(graphql_routes)
         .leptos_routes_with_context(
             &app_state,
             routes,
@@ -143,6 +679,8 @@ async fn main() -> Result<()> {
         .socket_route(connect_to_websocket)
         .fallback(leptos_axum::file_and_error_handler::<AppState, _>(shell))
         .with_state(app_state)
+        // --- refuse server function calls of outdated clients ---
+        .layer(from_fn(require_app_build))
         // --- request id handling: set + propagate x-request-id ---
         .layer(PropagateRequestIdLayer::new(HeaderName::from_static("x-request-id")))
         .layer(SetRequestIdLayer::x_request_id(MakeRequestUuid))
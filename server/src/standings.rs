@@ -0,0 +1,53 @@
+//! background recomputation of cached group standings after results of matches changed
+
+use crate::domain_events::BroadcastDomainEvents;
+use app_core::{CoreState, DomainEvent};
+use std::{collections::HashSet, time::Duration};
+use tokio::sync::broadcast::error::RecvError;
+use tracing::{error, info, warn};
+use uuid::Uuid;
+
+/// interval between two recomputations; results entered in between are recomputed at once
+const STANDINGS_INTERVAL: Duration = Duration::from_secs(2);
+
+/// Recompute cached group standings of all tournaments, for which results of matches were
+/// entered since the last interval. Errors are logged and the tournament is recomputed with
+/// the next result.
+pub fn spawn_standings_recompute(core: CoreState, domain_events: BroadcastDomainEvents) {
+    let mut events = domain_events.subscribe();
+    tokio::spawn(async move {
+        let mut interval = tokio::time::interval(STANDINGS_INTERVAL);
+        interval.set_missed_tick_behavior(tokio::time::MissedTickBehavior::Delay);
+        let mut dirty: HashSet<Uuid> = HashSet::new();
+        loop {
+            tokio::select! {
+                received = events.recv() => match received {
+                    Ok(envelope) => {
+                        if let DomainEvent::MatchResultEntered { tournament_id, .. } =
+                            envelope.event
+                        {
+                            dirty.insert(tournament_id);
+                        }
+                    }
+                    Err(RecvError::Lagged(num_skipped)) => {
+                        warn!(num_skipped, "standings_recompute_lagged");
+                    }
+                    Err(RecvError::Closed) => break,
+                },
+                _ = interval.tick() => {
+                    for tournament_id in dirty.drain() {
+                        match core.recompute_group_standings(tournament_id).await {
+                            Ok(0) => {}
+                            Ok(num_recomputed) => {
+                                info!(%tournament_id, num_recomputed, "recomputed_standings")
+                            }
+                            Err(err) => {
+                                error!(%tournament_id, error = %err, "recompute_standings_failed")
+                            }
+                        }
+                    }
+                }
+            }
+        }
+    });
+}
@@ -0,0 +1,100 @@
+//! background delivery of webhooks with retries
+
+use app_core::{
+    CoreState, WEBHOOK_RETRY_DELAYS, WebhookError, WebhookJob, WebhookQueuePort, WebhookResult,
+};
+use async_trait::async_trait;
+use std::{iter, sync::Arc, time::Duration};
+use tokio::sync::{OwnedSemaphorePermit, Semaphore, mpsc};
+use tracing::{error, info, warn};
+
+/// number of queued deliveries; events are dropped, if the queue is full
+const QUEUE_CAPACITY: usize = 1024;
+
+/// number of deliveries, which are sent concurrently
+const MAX_CONCURRENT_DELIVERIES: usize = 8;
+
+/// Queue of webhook deliveries, which are sent by [`spawn_webhook_delivery`].
+#[derive(Clone)]
+pub struct ChannelWebhookQueue {
+    sender: mpsc::Sender<WebhookJob>,
+}
+
+impl ChannelWebhookQueue {
+    /// Create queue and receiver of its jobs.
+    pub fn new() -> (Self, mpsc::Receiver<WebhookJob>) {
+        let (sender, receiver) = mpsc::channel(QUEUE_CAPACITY);
+        (ChannelWebhookQueue { sender }, receiver)
+    }
+}
+
+#[async_trait]
+impl WebhookQueuePort for ChannelWebhookQueue {
+    async fn enqueue(&self, job: WebhookJob) -> WebhookResult<()> {
+        // a full queue must not block the operation, which triggered the event
+        self.sender
+            .try_send(job)
+            .map_err(|e| WebhookError::NotQueued(e.to_string()))
+    }
+}
+
+/// Deliver jobs of `receiver` in the background. Failed deliveries are retried after
+/// [`WEBHOOK_RETRY_DELAYS`]; every attempt is recorded in the delivery history.
+///
+/// At most [`MAX_CONCURRENT_DELIVERIES`] attempts are sent concurrently. Deliveries waiting for
+/// their next retry do not hold a permit, so they neither block new jobs nor fill the queue.
+pub fn spawn_webhook_delivery(core: CoreState, mut receiver: mpsc::Receiver<WebhookJob>) {
+    tokio::spawn(async move {
+        let permits = Arc::new(Semaphore::new(MAX_CONCURRENT_DELIVERIES));
+        while let Some(job) = receiver.recv().await {
+            let permit = acquire_permit(&permits).await;
+            let core = core.clone();
+            let permits = permits.clone();
+            tokio::spawn(async move {
+                deliver_with_retries(&core, &permits, permit, &job).await;
+            });
+        }
+    });
+}
+
+async fn acquire_permit(permits: &Arc<Semaphore>) -> OwnedSemaphorePermit {
+    permits
+        .clone()
+        .acquire_owned()
+        .await
+        .expect("semaphore of webhook deliveries is never closed")
+}
+
+/// Deliver `job` with the `permit` of its first attempt; the permit is released before each
+/// retry delay and acquired again for the next attempt.
+async fn deliver_with_retries(
+    core: &CoreState,
+    permits: &Arc<Semaphore>,
+    permit: OwnedSemaphorePermit,
+    job: &WebhookJob,
+) {
+    let endpoint_id = job.endpoint.get_id();
+    let event_id = job.payload.event_id;
+    let mut permit = Some(permit);
+    for delay in iter::once(Duration::ZERO).chain(WEBHOOK_RETRY_DELAYS) {
+        if !delay.is_zero() {
+            tokio::time::sleep(delay).await;
+        }
+        let _permit = match permit.take() {
+            Some(permit) => permit,
+            None => acquire_permit(permits).await,
+        };
+        match core.deliver_webhook_job(job).await {
+            Ok(Some(delivery)) if delivery.is_retryable() => {
+                info!(%endpoint_id, %event_id, status_code = ?delivery.status_code, "webhook_delivery_failed");
+            }
+            Ok(_) => return,
+            Err(err) => {
+                // the delivery may have been sent; retries could duplicate it
+                error!(%endpoint_id, %event_id, error = %err, "webhook_delivery_not_recorded");
+                return;
+            }
+        }
+    }
+    warn!(%endpoint_id, %event_id, "webhook_delivery_given_up");
+}
@@ -2,11 +2,90 @@
 
 use app_core::{SportConfig, SportPort};
 use leptos::prelude::*;
+use std::time::Duration;
 
 /// Trait for rendering sport port specifics in web ui, e.g., configuration forms and previews.
 pub trait SportPortWebUi: Send + Sync + SportPort {
     fn render_plugin_selection(&self) -> AnyView;
     fn render_preview(&self, config: &SportConfig) -> AnyView;
     fn render_detailed_preview(&self, config: &SportConfig) -> AnyView;
-    fn render_configuration(&self) -> AnyView;
+    /// Renders the form of the sport specific configuration. Plugins, which provide a
+    /// `config_schema()`, get a generic form and do not need to implement it.
+    fn render_configuration(&self) -> AnyView {
+        ().into_any()
+    }
+    /// Renders facts derived from `config`, e.g. with [`SportConfigSummary::render`], so
+    /// that directors see the consequences of a configuration before they use it in a
+    /// tournament. Plugins without a summary render nothing.
+    fn render_summary(&self, _config: &SportConfig) -> AnyView {
+        ().into_any()
+    }
+}
+
+/// Facts derived from a sport configuration
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct SportConfigSummary {
+    /// minimum number of sets of a match
+    pub min_sets: u16,
+    /// maximum number of sets of a match
+    pub max_sets: u16,
+    /// label of sets in the sport, e.g. "sets" or "periods"
+    pub sets_label: &'static str,
+    /// expected duration of a match
+    pub match_duration: Duration,
+    /// time between two matches at a station, e.g. to change entrants and warm up
+    pub changeover: Duration,
+    /// matches may end in a draw
+    pub draws_possible: bool,
+}
+
+impl SportConfigSummary {
+    /// expected time a match occupies its station, i.e. duration of the match and changeover
+    pub fn slot_duration(&self) -> Duration {
+        self.match_duration + self.changeover
+    }
+
+    pub fn display_sets(&self) -> String {
+        if self.min_sets == self.max_sets {
+            format!("{} {}", self.max_sets, self.sets_label)
+        } else {
+            format!("{}–{} {}", self.min_sets, self.max_sets, self.sets_label)
+        }
+    }
+
+    pub fn display_duration(&self) -> String {
+        format!(
+            "~{} min + {} min changeover = {} min",
+            self.match_duration.as_secs() / 60,
+            self.changeover.as_secs() / 60,
+            self.slot_duration().as_secs() / 60
+        )
+    }
+
+    pub fn render(&self) -> AnyView {
+        let draws = if self.draws_possible {
+            "Draws possible"
+        } else {
+            "No draws"
+        };
+        view! {
+            <div
+                class="flex flex-wrap items-center gap-x-4 gap-y-2 p-3 bg-base-200 text-sm rounded-lg"
+                data-testid="sport-config-summary"
+            >
+                <div class="flex items-center gap-1" title="Sets per Match">
+                    <span class="icon-[heroicons--squares-2x2] w-4 h-4 opacity-70"></span>
+                    <span data-testid="summary-sets">{self.display_sets()}</span>
+                </div>
+                <div class="flex items-center gap-1" title="Expected Duration incl. Changeover">
+                    <span class="icon-[heroicons--clock] w-4 h-4 opacity-70"></span>
+                    <span data-testid="summary-duration">{self.display_duration()}</span>
+                </div>
+                <span class="badge badge-sm badge-outline" data-testid="summary-draws">
+                    {draws}
+                </span>
+            </div>
+        }
+        .into_any()
+    }
 }
@@ -1,16 +1,24 @@
 //! Implementation of sport plugin manager port
 
-use anyhow::{Result, bail};
-use app_core::{SportPluginManagerPort, SportPort};
+use anyhow::{Context, Result, bail};
+use app_core::{ClientRegistryPort, CrMsg, CrTopic, SportPluginManagerPort, SportPort};
 use shared::SportPortWebUi;
-use std::{collections::HashMap, sync::Arc};
+use std::{
+    collections::HashMap,
+    sync::{Arc, PoisonError, RwLock, RwLockReadGuard, RwLockWriteGuard},
+};
 use uuid::Uuid;
 
 /// A concrete implementation of the `SportPluginManagerPort` (see mod server) that uses a `HashMap`
 /// to store and retrieve sport plugins.
+///
+/// Plugins may be registered, deregistered and replaced after the manager is shared, e.g. by
+/// the server after start. Clones share their plugins.
 #[derive(Clone, Default)]
 pub struct SportPluginManagerMap {
-    plugins: HashMap<Uuid, Arc<dyn SportPortWebUi>>,
+    plugins: Arc<RwLock<HashMap<Uuid, Arc<dyn SportPortWebUi>>>>,
+    /// notifies clients about deregistered and replaced plugins, if set
+    client_registry: Option<Arc<dyn ClientRegistryPort>>,
 }
 
 impl SportPluginManagerMap {
@@ -24,9 +32,14 @@ impl SportPluginManagerMap {
     /// // The manager is now ready to register plugins.
     /// ```
     pub fn new() -> Self {
-        Self {
-            plugins: HashMap::new(),
-        }
+        Self::default()
+    }
+
+    /// Notify clients of `client_registry` about deregistered and replaced plugins with
+    /// [`CrMsg::SportPluginsUpdated`] on [`CrTopic::SportPlugins`].
+    pub fn with_client_registry(mut self, client_registry: Arc<dyn ClientRegistryPort>) -> Self {
+        self.client_registry = Some(client_registry);
+        self
     }
 
     /// Registers a new sport plugin with the manager.
@@ -63,7 +76,7 @@ impl SportPluginManagerMap {
     /// #     fn estimate_match_duration(&self, _config: &SportConfig) -> SportResult<Duration> { Ok(Duration::from_secs(0)) }
     /// #     fn validate_final_score(&self, _config: &SportConfig, _score: &Match) -> SportResult<()> { Ok(()) }
     /// #     fn get_entrant_group_score(&self, _config: &SportConfig, group_id: Uuid, entrant_id: Uuid, _all_matches: &[Match]) -> SportResult<EntrantGroupScore> {
-    /// #         Ok(EntrantGroupScore { entrant_id, group_id, victory_points: 0.0, relative_score: 0, total_score: 0 })
+    /// #         Ok(EntrantGroupScore::new(entrant_id, group_id))
     /// #     }
     /// # }
     /// # impl SportPortWebUi for MockSport {
@@ -81,7 +94,7 @@ impl SportPluginManagerMap {
     /// #     }
     /// # }
     /// #
-    /// let mut manager = SportPluginManagerMap::new();
+    /// let manager = SportPluginManagerMap::new();
     /// let sport_id = Uuid::new_v4();
     /// let plugin = Arc::new(MockSport { id: sport_id, name: "MockSport" });
     ///
@@ -89,20 +102,65 @@ impl SportPluginManagerMap {
     ///
     /// assert!(manager.get(&sport_id).is_some());
     /// ```
-    pub fn register(&mut self, plugin: Arc<dyn SportPortWebUi>) -> Result<()> {
+    pub fn register(&self, plugin: Arc<dyn SportPortWebUi>) -> Result<()> {
         let plugin_id = plugin.get_id_version().get_id();
-        if self.plugins.contains_key(&plugin_id) {
+        let mut plugins = self.write();
+        if plugins.contains_key(&plugin_id) {
             bail!("A plugin with ID {} is already registered", plugin_id);
         }
-        if self.plugins.values().any(|p| p.name() == plugin.name()) {
+        if plugins.values().any(|p| p.name() == plugin.name()) {
             bail!(
                 "A plugin with name '{}' is already registered",
                 plugin.name()
             );
         }
-        self.plugins.insert(plugin_id, plugin);
+        plugins.insert(plugin_id, plugin);
         Ok(())
     }
+
+    /// Removes the plugin with ID `sport_id` from the manager and returns it.
+    ///
+    /// If no plugin with this ID is registered, an error is returned. Sport configs of the
+    /// plugin stay in the database; they are usable again, if the plugin is registered again.
+    pub async fn deregister(&self, sport_id: &Uuid) -> Result<Arc<dyn SportPortWebUi>> {
+        let Some(plugin) = self.write().remove(sport_id) else {
+            bail!("No plugin with ID {} is registered", sport_id);
+        };
+        self.notify(*sport_id, 0).await?;
+        Ok(plugin)
+    }
+
+    /// Replaces the registered plugin with the ID of `plugin`, e.g. by a newer version of the
+    /// plugin, and returns the replaced plugin.
+    ///
+    /// If no plugin with this ID is registered or another plugin has the same name, an error
+    /// is returned.
+    pub async fn replace(
+        &self,
+        plugin: Arc<dyn SportPortWebUi>,
+    ) -> Result<Arc<dyn SportPortWebUi>> {
+        let plugin_id = plugin.get_id_version().get_id();
+        let plugin_version = plugin.plugin_version();
+        let replaced = {
+            let mut plugins = self.write();
+            if !plugins.contains_key(&plugin_id) {
+                bail!("No plugin with ID {} is registered", plugin_id);
+            }
+            if plugins
+                .iter()
+                .any(|(id, p)| *id != plugin_id && p.name() == plugin.name())
+            {
+                bail!(
+                    "A plugin with name '{}' is already registered",
+                    plugin.name()
+                );
+            }
+            // unwrap is safe, because the plugin is registered
+            plugins.insert(plugin_id, plugin).unwrap()
+        };
+        self.notify(plugin_id, plugin_version).await?;
+        Ok(replaced)
+    }
     /// Retrieves a registered sport configuration preview plugin by its unique ID.
     ///
     /// Returns `None` if no plugin with the given ID is found.
@@ -137,7 +195,7 @@ impl SportPluginManagerMap {
     /// #     fn estimate_match_duration(&self, _config: &SportConfig) -> SportResult<Duration> { Ok(Duration::from_secs(0)) }
     /// #     fn validate_final_score(&self, _config: &SportConfig, _score: &Match) -> SportResult<()> { Ok(()) }
     /// #     fn get_entrant_group_score(&self, _config: &SportConfig, group_id: Uuid, entrant_id: Uuid, _all_matches: &[Match]) -> SportResult<EntrantGroupScore> {
-    /// #         Ok(EntrantGroupScore { entrant_id, group_id, victory_points: 0.0, relative_score: 0, total_score: 0 })
+    /// #         Ok(EntrantGroupScore::new(entrant_id, group_id))
     /// #     }
     /// # }
     /// # impl SportPortWebUi for MockSport {
@@ -155,7 +213,7 @@ impl SportPluginManagerMap {
     /// #     }
     /// # }
     /// #
-    /// let mut manager = SportPluginManagerMap::new();
+    /// let manager = SportPluginManagerMap::new();
     /// let sport_id = Uuid::new_v4();
     /// let plugin = Arc::new(MockSport { id: sport_id, name: "MockSport" });
     /// manager.register(plugin).unwrap();
@@ -170,7 +228,32 @@ impl SportPluginManagerMap {
     /// assert!(not_found_plugin.is_none());
     /// ```
     pub fn get_web_ui(&self, sport_id: &Uuid) -> Option<Arc<dyn SportPortWebUi>> {
-        self.plugins.get(sport_id).cloned()
+        self.read().get(sport_id).cloned()
+    }
+
+    /// Publish the change of plugin `sport_id` to clients, if a client registry is set.
+    async fn notify(&self, sport_id: Uuid, version: u32) -> Result<()> {
+        let Some(client_registry) = self.client_registry.as_ref() else {
+            return Ok(());
+        };
+        let msg = CrMsg::SportPluginsUpdated {
+            id: sport_id,
+            version,
+        };
+        client_registry
+            .publish(CrTopic::SportPlugins, msg)
+            .await
+            .context("failed to notify clients about changed sport plugins")
+    }
+
+    // a poisoned lock only means, that a thread panicked while holding it; the map itself
+    // is always consistent, because it is changed by single inserts and removes
+    fn read(&self) -> RwLockReadGuard<'_, HashMap<Uuid, Arc<dyn SportPortWebUi>>> {
+        self.plugins.read().unwrap_or_else(PoisonError::into_inner)
+    }
+
+    fn write(&self) -> RwLockWriteGuard<'_, HashMap<Uuid, Arc<dyn SportPortWebUi>>> {
+        self.plugins.write().unwrap_or_else(PoisonError::into_inner)
     }
 }
 
@@ -209,7 +292,7 @@ impl SportPluginManagerPort for SportPluginManagerMap {
     /// #     fn estimate_match_duration(&self, _config: &SportConfig) -> SportResult<Duration> { Ok(Duration::from_secs(0)) }
     /// #     fn validate_final_score(&self, _config: &SportConfig, _score: &Match) -> SportResult<()> { Ok(()) }
     /// #     fn get_entrant_group_score(&self, _config: &SportConfig, group_id: Uuid, entrant_id: Uuid, _all_matches: &[Match]) -> SportResult<EntrantGroupScore> {
-    /// #         Ok(EntrantGroupScore { entrant_id, group_id, victory_points: 0.0, relative_score: 0, total_score: 0 })
+    /// #         Ok(EntrantGroupScore::new(entrant_id, group_id))
     /// #     }
     /// # }
     /// # impl SportPortWebUi for MockSport {
@@ -227,7 +310,7 @@ impl SportPluginManagerPort for SportPluginManagerMap {
     /// #     }
     /// # }
     /// #
-    /// let mut manager = SportPluginManagerMap::new();
+    /// let manager = SportPluginManagerMap::new();
     /// let sport_id = Uuid::new_v4();
     /// let plugin = Arc::new(MockSport { id: sport_id, name: "MockSport" });
     /// manager.register(plugin).unwrap();
@@ -242,7 +325,7 @@ impl SportPluginManagerPort for SportPluginManagerMap {
     /// assert!(not_found_plugin.is_none());
     /// ```
     fn get(&self, sport_id: &Uuid) -> Option<Arc<dyn SportPort>> {
-        self.plugins
+        self.read()
             .get(sport_id)
             .map(|p| p.clone() as Arc<dyn SportPort>)
     }
@@ -281,7 +364,7 @@ impl SportPluginManagerPort for SportPluginManagerMap {
     /// #     fn estimate_match_duration(&self, _config: &SportConfig) -> SportResult<Duration> { Ok(Duration::from_secs(0)) }
     /// #     fn validate_final_score(&self, _config: &SportConfig, _score: &Match) -> SportResult<()> { Ok(()) }
     /// #     fn get_entrant_group_score(&self, _config: &SportConfig, group_id: Uuid, entrant_id: Uuid, _all_matches: &[Match]) -> SportResult<EntrantGroupScore> {
-    /// #         Ok(EntrantGroupScore { entrant_id, group_id, victory_points: 0.0, relative_score: 0, total_score: 0 })
+    /// #         Ok(EntrantGroupScore::new(entrant_id, group_id))
     /// #     }
     /// # }
     /// # impl SportPortWebUi for MockSport {
@@ -299,7 +382,7 @@ impl SportPluginManagerPort for SportPluginManagerMap {
     /// #     }
     /// # }
     /// #
-    /// let mut manager = SportPluginManagerMap::new();
+    /// let manager = SportPluginManagerMap::new();
     /// manager.register(Arc::new(MockSport { id: Uuid::new_v4(), name: "Sport1" })).unwrap();
     /// manager.register(Arc::new(MockSport { id: Uuid::new_v4(), name: "Sport2" })).unwrap();
     ///
@@ -307,7 +390,7 @@ impl SportPluginManagerPort for SportPluginManagerMap {
     /// assert_eq!(all_plugins.len(), 2);
     /// ```
     fn list(&self) -> Vec<Arc<dyn SportPort>> {
-        self.plugins
+        self.read()
             .values()
             .map(|p| p.clone() as Arc<dyn SportPort>)
             .collect()
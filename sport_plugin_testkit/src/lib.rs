@@ -0,0 +1,240 @@
+//! Conformance test kit for sport plugins
+//!
+//! The kit exercises any [`SportPort`] implementation with configurations generated from
+//! its config schema and with generated scores. Plugin authors get a ready-made conformance
+//! suite by adding the kit as dev dependency and a single test:
+//!
+//! ```ignore
+//! #[test]
+//! fn test_sport_plugin_conformance() {
+//!     sport_plugin_testkit::assert_conformance(Arc::new(MySportPlugin::new()));
+//! }
+//! ```
+
+pub mod strategy;
+
+use app_core::{
+    Match, SportConfig, SportPort,
+    utils::{id_version::IdVersion, traits::ObjectIdVersion},
+};
+use proptest::{
+    prelude::*,
+    test_runner::{Config, TestCaseError, TestError, TestRunner},
+};
+use serde_json::Value;
+use std::{fmt::Debug, sync::Arc, time::Duration};
+use thiserror::Error;
+use uuid::Uuid;
+
+/// default number of generated cases of each property
+pub const DEFAULT_CASES: u32 = 256;
+
+/// A sport plugin violates a property of the conformance suite.
+#[derive(Debug, Clone, Error)]
+pub enum ConformanceError {
+    #[error("default config: {0}")]
+    DefaultConfig(String),
+    #[error("duration estimate: {0}")]
+    DurationEstimate(String),
+    #[error("score validation: {0}")]
+    ScoreValidation(String),
+}
+
+pub type ConformanceResult<T> = Result<T, ConformanceError>;
+
+/// Run all checks of the conformance suite against `plugin` and panic on the first violated
+/// property, e.g. in a unit test of the plugin.
+pub fn assert_conformance(plugin: Arc<dyn SportPort>) {
+    if let Err(err) = SportPluginTestkit::new(plugin).check_all() {
+        panic!("sport plugin is not conform: {err}");
+    }
+}
+
+/// Conformance suite of a sport plugin.
+pub struct SportPluginTestkit {
+    plugin: Arc<dyn SportPort>,
+    cases: u32,
+}
+
+impl SportPluginTestkit {
+    pub fn new(plugin: Arc<dyn SportPort>) -> Self {
+        SportPluginTestkit {
+            plugin,
+            cases: DEFAULT_CASES,
+        }
+    }
+
+    /// Set the number of generated cases of each property.
+    pub fn set_cases(&mut self, cases: u32) -> &mut Self {
+        self.cases = cases;
+        self
+    }
+
+    /// Run all checks; returns the first violated property.
+    pub fn check_all(&self) -> ConformanceResult<()> {
+        self.check_default_config()?;
+        self.check_duration_estimates()?;
+        self.check_score_validation()
+    }
+
+    /// The default configuration is valid and stays valid after a round trip through JSON.
+    pub fn check_default_config(&self) -> ConformanceResult<()> {
+        let config = sport_config(self.plugin.as_ref(), self.plugin.get_default_config());
+        config
+            .validate(self.plugin.clone())
+            .map_err(|e| ConformanceError::DefaultConfig(format!("invalid: {e}")))?;
+
+        let json = serde_json::to_string(&config)
+            .map_err(|e| ConformanceError::DefaultConfig(format!("serialize: {e}")))?;
+        let round_trip: SportConfig = serde_json::from_str(&json)
+            .map_err(|e| ConformanceError::DefaultConfig(format!("deserialize: {e}")))?;
+        if round_trip != config {
+            return Err(ConformanceError::DefaultConfig(
+                "changed by round trip through JSON".to_string(),
+            ));
+        }
+        round_trip
+            .validate(self.plugin.clone())
+            .map_err(|e| ConformanceError::DefaultConfig(format!("invalid after round trip: {e}")))
+    }
+
+    /// Every valid configuration has a positive estimate of the duration of a match.
+    pub fn check_duration_estimates(&self) -> ConformanceResult<()> {
+        let strategy = strategy::config_values(self.plugin.as_ref());
+        self.run(&strategy, |values| {
+            let Some(config) = self.valid_config(values) else {
+                return Ok(());
+            };
+            let duration = self
+                .plugin
+                .estimate_match_duration(&config)
+                .map_err(|e| TestCaseError::fail(format!("estimate failed: {e}")))?;
+            prop_assert!(duration > Duration::ZERO, "estimate is not positive");
+            Ok(())
+        })
+        .map_err(ConformanceError::DurationEstimate)
+    }
+
+    /// Validation of scores is consistent:
+    /// - a free ticket is a valid result,
+    /// - a score is valid regardless of the side, which scored it,
+    /// - a valid score decides the match exactly once for each side: the win of one side is
+    ///   the loss of the other side and draws only occur, if the plugin supports them.
+    pub fn check_score_validation(&self) -> ConformanceResult<()> {
+        let plugin = self.plugin.clone();
+        let strategy = strategy::config_values(self.plugin.as_ref()).prop_flat_map(move |values| {
+            let config = sport_config(plugin.as_ref(), values.clone());
+            let max_sets = plugin.max_sets(&config).unwrap_or(1);
+            let free_ticket = plugin.free_ticket_score(&config).unwrap_or_default();
+            (Just(values), strategy::set_scores(max_sets, free_ticket))
+        });
+        self.run(&strategy, |(values, (score_a, score_b))| {
+            let Some(config) = self.valid_config(values) else {
+                return Ok(());
+            };
+            self.check_free_ticket(&config)?;
+
+            let a_b = self.played(score_a.clone(), score_b.clone());
+            let b_a = self.played(score_b, score_a);
+            let valid = self.plugin.validate_final_score(&config, &a_b).is_ok();
+            prop_assert_eq!(
+                valid,
+                self.plugin.validate_final_score(&config, &b_a).is_ok(),
+                "validation depends on side"
+            );
+            if valid {
+                self.check_decided(&config, &a_b)?;
+            }
+            Ok(())
+        })
+        .map_err(ConformanceError::ScoreValidation)
+    }
+
+    fn check_free_ticket(&self, config: &SportConfig) -> Result<(), TestCaseError> {
+        let free_ticket = self
+            .plugin
+            .free_ticket_score(config)
+            .map_err(|e| TestCaseError::fail(format!("free ticket failed: {e}")))?;
+        let zeros = vec![0; free_ticket.len()];
+        let m = self.played(free_ticket, zeros);
+        self.plugin
+            .validate_final_score(config, &m)
+            .map_err(|e| TestCaseError::fail(format!("free ticket is invalid: {e}")))?;
+        self.check_decided(config, &m)
+    }
+
+    fn check_decided(&self, config: &SportConfig, m: &Match) -> Result<(), TestCaseError> {
+        let Some((entrant_a, entrant_b)) = m.get_entrants() else {
+            return Ok(());
+        };
+        let matches = std::slice::from_ref(m);
+        let score = |entrant_id: Uuid| {
+            self.plugin
+                .get_entrant_group_score(config, Uuid::nil(), entrant_id, matches)
+                .map_err(|e| TestCaseError::fail(format!("group score failed: {e}")))
+        };
+        let a = score(*entrant_a)?;
+        let b = score(*entrant_b)?;
+        prop_assert_eq!(a.played(), 1, "side a did not play once");
+        prop_assert_eq!(b.played(), 1, "side b did not play once");
+        prop_assert_eq!(a.wins, b.losses, "win of side a is not loss of side b");
+        prop_assert_eq!(a.draws, b.draws, "draw of one side only");
+        prop_assert!(
+            a.draws == 0 || self.plugin.capabilities().supports_draws,
+            "draw without support of draws"
+        );
+        prop_assert_eq!(
+            a.relative_score,
+            -b.relative_score,
+            "relative scores do not cancel out"
+        );
+        prop_assert_eq!(score(Uuid::new_v4())?.played(), 0, "bystander played");
+        Ok(())
+    }
+
+    /// sport config with `values`, if the plugin accepts them
+    fn valid_config(&self, values: Value) -> Option<SportConfig> {
+        let config = sport_config(self.plugin.as_ref(), values);
+        config.validate(self.plugin.clone()).ok().map(|_| config)
+    }
+
+    fn played(&self, score_a: Vec<u16>, score_b: Vec<u16>) -> Match {
+        Match::new_played(
+            Uuid::new_v4(),
+            Uuid::new_v4(),
+            Uuid::new_v4(),
+            self.plugin.get_id_version().get_id(),
+            score_a,
+            score_b,
+        )
+    }
+
+    fn run<S, F>(&self, strategy: &S, test: F) -> Result<(), String>
+    where
+        S: Strategy,
+        S::Value: Debug,
+        F: Fn(S::Value) -> Result<(), TestCaseError>,
+    {
+        let config = Config {
+            cases: self.cases,
+            // the kit runs in tests of plugins, which do not share a source tree for
+            // persisted failures
+            failure_persistence: None,
+            ..Config::default()
+        };
+        TestRunner::new(config)
+            .run(strategy, test)
+            .map_err(|e: TestError<S::Value>| e.to_string())
+    }
+}
+
+/// sport config of `plugin` with the sport specific `values`
+fn sport_config(plugin: &dyn SportPort, values: Value) -> SportConfig {
+    let mut config = SportConfig::new(IdVersion::new(Uuid::new_v4(), Some(0)));
+    config
+        .set_sport_id(plugin.get_id_version().get_id())
+        .set_name("Conformance")
+        .set_plugin_version(plugin.plugin_version())
+        .set_config(values);
+    config
+}
@@ -0,0 +1,105 @@
+//! proptest strategies for configurations and scores of sport plugins
+
+use app_core::{ConfigDurationUnit, ConfigFieldKind, ConfigFieldSpec, SportPort};
+use proptest::{collection::vec, prelude::*, sample::select};
+use serde_json::{Value, json};
+
+/// largest generated score of a side in a set
+pub const MAX_SCORE: u16 = 30;
+
+/// largest generated whole number above the minimum of an integer field
+const MAX_INTEGER_SPAN: i64 = 50;
+
+/// largest generated decimal number above the minimum of a decimal field
+const MAX_DECIMAL_SPAN: f64 = 100.0;
+
+/// largest generated duration in minutes
+const MAX_DURATION_MINUTES: u64 = 240;
+
+/// Strategy for sport specific configurations of `plugin`.
+///
+/// Generation starts with the default configuration of the plugin. Every field of the
+/// config schema gets a generated value; fields, which do not exist in the generated
+/// configuration (e.g. data of another variant), keep their default. Generated
+/// configurations are not necessarily valid.
+pub fn config_values(plugin: &dyn SportPort) -> BoxedStrategy<Value> {
+    let default = plugin.get_default_config();
+    let schema = plugin.config_schema();
+    let fields: Vec<BoxedStrategy<Value>> = schema
+        .iter()
+        .map(|spec| field_value(spec, spec.read(&default)))
+        .collect();
+    fields
+        .prop_map(move |values| {
+            let mut config = default.clone();
+            for (spec, value) in schema.iter().zip(values) {
+                spec.write(&mut config, value);
+            }
+            config
+        })
+        .boxed()
+}
+
+/// Strategy for the value of field `spec`. Optional fields, whose value is `null` in the
+/// default configuration, generate `null` as well.
+pub fn field_value(spec: &ConfigFieldSpec, default: Option<&Value>) -> BoxedStrategy<Value> {
+    let value = match &spec.kind {
+        ConfigFieldKind::Integer { min } => {
+            let min = min.unwrap_or(0);
+            (min..=min + MAX_INTEGER_SPAN).prop_map(Value::from).boxed()
+        }
+        ConfigFieldKind::Decimal { min, .. } => {
+            let min = min.unwrap_or(0.0);
+            (min..min + MAX_DECIMAL_SPAN).prop_map(Value::from).boxed()
+        }
+        ConfigFieldKind::Duration { unit } => {
+            let factor = match unit {
+                ConfigDurationUnit::Seconds => 1,
+                ConfigDurationUnit::Minutes => 60,
+            };
+            (0..=MAX_DURATION_MINUTES * 60 / factor)
+                .prop_map(move |n| json!({ "secs": n * factor, "nanos": 0 }))
+                .boxed()
+        }
+        ConfigFieldKind::Toggle => any::<bool>().prop_map(Value::from).boxed(),
+        ConfigFieldKind::Text => "[A-Za-z ]{0,16}".prop_map(Value::from).boxed(),
+        ConfigFieldKind::Select { options } if !options.is_empty() => {
+            let values: Vec<Value> = options.iter().map(|o| o.value.clone()).collect();
+            select(values).boxed()
+        }
+        ConfigFieldKind::Select { .. } => Just(default.cloned().unwrap_or_default()).boxed(),
+    };
+    if default.is_some_and(Value::is_null) {
+        prop_oneof![Just(Value::Null), value].boxed()
+    } else {
+        value
+    }
+}
+
+/// Strategy for scores of both sides of a match with 1 to `max_sets` sets.
+///
+/// Half of the scores are arbitrary. The other half is shaped like the free ticket score
+/// `free_ticket` of the plugin: each set is won by a random side with the score of the free
+/// ticket for this set against a lower score. This way plugins with strict rules for set
+/// scores, e.g. table tennis, get valid scores as well.
+pub fn set_scores(max_sets: u16, free_ticket: Vec<u16>) -> BoxedStrategy<(Vec<u16>, Vec<u16>)> {
+    let arbitrary = (1..=max_sets.max(1) as usize)
+        .prop_flat_map(|num_sets| (vec(0..=MAX_SCORE, num_sets), vec(0..=MAX_SCORE, num_sets)));
+    if free_ticket.is_empty() {
+        return arbitrary.boxed();
+    }
+    let shaped = (1..=max_sets.max(1) as usize).prop_flat_map(move |num_sets| {
+        let sets: Vec<_> = (0..num_sets)
+            .map(|set| {
+                let won = free_ticket[set.min(free_ticket.len() - 1)];
+                (Just(won), 0..won.max(1), any::<bool>())
+            })
+            .collect();
+        sets.prop_map(|sets| {
+            sets.into_iter()
+                .map(|(won, lost, a_wins)| if a_wins { (won, lost) } else { (lost, won) })
+                .unzip()
+        })
+    });
+    prop_oneof![arbitrary, shaped].boxed()
+}
@@ -0,0 +1,279 @@
+use app_core::{
+    SportError, SportResult,
+    utils::validation::{FieldError, ValidationErrors, ValidationResult},
+};
+use app_utils::enum_utils::SelectableOption;
+use serde::{Deserialize, Serialize};
+use serde_json::Value;
+use std::{fmt::Display, time::Duration};
+use uuid::Uuid;
+
+/// points to win a set in table tennis
+pub const POINTS_TO_WIN: u16 = 11;
+/// margin to win a set after deuce at 10:10
+pub const WIN_BY_MARGIN: u16 = 2;
+
+/// TtSetCfg - configuration for sets in table tennis
+#[derive(Debug, Clone, Copy, Serialize, Deserialize, Default, PartialEq)]
+pub enum TtSetCfg {
+    /// Best of 5 sets
+    #[default]
+    BestOf5,
+    /// Best of 7 sets
+    BestOf7,
+}
+
+impl Display for TtSetCfg {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            TtSetCfg::BestOf5 => write!(f, "Best of 5 sets"),
+            TtSetCfg::BestOf7 => write!(f, "Best of 7 sets"),
+        }
+    }
+}
+
+impl SelectableOption for TtSetCfg {
+    fn value(&self) -> String {
+        self.to_string()
+    }
+
+    fn label(&self) -> String {
+        self.to_string()
+    }
+
+    fn options(&self) -> Vec<Self> {
+        vec![TtSetCfg::BestOf5, TtSetCfg::BestOf7]
+    }
+
+    fn static_options() -> Vec<Self> {
+        Self::options(&Self::default())
+    }
+}
+
+impl TtSetCfg {
+    /// Returns the number of sets to win a match.
+    pub fn sets_to_win(&self) -> u16 {
+        match self {
+            TtSetCfg::BestOf5 => 3,
+            TtSetCfg::BestOf7 => 4,
+        }
+    }
+
+    /// Returns the minimum and maximum number of sets to play.
+    pub fn sets_to_play(&self) -> (u16, u16) {
+        let sets_to_win = self.sets_to_win();
+        (sets_to_win, sets_to_win * 2 - 1)
+    }
+}
+
+/// TtExpediteCfg - configuration of the expedite system
+///
+/// If a set is not finished after the time limit, the expedite system is introduced, unless
+/// both players scored at least 9 points: service alternates after each point and the
+/// receiver wins the rally with the 13th good return. The expedite system remains in
+/// effect for the rest of the match. It does not change the scoring of a set.
+#[derive(Debug, Clone, Copy, Serialize, Deserialize, Default, PartialEq)]
+pub enum TtExpediteCfg {
+    /// expedite system is introduced after 10 minutes of a set
+    #[default]
+    After10Minutes,
+    /// custom time limit of a set in minutes
+    Custom { minutes: u16 },
+    /// expedite system is not used
+    Off,
+}
+
+impl Display for TtExpediteCfg {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            TtExpediteCfg::After10Minutes => write!(f, "Expedite after 10 min"),
+            TtExpediteCfg::Custom { minutes } => {
+                write!(f, "Custom expedite after {} min", minutes)
+            }
+            TtExpediteCfg::Off => write!(f, "No expedite"),
+        }
+    }
+}
+
+impl SelectableOption for TtExpediteCfg {
+    fn value(&self) -> String {
+        self.to_string()
+    }
+
+    fn label(&self) -> String {
+        self.to_string()
+    }
+
+    fn options(&self) -> Vec<Self> {
+        let minutes = match self {
+            TtExpediteCfg::Custom { minutes } => *minutes,
+            _ => 0,
+        };
+        vec![
+            TtExpediteCfg::After10Minutes,
+            TtExpediteCfg::Custom { minutes },
+            TtExpediteCfg::Off,
+        ]
+    }
+
+    fn static_options() -> Vec<Self> {
+        Self::options(&Self::default())
+    }
+}
+
+impl TtExpediteCfg {
+    /// validates the expedite configuration
+    pub fn validate(&self, object_id: Uuid, mut errs: ValidationErrors) -> ValidationErrors {
+        if let TtExpediteCfg::Custom { minutes: 0 } = self {
+            errs.add(
+                FieldError::builder()
+                    .set_field("expedite_cfg")
+                    .add_user_defined_code("invalid_value")
+                    .add_message("minutes must be at least 1")
+                    .set_object_id(object_id)
+                    .build(),
+            );
+        }
+        errs
+    }
+
+    /// Returns the time limit of a set, after which the expedite system is introduced.
+    pub fn time_limit(&self) -> Option<Duration> {
+        match self {
+            TtExpediteCfg::After10Minutes => Some(Duration::from_secs(10 * 60)),
+            TtExpediteCfg::Custom { minutes } => Some(Duration::from_secs(*minutes as u64 * 60)),
+            TtExpediteCfg::Off => None,
+        }
+    }
+}
+
+/// Validates the final score of a set: 11 points win a set; at 10:10 a set is won by a
+/// margin of 2 points without hard cap.
+pub fn validate_final_set_score(score_a: u16, score_b: u16) -> SportResult<()> {
+    let max_score = score_a.max(score_b);
+    let min_score = score_a.min(score_b);
+    if max_score < POINTS_TO_WIN {
+        return Err(SportError::InvalidScore(
+            "No entrant has reached the score to win".to_string(),
+        ));
+    }
+    if min_score < POINTS_TO_WIN - 1 {
+        if max_score != POINTS_TO_WIN {
+            return Err(SportError::InvalidScore(format!(
+                "Set is finished at {}:{}",
+                POINTS_TO_WIN, min_score
+            )));
+        }
+    } else if max_score - min_score != WIN_BY_MARGIN {
+        return Err(SportError::InvalidScore(format!(
+            "Set must be won by a margin of {} after deuce",
+            WIN_BY_MARGIN
+        )));
+    }
+    Ok(())
+}
+
+/// Configuration for the table tennis plugin
+#[derive(Debug, Clone, Copy, Serialize, Deserialize)]
+pub struct TtSportConfig {
+    /// configuration for sets
+    pub sets_cfg: TtSetCfg,
+    /// configuration of the expedite system
+    #[serde(default)]
+    pub expedite_cfg: TtExpediteCfg,
+    /// victory points gained by a win
+    pub victory_points_win: f32,
+    /// victory points gained by a loss
+    pub victory_points_loss: f32,
+    /// expected mean duration of a rally in seconds
+    /// Used to estimate match duration by multiplying with max number of rallies of a set
+    /// without deuce, which is 11:9 or 20 rallies.
+    pub expected_rally_duration_seconds: Duration,
+}
+
+impl Default for TtSportConfig {
+    fn default() -> Self {
+        Self {
+            sets_cfg: TtSetCfg::BestOf5,
+            expedite_cfg: TtExpediteCfg::After10Minutes,
+            victory_points_win: 1.0,
+            victory_points_loss: 0.0,
+            expected_rally_duration_seconds: Duration::from_secs(15),
+        }
+    }
+}
+
+impl TtSportConfig {
+    pub fn parse_config(config: Value) -> SportResult<Self> {
+        match serde_json::from_value(config) {
+            Ok(sc) => Ok(sc),
+            Err(e) => Err(SportError::InvalidJsonConfig(format!(
+                "Failed to parse TtSportConfig: {}",
+                e
+            ))),
+        }
+    }
+    pub fn validate(&self, object_id: Uuid, errs: ValidationErrors) -> ValidationResult<()> {
+        let mut errs = self.expedite_cfg.validate(object_id, errs);
+        if self.victory_points_win <= 0.0 {
+            errs.add(
+                FieldError::builder()
+                    .set_field("victory_points_win")
+                    .add_user_defined_code("invalid_value")
+                    .add_message("victory_points_win must be greater than 0")
+                    .set_object_id(object_id)
+                    .build(),
+            );
+        }
+        if self.victory_points_loss < 0.0 {
+            errs.add(
+                FieldError::builder()
+                    .set_field("victory_points_loss")
+                    .add_user_defined_code("invalid_value")
+                    .add_message("victory_points_loss must not be negative")
+                    .set_object_id(object_id)
+                    .build(),
+            );
+        }
+        if self.victory_points_win <= self.victory_points_loss {
+            errs.add(
+                FieldError::builder()
+                    .set_field("victory_points_loss")
+                    .add_user_defined_code("invalid_value")
+                    .add_message("victory_points_loss must be less than victory_points_win")
+                    .set_object_id(object_id)
+                    .build(),
+            );
+        }
+        if self.expected_rally_duration_seconds.as_secs() == 0 {
+            errs.add(
+                FieldError::builder()
+                    .set_field("expected_rally_duration_seconds")
+                    .add_user_defined_code("invalid_value")
+                    .add_message("expected_rally_duration_seconds must be greater than 0")
+                    .set_object_id(object_id)
+                    .build(),
+            );
+        }
+        if errs.is_empty() { Ok(()) } else { Err(errs) }
+    }
+    /// Estimates the duration of a match with the maximum number of sets.
+    /// If a set exceeds the time limit of the expedite system, the remaining rallies of the
+    /// match are limited to 13 returns. They are assumed to take half of the expected rally
+    /// duration.
+    pub fn estimate_match_duration(&self) -> Duration {
+        let max_sets = self.sets_cfg.sets_to_play().1 as u32;
+        let rallies = (POINTS_TO_WIN + POINTS_TO_WIN - WIN_BY_MARGIN) as u32;
+        let rally = self.expected_rally_duration_seconds;
+        let set_duration = rally * rallies;
+        match self.expedite_cfg.time_limit() {
+            Some(limit) if set_duration > limit && !rally.is_zero() => {
+                // rallies played in first set until expedite system is introduced
+                let played = (limit.as_secs_f64() / rally.as_secs_f64()) as u32;
+                let expedite_rallies = rallies - played + (max_sets - 1) * rallies;
+                limit + rally * expedite_rallies / 2
+            }
+            _ => set_duration * max_sets,
+        }
+    }
+}
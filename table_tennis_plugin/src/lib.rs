@@ -0,0 +1,281 @@
+//! Table tennis sport plugin
+//! Matches are played best of 5 or 7 sets to 11 points with a winning margin of 2 after
+//! deuce. Optionally the expedite system limits the duration of sets.
+
+pub mod config;
+pub mod sport_port;
+pub mod sport_web_ui;
+
+use app_core::{
+    Match, SportConfig, SportError, SportResult,
+    utils::{
+        id_version::IdVersion,
+        namespace::project_namespace,
+        traits::ObjectIdVersion,
+        validation::{FieldError, ValidationErrors, ValidationResult},
+    },
+};
+use config::{TtSportConfig, validate_final_set_score};
+use uuid::Uuid;
+
+/// Implementation of the `SportPort` for table tennis.
+#[derive(Debug, Default, Clone, Copy)]
+pub struct TtSportPlugin {}
+
+impl TtSportPlugin {
+    pub fn new() -> Self {
+        Self {}
+    }
+    fn id(&self) -> Uuid {
+        // The table tennis sport plugin must use a fixed UUID.
+        let sport_name = "table_tennis";
+        Uuid::new_v5(&project_namespace(), sport_name.as_bytes())
+    }
+    fn validate_config(
+        &self,
+        config: &SportConfig,
+        errs: ValidationErrors,
+    ) -> ValidationResult<TtSportConfig> {
+        if config.get_sport_id() != self.id() {
+            let err = FieldError::builder()
+                .set_field("sport_id")
+                .add_message(format!(
+                    "Sport ID does not match TtSportPlugin id: expected {}, got {}",
+                    self.id(),
+                    config.get_sport_id()
+                ))
+                .set_object_id(config.get_id())
+                .build();
+            return Err(err.into());
+        }
+        let tt_config = match TtSportConfig::parse_config(config.get_config().clone()) {
+            Ok(cfg) => cfg,
+            Err(e) => {
+                let err = FieldError::builder()
+                    .set_field("sport_config_json")
+                    .add_message(format!("Invalid sport configuration JSON: {}", e))
+                    .set_object_id(config.get_id())
+                    .build();
+                return Err(err.into());
+            }
+        };
+        tt_config.validate(config.get_id(), errs)?;
+        Ok(tt_config)
+    }
+    fn validate_final_score_internal(
+        &self,
+        config: &TtSportConfig,
+        score: &Match,
+    ) -> SportResult<()> {
+        let (score_a, score_b) = score.get_scores();
+        let (min_sets, max_sets) = config.sets_cfg.sets_to_play();
+        if score_a.len() != score_b.len() {
+            return Err(SportError::InvalidScore(
+                "Score vectors for both entrants must have the same length".to_string(),
+            ));
+        }
+        if !(min_sets as usize..=max_sets as usize).contains(&score_a.len()) {
+            return Err(SportError::InvalidScore(
+                "Score does not have the correct number of sets".to_string(),
+            ));
+        }
+        let mut sets_a = 0;
+        let mut sets_b = 0;
+        for (&a, &b) in score_a.iter().zip(score_b.iter()) {
+            if sets_a == config.sets_cfg.sets_to_win() || sets_b == config.sets_cfg.sets_to_win() {
+                return Err(SportError::InvalidScore(
+                    "Match is finished before last set".to_string(),
+                ));
+            }
+            validate_final_set_score(a, b)?;
+            if a > b {
+                sets_a += 1;
+            } else {
+                sets_b += 1;
+            }
+        }
+        if sets_a.max(sets_b) != config.sets_cfg.sets_to_win() {
+            return Err(SportError::InvalidScore(
+                "No entrant has won the number of sets to win".to_string(),
+            ));
+        }
+
+        Ok(())
+    }
+}
+
+impl ObjectIdVersion for TtSportPlugin {
+    fn get_id_version(&self) -> IdVersion {
+        // we can increment version later if changes are made to the sport plugin
+        IdVersion::new(self.id(), Some(0))
+    }
+}
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use app_core::{ConfigFieldKind, SportPort, utils::id_version::IdVersion};
+    use config::{TtExpediteCfg, TtSetCfg};
+    use serde_json::json;
+    use std::time::Duration;
+
+    fn sport_config(plugin: &TtSportPlugin, config: serde_json::Value) -> SportConfig {
+        let id_version = IdVersion::new(Uuid::new_v4(), Some(1));
+        let mut sport_config = SportConfig::new(id_version);
+        sport_config
+            .set_sport_id(plugin.id())
+            .set_name("Table Tennis")
+            .set_config(config);
+        sport_config
+    }
+
+    fn score(plugin: &TtSportPlugin, score_a: Vec<u16>, score_b: Vec<u16>) -> Match {
+        Match::new_played(
+            Uuid::new_v4(),
+            Uuid::new_v4(),
+            Uuid::new_v4(),
+            plugin.id(),
+            score_a,
+            score_b,
+        )
+    }
+
+    #[test]
+    fn test_validate_config() {
+        let plugin = TtSportPlugin::new();
+        let default_config = sport_config(&plugin, plugin.get_default_config());
+        assert!(
+            plugin
+                .validate_config(&default_config, ValidationErrors::new())
+                .is_ok()
+        );
+
+        let custom_config = sport_config(
+            &plugin,
+            json!({
+                "sets_cfg": "BestOf7",
+                "expedite_cfg": { "Custom": { "minutes": 0 } },
+                "victory_points_win": 2.0,
+                "victory_points_loss": 2.0,
+                "expected_rally_duration_seconds": { "secs": 15, "nanos": 0 }
+            }),
+        );
+        let errs = plugin
+            .validate_config(&custom_config, ValidationErrors::new())
+            .unwrap_err();
+        assert_eq!(errs.errors.len(), 2);
+    }
+
+    #[test]
+    fn test_validate_final_score() {
+        let plugin = TtSportPlugin::new();
+        let config = sport_config(&plugin, plugin.get_default_config());
+        let validate = |score_a: Vec<u16>, score_b: Vec<u16>| {
+            plugin.validate_final_score(&config, &score(&plugin, score_a, score_b))
+        };
+
+        // 3:0 and 3:2 sets with deuce
+        assert!(validate(vec![11, 11, 11], vec![5, 9, 3]).is_ok());
+        assert!(validate(vec![11, 9, 14, 4, 12], vec![7, 11, 12, 11, 10]).is_ok());
+        // set finished at 11:9
+        assert!(validate(vec![12, 11, 11], vec![9, 5, 3]).is_err());
+        // margin after deuce must be exactly 2
+        assert!(validate(vec![12, 11, 11], vec![11, 5, 3]).is_err());
+        assert!(validate(vec![15, 11, 11], vec![12, 5, 3]).is_err());
+        // score to win not reached
+        assert!(validate(vec![10, 11, 11], vec![8, 5, 3]).is_err());
+        // no winner after 4 sets
+        assert!(validate(vec![11, 11, 5, 5], vec![5, 5, 11, 11]).is_err());
+        // set played after match is finished
+        assert!(validate(vec![11, 11, 11, 5], vec![5, 5, 5, 11]).is_err());
+    }
+
+    #[test]
+    fn test_estimate_match_duration() {
+        let mut config = TtSportConfig {
+            expedite_cfg: TtExpediteCfg::Off,
+            ..Default::default()
+        };
+        // 5 sets of 20 rallies of 15 seconds
+        assert_eq!(
+            config.estimate_match_duration(),
+            Duration::from_secs(5 * 20 * 15)
+        );
+        // sets of 5 minutes do not reach the time limit of the expedite system
+        config.expedite_cfg = TtExpediteCfg::After10Minutes;
+        assert_eq!(
+            config.estimate_match_duration(),
+            Duration::from_secs(5 * 20 * 15)
+        );
+        // after 4 minutes of the first set, the remaining 84 rallies are expedited
+        config.expedite_cfg = TtExpediteCfg::Custom { minutes: 4 };
+        assert_eq!(
+            config.estimate_match_duration(),
+            Duration::from_secs(4 * 60 + 84 * 15 / 2)
+        );
+    }
+
+    #[test]
+    fn test_max_sets() {
+        let plugin = TtSportPlugin::new();
+        let mut values = plugin.get_default_config();
+        let config = sport_config(&plugin, values.clone());
+        assert_eq!(plugin.max_sets(&config).unwrap(), 5);
+        values["sets_cfg"] = json!(TtSetCfg::BestOf7);
+        let config = sport_config(&plugin, values);
+        assert_eq!(plugin.max_sets(&config).unwrap(), 7);
+    }
+
+    #[test]
+    fn test_config_schema() {
+        let plugin = TtSportPlugin::new();
+        let schema = plugin.config_schema();
+        let field = |name: &str| schema.iter().find(|s| s.name == name).unwrap();
+        let mut config = plugin.get_default_config();
+
+        // all fields except the minutes of a custom expedite system exist in the default config
+        let visible = schema
+            .iter()
+            .filter(|s| s.read(&config).is_some())
+            .map(|s| s.name.as_str())
+            .collect::<Vec<_>>();
+        assert_eq!(
+            visible,
+            vec![
+                "sets_cfg",
+                "expedite_cfg",
+                "victory_points_win",
+                "victory_points_loss",
+                "expected_rally_duration_seconds"
+            ]
+        );
+
+        // selecting the custom expedite system shows its minutes
+        let ConfigFieldKind::Select { options } = &field("expedite_cfg").kind else {
+            panic!("expedite_cfg must be a select");
+        };
+        let custom = options
+            .iter()
+            .find(|o| o.variant() == Some("Custom"))
+            .unwrap();
+        assert!(field("expedite_cfg").write(&mut config, custom.value.clone()));
+        assert!(field("expedite_minutes").write(&mut config, json!(4)));
+        assert!(field("victory_points_win").write(&mut config, json!(2.5)));
+        assert!(
+            field("expected_rally_duration_seconds")
+                .write_duration(&mut config, Duration::from_secs(20))
+        );
+
+        let tt_config = TtSportConfig::parse_config(config).unwrap();
+        assert_eq!(tt_config.expedite_cfg, TtExpediteCfg::Custom { minutes: 4 });
+        assert_eq!(tt_config.victory_points_win, 2.5);
+        assert_eq!(
+            tt_config.expected_rally_duration_seconds,
+            Duration::from_secs(20)
+        );
+    }
+
+    #[test]
+    fn test_sport_plugin_conformance() {
+        sport_plugin_testkit::assert_conformance(std::sync::Arc::new(TtSportPlugin::new()));
+    }
+}
@@ -0,0 +1,192 @@
+//! Implementation of SportPort for table tennis
+
+use super::{
+    TtSportPlugin,
+    config::{TtExpediteCfg, TtSetCfg, TtSportConfig},
+};
+use app_core::{
+    ConfigDurationUnit, ConfigFieldKind, ConfigFieldOption, ConfigFieldSpec, EntrantGroupScore,
+    Match, MatchOutcome, SportCapabilities, SportConfig, SportError, SportPort, SportResult,
+    StationType,
+    utils::validation::{ValidationErrors, ValidationResult},
+};
+use serde_json::{Value, json};
+use std::time::Duration;
+use uuid::Uuid;
+
+impl SportPort for TtSportPlugin {
+    fn name(&self) -> &'static str {
+        "Table Tennis"
+    }
+    fn get_default_config(&self) -> Value {
+        serde_json::to_value(TtSportConfig::default()).unwrap()
+    }
+    fn capabilities(&self) -> SportCapabilities {
+        SportCapabilities {
+            supports_draws: false,
+            per_set_scoring: true,
+            supports_handicap: false,
+            supports_time_cap: false,
+            needs_station_type: Some(StationType::Table),
+        }
+    }
+    fn config_schema(&self) -> Vec<ConfigFieldSpec> {
+        let set_option = |cfg: TtSetCfg| ConfigFieldOption::new(cfg.to_string(), json!(cfg));
+        vec![
+            ConfigFieldSpec::new(
+                "sets_cfg",
+                "Sets Configuration",
+                ConfigFieldKind::Select {
+                    options: vec![set_option(TtSetCfg::BestOf5), set_option(TtSetCfg::BestOf7)],
+                },
+            ),
+            ConfigFieldSpec::new(
+                "expedite_cfg",
+                "Expedite System",
+                ConfigFieldKind::Select {
+                    options: vec![
+                        ConfigFieldOption::new(
+                            TtExpediteCfg::After10Minutes.to_string(),
+                            json!(TtExpediteCfg::After10Minutes),
+                        ),
+                        ConfigFieldOption::new(
+                            "Custom expedite",
+                            json!(TtExpediteCfg::Custom { minutes: 0 }),
+                        ),
+                        ConfigFieldOption::new(
+                            TtExpediteCfg::Off.to_string(),
+                            json!(TtExpediteCfg::Off),
+                        ),
+                    ],
+                },
+            ),
+            ConfigFieldSpec::new(
+                "expedite_minutes",
+                "Expedite after Minutes",
+                ConfigFieldKind::Integer { min: Some(1) },
+            )
+            .at("/expedite_cfg/Custom/minutes")
+            .reported_as("expedite_cfg"),
+            ConfigFieldSpec::new(
+                "victory_points_win",
+                "Victory Points for Win",
+                ConfigFieldKind::Decimal {
+                    min: Some(0.0),
+                    step: Some(0.1),
+                },
+            ),
+            ConfigFieldSpec::new(
+                "victory_points_loss",
+                "Victory Points for Loss",
+                ConfigFieldKind::Decimal {
+                    min: Some(0.0),
+                    step: Some(0.1),
+                },
+            ),
+            ConfigFieldSpec::new(
+                "expected_rally_duration_seconds",
+                "Expected Rally Duration",
+                ConfigFieldKind::Duration {
+                    unit: ConfigDurationUnit::Seconds,
+                },
+            ),
+        ]
+    }
+    fn estimate_match_duration(&self, config: &SportConfig) -> SportResult<Duration> {
+        let tt_config = self.validate_config(config, ValidationErrors::new())?;
+        Ok(tt_config.estimate_match_duration())
+    }
+    fn max_sets(&self, config: &SportConfig) -> SportResult<u16> {
+        let tt_config = self.validate_config(config, ValidationErrors::new())?;
+        Ok(tt_config.sets_cfg.sets_to_play().1)
+    }
+    fn free_ticket_score(&self, config: &SportConfig) -> SportResult<Vec<u16>> {
+        let tt_config = self.validate_config(config, ValidationErrors::new())?;
+        // a free ticket wins the sets to win by 11:0
+        Ok(vec![11; tt_config.sets_cfg.sets_to_win() as usize])
+    }
+    fn validate_config_values(
+        &self,
+        config: &SportConfig,
+        errs: ValidationErrors,
+    ) -> ValidationResult<()> {
+        self.validate_config(config, errs)?;
+        Ok(())
+    }
+
+    /// Validates a final score against the rules defined in the configuration.
+    /// Each set is won with 11 points or, after deuce at 10:10, with a margin of exactly
+    /// 2 points. The match ends, when an entrant has won the sets to win.
+    /// Matches decided by forfeit or bye have no scores to validate.
+    fn validate_final_score(&self, config: &SportConfig, score: &Match) -> SportResult<()> {
+        if score.get_sport_id() != &self.id() {
+            return Err(SportError::InvalidScore(
+                "Match sport_id does not match TtSportPlugin id".to_string(),
+            ));
+        }
+        if score.get_entrants().is_none() {
+            return Err(SportError::InvalidScore(
+                "Both sides of the match must have concrete entrant IDs".to_string(),
+            ));
+        }
+        let tt_config = self.validate_config(config, ValidationErrors::new())?;
+        if score.get_outcome().is_walkover() {
+            return Ok(());
+        }
+        self.validate_final_score_internal(&tt_config, score)?;
+        Ok(())
+    }
+
+    /// Gathers and calculates entrant group score
+    fn get_entrant_group_score(
+        &self,
+        config: &SportConfig,
+        group_id: Uuid,
+        entrant_id: Uuid,
+        all_matches: &[Match],
+    ) -> SportResult<EntrantGroupScore> {
+        let tt_config = self.validate_config(config, ValidationErrors::new())?;
+        let free_ticket = self.free_ticket_score(config)?;
+        let mut group_score = EntrantGroupScore::new(entrant_id, group_id);
+        for m in all_matches.iter().filter(|m| {
+            if let Some((id_a, id_b)) = m.get_entrants() {
+                (id_a == &entrant_id || id_b == &entrant_id)
+                    && m.get_group_id() == &group_id
+                    && m.is_decided()
+            } else {
+                false
+            }
+        }) {
+            if m.get_outcome() == MatchOutcome::DoubleForfeit {
+                group_score.losses += 1;
+                continue;
+            }
+            // unwrap is safe due to filter
+            let (id_a, _id_b) = m.get_entrants().unwrap();
+            let entrant_is_a = id_a == &entrant_id;
+            let (score_a, score_b) = m.get_result_scores(&free_ticket);
+            let entrant_score = if entrant_is_a { &score_a } else { &score_b };
+            let opponent_score = if entrant_is_a { &score_b } else { &score_a };
+            let mut sets_won = 0;
+            let mut sets_lost = 0;
+            for (&a, &b) in entrant_score.iter().zip(opponent_score.iter()) {
+                if a > b {
+                    sets_won += 1;
+                } else if b > a {
+                    sets_lost += 1;
+                }
+                group_score.total_score += a;
+                group_score.relative_score += a as i16 - b as i16;
+            }
+            // table tennis matches cannot end in a draw
+            if sets_won > sets_lost {
+                group_score.victory_points += tt_config.victory_points_win;
+                group_score.wins += 1;
+            } else {
+                group_score.victory_points += tt_config.victory_points_loss;
+                group_score.losses += 1;
+            }
+        }
+        Ok(group_score)
+    }
+}
@@ -0,0 +1,119 @@
+//! Implementation of sport preview for table tennis
+//!
+//! The configuration form is rendered from `config_schema()`, see `sport_port.rs`.
+
+use super::TtSportPlugin;
+use crate::config::{POINTS_TO_WIN, WIN_BY_MARGIN};
+use app_core::{SportConfig, SportPort, utils::validation::ValidationErrors};
+use leptos::prelude::*;
+use shared::SportPortWebUi;
+use std::time::Duration;
+
+impl SportPortWebUi for TtSportPlugin {
+    fn render_plugin_selection(&self) -> AnyView {
+        view! {
+            <div
+                class="flex flex-col items-center justify-center gap-4 w-full"
+                data-testid="table-tennis-plugin-selection"
+            >
+                // ToDo: Replace with proper icon later
+                <div class="text-6xl">"🏓"</div>
+                <div class="flex flex-col items-center">
+                    <h3 class="text-xl font-bold text-center">"Table Tennis"</h3>
+                    <span class="text-xs uppercase tracking-widest opacity-70">"ITTF"</span>
+                </div>
+            </div>
+        }
+        .into_any()
+    }
+    fn render_detailed_preview(&self, config: &SportConfig) -> AnyView {
+        let tt_config = match self.validate_config(config, ValidationErrors::new()) {
+            Ok(cfg) => cfg,
+            Err(_) => return view! { <div>{"Invalid Configuration"}</div> }.into_any(),
+        };
+
+        let duration_minutes = Self::new()
+            .estimate_match_duration(config)
+            .unwrap_or(Duration::from_secs(0))
+            .as_secs()
+            / 60;
+
+        view! {
+            <div
+                class="flex flex-wrap items-center gap-x-4 gap-y-2 p-3 bg-base-200 text-sm rounded-lg"
+                data-testid="table-entry-detailed-preview"
+            >
+                // 1. Block: Match Rules (Sets, Scoring & Expedite)
+                <div class="flex flex-wrap items-center gap-2">
+                    <div class="flex items-center gap-1 font-semibold text-base-content">
+                        <span class="icon-[heroicons--trophy] w-4 h-4 opacity-70"></span>
+                        <span data-testid="preview-set-config">
+                            {tt_config.sets_cfg.to_string()}
+                        </span>
+                    </div>
+
+                    <span class="hidden sm:inline text-base-content/30">"|"</span>
+
+                    <span class="text-base-content/80" data-testid="preview-set-winning-config">
+                        {format!("Score: {} (+{})", POINTS_TO_WIN, WIN_BY_MARGIN)}
+                    </span>
+
+                    <span class="hidden sm:inline text-base-content/30">"|"</span>
+
+                    <span class="text-base-content/80" data-testid="preview-expedite-config">
+                        {tt_config.expedite_cfg.to_string()}
+                    </span>
+                </div>
+
+                // 2. Block: Meta Info (Duration & Points)
+                <div class="flex items-center gap-3 ml-auto sm:ml-0">
+                    // Duration
+                    <div
+                        class="flex items-center gap-1 text-xs opacity-70"
+                        title="Expected Match Duration"
+                    >
+                        <span class="icon-[heroicons--clock] w-4 h-4"></span>
+                        <span data-testid="preview-expected-duration">
+                            {format!("~{} min", duration_minutes)}
+                        </span>
+                    </div>
+
+                    // Victory Points Badges
+                    <div class="flex gap-1">
+                        <span
+                            class="badge badge-sm badge-success badge-outline gap-1"
+                            title="Victory Points (Win)"
+                            data-testid="preview-victory-points-win"
+                        >
+                            <span class="font-bold">"W"</span>
+                            {tt_config.victory_points_win}
+                        </span>
+                        <span
+                            class="badge badge-sm badge-ghost badge-outline gap-1"
+                            title="Victory Points (Loss)"
+                            data-testid="preview-victory-points-loss"
+                        >
+                            <span class="font-bold">"L"</span>
+                            {tt_config.victory_points_loss}
+                        </span>
+                    </div>
+                </div>
+            </div>
+        }
+        .into_any()
+    }
+    fn render_preview(&self, config: &SportConfig) -> AnyView {
+        let tt_config = match self.validate_config(config, ValidationErrors::new()) {
+            Ok(cfg) => cfg,
+            Err(_) => return view! { <div>{"Invalid Configuration"}</div> }.into_any(),
+        };
+        view! {
+            <div class="p-2">
+                <span class="font-medium">{tt_config.sets_cfg.to_string()}</span>
+                <span class="hidden sm:inline text-base-content/30">"|"</span>
+                <span class="font-medium">{tt_config.expedite_cfg.to_string()}</span>
+            </div>
+        }
+        .into_any()
+    }
+}
@@ -0,0 +1,446 @@
+use app_core::{
+    SportError, SportResult,
+    utils::validation::{FieldError, ValidationErrors, ValidationResult},
+};
+use app_utils::enum_utils::SelectableOption;
+use serde::{Deserialize, Serialize};
+use serde_json::Value;
+use std::{fmt::Display, time::Duration};
+use uuid::Uuid;
+
+/// UltimateGameToCfg - points to win a game of ultimate frisbee
+#[derive(Debug, Clone, Copy, Serialize, Deserialize, Default, PartialEq)]
+pub enum UltimateGameToCfg {
+    /// Game to 11
+    GameTo11,
+    /// Game to 13
+    GameTo13,
+    /// Game to 15
+    #[default]
+    GameTo15,
+    /// Custom points to win a game
+    Custom { points: u16 },
+}
+
+impl Display for UltimateGameToCfg {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            UltimateGameToCfg::GameTo11 => write!(f, "Game to 11"),
+            UltimateGameToCfg::GameTo13 => write!(f, "Game to 13"),
+            UltimateGameToCfg::GameTo15 => write!(f, "Game to 15"),
+            UltimateGameToCfg::Custom { points } => write!(f, "Custom game to {}", points),
+        }
+    }
+}
+
+impl SelectableOption for UltimateGameToCfg {
+    fn value(&self) -> String {
+        self.to_string()
+    }
+
+    fn label(&self) -> String {
+        self.to_string()
+    }
+
+    fn options(&self) -> Vec<Self> {
+        let points = match self {
+            UltimateGameToCfg::Custom { points } => *points,
+            _ => 0,
+        };
+        vec![
+            UltimateGameToCfg::GameTo11,
+            UltimateGameToCfg::GameTo13,
+            UltimateGameToCfg::GameTo15,
+            UltimateGameToCfg::Custom { points },
+        ]
+    }
+
+    fn static_options() -> Vec<Self> {
+        Self::options(&Self::default())
+    }
+}
+
+impl UltimateGameToCfg {
+    /// Returns the points to win a game.
+    pub fn points(&self) -> u16 {
+        match self {
+            UltimateGameToCfg::GameTo11 => 11,
+            UltimateGameToCfg::GameTo13 => 13,
+            UltimateGameToCfg::GameTo15 => 15,
+            UltimateGameToCfg::Custom { points } => *points,
+        }
+    }
+
+    /// validates the game to configuration
+    pub fn validate(&self, object_id: Uuid, mut errs: ValidationErrors) -> ValidationErrors {
+        if self.points() == 0 {
+            errs.add(
+                FieldError::builder()
+                    .set_field("game_to_cfg")
+                    .add_user_defined_code("invalid_value")
+                    .add_message("points must be at least 1")
+                    .set_object_id(object_id)
+                    .build(),
+            );
+        }
+        errs
+    }
+}
+
+/// UltimatePointCapCfg - how a game is won on points
+#[derive(Debug, Clone, Copy, Serialize, Deserialize, Default, PartialEq)]
+pub enum UltimatePointCapCfg {
+    /// first team to reach the points to win wins the game
+    FirstTo,
+    /// game must be won by a margin of 2 points without cap
+    WinByTwo,
+    /// game must be won by a margin of 2 points; the first team to reach the cap wins
+    #[default]
+    WinByTwoCap17,
+    /// custom cap, if game is won by a margin of 2 points
+    WinByTwoCustomCap { cap: u16 },
+}
+
+impl Display for UltimatePointCapCfg {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            UltimatePointCapCfg::FirstTo => write!(f, "First to score"),
+            UltimatePointCapCfg::WinByTwo => write!(f, "Win by 2, no cap"),
+            UltimatePointCapCfg::WinByTwoCap17 => write!(f, "Win by 2, cap 17"),
+            UltimatePointCapCfg::WinByTwoCustomCap { cap } => {
+                write!(f, "Win by 2, custom cap {}", cap)
+            }
+        }
+    }
+}
+
+impl SelectableOption for UltimatePointCapCfg {
+    fn value(&self) -> String {
+        self.to_string()
+    }
+
+    fn label(&self) -> String {
+        self.to_string()
+    }
+
+    fn options(&self) -> Vec<Self> {
+        let cap = match self {
+            UltimatePointCapCfg::WinByTwoCustomCap { cap } => *cap,
+            _ => 0,
+        };
+        vec![
+            UltimatePointCapCfg::FirstTo,
+            UltimatePointCapCfg::WinByTwo,
+            UltimatePointCapCfg::WinByTwoCap17,
+            UltimatePointCapCfg::WinByTwoCustomCap { cap },
+        ]
+    }
+
+    fn static_options() -> Vec<Self> {
+        Self::options(&Self::default())
+    }
+}
+
+impl UltimatePointCapCfg {
+    /// Returns the point cap, if any.
+    pub fn cap(&self) -> Option<u16> {
+        match self {
+            UltimatePointCapCfg::WinByTwoCap17 => Some(17),
+            UltimatePointCapCfg::WinByTwoCustomCap { cap } => Some(*cap),
+            UltimatePointCapCfg::FirstTo | UltimatePointCapCfg::WinByTwo => None,
+        }
+    }
+
+    /// Returns the margin to win a game.
+    pub fn win_by_margin(&self) -> u16 {
+        match self {
+            UltimatePointCapCfg::FirstTo => 1,
+            _ => 2,
+        }
+    }
+
+    /// validates the point cap configuration against the points to win a game
+    pub fn validate(
+        &self,
+        game_to: u16,
+        object_id: Uuid,
+        mut errs: ValidationErrors,
+    ) -> ValidationErrors {
+        if let Some(cap) = self.cap()
+            && cap <= game_to
+        {
+            errs.add(
+                FieldError::builder()
+                    .set_field("point_cap_cfg")
+                    .add_user_defined_code("invalid_value")
+                    .add_message("cap must be greater than the points to win a game")
+                    .set_object_id(object_id)
+                    .build(),
+            );
+        }
+        errs
+    }
+}
+
+/// UltimateTimeCapCfg - time caps of a game
+///
+/// At the soft cap, the game continues to the highest score plus one point. At the hard
+/// cap, the game ends after the point in play; if the score is tied, one more point is
+/// played. Therefore a game may end with any score, as long as the last point was
+/// scored by the winner.
+#[derive(Debug, Clone, Copy, Serialize, Deserialize, Default, PartialEq)]
+pub enum UltimateTimeCapCfg {
+    /// no time caps
+    NoTimeCap,
+    /// soft and hard cap in minutes after start of game
+    #[default]
+    SoftCap75HardCap90,
+    /// custom soft and hard cap in minutes after start of game
+    Custom {
+        soft_cap_minutes: u16,
+        hard_cap_minutes: u16,
+    },
+}
+
+impl Display for UltimateTimeCapCfg {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            UltimateTimeCapCfg::NoTimeCap => write!(f, "No time cap"),
+            UltimateTimeCapCfg::SoftCap75HardCap90 => write!(f, "Soft cap 75 min, hard cap 90 min"),
+            UltimateTimeCapCfg::Custom {
+                soft_cap_minutes,
+                hard_cap_minutes,
+            } => write!(
+                f,
+                "Custom soft cap {} min, hard cap {} min",
+                soft_cap_minutes, hard_cap_minutes
+            ),
+        }
+    }
+}
+
+impl SelectableOption for UltimateTimeCapCfg {
+    fn value(&self) -> String {
+        self.to_string()
+    }
+
+    fn label(&self) -> String {
+        self.to_string()
+    }
+
+    fn options(&self) -> Vec<Self> {
+        let (soft_cap_minutes, hard_cap_minutes) = match self {
+            UltimateTimeCapCfg::Custom {
+                soft_cap_minutes,
+                hard_cap_minutes,
+            } => (*soft_cap_minutes, *hard_cap_minutes),
+            _ => (0, 0),
+        };
+        vec![
+            UltimateTimeCapCfg::NoTimeCap,
+            UltimateTimeCapCfg::SoftCap75HardCap90,
+            UltimateTimeCapCfg::Custom {
+                soft_cap_minutes,
+                hard_cap_minutes,
+            },
+        ]
+    }
+
+    fn static_options() -> Vec<Self> {
+        Self::options(&Self::default())
+    }
+}
+
+impl UltimateTimeCapCfg {
+    /// Returns soft and hard cap, if time caps are used.
+    pub fn caps(&self) -> Option<(Duration, Duration)> {
+        let (soft, hard) = match self {
+            UltimateTimeCapCfg::NoTimeCap => return None,
+            UltimateTimeCapCfg::SoftCap75HardCap90 => (75, 90),
+            UltimateTimeCapCfg::Custom {
+                soft_cap_minutes,
+                hard_cap_minutes,
+            } => (*soft_cap_minutes, *hard_cap_minutes),
+        };
+        Some((
+            Duration::from_secs(soft as u64 * 60),
+            Duration::from_secs(hard as u64 * 60),
+        ))
+    }
+
+    /// validates the time cap configuration
+    pub fn validate(&self, object_id: Uuid, mut errs: ValidationErrors) -> ValidationErrors {
+        if let UltimateTimeCapCfg::Custom {
+            soft_cap_minutes,
+            hard_cap_minutes,
+        } = self
+        {
+            if *hard_cap_minutes == 0 {
+                errs.add(
+                    FieldError::builder()
+                        .set_field("hard_cap_minutes")
+                        .add_user_defined_code("invalid_value")
+                        .add_message("hard cap must be at least 1 minute")
+                        .set_object_id(object_id)
+                        .build(),
+                );
+            }
+            if soft_cap_minutes > hard_cap_minutes {
+                errs.add(
+                    FieldError::builder()
+                        .set_field("soft_cap_minutes")
+                        .add_user_defined_code("invalid_value")
+                        .add_message("soft cap must not be after hard cap")
+                        .set_object_id(object_id)
+                        .build(),
+                );
+            }
+        }
+        errs
+    }
+}
+
+/// Configuration for the ultimate frisbee plugin
+#[derive(Debug, Clone, Copy, Serialize, Deserialize)]
+pub struct UltimateSportConfig {
+    /// points to win a game
+    pub game_to_cfg: UltimateGameToCfg,
+    /// winning margin and point cap
+    pub point_cap_cfg: UltimatePointCapCfg,
+    /// time caps of a game
+    pub time_cap_cfg: UltimateTimeCapCfg,
+    /// victory points gained by a win
+    pub victory_points_win: f32,
+    /// victory points gained by a loss
+    pub victory_points_loss: f32,
+    /// expected mean duration of a point including pull and time between points
+    /// Used to estimate match duration by multiplying with max number of points played
+    /// without extension, e.g. 15:13 for a game to 15, which must be won by 2 points.
+    pub expected_point_duration_seconds: Duration,
+}
+
+impl Default for UltimateSportConfig {
+    fn default() -> Self {
+        Self {
+            game_to_cfg: UltimateGameToCfg::GameTo15,
+            point_cap_cfg: UltimatePointCapCfg::WinByTwoCap17,
+            time_cap_cfg: UltimateTimeCapCfg::SoftCap75HardCap90,
+            victory_points_win: 1.0,
+            victory_points_loss: 0.0,
+            expected_point_duration_seconds: Duration::from_secs(240),
+        }
+    }
+}
+
+impl UltimateSportConfig {
+    pub fn parse_config(config: Value) -> SportResult<Self> {
+        match serde_json::from_value(config) {
+            Ok(sc) => Ok(sc),
+            Err(e) => Err(SportError::InvalidJsonConfig(format!(
+                "Failed to parse UltimateSportConfig: {}",
+                e
+            ))),
+        }
+    }
+    pub fn validate(&self, object_id: Uuid, errs: ValidationErrors) -> ValidationResult<()> {
+        let errs = self.game_to_cfg.validate(object_id, errs);
+        let errs = self
+            .point_cap_cfg
+            .validate(self.game_to_cfg.points(), object_id, errs);
+        let mut errs = self.time_cap_cfg.validate(object_id, errs);
+        if self.victory_points_win <= 0.0 {
+            errs.add(
+                FieldError::builder()
+                    .set_field("victory_points_win")
+                    .add_user_defined_code("invalid_value")
+                    .add_message("victory_points_win must be greater than 0")
+                    .set_object_id(object_id)
+                    .build(),
+            );
+        }
+        if self.victory_points_loss < 0.0 {
+            errs.add(
+                FieldError::builder()
+                    .set_field("victory_points_loss")
+                    .add_user_defined_code("invalid_value")
+                    .add_message("victory_points_loss must not be negative")
+                    .set_object_id(object_id)
+                    .build(),
+            );
+        }
+        if self.victory_points_win <= self.victory_points_loss {
+            errs.add(
+                FieldError::builder()
+                    .set_field("victory_points_loss")
+                    .add_user_defined_code("invalid_value")
+                    .add_message("victory_points_loss must be less than victory_points_win")
+                    .set_object_id(object_id)
+                    .build(),
+            );
+        }
+        if self.expected_point_duration_seconds.as_secs() == 0 {
+            errs.add(
+                FieldError::builder()
+                    .set_field("expected_point_duration_seconds")
+                    .add_user_defined_code("invalid_value")
+                    .add_message("expected_point_duration_seconds must be greater than 0")
+                    .set_object_id(object_id)
+                    .build(),
+            );
+        }
+        if errs.is_empty() { Ok(()) } else { Err(errs) }
+    }
+
+    /// Returns true, if a game with scores `score_a` and `score_b` is finished on points.
+    pub fn is_game_finished(&self, score_a: u16, score_b: u16) -> bool {
+        let max_score = score_a.max(score_b);
+        let min_score = score_a.min(score_b);
+        self.point_cap_cfg.cap().is_some_and(|cap| max_score >= cap)
+            || (max_score >= self.game_to_cfg.points()
+                && max_score - min_score >= self.point_cap_cfg.win_by_margin())
+    }
+
+    /// Validates the final score of a game. Games cannot end in a draw and the last point
+    /// is scored by the winner. Without time caps, the game must be finished on points.
+    pub fn validate_final_game_score(&self, score_a: u16, score_b: u16) -> SportResult<()> {
+        let max_score = score_a.max(score_b);
+        let min_score = score_a.min(score_b);
+        if max_score == min_score {
+            return Err(SportError::InvalidScore(
+                "Games of ultimate cannot end in a draw".to_string(),
+            ));
+        }
+        if self.time_cap_cfg.caps().is_none() && !self.is_game_finished(max_score, min_score) {
+            return Err(SportError::InvalidScore(
+                "Game is not finished on points".to_string(),
+            ));
+        }
+        if self.is_game_finished(max_score - 1, min_score) {
+            return Err(SportError::InvalidScore(
+                "Game is finished before last point".to_string(),
+            ));
+        }
+        Ok(())
+    }
+
+    /// Returns the maximum number of points played without extension beyond the points to
+    /// win, e.g. 15:13 or 28 points for a game to 15, which must be won by 2 points.
+    pub fn max_num_points_without_extension(&self) -> u16 {
+        let game_to = self.game_to_cfg.points();
+        game_to + game_to.saturating_sub(self.point_cap_cfg.win_by_margin())
+    }
+
+    /// Estimates the duration of a game by the number of points played. With time caps,
+    /// the game ends at the latest after the hard cap, the point in play and one more
+    /// point to break a tie.
+    pub fn estimate_match_duration(&self) -> Duration {
+        let duration =
+            self.expected_point_duration_seconds * self.max_num_points_without_extension() as u32;
+        match self.time_cap_cfg.caps() {
+            Some((_soft_cap, hard_cap)) => {
+                duration.min(hard_cap + self.expected_point_duration_seconds * 2)
+            }
+            None => duration,
+        }
+    }
+}
@@ -0,0 +1,222 @@
+//! Ultimate frisbee sport plugin
+//! A match is a single game to a configurable number of points with optional winning
+//! margin, point cap and soft/hard time caps.
+
+pub mod config;
+pub mod sport_port;
+pub mod sport_web_ui;
+
+use app_core::{
+    Match, SportConfig, SportError, SportResult,
+    utils::{
+        id_version::IdVersion,
+        namespace::project_namespace,
+        traits::ObjectIdVersion,
+        validation::{FieldError, ValidationErrors, ValidationResult},
+    },
+};
+use config::UltimateSportConfig;
+use uuid::Uuid;
+
+/// Implementation of the `SportPort` for ultimate frisbee.
+#[derive(Debug, Default, Clone, Copy)]
+pub struct UltimateSportPlugin {}
+
+impl UltimateSportPlugin {
+    pub fn new() -> Self {
+        Self {}
+    }
+    fn id(&self) -> Uuid {
+        // The ultimate sport plugin must use a fixed UUID.
+        let sport_name = "ultimate_frisbee";
+        Uuid::new_v5(&project_namespace(), sport_name.as_bytes())
+    }
+    fn validate_config(
+        &self,
+        config: &SportConfig,
+        errs: ValidationErrors,
+    ) -> ValidationResult<UltimateSportConfig> {
+        if config.get_sport_id() != self.id() {
+            let err = FieldError::builder()
+                .set_field("sport_id")
+                .add_message(format!(
+                    "Sport ID does not match UltimateSportPlugin id: expected {}, got {}",
+                    self.id(),
+                    config.get_sport_id()
+                ))
+                .set_object_id(config.get_id())
+                .build();
+            return Err(err.into());
+        }
+        let ultimate_config = match UltimateSportConfig::parse_config(config.get_config().clone()) {
+            Ok(cfg) => cfg,
+            Err(e) => {
+                let err = FieldError::builder()
+                    .set_field("sport_config_json")
+                    .add_message(format!("Invalid sport configuration JSON: {}", e))
+                    .set_object_id(config.get_id())
+                    .build();
+                return Err(err.into());
+            }
+        };
+        ultimate_config.validate(config.get_id(), errs)?;
+        Ok(ultimate_config)
+    }
+    fn validate_final_score_internal(
+        &self,
+        config: &UltimateSportConfig,
+        score: &Match,
+    ) -> SportResult<()> {
+        let (score_a, score_b) = score.get_scores();
+        if score_a.len() != 1 || score_b.len() != 1 {
+            return Err(SportError::InvalidScore(
+                "A match of ultimate consists of exactly one game".to_string(),
+            ));
+        }
+        config.validate_final_game_score(score_a[0], score_b[0])
+    }
+}
+
+impl ObjectIdVersion for UltimateSportPlugin {
+    fn get_id_version(&self) -> IdVersion {
+        // we can increment version later if changes are made to the sport plugin
+        IdVersion::new(self.id(), Some(0))
+    }
+}
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use app_core::{SportPort, utils::id_version::IdVersion};
+    use config::{UltimatePointCapCfg, UltimateTimeCapCfg};
+    use serde_json::json;
+    use std::time::Duration;
+
+    fn sport_config(plugin: &UltimateSportPlugin, config: serde_json::Value) -> SportConfig {
+        let id_version = IdVersion::new(Uuid::new_v4(), Some(1));
+        let mut sport_config = SportConfig::new(id_version);
+        sport_config
+            .set_sport_id(plugin.id())
+            .set_name("Ultimate")
+            .set_config(config);
+        sport_config
+    }
+
+    fn played(plugin: &UltimateSportPlugin, score_a: Vec<u16>, score_b: Vec<u16>) -> Match {
+        Match::new_played(
+            Uuid::new_v4(),
+            Uuid::new_v4(),
+            Uuid::new_v4(),
+            plugin.id(),
+            score_a,
+            score_b,
+        )
+    }
+
+    #[test]
+    fn test_validate_config() {
+        let plugin = UltimateSportPlugin::new();
+        let default_config = sport_config(&plugin, plugin.get_default_config());
+        assert!(
+            plugin
+                .validate_config(&default_config, ValidationErrors::new())
+                .is_ok()
+        );
+
+        let invalid_config = sport_config(
+            &plugin,
+            json!({
+                "game_to_cfg": "GameTo15",
+                "point_cap_cfg": { "WinByTwoCustomCap": { "cap": 15 } },
+                "time_cap_cfg": { "Custom": { "soft_cap_minutes": 90, "hard_cap_minutes": 75 } },
+                "victory_points_win": 1.0,
+                "victory_points_loss": 0.0,
+                "expected_point_duration_seconds": { "secs": 240, "nanos": 0 }
+            }),
+        );
+        let errs = plugin
+            .validate_config(&invalid_config, ValidationErrors::new())
+            .unwrap_err();
+        let fields: Vec<_> = errs.errors.iter().map(|e| e.get_field()).collect();
+        assert_eq!(fields, vec!["point_cap_cfg", "soft_cap_minutes"]);
+    }
+
+    #[test]
+    fn test_validate_final_score_without_time_cap() {
+        let plugin = UltimateSportPlugin::new();
+        let config = UltimateSportConfig {
+            time_cap_cfg: UltimateTimeCapCfg::NoTimeCap,
+            ..Default::default()
+        };
+        let config = sport_config(&plugin, serde_json::to_value(config).unwrap());
+        let validate = |a: u16, b: u16| {
+            plugin.validate_final_score(&config, &played(&plugin, vec![a], vec![b]))
+        };
+
+        assert!(validate(15, 9).is_ok());
+        assert!(validate(16, 14).is_ok());
+        // point cap 17 is reached at 16:16
+        assert!(validate(16, 17).is_ok());
+        // not finished on points
+        assert!(validate(14, 9).is_err());
+        assert!(validate(15, 14).is_err());
+        // finished before last point
+        assert!(validate(16, 9).is_err());
+        assert!(validate(18, 16).is_err());
+        // no draws
+        assert!(validate(15, 15).is_err());
+        // exactly one game
+        assert!(
+            plugin
+                .validate_final_score(&config, &played(&plugin, vec![15, 15], vec![9, 9]))
+                .is_err()
+        );
+    }
+
+    #[test]
+    fn test_validate_final_score_with_time_cap() {
+        let plugin = UltimateSportPlugin::new();
+        let config = sport_config(&plugin, plugin.get_default_config());
+        let validate = |a: u16, b: u16| {
+            plugin.validate_final_score(&config, &played(&plugin, vec![a], vec![b]))
+        };
+
+        // games may end at the hard cap with any lead
+        assert!(validate(11, 10).is_ok());
+        assert!(validate(15, 9).is_ok());
+        // but the game is still finished on points
+        assert!(validate(16, 9).is_err());
+        assert!(validate(10, 10).is_err());
+    }
+
+    #[test]
+    fn test_estimate_match_duration() {
+        let mut config = UltimateSportConfig {
+            point_cap_cfg: UltimatePointCapCfg::FirstTo,
+            time_cap_cfg: UltimateTimeCapCfg::NoTimeCap,
+            expected_point_duration_seconds: Duration::from_secs(180),
+            ..Default::default()
+        };
+        // 15:14 are 29 points
+        assert_eq!(
+            config.estimate_match_duration(),
+            Duration::from_secs(29 * 180)
+        );
+        // 15:13 are 28 points; hard cap after 90 minutes and two more points
+        config.point_cap_cfg = UltimatePointCapCfg::WinByTwoCap17;
+        config.time_cap_cfg = UltimateTimeCapCfg::SoftCap75HardCap90;
+        assert_eq!(
+            config.estimate_match_duration(),
+            Duration::from_secs(28 * 180).min(Duration::from_secs(90 * 60 + 2 * 180))
+        );
+        config.expected_point_duration_seconds = Duration::from_secs(240);
+        assert_eq!(
+            config.estimate_match_duration(),
+            Duration::from_secs(90 * 60 + 2 * 240)
+        );
+    }
+
+    #[test]
+    fn test_sport_plugin_conformance() {
+        sport_plugin_testkit::assert_conformance(std::sync::Arc::new(UltimateSportPlugin::new()));
+    }
+}
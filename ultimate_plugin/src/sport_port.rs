@@ -0,0 +1,116 @@
+//! Implementation of SportPort for ultimate frisbee
+
+use super::{UltimateSportPlugin, config::UltimateSportConfig};
+use app_core::{
+    EntrantGroupScore, Match, MatchOutcome, SportCapabilities, SportConfig, SportError, SportPort,
+    SportResult, StationType,
+    utils::validation::{ValidationErrors, ValidationResult},
+};
+use serde_json::Value;
+use std::time::Duration;
+use uuid::Uuid;
+
+impl SportPort for UltimateSportPlugin {
+    fn name(&self) -> &'static str {
+        "Ultimate Frisbee"
+    }
+    fn get_default_config(&self) -> Value {
+        serde_json::to_value(UltimateSportConfig::default()).unwrap()
+    }
+    fn capabilities(&self) -> SportCapabilities {
+        SportCapabilities {
+            supports_draws: false,
+            per_set_scoring: false,
+            supports_handicap: false,
+            supports_time_cap: true,
+            needs_station_type: Some(StationType::Field),
+        }
+    }
+    fn estimate_match_duration(&self, config: &SportConfig) -> SportResult<Duration> {
+        let ultimate_config = self.validate_config(config, ValidationErrors::new())?;
+        Ok(ultimate_config.estimate_match_duration())
+    }
+    fn free_ticket_score(&self, config: &SportConfig) -> SportResult<Vec<u16>> {
+        let ultimate_config = self.validate_config(config, ValidationErrors::new())?;
+        // a free ticket wins the game with the points of game to
+        Ok(vec![ultimate_config.game_to_cfg.points()])
+    }
+    fn validate_config_values(
+        &self,
+        config: &SportConfig,
+        errs: ValidationErrors,
+    ) -> ValidationResult<()> {
+        self.validate_config(config, errs)?;
+        Ok(())
+    }
+
+    /// Validates a final score against the rules defined in the configuration.
+    /// A match is a single game, which is won on points or ends at the time caps. The last
+    /// point is always scored by the winner.
+    /// Matches decided by forfeit or bye have no scores to validate.
+    fn validate_final_score(&self, config: &SportConfig, score: &Match) -> SportResult<()> {
+        if score.get_sport_id() != &self.id() {
+            return Err(SportError::InvalidScore(
+                "Match sport_id does not match UltimateSportPlugin id".to_string(),
+            ));
+        }
+        if score.get_entrants().is_none() {
+            return Err(SportError::InvalidScore(
+                "Both sides of the match must have concrete entrant IDs".to_string(),
+            ));
+        }
+        let ultimate_config = self.validate_config(config, ValidationErrors::new())?;
+        if score.get_outcome().is_walkover() {
+            return Ok(());
+        }
+        self.validate_final_score_internal(&ultimate_config, score)?;
+        Ok(())
+    }
+
+    /// Gathers and calculates entrant group score
+    fn get_entrant_group_score(
+        &self,
+        config: &SportConfig,
+        group_id: Uuid,
+        entrant_id: Uuid,
+        all_matches: &[Match],
+    ) -> SportResult<EntrantGroupScore> {
+        let ultimate_config = self.validate_config(config, ValidationErrors::new())?;
+        let free_ticket = self.free_ticket_score(config)?;
+        let mut group_score = EntrantGroupScore::new(entrant_id, group_id);
+        for m in all_matches.iter().filter(|m| {
+            if let Some((id_a, id_b)) = m.get_entrants() {
+                (id_a == &entrant_id || id_b == &entrant_id)
+                    && m.get_group_id() == &group_id
+                    && m.is_decided()
+            } else {
+                false
+            }
+        }) {
+            if m.get_outcome() == MatchOutcome::DoubleForfeit {
+                group_score.losses += 1;
+                continue;
+            }
+            // unwrap is safe due to filter
+            let (id_a, _id_b) = m.get_entrants().unwrap();
+            let entrant_is_a = id_a == &entrant_id;
+            let (score_a, score_b) = m.get_result_scores(&free_ticket);
+            let entrant_score = if entrant_is_a { &score_a } else { &score_b };
+            let opponent_score = if entrant_is_a { &score_b } else { &score_a };
+            // validation of final score guarantees exactly one game without draw
+            let (Some(&a), Some(&b)) = (entrant_score.first(), opponent_score.first()) else {
+                continue;
+            };
+            group_score.total_score += a;
+            group_score.relative_score += a as i16 - b as i16;
+            if a > b {
+                group_score.victory_points += ultimate_config.victory_points_win;
+                group_score.wins += 1;
+            } else {
+                group_score.victory_points += ultimate_config.victory_points_loss;
+                group_score.losses += 1;
+            }
+        }
+        Ok(group_score)
+    }
+}
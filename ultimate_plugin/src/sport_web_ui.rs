@@ -0,0 +1,439 @@
+//! Implementation of sport preview and configuration form for ultimate frisbee
+
+use super::UltimateSportPlugin;
+use crate::config::{
+    UltimateGameToCfg, UltimatePointCapCfg, UltimateSportConfig, UltimateTimeCapCfg,
+};
+use app_core::{
+    SportConfig, SportPort,
+    utils::validation::{ValidationErrors, ValidationResult},
+};
+use app_utils::{
+    components::inputs::{
+        DurationInput, DurationInputUnit, EnumSelect, InputCommitAction, NumberInput,
+    },
+    state::sport_config::SportConfigEditorContext,
+};
+use leptos::prelude::*;
+use shared::SportPortWebUi;
+use std::time::Duration;
+
+impl SportPortWebUi for UltimateSportPlugin {
+    fn render_plugin_selection(&self) -> AnyView {
+        view! {
+            <div
+                class="flex flex-col items-center justify-center gap-4 w-full"
+                data-testid="ultimate-plugin-selection"
+            >
+                // ToDo: Replace with proper icon later
+                <div class="text-6xl">"🥏"</div>
+                <div class="flex flex-col items-center">
+                    <h3 class="text-xl font-bold text-center">"Ultimate Frisbee"</h3>
+                    <span class="text-xs uppercase tracking-widest opacity-70">"WFDF"</span>
+                </div>
+            </div>
+        }
+        .into_any()
+    }
+    fn render_detailed_preview(&self, config: &SportConfig) -> AnyView {
+        let ultimate_config = match self.validate_config(config, ValidationErrors::new()) {
+            Ok(cfg) => cfg,
+            Err(_) => return view! { <div>{"Invalid Configuration"}</div> }.into_any(),
+        };
+
+        let duration_minutes = Self::new()
+            .estimate_match_duration(config)
+            .unwrap_or(Duration::from_secs(0))
+            .as_secs()
+            / 60;
+
+        view! {
+            <div
+                class="flex flex-wrap items-center gap-x-4 gap-y-2 p-3 bg-base-200 text-sm rounded-lg"
+                data-testid="table-entry-detailed-preview"
+            >
+                // 1. Block: Game Rules (Points, Point Cap & Time Cap)
+                <div class="flex flex-wrap items-center gap-2">
+                    <div class="flex items-center gap-1 font-semibold text-base-content">
+                        <span class="icon-[heroicons--trophy] w-4 h-4 opacity-70"></span>
+                        <span data-testid="preview-game-to-config">
+                            {ultimate_config.game_to_cfg.to_string()}
+                        </span>
+                    </div>
+
+                    <span class="hidden sm:inline text-base-content/30">"|"</span>
+
+                    <span class="text-base-content/80" data-testid="preview-point-cap-config">
+                        {ultimate_config.point_cap_cfg.to_string()}
+                    </span>
+
+                    <span class="hidden sm:inline text-base-content/30">"|"</span>
+
+                    <span class="text-base-content/80" data-testid="preview-time-cap-config">
+                        {ultimate_config.time_cap_cfg.to_string()}
+                    </span>
+                </div>
+
+                // 2. Block: Meta Info (Duration & Points)
+                <div class="flex items-center gap-3 ml-auto sm:ml-0">
+                    // Duration
+                    <div
+                        class="flex items-center gap-1 text-xs opacity-70"
+                        title="Expected Match Duration"
+                    >
+                        <span class="icon-[heroicons--clock] w-4 h-4"></span>
+                        <span data-testid="preview-expected-duration">
+                            {format!("~{} min", duration_minutes)}
+                        </span>
+                    </div>
+
+                    // Victory Points Badges
+                    <div class="flex gap-1">
+                        <span
+                            class="badge badge-sm badge-success badge-outline gap-1"
+                            title="Victory Points (Win)"
+                            data-testid="preview-victory-points-win"
+                        >
+                            <span class="font-bold">"W"</span>
+                            {ultimate_config.victory_points_win}
+                        </span>
+                        <span
+                            class="badge badge-sm badge-ghost badge-outline gap-1"
+                            title="Victory Points (Loss)"
+                            data-testid="preview-victory-points-loss"
+                        >
+                            <span class="font-bold">"L"</span>
+                            {ultimate_config.victory_points_loss}
+                        </span>
+                    </div>
+                </div>
+            </div>
+        }
+        .into_any()
+    }
+    fn render_preview(&self, config: &SportConfig) -> AnyView {
+        let ultimate_config = match self.validate_config(config, ValidationErrors::new()) {
+            Ok(cfg) => cfg,
+            Err(_) => return view! { <div>{"Invalid Configuration"}</div> }.into_any(),
+        };
+        view! {
+            <div class="p-2">
+                <span class="font-medium">{ultimate_config.game_to_cfg.to_string()}</span>
+                <span class="hidden sm:inline text-base-content/30">"|"</span>
+                <span class="font-medium">{ultimate_config.point_cap_cfg.to_string()}</span>
+            </div>
+        }
+        .into_any()
+    }
+    fn render_configuration(&self) -> AnyView {
+        // get editor context
+        let sport_config_editor = expect_context::<SportConfigEditorContext>();
+
+        // --- extract current configuration ---
+        let current_config = Signal::derive(move || {
+            if let Some(json_cfg) = sport_config_editor.config.get()
+                && let Ok(cfg) = UltimateSportConfig::parse_config(json_cfg)
+            {
+                Some(cfg)
+            } else {
+                None
+            }
+        });
+
+        let validation_result = Signal::derive(move || {
+            if let Some(object_id) = sport_config_editor.id.get()
+                && let Some(cfg) = current_config.get()
+            {
+                cfg.validate(object_id, ValidationErrors::new())
+            } else {
+                ValidationResult::Ok(())
+            }
+        });
+
+        // --- Signals for form fields ---
+        // configuration of points to win a game
+        let game_to_cfg =
+            Signal::derive(move || current_config.with(|cfg| cfg.as_ref().map(|c| c.game_to_cfg)));
+        let set_game_to_cfg = Callback::new(move |new_cfg: Option<UltimateGameToCfg>| {
+            if let Some(mut cfg) = current_config.get()
+                && let Some(new_cfg) = new_cfg
+            {
+                cfg.game_to_cfg = new_cfg;
+                sport_config_editor
+                    .set_config
+                    .set(serde_json::to_value(cfg).unwrap());
+            }
+        });
+        let game_to_points = Signal::derive(move || match game_to_cfg.get() {
+            Some(UltimateGameToCfg::Custom { points }) => Some(points),
+            _ => None,
+        });
+        let set_game_to_points = Callback::new(move |points: Option<u16>| {
+            if let Some(points) = points {
+                set_game_to_cfg.run(Some(UltimateGameToCfg::Custom { points }));
+            }
+        });
+        // configuration of winning margin and point cap
+        let point_cap_cfg = Signal::derive(move || {
+            current_config.with(|cfg| cfg.as_ref().map(|c| c.point_cap_cfg))
+        });
+        let set_point_cap_cfg = Callback::new(move |new_cfg: Option<UltimatePointCapCfg>| {
+            if let Some(mut cfg) = current_config.get()
+                && let Some(new_cfg) = new_cfg
+            {
+                cfg.point_cap_cfg = new_cfg;
+                sport_config_editor
+                    .set_config
+                    .set(serde_json::to_value(cfg).unwrap());
+            }
+        });
+        let point_cap = Signal::derive(move || match point_cap_cfg.get() {
+            Some(UltimatePointCapCfg::WinByTwoCustomCap { cap }) => Some(cap),
+            _ => None,
+        });
+        let set_point_cap = Callback::new(move |cap: Option<u16>| {
+            if let Some(cap) = cap {
+                set_point_cap_cfg.run(Some(UltimatePointCapCfg::WinByTwoCustomCap { cap }));
+            }
+        });
+        // configuration of time caps
+        let time_cap_cfg =
+            Signal::derive(move || current_config.with(|cfg| cfg.as_ref().map(|c| c.time_cap_cfg)));
+        let set_time_cap_cfg = Callback::new(move |new_cfg: Option<UltimateTimeCapCfg>| {
+            if let Some(mut cfg) = current_config.get()
+                && let Some(new_cfg) = new_cfg
+            {
+                cfg.time_cap_cfg = new_cfg;
+                sport_config_editor
+                    .set_config
+                    .set(serde_json::to_value(cfg).unwrap());
+            }
+        });
+        let custom_time_caps = Signal::derive(move || match time_cap_cfg.get() {
+            Some(UltimateTimeCapCfg::Custom {
+                soft_cap_minutes,
+                hard_cap_minutes,
+            }) => Some((soft_cap_minutes, hard_cap_minutes)),
+            _ => None,
+        });
+        let soft_cap_minutes = Signal::derive(move || custom_time_caps.get().map(|(soft, _)| soft));
+        let set_soft_cap_minutes = Callback::new(move |minutes: Option<u16>| {
+            if let Some(soft_cap_minutes) = minutes
+                && let Some((_, hard_cap_minutes)) = custom_time_caps.get()
+            {
+                set_time_cap_cfg.run(Some(UltimateTimeCapCfg::Custom {
+                    soft_cap_minutes,
+                    hard_cap_minutes,
+                }));
+            }
+        });
+        let hard_cap_minutes = Signal::derive(move || custom_time_caps.get().map(|(_, hard)| hard));
+        let set_hard_cap_minutes = Callback::new(move |minutes: Option<u16>| {
+            if let Some(hard_cap_minutes) = minutes
+                && let Some((soft_cap_minutes, _)) = custom_time_caps.get()
+            {
+                set_time_cap_cfg.run(Some(UltimateTimeCapCfg::Custom {
+                    soft_cap_minutes,
+                    hard_cap_minutes,
+                }));
+            }
+        });
+        let victory_points_win = Signal::derive(move || {
+            current_config.with(|cfg| cfg.as_ref().map(|c| c.victory_points_win))
+        });
+        let set_victory_points_win = Callback::new(move |points: Option<f32>| {
+            if let Some(mut cfg) = current_config.get() {
+                cfg.victory_points_win = points.unwrap_or_default();
+                sport_config_editor
+                    .set_config
+                    .set(serde_json::to_value(cfg).unwrap());
+            }
+        });
+        let victory_points_loss = Signal::derive(move || {
+            current_config.with(|cfg| cfg.as_ref().map(|c| c.victory_points_loss))
+        });
+        let set_victory_points_loss = Callback::new(move |points: Option<f32>| {
+            if let Some(mut cfg) = current_config.get() {
+                cfg.victory_points_loss = points.unwrap_or_default();
+                sport_config_editor
+                    .set_config
+                    .set(serde_json::to_value(cfg).unwrap());
+            }
+        });
+        let expected_point_duration_seconds = Signal::derive(move || {
+            current_config.with(|cfg| cfg.as_ref().map(|c| c.expected_point_duration_seconds))
+        });
+        let set_expected_point_duration_seconds =
+            Callback::new(move |duration: Option<Duration>| {
+                if let Some(mut cfg) = current_config.get() {
+                    cfg.expected_point_duration_seconds =
+                        duration.unwrap_or(Duration::from_secs(0));
+                    sport_config_editor
+                        .set_config
+                        .set(serde_json::to_value(cfg).unwrap());
+                }
+            });
+
+        view! {
+            <div class="space-y-4" data-testid="sport-config-configuration">
+                <EnumSelect
+                    label="Game to"
+                    name="game_to_cfg"
+                    data_testid="select-game_to_cfg"
+                    value=game_to_cfg
+                    action=InputCommitAction::WriteAndSubmit(set_game_to_cfg)
+                />
+                {move || {
+                    match game_to_cfg.get() {
+                        Some(UltimateGameToCfg::Custom { .. }) => {
+                            view! {
+                                <NumberInput
+                                    label="Points to win a Game"
+                                    name="game_to_points"
+                                    data_testid="input-game_to_points"
+                                    value=game_to_points
+                                    action=InputCommitAction::WriteAndSubmit(set_game_to_points)
+                                    validation_result=validation_result
+                                    object_id=sport_config_editor.id
+                                    field="game_to_cfg"
+                                    min="1"
+                                />
+                            }
+                                .into_any()
+                        }
+                        _ => ().into_any(),
+                    }
+                }}
+                <EnumSelect
+                    label="Point Cap"
+                    name="point_cap_cfg"
+                    data_testid="select-point_cap_cfg"
+                    value=point_cap_cfg
+                    action=InputCommitAction::WriteAndSubmit(set_point_cap_cfg)
+                />
+                {move || {
+                    match point_cap_cfg.get() {
+                        Some(UltimatePointCapCfg::WinByTwoCustomCap { .. }) => {
+                            view! {
+                                <NumberInput
+                                    label="Cap"
+                                    name="point_cap"
+                                    data_testid="input-point_cap"
+                                    value=point_cap
+                                    action=InputCommitAction::WriteAndSubmit(set_point_cap)
+                                    validation_result=validation_result
+                                    object_id=sport_config_editor.id
+                                    field="point_cap_cfg"
+                                    min="1"
+                                />
+                            }
+                                .into_any()
+                        }
+                        _ => ().into_any(),
+                    }
+                }}
+                <EnumSelect
+                    label="Time Cap"
+                    name="time_cap_cfg"
+                    data_testid="select-time_cap_cfg"
+                    value=time_cap_cfg
+                    action=InputCommitAction::WriteAndSubmit(set_time_cap_cfg)
+                />
+                {move || {
+                    match time_cap_cfg.get() {
+                        Some(UltimateTimeCapCfg::Custom { .. }) => {
+                            view! {
+                                <div class="grid grid-cols-2 gap-4">
+                                    <NumberInput
+                                        label="Soft Cap (Minutes)"
+                                        name="soft_cap_minutes"
+                                        data_testid="input-soft_cap_minutes"
+                                        value=soft_cap_minutes
+                                        action=InputCommitAction::WriteAndSubmit(
+                                            set_soft_cap_minutes,
+                                        )
+                                        validation_result=validation_result
+                                        object_id=sport_config_editor.id
+                                        field="soft_cap_minutes"
+                                        min="0"
+                                    />
+                                    <NumberInput
+                                        label="Hard Cap (Minutes)"
+                                        name="hard_cap_minutes"
+                                        data_testid="input-hard_cap_minutes"
+                                        value=hard_cap_minutes
+                                        action=InputCommitAction::WriteAndSubmit(
+                                            set_hard_cap_minutes,
+                                        )
+                                        validation_result=validation_result
+                                        object_id=sport_config_editor.id
+                                        field="hard_cap_minutes"
+                                        min="1"
+                                    />
+                                </div>
+                            }
+                                .into_any()
+                        }
+                        _ => ().into_any(),
+                    }
+                }}
+                <div class="grid grid-cols-2 gap-4">
+                    <NumberInput
+                        label="Victory Points for Win"
+                        name="victory_points_win"
+                        data_testid="input-victory_points_win"
+                        value=victory_points_win
+                        action=InputCommitAction::WriteAndSubmit(set_victory_points_win)
+                        validation_result=validation_result
+                        object_id=sport_config_editor.id
+                        field="victory_points_win"
+                        min="0"
+                        step="0.1"
+                    />
+                    <NumberInput
+                        label="Victory Points for Loss"
+                        name="victory_points_loss"
+                        data_testid="input-victory_points_loss"
+                        value=victory_points_loss
+                        action=InputCommitAction::WriteAndSubmit(set_victory_points_loss)
+                        validation_result=validation_result
+                        object_id=sport_config_editor.id
+                        field="victory_points_loss"
+                        min="0"
+                        step="0.1"
+                    />
+                </div>
+                <DurationInput
+                    label="Expected Point Duration"
+                    name="expected_point_duration_seconds"
+                    data_testid="input-expected_point_duration_seconds"
+                    value=expected_point_duration_seconds
+                    action=InputCommitAction::WriteAndSubmit(set_expected_point_duration_seconds)
+                    unit=DurationInputUnit::Seconds
+                    validation_result=validation_result
+                    object_id=sport_config_editor.id
+                    field="expected_point_duration_seconds"
+                />
+                <div class="form-control w-full">
+                    <label class="label">
+                        <span class="label-text">"Estimated Match Duration"</span>
+                    </label>
+                    <div class="input input-bordered flex items-center bg-base-200 text-base-content/70 cursor-not-allowed">
+                        {move || {
+                            let minutes = current_config
+                                .with(|cfg_opt| {
+                                    if let Some(cfg) = cfg_opt {
+                                        cfg.estimate_match_duration().as_secs() / 60
+                                    } else {
+                                        0
+                                    }
+                                });
+                            format!("{minutes} minutes")
+                        }}
+                    </div>
+                </div>
+            </div>
+        }
+        .into_any()
+    }
+}
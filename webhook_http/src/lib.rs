@@ -0,0 +1,60 @@
+// webhook transport posting payloads via http(s)
+
+use app_core::{WebhookError, WebhookResult, WebhookTransportPort};
+use async_trait::async_trait;
+use reqwest::{Client, header::CONTENT_TYPE};
+use std::time::Duration;
+use tracing::{instrument, warn};
+
+/// default timeout of a single webhook request
+pub const DEFAULT_TIMEOUT: Duration = Duration::from_secs(10);
+
+/// Webhook transport based upon reqwest.
+///
+/// Redirects are not followed, since receivers are expected to answer at the configured
+/// url. Any response status is returned to the caller and recorded in the delivery history.
+#[derive(Clone)]
+pub struct HttpWebhookTransport {
+    client: Client,
+}
+
+impl HttpWebhookTransport {
+    pub fn new(timeout: Duration) -> WebhookResult<Self> {
+        let client = Client::builder()
+            .timeout(timeout)
+            .redirect(reqwest::redirect::Policy::none())
+            .user_agent(concat!("fk_tournament_planer/", env!("CARGO_PKG_VERSION")))
+            .build()
+            .map_err(|e| WebhookError::Request(e.to_string()))?;
+        Ok(HttpWebhookTransport { client })
+    }
+}
+
+#[async_trait]
+impl WebhookTransportPort for HttpWebhookTransport {
+    #[instrument(name = "webhook.post", skip(self, headers, body))]
+    async fn post_json(
+        &self,
+        url: &str,
+        headers: &[(String, String)],
+        body: &str,
+    ) -> WebhookResult<u16> {
+        let mut request = self
+            .client
+            .post(url)
+            .header(CONTENT_TYPE, "application/json")
+            .body(body.to_string());
+        for (name, value) in headers {
+            request = request.header(name, value);
+        }
+        let response = request.send().await.map_err(|e| {
+            warn!(error = %e, "webhook_request_failed");
+            if e.is_timeout() {
+                WebhookError::Timeout
+            } else {
+                WebhookError::Request(e.to_string())
+            }
+        })?;
+        Ok(response.status().as_u16())
+    }
+}